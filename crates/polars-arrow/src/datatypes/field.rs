@@ -8,6 +8,8 @@ use super::{ArrowDataType, Metadata};
 
 pub static DTYPE_ENUM_VALUES: &str = "_PL_ENUM_VALUES";
 pub static DTYPE_CATEGORICAL: &str = "_PL_CATEGORICAL";
+pub static DTYPE_FLOAT16: &str = "_PL_FLOAT16";
+pub static DTYPE_GEOMETRY: &str = "_PL_GEOMETRY";
 
 /// Represents Arrow's metadata of a "column".
 ///
@@ -76,17 +76,26 @@ where
         }
     }
 
-    #[inline]
-    fn hash_value(&self, obj: &T) -> u64 {
-        SEED.hash_one(obj)
+    /// Creates a HyperLogLog from the registers of another HyperLogLog, see [`AsRef<[u8]>`].
+    ///
+    /// Returns `None` if `registers` aren't valid registers of a HyperLogLog.
+    pub fn try_from_registers(registers: &[u8]) -> Option<Self> {
+        if !Self::is_valid_registers(registers) {
+            return None;
+        }
+        Some(Self::new_with_registers(registers.try_into().ok()?))
+    }
+
+    /// Whether `registers` have the length of the registers of a HyperLogLog, and every register
+    /// a value that [`add`](Self::add) can set.
+    fn is_valid_registers(registers: &[u8]) -> bool {
+        registers.len() == NUM_REGISTERS && registers.iter().all(|&r| r as usize <= HLL_Q + 1)
     }
 
     /// Adds an element to the HyperLogLog.
     pub fn add(&mut self, obj: &T) {
-        let hash = self.hash_value(obj);
-        let index = (hash & HLL_P_MASK) as usize;
-        let p = ((hash >> HLL_P) | (1_u64 << HLL_Q)).trailing_zeros() + 1;
-        self.registers[index] = self.registers[index].max(p as u8);
+        let (index, p) = register(hash(obj));
+        self.registers[index] = self.registers[index].max(p);
     }
 
     /// Get the register histogram (each value in register index into
@@ -114,17 +123,190 @@ where
         }
     }
 
+    /// Merge the registers of another [`HyperLogLog`] into this one, see [`AsRef<[u8]>`].
+    ///
+    /// Returns `false` without merging if `registers` aren't valid registers of a HyperLogLog.
+    pub fn merge_registers(&mut self, registers: &[u8]) -> bool {
+        if !Self::is_valid_registers(registers) {
+            return false;
+        }
+        for (r, other) in self.registers.iter_mut().zip(registers) {
+            *r = (*r).max(*other);
+        }
+        true
+    }
+
     /// Guess the number of unique elements seen by the HyperLogLog.
     pub fn count(&self) -> usize {
-        let histogram = self.get_histogram();
-        let m = NUM_REGISTERS as f64;
-        let mut z = m * hll_tau((m - histogram[HLL_Q + 1] as f64) / m);
-        for i in histogram[1..=HLL_Q].iter().rev() {
-            z += *i as f64;
-            z *= 0.5;
+        estimate(&self.get_histogram())
+    }
+}
+
+/// The hash a HyperLogLog sets a register from when an element is added.
+#[inline]
+pub fn hash<T: Hash + ?Sized>(obj: &T) -> u64 {
+    SEED.hash_one(obj)
+}
+
+/// The index of the register a hash sets, and the value it sets it to at least.
+#[inline]
+fn register(hash: u64) -> (usize, u8) {
+    let index = (hash & HLL_P_MASK) as usize;
+    let p = ((hash >> HLL_P) | (1_u64 << HLL_Q)).trailing_zeros() + 1;
+    (index, p as u8)
+}
+
+/// Estimate the number of unique elements from the histogram of the register values.
+fn estimate(histogram: &[u32; HLL_Q + 2]) -> usize {
+    let m = NUM_REGISTERS as f64;
+    let mut z = m * hll_tau((m - histogram[HLL_Q + 1] as f64) / m);
+    for i in histogram[1..=HLL_Q].iter().rev() {
+        z += *i as f64;
+        z *= 0.5;
+    }
+    z += m * hll_sigma(histogram[0] as f64 / m);
+    (0.5 / 2_f64.ln() * m * m / z).round() as usize
+}
+
+/// The number of non-zero registers a [`SparseHyperLogLog`] stores sparsely. A sparse register
+/// takes four bytes, so at this size the sparse registers take a quarter of the dense ones.
+const MAX_SPARSE_REGISTERS: usize = NUM_REGISTERS / 16;
+
+#[derive(Clone, Debug)]
+enum Registers {
+    /// The non-zero registers as `index << 8 | value`, sorted by index.
+    Sparse(Vec<u32>),
+    Dense(Box<[u8; NUM_REGISTERS]>),
+}
+
+/// A HyperLogLog that only stores its non-zero registers until there are many of them, for when
+/// many sketches are kept at once, e.g. one per group.
+///
+/// It is updated with [`hash`]es, and has the same registers as a [`HyperLogLog`] that saw the
+/// same elements.
+#[derive(Clone, Debug)]
+pub struct SparseHyperLogLog {
+    registers: Registers,
+}
+
+impl Default for SparseHyperLogLog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SparseHyperLogLog {
+    /// Creates a new, empty HyperLogLog.
+    pub fn new() -> Self {
+        Self {
+            registers: Registers::Sparse(Vec::new()),
         }
-        z += m * hll_sigma(histogram[0] as f64 / m);
-        (0.5 / 2_f64.ln() * m * m / z).round() as usize
+    }
+
+    /// Adds the [`hash`] of an element.
+    #[inline]
+    pub fn add_hash(&mut self, hash: u64) {
+        let (index, p) = register(hash);
+        self.set_max(index, p);
+    }
+
+    fn set_max(&mut self, index: usize, value: u8) {
+        match &mut self.registers {
+            Registers::Dense(registers) => {
+                registers[index] = registers[index].max(value);
+            },
+            Registers::Sparse(entries) => {
+                let entry = ((index as u32) << 8) | value as u32;
+                match entries.binary_search_by_key(&(index as u32), |e| e >> 8) {
+                    Ok(i) => entries[i] = entries[i].max(entry),
+                    Err(_) if value == 0 => {},
+                    Err(i) => {
+                        entries.insert(i, entry);
+                        if entries.len() > MAX_SPARSE_REGISTERS {
+                            self.densify();
+                        }
+                    },
+                }
+            },
+        }
+    }
+
+    fn densify(&mut self) {
+        if let Registers::Sparse(entries) = &self.registers {
+            let mut registers = Box::new([0; NUM_REGISTERS]);
+            for e in entries {
+                registers[(e >> 8) as usize] = *e as u8;
+            }
+            self.registers = Registers::Dense(registers);
+        }
+    }
+
+    /// Merge the other [`SparseHyperLogLog`] into this one.
+    pub fn merge(&mut self, other: &SparseHyperLogLog) {
+        match &other.registers {
+            Registers::Sparse(entries) => {
+                for e in entries {
+                    self.set_max((e >> 8) as usize, *e as u8);
+                }
+            },
+            Registers::Dense(other) => {
+                self.densify();
+                let Registers::Dense(registers) = &mut self.registers else {
+                    unreachable!()
+                };
+                for (r, other) in registers.iter_mut().zip(other.iter()) {
+                    *r = (*r).max(*other);
+                }
+            },
+        }
+    }
+
+    /// Merge the registers of a [`HyperLogLog`] into this one, see [`AsRef<[u8]>`].
+    ///
+    /// Returns `false` without merging if `registers` aren't valid registers of a HyperLogLog.
+    pub fn merge_registers(&mut self, registers: &[u8]) -> bool {
+        if !HyperLogLog::<()>::is_valid_registers(registers) {
+            return false;
+        }
+        for (index, r) in registers.iter().enumerate() {
+            if *r > 0 {
+                self.set_max(index, *r);
+            }
+        }
+        true
+    }
+
+    /// The registers of the [`HyperLogLog`] that saw the same elements.
+    pub fn to_registers(&self) -> Vec<u8> {
+        match &self.registers {
+            Registers::Sparse(entries) => {
+                let mut registers = vec![0; NUM_REGISTERS];
+                for e in entries {
+                    registers[(e >> 8) as usize] = *e as u8;
+                }
+                registers
+            },
+            Registers::Dense(registers) => registers.to_vec(),
+        }
+    }
+
+    /// Guess the number of unique elements seen by the HyperLogLog.
+    pub fn count(&self) -> usize {
+        let mut histogram = [0; HLL_Q + 2];
+        match &self.registers {
+            Registers::Sparse(entries) => {
+                histogram[0] = (NUM_REGISTERS - entries.len()) as u32;
+                for e in entries {
+                    histogram[(*e as u8) as usize] += 1;
+                }
+            },
+            Registers::Dense(registers) => {
+                for r in registers.iter() {
+                    histogram[*r as usize] += 1;
+                }
+            },
+        }
+        estimate(&histogram)
     }
 }
 
@@ -209,7 +391,7 @@ where
 
 #[cfg(test)]
 mod tests {
-    use super::{HyperLogLog, NUM_REGISTERS};
+    use super::{HLL_Q, HyperLogLog, NUM_REGISTERS, SparseHyperLogLog, hash};
 
     fn compare_with_delta(got: usize, expected: usize) {
         let expected = expected as f64;
@@ -334,6 +516,71 @@ mod tests {
         compare_with_delta(hll.count(), 1000);
     }
 
+    #[test]
+    fn test_merge_registers() {
+        let mut hll = HyperLogLog::<u64>::new();
+        hll.extend(0..1000);
+
+        let mut other = HyperLogLog::<u64>::new();
+        other.extend(500..2000);
+
+        let mut merged = HyperLogLog::<u64>::try_from_registers(hll.as_ref()).unwrap();
+        assert!(merged.merge_registers(other.as_ref()));
+        compare_with_delta(merged.count(), 2000);
+
+        assert!(!merged.merge_registers(&[0; 3]));
+        assert!(HyperLogLog::<u64>::try_from_registers(&[0; 3]).is_none());
+
+        // a register can't be larger than the number of bits of a hash after the index, plus one
+        let mut registers = hll.as_ref().to_vec();
+        registers[0] = (HLL_Q + 1) as u8;
+        assert!(HyperLogLog::<u64>::try_from_registers(&registers).is_some());
+        registers[0] = (HLL_Q + 2) as u8;
+        assert!(HyperLogLog::<u64>::try_from_registers(&registers).is_none());
+        assert!(!merged.merge_registers(&registers));
+        compare_with_delta(merged.count(), 2000);
+    }
+
+    #[test]
+    fn test_sparse() {
+        for n in [0, 10, 1000, 100_000] {
+            let mut hll = HyperLogLog::<u64>::new();
+            hll.extend(0..n);
+            let mut sparse = SparseHyperLogLog::new();
+            for i in 0..n {
+                sparse.add_hash(hash(&i));
+            }
+            assert_eq!(sparse.to_registers(), hll.as_ref());
+            assert_eq!(sparse.count(), hll.count());
+        }
+    }
+
+    #[test]
+    fn test_sparse_merge() {
+        let mut hll = HyperLogLog::<u64>::new();
+        hll.extend(0..20_000);
+
+        let mut small = SparseHyperLogLog::new();
+        let mut large = SparseHyperLogLog::new();
+        for i in 0..10 {
+            small.add_hash(hash(&i));
+        }
+        for i in 10..20_000 {
+            large.add_hash(hash(&i));
+        }
+
+        let mut merged = small.clone();
+        merged.merge(&large);
+        assert_eq!(merged.to_registers(), hll.as_ref());
+        large.merge(&small);
+        assert_eq!(large.to_registers(), hll.as_ref());
+
+        let mut merged = SparseHyperLogLog::new();
+        assert!(merged.merge_registers(hll.as_ref()));
+        assert_eq!(merged.count(), hll.count());
+        assert!(!merged.merge_registers(&[0; 3]));
+    }
+
     #[test]
     fn test_repetition() {
         let mut hll = HyperLogLog::<u32>::new();
@@ -29,6 +29,8 @@ pub mod propagate_dictionary;
 pub mod rolling;
 pub mod size;
 pub mod sum;
+#[cfg(feature = "approx_quantile")]
+pub mod tdigest;
 pub mod unique;
 pub mod var_cov;
 
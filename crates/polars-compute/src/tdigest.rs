@@ -0,0 +1,272 @@
+//! # t-digest
+//!
+//! A mergeable sketch of a distribution of floats, used to compute approximate quantiles in a
+//! single pass with bounded memory. This is the merging variant of the t-digest described in
+//! [Computing Extremely Accurate Quantiles Using t-Digests](https://arxiv.org/abs/1902.04023).
+//!
+//! The values are summarized by centroids, weighted means of adjacent values. Centroids near the
+//! tails hold fewer values than centroids near the median, so extreme quantiles are more accurate
+//! than a uniform summary of the same size would be.
+//!
+//! # Examples
+//!
+//! ```
+//!     # use polars_compute::tdigest::*;
+//!     let mut digest = TDigest::new();
+//!     for v in 0..=100 {
+//!         digest.add(v as f64);
+//!     }
+//!
+//!     assert_eq!(digest.quantile(0.5), Some(50.0));
+//! ```
+
+/// The default compression, which bounds the number of centroids to about twice its value.
+pub const DEFAULT_COMPRESSION: f64 = 100.0;
+/// The number of values that are buffered per unit of compression before they are merged.
+const BUFFER_FACTOR: usize = 5;
+
+#[derive(Clone, Copy, Debug)]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+impl Centroid {
+    fn absorb(&mut self, other: &Centroid) {
+        self.weight += other.weight;
+        self.mean += (other.mean - self.mean) * other.weight / self.weight;
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct TDigest {
+    compression: f64,
+    /// The merged centroids, sorted by their mean.
+    centroids: Vec<Centroid>,
+    /// Values that haven't been merged into the centroids yet.
+    buffer: Vec<f64>,
+    min: f64,
+    max: f64,
+}
+
+impl Default for TDigest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TDigest {
+    pub fn new() -> Self {
+        Self::with_compression(DEFAULT_COMPRESSION)
+    }
+
+    /// A digest that keeps about `2 * compression` centroids. A higher compression is more
+    /// accurate but uses more memory.
+    pub fn with_compression(compression: f64) -> Self {
+        assert!(
+            compression >= 1.0,
+            "t-digest compression must be at least 1"
+        );
+        Self {
+            compression,
+            centroids: Vec::new(),
+            buffer: Vec::new(),
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+
+    /// Adds a value, NaN values are ignored.
+    pub fn add(&mut self, value: f64) {
+        if value.is_nan() {
+            return;
+        }
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        self.buffer.push(value);
+        if self.buffer.len() >= BUFFER_FACTOR * self.compression as usize {
+            self.compress();
+        }
+    }
+
+    /// Merges `other` into `self`, as if all values added to `other` were added to `self`.
+    pub fn merge(&mut self, other: &TDigest) {
+        if other.is_empty() {
+            return;
+        }
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+        self.centroids.extend_from_slice(&other.centroids);
+        self.buffer.extend_from_slice(&other.buffer);
+        self.merge_centroids();
+    }
+
+    /// The number of values added to the digest.
+    pub fn count(&self) -> u64 {
+        let merged: f64 = self.centroids.iter().map(|c| c.weight).sum();
+        merged as u64 + self.buffer.len() as u64
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.centroids.is_empty() && self.buffer.is_empty()
+    }
+
+    /// The estimated value at the `quantile`, which must be in `[0, 1]`. Returns `None` if no
+    /// values were added.
+    pub fn quantile(&mut self, quantile: f64) -> Option<f64> {
+        debug_assert!((0.0..=1.0).contains(&quantile));
+        self.compress();
+        let centroids = &self.centroids;
+        let first = centroids.first()?;
+        let last = centroids.last().unwrap();
+
+        let total: f64 = centroids.iter().map(|c| c.weight).sum();
+        let target = quantile * total;
+        if target <= 0.0 {
+            return Some(self.min);
+        }
+        if target >= total {
+            return Some(self.max);
+        }
+
+        // Interpolate between the minimum and the center of the first centroid, the centers of
+        // two adjacent centroids, or the center of the last centroid and the maximum.
+        if target < first.weight / 2.0 {
+            let t = target / (first.weight / 2.0);
+            return Some(self.min + (first.mean - self.min) * t);
+        }
+        if target > total - last.weight / 2.0 {
+            let t = (total - target) / (last.weight / 2.0);
+            return Some(self.max - (self.max - last.mean) * t);
+        }
+
+        let mut left_center = first.weight / 2.0;
+        for window in centroids.windows(2) {
+            let (left, right) = (&window[0], &window[1]);
+            let right_center = left_center + (left.weight + right.weight) / 2.0;
+            if target <= right_center {
+                let t = (target - left_center) / (right_center - left_center);
+                return Some(left.mean + (right.mean - left.mean) * t);
+            }
+            left_center = right_center;
+        }
+        Some(last.mean)
+    }
+
+    /// The fraction of the values a centroid starting at the fraction `q` may extend to, using
+    /// the `k1` scale function of the paper.
+    fn q_limit(&self, q: f64) -> f64 {
+        use std::f64::consts::PI;
+
+        let k = self.compression / (2.0 * PI) * (2.0 * q - 1.0).asin() + 1.0;
+        if k >= self.compression / 4.0 {
+            return 1.0;
+        }
+        ((2.0 * PI * k / self.compression).sin() + 1.0) / 2.0
+    }
+
+    /// Merges the buffered values into the centroids.
+    fn compress(&mut self) {
+        if !self.buffer.is_empty() {
+            self.merge_centroids();
+        }
+    }
+
+    /// Merges the buffered values and the centroids into as few centroids as the scale function
+    /// allows.
+    fn merge_centroids(&mut self) {
+        let mut all = std::mem::take(&mut self.centroids);
+        all.extend(
+            self.buffer
+                .drain(..)
+                .map(|mean| Centroid { mean, weight: 1.0 }),
+        );
+        all.sort_unstable_by(|a, b| a.mean.total_cmp(&b.mean));
+
+        let total: f64 = all.iter().map(|c| c.weight).sum();
+        let mut merged = Vec::with_capacity(2 * self.compression as usize);
+        let mut current = all[0];
+        let mut weight_before = 0.0;
+        let mut limit = total * self.q_limit(0.0);
+        for c in &all[1..] {
+            if weight_before + current.weight + c.weight <= limit {
+                current.absorb(c);
+            } else {
+                weight_before += current.weight;
+                merged.push(current);
+                limit = total * self.q_limit((weight_before / total).min(1.0));
+                current = *c;
+            }
+        }
+        merged.push(current);
+        self.centroids = merged;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TDigest;
+
+    fn digest_of(values: impl IntoIterator<Item = f64>) -> TDigest {
+        let mut digest = TDigest::new();
+        for v in values {
+            digest.add(v);
+        }
+        digest
+    }
+
+    /// A permutation of `0..n` so the values aren't added in order.
+    fn shuffled(n: u64) -> impl Iterator<Item = f64> {
+        (0..n).map(move |i| ((i * 7919) % n) as f64)
+    }
+
+    #[test]
+    fn test_small_is_exact() {
+        let mut digest = digest_of([3.0, 1.0, 4.0, 2.0]);
+        assert_eq!(digest.quantile(0.0), Some(1.0));
+        assert_eq!(digest.quantile(0.5), Some(2.5));
+        assert_eq!(digest.quantile(1.0), Some(4.0));
+        assert_eq!(digest.count(), 4);
+
+        assert_eq!(TDigest::new().quantile(0.5), None);
+        assert_eq!(digest_of([f64::NAN]).quantile(0.5), None);
+    }
+
+    #[test]
+    fn test_accuracy() {
+        let n = 100_000;
+        let mut digest = digest_of(shuffled(n));
+        assert_eq!(digest.count(), n);
+        assert!(digest.centroids.len() <= 2 * digest.compression as usize);
+
+        for q in [0.001, 0.01, 0.1, 0.25, 0.5, 0.75, 0.9, 0.99, 0.999] {
+            let expected = q * (n - 1) as f64;
+            let got = digest.quantile(q).unwrap();
+            assert!(
+                (got - expected).abs() <= 0.005 * n as f64,
+                "quantile {q}: got {got}, expected {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_merge() {
+        let n = 100_000;
+        let mut merged = TDigest::new();
+        let values = shuffled(n).collect::<Vec<_>>();
+        for chunk in values.chunks(7_000) {
+            merged.merge(&digest_of(chunk.iter().copied()));
+        }
+        assert_eq!(merged.count(), n);
+        assert!(merged.centroids.len() <= 2 * merged.compression as usize);
+
+        let mut single = digest_of(values);
+        for q in [0.01, 0.5, 0.99] {
+            let got = merged.quantile(q).unwrap();
+            let expected = single.quantile(q).unwrap();
+            assert!((got - expected).abs() <= 0.005 * n as f64);
+        }
+        assert_eq!(merged.quantile(0.0), Some(0.0));
+        assert_eq!(merged.quantile(1.0), Some((n - 1) as f64));
+    }
+}
@@ -1,13 +1,22 @@
 use super::*;
 
+/// The maximum number of significant digits a 128-bit decimal can hold.
+const MAX_DECIMAL_PRECISION: usize = 38;
+
 impl Add for &DecimalChunked {
     type Output = PolarsResult<DecimalChunked>;
 
     fn add(self, rhs: Self) -> Self::Output {
         let scale = _get_decimal_scale_add_sub(self.scale(), rhs.scale());
+        let precision = _get_decimal_precision_add_sub(
+            self.precision(),
+            self.scale(),
+            rhs.precision(),
+            rhs.scale(),
+        );
         let lhs = self.to_scale(scale)?;
         let rhs = rhs.to_scale(scale)?;
-        Ok((&lhs.0 + &rhs.0).into_decimal_unchecked(None, scale))
+        Ok((&lhs.0 + &rhs.0).into_decimal_unchecked(precision, scale))
     }
 }
 
@@ -16,9 +25,15 @@ impl Sub for &DecimalChunked {
 
     fn sub(self, rhs: Self) -> Self::Output {
         let scale = _get_decimal_scale_add_sub(self.scale(), rhs.scale());
+        let precision = _get_decimal_precision_add_sub(
+            self.precision(),
+            self.scale(),
+            rhs.precision(),
+            rhs.scale(),
+        );
         let lhs = self.to_scale(scale)?;
         let rhs = rhs.to_scale(scale)?;
-        Ok((&lhs.0 - &rhs.0).into_decimal_unchecked(None, scale))
+        Ok((&lhs.0 - &rhs.0).into_decimal_unchecked(precision, scale))
     }
 }
 
@@ -27,7 +42,8 @@ impl Mul for &DecimalChunked {
 
     fn mul(self, rhs: Self) -> Self::Output {
         let scale = _get_decimal_scale_mul(self.scale(), rhs.scale());
-        Ok((&self.0 * &rhs.0).into_decimal_unchecked(None, scale))
+        let precision = _get_decimal_precision_mul(self.precision(), rhs.precision());
+        Ok((&self.0 * &rhs.0).into_decimal_unchecked(precision, scale))
     }
 }
 
@@ -54,3 +70,43 @@ pub fn _get_decimal_scale_div(scale_left: usize) -> usize {
     // Follow postgres and MySQL adding a fixed scale increment of 4
     scale_left + 4
 }
+
+/// Precision of an addition or subtraction, if it is known and fits in a 128-bit decimal.
+///
+/// The result needs as many integer digits as the widest input plus one for the carry.
+pub fn _get_decimal_precision_add_sub(
+    precision_left: Option<usize>,
+    scale_left: usize,
+    precision_right: Option<usize>,
+    scale_right: usize,
+) -> Option<usize> {
+    let (precision_left, precision_right) = precision_left.zip(precision_right)?;
+    let int_digits = precision_left
+        .saturating_sub(scale_left)
+        .max(precision_right.saturating_sub(scale_right));
+    let precision = int_digits + _get_decimal_scale_add_sub(scale_left, scale_right) + 1;
+    (precision <= MAX_DECIMAL_PRECISION).then_some(precision)
+}
+
+/// Precision of a multiplication, if it is known and fits in a 128-bit decimal.
+pub fn _get_decimal_precision_mul(
+    precision_left: Option<usize>,
+    precision_right: Option<usize>,
+) -> Option<usize> {
+    let (precision_left, precision_right) = precision_left.zip(precision_right)?;
+    let precision = precision_left + precision_right;
+    (precision <= MAX_DECIMAL_PRECISION).then_some(precision)
+}
+
+/// Exact mean of a decimal column given the sum and count of its (scaled) values.
+///
+/// The result has the scale returned by [`_get_decimal_scale_div`], and is truncated like a
+/// decimal division.
+pub fn _decimal_mean(sum: i128, count: usize, scale: usize) -> Option<i128> {
+    if count == 0 {
+        return None;
+    }
+    let extra_scale = _get_decimal_scale_div(scale) - scale;
+    sum.checked_mul(10i128.pow(extra_scale as u32))
+        .map(|v| v / count as i128)
+}
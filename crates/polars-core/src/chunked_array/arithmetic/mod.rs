@@ -7,7 +7,10 @@ use std::ops::{Add, Div, Mul, Rem, Sub};
 
 use arrow::compute::utils::combine_validities_and;
 #[cfg(feature = "dtype-decimal")]
-pub use decimal::{_get_decimal_scale_add_sub, _get_decimal_scale_div, _get_decimal_scale_mul};
+pub use decimal::{
+    _decimal_mean, _get_decimal_precision_add_sub, _get_decimal_precision_mul,
+    _get_decimal_scale_add_sub, _get_decimal_scale_div, _get_decimal_scale_mul,
+};
 use num_traits::{Num, NumCast, ToPrimitive};
 pub use numeric::ArithmeticChunked;
 
@@ -75,6 +75,12 @@ fn cast_impl_inner(
     dtype: &DataType,
     options: CastOptions,
 ) -> PolarsResult<Series> {
+    #[cfg(feature = "dtype-geometry")]
+    polars_ensure!(
+        !dtype.is_geometry(),
+        InvalidOperation: "only binary values holding WKB can be cast to geometry"
+    );
+
     let chunks = match dtype {
         #[cfg(feature = "dtype-decimal")]
         DataType::Decimal(_, _) => {
@@ -110,6 +116,8 @@ fn cast_impl_inner(
         Duration(tu) => out.into_duration(*tu),
         #[cfg(feature = "dtype-time")]
         Time => out.into_time(),
+        #[cfg(feature = "dtype-f16")]
+        Float16 => out.into_float16(),
         #[cfg(feature = "dtype-decimal")]
         Decimal(precision, scale) => out.into_decimal(*precision, scale.unwrap_or(0))?,
         _ => out,
@@ -406,6 +414,8 @@ impl StringChunked {
 impl ChunkCast for BinaryChunked {
     fn cast_with_options(&self, dtype: &DataType, options: CastOptions) -> PolarsResult<Series> {
         match dtype {
+            #[cfg(feature = "dtype-geometry")]
+            DataType::Geometry => Ok(self.clone().into_geometry().into_series()),
             #[cfg(feature = "dtype-struct")]
             DataType::Struct(fields) => {
                 cast_single_to_struct(self.name().clone(), &self.chunks, fields, options)
@@ -0,0 +1,54 @@
+use arrow::types::f16;
+
+use super::*;
+use crate::prelude::*;
+
+pub type Float16Chunked = Logical<Float16Type, Float32Type>;
+
+impl From<Float32Chunked> for Float16Chunked {
+    fn from(ca: Float32Chunked) -> Self {
+        ca.into_float16()
+    }
+}
+
+impl Float32Chunked {
+    /// Round every value to the nearest half-precision float and mark the array as `Float16`.
+    pub fn into_float16(self) -> Float16Chunked {
+        let ca = self.apply_values(|v| f16::from_f32(v).to_f32());
+        Float16Chunked::new_logical(ca)
+    }
+
+    /// Mark the array as `Float16` without rounding.
+    ///
+    /// The caller should make sure all values are representable as `f16`, e.g. because they
+    /// were upcast from an `f16` array.
+    pub fn into_float16_unchecked(self) -> Float16Chunked {
+        Float16Chunked::new_logical(self)
+    }
+}
+
+impl LogicalType for Float16Chunked {
+    fn dtype(&self) -> &'static DataType {
+        &DataType::Float16
+    }
+
+    fn get_any_value(&self, i: usize) -> PolarsResult<AnyValue<'_>> {
+        self.0.get_any_value(i)
+    }
+
+    unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
+        self.0.get_any_value_unchecked(i)
+    }
+
+    fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        cast_options: CastOptions,
+    ) -> PolarsResult<Series> {
+        match dtype {
+            DataType::Float16 => Ok(self.clone().into_series()),
+            DataType::Float32 => Ok(self.0.clone().into_series()),
+            _ => self.0.cast_with_options(dtype, cast_options),
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use super::*;
+use crate::prelude::*;
+
+pub type GeometryChunked = Logical<GeometryType, BinaryType>;
+
+impl From<BinaryChunked> for GeometryChunked {
+    fn from(ca: BinaryChunked) -> Self {
+        ca.into_geometry()
+    }
+}
+
+impl BinaryChunked {
+    /// Mark the values as Well-Known Binary encoded geometries.
+    ///
+    /// The values are not validated; operations on the geometries raise an error on invalid
+    /// WKB.
+    pub fn into_geometry(self) -> GeometryChunked {
+        GeometryChunked::new_logical(self)
+    }
+}
+
+impl LogicalType for GeometryChunked {
+    fn dtype(&self) -> &'static DataType {
+        &DataType::Geometry
+    }
+
+    fn get_any_value(&self, i: usize) -> PolarsResult<AnyValue<'_>> {
+        self.0.get_any_value(i)
+    }
+
+    unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
+        self.0.get_any_value_unchecked(i)
+    }
+
+    fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        cast_options: CastOptions,
+    ) -> PolarsResult<Series> {
+        match dtype {
+            DataType::Geometry => Ok(self.clone().into_series()),
+            DataType::Binary => Ok(self.0.clone().into_series()),
+            _ => self.0.cast_with_options(dtype, cast_options),
+        }
+    }
+}
@@ -36,6 +36,10 @@ pub(crate) unsafe fn arr_to_any_value<'a>(
     match dtype {
         DataType::String => downcast_and_pack!(Utf8ViewArray, String),
         DataType::Binary => downcast_and_pack!(BinaryViewArray, Binary),
+        #[cfg(feature = "dtype-geometry")]
+        DataType::Geometry => downcast_and_pack!(BinaryViewArray, Binary),
+        #[cfg(feature = "dtype-extension")]
+        DataType::Extension(ext) => arr_to_any_value(arr, idx, ext.storage()),
         DataType::Boolean => downcast_and_pack!(BooleanArray, Boolean),
         DataType::UInt8 => downcast_and_pack!(UInt8Array, UInt8),
         DataType::UInt16 => downcast_and_pack!(UInt16Array, UInt16),
@@ -48,6 +52,8 @@ pub(crate) unsafe fn arr_to_any_value<'a>(
         DataType::Int128 => downcast_and_pack!(Int128Array, Int128),
         DataType::Float32 => downcast_and_pack!(Float32Array, Float32),
         DataType::Float64 => downcast_and_pack!(Float64Array, Float64),
+        #[cfg(feature = "dtype-f16")]
+        DataType::Float16 => downcast_and_pack!(Float32Array, Float32),
         DataType::List(dt) => {
             let v: ArrayRef = downcast!(LargeListArray);
             if dt.is_primitive() {
@@ -1,6 +1,6 @@
 use std::hash::Hash;
 
-use polars_compute::hyperloglogplus::HyperLogLog;
+use polars_compute::hyperloglogplus::{HyperLogLog, hash};
 use polars_utils::IdxSize;
 use polars_utils::total_ord::{ToTotalOrd, TotalEq, TotalHash};
 
@@ -17,4 +17,14 @@ where
         self.iter().for_each(|item| hllp.add(&item.to_total_ord()));
         hllp.count() as IdxSize
     }
+
+    fn approx_n_unique_sketch(&self) -> Vec<u8> {
+        let mut hllp = HyperLogLog::new();
+        self.iter().for_each(|item| hllp.add(&item.to_total_ord()));
+        hllp.as_ref().to_vec()
+    }
+
+    fn approx_n_unique_hashes(&self) -> Vec<u64> {
+        self.iter().map(|item| hash(&item.to_total_ord())).collect()
+    }
 }
@@ -378,6 +378,12 @@ pub trait ChunkUnique {
 #[cfg(feature = "approx_unique")]
 pub trait ChunkApproxNUnique {
     fn approx_n_unique(&self) -> IdxSize;
+
+    /// The registers of the HyperLogLog sketch that `approx_n_unique` is computed from.
+    fn approx_n_unique_sketch(&self) -> Vec<u8>;
+
+    /// The hashes the values set the registers of the sketch from.
+    fn approx_n_unique_hashes(&self) -> Vec<u64>;
 }
 
 /// Sort operations on `ChunkedArray`.
@@ -97,6 +97,13 @@ pub fn get_row_encoding_context(dtype: &DataType, ordered: bool) -> Option<RowEn
         | DataType::Datetime(_, _)
         | DataType::Duration(_) => None,
 
+        #[cfg(feature = "dtype-f16")]
+        DataType::Float16 => None,
+        #[cfg(feature = "dtype-geometry")]
+        DataType::Geometry => None,
+        #[cfg(feature = "dtype-extension")]
+        DataType::Extension(ext) => get_row_encoding_context(ext.storage(), ordered),
+
         DataType::Unknown(_) => panic!("Unsupported in row encoding"),
 
         #[cfg(feature = "object")]
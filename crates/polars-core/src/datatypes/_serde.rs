@@ -116,6 +116,16 @@ enum SerializableDataType {
     Enum(Option<Series>, CategoricalOrdering),
     #[cfg(feature = "object")]
     Object(String),
+    #[cfg(feature = "dtype-f16")]
+    Float16,
+    #[cfg(feature = "dtype-geometry")]
+    Geometry,
+    #[cfg(feature = "dtype-extension")]
+    Extension {
+        name: String,
+        metadata: Option<String>,
+        storage: Box<SerializableDataType>,
+    },
 }
 
 impl From<&DataType> for SerializableDataType {
@@ -171,6 +181,16 @@ impl From<&DataType> for SerializableDataType {
             Decimal(precision, scale) => Self::Decimal(*precision, *scale),
             #[cfg(feature = "object")]
             Object(name) => Self::Object(name.to_string()),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Self::Float16,
+            #[cfg(feature = "dtype-geometry")]
+            Geometry => Self::Geometry,
+            #[cfg(feature = "dtype-extension")]
+            Extension(ext) => Self::Extension {
+                name: ext.name().to_string(),
+                metadata: ext.metadata(),
+                storage: Box::new(ext.storage().into()),
+            },
             dt => panic!("{dt:?} not supported"),
         }
     }
@@ -231,6 +251,21 @@ impl From<SerializableDataType> for DataType {
             Decimal(precision, scale) => Self::Decimal(precision, scale),
             #[cfg(feature = "object")]
             Object(_) => Self::Object("unknown"),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Self::Float16,
+            #[cfg(feature = "dtype-geometry")]
+            Geometry => Self::Geometry,
+            // Fall back to the storage type if the extension type isn't registered in this
+            // process.
+            #[cfg(feature = "dtype-extension")]
+            Extension {
+                name,
+                metadata,
+                storage,
+            } => match get_extension_type(&name, metadata.as_deref()) {
+                Some(Ok(ext)) => Self::Extension(ext),
+                _ => (*storage).into(),
+            },
         }
     }
 }
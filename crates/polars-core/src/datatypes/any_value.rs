@@ -582,6 +582,10 @@ impl<'a> AnyValue<'a> {
             (av, DataType::Int128) => AnyValue::Int128(av.extract::<i128>()?),
             (av, DataType::Float32) => AnyValue::Float32(av.extract::<f32>()?),
             (av, DataType::Float64) => AnyValue::Float64(av.extract::<f64>()?),
+            #[cfg(feature = "dtype-f16")]
+            (av, DataType::Float16) => {
+                AnyValue::Float32(arrow::types::f16::from_f32(av.extract::<f32>()?).to_f32())
+            },
 
             // to boolean
             (AnyValue::UInt8(v), DataType::Boolean) => AnyValue::Boolean(*v != u8::default()),
@@ -617,6 +621,8 @@ impl<'a> AnyValue<'a> {
 
             // to binary
             (AnyValue::String(v), DataType::Binary) => AnyValue::Binary(v.as_bytes()),
+            #[cfg(feature = "dtype-geometry")]
+            (AnyValue::Binary(v), DataType::Geometry) => AnyValue::Binary(v),
 
             // to datetime
             #[cfg(feature = "dtype-datetime")]
@@ -1,6 +1,10 @@
 use std::collections::BTreeMap;
 
-use arrow::datatypes::{DTYPE_CATEGORICAL, DTYPE_ENUM_VALUES, Metadata};
+#[cfg(feature = "dtype-extension")]
+use arrow::datatypes::ExtensionType;
+use arrow::datatypes::{
+    DTYPE_CATEGORICAL, DTYPE_ENUM_VALUES, DTYPE_FLOAT16, DTYPE_GEOMETRY, Metadata,
+};
 #[cfg(feature = "dtype-array")]
 use polars_utils::format_tuple;
 use polars_utils::itertools::Itertools;
@@ -37,6 +41,18 @@ pub trait MetaDataExt: IntoMetadata {
         let metadata = self.into_metadata_ref();
         metadata.get(PL_KEY).map(|s| s.as_str()) == Some(MAINTAIN_PL_TYPE)
     }
+
+    /// Whether a `Float32` field holds values that were stored as half-precision floats.
+    fn float16(&self) -> bool {
+        let metadata = self.into_metadata_ref();
+        metadata.get(DTYPE_FLOAT16).is_some()
+    }
+
+    /// Whether a binary field holds geometries encoded as Well-Known Binary.
+    fn geometry(&self) -> bool {
+        let metadata = self.into_metadata_ref();
+        metadata.get(DTYPE_GEOMETRY).is_some()
+    }
 }
 
 impl MetaDataExt for Metadata {}
@@ -104,6 +120,12 @@ pub enum DataType {
     Int128,
     Float32,
     Float64,
+    /// Half-precision floating point. Values are stored as [`f32`] and rounded to the nearest
+    /// representable `f16` value on construction; arithmetic upcasts to [`DataType::Float32`].
+    /// Aggregations accumulate in `f32` as well: `sum`, `mean` and the like return
+    /// [`DataType::Float32`], while `min` and `max` keep the half-precision type.
+    #[cfg(feature = "dtype-f16")]
+    Float16,
     /// Fixed point decimal type optional precision and non-negative scale.
     /// This is backed by a signed 128-bit integer which allows for up to 38 significant digits.
     /// Meaning max precision is 38.
@@ -113,6 +135,9 @@ pub enum DataType {
     String,
     Binary,
     BinaryOffset,
+    /// Geometries encoded as Well-Known Binary (WKB). This is backed by [`DataType::Binary`].
+    #[cfg(feature = "dtype-geometry")]
+    Geometry,
     /// A 32-bit date representing the elapsed time since UNIX epoch (1970-01-01)
     /// in days (32 bits).
     Date,
@@ -142,6 +167,9 @@ pub enum DataType {
     Enum(Option<Arc<RevMapping>>, CategoricalOrdering),
     #[cfg(feature = "dtype-struct")]
     Struct(Vec<Field>),
+    /// A user-defined logical type, see [`PolarsExtensionType`].
+    #[cfg(feature = "dtype-extension")]
+    Extension(ExtensionTypeRef),
     // some logical types we cannot know statically, e.g. Datetime
     Unknown(UnknownKind),
 }
@@ -197,6 +225,10 @@ impl PartialEq for DataType {
                 (Array(left_inner, left_width), Array(right_inner, right_width)) => {
                     left_width == right_width && left_inner == right_inner
                 },
+                #[cfg(feature = "dtype-extension")]
+                (Extension(lhs), Extension(rhs)) => {
+                    lhs.name() == rhs.name() && lhs.metadata() == rhs.metadata()
+                },
                 (Unknown(l), Unknown(r)) => match (l, r) {
                     (UnknownKind::Int(_), UnknownKind::Int(_)) => true,
                     _ => l == r,
@@ -440,6 +472,12 @@ impl DataType {
             Datetime(_, _) => Int64,
             Duration(_) => Int64,
             Time => Int64,
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Float32,
+            #[cfg(feature = "dtype-geometry")]
+            Geometry => Binary,
+            #[cfg(feature = "dtype-extension")]
+            Extension(ext) => ext.storage().to_physical(),
             #[cfg(feature = "dtype-decimal")]
             Decimal(_, _) => Int128,
             #[cfg(feature = "dtype-categorical")]
@@ -531,6 +569,30 @@ impl DataType {
         matches!(self, DataType::Binary)
     }
 
+    /// Check if this [`DataType`] is a WKB encoded geometry.
+    pub fn is_geometry(&self) -> bool {
+        #[cfg(feature = "dtype-geometry")]
+        {
+            matches!(self, DataType::Geometry)
+        }
+        #[cfg(not(feature = "dtype-geometry"))]
+        {
+            false
+        }
+    }
+
+    /// Check if this [`DataType`] is a user-defined extension type.
+    pub fn is_extension(&self) -> bool {
+        #[cfg(feature = "dtype-extension")]
+        {
+            matches!(self, DataType::Extension(_))
+        }
+        #[cfg(not(feature = "dtype-extension"))]
+        {
+            false
+        }
+    }
+
     pub fn is_date(&self) -> bool {
         matches!(self, DataType::Date)
     }
@@ -624,6 +686,18 @@ impl DataType {
         }
     }
 
+    /// Check if this [`DataType`] is a half-precision float.
+    pub fn is_float16(&self) -> bool {
+        #[cfg(feature = "dtype-f16")]
+        {
+            matches!(self, DataType::Float16)
+        }
+        #[cfg(not(feature = "dtype-f16"))]
+        {
+            false
+        }
+    }
+
     /// Check if this [`DataType`] is a basic floating point type (excludes Decimal).
     /// Note, this also includes `Unknown(UnknownKind::Float)`.
     pub fn is_float(&self) -> bool {
@@ -717,6 +791,11 @@ impl DataType {
                 PlSmallStr::from_static(PL_KEY),
                 PlSmallStr::from_static(MAINTAIN_PL_TYPE),
             )])),
+            #[cfg(feature = "dtype-geometry")]
+            DataType::Geometry => Some(BTreeMap::from([(
+                PlSmallStr::from_static(DTYPE_GEOMETRY),
+                PlSmallStr::from_static("wkb"),
+            )])),
             _ => None,
         };
 
@@ -744,6 +823,8 @@ impl DataType {
             UInt64 => Scalar::from(u64::MAX),
             Float32 => Scalar::from(f32::INFINITY),
             Float64 => Scalar::from(f64::INFINITY),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Scalar::new(Float16, AnyValue::Float32(f32::INFINITY)),
             #[cfg(feature = "dtype-time")]
             Time => Scalar::new(Time, AnyValue::Time(NS_IN_DAY - 1)),
             dt => polars_bail!(ComputeError: "cannot determine upper bound for dtype `{}`", dt),
@@ -766,6 +847,8 @@ impl DataType {
             UInt64 => Scalar::from(u64::MIN),
             Float32 => Scalar::from(f32::NEG_INFINITY),
             Float64 => Scalar::from(f64::NEG_INFINITY),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Scalar::new(Float16, AnyValue::Float32(f32::NEG_INFINITY)),
             #[cfg(feature = "dtype-time")]
             Time => Scalar::new(Time, AnyValue::Time(0)),
             dt => polars_bail!(ComputeError: "cannot determine lower bound for dtype `{}`", dt),
@@ -795,6 +878,8 @@ impl DataType {
             Int128 => Ok(ArrowDataType::Int128),
             Float32 => Ok(ArrowDataType::Float32),
             Float64 => Ok(ArrowDataType::Float64),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Ok(ArrowDataType::Float16),
             #[cfg(feature = "dtype-decimal")]
             Decimal(precision, scale) => {
                 let precision = (*precision).unwrap_or(38);
@@ -813,6 +898,8 @@ impl DataType {
                 };
                 Ok(dt)
             },
+            #[cfg(feature = "dtype-geometry")]
+            Geometry => Binary.try_to_arrow(compat_level),
             Binary => {
                 let dt = if compat_level.0 >= 1 {
                     ArrowDataType::BinaryView
@@ -857,6 +944,12 @@ impl DataType {
                 Ok(ArrowDataType::Struct(fields))
             },
             BinaryOffset => Ok(ArrowDataType::LargeBinary),
+            #[cfg(feature = "dtype-extension")]
+            Extension(ext) => Ok(ArrowDataType::Extension(Box::new(ExtensionType {
+                name: ext.name().into(),
+                inner: ext.storage().try_to_arrow(compat_level)?,
+                metadata: ext.metadata().map(PlSmallStr::from_string),
+            }))),
             Unknown(kind) => {
                 let dt = match kind {
                     UnknownKind::Any => ArrowDataType::Unknown,
@@ -935,6 +1028,8 @@ impl Display for DataType {
             DataType::Int128 => "i128",
             DataType::Float32 => "f32",
             DataType::Float64 => "f64",
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => "f16",
             #[cfg(feature = "dtype-decimal")]
             DataType::Decimal(precision, scale) => {
                 return match (precision, scale) {
@@ -947,6 +1042,8 @@ impl Display for DataType {
             },
             DataType::String => "str",
             DataType::Binary => "binary",
+            #[cfg(feature = "dtype-geometry")]
+            DataType::Geometry => "geometry",
             DataType::Date => "date",
             DataType::Datetime(tu, tz) => {
                 let s = match tz {
@@ -978,6 +1075,8 @@ impl Display for DataType {
             DataType::Enum(_, _) => "enum",
             #[cfg(feature = "dtype-struct")]
             DataType::Struct(fields) => return write!(f, "struct[{}]", fields.len()),
+            #[cfg(feature = "dtype-extension")]
+            DataType::Extension(ext) => ext.name(),
             DataType::Unknown(kind) => match kind {
                 UnknownKind::Any => "unknown",
                 UnknownKind::Int(_) => "dyn int",
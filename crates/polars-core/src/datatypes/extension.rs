@@ -0,0 +1,382 @@
+//! User-defined logical types.
+//!
+//! An extension type gives a name and behavior to values that are stored as another
+//! ("storage") [`DataType`], e.g. a `Money` type stored as cents in an `Int64`. Extension types
+//! are registered by name, so that they can be recreated from the Arrow extension metadata when
+//! reading Parquet or IPC files or importing Arrow data.
+use std::fmt::Debug;
+use std::sync::{Arc, LazyLock, RwLock};
+
+use arrow::array::{
+    BinaryArray, BinaryViewArray, BooleanArray, DictionaryArray, FixedSizeBinaryArray,
+    FixedSizeListArray, ListArray, MapArray, NullArray, PrimitiveArray, StructArray, Utf8Array,
+    Utf8ViewArray,
+};
+use arrow::datatypes::ExtensionType;
+
+use crate::prelude::*;
+
+/// A logical type that is backed by a physical storage [`DataType`].
+pub trait PolarsExtensionType: Send + Sync + Debug {
+    /// The name this type is registered under, e.g. `"myorg.money"`.
+    fn name(&self) -> &str;
+
+    /// The [`DataType`] the values are stored as.
+    fn storage(&self) -> &DataType;
+
+    /// The parameters of this type, e.g. a currency.
+    ///
+    /// This is written to the Arrow extension metadata and given back to the factory of the
+    /// type when it is recreated.
+    fn metadata(&self) -> Option<String> {
+        None
+    }
+
+    /// Format a single value, given as a value of the storage dtype.
+    fn format_value(&self, value: AnyValue<'_>) -> String {
+        value.to_string()
+    }
+
+    /// Cast the `storage` of a column of this type to `dtype`.
+    ///
+    /// Return `None` to cast the storage as is.
+    fn cast_to(&self, _storage: &Series, _dtype: &DataType) -> Option<PolarsResult<Series>> {
+        None
+    }
+
+    /// Cast `s` to the storage dtype of this type.
+    ///
+    /// Return `None` to cast `s` to the storage dtype as is.
+    fn cast_from(&self, _s: &Series) -> Option<PolarsResult<Series>> {
+        None
+    }
+
+    /// Run the custom kernel `function` on `inputs`, of which the first is of this type.
+    ///
+    /// Return `None` if this type has no kernel with that name.
+    fn call(&self, _function: &str, _inputs: &[Series]) -> Option<PolarsResult<Series>> {
+        None
+    }
+}
+
+pub type ExtensionTypeRef = Arc<dyn PolarsExtensionType>;
+
+/// Creates an extension type from its Arrow extension metadata.
+pub type ExtensionTypeFactory =
+    Arc<dyn Fn(Option<&str>) -> PolarsResult<ExtensionTypeRef> + Send + Sync>;
+
+static EXTENSION_TYPES: LazyLock<RwLock<PlHashMap<String, ExtensionTypeFactory>>> =
+    LazyLock::new(Default::default);
+
+/// Register an extension type under `name`, replacing any type with the same name.
+pub fn register_extension_type(name: &str, factory: ExtensionTypeFactory) {
+    EXTENSION_TYPES
+        .write()
+        .unwrap()
+        .insert(name.to_string(), factory);
+}
+
+/// Remove the extension type registered under `name`.
+///
+/// Returns whether a type was registered under that name.
+pub fn unregister_extension_type(name: &str) -> bool {
+    EXTENSION_TYPES.write().unwrap().remove(name).is_some()
+}
+
+/// Create the extension type registered under `name` with the given metadata.
+///
+/// Returns `None` if no type is registered under `name`.
+pub fn get_extension_type(
+    name: &str,
+    metadata: Option<&str>,
+) -> Option<PolarsResult<ExtensionTypeRef>> {
+    // Don't hold the lock while the factory runs, it may register types itself.
+    let factory = EXTENSION_TYPES.read().unwrap().get(name).cloned()?;
+    Some(factory(metadata))
+}
+
+/// Run the custom kernel `function` of the extension type of the first input.
+pub fn call_extension_kernel(function: &str, inputs: &[Series]) -> PolarsResult<Series> {
+    let Some(DataType::Extension(ext)) = inputs.first().map(|s| s.dtype()) else {
+        polars_bail!(
+            InvalidOperation: "extension kernel '{}' expects an extension type as first input",
+            function
+        )
+    };
+    match ext.call(function, inputs) {
+        Some(out) => out,
+        None => polars_bail!(
+            InvalidOperation: "extension type '{}' has no kernel '{}'", ext.name(), function
+        ),
+    }
+}
+
+impl DataType {
+    /// Get the extension type of a registered Arrow extension.
+    pub(crate) fn from_arrow_extension(ext: &ExtensionType) -> Option<DataType> {
+        let out = get_extension_type(ext.name.as_str(), ext.metadata.as_deref())?;
+        // Reading a file shouldn't fail on a type that was registered with another signature,
+        // in that case the values are read as their storage dtype.
+        out.ok().map(DataType::Extension)
+    }
+}
+
+/// Change the Arrow dtype of `arr` without copying its buffers.
+///
+/// `dtype` must have the same physical type as the dtype of `arr`.
+pub(crate) fn with_arrow_dtype(arr: ArrayRef, dtype: ArrowDataType) -> PolarsResult<ArrayRef> {
+    if arr.dtype() == &dtype {
+        return Ok(arr);
+    }
+    polars_ensure!(
+        arr.dtype().to_physical_type() == dtype.to_physical_type(),
+        ComputeError: "cannot store an array of {:?} as {:?}", arr.dtype(), dtype
+    );
+
+    macro_rules! downcast {
+        ($T:ty) => {
+            arr.as_any().downcast_ref::<$T>().unwrap()
+        };
+    }
+    macro_rules! with_offsets {
+        ($T:ty) => {{
+            let arr = downcast!($T);
+            <$T>::new(
+                dtype,
+                arr.offsets().clone(),
+                arr.values().clone(),
+                arr.validity().cloned(),
+            )
+            .boxed()
+        }};
+    }
+    macro_rules! with_views {
+        ($T:ty) => {{
+            let (views, buffers, validity, total_bytes_len, total_buffer_len) =
+                downcast!($T).clone().into_inner();
+            // SAFETY: the views and buffers come from a valid array.
+            unsafe {
+                <$T>::new_unchecked(
+                    dtype,
+                    views,
+                    buffers,
+                    validity,
+                    total_bytes_len,
+                    total_buffer_len,
+                )
+            }
+            .boxed()
+        }};
+    }
+
+    use arrow::datatypes::PhysicalType as P;
+    let out = match dtype.to_physical_type() {
+        P::Null => NullArray::new(dtype, arr.len()).boxed(),
+        P::Boolean => {
+            let arr = downcast!(BooleanArray);
+            BooleanArray::new(dtype, arr.values().clone(), arr.validity().cloned()).boxed()
+        },
+        P::Primitive(primitive) => arrow::with_match_primitive_type_full!(primitive, |$T| {
+            downcast!(PrimitiveArray<$T>).clone().to(dtype).boxed()
+        }),
+        P::Binary => with_offsets!(BinaryArray<i32>),
+        P::LargeBinary => with_offsets!(BinaryArray<i64>),
+        P::Utf8 => {
+            let arr = downcast!(Utf8Array<i32>);
+            let (offsets, values, validity) = (
+                arr.offsets().clone(),
+                arr.values().clone(),
+                arr.validity().cloned(),
+            );
+            // SAFETY: the values come from a valid array.
+            unsafe { Utf8Array::<i32>::new_unchecked(dtype, offsets, values, validity) }.boxed()
+        },
+        P::LargeUtf8 => {
+            let arr = downcast!(Utf8Array<i64>);
+            let (offsets, values, validity) = (
+                arr.offsets().clone(),
+                arr.values().clone(),
+                arr.validity().cloned(),
+            );
+            // SAFETY: the values come from a valid array.
+            unsafe { Utf8Array::<i64>::new_unchecked(dtype, offsets, values, validity) }.boxed()
+        },
+        P::BinaryView => with_views!(BinaryViewArray),
+        P::Utf8View => with_views!(Utf8ViewArray),
+        P::FixedSizeBinary => downcast!(FixedSizeBinaryArray).clone().to(dtype).boxed(),
+        P::List => with_offsets!(ListArray<i32>),
+        P::LargeList => with_offsets!(ListArray<i64>),
+        P::FixedSizeList => {
+            let arr = downcast!(FixedSizeListArray);
+            let (values, validity) = (arr.values().clone(), arr.validity().cloned());
+            FixedSizeListArray::new(dtype, arr.len(), values, validity).boxed()
+        },
+        P::Struct => {
+            let arr = downcast!(StructArray);
+            let (values, validity) = (arr.values().to_vec(), arr.validity().cloned());
+            StructArray::new(dtype, arr.len(), values, validity).boxed()
+        },
+        P::Map => {
+            let arr = downcast!(MapArray);
+            let (offsets, field) = (arr.offsets().clone(), arr.field().clone());
+            MapArray::new(dtype, offsets, field, arr.validity().cloned()).boxed()
+        },
+        P::Dictionary(key_type) => arrow::match_integer_type!(key_type, |$T| {
+            let arr = downcast!(DictionaryArray<$T>);
+            let (keys, values) = (arr.keys().clone(), arr.values().clone());
+            // SAFETY: the keys come from a valid array.
+            unsafe { DictionaryArray::<$T>::try_new_unchecked(dtype, keys, values) }?.boxed()
+        }),
+        P::Union => polars_bail!(ComputeError: "cannot store a union array as {:?}", dtype),
+    };
+    Ok(out)
+}
+
+/// A column of an extension type, stored as a [`Series`] of its storage dtype.
+#[derive(Clone)]
+pub struct ExtensionSeries {
+    storage: Series,
+    dtype: DataType,
+}
+
+impl ExtensionSeries {
+    /// Mark the values of `storage` as values of the extension type `ext`.
+    pub fn new(ext: ExtensionTypeRef, storage: Series) -> PolarsResult<Self> {
+        polars_ensure!(
+            storage.dtype() == ext.storage(),
+            SchemaMismatch: "extension type '{}' is stored as {}, got {}",
+            ext.name(), ext.storage(), storage.dtype()
+        );
+        Ok(Self::new_unchecked(storage, DataType::Extension(ext)))
+    }
+
+    /// `dtype` must be an extension type stored as the dtype of `storage`.
+    pub(crate) fn new_unchecked(storage: Series, dtype: DataType) -> Self {
+        debug_assert!(dtype.is_extension());
+        Self { storage, dtype }
+    }
+
+    pub fn extension_type(&self) -> &ExtensionTypeRef {
+        let DataType::Extension(ext) = &self.dtype else {
+            unreachable!()
+        };
+        ext
+    }
+
+    pub fn dtype(&self) -> &DataType {
+        &self.dtype
+    }
+
+    /// The values as a [`Series`] of the storage dtype.
+    pub fn storage(&self) -> &Series {
+        &self.storage
+    }
+
+    pub(crate) fn storage_mut(&mut self) -> &mut Series {
+        &mut self.storage
+    }
+
+    pub fn into_storage(self) -> Series {
+        self.storage
+    }
+
+    /// Wrap a [`Series`] that has the storage dtype of this column.
+    pub(crate) fn with_storage(&self, storage: Series) -> Self {
+        Self::new_unchecked(storage, self.dtype.clone())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Debug)]
+    struct Money {
+        currency: String,
+    }
+
+    impl PolarsExtensionType for Money {
+        fn name(&self) -> &str {
+            "test.money"
+        }
+
+        fn storage(&self) -> &DataType {
+            &DataType::Int64
+        }
+
+        fn metadata(&self) -> Option<String> {
+            Some(self.currency.clone())
+        }
+
+        fn format_value(&self, value: AnyValue<'_>) -> String {
+            match value.extract::<i64>() {
+                Some(cents) => format!("{}.{:02} {}", cents / 100, cents % 100, self.currency),
+                None => value.to_string(),
+            }
+        }
+
+        fn call(&self, function: &str, inputs: &[Series]) -> Option<PolarsResult<Series>> {
+            match function {
+                "units" => Some(inputs[0].cast(&DataType::Int64).map(|s| &s / 100)),
+                _ => None,
+            }
+        }
+    }
+
+    fn register_money() {
+        register_extension_type(
+            "test.money",
+            Arc::new(|metadata| {
+                let currency = metadata.unwrap_or("USD").to_string();
+                Ok(Arc::new(Money { currency }) as ExtensionTypeRef)
+            }),
+        );
+    }
+
+    fn money(name: &str, values: &[Option<i64>]) -> Series {
+        let ext = get_extension_type("test.money", Some("EUR"))
+            .unwrap()
+            .unwrap();
+        let storage = Series::new(name.into(), values);
+        ExtensionSeries::new(ext, storage).unwrap().into_series()
+    }
+
+    #[test]
+    fn test_extension_series() {
+        register_money();
+        let s = money("a", &[Some(150), None, Some(25)]);
+        assert!(s.dtype().is_extension());
+        assert_eq!(s.dtype().to_string(), "test.money");
+        assert_eq!(s.str_value(0).unwrap(), "1.50 EUR");
+        assert_eq!(s.null_count(), 1);
+
+        let taken = s.take_slice(&[2, 0]).unwrap();
+        assert_eq!(taken.dtype(), s.dtype());
+        assert_eq!(taken.to_physical_repr().i64().unwrap().get(0), Some(25));
+
+        let storage = s.cast(&DataType::Int64).unwrap();
+        assert_eq!(storage.dtype(), &DataType::Int64);
+        assert!(storage.cast(s.dtype()).unwrap().dtype().is_extension());
+
+        let units = call_extension_kernel("units", &[s.clone()]).unwrap();
+        assert_eq!(units.i64().unwrap().get(0), Some(1));
+        assert!(call_extension_kernel("unknown", &[s]).is_err());
+    }
+
+    #[test]
+    fn test_extension_arrow_roundtrip() {
+        register_money();
+        let s = money("a", &[Some(1), Some(2)]);
+        let field = s.field().to_arrow(CompatLevel::newest());
+        let ArrowDataType::Extension(ext) = field.dtype() else {
+            panic!("expected an extension type")
+        };
+        assert_eq!(ext.name.as_str(), "test.money");
+        assert_eq!(ext.metadata.as_deref(), Some("EUR"));
+
+        let arr = s.to_arrow(0, CompatLevel::newest());
+        let out = Series::from_arrow(s.name().clone(), arr).unwrap();
+        assert_eq!(out.dtype(), s.dtype());
+        assert!(out.to_physical_repr().equals_missing(&s.to_physical_repr()));
+    }
+}
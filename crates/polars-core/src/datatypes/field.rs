@@ -3,6 +3,8 @@ use polars_utils::pl_str::PlSmallStr;
 
 use super::*;
 pub static EXTENSION_NAME: &str = "POLARS_EXTENSION_TYPE";
+/// Name of the GeoArrow extension type for WKB encoded geometries.
+pub static GEOARROW_WKB_EXTENSION_NAME: &str = "geoarrow.wkb";
 
 /// Characterizes the name and the [`DataType`] of a column.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -160,7 +162,14 @@ impl DataType {
             #[cfg(feature = "dtype-i128")]
             ArrowDataType::Int128 => DataType::Int128,
             ArrowDataType::Boolean => DataType::Boolean,
+            #[cfg(feature = "dtype-f16")]
+            ArrowDataType::Float16 => DataType::Float16,
+            #[cfg(not(feature = "dtype-f16"))]
             ArrowDataType::Float16 => DataType::Float32,
+            #[cfg(feature = "dtype-f16")]
+            ArrowDataType::Float32 if md.map(|md| md.float16()).unwrap_or(false) => {
+                DataType::Float16
+            },
             ArrowDataType::Float32 => DataType::Float32,
             ArrowDataType::Float64 => DataType::Float64,
             #[cfg(feature = "dtype-array")]
@@ -231,6 +240,13 @@ impl DataType {
                     panic!("activate the 'object' feature to be able to load POLARS_EXTENSION_TYPE")
                 }
             },
+            #[cfg(feature = "dtype-geometry")]
+            ArrowDataType::Extension(ext) if ext.name.as_str() == GEOARROW_WKB_EXTENSION_NAME => {
+                DataType::Geometry
+            },
+            #[cfg(feature = "dtype-extension")]
+            ArrowDataType::Extension(ext) => DataType::from_arrow_extension(ext)
+                .unwrap_or_else(|| Self::from_arrow(&ext.inner, bin_to_view, md)),
             #[cfg(feature = "dtype-decimal")]
             ArrowDataType::Decimal(precision, scale) => {
                 DataType::Decimal(Some(*precision), Some(*scale))
@@ -238,6 +254,12 @@ impl DataType {
             ArrowDataType::Utf8View | ArrowDataType::LargeUtf8 | ArrowDataType::Utf8 => {
                 DataType::String
             },
+            #[cfg(feature = "dtype-geometry")]
+            ArrowDataType::BinaryView | ArrowDataType::LargeBinary | ArrowDataType::Binary
+                if md.map(|md| md.geometry()).unwrap_or(false) =>
+            {
+                DataType::Geometry
+            },
             ArrowDataType::BinaryView => DataType::Binary,
             ArrowDataType::LargeBinary | ArrowDataType::Binary => {
                 if bin_to_view {
@@ -215,6 +215,42 @@ macro_rules! format_array {
     }};
 }
 
+#[cfg(feature = "dtype-extension")]
+fn format_extension_array(
+    f: &mut Formatter<'_>,
+    s: &Series,
+    name: &str,
+    array_type: &str,
+) -> fmt::Result {
+    write!(
+        f,
+        "shape: ({},)\n{}: '{}' [{}]\n[\n",
+        fmt_int_string_custom(&s.len().to_string(), 3, "_"),
+        array_type,
+        name,
+        s.dtype()
+    )?;
+    let write_value = |f: &mut Formatter<'_>, i| writeln!(f, "\t{}", s.str_value(i).unwrap());
+
+    let limit = get_row_limit();
+    if s.len() > limit {
+        let half = limit / 2;
+        let rest = limit % 2;
+        for i in 0..(half + rest) {
+            write_value(f, i)?;
+        }
+        writeln!(f, "\t{}", get_ellipsis())?;
+        for i in (s.len() - half)..s.len() {
+            write_value(f, i)?;
+        }
+    } else {
+        for i in 0..s.len() {
+            write_value(f, i)?;
+        }
+    }
+    write!(f, "]")
+}
+
 #[cfg(feature = "object")]
 fn format_object_array(
     f: &mut Formatter<'_>,
@@ -371,6 +407,10 @@ impl Debug for Series {
             DataType::Float64 => {
                 format_array!(f, self.f64().unwrap(), "f64", self.name(), "Series")
             },
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => {
+                format_array!(f, self.float16().unwrap(), "f16", self.name(), "Series")
+            },
             #[cfg(feature = "dtype-date")]
             DataType::Date => format_array!(f, self.date().unwrap(), "date", self.name(), "Series"),
             #[cfg(feature = "dtype-datetime")]
@@ -428,6 +468,18 @@ impl Debug for Series {
             DataType::Binary => {
                 format_array!(f, self.binary().unwrap(), "binary", self.name(), "Series")
             },
+            #[cfg(feature = "dtype-geometry")]
+            DataType::Geometry => {
+                format_array!(
+                    f,
+                    self.geometry().unwrap(),
+                    "geometry",
+                    self.name(),
+                    "Series"
+                )
+            },
+            #[cfg(feature = "dtype-extension")]
+            DataType::Extension(_) => format_extension_array(f, self, self.name(), "Series"),
             DataType::BinaryOffset => {
                 format_array!(
                     f,
@@ -1461,7 +1461,12 @@ impl Column {
     }
 
     pub(crate) fn str_value(&self, index: usize) -> PolarsResult<Cow<str>> {
-        Ok(self.get(index)?.str_value())
+        let value = self.get(index)?;
+        #[cfg(feature = "dtype-extension")]
+        if let (DataType::Extension(ext), false) = (self.dtype(), value.is_null()) {
+            return Ok(Cow::Owned(ext.format_value(value)));
+        }
+        Ok(value.str_value())
     }
 
     pub fn min_reduce(&self) -> PolarsResult<Scalar> {
@@ -1749,6 +1754,16 @@ impl Column {
         }
     }
 
+    #[cfg(feature = "approx_unique")]
+    pub fn approx_n_unique_sketch(&self) -> PolarsResult<Vec<u8>> {
+        match self {
+            Column::Series(s) => s.approx_n_unique_sketch(),
+            // @partition-opt
+            Column::Partitioned(s) => s.as_materialized_series().approx_n_unique_sketch(),
+            Column::Scalar(s) => s.as_single_value_series().approx_n_unique_sketch(),
+        }
+    }
+
     pub fn n_chunks(&self) -> usize {
         match self {
             Column::Series(s) => s.n_chunks(),
@@ -0,0 +1,55 @@
+use arrow::array::FixedSizeListArray;
+
+use super::*;
+
+impl ArrayChunked {
+    /// Aggregate every position of the arrays in a group separately, e.g. to get the elementwise
+    /// sum of the arrays. Null arrays are skipped.
+    ///
+    /// `agg` is applied to the inner values with one group per position of every group.
+    pub(crate) unsafe fn agg_elementwise(
+        &self,
+        groups: &GroupsType,
+        agg: impl Fn(&Series, &GroupsType) -> Series,
+    ) -> Series {
+        let ca = self.rechunk();
+        let width = ca.width();
+        let inner = ca.get_inner();
+        let validity = ca.rechunk_validity();
+        let is_valid = |row: IdxSize| validity.as_ref().is_none_or(|v| v.get_bit(row as usize));
+
+        let mut first = Vec::with_capacity(groups.len() * width);
+        let mut all = Vec::with_capacity(groups.len() * width);
+        for group in groups.iter() {
+            let rows: Vec<IdxSize> = match group {
+                GroupsIndicator::Idx((_, idx)) => {
+                    idx.iter().copied().filter(|&row| is_valid(row)).collect()
+                },
+                GroupsIndicator::Slice([start, len]) => {
+                    (start..start + len).filter(|&row| is_valid(row)).collect()
+                },
+            };
+            for pos in 0..width as IdxSize {
+                let idx: IdxVec = rows
+                    .iter()
+                    .map(|&row| row * width as IdxSize + pos)
+                    .collect();
+                first.push(idx.first().copied().unwrap_or(0));
+                all.push(idx);
+            }
+        }
+        let inner_groups = GroupsType::Idx(GroupsIdx::new(first, all, false));
+        let values = agg(&inner, &inner_groups).rechunk();
+
+        let dtype = DataType::Array(Box::new(values.dtype().clone()), width);
+        let arr = FixedSizeListArray::new(
+            dtype.to_physical().to_arrow(CompatLevel::newest()),
+            groups.len(),
+            values.chunks()[0].clone(),
+            None,
+        );
+        unsafe {
+            Series::from_chunks_and_dtype_unchecked(self.name().clone(), vec![arr.boxed()], &dtype)
+        }
+    }
+}
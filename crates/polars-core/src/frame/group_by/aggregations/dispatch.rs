@@ -1,4 +1,6 @@
 use super::*;
+#[cfg(feature = "dtype-decimal")]
+use crate::chunked_array::arithmetic::{_decimal_mean, _get_decimal_scale_div};
 
 // implemented on the series because we don't need types
 impl Series {
@@ -135,6 +137,8 @@ impl Series {
             Boolean => s.cast(&Float64).unwrap().agg_mean(groups),
             Float32 => SeriesWrap(s.f32().unwrap().clone()).agg_mean(groups),
             Float64 => SeriesWrap(s.f64().unwrap().clone()).agg_mean(groups),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => s.to_physical_repr().agg_mean(groups),
             dt if dt.is_primitive_numeric() => apply_method_physical_integer!(s, agg_mean, groups),
             #[cfg(feature = "dtype-datetime")]
             dt @ Datetime(_, _) => self
@@ -169,6 +173,29 @@ impl Series {
                 * (MS_IN_DAY as f64))
                 .cast(&Datetime(TimeUnit::Milliseconds, None))
                 .unwrap(),
+            #[cfg(feature = "dtype-decimal")]
+            Decimal(_, _) => {
+                let ca = s.decimal().unwrap();
+                let scale = ca.scale();
+                let sums = ca.physical().agg_sum(groups);
+                let counts = s.agg_valid_count(groups);
+                let out: Int128Chunked = sums
+                    .i128()
+                    .unwrap()
+                    .iter()
+                    .zip(counts.idx().unwrap().iter())
+                    .map(|(sum, count)| {
+                        _decimal_mean(sum.unwrap_or(0), count.unwrap_or(0) as usize, scale)
+                    })
+                    .collect_ca(PlSmallStr::EMPTY);
+                out.into_decimal_unchecked(None, _get_decimal_scale_div(scale))
+                    .into_series()
+            },
+            #[cfg(feature = "dtype-array")]
+            Array(_, _) => s
+                .array()
+                .unwrap()
+                .agg_elementwise(groups, |s, g| unsafe { s.agg_mean(g) }),
             _ => Series::full_null(PlSmallStr::EMPTY, groups.len(), s.dtype()),
         }
     }
@@ -187,6 +214,8 @@ impl Series {
             Boolean => s.cast(&Float64).unwrap().agg_median(groups),
             Float32 => SeriesWrap(s.f32().unwrap().clone()).agg_median(groups),
             Float64 => SeriesWrap(s.f64().unwrap().clone()).agg_median(groups),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => s.to_physical_repr().agg_median(groups),
             dt if dt.is_primitive_numeric() => {
                 apply_method_physical_integer!(s, agg_median, groups)
             },
@@ -245,6 +274,8 @@ impl Series {
         match s.dtype() {
             Float32 => s.f32().unwrap().agg_quantile(groups, quantile, method),
             Float64 => s.f64().unwrap().agg_quantile(groups, quantile, method),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => s.to_physical_repr().agg_quantile(groups, quantile, method),
             dt if dt.is_primitive_numeric() || dt.is_temporal() => {
                 let ca = s.to_physical_repr();
                 let physical_type = ca.dtype();
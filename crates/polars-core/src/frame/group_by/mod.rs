@@ -875,10 +875,36 @@ pub enum GroupByMethod {
     Groups,
     NUnique,
     Quantile(f64, QuantileMethod),
-    Count { include_nulls: bool },
+    Count {
+        include_nulls: bool,
+    },
     Implode,
     Std(u8),
     Var(u8),
+    #[cfg(feature = "bitwise")]
+    Bitwise(GroupByBitwiseMethod),
+}
+
+/// The bitwise reduction applied by [`GroupByMethod::Bitwise`].
+#[cfg(feature = "bitwise")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GroupByBitwiseMethod {
+    And,
+    Or,
+    Xor,
+}
+
+#[cfg(feature = "bitwise")]
+impl Display for GroupByBitwiseMethod {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::And => "bitwise_and",
+            Self::Or => "bitwise_or",
+            Self::Xor => "bitwise_xor",
+        };
+        write!(f, "{s}")
+    }
 }
 
 impl Display for GroupByMethod {
@@ -901,6 +927,8 @@ impl Display for GroupByMethod {
             Implode => "list",
             Std(_) => "std",
             Var(_) => "var",
+            #[cfg(feature = "bitwise")]
+            Bitwise(method) => return write!(f, "{method}"),
         };
         write!(f, "{s}")
     }
@@ -926,6 +954,8 @@ pub fn fmt_group_by_column(name: &str, method: GroupByMethod) -> PlSmallStr {
         Quantile(quantile, _interpol) => format_pl_smallstr!("{name}_quantile_{quantile:.2}"),
         Std(_) => format_pl_smallstr!("{name}_agg_std"),
         Var(_) => format_pl_smallstr!("{name}_agg_var"),
+        #[cfg(feature = "bitwise")]
+        Bitwise(method) => format_pl_smallstr!("{name}_{method}"),
     }
 }
 
@@ -32,6 +32,7 @@ pub(crate) mod horizontal;
 #[cfg(any(feature = "rows", feature = "object"))]
 pub mod row;
 mod top_k;
+pub mod typed_rows;
 mod upstream_traits;
 mod validation;
 
@@ -0,0 +1,389 @@
+//! Conversions between [`DataFrame`]s and the rows of a Rust struct, with one column per field.
+//!
+//! The conversions of a struct are implemented with `#[derive(IntoDataFrame, FromDataFrame)]` of
+//! `polars-derive`, for structs whose fields implement [`ColumnValue`]. These are the numeric
+//! types, `bool`, `String`, the `chrono` and `time` dates, datetimes and times, and [`Option`]s
+//! and [`Vec`]s of those, which are nullable and list columns.
+#[cfg(feature = "dtype-time")]
+use arrow::temporal_conversions::time64ns_to_time_opt;
+#[cfg(feature = "dtype-datetime")]
+use arrow::temporal_conversions::timestamp_us_to_datetime_opt;
+#[cfg(feature = "dtype-date")]
+use arrow::temporal_conversions::{EPOCH_DAYS_FROM_CE, date32_to_date_opt};
+#[cfg(feature = "dtype-datetime")]
+use chrono::NaiveDateTime;
+#[cfg(feature = "dtype-time")]
+use chrono::NaiveTime;
+#[cfg(feature = "dtype-date")]
+use chrono::{Datelike, NaiveDate};
+
+#[cfg(feature = "dtype-time")]
+use crate::chunked_array::temporal::time_to_time64ns;
+use crate::prelude::*;
+
+/// A Rust type that is stored in a column of a [`DataFrame`].
+pub trait ColumnValue: Sized {
+    /// The data type of the column.
+    fn dtype() -> DataType;
+
+    /// Builds the column of `values`.
+    fn into_column(name: PlSmallStr, values: Vec<Self>) -> PolarsResult<Column>;
+
+    /// Extracts the values of `column`, after casting it to [`ColumnValue::dtype`]. Fails if the
+    /// column has nulls.
+    fn from_column(column: &Column) -> PolarsResult<Vec<Self>>;
+
+    /// Builds the column of `values`, with `None` as nulls.
+    fn into_column_opt(name: PlSmallStr, values: Vec<Option<Self>>) -> PolarsResult<Column>;
+
+    /// Extracts the values of `column`, after casting it to [`ColumnValue::dtype`], with nulls as
+    /// `None`.
+    fn from_column_opt(column: &Column) -> PolarsResult<Vec<Option<Self>>>;
+}
+
+/// A type whose values can be collected into a [`DataFrame`], usually a struct whose fields are
+/// the columns.
+pub trait IntoDataFrame: Sized {
+    /// Collects the values one at a time, e.g. as they are received.
+    type Builder: RowsBuilder<Row = Self>;
+
+    /// The schema of the frames of the values.
+    fn schema() -> Schema;
+
+    /// Collects `rows` into a [`DataFrame`].
+    fn into_df<I: IntoIterator<Item = Self>>(rows: I) -> PolarsResult<DataFrame> {
+        let rows = rows.into_iter();
+        let mut builder = Self::Builder::with_capacity(rows.size_hint().0);
+        for row in rows {
+            builder.push(row);
+        }
+        builder.finish()
+    }
+}
+
+/// Builds a [`DataFrame`] from rows that are pushed one at a time. Each column is collected in a
+/// `Vec` of its values, which is converted to a column once at the end.
+pub trait RowsBuilder: Default {
+    type Row;
+
+    fn with_capacity(capacity: usize) -> Self;
+
+    fn push(&mut self, row: Self::Row);
+
+    /// The number of rows that were pushed.
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Builds the [`DataFrame`] of the pushed rows.
+    fn finish(self) -> PolarsResult<DataFrame>;
+}
+
+/// A type whose values can be extracted from the rows of a [`DataFrame`], usually a struct whose
+/// fields are the columns.
+pub trait FromDataFrame: Sized {
+    /// Extracts the rows of `df`. The columns are looked up by name, so `df` may have other
+    /// columns, in any order.
+    fn from_df(df: &DataFrame) -> PolarsResult<Vec<Self>>;
+}
+
+/// Casts `column` to the data type of `T`, failing if the result has nulls if `nullable` is false.
+fn cast_column<T: ColumnValue>(column: &Column, nullable: bool) -> PolarsResult<Column> {
+    let column = column.strict_cast(&T::dtype())?;
+    polars_ensure!(
+        nullable || column.null_count() == 0,
+        ComputeError: "column '{}' has nulls; extract it into an `Option` field instead",
+        column.name()
+    );
+    Ok(column)
+}
+
+macro_rules! impl_numeric_column_value {
+    ($native:ty, $polars_type:ty) => {
+        impl ColumnValue for $native {
+            fn dtype() -> DataType {
+                <$polars_type as PolarsDataType>::get_dtype()
+            }
+
+            fn into_column(name: PlSmallStr, values: Vec<Self>) -> PolarsResult<Column> {
+                Ok(ChunkedArray::<$polars_type>::from_vec(name, values).into_column())
+            }
+
+            fn from_column(column: &Column) -> PolarsResult<Vec<Self>> {
+                let column = cast_column::<Self>(column, false)?;
+                let ca = column.as_materialized_series().unpack::<$polars_type>()?;
+                Ok(ca.into_no_null_iter().collect())
+            }
+
+            fn into_column_opt(
+                name: PlSmallStr,
+                values: Vec<Option<Self>>,
+            ) -> PolarsResult<Column> {
+                let ca = ChunkedArray::<$polars_type>::from_iter_options(name, values.into_iter());
+                Ok(ca.into_column())
+            }
+
+            fn from_column_opt(column: &Column) -> PolarsResult<Vec<Option<Self>>> {
+                let column = cast_column::<Self>(column, true)?;
+                let ca = column.as_materialized_series().unpack::<$polars_type>()?;
+                Ok(ca.iter().collect())
+            }
+        }
+    };
+}
+
+#[cfg(feature = "dtype-i8")]
+impl_numeric_column_value!(i8, Int8Type);
+#[cfg(feature = "dtype-i16")]
+impl_numeric_column_value!(i16, Int16Type);
+impl_numeric_column_value!(i32, Int32Type);
+impl_numeric_column_value!(i64, Int64Type);
+#[cfg(feature = "dtype-u8")]
+impl_numeric_column_value!(u8, UInt8Type);
+#[cfg(feature = "dtype-u16")]
+impl_numeric_column_value!(u16, UInt16Type);
+impl_numeric_column_value!(u32, UInt32Type);
+impl_numeric_column_value!(u64, UInt64Type);
+impl_numeric_column_value!(f32, Float32Type);
+impl_numeric_column_value!(f64, Float64Type);
+
+impl ColumnValue for bool {
+    fn dtype() -> DataType {
+        DataType::Boolean
+    }
+
+    fn into_column(name: PlSmallStr, values: Vec<Self>) -> PolarsResult<Column> {
+        Ok(BooleanChunked::from_slice(name, &values).into_column())
+    }
+
+    fn from_column(column: &Column) -> PolarsResult<Vec<Self>> {
+        let column = cast_column::<Self>(column, false)?;
+        Ok(column.bool()?.into_no_null_iter().collect())
+    }
+
+    fn into_column_opt(name: PlSmallStr, values: Vec<Option<Self>>) -> PolarsResult<Column> {
+        Ok(BooleanChunked::from_slice_options(name, &values).into_column())
+    }
+
+    fn from_column_opt(column: &Column) -> PolarsResult<Vec<Option<Self>>> {
+        let column = cast_column::<Self>(column, true)?;
+        Ok(column.bool()?.iter().collect())
+    }
+}
+
+impl ColumnValue for String {
+    fn dtype() -> DataType {
+        DataType::String
+    }
+
+    fn into_column(name: PlSmallStr, values: Vec<Self>) -> PolarsResult<Column> {
+        Ok(StringChunked::from_slice(name, &values).into_column())
+    }
+
+    fn from_column(column: &Column) -> PolarsResult<Vec<Self>> {
+        let column = cast_column::<Self>(column, false)?;
+        Ok(column
+            .str()?
+            .into_no_null_iter()
+            .map(str::to_owned)
+            .collect())
+    }
+
+    fn into_column_opt(name: PlSmallStr, values: Vec<Option<Self>>) -> PolarsResult<Column> {
+        Ok(StringChunked::from_slice_options(name, &values).into_column())
+    }
+
+    fn from_column_opt(column: &Column) -> PolarsResult<Vec<Option<Self>>> {
+        let column = cast_column::<Self>(column, true)?;
+        Ok(column.str()?.iter().map(|v| v.map(str::to_owned)).collect())
+    }
+}
+
+/// Implements [`ColumnValue`] for a type that is stored as the physical values of a logical type.
+#[cfg(any(
+    feature = "dtype-date",
+    feature = "dtype-datetime",
+    feature = "dtype-time"
+))]
+macro_rules! impl_logical_column_value {
+    ($native:ty, $physical:ty, $dtype:expr, $to_physical:expr, $from_physical:expr) => {
+        impl ColumnValue for $native {
+            fn dtype() -> DataType {
+                $dtype
+            }
+
+            fn into_column(name: PlSmallStr, values: Vec<Self>) -> PolarsResult<Column> {
+                let physical = values.into_iter().map($to_physical).collect();
+                <$physical>::into_column(name, physical)?.cast(&Self::dtype())
+            }
+
+            fn from_column(column: &Column) -> PolarsResult<Vec<Self>> {
+                let column = cast_column::<Self>(column, false)?.to_physical_repr();
+                <$physical>::from_column(&column)?
+                    .into_iter()
+                    .map($from_physical)
+                    .collect()
+            }
+
+            fn into_column_opt(
+                name: PlSmallStr,
+                values: Vec<Option<Self>>,
+            ) -> PolarsResult<Column> {
+                let physical = values.into_iter().map(|v| v.map($to_physical)).collect();
+                <$physical>::into_column_opt(name, physical)?.cast(&Self::dtype())
+            }
+
+            fn from_column_opt(column: &Column) -> PolarsResult<Vec<Option<Self>>> {
+                let column = cast_column::<Self>(column, true)?.to_physical_repr();
+                <$physical>::from_column_opt(&column)?
+                    .into_iter()
+                    .map(|v| v.map($from_physical).transpose())
+                    .collect()
+            }
+        }
+    };
+}
+
+#[cfg(any(
+    feature = "dtype-date",
+    feature = "dtype-datetime",
+    feature = "dtype-time"
+))]
+fn out_of_range(kind: &str) -> PolarsError {
+    polars_err!(ComputeError: "{} is out of the range of the Rust type", kind)
+}
+
+#[cfg(feature = "dtype-date")]
+impl_logical_column_value!(
+    NaiveDate,
+    i32,
+    DataType::Date,
+    |v: NaiveDate| v.num_days_from_ce() - EPOCH_DAYS_FROM_CE,
+    |v| date32_to_date_opt(v).ok_or_else(|| out_of_range("date"))
+);
+#[cfg(feature = "dtype-datetime")]
+impl_logical_column_value!(
+    NaiveDateTime,
+    i64,
+    DataType::Datetime(TimeUnit::Microseconds, None),
+    datetime_to_timestamp_us,
+    |v| timestamp_us_to_datetime_opt(v).ok_or_else(|| out_of_range("datetime"))
+);
+#[cfg(feature = "dtype-time")]
+impl_logical_column_value!(
+    NaiveTime,
+    i64,
+    DataType::Time,
+    |v: NaiveTime| time_to_time64ns(&v),
+    |v| time64ns_to_time_opt(v).ok_or_else(|| out_of_range("time"))
+);
+
+/// The Julian day of the UNIX epoch.
+#[cfg(all(feature = "time", feature = "dtype-date"))]
+const UNIX_EPOCH_JULIAN_DAY: i32 = 2_440_588;
+
+#[cfg(all(feature = "time", feature = "dtype-date"))]
+impl_logical_column_value!(
+    time::Date,
+    i32,
+    DataType::Date,
+    |v: time::Date| v.to_julian_day() - UNIX_EPOCH_JULIAN_DAY,
+    |v| time::Date::from_julian_day(v + UNIX_EPOCH_JULIAN_DAY).map_err(|_| out_of_range("date"))
+);
+#[cfg(all(feature = "time", feature = "dtype-datetime"))]
+impl_logical_column_value!(
+    time::PrimitiveDateTime,
+    i64,
+    DataType::Datetime(TimeUnit::Microseconds, None),
+    |v: time::PrimitiveDateTime| v.assume_utc().unix_timestamp_nanos().div_euclid(1000) as i64,
+    |v| {
+        let v = time::OffsetDateTime::from_unix_timestamp_nanos(v as i128 * 1000)
+            .map_err(|_| out_of_range("datetime"))?;
+        Ok(time::PrimitiveDateTime::new(v.date(), v.time()))
+    }
+);
+#[cfg(all(feature = "time", feature = "dtype-time"))]
+impl_logical_column_value!(
+    time::Time,
+    i64,
+    DataType::Time,
+    |v: time::Time| {
+        let (h, m, s, ns) = v.as_hms_nano();
+        (h as i64 * 3600 + m as i64 * 60 + s as i64) * 1_000_000_000 + ns as i64
+    },
+    |v: i64| {
+        let secs = v.div_euclid(1_000_000_000);
+        let ns = v.rem_euclid(1_000_000_000) as u32;
+        let (h, m, s) = (
+            (secs / 3600) as u8,
+            (secs / 60 % 60) as u8,
+            (secs % 60) as u8,
+        );
+        time::Time::from_hms_nano(h, m, s, ns).map_err(|_| out_of_range("time"))
+    }
+);
+
+impl<T: ColumnValue> ColumnValue for Option<T> {
+    fn dtype() -> DataType {
+        T::dtype()
+    }
+
+    fn into_column(name: PlSmallStr, values: Vec<Self>) -> PolarsResult<Column> {
+        T::into_column_opt(name, values)
+    }
+
+    fn from_column(column: &Column) -> PolarsResult<Vec<Self>> {
+        T::from_column_opt(column)
+    }
+
+    /// Both levels of `None` become nulls.
+    fn into_column_opt(name: PlSmallStr, values: Vec<Option<Self>>) -> PolarsResult<Column> {
+        T::into_column_opt(name, values.into_iter().map(Option::flatten).collect())
+    }
+
+    fn from_column_opt(column: &Column) -> PolarsResult<Vec<Option<Self>>> {
+        Ok(T::from_column_opt(column)?.into_iter().map(Some).collect())
+    }
+}
+
+impl<T: ColumnValue> ColumnValue for Vec<T> {
+    fn dtype() -> DataType {
+        DataType::List(Box::new(T::dtype()))
+    }
+
+    fn into_column(name: PlSmallStr, values: Vec<Self>) -> PolarsResult<Column> {
+        Self::into_column_opt(name, values.into_iter().map(Some).collect())
+    }
+
+    fn from_column(column: &Column) -> PolarsResult<Vec<Self>> {
+        cast_column::<Self>(column, false)?
+            .list()?
+            .into_no_null_iter()
+            .map(|s| T::from_column(&s.into_column()))
+            .collect()
+    }
+
+    fn into_column_opt(name: PlSmallStr, values: Vec<Option<Self>>) -> PolarsResult<Column> {
+        let lists = values
+            .into_iter()
+            .map(|v| {
+                v.map(|v| Ok(T::into_column(PlSmallStr::EMPTY, v)?.take_materialized_series()))
+                    .transpose()
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let mut ca: ListChunked = lists.into_iter().collect();
+        ca.rename(name);
+        // The inner type can't be inferred without values.
+        ca.into_column().cast(&Self::dtype())
+    }
+
+    fn from_column_opt(column: &Column) -> PolarsResult<Vec<Option<Self>>> {
+        cast_column::<Self>(column, true)?
+            .list()?
+            .into_iter()
+            .map(|s| s.map(|s| T::from_column(&s.into_column())).transpose())
+            .collect()
+    }
+}
@@ -109,9 +109,27 @@ impl Series {
             DataType::UInt64 => any_values_to_integer::<UInt64Type>(values, strict)?.into_series(),
             DataType::Float32 => any_values_to_f32(values, strict)?.into_series(),
             DataType::Float64 => any_values_to_f64(values, strict)?.into_series(),
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => any_values_to_f32(values, strict)?
+                .into_float16()
+                .into_series(),
             DataType::Boolean => any_values_to_bool(values, strict)?.into_series(),
             DataType::String => any_values_to_string(values, strict)?.into_series(),
             DataType::Binary => any_values_to_binary(values, strict)?.into_series(),
+            #[cfg(feature = "dtype-geometry")]
+            DataType::Geometry => any_values_to_binary(values, strict)?
+                .into_geometry()
+                .into_series(),
+            #[cfg(feature = "dtype-extension")]
+            DataType::Extension(ext) => {
+                let storage = Series::from_any_values_and_dtype(
+                    PlSmallStr::EMPTY,
+                    values,
+                    ext.storage(),
+                    strict,
+                )?;
+                ExtensionSeries::new_unchecked(storage, dtype.clone()).into_series()
+            },
             #[cfg(feature = "dtype-date")]
             DataType::Date => any_values_to_date(values, strict)?.into_series(),
             #[cfg(feature = "dtype-time")]
@@ -200,3 +200,239 @@ impl SeriesBuilder {
         self.builder.opt_gather_extend(&*chunks[0], idxs, share);
     }
 }
+
+/// A data type whose values can be appended one at a time to a [`TypedSeriesBuilder`].
+pub trait PolarsBuildableType: PolarsDataType {
+    type MutableArray: MutableArray + 'static;
+
+    fn new_mutable_array(capacity: usize) -> Self::MutableArray;
+
+    fn push(array: &mut Self::MutableArray, value: Option<Self::Physical<'_>>);
+}
+
+impl<T: PolarsNumericType> PolarsBuildableType for T {
+    type MutableArray = MutablePrimitiveArray<T::Native>;
+
+    fn new_mutable_array(capacity: usize) -> Self::MutableArray {
+        MutablePrimitiveArray::with_capacity(capacity)
+            .to(T::get_dtype().to_arrow(CompatLevel::newest()))
+    }
+
+    #[inline(always)]
+    fn push(array: &mut Self::MutableArray, value: Option<T::Native>) {
+        array.push(value)
+    }
+}
+
+impl PolarsBuildableType for BooleanType {
+    type MutableArray = MutableBooleanArray;
+
+    fn new_mutable_array(capacity: usize) -> Self::MutableArray {
+        MutableBooleanArray::with_capacity(capacity)
+    }
+
+    #[inline(always)]
+    fn push(array: &mut Self::MutableArray, value: Option<bool>) {
+        array.push(value)
+    }
+}
+
+impl PolarsBuildableType for StringType {
+    type MutableArray = MutableBinaryViewArray<str>;
+
+    fn new_mutable_array(capacity: usize) -> Self::MutableArray {
+        MutableBinaryViewArray::with_capacity(capacity)
+    }
+
+    #[inline(always)]
+    fn push(array: &mut Self::MutableArray, value: Option<&str>) {
+        array.push(value)
+    }
+}
+
+impl PolarsBuildableType for BinaryType {
+    type MutableArray = MutableBinaryViewArray<[u8]>;
+
+    fn new_mutable_array(capacity: usize) -> Self::MutableArray {
+        MutableBinaryViewArray::with_capacity(capacity)
+    }
+
+    #[inline(always)]
+    fn push(array: &mut Self::MutableArray, value: Option<&[u8]>) {
+        array.push(value)
+    }
+}
+
+/// A statically typed builder that appends values one at a time, e.g. for custom sources and
+/// FFI layers that produce their data incrementally.
+///
+/// The values are appended to the current chunk, which [`TypedSeriesBuilder::finish_chunk`]
+/// closes, so a source can produce a chunk per batch of its input without copying the chunks
+/// together afterwards.
+pub struct TypedSeriesBuilder<T: PolarsBuildableType> {
+    name: PlSmallStr,
+    dtype: DataType,
+    chunks: Vec<ArrayRef>,
+    current: T::MutableArray,
+}
+
+impl<T: PolarsBuildableType> TypedSeriesBuilder<T> {
+    pub fn new(name: PlSmallStr) -> Self {
+        Self::with_capacity(name, 0)
+    }
+
+    pub fn with_capacity(name: PlSmallStr, capacity: usize) -> Self {
+        Self {
+            name,
+            dtype: T::get_dtype(),
+            chunks: Vec::new(),
+            current: T::new_mutable_array(capacity),
+        }
+    }
+
+    /// Build a column of the logical type `dtype`, which must have `T` as its physical type, e.g.
+    /// a `Date` column from `Int32` values.
+    ///
+    /// Categoricals and enums are not supported, as their physical values are only meaningful
+    /// together with their categories.
+    pub fn with_dtype(self, dtype: DataType) -> PolarsResult<Self> {
+        polars_ensure!(
+            dtype.to_physical() == T::get_dtype()
+                && dtype.is_known()
+                && !dtype.is_categorical()
+                && !dtype.is_enum(),
+            SchemaMismatch: "cannot build a column of type {} from values of type {}",
+            dtype, T::get_dtype()
+        );
+        Ok(Self { dtype, ..self })
+    }
+
+    pub fn dtype(&self) -> &DataType {
+        &self.dtype
+    }
+
+    /// Reserve capacity for at least `additional` more values in the current chunk.
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.current.reserve(additional);
+    }
+
+    #[inline]
+    pub fn append_value(&mut self, value: T::Physical<'_>) {
+        T::push(&mut self.current, Some(value));
+    }
+
+    #[inline]
+    pub fn append_null(&mut self) {
+        T::push(&mut self.current, None);
+    }
+
+    #[inline]
+    pub fn append_option(&mut self, value: Option<T::Physical<'_>>) {
+        T::push(&mut self.current, value);
+    }
+
+    /// Append all values of `iter`.
+    pub fn extend<'a, I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = Option<T::Physical<'a>>>,
+    {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        iter.for_each(|value| T::push(&mut self.current, value));
+    }
+
+    /// Append all values of `iter`, none of which are null.
+    pub fn extend_values<'a, I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T::Physical<'a>>,
+    {
+        self.extend(iter.into_iter().map(Some));
+    }
+
+    pub fn extend_nulls(&mut self, n: usize) {
+        self.reserve(n);
+        (0..n).for_each(|_| self.current.push_null());
+    }
+
+    /// Close the current chunk, so that the next values are appended to a new chunk.
+    ///
+    /// Does nothing if no values were appended to the current chunk.
+    pub fn finish_chunk(&mut self) {
+        if self.current.len() > 0 {
+            self.chunks.push(self.current.as_box());
+        }
+    }
+
+    /// The number of values appended so far.
+    pub fn len(&self) -> usize {
+        self.chunks.iter().map(|arr| arr.len()).sum::<usize>() + self.current.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The number of chunks of the result.
+    pub fn n_chunks(&self) -> usize {
+        self.chunks.len() + (self.current.len() > 0) as usize
+    }
+
+    fn into_chunks(mut self) -> (PlSmallStr, DataType, Vec<ArrayRef>) {
+        self.finish_chunk();
+        if self.chunks.is_empty() {
+            self.chunks.push(self.current.as_box());
+        }
+        (self.name, self.dtype, self.chunks)
+    }
+
+    /// Finish the builder into a [`ChunkedArray`] of the physical type.
+    pub fn finish(self) -> ChunkedArray<T> {
+        let (name, _, chunks) = self.into_chunks();
+        unsafe { ChunkedArray::from_chunks(name, chunks) }
+    }
+
+    /// Finish the builder into a [`Series`] of the logical type of the builder.
+    pub fn finish_series(self) -> Series {
+        let (name, dtype, chunks) = self.into_chunks();
+        // SAFETY: `with_dtype` checked that the physical type of `dtype` is `T`.
+        unsafe { Series::from_chunks_and_dtype_unchecked(name, chunks, &dtype) }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_typed_series_builder() {
+        let mut builder = TypedSeriesBuilder::<StringType>::new(PlSmallStr::from_static("a"));
+        builder.append_value("x");
+        builder.append_null();
+        builder.finish_chunk();
+        builder.finish_chunk();
+        builder.extend([Some("y"), None]);
+        builder.extend_values(["z"]);
+        assert_eq!((builder.len(), builder.n_chunks()), (5, 2));
+
+        let ca = builder.finish();
+        assert_eq!(ca.chunks().len(), 2);
+        let values = ca.iter().collect::<Vec<_>>();
+        assert_eq!(values, [Some("x"), None, Some("y"), None, Some("z")]);
+
+        let mut builder = TypedSeriesBuilder::<Int32Type>::new(PlSmallStr::from_static("d"))
+            .with_dtype(DataType::Date)
+            .unwrap();
+        builder.extend_values([1, 2]);
+        builder.extend_nulls(1);
+        let s = builder.finish_series();
+        assert_eq!(s.dtype(), &DataType::Date);
+        assert_eq!(s.null_count(), 1);
+
+        let empty = TypedSeriesBuilder::<BooleanType>::new(PlSmallStr::EMPTY).finish_series();
+        assert_eq!((empty.len(), empty.dtype()), (0, &DataType::Boolean));
+
+        let builder = TypedSeriesBuilder::<Int64Type>::new(PlSmallStr::EMPTY);
+        assert!(builder.with_dtype(DataType::Date).is_err());
+    }
+}
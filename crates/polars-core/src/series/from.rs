@@ -103,7 +103,21 @@ impl Series {
             Boolean => BooleanChunked::from_chunks(name, chunks).into_series(),
             Float32 => Float32Chunked::from_chunks(name, chunks).into_series(),
             Float64 => Float64Chunked::from_chunks(name, chunks).into_series(),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Float32Chunked::from_chunks(name, chunks)
+                .into_float16_unchecked()
+                .into_series(),
             BinaryOffset => BinaryOffsetChunked::from_chunks(name, chunks).into_series(),
+            #[cfg(feature = "dtype-geometry")]
+            Geometry => BinaryChunked::from_chunks(name, chunks)
+                .into_geometry()
+                .into_series(),
+            #[cfg(feature = "dtype-extension")]
+            Extension(ext) => ExtensionSeries::new_unchecked(
+                Series::from_chunks_and_dtype_unchecked(name, chunks, ext.storage()),
+                dtype.clone(),
+            )
+            .into_series(),
             #[cfg(feature = "dtype-struct")]
             Struct(_) => {
                 let mut ca =
@@ -165,6 +179,16 @@ impl Series {
                     cast_chunks(&chunks, &DataType::String, CastOptions::NonStrict).unwrap();
                 Ok(StringChunked::from_chunks(name, chunks).into_series())
             },
+            #[cfg(feature = "dtype-geometry")]
+            ArrowDataType::BinaryView | ArrowDataType::LargeBinary | ArrowDataType::Binary
+                if md.map(|md| md.geometry()).unwrap_or(false) =>
+            {
+                let chunks =
+                    cast_chunks(&chunks, &DataType::Binary, CastOptions::NonStrict).unwrap();
+                Ok(BinaryChunked::from_chunks(name, chunks)
+                    .into_geometry()
+                    .into_series())
+            },
             ArrowDataType::BinaryView => Ok(BinaryChunked::from_chunks(name, chunks).into_series()),
             ArrowDataType::LargeBinary => {
                 if let Some(md) = md {
@@ -220,7 +244,22 @@ impl Series {
             ArrowDataType::Float16 => {
                 let chunks =
                     cast_chunks(&chunks, &DataType::Float32, CastOptions::NonStrict).unwrap();
-                Ok(Float32Chunked::from_chunks(name, chunks).into_series())
+                let ca = Float32Chunked::from_chunks(name, chunks);
+                #[cfg(feature = "dtype-f16")]
+                {
+                    // Every f16 is exactly representable as f32, so no rounding is needed.
+                    Ok(ca.into_float16_unchecked().into_series())
+                }
+                #[cfg(not(feature = "dtype-f16"))]
+                {
+                    Ok(ca.into_series())
+                }
+            },
+            #[cfg(feature = "dtype-f16")]
+            ArrowDataType::Float32 if md.map(|md| md.float16()).unwrap_or(false) => {
+                Ok(Float32Chunked::from_chunks(name, chunks)
+                    .into_float16_unchecked()
+                    .into_series())
             },
             ArrowDataType::Float32 => Ok(Float32Chunked::from_chunks(name, chunks).into_series()),
             ArrowDataType::Float64 => Ok(Float64Chunked::from_chunks(name, chunks).into_series()),
@@ -461,6 +500,34 @@ impl Series {
                 };
                 Ok(s)
             },
+            #[cfg(feature = "dtype-geometry")]
+            ArrowDataType::Extension(ext) if ext.name == GEOARROW_WKB_EXTENSION_NAME => {
+                let chunks = chunks
+                    .iter()
+                    .map(|arr| geoarrow_wkb_to_binary(arr.as_ref()))
+                    .collect::<Vec<_>>();
+                let chunks = cast_chunks(&chunks, &DataType::Binary, CastOptions::NonStrict)?;
+                Ok(BinaryChunked::from_chunks(name, chunks)
+                    .into_geometry()
+                    .into_series())
+            },
+            #[cfg(feature = "dtype-extension")]
+            ArrowDataType::Extension(ext) => {
+                let chunks = chunks
+                    .into_iter()
+                    .map(|arr| with_arrow_dtype(arr, ext.inner.clone()))
+                    .collect::<PolarsResult<_>>()?;
+                let storage =
+                    Self::_try_from_arrow_unchecked_with_md(name, chunks, &ext.inner, md)?;
+                // Unregistered extension types are read as their storage type.
+                match DataType::from_arrow_extension(ext) {
+                    Some(DataType::Extension(ext)) => {
+                        let storage = storage.cast(ext.storage())?;
+                        Ok(ExtensionSeries::new(ext, storage)?.into_series())
+                    },
+                    _ => Ok(storage),
+                }
+            },
             #[cfg(feature = "dtype-struct")]
             ArrowDataType::Struct(_) => {
                 let (chunks, dtype) = to_physical_and_dtype(chunks, md);
@@ -656,6 +723,7 @@ unsafe fn to_physical_and_dtype(
         | ArrowDataType::Timestamp(_, _)
         | ArrowDataType::Date32
         | ArrowDataType::Decimal(_, _)
+        | ArrowDataType::Float16
         | ArrowDataType::Date64) => {
             let dt = dt.clone();
             let mut s = Series::_try_from_arrow_unchecked(PlSmallStr::EMPTY, arrays, &dt).unwrap();
@@ -824,6 +892,33 @@ unsafe impl IntoSeries for Series {
     }
 }
 
+/// Drop the GeoArrow extension type of a WKB array and keep its binary storage.
+#[cfg(feature = "dtype-geometry")]
+fn geoarrow_wkb_to_binary(arr: &dyn Array) -> ArrayRef {
+    let any = arr.as_any();
+    if let Some(arr) = any.downcast_ref::<BinaryArray<i64>>() {
+        let (_, offsets, values, validity) = arr.clone().into_inner();
+        BinaryArray::<i64>::new(ArrowDataType::LargeBinary, offsets, values, validity).boxed()
+    } else if let Some(arr) = any.downcast_ref::<BinaryArray<i32>>() {
+        let (_, offsets, values, validity) = arr.clone().into_inner();
+        BinaryArray::<i32>::new(ArrowDataType::Binary, offsets, values, validity).boxed()
+    } else {
+        let arr = any.downcast_ref::<BinaryViewArray>().unwrap();
+        // SAFETY: only the dtype changes, the buffers are untouched.
+        unsafe {
+            BinaryViewArray::new_unchecked(
+                ArrowDataType::BinaryView,
+                arr.views().clone(),
+                arr.data_buffers().clone(),
+                arr.validity().cloned(),
+                arr.total_bytes_len(),
+                arr.total_buffer_len(),
+            )
+        }
+        .boxed()
+    }
+}
+
 fn new_null(name: PlSmallStr, chunks: &[ArrayRef]) -> Series {
     let len = chunks.iter().map(|arr| arr.len()).sum();
     Series::new_null(name, len)
@@ -45,6 +45,12 @@ impl private::PrivateSeries for SeriesWrap<ArrayChunked> {
         self.0.agg_list(groups)
     }
 
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_sum(&self, groups: &GroupsType) -> Series {
+        self.0
+            .agg_elementwise(groups, |s, g| unsafe { s.agg_sum(g) })
+    }
+
     #[cfg(feature = "algorithm_group_by")]
     fn group_tuples(&self, multithreaded: bool, sorted: bool) -> PolarsResult<GroupsType> {
         IntoGroupsType::group_tuples(&self.0, multithreaded, sorted)
@@ -98,6 +104,17 @@ impl SeriesTrait for SeriesWrap<ArrayChunked> {
         self.0.shrink_to_fit()
     }
 
+    #[cfg(feature = "algorithm_group_by")]
+    fn sum_reduce(&self) -> PolarsResult<Scalar> {
+        let groups = GroupsType::Slice {
+            groups: vec![[0, self.len() as IdxSize]],
+            rolling: false,
+        };
+        let out = unsafe { self.0.agg_elementwise(&groups, |s, g| s.agg_sum(g)) };
+        let value = out.get(0).unwrap().into_static();
+        Ok(Scalar::new(out.dtype().clone(), value))
+    }
+
     fn arg_sort(&self, options: SortOptions) -> IdxCa {
         let slf = (*self).clone();
         let slf = slf.into_column();
@@ -223,6 +223,16 @@ impl SeriesTrait for SeriesWrap<BinaryChunked> {
         Ok(ChunkApproxNUnique::approx_n_unique(&self.0))
     }
 
+    #[cfg(feature = "approx_unique")]
+    fn approx_n_unique_sketch(&self) -> PolarsResult<Vec<u8>> {
+        Ok(ChunkApproxNUnique::approx_n_unique_sketch(&self.0))
+    }
+
+    #[cfg(feature = "approx_unique")]
+    fn approx_n_unique_hashes(&self) -> PolarsResult<Vec<u64>> {
+        Ok(ChunkApproxNUnique::approx_n_unique_hashes(&self.0))
+    }
+
     fn is_null(&self) -> BooleanChunked {
         self.0.is_null()
     }
@@ -14,7 +14,13 @@ mod datetime;
 mod decimal;
 #[cfg(feature = "dtype-duration")]
 mod duration;
+#[cfg(feature = "dtype-extension")]
+mod extension;
+#[cfg(feature = "dtype-f16")]
+mod float16;
 mod floats;
+#[cfg(feature = "dtype-geometry")]
+mod geometry;
 mod list;
 pub(crate) mod null;
 #[cfg(feature = "object")]
@@ -435,6 +441,16 @@ macro_rules! impl_dyn_series {
                 Ok(ChunkApproxNUnique::approx_n_unique(&self.0))
             }
 
+            #[cfg(feature = "approx_unique")]
+            fn approx_n_unique_sketch(&self) -> PolarsResult<Vec<u8>> {
+                Ok(ChunkApproxNUnique::approx_n_unique_sketch(&self.0))
+            }
+
+            #[cfg(feature = "approx_unique")]
+            fn approx_n_unique_hashes(&self) -> PolarsResult<Vec<u64>> {
+                Ok(ChunkApproxNUnique::approx_n_unique_hashes(&self.0))
+            }
+
             fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
                 Arc::new(SeriesWrap(Clone::clone(&self.0)))
             }
@@ -480,7 +496,8 @@ impl_dyn_series!(Int128Chunked, Int128Type);
 
 impl<T: PolarsNumericType> private::PrivateSeriesNumeric for SeriesWrap<ChunkedArray<T>> {
     fn bit_repr(&self) -> Option<BitRepr> {
-        Some(self.0.to_bit_repr())
+        // 128-bit values don't fit in a `BitRepr`.
+        (size_of::<T::Native>() <= 8).then(|| self.0.to_bit_repr())
     }
 }
 
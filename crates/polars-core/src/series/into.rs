@@ -156,6 +156,12 @@ impl Series {
                 &DataType::Time.to_arrow(compat_level),
             )
             .unwrap(),
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => cast(
+                &*self.chunks()[chunk_idx],
+                &DataType::Float16.to_arrow(compat_level),
+            )
+            .unwrap(),
             #[cfg(feature = "dtype-decimal")]
             DataType::Decimal(_, _) => self.decimal().unwrap().chunks()[chunk_idx]
                 .as_any()
@@ -188,6 +194,15 @@ impl Series {
                     cast_unchecked(arr.as_ref(), &ArrowDataType::LargeUtf8).unwrap()
                 }
             },
+            #[cfg(feature = "dtype-geometry")]
+            DataType::Geometry => self.to_physical_repr().to_arrow(chunk_idx, compat_level),
+            #[cfg(feature = "dtype-extension")]
+            DataType::Extension(_) => {
+                let storage = self.extension().unwrap().storage();
+                let arr = storage.to_arrow(chunk_idx, compat_level);
+                // The storage dtypes are polars dtypes, which are never unions.
+                with_arrow_dtype(arr, self.dtype().to_arrow(compat_level)).unwrap()
+            },
             DataType::Binary => {
                 if compat_level.0 >= 1 {
                     self.array_ref(chunk_idx).clone()
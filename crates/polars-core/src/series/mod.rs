@@ -454,6 +454,17 @@ impl Series {
             return Ok(Series::full_null(self.name().clone(), len, dtype));
         }
 
+        #[cfg(feature = "dtype-extension")]
+        if let D::Extension(ext) = dtype {
+            if !self.dtype().is_extension() {
+                let storage = match ext.cast_from(self) {
+                    Some(storage) => storage?,
+                    None => self.cast_with_options(ext.storage(), options)?,
+                };
+                return Ok(ExtensionSeries::new(ext.clone(), storage)?.into_series());
+            }
+        }
+
         let new_options = match options {
             // Strictness is handled on this level to improve error messages.
             CastOptions::Strict => CastOptions::NonStrict,
@@ -547,6 +558,22 @@ impl Series {
             .into_series()),
 
             (D::Int32, D::Date) => feature_gated!("dtype-time", Ok(self.clone().into_date())),
+            #[cfg(feature = "dtype-f16")]
+            (D::Float32, D::Float16) => Ok(self
+                .f32()
+                .unwrap()
+                .clone()
+                .into_float16_unchecked()
+                .into_series()),
+            #[cfg(feature = "dtype-extension")]
+            (_, D::Extension(ext)) => {
+                let storage = self.from_physical_unchecked(ext.storage())?;
+                Ok(ExtensionSeries::new(ext.clone(), storage)?.into_series())
+            },
+            #[cfg(feature = "dtype-geometry")]
+            (D::Binary, D::Geometry) => {
+                Ok(self.binary().unwrap().clone().into_geometry().into_series())
+            },
             (D::Int64, D::Datetime(tu, tz)) => feature_gated!(
                 "dtype-datetime",
                 Ok(self.clone().into_datetime(*tu, tz.clone()))
@@ -736,6 +763,12 @@ impl Series {
             },
             #[cfg(feature = "dtype-decimal")]
             Decimal(_, _) => Cow::Owned(self.decimal().unwrap().0.clone().into_series()),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Cow::Owned(self.float16().unwrap().0.clone().into_series()),
+            #[cfg(feature = "dtype-geometry")]
+            Geometry => Cow::Owned(self.geometry().unwrap().0.clone().into_series()),
+            #[cfg(feature = "dtype-extension")]
+            Extension(_) => self.extension().unwrap().storage().to_physical_repr(),
             List(_) => match self.list().unwrap().to_physical_repr() {
                 Cow::Borrowed(_) => Cow::Borrowed(self),
                 Cow::Owned(ca) => Cow::Owned(ca.into_series()),
@@ -855,6 +888,15 @@ impl Series {
         }
     }
 
+    #[cfg(feature = "dtype-f16")]
+    pub(crate) fn into_float16(self) -> Series {
+        match self.dtype() {
+            DataType::Float32 => self.f32().unwrap().clone().into_float16().into_series(),
+            DataType::Float16 => self,
+            dt => panic!("float16 not implemented for {dt:?}"),
+        }
+    }
+
     pub(crate) fn into_date(self) -> Series {
         #[cfg(not(feature = "dtype-date"))]
         {
@@ -927,7 +969,12 @@ impl Series {
 
     // used for formatting
     pub fn str_value(&self, index: usize) -> PolarsResult<Cow<str>> {
-        Ok(self.0.get(index)?.str_value())
+        let value = self.0.get(index)?;
+        #[cfg(feature = "dtype-extension")]
+        if let (DataType::Extension(ext), false) = (self.dtype(), value.is_null()) {
+            return Ok(Cow::Owned(ext.format_value(value)));
+        }
+        Ok(value.str_value())
     }
     /// Get the head of the Series.
     pub fn head(&self, length: Option<usize>) -> Series {
@@ -943,6 +990,29 @@ impl Series {
     }
 
     pub fn mean_reduce(&self) -> Scalar {
+        #[cfg(feature = "dtype-decimal")]
+        if let Some(ca) = self.try_decimal() {
+            use crate::chunked_array::arithmetic::{_decimal_mean, _get_decimal_scale_div};
+
+            let count = ca.len() - ca.null_count();
+            let mean = ca
+                .physical()
+                .sum()
+                .and_then(|sum| _decimal_mean(sum, count, ca.scale()));
+            let scale = _get_decimal_scale_div(ca.scale());
+            let av = mean.map_or(AnyValue::Null, |v| AnyValue::Decimal(v, scale));
+            return Scalar::new(DataType::Decimal(None, Some(scale)), av);
+        }
+        #[cfg(all(feature = "dtype-array", feature = "algorithm_group_by"))]
+        if let Some(ca) = self.try_array() {
+            let groups = GroupsType::Slice {
+                groups: vec![[0, self.len() as IdxSize]],
+                rolling: false,
+            };
+            let out = unsafe { ca.agg_elementwise(&groups, |s, g| s.agg_mean(g)) };
+            let value = out.get(0).unwrap().into_static();
+            return Scalar::new(out.dtype().clone(), value);
+        }
         crate::scalar::reduce::mean_reduce(self.mean(), self.dtype().clone())
     }
 
@@ -118,6 +118,24 @@ impl Series {
         try_unpack_chunked!(self, DataType::BinaryOffset => BinaryOffsetChunked)
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Geometry`]
+    #[cfg(feature = "dtype-geometry")]
+    pub fn try_geometry(&self) -> Option<&GeometryChunked> {
+        try_unpack_chunked!(self, DataType::Geometry => GeometryChunked)
+    }
+
+    /// Unpack to [`ExtensionSeries`] of dtype [`DataType::Extension`]
+    #[cfg(feature = "dtype-extension")]
+    pub fn try_extension(&self) -> Option<&ExtensionSeries> {
+        try_unpack_chunked!(self, DataType::Extension(_) => ExtensionSeries)
+    }
+
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Float16`]
+    #[cfg(feature = "dtype-f16")]
+    pub fn try_float16(&self) -> Option<&Float16Chunked> {
+        try_unpack_chunked!(self, DataType::Float16 => Float16Chunked)
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype [`DataType::Time`]
     #[cfg(feature = "dtype-time")]
     pub fn try_time(&self) -> Option<&TimeChunked> {
@@ -287,6 +305,27 @@ impl Series {
             .ok_or_else(|| unpack_chunked_err!(self => "BinaryOffset"))
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Geometry`]
+    #[cfg(feature = "dtype-geometry")]
+    pub fn geometry(&self) -> PolarsResult<&GeometryChunked> {
+        self.try_geometry()
+            .ok_or_else(|| unpack_chunked_err!(self => "Geometry"))
+    }
+
+    /// Unpack to [`ExtensionSeries`] of dtype [`DataType::Extension`]
+    #[cfg(feature = "dtype-extension")]
+    pub fn extension(&self) -> PolarsResult<&ExtensionSeries> {
+        self.try_extension()
+            .ok_or_else(|| unpack_chunked_err!(self => "Extension"))
+    }
+
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Float16`]
+    #[cfg(feature = "dtype-f16")]
+    pub fn float16(&self) -> PolarsResult<&Float16Chunked> {
+        self.try_float16()
+            .ok_or_else(|| unpack_chunked_err!(self => "Float16"))
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype [`DataType::Time`]
     #[cfg(feature = "dtype-time")]
     pub fn time(&self) -> PolarsResult<&TimeChunked> {
@@ -47,6 +47,20 @@ impl Series {
             DataType::Time => Int64Chunked::full_null(name, size)
                 .into_time()
                 .into_series(),
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => Float32Chunked::full_null(name, size)
+                .into_float16_unchecked()
+                .into_series(),
+            #[cfg(feature = "dtype-geometry")]
+            DataType::Geometry => BinaryChunked::full_null(name, size)
+                .into_geometry()
+                .into_series(),
+            #[cfg(feature = "dtype-extension")]
+            DataType::Extension(ext) => ExtensionSeries::new_unchecked(
+                Series::full_null(name, size, ext.storage()),
+                dtype.clone(),
+            )
+            .into_series(),
             #[cfg(feature = "dtype-decimal")]
             DataType::Decimal(precision, scale) => Int128Chunked::full_null(name, size)
                 .into_decimal_unchecked(*precision, scale.unwrap_or(0))
@@ -548,6 +548,20 @@ pub trait SeriesTrait:
         polars_bail!(opq = approx_n_unique, self._dtype());
     }
 
+    /// The registers of the HyperLogLog sketch that [`SeriesTrait::approx_n_unique`] estimates
+    /// the number of unique values from. Sketches of different series can be merged.
+    #[cfg(feature = "approx_unique")]
+    fn approx_n_unique_sketch(&self) -> PolarsResult<Vec<u8>> {
+        polars_bail!(opq = approx_n_unique_sketch, self._dtype());
+    }
+
+    /// The hashes the values set the registers of [`SeriesTrait::approx_n_unique_sketch`] from,
+    /// so sketches can be updated in place.
+    #[cfg(feature = "approx_unique")]
+    fn approx_n_unique_hashes(&self) -> PolarsResult<Vec<u64>> {
+        polars_bail!(opq = approx_n_unique_hashes, self._dtype());
+    }
+
     /// Clone inner ChunkedArray and wrap in a new Arc
     fn clone_inner(&self) -> Arc<dyn SeriesTrait>;
 
@@ -274,6 +274,13 @@ pub fn get_supertype_with_options(
 
             (Float64, Float32) => Some(Float64),
 
+            #[cfg(feature = "dtype-f16")]
+            (Float16, Boolean | UInt8 | Int8) => Some(Float16),
+            #[cfg(feature = "dtype-f16")]
+            (Float16, UInt16 | Int16 | Float32) => Some(Float32),
+            #[cfg(feature = "dtype-f16")]
+            (Float16, UInt32 | UInt64 | Int32 | Int64 | Int128 | Float64) => Some(Float64),
+
             // Time related dtypes
             #[cfg(feature = "dtype-date")]
             (Date, UInt32) => Some(Int64),
@@ -403,7 +410,7 @@ pub fn get_supertype_with_options(
                         }
                     },
                     // numeric vs float|str -> always float|str|decimal
-                    UnknownKind::Float | UnknownKind::Int(_) if dt.is_float() | dt.is_decimal() => Some(dt.clone()),
+                    UnknownKind::Float | UnknownKind::Int(_) if dt.is_float() | dt.is_float16() | dt.is_decimal() => Some(dt.clone()),
                     UnknownKind::Float if dt.is_integer() => Some(Unknown(UnknownKind::Float)),
                     // Materialize float to float or decimal
                     UnknownKind::Float if dt.is_float() | dt.is_decimal() => Some(dt.clone()),
@@ -461,12 +468,34 @@ pub fn get_supertype_with_options(
             }
             #[cfg(feature = "dtype-decimal")]
             (Decimal(p1, s1), Decimal(p2, s2)) => {
-                Some(Decimal((*p1).zip(*p2).map(|(p1, p2)| p1.max(p2)), (*s1).max(*s2)))
+                let scale = (*s1).max(*s2);
+                // Keep enough integer digits for both sides.
+                let precision = (*p1).zip(*p2).and_then(|(p1, p2)| {
+                    let int_digits = p1.saturating_sub(s1.unwrap_or(0)).max(p2.saturating_sub(s2.unwrap_or(0)));
+                    let precision = int_digits + scale.unwrap_or(0);
+                    (precision <= 38).then_some(precision)
+                });
+                Some(Decimal(precision, scale))
             }
             #[cfg(feature = "dtype-decimal")]
             (Decimal(_, _), f @ (Float32 | Float64)) => Some(f.clone()),
             #[cfg(feature = "dtype-decimal")]
-            (d @ Decimal(_, _), dt) if dt.is_signed_integer() || dt.is_unsigned_integer() => Some(d.clone()),
+            (Decimal(p, s), dt) if dt.is_signed_integer() || dt.is_unsigned_integer() => {
+                // The integer must fit in the integer digits of the decimal.
+                let int_digits = match dt {
+                    Int8 | UInt8 => 3,
+                    Int16 | UInt16 => 5,
+                    Int32 | UInt32 => 10,
+                    Int64 => 19,
+                    UInt64 => 20,
+                    _ => 39,
+                };
+                let precision = p.and_then(|p| {
+                    let precision = p.max(int_digits + s.unwrap_or(0));
+                    (precision <= 38).then_some(precision)
+                });
+                Some(Decimal(precision, *s))
+            }
             _ => None,
         }
     }
@@ -0,0 +1,226 @@
+//! Derive macros for the conversions between [`DataFrame`]s and the rows of a struct, with one
+//! column per field. See `polars_core::frame::typed_rows` for the supported field types.
+//!
+//! ```ignore
+//! use polars::prelude::*;
+//!
+//! #[derive(IntoDataFrame, FromDataFrame)]
+//! struct Trade {
+//!     #[polars(rename = "sym")]
+//!     symbol: String,
+//!     price: f64,
+//!     fills: Vec<u32>,
+//!     note: Option<String>,
+//! }
+//!
+//! let df = Trade::into_df(trades)?;
+//! let trades = Trade::from_df(&df)?;
+//! ```
+//!
+//! `IntoDataFrame` also generates a `{Struct}RowsBuilder` with the visibility of the struct, to
+//! collect rows one at a time. Crates that depend on `polars-core` instead of `polars` set the path
+//! of the crate with `#[polars(crate = "polars_core")]` on the struct.
+//!
+//! [`DataFrame`]: https://docs.rs/polars/latest/polars/frame/struct.DataFrame.html
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::ext::IdentExt;
+use syn::{Data, DeriveInput, Fields, Ident, LitStr, Path, Type, parse_macro_input};
+
+/// A field of the struct, and the name of its column.
+struct RowField {
+    ident: Ident,
+    ty: Type,
+    column: LitStr,
+}
+
+/// A struct with named fields, whose rows are the columns of a frame.
+struct RowStruct {
+    input: DeriveInput,
+    krate: Path,
+    fields: Vec<RowField>,
+}
+
+impl RowStruct {
+    fn parse(input: DeriveInput) -> syn::Result<Self> {
+        if input.generics.lt_token.is_some() || input.generics.where_clause.is_some() {
+            return Err(syn::Error::new_spanned(
+                &input.generics,
+                "generic structs can't be converted to DataFrames",
+            ));
+        }
+
+        let mut krate = syn::parse_quote!(::polars);
+        for attr in input.attrs.iter().filter(|a| a.path().is_ident("polars")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("crate") {
+                    krate = meta.value()?.parse::<LitStr>()?.parse()?;
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported polars attribute, expected `crate`"))
+                }
+            })?;
+        }
+
+        let named = match &input.data {
+            Data::Struct(data) => match &data.fields {
+                Fields::Named(named) if !named.named.is_empty() => named,
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        &input.ident,
+                        "only structs with named fields can be converted to DataFrames",
+                    ));
+                },
+            },
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "only structs can be converted to DataFrames",
+                ));
+            },
+        };
+
+        let mut fields = Vec::with_capacity(named.named.len());
+        for field in &named.named {
+            let ident = field.ident.clone().unwrap();
+            let mut column = LitStr::new(&ident.unraw().to_string(), ident.span());
+            for attr in field.attrs.iter().filter(|a| a.path().is_ident("polars")) {
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("rename") {
+                        column = meta.value()?.parse()?;
+                        Ok(())
+                    } else {
+                        Err(meta.error("unsupported polars attribute, expected `rename`"))
+                    }
+                })?;
+            }
+            fields.push(RowField {
+                ident,
+                ty: field.ty.clone(),
+                column,
+            });
+        }
+
+        Ok(Self {
+            input,
+            krate,
+            fields,
+        })
+    }
+
+    fn impl_into_data_frame(&self) -> TokenStream2 {
+        let krate = &self.krate;
+        let name = &self.input.ident;
+        let vis = &self.input.vis;
+        let builder = format_ident!("{}RowsBuilder", name);
+        let builder_doc = format!("Collects [`{name}`]s into a `DataFrame`, one row at a time.");
+
+        let idents = self.fields.iter().map(|f| &f.ident).collect::<Vec<_>>();
+        let types = self.fields.iter().map(|f| &f.ty).collect::<Vec<_>>();
+        let columns = self.fields.iter().map(|f| &f.column).collect::<Vec<_>>();
+        let indices = (0..self.fields.len())
+            .map(syn::Index::from)
+            .collect::<Vec<_>>();
+        let with_capacity = types
+            .iter()
+            .map(|_| quote!(::std::vec::Vec::with_capacity(capacity)));
+
+        quote! {
+            #[doc = #builder_doc]
+            #[derive(Default)]
+            #vis struct #builder(#(::std::vec::Vec<#types>),*);
+
+            impl #krate::frame::typed_rows::RowsBuilder for #builder {
+                type Row = #name;
+
+                fn with_capacity(capacity: usize) -> Self {
+                    Self(#(#with_capacity),*)
+                }
+
+                fn push(&mut self, row: #name) {
+                    #(self.#indices.push(row.#idents);)*
+                }
+
+                fn len(&self) -> usize {
+                    self.0.len()
+                }
+
+                fn finish(self) -> #krate::prelude::PolarsResult<#krate::prelude::DataFrame> {
+                    #krate::prelude::DataFrame::new(::std::vec![#(
+                        <#types as #krate::frame::typed_rows::ColumnValue>::into_column(
+                            #krate::prelude::PlSmallStr::from_static(#columns),
+                            self.#indices,
+                        )?
+                    ),*])
+                }
+            }
+
+            impl #krate::frame::typed_rows::IntoDataFrame for #name {
+                type Builder = #builder;
+
+                fn schema() -> #krate::prelude::Schema {
+                    #krate::prelude::Schema::from_iter([#((
+                        #krate::prelude::PlSmallStr::from_static(#columns),
+                        <#types as #krate::frame::typed_rows::ColumnValue>::dtype(),
+                    )),*])
+                }
+            }
+        }
+    }
+
+    fn impl_from_data_frame(&self) -> TokenStream2 {
+        let krate = &self.krate;
+        let name = &self.input.ident;
+
+        let idents = self.fields.iter().map(|f| &f.ident).collect::<Vec<_>>();
+        let types = self.fields.iter().map(|f| &f.ty).collect::<Vec<_>>();
+        let columns = self.fields.iter().map(|f| &f.column).collect::<Vec<_>>();
+        let values = (0..self.fields.len())
+            .map(|i| format_ident!("__values_{}", i))
+            .collect::<Vec<_>>();
+
+        quote! {
+            impl #krate::frame::typed_rows::FromDataFrame for #name {
+                fn from_df(
+                    df: &#krate::prelude::DataFrame,
+                ) -> #krate::prelude::PolarsResult<::std::vec::Vec<Self>> {
+                    #(
+                        let mut #values =
+                            <#types as #krate::frame::typed_rows::ColumnValue>::from_column(
+                                df.column(#columns)?,
+                            )?
+                            .into_iter();
+                    )*
+                    ::std::result::Result::Ok(
+                        (0..df.height())
+                            .map(|_| Self {
+                                #(#idents: #values.next().unwrap(),)*
+                            })
+                            .collect(),
+                    )
+                }
+            }
+        }
+    }
+}
+
+fn expand(input: TokenStream, f: impl FnOnce(&RowStruct) -> TokenStream2) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match RowStruct::parse(input) {
+        Ok(row) => f(&row).into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+/// Implements `IntoDataFrame` for a struct with named fields, and generates its `RowsBuilder`.
+#[proc_macro_derive(IntoDataFrame, attributes(polars))]
+pub fn derive_into_data_frame(input: TokenStream) -> TokenStream {
+    expand(input, RowStruct::impl_into_data_frame)
+}
+
+/// Implements `FromDataFrame` for a struct with named fields.
+#[proc_macro_derive(FromDataFrame, attributes(polars))]
+pub fn derive_from_data_frame(input: TokenStream) -> TokenStream {
+    expand(input, RowStruct::impl_from_data_frame)
+}
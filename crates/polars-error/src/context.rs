@@ -0,0 +1,31 @@
+use std::borrow::Cow;
+use std::fmt::{self, Display, Formatter};
+
+/// Structured information about the operation that raised an error.
+///
+/// This is attached to a [`PolarsError`](crate::PolarsError) with
+/// [`with_context`](crate::PolarsError::with_context) and can be retrieved with
+/// [`contexts`](crate::PolarsError::contexts), so that callers don't have to parse the error
+/// message to find the responsible operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorContext {
+    /// A node of the logical plan, identified by its index in the plan arena.
+    PlanNode { id: usize, name: Cow<'static, str> },
+    /// An expression, formatted as in the query plan.
+    Expr(String),
+    /// A file that was being read or written.
+    File(String),
+    /// The index of the row group that was being read.
+    RowGroup(usize),
+}
+
+impl Display for ErrorContext {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PlanNode { id, name } => write!(f, "in plan node {id} ({name})"),
+            Self::Expr(expr) => write!(f, "in expression: {expr}"),
+            Self::File(path) => write!(f, "in file '{path}'"),
+            Self::RowGroup(idx) => write!(f, "in row group {idx}"),
+        }
+    }
+}
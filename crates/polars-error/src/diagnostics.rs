@@ -0,0 +1,148 @@
+use std::cell::RefCell;
+use std::fmt::{self, Display, Formatter};
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+
+use crate::{PolarsResult, polars_bail};
+
+thread_local! {
+    /// The diagnostics collector of the queries started on this thread, see [`Diagnostics::hold`].
+    static ACTIVE_DIAGNOSTICS: RefCell<Option<Arc<Diagnostics>>> = const { RefCell::new(None) };
+}
+
+/// The kind of a [`Diagnostic`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DiagnosticKind {
+    /// A column whose length didn't match the other columns was broadcast.
+    LengthMismatchBroadcast,
+    /// A non-strict cast turned non-null values into nulls.
+    LossyCast,
+    /// The time zone of a datetime was dropped.
+    DroppedTimezone,
+    /// A file was skipped while reading.
+    SkippedFile,
+}
+
+impl DiagnosticKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::LengthMismatchBroadcast => "length_mismatch_broadcast",
+            Self::LossyCast => "lossy_cast",
+            Self::DroppedTimezone => "dropped_timezone",
+            Self::SkippedFile => "skipped_file",
+        }
+    }
+}
+
+impl Display for DiagnosticKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Something that happened during a query which is not an error, but could indicate one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub kind: DiagnosticKind,
+    pub message: String,
+}
+
+/// Collects the [`Diagnostic`]s raised by the queries that are started while it is active.
+///
+/// Diagnostics of a kind that is `strict` are raised as errors instead.
+#[derive(Default)]
+pub struct Diagnostics {
+    strict: Vec<DiagnosticKind>,
+    entries: Mutex<Vec<Diagnostic>>,
+}
+
+impl Diagnostics {
+    pub fn new(strict: &[DiagnosticKind]) -> Arc<Self> {
+        Arc::new(Self {
+            strict: strict.to_vec(),
+            entries: Mutex::default(),
+        })
+    }
+
+    /// Make this the diagnostics collector of the queries that are started on this thread, for
+    /// as long as the returned holder is alive.
+    ///
+    /// A query picks up the collector when it is planned and keeps it in its execution state, so
+    /// queries that run at the same time on other threads don't report to it.
+    pub fn hold(self: &Arc<Self>) -> DiagnosticsHolder {
+        let previous = ACTIVE_DIAGNOSTICS.with_borrow_mut(|d| d.replace(self.clone()));
+        DiagnosticsHolder {
+            previous,
+            _not_send: PhantomData,
+        }
+    }
+
+    /// Returns the collector of the queries started on this thread, if any.
+    pub fn current() -> Option<Arc<Self>> {
+        ACTIVE_DIAGNOSTICS.with_borrow(|d| d.clone())
+    }
+
+    /// Takes the collected diagnostics, in the order in which they were raised.
+    pub fn take(&self) -> Vec<Diagnostic> {
+        std::mem::take(&mut *self.entries.lock())
+    }
+
+    /// Records a diagnostic, or returns an error if this collector is strict about its kind.
+    pub fn report(&self, kind: DiagnosticKind, message: String) -> PolarsResult<()> {
+        if self.strict.contains(&kind) {
+            polars_bail!(InvalidOperation: "{} (strict diagnostics: {})", message, kind);
+        }
+        self.entries.lock().push(Diagnostic { kind, message });
+        Ok(())
+    }
+}
+
+/// Keeps a [`Diagnostics`] collector active ([RAII]), restoring the previously active one on drop.
+///
+/// [RAII]: https://en.wikipedia.org/wiki/Resource_acquisition_is_initialization
+pub struct DiagnosticsHolder {
+    previous: Option<Arc<Diagnostics>>,
+    // The collector is restored on the thread that holds it.
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Drop for DiagnosticsHolder {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        ACTIVE_DIAGNOSTICS.with_borrow_mut(|d| *d = previous);
+    }
+}
+
+/// Reports a diagnostic to `diagnostics`, or does nothing if there is no collector.
+///
+/// Returns an error if the collector is strict about this kind of diagnostic.
+pub fn report_diagnostic(
+    diagnostics: Option<&Diagnostics>,
+    kind: DiagnosticKind,
+    message: impl FnOnce() -> String,
+) -> PolarsResult<()> {
+    match diagnostics {
+        Some(diagnostics) => diagnostics.report(kind, message()),
+        None => Ok(()),
+    }
+}
+
+/// Reports a diagnostic to the collector of a query, e.g. the one of its execution state, or to
+/// the collector of the current thread if none is given, while the query is being planned.
+#[macro_export]
+macro_rules! polars_diagnostic {
+    ($kind:ident: $($arg:tt)+) => {
+        $crate::report_diagnostic(
+            $crate::Diagnostics::current().as_deref(),
+            $crate::DiagnosticKind::$kind,
+            || format!($($arg)+),
+        )
+    };
+    ($diagnostics:expr, $kind:ident: $($arg:tt)+) => {
+        $crate::report_diagnostic($diagnostics, $crate::DiagnosticKind::$kind, || {
+            format!($($arg)+)
+        })
+    };
+}
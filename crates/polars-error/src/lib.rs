@@ -1,4 +1,6 @@
 pub mod constants;
+mod context;
+mod diagnostics;
 mod warning;
 
 use std::borrow::Cow;
@@ -11,6 +13,8 @@ use std::sync::{Arc, LazyLock};
 use std::{env, io};
 pub mod signals;
 
+pub use context::ErrorContext;
+pub use diagnostics::*;
 pub use warning::*;
 
 enum ErrorStrategy {
@@ -95,9 +99,11 @@ pub enum PolarsError {
     SQLSyntax(ErrString),
     StringCacheMismatch(ErrString),
     StructFieldNotFound(ErrString),
+    Timeout(ErrString),
     Context {
         error: Box<PolarsError>,
         msg: ErrString,
+        context: Option<Box<ErrorContext>>,
     },
 }
 
@@ -126,7 +132,14 @@ impl Display for PolarsError {
             ShapeMismatch(msg) => write!(f, "lengths don't match: {msg}"),
             StringCacheMismatch(msg) => write!(f, "string caches don't match: {msg}"),
             StructFieldNotFound(msg) => write!(f, "field not found: {msg}"),
-            Context { error, msg } => write!(f, "{error}: {msg}"),
+            Timeout(msg) => write!(f, "timed out: {msg}"),
+            // Structured context is exposed through the accessors, it keeps the message as is.
+            Context {
+                error,
+                context: Some(_),
+                ..
+            } => write!(f, "{error}"),
+            Context { error, msg, .. } => write!(f, "{error}: {msg}"),
         }
     }
 }
@@ -191,19 +204,26 @@ impl PolarsError {
     pub fn context_trace(self) -> Self {
         use PolarsError::*;
         match self {
-            Context { error, msg } => {
-                // If context is 1 level deep, just return error.
-                if !matches!(&*error, PolarsError::Context { .. }) {
-                    return *error;
+            Context { .. } => {
+                let material_error = self.get_err();
+
+                let mut messages = Vec::new();
+                let mut current_error = &self;
+                while let PolarsError::Context {
+                    msg,
+                    error,
+                    context,
+                } = current_error
+                {
+                    if context.is_none() {
+                        messages.push(msg)
+                    }
+                    current_error = error;
                 }
-                let mut current_error = &*error;
-                let material_error = error.get_err();
-
-                let mut messages = vec![&msg];
 
-                while let PolarsError::Context { msg, error } = current_error {
-                    current_error = error;
-                    messages.push(msg)
+                // If context is 1 level deep, just return error.
+                if messages.len() <= 1 {
+                    return material_error.clone();
                 }
 
                 let mut bt = String::new();
@@ -248,6 +268,7 @@ impl PolarsError {
             StructFieldNotFound(msg) => StructFieldNotFound(func(msg).into()),
             SQLInterface(msg) => SQLInterface(func(msg).into()),
             SQLSyntax(msg) => SQLSyntax(func(msg).into()),
+            Timeout(msg) => Timeout(func(msg).into()),
             Context { error, .. } => error.wrap_msg(func),
         }
     }
@@ -264,8 +285,67 @@ impl PolarsError {
         PolarsError::Context {
             msg,
             error: Box::new(self),
+            context: None,
+        }
+    }
+
+    /// Attach structured context to this error. Unlike [`PolarsError::context`], it doesn't change
+    /// the error message.
+    pub fn with_context(self, context: ErrorContext) -> Self {
+        PolarsError::Context {
+            // Not through `From`, we don't want a backtrace for every context.
+            msg: ErrString(Cow::Owned(context.to_string())),
+            error: Box::new(self),
+            context: Some(Box::new(context)),
         }
     }
+
+    /// The structured context attached to this error, from the outermost to the innermost.
+    pub fn contexts(&self) -> Vec<&ErrorContext> {
+        let mut out = Vec::new();
+        let mut current = self;
+        while let PolarsError::Context { error, context, .. } = current {
+            out.extend(context.as_deref());
+            current = error;
+        }
+        out
+    }
+
+    fn find_context<'a, T>(&'a self, f: impl Fn(&'a ErrorContext) -> Option<T>) -> Option<T> {
+        self.contexts().into_iter().rev().find_map(f)
+    }
+
+    /// The innermost plan node in which this error occurred, as `(id, name)`.
+    pub fn plan_node(&self) -> Option<(usize, &str)> {
+        self.find_context(|c| match c {
+            ErrorContext::PlanNode { id, name } => Some((*id, name.as_ref())),
+            _ => None,
+        })
+    }
+
+    /// The innermost expression in which this error occurred.
+    pub fn expr(&self) -> Option<&str> {
+        self.find_context(|c| match c {
+            ErrorContext::Expr(expr) => Some(expr.as_str()),
+            _ => None,
+        })
+    }
+
+    /// The file that was being read or written when this error occurred.
+    pub fn file(&self) -> Option<&str> {
+        self.find_context(|c| match c {
+            ErrorContext::File(path) => Some(path.as_str()),
+            _ => None,
+        })
+    }
+
+    /// The row group that was being read when this error occurred.
+    pub fn row_group(&self) -> Option<usize> {
+        self.find_context(|c| match c {
+            ErrorContext::RowGroup(idx) => Some(*idx),
+            _ => None,
+        })
+    }
 }
 
 pub fn map_err<E: Error>(error: E) -> PolarsError {
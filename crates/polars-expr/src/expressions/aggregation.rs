@@ -152,6 +152,15 @@ impl PhysicalExpr for AggregationExpr {
                 .var_reduce(ddof)
                 .map(|sc| sc.into_column(s.name().clone())),
             GroupByMethod::Quantile(_, _) => unimplemented!(),
+            #[cfg(feature = "bitwise")]
+            GroupByMethod::Bitwise(method) => {
+                let sc = match method {
+                    GroupByBitwiseMethod::And => s.and_reduce(),
+                    GroupByBitwiseMethod::Or => s.or_reduce(),
+                    GroupByBitwiseMethod::Xor => s.xor_reduce(),
+                }?;
+                Ok(sc.into_column(s.name().clone()))
+            },
         }
     }
     #[allow(clippy::ptr_arg)]
@@ -386,6 +395,16 @@ impl PhysicalExpr for AggregationExpr {
                     // implemented explicitly in AggQuantile struct
                     unimplemented!()
                 },
+                #[cfg(feature = "bitwise")]
+                GroupByMethod::Bitwise(method) => {
+                    let (c, groups) = ac.get_final_aggregation();
+                    let agg_c = match method {
+                        GroupByBitwiseMethod::And => c.agg_and(&groups),
+                        GroupByBitwiseMethod::Or => c.agg_or(&groups),
+                        GroupByBitwiseMethod::Xor => c.agg_xor(&groups),
+                    };
+                    AggregatedScalar(agg_c.with_name(keep_name))
+                },
                 GroupByMethod::NanMin => {
                     #[cfg(feature = "propagate_nans")]
                     {
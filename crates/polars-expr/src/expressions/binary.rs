@@ -72,6 +72,8 @@ pub fn apply_operator(left: &Column, right: &Column, op: Operator) -> PolarsResu
             #[cfg(feature = "dtype-decimal")]
             Decimal(_, _) => left / right,
             Duration(_) | Date | Datetime(_, _) | Float32 | Float64 => left / right,
+            #[cfg(feature = "dtype-f16")]
+            Float16 => left / right,
             #[cfg(feature = "dtype-array")]
             Array(..) => left / right,
             #[cfg(feature = "dtype-array")]
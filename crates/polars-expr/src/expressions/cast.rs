@@ -1,6 +1,7 @@
 use std::sync::OnceLock;
 
 use polars_core::chunked_array::cast::CastOptions;
+use polars_core::error::{Diagnostics, polars_diagnostic};
 use polars_core::prelude::*;
 
 use super::*;
@@ -15,8 +16,44 @@ pub struct CastExpr {
 }
 
 impl CastExpr {
-    fn finish(&self, input: &Column) -> PolarsResult<Column> {
-        input.cast_with_options(&self.dtype, self.options)
+    fn finish(&self, input: &Column, diagnostics: Option<&Diagnostics>) -> PolarsResult<Column> {
+        let out = input.cast_with_options(&self.dtype, self.options)?;
+        if let Some(diagnostics) = diagnostics {
+            self.report_diagnostics(input, &out, diagnostics)?;
+        }
+        Ok(out)
+    }
+
+    fn report_diagnostics(
+        &self,
+        input: &Column,
+        out: &Column,
+        diagnostics: &Diagnostics,
+    ) -> PolarsResult<()> {
+        if self.options == CastOptions::NonStrict && out.null_count() > input.null_count() {
+            polars_diagnostic!(
+                Some(diagnostics), LossyCast: "casting '{}' from {} to {} turned {} values into null",
+                input.name(), input.dtype(), self.dtype, out.null_count() - input.null_count()
+            )?;
+        }
+        #[cfg(feature = "dtype-datetime")]
+        if let DataType::Datetime(_, Some(tz)) = input.dtype() {
+            let drops_tz = match self.dtype {
+                DataType::Datetime(_, None) => true,
+                #[cfg(feature = "dtype-date")]
+                DataType::Date => true,
+                #[cfg(feature = "dtype-time")]
+                DataType::Time => true,
+                _ => false,
+            };
+            if drops_tz {
+                polars_diagnostic!(
+                    Some(diagnostics), DroppedTimezone: "casting '{}' to {} dropped the time zone '{}'",
+                    input.name(), self.dtype, tz
+                )?;
+            }
+        }
+        Ok(())
     }
 }
 
@@ -27,7 +64,7 @@ impl PhysicalExpr for CastExpr {
 
     fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<Column> {
         let column = self.input.evaluate(df, state)?;
-        self.finish(&column)
+        self.finish(&column, state.diagnostics.as_deref())
     }
 
     fn evaluate_inline_impl(&self, depth_limit: u8) -> Option<Column> {
@@ -37,7 +74,7 @@ impl PhysicalExpr for CastExpr {
                 self.input
                     .evaluate_inline_impl(depth_limit)
                     .filter(|x| x.len() == 1)
-                    .and_then(|x| self.finish(&x).ok())
+                    .and_then(|x| self.finish(&x, None).ok())
             })
             .clone()
     }
@@ -56,13 +93,13 @@ impl PhysicalExpr for CastExpr {
             AggState::AggregatedList(s) => {
                 let ca = s.list().unwrap();
                 let casted = ca.apply_to_inner(&|s| {
-                    self.finish(&s.into_column())
+                    self.finish(&s.into_column(), state.diagnostics.as_deref())
                         .map(|c| c.take_materialized_series())
                 })?;
                 ac.with_values(casted.into_column(), true, None)?;
             },
             AggState::AggregatedScalar(s) => {
-                let s = self.finish(&s.clone().into_column())?;
+                let s = self.finish(&s.clone().into_column(), state.diagnostics.as_deref())?;
                 if ac.is_literal() {
                     ac.with_literal(s);
                 } else {
@@ -74,7 +111,10 @@ impl PhysicalExpr for CastExpr {
                 ac.groups();
 
                 let s = ac.flat_naive();
-                let s = self.finish(&s.as_ref().clone().into_column())?;
+                let s = self.finish(
+                    &s.as_ref().clone().into_column(),
+                    state.diagnostics.as_deref(),
+                )?;
 
                 if ac.is_literal() {
                     ac.with_literal(s);
@@ -111,7 +151,10 @@ impl PartitionedAggregation for CastExpr {
         state: &ExecutionState,
     ) -> PolarsResult<Column> {
         let e = self.input.as_partitioned_aggregator().unwrap();
-        self.finish(&e.evaluate_partitioned(df, groups, state)?)
+        self.finish(
+            &e.evaluate_partitioned(df, groups, state)?,
+            state.diagnostics.as_deref(),
+        )
     }
 
     fn finalize(
@@ -33,6 +33,9 @@ impl LiteralExpr {
                 sc.clone().into_column(get_literal_name().clone())
             },
             L::Series(s) => s.deref().clone().into_column(),
+            L::Placeholder(p) => polars_bail!(
+                InvalidOperation: "placeholder `{}` is not bound to a value", p.name
+            ),
             lv @ L::Dyn(_) => polars_core::prelude::Series::from_any_values(
                 get_literal_name().clone(),
                 &[lv.to_any_value().unwrap()],
@@ -11,6 +11,8 @@ mod group_iter;
 mod literal;
 #[cfg(feature = "dynamic_group_by")]
 mod rolling;
+#[cfg(feature = "random")]
+mod sample;
 mod slice;
 mod sort;
 mod sortby;
@@ -37,6 +39,8 @@ use polars_io::predicates::PhysicalIoExpr;
 use polars_plan::prelude::*;
 #[cfg(feature = "dynamic_group_by")]
 pub(crate) use rolling::RollingExpr;
+#[cfg(feature = "random")]
+pub(crate) use sample::*;
 pub(crate) use slice::*;
 pub(crate) use sort::*;
 pub(crate) use sortby::*;
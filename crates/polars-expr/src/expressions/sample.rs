@@ -0,0 +1,186 @@
+use polars_core::POOL;
+use polars_core::prelude::*;
+use polars_utils::hashing::_boost_hash_combine;
+use polars_utils::idx_vec::IdxVec;
+use rayon::prelude::*;
+
+use super::*;
+use crate::expressions::{AggregationContext, PhysicalExpr};
+
+/// Random sampling that samples every group on its own, e.g. for stratified sampling in
+/// `group_by().agg()` and `over()`.
+pub struct SampleExpr {
+    pub(crate) input: Arc<dyn PhysicalExpr>,
+    /// The sample size or the fraction to sample, depending on `is_fraction`.
+    pub(crate) arg: Arc<dyn PhysicalExpr>,
+    pub(crate) is_fraction: bool,
+    pub(crate) with_replacement: bool,
+    pub(crate) shuffle: bool,
+    pub(crate) seed: Option<u64>,
+    pub(crate) expr: Expr,
+}
+
+impl SampleExpr {
+    fn arg_name(&self) -> &'static str {
+        if self.is_fraction { "fraction" } else { "size" }
+    }
+
+    /// Get the sample size for every population length in `lens`.
+    fn sample_sizes(
+        &self,
+        arg: &Column,
+        lens: impl Iterator<Item = usize>,
+    ) -> PolarsResult<Vec<Option<usize>>> {
+        if self.is_fraction {
+            let arg = arg.cast(&DataType::Float64)?;
+            Ok(arg
+                .f64()?
+                .iter()
+                .zip(lens)
+                .map(|(frac, len)| frac.map(|frac| (len as f64 * frac) as usize))
+                .collect())
+        } else {
+            let arg = arg.cast(&IDX_DTYPE)?;
+            Ok(arg.idx()?.iter().map(|n| n.map(|n| n as usize)).collect())
+        }
+    }
+
+    fn sample_column(&self, column: &Column, arg: &Column) -> PolarsResult<Column> {
+        polars_ensure!(
+            arg.len() == 1,
+            expr = self.expr, ComputeError: "Sample {} must be a single value.", self.arg_name()
+        );
+        match self.sample_sizes(arg, std::iter::once(column.len()))?[0] {
+            Some(n) => column.sample_n(n, self.with_replacement, self.shuffle, self.seed),
+            None => Ok(column.clear()),
+        }
+    }
+
+    /// Sample the group starting at row `first`.
+    fn sample_group(
+        &self,
+        first: IdxSize,
+        idx: &[IdxSize],
+        n: Option<usize>,
+    ) -> PolarsResult<IdxItem> {
+        let Some(n) = n else {
+            return Ok((first, IdxVec::new()));
+        };
+        // Every group gets its own seed so that groups of the same length don't sample the same
+        // positions. It is derived from the group's first row rather than its position in the
+        // groups, which depends on the group-by order, so results are reproducible. Sampling
+        // other columns of the same group with the same seed selects the same rows.
+        let seed = self
+            .seed
+            .map(|seed| _boost_hash_combine(seed, first as u64));
+        let idx = IdxCa::from_slice(PlSmallStr::EMPTY, idx);
+        let idx: IdxVec = idx
+            .sample_n(n, self.with_replacement, self.shuffle, seed)?
+            .into_no_null_iter()
+            .collect();
+        Ok((idx.first().copied().unwrap_or(first), idx))
+    }
+}
+
+impl PhysicalExpr for SampleExpr {
+    fn as_expression(&self) -> Option<&Expr> {
+        Some(&self.expr)
+    }
+
+    fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<Column> {
+        let results = POOL.install(|| {
+            [&self.arg, &self.input]
+                .par_iter()
+                .map(|e| e.evaluate(df, state))
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+        self.sample_column(&results[1], &results[0])
+    }
+
+    fn evaluate_on_groups<'a>(
+        &self,
+        df: &DataFrame,
+        groups: &'a GroupPositions,
+        state: &ExecutionState,
+    ) -> PolarsResult<AggregationContext<'a>> {
+        let mut results = POOL.install(|| {
+            [&self.arg, &self.input]
+                .par_iter()
+                .map(|e| e.evaluate_on_groups(df, groups, state))
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+        let mut ac = results.pop().unwrap();
+        let mut ac_arg = results.pop().unwrap();
+
+        use AggState::*;
+        let arg = match (ac.agg_state(), ac_arg.agg_state()) {
+            (AggregatedScalar(_), _) => {
+                polars_bail!(InvalidOperation: "cannot sample() an aggregated scalar value")
+            },
+            (Literal(s), Literal(arg)) => {
+                let out = self.sample_column(s, arg)?;
+                ac.with_literal(out);
+                return Ok(ac);
+            },
+            (_, Literal(arg)) => {
+                polars_ensure!(
+                    arg.len() == 1,
+                    expr = self.expr, ComputeError:
+                    "Sample {} must be a single value.", self.arg_name()
+                );
+                arg.new_from_index(0, ac.groups().len())
+            },
+            _ => {
+                let arg = ac_arg.aggregated();
+                polars_ensure!(
+                    !matches!(arg.dtype(), DataType::List(_)) && arg.len() == ac.groups().len(),
+                    expr = self.expr, ComputeError:
+                    "invalid sample argument: the {} must be a single value per group",
+                    self.arg_name()
+                );
+                arg
+            },
+        };
+
+        let groups = match ac.groups().as_ref().as_ref() {
+            GroupsType::Idx(groups) => {
+                let sizes = self.sample_sizes(&arg, groups.all().iter().map(|idx| idx.len()))?;
+                POOL.install(|| {
+                    groups
+                        .first()
+                        .par_iter()
+                        .zip(groups.all().par_iter())
+                        .zip(sizes)
+                        .map(|((&first, idx), n)| self.sample_group(first, idx, n))
+                        .collect::<PolarsResult<Vec<_>>>()
+                })?
+            },
+            GroupsType::Slice { groups, .. } => {
+                let sizes = self.sample_sizes(&arg, groups.iter().map(|[_, len]| *len as usize))?;
+                POOL.install(|| {
+                    groups
+                        .par_iter()
+                        .zip(sizes)
+                        .map(|(&[first, len], n)| {
+                            let idx = (first..first + len).collect::<Vec<_>>();
+                            self.sample_group(first, &idx, n)
+                        })
+                        .collect::<PolarsResult<Vec<_>>>()
+                })?
+            },
+        };
+
+        ac.with_groups(GroupsType::Idx(groups.into_iter().collect()).into_sliceable())
+            .set_original_len(false);
+
+        Ok(ac)
+    }
+
+    fn to_field(&self, input_schema: &Schema) -> PolarsResult<Field> {
+        self.input.to_field(input_schema)
+    }
+
+    fn is_scalar(&self) -> bool {
+        false
+    }
+}
@@ -185,6 +185,19 @@ impl HashKeys {
         self.len() == 0
     }
 
+    /// An estimate of the memory held by the keys and their hashes, in bytes.
+    pub fn estimated_size(&self) -> usize {
+        let hash_size = self.len() * size_of::<u64>();
+        match self {
+            HashKeys::RowEncoded(s) => {
+                hash_size + s.keys.values().len() + s.keys.offsets().len() * size_of::<i64>()
+            },
+            // Every view takes up 16 bytes.
+            HashKeys::Binview(s) => hash_size + s.keys.total_buffer_len() + s.keys.len() * 16,
+            HashKeys::Single(s) => s.keys.estimated_size(),
+        }
+    }
+
     pub fn validity(&self) -> Option<&Bitmap> {
         match self {
             HashKeys::RowEncoded(s) => s.keys.validity(),
@@ -357,6 +357,8 @@ fn create_physical_expr_inner(
                         },
                         I::Std(_, ddof) => GBM::Std(*ddof),
                         I::Var(_, ddof) => GBM::Var(*ddof),
+                        #[cfg(feature = "bitwise")]
+                        I::Bitwise(_, method) => GBM::Bitwise(*method),
                         I::AggGroups(_) => {
                             polars_bail!(InvalidOperation: "agg groups expression only supported in aggregation context")
                         },
@@ -474,6 +476,35 @@ fn create_physical_expr_inner(
                 is_scalar,
             )))
         },
+        #[cfg(feature = "random")]
+        Function {
+            input,
+            function:
+                FunctionExpr::Random {
+                    method:
+                        RandomMethod::Sample {
+                            is_fraction,
+                            with_replacement,
+                            shuffle,
+                        },
+                    seed,
+                },
+            ..
+        } => {
+            let mut input =
+                create_physical_expressions_from_irs(input, ctxt, expr_arena, schema, state)?;
+            polars_ensure!(!(state.has_implode() && matches!(ctxt, Context::Aggregation)), InvalidOperation: "'implode' followed by a sample during aggregation is not allowed");
+            let arg = input.pop().unwrap();
+            Ok(Arc::new(SampleExpr {
+                input: input.pop().unwrap(),
+                arg,
+                is_fraction: *is_fraction,
+                with_replacement: *with_replacement,
+                shuffle: *shuffle,
+                seed: *seed,
+                expr: node_to_expr(expression, expr_arena),
+            }))
+        },
         Function {
             input,
             function,
@@ -0,0 +1,137 @@
+#![allow(unsafe_op_in_unsafe_fn)]
+use polars_compute::hyperloglogplus::SparseHyperLogLog;
+
+use super::*;
+use crate::reduce::partition::partition_vec;
+
+/// Approximately counts the unique values with a HyperLogLog sketch per group. The sketches of
+/// partial reductions are merged, so the result doesn't depend on how the input was split.
+///
+/// The sketches are sparse while a group has seen few values, and are updated in place with the
+/// hash of every value.
+pub struct ApproxNUniqueReduce {
+    groups: Vec<SparseHyperLogLog>,
+    /// Output the sketches instead of the estimated counts.
+    output_sketch: bool,
+}
+
+impl ApproxNUniqueReduce {
+    pub fn new(output_sketch: bool) -> Self {
+        Self {
+            groups: Vec::new(),
+            output_sketch,
+        }
+    }
+}
+
+impl GroupedReduction for ApproxNUniqueReduce {
+    fn new_empty(&self) -> Box<dyn GroupedReduction> {
+        Box::new(Self::new(self.output_sketch))
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.groups.reserve(additional);
+    }
+
+    fn resize(&mut self, num_groups: IdxSize) {
+        self.groups
+            .resize_with(num_groups as usize, SparseHyperLogLog::new);
+    }
+
+    fn update_group(
+        &mut self,
+        values: &Series,
+        group_idx: IdxSize,
+        _seq_id: u64,
+    ) -> PolarsResult<()> {
+        let group = &mut self.groups[group_idx as usize];
+        for hash in values.approx_n_unique_hashes()? {
+            group.add_hash(hash);
+        }
+        Ok(())
+    }
+
+    unsafe fn update_groups(
+        &mut self,
+        values: &Series,
+        group_idxs: &[IdxSize],
+        _seq_id: u64,
+    ) -> PolarsResult<()> {
+        assert!(values.len() == group_idxs.len());
+        let hashes = values.approx_n_unique_hashes()?;
+        // SAFETY: indices are in-bounds guaranteed by trait.
+        for (g, hash) in group_idxs.iter().zip(hashes) {
+            self.groups.get_unchecked_mut(*g as usize).add_hash(hash);
+        }
+        Ok(())
+    }
+
+    unsafe fn combine(
+        &mut self,
+        other: &dyn GroupedReduction,
+        group_idxs: &[IdxSize],
+    ) -> PolarsResult<()> {
+        let other = other.as_any().downcast_ref::<Self>().unwrap();
+        assert!(other.groups.len() == group_idxs.len());
+        // SAFETY: indices are in-bounds guaranteed by trait.
+        for (g, v) in group_idxs.iter().zip(other.groups.iter()) {
+            self.groups.get_unchecked_mut(*g as usize).merge(v);
+        }
+        Ok(())
+    }
+
+    unsafe fn gather_combine(
+        &mut self,
+        other: &dyn GroupedReduction,
+        subset: &[IdxSize],
+        group_idxs: &[IdxSize],
+    ) -> PolarsResult<()> {
+        let other = other.as_any().downcast_ref::<Self>().unwrap();
+        assert!(subset.len() == group_idxs.len());
+        // SAFETY: indices are in-bounds guaranteed by trait.
+        for (i, g) in subset.iter().zip(group_idxs) {
+            self.groups
+                .get_unchecked_mut(*g as usize)
+                .merge(other.groups.get_unchecked(*i as usize));
+        }
+        Ok(())
+    }
+
+    fn finalize(&mut self) -> PolarsResult<Series> {
+        let groups = std::mem::take(&mut self.groups);
+        let out = if self.output_sketch {
+            BinaryChunked::from_iter_values(
+                PlSmallStr::EMPTY,
+                groups.iter().map(|h| h.to_registers()),
+            )
+            .into_series()
+        } else {
+            IdxCa::from_iter_values(
+                PlSmallStr::EMPTY,
+                groups.iter().map(|h| h.count() as IdxSize),
+            )
+            .into_series()
+        };
+        Ok(out)
+    }
+
+    unsafe fn partition(
+        self: Box<Self>,
+        partition_sizes: &[IdxSize],
+        partition_idxs: &[IdxSize],
+    ) -> Vec<Box<dyn GroupedReduction>> {
+        partition_vec(self.groups, partition_sizes, partition_idxs)
+            .into_iter()
+            .map(|groups| {
+                Box::new(Self {
+                    groups,
+                    output_sketch: self.output_sketch,
+                }) as _
+            })
+            .collect()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -0,0 +1,126 @@
+#![allow(unsafe_op_in_unsafe_fn)]
+use polars_compute::tdigest::TDigest;
+use polars_plan::dsl::add_to_tdigest;
+
+use super::*;
+use crate::reduce::partition::partition_vec;
+
+/// Approximates a quantile with a t-digest per group. The digests of partial reductions are
+/// merged, so only a bounded amount of memory per group is used.
+pub struct ApproxQuantileReduce {
+    groups: Vec<TDigest>,
+    quantile: f64,
+    out_dtype: DataType,
+}
+
+impl ApproxQuantileReduce {
+    pub fn new(quantile: f64, out_dtype: DataType) -> Self {
+        Self {
+            groups: Vec::new(),
+            quantile,
+            out_dtype,
+        }
+    }
+}
+
+impl GroupedReduction for ApproxQuantileReduce {
+    fn new_empty(&self) -> Box<dyn GroupedReduction> {
+        Box::new(Self::new(self.quantile, self.out_dtype.clone()))
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.groups.reserve(additional);
+    }
+
+    fn resize(&mut self, num_groups: IdxSize) {
+        self.groups.resize_with(num_groups as usize, TDigest::new);
+    }
+
+    fn update_group(
+        &mut self,
+        values: &Series,
+        group_idx: IdxSize,
+        _seq_id: u64,
+    ) -> PolarsResult<()> {
+        add_to_tdigest(&mut self.groups[group_idx as usize], values)
+    }
+
+    unsafe fn update_groups(
+        &mut self,
+        values: &Series,
+        group_idxs: &[IdxSize],
+        _seq_id: u64,
+    ) -> PolarsResult<()> {
+        assert!(values.len() == group_idxs.len());
+        let values = values.cast(&DataType::Float64)?;
+        let values = values.f64()?;
+        // SAFETY: indices are in-bounds guaranteed by trait.
+        for (v, g) in values.iter().zip(group_idxs) {
+            if let Some(v) = v {
+                self.groups.get_unchecked_mut(*g as usize).add(v);
+            }
+        }
+        Ok(())
+    }
+
+    unsafe fn combine(
+        &mut self,
+        other: &dyn GroupedReduction,
+        group_idxs: &[IdxSize],
+    ) -> PolarsResult<()> {
+        let other = other.as_any().downcast_ref::<Self>().unwrap();
+        assert!(other.groups.len() == group_idxs.len());
+        // SAFETY: indices are in-bounds guaranteed by trait.
+        for (g, v) in group_idxs.iter().zip(other.groups.iter()) {
+            self.groups.get_unchecked_mut(*g as usize).merge(v);
+        }
+        Ok(())
+    }
+
+    unsafe fn gather_combine(
+        &mut self,
+        other: &dyn GroupedReduction,
+        subset: &[IdxSize],
+        group_idxs: &[IdxSize],
+    ) -> PolarsResult<()> {
+        let other = other.as_any().downcast_ref::<Self>().unwrap();
+        assert!(subset.len() == group_idxs.len());
+        // SAFETY: indices are in-bounds guaranteed by trait.
+        for (i, g) in subset.iter().zip(group_idxs) {
+            self.groups
+                .get_unchecked_mut(*g as usize)
+                .merge(other.groups.get_unchecked(*i as usize));
+        }
+        Ok(())
+    }
+
+    fn finalize(&mut self) -> PolarsResult<Series> {
+        let groups = std::mem::take(&mut self.groups);
+        let out: Float64Chunked = groups
+            .into_iter()
+            .map(|mut d| d.quantile(self.quantile))
+            .collect();
+        out.into_series().cast(&self.out_dtype)
+    }
+
+    unsafe fn partition(
+        self: Box<Self>,
+        partition_sizes: &[IdxSize],
+        partition_idxs: &[IdxSize],
+    ) -> Vec<Box<dyn GroupedReduction>> {
+        partition_vec(self.groups, partition_sizes, partition_idxs)
+            .into_iter()
+            .map(|groups| {
+                Box::new(Self {
+                    groups,
+                    quantile: self.quantile,
+                    out_dtype: self.out_dtype.clone(),
+                }) as _
+            })
+            .collect()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
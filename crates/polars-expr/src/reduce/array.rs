@@ -0,0 +1,177 @@
+use arrow::array::FixedSizeListArray;
+
+use super::*;
+
+/// Reduces every position of the arrays in a group separately, e.g. to get the elementwise sum
+/// of the arrays. Null arrays are skipped.
+///
+/// Position `p` of group `g` is reduced in group `g * width + p` of the inner reduction, which
+/// reduces the inner values of the arrays.
+pub struct ArrayGroupedReduction {
+    in_dtype: DataType,
+    width: usize,
+    num_groups: IdxSize,
+    inner: Box<dyn GroupedReduction>,
+}
+
+impl ArrayGroupedReduction {
+    pub fn new(in_dtype: DataType, inner: Box<dyn GroupedReduction>) -> Self {
+        let DataType::Array(_, width) = in_dtype else {
+            unreachable!()
+        };
+        Self {
+            in_dtype,
+            width,
+            num_groups: 0,
+            inner,
+        }
+    }
+
+    /// The inner groups of the positions of the given groups.
+    fn inner_group_idxs(&self, group_idxs: impl IntoIterator<Item = IdxSize>) -> Vec<IdxSize> {
+        let width = self.width as IdxSize;
+        group_idxs
+            .into_iter()
+            .flat_map(|g| (g * width)..(g + 1) * width)
+            .collect()
+    }
+
+    /// Updates the inner reduction with the inner values of the non-null arrays in `values`,
+    /// where `group_idx(i)` is the group of the ith array.
+    fn update_inner(
+        &mut self,
+        values: &Series,
+        group_idx: impl Fn(usize) -> IdxSize,
+        seq_id: u64,
+    ) -> PolarsResult<()> {
+        assert!(values.dtype() == &self.in_dtype);
+        let ca = values.array()?.rechunk();
+        let mut inner_values = ca.get_inner();
+        let inner_group_idxs = if let Some(validity) = ca.rechunk_validity() {
+            let rows = validity.true_idx_iter().collect::<Vec<_>>();
+            let idx = self.inner_group_idxs(rows.iter().map(|&i| i as IdxSize));
+            inner_values = inner_values.take(&IdxCa::from_vec(PlSmallStr::EMPTY, idx))?;
+            self.inner_group_idxs(rows.into_iter().map(group_idx))
+        } else {
+            self.inner_group_idxs((0..ca.len()).map(group_idx))
+        };
+        // SAFETY: the groups are below `num_groups * width`, the number of inner groups.
+        unsafe {
+            self.inner
+                .update_groups(&inner_values, &inner_group_idxs, seq_id)
+        }
+    }
+}
+
+impl GroupedReduction for ArrayGroupedReduction {
+    fn new_empty(&self) -> Box<dyn GroupedReduction> {
+        Box::new(Self {
+            in_dtype: self.in_dtype.clone(),
+            width: self.width,
+            num_groups: 0,
+            inner: self.inner.new_empty(),
+        })
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.inner.reserve(additional * self.width);
+    }
+
+    fn resize(&mut self, num_groups: IdxSize) {
+        self.num_groups = num_groups;
+        self.inner.resize(num_groups * self.width as IdxSize);
+    }
+
+    fn update_group(
+        &mut self,
+        values: &Series,
+        group_idx: IdxSize,
+        seq_id: u64,
+    ) -> PolarsResult<()> {
+        assert!(group_idx < self.num_groups);
+        self.update_inner(values, |_| group_idx, seq_id)
+    }
+
+    unsafe fn update_groups(
+        &mut self,
+        values: &Series,
+        group_idxs: &[IdxSize],
+        seq_id: u64,
+    ) -> PolarsResult<()> {
+        assert!(values.len() == group_idxs.len());
+        self.update_inner(values, |i| group_idxs[i], seq_id)
+    }
+
+    unsafe fn combine(
+        &mut self,
+        other: &dyn GroupedReduction,
+        group_idxs: &[IdxSize],
+    ) -> PolarsResult<()> {
+        let other = other.as_any().downcast_ref::<Self>().unwrap();
+        assert!(self.in_dtype == other.in_dtype);
+        let group_idxs = self.inner_group_idxs(group_idxs.iter().copied());
+        self.inner.combine(&*other.inner, &group_idxs)
+    }
+
+    unsafe fn gather_combine(
+        &mut self,
+        other: &dyn GroupedReduction,
+        subset: &[IdxSize],
+        group_idxs: &[IdxSize],
+    ) -> PolarsResult<()> {
+        let other = other.as_any().downcast_ref::<Self>().unwrap();
+        assert!(self.in_dtype == other.in_dtype);
+        let subset = self.inner_group_idxs(subset.iter().copied());
+        let group_idxs = self.inner_group_idxs(group_idxs.iter().copied());
+        self.inner
+            .gather_combine(&*other.inner, &subset, &group_idxs)
+    }
+
+    unsafe fn partition(
+        self: Box<Self>,
+        partition_sizes: &[IdxSize],
+        partition_idxs: &[IdxSize],
+    ) -> Vec<Box<dyn GroupedReduction>> {
+        let width = self.width;
+        let inner_sizes = partition_sizes
+            .iter()
+            .map(|s| s * width as IdxSize)
+            .collect::<Vec<_>>();
+        let inner_idxs = partition_idxs
+            .iter()
+            .flat_map(|p| std::iter::repeat_n(*p, width))
+            .collect::<Vec<_>>();
+        self.inner
+            .partition(&inner_sizes, &inner_idxs)
+            .into_iter()
+            .zip(partition_sizes)
+            .map(|(inner, num_groups)| {
+                Box::new(Self {
+                    in_dtype: self.in_dtype.clone(),
+                    width,
+                    num_groups: *num_groups,
+                    inner,
+                }) as _
+            })
+            .collect()
+    }
+
+    fn finalize(&mut self) -> PolarsResult<Series> {
+        let values = self.inner.finalize()?.rechunk();
+        let num_groups = std::mem::take(&mut self.num_groups) as usize;
+        let dtype = DataType::Array(Box::new(values.dtype().clone()), self.width);
+        let arr = FixedSizeListArray::new(
+            dtype.to_physical().to_arrow(CompatLevel::newest()),
+            num_groups,
+            values.chunks()[0].clone(),
+            None,
+        );
+        Ok(unsafe {
+            Series::from_chunks_and_dtype_unchecked(PlSmallStr::EMPTY, vec![arr.boxed()], &dtype)
+        })
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -3,6 +3,10 @@ use polars_plan::prelude::*;
 use polars_utils::arena::{Arena, Node};
 
 use super::*;
+#[cfg(feature = "approx_unique")]
+use crate::reduce::approx_n_unique::ApproxNUniqueReduce;
+#[cfg(feature = "approx_quantile")]
+use crate::reduce::approx_quantile::ApproxQuantileReduce;
 use crate::reduce::count::CountReduce;
 use crate::reduce::first_last::{new_first_reduction, new_last_reduction};
 use crate::reduce::len::LenReduce;
@@ -52,6 +56,48 @@ pub fn into_reduction(
             IRAggExpr::NUnique(_) => todo!(),
             IRAggExpr::Implode(_) => todo!(),
             IRAggExpr::AggGroups(_) => todo!(),
+            #[cfg(feature = "bitwise")]
+            IRAggExpr::Bitwise(..) => {
+                polars_bail!(InvalidOperation: "bitwise aggregations are not supported as a streaming reduction")
+            },
+        },
+        #[cfg(feature = "approx_unique")]
+        AExpr::Function {
+            input,
+            function: FunctionExpr::ApproxNUnique,
+            ..
+        } => {
+            let reduce = Box::new(ApproxNUniqueReduce::new(false)) as Box<_>;
+            (reduce, input[0].node())
+        },
+        #[cfg(feature = "approx_unique")]
+        AExpr::Function {
+            input,
+            function: FunctionExpr::ApproxNUniqueSketch(ApproxNUniqueSketchFunction::Build),
+            ..
+        } => {
+            let reduce = Box::new(ApproxNUniqueReduce::new(true)) as Box<_>;
+            (reduce, input[0].node())
+        },
+        #[cfg(feature = "approx_quantile")]
+        AExpr::Function {
+            input,
+            function: FunctionExpr::ApproxQuantile,
+            ..
+        } => {
+            let quantile = match expr_arena.get(input[1].node()) {
+                AExpr::Literal(lv) => lv.to_any_value().and_then(|av| av.extract::<f64>()),
+                _ => None,
+            };
+            let Some(quantile) = quantile.filter(|q| (0.0..=1.0).contains(q)) else {
+                polars_bail!(ComputeError: "quantile should be a literal between 0.0 and 1.0")
+            };
+            let out_dtype = match get_dt(input[0].node())? {
+                DataType::Float32 => DataType::Float32,
+                _ => DataType::Float64,
+            };
+            let reduce = Box::new(ApproxQuantileReduce::new(quantile, out_dtype)) as Box<_>;
+            (reduce, input[0].node())
         },
         AExpr::Len => {
             // Compute length on the first column, or if none exist we'll use
@@ -15,8 +15,15 @@ pub fn new_mean_reduction(dtype: DataType) -> Box<dyn GroupedReduction> {
                 Box::new(VGR::new(dtype, NumMeanReducer::<$T>(PhantomData)))
             })
         },
+        #[cfg(feature = "dtype-f16")]
+        Float16 => Box::new(VGR::new(dtype, NumMeanReducer::<Float32Type>(PhantomData))),
         #[cfg(feature = "dtype-decimal")]
-        Decimal(_, _) => Box::new(VGR::new(dtype, NumMeanReducer::<Int128Type>(PhantomData))),
+        Decimal(_, _) => Box::new(VGR::new(dtype, DecimalMeanReducer)),
+        #[cfg(feature = "dtype-array")]
+        Array(ref inner, _) => {
+            let inner = new_mean_reduction(inner.as_ref().clone());
+            Box::new(super::array::ArrayGroupedReduction::new(dtype, inner))
+        },
 
         // For compatibility with the current engine, should probably be an error.
         String | Binary => Box::new(super::NullGroupedReduction::new(dtype)),
@@ -34,6 +41,9 @@ fn finish_output(values: Vec<(f64, usize)>, dtype: &DataType) -> Series {
                 .collect_ca(PlSmallStr::EMPTY);
             ca.into_series()
         },
+        // The mean of half-precision floats is computed in single precision.
+        #[cfg(feature = "dtype-f16")]
+        DataType::Float16 => finish_output(values, &DataType::Float32),
         dt if dt.is_primitive_numeric() => {
             let ca: Float64Chunked = values
                 .into_iter()
@@ -41,15 +51,6 @@ fn finish_output(values: Vec<(f64, usize)>, dtype: &DataType) -> Series {
                 .collect_ca(PlSmallStr::EMPTY);
             ca.into_series()
         },
-        #[cfg(feature = "dtype-decimal")]
-        DataType::Decimal(_prec, scale) => {
-            let inv_scale_factor = 1.0 / 10u128.pow(scale.unwrap() as u32) as f64;
-            let ca: Float64Chunked = values
-                .into_iter()
-                .map(|(s, c)| (c != 0).then(|| s / c as f64 * inv_scale_factor))
-                .collect_ca(PlSmallStr::EMPTY);
-            ca.into_series()
-        },
         #[cfg(feature = "dtype-datetime")]
         DataType::Date => {
             const MS_IN_DAY: i64 = 86_400_000;
@@ -122,6 +123,63 @@ where
     }
 }
 
+#[cfg(feature = "dtype-decimal")]
+#[derive(Clone)]
+struct DecimalMeanReducer;
+
+#[cfg(feature = "dtype-decimal")]
+impl Reducer for DecimalMeanReducer {
+    type Dtype = Int128Type;
+    type Value = (i128, usize);
+
+    #[inline(always)]
+    fn init(&self) -> Self::Value {
+        (0, 0)
+    }
+
+    fn cast_series<'a>(&self, s: &'a Series) -> Cow<'a, Series> {
+        s.to_physical_repr()
+    }
+
+    #[inline(always)]
+    fn combine(&self, a: &mut Self::Value, b: &Self::Value) {
+        a.0 = a.0.wrapping_add(b.0);
+        a.1 += b.1;
+    }
+
+    #[inline(always)]
+    fn reduce_one(&self, a: &mut Self::Value, b: Option<i128>, _seq_id: u64) {
+        a.0 = a.0.wrapping_add(b.unwrap_or(0));
+        a.1 += b.is_some() as usize;
+    }
+
+    fn reduce_ca(&self, v: &mut Self::Value, ca: &ChunkedArray<Self::Dtype>, _seq_id: u64) {
+        v.0 = v.0.wrapping_add(ca.sum().unwrap_or(0));
+        v.1 += ca.len() - ca.null_count();
+    }
+
+    fn finish(
+        &self,
+        v: Vec<Self::Value>,
+        m: Option<Bitmap>,
+        dtype: &DataType,
+    ) -> PolarsResult<Series> {
+        use polars_core::chunked_array::arithmetic::{_decimal_mean, _get_decimal_scale_div};
+
+        assert!(m.is_none());
+        let DataType::Decimal(_, Some(scale)) = dtype else {
+            unreachable!()
+        };
+        let ca: Int128Chunked = v
+            .into_iter()
+            .map(|(s, c)| _decimal_mean(s, c, *scale))
+            .collect_ca(PlSmallStr::EMPTY);
+        Ok(ca
+            .into_decimal_unchecked(None, _get_decimal_scale_div(*scale))
+            .into_series())
+    }
+}
+
 #[derive(Clone)]
 struct BoolMeanReducer;
 
@@ -29,6 +29,13 @@ pub fn new_min_reduction(dtype: DataType, propagate_nans: bool) -> Box<dyn Group
         },
         Float32 => Box::new(VMGR::new(dtype, NumReducer::<Min<Float32Type>>::new())),
         Float64 => Box::new(VMGR::new(dtype, NumReducer::<Min<Float64Type>>::new())),
+        // Half-precision floats are stored as single precision, which has the same order.
+        #[cfg(all(feature = "dtype-f16", feature = "propagate_nans"))]
+        Float16 if propagate_nans => {
+            Box::new(VMGR::new(dtype, NumReducer::<NanMin<Float32Type>>::new()))
+        },
+        #[cfg(feature = "dtype-f16")]
+        Float16 => Box::new(VMGR::new(dtype, NumReducer::<Min<Float32Type>>::new())),
         String | Binary => Box::new(VecGroupedReduction::new(dtype, BinaryMinReducer)),
         _ if dtype.is_integer() || dtype.is_temporal() => {
             with_match_physical_integer_polars_type!(dtype.to_physical(), |$T| {
@@ -56,6 +63,13 @@ pub fn new_max_reduction(dtype: DataType, propagate_nans: bool) -> Box<dyn Group
         },
         Float32 => Box::new(VMGR::new(dtype, NumReducer::<Max<Float32Type>>::new())),
         Float64 => Box::new(VMGR::new(dtype, NumReducer::<Max<Float64Type>>::new())),
+        // Half-precision floats are stored as single precision, which has the same order.
+        #[cfg(all(feature = "dtype-f16", feature = "propagate_nans"))]
+        Float16 if propagate_nans => {
+            Box::new(VMGR::new(dtype, NumReducer::<NanMax<Float32Type>>::new()))
+        },
+        #[cfg(feature = "dtype-f16")]
+        Float16 => Box::new(VMGR::new(dtype, NumReducer::<Max<Float32Type>>::new())),
         String | Binary => Box::new(VecGroupedReduction::new(dtype, BinaryMaxReducer)),
         _ if dtype.is_integer() || dtype.is_temporal() => {
             with_match_physical_integer_polars_type!(dtype.to_physical(), |$T| {
@@ -1,4 +1,10 @@
 #![allow(unsafe_op_in_unsafe_fn)]
+#[cfg(feature = "approx_unique")]
+mod approx_n_unique;
+#[cfg(feature = "approx_quantile")]
+mod approx_quantile;
+#[cfg(feature = "dtype-array")]
+mod array;
 mod convert;
 mod count;
 mod first_last;
@@ -31,10 +31,17 @@ pub fn new_sum_reduction(dtype: DataType) -> Box<dyn GroupedReduction> {
         #[cfg(feature = "dtype-i128")]
         Int128 => Box::new(SumReduce::<Int128Type>::new(dtype)),
         Float32 => Box::new(SumReduce::<Float32Type>::new(dtype)),
+        #[cfg(feature = "dtype-f16")]
+        Float16 => Box::new(SumReduce::<Float32Type>::new(dtype)),
         Float64 => Box::new(SumReduce::<Float64Type>::new(dtype)),
         #[cfg(feature = "dtype-decimal")]
         Decimal(_, _) => Box::new(SumReduce::<Int128Type>::new(dtype)),
         Duration(_) => Box::new(SumReduce::<Int64Type>::new(dtype)),
+        #[cfg(feature = "dtype-array")]
+        Array(ref inner, _) => {
+            let inner = new_sum_reduction(inner.as_ref().clone());
+            Box::new(super::array::ArrayGroupedReduction::new(dtype, inner))
+        },
         // For compatibility with the current engine, should probably be an error.
         String | Binary => Box::new(super::NullGroupedReduction::new(dtype)),
         _ => unimplemented!("{dtype:?} is not supported by sum reduction"),
@@ -46,6 +53,8 @@ fn cast_sum_input<'a>(s: &'a Series, dt: &DataType) -> PolarsResult<Cow<'a, Seri
     match dt {
         Boolean => Ok(Cow::Owned(s.cast(&IDX_DTYPE)?)),
         Int8 | UInt8 | Int16 | UInt16 => Ok(Cow::Owned(s.cast(&Int64)?)),
+        #[cfg(feature = "dtype-f16")]
+        Float16 => Ok(Cow::Owned(s.to_physical_repr().into_owned())),
         #[cfg(feature = "dtype-decimal")]
         Decimal(_, _) => Ok(Cow::Owned(
             s.decimal().unwrap().physical().clone().into_series(),
@@ -63,6 +72,9 @@ fn out_dtype(in_dtype: &DataType) -> DataType {
     match in_dtype {
         Boolean => IDX_DTYPE,
         Int8 | UInt8 | Int16 | UInt16 => Int64,
+        // Half-precision floats are summed in single precision.
+        #[cfg(feature = "dtype-f16")]
+        Float16 => Float32,
         dt => dt.clone(),
     }
 }
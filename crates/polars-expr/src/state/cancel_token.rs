@@ -0,0 +1,61 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use polars_core::prelude::*;
+use tokio::sync::Notify;
+
+const INTERRUPTED: &str = "query interrupted";
+
+/// Cancels a running query, on any engine.
+///
+/// The in-memory engine checks the token between operations, the streaming engine stops all tasks
+/// of the query as soon as it is cancelled.
+#[derive(Clone, Default)]
+pub struct CancelToken {
+    inner: Arc<CancelTokenInner>,
+}
+
+#[derive(Default)]
+struct CancelTokenInner {
+    cancelled: AtomicBool,
+    notify: Notify,
+}
+
+impl CancelToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.inner.cancelled.store(true, Ordering::SeqCst);
+        self.inner.notify.notify_waiters();
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Waits until the token is cancelled.
+    pub async fn cancelled(&self) {
+        // Register for the notification before checking, so a cancel in between isn't missed.
+        let notified = self.inner.notify.notified();
+        if self.is_cancelled() {
+            return;
+        }
+        notified.await;
+    }
+
+    /// The error a query returns when it is stopped by its token.
+    pub fn error() -> PolarsError {
+        polars_err!(ComputeError: INTERRUPTED)
+    }
+
+    /// Whether `err` is the error of a query that was stopped by its token.
+    pub fn is_cancel_error(err: &PolarsError) -> bool {
+        match err {
+            PolarsError::ComputeError(msg) => &**msg == INTERRUPTED,
+            PolarsError::Context { error, .. } => Self::is_cancel_error(error),
+            _ => false,
+        }
+    }
+}
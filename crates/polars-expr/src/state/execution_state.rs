@@ -1,14 +1,15 @@
 use std::borrow::Cow;
-use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU8, Ordering};
+use std::sync::atomic::{AtomicI64, AtomicU8, Ordering};
 use std::sync::{Mutex, OnceLock, RwLock};
 use std::time::Duration;
 
 use bitflags::bitflags;
 use polars_core::config::verbose;
+use polars_core::error::Diagnostics;
 use polars_core::prelude::*;
 use polars_ops::prelude::ChunkJoinOptIds;
 
-use super::NodeTimer;
+use super::{CancelToken, NodeTimer};
 
 pub type JoinTuplesCache = Arc<Mutex<PlHashMap<String, ChunkJoinOptIds>>>;
 
@@ -114,7 +115,9 @@ pub struct ExecutionState {
     pub flags: AtomicU8,
     pub ext_contexts: Arc<Vec<DataFrame>>,
     node_timer: Option<NodeTimer>,
-    stop: Arc<AtomicBool>,
+    stop: CancelToken,
+    /// The diagnostics collector of the query, picked up from the thread that created the state.
+    pub diagnostics: Option<Arc<Diagnostics>>,
 }
 
 impl ExecutionState {
@@ -131,7 +134,8 @@ impl ExecutionState {
             flags: AtomicU8::new(StateFlags::init().as_u8()),
             ext_contexts: Default::default(),
             node_timer: None,
-            stop: Arc::new(AtomicBool::new(false)),
+            stop: CancelToken::new(),
+            diagnostics: Diagnostics::current(),
         }
     }
 
@@ -162,14 +166,21 @@ impl ExecutionState {
     // This is wrong when the U64 overflows which will never happen.
     pub fn should_stop(&self) -> PolarsResult<()> {
         try_raise_keyboard_interrupt();
-        polars_ensure!(!self.stop.load(Ordering::Relaxed), ComputeError: "query interrupted");
+        if self.stop.is_cancelled() {
+            return Err(CancelToken::error());
+        }
         Ok(())
     }
 
-    pub fn cancel_token(&self) -> Arc<AtomicBool> {
+    pub fn cancel_token(&self) -> CancelToken {
         self.stop.clone()
     }
 
+    /// Use an externally owned token to cancel the query.
+    pub fn set_cancel_token(&mut self, token: CancelToken) {
+        self.stop = token;
+    }
+
     pub fn record<T, F: FnOnce() -> T>(&self, func: F, name: Cow<'static, str>) -> T {
         match &self.node_timer {
             None => func(),
@@ -196,6 +207,7 @@ impl ExecutionState {
             ext_contexts: self.ext_contexts.clone(),
             node_timer: self.node_timer.clone(),
             stop: self.stop.clone(),
+            diagnostics: self.diagnostics.clone(),
         }
     }
 
@@ -309,6 +321,7 @@ impl Clone for ExecutionState {
             ext_contexts: self.ext_contexts.clone(),
             node_timer: self.node_timer.clone(),
             stop: self.stop.clone(),
+            diagnostics: self.diagnostics.clone(),
         }
     }
 }
@@ -1,5 +1,7 @@
+mod cancel_token;
 mod execution_state;
 mod node_timer;
 
+pub use cancel_token::CancelToken;
 pub use execution_state::*;
 use node_timer::*;
@@ -1,4 +1,5 @@
 #![allow(unsafe_op_in_unsafe_fn)]
+pub mod manifest;
 pub mod version_0;
 
 use std::mem::ManuallyDrop;
@@ -0,0 +1,143 @@
+//! The manifest of a plugin library, which lists the kernels that it exports.
+//!
+//! A plugin library is a `cdylib` that exports its kernels with the same symbols as the Python
+//! expression plugins: `_polars_plugin_{name}` computes the output of the kernel `name` and
+//! `_polars_plugin_field_{name}` computes its output field. With a manifest, Rust programs can
+//! load the library at runtime and call its kernels without knowing them at compile time. The
+//! library exports the manifest with [`polars_plugin_manifest`](crate::polars_plugin_manifest):
+//!
+//! ```
+//! use polars_ffi::manifest::{KERNEL_ELEMENTWISE, KERNEL_RETURNS_SCALAR};
+//!
+//! polars_ffi::polars_plugin_manifest! {
+//!     c"pig_latin" => KERNEL_ELEMENTWISE,
+//!     c"weighted_mean" => KERNEL_RETURNS_SCALAR,
+//! }
+//! ```
+use std::ffi::{CStr, c_char};
+
+/// The version of the layout of [`PluginManifest`].
+pub const MANIFEST_VERSION: u32 = 0;
+
+/// The symbol of the function that returns a pointer to the [`PluginManifest`] of a library.
+pub const MANIFEST_SYMBOL: &[u8] = b"_polars_plugin_get_manifest\0";
+
+/// Every row of the output only depends on the same row of the inputs.
+pub const KERNEL_ELEMENTWISE: u32 = 1;
+/// The output is a single value, in a group-by the kernel is called once per group.
+pub const KERNEL_RETURNS_SCALAR: u32 = 1 << 1;
+/// The output may have a different length than the inputs.
+pub const KERNEL_CHANGES_LENGTH: u32 = 1 << 2;
+/// The inputs are cast to their supertype before calling the kernel.
+pub const KERNEL_CAST_TO_SUPERTYPE: u32 = 1 << 3;
+/// Wildcards and regexes in the inputs expand to a single call, instead of one call per column.
+pub const KERNEL_INPUT_WILDCARD_EXPANSION: u32 = 1 << 4;
+/// The output name of the kernel is passed to it, as the name of its first input.
+pub const KERNEL_PASS_NAME_TO_APPLY: u32 = 1 << 5;
+
+/// A kernel exported by a plugin library.
+#[derive(Debug)]
+#[repr(C)]
+pub struct KernelDescriptor {
+    name: *const c_char,
+    flags: u32,
+}
+
+// SAFETY: the name points to a static string.
+unsafe impl Sync for KernelDescriptor {}
+
+impl KernelDescriptor {
+    /// `flags` is a combination of the `KERNEL_*` constants of this module.
+    pub const fn new(name: &'static CStr, flags: u32) -> Self {
+        Self {
+            name: name.as_ptr(),
+            flags,
+        }
+    }
+
+    /// # Safety
+    /// The descriptor must come from a loaded library.
+    pub unsafe fn name(&self) -> &CStr {
+        unsafe { CStr::from_ptr(self.name) }
+    }
+
+    pub fn flags(&self) -> u32 {
+        self.flags
+    }
+}
+
+/// The kernels exported by a plugin library.
+#[derive(Debug)]
+#[repr(C)]
+pub struct PluginManifest {
+    version: u32,
+    kernels: *const KernelDescriptor,
+    n_kernels: usize,
+}
+
+// SAFETY: the kernels point to a static slice.
+unsafe impl Sync for PluginManifest {}
+
+impl PluginManifest {
+    pub const fn new(kernels: &'static [KernelDescriptor]) -> Self {
+        Self {
+            version: MANIFEST_VERSION,
+            kernels: kernels.as_ptr(),
+            n_kernels: kernels.len(),
+        }
+    }
+
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// # Safety
+    /// The manifest must come from a loaded library, and have version [`MANIFEST_VERSION`].
+    pub unsafe fn kernels(&self) -> &[KernelDescriptor] {
+        unsafe { std::slice::from_raw_parts(self.kernels, self.n_kernels) }
+    }
+}
+
+/// Export the [`PluginManifest`] of a plugin library, from a list of `c"name" => flags` pairs.
+///
+/// The kernels themselves are exported separately, e.g. with `#[polars_expr]` of `pyo3-polars`.
+#[macro_export]
+macro_rules! polars_plugin_manifest {
+    ($($name:expr => $flags:expr),* $(,)?) => {
+        #[unsafe(no_mangle)]
+        pub extern "C" fn _polars_plugin_get_manifest() -> *const $crate::manifest::PluginManifest {
+            static KERNELS: &[$crate::manifest::KernelDescriptor] =
+                &[$($crate::manifest::KernelDescriptor::new($name, $flags)),*];
+            static MANIFEST: $crate::manifest::PluginManifest =
+                $crate::manifest::PluginManifest::new(KERNELS);
+            &MANIFEST
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    crate::polars_plugin_manifest! {
+        c"add_one" => KERNEL_ELEMENTWISE,
+        c"total" => KERNEL_RETURNS_SCALAR | KERNEL_CAST_TO_SUPERTYPE,
+    }
+
+    #[test]
+    fn test_manifest() {
+        let manifest = unsafe { &*_polars_plugin_get_manifest() };
+        assert_eq!(manifest.version(), MANIFEST_VERSION);
+        let kernels = unsafe { manifest.kernels() }
+            .iter()
+            .map(|k| (unsafe { k.name() }.to_str().unwrap(), k.flags()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            kernels,
+            [
+                ("add_one", KERNEL_ELEMENTWISE),
+                ("total", KERNEL_RETURNS_SCALAR | KERNEL_CAST_TO_SUPERTYPE),
+            ]
+        );
+    }
+}
@@ -7,6 +7,7 @@ use object_store::{ObjectMeta, ObjectStore};
 use polars_core::prelude::{InitHashMaps, PlHashMap};
 use polars_error::{PolarsError, PolarsResult, to_compute_err};
 use tokio::io::{AsyncSeekExt, AsyncWriteExt};
+use tracing::Instrument;
 
 use crate::pl_async::{
     self, MAX_BUDGET_PER_REQUEST, get_concurrency_limit, get_download_chunk_size,
@@ -166,6 +167,12 @@ impl PolarsObjectStore {
     }
 
     pub async fn get_range(&self, path: &Path, range: Range<usize>) -> PolarsResult<Bytes> {
+        let span = tracing::debug_span!(
+            "get_range",
+            path = %path,
+            start = range.start,
+            end = range.end
+        );
         self.try_exec_rebuild_on_err(move |store| {
             let range = range.clone();
             let st = store.clone();
@@ -200,6 +207,7 @@ impl PolarsObjectStore {
                 }
             }
         })
+        .instrument(span)
         .await
     }
 
@@ -225,6 +233,14 @@ impl PolarsObjectStore {
         let ranges_len = ranges.len();
         let (merged_ranges, merged_ends): (Vec<_>, Vec<_>) = merge_ranges(ranges).unzip();
 
+        let span = tracing::debug_span!(
+            "get_ranges",
+            path = %path,
+            num_ranges = ranges_len,
+            num_requests = merged_ranges.len(),
+            bytes = ranges.iter().map(|r| r.len()).sum::<usize>()
+        );
+
         self.try_exec_rebuild_on_err(|store| {
             let st = store.clone();
 
@@ -299,11 +315,13 @@ impl PolarsObjectStore {
                 Ok(out)
             }
         })
+        .instrument(span)
         .await
     }
 
     pub async fn download(&self, path: &Path, file: &mut tokio::fs::File) -> PolarsResult<()> {
         let opt_size = self.head(path).await.ok().map(|x| x.size);
+        let span = tracing::debug_span!("download", path = %path, size = ?opt_size);
 
         let initial_pos = file.stream_position().await?;
 
@@ -359,6 +377,7 @@ impl PolarsObjectStore {
                 Ok(())
             }
         })
+        .instrument(span)
         .await
     }
 
@@ -396,6 +415,7 @@ impl PolarsObjectStore {
                 .map_err(to_compute_err)
             }
         })
+        .instrument(tracing::debug_span!("head", path = %path))
         .await
     }
 }
@@ -38,7 +38,7 @@ pub use read_impl::{create_sorting_map, try_set_sorted_flag};
 #[cfg(feature = "cloud")]
 pub use reader::ParquetAsyncReader;
 pub use reader::{BatchedParquetReader, ParquetReader};
-pub use utils::materialize_empty_df;
+pub use utils::{materialize_empty_df, read_strings_as_categorical};
 
 pub mod _internal {
     pub use super::mmap::to_deserializer;
@@ -10,6 +10,7 @@ use polars_core::prelude::*;
 use polars_core::series::IsSorted;
 use polars_core::utils::{accumulate_dataframes_vertical, split_df};
 use polars_core::{POOL, config};
+use polars_error::ErrorContext;
 use polars_parquet::read::{
     self, ColumnChunkMetadata, FileMetadata, Filter, PredicateFilter, RowGroupMetadata,
 };
@@ -113,6 +114,7 @@ pub fn create_sorting_map(md: &RowGroupMetadata) -> PlHashMap<usize, IsSorted> {
 
 fn column_idx_to_series(
     column_i: usize,
+    rg_idx: usize,
     // The metadata belonging to this column
     field_md: &[&ColumnChunkMetadata],
     filter: Option<Filter>,
@@ -126,7 +128,8 @@ fn column_idx_to_series(
         assert_dtypes(field.dtype())
     }
     let columns = mmap_columns(store, field_md);
-    let (array, pred_true_mask) = mmap::to_deserializer(columns, field.clone(), filter)?;
+    let (array, pred_true_mask) = mmap::to_deserializer(columns, field.clone(), filter)
+        .map_err(|err| err.with_context(ErrorContext::RowGroup(rg_idx)))?;
     let series = Series::try_from((field, array))?;
 
     Ok((series, pred_true_mask))
@@ -385,8 +388,14 @@ fn rg_to_dfs_prefiltered(
                             },
                         };
 
-                        let (mut series, pred_true_mask) =
-                            column_idx_to_series(col_idx, part.as_slice(), filter, schema, store)?;
+                        let (mut series, pred_true_mask) = column_idx_to_series(
+                            col_idx,
+                            rg_idx,
+                            part.as_slice(),
+                            filter,
+                            schema,
+                            store,
+                        )?;
 
                         debug_assert!(
                             pred_true_mask.is_empty() || pred_true_mask.len() == md.num_rows()
@@ -525,6 +534,7 @@ fn rg_to_dfs_prefiltered(
                         let pre = || {
                             let (array, _) = column_idx_to_series(
                                 col_idx,
+                                rg_idx,
                                 field_md.as_slice(),
                                 Some(Filter::new_masked(filter_mask.clone())),
                                 schema,
@@ -536,6 +546,7 @@ fn rg_to_dfs_prefiltered(
                         let post = || {
                             let (array, _) = column_idx_to_series(
                                 col_idx,
+                                rg_idx,
                                 field_md.as_slice(),
                                 None,
                                 schema,
@@ -673,6 +684,7 @@ fn rg_to_dfs_optionally_par_over_columns(
 
             let (mut series, _) = column_idx_to_series(
                 *column_i,
+                rg_idx,
                 part.as_slice(),
                 Some(Filter::new_ranged(rg_slice.0, rg_slice.0 + rg_slice.1)),
                 schema,
@@ -784,7 +796,7 @@ fn rg_to_dfs_par_over_rg(
             continue;
         }
 
-        row_groups.push((rg_md, rg_slice, row_count_start));
+        row_groups.push((i, rg_md, rg_slice, row_count_start));
     }
 
     let dfs = POOL.install(|| {
@@ -792,7 +804,7 @@ fn rg_to_dfs_par_over_rg(
         // Ensure all row groups are partitioned.
         row_groups
             .into_par_iter()
-            .map(|(md, slice, row_count_start)| {
+            .map(|(rg_idx, md, slice, row_count_start)| {
                 if slice.1 == 0 || use_statistics && !read_this_row_group(predicate, md, schema)? {
                     return Ok(None);
                 }
@@ -821,6 +833,7 @@ fn rg_to_dfs_par_over_rg(
 
                         let (mut series, _) = column_idx_to_series(
                             *column_i,
+                            rg_idx,
                             part.as_slice(),
                             Some(Filter::new_ranged(slice.0, slice.0 + slice.1)),
                             schema,
@@ -15,7 +15,10 @@ pub use super::read_impl::BatchedParquetReader;
 use super::read_impl::{FetchRowGroupsFromMmapReader, compute_row_group_range, read_parquet};
 #[cfg(feature = "cloud")]
 use super::utils::materialize_empty_df;
-use super::utils::{ensure_matching_dtypes_if_found, projected_arrow_schema_to_projection_indices};
+use super::utils::{
+    ensure_matching_dtypes_if_found, projected_arrow_schema_to_projection_indices,
+    read_strings_as_categorical,
+};
 use crate::RowIndex;
 #[cfg(feature = "cloud")]
 use crate::cloud::CloudOptions;
@@ -89,6 +92,18 @@ impl<R: MmapBytesReader> ParquetReader<R> {
         projected_arrow_schema: Option<&ArrowSchema>,
         allow_missing_columns: bool,
     ) -> PolarsResult<Self> {
+        // Read the strings that the scan expects as categoricals as categoricals.
+        let target_schema = projected_arrow_schema.unwrap_or(first_schema.as_ref());
+        let slf_schema = self.schema()?;
+        if let Some(schema) =
+            read_strings_as_categorical(&slf_schema, self.get_metadata()?, |name| {
+                target_schema
+                    .get(name)
+                    .is_some_and(|field| DataType::from_arrow_field(field).is_categorical())
+            })
+        {
+            self.schema = Some(Arc::new(schema));
+        }
         let slf_schema = self.schema()?;
         let slf_schema_width = slf_schema.len();
 
@@ -341,6 +356,18 @@ impl ParquetAsyncReader {
         projected_arrow_schema: Option<&ArrowSchema>,
         allow_missing_columns: bool,
     ) -> PolarsResult<Self> {
+        // Read the strings that the scan expects as categoricals as categoricals.
+        let target_schema = projected_arrow_schema.unwrap_or(first_schema.as_ref());
+        let slf_schema = self.schema().await?;
+        if let Some(schema) =
+            read_strings_as_categorical(&slf_schema, self.get_metadata().await?, |name| {
+                target_schema
+                    .get(name)
+                    .is_some_and(|field| DataType::from_arrow_field(field).is_categorical())
+            })
+        {
+            self.schema = Some(Arc::new(schema));
+        }
         let slf_schema = self.schema().await?;
         let slf_schema_width = slf_schema.len();
 
@@ -1,8 +1,11 @@
 use std::borrow::Cow;
 
-use polars_core::prelude::{ArrowSchema, DataFrame, DataType, IDX_DTYPE, Series};
+use arrow::datatypes::{ArrowDataType, Field as ArrowField};
+use polars_core::prelude::{ArrowSchema, CompatLevel, DataFrame, DataType, IDX_DTYPE, Series};
 use polars_core::schema::SchemaNamesAndDtypes;
 use polars_error::{PolarsResult, polars_bail};
+use polars_parquet::read::FileMetadata;
+use polars_utils::pl_str::PlSmallStr;
 
 use crate::RowIndex;
 use crate::hive::materialize_hive_partitions;
@@ -92,3 +95,49 @@ pub fn ensure_matching_dtypes_if_found(
             Ok(())
         })
 }
+
+/// The schema to read a file with if the string columns for which `is_categorical` holds are read
+/// as categoricals, or `None` if there are no such columns.
+///
+/// A column is read with the dictionary decoder if all its pages are dictionary-encoded, so its
+/// strings are only decoded once per row group. Otherwise its strings are decoded and cast.
+pub fn read_strings_as_categorical(
+    schema: &ArrowSchema,
+    metadata: &FileMetadata,
+    is_categorical: impl Fn(&PlSmallStr) -> bool,
+) -> Option<ArrowSchema> {
+    let is_string = |field: &ArrowField| {
+        matches!(
+            field.dtype(),
+            ArrowDataType::Utf8 | ArrowDataType::LargeUtf8 | ArrowDataType::Utf8View
+        )
+    };
+    if !schema
+        .iter()
+        .any(|(name, field)| is_string(field) && is_categorical(name))
+    {
+        return None;
+    }
+
+    let schema = schema
+        .iter()
+        .map(|(name, field)| {
+            if !(is_string(field) && is_categorical(name)) {
+                return (name.clone(), field.clone());
+            }
+            let dtype = DataType::Categorical(None, Default::default());
+            let mut categorical = dtype.to_arrow_field(name.clone(), CompatLevel::newest());
+            categorical.is_nullable = field.is_nullable;
+            let dictionary_encoded = metadata.row_groups.iter().all(|rg| {
+                rg.columns_under_root_iter(name)
+                    .is_some_and(|mut c| c.len() == 1 && c.next().unwrap().is_dictionary_encoded())
+            });
+            // Without the categorical metadata the decoder decodes the strings and casts them.
+            if !dictionary_encoded {
+                categorical.metadata = None;
+            }
+            (name.clone(), categorical)
+        })
+        .collect();
+    Some(schema)
+}
@@ -3,11 +3,16 @@ use std::path::{Path, PathBuf};
 use std::sync::{Arc, LazyLock};
 
 use polars_core::config;
-use polars_core::error::{PolarsError, PolarsResult, polars_bail, to_compute_err};
+use polars_core::error::{
+    PolarsError, PolarsResult, polars_bail, polars_diagnostic, to_compute_err,
+};
 use polars_utils::pl_str::PlSmallStr;
 
 #[cfg(feature = "cloud")]
 mod hugging_face;
+mod query_temp_dir;
+
+pub use query_temp_dir::*;
 
 use crate::cloud::CloudOptions;
 
@@ -464,6 +469,10 @@ pub fn expand_paths_hive(
                             stack.push_back(path);
                         } else if path.metadata()?.len() > 0 {
                             out_paths.push(path);
+                        } else {
+                            polars_diagnostic!(
+                                SkippedFile: "skipped empty file '{}'", path.display()
+                            )?;
                         }
                     }
                 }
@@ -482,8 +491,15 @@ pub fn expand_paths_hive(
 
                 for path in paths {
                     let path = path.map_err(to_compute_err)?;
-                    if !path.is_dir() && path.metadata()?.len() > 0 {
+                    if path.is_dir() {
+                        continue;
+                    }
+                    if path.metadata()?.len() > 0 {
                         out_paths.push(path);
+                    } else {
+                        polars_diagnostic!(
+                            SkippedFile: "skipped empty file '{}'", path.display()
+                        )?;
                     }
                 }
             } else {
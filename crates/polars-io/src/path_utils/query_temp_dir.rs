@@ -0,0 +1,318 @@
+//! Per-query directory for temporary artifacts.
+//!
+//! By default every operation that needs scratch space (out-of-core spills) picks its own uniquely named location underneath the temporary directory. This makes it hard to
+//! audit what a failed query left behind. A [`QueryTempDir`] groups all artifacts of a query under
+//! a single directory with a deterministic name and records every artifact in a manifest.
+//!
+//! ```no_run
+//! use polars_io::path_utils::QueryTempDir;
+//!
+//! let tmp = QueryTempDir::new("nightly-etl").unwrap();
+//! let _holder = tmp.hold();
+//! // Artifacts created by queries started on this thread end up in `tmp.path()`.
+//! ```
+use std::cell::RefCell;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use polars_core::config;
+use polars_error::{PolarsResult, polars_bail, polars_ensure, to_compute_err};
+
+use super::POLARS_TEMP_DIR_BASE_PATH;
+
+pub const MANIFEST_FILE_NAME: &str = "MANIFEST.tsv";
+
+thread_local! {
+    /// The query temporary directory of the queries started on this thread, see
+    /// [`QueryTempDir::hold`].
+    static ACTIVE_QUERY_TEMP_DIR: RefCell<Option<Arc<QueryTempDir>>> = const { RefCell::new(None) };
+}
+
+/// The kind of temporary artifact, used in the artifact name and manifest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TempArtifactKind {
+    /// Data spilled to disk by an out-of-core operation.
+    Spill,
+}
+
+impl TempArtifactKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Spill => "spill",
+        }
+    }
+}
+
+/// Returns the root directory under which query temporary directories are created.
+///
+/// This is `POLARS_QUERY_TEMP_DIR` if set, otherwise `queries/` under the polars temporary
+/// directory. Setting the variable allows co-locating temporary IO on fast disks.
+pub fn query_temp_dir_root() -> PathBuf {
+    std::env::var("POLARS_QUERY_TEMP_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| POLARS_TEMP_DIR_BASE_PATH.join("queries"))
+}
+
+/// A directory that holds all temporary artifacts of a query, together with a manifest.
+///
+/// Artifact names are deterministic: `{seq:06}-{kind}-{label}`, where `seq` is the order in which
+/// the artifacts were requested. Each artifact is appended to `MANIFEST.tsv` before it is handed
+/// out, so the manifest is complete even if the process dies while writing the artifact.
+///
+/// On drop, the directory is removed if this created it. If it already existed, only the manifest
+/// and the artifacts handed out by this are removed.
+pub struct QueryTempDir {
+    path: PathBuf,
+    created_dir: bool,
+    manifest: Mutex<Manifest>,
+    next_seq: AtomicUsize,
+    keep: AtomicBool,
+}
+
+impl QueryTempDir {
+    /// Create the directory `query_name` under [`query_temp_dir_root`].
+    pub fn new(query_name: &str) -> PolarsResult<Arc<Self>> {
+        Self::new_in(&query_temp_dir_root(), query_name)
+    }
+
+    /// Create the directory `query_name` under `root`.
+    ///
+    /// Fails if the directory already contains a manifest, as that means the artifacts of an
+    /// earlier run were not cleaned up.
+    pub fn new_in(root: &Path, query_name: &str) -> PolarsResult<Arc<Self>> {
+        polars_ensure!(
+            !query_name.is_empty()
+                && !query_name.contains(['/', '\\'])
+                && query_name != "."
+                && query_name != "..",
+            InvalidOperation: "invalid query temporary directory name: {:?}", query_name
+        );
+
+        let path = root.join(query_name);
+        std::fs::create_dir_all(root).map_err(to_compute_err)?;
+        let created_dir = match std::fs::create_dir(&path) {
+            Ok(()) => true,
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists && path.is_dir() => false,
+            Err(e) => return Err(to_compute_err(e)),
+        };
+
+        let manifest_path = path.join(MANIFEST_FILE_NAME);
+        if manifest_path.exists() {
+            polars_bail!(
+                ComputeError:
+                "query temporary directory {} is already in use; remove it or pick another name",
+                path.display()
+            );
+        }
+        let mut manifest = OpenOptions::new()
+            .create_new(true)
+            .append(true)
+            .open(&manifest_path)
+            .map_err(to_compute_err)?;
+        writeln!(manifest, "seq\tkind\tlabel\tcreated_unix_ms\tpath").map_err(to_compute_err)?;
+
+        if config::verbose() {
+            eprintln!("query temporary directory: {}", path.display());
+        }
+
+        Ok(Arc::new(Self {
+            path,
+            created_dir,
+            manifest: Mutex::new(Manifest {
+                file: manifest,
+                artifacts: Vec::new(),
+            }),
+            next_seq: AtomicUsize::new(0),
+            keep: AtomicBool::new(
+                std::env::var("POLARS_KEEP_QUERY_TEMP_DIR").as_deref() == Ok("1"),
+            ),
+        }))
+    }
+
+    /// Keep the directory and its artifacts when this is dropped, e.g. for post-mortem analysis.
+    pub fn set_keep(&self, keep: bool) {
+        self.keep.store(keep, Ordering::Relaxed);
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn manifest_path(&self) -> PathBuf {
+        self.path.join(MANIFEST_FILE_NAME)
+    }
+
+    /// Reserve a path for a new artifact and record it in the manifest.
+    ///
+    /// The artifact itself is not created, the caller decides whether it is a file or directory.
+    pub fn new_artifact(&self, kind: TempArtifactKind, label: &str) -> PolarsResult<PathBuf> {
+        let seq = self.next_seq.fetch_add(1, Ordering::Relaxed);
+        let label = sanitize_label(label);
+        let path = self
+            .path
+            .join(format!("{seq:06}-{}-{label}", kind.as_str()));
+
+        let created = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis())
+            .unwrap_or_default();
+
+        let mut manifest = self.manifest.lock().unwrap();
+        writeln!(
+            manifest.file,
+            "{seq}\t{}\t{label}\t{created}\t{}",
+            kind.as_str(),
+            path.display()
+        )
+        .map_err(to_compute_err)?;
+        manifest.file.flush().map_err(to_compute_err)?;
+        manifest.artifacts.push(path.clone());
+
+        Ok(path)
+    }
+
+    /// Make this the temporary directory of the queries that are started on this thread, for as
+    /// long as the returned holder is alive.
+    ///
+    /// Queries pick up the directory when their operators are created, so queries that are
+    /// started on other threads at the same time keep their own directories.
+    pub fn hold(self: &Arc<Self>) -> QueryTempDirHolder {
+        let previous = ACTIVE_QUERY_TEMP_DIR.with_borrow_mut(|dir| dir.replace(self.clone()));
+        QueryTempDirHolder {
+            previous,
+            _not_send: PhantomData,
+        }
+    }
+}
+
+impl Drop for QueryTempDir {
+    fn drop(&mut self) {
+        if self.keep.load(Ordering::Relaxed) {
+            return;
+        }
+
+        // Another process may have already removed these, which is fine.
+        if self.created_dir {
+            let _ = std::fs::remove_dir_all(&self.path);
+        } else {
+            // Don't touch anything in a directory we didn't create other than our own artifacts.
+            let manifest = self.manifest.get_mut().unwrap_or_else(|e| e.into_inner());
+            for artifact in &manifest.artifacts {
+                if artifact.is_dir() {
+                    let _ = std::fs::remove_dir_all(artifact);
+                } else {
+                    let _ = std::fs::remove_file(artifact);
+                }
+            }
+            let _ = std::fs::remove_file(self.path.join(MANIFEST_FILE_NAME));
+        }
+    }
+}
+
+struct Manifest {
+    file: File,
+    /// The artifacts handed out so far, removed on drop.
+    artifacts: Vec<PathBuf>,
+}
+
+/// Keeps a [`QueryTempDir`] active ([RAII]), restoring the previously active one on drop.
+///
+/// [RAII]: https://en.wikipedia.org/wiki/Resource_acquisition_is_initialization
+pub struct QueryTempDirHolder {
+    previous: Option<Arc<QueryTempDir>>,
+    // The directory is restored on the thread that holds it.
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Drop for QueryTempDirHolder {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        ACTIVE_QUERY_TEMP_DIR.with_borrow_mut(|dir| *dir = previous);
+    }
+}
+
+/// Returns the query temporary directory of the queries started on this thread, if any.
+///
+/// Operators call this when they are created, on the thread that runs the query, and keep the
+/// directory for their spills.
+pub fn active_query_temp_dir() -> Option<Arc<QueryTempDir>> {
+    ACTIVE_QUERY_TEMP_DIR.with_borrow(|dir| dir.clone())
+}
+
+fn sanitize_label(label: &str) -> String {
+    label
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_query_temp_dir_manifest() {
+        let root = tempfile::tempdir().unwrap();
+        let tmp = QueryTempDir::new_in(root.path(), "my-query").unwrap();
+        assert_eq!(tmp.path(), root.path().join("my-query"));
+
+        let a = tmp.new_artifact(TempArtifactKind::Spill, "sort").unwrap();
+        let b = tmp
+            .new_artifact(TempArtifactKind::Spill, "group.by")
+            .unwrap();
+        assert_eq!(a.file_name().unwrap(), "000000-spill-sort");
+        assert_eq!(b.file_name().unwrap(), "000001-spill-group_by");
+
+        let manifest = std::fs::read_to_string(tmp.manifest_path()).unwrap();
+        let lines = manifest.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[1].starts_with("0\tspill\tsort\t"));
+        assert!(lines[2].starts_with("1\tspill\tgroup_by\t"));
+
+        // The name is already in use.
+        assert!(QueryTempDir::new_in(root.path(), "my-query").is_err());
+
+        {
+            let _holder = tmp.hold();
+            assert!(active_query_temp_dir().is_some());
+            // Queries started on other threads don't use it.
+            let other = std::thread::spawn(|| active_query_temp_dir().is_none());
+            assert!(other.join().unwrap());
+        }
+        assert!(active_query_temp_dir().is_none());
+
+        let path = tmp.path().to_path_buf();
+        drop(tmp);
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_query_temp_dir_existing_dir() {
+        let root = tempfile::tempdir().unwrap();
+        let path = root.path().join("user-dir");
+        std::fs::create_dir(&path).unwrap();
+        std::fs::write(path.join("data.csv"), "a\n1\n").unwrap();
+
+        let tmp = QueryTempDir::new_in(root.path(), "user-dir").unwrap();
+        let artifact = tmp.new_artifact(TempArtifactKind::Spill, "sort").unwrap();
+        std::fs::create_dir(&artifact).unwrap();
+        std::fs::write(artifact.join("0.ipc"), "").unwrap();
+        drop(tmp);
+
+        // Only the artifacts and manifest are removed, the user's data is kept.
+        assert!(path.join("data.csv").exists());
+        assert!(!artifact.exists());
+        assert!(!path.join(MANIFEST_FILE_NAME).exists());
+    }
+}
@@ -285,12 +285,16 @@ impl RuntimeManager {
             eprintln!("async thread count: {}", n_threads);
         }
 
+        #[cfg(not(target_family = "wasm"))]
         let rt = Builder::new_multi_thread()
             .worker_threads(n_threads)
             .enable_io()
             .enable_time()
             .build()
             .unwrap();
+        // Tokio has no worker threads or I/O driver on wasm.
+        #[cfg(target_family = "wasm")]
+        let rt = Builder::new_current_thread().enable_time().build().unwrap();
 
         Self { rt }
     }
@@ -304,7 +308,15 @@ impl RuntimeManager {
     where
         F: Future,
     {
-        tokio::task::block_in_place(|| self.rt.block_on(future))
+        #[cfg(not(target_family = "wasm"))]
+        {
+            tokio::task::block_in_place(|| self.rt.block_on(future))
+        }
+        // There are no other threads to hand the async tasks to.
+        #[cfg(target_family = "wasm")]
+        {
+            self.rt.block_on(future)
+        }
     }
 
     /// Blocks this thread to evaluate the given future. Panics if the current
@@ -346,7 +358,14 @@ impl RuntimeManager {
             // We are a rayon thread, so we can't use POOL.spawn as it would mean we spawn a task and block until
             // another rayon thread executes it - we would deadlock if all rayon threads did this.
             // Safety: The tokio runtime flavor is multi-threaded.
-            tokio::task::block_in_place(func)
+            #[cfg(not(target_family = "wasm"))]
+            {
+                tokio::task::block_in_place(func)
+            }
+            #[cfg(target_family = "wasm")]
+            {
+                func()
+            }
         } else {
             let (tx, rx) = tokio::sync::oneshot::channel();
 
@@ -456,6 +456,29 @@ impl PhysicalIoExpr for PhysicalExprWithConstCols<Arc<dyn PhysicalIoExpr>> {
     }
 }
 
+/// Casts a column to a data type, e.g. according to the cast policy of a scan.
+pub type ColumnCast = Arc<dyn Fn(&Column, &DataType) -> PolarsResult<Column> + Send + Sync>;
+
+pub struct PhysicalExprWithCasts {
+    casts: Vec<(PlSmallStr, DataType)>,
+    cast: ColumnCast,
+    child: Arc<dyn PhysicalIoExpr>,
+}
+
+impl PhysicalIoExpr for PhysicalExprWithCasts {
+    fn evaluate_io(&self, df: &DataFrame) -> PolarsResult<Series> {
+        let mut df = df.clone();
+        for (name, dtype) in self.casts.iter() {
+            if let Ok(column) = df.column(name) {
+                let column = (self.cast)(column, dtype)?;
+                df.with_column(column)?;
+            }
+        }
+
+        self.child.evaluate_io(&df)
+    }
+}
+
 #[derive(Clone)]
 pub struct ScanIOPredicate {
     pub predicate: Arc<dyn PhysicalIoExpr>,
@@ -511,6 +534,30 @@ impl ScanIOPredicate {
             child: self.predicate.clone(),
         });
     }
+
+    /// Evaluate the predicate after casting the given columns of a file with `cast`. The
+    /// statistics of the file describe the uncast values, so these are no longer used to skip
+    /// batches.
+    pub fn set_column_casts(&mut self, casts: Vec<(PlSmallStr, DataType)>, cast: ColumnCast) {
+        if casts.is_empty() {
+            return;
+        }
+
+        self.skip_batch_predicate = None;
+
+        let mut column_predicates = self.column_predicates.as_ref().clone();
+        for (c, _) in casts.iter() {
+            column_predicates.predicates.remove(c);
+        }
+        column_predicates.is_sumwise_complete = false;
+        self.column_predicates = Arc::new(column_predicates);
+
+        self.predicate = Arc::new(PhysicalExprWithCasts {
+            casts,
+            cast,
+            child: self.predicate.clone(),
+        });
+    }
 }
 
 impl fmt::Debug for ScanIOPredicate {
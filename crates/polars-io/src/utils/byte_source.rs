@@ -1,10 +1,12 @@
 use std::ops::Range;
-use std::sync::Arc;
+use std::sync::{Arc, LazyLock, RwLock};
 
+use async_trait::async_trait;
 use polars_core::prelude::PlHashMap;
 use polars_error::PolarsResult;
-use polars_utils::_limit_path_len_io_err;
 use polars_utils::mmap::MemSlice;
+use polars_utils::pl_str::PlSmallStr;
+use polars_utils::{_limit_path_len_io_err, format_pl_smallstr};
 
 use crate::cloud::{
     CloudLocation, CloudOptions, ObjectStorePath, PolarsObjectStore, build_object_store,
@@ -32,15 +34,25 @@ impl MemSliceByteSource {
         path: &str,
         _cloud_options: Option<&CloudOptions>,
     ) -> PolarsResult<Self> {
-        let file = Arc::new(
-            tokio::fs::File::open(path)
+        let open = |path: &str| {
+            std::fs::File::open(path).map_err(|err| _limit_path_len_io_err(path.as_ref(), err))
+        };
+
+        // Not `tokio::fs`, which only works within a Tokio runtime. The blocking pool can be
+        // awaited from any executor.
+        #[cfg(not(target_family = "wasm"))]
+        let file = {
+            let path = path.to_string();
+            crate::pl_async::get_runtime()
+                .spawn_blocking(move || open(&path))
                 .await
-                .map_err(|err| _limit_path_len_io_err(path.as_ref(), err))?
-                .into_std()
-                .await,
-        );
+                .unwrap()?
+        };
+        // There are no threads to block on wasm.
+        #[cfg(target_family = "wasm")]
+        let file = open(path)?;
 
-        Ok(Self(MemSlice::from_file(file.as_ref())?))
+        Ok(Self(MemSlice::from_file(&file)?))
     }
 }
 
@@ -103,10 +115,73 @@ impl ByteSource for ObjectStoreByteSource {
     }
 }
 
+/// Reads byte ranges of the files of a URL scheme that Polars doesn't read itself, e.g. files over
+/// HTTP on wasm32, where the reads must go through `fetch`.
+#[async_trait]
+pub trait RangeReader: Send + Sync {
+    async fn size(&self) -> PolarsResult<usize>;
+    async fn read_range(&self, range: Range<usize>) -> PolarsResult<MemSlice>;
+}
+
+/// Opens a [`RangeReader`] for a path.
+pub type RangeReaderFactory = Arc<dyn Fn(&str) -> PolarsResult<Arc<dyn RangeReader>> + Send + Sync>;
+
+static RANGE_READERS: LazyLock<RwLock<PlHashMap<PlSmallStr, RangeReaderFactory>>> =
+    LazyLock::new(Default::default);
+
+/// Reads the paths starting with `{scheme}://` with the readers of `factory`, instead of the
+/// built-in readers, replacing the factory registered for the same scheme.
+pub fn register_range_reader(scheme: &str, factory: RangeReaderFactory) {
+    RANGE_READERS
+        .write()
+        .unwrap()
+        .insert(scheme.to_ascii_lowercase().into(), factory);
+}
+
+/// Removes the factory registered for `scheme`, returning whether there was one.
+pub fn unregister_range_reader(scheme: &str) -> bool {
+    RANGE_READERS
+        .write()
+        .unwrap()
+        .remove(scheme.to_ascii_lowercase().as_str())
+        .is_some()
+}
+
+fn registered_range_reader(path: &str) -> Option<RangeReaderFactory> {
+    let (scheme, _) = path.split_once("://")?;
+    let readers = RANGE_READERS.read().unwrap();
+    readers.get(scheme.to_ascii_lowercase().as_str()).cloned()
+}
+
+/// Byte source backed by a registered [`RangeReader`].
+pub struct RangeReaderByteSource(pub Arc<dyn RangeReader>);
+
+impl ByteSource for RangeReaderByteSource {
+    async fn get_size(&self) -> PolarsResult<usize> {
+        self.0.size().await
+    }
+
+    async fn get_range(&self, range: Range<usize>) -> PolarsResult<MemSlice> {
+        self.0.read_range(range).await
+    }
+
+    async fn get_ranges(
+        &self,
+        ranges: &mut [Range<usize>],
+    ) -> PolarsResult<PlHashMap<usize, MemSlice>> {
+        let slices = futures::future::try_join_all(
+            ranges.iter().map(|range| self.0.read_range(range.clone())),
+        )
+        .await?;
+        Ok(ranges.iter().map(|range| range.start).zip(slices).collect())
+    }
+}
+
 /// Dynamic dispatch to async functions.
 pub enum DynByteSource {
     MemSlice(MemSliceByteSource),
     Cloud(ObjectStoreByteSource),
+    RangeReader(RangeReaderByteSource),
 }
 
 impl DynByteSource {
@@ -114,8 +189,26 @@ impl DynByteSource {
         match self {
             Self::MemSlice(_) => "MemSlice",
             Self::Cloud(_) => "Cloud",
+            Self::RangeReader(_) => "RangeReader",
         }
     }
+
+    /// Returns an identifier of the version of a cloud object, which changes when the object is
+    /// rewritten: its ETag, or else its version or time of modification. Returns `None` for the
+    /// other sources.
+    pub async fn cloud_object_version(&self) -> PolarsResult<Option<PlSmallStr>> {
+        let Self::Cloud(v) = self else {
+            return Ok(None);
+        };
+        let meta = v.store.head(&v.path).await?;
+        Ok(Some(match (meta.e_tag, meta.version) {
+            (Some(e_tag), _) => e_tag.into(),
+            (None, Some(version)) => version.into(),
+            (None, None) => {
+                format_pl_smallstr!("{}:{}", meta.last_modified.to_rfc3339(), meta.size)
+            },
+        }))
+    }
 }
 
 impl Default for DynByteSource {
@@ -129,6 +222,7 @@ impl ByteSource for DynByteSource {
         match self {
             Self::MemSlice(v) => v.get_size().await,
             Self::Cloud(v) => v.get_size().await,
+            Self::RangeReader(v) => v.get_size().await,
         }
     }
 
@@ -136,6 +230,7 @@ impl ByteSource for DynByteSource {
         match self {
             Self::MemSlice(v) => v.get_range(range).await,
             Self::Cloud(v) => v.get_range(range).await,
+            Self::RangeReader(v) => v.get_range(range).await,
         }
     }
 
@@ -146,6 +241,7 @@ impl ByteSource for DynByteSource {
         match self {
             Self::MemSlice(v) => v.get_ranges(ranges).await,
             Self::Cloud(v) => v.get_ranges(ranges).await,
+            Self::RangeReader(v) => v.get_ranges(ranges).await,
         }
     }
 }
@@ -162,6 +258,12 @@ impl From<ObjectStoreByteSource> for DynByteSource {
     }
 }
 
+impl From<RangeReaderByteSource> for DynByteSource {
+    fn from(value: RangeReaderByteSource) -> Self {
+        Self::RangeReader(value)
+    }
+}
+
 impl From<MemSlice> for DynByteSource {
     fn from(value: MemSlice) -> Self {
         Self::MemSlice(MemSliceByteSource(value))
@@ -181,6 +283,10 @@ impl DynByteSourceBuilder {
         path: &str,
         cloud_options: Option<&CloudOptions>,
     ) -> PolarsResult<DynByteSource> {
+        if let Some(factory) = registered_range_reader(path) {
+            return Ok(RangeReaderByteSource(factory(path)?).into());
+        }
+
         Ok(match self {
             Self::Mmap => MemSliceByteSource::try_new_mmap_from_path(path, cloud_options)
                 .await?
@@ -191,3 +297,51 @@ impl DynByteSourceBuilder {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct InMemoryReader(MemSlice);
+
+    #[async_trait]
+    impl RangeReader for InMemoryReader {
+        async fn size(&self) -> PolarsResult<usize> {
+            Ok(self.0.as_ref().len())
+        }
+
+        async fn read_range(&self, range: Range<usize>) -> PolarsResult<MemSlice> {
+            Ok(self.0.slice(range))
+        }
+    }
+
+    #[test]
+    fn test_registered_range_reader() -> PolarsResult<()> {
+        register_range_reader(
+            "memtest",
+            Arc::new(|path| {
+                let bytes = path.strip_prefix("memtest://").unwrap().as_bytes().to_vec();
+                Ok(Arc::new(InMemoryReader(MemSlice::from_vec(bytes))) as Arc<dyn RangeReader>)
+            }),
+        );
+        let source = crate::pl_async::get_runtime()
+            .block_on(DynByteSourceBuilder::Mmap.try_build_from_path("MEMTEST://abcdef", None))?;
+        assert_eq!(source.variant_name(), "RangeReader");
+
+        let mut ranges = [3..5, 0..2];
+        let (size, range, ranges) = crate::pl_async::get_runtime().block_on(async {
+            PolarsResult::Ok((
+                source.get_size().await?,
+                source.get_range(1..3).await?,
+                source.get_ranges(&mut ranges).await?,
+            ))
+        })?;
+        assert_eq!(size, 6);
+        assert_eq!(range.as_ref(), b"bc");
+        assert_eq!(ranges[&0].as_ref(), b"ab");
+        assert_eq!(ranges[&3].as_ref(), b"de");
+
+        assert!(unregister_range_reader("memtest"));
+        Ok(())
+    }
+}
@@ -8,7 +8,7 @@ pub fn mkdir_recursive(path: &Path) -> io::Result<()> {
     )
 }
 
-#[cfg(feature = "tokio")]
+#[cfg(all(feature = "tokio", not(target_family = "wasm")))]
 pub async fn tokio_mkdir_recursive(path: &Path) -> io::Result<()> {
     tokio::fs::DirBuilder::new()
         .recursive(true)
@@ -21,7 +21,7 @@ pub fn sync_on_close(sync_on_close: SyncOnCloseType, file: &mut fs::File) -> io:
     }
 }
 
-#[cfg(feature = "tokio")]
+#[cfg(all(feature = "tokio", not(target_family = "wasm")))]
 pub async fn tokio_sync_on_close(
     sync_on_close: SyncOnCloseType,
     file: &mut tokio::fs::File,
@@ -76,6 +76,36 @@ pub fn concat<L: AsRef<[LazyFrame]>>(inputs: L, args: UnionArgs) -> PolarsResult
     concat_impl(inputs, args)
 }
 
+/// Merge multiple [`LazyFrame`]s that are sorted on `key` into a single sorted [`LazyFrame`].
+///
+/// The inputs must have the same schema. The streaming engine merges all inputs at once instead
+/// of pairwise.
+#[cfg(feature = "merge_sorted")]
+pub fn merge_sorted<L, S>(inputs: L, key: S) -> PolarsResult<LazyFrame>
+where
+    L: AsRef<[LazyFrame]>,
+    S: Into<PlSmallStr>,
+{
+    let key = key.into();
+    let mut inputs = inputs.as_ref().to_vec();
+    polars_ensure!(!inputs.is_empty(), NoData: "empty container given");
+
+    // Merge pairwise in a balanced tree, so that the in-memory engine doesn't repeatedly merge
+    // the growing output with a single input.
+    while inputs.len() > 1 {
+        let mut merged = Vec::with_capacity(inputs.len().div_ceil(2));
+        let mut iter = inputs.into_iter();
+        while let Some(left) = iter.next() {
+            match iter.next() {
+                Some(right) => merged.push(left.merge_sorted(right, key.clone())?),
+                None => merged.push(left),
+            }
+        }
+        inputs = merged;
+    }
+    Ok(inputs.pop().unwrap())
+}
+
 /// Collect all [`LazyFrame`] computations.
 pub fn collect_all<I>(lfs: I) -> PolarsResult<Vec<DataFrame>>
 where
@@ -132,4 +162,18 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    #[cfg(feature = "merge_sorted")]
+    fn test_merge_sorted_many() -> PolarsResult<()> {
+        let a = df!["a" => [1, 4, 7]]?;
+        let b = df!["a" => [2, 5, 8]]?;
+        let c = df!["a" => [3, 6, 9]]?;
+
+        let out = merge_sorted([a.lazy(), b.lazy(), c.lazy()], "a")?.collect()?;
+        let expected = df!["a" => [1, 2, 3, 4, 5, 6, 7, 8, 9]]?;
+        assert!(out.equals(&expected));
+
+        Ok(())
+    }
 }
@@ -0,0 +1,154 @@
+//! Selection of the engine that runs a query for [`Engine::Auto`].
+use std::fmt;
+
+use polars_core::prelude::*;
+
+use crate::prelude::*;
+
+/// Reads the fraction of the available memory the estimated input of a query may take up before
+/// [`Engine::Auto`] runs it on the streaming engine from `POLARS_AUTO_ENGINE_MEMORY_FRACTION`.
+#[cfg(feature = "new_streaming")]
+fn auto_engine_memory_fraction() -> PolarsResult<f64> {
+    match std::env::var("POLARS_AUTO_ENGINE_MEMORY_FRACTION") {
+        Ok(fraction) => match fraction.parse::<f64>() {
+            Ok(fraction) if fraction >= 0.0 => Ok(fraction),
+            _ => polars_bail!(
+                ComputeError: "could not parse 'POLARS_AUTO_ENGINE_MEMORY_FRACTION' env var"
+            ),
+        },
+        Err(_) => Ok(0.5),
+    }
+}
+
+/// The engine chosen for a query and why.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct EngineSelection {
+    pub engine: Engine,
+    pub reason: String,
+}
+
+impl fmt::Display for EngineSelection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "ENGINE: {} ({})",
+            self.engine.into_static_str(),
+            self.reason
+        )
+    }
+}
+
+/// A rough estimate of the in-memory size of a row, as the schema is all we know of a scan
+/// up front.
+#[cfg(feature = "new_streaming")]
+fn estimated_row_size(schema: &Schema) -> usize {
+    schema
+        .iter_values()
+        .map(|dtype| match dtype {
+            DataType::String | DataType::Binary => 32,
+            dtype if dtype.is_nested() => 64,
+            _ => 8,
+        })
+        .sum()
+}
+
+/// Returns the name of a node the streaming engine can only run by falling back to the in-memory
+/// engine, if any.
+#[cfg(feature = "new_streaming")]
+fn non_streaming_node(ir: &IR) -> Option<&'static str> {
+    match ir {
+        IR::MapFunction {
+            function: FunctionIR::Opaque { function, .. },
+            ..
+        } if function.streaming_operator().is_some() => None,
+        IR::MapFunction { function, .. }
+            if !function.is_streamable() && !matches!(function, FunctionIR::RowIndex { .. }) =>
+        {
+            Some(ir.name())
+        },
+        IR::GroupBy { apply: Some(_), .. } => Some("group_by.map_groups"),
+        #[cfg(feature = "python")]
+        IR::PythonScan { .. } => Some(ir.name()),
+        IR::ExtContext { .. } => Some(ir.name()),
+        _ => None,
+    }
+}
+
+/// Estimates the number of bytes the scans of the plan produce.
+#[cfg(feature = "new_streaming")]
+fn estimated_input_size(plan: &IRPlan) -> usize {
+    (&plan.lp_arena)
+        .iter(plan.lp_top)
+        .map(|(_, ir)| match ir {
+            IR::DataFrameScan { df, .. } => df.estimated_size(),
+            IR::Scan {
+                file_info,
+                output_schema,
+                ..
+            } => {
+                let schema = output_schema.as_ref().unwrap_or(&file_info.schema);
+                file_info
+                    .row_estimation
+                    .1
+                    .saturating_mul(estimated_row_size(schema))
+            },
+            _ => 0,
+        })
+        .fold(0, usize::saturating_add)
+}
+
+/// Chooses between the in-memory and the streaming engine for an optimized plan.
+///
+/// Queries whose estimated input is a large fraction of the available memory are streamed,
+/// unless they contain operations the streaming engine doesn't support natively.
+pub(crate) fn select_engine(plan: &IRPlan) -> PolarsResult<EngineSelection> {
+    #[cfg(not(feature = "new_streaming"))]
+    {
+        let _ = plan;
+        Ok(EngineSelection {
+            engine: Engine::InMemory,
+            reason: "the streaming engine is not available".into(),
+        })
+    }
+
+    #[cfg(feature = "new_streaming")]
+    {
+        if let Some(name) = (&plan.lp_arena)
+            .iter(plan.lp_top)
+            .find_map(|(_, ir)| non_streaming_node(ir))
+        {
+            return Ok(EngineSelection {
+                engine: Engine::InMemory,
+                reason: format!("'{name}' is not supported natively by the streaming engine"),
+            });
+        }
+
+        let fraction = auto_engine_memory_fraction()?;
+        let input_size = estimated_input_size(plan);
+        let available = polars_utils::sys::MEMINFO.free() as usize;
+        let budget = (available as f64 * fraction) as usize;
+        let mb = |bytes: usize| bytes as f64 / 1e6;
+        let selection = if input_size > budget {
+            EngineSelection {
+                engine: Engine::Streaming,
+                reason: format!(
+                    "estimated input of {:.1} MB exceeds {:.1} MB, {:.0}% of the available memory",
+                    mb(input_size),
+                    mb(budget),
+                    fraction * 100.0
+                ),
+            }
+        } else {
+            EngineSelection {
+                engine: Engine::InMemory,
+                reason: format!(
+                    "estimated input of {:.1} MB fits in {:.1} MB, {:.0}% of the available memory",
+                    mb(input_size),
+                    mb(budget),
+                    fraction * 100.0
+                ),
+            }
+        };
+        Ok(selection)
+    }
+}
@@ -1,12 +1,51 @@
 use std::sync::Mutex;
-use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::mpsc::{Receiver, channel};
+use std::sync::mpsc::{Receiver, RecvTimeoutError, channel};
+use std::time::Duration;
 
 use polars_core::POOL;
 
 use super::*;
 
 impl LazyFrame {
+    /// Execute all the lazy operations like [`LazyFrame::collect_with_engine`], stopping the query
+    /// once it runs longer than `timeout`.
+    ///
+    /// Returns a [`PolarsError::Timeout`] if the query was stopped, other errors are returned as is.
+    /// Sinks can be given a deadline by collecting the [`LazyFrame`] they return with this method.
+    pub fn collect_with_timeout(
+        self,
+        engine: Engine,
+        timeout: Duration,
+    ) -> PolarsResult<DataFrame> {
+        let cancel_token = CancelToken::new();
+        let (done_tx, done_rx) = channel::<()>();
+        let watchdog = {
+            let cancel_token = cancel_token.clone();
+            std::thread::spawn(move || {
+                // The sender is dropped once the query is done.
+                let timed_out = matches!(
+                    done_rx.recv_timeout(timeout),
+                    Err(RecvTimeoutError::Timeout)
+                );
+                if timed_out {
+                    cancel_token.cancel();
+                }
+                timed_out
+            })
+        };
+
+        let result = self.collect_with_engine_impl(engine, Some(cancel_token));
+        drop(done_tx);
+        let timed_out = watchdog.join().unwrap();
+        match result {
+            Err(err) if timed_out && CancelToken::is_cancel_error(&err) => Err(polars_err!(
+                Timeout: "query did not finish within {:?}",
+                timeout
+            )),
+            result => result,
+        }
+    }
+
     pub fn collect_concurrently(self) -> PolarsResult<InProcessQuery> {
         let (mut state, mut physical_plan, _) = self.prepare_collect(false, None)?;
 
@@ -20,6 +59,63 @@ impl LazyFrame {
         Ok(InProcessQuery {
             rx: Arc::new(Mutex::new(rx)),
             token,
+            #[cfg(feature = "new_streaming")]
+            pause: None,
+        })
+    }
+
+    /// Run the query on the streaming engine in the background, returning a handle that can
+    /// cancel, pause and resume it.
+    ///
+    /// On cancellation all source and compute tasks of the query are stopped promptly, including
+    /// in-flight cloud requests, and the result is an error.
+    #[cfg(feature = "new_streaming")]
+    pub fn collect_cancellable(self) -> PolarsResult<InProcessQuery> {
+        self.collect_cancellable_with_priority(QueryPriority::default())
+    }
+
+    /// Like [`LazyFrame::collect_cancellable`], with the share of the streaming executor the query
+    /// gets while other queries are running set by its `priority`.
+    #[cfg(feature = "new_streaming")]
+    pub fn collect_cancellable_with_priority(
+        self,
+        priority: QueryPriority,
+    ) -> PolarsResult<InProcessQuery> {
+        let mut lf = self.with_new_streaming(true);
+        if !matches!(lf.logical_plan, DslPlan::Sink { .. }) {
+            lf.logical_plan = DslPlan::Sink {
+                input: Arc::new(lf.logical_plan),
+                payload: SinkType::Memory,
+            };
+        }
+        let mut alp_plan = lf.to_alp_optimized()?;
+
+        let (tx, rx) = channel();
+        let token = CancelToken::new();
+        let pause = polars_stream::PauseToken::new();
+        let options = QueryOptions {
+            cancel_token: Some(token.clone()),
+            pause_token: Some(pause.clone()),
+            priority,
+            ..Default::default()
+        };
+        std::thread::spawn(move || {
+            let _hold = StringCacheHolder::hold();
+            let result = polars_stream::run_query_with_options(
+                alp_plan.lp_top,
+                &mut alp_plan.lp_arena,
+                &mut alp_plan.expr_arena,
+                options,
+            )
+            .map(|out| out.unwrap());
+            // The handle may have been dropped already.
+            let _ = tx.send(result);
+        });
+
+        Ok(InProcessQuery {
+            rx: Arc::new(Mutex::new(rx)),
+            token,
+            pause: Some(pause),
         })
     }
 }
@@ -27,13 +123,36 @@ impl LazyFrame {
 #[derive(Clone)]
 pub struct InProcessQuery {
     rx: Arc<Mutex<Receiver<PolarsResult<DataFrame>>>>,
-    token: Arc<AtomicBool>,
+    token: CancelToken,
+    #[cfg(feature = "new_streaming")]
+    pause: Option<polars_stream::PauseToken>,
 }
 
 impl InProcessQuery {
     /// Cancel the query at earliest convenience.
     pub fn cancel(&self) {
-        self.token.store(true, Ordering::Relaxed)
+        self.token.cancel()
+    }
+
+    /// Pause the query: its sources stop producing data while the morsels already in flight are
+    /// processed. Only queries started with [`LazyFrame::collect_cancellable`] can be paused.
+    pub fn pause(&self) -> PolarsResult<()> {
+        #[cfg(feature = "new_streaming")]
+        if let Some(pause) = &self.pause {
+            pause.pause();
+            return Ok(());
+        }
+        polars_bail!(InvalidOperation: "only queries on the streaming engine can be paused")
+    }
+
+    /// Resume a paused query.
+    pub fn resume(&self) -> PolarsResult<()> {
+        #[cfg(feature = "new_streaming")]
+        if let Some(pause) = &self.pause {
+            pause.resume();
+            return Ok(());
+        }
+        polars_bail!(InvalidOperation: "only queries on the streaming engine can be paused")
     }
 
     /// Fetch the result.
@@ -54,6 +173,6 @@ impl InProcessQuery {
 
 impl Drop for InProcessQuery {
     fn drop(&mut self) {
-        self.token.store(true, Ordering::Relaxed);
+        self.token.cancel();
     }
 }
@@ -0,0 +1,230 @@
+//! Materialized views over append-only sources.
+//!
+//! A [`MaterializedView`] stores the result of a query over a source that only ever grows. When
+//! rows are appended to the source, the view only runs the query on the new rows if the query
+//! allows it, and merges that result into the stored one:
+//!
+//! - Queries that only filter, project or compute elementwise columns produce the rows of the
+//!   appended rows independently of the other rows. Their results are appended.
+//! - Queries that end in a group-by whose aggregations are all sums, minimums, maximums, counts,
+//!   firsts or lasts are aggregated again over the stored result and the result of the appended
+//!   rows.
+//!
+//! Other queries are run again over all of the rows of the source.
+use polars_core::prelude::*;
+
+use super::*;
+
+pub type ViewQuery = Arc<dyn Fn(LazyFrame) -> LazyFrame + Send + Sync>;
+
+enum Maintenance {
+    /// The result of the appended rows is appended to the stored result.
+    Append,
+    /// The result of the appended rows and the stored result are aggregated again.
+    Merge {
+        keys: Vec<Expr>,
+        aggs: Vec<Expr>,
+        maintain_order: bool,
+    },
+    /// The query is run again over all of the rows of the source.
+    Recompute { source: DataFrame },
+}
+
+/// The stored result of a query over an append-only source, see the [module level
+/// documentation](self).
+pub struct MaterializedView {
+    query: ViewQuery,
+    source_schema: SchemaRef,
+    maintenance: Maintenance,
+    result: DataFrame,
+}
+
+impl MaterializedView {
+    /// Run `query` over the `initial` rows of the source and store the result.
+    pub fn new<F>(initial: DataFrame, query: F) -> PolarsResult<Self>
+    where
+        F: Fn(LazyFrame) -> LazyFrame + Send + Sync + 'static,
+    {
+        let query: ViewQuery = Arc::new(query);
+        let source_schema = initial.schema().clone();
+
+        let plan = query(initial.clear().lazy()).to_alp()?;
+        let maintenance = maintenance(&plan).unwrap_or(Maintenance::Recompute {
+            source: initial.clone(),
+        });
+        let result = query(initial.lazy()).collect()?;
+
+        Ok(Self {
+            query,
+            source_schema,
+            maintenance,
+            result,
+        })
+    }
+
+    /// Append `rows` to the source and update the stored result.
+    pub fn append(&mut self, rows: DataFrame) -> PolarsResult<()> {
+        polars_ensure!(
+            rows.schema() == &self.source_schema,
+            SchemaMismatch: "appended rows don't match the schema of the source of the view: \
+            expected {:?}, got {:?}", self.source_schema, rows.schema()
+        );
+
+        match &mut self.maintenance {
+            Maintenance::Append => {
+                let delta = (self.query)(rows.lazy()).collect()?;
+                self.result.vstack_mut(&delta)?;
+            },
+            Maintenance::Merge {
+                keys,
+                aggs,
+                maintain_order,
+            } => {
+                let delta = (self.query)(rows.lazy()).collect()?;
+                let union = concat([self.lazy(), delta.lazy()], UnionArgs::default())?;
+                let group_by = if *maintain_order {
+                    union.group_by_stable(keys.as_slice())
+                } else {
+                    union.group_by(keys.as_slice())
+                };
+                self.result = group_by.agg(aggs.as_slice()).collect()?;
+            },
+            Maintenance::Recompute { source } => {
+                source.vstack_mut(&rows)?;
+                self.result = (self.query)(source.clone().lazy()).collect()?;
+            },
+        }
+        Ok(())
+    }
+
+    /// The stored result of the query.
+    pub fn result(&self) -> &DataFrame {
+        &self.result
+    }
+
+    /// A [`LazyFrame`] over the stored result of the query.
+    pub fn lazy(&self) -> LazyFrame {
+        self.result.clone().lazy()
+    }
+
+    /// Whether appended rows are processed without running the query over the full source.
+    pub fn is_incremental(&self) -> bool {
+        !matches!(self.maintenance, Maintenance::Recompute { .. })
+    }
+}
+
+/// How the result of `plan` can be updated with the result over appended rows, if it can.
+fn maintenance(plan: &IRPlan) -> Option<Maintenance> {
+    let lp_arena = &plan.lp_arena;
+    let expr_arena = &plan.expr_arena;
+
+    match lp_arena.get(plan.lp_top) {
+        IR::GroupBy {
+            input,
+            keys,
+            aggs,
+            maintain_order,
+            options,
+            apply: None,
+            ..
+        } => {
+            #[cfg(feature = "dynamic_group_by")]
+            if options.dynamic.is_some() || options.rolling.is_some() {
+                return None;
+            }
+            if options.slice.is_some()
+                || keys.is_empty()
+                || !is_row_wise(*input, lp_arena, expr_arena)
+                || !keys
+                    .iter()
+                    .all(|e| is_elementwise_rec(e.node(), expr_arena))
+            {
+                return None;
+            }
+
+            let aggs = aggs
+                .iter()
+                .map(|e| merge_agg(e, expr_arena))
+                .collect::<Option<Vec<_>>>()?;
+            let keys = keys.iter().map(|e| col(e.output_name().clone())).collect();
+            Some(Maintenance::Merge {
+                keys,
+                aggs,
+                maintain_order: *maintain_order,
+            })
+        },
+        _ => is_row_wise(plan.lp_top, lp_arena, expr_arena).then_some(Maintenance::Append),
+    }
+}
+
+/// Whether the rows `node` produces for the union of two sources are the union of the rows it
+/// produces for each of them.
+fn is_row_wise(mut node: Node, lp_arena: &Arena<IR>, expr_arena: &Arena<AExpr>) -> bool {
+    let has_column = |e: &ExprIR| {
+        aexpr_to_leaf_names_iter(e.node(), expr_arena)
+            .next()
+            .is_some()
+    };
+
+    loop {
+        node = match lp_arena.get(node) {
+            IR::DataFrameScan { .. } => return true,
+            IR::Filter { input, predicate } if is_elementwise_rec(predicate.node(), expr_arena) => {
+                *input
+            },
+            // A projection of only literals produces a single row.
+            IR::Select { input, expr, .. }
+                if expr.iter().any(has_column)
+                    && expr
+                        .iter()
+                        .all(|e| is_elementwise_rec(e.node(), expr_arena)) =>
+            {
+                *input
+            },
+            IR::HStack { input, exprs, .. }
+                if exprs
+                    .iter()
+                    .all(|e| is_elementwise_rec(e.node(), expr_arena)) =>
+            {
+                *input
+            },
+            IR::SimpleProjection { input, .. } => *input,
+            IR::MapFunction { input, function } => match function {
+                FunctionIR::Rename { .. }
+                | FunctionIR::Explode { .. }
+                | FunctionIR::Unnest { .. } => *input,
+                _ => return false,
+            },
+            _ => return false,
+        };
+    }
+}
+
+/// The aggregation that merges the results of `agg` over two sets of rows.
+fn merge_agg(agg: &ExprIR, expr_arena: &Arena<AExpr>) -> Option<Expr> {
+    let name = agg.output_name().clone();
+    let input = match expr_arena.get(agg.node()) {
+        AExpr::Len => return Some(col(name.clone()).sum().alias(name)),
+        AExpr::Agg(agg) => agg.get_input().first(),
+        _ => return None,
+    };
+    // An aggregation of literals is computed once per group, not once per row.
+    if !is_elementwise_rec(input, expr_arena)
+        || aexpr_to_leaf_names_iter(input, expr_arena).next().is_none()
+    {
+        return None;
+    }
+
+    let merged = col(name.clone());
+    let merged = match expr_arena.get(agg.node()) {
+        AExpr::Agg(IRAggExpr::Sum(_) | IRAggExpr::Count(..)) => merged.sum(),
+        AExpr::Agg(IRAggExpr::Min { propagate_nans, .. }) if *propagate_nans => merged.nan_min(),
+        AExpr::Agg(IRAggExpr::Min { .. }) => merged.min(),
+        AExpr::Agg(IRAggExpr::Max { propagate_nans, .. }) if *propagate_nans => merged.nan_max(),
+        AExpr::Agg(IRAggExpr::Max { .. }) => merged.max(),
+        AExpr::Agg(IRAggExpr::First(_)) => merged.first(),
+        AExpr::Agg(IRAggExpr::Last(_)) => merged.last(),
+        _ => return None,
+    };
+    Some(merged.alias(name))
+}
@@ -3,11 +3,19 @@
 mod python;
 
 mod cached_arenas;
+mod engine_selection;
 mod err;
 #[cfg(not(target_arch = "wasm32"))]
 mod exitable;
+mod materialized_view;
 #[cfg(feature = "pivot")]
 pub mod pivot;
+#[cfg(feature = "new_streaming")]
+mod plan_cache;
+#[cfg(feature = "substrait")]
+mod substrait;
+#[cfg(feature = "datafusion")]
+mod table_provider;
 
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
@@ -20,30 +28,85 @@ pub use exitable::*;
 pub use file_list_reader::*;
 #[cfg(feature = "ipc")]
 pub use ipc::*;
+pub use materialized_view::{MaterializedView, ViewQuery};
 #[cfg(feature = "json")]
 pub use ndjson::*;
 #[cfg(feature = "parquet")]
 pub use parquet::*;
+#[cfg(all(test, feature = "new_streaming"))]
+pub(crate) use plan_cache::PlanCacheCapacityGuard;
+#[cfg(feature = "new_streaming")]
+pub use plan_cache::{clear_plan_cache, set_plan_cache_capacity};
 use polars_compute::rolling::QuantileMethod;
 use polars_core::POOL;
-use polars_core::error::feature_gated;
+use polars_core::error::{DiagnosticKind, Diagnostics, feature_gated};
 use polars_core::prelude::*;
 use polars_expr::{ExpressionConversionState, create_physical_expr};
 use polars_io::RowIndex;
+use polars_io::predicates::PhysicalIoExpr;
 use polars_mem_engine::{Executor, create_multiple_physical_plans, create_physical_plan};
 use polars_ops::frame::{JoinCoalesce, MaintainOrderJoin};
-#[cfg(feature = "is_between")]
 use polars_ops::prelude::ClosedInterval;
 pub use polars_plan::frame::{AllowedOptimizations, OptFlags};
 use polars_plan::global::FETCH_ROWS;
+use polars_plan::plans::expr_ir::ExprIR;
+use polars_utils::format_pl_smallstr;
 use polars_utils::pl_str::PlSmallStr;
 use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+#[cfg(feature = "datafusion")]
+pub use table_provider::{LazyFrameExec, LazyFrameTable};
 
 use crate::frame::cached_arenas::CachedArena;
+use crate::frame::engine_selection::select_engine;
 #[cfg(feature = "streaming")]
 use crate::physical_plan::streaming::insert_streaming_nodes;
 use crate::prelude::*;
 
+/// Converts predicates to expressions the readers can evaluate, for the predicate pushdown.
+fn eval_io_expr(
+    expr: &ExprIR,
+    expr_arena: &Arena<AExpr>,
+    schema: &SchemaRef,
+) -> Option<Arc<dyn PhysicalIoExpr>> {
+    let phys_expr = create_physical_expr(
+        expr,
+        Context::Default,
+        expr_arena,
+        schema,
+        &mut ExpressionConversionState::new(true),
+    )
+    .ok()?;
+    Some(phys_expr_to_io_expr(phys_expr))
+}
+
+/// The sources and options of a file scan as the optimizer sees them, with its globs and
+/// directories expanded once the plan was converted to IR.
+fn resolved_scan(plan: &DslPlan) -> Option<(ScanSources, Box<FileScan>, Box<FileScanOptions>)> {
+    let plan = match plan {
+        DslPlan::IR { dsl, .. } => dsl.as_ref(),
+        plan => plan,
+    };
+    let DslPlan::Scan {
+        sources,
+        scan_type,
+        file_options,
+        cached_ir,
+        ..
+    } = plan
+    else {
+        return None;
+    };
+    match &*cached_ir.lock().unwrap() {
+        Some(IR::Scan {
+            sources,
+            scan_type,
+            file_options,
+            ..
+        }) => Some((sources.clone(), scan_type.clone(), file_options.clone())),
+        _ => Some((sources.clone(), scan_type.clone(), file_options.clone())),
+    }
+}
+
 pub trait IntoLazy {
     fn lazy(self) -> LazyFrame;
 }
@@ -151,6 +214,39 @@ impl LazyFrame {
         self
     }
 
+    /// Toggle reordering chains of inner joins by the estimated sizes of the joined tables.
+    ///
+    /// This is off by default, as it changes the order of the rows of the joins.
+    pub fn with_join_reorder(mut self, toggle: bool) -> Self {
+        self.opt_state.set(OptFlags::JOIN_REORDER, toggle);
+        self
+    }
+
+    /// Toggle turning filters on the existence of rows of another table into semi and anti joins,
+    /// and filtering the probe side of joins with the keys of small in-memory tables.
+    ///
+    /// This is off by default, as pushing down the keys can cost more than it saves.
+    pub fn with_semi_join_reduction(mut self, toggle: bool) -> Self {
+        self.opt_state.set(OptFlags::SEMI_JOIN_REDUCTION, toggle);
+        self
+    }
+
+    /// Toggle keeping low-cardinality string columns of Parquet scans dictionary-encoded through
+    /// filters, joins and group-bys, only materializing their values when they are needed.
+    pub fn with_dictionary_strings(mut self, toggle: bool) -> Self {
+        self.opt_state.set(OptFlags::DICTIONARY_STRINGS, toggle);
+        self
+    }
+
+    /// Toggle taking the sort order of columns from the metadata of scanned files, which is used to
+    /// remove redundant sorts and to merge sorted join keys.
+    ///
+    /// This is off by default, as files may declare a sort order that their data doesn't have.
+    pub fn with_sorted_metadata(mut self, toggle: bool) -> Self {
+        self.opt_state.set(OptFlags::SORTED_METADATA, toggle);
+        self
+    }
+
     /// Check if operations are order dependent and unset maintaining_order if
     /// the order would not be observed.
     pub fn with_check_order(mut self, toggle: bool) -> Self {
@@ -275,6 +371,51 @@ impl LazyFrame {
         }
     }
 
+    /// Return a String describing the optimized logical plan, annotated with the engine that
+    /// [`Engine::Auto`] would run it on and why.
+    ///
+    /// Returns `Err` if optimizing the logical plan fails.
+    pub fn explain_auto_engine(&self) -> PolarsResult<String> {
+        let plan = self.clone()._describe_to_alp_optimized()?;
+        let selection = select_engine(&plan)?;
+        Ok(format!("{}\n{selection}", plan.describe()))
+    }
+
+    /// Return the graph of the physical plan the streaming engine would run, as a Graphviz DOT
+    /// graph or a Mermaid flowchart.
+    ///
+    /// Unlike [`LazyFrame::explain`], this shows the streaming nodes the plan is lowered to, the
+    /// pushdowns each scan got, the ports of the streams between nodes and which nodes run in
+    /// parallel, serially or fall back to the in-memory engine.
+    #[cfg(feature = "new_streaming")]
+    pub fn explain_streaming(&self, format: PhysicalPlanFormat) -> PolarsResult<String> {
+        let mut lf = self.clone().with_new_streaming(true);
+        if !matches!(lf.logical_plan, DslPlan::Sink { .. }) {
+            lf.logical_plan = DslPlan::Sink {
+                input: Arc::new(lf.logical_plan),
+                payload: SinkType::Memory,
+            };
+        }
+        let mut alp_plan = lf.to_alp_optimized()?;
+        polars_stream::visualize_physical_plan(
+            alp_plan.lp_top,
+            &mut alp_plan.lp_arena,
+            &mut alp_plan.expr_arena,
+            format,
+        )
+    }
+
+    /// Return the optimized logical plan as a [Substrait](https://substrait.io) plan, in the JSON
+    /// encoding of its protobuf messages, so it can be executed by other engines.
+    ///
+    /// Returns `Err` if optimizing the logical plan fails or if it has nodes or expressions that
+    /// can't be expressed in Substrait.
+    #[cfg(feature = "substrait")]
+    pub fn to_substrait(&self) -> PolarsResult<String> {
+        let plan = self.clone().to_alp_optimized()?.to_substrait()?;
+        Ok(plan.to_string())
+    }
+
     /// Add a sort operation to the logical plan.
     ///
     /// Sorts the LazyFrame by the column name specified using the provided options.
@@ -612,31 +753,13 @@ impl LazyFrame {
             opt_state &= !OptFlags::COMM_SUBPLAN_ELIM;
         }
 
-        #[cfg(feature = "cse")]
-        if new_streaming {
-            // The new streaming engine can't deal with the way the common
-            // subexpression elimination adds length-incorrect with_columns.
-            opt_state &= !OptFlags::COMM_SUBEXPR_ELIM;
-        }
-
         let lp_top = optimize(
             self.logical_plan,
             opt_state,
             lp_arena,
             expr_arena,
             scratch,
-            Some(&|expr, expr_arena, schema| {
-                let phys_expr = create_physical_expr(
-                    expr,
-                    Context::Default,
-                    expr_arena,
-                    schema,
-                    &mut ExpressionConversionState::new(true),
-                )
-                .ok()?;
-                let io_expr = phys_expr_to_io_expr(phys_expr);
-                Some(io_expr)
-            }),
+            Some(&eval_io_expr),
         )?;
 
         if streaming {
@@ -737,7 +860,16 @@ impl LazyFrame {
     /// `engine`.
     ///
     /// The query is optimized prior to execution.
-    pub fn collect_with_engine(mut self, mut engine: Engine) -> PolarsResult<DataFrame> {
+    pub fn collect_with_engine(self, engine: Engine) -> PolarsResult<DataFrame> {
+        self.collect_with_engine_impl(engine, None)
+    }
+
+    /// Runs the query, stopping it once the `cancel_token` is set.
+    fn collect_with_engine_impl(
+        mut self,
+        mut engine: Engine,
+        cancel_token: Option<CancelToken>,
+    ) -> PolarsResult<DataFrame> {
         let payload = if let DslPlan::Sink { payload, .. } = &self.logical_plan {
             payload.clone()
         } else {
@@ -748,12 +880,21 @@ impl LazyFrame {
             SinkType::Memory
         };
 
-        // Default engine for collect is InMemory, sink_* is Streaming
+        // Default engine for sink_* is Streaming, for collect it depends on the plan.
+        let mut auto_plan = None;
         if engine == Engine::Auto {
             engine = match payload {
                 #[cfg(feature = "new_streaming")]
                 SinkType::File { .. } | SinkType::Partition { .. } => Engine::Streaming,
-                _ => Engine::InMemory,
+                _ => {
+                    let plan = self.clone().to_alp_optimized()?;
+                    let selection = select_engine(&plan)?;
+                    if polars_core::config::verbose() {
+                        eprintln!("{selection}");
+                    }
+                    auto_plan = Some(plan);
+                    selection.engine
+                },
             };
         }
         // Gpu uses some hacks to dispatch.
@@ -762,7 +903,7 @@ impl LazyFrame {
         }
 
         #[cfg(feature = "new_streaming")]
-        {
+        if cancel_token.is_none() {
             if let Some(result) = self.try_new_streaming_if_requested() {
                 return result.map(|v| v.unwrap());
             }
@@ -776,16 +917,23 @@ impl LazyFrame {
             Engine::OldStreaming => feature_gated!("streaming", self = self.with_streaming(true)),
             _ => {},
         }
-        let mut alp_plan = self.clone().to_alp_optimized()?;
 
         match engine {
             Engine::Auto | Engine::Streaming => feature_gated!("new_streaming", {
+                // The plan optimized to select the engine is lowered as is.
+                let plan = match auto_plan {
+                    Some(mut plan) => Arc::new(polars_stream::StreamingPlan::lower(
+                        plan.lp_top,
+                        &mut plan.lp_arena,
+                        &mut plan.expr_arena,
+                    )?),
+                    None => self.to_streaming_plan()?,
+                };
                 let string_cache_hold = StringCacheHolder::hold();
-                let result = polars_stream::run_query(
-                    alp_plan.lp_top,
-                    &mut alp_plan.lp_arena,
-                    &mut alp_plan.expr_arena,
-                );
+                let result = plan.run(QueryOptions {
+                    cancel_token,
+                    ..Default::default()
+                });
                 drop(string_cache_hold);
                 result.map(|v| v.unwrap())
             }),
@@ -797,12 +945,20 @@ impl LazyFrame {
                 Err(polars_err!(InvalidOperation: "sink is not supported for the gpu engine"))
             },
             Engine::InMemory => {
+                // The plan optimized to select the engine can be reused.
+                let mut alp_plan = match auto_plan {
+                    Some(plan) => plan,
+                    None => self.to_alp_optimized()?,
+                };
                 let mut physical_plan = create_physical_plan(
                     alp_plan.lp_top,
                     &mut alp_plan.lp_arena,
                     &mut alp_plan.expr_arena,
                 )?;
                 let mut state = ExecutionState::new();
+                if let Some(cancel_token) = cancel_token {
+                    state.set_cancel_token(cancel_token);
+                }
                 physical_plan.execute(&mut state)
             },
             Engine::OldStreaming => {
@@ -813,6 +969,9 @@ impl LazyFrame {
                     is_streaming,
                     ComputeError: format!("cannot run the whole query in a streaming order")
                 );
+                if let Some(cancel_token) = cancel_token {
+                    state.set_cancel_token(cancel_token);
+                }
                 physical_plan.execute(&mut state)
             },
         }
@@ -988,6 +1147,122 @@ impl LazyFrame {
         self._profile_post_opt(|_, _, _, _| Ok(()))
     }
 
+    /// Collect a LazyFrame and the diagnostics raised while running it.
+    ///
+    /// This will run the query and return a tuple containing the materialized DataFrame and a
+    /// DataFrame with a `kind` and `message` column for each diagnostic, e.g. lossy casts or
+    /// skipped files. Diagnostics of a kind in `strict` are raised as errors instead.
+    pub fn collect_with_diagnostics(
+        self,
+        engine: Engine,
+        strict: &[DiagnosticKind],
+    ) -> PolarsResult<(DataFrame, DataFrame)> {
+        let diagnostics = Diagnostics::new(strict);
+        let out = {
+            let _holder = diagnostics.hold();
+            self.collect_with_engine(engine)?
+        };
+
+        let (kinds, messages): (Vec<_>, Vec<_>) = diagnostics
+            .take()
+            .into_iter()
+            .map(|d| (d.kind.as_str(), d.message))
+            .unzip();
+        let diagnostics_df = DataFrame::new(vec![
+            Column::new(PlSmallStr::from_static("kind"), kinds),
+            Column::new(PlSmallStr::from_static("message"), messages),
+        ])?;
+        Ok((out, diagnostics_df))
+    }
+
+    /// Collect a LazyFrame with the streaming engine, together with the runtime metrics of every
+    /// node of the streaming graph.
+    ///
+    /// The metrics can be turned into a DataFrame with [`QueryMetrics::to_df`].
+    #[cfg(feature = "new_streaming")]
+    pub fn collect_with_metrics(self) -> PolarsResult<(DataFrame, QueryMetrics)> {
+        let mut lf = self.with_new_streaming(true);
+        if !matches!(lf.logical_plan, DslPlan::Sink { .. }) {
+            lf.logical_plan = DslPlan::Sink {
+                input: Arc::new(lf.logical_plan),
+                payload: SinkType::Memory,
+            };
+        }
+        let mut alp_plan = lf.to_alp_optimized()?;
+
+        let string_cache_hold = StringCacheHolder::hold();
+        let (result, metrics) = polars_stream::run_query_with_metrics(
+            alp_plan.lp_top,
+            &mut alp_plan.lp_arena,
+            &mut alp_plan.expr_arena,
+        )?;
+        drop(string_cache_hold);
+        Ok((result.unwrap(), metrics))
+    }
+
+    /// Run the query with the streaming engine and return its physical plan, annotated with the
+    /// rows every node received and sent, the time it ran, its peak memory and the bytes it
+    /// spilled to disk. The result of the query is discarded.
+    ///
+    /// Unlike [`LazyFrame::explain`], this shows where the time is actually spent.
+    #[cfg(feature = "new_streaming")]
+    pub fn explain_analyze(&self) -> PolarsResult<String> {
+        let mut lf = self.clone().with_new_streaming(true);
+        if !matches!(lf.logical_plan, DslPlan::Sink { .. }) {
+            lf.logical_plan = DslPlan::Sink {
+                input: Arc::new(lf.logical_plan),
+                payload: SinkType::Memory,
+            };
+        }
+        let mut alp_plan = lf.to_alp_optimized()?;
+
+        let string_cache_hold = StringCacheHolder::hold();
+        let explained = polars_stream::explain_analyze(
+            alp_plan.lp_top,
+            &mut alp_plan.lp_arena,
+            &mut alp_plan.expr_arena,
+        );
+        drop(string_cache_hold);
+        explained
+    }
+
+    /// Collect a LazyFrame with the streaming engine, controlled by the given [`QueryOptions`].
+    ///
+    /// This allows e.g. the channel capacities and row group prefetching to be tuned for a single
+    /// query with [`BufferSizes`].
+    #[cfg(feature = "new_streaming")]
+    pub fn collect_with_options(self, options: QueryOptions) -> PolarsResult<DataFrame> {
+        let mut lf = self.with_new_streaming(true);
+        if !matches!(lf.logical_plan, DslPlan::Sink { .. }) {
+            lf.logical_plan = DslPlan::Sink {
+                input: Arc::new(lf.logical_plan),
+                payload: SinkType::Memory,
+            };
+        }
+        let plan = lf.to_streaming_plan()?;
+
+        let string_cache_hold = StringCacheHolder::hold();
+        let result = plan.run(options);
+        drop(string_cache_hold);
+        result.map(|v| v.unwrap())
+    }
+
+    /// Collect a LazyFrame with the streaming engine, binding its [`placeholder`]s to the values
+    /// in `params`.
+    ///
+    /// With the plan cache enabled the query is only optimized the first time it is collected,
+    /// later calls with other values reuse the cached plan.
+    #[cfg(feature = "new_streaming")]
+    pub fn collect_with_params(
+        self,
+        params: PlHashMap<PlSmallStr, Scalar>,
+    ) -> PolarsResult<DataFrame> {
+        self.collect_with_options(QueryOptions {
+            params,
+            ..Default::default()
+        })
+    }
+
     /// Stream a query result into a parquet file. This is useful if the final result doesn't fit
     /// into memory. This methods will return an error if the query cannot be completely done in a
     /// streaming fashion.
@@ -1905,6 +2180,88 @@ impl LazyFrame {
         self.select(vec![col(PlSmallStr::from_static("*")).null_count()])
     }
 
+    /// Compute statistics of every column: the null count, an estimate of the number of distinct
+    /// values and the minimum and maximum of orderable columns.
+    ///
+    /// If this `LazyFrame` is a file scan, the statistics are also stored in the statistics
+    /// catalog, where the optimizer uses them for cost-based decisions and
+    /// [`LazyFrame::statistics`] returns them.
+    pub fn analyze(mut self) -> PolarsResult<Arc<TableStatistics>> {
+        let schema = self.collect_schema()?;
+        let scan = resolved_scan(&self.logical_plan);
+
+        let mut exprs = vec![len()];
+        for (name, dtype) in schema.iter() {
+            let c = col(name.clone());
+            exprs.push(c.clone().null_count());
+            #[cfg(feature = "approx_unique")]
+            exprs.push(c.clone().approx_n_unique());
+            #[cfg(not(feature = "approx_unique"))]
+            exprs.push(c.clone().n_unique());
+            if dtype.is_ord() {
+                exprs.push(c.clone().min());
+                exprs.push(c.max());
+            }
+        }
+        // The statistics are read by their position, their names only have to be unique.
+        let exprs = exprs
+            .into_iter()
+            .enumerate()
+            .map(|(i, e)| e.alias(format_pl_smallstr!("{i}")))
+            .collect::<Vec<_>>();
+        let df = self.select(exprs).collect()?;
+
+        let mut values = df
+            .get_columns()
+            .iter()
+            .map(|c| PolarsResult::Ok(Scalar::new(c.dtype().clone(), c.get(0)?.into_static())));
+        let mut next_scalar = || values.next().unwrap();
+        let count = |value: Scalar| -> PolarsResult<usize> {
+            value.value().extract::<usize>().ok_or_else(|| {
+                polars_err!(ComputeError: "expected a count of the table, got {}", value.value())
+            })
+        };
+
+        let num_rows = count(next_scalar()?)?;
+        let mut columns = PlIndexMap::with_capacity(schema.len());
+        for (name, dtype) in schema.iter() {
+            let null_count = count(next_scalar()?)?;
+            let n_unique = count(next_scalar()?)?;
+            let (min, max) = if dtype.is_ord() {
+                let min = next_scalar()?;
+                let max = next_scalar()?;
+                (
+                    (!min.is_null()).then_some(min),
+                    (!max.is_null()).then_some(max),
+                )
+            } else {
+                (None, None)
+            };
+            let column = ColumnStatistics {
+                null_count,
+                n_unique,
+                min,
+                max,
+            };
+            columns.insert(name.clone(), column);
+        }
+        let statistics = Arc::new(TableStatistics { num_rows, columns });
+
+        if let Some((sources, scan_type, file_options)) = scan {
+            set_table_statistics(&sources, &scan_type, &file_options, statistics.clone());
+        }
+        Ok(statistics)
+    }
+
+    /// Get the statistics of this file scan collected by [`LazyFrame::analyze`], if any.
+    pub fn statistics(&self) -> Option<Arc<TableStatistics>> {
+        // Resolves the globs and directories of the scan.
+        let mut lf = self.clone();
+        lf.collect_schema().ok()?;
+        let (sources, scan_type, file_options) = resolved_scan(&lf.logical_plan)?;
+        get_table_statistics(&sources, &scan_type, &file_options)
+    }
+
     /// Drop non-unique rows and maintain the order of kept rows.
     ///
     /// `subset` is an optional `Vec` of column names to consider for uniqueness; if
@@ -2089,6 +2446,39 @@ impl LazyFrame {
         Self::from_logical_plan(lp, opt_state)
     }
 
+    /// Apply the [`StreamingOperator`] registered under `name` to the [`LazyFrame`].
+    ///
+    /// The streaming engine runs the operator natively on the morsels of the input, other engines
+    /// run it over the whole input at once. As the operator may be stateful no optimizations are
+    /// pushed past it. The `schema` of the output must be given if the operator changes it.
+    #[cfg(feature = "new_streaming")]
+    pub fn map_streaming_operator(
+        self,
+        name: &str,
+        schema: Option<Arc<dyn UdfSchema>>,
+    ) -> LazyFrame {
+        let opt_state = self.get_opt_state();
+        let lp = self
+            .get_plan_builder()
+            .map(
+                polars_stream::StreamingOperatorUdf::new(name),
+                AllowedOptimizations::empty(),
+                schema,
+                name.into(),
+            )
+            .build();
+        Self::from_logical_plan(lp, opt_state)
+    }
+
+    /// Apply a [`QueryHint`] to the plan of this [`LazyFrame`], e.g.
+    /// `small.hint(JoinStrategy::Broadcast)` to build the hash table of a join on `small`, or
+    /// `lf.hint(NoStreaming)` to run `lf` on the in-memory engine.
+    ///
+    /// Hints only apply to the plan up to this point and never change the result of the query.
+    pub fn hint(self, hint: impl Into<QueryHint>) -> LazyFrame {
+        self.map_private(DslFunction::Hint(hint.into()))
+    }
+
     #[cfg(feature = "python")]
     pub fn map_python(
         self,
@@ -2179,6 +2569,11 @@ impl LazyFrame {
         self.map_private(DslFunction::Unnest(cols))
     }
 
+    /// Merge this [`LazyFrame`] with `other`, both sorted on `key`, into a sorted [`LazyFrame`].
+    ///
+    /// Chained merges on the same key are executed as a single k-way merge by the streaming
+    /// engine. See [`merge_sorted`](crate::dsl::functions::merge_sorted) to merge any number of
+    /// inputs at once.
     #[cfg(feature = "merge_sorted")]
     pub fn merge_sorted<S>(self, other: LazyFrame, key: S) -> PolarsResult<LazyFrame>
     where
@@ -2565,4 +2960,39 @@ impl JoinBuilder {
 
         LazyFrame::from_logical_plan(lp, opt_state)
     }
+
+    /// Finish with a range join: match the rows where `left_on` lies between `right_lower` and
+    /// `right_upper` of the other frame.
+    ///
+    /// This is the common "event timestamp within a validity window" join. It runs as a sort
+    /// based inequality join, which is far cheaper than a cross join followed by a filter.
+    pub fn join_between(
+        mut self,
+        left_on: Expr,
+        right_lower: Expr,
+        right_upper: Expr,
+        closed: ClosedInterval,
+    ) -> LazyFrame {
+        // Join on temporary columns, so the bounds can't be confused with left columns that have
+        // the same name.
+        let key = PlSmallStr::from_static("__POLARS_BETWEEN_KEY");
+        let lower = PlSmallStr::from_static("__POLARS_BETWEEN_LOWER");
+        let upper = PlSmallStr::from_static("__POLARS_BETWEEN_UPPER");
+        self.lf = self.lf.with_column(left_on.alias(key.clone()));
+        self.other = self.other.map(|other| {
+            other.with_columns([
+                right_lower.alias(lower.clone()),
+                right_upper.alias(upper.clone()),
+            ])
+        });
+
+        let (x, lo, hi) = (col(key.clone()), col(lower.clone()), col(upper.clone()));
+        let predicates = match closed {
+            ClosedInterval::Both => vec![x.clone().gt_eq(lo), x.lt_eq(hi)],
+            ClosedInterval::Left => vec![x.clone().gt_eq(lo), x.lt(hi)],
+            ClosedInterval::Right => vec![x.clone().gt(lo), x.lt_eq(hi)],
+            ClosedInterval::None => vec![x.clone().gt(lo), x.lt(hi)],
+        };
+        self.join_where(predicates).drop([key, lower, upper])
+    }
 }
@@ -1,8 +1,8 @@
 //! Module containing implementation of the pivot operation.
 //!
-//! Polars lazy does not implement a pivot because it is impossible to know the schema without
-//! materializing the whole dataset. This makes a pivot quite a terrible operation for performant
-//! workflows. An optimization can never be pushed down passed a pivot.
+//! The schema of a pivot depends on the values of the pivoted columns, so it can't be known
+//! without materializing them. A lazy pivot therefore takes these values up front, or collects
+//! them in a separate pass, after which the pivot is a plain group-by aggregation.
 //!
 //! We can do a pivot on an eager `DataFrame` as that is already materialized. The code for the
 //! pivot is here, because we want to be able to pass expressions to the pivot operation.
@@ -10,7 +10,9 @@
 
 use polars_core::frame::group_by::expr::PhysicalAggExpr;
 use polars_core::prelude::*;
+use polars_ops::frame::MaintainOrderJoin;
 use polars_ops::pivot::PivotAgg;
+use polars_utils::format_pl_smallstr;
 
 use crate::physical_plan::exotic::{prepare_eval_expr, prepare_expression_for_context};
 use crate::prelude::*;
@@ -88,3 +90,199 @@ where
     });
     polars_ops::pivot::pivot_stable(df, on, index, values, sort_columns, agg_expr, separator)
 }
+
+/// Arguments of [`LazyFrame::pivot`].
+#[derive(Clone, Debug)]
+pub struct PivotArgs {
+    /// The columns of which the values become output columns.
+    pub on: Vec<PlSmallStr>,
+    /// The values of `on` that become output columns, with a column for each column of `on`.
+    /// Rows with other values are ignored. If `None`, the unique values of `on` are collected in
+    /// order of appearance.
+    pub on_columns: Option<DataFrame>,
+    /// The columns to group by. If `None`, all columns not in `on` and `values`.
+    pub index: Option<Vec<PlSmallStr>>,
+    /// The columns to aggregate. If `None`, all columns not in `on` and `index`.
+    pub values: Option<Vec<PlSmallStr>>,
+    /// The aggregation of the values of a cell, in which any column refers to these values.
+    pub agg: Expr,
+    pub maintain_order: bool,
+    pub sort_columns: bool,
+    /// Used as separator in the output column names if there are multiple `values`.
+    pub separator: PlSmallStr,
+}
+
+impl LazyFrame {
+    /// Pivot the frame from long to wide format, like [`pivot_stable`] does for a [`DataFrame`].
+    ///
+    /// Every cell is aggregated by a single group-by on the `index` and `on` columns, which can
+    /// run on the streaming engine. The aggregated cells, of which there are at most as many as
+    /// output values, are then spread over the output columns with a gather per column. If
+    /// `on_columns` isn't given, the unique values of `on` are collected first, which is an extra
+    /// pass over the data.
+    pub fn pivot(mut self, args: PivotArgs) -> PolarsResult<LazyFrame> {
+        let PivotArgs {
+            on,
+            on_columns,
+            index,
+            values,
+            agg,
+            maintain_order,
+            sort_columns,
+            separator,
+        } = args;
+        polars_ensure!(!on.is_empty(), InvalidOperation: "`on` cannot be zero length");
+
+        let schema = self.collect_schema()?;
+        let remaining = |exclude: &[PlSmallStr]| -> Vec<PlSmallStr> {
+            schema
+                .iter_names()
+                .filter(|name| !on.contains(name) && !exclude.contains(name))
+                .cloned()
+                .collect()
+        };
+        let (index, values) = match (index, values) {
+            (Some(index), Some(values)) => (index, values),
+            (Some(index), None) => {
+                let values = remaining(&index);
+                (index, values)
+            },
+            (None, Some(values)) => (remaining(&values), values),
+            (None, None) => polars_bail!(
+                InvalidOperation: "`index` and `values` cannot both be None in `pivot` operation"
+            ),
+        };
+        polars_ensure!(!index.is_empty(), InvalidOperation: "`index` cannot be zero length");
+
+        let on_columns = match on_columns {
+            Some(df) => {
+                // Name the output columns as if the values were taken from the frame.
+                let columns = on
+                    .iter()
+                    .map(|name| {
+                        let dtype = schema.try_get(name)?;
+                        df.column(name)?.strict_cast(dtype)
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                DataFrame::new(columns)?
+            },
+            None => self
+                .clone()
+                .select(on.iter().map(|name| col(name.clone())).collect::<Vec<_>>())
+                .unique_stable(None, UniqueKeepStrategy::First)
+                .collect()?,
+        };
+
+        // Name the output columns like an eager pivot does.
+        let headers = if on.len() == 1 {
+            on_columns.get_columns()[0].cast(&DataType::String)?
+        } else {
+            let fields = on_columns.get_columns();
+            StructChunked::from_columns(PlSmallStr::EMPTY, on_columns.height(), fields)?
+                .into_column()
+                .cast(&DataType::String)?
+        };
+        let mut headers = headers
+            .str()?
+            .iter()
+            .enumerate()
+            .map(|(key, header)| (key, PlSmallStr::from(header.unwrap_or("null"))))
+            .collect::<Vec<_>>();
+        if sort_columns {
+            headers.sort_by(|a, b| a.1.cmp(&b.1));
+        }
+
+        // Number the output columns, which also drops the rows with other values of `on`.
+        let key_name = PlSmallStr::from_static("__POLARS_PIVOT_KEY");
+        let keys = on_columns.with_row_index(key_name.clone(), None)?.lazy();
+        let on_exprs = on.iter().map(|name| col(name.clone())).collect::<Vec<_>>();
+        let mut join_args = JoinArgs::new(JoinType::Inner);
+        join_args.nulls_equal = true;
+        if maintain_order {
+            join_args.maintain_order = MaintainOrderJoin::Left;
+        }
+        let cells = self.join(keys, &on_exprs, &on_exprs, join_args);
+
+        let aggs = values
+            .iter()
+            .map(|value| {
+                agg.clone()
+                    .map_expr(|e| match e {
+                        Expr::Column(_) | Expr::Nth(_) => col(value.clone()),
+                        Expr::Len => col(value.clone()).len(),
+                        e => e,
+                    })
+                    .alias(value.clone())
+            })
+            .collect::<Vec<_>>();
+        let by = index
+            .iter()
+            .cloned()
+            .chain([key_name.clone()])
+            .map(col)
+            .collect::<Vec<_>>();
+        let mut cells = if maintain_order {
+            cells.group_by_stable(by)
+        } else {
+            cells.group_by(by)
+        }
+        .agg(aggs);
+
+        let cell_schema = cells.collect_schema()?;
+        let mut output_schema = Schema::with_capacity(index.len() + values.len() * headers.len());
+        for name in &index {
+            output_schema.with_column(name.clone(), cell_schema.try_get(name)?.clone());
+        }
+        let mut output_columns = Vec::with_capacity(values.len() * headers.len());
+        for value in &values {
+            let dtype = cell_schema.try_get(value)?;
+            for (key, header) in &headers {
+                let name = if values.len() > 1 {
+                    format_pl_smallstr!("{value}{separator}{header}")
+                } else {
+                    header.clone()
+                };
+                output_schema.with_column(name.clone(), dtype.clone());
+                output_columns.push((value.clone(), *key, name));
+            }
+        }
+        let output_schema = Arc::new(output_schema);
+
+        let n_keys = on_columns.height();
+        let function = move |cells: DataFrame| {
+            // The rows of the cells of every output row, per output column.
+            let by = cells.group_by_stable(index.iter().cloned())?;
+            let groups = by.get_groups();
+            let cell_keys = cells.column(&key_name)?.idx()?;
+            let mut rows = vec![vec![None; groups.len()]; n_keys];
+            for (i, group) in groups.iter().enumerate() {
+                let mut place = |row: IdxSize| {
+                    if let Some(key) = cell_keys.get(row as usize) {
+                        rows[key as usize][i] = Some(row);
+                    }
+                };
+                match group {
+                    GroupsIndicator::Idx((_, idx)) => idx.iter().copied().for_each(&mut place),
+                    GroupsIndicator::Slice([first, len]) => (first..first + len).for_each(place),
+                }
+            }
+            let rows = rows
+                .into_iter()
+                .map(|rows| IdxCa::from_iter_options(PlSmallStr::EMPTY, rows.into_iter()))
+                .collect::<Vec<_>>();
+
+            let mut columns = by.keys();
+            for (value, key, name) in &output_columns {
+                let column = cells.column(value)?.take(&rows[*key])?;
+                columns.push(column.with_name(name.clone()));
+            }
+            DataFrame::new(columns)
+        };
+        Ok(cells.map(
+            function,
+            AllowedOptimizations::default(),
+            Some(Arc::new(move |_: &Schema| Ok(output_schema.clone()))),
+            Some("PIVOT"),
+        ))
+    }
+}
@@ -0,0 +1,283 @@
+//! A process-level cache of the physical plans of the streaming engine, so that running the same
+//! query again skips its optimization and lowering.
+//!
+//! Plans are keyed by their IR before optimization, the optimization flags and the versions of the
+//! registered optimization rules and the table statistics; a plan is only reused if its IR is
+//! equal to the IR of the query, not only its hash. Queries on in-memory data aren't cached, as
+//! the cache would keep the data alive. The cache is disabled unless `POLARS_PLAN_CACHE_SIZE` is
+//! set or [`set_plan_cache_capacity`] is called. Cached plans are not invalidated if the files a
+//! query scans change, call [`clear_plan_cache`] instead.
+#[cfg(feature = "cse")]
+use std::hash::{BuildHasher, Hasher};
+use std::sync::{Arc, LazyLock, Mutex};
+
+use polars_core::prelude::*;
+#[cfg(feature = "parquet")]
+use polars_io::parquet::metadata::FileMetadataRef;
+use polars_plan::plans::optimization_rules_version;
+use polars_plan::plans::statistics::table_statistics_version;
+#[cfg(feature = "cse")]
+use polars_plan::plans::visitor::{hash_ir_plan, ir_plan_eq};
+use polars_stream::StreamingPlan;
+use polars_utils::aliases::PlRandomState;
+
+use super::*;
+
+static PLAN_CACHE: LazyLock<Mutex<PlanCache>> = LazyLock::new(|| {
+    Mutex::new(PlanCache {
+        capacity: None,
+        random_state: PlRandomState::default(),
+        plans: Vec::new(),
+    })
+});
+
+/// Reads the capacity of the plan cache from `POLARS_PLAN_CACHE_SIZE`, zero if it isn't set.
+fn get_plan_cache_size() -> PolarsResult<usize> {
+    match std::env::var("POLARS_PLAN_CACHE_SIZE") {
+        Ok(size) => size.parse().map_err(
+            |_| polars_err!(ComputeError: "could not parse 'POLARS_PLAN_CACHE_SIZE' env var"),
+        ),
+        Err(_) => Ok(0),
+    }
+}
+
+/// The query a cached plan was created for, as it was before optimization.
+struct PlanKey {
+    hash: u64,
+    lp_top: Node,
+    lp_arena: Arena<IR>,
+    expr_arena: Arena<AExpr>,
+    opt_flags: OptFlags,
+    rules_version: u64,
+    statistics_version: u64,
+    /// The metadata the sort order of Parquet scans is taken from, which isn't compared as part
+    /// of the IR. Only set if `OptFlags::SORTED_METADATA` is on.
+    #[cfg(feature = "parquet")]
+    sorted_metadata: Vec<FileMetadataRef>,
+}
+
+impl PlanKey {
+    #[cfg(feature = "cse")]
+    fn is_equal(&self, other: &Self) -> bool {
+        #[cfg(feature = "parquet")]
+        let same_metadata = self.sorted_metadata.len() == other.sorted_metadata.len()
+            && self
+                .sorted_metadata
+                .iter()
+                .zip(&other.sorted_metadata)
+                .all(|(l, r)| Arc::ptr_eq(l, r));
+        #[cfg(not(feature = "parquet"))]
+        let same_metadata = true;
+
+        self.hash == other.hash
+            && self.opt_flags.bits() == other.opt_flags.bits()
+            && self.rules_version == other.rules_version
+            && self.statistics_version == other.statistics_version
+            && same_metadata
+            && ir_plan_eq(
+                self.lp_top,
+                (&self.lp_arena, &self.expr_arena),
+                other.lp_top,
+                (&other.lp_arena, &other.expr_arena),
+            )
+    }
+
+    #[cfg(not(feature = "cse"))]
+    fn is_equal(&self, _other: &Self) -> bool {
+        false
+    }
+}
+
+struct PlanCache {
+    /// The maximum number of plans, zero disables the cache. Read from `POLARS_PLAN_CACHE_SIZE`
+    /// on first use unless it was set with [`set_plan_cache_capacity`].
+    capacity: Option<usize>,
+    random_state: PlRandomState,
+    /// The plans in the order they were last used.
+    plans: Vec<(PlanKey, Arc<StreamingPlan>)>,
+}
+
+impl PlanCache {
+    fn capacity(&mut self) -> PolarsResult<usize> {
+        match self.capacity {
+            Some(capacity) => Ok(capacity),
+            None => {
+                let capacity = get_plan_cache_size()?;
+                self.capacity = Some(capacity);
+                Ok(capacity)
+            },
+        }
+    }
+
+    fn get(&mut self, key: &PlanKey) -> Option<Arc<StreamingPlan>> {
+        let idx = self.plans.iter().position(|(k, _)| k.is_equal(key))?;
+        let entry = self.plans.remove(idx);
+        let plan = entry.1.clone();
+        self.plans.push(entry);
+        Some(plan)
+    }
+
+    fn insert(&mut self, key: PlanKey, plan: Arc<StreamingPlan>) {
+        if let Some(idx) = self.plans.iter().position(|(k, _)| k.is_equal(&key)) {
+            self.plans.remove(idx);
+        }
+        self.plans.push((key, plan));
+        self.evict();
+    }
+
+    fn evict(&mut self) {
+        let capacity = self.capacity.unwrap_or(0);
+        if self.plans.len() > capacity {
+            self.plans.drain(..self.plans.len() - capacity);
+        }
+    }
+}
+
+/// Whether the plan below `root` scans in-memory data, such as `DataFrame`s or buffers.
+#[cfg(feature = "cse")]
+fn scans_in_memory_data(root: Node, lp_arena: &Arena<IR>) -> bool {
+    lp_arena.iter(root).any(|(_, ir)| match ir {
+        IR::DataFrameScan { .. } => true,
+        IR::Scan { sources, .. } => sources.as_paths().is_none(),
+        _ => false,
+    })
+}
+
+/// The metadata of the Parquet scans below `root` that the optimizer takes the sort order from.
+#[cfg(feature = "parquet")]
+fn sorted_metadata(root: Node, lp_arena: &Arena<IR>, opt_flags: OptFlags) -> Vec<FileMetadataRef> {
+    if !opt_flags.sorted_metadata() {
+        return Vec::new();
+    }
+    lp_arena
+        .iter(root)
+        .filter_map(|(_, ir)| match ir {
+            IR::Scan { scan_type, .. } => match scan_type.as_ref() {
+                FileScan::Parquet {
+                    metadata: Some(metadata),
+                    ..
+                } => Some(metadata.clone()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+/// Sets the maximum number of physical plans the process-level plan cache holds, evicting the
+/// least recently used plans. A capacity of zero disables the cache.
+pub fn set_plan_cache_capacity(capacity: usize) {
+    let mut cache = PLAN_CACHE.lock().unwrap();
+    cache.capacity = Some(capacity);
+    cache.evict();
+}
+
+/// Removes all plans from the process-level plan cache.
+pub fn clear_plan_cache() {
+    PLAN_CACHE.lock().unwrap().plans.clear();
+}
+
+/// Sets the capacity of the process-level plan cache while it is alive ([RAII]), restoring the
+/// previous capacity and removing the cached plans on drop. Tests that use the cache hold one, so
+/// that they don't run at the same time or leave the cache enabled for other tests.
+///
+/// [RAII]: https://en.wikipedia.org/wiki/Resource_acquisition_is_initialization
+#[cfg(test)]
+pub(crate) struct PlanCacheCapacityGuard {
+    previous: Option<usize>,
+    _lock: std::sync::MutexGuard<'static, ()>,
+}
+
+#[cfg(test)]
+impl PlanCacheCapacityGuard {
+    pub(crate) fn new(capacity: usize) -> Self {
+        static LOCK: Mutex<()> = Mutex::new(());
+        let lock = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let mut cache = PLAN_CACHE.lock().unwrap();
+        let previous = cache.capacity.replace(capacity);
+        cache.evict();
+        Self {
+            previous,
+            _lock: lock,
+        }
+    }
+}
+
+#[cfg(test)]
+impl Drop for PlanCacheCapacityGuard {
+    fn drop(&mut self) {
+        let mut cache = PLAN_CACHE.lock().unwrap_or_else(|e| e.into_inner());
+        cache.capacity = self.previous;
+        cache.plans.clear();
+    }
+}
+
+impl LazyFrame {
+    /// Optimizes and lowers the query for the streaming engine, reusing the plan of an equal
+    /// query from the plan cache if it is enabled.
+    pub(crate) fn to_streaming_plan(mut self) -> PolarsResult<Arc<StreamingPlan>> {
+        let (mut lp_arena, mut expr_arena) = self.get_arenas();
+        let mut opt_flags = self.opt_state;
+        let lp_top = to_alp_for_optimization(
+            self.logical_plan,
+            &mut opt_flags,
+            &mut lp_arena,
+            &mut expr_arena,
+        )?;
+
+        let (capacity, random_state) = {
+            let mut cache = PLAN_CACHE.lock().unwrap();
+            (cache.capacity()?, cache.random_state)
+        };
+        // Plans with user-defined functions can't be compared, so they aren't cached.
+        #[cfg(feature = "cse")]
+        let hash = if capacity > 0 && !scans_in_memory_data(lp_top, &lp_arena) {
+            let mut hasher = random_state.build_hasher();
+            hash_ir_plan(lp_top, &lp_arena, &expr_arena, &mut hasher).map(|()| hasher.finish())
+        } else {
+            None
+        };
+        #[cfg(not(feature = "cse"))]
+        let hash: Option<u64> = {
+            let _ = (capacity, random_state);
+            None
+        };
+        // The arenas are optimized in place, so the key keeps a copy of the plan.
+        let key = hash.map(|hash| PlanKey {
+            hash,
+            lp_top,
+            lp_arena: lp_arena.clone(),
+            expr_arena: expr_arena.clone(),
+            opt_flags,
+            rules_version: optimization_rules_version(),
+            statistics_version: table_statistics_version(),
+            #[cfg(feature = "parquet")]
+            sorted_metadata: sorted_metadata(lp_top, &lp_arena, opt_flags),
+        });
+
+        if let Some(plan) = key
+            .as_ref()
+            .and_then(|key| PLAN_CACHE.lock().unwrap().get(key))
+        {
+            return Ok(plan);
+        }
+
+        let lp_top = optimize_ir(
+            lp_top,
+            opt_flags,
+            &mut lp_arena,
+            &mut expr_arena,
+            &mut vec![],
+            Some(&eval_io_expr),
+        )?;
+        let plan = Arc::new(StreamingPlan::lower(
+            lp_top,
+            &mut lp_arena,
+            &mut expr_arena,
+        )?);
+        if let Some(key) = key {
+            PLAN_CACHE.lock().unwrap().insert(key, plan.clone());
+        }
+        Ok(plan)
+    }
+}
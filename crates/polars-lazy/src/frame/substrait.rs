@@ -0,0 +1,907 @@
+//! Conversion of a [Substrait](https://substrait.io) plan, in the JSON encoding of its protobuf
+//! messages, to a [`LazyFrame`].
+//!
+//! This supports the relations and expressions that the Substrait producer of `polars-plan`
+//! emits: reads of Parquet and IPC files, virtual and named tables, filters, projections, joins,
+//! aggregations, sorts, fetches and unions.
+
+use std::path::PathBuf;
+
+use polars_core::error::to_compute_err;
+use polars_core::prelude::*;
+use polars_ops::frame::JoinCoalesce;
+use polars_utils::format_pl_smallstr;
+use serde_json::Value;
+
+use crate::prelude::*;
+
+/// The suffix of the columns of the right input of a join that have the same name as a column
+/// of the left input.
+const JOIN_SUFFIX: &str = "_right";
+
+impl LazyFrame {
+    /// Create a [`LazyFrame`] from a [Substrait](https://substrait.io) plan, in the JSON encoding
+    /// of its protobuf messages, so that Polars can execute plans of other tools.
+    ///
+    /// Named tables are looked up in `tables`. The resulting `LazyFrame` runs on the streaming
+    /// engine.
+    pub fn from_substrait(
+        plan: &str,
+        tables: &PlHashMap<PlSmallStr, LazyFrame>,
+    ) -> PolarsResult<LazyFrame> {
+        let plan: Value = serde_json::from_str(plan).map_err(to_compute_err)?;
+
+        let mut functions = PlHashMap::new();
+        for extension in plan["extensions"].as_array().into_iter().flatten() {
+            let function = &extension["extensionFunction"];
+            if let Some(name) = function["name"].as_str() {
+                // Compound names include the signature, e.g. `equal:any_any`.
+                let name = name.split(':').next().unwrap();
+                functions.insert(int(&function["functionAnchor"])?, name.to_string());
+            }
+        }
+        let consumer = SubstraitConsumer { functions, tables };
+
+        let Some(root) = plan["relations"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .find_map(|relation| relation.get("root"))
+        else {
+            polars_bail!(ComputeError: "Substrait plan has no root relation");
+        };
+        let rel = consumer.rel(&root["input"])?;
+        let lf = consumer.rename_root(rel, &root["names"])?;
+
+        #[cfg(feature = "new_streaming")]
+        let lf = lf.with_new_streaming(true);
+        Ok(lf)
+    }
+}
+
+/// A converted relation, with the names of its output fields in order.
+struct Rel {
+    lf: LazyFrame,
+    names: Vec<PlSmallStr>,
+}
+
+struct SubstraitConsumer<'a> {
+    /// The function names by their anchor.
+    functions: PlHashMap<i64, String>,
+    tables: &'a PlHashMap<PlSmallStr, LazyFrame>,
+}
+
+impl SubstraitConsumer<'_> {
+    fn rel(&self, rel: &Value) -> PolarsResult<Rel> {
+        let Some((kind, rel)) = single_entry(rel) else {
+            polars_bail!(ComputeError: "invalid Substrait relation: {}", rel);
+        };
+        let out = match kind {
+            "read" => self.read(rel)?,
+            "filter" => {
+                let input = self.rel(&rel["input"])?;
+                let predicate = self.expression(&rel["condition"], &input.names)?;
+                Rel {
+                    lf: input.lf.filter(predicate),
+                    names: input.names,
+                }
+            },
+            "project" => {
+                let input = self.rel(&rel["input"])?;
+                let mut names = input.names.clone();
+                let mut exprs = vec![];
+                for expression in rel["expressions"].as_array().into_iter().flatten() {
+                    let name = unique_name(&names, "expr");
+                    exprs.push(
+                        self.expression(expression, &input.names)?
+                            .alias(name.clone()),
+                    );
+                    names.push(name);
+                }
+                Rel {
+                    lf: input.lf.with_columns(exprs),
+                    names,
+                }
+            },
+            "fetch" => {
+                let input = self.rel(&rel["input"])?;
+                let offset = match rel.get("offsetExpr") {
+                    Some(offset) => int(&offset["literal"]["i64"])?,
+                    None => int(&rel["offset"])?,
+                };
+                let count = match rel.get("countExpr") {
+                    Some(count) => int(&count["literal"]["i64"])?,
+                    None => int(&rel["count"])?,
+                };
+                // A count of -1 returns all rows.
+                let len = IdxSize::try_from(count).unwrap_or(IdxSize::MAX);
+                Rel {
+                    lf: input.lf.slice(offset, len),
+                    names: input.names,
+                }
+            },
+            "sort" => {
+                let input = self.rel(&rel["input"])?;
+                let mut by = vec![];
+                let mut descending = vec![];
+                let mut nulls_last = vec![];
+                for sort in rel["sorts"].as_array().into_iter().flatten() {
+                    by.push(self.expression(&sort["expr"], &input.names)?);
+                    let (desc, last) = match sort["direction"].as_str() {
+                        Some("SORT_DIRECTION_ASC_NULLS_FIRST") => (false, false),
+                        Some("SORT_DIRECTION_ASC_NULLS_LAST") | None => (false, true),
+                        Some("SORT_DIRECTION_DESC_NULLS_FIRST") => (true, false),
+                        Some("SORT_DIRECTION_DESC_NULLS_LAST") => (true, true),
+                        Some(direction) => polars_bail!(
+                            InvalidOperation: "Substrait sort direction {} is not supported",
+                            direction
+                        ),
+                    };
+                    descending.push(desc);
+                    nulls_last.push(last);
+                }
+                let options = SortMultipleOptions::default()
+                    .with_order_descending_multi(descending)
+                    .with_nulls_last_multi(nulls_last);
+                Rel {
+                    lf: input.lf.sort_by_exprs(by, options),
+                    names: input.names,
+                }
+            },
+            "aggregate" => self.aggregate(rel)?,
+            "join" => self.join(rel)?,
+            "cross" => {
+                let left = self.rel(&rel["left"])?;
+                let right = self.rel(&rel["right"])?;
+                let names = join_names(&left.names, &right.names);
+                Rel {
+                    lf: left.lf.cross_join(right.lf, Some(JOIN_SUFFIX.into())),
+                    names,
+                }
+            },
+            "set" => {
+                let mut inputs = rel["inputs"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .map(|input| self.rel(input));
+                let Some(first) = inputs.next().transpose()? else {
+                    polars_bail!(ComputeError: "Substrait set relation has no inputs");
+                };
+                let mut lfs = vec![first.lf];
+                for input in inputs {
+                    let input = input?;
+                    polars_ensure!(
+                        input.names.len() == first.names.len(),
+                        ComputeError: "inputs of a Substrait set relation have different schemas"
+                    );
+                    // The fields of the inputs are matched by position.
+                    let exprs = input
+                        .names
+                        .iter()
+                        .zip(&first.names)
+                        .map(|(name, first)| col(name.clone()).alias(first.clone()))
+                        .collect::<Vec<_>>();
+                    lfs.push(input.lf.select(exprs));
+                }
+                let lf = concat(lfs, UnionArgs::default())?;
+                let lf = match rel["op"].as_str() {
+                    Some("SET_OP_UNION_ALL") => lf,
+                    Some("SET_OP_UNION_DISTINCT") => lf.unique(None, UniqueKeepStrategy::Any),
+                    op => polars_bail!(
+                        InvalidOperation: "Substrait set operation {:?} is not supported", op
+                    ),
+                };
+                Rel {
+                    lf,
+                    names: first.names,
+                }
+            },
+            _ => polars_bail!(InvalidOperation: "Substrait {} relation is not supported", kind),
+        };
+        emit(out, rel.get("common"))
+    }
+
+    fn read(&self, read: &Value) -> PolarsResult<Rel> {
+        let schema = named_struct(&read["baseSchema"])?;
+        let names = schema.iter_names_cloned().collect::<Vec<_>>();
+
+        let lf = if let Some(local_files) = read.get("localFiles") {
+            let mut paths = vec![];
+            let mut format = None;
+            for item in local_files["items"].as_array().into_iter().flatten() {
+                let Some(uri) = ["uriFile", "uriPath", "uriPathGlob"]
+                    .iter()
+                    .find_map(|key| item[*key].as_str())
+                else {
+                    polars_bail!(InvalidOperation: "Substrait file item without a path");
+                };
+                paths.push(PathBuf::from(uri.strip_prefix("file://").unwrap_or(uri)));
+                let item_format = ["parquet", "arrow"]
+                    .into_iter()
+                    .find(|format| item.get(*format).is_some());
+                polars_ensure!(
+                    format.is_none() || format == item_format,
+                    InvalidOperation: "Substrait reads of files with different formats are not \
+                    supported"
+                );
+                format = item_format;
+            }
+            let sources = ScanSources::Paths(paths.into());
+            let lf = match format {
+                #[cfg(feature = "parquet")]
+                Some("parquet") => LazyFrame::scan_parquet_sources(sources, Default::default())?,
+                #[cfg(feature = "ipc")]
+                Some("arrow") => LazyFrame::scan_ipc_sources(sources, Default::default())?,
+                _ => polars_bail!(
+                    InvalidOperation: "only Substrait reads of Parquet and IPC files are supported"
+                ),
+            };
+            lf.select(names.iter().cloned().map(col).collect::<Vec<_>>())
+        } else if let Some(virtual_table) = read.get("virtualTable") {
+            self.virtual_table(virtual_table, &schema)?.lazy()
+        } else if let Some(named_table) = read.get("namedTable") {
+            let name = named_table["names"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(|name| name.as_str())
+                .collect::<Vec<_>>()
+                .join(".");
+            let Some(lf) = self.tables.get(name.as_str()) else {
+                polars_bail!(ComputeError: "Substrait plan reads unknown table '{}'", name);
+            };
+            lf.clone()
+                .select(names.iter().cloned().map(col).collect::<Vec<_>>())
+        } else {
+            polars_bail!(InvalidOperation: "Substrait read type is not supported");
+        };
+
+        let lf = match read.get("filter") {
+            Some(filter) => lf.filter(self.expression(filter, &names)?),
+            None => lf,
+        };
+        match read.get("projection") {
+            Some(projection) => {
+                let names = projection["select"]["structItems"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .map(|item| field(&names, &item["field"]))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                Ok(Rel {
+                    lf: lf.select(names.iter().cloned().map(col).collect::<Vec<_>>()),
+                    names,
+                })
+            },
+            None => Ok(Rel { lf, names }),
+        }
+    }
+
+    fn virtual_table(&self, virtual_table: &Value, schema: &Schema) -> PolarsResult<DataFrame> {
+        // Rows are given as structs of literals or, in newer versions, of literal expressions.
+        let (rows, is_expression) = match virtual_table.get("expressions") {
+            Some(rows) => (rows, true),
+            None => (&virtual_table["values"], false),
+        };
+        let rows = rows.as_array().map(Vec::as_slice).unwrap_or_default();
+
+        let mut columns = schema
+            .iter()
+            .map(|(name, dtype)| Series::new_empty(name.clone(), dtype))
+            .collect::<Vec<_>>();
+        for row in rows {
+            let fields = row["fields"]
+                .as_array()
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+            polars_ensure!(
+                fields.len() == columns.len(),
+                ComputeError: "Substrait virtual table row has {} fields, expected {}",
+                fields.len(), columns.len()
+            );
+            for (column, field) in columns.iter_mut().zip(fields) {
+                let literal = if is_expression {
+                    &field["literal"]
+                } else {
+                    field
+                };
+                let value = literal_series(literal)?.cast(column.dtype())?;
+                column.append(&value)?;
+            }
+        }
+        DataFrame::new(columns.into_iter().map(Column::from).collect())
+    }
+
+    fn join(&self, join: &Value) -> PolarsResult<Rel> {
+        let left = self.rel(&join["left"])?;
+        let right = self.rel(&join["right"])?;
+        let how = match join["type"].as_str() {
+            Some("JOIN_TYPE_INNER") => JoinType::Inner,
+            Some("JOIN_TYPE_LEFT") => JoinType::Left,
+            Some("JOIN_TYPE_RIGHT") => JoinType::Right,
+            Some("JOIN_TYPE_OUTER") => JoinType::Full,
+            #[cfg(feature = "semi_anti_join")]
+            Some("JOIN_TYPE_LEFT_SEMI" | "JOIN_TYPE_SEMI") => JoinType::Semi,
+            #[cfg(feature = "semi_anti_join")]
+            Some("JOIN_TYPE_LEFT_ANTI" | "JOIN_TYPE_ANTI") => JoinType::Anti,
+            how => polars_bail!(InvalidOperation: "Substrait join type {:?} is not supported", how),
+        };
+        let names = match how {
+            #[cfg(feature = "semi_anti_join")]
+            JoinType::Semi | JoinType::Anti => left.names.clone(),
+            _ => join_names(&left.names, &right.names),
+        };
+
+        // Split the condition into equalities of a left and a right field.
+        let mut conjuncts = vec![];
+        self.split_conjunction(&join["expression"], &mut conjuncts);
+        let mut left_on = vec![];
+        let mut right_on = vec![];
+        let mut nulls_equal = None;
+        let mut is_equi_join = true;
+        for conjunct in &conjuncts {
+            let key = self.equi_join_key(conjunct, left.names.len());
+            match key {
+                Some((l, r, eq_missing)) if nulls_equal.is_none_or(|n| n == eq_missing) => {
+                    left_on.push(col(left.names[l].clone()));
+                    right_on.push(col(right.names[r].clone()));
+                    nulls_equal = Some(eq_missing);
+                },
+                _ => is_equi_join = false,
+            }
+        }
+
+        let lf = if is_equi_join && !left_on.is_empty() {
+            left.lf
+                .join_builder()
+                .with(right.lf)
+                .how(how)
+                .left_on(left_on)
+                .right_on(right_on)
+                .join_nulls(nulls_equal.unwrap_or(false))
+                .coalesce(JoinCoalesce::KeepColumns)
+                .suffix(JOIN_SUFFIX)
+                .finish()
+        } else {
+            // Other conditions can only be evaluated on the pairs of rows of an inner join.
+            polars_ensure!(
+                matches!(how, JoinType::Inner),
+                InvalidOperation: "Substrait joins that aren't inner joins must join on \
+                equalities of fields"
+            );
+            let predicate = self.expression(&join["expression"], &names)?;
+            left.lf
+                .cross_join(right.lf, Some(JOIN_SUFFIX.into()))
+                .filter(predicate)
+        };
+
+        let lf = match join.get("postJoinFilter") {
+            Some(filter) => lf.filter(self.expression(filter, &names)?),
+            None => lf,
+        };
+        Ok(Rel { lf, names })
+    }
+
+    fn split_conjunction<'v>(&self, expression: &'v Value, out: &mut Vec<&'v Value>) {
+        let function = &expression["scalarFunction"];
+        if !function.is_null() && self.function_name(function).ok() == Some("and") {
+            for argument in function["arguments"].as_array().into_iter().flatten() {
+                self.split_conjunction(&argument["value"], out);
+            }
+        } else {
+            out.push(expression);
+        }
+    }
+
+    /// The left and right field of an equality, and whether nulls are equal.
+    fn equi_join_key(&self, expression: &Value, num_left: usize) -> Option<(usize, usize, bool)> {
+        let function = expression.get("scalarFunction")?;
+        let eq_missing = match self.function_name(function).ok()? {
+            "equal" => false,
+            "is_not_distinct_from" => true,
+            _ => return None,
+        };
+        let [a, b] = function["arguments"].as_array()?.as_slice() else {
+            return None;
+        };
+        let a = field_index(&a["value"])?;
+        let b = field_index(&b["value"])?;
+        match (a < num_left, b < num_left) {
+            (true, false) => Some((a, b - num_left, eq_missing)),
+            (false, true) => Some((b, a - num_left, eq_missing)),
+            _ => None,
+        }
+    }
+
+    fn aggregate(&self, aggregate: &Value) -> PolarsResult<Rel> {
+        let input = self.rel(&aggregate["input"])?;
+        let groupings = aggregate["groupings"]
+            .as_array()
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        polars_ensure!(
+            groupings.len() <= 1,
+            InvalidOperation: "Substrait aggregations with multiple grouping sets are not supported"
+        );
+
+        // Grouping expressions are given per grouping set or, in newer versions, once for the
+        // relation and referenced by the grouping sets.
+        let keys = match groupings.first() {
+            None => vec![],
+            Some(grouping) => match grouping.get("expressionReferences") {
+                Some(references) => references
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .map(|reference| {
+                        let i = int(reference)? as usize;
+                        Ok(&aggregate["groupingExpressions"][i])
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?,
+                None => grouping["groupingExpressions"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .collect(),
+            },
+        };
+
+        let mut names = vec![];
+        let mut key_exprs = vec![];
+        for key in keys {
+            let name = match field_index(key) {
+                Some(i) if i < input.names.len() => input.names[i].clone(),
+                _ => PlSmallStr::from_static("key"),
+            };
+            let name = unique_name(&names, &name);
+            key_exprs.push(self.expression(key, &input.names)?.alias(name.clone()));
+            names.push(name);
+        }
+        let mut aggs = vec![];
+        for measure in aggregate["measures"].as_array().into_iter().flatten() {
+            let (name, expr) = self.measure(&measure["measure"], &input.names)?;
+            polars_ensure!(
+                measure.get("filter").is_none(),
+                InvalidOperation: "Substrait measures with a filter are not supported"
+            );
+            let name = unique_name(&names, name);
+            aggs.push(expr.alias(name.clone()));
+            names.push(name);
+        }
+
+        let lf = match (key_exprs.is_empty(), aggs.is_empty()) {
+            (true, _) => input.lf.select(aggs),
+            (false, true) => input
+                .lf
+                .select(key_exprs)
+                .unique(None, UniqueKeepStrategy::Any),
+            (false, false) => input.lf.group_by(key_exprs).agg(aggs),
+        };
+        Ok(Rel { lf, names })
+    }
+
+    fn measure<'f>(
+        &'f self,
+        measure: &Value,
+        names: &[PlSmallStr],
+    ) -> PolarsResult<(&'f str, Expr)> {
+        let name = self.function_name(measure)?;
+        let mut args = self.arguments(measure, names)?;
+        let distinct = measure["invocation"].as_str() == Some("AGGREGATION_INVOCATION_DISTINCT");
+        if name == "count" && args.is_empty() {
+            return Ok((name, len()));
+        }
+        polars_ensure!(
+            args.len() == 1,
+            InvalidOperation: "Substrait aggregate function {} with {} arguments is not supported",
+            name, args.len()
+        );
+        let mut arg = args.pop().unwrap();
+        if name == "count" && distinct {
+            return Ok((name, arg.drop_nulls().n_unique()));
+        }
+        if distinct {
+            arg = arg.unique();
+        }
+        let ddof = || {
+            let population = measure["options"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter(|option| option["name"] == "distribution")
+                .any(|option| option["preference"][0] == "POPULATION");
+            if population { 0 } else { 1 }
+        };
+        let expr = match name {
+            "count" => arg.count(),
+            "sum" => arg.sum(),
+            "avg" | "mean" => arg.mean(),
+            "min" => arg.min(),
+            "max" => arg.max(),
+            "std_dev" => arg.std(ddof()),
+            "variance" => arg.var(ddof()),
+            "any_value" => arg.drop_nulls().first(),
+            _ => polars_bail!(
+                InvalidOperation: "Substrait aggregate function {} is not supported", name
+            ),
+        };
+        Ok((name, expr))
+    }
+
+    fn function_name(&self, function: &Value) -> PolarsResult<&str> {
+        let anchor = int(&function["functionReference"])?;
+        match self.functions.get(&anchor) {
+            Some(name) => Ok(name),
+            None => polars_bail!(ComputeError: "Substrait function {} is not declared", anchor),
+        }
+    }
+
+    fn arguments(&self, function: &Value, names: &[PlSmallStr]) -> PolarsResult<Vec<Expr>> {
+        function["arguments"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .map(|argument| match argument.get("value") {
+                Some(value) => self.expression(value, names),
+                None => polars_bail!(
+                    InvalidOperation: "Substrait function arguments that aren't values are not \
+                    supported"
+                ),
+            })
+            .collect()
+    }
+
+    /// Converts an expression over fields with the given `names`.
+    fn expression(&self, expression: &Value, names: &[PlSmallStr]) -> PolarsResult<Expr> {
+        let Some((kind, value)) = single_entry(expression) else {
+            polars_bail!(ComputeError: "invalid Substrait expression: {}", expression);
+        };
+        let expr = match kind {
+            "selection" => {
+                let Some(i) = field_index(expression) else {
+                    polars_bail!(
+                        InvalidOperation: "only Substrait references to fields of the input are \
+                        supported"
+                    );
+                };
+                let Some(name) = names.get(i) else {
+                    polars_bail!(ComputeError: "Substrait field {} is out of bounds", i);
+                };
+                col(name.clone())
+            },
+            "literal" => {
+                let s = literal_series(value)?;
+                lit(Scalar::new(s.dtype().clone(), s.get(0)?.into_static()))
+            },
+            "scalarFunction" => {
+                let name = self.function_name(value)?;
+                let mut args = self.arguments(value, names)?.into_iter();
+                let mut arg = || {
+                    args.next().ok_or_else(|| {
+                        polars_err!(ComputeError: "missing argument of Substrait function {}", name)
+                    })
+                };
+                match name {
+                    "equal" => arg()?.eq(arg()?),
+                    "not_equal" => arg()?.neq(arg()?),
+                    "is_not_distinct_from" => arg()?.eq_missing(arg()?),
+                    "is_distinct_from" => arg()?.neq_missing(arg()?),
+                    "lt" => arg()?.lt(arg()?),
+                    "lte" => arg()?.lt_eq(arg()?),
+                    "gt" => arg()?.gt(arg()?),
+                    "gte" => arg()?.gt_eq(arg()?),
+                    "is_null" => arg()?.is_null(),
+                    "is_not_null" => arg()?.is_not_null(),
+                    "add" => arg()? + arg()?,
+                    "subtract" => arg()? - arg()?,
+                    "multiply" => arg()? * arg()?,
+                    "divide" => arg()? / arg()?,
+                    "modulus" => arg()? % arg()?,
+                    "negate" => -arg()?,
+                    "not" => arg()?.not(),
+                    "xor" => arg()?.xor(arg()?),
+                    "and" | "or" => {
+                        let mut expr = arg()?;
+                        for other in args.by_ref() {
+                            expr = match name {
+                                "and" => expr.and(other),
+                                _ => expr.or(other),
+                            };
+                        }
+                        expr
+                    },
+                    _ => polars_bail!(
+                        InvalidOperation: "Substrait function {} is not supported", name
+                    ),
+                }
+            },
+            "cast" => {
+                let input = self.expression(&value["input"], names)?;
+                let dtype = data_type(&value["type"], &mut std::iter::empty())?;
+                match value["failureBehavior"].as_str() {
+                    Some("FAILURE_BEHAVIOR_RETURN_NULL") => input.cast(dtype),
+                    _ => input.strict_cast(dtype),
+                }
+            },
+            "ifThen" => {
+                // Nest the clauses from the last one, so that the first true condition wins.
+                let mut expr = self.expression(&value["else"], names)?;
+                for clause in value["ifs"].as_array().into_iter().flatten().rev() {
+                    let condition = self.expression(&clause["if"], names)?;
+                    let then = self.expression(&clause["then"], names)?;
+                    expr = when(condition).then(then).otherwise(expr);
+                }
+                expr
+            },
+            _ => polars_bail!(InvalidOperation: "Substrait {} expression is not supported", kind),
+        };
+        Ok(expr)
+    }
+
+    /// Renames the output fields to the names of the root relation.
+    fn rename_root(&self, rel: Rel, names: &Value) -> PolarsResult<LazyFrame> {
+        let root_names = names
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|name| name.as_str().map(PlSmallStr::from))
+            .collect::<Vec<_>>();
+        if root_names.is_empty() {
+            return Ok(rel.lf);
+        }
+
+        // The names of the fields of structs follow the name of the struct.
+        let mut lf = rel.lf;
+        let mut top_level = Vec::with_capacity(rel.names.len());
+        let mut root_names = root_names.into_iter();
+        let schema = lf.collect_schema()?;
+        for dtype in schema.iter_values() {
+            let Some(name) = root_names.next() else {
+                polars_bail!(ComputeError: "Substrait root relation has too few names");
+            };
+            top_level.push(name);
+            skip_nested_names(dtype, &mut root_names);
+        }
+        let exprs = rel
+            .names
+            .into_iter()
+            .zip(top_level)
+            .map(|(name, root_name)| col(name).alias(root_name))
+            .collect::<Vec<_>>();
+        Ok(lf.select(exprs))
+    }
+}
+
+/// Selects the fields of the `emit` of the `RelCommon` of a relation.
+fn emit(rel: Rel, common: Option<&Value>) -> PolarsResult<Rel> {
+    let Some(mapping) = common.and_then(|common| common["emit"]["outputMapping"].as_array()) else {
+        return Ok(rel);
+    };
+    let mut names = Vec::with_capacity(mapping.len());
+    let mut exprs = Vec::with_capacity(mapping.len());
+    for field_value in mapping {
+        let source = field(&rel.names, field_value)?;
+        // The same field may be output more than once.
+        let name = unique_name(&names, &source);
+        exprs.push(col(source).alias(name.clone()));
+        names.push(name);
+    }
+    Ok(Rel {
+        lf: rel.lf.select(exprs),
+        names,
+    })
+}
+
+/// The names of the output of a join that keeps all columns of both inputs.
+fn join_names(left: &[PlSmallStr], right: &[PlSmallStr]) -> Vec<PlSmallStr> {
+    let mut names = left.to_vec();
+    for name in right {
+        let name = match left.contains(name) {
+            true => format_pl_smallstr!("{name}{JOIN_SUFFIX}"),
+            false => name.clone(),
+        };
+        names.push(name);
+    }
+    names
+}
+
+fn unique_name(names: &[PlSmallStr], base: &str) -> PlSmallStr {
+    let mut name = PlSmallStr::from(base);
+    let mut i = 1;
+    while names.contains(&name) {
+        name = format_pl_smallstr!("{base}_{i}");
+        i += 1;
+    }
+    name
+}
+
+fn single_entry(value: &Value) -> Option<(&str, &Value)> {
+    let object = value.as_object()?;
+    object
+        .iter()
+        .find(|(key, _)| *key != "common")
+        .map(|(key, value)| (key.as_str(), value))
+}
+
+/// Parses an integer, 64-bit integers are encoded as strings and zero may be omitted.
+fn int(value: &Value) -> PolarsResult<i64> {
+    match value {
+        Value::Null => Ok(0),
+        Value::Number(n) => n
+            .as_i64()
+            .ok_or_else(|| polars_err!(ComputeError: "invalid Substrait integer {}", n)),
+        Value::String(s) => s
+            .parse()
+            .map_err(|_| polars_err!(ComputeError: "invalid Substrait integer {}", s)),
+        _ => polars_bail!(ComputeError: "invalid Substrait integer {}", value),
+    }
+}
+
+fn field(names: &[PlSmallStr], index: &Value) -> PolarsResult<PlSmallStr> {
+    let i = int(index)? as usize;
+    match names.get(i) {
+        Some(name) => Ok(name.clone()),
+        None => polars_bail!(ComputeError: "Substrait field {} is out of bounds", i),
+    }
+}
+
+/// The index of the field an expression references, if it references a field of the input.
+fn field_index(expression: &Value) -> Option<usize> {
+    let selection = expression.get("selection")?;
+    if selection.get("outerReference").is_some() {
+        return None;
+    }
+    let field = selection.get("directReference")?.get("structField")?;
+    if field.get("child").is_some() {
+        return None;
+    }
+    int(&field["field"]).ok().map(|i| i as usize)
+}
+
+fn named_struct(named_struct: &Value) -> PolarsResult<Schema> {
+    let mut names = named_struct["names"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|name| name.as_str().map(PlSmallStr::from));
+    let types = named_struct["struct"]["types"]
+        .as_array()
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    let mut schema = Schema::with_capacity(types.len());
+    for ty in types {
+        let Some(name) = names.next() else {
+            polars_bail!(ComputeError: "Substrait schema has too few names");
+        };
+        let dtype = data_type(ty, &mut names)?;
+        schema.with_column(name, dtype);
+    }
+    Ok(schema)
+}
+
+fn skip_nested_names(dtype: &DataType, names: &mut impl Iterator<Item = PlSmallStr>) {
+    #[cfg(feature = "dtype-struct")]
+    if let DataType::Struct(fields) = dtype {
+        for field in fields {
+            names.next();
+            skip_nested_names(&field.dtype, names);
+        }
+    }
+    #[cfg(not(feature = "dtype-struct"))]
+    let _ = (dtype, names);
+}
+
+#[cfg(feature = "dtype-datetime")]
+fn time_unit(precision: &Value) -> PolarsResult<TimeUnit> {
+    match int(precision)? {
+        3 => Ok(TimeUnit::Milliseconds),
+        6 => Ok(TimeUnit::Microseconds),
+        9 => Ok(TimeUnit::Nanoseconds),
+        p => polars_bail!(InvalidOperation: "Substrait timestamp precision {} is not supported", p),
+    }
+}
+
+/// Parses a type, taking the names of the fields of structs from `names`.
+fn data_type(ty: &Value, names: &mut dyn Iterator<Item = PlSmallStr>) -> PolarsResult<DataType> {
+    let Some((kind, value)) = single_entry(ty) else {
+        polars_bail!(ComputeError: "invalid Substrait type: {}", ty);
+    };
+    #[cfg(feature = "dtype-datetime")]
+    let utc = || Some(PlSmallStr::from_static("UTC"));
+    let dtype = match kind {
+        "bool" => DataType::Boolean,
+        "i8" => DataType::Int8,
+        "i16" => DataType::Int16,
+        "i32" => DataType::Int32,
+        "i64" => DataType::Int64,
+        "fp32" => DataType::Float32,
+        "fp64" => DataType::Float64,
+        "string" | "varchar" | "fixedChar" => DataType::String,
+        "binary" | "fixedBinary" => DataType::Binary,
+        #[cfg(feature = "dtype-date")]
+        "date" => DataType::Date,
+        #[cfg(feature = "dtype-datetime")]
+        "timestamp" => DataType::Datetime(TimeUnit::Microseconds, None),
+        #[cfg(feature = "dtype-datetime")]
+        "timestampTz" => DataType::Datetime(TimeUnit::Microseconds, utc()),
+        #[cfg(feature = "dtype-datetime")]
+        "precisionTimestamp" => DataType::Datetime(time_unit(&value["precision"])?, None),
+        #[cfg(feature = "dtype-datetime")]
+        "precisionTimestampTz" => DataType::Datetime(time_unit(&value["precision"])?, utc()),
+        #[cfg(feature = "dtype-decimal")]
+        "decimal" => DataType::Decimal(
+            Some(int(&value["precision"])? as usize),
+            Some(int(&value["scale"])? as usize),
+        ),
+        "list" => DataType::List(Box::new(data_type(&value["type"], names)?)),
+        #[cfg(feature = "dtype-struct")]
+        "struct" => {
+            let types = value["types"]
+                .as_array()
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+            let fields = types
+                .iter()
+                .enumerate()
+                .map(|(i, ty)| {
+                    let name = names.next().unwrap_or_else(|| format_pl_smallstr!("f{i}"));
+                    Ok(Field::new(name, data_type(ty, names)?))
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+            DataType::Struct(fields)
+        },
+        _ => polars_bail!(InvalidOperation: "Substrait type {} is not supported", kind),
+    };
+    Ok(dtype)
+}
+
+/// Parses a literal as a `Series` of length one.
+fn literal_series(literal: &Value) -> PolarsResult<Series> {
+    let Some((kind, value)) = single_entry(literal) else {
+        polars_bail!(ComputeError: "invalid Substrait literal: {}", literal);
+    };
+    let name = PlSmallStr::from_static("literal");
+    let av = |av: AnyValue, dtype: &DataType| {
+        Series::from_any_values_and_dtype(name.clone(), &[av], dtype, true)
+    };
+    let float = |value: &Value| {
+        value
+            .as_f64()
+            .ok_or_else(|| polars_err!(ComputeError: "invalid Substrait float {}", value))
+    };
+    let s = match kind {
+        "null" => Series::full_null(name, 1, &data_type(value, &mut std::iter::empty())?),
+        "boolean" => av(
+            AnyValue::Boolean(value.as_bool().unwrap_or(false)),
+            &DataType::Boolean,
+        )?,
+        "i8" => av(AnyValue::Int64(int(value)?), &DataType::Int8)?,
+        "i16" => av(AnyValue::Int64(int(value)?), &DataType::Int16)?,
+        "i32" => av(AnyValue::Int64(int(value)?), &DataType::Int32)?,
+        "i64" => av(AnyValue::Int64(int(value)?), &DataType::Int64)?,
+        "fp32" => av(AnyValue::Float64(float(value)?), &DataType::Float32)?,
+        "fp64" => av(AnyValue::Float64(float(value)?), &DataType::Float64)?,
+        "string" | "varChar" | "fixedChar" => {
+            let Some(v) = value.as_str().or_else(|| value["value"].as_str()) else {
+                polars_bail!(ComputeError: "invalid Substrait string {}", value);
+            };
+            av(AnyValue::String(v), &DataType::String)?
+        },
+        #[cfg(feature = "dtype-date")]
+        "date" => av(AnyValue::Int64(int(value)?), &DataType::Int32)?.cast(&DataType::Date)?,
+        #[cfg(feature = "dtype-datetime")]
+        "timestamp" | "timestampTz" | "precisionTimestamp" | "precisionTimestampTz" => {
+            let (v, tu) = match kind {
+                "timestamp" | "timestampTz" => (int(value)?, TimeUnit::Microseconds),
+                _ => (int(&value["value"])?, time_unit(&value["precision"])?),
+            };
+            let tz = kind
+                .ends_with("Tz")
+                .then_some(PlSmallStr::from_static("UTC"));
+            av(AnyValue::Int64(v), &DataType::Int64)?.cast(&DataType::Datetime(tu, tz))?
+        },
+        _ => polars_bail!(InvalidOperation: "Substrait {} literal is not supported", kind),
+    };
+    Ok(s)
+}
@@ -0,0 +1,476 @@
+//! A [DataFusion](https://datafusion.apache.org) table that is backed by a [`LazyFrame`].
+//!
+//! The filters, projection and limit of a DataFusion scan are added to the plan of the
+//! `LazyFrame`, so Polars optimizes them into its own scans, and only the rows and columns that
+//! DataFusion asks for are passed on. The query runs on the streaming engine, and its morsels are
+//! passed to DataFusion as they are produced. Polars and arrow-rs arrays are exchanged through the
+//! Arrow C data interface, without copying their buffers.
+
+use std::any::Any;
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use arrow::ffi;
+use async_trait::async_trait;
+use datafusion::arrow::array::{
+    ArrayRef as ArrowRsArrayRef, RecordBatch, RecordBatchOptions, make_array,
+};
+use datafusion::arrow::datatypes::{
+    DataType as ArrowRsDataType, Field as ArrowRsField, Schema as ArrowRsSchema,
+    SchemaRef as ArrowRsSchemaRef,
+};
+use datafusion::arrow::error::ArrowError;
+use datafusion::arrow::ffi::{FFI_ArrowArray, FFI_ArrowSchema, from_ffi};
+use datafusion::catalog::Session;
+use datafusion::common::{DataFusionError, Result as DFResult, ScalarValue};
+use datafusion::datasource::{TableProvider, TableType};
+use datafusion::execution::TaskContext;
+use datafusion::logical_expr::expr::InList;
+use datafusion::logical_expr::{
+    Between, BinaryExpr, Cast, Expr as DFExpr, Operator as DFOperator, TableProviderFilterPushDown,
+    TryCast,
+};
+use datafusion::physical_expr::EquivalenceProperties;
+use datafusion::physical_plan::execution_plan::{Boundedness, EmissionType};
+use datafusion::physical_plan::stream::RecordBatchStreamAdapter;
+use datafusion::physical_plan::{
+    DisplayAs, DisplayFormatType, ExecutionPlan, Partitioning, PlanProperties,
+    SendableRecordBatchStream,
+};
+use futures::{StreamExt, stream};
+use polars_core::error::to_compute_err;
+use polars_core::prelude::*;
+use polars_plan::dsl::Engine;
+use tokio::sync::mpsc;
+
+use crate::prelude::*;
+
+/// The record batches of a scan that may be buffered before the engine waits for DataFusion.
+const BATCH_BUFFER_SIZE: usize = 2;
+
+/// Gives every scan a unique streaming operator name.
+static NEXT_SCAN_ID: AtomicU64 = AtomicU64::new(0);
+
+impl LazyFrame {
+    /// Wrap the [`LazyFrame`] in a DataFusion [`TableProvider`], so that DataFusion queries can
+    /// read it without collecting it first.
+    pub fn into_table_provider(self) -> PolarsResult<LazyFrameTable> {
+        LazyFrameTable::try_new(self)
+    }
+}
+
+/// A DataFusion [`TableProvider`] that scans a [`LazyFrame`].
+///
+/// Filters that can be expressed in Polars are pushed down exactly, so DataFusion doesn't
+/// evaluate them again; the others are left to DataFusion.
+#[derive(Clone)]
+pub struct LazyFrameTable {
+    lf: LazyFrame,
+    schema: ArrowRsSchemaRef,
+}
+
+impl LazyFrameTable {
+    /// Create a table for `lf`; this resolves the schema of its plan.
+    pub fn try_new(mut lf: LazyFrame) -> PolarsResult<Self> {
+        let schema = to_arrow_rs_schema(lf.collect_schema()?.as_ref()).map_err(to_compute_err)?;
+        Ok(Self { lf, schema })
+    }
+}
+
+impl fmt::Debug for LazyFrameTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LazyFrameTable")
+            .field("schema", &self.schema)
+            .finish()
+    }
+}
+
+#[async_trait]
+impl TableProvider for LazyFrameTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> ArrowRsSchemaRef {
+        self.schema.clone()
+    }
+
+    fn table_type(&self) -> TableType {
+        TableType::Base
+    }
+
+    async fn scan(
+        &self,
+        _state: &dyn Session,
+        projection: Option<&Vec<usize>>,
+        filters: &[DFExpr],
+        limit: Option<usize>,
+    ) -> DFResult<Arc<dyn ExecutionPlan>> {
+        let mut lf = self.lf.clone();
+        // Only the filters that were reported as exact are passed to the scan.
+        if let Some(predicate) = filters.iter().filter_map(to_polars_expr).reduce(Expr::and) {
+            lf = lf.filter(predicate);
+        }
+        let schema = match projection {
+            Some(projection) => {
+                let columns = projection
+                    .iter()
+                    .map(|&i| col(self.schema.field(i).name().as_str()))
+                    .collect::<Vec<_>>();
+                lf = lf.select(columns);
+                Arc::new(self.schema.project(projection)?)
+            },
+            None => self.schema.clone(),
+        };
+        if let Some(limit) = limit {
+            lf = lf.limit(limit as IdxSize);
+        }
+        Ok(Arc::new(LazyFrameExec::new(lf, schema)))
+    }
+
+    fn supports_filters_pushdown(
+        &self,
+        filters: &[&DFExpr],
+    ) -> DFResult<Vec<TableProviderFilterPushDown>> {
+        Ok(filters
+            .iter()
+            .map(|filter| match to_polars_expr(filter) {
+                Some(_) => TableProviderFilterPushDown::Exact,
+                None => TableProviderFilterPushDown::Unsupported,
+            })
+            .collect())
+    }
+}
+
+/// A DataFusion [`ExecutionPlan`] that runs a [`LazyFrame`] on the streaming engine and passes on
+/// the record batches of the result as they are produced.
+pub struct LazyFrameExec {
+    lf: LazyFrame,
+    schema: ArrowRsSchemaRef,
+    properties: PlanProperties,
+}
+
+impl LazyFrameExec {
+    /// The `schema` must be that of `lf`, converted with the oldest compatibility level (i.e.
+    /// without string views) as [`LazyFrameTable`] does.
+    pub fn new(lf: LazyFrame, schema: ArrowRsSchemaRef) -> Self {
+        let properties = PlanProperties::new(
+            EquivalenceProperties::new(schema.clone()),
+            Partitioning::UnknownPartitioning(1),
+            EmissionType::Final,
+            Boundedness::Bounded,
+        );
+        Self {
+            lf,
+            schema,
+            properties,
+        }
+    }
+
+    /// Run the query, sending the record batches of its result to `tx`.
+    fn run(
+        lf: LazyFrame,
+        schema: ArrowRsSchemaRef,
+        tx: mpsc::Sender<DFResult<RecordBatch>>,
+    ) -> PolarsResult<()> {
+        if schema.fields().is_empty() {
+            // DataFusion only needs the number of rows, e.g. for `COUNT(*)`.
+            let df = lf.select([len()]).collect()?;
+            let height = df.get_columns()[0].idx()?.get(0);
+            let options = RecordBatchOptions::new().with_row_count(height.map(|h| h as usize));
+            let batch = RecordBatch::try_new_with_options(schema, vec![], &options);
+            let _ = tx.blocking_send(batch.map_err(DataFusionError::from));
+            return Ok(());
+        }
+
+        let name = format!(
+            "datafusion_scan_{}",
+            NEXT_SCAN_ID.fetch_add(1, Ordering::Relaxed)
+        );
+        register_streaming_operator(
+            &name,
+            Arc::new(move || {
+                Box::new(SendBatches {
+                    schema: schema.clone(),
+                    tx: tx.clone(),
+                }) as Box<dyn StreamingOperator>
+            }),
+        );
+        let result = lf
+            .map_streaming_operator(&name, None)
+            .collect_with_engine(Engine::Streaming);
+        // drops the senders of the operator, which ends the stream
+        unregister_streaming_operator(&name);
+        result.map(|_| ())
+    }
+}
+
+/// Sends the morsels of the result of a scan to DataFusion, instead of passing them on.
+struct SendBatches {
+    schema: ArrowRsSchemaRef,
+    tx: mpsc::Sender<DFResult<RecordBatch>>,
+}
+
+#[async_trait]
+impl StreamingOperator for SendBatches {
+    async fn process(&mut self, mut df: DataFrame) -> PolarsResult<DataFrame> {
+        let empty = df.clear();
+        df.align_chunks();
+        for batch in df.iter_chunks(CompatLevel::oldest(), false) {
+            let columns = batch
+                .into_arrays()
+                .into_iter()
+                .map(to_arrow_rs_array)
+                .collect::<Result<Vec<_>, _>>();
+            let batch = columns
+                .and_then(|columns| RecordBatch::try_new(self.schema.clone(), columns))
+                .map_err(DataFusionError::from);
+            // fails once DataFusion has dropped the stream, which stops the query
+            self.tx
+                .send(batch)
+                .await
+                .map_err(|_| polars_err!(ComputeError: "the DataFusion scan was dropped"))?;
+        }
+        Ok(empty)
+    }
+}
+
+impl fmt::Debug for LazyFrameExec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LazyFrameExec")
+            .field("schema", &self.schema)
+            .finish()
+    }
+}
+
+impl DisplayAs for LazyFrameExec {
+    fn fmt_as(&self, _t: DisplayFormatType, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let plan = self.lf.describe_plan().map_err(|_| fmt::Error)?;
+        write!(f, "LazyFrameExec: {}", plan.replace('\n', " "))
+    }
+}
+
+impl ExecutionPlan for LazyFrameExec {
+    fn name(&self) -> &str {
+        "LazyFrameExec"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn properties(&self) -> &PlanProperties {
+        &self.properties
+    }
+
+    fn children(&self) -> Vec<&Arc<dyn ExecutionPlan>> {
+        vec![]
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        children: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> DFResult<Arc<dyn ExecutionPlan>> {
+        if children.is_empty() {
+            Ok(self)
+        } else {
+            Err(DataFusionError::Internal(
+                "LazyFrameExec does not have children".into(),
+            ))
+        }
+    }
+
+    fn execute(
+        &self,
+        partition: usize,
+        _context: Arc<TaskContext>,
+    ) -> DFResult<SendableRecordBatchStream> {
+        if partition != 0 {
+            return Err(DataFusionError::Internal(format!(
+                "LazyFrameExec has a single partition; got partition {partition}"
+            )));
+        }
+        let (tx, rx) = mpsc::channel(BATCH_BUFFER_SIZE);
+        let lf = self.lf.clone();
+        let schema = self.schema.clone();
+        // Polars runs the query on its own threads; don't block the async runtime.
+        tokio::task::spawn_blocking(move || {
+            let error_tx = tx.clone();
+            if let Err(err) = Self::run(lf, schema, tx) {
+                let _ = error_tx.blocking_send(Err(to_datafusion_err(err)));
+            }
+        });
+        let batches = stream::unfold(rx, |mut rx| async move {
+            let batch = rx.recv().await?;
+            Some((batch, rx))
+        });
+        Ok(Box::pin(RecordBatchStreamAdapter::new(
+            self.schema.clone(),
+            batches.boxed(),
+        )))
+    }
+}
+
+fn to_datafusion_err(err: PolarsError) -> DataFusionError {
+    DataFusionError::External(Box::new(err))
+}
+
+/// Convert a Polars schema to an arrow-rs schema, with the oldest compatibility level.
+fn to_arrow_rs_schema(schema: &Schema) -> Result<ArrowRsSchemaRef, ArrowError> {
+    let fields = schema
+        .iter_fields()
+        .map(|field| {
+            let c_schema = export_field(&field.to_arrow(CompatLevel::oldest()));
+            ArrowRsField::try_from(&c_schema)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(Arc::new(ArrowRsSchema::new(fields)))
+}
+
+/// Export a Polars field to an `ArrowSchema` of the C data interface that arrow-rs can import.
+fn export_field(field: &ArrowField) -> FFI_ArrowSchema {
+    let mut c_schema = FFI_ArrowSchema::empty();
+    // SAFETY: the struct is the `ArrowSchema` of the C data interface, which Polars exports into
+    // through a pointer like any other consumer would. The empty struct has no release callback,
+    // so overwriting it doesn't leak.
+    unsafe {
+        std::ptr::write(
+            (&raw mut c_schema).cast::<ffi::ArrowSchema>(),
+            ffi::export_field_to_c(field),
+        )
+    };
+    c_schema
+}
+
+/// Move a Polars array into an arrow-rs array, sharing its buffers.
+fn to_arrow_rs_array(array: ArrayRef) -> Result<ArrowRsArrayRef, ArrowError> {
+    let field = ArrowField::new(PlSmallStr::EMPTY, array.dtype().clone(), true);
+    let c_schema = export_field(&field);
+    let mut c_array = FFI_ArrowArray::empty();
+    // SAFETY: as in `export_field`, for the `ArrowArray` struct of the C data interface. The
+    // array is exported with the data type of the schema.
+    let data = unsafe {
+        std::ptr::write(
+            (&raw mut c_array).cast::<ffi::ArrowArray>(),
+            ffi::export_array_to_c(array),
+        );
+        from_ffi(c_array, &c_schema)?
+    };
+    Ok(make_array(data))
+}
+
+/// Translate a DataFusion filter to a Polars expression with the same result, if possible.
+fn to_polars_expr(expr: &DFExpr) -> Option<Expr> {
+    Some(match expr {
+        DFExpr::Column(column) => col(column.name.as_str()),
+        DFExpr::Literal(value) => scalar_to_lit(value)?,
+        DFExpr::BinaryExpr(BinaryExpr { left, op, right }) => {
+            let (l, r) = (to_polars_expr(left)?, to_polars_expr(right)?);
+            match op {
+                DFOperator::Eq => l.eq(r),
+                DFOperator::NotEq => l.neq(r),
+                DFOperator::Lt => l.lt(r),
+                DFOperator::LtEq => l.lt_eq(r),
+                DFOperator::Gt => l.gt(r),
+                DFOperator::GtEq => l.gt_eq(r),
+                DFOperator::And => l.and(r),
+                DFOperator::Or => l.or(r),
+                DFOperator::IsDistinctFrom => l.neq_missing(r),
+                DFOperator::IsNotDistinctFrom => l.eq_missing(r),
+                DFOperator::Plus => l + r,
+                DFOperator::Minus => l - r,
+                DFOperator::Multiply => l * r,
+                // Division and modulo differ for integers and negative numbers.
+                _ => return None,
+            }
+        },
+        DFExpr::Not(e) => to_polars_expr(e)?.not(),
+        DFExpr::IsNull(e) => to_polars_expr(e)?.is_null(),
+        DFExpr::IsNotNull(e) => to_polars_expr(e)?.is_not_null(),
+        DFExpr::Between(Between {
+            expr,
+            negated,
+            low,
+            high,
+        }) => {
+            let e = to_polars_expr(expr)?;
+            let between = e
+                .clone()
+                .gt_eq(to_polars_expr(low)?)
+                .and(e.lt_eq(to_polars_expr(high)?));
+            if *negated { between.not() } else { between }
+        },
+        DFExpr::InList(InList {
+            expr,
+            list,
+            negated,
+        }) => {
+            // `x IN (a, b)` is null if `x` is null, or if there's no match and the list has nulls.
+            let e = to_polars_expr(expr)?;
+            let any_eq = list
+                .iter()
+                .map(|v| Some(e.clone().eq(to_polars_expr(v)?)))
+                .collect::<Option<Vec<_>>>()?
+                .into_iter()
+                .reduce(Expr::or)?;
+            if *negated { any_eq.not() } else { any_eq }
+        },
+        DFExpr::Cast(Cast { expr, data_type }) => {
+            to_polars_expr(expr)?.strict_cast(to_polars_dtype(data_type)?)
+        },
+        DFExpr::TryCast(TryCast { expr, data_type }) => {
+            to_polars_expr(expr)?.cast(to_polars_dtype(data_type)?)
+        },
+        _ => return None,
+    })
+}
+
+fn scalar_to_lit(value: &ScalarValue) -> Option<Expr> {
+    Some(match value {
+        ScalarValue::Boolean(Some(v)) => lit(*v),
+        ScalarValue::Int8(Some(v)) => lit(*v),
+        ScalarValue::Int16(Some(v)) => lit(*v),
+        ScalarValue::Int32(Some(v)) => lit(*v),
+        ScalarValue::Int64(Some(v)) => lit(*v),
+        ScalarValue::UInt8(Some(v)) => lit(*v),
+        ScalarValue::UInt16(Some(v)) => lit(*v),
+        ScalarValue::UInt32(Some(v)) => lit(*v),
+        ScalarValue::UInt64(Some(v)) => lit(*v),
+        ScalarValue::Float32(Some(v)) => lit(*v),
+        ScalarValue::Float64(Some(v)) => lit(*v),
+        ScalarValue::Utf8(Some(v))
+        | ScalarValue::LargeUtf8(Some(v))
+        | ScalarValue::Utf8View(Some(v)) => lit(v.as_str()),
+        #[cfg(feature = "dtype-date")]
+        ScalarValue::Date32(Some(v)) => lit(*v).cast(DataType::Date),
+        // Nulls make comparisons null, which Polars and DataFusion filter the same way, but
+        // they aren't worth the type inference.
+        _ => return None,
+    })
+}
+
+fn to_polars_dtype(dtype: &ArrowRsDataType) -> Option<DataType> {
+    Some(match dtype {
+        ArrowRsDataType::Boolean => DataType::Boolean,
+        #[cfg(feature = "dtype-i8")]
+        ArrowRsDataType::Int8 => DataType::Int8,
+        #[cfg(feature = "dtype-i16")]
+        ArrowRsDataType::Int16 => DataType::Int16,
+        ArrowRsDataType::Int32 => DataType::Int32,
+        ArrowRsDataType::Int64 => DataType::Int64,
+        #[cfg(feature = "dtype-u8")]
+        ArrowRsDataType::UInt8 => DataType::UInt8,
+        #[cfg(feature = "dtype-u16")]
+        ArrowRsDataType::UInt16 => DataType::UInt16,
+        ArrowRsDataType::UInt32 => DataType::UInt32,
+        ArrowRsDataType::UInt64 => DataType::UInt64,
+        ArrowRsDataType::Float32 => DataType::Float32,
+        ArrowRsDataType::Float64 => DataType::Float64,
+        ArrowRsDataType::Utf8 | ArrowRsDataType::LargeUtf8 | ArrowRsDataType::Utf8View => {
+            DataType::String
+        },
+        #[cfg(feature = "dtype-date")]
+        ArrowRsDataType::Date32 => DataType::Date,
+        _ => return None,
+    })
+}
@@ -81,6 +81,17 @@ fn insert_file_sink(mut root: Node, lp_arena: &mut Arena<IR>) -> Node {
     root
 }
 
+/// The old streaming engine doesn't cast the files to the schema of the scan.
+fn has_cast_policy(file_options: &FileScanOptions) -> bool {
+    #[cfg(feature = "cast_policy")]
+    return file_options.cast_policy.is_some();
+    #[cfg(not(feature = "cast_policy"))]
+    {
+        let _ = file_options;
+        false
+    }
+}
+
 pub(crate) fn insert_streaming_nodes(
     root: Node,
     lp_arena: &mut Arena<IR>,
@@ -235,7 +246,8 @@ pub(crate) fn insert_streaming_nodes(
                 && file_options
                     .pre_slice
                     .map(|slice| slice.0 >= 0)
-                    .unwrap_or(true) =>
+                    .unwrap_or(true)
+                && !has_cast_policy(file_options) =>
             {
                 if state.streamable {
                     state.sources.push(root);
@@ -1,4 +1,5 @@
 pub(crate) use polars_expr::prelude::*;
+pub use polars_expr::state::CancelToken;
 #[cfg(feature = "csv")]
 pub use polars_io::csv::write::CsvWriterOptions;
 #[cfg(feature = "ipc")]
@@ -13,9 +14,23 @@ pub use polars_ops::prelude::{RankMethod, RankOptions};
 #[cfg(feature = "polars_cloud")]
 pub use polars_plan::client::prepare_cloud_plan;
 pub use polars_plan::dsl::AnonymousScanOptions;
-pub use polars_plan::plans::{AnonymousScan, AnonymousScanArgs, Literal, LiteralValue, NULL, Null};
+pub use polars_plan::plans::statistics::{
+    ColumnStatistics, TableStatistics, clear_table_statistics, get_table_statistics,
+    set_table_statistics,
+};
+pub use polars_plan::plans::{
+    AnonymousScan, AnonymousScanArgs, Literal, LiteralValue, NULL, Null, OptimizationRule,
+    OptimizationRuleFactory, OptimizationStage, register_optimization_rule,
+    unregister_optimization_rule,
+};
 pub use polars_plan::prelude::UnionArgs;
 pub(crate) use polars_plan::prelude::*;
+#[cfg(feature = "new_streaming")]
+pub use polars_stream::{
+    BufferSizes, NodeMetrics, PauseToken, PhysicalPlanFormat, QueryMetrics, QueryOptions,
+    QueryPriority, StreamingOperator, StreamingOperatorFactory, StreamingRuntime,
+    register_streaming_operator, set_streaming_runtime, unregister_streaming_operator,
+};
 #[cfg(feature = "rolling_window_by")]
 pub use polars_time::Duration;
 #[cfg(feature = "dynamic_group_by")]
@@ -0,0 +1,219 @@
+//! Exchange of data with other Arrow-native engines in the same process, e.g. DuckDB or
+//! DataFusion, through the
+//! [Arrow C stream interface](https://arrow.apache.org/docs/format/CStreamInterface.html).
+
+use std::any::Any;
+use std::sync::Mutex;
+#[cfg(feature = "new_streaming")]
+use std::sync::atomic::{AtomicU64, Ordering};
+
+#[cfg(feature = "new_streaming")]
+use arrow::array::ArrayRef;
+use arrow::array::StructArray;
+use arrow::datatypes::{ArrowDataType, ArrowSchema, Field};
+use arrow::ffi::{self, ArrowArrayStream, ArrowArrayStreamReader};
+use arrow::record_batch::RecordBatch;
+#[cfg(feature = "new_streaming")]
+use async_trait::async_trait;
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+#[cfg(feature = "new_streaming")]
+use tokio::sync::mpsc;
+
+use crate::prelude::*;
+
+/// The batches of an exported stream that may be buffered before the query waits for the
+/// consumer.
+#[cfg(feature = "new_streaming")]
+const EXPORT_BUFFER_SIZE: usize = 2;
+
+/// Gives every export a unique streaming operator name.
+#[cfg(feature = "new_streaming")]
+static NEXT_EXPORT_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Reads the record batches of an Arrow C stream as the query consumes them.
+struct ArrowCStreamScan {
+    /// `None` once the stream is exhausted.
+    reader: Mutex<Option<ArrowArrayStreamReader<Box<ArrowArrayStream>>>>,
+    schema: SchemaRef,
+}
+
+impl ArrowCStreamScan {
+    /// The next record batch of the stream, or `None` once the stream is exhausted.
+    ///
+    /// A stream can't be rewound, so reading it again after it was exhausted is an error, instead
+    /// of returning no rows.
+    fn next_df(&self, with_columns: Option<&[PlSmallStr]>) -> PolarsResult<Option<DataFrame>> {
+        let mut guard = self.reader.lock().unwrap();
+        let Some(reader) = guard.as_mut() else {
+            polars_bail!(
+                ComputeError: "the Arrow C stream was already consumed, it can only be scanned once"
+            );
+        };
+        // SAFETY: the stream fulfills the C stream interface, see `scan_arrow_c_stream`.
+        let Some(array) = unsafe { reader.next() }.transpose()? else {
+            // Releases the stream.
+            *guard = None;
+            return Ok(None);
+        };
+        let array = array
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .unwrap()
+            .clone();
+        let (fields, height, arrays, _) = array.into_data();
+        let df = DataFrame::from(RecordBatch::new(
+            height,
+            Arc::new(fields.into_iter().collect()),
+            arrays,
+        ));
+        match with_columns {
+            Some(columns) => df.select(columns.iter().cloned()).map(Some),
+            None => Ok(Some(df)),
+        }
+    }
+}
+
+impl AnonymousScan for ArrowCStreamScan {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        let with_columns = scan_opts.with_columns.as_deref();
+        let mut dfs = vec![];
+        while let Some(df) = self.next_df(with_columns)? {
+            dfs.push(df);
+        }
+        if dfs.is_empty() {
+            let schema = scan_opts.output_schema.unwrap_or(scan_opts.schema);
+            return Ok(DataFrame::empty_with_schema(schema.as_ref()));
+        }
+        Ok(accumulate_dataframes_vertical_unchecked(dfs))
+    }
+
+    fn next_batch(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<Option<DataFrame>> {
+        self.next_df(scan_opts.with_columns.as_deref())
+    }
+
+    fn schema(&self, _infer_schema_length: Option<usize>) -> PolarsResult<SchemaRef> {
+        Ok(self.schema.clone())
+    }
+
+    fn is_batched(&self) -> bool {
+        true
+    }
+
+    fn allows_projection_pushdown(&self) -> bool {
+        true
+    }
+}
+
+impl LazyFrame {
+    /// Scan the record batches of an Arrow C stream, e.g. one produced by DuckDB or DataFusion.
+    ///
+    /// The stream is moved out of `stream_ptr`, which is left released. The batches are imported
+    /// without copying as the query consumes them, and the streaming engine reads them batch by
+    /// batch. A stream can only be read once, so collecting the resulting [`LazyFrame`] again
+    /// after the stream was exhausted is an error.
+    ///
+    /// # Safety
+    /// `stream_ptr` must point to a valid `ArrowArrayStream` of struct arrays that fulfills the
+    /// C stream interface.
+    pub unsafe fn scan_arrow_c_stream(stream_ptr: *mut ArrowArrayStream) -> PolarsResult<Self> {
+        let stream = Box::new(unsafe { std::ptr::replace(stream_ptr, ArrowArrayStream::empty()) });
+        let reader = unsafe { ArrowArrayStreamReader::try_new(stream)? };
+        let ArrowDataType::Struct(fields) = reader.field().dtype() else {
+            polars_bail!(
+                ComputeError: "expected an Arrow C stream of struct arrays, got {:?}",
+                reader.field().dtype()
+            );
+        };
+        let schema = Arc::new(Schema::from_arrow_schema(
+            &fields.iter().cloned().collect::<ArrowSchema>(),
+        ));
+
+        let function = Arc::new(ArrowCStreamScan {
+            reader: Mutex::new(Some(reader)),
+            schema: schema.clone(),
+        });
+        let args = ScanArgsAnonymous {
+            schema: Some(schema),
+            name: "ARROW C STREAM",
+            ..Default::default()
+        };
+        Self::anonymous_scan(function, args)
+    }
+
+    /// Run the query and export its result as an Arrow C stream of struct arrays, so that other
+    /// Arrow-native engines in the same process can consume it without copying.
+    ///
+    /// The query runs on the streaming engine in a background thread, and its morsels are passed
+    /// on as the stream is read, with a bounded buffer. The query is stopped when the stream is
+    /// released before it was read to the end.
+    #[cfg(feature = "new_streaming")]
+    pub fn collect_arrow_c_stream(mut self) -> PolarsResult<ArrowArrayStream> {
+        let compat_level = CompatLevel::newest();
+        let schema = self.collect_schema()?.to_arrow(compat_level);
+        let dtype = ArrowDataType::Struct(schema.iter_values().cloned().collect());
+
+        let (tx, mut rx) = mpsc::channel(EXPORT_BUFFER_SIZE);
+        let name = format!(
+            "arrow_c_stream_export_{}",
+            NEXT_EXPORT_ID.fetch_add(1, Ordering::Relaxed)
+        );
+        let sender_dtype = dtype.clone();
+        let error_tx = tx.clone();
+        register_streaming_operator(
+            &name,
+            Arc::new(move || {
+                Box::new(SendArrays {
+                    dtype: sender_dtype.clone(),
+                    compat_level,
+                    tx: tx.clone(),
+                }) as Box<dyn StreamingOperator>
+            }),
+        );
+        std::thread::spawn(move || {
+            let result = self
+                .map_streaming_operator(&name, None)
+                .collect_with_engine(Engine::Streaming);
+            // drops the senders of the operator, which ends the stream
+            unregister_streaming_operator(&name);
+            if let Err(err) = result {
+                let _ = error_tx.blocking_send(Err(err));
+            }
+        });
+
+        let arrays = std::iter::from_fn(move || rx.blocking_recv());
+        let field = Field::new(PlSmallStr::EMPTY, dtype, false);
+        Ok(ffi::export_iterator(Box::new(arrays), field))
+    }
+}
+
+/// Sends the morsels of an exported query to the consumer of the stream, instead of passing them
+/// on.
+#[cfg(feature = "new_streaming")]
+struct SendArrays {
+    dtype: ArrowDataType,
+    compat_level: CompatLevel,
+    tx: mpsc::Sender<PolarsResult<ArrayRef>>,
+}
+
+#[cfg(feature = "new_streaming")]
+#[async_trait]
+impl StreamingOperator for SendArrays {
+    async fn process(&mut self, mut df: DataFrame) -> PolarsResult<DataFrame> {
+        let empty = df.clear();
+        df.align_chunks();
+        for batch in df.iter_chunks(self.compat_level, false) {
+            let height = batch.height();
+            let array = StructArray::new(self.dtype.clone(), height, batch.into_arrays(), None);
+            // fails once the stream was released, which stops the query
+            self.tx.send(Ok(array.boxed())).await.map_err(
+                |_| polars_err!(ComputeError: "the exported Arrow C stream was released"),
+            )?;
+        }
+        Ok(empty)
+    }
+}
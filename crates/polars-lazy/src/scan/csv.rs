@@ -22,6 +22,7 @@ pub struct LazyCsvReader {
     read_options: CsvReadOptions,
     cloud_options: Option<CloudOptions>,
     include_file_paths: Option<PlSmallStr>,
+    column_parsers: Option<Arc<[Expr]>>,
 }
 
 #[cfg(feature = "csv")]
@@ -47,6 +48,7 @@ impl LazyCsvReader {
             read_options: Default::default(),
             cloud_options: Default::default(),
             include_file_paths: None,
+            column_parsers: None,
         }
     }
 
@@ -123,6 +125,15 @@ impl LazyCsvReader {
         self
     }
 
+    /// Parse columns with expressions while they are read, e.g. to parse `"1,234.56"` strings to
+    /// a decimal. Every expression replaces the column with its output name. Unlike parsing in a
+    /// later `with_columns`, predicates on the parsed columns are still pushed into the scan.
+    #[must_use]
+    pub fn with_column_parsers(mut self, column_parsers: Option<Vec<Expr>>) -> Self {
+        self.column_parsers = column_parsers.map(Arc::from);
+        self
+    }
+
     /// Set whether the CSV file has headers
     #[must_use]
     pub fn with_has_header(mut self, has_header: bool) -> Self {
@@ -329,6 +340,7 @@ impl LazyFileListReader for LazyCsvReader {
             self.glob,
             self.include_file_paths,
         )?
+        .with_column_parsers(self.column_parsers)
         .build()
         .into();
         Ok(lf)
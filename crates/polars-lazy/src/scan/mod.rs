@@ -1,4 +1,5 @@
 pub(super) mod anonymous_scan;
+pub(super) mod arrow_c_stream;
 #[cfg(feature = "csv")]
 pub(super) mod csv;
 pub(super) mod file_list_reader;
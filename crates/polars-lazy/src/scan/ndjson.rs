@@ -5,7 +5,7 @@ use std::sync::Arc;
 use polars_core::prelude::*;
 use polars_io::cloud::CloudOptions;
 use polars_io::{HiveOptions, RowIndex};
-use polars_plan::dsl::{DslPlan, FileScan, ScanSources};
+use polars_plan::dsl::{DslPlan, Expr, FileScan, ScanSources};
 use polars_plan::prelude::{FileScanOptions, NDJsonReadOptions};
 
 use crate::prelude::LazyFrame;
@@ -19,6 +19,7 @@ pub struct LazyJsonLineReader {
     pub(crate) rechunk: bool,
     pub(crate) schema: Option<SchemaRef>,
     pub(crate) schema_overwrite: Option<SchemaRef>,
+    pub(crate) column_parsers: Option<Arc<[Expr]>>,
     pub(crate) row_index: Option<RowIndex>,
     pub(crate) infer_schema_length: Option<NonZeroUsize>,
     pub(crate) n_rows: Option<usize>,
@@ -40,6 +41,7 @@ impl LazyJsonLineReader {
             rechunk: false,
             schema: None,
             schema_overwrite: None,
+            column_parsers: None,
             row_index: None,
             infer_schema_length: NonZeroUsize::new(100),
             ignore_errors: false,
@@ -96,6 +98,13 @@ impl LazyJsonLineReader {
         self
     }
 
+    /// Parse columns with expressions while they are read, see [`FileScanOptions::column_parsers`].
+    #[must_use]
+    pub fn with_column_parsers(mut self, column_parsers: Option<Vec<Expr>>) -> Self {
+        self.column_parsers = column_parsers.map(Arc::from);
+        self
+    }
+
     /// Reduce memory usage at the expense of performance
     #[must_use]
     pub fn low_memory(mut self, toggle: bool) -> Self {
@@ -138,6 +147,9 @@ impl LazyFileListReader for LazyJsonLineReader {
             glob: true,
             include_file_paths: self.include_file_paths,
             allow_missing_columns: false,
+            column_parsers: self.column_parsers,
+            #[cfg(feature = "cast_policy")]
+            cast_policy: None,
         });
 
         let options = NDJsonReadOptions {
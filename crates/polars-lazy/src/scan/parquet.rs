@@ -4,6 +4,8 @@ use polars_core::prelude::*;
 use polars_io::cloud::CloudOptions;
 use polars_io::parquet::read::ParallelStrategy;
 use polars_io::{HiveOptions, RowIndex};
+#[cfg(feature = "cast_policy")]
+use polars_ops::prelude::CastPolicy;
 
 use crate::prelude::*;
 
@@ -23,6 +25,10 @@ pub struct ScanArgsParquet {
     pub glob: bool,
     pub include_file_paths: Option<PlSmallStr>,
     pub allow_missing_columns: bool,
+    /// Conform the scanned data to `schema` with this policy, instead of requiring the files to
+    /// match `schema` exactly.
+    #[cfg(feature = "cast_policy")]
+    pub cast_policy: Option<CastPolicy>,
 }
 
 impl Default for ScanArgsParquet {
@@ -41,6 +47,8 @@ impl Default for ScanArgsParquet {
             glob: true,
             include_file_paths: None,
             allow_missing_columns: false,
+            #[cfg(feature = "cast_policy")]
+            cast_policy: None,
         }
     }
 }
@@ -65,7 +73,16 @@ impl LazyFileListReader for LazyParquetReader {
     fn finish(self) -> PolarsResult<LazyFrame> {
         let row_index = self.args.row_index;
 
-        let mut lf: LazyFrame = DslBuilder::scan_parquet(
+        #[cfg(feature = "cast_policy")]
+        let cast_policy = self.args.cast_policy;
+        #[cfg(feature = "cast_policy")]
+        polars_ensure!(
+            cast_policy.is_none() || self.args.schema.is_some(),
+            InvalidOperation: "a `cast_policy` needs a `schema` to cast to"
+        );
+
+        #[allow(unused_mut)]
+        let mut plan = DslBuilder::scan_parquet(
             self.sources,
             self.args.n_rows,
             self.args.cache,
@@ -81,8 +98,12 @@ impl LazyFileListReader for LazyParquetReader {
             self.args.include_file_paths,
             self.args.allow_missing_columns,
         )?
-        .build()
-        .into();
+        .build();
+        #[cfg(feature = "cast_policy")]
+        if let DslPlan::Scan { file_options, .. } = &mut plan {
+            file_options.cast_policy = cast_policy;
+        }
+        let mut lf: LazyFrame = plan.into();
 
         // It's a bit hacky, but this row_index function updates the schema.
         if let Some(row_index) = row_index {
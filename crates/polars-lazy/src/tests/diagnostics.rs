@@ -0,0 +1,125 @@
+use super::*;
+
+#[test]
+fn test_error_context() -> PolarsResult<()> {
+    let df = df!["a" => ["x"]]?;
+    let err = df
+        .lazy()
+        .select([col("a").strict_cast(DataType::Int32)])
+        .collect()
+        .unwrap_err();
+
+    assert_eq!(err.plan_node().map(|(_, name)| name), Some("projection"));
+    assert!(err.expr().unwrap().contains("strict_cast"));
+    assert!(err.file().is_none());
+    // The context doesn't change the message of the error.
+    let msg = err.to_string();
+    assert!(!msg.contains("in plan node") && !msg.contains("in expression"));
+
+    Ok(())
+}
+
+#[test]
+fn test_collect_with_diagnostics() -> PolarsResult<()> {
+    use polars_core::error::DiagnosticKind;
+
+    let df = df!["a" => ["1", "x"]]?;
+    let q = df.lazy().select([col("a").cast(DataType::Int32)]);
+
+    let (out, diagnostics) = q.clone().collect_with_diagnostics(Engine::InMemory, &[])?;
+    assert_eq!(out.column("a")?.null_count(), 1);
+    assert_eq!(diagnostics.height(), 1);
+    assert_eq!(
+        diagnostics.column("kind")?.str()?.get(0),
+        Some("lossy_cast")
+    );
+
+    let err = q
+        .clone()
+        .collect_with_diagnostics(Engine::InMemory, &[DiagnosticKind::LossyCast])
+        .unwrap_err();
+    assert!(matches!(err, PolarsError::InvalidOperation(_)));
+
+    // Queries started on other threads don't report to the collector of this thread.
+    let diagnostics = polars_core::error::Diagnostics::new(&[]);
+    let _holder = diagnostics.hold();
+    let other = q.clone();
+    std::thread::spawn(move || other.collect())
+        .join()
+        .unwrap()?;
+    assert!(diagnostics.take().is_empty());
+    q.collect()?;
+    assert_eq!(diagnostics.take().len(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn test_cast_diagnostics() -> PolarsResult<()> {
+    let df = df!["a" => [Some("1"), Some("x"), None, Some("y")]]?;
+
+    // Only the values that weren't null before count as lost.
+    let (_, diagnostics) = df
+        .clone()
+        .lazy()
+        .select([col("a").cast(DataType::Int32)])
+        .collect_with_diagnostics(Engine::InMemory, &[])?;
+    assert_eq!(diagnostics.height(), 1);
+    assert_eq!(
+        diagnostics.column("message")?.str()?.get(0),
+        Some("casting 'a' from str to i32 turned 2 values into null")
+    );
+
+    // Casts which keep all values don't report anything.
+    let (_, diagnostics) = df
+        .clone()
+        .lazy()
+        .select([col("a").cast(DataType::Binary)])
+        .collect_with_diagnostics(Engine::InMemory, &[])?;
+    assert_eq!(diagnostics.height(), 0);
+
+    // Strict casts fail instead.
+    let (_, diagnostics) = df
+        .lazy()
+        .filter(col("a").eq(lit("1")))
+        .select([col("a").strict_cast(DataType::Int32)])
+        .collect_with_diagnostics(Engine::InMemory, &[])?;
+    assert_eq!(diagnostics.height(), 0);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "dtype-datetime", feature = "timezones"))]
+fn test_cast_diagnostics_dropped_timezone() -> PolarsResult<()> {
+    let tz = Some(TimeZone::from_static("Europe/Amsterdam"));
+    let df = df!["t" => [0i64, 1_000]]?
+        .lazy()
+        .select([col("t").cast(DataType::Datetime(TimeUnit::Milliseconds, tz))])
+        .collect()?;
+
+    let (_, diagnostics) = df
+        .clone()
+        .lazy()
+        .select([col("t").cast(DataType::Datetime(TimeUnit::Milliseconds, None))])
+        .collect_with_diagnostics(Engine::InMemory, &[])?;
+    assert_eq!(diagnostics.height(), 1);
+    assert_eq!(
+        diagnostics.column("kind")?.str()?.get(0),
+        Some("dropped_timezone")
+    );
+    assert_eq!(
+        diagnostics.column("message")?.str()?.get(0),
+        Some("casting 't' to datetime[ms] dropped the time zone 'Europe/Amsterdam'")
+    );
+
+    // Changing the time unit keeps the time zone.
+    let tz = Some(TimeZone::from_static("Europe/Amsterdam"));
+    let (_, diagnostics) = df
+        .lazy()
+        .select([col("t").cast(DataType::Datetime(TimeUnit::Microseconds, tz))])
+        .collect_with_diagnostics(Engine::InMemory, &[])?;
+    assert_eq!(diagnostics.height(), 0);
+
+    Ok(())
+}
@@ -0,0 +1,217 @@
+use super::*;
+
+#[test]
+#[cfg(feature = "new_streaming")]
+fn test_collect_cancellable() -> PolarsResult<()> {
+    let df = df!["a" => [1, 2, 3, 4, 5]]?;
+    let q = df.lazy().select([col("a") * lit(2)]);
+
+    let out = q.clone().collect_cancellable()?.fetch_blocking()?;
+    assert!(out.equals(&q.clone().collect()?));
+
+    let handle = q.clone().collect_cancellable()?;
+    handle.pause()?;
+    std::thread::sleep(std::time::Duration::from_millis(50));
+    handle.resume()?;
+    assert!(handle.fetch_blocking()?.equals(&q.clone().collect()?));
+    assert!(q.collect_concurrently()?.pause().is_err());
+
+    let df = df!["a" => [1, 2, 3]]?;
+    let handle = df
+        .lazy()
+        .select([col("a").map(
+            |c| {
+                std::thread::sleep(std::time::Duration::from_millis(500));
+                Ok(Some(c))
+            },
+            GetOutput::same_type(),
+        )])
+        .collect_cancellable()?;
+    handle.cancel();
+    assert!(handle.fetch_blocking().is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "new_streaming")]
+fn test_collect_paused() -> PolarsResult<()> {
+    let q = df!["a" => (0..1000).collect::<Vec<i32>>()]?
+        .lazy()
+        .select([col("a") * lit(2)]);
+    let expected = q.clone().collect()?;
+
+    // A query paused before it starts must not produce a result until it is resumed.
+    let pause_token = PauseToken::new();
+    pause_token.pause();
+    let options = QueryOptions {
+        pause_token: Some(pause_token.clone()),
+        ..Default::default()
+    };
+    let handle = std::thread::spawn(move || q.collect_with_options(options));
+    std::thread::sleep(std::time::Duration::from_millis(200));
+    assert!(!handle.is_finished());
+
+    pause_token.resume();
+    assert!(handle.join().unwrap()?.equals(&expected));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "new_streaming")]
+fn test_concurrent_queries_with_priority() -> PolarsResult<()> {
+    let q = df!["a" => (0..1000).collect::<Vec<i32>>()]?
+        .lazy()
+        .select([col("a") * lit(2)]);
+    let expected = q.clone().collect()?;
+
+    let handles = [
+        QueryPriority::Low,
+        QueryPriority::Normal,
+        QueryPriority::High,
+    ]
+    .into_iter()
+    .map(|priority| q.clone().collect_cancellable_with_priority(priority))
+    .collect::<PolarsResult<Vec<_>>>()?;
+    for handle in handles {
+        assert!(handle.fetch_blocking()?.equals(&expected));
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "new_streaming")]
+fn test_collect_with_buffer_sizes() -> PolarsResult<()> {
+    let q = df!["a" => (0..1000).collect::<Vec<i32>>()]?
+        .lazy()
+        .with_row_index("idx", None)
+        .select([col("idx"), col("a") * lit(2)]);
+    let expected = q.clone().collect()?;
+
+    let options = QueryOptions {
+        buffer_sizes: BufferSizes {
+            linearizer: 1,
+            distributor: 1,
+            zip_head: 1,
+            row_group_prefetch: 1,
+        },
+        ..Default::default()
+    };
+    assert!(q.clone().collect_with_options(options)?.equals(&expected));
+
+    // A buffer that can't hold a single morsel is rejected instead of panicking.
+    let options = QueryOptions {
+        buffer_sizes: BufferSizes {
+            zip_head: 0,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    assert!(q.collect_with_options(options).is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "new_streaming", feature = "dynamic_group_by"))]
+fn test_collect_with_flush_deadline() -> PolarsResult<()> {
+    let q = df!["t" => (0..10_000i64).collect::<Vec<_>>(), "a" => vec![1i32; 10_000]]?
+        .lazy()
+        .group_by_dynamic(
+            col("t"),
+            [],
+            DynamicGroupOptions {
+                every: Duration::parse("10i"),
+                period: Duration::parse("10i"),
+                offset: Duration::parse("0i"),
+                ..Default::default()
+            },
+        )
+        .agg([col("a").sum()]);
+    let expected = q.clone().collect()?;
+
+    // Flushing immediately emits smaller morsels, but must not change the result.
+    let options = QueryOptions {
+        flush_deadline: Some(std::time::Duration::ZERO),
+        ..Default::default()
+    };
+    assert!(q.collect_with_options(options)?.equals(&expected));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "new_streaming")]
+fn test_collect_unordered() -> PolarsResult<()> {
+    let q = df![
+        "g" => (0..10_000).map(|i| i % 7).collect::<Vec<i32>>(),
+        "a" => (0..10_000).collect::<Vec<i32>>(),
+    ]?
+    .lazy()
+    .filter(col("a").gt(lit(10)))
+    .group_by([col("g")])
+    .agg([col("a").sum(), col("a").count().alias("n")]);
+    let expected = q.clone().sort(["g"], Default::default()).collect()?;
+
+    let options = QueryOptions {
+        maintain_order: false,
+        ..Default::default()
+    };
+    let out = q
+        .collect_with_options(options.clone())?
+        .sort(["g"], Default::default())?;
+    assert!(out.equals(&expected));
+
+    // Operations which depend on the order of their input still get it.
+    let q = df!["a" => (0..10_000).collect::<Vec<i32>>()]?
+        .lazy()
+        .filter(col("a").gt(lit(10)))
+        .with_row_index("i", None)
+        .slice(5, 100);
+    let out = q.clone().collect_with_options(options)?;
+    assert!(out.equals(&q.collect()?));
+    Ok(())
+}
+
+#[test]
+fn test_collect_with_timeout() -> PolarsResult<()> {
+    use std::time::Duration;
+
+    let slow_query = || {
+        df!["a" => [3, 1, 2]]
+            .unwrap()
+            .lazy()
+            .select([col("a").map(
+                |c| {
+                    std::thread::sleep(Duration::from_millis(500));
+                    Ok(Some(c))
+                },
+                GetOutput::same_type(),
+            )])
+            .sort(["a"], Default::default())
+    };
+
+    let out = slow_query().collect_with_timeout(Engine::InMemory, Duration::from_secs(60))?;
+    assert_eq!(out.height(), 3);
+
+    let result = slow_query().collect_with_timeout(Engine::InMemory, Duration::from_millis(50));
+    assert!(matches!(result, Err(PolarsError::Timeout(_))));
+
+    #[cfg(feature = "new_streaming")]
+    {
+        let result =
+            slow_query().collect_with_timeout(Engine::Streaming, Duration::from_millis(50));
+        assert!(matches!(result, Err(PolarsError::Timeout(_))));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_auto_engine_small_input() -> PolarsResult<()> {
+    let q = df!["a" => [1, 2, 3]]?.lazy().filter(col("a").gt(lit(1)));
+
+    let explained = q.explain_auto_engine()?;
+    assert!(explained.ends_with(')'));
+    assert!(explained.contains("ENGINE: in-memory"));
+
+    let out = q.collect_with_engine(Engine::Auto)?;
+    assert_eq!(out.height(), 2);
+    Ok(())
+}
@@ -0,0 +1,124 @@
+use super::*;
+
+#[test]
+fn test_collect_with_metrics() -> PolarsResult<()> {
+    let df = df!["a" => [1, 2, 3, 4, 5]]?;
+    let q = df.lazy().select([col("a") * lit(2)]);
+
+    let (out, metrics) = q.collect_with_metrics()?;
+    assert_eq!(out.height(), 5);
+
+    let source = metrics
+        .nodes
+        .iter()
+        .find(|m| m.name == "in_memory_source")
+        .unwrap();
+    assert_eq!(source.rows_out, 5);
+    let sink = metrics
+        .nodes
+        .iter()
+        .find(|m| m.name == "in_memory_sink")
+        .unwrap();
+    assert_eq!(sink.rows_in, 5);
+
+    let metrics_df = metrics.to_df()?;
+    assert_eq!(metrics_df.height(), metrics.nodes.len());
+    assert!(metrics_df.column("cpu_time_ns").is_ok());
+    Ok(())
+}
+
+#[test]
+fn test_progress_observer() -> PolarsResult<()> {
+    use std::sync::Mutex;
+
+    use polars_stream::{ProgressEvent, ProgressObserver, set_progress_observer};
+
+    #[derive(Default)]
+    struct Recorder {
+        // (node name, total rows, estimated completion) of every produced morsel.
+        rows: Mutex<Vec<(String, u64, Option<f64>)>>,
+        finished: Mutex<Vec<String>>,
+    }
+
+    impl ProgressObserver for Recorder {
+        fn on_event(&self, event: &ProgressEvent<'_>) {
+            match event {
+                ProgressEvent::RowsProduced {
+                    name,
+                    total_rows,
+                    estimated_completion,
+                    ..
+                } => self.rows.lock().unwrap().push((
+                    name.to_string(),
+                    *total_rows,
+                    *estimated_completion,
+                )),
+                ProgressEvent::NodeFinished { name, .. } => {
+                    self.finished.lock().unwrap().push(name.to_string())
+                },
+                _ => {},
+            }
+        }
+    }
+
+    // An unusual number of rows, as the observer also sees queries of tests running concurrently.
+    let n = 123_457;
+    let recorder = Arc::new(Recorder::default());
+    set_progress_observer(Some(recorder.clone()));
+    let out = df!["a" => (0..n).collect::<Vec<i64>>()]?
+        .lazy()
+        .select([col("a") * lit(2)])
+        .collect_with_options(QueryOptions::default());
+    set_progress_observer(None);
+    assert_eq!(out?.height(), n as usize);
+
+    let rows = recorder.rows.lock().unwrap();
+    assert!(
+        rows.iter()
+            .any(|(name, total, completion)| name == "in_memory_source"
+                && *total == n as u64
+                && *completion == Some(1.0))
+    );
+    assert!(
+        recorder
+            .finished
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|name| name == "in_memory_sink")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_explain_streaming() -> PolarsResult<()> {
+    let df = df!["a" => [1, 2, 3, 4, 5]]?;
+    let q = df
+        .lazy()
+        .filter(col("a").gt(lit(2)))
+        .sort(["a"], Default::default());
+
+    let dot = q.explain_streaming(PhysicalPlanFormat::Dot)?;
+    assert!(dot.starts_with("digraph polars"));
+    assert!(dot.contains("filter"));
+    assert!(dot.contains("parallelism: in-memory"));
+
+    let mermaid = q.explain_streaming(PhysicalPlanFormat::Mermaid)?;
+    assert!(mermaid.starts_with("flowchart BT"));
+    assert!(mermaid.contains("in-memory-source<br/>"));
+    assert!(mermaid.contains(" --> "));
+    Ok(())
+}
+
+#[test]
+fn test_explain_analyze() -> PolarsResult<()> {
+    let df = df!["a" => [1, 2, 3, 4, 5]]?;
+    let q = df.lazy().filter(col("a").gt(lit(2)));
+
+    let explained = q.explain_analyze()?;
+    assert!(explained.starts_with("total wall time: "));
+    assert!(explained.contains("└─ filter"));
+    assert!(explained.contains("rows in: 5, rows out: 3"));
+    assert!(explained.contains("spilled: 0 bytes"));
+    Ok(())
+}
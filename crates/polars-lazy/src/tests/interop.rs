@@ -0,0 +1,93 @@
+use super::*;
+
+#[test]
+#[cfg(feature = "substrait")]
+fn test_to_substrait() -> PolarsResult<()> {
+    let q = fruits_cars()
+        .lazy()
+        .filter(col("A").gt(lit(1)))
+        .group_by([col("fruits")])
+        .agg([col("B").sum()]);
+
+    let plan: serde_json::Value = serde_json::from_str(&q.to_substrait()?).unwrap();
+    let root = &plan["relations"][0]["root"];
+    assert_eq!(root["names"], serde_json::json!(["fruits", "B"]));
+    let aggregate = &root["input"]["aggregate"];
+    assert_eq!(aggregate["measures"].as_array().unwrap().len(), 1);
+    let filter = &aggregate["input"]["filter"];
+    assert!(filter["input"]["read"]["virtualTable"].is_object());
+
+    // Nodes without an equivalent in Substrait raise an error.
+    let q = fruits_cars().lazy().explode([col("A")]);
+    assert!(q.to_substrait().is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "substrait")]
+fn test_from_substrait() -> PolarsResult<()> {
+    let sort_options = SortMultipleOptions::default().with_maintain_order(true);
+    let q = fruits_cars()
+        .lazy()
+        .filter(col("A").gt(lit(1)))
+        .group_by([col("fruits")])
+        .agg([col("B").sum(), len().alias("count")])
+        .sort(["fruits"], sort_options.clone());
+    let out = LazyFrame::from_substrait(&q.to_substrait()?, &Default::default())?.collect()?;
+    assert!(out.equals(&q.collect()?));
+
+    let right = df![
+        "fruits" => ["banana", "apple"],
+        "color" => ["yellow", "green"],
+    ]?;
+    let q = fruits_cars()
+        .lazy()
+        .inner_join(right.lazy(), col("fruits"), col("fruits"))
+        .select([col("A"), col("color")])
+        .sort(["A"], sort_options);
+    let out = LazyFrame::from_substrait(&q.to_substrait()?, &Default::default())?.collect()?;
+    assert!(out.equals(&q.collect()?));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "datafusion")]
+fn test_datafusion_table_provider() -> PolarsResult<()> {
+    use datafusion::arrow::array::{AsArray, Int64Array};
+    use datafusion::arrow::datatypes::Int32Type;
+    use datafusion::prelude::SessionContext;
+
+    let ctx = SessionContext::new();
+    ctx.register_table("t", Arc::new(fruits_cars().lazy().into_table_provider()?))
+        .unwrap();
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    let query = |sql: &str| {
+        rt.block_on(async { ctx.sql(sql).await?.collect().await })
+            .unwrap()
+    };
+
+    // The filter is pushed down into the `LazyFrame`.
+    let batches = query("SELECT A, fruits FROM t WHERE A > 2 AND fruits = 'banana'");
+    let a = batches
+        .iter()
+        .flat_map(|batch| {
+            batch
+                .column(0)
+                .as_primitive::<Int32Type>()
+                .values()
+                .to_vec()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(a, [5]);
+
+    let batches = query("SELECT COUNT(*) FROM t WHERE cars <> 'beetle'");
+    let count = batches[0]
+        .column(0)
+        .as_any()
+        .downcast_ref::<Int64Array>()
+        .unwrap();
+    assert_eq!(count.value(0), 1);
+    Ok(())
+}
@@ -648,6 +648,37 @@ fn scan_predicate_on_set_null_values() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn scan_column_parsers() -> PolarsResult<()> {
+    let scan = || {
+        LazyCsvReader::new(FOODS_CSV)
+            .with_infer_schema_length(Some(0))
+            .with_column_parsers(Some(vec![col("calories").cast(DataType::Int64)]))
+            .finish()
+    };
+    let q = scan()?.filter(col("calories").gt(lit(100)));
+    assert!(predicate_at_scan(q.clone()));
+
+    let df = q.collect()?;
+    assert_eq!(df.column("calories")?.dtype(), &DataType::Int64);
+    assert_eq!(df.column("fats_g")?.dtype(), &DataType::String);
+
+    let expected = LazyCsvReader::new(FOODS_CSV)
+        .with_infer_schema_length(Some(0))
+        .finish()?
+        .with_column(col("calories").cast(DataType::Int64))
+        .filter(col("calories").gt(lit(100)))
+        .collect()?;
+    assert!(df.equals(&expected));
+
+    // Parsers must be elementwise.
+    let q = LazyCsvReader::new(FOODS_CSV)
+        .with_column_parsers(Some(vec![col("calories").sort(Default::default())]))
+        .finish()?;
+    assert!(q.collect().is_err());
+    Ok(())
+}
+
 #[test]
 fn scan_anonymous_fn_with_options() -> PolarsResult<()> {
     struct MyScan {}
@@ -687,6 +718,46 @@ fn scan_anonymous_fn_with_options() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn scan_anonymous_fn_with_skippable_columns() -> PolarsResult<()> {
+    struct MyScan {}
+
+    impl AnonymousScan for MyScan {
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+
+        fn allows_projection_pushdown(&self) -> bool {
+            true
+        }
+
+        fn skippable_columns(&self) -> Option<Arc<[PlSmallStr]>> {
+            Some(["fruits".into(), "cars".into()].into())
+        }
+
+        fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+            let with_columns = scan_opts.with_columns.unwrap();
+            assert_eq!(with_columns.len(), 3);
+            assert!(!with_columns.contains(&PlSmallStr::from_static("cars")));
+            fruits_cars().select(with_columns.iter().cloned())
+        }
+    }
+
+    let args = ScanArgsAnonymous {
+        schema: Some(fruits_cars().schema().clone()),
+        ..ScanArgsAnonymous::default()
+    };
+
+    let df = LazyFrame::anonymous_scan(Arc::new(MyScan {}), args)?
+        .filter(col("fruits").eq(lit("banana")))
+        .select([col("A")])
+        .collect()?;
+
+    assert_eq!(df.get_column_names(), &["A"]);
+    assert_eq!(df.height(), 3);
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "dtype-full")]
 fn scan_small_dtypes() -> PolarsResult<()> {
@@ -711,3 +782,54 @@ fn scan_small_dtypes() -> PolarsResult<()> {
     }
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "new_streaming")]
+fn arrow_c_stream_round_trip() -> PolarsResult<()> {
+    let df = df!["a" => (0..100_000i64).collect::<Vec<_>>()]?;
+    let q = df.lazy().filter(col("a").gt(lit(10)));
+    let expected = q.clone().collect()?;
+
+    let mut stream = q.collect_arrow_c_stream()?;
+    let lf = unsafe { LazyFrame::scan_arrow_c_stream(&mut stream) }?;
+    let out = lf.clone().collect()?;
+    assert!(out.equals(&expected));
+
+    // The stream can't be rewound.
+    let err = lf.collect().unwrap_err();
+    assert!(err.to_string().contains("already consumed"), "{err}");
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "csv")]
+fn test_analyze_glob_scan() -> PolarsResult<()> {
+    let q = LazyCsvReader::new(GLOB_CSV).finish()?;
+    let stats = q.clone().analyze()?;
+    assert_eq!(q.statistics(), Some(stats.clone()));
+
+    // The optimizer looks the statistics up by the expanded paths of the glob.
+    let plan = q.to_alp()?;
+    let IR::Scan {
+        sources,
+        scan_type,
+        file_options,
+        ..
+    } = plan.lp_arena.get(plan.lp_top)
+    else {
+        panic!("expected a scan");
+    };
+    assert_eq!(sources.as_paths().unwrap().len(), 5);
+    assert_eq!(
+        get_table_statistics(sources, scan_type, file_options),
+        Some(stats)
+    );
+
+    // Reading the files with other options is another table.
+    let q = LazyCsvReader::new(GLOB_CSV).with_n_rows(Some(2)).finish()?;
+    assert!(q.statistics().is_none());
+    let stats = q.clone().analyze()?;
+    assert_eq!(stats.num_rows, 2);
+    assert_eq!(q.statistics(), Some(stats));
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use super::*;
+
+#[test]
+fn test_materialized_view() -> PolarsResult<()> {
+    let sort_options = SortMultipleOptions::default().with_maintain_order(true);
+    let batches = [
+        df!["g" => ["a", "b", "a"], "v" => [1, 2, 3]]?,
+        df!["g" => ["b", "c"], "v" => [4, 5]]?,
+        df!["g" => ["a"], "v" => [6]]?,
+    ];
+    let queries: [(fn(LazyFrame) -> LazyFrame, bool); 3] = [
+        (
+            |lf| {
+                lf.filter(col("v").gt(lit(1)))
+                    .with_column((col("v") * lit(10)).alias("w"))
+            },
+            true,
+        ),
+        (
+            |lf| {
+                lf.group_by([col("g")]).agg([
+                    col("v").sum().alias("sum"),
+                    col("v").min().alias("min"),
+                    col("v").max().alias("max"),
+                    len(),
+                ])
+            },
+            true,
+        ),
+        (|lf| lf.group_by([col("g")]).agg([col("v").mean()]), false),
+    ];
+
+    for (query, is_incremental) in queries {
+        let mut view = MaterializedView::new(batches[0].clone(), query)?;
+        assert_eq!(view.is_incremental(), is_incremental);
+        let mut source = batches[0].clone();
+        for batch in &batches[1..] {
+            view.append(batch.clone())?;
+            source.vstack_mut(batch)?;
+        }
+
+        let names = view.result().get_column_names_owned();
+        let expected = query(source.lazy())
+            .sort(names.clone(), sort_options.clone())
+            .collect()?;
+        let out = view.lazy().sort(names, sort_options.clone()).collect()?;
+        assert!(out.equals(&expected));
+    }
+
+    // Appended rows must match the schema of the source.
+    let mut view = MaterializedView::new(batches[0].clone(), |lf| lf)?;
+    assert!(view.append(df!["v" => [1]]?).is_err());
+    Ok(())
+}
@@ -2,18 +2,39 @@ mod aggregations;
 mod arity;
 #[cfg(all(feature = "strings", feature = "cse"))]
 mod cse;
+mod diagnostics;
+mod execution_control;
+#[cfg(feature = "new_streaming")]
+mod instrumentation;
+#[cfg(any(feature = "substrait", feature = "datafusion"))]
+mod interop;
 #[cfg(feature = "parquet")]
 mod io;
 mod logical;
+mod materialized_views;
 mod optimization_checks;
 #[cfg(all(feature = "strings", feature = "cse"))]
 mod pdsh;
+#[cfg(feature = "new_streaming")]
+mod placeholders;
+#[cfg(all(
+    feature = "new_streaming",
+    feature = "cse",
+    feature = "csv",
+    feature = "is_in"
+))]
+mod plan_cache;
 mod predicate_queries;
 mod projection_queries;
 mod queries;
 mod schema;
+mod statistics;
 #[cfg(feature = "streaming")]
 mod streaming;
+#[cfg(feature = "new_streaming")]
+mod streaming_nodes;
+#[cfg(any(feature = "new_streaming", feature = "serde"))]
+mod udfs;
 
 fn get_arenas() -> (Arena<AExpr>, Arena<IR>) {
     let expr_arena = Arena::with_capacity(16);
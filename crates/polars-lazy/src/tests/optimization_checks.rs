@@ -675,3 +675,372 @@ fn test_cluster_with_columns_chain() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn test_join_reorder() -> PolarsResult<()> {
+    let fact = df![
+        "fk1" => (0..1000).collect::<Vec<i32>>(),
+        "fk2" => (0..1000).map(|i| i % 20).collect::<Vec<i32>>(),
+    ]?
+    .lazy();
+    let big_dim = df![
+        "id1" => (0..1000).collect::<Vec<i32>>(),
+        "v1" => (0..1000).map(|i| i * 2).collect::<Vec<i32>>(),
+    ]?
+    .lazy();
+    let small_dim = df![
+        "id2" => (0..10).collect::<Vec<i32>>(),
+        "v2" => (0..10).map(|i| i * 3).collect::<Vec<i32>>(),
+    ]?
+    .lazy();
+
+    let q = fact
+        .inner_join(big_dim, col("fk1"), col("id1"))
+        .inner_join(small_dim, col("fk2"), col("id2"))
+        .with_join_reorder(true);
+
+    // The small dimension is joined first.
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+    let first_join_right = (&lp_arena).iter(lp).find_map(|(_, ir)| match ir {
+        IR::Join {
+            input_left,
+            input_right,
+            ..
+        } if matches!(lp_arena.get(*input_left), IR::DataFrameScan { .. }) => Some(*input_right),
+        _ => None,
+    });
+    assert!(matches!(
+        lp_arena.get(first_join_right.unwrap()),
+        IR::DataFrameScan { df, .. } if df.height() == 10
+    ));
+
+    let out = q.clone().collect()?.sort(["fk1"], Default::default())?;
+    let expected = q
+        .with_join_reorder(false)
+        .collect()?
+        .sort(["fk1"], Default::default())?;
+    assert_eq!(out.get_column_names(), expected.get_column_names());
+    assert!(out.equals(&expected));
+    Ok(())
+}
+
+/// Replaces slices of a sentinel length, so that it doesn't affect other tests.
+struct ShortenSlice;
+
+impl OptimizationRule for ShortenSlice {
+    fn optimize_plan(
+        &mut self,
+        lp_arena: &mut Arena<IR>,
+        _expr_arena: &mut Arena<AExpr>,
+        node: Node,
+    ) -> PolarsResult<Option<IR>> {
+        Ok(match lp_arena.get(node) {
+            IR::Slice { input, offset, len } if *len == 12345 => Some(IR::Slice {
+                input: *input,
+                offset: *offset,
+                len: 1,
+            }),
+            _ => None,
+        })
+    }
+}
+
+#[test]
+fn test_custom_optimization_rule() -> PolarsResult<()> {
+    let df = df!["a" => [1, 2, 3, 4, 5]]?;
+    let q = df.lazy().slice(0, 12345);
+    let register = || {
+        register_optimization_rule(
+            "shorten_slice",
+            OptimizationStage::Start,
+            Arc::new(|| Box::new(ShortenSlice) as Box<dyn OptimizationRule>),
+        )
+    };
+
+    register();
+    assert_eq!(q.clone().collect()?.height(), 1);
+
+    assert!(unregister_optimization_rule("shorten_slice"));
+    assert!(!unregister_optimization_rule("shorten_slice"));
+    assert_eq!(q.clone().collect()?.height(), 5);
+
+    // Plans cached before a rule is registered are not reused after.
+    #[cfg(all(feature = "new_streaming", feature = "cse"))]
+    {
+        let _capacity = PlanCacheCapacityGuard::new(4);
+        let streaming = || q.clone().collect_with_engine(Engine::Streaming);
+        assert_eq!(streaming()?.height(), 5);
+        register();
+        assert_eq!(streaming()?.height(), 1);
+        assert!(unregister_optimization_rule("shorten_slice"));
+        assert_eq!(streaming()?.height(), 5);
+    }
+    Ok(())
+}
+
+#[cfg(all(feature = "semi_anti_join", feature = "is_in"))]
+fn has_join_type(q: LazyFrame, how: JoinType) -> PolarsResult<bool> {
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q.optimize(&mut lp_arena, &mut expr_arena)?;
+    Ok((&lp_arena)
+        .iter(lp)
+        .any(|(_, ir)| matches!(ir, IR::Join { options, .. } if options.args.how == how)))
+}
+
+#[test]
+#[cfg(all(feature = "semi_anti_join", feature = "is_in"))]
+fn test_in_subquery_to_semi_join() -> PolarsResult<()> {
+    let df = df!["a" => [1, 2, 3, 4, 5], "x" => [1, 1, 2, 2, 3]]?;
+    let sub = df!["b" => [Some(2), Some(4), None]]?.lazy();
+
+    for (negated, how) in [(false, JoinType::Semi), (true, JoinType::Anti)] {
+        let mut predicate = col("a").is_in(col("b"), false);
+        if negated {
+            predicate = predicate.not();
+        }
+        let q = df
+            .clone()
+            .lazy()
+            .with_context([sub.clone()])
+            .filter(predicate.and(col("x").gt(lit(1))))
+            .with_semi_join_reduction(true);
+        assert!(has_join_type(q.clone(), how)?);
+
+        let out = q.clone().collect()?;
+        let expected = q.with_semi_join_reduction(false).collect()?;
+        assert!(out.equals_missing(&expected));
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "semi_anti_join", feature = "is_in"))]
+fn test_left_join_to_anti_join() -> PolarsResult<()> {
+    use polars_ops::frame::JoinCoalesce;
+
+    let left = df!["a" => [Some(1), Some(2), Some(3), None]]?.lazy();
+    let right = df!["b" => [2, 3], "c" => ["x", "y"]]?.lazy();
+    let q = left
+        .join_builder()
+        .with(right)
+        .left_on([col("a")])
+        .right_on([col("b")])
+        .how(JoinType::Left)
+        .coalesce(JoinCoalesce::KeepColumns)
+        .finish()
+        .filter(col("b").is_null())
+        .with_semi_join_reduction(true);
+    assert!(has_join_type(q.clone(), JoinType::Anti)?);
+
+    let out = q.clone().collect()?;
+    let expected = q.with_semi_join_reduction(false).collect()?;
+    assert_eq!(out.get_column_names(), ["a", "b", "c"]);
+    assert_eq!(out.height(), 2);
+    assert!(out.equals_missing(&expected));
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "semi_anti_join", feature = "is_in", feature = "parquet"))]
+fn test_join_keys_pushed_to_scan() -> PolarsResult<()> {
+    let dim = df!["category" => ["fruit", "meat"], "x" => [1, 2]]?.lazy();
+    let q = scan_foods_parquet(false)
+        .inner_join(dim, col("category"), col("category"))
+        .with_semi_join_reduction(true);
+
+    assert!(predicate_at_scan(q.clone()));
+    assert!(!predicate_at_scan(
+        q.clone().with_semi_join_reduction(false)
+    ));
+
+    let sort = |df: DataFrame| df.sort(df.get_column_names_owned(), Default::default());
+    let out = sort(q.clone().collect()?)?;
+    let expected = sort(q.with_semi_join_reduction(false).collect()?)?;
+    assert!(out.equals_missing(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_sorted_join_keys() -> PolarsResult<()> {
+    use polars_core::series::IsSorted;
+
+    let left = df!["k" => [4, 1, 3, 2], "x" => [1, 2, 3, 4]]?.sort(["k"], Default::default())?;
+    let right = df!["k" => [3, 1, 5], "y" => [1, 2, 3]]?;
+
+    // The inputs of the joins with their key flagged as sorted.
+    let sorted_inputs = |q: LazyFrame| -> PolarsResult<usize> {
+        let (mut expr_arena, mut lp_arena) = get_arenas();
+        let lp = q.optimize(&mut lp_arena, &mut expr_arena)?;
+        let Some((
+            _,
+            IR::Join {
+                input_left,
+                input_right,
+                ..
+            },
+        )) = (&lp_arena)
+            .iter(lp)
+            .find(|(_, ir)| matches!(ir, IR::Join { .. }))
+        else {
+            panic!("expected a join");
+        };
+        Ok([*input_left, *input_right]
+            .into_iter()
+            .filter(|input| {
+                matches!(lp_arena.get(*input), IR::HStack { exprs, .. } if exprs.iter().any(|e| {
+                    matches!(
+                        expr_arena.get(e.node()),
+                        AExpr::Function {
+                            function: FunctionExpr::SetSortedFlag(IsSorted::Ascending),
+                            ..
+                        }
+                    )
+                }))
+            })
+            .count())
+    };
+
+    // The left key is flagged as sorted, the right one is sorted by the query.
+    let q = left.clone().lazy().inner_join(
+        right.clone().lazy().sort(["k"], Default::default()),
+        col("k"),
+        col("k"),
+    );
+    assert_eq!(sorted_inputs(q.clone())?, 2);
+    let out = q.collect()?;
+    assert_eq!(
+        out.column("k")?
+            .i32()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        [1, 3]
+    );
+
+    // Both keys must be sorted.
+    let q = left.lazy().inner_join(right.lazy(), col("k"), col("k"));
+    assert_eq!(sorted_inputs(q)?, 0);
+    Ok(())
+}
+
+fn num_sorts(q: LazyFrame) -> PolarsResult<usize> {
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q.optimize(&mut lp_arena, &mut expr_arena)?;
+    Ok((&lp_arena)
+        .iter(lp)
+        .filter(|(_, ir)| matches!(ir, IR::Sort { .. }))
+        .count())
+}
+
+#[test]
+fn test_sort_of_sorted_input_is_removed() -> PolarsResult<()> {
+    use polars_core::series::IsSorted;
+
+    let df = df!["a" => [1, 2, 3, 4, 5], "b" => [5, 3, 4, 1, 2]]?;
+    let sorted = df
+        .clone()
+        .lazy()
+        .with_columns([col("a").set_sorted_flag(IsSorted::Ascending)])
+        .filter(col("b").gt(lit(1)));
+
+    let q = sorted.clone().sort(["a"], Default::default()).slice(1, 2);
+    assert_eq!(num_sorts(q.clone())?, 0);
+    let out = q.collect()?;
+    assert_eq!(
+        out.column("a")?
+            .i32()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        [2, 3]
+    );
+
+    // A different order still needs to be sorted.
+    let descending = SortMultipleOptions::default().with_order_descending(true);
+    assert_eq!(num_sorts(sorted.clone().sort(["a"], descending))?, 1);
+    assert_eq!(num_sorts(sorted.sort(["b"], Default::default()))?, 1);
+
+    // The output of a sort is sorted.
+    let q = df
+        .lazy()
+        .sort(["b"], Default::default())
+        .select([col("b"), col("a")])
+        .sort(["b"], Default::default());
+    assert_eq!(num_sorts(q.clone())?, 1);
+    let out = q.collect()?;
+    assert_eq!(
+        out.column("b")?
+            .i32()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        [1, 2, 3, 4, 5]
+    );
+    Ok(())
+}
+
+#[cfg(all(feature = "parquet", feature = "dtype-categorical"))]
+fn num_categorical_scan_columns(q: LazyFrame) -> PolarsResult<usize> {
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q.optimize(&mut lp_arena, &mut expr_arena)?;
+    Ok((&lp_arena)
+        .iter(lp)
+        .filter(|(_, ir)| matches!(ir, IR::Scan { .. }))
+        .map(|(_, ir)| {
+            ir.schema(&lp_arena)
+                .iter_values()
+                .filter(|dtype| dtype.is_categorical())
+                .count()
+        })
+        .sum())
+}
+
+#[test]
+#[cfg(all(
+    feature = "parquet",
+    feature = "dtype-categorical",
+    feature = "strings"
+))]
+fn test_dictionary_strings() -> PolarsResult<()> {
+    let q = scan_foods_parquet(false)
+        .filter(col("calories").gt(lit(50)))
+        .group_by([col("category")])
+        .agg([col("calories").sum()]);
+
+    // The Parquet reader decodes the dictionaries of the column.
+    assert_eq!(
+        num_categorical_scan_columns(q.clone().with_dictionary_strings(true))?,
+        1
+    );
+    assert_eq!(num_categorical_scan_columns(q.clone())?, 0);
+
+    let out = q.clone().with_dictionary_strings(true).collect()?;
+    let expected = q.collect()?;
+    assert_eq!(out.column("category")?.dtype(), &DataType::String);
+    assert!(
+        out.sort(["category"], Default::default())?
+            .equals_missing(&expected.sort(["category"], Default::default())?)
+    );
+
+    // Columns that the filter of the scan uses are read as strings.
+    let q = scan_foods_parquet(false)
+        .filter(col("category").eq(lit("fruit")))
+        .select([col("category"), col("calories")]);
+    assert_eq!(
+        num_categorical_scan_columns(q.clone().with_dictionary_strings(true))?,
+        0
+    );
+
+    // Columns that are used by other operations are materialized before them.
+    let q = scan_foods_parquet(false)
+        .with_dictionary_strings(true)
+        .select([
+            col("category"),
+            col("category")
+                .str()
+                .starts_with(lit("fr"))
+                .alias("is_fruit"),
+        ]);
+    let out = q.collect()?;
+    assert_eq!(out.column("category")?.dtype(), &DataType::String);
+    assert_eq!(out.column("is_fruit")?.bool()?.sum(), Some(7));
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use super::*;
+
+#[test]
+fn test_placeholder() -> PolarsResult<()> {
+    let df = df!["a" => [1i64, 2, 3, 4, 5]]?;
+    let q = df
+        .lazy()
+        .filter(col("a").gt(placeholder("min", DataType::Int64)));
+    let params = |min: i32| PlHashMap::from_iter([("min".into(), Scalar::from(min))]);
+
+    assert_eq!(q.clone().collect_with_params(params(2))?.height(), 3);
+    assert_eq!(q.clone().collect_with_params(params(4))?.height(), 1);
+
+    // Unbound placeholders are reported when the query is collected.
+    let err = q
+        .clone()
+        .collect_with_params(PlHashMap::default())
+        .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("no value given for placeholder `min`"),
+        "{err}"
+    );
+    assert!(q.clone().collect().is_err());
+
+    // So are values that can't be cast to the dtype of the placeholder.
+    let value = Scalar::new(DataType::String, AnyValue::StringOwned("two".into()));
+    let params = PlHashMap::from_iter([("min".into(), value)]);
+    assert!(q.collect_with_params(params).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_placeholder_in_memory_nodes() -> PolarsResult<()> {
+    let df = df!["k" => [1, 1, 2], "v" => [1i64, 2, 3]]?;
+    let params = |p: i64| PlHashMap::from_iter([("p".into(), Scalar::from(p))]);
+
+    // Scalar aggregations and unsupported group-by aggregations are lowered to nodes with
+    // physical expressions of the in-memory engine.
+    let sum = df
+        .clone()
+        .lazy()
+        .select([(col("v").sum() + placeholder("p", DataType::Int64)).alias("v")]);
+    let median = df
+        .lazy()
+        .group_by_stable([col("k")])
+        .agg([(col("v").median() + placeholder("p", DataType::Float64)).alias("v")]);
+    for p in [10, 20] {
+        let out = sum.clone().collect_with_params(params(p))?;
+        assert_eq!(out.column("v")?.i64()?.get(0), Some(6 + p));
+
+        let out = median.clone().collect_with_params(params(p))?;
+        let expected = [Some(1.5 + p as f64), Some(3.0 + p as f64)];
+        assert_eq!(Vec::from(out.column("v")?.f64()?), expected);
+    }
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use super::*;
+
+#[test]
+fn test_plan_cache() -> PolarsResult<()> {
+    let _capacity = PlanCacheCapacityGuard::new(4);
+    // Queries on in-memory data aren't cached, so this scans a file.
+    let streaming = |q: LazyFrame| q.collect_with_engine(Engine::Streaming);
+    let query = |threshold: i64| scan_foods_csv().filter(col("calories").gt(lit(threshold)));
+
+    let first = streaming(query(100))?;
+    // The second run reuses the physical plan of the first.
+    let second = streaming(query(100))?;
+    assert!(first.equals(&second));
+    assert!(first.equals(&query(100).collect()?));
+    // A different literal is a different plan.
+    assert!(streaming(query(50))?.equals(&query(50).collect()?));
+
+    // Also if the literals only differ in one of many values.
+    let is_in = |last: i64| {
+        let values = Series::new("".into(), (1000..1100).chain([last]).collect::<Vec<_>>());
+        scan_foods_csv().filter(col("calories").is_in(lit(values), false))
+    };
+    assert!(streaming(is_in(100))?.equals(&is_in(100).collect()?));
+    assert!(streaming(is_in(0))?.equals(&is_in(0).collect()?));
+    assert_eq!(streaming(is_in(0))?.height(), 0);
+    Ok(())
+}
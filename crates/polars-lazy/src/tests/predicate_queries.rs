@@ -84,6 +84,29 @@ fn test_pass_unrelated_apply() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_pass_elementwise_map_many() -> PolarsResult<()> {
+    let df = fruits_cars();
+
+    let sum = map_many(
+        [col("A"), col("B")],
+        |s| &s[0] + &s[1],
+        DataType::Int32,
+        true,
+    )
+    .alias("sum");
+    let q = df.lazy().with_column(sum).filter(col("B").gt(lit(2i32)));
+
+    assert!(predicate_at_scan(q.clone()));
+    let out = q.collect()?;
+    assert_eq!(
+        out.column("sum")?.as_materialized_series(),
+        &Series::new("sum".into(), [6i32, 6, 6])
+    );
+
+    Ok(())
+}
+
 #[test]
 fn filter_added_column_issue_2470() -> PolarsResult<()> {
     let df = fruits_cars();
@@ -0,0 +1,37 @@
+use super::*;
+
+#[test]
+fn test_analyze() -> PolarsResult<()> {
+    let q = df![
+        "a" => [Some(3), None, Some(1), Some(3)],
+        "b" => ["x", "y", "x", "x"],
+        // The statistics of other columns don't clash with it.
+        "a:min" => [7, 8, 9, 10],
+    ]?
+    .lazy();
+
+    let stats = q.clone().analyze()?;
+    assert_eq!(stats.num_rows, 4);
+    let a = stats.column("a").unwrap();
+    assert_eq!(a.null_count, 1);
+    assert_eq!(a.n_unique, 3);
+    assert_eq!(
+        a.min,
+        Some(Scalar::new(DataType::Int32, AnyValue::Int32(1)))
+    );
+    assert_eq!(
+        a.max,
+        Some(Scalar::new(DataType::Int32, AnyValue::Int32(3)))
+    );
+    let b = stats.column("b").unwrap();
+    assert_eq!(b.null_count, 0);
+    assert_eq!(b.n_unique, 2);
+    assert_eq!(
+        stats.column("a:min").unwrap().min,
+        Some(Scalar::new(DataType::Int32, AnyValue::Int32(7)))
+    );
+
+    // Only file scans are stored in the catalog.
+    assert!(q.statistics().is_none());
+    Ok(())
+}
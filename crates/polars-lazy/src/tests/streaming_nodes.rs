@@ -0,0 +1,332 @@
+use super::*;
+
+#[test]
+fn test_streaming_top_k() -> PolarsResult<()> {
+    let df = df![
+        "a" => (0..50_000i64).map(|i| (i * 7919) % 50_000).collect::<Vec<_>>(),
+        "b" => (0..50_000i64).collect::<Vec<_>>(),
+    ]?;
+    let sort_options = SortMultipleOptions::default().with_order_descending(true);
+    let q = df.lazy().sort(["a"], sort_options).slice(2, 5);
+
+    let dot = q.clone().explain_streaming(PhysicalPlanFormat::Dot)?;
+    assert!(dot.contains("top-k"));
+
+    let out = q.clone().collect_with_engine(Engine::Streaming)?;
+    let expected = q.collect_with_engine(Engine::InMemory)?;
+    assert!(out.equals(&expected));
+    assert_eq!(
+        out.column("a")?
+            .i64()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        [49_997, 49_996, 49_995, 49_994, 49_993]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "pivot")]
+fn test_streaming_unpivot() -> PolarsResult<()> {
+    let n = 50_000i64;
+    let df = df![
+        "id" => (0..n).collect::<Vec<_>>(),
+        "a" => (0..n).map(|i| i as i32).collect::<Vec<_>>(),
+        "b" => (0..n).map(|i| (i * 2) as f64).collect::<Vec<_>>(),
+    ]?;
+    let args = UnpivotArgsDSL {
+        index: vec!["id".into()],
+        ..Default::default()
+    };
+    let q = df
+        .lazy()
+        .unpivot(args)
+        .with_row_index("row", None)
+        .filter(col("id").lt(lit(n - 10)));
+
+    let dot = q.clone().explain_streaming(PhysicalPlanFormat::Dot)?;
+    assert!(dot.contains("unpivot"));
+    assert!(!dot.contains("in-memory-map"));
+
+    // The streaming engine unpivots every morsel on its own, so only the set of rows matches.
+    let sort_options = SortMultipleOptions::default();
+    let out = q
+        .clone()
+        .drop(["row"])
+        .sort(["id", "variable"], sort_options.clone())
+        .collect_with_engine(Engine::Streaming)?;
+    let expected = q
+        .clone()
+        .drop(["row"])
+        .sort(["id", "variable"], sort_options)
+        .collect_with_engine(Engine::InMemory)?;
+    assert!(out.equals(&expected));
+    assert_eq!(out.column("value")?.dtype(), &DataType::Float64);
+
+    let rows = q.collect_with_engine(Engine::Streaming)?;
+    assert_eq!(rows.height(), 2 * (n as usize - 10));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dynamic_group_by")]
+fn test_streaming_group_by_dynamic() -> PolarsResult<()> {
+    // Spans several morsels, and overlapping windows are split over the flushes.
+    let n = 250_000;
+    let df = df![
+        "t" => (0..n).map(|i| i / 3).collect::<Vec<i64>>(),
+        "a" => (0..n).map(|i| i % 11).collect::<Vec<i64>>(),
+    ]?;
+    let options = QueryOptions {
+        flush_deadline: Some(std::time::Duration::ZERO),
+        ..Default::default()
+    };
+
+    let q = df
+        .clone()
+        .lazy()
+        .group_by_dynamic(
+            col("t"),
+            [],
+            DynamicGroupOptions {
+                every: Duration::parse("7i"),
+                period: Duration::parse("20i"),
+                offset: Duration::parse("0i"),
+                include_boundaries: true,
+                ..Default::default()
+            },
+        )
+        .agg([col("a").sum(), col("a").count().alias("n")]);
+    let expected = q.clone().collect()?;
+    assert!(q.collect_with_options(options.clone())?.equals(&expected));
+
+    let q = df
+        .lazy()
+        .rolling(
+            col("t"),
+            [],
+            RollingGroupOptions {
+                period: Duration::parse("5i"),
+                offset: Duration::parse("-5i"),
+                ..Default::default()
+            },
+        )
+        .agg([col("a").sum(), col("a").count().alias("n")]);
+    let expected = q.clone().collect()?;
+    assert!(q.collect_with_options(options)?.equals(&expected));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dynamic_group_by")]
+fn test_streaming_group_by_dynamic_empty() -> PolarsResult<()> {
+    // Without rows nothing is flushed when the input ends.
+    let q = df!["t" => Vec::<i64>::new(), "a" => Vec::<i64>::new()]?
+        .lazy()
+        .group_by_dynamic(
+            col("t"),
+            [],
+            DynamicGroupOptions {
+                every: Duration::parse("10i"),
+                period: Duration::parse("10i"),
+                offset: Duration::parse("0i"),
+                ..Default::default()
+            },
+        )
+        .agg([col("a").sum()]);
+    let out = q.collect_with_options(QueryOptions::default())?;
+    assert_eq!(out.height(), 0);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "merge_sorted")]
+fn test_streaming_merge_sorted_many() -> PolarsResult<()> {
+    use crate::dsl::functions::merge_sorted;
+
+    // Several morsels per input, keys shared within and across the inputs, and null keys.
+    let input = |k: i64, leading_nulls: bool| -> PolarsResult<LazyFrame> {
+        let n = 150_000;
+        let nulls = std::iter::repeat_n(None, 1_000);
+        let keys = (0..n).map(|i| Some(i * (k + 1) / 7));
+        let keys: Vec<Option<i64>> = if leading_nulls {
+            nulls.chain(keys).collect()
+        } else {
+            keys.chain(nulls).collect()
+        };
+        let id = (0..keys.len() as i64).map(|i| k * 1_000_000 + i);
+        Ok(df!["key" => keys, "id" => id.collect::<Vec<_>>()]?.lazy())
+    };
+
+    for leading_nulls in [false, true] {
+        let inputs = (0..4)
+            .map(|k| input(k, leading_nulls))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let out = merge_sorted(&inputs, "key")?.collect_with_options(QueryOptions::default())?;
+
+        let sort_options = SortMultipleOptions::default()
+            .with_nulls_last(!leading_nulls)
+            .with_maintain_order(true);
+        let expected = concat(&inputs, UnionArgs::default())?
+            .sort(["key"], sort_options.clone())
+            .collect()?;
+        assert_eq!(out.height(), expected.height());
+        assert!(out.column("key")?.equals_missing(expected.column("key")?));
+
+        // Rows with equal keys may come from the inputs in any order.
+        let out = out
+            .lazy()
+            .sort(["key", "id"], sort_options.clone())
+            .collect()?;
+        let expected = expected
+            .lazy()
+            .sort(["key", "id"], sort_options)
+            .collect()?;
+        assert!(out.equals_missing(&expected));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_streaming_unique_spill_threshold() -> PolarsResult<()> {
+    let n = 250_000;
+    let df = df![
+        "a" => (0..n).map(|i| i % 1_000).collect::<Vec<i64>>(),
+        "b" => (0..n).collect::<Vec<i64>>(),
+    ]?;
+    // Every morsel is spilled, the first and last occurrences must still be found.
+    let options = QueryOptions {
+        spill_threshold: Some(0),
+        ..Default::default()
+    };
+    for keep in [UniqueKeepStrategy::First, UniqueKeepStrategy::Last] {
+        let q = df
+            .clone()
+            .lazy()
+            .unique_stable(Some(vec!["a".into()]), keep);
+        let expected = q.clone().collect()?;
+        assert!(q.collect_with_options(options.clone())?.equals(&expected));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_query_hints() -> PolarsResult<()> {
+    let left = df!["a" => [1, 2, 3], "b" => ["x", "y", "z"]]?.lazy();
+    let right = df!["a" => [2, 3, 4], "c" => [true, false, true]]?.lazy();
+    let sort = |df: DataFrame| df.sort(["a"], Default::default());
+    let expected = sort(
+        left.clone()
+            .inner_join(right.clone(), col("a"), col("a"))
+            .collect()?,
+    )?;
+
+    for (strategy, build) in [
+        (JoinStrategy::Broadcast, "build: right"),
+        (JoinStrategy::Stream, "build: left"),
+    ] {
+        let q = left
+            .clone()
+            .inner_join(right.clone().hint(strategy), col("a"), col("a"));
+        let dot = q.clone().explain_streaming(PhysicalPlanFormat::Dot)?;
+        assert!(dot.contains("equi-join"));
+        assert!(dot.contains(build));
+        assert!(sort(q.clone().collect_with_engine(Engine::Streaming)?)?.equals(&expected));
+        assert!(sort(q.collect_with_engine(Engine::InMemory)?)?.equals(&expected));
+    }
+
+    let q = left.hint(NoStreaming).filter(col("a").gt(lit(1)));
+    let dot = q.clone().explain_streaming(PhysicalPlanFormat::Dot)?;
+    assert!(dot.contains("in-memory-subplan"));
+    let out = q.collect_with_engine(Engine::Streaming)?;
+    assert_eq!(
+        out.column("a")?
+            .i32()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        [2, 3]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "approx_unique")]
+fn test_approx_n_unique_sketches() -> PolarsResult<()> {
+    let a = df!["v" => (0..10_000i64).collect::<Vec<_>>()]?;
+    let b = df!["v" => (5_000..20_000i64).collect::<Vec<_>>()]?;
+    let union = concat([a.clone().lazy(), b.clone().lazy()], UnionArgs::default())?;
+
+    let q = union.clone().select([col("v").approx_n_unique()]);
+    let expected = q.clone().collect()?;
+    let out = q.collect_with_engine(Engine::Streaming)?;
+    assert!(out.equals(&expected));
+
+    // Merging the sketches of the parts gives the estimate of the union.
+    let sketch_q = union.select([col("v").approx_n_unique_sketch()]);
+    let sketch = sketch_q.clone().collect()?;
+    assert_eq!(sketch.column("v")?.dtype(), &DataType::Binary);
+    assert!(sketch.equals(&sketch_q.collect_with_engine(Engine::Streaming)?));
+
+    let sketches = [a, b]
+        .into_iter()
+        .map(|df| df.lazy().select([col("v").approx_n_unique_sketch()]))
+        .collect::<Vec<_>>();
+    let sketches = concat(sketches, UnionArgs::default())?;
+    let merged = sketches
+        .clone()
+        .select([col("v").approx_n_unique_merge()])
+        .collect()?;
+    assert!(merged.equals(&sketch));
+    let estimate = sketches
+        .select([col("v").approx_n_unique_estimate()])
+        .collect()?;
+    assert!(estimate.equals(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_streaming_runtime() -> PolarsResult<()> {
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    use polars_io::pl_async::get_runtime;
+
+    // Runs on the default Tokio runtime, counting the queries that block on it.
+    struct CountingRuntime(AtomicUsize);
+
+    impl StreamingRuntime for CountingRuntime {
+        fn spawn(&self, future: Pin<Box<dyn Future<Output = ()> + Send>>) {
+            get_runtime().spawn(future);
+        }
+
+        fn spawn_blocking(&self, f: Box<dyn FnOnce() + Send>) {
+            get_runtime().spawn_blocking(f);
+        }
+
+        fn block_on(&self, future: Pin<Box<dyn Future<Output = ()> + '_>>) {
+            self.0.fetch_add(1, Ordering::Relaxed);
+            get_runtime().block_on(future)
+        }
+
+        fn sleep(&self, duration: Duration) -> Pin<Box<dyn Future<Output = ()> + Send>> {
+            let timer = get_runtime().spawn_blocking(move || std::thread::sleep(duration));
+            Box::pin(async move {
+                let _ = timer.await;
+            })
+        }
+    }
+
+    let runtime = Arc::new(CountingRuntime(AtomicUsize::new(0)));
+    set_streaming_runtime(Some(runtime.clone()));
+    let out = df!["a" => [1, 2, 3]]?
+        .lazy()
+        .select([col("a").sum()])
+        .collect_with_engine(Engine::Streaming);
+    set_streaming_runtime(None);
+
+    assert!(out?.equals(&df!["a" => [6]]?));
+    assert!(runtime.0.load(Ordering::Relaxed) > 0);
+    Ok(())
+}
@@ -0,0 +1,149 @@
+use super::*;
+
+#[test]
+#[cfg(feature = "new_streaming")]
+fn test_streaming_operator() -> PolarsResult<()> {
+    use std::collections::HashSet;
+
+    struct Dedupe {
+        seen: HashSet<i32>,
+    }
+
+    #[async_trait::async_trait]
+    impl StreamingOperator for Dedupe {
+        async fn process(&mut self, df: DataFrame) -> PolarsResult<DataFrame> {
+            let mask: BooleanChunked = df
+                .column("a")?
+                .i32()?
+                .into_no_null_iter()
+                .map(|v| self.seen.insert(v))
+                .collect();
+            df.filter(&mask)
+        }
+
+        async fn finish(&mut self) -> PolarsResult<Option<DataFrame>> {
+            Ok(Some(df!["a" => [self.seen.len() as i32]]?))
+        }
+    }
+
+    register_streaming_operator(
+        "dedupe",
+        Arc::new(|| {
+            Box::new(Dedupe {
+                seen: HashSet::new(),
+            }) as Box<dyn StreamingOperator>
+        }),
+    );
+    let q = df!["a" => [1, 2, 1, 3, 2]]?
+        .lazy()
+        .map_streaming_operator("dedupe", None);
+    let expected = df!["a" => [1, 2, 3, 3]]?;
+    assert!(
+        q.clone()
+            .collect_with_engine(Engine::Streaming)?
+            .equals(&expected)
+    );
+    assert!(q.collect_with_engine(Engine::InMemory)?.equals(&expected));
+    assert!(unregister_streaming_operator("dedupe"));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn test_registered_udf_serde() -> PolarsResult<()> {
+    register_udf(
+        "test_add_one",
+        DataType::Int32,
+        UdfKind::Elementwise,
+        Arc::new(|s: &[Series]| Ok(&s[0] + 1)),
+    );
+    register_udf(
+        "test_sum",
+        DataType::Int32,
+        UdfKind::Aggregation,
+        Arc::new(|s: &[Series]| {
+            s[0].sum_reduce()
+                .map(|sc| sc.into_series(s[0].name().clone()))
+        }),
+    );
+
+    let q = df!["g" => ["a", "b", "a"], "v" => [1i32, 2, 3]]?
+        .lazy()
+        .group_by_stable([col("g")])
+        .agg([call_registered_udf(
+            "test_sum",
+            vec![call_registered_udf("test_add_one", vec![col("v")])?],
+        )?]);
+    let expected = df!["g" => ["a", "b"], "v" => [6i32, 3]]?;
+
+    let mut buf = vec![];
+    q.logical_plan.serialize_versioned(&mut buf)?;
+    let plan = DslPlan::deserialize_versioned(buf.as_slice())?;
+    let out = LazyFrame::from(plan).collect()?;
+    assert!(out.equals(&expected));
+
+    // Deserialized plans look the UDFs up by name.
+    assert!(unregister_udf("test_add_one"));
+    assert!(unregister_udf("test_sum"));
+    let plan = DslPlan::deserialize_versioned(buf.as_slice())?;
+    assert!(LazyFrame::from(plan).collect().is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "serde", feature = "rolling_window"))]
+fn test_rolling_registered_udf() -> PolarsResult<()> {
+    register_udf(
+        "test_range",
+        DataType::Float64,
+        UdfKind::Aggregation,
+        Arc::new(|s: &[Series]| {
+            let max = s[0].max::<f64>()?.unwrap();
+            let min = s[0].min::<f64>()?.unwrap();
+            Ok(Series::new(s[0].name().clone(), [max - min]))
+        }),
+    );
+    let options = RollingOptionsFixedWindow {
+        window_size: 2,
+        min_periods: 2,
+        ..Default::default()
+    };
+
+    let q = df!["v" => [1i32, 4, 2, 8]]?
+        .lazy()
+        .select([rolling_registered_udf(
+            "test_range",
+            col("v"),
+            options.clone(),
+        )?]);
+    let expected = df!["v" => [None, Some(3.0), Some(2.0), Some(6.0)]]?;
+
+    let mut buf = vec![];
+    q.logical_plan.serialize_versioned(&mut buf)?;
+    let plan = DslPlan::deserialize_versioned(buf.as_slice())?;
+    let out = LazyFrame::from(plan).collect()?;
+    assert!(out.equals_missing(&expected));
+
+    // Closures may compute a statistic of another dtype than the values.
+    let n_unique = |s: &Series| -> PolarsResult<Series> {
+        Ok(Series::new(s.name().clone(), [s.n_unique()? as u32]))
+    };
+    let out = df!["v" => ["a", "b", "b", "b"]]?
+        .lazy()
+        .select([col("v").rolling_map_udf(n_unique, DataType::UInt32, options)])
+        .collect()?;
+    let expected = df!["v" => [None, Some(2u32), Some(1), Some(1)]]?;
+    assert!(out.equals_missing(&expected));
+
+    // Only aggregations can be called on windows.
+    register_udf(
+        "test_identity",
+        DataType::Int32,
+        UdfKind::Elementwise,
+        Arc::new(|s: &[Series]| Ok(s[0].clone())),
+    );
+    assert!(rolling_registered_udf("test_identity", col("v"), Default::default()).is_err());
+    assert!(unregister_udf("test_range"));
+    assert!(unregister_udf("test_identity"));
+    Ok(())
+}
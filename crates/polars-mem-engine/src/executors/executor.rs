@@ -1,3 +1,5 @@
+use polars_error::ErrorContext;
+
 use super::*;
 
 // Executor are the executors of the physical plan and produce DataFrames. They
@@ -43,6 +45,29 @@ impl Executor for SinkExecutor {
     }
 }
 
+/// Attaches the plan node it was created from to the errors of the wrapped executor.
+pub struct PlanNodeExec {
+    pub node: Node,
+    pub name: &'static str,
+    pub input: Box<dyn Executor>,
+}
+
+impl Executor for PlanNodeExec {
+    fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        self.input.execute(state).map_err(|err| {
+            // Only the innermost node is attached, the nodes above it merely propagate the error.
+            if err.plan_node().is_some() {
+                err
+            } else {
+                err.with_context(ErrorContext::PlanNode {
+                    id: self.node.0,
+                    name: Cow::Borrowed(self.name),
+                })
+            }
+        })
+    }
+}
+
 pub struct Dummy {}
 impl Executor for Dummy {
     fn execute(&mut self, _cache: &mut ExecutionState) -> PolarsResult<DataFrame> {
@@ -41,7 +41,7 @@ impl FilterExec {
         if self.has_window {
             state.insert_has_window_function_flag()
         }
-        let c = self.predicate.evaluate(&df, state)?;
+        let c = evaluate_expr(self.predicate.as_ref(), &df, state)?;
         if self.has_window {
             state.clear_window_expr_cache()
         }
@@ -57,7 +57,7 @@ impl FilterExec {
         state: &ExecutionState,
     ) -> PolarsResult<DataFrame> {
         let iter = chunks.into_par_iter().map(|df| {
-            let c = self.predicate.evaluate(&df, state)?;
+            let c = evaluate_expr(self.predicate.as_ref(), &df, state)?;
 
             // @scalar-opt
             // @partition-opt
@@ -62,7 +62,7 @@ fn compute_keys(
         .iter()
         .map(|s| s.evaluate(df, state))
         .collect::<PolarsResult<_>>()?;
-    let df = check_expand_literals(df, keys, evaluated, false, Default::default())?;
+    let df = check_expand_literals(df, keys, evaluated, false, Default::default(), state)?;
     Ok(df.take_columns())
 }
 
@@ -37,7 +37,14 @@ impl ProjectionExec {
                     self.has_windows,
                     self.options.run_parallel,
                 )?;
-                check_expand_literals(&df, &self.expr, selected_cols, df.is_empty(), self.options)
+                check_expand_literals(
+                    &df,
+                    &self.expr,
+                    selected_cols,
+                    df.is_empty(),
+                    self.options,
+                    state,
+                )
             });
 
             let df = POOL.install(|| iter.collect::<PolarsResult<Vec<_>>>())?;
@@ -53,7 +60,14 @@ impl ProjectionExec {
                 self.has_windows,
                 self.options.run_parallel,
             )?;
-            check_expand_literals(&df, &self.expr, selected_cols, df.is_empty(), self.options)?
+            check_expand_literals(
+                &df,
+                &self.expr,
+                selected_cols,
+                df.is_empty(),
+                self.options,
+                state,
+            )?
         };
 
         // this only runs during testing and check if the runtime type matches the predicted schema
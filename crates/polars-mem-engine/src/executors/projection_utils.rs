@@ -1,3 +1,4 @@
+use polars_error::{ErrorContext, polars_diagnostic};
 use polars_plan::constants::CSE_REPLACED;
 use polars_utils::itertools::Itertools;
 
@@ -13,6 +14,21 @@ pub(super) fn profile_name(
     }
 }
 
+/// Evaluates `expr`, attaching the expression to the error if it fails.
+pub(super) fn evaluate_expr(
+    expr: &dyn PhysicalExpr,
+    df: &DataFrame,
+    state: &ExecutionState,
+) -> PolarsResult<Column> {
+    expr.evaluate(df, state)
+        .map_err(|err| match expr.as_expression() {
+            Some(e) if err.expr().is_none() => {
+                err.with_context(ErrorContext::Expr(format!("{e:?}")))
+            },
+            _ => err,
+        })
+}
+
 type IdAndExpression = (u32, Arc<dyn PhysicalExpr>);
 
 #[cfg(feature = "dynamic_group_by")]
@@ -193,7 +209,7 @@ fn execute_projection_cached_window_fns(
     let mut selected_columns = POOL.install(|| {
         other
             .par_iter()
-            .map(|(idx, expr)| expr.evaluate(df, state).map(|s| (*idx, s)))
+            .map(|(idx, expr)| evaluate_expr(*expr, df, state).map(|s| (*idx, s)))
             .collect::<PolarsResult<Vec<_>>>()
     })?;
 
@@ -237,7 +253,7 @@ fn run_exprs_par(
     POOL.install(|| {
         exprs
             .par_iter()
-            .map(|expr| expr.evaluate(df, state))
+            .map(|expr| evaluate_expr(expr.as_ref(), df, state))
             .collect()
     })
 }
@@ -247,7 +263,10 @@ fn run_exprs_seq(
     exprs: &[Arc<dyn PhysicalExpr>],
     state: &ExecutionState,
 ) -> PolarsResult<Vec<Column>> {
-    exprs.iter().map(|expr| expr.evaluate(df, state)).collect()
+    exprs
+        .iter()
+        .map(|expr| evaluate_expr(expr.as_ref(), df, state))
+        .collect()
 }
 
 pub(super) fn evaluate_physical_expressions(
@@ -280,6 +299,7 @@ pub(super) fn check_expand_literals(
     mut selected_columns: Vec<Column>,
     zero_length: bool,
     options: ProjectionOptions,
+    state: &ExecutionState,
 ) -> PolarsResult<DataFrame> {
     let Some(first_len) = selected_columns.first().map(|s| s.len()) else {
         return Ok(DataFrame::empty());
@@ -353,6 +373,12 @@ pub(super) fn check_expand_literals(
                                         series.name(), series.len(), df_height *(!has_empty as usize), identifier
                                     );
                             }
+                            if !phys.is_scalar() {
+                                polars_diagnostic!(
+                                    state.diagnostics.as_deref(), LengthMismatchBroadcast: "broadcast Series {} of length {} to the DataFrame height of {}",
+                                    series.name(), series.len(), df_height * (!has_empty as usize)
+                                )?;
+                            }
                             series.new_from_index(0, df_height * (!has_empty as usize) )
                         }
                     },
@@ -3,12 +3,16 @@ use polars_core::config;
 #[cfg(feature = "cloud")]
 use polars_core::config::{get_file_prefetch_size, verbose};
 use polars_core::utils::accumulate_dataframes_vertical;
-use polars_error::feature_gated;
+use polars_error::{ErrorContext, feature_gated};
 use polars_io::RowIndex;
 use polars_io::cloud::CloudOptions;
 use polars_io::parquet::metadata::FileMetadataRef;
 use polars_io::predicates::{ScanIOPredicate, SkipBatchPredicate};
 use polars_io::utils::slice::split_slice_at_file;
+#[cfg(feature = "cast_policy")]
+use polars_ops::frame::cast_policy::{
+    CastPolicy, MissingColumns, cast_column, check_extra_columns,
+};
 
 use super::*;
 use crate::ScanPredicate;
@@ -58,6 +62,29 @@ impl ParquetExec {
         }
     }
 
+    fn file_context(&self, source_idx: usize) -> ErrorContext {
+        ErrorContext::File(
+            self.sources
+                .at(source_idx)
+                .to_include_path_name()
+                .to_string(),
+        )
+    }
+
+    /// Columns that are missing in a file are filled with nulls if the scan allows it, or its cast
+    /// policy inserts them.
+    fn allow_missing_columns(&self) -> bool {
+        #[cfg(feature = "cast_policy")]
+        if self
+            .file_options
+            .cast_policy
+            .is_some_and(|p| p.missing_columns == MissingColumns::Insert)
+        {
+            return true;
+        }
+        self.file_options.allow_missing_columns
+    }
+
     fn read_par(&mut self) -> PolarsResult<Vec<DataFrame>> {
         let parallel = match self.options.parallel {
             ParallelStrategy::Auto if self.sources.len() > POOL.current_num_threads() => {
@@ -177,7 +204,7 @@ impl ParquetExec {
             // First initialize the readers, predicates and metadata.
             // This will be used to determine the slices. That way we can actually read all the
             // files in parallel even if we add row index columns or slices.
-            let iter = (i..end).into_par_iter().map(|i| {
+            let init_reader = |i: usize| {
                 let source = self.sources.at(i);
                 let hive_partitions = self
                     .hive_parts
@@ -208,7 +235,10 @@ impl ParquetExec {
                     );
 
                 reader.num_rows().map(|num_rows| (reader, num_rows))
-            });
+            };
+            let iter = (i..end)
+                .into_par_iter()
+                .map(|i| init_reader(i).map_err(|err| err.with_context(self.file_context(i))));
 
             // We do this in parallel because wide tables can take a long time deserializing metadata.
             let readers_and_metadata = POOL.install(|| iter.collect::<PolarsResult<Vec<_>>>())?;
@@ -225,29 +255,68 @@ impl ParquetExec {
                 })
                 .collect::<Vec<_>>();
 
-            let allow_missing_columns = self.file_options.allow_missing_columns;
+            #[cfg(feature = "cast_policy")]
+            let cast_policy = self.file_options.cast_policy;
+            let allow_missing_columns = self.allow_missing_columns();
+            #[cfg(feature = "cast_policy")]
+            let scan_schema = &self.file_info.schema;
 
             let out = POOL.install(|| {
                 readers_and_metadata
                     .into_par_iter()
                     .zip(row_statistics.into_par_iter())
-                    .map(|((reader, _), (cumulative_read, slice))| {
+                    .zip(i..end)
+                    .map(|(((reader, _), (cumulative_read, slice)), source_idx)| {
                         let row_index = base_row_index.as_ref().map(|rc| RowIndex {
                             name: rc.name.clone(),
                             offset: rc.offset + cumulative_read as IdxSize,
                         });
 
+                        #[allow(unused_mut)]
+                        let mut reader = reader.with_slice(Some(slice)).with_row_index(row_index);
+                        #[allow(unused_mut)]
+                        let mut predicate = predicate.clone();
+                        #[cfg(feature = "cast_policy")]
+                        let read_schemas = match cast_policy {
+                            Some(policy) => {
+                                let file_schema = reader.schema()?;
+                                if let Some(predicate) = predicate.as_mut() {
+                                    cast_predicate_columns(
+                                        predicate,
+                                        policy,
+                                        scan_schema,
+                                        &file_schema,
+                                    );
+                                }
+                                Some(cast_policy_read_schemas(
+                                    policy,
+                                    scan_schema,
+                                    &first_schema,
+                                    projected_arrow_schema.as_deref(),
+                                    &file_schema,
+                                )?)
+                            },
+                            None => None,
+                        };
+                        #[cfg(not(feature = "cast_policy"))]
+                        let read_schemas: Option<ReadSchemas> = None;
+                        let (first_schema, projected_arrow_schema) = match &read_schemas {
+                            Some((first, projected)) => (first, Some(projected)),
+                            None => (&first_schema, projected_arrow_schema.as_deref()),
+                        };
+
                         let df = reader
-                            .with_slice(Some(slice))
-                            .with_row_index(row_index)
-                            .with_predicate(predicate.clone())
+                            .with_predicate(predicate)
                             .with_arrow_schema_projection(
-                                &first_schema,
-                                projected_arrow_schema.as_deref(),
+                                first_schema,
+                                projected_arrow_schema,
                                 allow_missing_columns,
                             )?
-                            .finish()?;
+                            .finish()
+                            .map_err(|err| err.with_context(self.file_context(source_idx)))?;
 
+                        #[cfg(feature = "cast_policy")]
+                        let df = cast_to_scan_schema(df, scan_schema, cast_policy)?;
                         Ok(df)
                     })
                     .collect::<PolarsResult<Vec<_>>>()
@@ -441,7 +510,11 @@ impl ParquetExec {
             let first_schema = first_schema.clone();
             let projected_arrow_schema = projected_arrow_schema.clone();
             let predicate = predicate.clone();
-            let allow_missing_columns = self.file_options.allow_missing_columns;
+            #[cfg(feature = "cast_policy")]
+            let cast_policy = self.file_options.cast_policy;
+            let allow_missing_columns = self.allow_missing_columns();
+            #[cfg(feature = "cast_policy")]
+            let scan_schema = &self.file_info.schema;
 
             if verbose {
                 eprintln!("reading of {}/{} file...", processed, paths.len());
@@ -465,12 +538,43 @@ impl ParquetExec {
                             offset: rc.offset + cumulative_read as IdxSize,
                         });
 
+                        #[allow(unused_mut)]
+                        let mut reader = reader.with_slice(Some(slice)).with_row_index(row_index);
+                        #[allow(unused_mut)]
+                        let mut predicate = predicate;
+                        #[cfg(feature = "cast_policy")]
+                        let read_schemas = match cast_policy {
+                            Some(policy) => {
+                                let file_schema = reader.schema().await?;
+                                if let Some(predicate) = predicate.as_mut() {
+                                    cast_predicate_columns(
+                                        predicate,
+                                        policy,
+                                        scan_schema,
+                                        &file_schema,
+                                    );
+                                }
+                                Some(cast_policy_read_schemas(
+                                    policy,
+                                    scan_schema,
+                                    &first_schema,
+                                    projected_arrow_schema.as_deref(),
+                                    &file_schema,
+                                )?)
+                            },
+                            None => None,
+                        };
+                        #[cfg(not(feature = "cast_policy"))]
+                        let read_schemas: Option<ReadSchemas> = None;
+                        let (first_schema, projected_arrow_schema) = match &read_schemas {
+                            Some((first, projected)) => (first, Some(projected)),
+                            None => (&first_schema, projected_arrow_schema.as_deref()),
+                        };
+
                         let df = reader
-                            .with_slice(Some(slice))
-                            .with_row_index(row_index)
                             .with_arrow_schema_projection(
-                                &first_schema,
-                                projected_arrow_schema.as_deref(),
+                                first_schema,
+                                projected_arrow_schema,
                                 allow_missing_columns,
                             )
                             .await?
@@ -485,6 +589,8 @@ impl ParquetExec {
                             .finish()
                             .await?;
 
+                        #[cfg(feature = "cast_policy")]
+                        let df = cast_to_scan_schema(df, scan_schema, cast_policy)?;
                         PolarsResult::Ok(df)
                     }
                 });
@@ -573,6 +679,77 @@ impl ParquetExec {
     }
 }
 
+/// The first and projected schemas to read a file with.
+type ReadSchemas = (Arc<ArrowSchema>, ArrowSchema);
+
+/// The schemas to read a file of a scan with a cast policy with: the schema of the scan and its
+/// projection, with the data types of the file. The columns are cast after they are read, see
+/// [`cast_to_scan_schema`].
+#[cfg(feature = "cast_policy")]
+fn cast_policy_read_schemas(
+    policy: CastPolicy,
+    scan_schema: &Schema,
+    first_schema: &ArrowSchema,
+    projected_arrow_schema: Option<&ArrowSchema>,
+    file_schema: &ArrowSchema,
+) -> PolarsResult<ReadSchemas> {
+    check_extra_columns(file_schema.iter_names(), scan_schema, policy)?;
+    let with_file_dtypes = |schema: &ArrowSchema| -> ArrowSchema {
+        schema
+            .iter()
+            .map(|(name, field)| (name.clone(), file_schema.get(name).unwrap_or(field).clone()))
+            .collect()
+    };
+    let projected = with_file_dtypes(projected_arrow_schema.unwrap_or(first_schema));
+    Ok((Arc::new(with_file_dtypes(first_schema)), projected))
+}
+
+/// Evaluate the predicate on the columns of a file that are cast after casting them, as the
+/// values of the file may compare differently.
+#[cfg(feature = "cast_policy")]
+fn cast_predicate_columns(
+    predicate: &mut ScanIOPredicate,
+    policy: CastPolicy,
+    scan_schema: &Schema,
+    file_schema: &ArrowSchema,
+) {
+    let casts = predicate
+        .live_columns
+        .iter()
+        .filter_map(|name| {
+            let dtype = scan_schema.get(name)?;
+            let field = file_schema.get(name)?;
+            (&DataType::from_arrow_field(field) != dtype).then(|| (name.clone(), dtype.clone()))
+        })
+        .collect();
+    predicate.set_column_casts(
+        casts,
+        Arc::new(move |column, dtype| cast_column(column, dtype, policy.lossy_casts)),
+    );
+}
+
+/// Cast the columns of a file to the data types of the scan, according to its cast policy.
+#[cfg(feature = "cast_policy")]
+fn cast_to_scan_schema(
+    df: DataFrame,
+    scan_schema: &Schema,
+    cast_policy: Option<CastPolicy>,
+) -> PolarsResult<DataFrame> {
+    let Some(policy) = cast_policy else {
+        return Ok(df);
+    };
+    let height = df.height();
+    let columns = df
+        .take_columns()
+        .into_iter()
+        .map(|column| match scan_schema.get(column.name()) {
+            Some(dtype) => cast_column(&column, dtype, policy.lossy_casts),
+            None => Ok(column),
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    DataFrame::new_with_height(height, columns)
+}
+
 impl ScanExec for ParquetExec {
     fn read(
         &mut self,
@@ -13,7 +13,7 @@ use self::python_dsl::PythonScanSource;
 use super::super::executors::{self, Executor};
 use super::*;
 use crate::ScanPredicate;
-use crate::executors::{CachePrefiller, SinkExecutor};
+use crate::executors::{CachePrefiller, PlanNodeExec, SinkExecutor};
 use crate::predicate::PhysicalColumnPredicates;
 
 fn partitionable_gb(
@@ -182,7 +182,7 @@ pub fn python_scan_predicate(
     Ok((predicate, predicate_serialized))
 }
 
-#[recursive]
+/// Creates the executor for `root` and its inputs, attaching the plan node to errors.
 fn create_physical_plan_impl(
     root: Node,
     lp_arena: &mut Arena<IR>,
@@ -190,6 +190,24 @@ fn create_physical_plan_impl(
     state: &mut ConversionState,
     // Cache nodes in order of discovery
     cache_nodes: &mut PlIndexMap<usize, Box<dyn Executor>>,
+) -> PolarsResult<Box<dyn Executor>> {
+    let name = lp_arena.get(root).name();
+    let input = create_physical_plan_node(root, lp_arena, expr_arena, state, cache_nodes)?;
+    Ok(Box::new(PlanNodeExec {
+        node: root,
+        name,
+        input,
+    }))
+}
+
+#[recursive]
+fn create_physical_plan_node(
+    root: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+    state: &mut ConversionState,
+    // Cache nodes in order of discovery
+    cache_nodes: &mut PlIndexMap<usize, Box<dyn Executor>>,
 ) -> PolarsResult<Box<dyn Executor>> {
     use IR::*;
 
@@ -0,0 +1,87 @@
+use arrow::array::PrimitiveArray;
+use arrow::legacy::utils::CustomIterTools;
+
+use super::*;
+
+/// The values of the arrays of `ca` as `f64`, and which of the arrays have no nulls.
+fn to_f64_rows(ca: &ArrayChunked) -> PolarsResult<(PrimitiveArray<f64>, Vec<bool>)> {
+    let ca = ca.rechunk();
+    let width = ca.width();
+    let inner = ca.get_inner().cast(&DataType::Float64)?;
+    let values = inner.f64()?.rechunk().downcast_as_array().clone();
+
+    let outer_validity = ca.rechunk_validity();
+    let valid = (0..ca.len())
+        .map(|i| {
+            outer_validity.as_ref().is_none_or(|v| v.get_bit(i))
+                && values
+                    .validity()
+                    .is_none_or(|v| (i * width..(i + 1) * width).all(|j| v.get_bit(j)))
+        })
+        .collect();
+    Ok((values, valid))
+}
+
+/// Apply `op` to the values of every pair of arrays in `lhs` and `rhs`.
+///
+/// Either side can have a single array, which is then paired with every array of the other side.
+/// Pairs in which an array is null or contains a null give a null.
+fn binary_rows(
+    lhs: &ArrayChunked,
+    rhs: &ArrayChunked,
+    name: &str,
+    op: impl Fn(&[f64], &[f64]) -> f64,
+) -> PolarsResult<Float64Chunked> {
+    let width = lhs.width();
+    polars_ensure!(
+        width == rhs.width(),
+        ShapeMismatch: "`arr.{}` requires arrays of the same width, got {} and {}",
+        name, width, rhs.width()
+    );
+    let len = match (lhs.len(), rhs.len()) {
+        (1, len) | (len, 1) => len,
+        (l, r) => {
+            polars_ensure!(
+                l == r,
+                ShapeMismatch: "arguments for `arr.{}` have different lengths ({} != {})",
+                name, l, r
+            );
+            l
+        },
+    };
+
+    let (lhs_values, lhs_valid) = to_f64_rows(lhs)?;
+    let (rhs_values, rhs_valid) = to_f64_rows(rhs)?;
+    let lhs_step = (lhs.len() != 1) as usize;
+    let rhs_step = (rhs.len() != 1) as usize;
+
+    let out: Float64Chunked = (0..len)
+        .map(|i| {
+            let (l, r) = (i * lhs_step, i * rhs_step);
+            (lhs_valid[l] && rhs_valid[r]).then(|| {
+                let a = &lhs_values.values()[l * width..(l + 1) * width];
+                let b = &rhs_values.values()[r * width..(r + 1) * width];
+                op(a, b)
+            })
+        })
+        .collect_trusted();
+    Ok(out.with_name(lhs.name().clone()))
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+pub(super) fn array_dot(lhs: &ArrayChunked, rhs: &ArrayChunked) -> PolarsResult<Series> {
+    binary_rows(lhs, rhs, "dot", dot).map(|ca| ca.into_series())
+}
+
+pub(super) fn array_cosine_similarity(
+    lhs: &ArrayChunked,
+    rhs: &ArrayChunked,
+) -> PolarsResult<Series> {
+    binary_rows(lhs, rhs, "cosine_similarity", |a, b| {
+        dot(a, b) / (dot(a, a).sqrt() * dot(b, b).sqrt())
+    })
+    .map(|ca| ca.into_series())
+}
@@ -2,6 +2,7 @@
 mod any_all;
 mod count;
 mod dispersion;
+mod dot;
 mod get;
 mod join;
 mod min_max;
@@ -3,6 +3,7 @@ use super::*;
 #[cfg(feature = "array_count")]
 use crate::chunked_array::array::count::array_count_matches;
 use crate::chunked_array::array::count::count_boolean_bits;
+use crate::chunked_array::array::dot::{array_cosine_similarity, array_dot};
 use crate::chunked_array::array::sum_mean::sum_with_nulls;
 #[cfg(feature = "array_any_all")]
 use crate::prelude::array::any_all::{array_all, array_any};
@@ -138,6 +139,16 @@ pub trait ArrayNameSpace: AsArray {
         array_count_matches(ca, element)
     }
 
+    /// The dot product of every array with the array of `other` at the same position.
+    fn array_dot(&self, other: &ArrayChunked) -> PolarsResult<Series> {
+        array_dot(self.as_array(), other)
+    }
+
+    /// The cosine similarity of every array with the array of `other` at the same position.
+    fn array_cosine_similarity(&self, other: &ArrayChunked) -> PolarsResult<Series> {
+        array_cosine_similarity(self.as_array(), other)
+    }
+
     fn array_shift(&self, n: &Series) -> PolarsResult<Series> {
         let ca = self.as_array();
         let n_s = n.cast(&DataType::Int64)?;
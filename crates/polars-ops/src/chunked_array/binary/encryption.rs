@@ -0,0 +1,179 @@
+//! Authenticated encryption of binary values with AES-GCM and AES-GCM-SIV.
+//!
+//! Keys are never part of a query. Expressions refer to a key by name and the key material is
+//! fetched from the global [`SecretProvider`] when the expression is evaluated.
+//!
+//! Every encrypted value is laid out as a random 12 byte nonce followed by the ciphertext and
+//! the 16 byte authentication tag.
+use std::fmt;
+use std::sync::{Arc, RwLock};
+
+use aes_gcm::aead::generic_array::typenum::Unsigned;
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, Nonce, OsRng};
+use aes_gcm::{Aes128Gcm, Aes256Gcm};
+use aes_gcm_siv::{Aes128GcmSiv, Aes256GcmSiv};
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum EncryptionAlgorithm {
+    /// AES-GCM, using AES-128 or AES-256 depending on the length of the key.
+    #[default]
+    AesGcm,
+    /// AES-GCM-SIV, using AES-128 or AES-256 depending on the length of the key.
+    ///
+    /// Unlike AES-GCM, this stays secure if a nonce is ever repeated.
+    AesGcmSiv,
+}
+
+impl fmt::Display for EncryptionAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::AesGcm => "aes-gcm",
+            Self::AesGcmSiv => "aes-gcm-siv",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// A source of key material for encryption expressions.
+pub trait SecretProvider: Send + Sync {
+    /// Get the secret with the given name.
+    fn get_secret(&self, name: &str) -> PolarsResult<Vec<u8>>;
+}
+
+/// The default [`SecretProvider`], which reads hex-encoded secrets from environment variables.
+pub struct EnvSecretProvider;
+
+impl SecretProvider for EnvSecretProvider {
+    fn get_secret(&self, name: &str) -> PolarsResult<Vec<u8>> {
+        let value = std::env::var(name).map_err(
+            |_| polars_err!(ComputeError: "secret '{}' is not set in the environment", name),
+        )?;
+        hex::decode(value.trim())
+            .map_err(|_| polars_err!(ComputeError: "secret '{}' is not hex-encoded", name))
+    }
+}
+
+static SECRET_PROVIDER: RwLock<Option<Arc<dyn SecretProvider>>> = RwLock::new(None);
+
+/// Set the global [`SecretProvider`]. Passing `None` restores the [`EnvSecretProvider`].
+pub fn set_secret_provider(provider: Option<Arc<dyn SecretProvider>>) {
+    *SECRET_PROVIDER.write().unwrap() = provider;
+}
+
+/// Get a secret from the global [`SecretProvider`].
+pub fn get_secret(name: &str) -> PolarsResult<Vec<u8>> {
+    // Don't hold the lock while the provider runs, it may call back into Polars.
+    let provider = SECRET_PROVIDER.read().unwrap().clone();
+    match provider {
+        Some(provider) => provider.get_secret(name),
+        None => EnvSecretProvider.get_secret(name),
+    }
+}
+
+fn invalid_key(key: &[u8]) -> PolarsError {
+    polars_err!(ComputeError: "encryption keys must be 16 or 32 bytes, got {}", key.len())
+}
+
+fn encrypt_with<C: Aead + AeadCore + KeyInit>(
+    ca: &BinaryChunked,
+    key: &[u8],
+) -> PolarsResult<BinaryChunked> {
+    let cipher = C::new_from_slice(key).map_err(|_| invalid_key(key))?;
+    ca.try_apply_nonnull_values_generic(|v| {
+        let nonce = C::generate_nonce(&mut OsRng);
+        let ciphertext = cipher
+            .encrypt(&nonce, v)
+            .map_err(|_| polars_err!(ComputeError: "failed to encrypt value"))?;
+        let mut out = Vec::with_capacity(nonce.len() + ciphertext.len());
+        out.extend_from_slice(&nonce);
+        out.extend_from_slice(&ciphertext);
+        Ok(out)
+    })
+}
+
+fn decrypt_with<C: Aead + AeadCore + KeyInit>(
+    ca: &BinaryChunked,
+    key: &[u8],
+) -> PolarsResult<BinaryChunked> {
+    let cipher = C::new_from_slice(key).map_err(|_| invalid_key(key))?;
+    let nonce_len = C::NonceSize::USIZE;
+    ca.try_apply_nonnull_values_generic(|v| {
+        polars_ensure!(
+            v.len() >= nonce_len,
+            ComputeError: "failed to decrypt value: expected at least {} bytes, got {}",
+            nonce_len, v.len()
+        );
+        let (nonce, ciphertext) = v.split_at(nonce_len);
+        cipher
+            .decrypt(Nonce::<C>::from_slice(nonce), ciphertext)
+            .map_err(|_| {
+                polars_err!(ComputeError: "failed to decrypt value: wrong key or modified data")
+            })
+    })
+}
+
+/// Encrypt every value of `ca` with a fresh random nonce.
+pub fn encrypt(
+    ca: &BinaryChunked,
+    algorithm: EncryptionAlgorithm,
+    key: &[u8],
+) -> PolarsResult<BinaryChunked> {
+    match (algorithm, key.len()) {
+        (EncryptionAlgorithm::AesGcm, 16) => encrypt_with::<Aes128Gcm>(ca, key),
+        (EncryptionAlgorithm::AesGcm, 32) => encrypt_with::<Aes256Gcm>(ca, key),
+        (EncryptionAlgorithm::AesGcmSiv, 16) => encrypt_with::<Aes128GcmSiv>(ca, key),
+        (EncryptionAlgorithm::AesGcmSiv, 32) => encrypt_with::<Aes256GcmSiv>(ca, key),
+        _ => Err(invalid_key(key)),
+    }
+}
+
+/// Decrypt and authenticate every value of `ca`, as encrypted by [`encrypt`].
+pub fn decrypt(
+    ca: &BinaryChunked,
+    algorithm: EncryptionAlgorithm,
+    key: &[u8],
+) -> PolarsResult<BinaryChunked> {
+    match (algorithm, key.len()) {
+        (EncryptionAlgorithm::AesGcm, 16) => decrypt_with::<Aes128Gcm>(ca, key),
+        (EncryptionAlgorithm::AesGcm, 32) => decrypt_with::<Aes256Gcm>(ca, key),
+        (EncryptionAlgorithm::AesGcmSiv, 16) => decrypt_with::<Aes128GcmSiv>(ca, key),
+        (EncryptionAlgorithm::AesGcmSiv, 32) => decrypt_with::<Aes256GcmSiv>(ca, key),
+        _ => Err(invalid_key(key)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_encrypt_roundtrip() {
+        let ca = BinaryChunked::from_slice_options(
+            "a".into(),
+            &[Some(b"secret".as_slice()), None, Some(b"".as_slice())],
+        );
+        for algorithm in [EncryptionAlgorithm::AesGcm, EncryptionAlgorithm::AesGcmSiv] {
+            for key in [[7u8; 16].as_slice(), [7u8; 32].as_slice()] {
+                let encrypted = encrypt(&ca, algorithm, key).unwrap();
+                assert_eq!(encrypted.get(0).unwrap().len(), 12 + 6 + 16);
+                assert_eq!(encrypted.get(1), None);
+                assert_ne!(
+                    encrypted.get(0),
+                    encrypt(&ca, algorithm, key).unwrap().get(0)
+                );
+
+                let decrypted = decrypt(&encrypted, algorithm, key).unwrap();
+                assert_eq!(
+                    decrypted.iter().collect::<Vec<_>>(),
+                    ca.iter().collect::<Vec<_>>()
+                );
+                assert!(decrypt(&encrypted, algorithm, &[8u8; 32][..key.len()]).is_err());
+            }
+        }
+        assert!(encrypt(&ca, EncryptionAlgorithm::AesGcm, &[0u8; 8]).is_err());
+    }
+}
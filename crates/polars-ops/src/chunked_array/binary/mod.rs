@@ -1,6 +1,10 @@
 mod cast_binary_to_numerical;
+#[cfg(feature = "binary_encryption")]
+mod encryption;
 mod namespace;
 
+#[cfg(feature = "binary_encryption")]
+pub use encryption::*;
 pub use namespace::*;
 use polars_core::prelude::*;
 
@@ -130,6 +130,20 @@ pub trait BinaryNameSpaceImpl: AsBinary {
         }
     }
 
+    /// Encrypt every value with the key named `key`, which is fetched from the global
+    /// [`SecretProvider`].
+    #[cfg(feature = "binary_encryption")]
+    fn encrypt(&self, algorithm: EncryptionAlgorithm, key: &str) -> PolarsResult<BinaryChunked> {
+        encryption::encrypt(self.as_binary(), algorithm, &get_secret(key)?)
+    }
+
+    /// Decrypt every value with the key named `key`, which is fetched from the global
+    /// [`SecretProvider`].
+    #[cfg(feature = "binary_encryption")]
+    fn decrypt(&self, algorithm: EncryptionAlgorithm, key: &str) -> PolarsResult<BinaryChunked> {
+        encryption::decrypt(self.as_binary(), algorithm, &get_secret(key)?)
+    }
+
     #[cfg(feature = "binary_encoding")]
     #[allow(clippy::wrong_self_convention)]
     fn from_buffer(&self, dtype: &DataType, is_little_endian: bool) -> PolarsResult<Series> {
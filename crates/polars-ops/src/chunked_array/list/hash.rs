@@ -72,6 +72,11 @@ pub(crate) fn hash(
                             hash_agg(ca, &build_hasher)
                         })
                     } else {
+                        // 128-bit values don't have a bit representation.
+                        #[cfg(feature = "dtype-i128")]
+                        if let Some(ca) = s.try_i128() {
+                            return hash_agg(ca, &build_hasher);
+                        }
                         match s.bit_repr() {
                             None => unimplemented!("Hash for lists without bit representation"),
                             Some(BitRepr::Small(ca)) => hash_agg(&ca, &build_hasher),
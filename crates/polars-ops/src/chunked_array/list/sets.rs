@@ -2,8 +2,8 @@ use std::fmt::{Display, Formatter};
 use std::hash::Hash;
 
 use arrow::array::{
-    Array, BinaryViewArray, ListArray, MutableArray, MutablePlBinary, MutablePrimitiveArray,
-    PrimitiveArray, Utf8ViewArray,
+    Array, BinaryViewArray, BooleanArray, ListArray, MutableArray, MutablePlBinary,
+    MutablePrimitiveArray, PrimitiveArray, Utf8ViewArray,
 };
 use arrow::bitmap::Bitmap;
 use arrow::compute::utils::combine_validities_and;
@@ -396,7 +396,29 @@ fn array_set_operation(
             binary(a, b, offsets_a, offsets_b, set_op, validity, false)
         },
         ArrowDataType::Boolean => {
-            polars_bail!(InvalidOperation: "boolean type not yet supported in list 'set' operations")
+            // Booleans take at most three distinct values (incl. null), so we run the
+            // primitive kernel on their u8 representation and convert back.
+            let to_u8 = |values: &dyn Array| {
+                let values = values.as_any().downcast_ref::<BooleanArray>().unwrap();
+                PrimitiveArray::<u8>::from_trusted_len_iter(values.iter().map(|v| v.map(u8::from)))
+            };
+            let a = to_u8(values_a.as_ref());
+            let b = to_u8(values_b.as_ref());
+            let out = primitive(&a, &b, offsets_a, offsets_b, set_op, validity)?;
+
+            let values = out
+                .values()
+                .as_any()
+                .downcast_ref::<PrimitiveArray<u8>>()
+                .unwrap();
+            let values =
+                BooleanArray::from_trusted_len_iter(values.iter().map(|v| v.map(|v| *v != 0)));
+            Ok(ListArray::new(
+                ListArray::<i64>::default_datatype(ArrowDataType::Boolean),
+                out.offsets().clone(),
+                values.boxed(),
+                out.validity().cloned(),
+            ))
         },
         _ => {
             with_match_physical_numeric_type!(DataType::from_arrow_dtype(dtype), |$T| {
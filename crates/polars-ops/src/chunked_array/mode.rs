@@ -1,5 +1,22 @@
 use polars_core::POOL;
 use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use strum_macros::IntoStaticStr;
+
+/// Which values to return if several values are the most frequent.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, IntoStaticStr)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[strum(serialize_all = "snake_case")]
+pub enum ModeTies {
+    /// All most frequent values, in the order of their first occurrence.
+    #[default]
+    All,
+    /// The most frequent value that occurs first.
+    First,
+    /// The smallest of the most frequent values.
+    Smallest,
+}
 
 fn mode_indices(groups: GroupsType) -> Vec<IdxSize> {
     match groups {
@@ -27,9 +44,33 @@ fn mode_indices(groups: GroupsType) -> Vec<IdxSize> {
 }
 
 pub fn mode(s: &Series) -> PolarsResult<Series> {
+    mode_with_ties(s, ModeTies::All)
+}
+
+/// The most frequent values of `s`, of which `ties` picks the values to return.
+///
+/// With [`ModeTies::First`] and [`ModeTies::Smallest`] the result is a single value, which is
+/// null if `s` is empty.
+pub fn mode_with_ties(s: &Series, ties: ModeTies) -> PolarsResult<Series> {
     let parallel = !POOL.current_thread_has_pending_tasks().unwrap_or(false);
     let groups = s.group_tuples(parallel, false).unwrap();
-    let idx = mode_indices(groups);
+    let mut idx = mode_indices(groups);
+    // The order of the groups is arbitrary, the first indices make it deterministic.
+    idx.sort_unstable();
+    match ties {
+        ModeTies::All => {},
+        ModeTies::First => idx.truncate(1),
+        ModeTies::Smallest if idx.len() > 1 => {
+            // SAFETY: group indices are in bounds.
+            let modes = unsafe { s.take_slice_unchecked(&idx) };
+            let order = modes.arg_sort(SortOptions::default().with_nulls_last(true));
+            idx = vec![idx[order.get(0).unwrap() as usize]];
+        },
+        ModeTies::Smallest => {},
+    }
+    if idx.is_empty() && ties != ModeTies::All {
+        return Ok(Series::full_null(s.name().clone(), 1, s.dtype()));
+    }
     let idx = IdxCa::from_vec("".into(), idx);
     // SAFETY:
     // group indices are in bounds
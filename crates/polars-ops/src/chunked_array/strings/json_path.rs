@@ -7,20 +7,36 @@ use serde_json::Value;
 
 use super::*;
 
+fn json_value_to_string(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Null => None,
+        v => Some(v.to_string()),
+    }
+}
+
+fn compile_json_path(json_path: &str) -> PolarsResult<PathCompiled> {
+    PathCompiled::compile(json_path)
+        .map_err(|e| polars_err!(ComputeError: "error compiling JSON path expression {}", e))
+}
+
 pub fn extract_json(expr: &PathCompiled, json_str: &str) -> Option<String> {
     serde_json::from_str(json_str).ok().and_then(|value| {
         // TODO: a lot of heap allocations here. Improve json path by adding a take?
         let result = expr.select(&value).ok()?;
         let first = *result.first()?;
-
-        match first {
-            Value::String(s) => Some(s.clone()),
-            Value::Null => None,
-            v => Some(v.to_string()),
-        }
+        json_value_to_string(first)
     })
 }
 
+/// Returns every value matched by the compiled JSON path expression, in document order.
+/// Values are cast to strings in the same way as [`extract_json`].
+pub fn extract_json_all(expr: &PathCompiled, json_str: &str) -> Option<Vec<Option<String>>> {
+    let value: Value = serde_json::from_str(json_str).ok()?;
+    let result = expr.select(&value).ok()?;
+    Some(result.into_iter().map(json_value_to_string).collect())
+}
+
 /// Returns a string of the most specific value given the compiled JSON path expression.
 /// This avoids creating a list to represent individual elements so that they can be
 /// selected directly.
@@ -42,6 +58,9 @@ pub fn select_json<'a>(expr: &PathCompiled, json_str: &'a str) -> Option<Cow<'a,
 pub trait Utf8JsonPathImpl: AsString {
     /// Extract json path, first match
     /// Refer to <https://goessner.net/articles/JsonPath/>
+    ///
+    /// Supports the full JSONPath syntax, including recursive descent (`$..a`),
+    /// filters (`$.a[?(@.b > 1)]`), unions (`$['a','b']`) and array slices (`$.a[1:3]`).
     fn json_path_match(&self, json_path: &StringChunked) -> PolarsResult<StringChunked> {
         let ca = self.as_string();
         match (ca.len(), json_path.len()) {
@@ -49,9 +68,7 @@ pub trait Utf8JsonPathImpl: AsString {
                 // SAFETY: `json_path` was verified to have exactly 1 element.
                 let opt_path = unsafe { json_path.get_unchecked(0) };
                 let out = if let Some(path) = opt_path {
-                    let pat = PathCompiled::compile(path).map_err(
-                        |e| polars_err!(ComputeError: "error compiling JSON path expression {}", e),
-                    )?;
+                    let pat = compile_json_path(path)?;
                     unary_elementwise(ca, |opt_s| opt_s.and_then(|s| extract_json(&pat, s)))
                 } else {
                     StringChunked::full_null(ca.name().clone(), ca.len())
@@ -61,13 +78,11 @@ pub trait Utf8JsonPathImpl: AsString {
             (len_ca, len_path) if len_ca == 1 || len_ca == len_path => {
                 broadcast_try_binary_elementwise(ca, json_path, |opt_str, opt_path| {
                     match (opt_str, opt_path) {
-                    (Some(str_val), Some(path)) => {
-                        PathCompiled::compile(path)
-                            .map_err(|e| polars_err!(ComputeError: "error compiling JSON path expression {}", e))
-                            .map(|path| extract_json(&path, str_val))
-                    },
-                    _ => Ok(None),
-                }
+                        (Some(str_val), Some(path)) => {
+                            compile_json_path(path).map(|path| extract_json(&path, str_val))
+                        },
+                        _ => Ok(None),
+                    }
                 })
             },
             (len_ca, len_path) => {
@@ -76,6 +91,55 @@ pub trait Utf8JsonPathImpl: AsString {
         }
     }
 
+    /// Extract json path, all matches as a list of strings.
+    ///
+    /// Rows with invalid JSON or a null value/path are null; a path that matches nothing
+    /// gives an empty list.
+    fn json_extract_all(&self, json_path: &StringChunked) -> PolarsResult<ListChunked> {
+        let ca = self.as_string();
+        let (len_ca, len_path) = (ca.len(), json_path.len());
+        polars_ensure!(
+            len_path == 1 || len_ca == 1 || len_ca == len_path,
+            ComputeError: "The length of `ca` and `json_path` should either 1 or the same, but `{}`, `{}` founded", len_ca, len_path
+        );
+
+        let mut builder = ListStringChunkedBuilder::new(
+            ca.name().clone(),
+            len_ca.max(len_path),
+            ca.get_values_size(),
+        );
+        let mut append = |opt_str: Option<&str>, path: Option<&PathCompiled>| match (opt_str, path)
+        {
+            (Some(str_val), Some(path)) => match extract_json_all(path, str_val) {
+                Some(values) => {
+                    builder.append_trusted_len_iter(values.iter().map(|v| v.as_deref()))
+                },
+                None => builder.append_null(),
+            },
+            _ => builder.append_null(),
+        };
+
+        if len_path == 1 {
+            // SAFETY: `json_path` was verified to have exactly 1 element.
+            let pat = unsafe { json_path.get_unchecked(0) }
+                .map(compile_json_path)
+                .transpose()?;
+            ca.iter().for_each(|opt_str| append(opt_str, pat.as_ref()));
+        } else if len_ca == 1 {
+            let str_val = ca.get(0);
+            for opt_path in json_path.iter() {
+                let pat = opt_path.map(compile_json_path).transpose()?;
+                append(str_val, pat.as_ref());
+            }
+        } else {
+            for (opt_str, opt_path) in ca.iter().zip(json_path.iter()) {
+                let pat = opt_path.map(compile_json_path).transpose()?;
+                append(opt_str, pat.as_ref());
+            }
+        }
+        Ok(builder.finish())
+    }
+
     /// Returns the inferred DataType for JSON values for each row
     /// in the StringChunked, with an optional number of rows to inspect.
     /// When None is passed for the number of rows, all rows are inspected.
@@ -169,6 +233,70 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_json_path_match_full_syntax() {
+        let s = Series::new(
+            "json".into(),
+            [
+                Some(r#"{"a":{"b":1},"c":[{"b":2,"d":"x"},{"b":3,"d":"y"},{"b":4,"d":"z"}]}"#),
+                None,
+            ],
+        );
+        let ca = s.str().unwrap();
+        let path = |p: &str| StringChunked::new("pat".into(), [p]);
+
+        let out = ca.json_path_match(&path("$..b")).unwrap();
+        assert_eq!(Vec::from(&out), &[Some("1"), None]);
+        let out = ca.json_path_match(&path("$.c[?(@.b > 2)].d")).unwrap();
+        assert_eq!(Vec::from(&out), &[Some("y"), None]);
+        let out = ca.json_path_match(&path("$.c[2:].d")).unwrap();
+        assert_eq!(Vec::from(&out), &[Some("z"), None]);
+    }
+
+    #[test]
+    fn test_json_extract_all() {
+        let s = Series::new(
+            "json".into(),
+            [
+                Some(r#"{"a":{"b":1},"c":[{"b":2,"d":"x"},{"b":null,"d":"y"}]}"#),
+                Some("not json"),
+                None,
+                Some(r#"{"a":2}"#),
+            ],
+        );
+        let ca = s.str().unwrap();
+
+        let out = ca
+            .json_extract_all(&StringChunked::new("pat".into(), ["$..b"]))
+            .unwrap();
+        let expected = Series::new(
+            "json".into(),
+            [
+                Some(Series::new("".into(), &[Some("1"), Some("2"), None])),
+                None,
+                None,
+                Some(Series::new("".into(), Vec::<Option<&str>>::new())),
+            ],
+        );
+        assert!(out.into_series().equals_missing(&expected));
+
+        let paths = StringChunked::new("pat".into(), [Some("$.c[:].d"), None, None, Some("$.a")]);
+        let out = ca.json_extract_all(&paths).unwrap();
+        let expected = Series::new(
+            "json".into(),
+            [
+                Some(Series::new("".into(), &["x", "y"])),
+                None,
+                None,
+                Some(Series::new("".into(), &["2"])),
+            ],
+        );
+        assert!(out.into_series().equals_missing(&expected));
+
+        let paths = StringChunked::new("pat".into(), ["$.a", "$.b"]);
+        assert!(ca.json_extract_all(&paths).is_err());
+    }
+
     #[test]
     fn test_json_infer() {
         let s = Series::new(
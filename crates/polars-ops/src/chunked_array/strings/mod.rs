@@ -16,8 +16,12 @@ mod namespace;
 mod normalize;
 #[cfg(feature = "string_pad")]
 mod pad;
+#[cfg(feature = "string_phonetic")]
+mod phonetic;
 #[cfg(feature = "string_reverse")]
 mod reverse;
+#[cfg(feature = "string_similarity")]
+pub(crate) mod similarity;
 #[cfg(feature = "strings")]
 mod split;
 #[cfg(feature = "strings")]
@@ -28,6 +32,8 @@ mod strip;
 mod substring;
 #[cfg(all(not(feature = "nightly"), feature = "strings"))]
 mod unicode_internals;
+#[cfg(feature = "url")]
+mod url;
 
 #[cfg(feature = "strings")]
 pub use concat::*;
@@ -49,6 +55,9 @@ pub use strip::*;
 #[cfg(feature = "strings")]
 pub use substring::{substring_ternary_offsets_value, update_view};
 
+#[cfg(feature = "url")]
+pub use self::url::*;
+
 pub trait AsString {
     fn as_string(&self) -> &StringChunked;
 }
@@ -650,6 +650,58 @@ pub trait StringNameSpaceImpl: AsString {
         reverse::reverse(ca)
     }
 
+    /// The Soundex code of the string values, null for strings without letters.
+    #[must_use]
+    #[cfg(feature = "string_phonetic")]
+    fn str_soundex(&self, ascii_fold: bool) -> StringChunked {
+        let ca = self.as_string();
+        phonetic::soundex(ca, ascii_fold)
+    }
+
+    /// The Metaphone code of the string values, null for strings without letters.
+    #[must_use]
+    #[cfg(feature = "string_phonetic")]
+    fn str_metaphone(&self, ascii_fold: bool) -> StringChunked {
+        let ca = self.as_string();
+        phonetic::metaphone(ca, ascii_fold)
+    }
+
+    /// The Levenshtein distance to the string values of `other`.
+    #[cfg(feature = "string_similarity")]
+    fn str_levenshtein(&self, other: &StringChunked) -> UInt32Chunked {
+        let ca = self.as_string();
+        similarity::levenshtein(ca, other)
+    }
+
+    /// The Levenshtein similarity in `[0, 1]` to the string values of `other`.
+    #[cfg(feature = "string_similarity")]
+    fn str_levenshtein_similarity(&self, other: &StringChunked) -> Float64Chunked {
+        let ca = self.as_string();
+        similarity::levenshtein_similarity(ca, other)
+    }
+
+    /// The Hamming distance to the string values of `other`, null where the lengths differ.
+    #[cfg(feature = "string_similarity")]
+    fn str_hamming(&self, other: &StringChunked) -> UInt32Chunked {
+        let ca = self.as_string();
+        similarity::hamming(ca, other)
+    }
+
+    /// The Hamming similarity in `[0, 1]` to the string values of `other`, null where the
+    /// lengths differ.
+    #[cfg(feature = "string_similarity")]
+    fn str_hamming_similarity(&self, other: &StringChunked) -> Float64Chunked {
+        let ca = self.as_string();
+        similarity::hamming_similarity(ca, other)
+    }
+
+    /// The Jaro-Winkler similarity in `[0, 1]` to the string values of `other`.
+    #[cfg(feature = "string_similarity")]
+    fn str_jaro_winkler(&self, other: &StringChunked) -> Float64Chunked {
+        let ca = self.as_string();
+        similarity::jaro_winkler_similarity(ca, other)
+    }
+
     /// Slice the string values.
     ///
     /// Determines a substring starting from `offset` and with length `length` of each of the elements in `array`.
@@ -0,0 +1,321 @@
+//! Phonetic encodings, which map strings that sound alike to the same code.
+//!
+//! Both encodings only consider the ASCII letters of a string. With `ascii_fold`, other letters
+//! are first folded to their closest ASCII equivalents, e.g. `é` to `e` and `ß` to `ss`;
+//! otherwise they are ignored.
+use polars_core::prelude::{StringChunked, StringChunkedBuilder};
+use unicode_normalization::char::decompose_compatible;
+
+/// Letters without a compatibility decomposition to ASCII.
+fn fold_special(c: char) -> Option<&'static str> {
+    Some(match c {
+        'ß' => "SS",
+        'æ' | 'Æ' => "AE",
+        'œ' | 'Œ' => "OE",
+        'ø' | 'Ø' => "O",
+        'ł' | 'Ł' => "L",
+        'đ' | 'Đ' => "D",
+        'þ' | 'Þ' => "TH",
+        'ı' => "I",
+        _ => return None,
+    })
+}
+
+/// Collect the uppercase ASCII letters of `s` into `out`.
+fn ascii_letters(s: &str, ascii_fold: bool, out: &mut Vec<u8>) {
+    out.clear();
+    for c in s.chars() {
+        if c.is_ascii() {
+            if c.is_ascii_alphabetic() {
+                out.push(c.to_ascii_uppercase() as u8);
+            }
+        } else if ascii_fold {
+            if let Some(folded) = fold_special(c) {
+                out.extend_from_slice(folded.as_bytes());
+            } else {
+                decompose_compatible(c, |d| {
+                    if d.is_ascii_alphabetic() {
+                        out.push(d.to_ascii_uppercase() as u8);
+                    }
+                });
+            }
+        }
+    }
+}
+
+fn soundex_digit(c: u8) -> Option<u8> {
+    Some(match c {
+        b'B' | b'F' | b'P' | b'V' => b'1',
+        b'C' | b'G' | b'J' | b'K' | b'Q' | b'S' | b'X' | b'Z' => b'2',
+        b'D' | b'T' => b'3',
+        b'L' => b'4',
+        b'M' | b'N' => b'5',
+        b'R' => b'6',
+        // Unlike vowels, these don't separate letters with the same digit.
+        b'H' | b'W' => return None,
+        _ => b'0',
+    })
+}
+
+/// American Soundex: the first letter followed by three digits.
+fn soundex_into(w: &[u8], out: &mut String) {
+    let Some((&first, rest)) = w.split_first() else {
+        return;
+    };
+    out.push(first as char);
+    let mut last = soundex_digit(first);
+    for &c in rest {
+        let Some(digit) = soundex_digit(c) else {
+            continue;
+        };
+        if digit != b'0' && Some(digit) != last {
+            out.push(digit as char);
+            if out.len() == 4 {
+                return;
+            }
+        }
+        last = Some(digit);
+    }
+    while out.len() < 4 {
+        out.push('0');
+    }
+}
+
+fn is_vowel(c: u8) -> bool {
+    matches!(c, b'A' | b'E' | b'I' | b'O' | b'U')
+}
+
+/// The original Metaphone algorithm by Lawrence Philips, without a limit on the code length.
+fn metaphone_into(w: &[u8], out: &mut String) {
+    let at = |i: usize| w.get(i).copied().unwrap_or(0);
+    let n = w.len();
+
+    // Initial letters that are silent or pronounced differently.
+    let mut i = match (at(0), at(1)) {
+        (b'A', b'E') | (b'G', b'N') | (b'K', b'N') | (b'P', b'N') | (b'W', b'R') => 1,
+        (b'X', _) => {
+            out.push('S');
+            1
+        },
+        (b'W', b'H') => {
+            out.push('W');
+            2
+        },
+        _ => 0,
+    };
+    let start = i;
+
+    while i < n {
+        let c = w[i];
+        let prev = if i > 0 { w[i - 1] } else { 0 };
+        let next = at(i + 1);
+        let next2 = at(i + 2);
+
+        // Duplicate letters are encoded once, except for `C`.
+        if c == prev && c != b'C' {
+            i += 1;
+            continue;
+        }
+
+        match c {
+            b'A' | b'E' | b'I' | b'O' | b'U' => {
+                if i == start && out.is_empty() {
+                    out.push(c as char)
+                }
+            },
+            b'B' => {
+                // Silent in a trailing `MB`.
+                if !(prev == b'M' && i + 1 == n) {
+                    out.push('B')
+                }
+            },
+            b'C' => {
+                if next == b'I' && next2 == b'A' {
+                    out.push('X')
+                } else if next == b'H' {
+                    out.push(if prev == b'S' { 'K' } else { 'X' })
+                } else if matches!(next, b'I' | b'E' | b'Y') {
+                    if prev != b'S' {
+                        out.push('S')
+                    }
+                } else {
+                    out.push('K')
+                }
+            },
+            b'D' => {
+                if next == b'G' && matches!(next2, b'E' | b'Y' | b'I') {
+                    out.push('J');
+                    i += 1;
+                } else {
+                    out.push('T')
+                }
+            },
+            b'G' => {
+                let silent_gh = next == b'H' && i + 2 < n && !is_vowel(next2);
+                let silent_gn = next == b'N'
+                    && (i + 2 == n || (next2 == b'E' && at(i + 3) == b'D' && i + 4 == n));
+                if !(silent_gh || silent_gn) {
+                    let soft = matches!(next, b'I' | b'E' | b'Y') && prev != b'G';
+                    out.push(if soft { 'J' } else { 'K' })
+                }
+            },
+            b'H' => {
+                let after_modifier = matches!(prev, b'C' | b'S' | b'P' | b'T' | b'G');
+                if !after_modifier && (!is_vowel(prev) || is_vowel(next)) {
+                    out.push('H')
+                }
+            },
+            b'K' => {
+                if prev != b'C' {
+                    out.push('K')
+                }
+            },
+            b'P' => out.push(if next == b'H' { 'F' } else { 'P' }),
+            b'Q' => out.push('K'),
+            b'S' => {
+                if next == b'H' || (next == b'I' && matches!(next2, b'O' | b'A')) {
+                    out.push('X')
+                } else {
+                    out.push('S')
+                }
+            },
+            b'T' => {
+                if next == b'I' && matches!(next2, b'O' | b'A') {
+                    out.push('X')
+                } else if next == b'H' {
+                    out.push('0')
+                } else if !(next == b'C' && next2 == b'H') {
+                    out.push('T')
+                }
+            },
+            b'V' => out.push('F'),
+            b'W' | b'Y' => {
+                if is_vowel(next) {
+                    out.push(c as char)
+                }
+            },
+            b'X' => out.push_str("KS"),
+            b'Z' => out.push('S'),
+            _ => out.push(c as char),
+        }
+        i += 1;
+    }
+}
+
+fn encode_with<F: Fn(&[u8], &mut String)>(
+    ca: &StringChunked,
+    ascii_fold: bool,
+    encoder: F,
+) -> StringChunked {
+    let mut letters = Vec::new();
+    let mut buffer = String::new();
+    let mut builder = StringChunkedBuilder::new(ca.name().clone(), ca.len());
+    for opt_s in ca.iter() {
+        match opt_s {
+            Some(s) => {
+                ascii_letters(s, ascii_fold, &mut letters);
+                if letters.is_empty() {
+                    builder.append_null();
+                } else {
+                    buffer.clear();
+                    encoder(&letters, &mut buffer);
+                    builder.append_value(&buffer);
+                }
+            },
+            None => builder.append_null(),
+        }
+    }
+    builder.finish()
+}
+
+/// The Soundex code of each string. Null for strings without letters.
+pub fn soundex(ca: &StringChunked, ascii_fold: bool) -> StringChunked {
+    encode_with(ca, ascii_fold, soundex_into)
+}
+
+/// The Metaphone code of each string. Null for strings without letters.
+pub fn metaphone(ca: &StringChunked, ascii_fold: bool) -> StringChunked {
+    encode_with(ca, ascii_fold, metaphone_into)
+}
+
+#[cfg(test)]
+mod test {
+    use polars_core::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_soundex() {
+        let ca = StringChunked::new(
+            "a".into(),
+            &[
+                Some("Robert"),
+                Some("Rupert"),
+                Some("Ashcraft"),
+                Some("Tymczak"),
+                Some("Pfister"),
+                Some("Honeyman"),
+                Some("123"),
+                None,
+            ],
+        );
+        let out = soundex(&ca, true);
+        assert_eq!(
+            Vec::from(&out),
+            &[
+                Some("R163"),
+                Some("R163"),
+                Some("A261"),
+                Some("T522"),
+                Some("P236"),
+                Some("H555"),
+                None,
+                None
+            ]
+        );
+    }
+
+    #[test]
+    fn test_metaphone() {
+        let ca = StringChunked::new(
+            "a".into(),
+            &[
+                "Knight",
+                "Wright",
+                "Xavier",
+                "Philip",
+                "Fillip",
+                "Catherine",
+                "Kathryn",
+                "Thumb",
+                "Dodge",
+                "signed",
+            ],
+        );
+        let out = metaphone(&ca, true);
+        assert_eq!(
+            Vec::from(&out),
+            &[
+                Some("NT"),
+                Some("RT"),
+                Some("SFR"),
+                Some("FLP"),
+                Some("FLP"),
+                Some("K0RN"),
+                Some("K0RN"),
+                Some("0M"),
+                Some("TJ"),
+                Some("SNT")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ascii_fold() {
+        let ca = StringChunked::new("a".into(), &["Straße", "Müller", "Ørsted"]);
+        let out = metaphone(&ca, true);
+        assert_eq!(Vec::from(&out), &[Some("STRS"), Some("MLR"), Some("ORSTT")]);
+        let out = metaphone(&ca, false);
+        assert_eq!(Vec::from(&out), &[Some("STR"), Some("MLR"), Some("RSTT")]);
+    }
+}
@@ -0,0 +1,291 @@
+//! Edit distances and similarity scores between pairs of strings.
+//!
+//! All metrics work on unicode scalar values (chars), with a fast path on the bytes if both
+//! strings are ASCII. The similarity variants are normalized to `[0, 1]`, where `1` means the
+//! strings are equal.
+use polars_core::prelude::arity::broadcast_binary_elementwise;
+use polars_core::prelude::*;
+
+/// Reusable allocations, so the metrics don't allocate per pair of strings.
+#[derive(Default)]
+struct Buffers {
+    a: Vec<char>,
+    b: Vec<char>,
+    row: Vec<usize>,
+    flags: Vec<bool>,
+}
+
+impl Buffers {
+    fn load_chars(&mut self, a: &str, b: &str) {
+        self.a.clear();
+        self.a.extend(a.chars());
+        self.b.clear();
+        self.b.extend(b.chars());
+    }
+}
+
+fn levenshtein_slices<T: PartialEq>(a: &[T], b: &[T], row: &mut Vec<usize>) -> usize {
+    // The common prefix and suffix don't contribute to the distance.
+    let prefix = a.iter().zip(b).take_while(|(x, y)| x == y).count();
+    let (a, b) = (&a[prefix..], &b[prefix..]);
+    let suffix = a
+        .iter()
+        .rev()
+        .zip(b.iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+    let (a, b) = (&a[..a.len() - suffix], &b[..b.len() - suffix]);
+
+    // Keep a single row of the shorter string.
+    let (a, b) = if a.len() < b.len() { (b, a) } else { (a, b) };
+    if b.is_empty() {
+        return a.len();
+    }
+    row.clear();
+    row.extend(0..=b.len());
+    for (i, x) in a.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, y) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if x == y {
+                diagonal
+            } else {
+                1 + diagonal.min(above).min(row[j])
+            };
+            diagonal = above;
+        }
+    }
+    row[b.len()]
+}
+
+fn jaro_slices<T: PartialEq>(a: &[T], b: &[T], flags: &mut Vec<bool>) -> f64 {
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+
+    // Characters only match if they are no further apart than the window.
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    flags.clear();
+    flags.resize(a.len() + b.len(), false);
+    let (a_flags, b_flags) = flags.split_at_mut(a.len());
+    let mut matches = 0usize;
+    for (i, x) in a.iter().enumerate() {
+        let end = (i + window + 1).min(b.len());
+        for j in i.saturating_sub(window)..end {
+            if !b_flags[j] && *x == b[j] {
+                a_flags[i] = true;
+                b_flags[j] = true;
+                matches += 1;
+                break;
+            }
+        }
+    }
+    if matches == 0 {
+        return 0.0;
+    }
+
+    // Count the matched characters that are out of order.
+    let mut b_matched = b.iter().zip(b_flags.iter()).filter(|(_, f)| **f);
+    let transpositions = a
+        .iter()
+        .zip(a_flags.iter())
+        .filter(|(_, f)| **f)
+        .filter(|(x, _)| b_matched.next().is_some_and(|(y, _)| x != &y))
+        .count();
+
+    let m = matches as f64;
+    let t = transpositions as f64 / 2.0;
+    (m / a.len() as f64 + m / b.len() as f64 + (m - t) / m) / 3.0
+}
+
+fn jaro_winkler_slices<T: PartialEq>(a: &[T], b: &[T], flags: &mut Vec<bool>) -> f64 {
+    const PREFIX_SCALE: f64 = 0.1;
+    const MAX_PREFIX: usize = 4;
+
+    let jaro = jaro_slices(a, b, flags);
+    let prefix = a
+        .iter()
+        .zip(b)
+        .take(MAX_PREFIX)
+        .take_while(|(x, y)| x == y)
+        .count();
+    jaro + prefix as f64 * PREFIX_SCALE * (1.0 - jaro)
+}
+
+/// Returns the distance and the length of the longest string.
+fn levenshtein_str(a: &str, b: &str, buffers: &mut Buffers) -> (usize, usize) {
+    if a.is_ascii() && b.is_ascii() {
+        let distance = levenshtein_slices(a.as_bytes(), b.as_bytes(), &mut buffers.row);
+        (distance, a.len().max(b.len()))
+    } else {
+        buffers.load_chars(a, b);
+        let distance = levenshtein_slices(&buffers.a, &buffers.b, &mut buffers.row);
+        (distance, buffers.a.len().max(buffers.b.len()))
+    }
+}
+
+/// Returns the distance and the length of the strings, or `None` if the lengths differ.
+fn hamming_str(a: &str, b: &str) -> Option<(usize, usize)> {
+    if a.is_ascii() && b.is_ascii() {
+        if a.len() != b.len() {
+            return None;
+        }
+        let distance = a.bytes().zip(b.bytes()).filter(|(x, y)| x != y).count();
+        return Some((distance, a.len()));
+    }
+    let len = a.chars().count();
+    if len != b.chars().count() {
+        return None;
+    }
+    let distance = a.chars().zip(b.chars()).filter(|(x, y)| x != y).count();
+    Some((distance, len))
+}
+
+fn jaro_winkler_str(a: &str, b: &str, buffers: &mut Buffers) -> f64 {
+    if a.is_ascii() && b.is_ascii() {
+        jaro_winkler_slices(a.as_bytes(), b.as_bytes(), &mut buffers.flags)
+    } else {
+        buffers.load_chars(a, b);
+        jaro_winkler_slices(&buffers.a, &buffers.b, &mut buffers.flags)
+    }
+}
+
+fn normalize_distance(distance: usize, len: usize) -> f64 {
+    if len == 0 {
+        1.0
+    } else {
+        1.0 - distance as f64 / len as f64
+    }
+}
+
+/// Scores single pairs of strings, reusing its allocations between pairs.
+#[derive(Default)]
+pub(crate) struct PairScorer {
+    buffers: Buffers,
+}
+
+impl PairScorer {
+    pub(crate) fn levenshtein_similarity(&mut self, a: &str, b: &str) -> f64 {
+        let (distance, len) = levenshtein_str(a, b, &mut self.buffers);
+        normalize_distance(distance, len)
+    }
+
+    pub(crate) fn jaro_winkler_similarity(&mut self, a: &str, b: &str) -> f64 {
+        jaro_winkler_str(a, b, &mut self.buffers)
+    }
+}
+
+/// The minimum number of single character insertions, deletions and substitutions needed to
+/// change one string into the other.
+pub fn levenshtein(ca: &StringChunked, other: &StringChunked) -> UInt32Chunked {
+    let mut buffers = Buffers::default();
+    broadcast_binary_elementwise(ca, other, |a: Option<&str>, b: Option<&str>| {
+        Some(levenshtein_str(a?, b?, &mut buffers).0 as u32)
+    })
+}
+
+/// The Levenshtein distance divided by the length of the longest string, subtracted from 1.
+pub fn levenshtein_similarity(ca: &StringChunked, other: &StringChunked) -> Float64Chunked {
+    let mut buffers = Buffers::default();
+    broadcast_binary_elementwise(ca, other, |a: Option<&str>, b: Option<&str>| {
+        let (distance, len) = levenshtein_str(a?, b?, &mut buffers);
+        Some(normalize_distance(distance, len))
+    })
+}
+
+/// The number of positions at which the characters differ. Null if the lengths differ.
+pub fn hamming(ca: &StringChunked, other: &StringChunked) -> UInt32Chunked {
+    broadcast_binary_elementwise(ca, other, |a: Option<&str>, b: Option<&str>| {
+        hamming_str(a?, b?).map(|(distance, _)| distance as u32)
+    })
+}
+
+/// The Hamming distance divided by the length of the strings, subtracted from 1. Null if the
+/// lengths differ.
+pub fn hamming_similarity(ca: &StringChunked, other: &StringChunked) -> Float64Chunked {
+    broadcast_binary_elementwise(ca, other, |a: Option<&str>, b: Option<&str>| {
+        hamming_str(a?, b?).map(|(distance, len)| normalize_distance(distance, len))
+    })
+}
+
+/// The Jaro similarity, boosted for strings that share a common prefix of up to 4 characters.
+pub fn jaro_winkler_similarity(ca: &StringChunked, other: &StringChunked) -> Float64Chunked {
+    let mut buffers = Buffers::default();
+    broadcast_binary_elementwise(ca, other, |a: Option<&str>, b: Option<&str>| {
+        Some(jaro_winkler_str(a?, b?, &mut buffers))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn assert_close(got: Option<f64>, expected: f64) {
+        let got = got.unwrap();
+        assert!(
+            (got - expected).abs() < 1e-3,
+            "got {got}, expected {expected}"
+        );
+    }
+
+    #[test]
+    fn test_levenshtein() {
+        let a = StringChunked::new(
+            "a".into(),
+            &[Some("kitten"), Some(""), Some("flaw"), Some("héllo"), None],
+        );
+        let b = StringChunked::new(
+            "b".into(),
+            &[
+                Some("sitting"),
+                Some(""),
+                Some("lawn"),
+                Some("hello"),
+                Some("x"),
+            ],
+        );
+        let out = levenshtein(&a, &b);
+        assert_eq!(Vec::from(&out), &[Some(3), Some(0), Some(2), Some(1), None]);
+
+        let out = levenshtein_similarity(&a, &b);
+        assert_close(out.get(0), 1.0 - 3.0 / 7.0);
+        assert_close(out.get(1), 1.0);
+        assert_close(out.get(3), 0.8);
+        assert_eq!(out.get(4), None);
+
+        // A unit length argument is broadcast.
+        let b = StringChunked::new("b".into(), &["kitten"]);
+        let out = levenshtein(&a, &b);
+        assert_eq!(Vec::from(&out), &[Some(0), Some(6), Some(6), Some(6), None]);
+    }
+
+    #[test]
+    fn test_hamming() {
+        let a = StringChunked::new("a".into(), &["karolin", "abc", "über", ""]);
+        let b = StringChunked::new("b".into(), &["kathrin", "ab", "uber", ""]);
+        let out = hamming(&a, &b);
+        assert_eq!(Vec::from(&out), &[Some(3), None, Some(1), Some(0)]);
+
+        let out = hamming_similarity(&a, &b);
+        assert_close(out.get(0), 4.0 / 7.0);
+        assert_eq!(out.get(1), None);
+        assert_close(out.get(2), 0.75);
+        assert_close(out.get(3), 1.0);
+    }
+
+    #[test]
+    fn test_jaro_winkler() {
+        let a = StringChunked::new("a".into(), &["martha", "dixon", "abc", "", "crâte"]);
+        let b = StringChunked::new("b".into(), &["marhta", "dicksonx", "xyz", "", "trâce"]);
+        let out = jaro_winkler_similarity(&a, &b);
+        assert_close(out.get(0), 0.961);
+        assert_close(out.get(1), 0.813);
+        assert_close(out.get(2), 0.0);
+        assert_close(out.get(3), 1.0);
+        assert_close(out.get(4), 0.733);
+    }
+}
@@ -0,0 +1,131 @@
+//! Parsing of URLs such as `https://example.com/search?q=polars`.
+//!
+//! URLs are parsed following the WHATWG URL standard. Strings that are not absolute URLs are
+//! null in the output.
+use std::borrow::Cow;
+
+use ::url::Url;
+use percent_encoding::percent_decode_str;
+use polars_core::prelude::{StringChunked, StringChunkedBuilder};
+
+fn map_strings<'a, F>(ca: &'a StringChunked, mut f: F) -> StringChunked
+where
+    F: FnMut(&'a str) -> Option<Cow<'a, str>>,
+{
+    let mut builder = StringChunkedBuilder::new(ca.name().clone(), ca.len());
+    for opt_s in ca.iter() {
+        match opt_s.and_then(&mut f) {
+            Some(v) => builder.append_value(v),
+            None => builder.append_null(),
+        }
+    }
+    builder.finish()
+}
+
+fn map_urls<F>(ca: &StringChunked, mut f: F) -> StringChunked
+where
+    F: FnMut(&Url) -> Option<String>,
+{
+    map_strings(ca, |s| {
+        Url::parse(s.trim())
+            .ok()
+            .and_then(|url| f(&url))
+            .map(Cow::Owned)
+    })
+}
+
+/// The host of each URL, e.g. `example.com`. Null if the URL has no host.
+pub fn url_host(ca: &StringChunked) -> StringChunked {
+    map_urls(ca, |url| url.host_str().map(str::to_string))
+}
+
+/// The path of each URL, e.g. `/search`. The path stays percent-encoded.
+pub fn url_path(ca: &StringChunked) -> StringChunked {
+    map_urls(ca, |url| Some(url.path().to_string()))
+}
+
+/// The decoded value of the first query parameter called `name` of each URL. Null if the URL
+/// has no such parameter.
+pub fn url_query_param(ca: &StringChunked, name: &str) -> StringChunked {
+    map_urls(ca, |url| {
+        url.query_pairs()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.into_owned())
+    })
+}
+
+/// Decode the percent-encoded sequences of each string, e.g. `%20` to a space. Null if the
+/// decoded bytes are not valid UTF-8.
+pub fn url_decode(ca: &StringChunked) -> StringChunked {
+    map_strings(ca, |s| percent_decode_str(s).decode_utf8().ok())
+}
+
+#[cfg(test)]
+mod test {
+    use polars_core::prelude::*;
+
+    use super::*;
+
+    fn urls() -> StringChunked {
+        StringChunked::new(
+            "url".into(),
+            &[
+                Some("https://Example.com/a/b%20c?q=polars&page=2&q=other"),
+                Some("http://user@[::1]:8080/?q=a+b%26c"),
+                Some("mailto:someone@example.com"),
+                Some("/relative/path?q=1"),
+                None,
+            ],
+        )
+    }
+
+    #[test]
+    fn test_url_host_and_path() {
+        let ca = urls();
+        assert_eq!(
+            Vec::from(&url_host(&ca)),
+            &[Some("example.com"), Some("[::1]"), None, None, None]
+        );
+        assert_eq!(
+            Vec::from(&url_path(&ca)),
+            &[
+                Some("/a/b%20c"),
+                Some("/"),
+                Some("someone@example.com"),
+                None,
+                None
+            ]
+        );
+    }
+
+    #[test]
+    fn test_url_query_param() {
+        let ca = urls();
+        assert_eq!(
+            Vec::from(&url_query_param(&ca, "q")),
+            &[Some("polars"), Some("a b&c"), None, None, None]
+        );
+        assert_eq!(
+            Vec::from(&url_query_param(&ca, "page")),
+            &[Some("2"), None, None, None, None]
+        );
+    }
+
+    #[test]
+    fn test_url_decode() {
+        let ca = StringChunked::new(
+            "url".into(),
+            &[
+                Some("a%20b%2Fc"),
+                Some("caf%C3%A9+"),
+                Some("%FF"),
+                Some("100%"),
+                None,
+            ],
+        );
+        assert_eq!(
+            Vec::from(&url_decode(&ca)),
+            &[Some("a b/c"), Some("café+"), None, Some("100%"), None]
+        );
+    }
+}
@@ -0,0 +1,149 @@
+use polars_core::chunked_array::cast::CastOptions;
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::series::{clip, clip_max, clip_min};
+
+/// What to do with columns of the target schema that the frame doesn't have.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MissingColumns {
+    /// Raise an error.
+    #[default]
+    Raise,
+    /// Insert a column of nulls.
+    Insert,
+}
+
+/// What to do with columns of the frame that the target schema doesn't have.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ExtraColumns {
+    /// Raise an error.
+    #[default]
+    Raise,
+    /// Drop the columns.
+    Drop,
+}
+
+/// What to do with values that can't be represented in the target data type.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum LossyCasts {
+    /// Raise an error.
+    #[default]
+    Raise,
+    /// Clamp numeric values to the bounds of the target data type. Other values that can't be
+    /// cast still raise an error.
+    Saturate,
+    /// Replace the values with nulls.
+    Null,
+}
+
+/// How to conform a [`DataFrame`] to a target schema, see [`DataFrameOps::cast_with_policy`].
+///
+/// The default policy is strict: any difference besides losslessly castable data types raises.
+///
+/// [`DataFrameOps::cast_with_policy`]: crate::frame::DataFrameOps::cast_with_policy
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CastPolicy {
+    pub missing_columns: MissingColumns,
+    pub extra_columns: ExtraColumns,
+    pub lossy_casts: LossyCasts,
+}
+
+impl CastPolicy {
+    pub fn with_missing_columns(mut self, missing_columns: MissingColumns) -> Self {
+        self.missing_columns = missing_columns;
+        self
+    }
+
+    pub fn with_extra_columns(mut self, extra_columns: ExtraColumns) -> Self {
+        self.extra_columns = extra_columns;
+        self
+    }
+
+    pub fn with_lossy_casts(mut self, lossy_casts: LossyCasts) -> Self {
+        self.lossy_casts = lossy_casts;
+        self
+    }
+}
+
+pub(super) fn cast_with_policy(
+    df: &DataFrame,
+    schema: &Schema,
+    policy: CastPolicy,
+) -> PolarsResult<DataFrame> {
+    check_extra_columns(df.get_column_names(), schema, policy)?;
+
+    let columns = schema
+        .iter()
+        .map(|(name, dtype)| match df.column(name) {
+            Ok(column) => cast_column(column, dtype, policy.lossy_casts),
+            Err(_) => match policy.missing_columns {
+                MissingColumns::Raise => polars_bail!(
+                    ColumnNotFound:
+                    "column {} of the target schema is missing, consider inserting missing columns",
+                    name
+                ),
+                MissingColumns::Insert => Ok(Column::full_null(name.clone(), df.height(), dtype)),
+            },
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    DataFrame::new_with_height(df.height(), columns)
+}
+
+/// Raises if a column of `names` isn't in `schema`, unless the `policy` drops extra columns.
+pub fn check_extra_columns<'a>(
+    names: impl IntoIterator<Item = &'a PlSmallStr>,
+    schema: &Schema,
+    policy: CastPolicy,
+) -> PolarsResult<()> {
+    if policy.extra_columns == ExtraColumns::Raise {
+        if let Some(name) = names.into_iter().find(|name| !schema.contains(name)) {
+            polars_bail!(
+                SchemaMismatch:
+                "column {} is not in the target schema, consider dropping extra columns",
+                name
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Cast `column` to `dtype`, dealing with lossy casts according to `lossy_casts`.
+pub fn cast_column(
+    column: &Column,
+    dtype: &DataType,
+    lossy_casts: LossyCasts,
+) -> PolarsResult<Column> {
+    if column.dtype() == dtype {
+        return Ok(column.clone());
+    }
+    match lossy_casts {
+        LossyCasts::Raise => column.strict_cast(dtype),
+        LossyCasts::Null => column.cast_with_options(dtype, CastOptions::NonStrict),
+        LossyCasts::Saturate => {
+            let s = column.as_materialized_series();
+            if !(s.dtype().is_primitive_numeric() && dtype.is_primitive_numeric()) {
+                return column.strict_cast(dtype);
+            }
+            // A bound of the target type that doesn't fit in the source type can't be exceeded.
+            let bound = |bound: Scalar| {
+                bound
+                    .into_series(PlSmallStr::EMPTY)
+                    .strict_cast(s.dtype())
+                    .ok()
+            };
+            let clipped = match (bound(dtype.min()?), bound(dtype.max()?)) {
+                (Some(min), Some(max)) => clip(s, &min, &max)?,
+                (Some(min), None) => clip_min(s, &min)?,
+                (None, Some(max)) => clip_max(s, &max)?,
+                (None, None) => s.clone(),
+            };
+            clipped.strict_cast(dtype).map(Column::from)
+        },
+    }
+}
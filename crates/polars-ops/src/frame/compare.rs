@@ -0,0 +1,182 @@
+use polars_core::prelude::*;
+
+use super::{DataFrameJoinOps, IntoDf};
+use crate::frame::join::{JoinArgs, JoinCoalesce, JoinType};
+
+const LEFT_IDX: &str = "__POLARS_COMPARE_LEFT_IDX";
+const RIGHT_IDX: &str = "__POLARS_COMPARE_RIGHT_IDX";
+
+/// The differences between two [`DataFrame`]s, as found by [`DataFrameCompare::compare`].
+#[derive(Clone, Debug)]
+pub struct DataFrameComparison {
+    /// The rows of the left frame whose keys don't occur in the right frame.
+    pub left_only: DataFrame,
+    /// The rows of the right frame whose keys don't occur in the left frame.
+    pub right_only: DataFrame,
+    /// For every compared column with differing values, the keys of the differing rows followed
+    /// by a `left` and a `right` column with the values of both frames.
+    pub mismatches: Vec<(PlSmallStr, DataFrame)>,
+    /// The non-key columns that only exist in the left frame.
+    pub left_only_columns: Vec<PlSmallStr>,
+    /// The non-key columns that only exist in the right frame.
+    pub right_only_columns: Vec<PlSmallStr>,
+}
+
+impl DataFrameComparison {
+    /// Whether the frames hold the same rows and columns.
+    pub fn is_equal(&self) -> bool {
+        self.left_only.height() == 0
+            && self.right_only.height() == 0
+            && self.mismatches.is_empty()
+            && self.left_only_columns.is_empty()
+            && self.right_only_columns.is_empty()
+    }
+}
+
+impl<T: IntoDf> DataFrameCompare for T {}
+
+pub trait DataFrameCompare: IntoDf {
+    /// Compare this frame with `other`, matching rows on the `keys` columns.
+    ///
+    /// Rows are matched regardless of their order, and null keys match each other. The keys must
+    /// be unique in both frames. Float columns are considered equal if their values differ by at
+    /// most `tolerance`, all other columns must be equal. Nulls are equal to nulls, as are NaNs
+    /// to NaNs. The reported rows keep the order of the frame they come from.
+    ///
+    /// Unlike an assertion, this returns all differences, which makes it suited to check the
+    /// output of a pipeline against a reference.
+    fn compare(
+        &self,
+        other: &DataFrame,
+        keys: &[PlSmallStr],
+        tolerance: Option<f64>,
+    ) -> PolarsResult<DataFrameComparison> {
+        let df = self.to_df();
+        polars_ensure!(
+            !keys.is_empty(),
+            InvalidOperation: "`compare` needs at least one key column"
+        );
+        if let Some(tolerance) = tolerance {
+            polars_ensure!(
+                tolerance >= 0.0,
+                InvalidOperation: "the tolerance of `compare` must be non-negative, got {}",
+                tolerance
+            );
+        }
+
+        let is_key = |name: &PlSmallStr| keys.contains(name);
+        let columns_not_in = |a: &DataFrame, b: &DataFrame| {
+            a.get_column_names()
+                .into_iter()
+                .filter(|name| !is_key(name) && b.get_column_index(name).is_none())
+                .cloned()
+                .collect::<Vec<_>>()
+        };
+        let left_only_columns = columns_not_in(df, other);
+        let right_only_columns = columns_not_in(other, df);
+
+        // Full join the keys, together with the row positions in both frames.
+        let left_keys = DataFrame::new(df.select_columns(keys.to_vec())?)?
+            .with_row_index(PlSmallStr::from_static(LEFT_IDX), None)?;
+        let right_keys = DataFrame::new(other.select_columns(keys.to_vec())?)?
+            .with_row_index(PlSmallStr::from_static(RIGHT_IDX), None)?;
+        let mut args = JoinArgs::new(JoinType::Full).with_coalesce(JoinCoalesce::CoalesceColumns);
+        args.nulls_equal = true;
+        let joined = left_keys
+            .join(&right_keys, keys.to_vec(), keys.to_vec(), args, None)?
+            .sort(
+                [LEFT_IDX],
+                SortMultipleOptions::default().with_nulls_last(true),
+            )?;
+
+        let left_idx = joined.column(LEFT_IDX)?.idx()?;
+        let right_idx = joined.column(RIGHT_IDX)?.idx()?;
+        ensure_unique(left_idx, df.height(), "left")?;
+        ensure_unique(right_idx, other.height(), "right")?;
+
+        let left_only_mask = right_idx.is_null();
+        let right_only_mask = left_idx.is_null();
+        let left_only = df.take(&left_idx.filter(&left_only_mask)?)?;
+        let right_only = other.take(&right_idx.filter(&right_only_mask)?.sort(false))?;
+
+        let matched = !&(&left_only_mask | &right_only_mask);
+        let left_idx = left_idx.filter(&matched)?;
+        let right_idx = right_idx.filter(&matched)?;
+        let matched_keys = DataFrame::new(joined.filter(&matched)?.select_columns(keys.to_vec())?)?;
+
+        let mut mismatches = vec![];
+        for column in df.get_columns() {
+            let name = column.name();
+            if is_key(name) {
+                continue;
+            }
+            let Ok(other_column) = other.column(name) else {
+                continue;
+            };
+            let left = column.take(&left_idx)?;
+            let right = other_column.take(&right_idx)?;
+            let equal = values_equal(
+                left.as_materialized_series(),
+                right.as_materialized_series(),
+                tolerance,
+            )?;
+            let differs = !&equal;
+            if !differs.any() {
+                continue;
+            }
+            let report = matched_keys.filter(&differs)?.hstack(&[
+                left.filter(&differs)?
+                    .with_name(PlSmallStr::from_static("left")),
+                right
+                    .filter(&differs)?
+                    .with_name(PlSmallStr::from_static("right")),
+            ])?;
+            mismatches.push((name.clone(), report));
+        }
+
+        Ok(DataFrameComparison {
+            left_only,
+            right_only,
+            mismatches,
+            left_only_columns,
+            right_only_columns,
+        })
+    }
+}
+
+fn ensure_unique(idx: &IdxCa, height: usize, side: &str) -> PolarsResult<()> {
+    let mut seen = vec![false; height];
+    for i in idx.iter().flatten() {
+        polars_ensure!(
+            !std::mem::replace(&mut seen[i as usize], true),
+            InvalidOperation: "the keys of `compare` are not unique in the {} frame", side
+        );
+    }
+    Ok(())
+}
+
+/// Elementwise equality where nulls equal nulls and NaNs equal NaNs.
+fn values_equal(
+    left: &Series,
+    right: &Series,
+    tolerance: Option<f64>,
+) -> PolarsResult<BooleanChunked> {
+    if !(left.dtype().is_float() && right.dtype().is_float()) {
+        return left.equal_missing(right);
+    }
+    let tolerance = tolerance.unwrap_or(0.0);
+    let left = left.cast(&DataType::Float64)?;
+    let right = right.cast(&DataType::Float64)?;
+    Ok(left
+        .f64()?
+        .iter()
+        .zip(right.f64()?.iter())
+        .map(|(l, r)| match (l, r) {
+            (Some(l), Some(r)) => {
+                l == r || (l - r).abs() <= tolerance || (l.is_nan() && r.is_nan())
+            },
+            (l, r) => l.is_none() && r.is_none(),
+        })
+        .collect::<BooleanChunked>()
+        .with_name(left.name().clone()))
+}
@@ -266,6 +266,15 @@ where
                     )?
                 })
             },
+            // 128-bit values don't have a bit representation.
+            #[cfg(feature = "dtype-i128")]
+            DataType::Int128 => {
+                let left_by = left_by_s.i128().unwrap();
+                let right_by = right_by_s.i128().unwrap();
+                asof_join_by_numeric::<T, Int128Type, A, F>(
+                    left_by, right_by, left_asof, right_asof, filter, allow_eq,
+                )?
+            },
             _ => {
                 let left_by = left_by_s.bit_repr();
                 let right_by = right_by_s.bit_repr();
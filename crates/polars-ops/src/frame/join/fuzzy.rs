@@ -0,0 +1,216 @@
+//! Joins on string similarity, for entity resolution between tables whose keys are spelled
+//! slightly differently.
+//!
+//! Comparing every pair of keys is quadratic, so the keys are blocked on n-grams first: only
+//! pairs of keys that share at least one n-gram are scored. This only finds every match if the
+//! threshold is high enough for similar keys to always share an n-gram, lower thresholds compare
+//! every pair of keys.
+use polars_core::POOL;
+use polars_core::prelude::*;
+use polars_utils::idx_vec::UnitVec;
+use rayon::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::_finish_join;
+use crate::chunked_array::strings::similarity::PairScorer;
+use crate::frame::IntoDf;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FuzzyMetric {
+    /// One minus the Levenshtein distance divided by the length of the longest key.
+    Levenshtein,
+    /// The Jaro-Winkler similarity.
+    #[default]
+    JaroWinkler,
+}
+
+impl FuzzyMetric {
+    /// The threshold above which keys with at least that similarity always share a padded n-gram
+    /// of size `n`.
+    fn blocking_bound(self, n: usize) -> f64 {
+        let n = n as f64;
+        match self {
+            // An edit changes at most `n` of the `len + n - 1` padded n-grams of a key, and keys
+            // with a similarity above `1 - 1 / n` are less than `len / n` edits apart.
+            FuzzyMetric::Levenshtein => 1.0 - 1.0 / n,
+            // Keys without a common n-gram start differently, so they get no prefix bonus, and
+            // every n-gram of them contains an unmatched or transposed character.
+            FuzzyMetric::JaroWinkler => 1.0 - 1.0 / (6.0 * n),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FuzzyJoinOptions {
+    pub metric: FuzzyMetric,
+    /// The minimum similarity in `[0, 1]` of a match.
+    pub threshold: f64,
+    /// The length of the n-grams the keys are blocked on. Shorter n-grams find more matches
+    /// between short or very different keys, but compare more pairs.
+    pub ngram_size: usize,
+    /// Name of the column with the similarity of each match. Not added if `None`.
+    pub similarity_column: Option<PlSmallStr>,
+    pub suffix: Option<PlSmallStr>,
+}
+
+impl Default for FuzzyJoinOptions {
+    fn default() -> Self {
+        Self {
+            metric: FuzzyMetric::default(),
+            threshold: 0.8,
+            ngram_size: 3,
+            similarity_column: None,
+            suffix: None,
+        }
+    }
+}
+
+/// The n-grams of `key`, padded so that its start and end form n-grams of their own.
+fn ngrams(key: &str, n: usize, out: &mut Vec<PlSmallStr>) {
+    out.clear();
+    let chars: Vec<char> = std::iter::repeat_n('\0', n - 1)
+        .chain(key.chars())
+        .chain(std::iter::repeat_n('\0', n - 1))
+        .collect();
+    out.extend(chars.windows(n).map(|w| PlSmallStr::from_iter(w.iter())));
+    // Without padding, empty keys have no n-grams to match each other on.
+    if out.is_empty() {
+        out.push(PlSmallStr::EMPTY);
+    }
+    out.sort_unstable();
+    out.dedup();
+}
+
+pub trait FuzzyJoin: IntoDf {
+    /// Join the rows of which the `left_on` and `right_on` string keys are similar.
+    ///
+    /// Every pair of rows with a similarity of at least `options.threshold` is matched, so
+    /// rows may be matched more than once. Null keys never match. The result is ordered by the
+    /// left rows, and then by the right rows.
+    ///
+    /// If the threshold is high enough, only pairs of keys that share an n-gram are compared,
+    /// which finds the pairs of similar keys without comparing all of them.
+    fn join_fuzzy(
+        &self,
+        other: &DataFrame,
+        left_on: &str,
+        right_on: &str,
+        options: FuzzyJoinOptions,
+    ) -> PolarsResult<DataFrame> {
+        let df = self.to_df();
+        polars_ensure!(
+            (0.0..=1.0).contains(&options.threshold),
+            InvalidOperation: "the threshold of `join_fuzzy` must be in [0, 1], got {}",
+            options.threshold
+        );
+        polars_ensure!(
+            options.ngram_size > 0,
+            InvalidOperation: "the n-gram size of `join_fuzzy` must be positive"
+        );
+        let left_key = df.column(left_on)?.str()?.rechunk();
+        let right_key = other.column(right_on)?.str()?.rechunk();
+        let n = options.ngram_size;
+        let blocked = options.threshold > options.metric.blocking_bound(n);
+
+        // Index the right keys by their n-grams.
+        let mut index = PlHashMap::<PlSmallStr, UnitVec<IdxSize>>::new();
+        let mut grams = vec![];
+        if blocked {
+            for (i, key) in right_key.iter().enumerate() {
+                let Some(key) = key else {
+                    continue;
+                };
+                ngrams(key, n, &mut grams);
+                for gram in grams.drain(..) {
+                    index.entry(gram).or_default().push(i as IdxSize);
+                }
+            }
+        }
+
+        let left_keys: Vec<Option<&str>> = left_key.iter().collect();
+        let matches: Vec<Vec<(IdxSize, IdxSize, f64)>> = POOL.install(|| {
+            left_keys
+                .par_iter()
+                .enumerate()
+                .map_init(
+                    || {
+                        (
+                            PairScorer::default(),
+                            Vec::<PlSmallStr>::new(),
+                            Vec::<IdxSize>::new(),
+                        )
+                    },
+                    |(scorer, grams, candidates), (i, key)| {
+                        let Some(key) = key else {
+                            return vec![];
+                        };
+                        candidates.clear();
+                        if blocked {
+                            ngrams(key, n, grams);
+                            for gram in grams.iter() {
+                                if let Some(rows) = index.get(gram) {
+                                    candidates.extend_from_slice(rows);
+                                }
+                            }
+                            candidates.sort_unstable();
+                            candidates.dedup();
+                        } else {
+                            candidates.extend(0..right_key.len() as IdxSize);
+                        }
+
+                        let mut out = vec![];
+                        for &j in candidates.iter() {
+                            let Some(other) = right_key.get(j as usize) else {
+                                continue;
+                            };
+                            let similarity = match options.metric {
+                                FuzzyMetric::Levenshtein => {
+                                    scorer.levenshtein_similarity(key, other)
+                                },
+                                FuzzyMetric::JaroWinkler => {
+                                    scorer.jaro_winkler_similarity(key, other)
+                                },
+                            };
+                            if similarity >= options.threshold {
+                                out.push((i as IdxSize, j, similarity));
+                            }
+                        }
+                        out
+                    },
+                )
+                .collect()
+        });
+
+        let n_matches = matches.iter().map(|m| m.len()).sum();
+        let mut left_idx = Vec::with_capacity(n_matches);
+        let mut right_idx = Vec::with_capacity(n_matches);
+        let mut similarities = Vec::with_capacity(n_matches);
+        for (l, r, similarity) in matches.into_iter().flatten() {
+            left_idx.push(l);
+            right_idx.push(r);
+            similarities.push(similarity);
+        }
+
+        // SAFETY: the indices are in bounds of both frames.
+        let (left, right) = unsafe {
+            (
+                df.take_unchecked(&IdxCa::from_vec(PlSmallStr::EMPTY, left_idx)),
+                other.take_unchecked(&IdxCa::from_vec(PlSmallStr::EMPTY, right_idx)),
+            )
+        };
+        let mut out = _finish_join(left, right, options.suffix)?;
+        if let Some(name) = options.similarity_column {
+            polars_ensure!(
+                out.get_column_index(&name).is_none(),
+                Duplicate: "the similarity column {} of `join_fuzzy` already exists", name
+            );
+            out.with_column(Float64Chunked::from_vec(name, similarities))?;
+        }
+        Ok(out)
+    }
+}
+
+impl FuzzyJoin for DataFrame {}
@@ -82,6 +82,13 @@ pub trait SeriesJoin: SeriesSealed + Sized {
                 let rhs = &encode_rows_unordered(&[rhs.into_owned().into()])?.into_series();
                 lhs.hash_join_left(rhs, validate, nulls_equal)
             },
+            // 128-bit integers have no bit representation.
+            #[cfg(feature = "dtype-i128")]
+            T::Int128 => {
+                let lhs = lhs.i128().unwrap();
+                let rhs = rhs.i128()?;
+                num_group_join_left(lhs, rhs, validate, nulls_equal)
+            },
             x if x.is_float() => {
                 with_match_physical_float_polars_type!(lhs.dtype(), |$T| {
                     let lhs: &ChunkedArray<$T> = lhs.as_ref().as_ref().as_ref();
@@ -177,6 +184,12 @@ pub trait SeriesJoin: SeriesSealed + Sized {
                 let rhs = &encode_rows_unordered(&[rhs.into_owned().into()])?.into_series();
                 lhs.hash_join_semi_anti(rhs, anti, nulls_equal)?
             },
+            #[cfg(feature = "dtype-i128")]
+            T::Int128 => {
+                let lhs = lhs.i128().unwrap();
+                let rhs = rhs.i128()?;
+                num_group_join_anti_semi(lhs, rhs, anti, nulls_equal)
+            },
             x if x.is_float() => {
                 with_match_physical_float_polars_type!(lhs.dtype(), |$T| {
                     let lhs: &ChunkedArray<$T> = lhs.as_ref().as_ref().as_ref();
@@ -295,6 +308,12 @@ pub trait SeriesJoin: SeriesSealed + Sized {
                 let rhs = &encode_rows_unordered(&[rhs.into_owned().into()])?.into_series();
                 lhs.hash_join_inner(rhs, validate, nulls_equal)
             },
+            #[cfg(feature = "dtype-i128")]
+            T::Int128 => {
+                let lhs = lhs.i128().unwrap();
+                let rhs = rhs.i128()?;
+                group_join_inner(lhs, rhs, validate, nulls_equal)
+            },
             x if x.is_float() => {
                 with_match_physical_float_polars_type!(lhs.dtype(), |$T| {
                     let lhs: &ChunkedArray<$T> = lhs.as_ref().as_ref().as_ref();
@@ -382,6 +401,12 @@ pub trait SeriesJoin: SeriesSealed + Sized {
                 let rhs = &encode_rows_unordered(&[rhs.into_owned().into()])?.into_series();
                 lhs.hash_join_outer(rhs, validate, nulls_equal)
             },
+            #[cfg(feature = "dtype-i128")]
+            T::Int128 => {
+                let lhs = lhs.i128().unwrap();
+                let rhs = rhs.i128()?;
+                hash_join_outer(lhs, rhs, validate, nulls_equal)
+            },
             x if x.is_float() => {
                 with_match_physical_float_polars_type!(lhs.dtype(), |$T| {
                     let lhs: &ChunkedArray<$T> = lhs.as_ref().as_ref().as_ref();
@@ -5,12 +5,15 @@ mod asof;
 mod checks;
 mod cross_join;
 mod dispatch_left_right;
+#[cfg(feature = "fuzzy_join")]
+mod fuzzy;
 mod general;
 mod hash_join;
 #[cfg(feature = "iejoin")]
 mod iejoin;
 #[cfg(feature = "merge_sorted")]
 mod merge_sorted;
+mod validation;
 
 use std::borrow::Cow;
 use std::fmt::{Debug, Display, Formatter};
@@ -25,6 +28,8 @@ pub(crate) use checks::*;
 pub use cross_join::CrossJoin;
 #[cfg(feature = "chunked_ids")]
 use either::Either;
+#[cfg(feature = "fuzzy_join")]
+pub use fuzzy::{FuzzyJoin, FuzzyJoinOptions, FuzzyMetric};
 #[cfg(feature = "chunked_ids")]
 use general::create_chunked_index_mapping;
 pub use general::{_coalesce_full_join, _finish_join, _join_suffix_name};
@@ -49,6 +54,7 @@ use polars_utils::hashing::BytesHash;
 use rayon::prelude::*;
 
 use self::cross_join::fused_cross_filter;
+use self::validation::with_validation_diagnostics;
 use super::IntoDf;
 
 pub trait DataFrameJoinOps: IntoDf {
@@ -264,7 +270,8 @@ pub trait DataFrameJoinOps: IntoDf {
             let s_right = &selected_right[0];
             let drop_names: Option<Vec<PlSmallStr>> =
                 if should_coalesce { None } else { Some(vec![]) };
-            return match args.how {
+            let (validation, nulls_equal) = (args.validation, args.nulls_equal);
+            let out = match args.how {
                 JoinType::Inner => left_df
                     ._inner_join_from_series(other, s_left, s_right, args, _verbose, drop_names),
                 JoinType::Left => dispatch_left_right::left_join_from_series(
@@ -342,6 +349,13 @@ pub trait DataFrameJoinOps: IntoDf {
                     unreachable!()
                 },
             };
+            return with_validation_diagnostics(
+                out,
+                &selected_left,
+                &selected_right,
+                validation,
+                nulls_equal,
+            );
         }
         let (lhs_keys, rhs_keys) =
             if left_df.is_empty() || other.is_empty() && matches!(&args.how, JoinType::Inner) {
@@ -374,7 +388,8 @@ pub trait DataFrameJoinOps: IntoDf {
         };
 
         // Multiple keys.
-        match args.how {
+        let (validation, nulls_equal) = (args.validation, args.nulls_equal);
+        let out = match args.how {
             #[cfg(feature = "asof_join")]
             JoinType::AsOf(_) => polars_bail!(
                 ComputeError: "asof join not supported for join on multiple keys"
@@ -443,7 +458,52 @@ pub trait DataFrameJoinOps: IntoDf {
                 _check_rechunk,
                 _verbose,
             ),
+        };
+        with_validation_diagnostics(
+            out,
+            &selected_left,
+            &selected_right,
+            validation,
+            nulls_equal,
+        )
+    }
+
+    /// Get the join keys that violate `validation`.
+    ///
+    /// Returns a frame with a `side` column holding `"left"` or `"right"`, the key columns named
+    /// after `left_on`, and a `count` column with the number of rows of that side with the key.
+    /// The frame is empty if a join with this validation would succeed.
+    fn join_violations(
+        &self,
+        other: &DataFrame,
+        left_on: impl IntoIterator<Item = impl Into<PlSmallStr>>,
+        right_on: impl IntoIterator<Item = impl Into<PlSmallStr>>,
+        validation: JoinValidation,
+        nulls_equal: bool,
+    ) -> PolarsResult<DataFrame> {
+        let select = |df: &DataFrame, on: Vec<PlSmallStr>| -> PolarsResult<Vec<Series>> {
+            Ok(df
+                .select_columns(on)?
+                .into_iter()
+                .map(Column::take_materialized_series)
+                .collect())
+        };
+        let left_on = left_on.into_iter().map(Into::into).collect();
+        let right_on = right_on.into_iter().map(Into::into).collect();
+        let selected_left = select(self.to_df(), left_on)?;
+        let selected_right = select(other, right_on)?;
+        if let Some((l, r)) = selected_left
+            .iter()
+            .zip(&selected_right)
+            .find(|(l, r)| l.dtype() != r.dtype())
+        {
+            polars_bail!(
+                SchemaMismatch: "datatypes of join keys don't match - `{}`: {} on left does not \
+                match `{}`: {} on right",
+                l.name(), l.dtype(), r.name(), r.dtype()
+            );
         }
+        validation::join_violations(&selected_left, &selected_right, validation, nulls_equal)
     }
 
     /// Perform an inner join on two DataFrames.
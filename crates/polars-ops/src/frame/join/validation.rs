@@ -0,0 +1,167 @@
+//! Diagnostics for joins of which the keys don't fulfill the requested [`JoinValidation`].
+use std::fmt::Write;
+
+use super::*;
+
+/// The maximum number of duplicate keys that are shown in a validation error.
+const N_SAMPLE_KEYS: usize = 5;
+
+/// The keys that occur more than once, in the order of their first occurrence, and the number of
+/// times they occur.
+struct DuplicateKeys {
+    keys: DataFrame,
+    counts: Vec<IdxSize>,
+}
+
+impl DuplicateKeys {
+    fn new(keys: &[Series], names: &[PlSmallStr], nulls_equal: bool) -> PolarsResult<Self> {
+        let columns = keys
+            .iter()
+            .zip(names)
+            .map(|(s, name)| s.clone().with_name(name.clone()).into_column())
+            .collect();
+        let mut df = DataFrame::new(columns)?;
+        // Null keys never match if nulls aren't equal, so they can't violate the validation.
+        if !nulls_equal {
+            df = df.drop_nulls::<String>(None)?;
+        }
+        let groups = df.group_by_stable(names.iter().cloned())?;
+        let (first, counts): (Vec<_>, Vec<_>) = groups
+            .get_groups()
+            .iter()
+            .filter(|g| g.len() > 1)
+            .map(|g| (g.first(), g.len() as IdxSize))
+            .unzip();
+        let keys = df.take(&IdxCa::from_vec(PlSmallStr::EMPTY, first))?;
+        Ok(Self { keys, counts })
+    }
+
+    fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    fn fmt_key(&self, idx: usize) -> String {
+        let values = self.keys.get(idx).unwrap();
+        let values = values.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+        if values.len() == 1 {
+            values.into_iter().next().unwrap()
+        } else {
+            format!("({})", values.join(", "))
+        }
+    }
+
+    fn into_frame(self, side: &str) -> PolarsResult<DataFrame> {
+        let height = self.counts.len();
+        let side = StringChunked::full(PlSmallStr::from_static("side"), side, height);
+        let counts = IdxCa::from_vec(PlSmallStr::from_static("count"), self.counts);
+        let mut columns = Vec::with_capacity(self.keys.width() + 2);
+        columns.push(side.into_column());
+        columns.extend(self.keys.take_columns());
+        columns.push(counts.into_column());
+        DataFrame::new(columns)
+    }
+}
+
+/// The duplicate keys of the left and right side that violate `validation`.
+fn duplicate_keys(
+    left: &[Series],
+    right: &[Series],
+    validation: JoinValidation,
+    nulls_equal: bool,
+) -> PolarsResult<[Option<DuplicateKeys>; 2]> {
+    use JoinValidation::*;
+    let names = left.iter().map(|s| s.name().clone()).collect::<Vec<_>>();
+    let check_left = matches!(validation, OneToMany | OneToOne);
+    let check_right = matches!(validation, ManyToOne | OneToOne);
+    Ok([
+        check_left
+            .then(|| DuplicateKeys::new(left, &names, nulls_equal))
+            .transpose()?,
+        check_right
+            .then(|| DuplicateKeys::new(right, &names, nulls_equal))
+            .transpose()?,
+    ])
+}
+
+/// Every key that violates `validation`.
+///
+/// Returns a frame with a `side` column, holding either `"left"` or `"right"`, the key columns
+/// named after the left keys, and a `count` column with the number of rows of that side with
+/// the key. The keys are ordered by side, and then by their first occurrence.
+pub(super) fn join_violations(
+    left: &[Series],
+    right: &[Series],
+    validation: JoinValidation,
+    nulls_equal: bool,
+) -> PolarsResult<DataFrame> {
+    polars_ensure!(
+        left.len() == right.len(),
+        InvalidOperation: "the number of left and right join keys must be equal"
+    );
+    let mut out: Option<DataFrame> = None;
+    let duplicates = duplicate_keys(left, right, validation, nulls_equal)?;
+    for (side, dups) in ["left", "right"].into_iter().zip(duplicates) {
+        let Some(dups) = dups else {
+            continue;
+        };
+        let df = dups.into_frame(side)?;
+        out = Some(match out {
+            Some(out) => out.vstack(&df)?,
+            None => df,
+        });
+    }
+    match out {
+        Some(out) => Ok(out),
+        None => {
+            let keys = left.iter().map(|s| s.clear().into_column()).collect();
+            let empty = DuplicateKeys {
+                keys: DataFrame::new(keys)?,
+                counts: vec![],
+            };
+            empty.into_frame("left")
+        },
+    }
+}
+
+/// Replace a validation error of a join with an error that describes the duplicate keys.
+///
+/// The hash joins validate the keys while building their tables, at which point the offending
+/// keys are no longer known. So if a join fails, the keys are checked again.
+pub(super) fn with_validation_diagnostics<T>(
+    out: PolarsResult<T>,
+    left: &[Series],
+    right: &[Series],
+    validation: JoinValidation,
+    nulls_equal: bool,
+) -> PolarsResult<T> {
+    let err = match out {
+        Err(err) if validation.needs_checks() => err,
+        out => return out,
+    };
+    let Ok(duplicates) = duplicate_keys(left, right, validation, nulls_equal) else {
+        return Err(err);
+    };
+
+    let mut msg = format!("join keys did not fulfill {validation} validation");
+    let mut found = false;
+    for (side, dups) in ["left", "right"].iter().zip(&duplicates) {
+        let Some(dups) = dups.as_ref().filter(|d| !d.is_empty()) else {
+            continue;
+        };
+        found = true;
+        let n = dups.counts.len();
+        let plural = if n == 1 { "key occurs" } else { "keys occur" };
+        write!(msg, "\n\n{n} {plural} more than once in the {side} frame:").unwrap();
+        for (i, count) in dups.counts.iter().take(N_SAMPLE_KEYS).enumerate() {
+            write!(msg, "\n  {} ({count} rows)", dups.fmt_key(i)).unwrap();
+        }
+        if n > N_SAMPLE_KEYS {
+            write!(msg, "\n  ...").unwrap();
+        }
+    }
+    if !found {
+        return Err(err);
+    }
+    msg.push_str("\n\nHint: use `join_violations` to get all duplicate keys.");
+    Err(PolarsError::ComputeError(msg.into()))
+}
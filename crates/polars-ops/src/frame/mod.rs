@@ -1,3 +1,7 @@
+#[cfg(feature = "cast_policy")]
+pub mod cast_policy;
+#[cfg(feature = "frame_compare")]
+pub mod compare;
 pub mod join;
 #[cfg(feature = "pivot")]
 pub mod pivot;
@@ -113,4 +117,18 @@ pub trait DataFrameOps: IntoDf {
 
         accumulate_dataframes_horizontal(cols)
     }
+
+    /// Conform the frame to `schema`, in the order of `schema`.
+    ///
+    /// The `policy` declares how to deal with columns that are missing or extra, and with values
+    /// that can't be cast losslessly. This allows ingest pipelines to state their tolerance for
+    /// schema evolution once, instead of handling every kind of mismatch by hand.
+    #[cfg(feature = "cast_policy")]
+    fn cast_with_policy(
+        &self,
+        schema: &Schema,
+        policy: cast_policy::CastPolicy,
+    ) -> PolarsResult<DataFrame> {
+        cast_policy::cast_with_policy(self.to_df(), schema, policy)
+    }
 }
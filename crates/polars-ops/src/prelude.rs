@@ -4,6 +4,10 @@ pub(crate) use {crate::series::*, rayon::prelude::*};
 pub use crate::chunked_array::*;
 #[cfg(feature = "merge_sorted")]
 pub use crate::frame::_merge_sorted_dfs;
+#[cfg(feature = "cast_policy")]
+pub use crate::frame::cast_policy::{CastPolicy, ExtraColumns, LossyCasts, MissingColumns};
+#[cfg(feature = "frame_compare")]
+pub use crate::frame::compare::{DataFrameCompare, DataFrameComparison};
 pub use crate::frame::join::*;
 #[cfg(feature = "pivot")]
 pub use crate::frame::pivot::UnpivotDF;
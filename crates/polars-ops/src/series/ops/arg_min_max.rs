@@ -4,6 +4,7 @@ use arrow::legacy::bit_util::*;
 use polars_core::chunked_array::ops::float_sorted_arg_max::{
     float_arg_max_sorted_ascending, float_arg_max_sorted_descending,
 };
+use polars_core::chunked_array::ops::row_encode::_get_rows_encoded_ca;
 use polars_core::series::IsSorted;
 use polars_core::with_match_physical_numeric_polars_type;
 
@@ -136,6 +137,67 @@ impl ArgAgg for Series {
     }
 }
 
+/// Get the index of the row of `by` that is the smallest in lexicographical order.
+///
+/// Nulls are ordered after all other values and rows in which every value is null are ignored.
+/// Ties resolve to the first row.
+pub fn arg_min_by(by: &[Column]) -> PolarsResult<Option<usize>> {
+    arg_min_max_by(by, false)
+}
+
+/// Get the index of the row of `by` that is the largest in lexicographical order.
+///
+/// Nulls are ordered after all other values and rows in which every value is null are ignored.
+/// Ties resolve to the first row.
+pub fn arg_max_by(by: &[Column]) -> PolarsResult<Option<usize>> {
+    arg_min_max_by(by, true)
+}
+
+fn arg_min_max_by(by: &[Column], max: bool) -> PolarsResult<Option<usize>> {
+    polars_ensure!(!by.is_empty(), ComputeError: "expected at least one column to order by");
+    let len = by[0].len();
+    for c in by {
+        polars_ensure!(
+            c.len() == len,
+            ShapeMismatch: "columns to order by must have the same length, got {} and {}",
+            len, c.len()
+        );
+    }
+
+    if let [c] = by {
+        let dtype = c.dtype();
+        if dtype.is_primitive_numeric()
+            || dtype.is_bool()
+            || dtype.is_string()
+            || dtype.is_temporal()
+        {
+            let s = c.as_materialized_series();
+            return Ok(if max { s.arg_max() } else { s.arg_min() });
+        }
+    }
+
+    // Encoding the rows descending turns the maximum into the minimum, with nulls still last.
+    let n = by.len();
+    let rows = _get_rows_encoded_ca(PlSmallStr::EMPTY, by, &vec![max; n], &vec![true; n])?;
+    let all_null = by
+        .iter()
+        .map(|c| c.is_null())
+        .reduce(|acc, is_null| &acc & &is_null)
+        .unwrap();
+
+    let mut best: Option<(usize, &[u8])> = None;
+    for (idx, (row, all_null)) in rows
+        .into_no_null_iter()
+        .zip(all_null.into_no_null_iter())
+        .enumerate()
+    {
+        if !all_null && best.is_none_or(|(_, best)| row < best) {
+            best = Some((idx, row));
+        }
+    }
+    Ok(best.map(|(idx, _)| idx))
+}
+
 fn arg_max_numeric_dispatch<T>(ca: &ChunkedArray<T>) -> Option<usize>
 where
     T: PolarsNumericType,
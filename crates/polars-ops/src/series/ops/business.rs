@@ -2,6 +2,7 @@
 use chrono::DateTime;
 use polars_core::prelude::arity::{binary_elementwise_values, try_binary_elementwise};
 use polars_core::prelude::*;
+use polars_core::utils::CustomIterTools;
 #[cfg(feature = "dtype-date")]
 use polars_core::utils::arrow::temporal_conversions::SECONDS_IN_DAY;
 use polars_utils::binary_search::{find_first_ge_index, find_first_gt_index};
@@ -263,6 +264,53 @@ pub fn add_business_days(
     Ok(out.into_date().into_series())
 }
 
+/// Determine whether each date is a business day.
+///
+/// # Arguments
+/// - `dates`: Series holding dates or datetimes.
+/// - `week_mask`: A boolean array of length 7, where `true` indicates that the day is a business day.
+/// - `holidays`: timestamps that are holidays. Must be provided as i32, i.e. the number of
+///   days since the UNIX epoch.
+pub fn is_business_day(
+    dates: &Series,
+    week_mask: [bool; 7],
+    holidays: &[i32],
+) -> PolarsResult<Series> {
+    if !week_mask.iter().any(|&x| x) {
+        polars_bail!(ComputeError:"`week_mask` must have at least one business day");
+    }
+
+    let dates = match dates.dtype() {
+        DataType::Date => dates.clone(),
+        #[cfg(feature = "dtype-datetime")]
+        DataType::Datetime(_, None) => dates.cast(&DataType::Date)?,
+        #[cfg(feature = "timezones")]
+        DataType::Datetime(_, Some(_)) => replace_time_zone(
+            dates.datetime().unwrap(),
+            None,
+            &StringChunked::from_iter(std::iter::once("raise")),
+            NonExistent::Raise,
+        )?
+        .cast(&DataType::Date)?,
+        _ => polars_bail!(InvalidOperation: "expected date or datetime, got {}", dates.dtype()),
+    };
+
+    // Sort now so we can use `binary_search` in the hot for-loop.
+    let holidays = normalise_holidays(holidays, &week_mask);
+    let dates = dates.date()?;
+    let out: BooleanChunked = dates
+        .iter()
+        .map(|opt_date| {
+            opt_date.map(|date| {
+                // SAFETY: week_mask is length 7, get_day_of_week result is between 0 and 6
+                let is_weekday = unsafe { *week_mask.get_unchecked(get_day_of_week(date)) };
+                is_weekday && holidays.binary_search(&date).is_err()
+            })
+        })
+        .collect_trusted();
+    Ok(out.with_name(dates.name().clone()).into_series())
+}
+
 /// Ported from:
 /// https://github.com/numpy/numpy/blob/e59c074842e3f73483afa5ddef031e856b9fd313/numpy/_core/src/multiarray/datetime_busday.c#L265-L353
 fn add_business_days_impl(
@@ -1,6 +1,7 @@
 pub use arrow::legacy::kernels::ewm::EWMOptions;
 use arrow::legacy::kernels::ewm::{
-    ewm_mean as kernel_ewm_mean, ewm_std as kernel_ewm_std, ewm_var as kernel_ewm_var,
+    ewm_cov as kernel_ewm_cov, ewm_mean as kernel_ewm_mean, ewm_std as kernel_ewm_std,
+    ewm_var as kernel_ewm_var,
 };
 use polars_core::prelude::*;
 
@@ -9,6 +10,15 @@ fn check_alpha(alpha: f64) -> PolarsResult<()> {
     Ok(())
 }
 
+fn check_lengths(x: &Series, y: &Series) -> PolarsResult<()> {
+    polars_ensure!(
+        x.len() == y.len(),
+        ShapeMismatch: "ewm_cov and ewm_corr require inputs of equal length, got {} and {}",
+        x.len(), y.len()
+    );
+    Ok(())
+}
+
 pub fn ewm_mean(s: &Series, options: EWMOptions) -> PolarsResult<Series> {
     check_alpha(options.alpha)?;
     match s.dtype() {
@@ -99,3 +109,74 @@ pub fn ewm_var(s: &Series, options: EWMOptions) -> PolarsResult<Series> {
         _ => ewm_var(&s.cast(&DataType::Float64)?, options),
     }
 }
+
+/// The exponentially-weighted moving covariance of `x` and `y`.
+///
+/// Only the pairs of which both values are valid are observations.
+pub fn ewm_cov(x: &Series, y: &Series, options: EWMOptions) -> PolarsResult<Series> {
+    check_alpha(options.alpha)?;
+    check_lengths(x, y)?;
+    match (x.dtype(), y.dtype()) {
+        (DataType::Float32, DataType::Float32) => {
+            let result = kernel_ewm_cov(
+                x.f32().unwrap(),
+                y.f32().unwrap(),
+                options.alpha as f32,
+                options.adjust,
+                options.bias,
+                options.min_periods,
+                options.ignore_nulls,
+            );
+            Series::try_from((x.name().clone(), Box::new(result) as ArrayRef))
+        },
+        (DataType::Float64, DataType::Float64) => {
+            let result = kernel_ewm_cov(
+                x.f64().unwrap(),
+                y.f64().unwrap(),
+                options.alpha,
+                options.adjust,
+                options.bias,
+                options.min_periods,
+                options.ignore_nulls,
+            );
+            Series::try_from((x.name().clone(), Box::new(result) as ArrayRef))
+        },
+        _ => ewm_cov(
+            &x.cast(&DataType::Float64)?,
+            &y.cast(&DataType::Float64)?,
+            options,
+        ),
+    }
+}
+
+/// The exponentially-weighted moving correlation of `x` and `y`.
+///
+/// The covariance and the standard deviations are estimated on the same observations: the
+/// pairs of which both values are valid. The bias correction cancels out, so `options.bias`
+/// is ignored.
+pub fn ewm_corr(x: &Series, y: &Series, options: EWMOptions) -> PolarsResult<Series> {
+    check_alpha(options.alpha)?;
+    check_lengths(x, y)?;
+    let options = EWMOptions {
+        bias: true,
+        ..options
+    };
+    let mask = x.is_not_null() & y.is_not_null();
+    let (x, y) = if mask.all() {
+        (x.clone(), y.clone())
+    } else {
+        (
+            x.zip_with(
+                &mask,
+                &Series::full_null(PlSmallStr::EMPTY, x.len(), x.dtype()),
+            )?,
+            y.zip_with(
+                &mask,
+                &Series::full_null(PlSmallStr::EMPTY, y.len(), y.dtype()),
+            )?,
+        )
+    };
+    let cov = ewm_cov(&x, &y, options)?;
+    let std = (&ewm_std(&x, options)? * &ewm_std(&y, options)?)?;
+    &cov / &std
+}
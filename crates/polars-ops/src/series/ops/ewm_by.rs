@@ -194,6 +194,149 @@ where
     ChunkedArray::with_chunk(values.name().clone(), arr)
 }
 
+/// Exponentially weighted moving variance by a time column.
+///
+/// Uses the same time-dependent decay as [`ewm_mean_by`] and updates the variance
+/// recursively: `var_i = (1 - alpha_i) * (var_{i-1} + alpha_i * (x_i - mean_{i-1})^2)`.
+pub fn ewm_var_by(
+    s: &Series,
+    times: &Series,
+    half_life: i64,
+    times_is_sorted: bool,
+) -> PolarsResult<Series> {
+    polars_ensure!(
+        s.len() == times.len(),
+        length_mismatch = "ewm_var_by",
+        s.len(),
+        times.len()
+    );
+
+    match (s.dtype(), times.dtype()) {
+        (DataType::Float64, DataType::Int64) => Ok(ewm_var_by_impl(
+            s.f64().unwrap(),
+            times.i64().unwrap(),
+            half_life,
+            times_is_sorted,
+        )
+        .into_series()),
+        (DataType::Float32, DataType::Int64) => Ok(ewm_var_by_impl(
+            s.f32().unwrap(),
+            times.i64().unwrap(),
+            half_life,
+            times_is_sorted,
+        )
+        .into_series()),
+        #[cfg(feature = "dtype-datetime")]
+        (_, DataType::Datetime(time_unit, _)) => {
+            let half_life = adjust_half_life_to_time_unit(half_life, time_unit);
+            ewm_var_by(
+                s,
+                &times.cast(&DataType::Int64)?,
+                half_life,
+                times_is_sorted,
+            )
+        },
+        #[cfg(feature = "dtype-date")]
+        (_, DataType::Date) => ewm_var_by(
+            s,
+            &times.cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?,
+            half_life,
+            times_is_sorted,
+        ),
+        (_, DataType::UInt64 | DataType::UInt32 | DataType::Int32) => ewm_var_by(
+            s,
+            &times.cast(&DataType::Int64)?,
+            half_life,
+            times_is_sorted,
+        ),
+        (DataType::UInt64 | DataType::UInt32 | DataType::Int64 | DataType::Int32, _) => ewm_var_by(
+            &s.cast(&DataType::Float64)?,
+            times,
+            half_life,
+            times_is_sorted,
+        ),
+        _ => {
+            polars_bail!(InvalidOperation: "expected series to be Float64, Float32, \
+                Int64, Int32, UInt64, UInt32, and `by` to be Date, Datetime, Int64, Int32, \
+                UInt64, or UInt32")
+        },
+    }
+}
+
+/// Exponentially weighted moving standard deviation by a time column, see [`ewm_var_by`].
+pub fn ewm_std_by(
+    s: &Series,
+    times: &Series,
+    half_life: i64,
+    times_is_sorted: bool,
+) -> PolarsResult<Series> {
+    let var = ewm_var_by(s, times, half_life, times_is_sorted)?;
+    Ok(match var.dtype() {
+        DataType::Float32 => var.f32().unwrap().apply_values(|v| v.sqrt()).into_series(),
+        _ => var.f64().unwrap().apply_values(|v| v.sqrt()).into_series(),
+    })
+}
+
+fn ewm_var_by_impl<T>(
+    values: &ChunkedArray<T>,
+    times: &Int64Chunked,
+    half_life: i64,
+    times_is_sorted: bool,
+) -> ChunkedArray<T>
+where
+    T: PolarsFloatType,
+    T::Native: Float + Zero + One,
+    ChunkedArray<T>: ChunkTakeUnchecked<IdxCa>,
+{
+    // Sort on behalf of user and scatter the results back afterwards.
+    let sorting_indices = (!times_is_sorted).then(|| times.arg_sort(Default::default()));
+    let (sorted_values, sorted_times) = match &sorting_indices {
+        Some(idx) => unsafe { (values.take_unchecked(idx), times.take_unchecked(idx)) },
+        None => (values.clone(), times.clone()),
+    };
+    let sorting_indices = sorting_indices.as_ref().map(|idx| {
+        idx.cont_slice()
+            .expect("`arg_sort` should have returned a single chunk")
+    });
+
+    let mut out: Vec<_> = zeroed_vec(sorted_times.len());
+    // (time, mean, var) of the previous valid observation.
+    let mut prev: Option<(i64, T::Native, T::Native)> = None;
+    for (i, (value, time)) in sorted_values.iter().zip(sorted_times.iter()).enumerate() {
+        let (Some(value), Some(time)) = (value, time) else {
+            continue;
+        };
+        let (mean, var) = match prev {
+            None => (value, T::Native::zero()),
+            Some((prev_time, prev_mean, prev_var)) => {
+                // equivalent to: alpha = 1 - exp(-delta_time*ln(2) / half_life)
+                let one_minus_alpha = T::Native::from_f64(0.5).unwrap().powf(
+                    T::Native::from_i64(time - prev_time).unwrap()
+                        / T::Native::from_i64(half_life).unwrap(),
+                );
+                let alpha = T::Native::one() - one_minus_alpha;
+                let diff = value - prev_mean;
+                (
+                    prev_mean + alpha * diff,
+                    one_minus_alpha * (prev_var + alpha * diff * diff),
+                )
+            },
+        };
+        prev = Some((time, mean, var));
+        let out_idx = match sorting_indices {
+            Some(sorting_indices) => sorting_indices[i] as usize,
+            None => i,
+        };
+        out[out_idx] = var;
+    }
+    let mut arr = T::Array::from_zeroable_vec(out, values.dtype().to_arrow(CompatLevel::newest()));
+    if (times.null_count() > 0) || (values.null_count() > 0) {
+        let validity = binary_concatenate_validities(times, values);
+        arr = arr.with_validity_typed(validity);
+    }
+    ChunkedArray::with_chunk(values.name().clone(), arr)
+}
+
 fn adjust_half_life_to_time_unit(half_life: i64, time_unit: &TimeUnit) -> i64 {
     match time_unit {
         TimeUnit::Milliseconds => half_life / 1_000_000,
@@ -0,0 +1,725 @@
+//! Spatial functions on planar geometries stored as Well-Known Binary (WKB).
+//!
+//! The functions accept [`DataType::Geometry`] and [`DataType::Binary`] values. Both byte orders
+//! are supported, as well as the ISO and extended (EWKB) flavors of 3D and measured geometries.
+//! Only the `x` and `y` coordinates take part in the computations.
+use std::borrow::Cow;
+
+use polars_core::prelude::arity::broadcast_try_binary_elementwise;
+use polars_core::prelude::*;
+
+type Coord = [f64; 2];
+type Ring = Vec<Coord>;
+type Polygon = Vec<Ring>;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Geometry {
+    Point(Option<Coord>),
+    LineString(Vec<Coord>),
+    Polygon(Polygon),
+    MultiPoint(Vec<Coord>),
+    MultiLineString(Vec<Vec<Coord>>),
+    MultiPolygon(Vec<Polygon>),
+    /// A geometry collection.
+    Collection(Vec<Geometry>),
+}
+
+const EWKB_Z: u32 = 0x8000_0000;
+const EWKB_M: u32 = 0x4000_0000;
+const EWKB_SRID: u32 = 0x2000_0000;
+/// Geometry collections nested deeper than this are rejected, so that a malicious value can't
+/// overflow the stack.
+const MAX_WKB_DEPTH: usize = 64;
+
+struct WkbReader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+    depth: usize,
+}
+
+impl WkbReader<'_> {
+    fn take<const N: usize>(&mut self) -> PolarsResult<[u8; N]> {
+        let bytes = self
+            .buf
+            .get(self.pos..self.pos + N)
+            .ok_or_else(|| polars_err!(ComputeError: "invalid WKB: unexpected end of geometry"))?;
+        self.pos += N;
+        Ok(bytes.try_into().unwrap())
+    }
+
+    fn u32(&mut self, le: bool) -> PolarsResult<u32> {
+        let bytes = self.take::<4>()?;
+        Ok(if le {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    }
+
+    fn f64(&mut self, le: bool) -> PolarsResult<f64> {
+        let bytes = self.take::<8>()?;
+        Ok(if le {
+            f64::from_le_bytes(bytes)
+        } else {
+            f64::from_be_bytes(bytes)
+        })
+    }
+
+    fn coord(&mut self, le: bool, n_dims: usize) -> PolarsResult<Coord> {
+        let x = self.f64(le)?;
+        let y = self.f64(le)?;
+        for _ in 2..n_dims {
+            self.f64(le)?;
+        }
+        Ok([x, y])
+    }
+
+    fn coords(&mut self, le: bool, n_dims: usize) -> PolarsResult<Vec<Coord>> {
+        let n = self.u32(le)? as usize;
+        // Guard against a corrupt length allocating a huge buffer.
+        let mut out = Vec::with_capacity(n.min(self.buf.len() / 16));
+        for _ in 0..n {
+            out.push(self.coord(le, n_dims)?);
+        }
+        Ok(out)
+    }
+
+    fn polygon(&mut self, le: bool, n_dims: usize) -> PolarsResult<Polygon> {
+        let n = self.u32(le)?;
+        (0..n).map(|_| self.coords(le, n_dims)).collect()
+    }
+
+    fn parts<T>(&mut self, le: bool, f: impl Fn(Geometry) -> Option<T>) -> PolarsResult<Vec<T>> {
+        let n = self.u32(le)?;
+        polars_ensure!(
+            self.depth < MAX_WKB_DEPTH,
+            ComputeError: "invalid WKB: geometries nested more than {} levels deep", MAX_WKB_DEPTH
+        );
+        self.depth += 1;
+        let out = (0..n)
+            .map(|_| {
+                f(self.geometry()?)
+                    .ok_or_else(|| polars_err!(ComputeError: "invalid WKB: unexpected part type"))
+            })
+            .collect();
+        self.depth -= 1;
+        out
+    }
+
+    fn geometry(&mut self) -> PolarsResult<Geometry> {
+        let le = match self.take::<1>()?[0] {
+            0 => false,
+            1 => true,
+            b => polars_bail!(ComputeError: "invalid WKB: unknown byte order {}", b),
+        };
+        let code = self.u32(le)?;
+        if code & EWKB_SRID != 0 {
+            self.u32(le)?;
+        }
+        let iso_dims = (code & 0xffff) / 1000;
+        let has_z = code & EWKB_Z != 0 || iso_dims == 1 || iso_dims == 3;
+        let has_m = code & EWKB_M != 0 || iso_dims == 2 || iso_dims == 3;
+        let n_dims = 2 + has_z as usize + has_m as usize;
+
+        Ok(match (code & 0xffff) % 1000 {
+            1 => {
+                let [x, y] = self.coord(le, n_dims)?;
+                // Empty points are encoded with NaN coordinates.
+                Geometry::Point((!x.is_nan() || !y.is_nan()).then_some([x, y]))
+            },
+            2 => Geometry::LineString(self.coords(le, n_dims)?),
+            3 => Geometry::Polygon(self.polygon(le, n_dims)?),
+            4 => {
+                let points = self.parts(le, |g| match g {
+                    Geometry::Point(p) => Some(p),
+                    _ => None,
+                })?;
+                Geometry::MultiPoint(points.into_iter().flatten().collect())
+            },
+            5 => Geometry::MultiLineString(self.parts(le, |g| match g {
+                Geometry::LineString(l) => Some(l),
+                _ => None,
+            })?),
+            6 => Geometry::MultiPolygon(self.parts(le, |g| match g {
+                Geometry::Polygon(p) => Some(p),
+                _ => None,
+            })?),
+            7 => Geometry::Collection(self.parts(le, Some)?),
+            t => polars_bail!(ComputeError: "invalid WKB: unsupported geometry type {}", t),
+        })
+    }
+}
+
+fn parse_wkb(value: &[u8]) -> PolarsResult<Geometry> {
+    WkbReader {
+        buf: value,
+        pos: 0,
+        depth: 0,
+    }
+    .geometry()
+}
+
+/// Encode a point as little-endian 2D WKB.
+fn point_to_wkb(p: Coord) -> Vec<u8> {
+    let mut out = Vec::with_capacity(21);
+    out.push(1);
+    out.extend_from_slice(&1u32.to_le_bytes());
+    out.extend_from_slice(&p[0].to_le_bytes());
+    out.extend_from_slice(&p[1].to_le_bytes());
+    out
+}
+
+/// The geometries of `s` in their WKB representation.
+fn to_wkb(s: &Series) -> PolarsResult<Cow<'_, BinaryChunked>> {
+    Ok(match s.dtype() {
+        DataType::Geometry => Cow::Borrowed(s.geometry()?.physical()),
+        DataType::Binary => Cow::Borrowed(s.binary()?),
+        DataType::Null => Cow::Owned(BinaryChunked::full_null(s.name().clone(), s.len())),
+        dt => polars_bail!(
+            InvalidOperation: "expected geometries as geometry or binary values, got {}", dt
+        ),
+    })
+}
+
+impl Geometry {
+    /// Visit the points, lines and polygons this geometry is composed of.
+    fn for_each_part<'a>(&'a self, f: &mut impl FnMut(Part<'a>)) {
+        match self {
+            Geometry::Point(p) => p.iter().for_each(|p| f(Part::Point(p))),
+            Geometry::MultiPoint(ps) => ps.iter().for_each(|p| f(Part::Point(p))),
+            Geometry::LineString(l) => f(Part::Line(l)),
+            Geometry::MultiLineString(ls) => ls.iter().for_each(|l| f(Part::Line(l))),
+            Geometry::Polygon(p) => f(Part::Polygon(p)),
+            Geometry::MultiPolygon(ps) => ps.iter().for_each(|p| f(Part::Polygon(p))),
+            Geometry::Collection(gs) => gs.iter().for_each(|g| g.for_each_part(f)),
+        }
+    }
+
+    fn parts(&self) -> Vec<Part<'_>> {
+        let mut out = vec![];
+        self.for_each_part(&mut |part| out.push(part));
+        out
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Part<'a> {
+    Point(&'a Coord),
+    Line(&'a [Coord]),
+    Polygon(&'a Polygon),
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Location {
+    Interior,
+    Boundary,
+    Exterior,
+}
+
+fn segments(line: &[Coord]) -> impl Iterator<Item = (Coord, Coord)> + '_ {
+    line.windows(2).map(|w| (w[0], w[1]))
+}
+
+/// Twice the signed area of a ring, positive for counter-clockwise rings.
+fn ring_signed_area2(ring: &[Coord]) -> f64 {
+    segments(ring).map(|(a, b)| a[0] * b[1] - b[0] * a[1]).sum()
+}
+
+fn polygon_area(polygon: &Polygon) -> f64 {
+    let mut rings = polygon.iter().map(|r| ring_signed_area2(r).abs() / 2.0);
+    let shell = rings.next().unwrap_or(0.0);
+    shell - rings.sum::<f64>()
+}
+
+fn cross(o: Coord, a: Coord, b: Coord) -> f64 {
+    (a[0] - o[0]) * (b[1] - o[1]) - (a[1] - o[1]) * (b[0] - o[0])
+}
+
+fn on_segment(p: Coord, (a, b): (Coord, Coord)) -> bool {
+    cross(a, b, p) == 0.0
+        && p[0] >= a[0].min(b[0])
+        && p[0] <= a[0].max(b[0])
+        && p[1] >= a[1].min(b[1])
+        && p[1] <= a[1].max(b[1])
+}
+
+/// Whether the segments have a point in common.
+fn segments_intersect((a, b): (Coord, Coord), (c, d): (Coord, Coord)) -> bool {
+    let d1 = cross(c, d, a);
+    let d2 = cross(c, d, b);
+    let d3 = cross(a, b, c);
+    let d4 = cross(a, b, d);
+    if ((d1 > 0.0 && d2 < 0.0) || (d1 < 0.0 && d2 > 0.0))
+        && ((d3 > 0.0 && d4 < 0.0) || (d3 < 0.0 && d4 > 0.0))
+    {
+        return true;
+    }
+    on_segment(a, (c, d)) || on_segment(b, (c, d)) || on_segment(c, (a, b)) || on_segment(d, (a, b))
+}
+
+/// Whether the segments cross in a single point that is interior to both of them.
+fn segments_cross((a, b): (Coord, Coord), (c, d): (Coord, Coord)) -> bool {
+    let d1 = cross(c, d, a);
+    let d2 = cross(c, d, b);
+    let d3 = cross(a, b, c);
+    let d4 = cross(a, b, d);
+    d1 * d2 < 0.0 && d3 * d4 < 0.0
+}
+
+fn point_in_ring(p: Coord, ring: &[Coord]) -> Location {
+    let mut inside = false;
+    for (a, b) in segments(ring) {
+        if on_segment(p, (a, b)) {
+            return Location::Boundary;
+        }
+        if (a[1] > p[1]) != (b[1] > p[1])
+            && p[0] < (b[0] - a[0]) * (p[1] - a[1]) / (b[1] - a[1]) + a[0]
+        {
+            inside = !inside;
+        }
+    }
+    if inside {
+        Location::Interior
+    } else {
+        Location::Exterior
+    }
+}
+
+fn point_in_polygon(p: Coord, polygon: &Polygon) -> Location {
+    let Some((shell, holes)) = polygon.split_first() else {
+        return Location::Exterior;
+    };
+    match point_in_ring(p, shell) {
+        Location::Interior => {},
+        loc => return loc,
+    }
+    for hole in holes {
+        match point_in_ring(p, hole) {
+            Location::Interior => return Location::Exterior,
+            Location::Boundary => return Location::Boundary,
+            Location::Exterior => {},
+        }
+    }
+    Location::Interior
+}
+
+/// The location of a point relative to a geometry.
+fn locate(p: Coord, parts: &[Part]) -> Location {
+    let mut location = Location::Exterior;
+    for part in parts {
+        let loc = match part {
+            Part::Point(q) => {
+                if **q == p {
+                    Location::Interior
+                } else {
+                    Location::Exterior
+                }
+            },
+            Part::Line(l) => {
+                let is_endpoint =
+                    l.first() != l.last() && (l.first() == Some(&p) || l.last() == Some(&p));
+                if is_endpoint {
+                    Location::Boundary
+                } else if (l.len() == 1 && l[0] == p) || segments(l).any(|s| on_segment(p, s)) {
+                    Location::Interior
+                } else {
+                    Location::Exterior
+                }
+            },
+            Part::Polygon(poly) => point_in_polygon(p, poly),
+        };
+        match loc {
+            Location::Interior => return Location::Interior,
+            Location::Boundary => location = Location::Boundary,
+            Location::Exterior => {},
+        }
+    }
+    location
+}
+
+fn part_segments<'a>(part: &Part<'a>) -> Box<dyn Iterator<Item = (Coord, Coord)> + 'a> {
+    match *part {
+        Part::Point(_) => Box::new(std::iter::empty()),
+        Part::Line(l) => Box::new(segments(l)),
+        Part::Polygon(p) => Box::new(p.iter().flat_map(|r| segments(r))),
+    }
+}
+
+fn part_vertices<'a>(part: &Part<'a>) -> Box<dyn Iterator<Item = Coord> + 'a> {
+    match *part {
+        Part::Point(p) => Box::new(std::iter::once(*p)),
+        Part::Line(l) => Box::new(l.iter().copied()),
+        Part::Polygon(p) => Box::new(p.iter().flatten().copied()),
+    }
+}
+
+fn area(g: &Geometry) -> f64 {
+    let mut out = 0.0;
+    g.for_each_part(&mut |part| {
+        if let Part::Polygon(p) = part {
+            out += polygon_area(p)
+        }
+    });
+    out
+}
+
+fn centroid(g: &Geometry) -> Option<Coord> {
+    // Area-weighted for polygons, length-weighted for lines and the mean for points. Only the
+    // components of the highest dimension count, as in other spatial libraries.
+    let (mut area, mut ax, mut ay) = (0.0, 0.0, 0.0);
+    let (mut length, mut lx, mut ly) = (0.0, 0.0, 0.0);
+    let (mut n, mut px, mut py) = (0.0, 0.0, 0.0);
+
+    let mut add_line = |line: &[Coord]| {
+        for (a, b) in segments(line) {
+            let len = ((b[0] - a[0]).powi(2) + (b[1] - a[1]).powi(2)).sqrt();
+            length += len;
+            lx += len * (a[0] + b[0]) / 2.0;
+            ly += len * (a[1] + b[1]) / 2.0;
+        }
+    };
+    let mut add_points = |points: &mut dyn Iterator<Item = Coord>| {
+        for p in points {
+            n += 1.0;
+            px += p[0];
+            py += p[1];
+        }
+    };
+
+    g.for_each_part(&mut |part| {
+        if let Part::Polygon(polygon) = part {
+            for (i, ring) in polygon.iter().enumerate() {
+                let a2 = ring_signed_area2(ring);
+                if a2 != 0.0 {
+                    // Shells add to the area and holes subtract from it, whatever their
+                    // orientation.
+                    let sign = a2.signum() * if i == 0 { 1.0 } else { -1.0 };
+                    let (cx, cy) = segments(ring).fold((0.0, 0.0), |(cx, cy), (a, b)| {
+                        let f = a[0] * b[1] - b[0] * a[1];
+                        (cx + (a[0] + b[0]) * f, cy + (a[1] + b[1]) * f)
+                    });
+                    area += sign * a2 / 2.0;
+                    ax += sign * cx / 6.0;
+                    ay += sign * cy / 6.0;
+                }
+            }
+        }
+        match part {
+            Part::Point(p) => add_points(&mut std::iter::once(*p)),
+            Part::Line(l) => {
+                add_line(l);
+                add_points(&mut l.iter().copied());
+            },
+            Part::Polygon(p) => {
+                p.iter().for_each(|r| add_line(r));
+                add_points(&mut p.iter().flatten().copied());
+            },
+        }
+    });
+
+    if area != 0.0 {
+        Some([ax / area, ay / area])
+    } else if length > 0.0 {
+        Some([lx / length, ly / length])
+    } else if n > 0.0 {
+        Some([px / n, py / n])
+    } else {
+        None
+    }
+}
+
+fn contains(a: &Geometry, b: &Geometry) -> bool {
+    let a = a.parts();
+    let b = b.parts();
+    if a.is_empty() || b.is_empty() {
+        return false;
+    }
+
+    // Every vertex of `b` and the midpoint of each of its edges must lie in `a`, and at least one
+    // of those in the interior of `a`.
+    let mut any_interior = false;
+    for part in &b {
+        let midpoints =
+            part_segments(part).map(|(p, q)| [(p[0] + q[0]) / 2.0, (p[1] + q[1]) / 2.0]);
+        for p in part_vertices(part).chain(midpoints) {
+            match locate(p, &a) {
+                Location::Exterior => return false,
+                Location::Interior => any_interior = true,
+                Location::Boundary => {},
+            }
+        }
+    }
+
+    // The edges of `b` may not cross the boundary of `a`.
+    let crosses = b.iter().flat_map(|part| part_segments(part)).any(|s| {
+        a.iter()
+            .flat_map(|part| part_segments(part))
+            .any(|t| segments_cross(s, t))
+    });
+    any_interior && !crosses
+}
+
+fn point_segment_distance(p: Coord, (a, b): (Coord, Coord)) -> f64 {
+    let (dx, dy) = (b[0] - a[0], b[1] - a[1]);
+    let len2 = dx * dx + dy * dy;
+    let t = if len2 == 0.0 {
+        0.0
+    } else {
+        (((p[0] - a[0]) * dx + (p[1] - a[1]) * dy) / len2).clamp(0.0, 1.0)
+    };
+    let (x, y) = (a[0] + t * dx, a[1] + t * dy);
+    ((p[0] - x).powi(2) + (p[1] - y).powi(2)).sqrt()
+}
+
+fn segment_distance(s: (Coord, Coord), t: (Coord, Coord)) -> f64 {
+    if segments_intersect(s, t) {
+        return 0.0;
+    }
+    point_segment_distance(s.0, t)
+        .min(point_segment_distance(s.1, t))
+        .min(point_segment_distance(t.0, s))
+        .min(point_segment_distance(t.1, s))
+}
+
+fn part_distance(a: &Part, b: &Part, a_parts: &[Part], b_parts: &[Part]) -> f64 {
+    // A part lying (partly) within a polygon of the other geometry has a distance of 0.
+    if part_vertices(a).any(|p| locate(p, b_parts) != Location::Exterior)
+        || part_vertices(b).any(|p| locate(p, a_parts) != Location::Exterior)
+    {
+        return 0.0;
+    }
+
+    let a_segments = part_segments(a).collect::<Vec<_>>();
+    let b_segments = part_segments(b).collect::<Vec<_>>();
+    let mut out = f64::INFINITY;
+    // Lone points and single vertex lines.
+    let a_points = if a_segments.is_empty() {
+        part_vertices(a).collect()
+    } else {
+        vec![]
+    };
+    let b_points = if b_segments.is_empty() {
+        part_vertices(b).collect()
+    } else {
+        vec![]
+    };
+
+    for p in &a_points {
+        for q in &b_points {
+            out = out.min(((p[0] - q[0]).powi(2) + (p[1] - q[1]).powi(2)).sqrt());
+        }
+        for t in &b_segments {
+            out = out.min(point_segment_distance(*p, *t));
+        }
+    }
+    for s in &a_segments {
+        for q in &b_points {
+            out = out.min(point_segment_distance(*q, *s));
+        }
+        for t in &b_segments {
+            out = out.min(segment_distance(*s, *t));
+        }
+    }
+    out
+}
+
+fn distance(a: &Geometry, b: &Geometry) -> Option<f64> {
+    let a_parts = a.parts();
+    let b_parts = b.parts();
+    let mut out = None::<f64>;
+    for p in &a_parts {
+        for q in &b_parts {
+            let d = part_distance(p, q, &[*p], &[*q]);
+            out = Some(out.map_or(d, |out| out.min(d)));
+        }
+    }
+    out
+}
+
+/// The area of the geometries. Holes are subtracted and geometries without polygons have an
+/// area of 0.
+pub fn st_area(s: &Series) -> PolarsResult<Series> {
+    let ca = to_wkb(s)?;
+    let out: Float64Chunked =
+        ca.try_apply_nonnull_values_generic(|v| parse_wkb(v).map(|g| area(&g)))?;
+    Ok(out.into_series())
+}
+
+/// The centroids of the geometries as points. The centroid of an empty geometry is null.
+pub fn st_centroid(s: &Series) -> PolarsResult<Series> {
+    let ca = to_wkb(s)?;
+    let centroids = ca
+        .iter()
+        .map(|opt_v| {
+            let Some(v) = opt_v else {
+                return Ok(None);
+            };
+            Ok(centroid(&parse_wkb(v)?).map(point_to_wkb))
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let out = BinaryChunked::from_iter_options(ca.name().clone(), centroids.into_iter());
+    Ok(out.into_geometry().into_series())
+}
+
+/// Whether the geometries contain the `other` geometries. A geometry contains another if no
+/// point of the other lies outside of it and at least one point lies in its interior.
+pub fn st_contains(s: &Series, other: &Series) -> PolarsResult<Series> {
+    let ca = to_wkb(s)?;
+    let other = to_wkb(other)?;
+    let out: BooleanChunked =
+        broadcast_try_binary_elementwise(&*ca, &*other, |a: Option<&[u8]>, b: Option<&[u8]>| {
+            let (Some(a), Some(b)) = (a, b) else {
+                return Ok(None);
+            };
+            PolarsResult::Ok(Some(contains(&parse_wkb(a)?, &parse_wkb(b)?)))
+        })?;
+    Ok(out.with_name(s.name().clone()).into_series())
+}
+
+/// The minimum Euclidean distance between the geometries and the `other` geometries. The
+/// distance is 0 if the geometries intersect and null if either of them is empty.
+pub fn st_distance(s: &Series, other: &Series) -> PolarsResult<Series> {
+    let ca = to_wkb(s)?;
+    let other = to_wkb(other)?;
+    let out: Float64Chunked =
+        broadcast_try_binary_elementwise(&*ca, &*other, |a: Option<&[u8]>, b: Option<&[u8]>| {
+            let (Some(a), Some(b)) = (a, b) else {
+                return Ok(None);
+            };
+            PolarsResult::Ok(distance(&parse_wkb(a)?, &parse_wkb(b)?))
+        })?;
+    Ok(out.with_name(s.name().clone()).into_series())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn polygon_wkb(rings: &[&[Coord]]) -> Vec<u8> {
+        let mut out = vec![1];
+        out.extend_from_slice(&3u32.to_le_bytes());
+        out.extend_from_slice(&(rings.len() as u32).to_le_bytes());
+        for ring in rings {
+            out.extend_from_slice(&(ring.len() as u32).to_le_bytes());
+            for p in *ring {
+                out.extend_from_slice(&p[0].to_le_bytes());
+                out.extend_from_slice(&p[1].to_le_bytes());
+            }
+        }
+        out
+    }
+
+    fn geometries(values: &[Vec<u8>]) -> Series {
+        let values = values.iter().map(|v| v.as_slice()).collect::<Vec<_>>();
+        BinaryChunked::from_slice("geom".into(), &values)
+            .into_geometry()
+            .into_series()
+    }
+
+    fn square(x: f64, y: f64, size: f64) -> Ring {
+        vec![
+            [x, y],
+            [x + size, y],
+            [x + size, y + size],
+            [x, y + size],
+            [x, y],
+        ]
+    }
+
+    #[test]
+    fn test_st_area_and_centroid() {
+        let s = geometries(&[
+            polygon_wkb(&[&square(0.0, 0.0, 4.0), &square(1.0, 1.0, 2.0)]),
+            polygon_wkb(&[&square(2.0, 2.0, 2.0)]),
+            point_to_wkb([1.0, 2.0]),
+        ]);
+        let out = st_area(&s).unwrap();
+        assert_eq!(
+            Vec::from(out.f64().unwrap()),
+            &[Some(12.0), Some(4.0), Some(0.0)]
+        );
+
+        let out = st_centroid(&s).unwrap();
+        assert_eq!(out.dtype(), &DataType::Geometry);
+        let points = out
+            .geometry()
+            .unwrap()
+            .physical()
+            .into_no_null_iter()
+            .map(|v| parse_wkb(v).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            points,
+            &[
+                Geometry::Point(Some([2.0, 2.0])),
+                Geometry::Point(Some([3.0, 3.0])),
+                Geometry::Point(Some([1.0, 2.0])),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_st_contains_and_distance() {
+        let s = geometries(&[polygon_wkb(&[
+            &square(0.0, 0.0, 4.0),
+            &square(1.0, 1.0, 2.0),
+        ])]);
+        let other = geometries(&[
+            point_to_wkb([0.5, 0.5]),
+            point_to_wkb([2.0, 2.0]),
+            point_to_wkb([4.0, 2.0]),
+            polygon_wkb(&[&square(3.0, 3.0, 2.0)]),
+            point_to_wkb([7.0, 8.0]),
+        ]);
+        let out = st_contains(&s, &other).unwrap();
+        assert_eq!(
+            Vec::from(out.bool().unwrap()),
+            &[
+                Some(true),
+                Some(false),
+                Some(false),
+                Some(false),
+                Some(false)
+            ]
+        );
+
+        let out = st_distance(&s, &other).unwrap();
+        assert_eq!(
+            Vec::from(out.f64().unwrap()),
+            &[Some(0.0), Some(1.0), Some(0.0), Some(0.0), Some(5.0)]
+        );
+    }
+
+    #[test]
+    fn test_parse_big_endian_z() {
+        // POINT Z (1 2 3) in big-endian ISO WKB.
+        let mut wkb = vec![0];
+        wkb.extend_from_slice(&1001u32.to_be_bytes());
+        for v in [1.0f64, 2.0, 3.0] {
+            wkb.extend_from_slice(&v.to_be_bytes());
+        }
+        assert_eq!(parse_wkb(&wkb).unwrap(), Geometry::Point(Some([1.0, 2.0])));
+        assert!(parse_wkb(&wkb[..10]).is_err());
+    }
+
+    #[test]
+    fn test_parse_nesting_limit() {
+        // GEOMETRYCOLLECTION (GEOMETRYCOLLECTION (... POINT (1 2))) nested `depth` times.
+        let nested = |depth: usize| {
+            let mut wkb = vec![];
+            for _ in 0..depth {
+                wkb.push(1);
+                wkb.extend_from_slice(&7u32.to_le_bytes());
+                wkb.extend_from_slice(&1u32.to_le_bytes());
+            }
+            wkb.push(1);
+            wkb.extend_from_slice(&1u32.to_le_bytes());
+            wkb.extend_from_slice(&1.0f64.to_le_bytes());
+            wkb.extend_from_slice(&2.0f64.to_le_bytes());
+            wkb
+        };
+        assert!(parse_wkb(&nested(MAX_WKB_DEPTH)).is_ok());
+        let err = parse_wkb(&nested(100_000)).unwrap_err();
+        assert!(matches!(err, PolarsError::ComputeError(_)));
+    }
+}
@@ -0,0 +1,143 @@
+//! Hashes with a fixed specification that don't change across Polars versions or platforms.
+//!
+//! Every value is hashed from a canonical byte representation:
+//!
+//! * Booleans are a single byte, `0` or `1`.
+//! * Integers, temporal types and decimals are the little-endian bytes of their physical
+//!   representation, e.g. 4 bytes for an `Int32` and 8 bytes for a `Datetime`.
+//! * Floats are their little-endian bytes after mapping `-0.0` to `0.0` and all NaNs to a
+//!   single canonical NaN.
+//! * Strings and categoricals are their UTF-8 bytes and binary values are their raw bytes.
+//!
+//! Null values stay null. Nested and object types are not supported.
+use std::fmt;
+use std::hash::Hasher;
+
+use polars_core::prelude::*;
+use polars_core::with_match_physical_integer_polars_type;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use sha2::Digest;
+use siphasher::sip::SipHasher24;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum HashAlgorithm {
+    /// The hasher Polars uses internally, seeded with four keys.
+    ///
+    /// It is fast and supports nested types, but its output is only stable within a single
+    /// version of Polars.
+    #[default]
+    Default,
+    /// XXH64, seeded with the first key.
+    XxHash64,
+    /// wyhash, as implemented by version 0.5 of the `wyhash` crate, seeded with the first key.
+    WyHash,
+    /// SipHash-2-4, keyed with the first two keys.
+    SipHash,
+    /// SHA-256, which ignores the keys and gives the 32 byte digest as a binary value.
+    Sha256,
+}
+
+impl HashAlgorithm {
+    /// Whether the output of this algorithm is guaranteed to be stable across Polars versions.
+    pub fn is_stable(&self) -> bool {
+        !matches!(self, Self::Default)
+    }
+}
+
+impl fmt::Display for HashAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Default => "default",
+            Self::XxHash64 => "xxhash64",
+            Self::WyHash => "wyhash",
+            Self::SipHash => "siphash",
+            Self::Sha256 => "sha256",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// Call `f` with the canonical byte representation of every value of `s`.
+fn for_each_bytes(s: &Series, mut f: impl FnMut(Option<&[u8]>)) -> PolarsResult<()> {
+    match s.dtype() {
+        DataType::Boolean => s
+            .bool()?
+            .iter()
+            .for_each(|v| f(v.map(|v| [v as u8]).as_ref().map(|b| &b[..]))),
+        DataType::String => s.str()?.iter().for_each(|v| f(v.map(str::as_bytes))),
+        DataType::Binary => s.binary()?.iter().for_each(f),
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Categorical(_, _) | DataType::Enum(_, _) => {
+            return for_each_bytes(&s.cast(&DataType::String)?, f);
+        },
+        DataType::Float32 => s.f32()?.iter().for_each(|v| {
+            f(v.map(|v| canonical_f32(v).to_le_bytes())
+                .as_ref()
+                .map(|b| &b[..]))
+        }),
+        DataType::Float64 => s.f64()?.iter().for_each(|v| {
+            f(v.map(|v| canonical_f64(v).to_le_bytes())
+                .as_ref()
+                .map(|b| &b[..]))
+        }),
+        dt if dt.to_physical().is_integer() => {
+            let s = s.to_physical_repr();
+            with_match_physical_integer_polars_type!(s.dtype(), |$T| {
+                let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+                ca.iter().for_each(|v| f(v.map(|v| v.to_le_bytes()).as_ref().map(|b| b.as_ref())))
+            })
+        },
+        dt if dt.to_physical() == DataType::Float32 => {
+            return for_each_bytes(&s.to_physical_repr(), f);
+        },
+        dt => polars_bail!(
+            InvalidOperation: "stable hashing is not supported for dtype {}", dt
+        ),
+    }
+    Ok(())
+}
+
+fn canonical_f32(v: f32) -> f32 {
+    if v.is_nan() { f32::NAN } else { v + 0.0 }
+}
+
+fn canonical_f64(v: f64) -> f64 {
+    if v.is_nan() { f64::NAN } else { v + 0.0 }
+}
+
+/// Hash every value of `s` with a stable [`HashAlgorithm`].
+///
+/// The result is a `UInt64` column, except for [`HashAlgorithm::Sha256`] which gives `Binary`
+/// digests.
+pub fn stable_hash(s: &Series, algorithm: HashAlgorithm, k0: u64, k1: u64) -> PolarsResult<Series> {
+    let name = s.name().clone();
+    let out = match algorithm {
+        HashAlgorithm::Default => polars_bail!(
+            InvalidOperation: "the default hash algorithm is not stable"
+        ),
+        HashAlgorithm::Sha256 => {
+            let mut out: Vec<Option<[u8; 32]>> = Vec::with_capacity(s.len());
+            for_each_bytes(s, |v| out.push(v.map(|v| sha2::Sha256::digest(v).into())))?;
+            let iter = out.iter().map(|v| v.as_ref().map(|v| &v[..]));
+            BinaryChunked::from_iter_options(name, iter).into_series()
+        },
+        _ => {
+            let hash = |v: &[u8]| match algorithm {
+                HashAlgorithm::XxHash64 => xxhash_rust::xxh64::xxh64(v, k0),
+                HashAlgorithm::WyHash => wyhash::wyhash(v, k0),
+                HashAlgorithm::SipHash => {
+                    let mut hasher = SipHasher24::new_with_keys(k0, k1);
+                    hasher.write(v);
+                    hasher.finish()
+                },
+                HashAlgorithm::Default | HashAlgorithm::Sha256 => unreachable!(),
+            };
+            let mut out = Vec::with_capacity(s.len());
+            for_each_bytes(s, |v| out.push(v.map(hash)))?;
+            UInt64Chunked::from_iter_options(name, out.into_iter()).into_series()
+        },
+    };
+    Ok(out)
+}
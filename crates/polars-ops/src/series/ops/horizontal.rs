@@ -347,6 +347,78 @@ pub fn mean_horizontal(
     }
 }
 
+#[cfg(feature = "bitwise")]
+fn bitwise_horizontal(
+    columns: &[Column],
+    op_name: &str,
+    op: fn(&Series, &Series) -> PolarsResult<Series>,
+) -> PolarsResult<Option<Column>> {
+    validate_column_lengths(columns)?;
+
+    // @scalar-opt
+    let non_null_cols = columns
+        .iter()
+        .filter(|c| c.dtype() != &DataType::Null)
+        .map(|c| c.as_materialized_series())
+        .collect::<Vec<_>>();
+    if non_null_cols.is_empty() {
+        return Ok(columns.first().cloned());
+    }
+
+    let dtype = dtypes_to_supertype(non_null_cols.iter().map(|s| s.dtype()))?;
+    polars_ensure!(
+        dtype.is_bool() || dtype.is_integer(),
+        InvalidOperation: "dtype {} not supported in '{}' operation", dtype, op_name
+    );
+    let name = non_null_cols[0].name().clone();
+    let len = columns.iter().map(|c| c.len()).max().unwrap();
+
+    // Nulls are skipped, so a row is only null if all its values are null.
+    let combine = |acc: Series, s: Series| -> PolarsResult<Series> {
+        let out = op(&acc, &s)?;
+        let out = if acc.has_nulls() {
+            s.zip_with(&acc.is_null(), &out)?
+        } else {
+            out
+        };
+        if s.has_nulls() {
+            acc.zip_with(&s.is_null(), &out)
+        } else {
+            Ok(out)
+        }
+    };
+
+    let mut iter = non_null_cols.into_iter().map(|s| {
+        let s = s.cast(&dtype)?;
+        PolarsResult::Ok(if s.len() == len {
+            s
+        } else {
+            s.new_from_index(0, len)
+        })
+    });
+    let first = iter.next().unwrap()?;
+    iter.try_fold(first, |acc, s| combine(acc, s?))
+        .map(|s| Some(s.with_name(name).into_column()))
+}
+
+/// Compute the bitwise AND of all values horizontally across columns, skipping nulls.
+#[cfg(feature = "bitwise")]
+pub fn bitwise_and_horizontal(columns: &[Column]) -> PolarsResult<Option<Column>> {
+    bitwise_horizontal(columns, "bitwise_and_horizontal", |l, r| l & r)
+}
+
+/// Compute the bitwise OR of all values horizontally across columns, skipping nulls.
+#[cfg(feature = "bitwise")]
+pub fn bitwise_or_horizontal(columns: &[Column]) -> PolarsResult<Option<Column>> {
+    bitwise_horizontal(columns, "bitwise_or_horizontal", |l, r| l | r)
+}
+
+/// Compute the bitwise XOR of all values horizontally across columns, skipping nulls.
+#[cfg(feature = "bitwise")]
+pub fn bitwise_xor_horizontal(columns: &[Column]) -> PolarsResult<Option<Column>> {
+    bitwise_horizontal(columns, "bitwise_xor_horizontal", |l, r| l ^ r)
+}
+
 pub fn coalesce_columns(s: &[Column]) -> PolarsResult<Column> {
     // TODO! this can be faster if we have more than two inputs.
     polars_ensure!(!s.is_empty(), NoData: "cannot coalesce empty list");
@@ -406,4 +478,32 @@ mod tests {
             &[Some(4), Some(2), Some(6)]
         );
     }
+
+    #[test]
+    #[cfg(feature = "bitwise")]
+    fn test_horizontal_bitwise() {
+        let a = Column::new("a".into(), [Some(0b1100u8), Some(0b1010), None]);
+        let b = Column::new("b".into(), [Some(0b1010u8), None, None]);
+        let c = Column::new("c".into(), [0b0110u8, 0b0011, 0b0001]);
+        let columns = [a, b, c];
+
+        let out = bitwise_and_horizontal(&columns).unwrap().unwrap();
+        assert_eq!(
+            Vec::from(out.u8().unwrap()),
+            &[Some(0b0000), Some(0b0010), Some(0b0001)]
+        );
+        let out = bitwise_or_horizontal(&columns).unwrap().unwrap();
+        assert_eq!(
+            Vec::from(out.u8().unwrap()),
+            &[Some(0b1110), Some(0b1011), Some(0b0001)]
+        );
+        let out = bitwise_xor_horizontal(&columns).unwrap().unwrap();
+        assert_eq!(
+            Vec::from(out.u8().unwrap()),
+            &[Some(0b0000), Some(0b1001), Some(0b0001)]
+        );
+
+        let columns = [Column::new("a".into(), [1.0f64])];
+        assert!(bitwise_and_horizontal(&columns).is_err());
+    }
 }
@@ -0,0 +1,194 @@
+//! Piecewise cubic interpolation over the row index.
+//!
+//! Both methods treat the non-null values as knots at their row positions and only fill
+//! the nulls between the first and last knot, like linear interpolation does.
+use polars_core::prelude::*;
+use polars_core::utils::CustomIterTools;
+
+#[derive(Copy, Clone)]
+pub(super) enum CubicKind {
+    /// Natural cubic spline: twice continuously differentiable, zero curvature at the ends.
+    Spline,
+    /// Piecewise cubic Hermite interpolation with monotonicity preserving (Fritsch-Carlson)
+    /// slopes, as in scipy's `PchipInterpolator`.
+    Pchip,
+}
+
+/// Second derivatives of the natural cubic spline through the knots.
+fn spline_second_derivatives(x: &[f64], y: &[f64]) -> Vec<f64> {
+    let n = x.len();
+    let mut m = vec![0.0; n];
+    if n < 3 {
+        return m;
+    }
+    // Thomas algorithm for the tridiagonal system of the interior knots.
+    let mut c_prime = vec![0.0; n];
+    let mut d_prime = vec![0.0; n];
+    for k in 1..n - 1 {
+        let h0 = x[k] - x[k - 1];
+        let h1 = x[k + 1] - x[k];
+        let rhs = 6.0 * ((y[k + 1] - y[k]) / h1 - (y[k] - y[k - 1]) / h0);
+        let denom = 2.0 * (h0 + h1) - h0 * c_prime[k - 1];
+        c_prime[k] = h1 / denom;
+        d_prime[k] = (rhs - h0 * d_prime[k - 1]) / denom;
+    }
+    for k in (1..n - 1).rev() {
+        m[k] = d_prime[k] - c_prime[k] * m[k + 1];
+    }
+    m
+}
+
+fn sign(v: f64) -> i8 {
+    (v > 0.0) as i8 - (v < 0.0) as i8
+}
+
+/// Endpoint slope of the PCHIP interpolant, following scipy's three-point rule.
+fn pchip_end_slope(h0: f64, h1: f64, d0: f64, d1: f64) -> f64 {
+    let m = ((2.0 * h0 + h1) * d0 - h0 * d1) / (h0 + h1);
+    if sign(m) != sign(d0) {
+        0.0
+    } else if sign(d0) != sign(d1) && m.abs() > 3.0 * d0.abs() {
+        3.0 * d0
+    } else {
+        m
+    }
+}
+
+/// First derivatives of the PCHIP interpolant at the knots.
+fn pchip_slopes(x: &[f64], y: &[f64]) -> Vec<f64> {
+    let n = x.len();
+    let h: Vec<f64> = x.windows(2).map(|w| w[1] - w[0]).collect();
+    let d: Vec<f64> = (0..n - 1).map(|k| (y[k + 1] - y[k]) / h[k]).collect();
+    if n == 2 {
+        return vec![d[0]; 2];
+    }
+
+    let mut m = vec![0.0; n];
+    for k in 1..n - 1 {
+        if d[k - 1] * d[k] > 0.0 {
+            let w1 = 2.0 * h[k] + h[k - 1];
+            let w2 = h[k] + 2.0 * h[k - 1];
+            m[k] = (w1 + w2) / (w1 / d[k - 1] + w2 / d[k]);
+        }
+    }
+    m[0] = pchip_end_slope(h[0], h[1], d[0], d[1]);
+    m[n - 1] = pchip_end_slope(h[n - 2], h[n - 3], d[n - 2], d[n - 3]);
+    m
+}
+
+pub(super) fn interpolate_cubic(ca: &Float64Chunked, kind: CubicKind) -> Float64Chunked {
+    if !ca.has_nulls() || ca.null_count() == ca.len() {
+        return ca.clone();
+    }
+    let (x, y): (Vec<f64>, Vec<f64>) = ca
+        .iter()
+        .enumerate()
+        .filter_map(|(i, v)| v.map(|v| (i as f64, v)))
+        .unzip();
+    if x.len() < 2 {
+        return ca.clone();
+    }
+
+    let derivatives = match kind {
+        CubicKind::Spline => spline_second_derivatives(&x, &y),
+        CubicKind::Pchip => pchip_slopes(&x, &y),
+    };
+    let eval = |k: usize, xi: f64| -> f64 {
+        let h = x[k + 1] - x[k];
+        match kind {
+            CubicKind::Spline => {
+                let (a, b) = (x[k + 1] - xi, xi - x[k]);
+                let (m0, m1) = (derivatives[k], derivatives[k + 1]);
+                m0 * a * a * a / (6.0 * h)
+                    + m1 * b * b * b / (6.0 * h)
+                    + (y[k] / h - m0 * h / 6.0) * a
+                    + (y[k + 1] / h - m1 * h / 6.0) * b
+            },
+            CubicKind::Pchip => {
+                let t = (xi - x[k]) / h;
+                let (t2, t3) = (t * t, t * t * t);
+                (2.0 * t3 - 3.0 * t2 + 1.0) * y[k]
+                    + (t3 - 2.0 * t2 + t) * h * derivatives[k]
+                    + (-2.0 * t3 + 3.0 * t2) * y[k + 1]
+                    + (t3 - t2) * h * derivatives[k + 1]
+            },
+        }
+    };
+
+    let first = x[0] as usize;
+    let last = x[x.len() - 1] as usize;
+    // Index of the knot that starts the current segment.
+    let mut k = 0;
+    let out: Float64Chunked = ca
+        .iter()
+        .enumerate()
+        .map(|(i, v)| match v {
+            Some(v) => {
+                if i != last {
+                    k = x.partition_point(|&xk| xk <= i as f64) - 1;
+                }
+                Some(v)
+            },
+            None if i > first && i < last => Some(eval(k, i as f64)),
+            None => None,
+        })
+        .collect_trusted();
+    out.with_name(ca.name().clone())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn interpolate(values: &[Option<f64>], kind: CubicKind) -> Vec<Option<f64>> {
+        let ca = Float64Chunked::new("".into(), values);
+        Vec::from(&interpolate_cubic(&ca, kind))
+    }
+
+    #[test]
+    fn test_cubic_reproduces_polynomials() {
+        // A natural spline through collinear points is the line itself.
+        let out = interpolate(
+            &[Some(1.0), None, Some(3.0), None, None, Some(6.0)],
+            CubicKind::Spline,
+        );
+        assert_eq!(
+            out,
+            &[
+                Some(1.0),
+                Some(2.0),
+                Some(3.0),
+                Some(4.0),
+                Some(5.0),
+                Some(6.0)
+            ]
+        );
+        let out = interpolate(&[None, Some(1.0), None, Some(3.0), None], CubicKind::Pchip);
+        assert_eq!(out, &[None, Some(1.0), Some(2.0), Some(3.0), None]);
+    }
+
+    #[test]
+    fn test_pchip_is_monotone() {
+        let out = interpolate(
+            &[Some(0.0), None, Some(1.0), None, Some(1.0), None, Some(5.0)],
+            CubicKind::Pchip,
+        );
+        let out: Vec<f64> = out.into_iter().map(Option::unwrap).collect();
+        assert!(out.windows(2).all(|w| w[0] <= w[1]));
+        // Flat segments stay flat.
+        assert_eq!(out[3], 1.0);
+    }
+
+    #[test]
+    fn test_spline_matches_reference() {
+        // Natural spline through (0, 0), (2, 4), (4, 0): M_1 = -3, so S(1) = S(3) = 2.75.
+        let out = interpolate(
+            &[Some(0.0), None, Some(4.0), None, Some(0.0)],
+            CubicKind::Spline,
+        );
+        assert_eq!(
+            out,
+            &[Some(0.0), Some(2.75), Some(4.0), Some(2.75), Some(0.0)]
+        );
+    }
+}
@@ -8,6 +8,7 @@ use polars_core::prelude::*;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use super::cubic::{CubicKind, interpolate_cubic};
 use super::{linear_itp, nearest_itp};
 
 fn near_interp<T>(low: T, high: T, steps: IdxSize, steps_n: T, out: &mut Vec<T>)
@@ -190,17 +191,49 @@ where
     interpolate_impl(ca, signed_interp::<T::Native>).into_series()
 }
 
+fn interpolate_cubic_series(s: &Series, kind: CubicKind) -> Series {
+    let logical = s.dtype();
+    let is_temporal = matches!(
+        logical,
+        DataType::Date | DataType::Datetime(_, _) | DataType::Duration(_) | DataType::Time
+    );
+    if !(logical.is_primitive_numeric() || is_temporal) {
+        return s.clone();
+    }
+
+    let s = s.to_physical_repr();
+    let ca = s.cast(&DataType::Float64).unwrap();
+    let out = interpolate_cubic(ca.f64().unwrap(), kind);
+    match logical {
+        DataType::Float32 => out.cast(&DataType::Float32).unwrap(),
+        // Round back to the physical integer representation.
+        _ if is_temporal => out
+            .apply_values(|v| v.round())
+            .cast(s.dtype())
+            .unwrap()
+            .cast(logical)
+            .unwrap(),
+        _ => out.into_series(),
+    }
+}
+
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum InterpolationMethod {
     Linear,
     Nearest,
+    /// Natural cubic spline through the non-null values.
+    Spline,
+    /// Monotonic piecewise cubic Hermite interpolation.
+    Pchip,
 }
 
 pub fn interpolate(s: &Series, method: InterpolationMethod) -> Series {
     match method {
         InterpolationMethod::Linear => interpolate_linear(s),
         InterpolationMethod::Nearest => interpolate_nearest(s),
+        InterpolationMethod::Spline => interpolate_cubic_series(s, CubicKind::Spline),
+        InterpolationMethod::Pchip => interpolate_cubic_series(s, CubicKind::Pchip),
     }
 }
 
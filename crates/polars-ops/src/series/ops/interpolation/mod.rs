@@ -1,5 +1,7 @@
 use std::ops::{Add, Div, Mul, Sub};
 #[cfg(feature = "interpolate")]
+mod cubic;
+#[cfg(feature = "interpolate")]
 pub mod interpolate;
 #[cfg(feature = "interpolate_by")]
 pub mod interpolate_by;
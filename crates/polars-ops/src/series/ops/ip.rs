@@ -0,0 +1,328 @@
+//! IP addresses, both IPv4 and IPv6, stored as 16 byte [`DataType::Binary`] values in network
+//! byte order. IPv4 addresses are stored as IPv4-mapped IPv6 addresses (`::ffff:a.b.c.d`), so
+//! the values of both versions can be compared and sorted in a single column.
+//!
+//! Besides such binary values, the functions accept strings, which are parsed, and unsigned
+//! integers, which are read as IPv4 addresses.
+use std::borrow::Cow;
+use std::fmt::Write;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use polars_core::prelude::arity::{broadcast_try_binary_elementwise, unary_elementwise_values};
+use polars_core::prelude::*;
+
+/// The size in bytes of an IP address.
+pub const IP_SIZE: usize = 16;
+/// The prefix of the 128-bit value of an IPv4-mapped address.
+const IPV4_MAPPED_PREFIX: u128 = 0xffff << 32;
+
+fn ip_to_bits(ip: IpAddr) -> u128 {
+    match ip {
+        IpAddr::V4(ip) => u128::from(ip.to_ipv6_mapped()),
+        IpAddr::V6(ip) => u128::from(ip),
+    }
+}
+
+fn bits_to_ip(bits: u128) -> IpAddr {
+    let ip = Ipv6Addr::from(bits);
+    match ip.to_ipv4_mapped() {
+        Some(ip) => IpAddr::V4(ip),
+        None => IpAddr::V6(ip),
+    }
+}
+
+fn is_ipv4(bits: u128) -> bool {
+    bits >> 32 == IPV4_MAPPED_PREFIX >> 32
+}
+
+fn read_bits(value: &[u8]) -> PolarsResult<u128> {
+    let octets: [u8; IP_SIZE] = value.try_into().map_err(|_| {
+        polars_err!(
+            ComputeError: "invalid IP address: expected {} bytes, got {}",
+            IP_SIZE, value.len()
+        )
+    })?;
+    Ok(u128::from_be_bytes(octets))
+}
+
+/// A mask of the `len` most significant bits.
+fn prefix_mask(len: u32) -> u128 {
+    u128::MAX.checked_shl(128 - len).unwrap_or(0)
+}
+
+fn parse_cidr(cidr: &str) -> PolarsResult<(u128, u128)> {
+    let (addr, len) = match cidr.split_once('/') {
+        Some((addr, len)) => (addr, Some(len)),
+        None => (cidr, None),
+    };
+    let invalid = || polars_err!(ComputeError: "invalid CIDR block: '{}'", cidr);
+    let ip: IpAddr = addr.parse().map_err(|_| invalid())?;
+    let max_len = if ip.is_ipv4() { 32 } else { 128 };
+    let len = match len {
+        Some(len) => len.parse::<u32>().map_err(|_| invalid())?,
+        None => max_len,
+    };
+    polars_ensure!(len <= max_len, ComputeError: "invalid CIDR block: '{}'", cidr);
+    let mask = prefix_mask(len + (128 - max_len));
+    Ok((ip_to_bits(ip) & mask, mask))
+}
+
+fn collect_ips<I: Iterator<Item = Option<u128>>>(
+    name: PlSmallStr,
+    len: usize,
+    iter: I,
+) -> BinaryChunked {
+    let mut builder = BinaryChunkedBuilder::new(name, len);
+    for bits in iter {
+        match bits {
+            Some(bits) => builder.append_value(bits.to_be_bytes()),
+            None => builder.append_null(),
+        }
+    }
+    builder.finish()
+}
+
+fn parse_strings(ca: &StringChunked, strict: bool) -> PolarsResult<BinaryChunked> {
+    let mut failure = None;
+    let out = collect_ips(
+        ca.name().clone(),
+        ca.len(),
+        ca.iter().map(|opt_s| {
+            let s = opt_s?;
+            let ip = s.trim().parse::<IpAddr>().ok();
+            if ip.is_none() && failure.is_none() {
+                failure = Some(s.to_string());
+            }
+            ip.map(ip_to_bits)
+        }),
+    );
+    if strict {
+        if let Some(s) = failure {
+            polars_bail!(
+                ComputeError: "invalid IP address: '{}'\n\nUse `strict=False` to convert \
+                invalid addresses to null.",
+                s
+            );
+        }
+    }
+    Ok(out)
+}
+
+/// The IP addresses of `s` in their binary representation.
+fn to_ips(s: &Series) -> PolarsResult<Cow<'_, BinaryChunked>> {
+    Ok(match s.dtype() {
+        DataType::Binary => {
+            let ca = s.binary()?;
+            for v in ca
+                .downcast_iter()
+                .flat_map(|arr| arr.non_null_values_iter())
+            {
+                read_bits(v)?;
+            }
+            Cow::Borrowed(ca)
+        },
+        DataType::String => Cow::Owned(parse_strings(s.str()?, true)?),
+        dt if dt.is_unsigned_integer() || dt.is_null() => {
+            let s = s.strict_cast(&DataType::UInt32)?;
+            let ca = s.u32()?;
+            Cow::Owned(collect_ips(
+                ca.name().clone(),
+                ca.len(),
+                ca.iter()
+                    .map(|v| v.map(|v| ip_to_bits(IpAddr::V4(Ipv4Addr::from(v))))),
+            ))
+        },
+        dt => polars_bail!(
+            InvalidOperation: "expected IP addresses as binary, string or unsigned \
+            integer values, got {}",
+            dt
+        ),
+    })
+}
+
+/// Parse strings to IP addresses. Invalid addresses are null if not `strict`.
+pub fn ip_parse(s: &Series, strict: bool) -> PolarsResult<Series> {
+    match s.dtype() {
+        DataType::String => Ok(parse_strings(s.str()?, strict)?.into_series()),
+        _ => Ok(to_ips(s)?.into_owned().into_series()),
+    }
+}
+
+/// Format IP addresses in their canonical text representation.
+pub fn ip_to_string(s: &Series) -> PolarsResult<Series> {
+    let ca = to_ips(s)?;
+    let mut builder = StringChunkedBuilder::new(ca.name().clone(), ca.len());
+    let mut buffer = String::new();
+    for opt_v in ca.iter() {
+        match opt_v {
+            Some(v) => {
+                buffer.clear();
+                write!(buffer, "{}", bits_to_ip(read_bits(v)?)).unwrap();
+                builder.append_value(&buffer);
+            },
+            None => builder.append_null(),
+        }
+    }
+    Ok(builder.finish().into_series())
+}
+
+/// The version of the IP addresses, 4 or 6.
+pub fn ip_version(s: &Series) -> PolarsResult<Series> {
+    let ca = to_ips(s)?;
+    let out: UInt8Chunked = unary_elementwise_values(&*ca, |v: &[u8]| {
+        if is_ipv4(u128::from_be_bytes(v.try_into().unwrap())) {
+            4
+        } else {
+            6
+        }
+    });
+    Ok(out.into_series())
+}
+
+/// Whether the IP addresses are in the CIDR blocks, e.g. `"10.0.0.0/8"` or `"2001:db8::/32"`.
+/// IPv4 blocks also contain the IPv4-mapped IPv6 addresses.
+pub fn ip_is_in_cidr(s: &Series, cidr: &Series) -> PolarsResult<Series> {
+    let ca = to_ips(s)?;
+    let cidr = cidr.cast(&DataType::String)?;
+    let cidr = cidr.str()?;
+    let out: BooleanChunked = if cidr.len() == 1 {
+        match cidr.get(0) {
+            Some(block) => {
+                let (network, mask) = parse_cidr(block)?;
+                unary_elementwise_values(&*ca, |v: &[u8]| {
+                    u128::from_be_bytes(v.try_into().unwrap()) & mask == network
+                })
+            },
+            None => BooleanChunked::full_null(ca.name().clone(), ca.len()),
+        }
+    } else {
+        broadcast_try_binary_elementwise(&*ca, cidr, |v: Option<&[u8]>, block: Option<&str>| {
+            let (Some(v), Some(block)) = (v, block) else {
+                return Ok(None);
+            };
+            let (network, mask) = parse_cidr(block)?;
+            PolarsResult::Ok(Some(read_bits(v)? & mask == network))
+        })?
+    };
+    Ok(out.into_series())
+}
+
+/// The network address of the subnet with a prefix of `prefix_len` bits that contains each IP
+/// address. The prefix length counts in the address space of the version of each address, so
+/// prefix lengths above 32 leave IPv4 addresses unchanged.
+pub fn ip_subnet(s: &Series, prefix_len: u8) -> PolarsResult<Series> {
+    polars_ensure!(
+        prefix_len <= 128,
+        ComputeError: "prefix length of an IP subnet must be at most 128, got {}", prefix_len
+    );
+    let ca = to_ips(s)?;
+    let prefix_len = prefix_len as u32;
+    let v4_mask = prefix_mask(96 + prefix_len.min(32));
+    let v6_mask = prefix_mask(prefix_len);
+    let out = collect_ips(
+        ca.name().clone(),
+        ca.len(),
+        ca.iter().map(|opt_v| {
+            let bits = u128::from_be_bytes(opt_v?.try_into().unwrap());
+            Some(bits & if is_ipv4(bits) { v4_mask } else { v6_mask })
+        }),
+    );
+    Ok(out.into_series())
+}
+
+/// The IPv4 addresses as unsigned 32-bit integers. IPv6 addresses don't fit and are null.
+pub fn ip_to_integer(s: &Series) -> PolarsResult<Series> {
+    let ca = to_ips(s)?;
+    let out: UInt32Chunked = ca
+        .iter()
+        .map(|opt_v| {
+            let bits = u128::from_be_bytes(opt_v?.try_into().unwrap());
+            is_ipv4(bits).then_some(bits as u32)
+        })
+        .collect();
+    Ok(out.with_name(ca.name().clone()).into_series())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn ips(values: &[Option<&str>]) -> Series {
+        ip_parse(&Series::new("ip".into(), values), true).unwrap()
+    }
+
+    #[test]
+    fn test_ip_parse_roundtrip() {
+        let s = ips(&[
+            Some("10.1.2.3"),
+            Some("2001:DB8::1"),
+            Some("::ffff:1.2.3.4"),
+            None,
+        ]);
+        assert_eq!(s.dtype(), &DataType::Binary);
+        let out = ip_to_string(&s).unwrap();
+        assert_eq!(
+            Vec::from(out.str().unwrap()),
+            &[Some("10.1.2.3"), Some("2001:db8::1"), Some("1.2.3.4"), None]
+        );
+        let out = ip_version(&s).unwrap();
+        assert_eq!(
+            Vec::from(out.u8().unwrap()),
+            &[Some(4), Some(6), Some(4), None]
+        );
+
+        let invalid = Series::new("ip".into(), &["1.2.3.4", "1.2.3.256"]);
+        assert!(ip_parse(&invalid, true).is_err());
+        let out = ip_parse(&invalid, false).unwrap();
+        assert_eq!(out.null_count(), 1);
+    }
+
+    #[test]
+    fn test_ip_is_in_cidr() {
+        let s = ips(&[
+            Some("10.1.2.3"),
+            Some("11.0.0.1"),
+            Some("2001:db8::1"),
+            None,
+        ]);
+        let cidr = Series::new("cidr".into(), &["10.0.0.0/8"]);
+        let out = ip_is_in_cidr(&s, &cidr).unwrap();
+        assert_eq!(
+            Vec::from(out.bool().unwrap()),
+            &[Some(true), Some(false), Some(false), None]
+        );
+
+        let cidr = Series::new(
+            "cidr".into(),
+            &["10.1.2.3", "0.0.0.0/0", "2001:db8::/32", "::/0"],
+        );
+        let out = ip_is_in_cidr(&s, &cidr).unwrap();
+        assert_eq!(
+            Vec::from(out.bool().unwrap()),
+            &[Some(true), Some(true), Some(true), None]
+        );
+
+        let cidr = Series::new("cidr".into(), &["10.0.0.0/33"]);
+        assert!(ip_is_in_cidr(&s, &cidr).is_err());
+    }
+
+    #[test]
+    fn test_ip_subnet_and_integer() {
+        let s = ips(&[Some("10.1.2.3"), Some("2001:db8:aaaa::1")]);
+        let out = ip_to_string(&ip_subnet(&s, 16).unwrap()).unwrap();
+        assert_eq!(
+            Vec::from(out.str().unwrap()),
+            &[Some("10.1.0.0"), Some("2001::")]
+        );
+        let out = ip_to_string(&ip_subnet(&s, 48).unwrap()).unwrap();
+        assert_eq!(
+            Vec::from(out.str().unwrap()),
+            &[Some("10.1.2.3"), Some("2001:db8:aaaa::")]
+        );
+
+        let out = ip_to_integer(&s).unwrap();
+        assert_eq!(Vec::from(out.u32().unwrap()), &[Some(0x0a010203), None]);
+        let back = ip_to_string(&out).unwrap();
+        assert_eq!(Vec::from(back.str().unwrap()), &[Some("10.1.2.3"), None]);
+    }
+}
@@ -19,6 +19,10 @@ mod ewm_by;
 mod floor_divide;
 #[cfg(feature = "fused")]
 mod fused;
+#[cfg(feature = "geometry")]
+mod geometry;
+#[cfg(feature = "hash")]
+mod hash;
 mod horizontal;
 mod index;
 #[cfg(feature = "index_of")]
@@ -26,6 +30,8 @@ mod index_of;
 mod int_range;
 #[cfg(any(feature = "interpolate_by", feature = "interpolate"))]
 mod interpolation;
+#[cfg(feature = "ip")]
+mod ip;
 #[cfg(feature = "is_between")]
 mod is_between;
 #[cfg(feature = "is_first_distinct")]
@@ -56,6 +62,8 @@ mod rle;
 mod rolling;
 #[cfg(feature = "round_series")]
 mod round;
+#[cfg(feature = "scatter_gather")]
+mod scatter_gather;
 #[cfg(feature = "search_sorted")]
 mod search_sorted;
 #[cfg(feature = "to_dummies")]
@@ -63,10 +71,12 @@ mod to_dummies;
 #[cfg(feature = "unique_counts")]
 mod unique;
 mod various;
+#[cfg(feature = "weighted_agg")]
+mod weighted;
 
 #[cfg(feature = "abs")]
 pub use abs::*;
-pub use arg_min_max::ArgAgg;
+pub use arg_min_max::{ArgAgg, arg_max_by, arg_min_by};
 pub use bitwise::*;
 #[cfg(feature = "business")]
 pub use business::*;
@@ -85,6 +95,10 @@ pub use ewm_by::*;
 pub use floor_divide::*;
 #[cfg(feature = "fused")]
 pub use fused::*;
+#[cfg(feature = "geometry")]
+pub use geometry::*;
+#[cfg(feature = "hash")]
+pub use hash::*;
 pub use horizontal::*;
 pub use index::*;
 #[cfg(feature = "index_of")]
@@ -96,6 +110,8 @@ pub use interpolation::interpolate::*;
 pub use interpolation::interpolate_by::*;
 #[cfg(any(feature = "interpolate", feature = "interpolate_by"))]
 pub use interpolation::*;
+#[cfg(feature = "ip")]
+pub use ip::*;
 #[cfg(feature = "is_between")]
 pub use is_between::*;
 #[cfg(feature = "is_first_distinct")]
@@ -128,6 +144,8 @@ pub use rle::*;
 pub use rolling::*;
 #[cfg(feature = "round_series")]
 pub use round::*;
+#[cfg(feature = "scatter_gather")]
+pub use scatter_gather::*;
 #[cfg(feature = "search_sorted")]
 pub use search_sorted::*;
 #[cfg(feature = "to_dummies")]
@@ -135,6 +153,8 @@ pub use to_dummies::*;
 #[cfg(feature = "unique_counts")]
 pub use unique::*;
 pub use various::*;
+#[cfg(feature = "weighted_agg")]
+pub use weighted::*;
 mod not;
 
 #[cfg(feature = "dtype-array")]
@@ -1,6 +1,7 @@
 #![allow(unsafe_op_in_unsafe_fn)]
 use arrow::array::BooleanArray;
 use arrow::compute::concatenate::concatenate_validities;
+use polars_core::prelude::arity::unary_elementwise_values;
 use polars_core::prelude::*;
 use rand::prelude::*;
 #[cfg(feature = "serde")]
@@ -195,6 +196,53 @@ pub trait SeriesRank: SeriesSealed {
     fn rank(&self, options: RankOptions, seed: Option<u64>) -> Series {
         rank(self.as_series(), options.method, options.descending, seed)
     }
+
+    /// Relative rank of each value, `(rank - 1) / (n - 1)`, where `rank` is the `min` rank
+    /// and `n` the number of non-null values. This matches SQL's `PERCENT_RANK`.
+    fn percent_rank(&self, descending: bool) -> Series {
+        let s = self.as_series();
+        let n = s.len() - s.null_count();
+        let denom = n.saturating_sub(1).max(1) as f64;
+        let ranks = rank(s, RankMethod::Min, descending, None);
+        let ranks = ranks.idx().unwrap();
+        unary_elementwise_values::<_, Float64Type, _>(ranks, |r| (r - 1) as f64 / denom)
+            .into_series()
+    }
+
+    /// Fraction of non-null values that are ordered before or tied with each value.
+    /// This matches SQL's `CUME_DIST`.
+    fn cume_dist(&self, descending: bool) -> Series {
+        let s = self.as_series();
+        let n = (s.len() - s.null_count()) as f64;
+        let ranks = rank(s, RankMethod::Max, descending, None);
+        let ranks = ranks.idx().unwrap();
+        unary_elementwise_values::<_, Float64Type, _>(ranks, |r| r as f64 / n).into_series()
+    }
+
+    /// Distribute the non-null values, in sorted order, over `n` buckets numbered from 1
+    /// that differ in size by at most one, with the larger buckets first. Ties are broken
+    /// by order of occurrence. This matches SQL's `NTILE`.
+    fn ntile(&self, n: IdxSize, descending: bool) -> PolarsResult<Series> {
+        polars_ensure!(n > 0, InvalidOperation: "`ntile` requires a positive number of buckets");
+        let s = self.as_series();
+        let count = (s.len() - s.null_count()) as IdxSize;
+        let size = count / n;
+        let remainder = count % n;
+        // The first `remainder` buckets hold one extra value.
+        let large_end = remainder * (size + 1);
+
+        let ranks = rank(s, RankMethod::Ordinal, descending, None);
+        let ranks = ranks.idx().unwrap();
+        let out = unary_elementwise_values::<_, IdxType, _>(ranks, |r| {
+            let i = r - 1;
+            if i < large_end {
+                i / (size + 1) + 1
+            } else {
+                remainder + (i - large_end) / size + 1
+            }
+        });
+        Ok(out.into_series())
+    }
 }
 
 impl SeriesRank for Series {}
@@ -333,6 +381,53 @@ mod test {
         assert_eq!(out.dtype(), &IDX_DTYPE);
     }
 
+    #[test]
+    fn test_percent_rank_cume_dist() -> PolarsResult<()> {
+        let s = Series::new("".into(), &[Some(3), None, Some(1), Some(3), Some(2)]);
+        let out = s.percent_rank(false).f64()?.into_iter().collect::<Vec<_>>();
+        assert_eq!(
+            out,
+            &[
+                Some(2.0 / 3.0),
+                None,
+                Some(0.0),
+                Some(2.0 / 3.0),
+                Some(1.0 / 3.0)
+            ]
+        );
+        let out = s.cume_dist(false).f64()?.into_iter().collect::<Vec<_>>();
+        assert_eq!(out, &[Some(1.0), None, Some(0.25), Some(1.0), Some(0.5)]);
+
+        let s = Series::new("".into(), &[7]);
+        assert_eq!(s.percent_rank(false).f64()?.get(0), Some(0.0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_ntile() -> PolarsResult<()> {
+        let s = Series::new(
+            "".into(),
+            &[Some(5), Some(1), None, Some(4), Some(2), Some(3)],
+        );
+        let out = s.ntile(2, false)?.idx()?.into_iter().collect::<Vec<_>>();
+        assert_eq!(
+            out,
+            &[Some(2 as IdxSize), Some(1), None, Some(2), Some(1), Some(1)]
+        );
+        let out = s.ntile(2, true)?.idx()?.into_iter().collect::<Vec<_>>();
+        assert_eq!(
+            out,
+            &[Some(1 as IdxSize), Some(2), None, Some(1), Some(2), Some(1)]
+        );
+        let out = s.ntile(10, false)?.idx()?.into_iter().collect::<Vec<_>>();
+        assert_eq!(
+            out,
+            &[Some(5 as IdxSize), Some(1), None, Some(4), Some(2), Some(3)]
+        );
+        assert!(s.ntile(0, false).is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_rank_reverse() -> PolarsResult<()> {
         let s = Series::new("".into(), &[None, Some(1), Some(1), Some(5), None]);
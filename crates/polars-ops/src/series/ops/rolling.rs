@@ -1,3 +1,5 @@
+use std::collections::BTreeSet;
+
 use polars_core::prelude::*;
 #[cfg(feature = "moment")]
 use {
@@ -37,6 +39,157 @@ where
     })
 }
 
+/// Dense ids of the values of `s`, numbered in the order of the values, and the first row of
+/// every id. Null values have no id.
+fn value_ids(s: &Series) -> PolarsResult<(Vec<Option<IdxSize>>, Vec<IdxSize>)> {
+    let groups = s.group_tuples(false, false)?;
+    let firsts = groups.iter().map(|g| g.first()).collect::<Vec<_>>();
+    let values = s.take_slice(&firsts)?;
+    let order = values.arg_sort(SortOptions::default().with_nulls_last(true));
+    let mut ranks: Vec<IdxSize> = vec![0; firsts.len()];
+    for (rank, group) in order.into_no_null_iter().enumerate() {
+        ranks[group as usize] = rank as IdxSize;
+    }
+
+    let mut ids = vec![None; s.len()];
+    let is_null = values.is_null();
+    for (group, indicator) in groups.iter().enumerate() {
+        if is_null.get(group) == Some(true) {
+            continue;
+        }
+        let id = Some(ranks[group]);
+        match indicator {
+            GroupsIndicator::Idx((_, idx)) => {
+                for &i in idx.iter() {
+                    ids[i as usize] = id;
+                }
+            },
+            GroupsIndicator::Slice([first, len]) => {
+                for i in first..first + len {
+                    ids[i as usize] = id;
+                }
+            },
+        }
+    }
+
+    let mut first_rows: Vec<IdxSize> = vec![0; firsts.len()];
+    for (group, first) in firsts.into_iter().enumerate() {
+        first_rows[ranks[group] as usize] = first;
+    }
+    Ok((ids, first_rows))
+}
+
+/// The number of occurrences of every value in a sliding window.
+struct WindowCounts {
+    counts: Vec<IdxSize>,
+    n_unique: usize,
+    n_valid: usize,
+    /// The values by their number of occurrences, only tracked to find the mode.
+    by_count: Option<Vec<BTreeSet<IdxSize>>>,
+    max_count: usize,
+}
+
+impl WindowCounts {
+    fn new(n_values: usize, window_size: usize, track_mode: bool) -> Self {
+        Self {
+            counts: vec![0; n_values],
+            n_unique: 0,
+            n_valid: 0,
+            by_count: track_mode.then(|| vec![BTreeSet::new(); window_size + 1]),
+            max_count: 0,
+        }
+    }
+
+    fn push(&mut self, id: IdxSize) {
+        let count = self.counts[id as usize] as usize;
+        self.counts[id as usize] += 1;
+        self.n_valid += 1;
+        if count == 0 {
+            self.n_unique += 1;
+        }
+        if let Some(by_count) = &mut self.by_count {
+            by_count[count].remove(&id);
+            by_count[count + 1].insert(id);
+            self.max_count = self.max_count.max(count + 1);
+        }
+    }
+
+    fn remove(&mut self, id: IdxSize) {
+        let count = self.counts[id as usize] as usize;
+        self.counts[id as usize] -= 1;
+        self.n_valid -= 1;
+        if count == 1 {
+            self.n_unique -= 1;
+        }
+        if let Some(by_count) = &mut self.by_count {
+            by_count[count].remove(&id);
+            if count > 1 {
+                by_count[count - 1].insert(id);
+            }
+            if count == self.max_count && by_count[count].is_empty() {
+                self.max_count -= 1;
+            }
+        }
+    }
+
+    /// The most frequent value, the smallest one if there are ties.
+    fn mode(&self) -> Option<IdxSize> {
+        let by_count = self.by_count.as_ref()?;
+        by_count.get(self.max_count)?.first().copied()
+    }
+}
+
+/// Slide a fixed window over the values with `ids`, and compute `f` of the counts of every
+/// window that has at least `min_periods` valid values.
+fn rolling_counts<T>(
+    ids: &[Option<IdxSize>],
+    n_values: usize,
+    options: &RollingOptionsFixedWindow,
+    track_mode: bool,
+    f: impl Fn(&WindowCounts) -> Option<T>,
+) -> PolarsResult<Vec<Option<T>>> {
+    polars_ensure!(
+        options.min_periods <= options.window_size,
+        ComputeError: "`window_size`: {} should be >= `min_periods`: {}",
+        options.window_size, options.min_periods
+    );
+    polars_ensure!(
+        options.weights.is_none(),
+        InvalidOperation: "`rolling_mode` and `rolling_n_unique` don't support weights"
+    );
+    let len = ids.len();
+    let window_size = options.window_size.min(len);
+    let mut counts = WindowCounts::new(n_values, window_size, track_mode);
+    let (mut lo, mut hi) = (0, 0);
+    let mut out = Vec::with_capacity(len);
+    for idx in 0..len {
+        let (start, end) = if options.center {
+            let right_window = window_size.div_ceil(2);
+            (
+                idx.saturating_sub(window_size - right_window),
+                len.min(idx + right_window),
+            )
+        } else {
+            (idx.saturating_sub(window_size.saturating_sub(1)), idx + 1)
+        };
+        // Remove the values that leave the window first, so no count exceeds the window size.
+        for id in ids[lo..start].iter().flatten() {
+            counts.remove(*id);
+        }
+        for id in ids[hi..end].iter().flatten() {
+            counts.push(*id);
+        }
+        (lo, hi) = (start, end);
+
+        if counts.n_valid < options.min_periods {
+            out.push(None);
+        } else {
+            out.push(f(&counts));
+        }
+    }
+    Ok(out)
+}
+
 pub trait RollingSeries: SeriesSealed {
     #[cfg(feature = "moment")]
     fn rolling_skew(&self, window_size: usize, bias: bool) -> PolarsResult<Series> {
@@ -58,6 +211,194 @@ pub trait RollingSeries: SeriesSealed {
             dt => polars_bail!(opq = rolling_skew, dt),
         }
     }
+
+    /// The most frequent value in every fixed-size window of the values. Ties are broken in
+    /// favor of the smallest value, and null values are ignored.
+    ///
+    /// The number of occurrences of every value is updated as the window slides, so this is
+    /// linear in the length of the values rather than in the window size times the length.
+    fn rolling_mode(&self, options: &RollingOptionsFixedWindow) -> PolarsResult<Series> {
+        let s = self.as_series();
+        let (ids, first_rows) = value_ids(s)?;
+        let rows = rolling_counts(&ids, first_rows.len(), options, true, |counts| {
+            counts.mode().map(|id| first_rows[id as usize])
+        })?;
+        let rows = IdxCa::from_iter_options(PlSmallStr::EMPTY, rows.into_iter());
+        s.take(&rows)
+    }
+
+    /// The number of unique values in every fixed-size window of the values. Null values are
+    /// ignored.
+    fn rolling_n_unique(&self, options: &RollingOptionsFixedWindow) -> PolarsResult<Series> {
+        let s = self.as_series();
+        let (ids, first_rows) = value_ids(s)?;
+        let n_unique = rolling_counts(&ids, first_rows.len(), options, false, |counts| {
+            Some(counts.n_unique as IdxSize)
+        })?;
+        Ok(IdxCa::from_iter_options(s.name().clone(), n_unique.into_iter()).into_series())
+    }
+
+    /// Apply `f` to every fixed-size window of the values. `f` must return a single value of
+    /// `output_dtype`, so unlike [`Series::rolling_map`] it can compute a statistic of a
+    /// different type than the values, and the values don't have to be numeric.
+    fn rolling_map_udf(
+        &self,
+        f: &dyn Fn(&Series) -> PolarsResult<Series>,
+        output_dtype: &DataType,
+        options: &RollingOptionsFixedWindow,
+    ) -> PolarsResult<Series> {
+        let s = self.as_series();
+        polars_ensure!(
+            options.min_periods <= options.window_size,
+            ComputeError: "`window_size`: {} should be >= `min_periods`: {}",
+            options.window_size, options.min_periods
+        );
+        let weights = match &options.weights {
+            Some(weights) => {
+                polars_ensure!(
+                    s.dtype().is_primitive_numeric(),
+                    InvalidOperation: "weighted `rolling_map` is not supported for dtype `{}`",
+                    s.dtype()
+                );
+                Some(Series::new(PlSmallStr::from_static("weights"), weights))
+            },
+            None => None,
+        };
+        let s = match weights {
+            Some(_) => s.cast(&DataType::Float64)?,
+            None => s.rechunk(),
+        };
+
+        let len = s.len();
+        let window_size = options.window_size.min(len);
+        // The number of rows of a full window before the current row, which aligns the weights
+        // with the windows that are truncated at the start of the series.
+        let full_left = if options.center {
+            options.window_size - options.window_size.div_ceil(2)
+        } else {
+            options.window_size.saturating_sub(1)
+        };
+        let mut values = Vec::with_capacity(len);
+        for idx in 0..len {
+            let (start, end) = if options.center {
+                let right_window = window_size.div_ceil(2);
+                (
+                    idx.saturating_sub(window_size - right_window),
+                    len.min(idx + right_window),
+                )
+            } else {
+                (idx.saturating_sub(window_size.saturating_sub(1)), idx + 1)
+            };
+            let window = s.slice(start as i64, end - start);
+            if window.len() - window.null_count() < options.min_periods {
+                values.push(AnyValue::Null);
+                continue;
+            }
+
+            let window = match &weights {
+                Some(weights) => {
+                    let offset = full_left - (idx - start);
+                    (&window * &weights.slice(offset as i64, window.len()))?
+                },
+                None => window,
+            };
+            let out = f(&window)?;
+            polars_ensure!(
+                out.len() == 1,
+                ComputeError: "the function of `rolling_map` must return a single value per \
+                window, got {} values", out.len()
+            );
+            let out = out.strict_cast(output_dtype)?;
+            values.push(out.get(0)?.into_static());
+        }
+        Series::from_any_values_and_dtype(s.name().clone(), &values, output_dtype, true)
+    }
 }
 
 impl RollingSeries for Series {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rolling_map_udf() -> PolarsResult<()> {
+        let s = Series::new("a".into(), ["a", "bb", "ccc", "dddd"]);
+        let options = RollingOptionsFixedWindow {
+            window_size: 2,
+            min_periods: 2,
+            ..Default::default()
+        };
+        let total_len = |s: &Series| {
+            let n: u64 = s.str()?.into_no_null_iter().map(|v| v.len() as u64).sum();
+            Ok(Series::new(s.name().clone(), [n]))
+        };
+        let out = s.rolling_map_udf(&total_len, &DataType::UInt64, &options)?;
+        let expected = Series::new("a".into(), [None, Some(3u64), Some(5), Some(7)]);
+        assert!(out.equals_missing(&expected));
+        Ok(())
+    }
+
+    #[test]
+    fn test_rolling_map_udf_weights() -> PolarsResult<()> {
+        let s = Series::new("a".into(), [1.0, 1.0, 1.0]);
+        let sum = |s: &Series| s.sum_reduce().map(|sc| sc.into_series(s.name().clone()));
+
+        // Truncated leading windows take the last weights, which belong to the current row.
+        let options = RollingOptionsFixedWindow {
+            window_size: 3,
+            min_periods: 1,
+            weights: Some(vec![1.0, 10.0, 100.0]),
+            ..Default::default()
+        };
+        let out = s.rolling_map_udf(&sum, &DataType::Float64, &options)?;
+        let expected = Series::new("a".into(), [100.0, 110.0, 111.0]);
+        assert!(out.equals_missing(&expected));
+
+        let options = RollingOptionsFixedWindow {
+            center: true,
+            ..options
+        };
+        let out = s.rolling_map_udf(&sum, &DataType::Float64, &options)?;
+        let expected = Series::new("a".into(), [110.0, 111.0, 11.0]);
+        assert!(out.equals_missing(&expected));
+        Ok(())
+    }
+
+    #[test]
+    fn test_rolling_mode_n_unique() -> PolarsResult<()> {
+        let s = Series::new(
+            "a".into(),
+            [Some(3), Some(1), Some(1), None, Some(3), Some(3)],
+        );
+        let options = RollingOptionsFixedWindow {
+            window_size: 3,
+            min_periods: 1,
+            ..Default::default()
+        };
+        let mode = s.rolling_mode(&options)?;
+        let expected = Series::new("a".into(), [3, 1, 1, 1, 1, 3]);
+        assert!(mode.equals_missing(&expected));
+
+        let n_unique = s.rolling_n_unique(&options)?;
+        let expected = IdxCa::from_vec("a".into(), vec![1, 2, 2, 1, 2, 1]).into_series();
+        assert!(n_unique.equals_missing(&expected));
+        Ok(())
+    }
+
+    #[test]
+    fn test_rolling_mode_single_value() -> PolarsResult<()> {
+        let s = Series::new("a".into(), [1, 1, 1, 1]);
+        for center in [false, true] {
+            let options = RollingOptionsFixedWindow {
+                window_size: 3,
+                min_periods: 1,
+                center,
+                ..Default::default()
+            };
+            let mode = s.rolling_mode(&options)?;
+            assert!(mode.equals_missing(&s));
+        }
+        Ok(())
+    }
+}
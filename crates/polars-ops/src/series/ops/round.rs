@@ -1,3 +1,4 @@
+use num_traits::Float;
 use num_traits::pow::Pow;
 use polars_core::prelude::*;
 use polars_core::with_match_physical_numeric_polars_type;
@@ -132,25 +133,43 @@ pub trait RoundSeries: SeriesSealed {
 
     /// Floor underlying floating point array to the lowest integers smaller or equal to the float value.
     fn floor(&self) -> PolarsResult<Series> {
+        self.floor_decimals(0)
+    }
+
+    /// Floor underlying floating point array to the largest number with `decimals` digits that is
+    /// smaller or equal to the value.
+    fn floor_decimals(&self, decimals: u32) -> PolarsResult<Series> {
         let s = self.as_series();
 
         if let Ok(ca) = s.f32() {
-            let s = ca.apply_values(|val| val.floor()).into_series();
+            let s = if decimals == 0 {
+                ca.apply_values(|val| val.floor()).into_series()
+            } else {
+                let multiplier = 10.0f32.powi(decimals as i32);
+                ca.apply_values(|val| floor_to_multiplier(val, multiplier))
+                    .into_series()
+            };
             return Ok(s);
         }
         if let Ok(ca) = s.f64() {
-            let s = ca.apply_values(|val| val.floor()).into_series();
+            let s = if decimals == 0 {
+                ca.apply_values(|val| val.floor()).into_series()
+            } else {
+                let multiplier = 10.0f64.powi(decimals as i32);
+                ca.apply_values(|val| floor_to_multiplier(val, multiplier))
+                    .into_series()
+            };
             return Ok(s);
         }
         #[cfg(feature = "dtype-decimal")]
         if let Some(ca) = s.try_decimal() {
             let precision = ca.precision();
             let scale = ca.scale() as u32;
-            if scale == 0 {
+            if scale <= decimals {
                 return Ok(ca.clone().into_series());
             }
 
-            let decimal_delta = scale;
+            let decimal_delta = scale - decimals;
             let multiplier = 10i128.pow(decimal_delta);
 
             let ca = ca
@@ -171,25 +190,43 @@ pub trait RoundSeries: SeriesSealed {
 
     /// Ceil underlying floating point array to the highest integers smaller or equal to the float value.
     fn ceil(&self) -> PolarsResult<Series> {
+        self.ceil_decimals(0)
+    }
+
+    /// Ceil underlying floating point array to the smallest number with `decimals` digits that is
+    /// larger or equal to the value.
+    fn ceil_decimals(&self, decimals: u32) -> PolarsResult<Series> {
         let s = self.as_series();
 
         if let Ok(ca) = s.f32() {
-            let s = ca.apply_values(|val| val.ceil()).into_series();
+            let s = if decimals == 0 {
+                ca.apply_values(|val| val.ceil()).into_series()
+            } else {
+                let multiplier = 10.0f32.powi(decimals as i32);
+                ca.apply_values(|val| ceil_to_multiplier(val, multiplier))
+                    .into_series()
+            };
             return Ok(s);
         }
         if let Ok(ca) = s.f64() {
-            let s = ca.apply_values(|val| val.ceil()).into_series();
+            let s = if decimals == 0 {
+                ca.apply_values(|val| val.ceil()).into_series()
+            } else {
+                let multiplier = 10.0f64.powi(decimals as i32);
+                ca.apply_values(|val| ceil_to_multiplier(val, multiplier))
+                    .into_series()
+            };
             return Ok(s);
         }
         #[cfg(feature = "dtype-decimal")]
         if let Some(ca) = s.try_decimal() {
             let precision = ca.precision();
             let scale = ca.scale() as u32;
-            if scale == 0 {
+            if scale <= decimals {
                 return Ok(ca.clone().into_series());
             }
 
-            let decimal_delta = scale;
+            let decimal_delta = scale - decimals;
             let multiplier = 10i128.pow(decimal_delta);
 
             let ca = ca
@@ -211,6 +248,40 @@ pub trait RoundSeries: SeriesSealed {
 
 impl RoundSeries for Series {}
 
+/// Floors `val` to a multiple of `1 / multiplier`. The scaled value can land just below an
+/// integer (`0.29 * 100.0 == 28.999999999999996`), so the result is corrected to the largest
+/// multiple that is still smaller or equal to `val`.
+fn floor_to_multiplier<T: Float>(val: T, multiplier: T) -> T {
+    let scaled = (val * multiplier).floor();
+    if !scaled.is_finite() {
+        return val;
+    }
+    let scaled = if (scaled + T::one()) / multiplier <= val {
+        scaled + T::one()
+    } else if scaled / multiplier > val {
+        scaled - T::one()
+    } else {
+        scaled
+    };
+    scaled / multiplier
+}
+
+/// Ceils `val` to a multiple of `1 / multiplier`, see [`floor_to_multiplier`].
+fn ceil_to_multiplier<T: Float>(val: T, multiplier: T) -> T {
+    let scaled = (val * multiplier).ceil();
+    if !scaled.is_finite() {
+        return val;
+    }
+    let scaled = if (scaled - T::one()) / multiplier >= val {
+        scaled - T::one()
+    } else if scaled / multiplier < val {
+        scaled + T::one()
+    } else {
+        scaled
+    };
+    scaled / multiplier
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -222,4 +293,29 @@ mod test {
         let ca = out.f64().unwrap();
         assert_eq!(ca.get(0), Some(1.0));
     }
+
+    #[test]
+    fn test_floor_ceil_decimals_series() {
+        let series = Series::new("a".into(), &[1.237, -1.231]);
+        let out = series.floor_decimals(2).unwrap();
+        assert_eq!(Vec::from(out.f64().unwrap()), &[Some(1.23), Some(-1.24)]);
+        let out = series.ceil_decimals(2).unwrap();
+        assert_eq!(Vec::from(out.f64().unwrap()), &[Some(1.24), Some(-1.23)]);
+
+        // the scaled values aren't exact, e.g. `0.29 * 100.0 == 28.999999999999996`
+        let series = Series::new("a".into(), &[0.29, 0.57, -0.29, 1.1]);
+        let out = series.floor_decimals(2).unwrap();
+        assert_eq!(
+            Vec::from(out.f64().unwrap()),
+            &[Some(0.29), Some(0.57), Some(-0.29), Some(1.1)]
+        );
+        let out = series.ceil_decimals(2).unwrap();
+        assert_eq!(
+            Vec::from(out.f64().unwrap()),
+            &[Some(0.29), Some(0.57), Some(-0.29), Some(1.1)]
+        );
+        let series = Series::new("a".into(), &[0.29f32, 0.57]);
+        let out = series.floor_decimals(2).unwrap();
+        assert_eq!(Vec::from(out.f32().unwrap()), &[Some(0.29), Some(0.57)]);
+    }
 }
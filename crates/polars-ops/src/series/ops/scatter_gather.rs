@@ -0,0 +1,120 @@
+use polars_core::prelude::*;
+use polars_core::with_match_physical_numeric_polars_type;
+
+use crate::chunked_array::ChunkedSet;
+
+/// Replace the values of `s` at the positions where `mask` is `true`.
+///
+/// `values` must either hold one value per `true` in `mask`, which are used in order, or a single
+/// value that is used for all of them. Nulls in `mask` count as `false`.
+///
+/// Numeric (and temporal) columns are updated in place if `s` holds the only reference to its
+/// buffers, so no full copy of the column is made.
+pub fn scatter_by_mask(s: Series, mask: &BooleanChunked, values: &Series) -> PolarsResult<Series> {
+    polars_ensure!(
+        mask.len() == s.len(),
+        ShapeMismatch: "mask of `scatter_by_mask` has length {}, but the series has length {}",
+        mask.len(), s.len()
+    );
+    let idx: Vec<IdxSize> = mask
+        .iter()
+        .enumerate()
+        .filter_map(|(i, m)| (m == Some(true)).then_some(i as IdxSize))
+        .collect();
+    polars_ensure!(
+        values.len() == idx.len() || values.len() == 1,
+        ShapeMismatch: "`scatter_by_mask` got {} values for {} masked positions",
+        values.len(), idx.len()
+    );
+    if idx.is_empty() {
+        return Ok(s);
+    }
+
+    let dtype = s.dtype().clone();
+    let values = values.strict_cast(&dtype)?;
+    let physical = dtype.to_physical();
+
+    // The physical values of categoricals depend on their mapping, so those can't be set directly.
+    if physical.is_primitive_numeric() && !dtype.is_categorical() {
+        let values = values.to_physical_repr();
+        let mut s = s.to_physical_repr().into_owned();
+        let out = with_match_physical_numeric_polars_type!(physical, |$T| {
+            let ca: &mut ChunkedArray<$T> = s._get_inner_mut().as_mut();
+            let values: &ChunkedArray<$T> = values.as_ref().as_ref().as_ref();
+            if values.len() == 1 {
+                ca.scatter(&idx, std::iter::repeat_n(values.get(0), idx.len()))
+            } else {
+                ca.scatter(&idx, values)
+            }
+        })?;
+        // SAFETY: the values were cast to `dtype` before going to the physical representation.
+        return unsafe { out.from_physical_unchecked(&dtype) };
+    }
+
+    // Gather from `s` followed by `values`.
+    let len = s.len() as IdxSize;
+    let step = (values.len() != 1) as IdxSize;
+    let mut next = len;
+    let gather_idx: Vec<IdxSize> = mask
+        .iter()
+        .enumerate()
+        .map(|(i, m)| {
+            if m == Some(true) {
+                next += step;
+                next - step
+            } else {
+                i as IdxSize
+            }
+        })
+        .collect();
+    let gather_idx = IdxCa::from_vec(PlSmallStr::EMPTY, gather_idx);
+
+    let mut combined = s;
+    combined.append(&values)?;
+    // SAFETY: all indices are smaller than `len + values.len()`.
+    Ok(unsafe { combined.take_unchecked(&gather_idx) })
+}
+
+/// Concatenate the slices `start..end` of `s` for every pair of `starts` and `ends`.
+///
+/// Either bound can be a single value that is then used for all ranges. The slices are
+/// zero-copy and copied into a single chunk once at the end, which makes this much cheaper than
+/// gathering long runs of rows by index.
+pub fn gather_by_ranges(s: &Series, starts: &Series, ends: &Series) -> PolarsResult<Series> {
+    let n = match (starts.len(), ends.len()) {
+        (1, n) | (n, 1) => n,
+        (l, r) => {
+            polars_ensure!(
+                l == r,
+                ShapeMismatch: "starts and ends of `gather_by_ranges` have different lengths ({} != {})",
+                l, r
+            );
+            l
+        },
+    };
+    let starts = starts.strict_cast(&IDX_DTYPE)?;
+    let ends = ends.strict_cast(&IDX_DTYPE)?;
+    let starts = starts.idx()?.rechunk();
+    let ends = ends.idx()?.rechunk();
+    polars_ensure!(
+        starts.null_count() == 0 && ends.null_count() == 0,
+        ComputeError: "the bounds of `gather_by_ranges` cannot be null"
+    );
+    let starts = starts.cont_slice()?;
+    let ends = ends.cont_slice()?;
+    let start_step = (starts.len() != 1) as usize;
+    let end_step = (ends.len() != 1) as usize;
+
+    let mut out = s.clear();
+    for i in 0..n {
+        let (start, end) = (starts[i * start_step], ends[i * end_step]);
+        polars_ensure!(
+            start <= end && end as usize <= s.len(),
+            OutOfBounds: "range {}..{} is out of bounds for a series of length {}",
+            start, end, s.len()
+        );
+        out.append(&s.slice(start as i64, (end - start) as usize))?;
+    }
+    // Every range added a chunk.
+    Ok(out.rechunk())
+}
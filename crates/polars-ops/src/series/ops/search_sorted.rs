@@ -1,6 +1,7 @@
 use polars_core::chunked_array::ops::search_sorted::{SearchSortedSide, binary_search_ca};
 use polars_core::prelude::row_encode::_get_rows_encoded_ca;
 use polars_core::prelude::*;
+use polars_core::utils::{SuperTypeFlags, get_supertype_with_options};
 use polars_core::with_match_physical_numeric_polars_type;
 
 pub fn search_sorted(
@@ -96,3 +97,65 @@ pub fn search_sorted(
         _ => polars_bail!(opq = search_sorted, original_dtype),
     }
 }
+
+/// Find the indices where the rows of `search_values` should be inserted into the rows of `by`
+/// to maintain order.
+///
+/// The rows of `by` must be sorted lexicographically, with every column ordered according to
+/// `descending` and `nulls_last`. Search values of length 1 are broadcast.
+pub fn search_sorted_by(
+    by: &[Column],
+    search_values: &[Column],
+    side: SearchSortedSide,
+    descending: &[bool],
+    nulls_last: &[bool],
+) -> PolarsResult<IdxCa> {
+    polars_ensure!(!by.is_empty(), ComputeError: "expected at least one column to search in");
+    polars_ensure!(
+        by.len() == search_values.len(),
+        ComputeError: "expected {} search values, one for every sorted column, got {}",
+        by.len(), search_values.len()
+    );
+    polars_ensure!(
+        by.len() == descending.len() && by.len() == nulls_last.len(),
+        ComputeError: "`descending` and `nulls_last` must have a value for every sorted column"
+    );
+
+    let len = search_values.iter().map(|c| c.len()).max().unwrap();
+    let mut sorted = Vec::with_capacity(by.len());
+    let mut values = Vec::with_capacity(by.len());
+    for (c, v) in by.iter().zip(search_values) {
+        polars_ensure!(
+            !c.dtype().is_categorical(),
+            InvalidOperation: "'search_sorted' is not supported on dtype: {}", c.dtype()
+        );
+        polars_ensure!(
+            c.len() == by[0].len(),
+            ShapeMismatch: "sorted columns must have the same length, got {} and {}",
+            by[0].len(), c.len()
+        );
+
+        let flags = SuperTypeFlags::default() & !SuperTypeFlags::ALLOW_PRIMITIVE_TO_STRING;
+        let supertype =
+            get_supertype_with_options(c.dtype(), v.dtype(), flags.into()).ok_or_else(|| {
+                polars_err!(
+                    InvalidOperation: "cannot search for dtype {} in a column of dtype {}",
+                    v.dtype(), c.dtype()
+                )
+            })?;
+        let v = match v.len() {
+            1 if len != 1 => v.new_from_index(0, len),
+            n if n == len => v.clone(),
+            n => polars_bail!(
+                ShapeMismatch: "search values must have the same length, got {} and {}", len, n
+            ),
+        };
+        sorted.push(c.cast(&supertype)?);
+        values.push(v.cast(&supertype)?);
+    }
+
+    let ca = _get_rows_encoded_ca(PlSmallStr::EMPTY, &sorted, descending, nulls_last)?;
+    let search_values = _get_rows_encoded_ca(PlSmallStr::EMPTY, &values, descending, nulls_last)?;
+    let idx = binary_search_ca(&ca, search_values.iter(), side, false);
+    Ok(IdxCa::new_vec(by[0].name().clone(), idx))
+}
@@ -0,0 +1,123 @@
+use polars_core::prelude::*;
+
+/// Collect the `(value, weight)` pairs where neither side is null.
+fn weighted_pairs(values: &Series, weights: &Series) -> PolarsResult<Vec<(f64, f64)>> {
+    let weights = match weights.len() {
+        1 if values.len() != 1 => weights.new_from_index(0, values.len()),
+        len => {
+            polars_ensure!(
+                len == values.len(),
+                ShapeMismatch: "weights should have the same length as the values, got {} and {}",
+                len, values.len()
+            );
+            weights.clone()
+        },
+    };
+    let values = values.cast(&DataType::Float64)?;
+    let weights = weights.cast(&DataType::Float64)?;
+
+    let mut pairs = Vec::with_capacity(values.len());
+    for (v, w) in values.f64()?.iter().zip(weights.f64()?.iter()) {
+        if let (Some(v), Some(w)) = (v, w) {
+            polars_ensure!(w >= 0.0, ComputeError: "weights should be non-negative, got {}", w);
+            pairs.push((v, w));
+        }
+    }
+    Ok(pairs)
+}
+
+fn mean_of_pairs(pairs: &[(f64, f64)]) -> Option<(f64, f64)> {
+    let total: f64 = pairs.iter().map(|(_, w)| w).sum();
+    (total > 0.0).then(|| (pairs.iter().map(|(v, w)| v * w).sum::<f64>() / total, total))
+}
+
+/// Mean of `values` weighted by `weights`.
+///
+/// Pairs where either the value or the weight is null are ignored. Returns `None` if the
+/// remaining weights sum to zero.
+pub fn weighted_mean(values: &Series, weights: &Series) -> PolarsResult<Option<f64>> {
+    let pairs = weighted_pairs(values, weights)?;
+    Ok(mean_of_pairs(&pairs).map(|(mean, _)| mean))
+}
+
+/// Variance of `values` weighted by `weights`, treating the weights as frequencies.
+///
+/// The sum of squared deviations is divided by `sum(weights) - ddof`. Returns `None` if
+/// that is not positive.
+pub fn weighted_var(values: &Series, weights: &Series, ddof: u8) -> PolarsResult<Option<f64>> {
+    let pairs = weighted_pairs(values, weights)?;
+    let Some((mean, total)) = mean_of_pairs(&pairs) else {
+        return Ok(None);
+    };
+    let denom = total - ddof as f64;
+    if denom <= 0.0 {
+        return Ok(None);
+    }
+    let ss: f64 = pairs.iter().map(|(v, w)| w * (v - mean) * (v - mean)).sum();
+    Ok(Some(ss / denom))
+}
+
+/// Standard deviation of `values` weighted by `weights`, see [`weighted_var`].
+pub fn weighted_std(values: &Series, weights: &Series, ddof: u8) -> PolarsResult<Option<f64>> {
+    Ok(weighted_var(values, weights, ddof)?.map(f64::sqrt))
+}
+
+/// Quantile of `values` weighted by `weights`.
+///
+/// This is the smallest value whose cumulative weight, in sorted order, is at least
+/// `quantile` times the total weight. Returns `None` if the weights sum to zero.
+pub fn weighted_quantile(
+    values: &Series,
+    weights: &Series,
+    quantile: f64,
+) -> PolarsResult<Option<f64>> {
+    polars_ensure!(
+        (0.0..=1.0).contains(&quantile),
+        ComputeError: "quantile should be between 0.0 and 1.0",
+    );
+    let mut pairs = weighted_pairs(values, weights)?;
+    pairs.retain(|(_, w)| *w > 0.0);
+    if pairs.is_empty() {
+        return Ok(None);
+    }
+    pairs.sort_unstable_by(|a, b| a.0.total_cmp(&b.0));
+
+    let total: f64 = pairs.iter().map(|(_, w)| w).sum();
+    let target = quantile * total;
+    let mut cum = 0.0;
+    for (v, w) in &pairs {
+        cum += w;
+        if cum >= target {
+            return Ok(Some(*v));
+        }
+    }
+    // Only reachable through rounding in the cumulative sum when `quantile` is 1.
+    Ok(pairs.last().map(|(v, _)| *v))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_weighted_aggregations() -> PolarsResult<()> {
+        let values = Series::new("".into(), &[Some(1.0), Some(2.0), None, Some(4.0)]);
+        let weights = Series::new("".into(), &[Some(1), Some(3), Some(5), None]);
+
+        assert_eq!(weighted_mean(&values, &weights)?, Some(1.75));
+        // Mean 1.75, weighted squared deviations 0.5625 and 0.1875.
+        assert_eq!(weighted_var(&values, &weights, 0)?, Some(0.75 / 4.0));
+        assert_eq!(weighted_var(&values, &weights, 1)?, Some(0.75 / 3.0));
+        assert_eq!(weighted_quantile(&values, &weights, 0.25)?, Some(1.0));
+        assert_eq!(weighted_quantile(&values, &weights, 0.5)?, Some(2.0));
+        assert_eq!(weighted_quantile(&values, &weights, 1.0)?, Some(2.0));
+
+        let zeros = Series::new("".into(), &[0, 0, 0, 0]);
+        assert_eq!(weighted_mean(&values, &zeros)?, None);
+        assert_eq!(weighted_quantile(&values, &zeros, 0.5)?, None);
+
+        let negative = Series::new("".into(), &[1, -1, 1, 1]);
+        assert!(weighted_mean(&values, &negative).is_err());
+        Ok(())
+    }
+}
@@ -6,3 +6,5 @@ pub mod write;
 pub use crate::parquet::bloom_filter;
 
 const ARROW_SCHEMA_META_KEY: &str = "ARROW:schema";
+/// Key of the [GeoParquet](https://geoparquet.org) file metadata.
+const GEOPARQUET_META_KEY: &str = "geo";
@@ -181,12 +181,34 @@ pub fn page_iter_to_array(
             init_nested,
         )?
         .collect_boxed(filter)?,
-        (PhysicalType::FixedLenByteArray(n), Decimal(_, _)) if *n > 16 => {
+        (PhysicalType::FixedLenByteArray(16), Int128) if logical_type.is_none() => {
+            let (nested, array, ptm) = PageDecoder::new(
+                pages,
+                ArrowDataType::FixedSizeBinary(16),
+                fixed_size_binary::BinaryDecoder { size: 16 },
+                init_nested,
+            )?
+            .collect(filter)?;
+
+            let values = array
+                .values()
+                .chunks_exact(16)
+                .map(super::super::convert_int128)
+                .collect::<Vec<_>>();
+            let validity = array.validity().cloned();
+
+            (
+                nested,
+                PrimitiveArray::<i128>::try_new(dtype.clone(), values.into(), validity)?.to_boxed(),
+                ptm,
+            )
+        },
+        (PhysicalType::FixedLenByteArray(n), Decimal(_, _) | Int128) if *n > 16 => {
             return Err(ParquetError::not_supported(format!(
                 "not implemented: can't decode Decimal128 type from Fixed Size Byte Array of len {n:?}"
             )));
         },
-        (PhysicalType::FixedLenByteArray(n), Decimal(_, _)) => {
+        (PhysicalType::FixedLenByteArray(n), Decimal(_, _) | Int128) => {
             // @TODO: Make a separate decoder for this
 
             let n = *n;
@@ -87,6 +87,12 @@ fn convert_i128(value: &[u8], n: usize) -> i128 {
     i128::from_be_bytes(bytes) >> (8 * (16 - n))
 }
 
+/// Reads a 128-bit integer that was stored without a logical type, see
+/// [`write::to_parquet_type`](crate::arrow::write::to_parquet_type).
+fn convert_int128(value: &[u8]) -> i128 {
+    (u128::from_be_bytes(value.try_into().unwrap()) ^ (1 << 127)) as i128
+}
+
 fn convert_i256(value: &[u8]) -> i256 {
     if value[0] >= 128 {
         let mut neg_bytes = [255u8; 32];
@@ -1,5 +1,7 @@
 //! This module has entry points, [`parquet_to_arrow_schema`] and the more configurable [`parquet_to_arrow_schema_with_options`].
-use arrow::datatypes::{ArrowDataType, ArrowSchema, Field, IntervalUnit, TimeUnit};
+use arrow::datatypes::{
+    ArrowDataType, ArrowSchema, DTYPE_FLOAT16, Field, IntervalUnit, Metadata, TimeUnit,
+};
 use polars_utils::pl_str::PlSmallStr;
 
 use crate::arrow::read::schema::SchemaInferenceOptions;
@@ -182,6 +184,8 @@ fn from_fixed_len_byte_array(
             // would be incorrect if all 12 bytes of the interval are populated
             ArrowDataType::Interval(IntervalUnit::DayTime)
         },
+        // Half-precision floats are decoded to f32, the field is tagged in `to_field`.
+        (Some(PrimitiveLogicalType::Float16), _) if length == 2 => ArrowDataType::Float32,
         _ => ArrowDataType::FixedSizeBinary(length),
     }
 }
@@ -309,11 +313,31 @@ pub(crate) fn is_nullable(field_info: &FieldInfo) -> bool {
 /// Returns `None` iff the parquet type has no associated primitive types,
 /// i.e. if it is a column-less group type.
 fn to_field(type_: &ParquetType, options: &SchemaInferenceOptions) -> Option<Field> {
-    Some(Field::new(
+    let field = Field::new(
         type_.get_field_info().name.clone(),
         to_dtype(type_, options)?,
         is_nullable(type_.get_field_info()),
-    ))
+    );
+
+    let is_float16 = matches!(
+        type_,
+        ParquetType::PrimitiveType(PrimitiveType {
+            logical_type: Some(PrimitiveLogicalType::Float16),
+            field_info: FieldInfo {
+                repetition: Repetition::Optional | Repetition::Required,
+                ..
+            },
+            ..
+        })
+    );
+    if is_float16 {
+        return Some(field.with_metadata(Metadata::from([(
+            PlSmallStr::from_static(DTYPE_FLOAT16),
+            PlSmallStr::EMPTY,
+        )])));
+    }
+
+    Some(field)
 }
 
 /// Converts a parquet list to arrow list.
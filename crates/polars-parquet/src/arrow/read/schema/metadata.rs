@@ -1,5 +1,6 @@
 use arrow::datatypes::{
-    ArrowDataType, ArrowSchema, DTYPE_CATEGORICAL, DTYPE_ENUM_VALUES, Field, Metadata,
+    ArrowDataType, ArrowSchema, DTYPE_CATEGORICAL, DTYPE_ENUM_VALUES, DTYPE_FLOAT16, Field,
+    Metadata,
 };
 use arrow::io::ipc::read::deserialize_schema;
 use base64::Engine as _;
@@ -21,6 +22,14 @@ pub fn read_schema_from_metadata(metadata: &mut Metadata) -> PolarsResult<Option
 }
 
 fn convert_field(field: &mut Field) {
+    // @NOTE: Half-precision floats are decoded to f32. We tag the field so that Polars can still
+    // recover the original type.
+    if matches!(field.dtype, ArrowDataType::Float16) {
+        let mut metadata = field.metadata.as_deref().cloned().unwrap_or_default();
+        metadata.insert(PlSmallStr::from_static(DTYPE_FLOAT16), PlSmallStr::EMPTY);
+        field.metadata = Some(std::sync::Arc::new(metadata));
+    }
+
     // @NOTE: We cast non-Polars dictionaries to normal values because Polars does not have a
     // generic dictionary type.
     field.dtype = match std::mem::take(&mut field.dtype) {
@@ -1,5 +1,5 @@
 //! APIs to handle Parquet <-> Arrow schemas.
-use arrow::datatypes::{ArrowSchema, TimeUnit};
+use arrow::datatypes::{ArrowDataType, ArrowSchema, DTYPE_GEOMETRY, TimeUnit};
 
 mod convert;
 mod metadata;
@@ -8,8 +8,10 @@ pub(crate) use convert::*;
 pub use convert::{parquet_to_arrow_schema, parquet_to_arrow_schema_with_options};
 pub use metadata::read_schema_from_metadata;
 use polars_error::PolarsResult;
+use polars_utils::pl_str::PlSmallStr;
 
 use self::metadata::parse_key_value_metadata;
+use super::super::GEOPARQUET_META_KEY;
 pub use crate::parquet::metadata::{FileMetadata, KeyValue, SchemaDescriptor};
 pub use crate::parquet::schema::types::ParquetType;
 
@@ -36,7 +38,8 @@ impl Default for SchemaInferenceOptions {
 /// Infers a [`ArrowSchema`] from parquet's [`FileMetadata`].
 ///
 /// This first looks for the metadata key `"ARROW:schema"`; if it does not exist, it converts the
-/// Parquet types declared in the file's Parquet schema to Arrow's equivalent.
+/// Parquet types declared in the file's Parquet schema to Arrow's equivalent. Columns that the
+/// GeoParquet `"geo"` metadata declares as WKB encoded are tagged as geometries.
 ///
 /// # Error
 /// This function errors iff the key `"ARROW:schema"` exists but is not correctly encoded,
@@ -53,7 +56,44 @@ pub fn infer_schema_with_options(
     let mut metadata = parse_key_value_metadata(file_metadata.key_value_metadata());
 
     let schema = read_schema_from_metadata(&mut metadata)?;
-    Ok(schema.unwrap_or_else(|| {
+    let mut schema = schema.unwrap_or_else(|| {
         parquet_to_arrow_schema_with_options(file_metadata.schema().fields(), options)
-    }))
+    });
+    if let Some(geo) = metadata.get(GEOPARQUET_META_KEY) {
+        tag_geometry_columns(&mut schema, geo);
+    }
+    Ok(schema)
+}
+
+/// Tags the WKB encoded columns listed in the GeoParquet metadata. Invalid metadata is ignored.
+fn tag_geometry_columns(schema: &mut ArrowSchema, geo: &str) {
+    let Ok(serde_json::Value::Object(geo)) = serde_json::from_str::<serde_json::Value>(geo) else {
+        return;
+    };
+    let Some(serde_json::Value::Object(columns)) = geo.get("columns") else {
+        return;
+    };
+
+    for (name, column) in columns {
+        let is_wkb = column
+            .get("encoding")
+            .and_then(|encoding| encoding.as_str())
+            .is_some_and(|encoding| encoding.eq_ignore_ascii_case("wkb"));
+        let Some(field) = schema.get_mut(name).filter(|_| is_wkb) else {
+            continue;
+        };
+        if !matches!(
+            field.dtype,
+            ArrowDataType::Binary | ArrowDataType::LargeBinary | ArrowDataType::BinaryView
+        ) {
+            continue;
+        }
+
+        let mut metadata = field.metadata.as_deref().cloned().unwrap_or_default();
+        metadata.insert(
+            PlSmallStr::from_static(DTYPE_GEOMETRY),
+            PlSmallStr::from_static("wkb"),
+        );
+        field.metadata = Some(std::sync::Arc::new(metadata));
+    }
 }
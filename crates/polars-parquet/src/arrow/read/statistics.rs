@@ -16,7 +16,7 @@ use crate::parquet::schema::types::PhysicalType as ParquetPhysicalType;
 use crate::parquet::statistics::Statistics as ParquetStatistics;
 use crate::read::{
     ColumnChunkMetadata, PrimitiveLogicalType, convert_days_ms, convert_i128, convert_i256,
-    convert_year_month, int96_to_i64_ns,
+    convert_int128, convert_year_month, int96_to_i64_ns,
 };
 
 /// Parquet statistics for a nesting level
@@ -231,12 +231,15 @@ impl ColumnStatistics {
 
             (D::Decimal(_, _), PPT::Int32) => rmap!(expect_int32, @prim i32 as i128),
             (D::Decimal(_, _), PPT::Int64) => rmap!(expect_int64, @prim i64 as i128),
-            (D::Decimal(_, _), PPT::FixedLenByteArray(n)) if *n > 16 => {
+            (D::Int128, PPT::FixedLenByteArray(16)) if self.logical_type.is_none() => {
+                rmap!(expect_fixedlen, @prim Vec<u8>, |x| convert_int128(&x))
+            },
+            (D::Decimal(_, _) | D::Int128, PPT::FixedLenByteArray(n)) if *n > 16 => {
                 return Err(ParquetError::not_supported(format!(
                     "Can't decode Decimal128 type from Fixed Size Byte Array of len {n:?}",
                 )));
             },
-            (D::Decimal(_, _), PPT::FixedLenByteArray(n)) => rmap!(
+            (D::Decimal(_, _) | D::Int128, PPT::FixedLenByteArray(n)) => rmap!(
                 expect_fixedlen,
                 @prim Vec<u8>,
                 |x| convert_i128(&x, *n)
@@ -483,12 +486,18 @@ pub fn deserialize_all(
                 (D::Decimal(_, _), PPT::Int64) => {
                     rmap!(expect_int64, MutablePrimitiveArray::<i128>, @prim i64 as i128)
                 },
-                (D::Decimal(_, _), PPT::FixedLenByteArray(n)) if *n > 16 => {
+                (D::Int128, PPT::FixedLenByteArray(16)) if logical_type.is_none() => rmap!(
+                    expect_fixedlen,
+                    MutablePrimitiveArray::<i128>,
+                    @prim Vec<u8>,
+                    |x| convert_int128(&x)
+                ),
+                (D::Decimal(_, _) | D::Int128, PPT::FixedLenByteArray(n)) if *n > 16 => {
                     return Err(ParquetError::not_supported(format!(
                         "Can't decode Decimal128 type from Fixed Size Byte Array of len {n:?}",
                     )));
                 },
-                (D::Decimal(_, _), PPT::FixedLenByteArray(n)) => rmap!(
+                (D::Decimal(_, _) | D::Int128, PPT::FixedLenByteArray(n)) => rmap!(
                     expect_fixedlen,
                     MutablePrimitiveArray::<i128>,
                     @prim Vec<u8>,
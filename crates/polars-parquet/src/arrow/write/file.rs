@@ -3,22 +3,26 @@ use std::io::Write;
 use arrow::datatypes::ArrowSchema;
 use polars_error::{PolarsError, PolarsResult};
 
-use super::schema::schema_to_metadata_key;
+use super::schema::{schema_to_geo_metadata_key, schema_to_metadata_key};
 use super::{ThriftFileMetadata, WriteOptions, to_parquet_schema};
 use crate::parquet::metadata::{KeyValue, SchemaDescriptor};
 use crate::parquet::write::{RowGroupIterColumns, WriteOptions as FileWriteOptions};
 
 /// Attaches [`ArrowSchema`] to `key_value_metadata`
+///
+/// If the schema has geometry columns, the GeoParquet metadata is attached as well.
 pub fn add_arrow_schema(
     schema: &ArrowSchema,
     key_value_metadata: Option<Vec<KeyValue>>,
 ) -> Option<Vec<KeyValue>> {
-    key_value_metadata
-        .map(|mut x| {
-            x.push(schema_to_metadata_key(schema));
-            x
-        })
-        .or_else(|| Some(vec![schema_to_metadata_key(schema)]))
+    let mut key_value_metadata = key_value_metadata.unwrap_or_default();
+    key_value_metadata.push(schema_to_metadata_key(schema));
+    if let Some(geo) = schema_to_geo_metadata_key(schema) {
+        if !key_value_metadata.iter().any(|kv| kv.key == geo.key) {
+            key_value_metadata.push(geo);
+        }
+    }
+    Some(key_value_metadata)
 }
 
 /// An interface to write a parquet to a [`Write`]
@@ -27,7 +27,7 @@ mod utils;
 
 use arrow::array::*;
 use arrow::datatypes::*;
-use arrow::types::{NativeType, days_ms, i256};
+use arrow::types::{NativeType, days_ms, f16, i256};
 pub use nested::{num_values, write_rep_and_def};
 pub use pages::{to_leaves, to_nested, to_parquet_leaves};
 use polars_utils::pl_str::PlSmallStr;
@@ -455,6 +455,11 @@ pub fn array_to_page_simple(
                 encoding,
             );
         },
+        ArrowDataType::Float16 => {
+            let array = f16_to_fixed_size_binary(array.as_any().downcast_ref().unwrap());
+            // Byte-wise min/max statistics are meaningless for little-endian floats.
+            fixed_size_binary::array_to_page(&array, options, type_, None)
+        },
         ArrowDataType::Float32 => primitive::array_to_page_plain::<f32, f32>(
             array.as_any().downcast_ref().unwrap(),
             options,
@@ -735,6 +740,19 @@ pub fn array_to_page_simple(
                 fixed_size_binary::array_to_page(&array, options, type_, statistics)
             }
         },
+        ArrowDataType::Int128 => {
+            let array = int128_to_fixed_size_binary(array.as_any().downcast_ref().unwrap());
+            let statistics = if options.has_statistics() {
+                Some(fixed_size_binary::build_statistics(
+                    &array,
+                    type_.clone(),
+                    &options.statistics,
+                ))
+            } else {
+                None
+            };
+            fixed_size_binary::array_to_page(&array, options, type_, statistics)
+        },
         other => polars_bail!(nyi = "Writing parquet pages for data type {other:?}"),
     }
     .map(Page::Data)
@@ -808,6 +826,10 @@ fn array_to_page_nested(
             let array = array.as_any().downcast_ref().unwrap();
             primitive::nested_array_to_page::<i64, i64>(array, options, type_, nested)
         },
+        Float16 => {
+            let array = f16_to_fixed_size_binary(array.as_any().downcast_ref().unwrap());
+            fixed_size_binary::nested_array_to_page(&array, options, type_, nested, None)
+        },
         Float32 => {
             let array = array.as_any().downcast_ref().unwrap();
             primitive::nested_array_to_page::<f32, f32>(array, options, type_, nested)
@@ -968,6 +990,19 @@ fn array_to_page_nested(
                 fixed_size_binary::nested_array_to_page(&array, options, type_, nested, statistics)
             }
         },
+        Int128 => {
+            let array = int128_to_fixed_size_binary(array.as_any().downcast_ref().unwrap());
+            let statistics = if options.has_statistics() {
+                Some(fixed_size_binary::build_statistics(
+                    &array,
+                    type_.clone(),
+                    &options.statistics,
+                ))
+            } else {
+                None
+            };
+            fixed_size_binary::nested_array_to_page(&array, options, type_, nested, statistics)
+        },
         other => polars_bail!(nyi = "Writing nested parquet pages for data type {other:?}"),
     }
     .map(Page::Data)
@@ -1029,3 +1064,32 @@ pub fn transverse<T, F: Fn(&ArrowDataType) -> T + Clone>(dtype: &ArrowDataType,
     transverse_recursive(dtype, map, &mut encodings);
     encodings
 }
+
+/// Parquet has no 128-bit integers, so they are stored as 16-byte big-endian fixed length byte
+/// arrays. The sign bit is flipped, so that the bytes sort like the values.
+fn int128_to_fixed_size_binary(array: &PrimitiveArray<i128>) -> FixedSizeBinaryArray {
+    let mut values = Vec::<u8>::with_capacity(16 * array.len());
+    array
+        .values()
+        .iter()
+        .for_each(|x| values.extend_from_slice(&((*x as u128) ^ (1 << 127)).to_be_bytes()));
+    FixedSizeBinaryArray::new(
+        ArrowDataType::FixedSizeBinary(16),
+        values.into(),
+        array.validity().cloned(),
+    )
+}
+
+/// Parquet stores half-precision floats as 2-byte little-endian fixed length byte arrays.
+fn f16_to_fixed_size_binary(array: &PrimitiveArray<f16>) -> FixedSizeBinaryArray {
+    let mut values = Vec::<u8>::with_capacity(2 * array.len());
+    array
+        .values()
+        .iter()
+        .for_each(|x| values.extend_from_slice(&x.to_le_bytes()));
+    FixedSizeBinaryArray::new(
+        ArrowDataType::FixedSizeBinary(2),
+        values.into(),
+        array.validity().cloned(),
+    )
+}
@@ -1,11 +1,13 @@
-use arrow::datatypes::{ArrowDataType, ArrowSchema, ExtensionType, Field, TimeUnit};
+use arrow::datatypes::{
+    ArrowDataType, ArrowSchema, DTYPE_GEOMETRY, ExtensionType, Field, TimeUnit,
+};
 use arrow::io::ipc::write::{default_ipc_fields, schema_to_bytes};
 use base64::Engine as _;
 use base64::engine::general_purpose;
 use polars_error::{PolarsResult, polars_bail};
 use polars_utils::pl_str::PlSmallStr;
 
-use super::super::ARROW_SCHEMA_META_KEY;
+use super::super::{ARROW_SCHEMA_META_KEY, GEOPARQUET_META_KEY};
 use crate::arrow::write::decimal_length_from_precision;
 use crate::parquet::metadata::KeyValue;
 use crate::parquet::schema::Repetition;
@@ -79,6 +81,37 @@ pub fn schema_to_metadata_key(schema: &ArrowSchema) -> KeyValue {
     }
 }
 
+/// Creates the GeoParquet `"geo"` metadata for the WKB geometry columns of `schema`.
+///
+/// Returns `None` if the schema has no geometry columns.
+pub fn schema_to_geo_metadata_key(schema: &ArrowSchema) -> Option<KeyValue> {
+    let columns = schema
+        .iter_values()
+        .filter(|field| {
+            field
+                .metadata
+                .as_ref()
+                .is_some_and(|md| md.contains_key(DTYPE_GEOMETRY))
+        })
+        .map(|field| {
+            let column = serde_json::json!({ "encoding": "WKB", "geometry_types": [] });
+            (field.name.to_string(), column)
+        })
+        .collect::<serde_json::Map<_, _>>();
+
+    let primary_column = columns.keys().next()?.clone();
+    let geo = serde_json::json!({
+        "version": "1.1.0",
+        "primary_column": primary_column,
+        "columns": columns,
+    });
+
+    Some(KeyValue {
+        key: GEOPARQUET_META_KEY.to_string(),
+        value: Some(geo.to_string()),
+    })
+}
+
 /// Creates a [`ParquetType`] from a [`Field`].
 pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
     let name = field.name.clone();
@@ -132,6 +165,14 @@ pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
             None,
             None,
         )?),
+        ArrowDataType::Float16 => Ok(ParquetType::try_from_primitive(
+            name,
+            PhysicalType::FixedLenByteArray(2),
+            repetition,
+            None,
+            Some(PrimitiveLogicalType::Float16),
+            None,
+        )?),
         ArrowDataType::Float32 => Ok(ParquetType::try_from_primitive(
             name,
             PhysicalType::Float,
@@ -339,6 +380,18 @@ pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
                 None,
             )?)
         },
+        ArrowDataType::Int128 => {
+            // Parquet has no 128-bit integers, and a `Decimal(38, 0)` doesn't fit 39-digit values,
+            // so these are stored without a logical type.
+            Ok(ParquetType::try_from_primitive(
+                name,
+                PhysicalType::FixedLenByteArray(16),
+                repetition,
+                None,
+                None,
+                None,
+            )?)
+        },
         ArrowDataType::Decimal256(precision, scale) => {
             let precision = *precision;
             let scale = *scale;
@@ -1,4 +1,4 @@
-use polars_parquet_format::{ColumnChunk, ColumnMetaData, Encoding};
+use polars_parquet_format::{ColumnChunk, ColumnMetaData, Encoding, PageType};
 
 use super::column_descriptor::ColumnDescriptor;
 use crate::parquet::compression::Compression;
@@ -20,6 +20,13 @@ mod serde_types {
 #[cfg(feature = "serde_types")]
 use serde_types::*;
 
+fn is_dictionary(encoding: &Encoding) -> bool {
+    matches!(
+        *encoding,
+        Encoding::PLAIN_DICTIONARY | Encoding::RLE_DICTIONARY
+    )
+}
+
 /// Metadata for a column chunk.
 ///
 /// This contains the `ColumnDescriptor` associated with the chunk so that deserializers have
@@ -169,6 +176,35 @@ impl ColumnChunkMetadata {
         &self.metadata().encodings
     }
 
+    /// Returns whether all data pages of this column chunk are dictionary-encoded.
+    ///
+    /// This is read from the page encoding statistics if the writer stored them. Otherwise it is
+    /// only known if no encoding other than the dictionary and level encodings is used, as the
+    /// dictionary page itself may be `PLAIN`-encoded.
+    pub fn is_dictionary_encoded(&self) -> bool {
+        let metadata = self.metadata();
+        if let Some(stats) = &metadata.encoding_stats {
+            return stats
+                .iter()
+                .any(|s| s.page_type == PageType::DICTIONARY_PAGE)
+                && stats
+                    .iter()
+                    .filter(|s| matches!(s.page_type, PageType::DATA_PAGE | PageType::DATA_PAGE_V2))
+                    .all(|s| is_dictionary(&s.encoding));
+        }
+        metadata.encodings.iter().any(is_dictionary)
+            && metadata
+                .encodings
+                .iter()
+                .all(|e| is_dictionary(e) || matches!(*e, Encoding::RLE | Encoding::BIT_PACKED))
+    }
+
+    /// Returns whether this column chunk has a dictionary page, i.e. whether at least some of its
+    /// data pages are dictionary-encoded.
+    pub fn has_dictionary_page(&self) -> bool {
+        self.dictionary_page_offset().is_some() || self.column_encoding().iter().any(is_dictionary)
+    }
+
     /// Returns the offset and length in bytes of the column chunk within the file
     pub fn byte_range(&self) -> core::ops::Range<u64> {
         // this has been validated in [`try_from_thrift`]
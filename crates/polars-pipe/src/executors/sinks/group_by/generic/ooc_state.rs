@@ -1,4 +1,5 @@
 use polars_core::config::verbose;
+use polars_io::path_utils::{QueryTempDir, active_query_temp_dir};
 
 use super::*;
 use crate::executors::sinks::memory::MemTracker;
@@ -16,6 +17,8 @@ pub(super) struct OocState {
     pub(super) io_thread: IOThreadRef,
     count: u16,
     to_disk_threshold: f64,
+    // Spills go to the temporary directory of the query, if any.
+    query_temp_dir: Option<Arc<QueryTempDir>>,
 }
 
 impl Default for OocState {
@@ -32,6 +35,7 @@ impl Default for OocState {
             io_thread: Default::default(),
             count: 0,
             to_disk_threshold,
+            query_temp_dir: active_query_temp_dir(),
         }
     }
 }
@@ -59,7 +63,14 @@ impl OocState {
         // start IO thread
         let mut iot = self.io_thread.lock().unwrap();
         if iot.is_none() {
-            *iot = Some(IOThread::try_new(Arc::new(spill_schema), "group_by").unwrap());
+            *iot = Some(
+                IOThread::try_new(
+                    Arc::new(spill_schema),
+                    "group_by",
+                    self.query_temp_dir.as_deref(),
+                )
+                .unwrap(),
+            );
         }
         Ok(())
     }
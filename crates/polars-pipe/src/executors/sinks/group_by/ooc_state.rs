@@ -2,6 +2,7 @@ use std::sync::Mutex;
 
 use polars_core::config::verbose;
 use polars_core::prelude::*;
+use polars_io::path_utils::{QueryTempDir, active_query_temp_dir};
 
 use crate::executors::sinks::io::IOThread;
 use crate::executors::sinks::memory::MemTracker;
@@ -19,6 +20,8 @@ pub(super) struct OocState {
     pub(super) ooc: bool,
     // when ooc, we write to disk using an IO thread
     pub(super) io_thread: Arc<Mutex<Option<IOThread>>>,
+    // Spills go to the temporary directory of the query, if any.
+    pub(super) query_temp_dir: Option<Arc<QueryTempDir>>,
 }
 
 impl OocState {
@@ -27,6 +30,7 @@ impl OocState {
             _mem_track: MemTracker::new(morsels_per_sink()),
             ooc,
             io_thread: io_thread.unwrap_or_default(),
+            query_temp_dir: active_query_temp_dir(),
         }
     }
 
@@ -39,7 +43,11 @@ impl OocState {
         // start IO thread
         let mut iot = self.io_thread.lock().unwrap();
         if iot.is_none() {
-            *iot = Some(IOThread::try_new(input_schema, "group_by")?)
+            *iot = Some(IOThread::try_new(
+                input_schema,
+                "group_by",
+                self.query_temp_dir.as_deref(),
+            )?)
         }
         Ok(())
     }
@@ -481,6 +481,7 @@ where
         );
         new.hb = self.hb;
         new.thread_no = thread_no;
+        new.ooc_state.query_temp_dir = self.ooc_state.query_temp_dir.clone();
         Box::new(new)
     }
 
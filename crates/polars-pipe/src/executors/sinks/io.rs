@@ -8,6 +8,7 @@ use crossbeam_channel::{Receiver, Sender, bounded, unbounded};
 use polars_core::error::ErrString;
 use polars_core::prelude::*;
 use polars_core::utils::arrow::temporal_conversions::SECONDS_IN_DAY;
+use polars_io::path_utils::{QueryTempDir, TempArtifactKind};
 use polars_io::prelude::*;
 
 use crate::executors::sinks::get_base_temp_dir;
@@ -36,11 +37,21 @@ fn get_lockfile_path(dir: &Path) -> PathBuf {
     lockfile_path
 }
 
-fn get_spill_dir(operation_name: &'static str) -> PolarsResult<PathBuf> {
-    let id = uuid::Uuid::new_v4();
+fn get_spill_dir(
+    operation_name: &'static str,
+    query_temp_dir: Option<&QueryTempDir>,
+) -> PolarsResult<PathBuf> {
+    // If the query has a temporary directory, all spills of the query are grouped there
+    // and recorded in its manifest.
+    let dir = if let Some(query_dir) = query_temp_dir {
+        query_dir.new_artifact(TempArtifactKind::Spill, operation_name)?
+    } else {
+        let id = uuid::Uuid::new_v4();
 
-    let mut dir = std::path::PathBuf::from(get_base_temp_dir());
-    dir.push(format!("polars/{operation_name}/{id}"));
+        let mut dir = std::path::PathBuf::from(get_base_temp_dir());
+        dir.push(format!("polars/{operation_name}/{id}"));
+        dir
+    };
 
     if !dir.exists() {
         fs::create_dir_all(&dir).map_err(|err| {
@@ -73,39 +84,45 @@ fn clean_after_delay(time: Option<SystemTime>, secs: u64, path: &Path) {
 
 /// Starts a new thread that will clean up operations of directories that don't
 /// have a lockfile (opened with 'w' permissions).
-fn gc_thread(operation_name: &'static str, rx: Receiver<PathBuf>) {
+///
+/// If `sweep_stale` is false only the paths sent over `rx` are removed. This is
+/// the case when spilling into a query temporary directory, as that cleans up
+/// after itself.
+fn gc_thread(operation_name: &'static str, rx: Receiver<PathBuf>, sweep_stale: bool) {
     let _ = std::thread::spawn(move || {
         // First clean all existing
-        let mut dir = std::path::PathBuf::from(get_base_temp_dir());
-        dir.push(format!("polars/{operation_name}"));
-
-        // if the directory does not exist, there is nothing to clean
-        let rd = match std::fs::read_dir(&dir) {
-            Ok(rd) => rd,
-            _ => panic!("cannot find {:?}", dir),
-        };
-
-        for entry in rd {
-            let path = entry.unwrap().path();
-            if path.is_dir() {
-                let lockfile_path = get_lockfile_path(&path);
-
-                if let Ok(lockfile) = File::open(lockfile_path) {
-                    // lockfile can be read
-                    if let Ok(md) = lockfile.metadata() {
-                        let time = md.modified().ok();
-                        // The lockfile can still exist if a process was canceled
-                        // so we also check the modified date
-                        // we don't expect queries that run a month.
-                        clean_after_delay(time, SECONDS_IN_DAY as u64 * 30, &path);
-                    }
-                } else {
-                    // If path already removed, we simply continue.
-                    if let Ok(md) = path.metadata() {
-                        let time = md.modified().ok();
-                        // Wait 15 seconds to ensure we don't remove before lockfile is created
-                        // in a `collect_all` contention case
-                        clean_after_delay(time, 15, &path);
+        if sweep_stale {
+            let mut dir = std::path::PathBuf::from(get_base_temp_dir());
+            dir.push(format!("polars/{operation_name}"));
+
+            // if the directory does not exist, there is nothing to clean
+            let rd = match std::fs::read_dir(&dir) {
+                Ok(rd) => rd,
+                _ => panic!("cannot find {:?}", dir),
+            };
+
+            for entry in rd {
+                let path = entry.unwrap().path();
+                if path.is_dir() {
+                    let lockfile_path = get_lockfile_path(&path);
+
+                    if let Ok(lockfile) = File::open(lockfile_path) {
+                        // lockfile can be read
+                        if let Ok(md) = lockfile.metadata() {
+                            let time = md.modified().ok();
+                            // The lockfile can still exist if a process was canceled
+                            // so we also check the modified date
+                            // we don't expect queries that run a month.
+                            clean_after_delay(time, SECONDS_IN_DAY as u64 * 30, &path);
+                        }
+                    } else {
+                        // If path already removed, we simply continue.
+                        if let Ok(md) = path.metadata() {
+                            let time = md.modified().ok();
+                            // Wait 15 seconds to ensure we don't remove before lockfile is created
+                            // in a `collect_all` contention case
+                            clean_after_delay(time, 15, &path);
+                        }
                     }
                 }
             }
@@ -130,8 +147,10 @@ impl IOThread {
         schema: SchemaRef,
         // Will be used as subdirectory name in `~/.base_dir/polars/`
         operation_name: &'static str,
+        // The temporary directory of the query, if any
+        query_temp_dir: Option<&QueryTempDir>,
     ) -> PolarsResult<Self> {
-        let dir = get_spill_dir(operation_name)?;
+        let dir = get_spill_dir(operation_name, query_temp_dir)?;
 
         // make sure we create lockfile before we GC
         let lockfile_path = get_lockfile_path(&dir);
@@ -140,7 +159,7 @@ impl IOThread {
         let (cleanup_tx, rx) = unbounded::<PathBuf>();
         // start a thread that will clean up old dumps.
         // TODO: if we will have more ooc in the future  we will have a dedicated GC thread
-        gc_thread(operation_name, rx);
+        gc_thread(operation_name, rx, query_temp_dir.is_none());
 
         // we need some pushback otherwise we still could go OOM.
         let (tx, rx) = bounded::<Payload>(morsels_per_sink() * 2);
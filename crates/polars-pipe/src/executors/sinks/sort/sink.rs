@@ -8,6 +8,7 @@ use polars_core::error::PolarsResult;
 use polars_core::frame::DataFrame;
 use polars_core::prelude::{AnyValue, SchemaRef, Series, SortOptions};
 use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_io::path_utils::{QueryTempDir, active_query_temp_dir};
 use polars_utils::pl_str::PlSmallStr;
 
 use crate::executors::sinks::io::{IOThread, block_thread_until_io_thread_done};
@@ -40,6 +41,8 @@ pub struct SortSink {
     current_chunks_size: usize,
     // Start time of OOC phase.
     ooc_start: Option<Instant>,
+    // Spills go to the temporary directory of the query, if any.
+    query_temp_dir: Option<Arc<QueryTempDir>>,
 }
 
 impl SortSink {
@@ -66,6 +69,7 @@ impl SortSink {
             current_chunk_rows: 0,
             current_chunks_size: 0,
             ooc_start: None,
+            query_temp_dir: active_query_temp_dir(),
         };
         if ooc {
             if verbose() {
@@ -86,7 +90,11 @@ impl SortSink {
         // start IO thread
         let mut iot = self.io_thread.write().unwrap();
         if iot.is_none() {
-            *iot = Some(IOThread::try_new(self.schema.clone(), "sort")?)
+            *iot = Some(IOThread::try_new(
+                self.schema.clone(),
+                "sort",
+                self.query_temp_dir.as_deref(),
+            )?)
         }
         Ok(())
     }
@@ -181,6 +189,7 @@ impl Sink for SortSink {
             current_chunk_rows: 0,
             current_chunks_size: 0,
             ooc_start: self.ooc_start,
+            query_temp_dir: self.query_temp_dir.clone(),
         })
     }
 
@@ -192,6 +192,26 @@ impl ArrayNameSpace {
             None,
         )
     }
+    /// Compute the dot product of every sub-array with the sub-array of `other`.
+    pub fn dot(self, other: Expr) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::ArrayExpr(ArrayFunction::Dot),
+            &[other],
+            false,
+            None,
+        )
+    }
+
+    /// Compute the cosine similarity of every sub-array with the sub-array of `other`.
+    pub fn cosine_similarity(self, other: Expr) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::ArrayExpr(ArrayFunction::CosineSimilarity),
+            &[other],
+            false,
+            None,
+        )
+    }
+
     /// Returns a column with a separate row for every array element.
     pub fn explode(self) -> Expr {
         self.0
@@ -65,6 +65,28 @@ impl BinaryNameSpace {
             .map_private(FunctionExpr::BinaryExpr(BinaryFunction::Base64Encode))
     }
 
+    /// Encrypt every value with the key named `key`, fetched from the global secret provider
+    /// when the expression is evaluated.
+    #[cfg(feature = "binary_encryption")]
+    pub fn encrypt(self, key: PlSmallStr, algorithm: EncryptionAlgorithm) -> Expr {
+        self.0
+            .map_private(FunctionExpr::BinaryExpr(BinaryFunction::Encrypt {
+                algorithm,
+                key,
+            }))
+    }
+
+    /// Decrypt every value with the key named `key`, fetched from the global secret provider
+    /// when the expression is evaluated.
+    #[cfg(feature = "binary_encryption")]
+    pub fn decrypt(self, key: PlSmallStr, algorithm: EncryptionAlgorithm) -> Expr {
+        self.0
+            .map_private(FunctionExpr::BinaryExpr(BinaryFunction::Decrypt {
+                algorithm,
+                key,
+            }))
+    }
+
     #[cfg(feature = "binary_encoding")]
     pub fn from_buffer(self, to_type: DataType, is_little_endian: bool) -> Expr {
         self.0
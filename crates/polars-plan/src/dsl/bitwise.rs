@@ -1,4 +1,8 @@
-use super::{BitwiseFunction, Expr, FunctionExpr, FunctionFlags};
+use std::sync::Arc;
+
+use polars_core::prelude::GroupByBitwiseMethod;
+
+use super::{AggExpr, BitwiseFunction, Expr, FunctionExpr};
 
 impl Expr {
     /// Evaluate the number of set bits.
@@ -33,28 +37,16 @@ impl Expr {
 
     /// Perform an aggregation of bitwise ANDs
     pub fn bitwise_and(self) -> Self {
-        self.apply_private(FunctionExpr::Bitwise(BitwiseFunction::And))
-            .with_function_options(|mut options| {
-                options.flags |= FunctionFlags::RETURNS_SCALAR;
-                options
-            })
+        AggExpr::Bitwise(Arc::new(self), GroupByBitwiseMethod::And).into()
     }
 
     /// Perform an aggregation of bitwise ORs
     pub fn bitwise_or(self) -> Self {
-        self.apply_private(FunctionExpr::Bitwise(BitwiseFunction::Or))
-            .with_function_options(|mut options| {
-                options.flags |= FunctionFlags::RETURNS_SCALAR;
-                options
-            })
+        AggExpr::Bitwise(Arc::new(self), GroupByBitwiseMethod::Or).into()
     }
 
     /// Perform an aggregation of bitwise XORs
     pub fn bitwise_xor(self) -> Self {
-        self.apply_private(FunctionExpr::Bitwise(BitwiseFunction::Xor))
-            .with_function_options(|mut options| {
-                options.flags |= FunctionFlags::RETURNS_SCALAR;
-                options
-            })
+        AggExpr::Bitwise(Arc::new(self), GroupByBitwiseMethod::Xor).into()
     }
 }
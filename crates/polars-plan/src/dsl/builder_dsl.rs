@@ -55,6 +55,9 @@ impl DslBuilder {
             glob: false,
             include_file_paths: None,
             allow_missing_columns: false,
+            column_parsers: None,
+            #[cfg(feature = "cast_policy")]
+            cast_policy: None,
         });
 
         Ok(DslPlan::Scan {
@@ -102,6 +105,9 @@ impl DslBuilder {
             glob,
             include_file_paths,
             allow_missing_columns,
+            column_parsers: None,
+            #[cfg(feature = "cast_policy")]
+            cast_policy: None,
         });
         Ok(DslPlan::Scan {
             sources,
@@ -149,6 +155,9 @@ impl DslBuilder {
                 glob: true,
                 include_file_paths,
                 allow_missing_columns: false,
+                column_parsers: None,
+                #[cfg(feature = "cast_policy")]
+                cast_policy: None,
             }),
             scan_type: Box::new(FileScan::Ipc {
                 options,
@@ -188,6 +197,9 @@ impl DslBuilder {
             glob,
             include_file_paths,
             allow_missing_columns: false,
+            column_parsers: None,
+            #[cfg(feature = "cast_policy")]
+            cast_policy: None,
         });
         Ok(DslPlan::Scan {
             sources,
@@ -202,6 +214,14 @@ impl DslBuilder {
         .into())
     }
 
+    /// Sets the [`FileScanOptions::column_parsers`] of a scan.
+    pub fn with_column_parsers(mut self, column_parsers: Option<Arc<[Expr]>>) -> Self {
+        if let DslPlan::Scan { file_options, .. } = &mut self.0 {
+            file_options.column_parsers = column_parsers;
+        }
+        self
+    }
+
     pub fn cache(self) -> Self {
         let input = Arc::new(self.0);
         let id = input.as_ref() as *const DslPlan as usize;
@@ -25,6 +25,16 @@ impl DateLikeNameSpace {
         )
     }
 
+    /// Determine whether each date is a business day.
+    #[cfg(feature = "business")]
+    pub fn is_business_day(self, week_mask: [bool; 7], holidays: Vec<i32>) -> Expr {
+        self.0
+            .map_private(FunctionExpr::Business(BusinessFunction::IsBusinessDay {
+                week_mask,
+                holidays,
+            }))
+    }
+
     /// Convert from Date/Time/Datetime into String with the given format.
     /// See [chrono strftime/strptime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
     pub fn to_string(self, format: &str) -> Expr {
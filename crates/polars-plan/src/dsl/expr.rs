@@ -40,6 +40,8 @@ pub enum AggExpr {
     AggGroups(Arc<Expr>),
     Std(Arc<Expr>, u8),
     Var(Arc<Expr>, u8),
+    #[cfg(feature = "bitwise")]
+    Bitwise(Arc<Expr>, GroupByBitwiseMethod),
 }
 
 impl AsRef<Expr> for AggExpr {
@@ -60,6 +62,8 @@ impl AsRef<Expr> for AggExpr {
             AggGroups(e) => e,
             Std(e, _) => e,
             Var(e, _) => e,
+            #[cfg(feature = "bitwise")]
+            Bitwise(e, _) => e,
         }
     }
 }
@@ -207,6 +211,11 @@ impl OpaqueColumnUdf {
         match self {
             Self::Deserialized(t) => Ok(t),
             Self::Bytes(_b) => {
+                #[cfg(feature = "serde")]
+                if _b.starts_with(REGISTERED_UDF_MAGIC_BYTE_MARK) {
+                    return RegisteredUdfExpression::try_deserialize(_b.as_ref())
+                        .map(SpecialEq::new);
+                }
                 feature_gated!("serde";"python", {
                     crate::dsl::python_dsl::PythonUdfExpression::try_deserialize(_b.as_ref()).map(SpecialEq::new)
                 })
@@ -2,7 +2,7 @@ use std::fmt::Formatter;
 use std::ops::Deref;
 use std::sync::Arc;
 
-#[cfg(feature = "python")]
+#[cfg(feature = "serde")]
 use polars_utils::pl_serialize::deserialize_map_bytes;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
@@ -69,28 +69,22 @@ impl<'a> Deserialize<'a> for SpecialEq<Arc<dyn ColumnsUdf>> {
         D: Deserializer<'a>,
     {
         use serde::de::Error;
-        #[cfg(feature = "python")]
-        {
-            deserialize_map_bytes(deserializer, |buf| {
-                if buf.starts_with(crate::dsl::python_dsl::PYTHON_SERDE_MAGIC_BYTE_MARK) {
-                    let udf = crate::dsl::python_dsl::PythonUdfExpression::try_deserialize(&buf)
-                        .map_err(|e| D::Error::custom(format!("{e}")))?;
-                    Ok(SpecialEq::new(udf))
-                } else {
-                    Err(D::Error::custom(
-                        "deserialization not supported for this 'opaque' function",
-                    ))
-                }
-            })?
-        }
-        #[cfg(not(feature = "python"))]
-        {
-            _ = deserializer;
-
+        deserialize_map_bytes(deserializer, |buf| {
+            if buf.starts_with(REGISTERED_UDF_MAGIC_BYTE_MARK) {
+                let udf = RegisteredUdfExpression::try_deserialize(&buf)
+                    .map_err(|e| D::Error::custom(format!("{e}")))?;
+                return Ok(SpecialEq::new(udf));
+            }
+            #[cfg(feature = "python")]
+            if buf.starts_with(crate::dsl::python_dsl::PYTHON_SERDE_MAGIC_BYTE_MARK) {
+                let udf = crate::dsl::python_dsl::PythonUdfExpression::try_deserialize(&buf)
+                    .map_err(|e| D::Error::custom(format!("{e}")))?;
+                return Ok(SpecialEq::new(udf));
+            }
             Err(D::Error::custom(
                 "deserialization not supported for this 'opaque' function",
             ))
-        }
+        })?
     }
 }
 
@@ -405,28 +399,22 @@ impl<'a> Deserialize<'a> for GetOutput {
         D: Deserializer<'a>,
     {
         use serde::de::Error;
-        #[cfg(feature = "python")]
-        {
-            deserialize_map_bytes(deserializer, |buf| {
-                if buf.starts_with(self::python_dsl::PYTHON_SERDE_MAGIC_BYTE_MARK) {
-                    let get_output = self::python_dsl::PythonGetOutput::try_deserialize(&buf)
-                        .map_err(|e| D::Error::custom(format!("{e}")))?;
-                    Ok(SpecialEq::new(get_output))
-                } else {
-                    Err(D::Error::custom(
-                        "deserialization not supported for this output field",
-                    ))
-                }
-            })?
-        }
-        #[cfg(not(feature = "python"))]
-        {
-            _ = deserializer;
-
+        deserialize_map_bytes(deserializer, |buf| {
+            if buf.starts_with(REGISTERED_UDF_MAGIC_BYTE_MARK) {
+                let get_output = RegisteredUdfOutput::try_deserialize(&buf)
+                    .map_err(|e| D::Error::custom(format!("{e}")))?;
+                return Ok(SpecialEq::new(get_output));
+            }
+            #[cfg(feature = "python")]
+            if buf.starts_with(self::python_dsl::PYTHON_SERDE_MAGIC_BYTE_MARK) {
+                let get_output = self::python_dsl::PythonGetOutput::try_deserialize(&buf)
+                    .map_err(|e| D::Error::custom(format!("{e}")))?;
+                return Ok(SpecialEq::new(get_output));
+            }
             Err(D::Error::custom(
                 "deserialization not supported for this output field",
             ))
-        }
+        })?
     }
 }
 
@@ -113,6 +113,8 @@ impl fmt::Debug for Expr {
                     Var(expr, _) => write!(f, "{expr:?}.var()"),
                     Std(expr, _) => write!(f, "{expr:?}.std()"),
                     Quantile { expr, .. } => write!(f, "{expr:?}.quantile()"),
+                    #[cfg(feature = "bitwise")]
+                    Bitwise(expr, method) => write!(f, "{expr:?}.{method}()"),
                 }
             },
             Cast {
@@ -0,0 +1,101 @@
+use std::fmt;
+use std::sync::Arc;
+
+use polars_compute::hyperloglogplus::HyperLogLog;
+use polars_core::prelude::*;
+
+use super::{ColumnsUdf, SpecialEq};
+use crate::dsl::FieldsMapper;
+use crate::map;
+
+/// Functions on the HyperLogLog sketches `approx_n_unique` estimates the number of unique values
+/// from. Sketches are binary values, so they can be stored and merged across datasets.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, PartialEq, Debug, Eq, Hash)]
+pub enum ApproxNUniqueSketchFunction {
+    /// Build the sketch of the values.
+    Build,
+    /// Merge a column of sketches into a single sketch.
+    Merge,
+    /// Estimate the number of unique values of the union of a column of sketches.
+    Estimate,
+}
+
+impl fmt::Display for ApproxNUniqueSketchFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use ApproxNUniqueSketchFunction as A;
+
+        let s = match self {
+            A::Build => "approx_n_unique_sketch",
+            A::Merge => "approx_n_unique_merge",
+            A::Estimate => "approx_n_unique_estimate",
+        };
+
+        f.write_str(s)
+    }
+}
+
+impl From<ApproxNUniqueSketchFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
+    fn from(func: ApproxNUniqueSketchFunction) -> Self {
+        use ApproxNUniqueSketchFunction as A;
+
+        match func {
+            A::Build => map!(build_sketch),
+            A::Merge => map!(merge_sketches),
+            A::Estimate => map!(estimate_sketches),
+        }
+    }
+}
+
+impl ApproxNUniqueSketchFunction {
+    pub(super) fn get_field(&self, mapper: FieldsMapper) -> PolarsResult<Field> {
+        match self {
+            Self::Build => mapper.with_dtype(DataType::Binary),
+            Self::Merge | Self::Estimate => mapper.try_map_dtype(|dtype| {
+                polars_ensure!(
+                    dtype == &DataType::Binary,
+                    InvalidOperation: "'{}' expects binary sketches, got {}", self, dtype
+                );
+                Ok(match self {
+                    Self::Estimate => IDX_DTYPE,
+                    _ => DataType::Binary,
+                })
+            }),
+        }
+    }
+}
+
+fn sketch_column(name: PlSmallStr, sketch: &[u8]) -> Column {
+    let value = AnyValue::BinaryOwned(sketch.to_vec());
+    Column::new_scalar(name, Scalar::new(DataType::Binary, value), 1)
+}
+
+fn build_sketch(c: &Column) -> PolarsResult<Column> {
+    let sketch = c.approx_n_unique_sketch()?;
+    Ok(sketch_column(c.name().clone(), &sketch))
+}
+
+fn merge(c: &Column) -> PolarsResult<HyperLogLog<()>> {
+    let mut hll = HyperLogLog::new();
+    for sketch in c.binary()?.iter().flatten() {
+        polars_ensure!(
+            hll.merge_registers(sketch),
+            ComputeError: "invalid approx_n_unique sketch: not the registers of a HyperLogLog"
+        );
+    }
+    Ok(hll)
+}
+
+fn merge_sketches(c: &Column) -> PolarsResult<Column> {
+    let hll = merge(c)?;
+    Ok(sketch_column(c.name().clone(), hll.as_ref()))
+}
+
+fn estimate_sketches(c: &Column) -> PolarsResult<Column> {
+    let count = merge(c)?.count() as IdxSize;
+    Ok(Column::new_scalar(
+        c.name().clone(),
+        Scalar::new(IDX_DTYPE, count.into()),
+        1,
+    ))
+}
@@ -0,0 +1,61 @@
+use arrow::array::Array;
+use polars_compute::tdigest::TDigest;
+
+use super::*;
+
+pub(super) fn approx_quantile_field(mapper: FieldsMapper) -> PolarsResult<Field> {
+    mapper.try_map_dtype(|dtype| {
+        polars_ensure!(
+            dtype.is_primitive_numeric() || dtype.is_null(),
+            InvalidOperation: "`approx_quantile` operation not supported for dtype `{}`", dtype
+        );
+        Ok(match dtype {
+            DataType::Float32 => DataType::Float32,
+            _ => DataType::Float64,
+        })
+    })
+}
+
+fn get_approx_quantile(quantile: &Column) -> PolarsResult<f64> {
+    polars_ensure!(
+        quantile.len() == 1,
+        ComputeError: "polars only supports computing a single quantile; \
+        make sure the 'quantile' expression input produces a single quantile"
+    );
+    let quantile: Option<f64> = quantile.get(0)?.extract();
+    polars_ensure!(
+        quantile.is_some_and(|q| (0.0..=1.0).contains(&q)),
+        ComputeError: "quantile should be between 0.0 and 1.0",
+    );
+    Ok(quantile.unwrap())
+}
+
+/// Add the non-null values of `values` to `digest`.
+pub fn add_to_tdigest(digest: &mut TDigest, values: &Series) -> PolarsResult<()> {
+    let values = values.cast(&DataType::Float64)?;
+    for v in values.f64()?.downcast_iter() {
+        if v.null_count() == 0 {
+            v.values_iter().for_each(|v| digest.add(*v));
+        } else {
+            v.non_null_values_iter().for_each(|v| digest.add(v));
+        }
+    }
+    Ok(())
+}
+
+pub(super) fn approx_quantile(s: &[Column]) -> PolarsResult<Column> {
+    let values = &s[0];
+    let quantile = get_approx_quantile(&s[1])?;
+
+    let mut digest = TDigest::new();
+    add_to_tdigest(&mut digest, values.as_materialized_series())?;
+    let out = Column::new_scalar(
+        values.name().clone(),
+        Scalar::new(DataType::Float64, digest.quantile(quantile).into()),
+        1,
+    );
+    match values.dtype() {
+        DataType::Float32 => out.cast(&DataType::Float32),
+        _ => Ok(out),
+    }
+}
@@ -33,6 +33,8 @@ pub enum ArrayFunction {
     Shift,
     Explode,
     Concat,
+    Dot,
+    CosineSimilarity,
 }
 
 impl ArrayFunction {
@@ -70,6 +72,7 @@ impl ArrayFunction {
             CountMatches => mapper.with_dtype(IDX_DTYPE),
             Shift => mapper.with_same_dtype(),
             Explode => mapper.try_map_to_array_inner_dtype(),
+            Dot | CosineSimilarity => mapper.with_dtype(DataType::Float64),
         }
     }
 }
@@ -113,6 +116,8 @@ impl Display for ArrayFunction {
             CountMatches => "count_matches",
             Shift => "shift",
             Explode => "explode",
+            Dot => "dot",
+            CosineSimilarity => "cosine_similarity",
         };
         write!(f, "arr.{name}")
     }
@@ -149,6 +154,8 @@ impl From<ArrayFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             CountMatches => map_as_slice!(count_matches),
             Shift => map_as_slice!(shift),
             Explode => map_as_slice!(explode),
+            Dot => map_as_slice!(dot),
+            CosineSimilarity => map_as_slice!(cosine_similarity),
         }
     }
 }
@@ -294,6 +301,26 @@ pub(super) fn shift(s: &[Column]) -> PolarsResult<Column> {
     ca.array_shift(n.as_materialized_series()).map(Column::from)
 }
 
+/// Get `other` as an array with the width of `ca`, it may be given as a list literal.
+fn other_as_array(ca: &ArrayChunked, other: &Column) -> PolarsResult<Column> {
+    match other.dtype() {
+        DataType::List(inner) => other.cast(&DataType::Array(inner.clone(), ca.width())),
+        _ => Ok(other.clone()),
+    }
+}
+
+pub(super) fn dot(s: &[Column]) -> PolarsResult<Column> {
+    let ca = s[0].array()?;
+    let other = other_as_array(ca, &s[1])?;
+    ca.array_dot(other.array()?).map(Column::from)
+}
+
+pub(super) fn cosine_similarity(s: &[Column]) -> PolarsResult<Column> {
+    let ca = s[0].array()?;
+    let other = other_as_array(ca, &s[1])?;
+    ca.array_cosine_similarity(other.array()?).map(Column::from)
+}
+
 fn explode(c: &[Column]) -> PolarsResult<Column> {
     c[0].explode()
 }
@@ -21,6 +21,16 @@ pub enum BinaryFunction {
     Size,
     #[cfg(feature = "binary_encoding")]
     FromBuffer(DataType, bool),
+    #[cfg(feature = "binary_encryption")]
+    Encrypt {
+        algorithm: EncryptionAlgorithm,
+        key: PlSmallStr,
+    },
+    #[cfg(feature = "binary_encryption")]
+    Decrypt {
+        algorithm: EncryptionAlgorithm,
+        key: PlSmallStr,
+    },
 }
 
 impl BinaryFunction {
@@ -36,6 +46,8 @@ impl BinaryFunction {
             Size => mapper.with_dtype(DataType::UInt32),
             #[cfg(feature = "binary_encoding")]
             FromBuffer(dtype, _) => mapper.with_dtype(dtype.clone()),
+            #[cfg(feature = "binary_encryption")]
+            Encrypt { .. } | Decrypt { .. } => mapper.with_dtype(DataType::Binary),
         }
     }
 }
@@ -58,6 +70,10 @@ impl Display for BinaryFunction {
             Size => "size_bytes",
             #[cfg(feature = "binary_encoding")]
             FromBuffer(_, _) => "from_buffer",
+            #[cfg(feature = "binary_encryption")]
+            Encrypt { .. } => "encrypt",
+            #[cfg(feature = "binary_encryption")]
+            Decrypt { .. } => "decrypt",
         };
         write!(f, "bin.{s}")
     }
@@ -87,6 +103,10 @@ impl From<BinaryFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Size => map!(size_bytes),
             #[cfg(feature = "binary_encoding")]
             FromBuffer(dtype, is_little_endian) => map!(from_buffer, &dtype, is_little_endian),
+            #[cfg(feature = "binary_encryption")]
+            Encrypt { algorithm, key } => map!(encrypt, algorithm, &key),
+            #[cfg(feature = "binary_encryption")]
+            Decrypt { algorithm, key } => map!(decrypt, algorithm, &key),
         }
     }
 }
@@ -160,6 +180,26 @@ pub(super) fn from_buffer(
         .map(|val| val.into())
 }
 
+#[cfg(feature = "binary_encryption")]
+pub(super) fn encrypt(
+    s: &Column,
+    algorithm: EncryptionAlgorithm,
+    key: &str,
+) -> PolarsResult<Column> {
+    let ca = s.binary()?;
+    ca.encrypt(algorithm, key).map(|ok| ok.into_column())
+}
+
+#[cfg(feature = "binary_encryption")]
+pub(super) fn decrypt(
+    s: &Column,
+    algorithm: EncryptionAlgorithm,
+    key: &str,
+) -> PolarsResult<Column> {
+    let ca = s.binary()?;
+    ca.decrypt(algorithm, key).map(|ok| ok.into_column())
+}
+
 impl From<BinaryFunction> for FunctionExpr {
     fn from(b: BinaryFunction) -> Self {
         FunctionExpr::BinaryExpr(b)
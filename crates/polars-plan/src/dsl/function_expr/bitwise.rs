@@ -6,7 +6,7 @@ use strum_macros::IntoStaticStr;
 
 use super::{ColumnsUdf, SpecialEq};
 use crate::dsl::FieldsMapper;
-use crate::map;
+use crate::{map, wrap};
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, PartialEq, Debug, Eq, Hash, IntoStaticStr)]
@@ -21,10 +21,10 @@ pub enum BitwiseFunction {
     TrailingOnes,
     TrailingZeros,
 
-    // Bitwise Aggregations
-    And,
-    Or,
-    Xor,
+    // Horizontal reductions
+    AndHorizontal,
+    OrHorizontal,
+    XorHorizontal,
 }
 
 impl fmt::Display for BitwiseFunction {
@@ -39,9 +39,9 @@ impl fmt::Display for BitwiseFunction {
             B::TrailingOnes => "trailing_ones",
             B::TrailingZeros => "trailing_zeros",
 
-            B::And => "and",
-            B::Or => "or",
-            B::Xor => "xor",
+            B::AndHorizontal => "and_horizontal",
+            B::OrHorizontal => "or_horizontal",
+            B::XorHorizontal => "xor_horizontal",
         };
 
         f.write_str(s)
@@ -60,15 +60,28 @@ impl From<BitwiseFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             B::TrailingOnes => map!(trailing_ones),
             B::TrailingZeros => map!(trailing_zeros),
 
-            B::And => map!(reduce_and),
-            B::Or => map!(reduce_or),
-            B::Xor => map!(reduce_xor),
+            B::AndHorizontal => wrap!(and_horizontal),
+            B::OrHorizontal => wrap!(or_horizontal),
+            B::XorHorizontal => wrap!(xor_horizontal),
         }
     }
 }
 
 impl BitwiseFunction {
     pub(super) fn get_field(&self, mapper: FieldsMapper) -> PolarsResult<Field> {
+        if matches!(
+            self,
+            Self::AndHorizontal | Self::OrHorizontal | Self::XorHorizontal
+        ) {
+            let field = mapper.map_to_supertype()?;
+            let dtype = field.dtype();
+            polars_ensure!(
+                dtype.is_bool() || dtype.is_integer() || dtype.is_null(),
+                InvalidOperation: "dtype {} not supported in 'bitwise_{}' operation", dtype, self
+            );
+            return Ok(field);
+        }
+
         mapper.try_map_dtype(|dtype| {
             let is_valid = match dtype {
                 DataType::Boolean => true,
@@ -81,17 +94,7 @@ impl BitwiseFunction {
                 polars_bail!(InvalidOperation: "dtype {} not supported in '{}' operation", dtype, self);
             }
 
-            match self {
-                Self::CountOnes |
-                Self::CountZeros |
-                Self::LeadingOnes |
-                Self::LeadingZeros |
-                Self::TrailingOnes |
-                Self::TrailingZeros => Ok(DataType::UInt32),
-                Self::And |
-                Self::Or |
-                Self::Xor => Ok(dtype.clone()),
-            }
+            Ok(DataType::UInt32)
         })
     }
 }
@@ -120,14 +123,14 @@ fn trailing_zeros(c: &Column) -> PolarsResult<Column> {
     c.try_apply_unary_elementwise(polars_ops::series::trailing_zeros)
 }
 
-fn reduce_and(c: &Column) -> PolarsResult<Column> {
-    c.and_reduce().map(|v| v.into_column(c.name().clone()))
+fn and_horizontal(s: &mut [Column]) -> PolarsResult<Option<Column>> {
+    polars_ops::series::bitwise_and_horizontal(s)
 }
 
-fn reduce_or(c: &Column) -> PolarsResult<Column> {
-    c.or_reduce().map(|v| v.into_column(c.name().clone()))
+fn or_horizontal(s: &mut [Column]) -> PolarsResult<Option<Column>> {
+    polars_ops::series::bitwise_or_horizontal(s)
 }
 
-fn reduce_xor(c: &Column) -> PolarsResult<Column> {
-    c.xor_reduce().map(|v| v.into_column(c.name().clone()))
+fn xor_horizontal(s: &mut [Column]) -> PolarsResult<Option<Column>> {
+    polars_ops::series::bitwise_xor_horizontal(s)
 }
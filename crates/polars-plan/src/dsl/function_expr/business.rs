@@ -6,8 +6,8 @@ use polars_ops::prelude::Roll;
 use serde::{Deserialize, Serialize};
 
 use crate::dsl::SpecialEq;
-use crate::map_as_slice;
 use crate::prelude::ColumnsUdf;
+use crate::{map, map_as_slice};
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, PartialEq, Debug, Eq, Hash)]
@@ -23,6 +23,11 @@ pub enum BusinessFunction {
         holidays: Vec<i32>,
         roll: Roll,
     },
+    #[cfg(feature = "business")]
+    IsBusinessDay {
+        week_mask: [bool; 7],
+        holidays: Vec<i32>,
+    },
 }
 
 impl Display for BusinessFunction {
@@ -33,6 +38,8 @@ impl Display for BusinessFunction {
             &BusinessDayCount { .. } => "business_day_count",
             #[cfg(feature = "business")]
             &AddBusinessDay { .. } => "add_business_days",
+            #[cfg(feature = "business")]
+            &IsBusinessDay { .. } => "is_business_day",
         };
         write!(f, "{s}")
     }
@@ -56,6 +63,13 @@ impl From<BusinessFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             } => {
                 map_as_slice!(add_business_days, week_mask, &holidays, roll)
             },
+            #[cfg(feature = "business")]
+            IsBusinessDay {
+                week_mask,
+                holidays,
+            } => {
+                map!(is_business_day, week_mask, &holidays)
+            },
         }
     }
 }
@@ -95,3 +109,13 @@ pub(super) fn add_business_days(
     )
     .map(Column::from)
 }
+
+#[cfg(feature = "business")]
+pub(super) fn is_business_day(
+    s: &Column,
+    week_mask: [bool; 7],
+    holidays: &[i32],
+) -> PolarsResult<Column> {
+    polars_ops::prelude::is_business_day(s.as_materialized_series(), week_mask, holidays)
+        .map(Column::from)
+}
@@ -131,8 +131,8 @@ pub(super) fn drop_nulls(s: &Column) -> PolarsResult<Column> {
 }
 
 #[cfg(feature = "mode")]
-pub(super) fn mode(s: &Column) -> PolarsResult<Column> {
-    mode::mode(s.as_materialized_series()).map(Column::from)
+pub(super) fn mode(s: &Column, ties: ModeTies) -> PolarsResult<Column> {
+    mode::mode_with_ties(s.as_materialized_series(), ties).map(Column::from)
 }
 
 #[cfg(feature = "moment")]
@@ -163,6 +163,27 @@ pub(super) fn rank(s: &Column, options: RankOptions, seed: Option<u64>) -> Polar
     Ok(s.as_materialized_series().rank(options, seed).into_column())
 }
 
+#[cfg(feature = "rank")]
+pub(super) fn percent_rank(s: &Column, descending: bool) -> PolarsResult<Column> {
+    Ok(s.as_materialized_series()
+        .percent_rank(descending)
+        .into_column())
+}
+
+#[cfg(feature = "rank")]
+pub(super) fn cume_dist(s: &Column, descending: bool) -> PolarsResult<Column> {
+    Ok(s.as_materialized_series()
+        .cume_dist(descending)
+        .into_column())
+}
+
+#[cfg(feature = "rank")]
+pub(super) fn ntile(s: &Column, n: IdxSize, descending: bool) -> PolarsResult<Column> {
+    s.as_materialized_series()
+        .ntile(n, descending)
+        .map(Column::from)
+}
+
 #[cfg(feature = "hist")]
 pub(super) fn hist(
     s: &[Column],
@@ -247,3 +268,36 @@ pub(super) fn extend_constant(s: &[Column]) -> PolarsResult<Column> {
         },
     }
 }
+
+#[cfg(feature = "scatter_gather")]
+pub(super) fn scatter_by_mask(s: &mut [Column]) -> PolarsResult<Column> {
+    let len = s[0].len();
+    let mask = match s[1].len() {
+        1 if len != 1 => s[1].new_from_index(0, len),
+        _ => s[1].clone(),
+    };
+    // Take the input so that numeric columns can be updated in place.
+    let input = std::mem::take(&mut s[0]).take_materialized_series();
+    let values = s[2].as_materialized_series();
+    polars_ops::series::scatter_by_mask(input, mask.bool()?, values).map(Column::from)
+}
+
+#[cfg(feature = "scatter_gather")]
+pub(super) fn gather_by_ranges(s: &[Column]) -> PolarsResult<Column> {
+    polars_ops::series::gather_by_ranges(
+        s[0].as_materialized_series(),
+        s[1].as_materialized_series(),
+        s[2].as_materialized_series(),
+    )
+    .map(Column::from)
+}
+
+pub(super) fn arg_min_by(s: &[Column]) -> PolarsResult<Column> {
+    let idx = polars_ops::series::arg_min_by(s)?.map(|idx| idx as IdxSize);
+    Ok(IdxCa::from_slice_options(s[0].name().clone(), &[idx]).into_column())
+}
+
+pub(super) fn arg_max_by(s: &[Column]) -> PolarsResult<Column> {
+    let idx = polars_ops::series::arg_max_by(s)?.map(|idx| idx as IdxSize);
+    Ok(IdxCa::from_slice_options(s[0].name().clone(), &[idx]).into_column())
+}
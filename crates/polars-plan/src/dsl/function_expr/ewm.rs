@@ -11,3 +11,13 @@ pub(super) fn ewm_std(s: &Column, options: EWMOptions) -> PolarsResult<Column> {
 pub(super) fn ewm_var(s: &Column, options: EWMOptions) -> PolarsResult<Column> {
     polars_ops::prelude::ewm_var(s.as_materialized_series(), options).map(Column::from)
 }
+
+pub(super) fn ewm_cov(s: &[Column], options: EWMOptions) -> PolarsResult<Column> {
+    let (x, y) = (s[0].as_materialized_series(), s[1].as_materialized_series());
+    polars_ops::prelude::ewm_cov(x, y, options).map(Column::from)
+}
+
+pub(super) fn ewm_corr(s: &[Column], options: EWMOptions) -> PolarsResult<Column> {
+    let (x, y) = (s[0].as_materialized_series(), s[1].as_materialized_series());
+    polars_ops::prelude::ewm_corr(x, y, options).map(Column::from)
+}
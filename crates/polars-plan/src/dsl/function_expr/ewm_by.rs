@@ -2,23 +2,48 @@ use polars_ops::series::SeriesMethods;
 
 use super::*;
 
-pub(super) fn ewm_mean_by(s: &[Column], half_life: Duration) -> PolarsResult<Column> {
+/// Validate `half_life` and return it in nanoseconds, along with whether the times are sorted.
+fn prepare_ewm_by(s: &[Column], half_life: Duration) -> PolarsResult<(i64, bool)> {
     let time_zone = match s[1].dtype() {
         DataType::Datetime(_, Some(time_zone)) => Some(time_zone.as_str()),
         _ => None,
     };
     polars_ensure!(!half_life.negative(), InvalidOperation: "half_life cannot be negative");
     ensure_is_constant_duration(half_life, time_zone, "half_life")?;
-    // `half_life` is a constant duration so we can safely use `duration_ns()`.
-    let half_life = half_life.duration_ns();
-    let values = &s[0];
-    let times = &s[1];
-    let times_is_sorted = times
+    let times_is_sorted = s[1]
         .as_materialized_series()
         .is_sorted(Default::default())?;
+    // `half_life` is a constant duration so we can safely use `duration_ns()`.
+    Ok((half_life.duration_ns(), times_is_sorted))
+}
+
+pub(super) fn ewm_mean_by(s: &[Column], half_life: Duration) -> PolarsResult<Column> {
+    let (half_life, times_is_sorted) = prepare_ewm_by(s, half_life)?;
     polars_ops::prelude::ewm_mean_by(
-        values.as_materialized_series(),
-        times.as_materialized_series(),
+        s[0].as_materialized_series(),
+        s[1].as_materialized_series(),
+        half_life,
+        times_is_sorted,
+    )
+    .map(Column::from)
+}
+
+pub(super) fn ewm_std_by(s: &[Column], half_life: Duration) -> PolarsResult<Column> {
+    let (half_life, times_is_sorted) = prepare_ewm_by(s, half_life)?;
+    polars_ops::prelude::ewm_std_by(
+        s[0].as_materialized_series(),
+        s[1].as_materialized_series(),
+        half_life,
+        times_is_sorted,
+    )
+    .map(Column::from)
+}
+
+pub(super) fn ewm_var_by(s: &[Column], half_life: Duration) -> PolarsResult<Column> {
+    let (half_life, times_is_sorted) = prepare_ewm_by(s, half_life)?;
+    polars_ops::prelude::ewm_var_by(
+        s[0].as_materialized_series(),
+        s[1].as_materialized_series(),
         half_life,
         times_is_sorted,
     )
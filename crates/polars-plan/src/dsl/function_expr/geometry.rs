@@ -0,0 +1,80 @@
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::*;
+use crate::{map, map_as_slice};
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, PartialEq, Debug, Eq, Hash)]
+pub enum GeometryFunction {
+    Area,
+    Centroid,
+    Contains,
+    Distance,
+}
+
+impl GeometryFunction {
+    pub(super) fn get_field(&self, mapper: FieldsMapper) -> PolarsResult<Field> {
+        use GeometryFunction::*;
+        match self {
+            Area | Distance => mapper.with_dtype(DataType::Float64),
+            Centroid => mapper.with_dtype(DataType::Geometry),
+            Contains => mapper.with_dtype(DataType::Boolean),
+        }
+    }
+}
+
+impl fmt::Display for GeometryFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use GeometryFunction::*;
+        let s = match self {
+            Area => "area",
+            Centroid => "centroid",
+            Contains => "contains",
+            Distance => "distance",
+        };
+        write!(f, "st.{s}")
+    }
+}
+
+impl From<GeometryFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
+    fn from(func: GeometryFunction) -> Self {
+        use GeometryFunction::*;
+        match func {
+            Area => map!(area),
+            Centroid => map!(centroid),
+            Contains => map_as_slice!(contains),
+            Distance => map_as_slice!(distance),
+        }
+    }
+}
+
+fn area(c: &Column) -> PolarsResult<Column> {
+    polars_ops::series::st_area(c.as_materialized_series()).map(Column::from)
+}
+
+fn centroid(c: &Column) -> PolarsResult<Column> {
+    polars_ops::series::st_centroid(c.as_materialized_series()).map(Column::from)
+}
+
+fn contains(s: &[Column]) -> PolarsResult<Column> {
+    let (a, b) = (&s[0], &s[1]);
+    polars_ensure!(
+        a.len() == b.len() || a.len() == 1 || b.len() == 1,
+        ShapeMismatch: "arguments of `st.contains` should have equal or unit length"
+    );
+    polars_ops::series::st_contains(a.as_materialized_series(), b.as_materialized_series())
+        .map(Column::from)
+}
+
+fn distance(s: &[Column]) -> PolarsResult<Column> {
+    let (a, b) = (&s[0], &s[1]);
+    polars_ensure!(
+        a.len() == b.len() || a.len() == 1 || b.len() == 1,
+        ShapeMismatch: "arguments of `st.distance` should have equal or unit length"
+    );
+    polars_ops::series::st_distance(a.as_materialized_series(), b.as_materialized_series())
+        .map(Column::from)
+}
@@ -0,0 +1,90 @@
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::*;
+use crate::{map, map_as_slice};
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, PartialEq, Debug, Eq, Hash)]
+pub enum IpFunction {
+    Parse { strict: bool },
+    ToString,
+    Version,
+    IsInCidr,
+    Subnet { prefix_len: u8 },
+    ToInteger,
+}
+
+impl IpFunction {
+    pub(super) fn get_field(&self, mapper: FieldsMapper) -> PolarsResult<Field> {
+        use IpFunction::*;
+        match self {
+            Parse { .. } | Subnet { .. } => mapper.with_dtype(DataType::Binary),
+            ToString => mapper.with_dtype(DataType::String),
+            Version => mapper.with_dtype(DataType::UInt8),
+            IsInCidr => mapper.with_dtype(DataType::Boolean),
+            ToInteger => mapper.with_dtype(DataType::UInt32),
+        }
+    }
+}
+
+impl fmt::Display for IpFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use IpFunction::*;
+        let s = match self {
+            Parse { .. } => "parse",
+            ToString => "to_string",
+            Version => "version",
+            IsInCidr => "is_in_cidr",
+            Subnet { .. } => "subnet",
+            ToInteger => "to_integer",
+        };
+        write!(f, "ip.{s}")
+    }
+}
+
+impl From<IpFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
+    fn from(func: IpFunction) -> Self {
+        use IpFunction::*;
+        match func {
+            Parse { strict } => map!(parse, strict),
+            ToString => map!(to_string),
+            Version => map!(version),
+            IsInCidr => map_as_slice!(is_in_cidr),
+            Subnet { prefix_len } => map!(subnet, prefix_len),
+            ToInteger => map!(to_integer),
+        }
+    }
+}
+
+fn parse(c: &Column, strict: bool) -> PolarsResult<Column> {
+    polars_ops::series::ip_parse(c.as_materialized_series(), strict).map(Column::from)
+}
+
+fn to_string(c: &Column) -> PolarsResult<Column> {
+    polars_ops::series::ip_to_string(c.as_materialized_series()).map(Column::from)
+}
+
+fn version(c: &Column) -> PolarsResult<Column> {
+    polars_ops::series::ip_version(c.as_materialized_series()).map(Column::from)
+}
+
+fn is_in_cidr(s: &[Column]) -> PolarsResult<Column> {
+    let (ips, cidr) = (&s[0], &s[1]);
+    polars_ensure!(
+        ips.len() == cidr.len() || ips.len() == 1 || cidr.len() == 1,
+        ShapeMismatch: "arguments of `ip.is_in_cidr` should have equal or unit length"
+    );
+    polars_ops::series::ip_is_in_cidr(ips.as_materialized_series(), cidr.as_materialized_series())
+        .map(Column::from)
+}
+
+fn subnet(c: &Column, prefix_len: u8) -> PolarsResult<Column> {
+    polars_ops::series::ip_subnet(c.as_materialized_series(), prefix_len).map(Column::from)
+}
+
+fn to_integer(c: &Column) -> PolarsResult<Column> {
+    polars_ops::series::ip_to_integer(c.as_materialized_series()).map(Column::from)
+}
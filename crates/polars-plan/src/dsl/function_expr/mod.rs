@@ -1,5 +1,9 @@
 #[cfg(feature = "abs")]
 mod abs;
+#[cfg(feature = "approx_unique")]
+mod approx_n_unique;
+#[cfg(feature = "approx_quantile")]
+mod approx_quantile;
 #[cfg(feature = "arg_where")]
 mod arg_where;
 #[cfg(feature = "dtype-array")]
@@ -34,8 +38,12 @@ mod ewm_by;
 mod fill_null;
 #[cfg(feature = "fused")]
 mod fused;
+#[cfg(feature = "geometry")]
+mod geometry;
 #[cfg(feature = "index_of")]
 mod index_of;
+#[cfg(feature = "ip")]
+mod ip;
 mod list;
 #[cfg(feature = "log")]
 mod log;
@@ -74,6 +82,10 @@ mod temporal;
 #[cfg(feature = "trigonometry")]
 pub mod trigonometry;
 mod unique;
+#[cfg(feature = "url")]
+mod url;
+#[cfg(feature = "weighted_agg")]
+mod weighted;
 
 use std::fmt::{Display, Formatter};
 use std::hash::{Hash, Hasher};
@@ -93,6 +105,10 @@ use schema::FieldsMapper;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "approx_unique")]
+pub use self::approx_n_unique::ApproxNUniqueSketchFunction;
+#[cfg(feature = "approx_quantile")]
+pub use self::approx_quantile::add_to_tdigest;
 pub use self::binary::BinaryFunction;
 #[cfg(feature = "bitwise")]
 pub use self::bitwise::BitwiseFunction;
@@ -103,6 +119,12 @@ pub use self::business::BusinessFunction;
 pub use self::cat::CategoricalFunction;
 #[cfg(feature = "temporal")]
 pub use self::datetime::TemporalFunction;
+#[cfg(feature = "geometry")]
+pub use self::geometry::GeometryFunction;
+#[cfg(feature = "ip")]
+pub use self::ip::IpFunction;
+#[cfg(feature = "ffi_plugin")]
+pub use self::plugin::PluginLibrary;
 pub use self::pow::PowFunction;
 #[cfg(feature = "range")]
 pub(super) use self::range::RangeFunction;
@@ -116,6 +138,10 @@ pub use self::strings::StringFunction;
 pub use self::struct_::StructFunction;
 #[cfg(feature = "trigonometry")]
 pub use self::trigonometry::TrigonometricFunction;
+#[cfg(feature = "url")]
+pub use self::url::UrlFunction;
+#[cfg(feature = "weighted_agg")]
+pub use self::weighted::WeightedAggMethod;
 use super::*;
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -136,6 +162,12 @@ pub enum FunctionExpr {
     TemporalExpr(TemporalFunction),
     #[cfg(feature = "bitwise")]
     Bitwise(BitwiseFunction),
+    #[cfg(feature = "ip")]
+    Ip(IpFunction),
+    #[cfg(feature = "geometry")]
+    Geometry(GeometryFunction),
+    #[cfg(feature = "url")]
+    Url(UrlFunction),
 
     // Other expressions
     Boolean(BooleanFunction),
@@ -154,12 +186,22 @@ pub enum FunctionExpr {
     Pow(PowFunction),
     #[cfg(feature = "row_hash")]
     Hash(u64, u64, u64, u64),
+    #[cfg(feature = "row_hash")]
+    StableHash(u64, u64, HashAlgorithm),
+    ArgMinBy,
+    ArgMaxBy,
     #[cfg(feature = "arg_where")]
     ArgWhere,
     #[cfg(feature = "index_of")]
     IndexOf,
     #[cfg(feature = "search_sorted")]
     SearchSorted(SearchSortedSide),
+    #[cfg(feature = "search_sorted")]
+    SearchSortedBy {
+        side: SearchSortedSide,
+        descending: Vec<bool>,
+        nulls_last: Vec<bool>,
+    },
     #[cfg(feature = "range")]
     Range(RangeFunction),
     #[cfg(feature = "trigonometry")]
@@ -179,7 +221,7 @@ pub enum FunctionExpr {
     DropNans,
     DropNulls,
     #[cfg(feature = "mode")]
-    Mode,
+    Mode(ModeTies),
     #[cfg(feature = "moment")]
     Skew(bool),
     #[cfg(feature = "moment")]
@@ -194,6 +236,19 @@ pub enum FunctionExpr {
         options: RankOptions,
         seed: Option<u64>,
     },
+    #[cfg(feature = "rank")]
+    PercentRank {
+        descending: bool,
+    },
+    #[cfg(feature = "rank")]
+    CumeDist {
+        descending: bool,
+    },
+    #[cfg(feature = "rank")]
+    Ntile {
+        n: IdxSize,
+        descending: bool,
+    },
     Repeat,
     #[cfg(feature = "round_series")]
     Clip {
@@ -242,6 +297,10 @@ pub enum FunctionExpr {
     UniqueCounts,
     #[cfg(feature = "approx_unique")]
     ApproxNUnique,
+    #[cfg(feature = "approx_unique")]
+    ApproxNUniqueSketch(ApproxNUniqueSketchFunction),
+    #[cfg(feature = "approx_quantile")]
+    ApproxQuantile,
     Coalesce,
     ShrinkType,
     #[cfg(feature = "diff")]
@@ -277,7 +336,15 @@ pub enum FunctionExpr {
     #[cfg(feature = "round_series")]
     Floor,
     #[cfg(feature = "round_series")]
+    FloorDecimals {
+        decimals: u32,
+    },
+    #[cfg(feature = "round_series")]
     Ceil,
+    #[cfg(feature = "round_series")]
+    CeilDecimals {
+        decimals: u32,
+    },
     UpperBound,
     LowerBound,
     #[cfg(feature = "fused")]
@@ -287,6 +354,8 @@ pub enum FunctionExpr {
     Correlation {
         method: correlation::CorrelationMethod,
     },
+    #[cfg(feature = "weighted_agg")]
+    WeightedAgg(weighted::WeightedAggMethod),
     #[cfg(feature = "peaks")]
     PeakMin,
     #[cfg(feature = "peaks")]
@@ -350,6 +419,14 @@ pub enum FunctionExpr {
     EwmMeanBy {
         half_life: Duration,
     },
+    #[cfg(feature = "ewma_by")]
+    EwmStdBy {
+        half_life: Duration,
+    },
+    #[cfg(feature = "ewma_by")]
+    EwmVarBy {
+        half_life: Duration,
+    },
     #[cfg(feature = "ewma")]
     EwmStd {
         options: EWMOptions,
@@ -358,6 +435,14 @@ pub enum FunctionExpr {
     EwmVar {
         options: EWMOptions,
     },
+    #[cfg(feature = "ewma")]
+    EwmCov {
+        options: EWMOptions,
+    },
+    #[cfg(feature = "ewma")]
+    EwmCorr {
+        options: EWMOptions,
+    },
     #[cfg(feature = "replace")]
     Replace,
     #[cfg(feature = "replace")]
@@ -371,6 +456,10 @@ pub enum FunctionExpr {
     #[cfg(feature = "reinterpret")]
     Reinterpret(bool),
     ExtendConstant,
+    #[cfg(feature = "scatter_gather")]
+    ScatterByMask,
+    #[cfg(feature = "scatter_gather")]
+    GatherByRanges,
 }
 
 impl Hash for FunctionExpr {
@@ -393,6 +482,12 @@ impl Hash for FunctionExpr {
             TemporalExpr(f) => f.hash(state),
             #[cfg(feature = "bitwise")]
             Bitwise(f) => f.hash(state),
+            #[cfg(feature = "ip")]
+            Ip(f) => f.hash(state),
+            #[cfg(feature = "geometry")]
+            Geometry(f) => f.hash(state),
+            #[cfg(feature = "url")]
+            Url(f) => f.hash(state),
 
             // Other expressions
             Boolean(f) => f.hash(state),
@@ -403,10 +498,22 @@ impl Hash for FunctionExpr {
             IndexOf => {},
             #[cfg(feature = "search_sorted")]
             SearchSorted(f) => f.hash(state),
+            #[cfg(feature = "search_sorted")]
+            SearchSortedBy {
+                side,
+                descending,
+                nulls_last,
+            } => {
+                side.hash(state);
+                descending.hash(state);
+                nulls_last.hash(state);
+            },
             #[cfg(feature = "random")]
             Random { method, .. } => method.hash(state),
             #[cfg(feature = "cov")]
             Correlation { method, .. } => method.hash(state),
+            #[cfg(feature = "weighted_agg")]
+            WeightedAgg(method) => method.hash(state),
             #[cfg(feature = "range")]
             Range(f) => f.hash(state),
             #[cfg(feature = "trigonometry")]
@@ -440,11 +547,12 @@ impl Hash for FunctionExpr {
             | Shift
             | ShiftAndFill => {},
             #[cfg(feature = "mode")]
-            Mode => {},
+            Mode(ties) => ties.hash(state),
             #[cfg(feature = "abs")]
             Abs => {},
             Negate => {},
             NullCount => {},
+            ArgMinBy | ArgMaxBy => {},
             #[cfg(feature = "arg_where")]
             ArgWhere => {},
             #[cfg(feature = "trigonometry")]
@@ -455,6 +563,8 @@ impl Hash for FunctionExpr {
             Sign => {},
             #[cfg(feature = "row_hash")]
             Hash(a, b, c, d) => (a, b, c, d).hash(state),
+            #[cfg(feature = "row_hash")]
+            StableHash(a, b, algorithm) => (a, b, algorithm).hash(state),
             FillNull => {},
             #[cfg(feature = "rolling_window")]
             RollingExpr(f) => {
@@ -477,6 +587,13 @@ impl Hash for FunctionExpr {
                 options.hash(state);
                 seed.hash(state);
             },
+            #[cfg(feature = "rank")]
+            PercentRank { descending } | CumeDist { descending } => descending.hash(state),
+            #[cfg(feature = "rank")]
+            Ntile { n, descending } => {
+                n.hash(state);
+                descending.hash(state);
+            },
             #[cfg(feature = "round_series")]
             Clip { has_min, has_max } => {
                 has_min.hash(state);
@@ -510,6 +627,10 @@ impl Hash for FunctionExpr {
             UniqueCounts => {},
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => {},
+            #[cfg(feature = "approx_unique")]
+            ApproxNUniqueSketch(f) => f.hash(state),
+            #[cfg(feature = "approx_quantile")]
+            ApproxQuantile => {},
             Coalesce => {},
             ShrinkType => {},
             #[cfg(feature = "pct_change")]
@@ -533,7 +654,11 @@ impl Hash for FunctionExpr {
             #[cfg(feature = "round_series")]
             FunctionExpr::Floor => {},
             #[cfg(feature = "round_series")]
+            FloorDecimals { decimals } => decimals.hash(state),
+            #[cfg(feature = "round_series")]
             Ceil => {},
+            #[cfg(feature = "round_series")]
+            CeilDecimals { decimals } => decimals.hash(state),
             UpperBound => {},
             LowerBound => {},
             ConcatExpr(a) => a.hash(state),
@@ -584,10 +709,18 @@ impl Hash for FunctionExpr {
             EwmMean { options } => options.hash(state),
             #[cfg(feature = "ewma_by")]
             EwmMeanBy { half_life } => (half_life).hash(state),
+            #[cfg(feature = "ewma_by")]
+            EwmStdBy { half_life } => (half_life).hash(state),
+            #[cfg(feature = "ewma_by")]
+            EwmVarBy { half_life } => (half_life).hash(state),
             #[cfg(feature = "ewma")]
             EwmStd { options } => options.hash(state),
             #[cfg(feature = "ewma")]
             EwmVar { options } => options.hash(state),
+            #[cfg(feature = "ewma")]
+            EwmCov { options } => options.hash(state),
+            #[cfg(feature = "ewma")]
+            EwmCorr { options } => options.hash(state),
             #[cfg(feature = "hist")]
             Hist {
                 bin_count,
@@ -607,6 +740,8 @@ impl Hash for FunctionExpr {
             #[cfg(feature = "reinterpret")]
             Reinterpret(signed) => signed.hash(state),
             ExtendConstant => {},
+            #[cfg(feature = "scatter_gather")]
+            ScatterByMask | GatherByRanges => {},
             #[cfg(feature = "top_k")]
             TopKBy { descending } => descending.hash(state),
         }
@@ -632,6 +767,12 @@ impl Display for FunctionExpr {
             TemporalExpr(func) => return write!(f, "{func}"),
             #[cfg(feature = "bitwise")]
             Bitwise(func) => return write!(f, "bitwise_{func}"),
+            #[cfg(feature = "ip")]
+            Ip(func) => return write!(f, "{func}"),
+            #[cfg(feature = "geometry")]
+            Geometry(func) => return write!(f, "{func}"),
+            #[cfg(feature = "url")]
+            Url(func) => return write!(f, "{func}"),
 
             // Other expressions
             Boolean(func) => return write!(f, "{func}"),
@@ -644,12 +785,18 @@ impl Display for FunctionExpr {
             Pow(func) => return write!(f, "{func}"),
             #[cfg(feature = "row_hash")]
             Hash(_, _, _, _) => "hash",
+            #[cfg(feature = "row_hash")]
+            StableHash(..) => "stable_hash",
+            ArgMinBy => "arg_min_by",
+            ArgMaxBy => "arg_max_by",
             #[cfg(feature = "arg_where")]
             ArgWhere => "arg_where",
             #[cfg(feature = "index_of")]
             IndexOf => "index_of",
             #[cfg(feature = "search_sorted")]
             SearchSorted(_) => "search_sorted",
+            #[cfg(feature = "search_sorted")]
+            SearchSortedBy { .. } => "search_sorted_by",
             #[cfg(feature = "range")]
             Range(func) => return write!(f, "{func}"),
             #[cfg(feature = "trigonometry")]
@@ -667,7 +814,7 @@ impl Display for FunctionExpr {
             DropNans => "drop_nans",
             DropNulls => "drop_nulls",
             #[cfg(feature = "mode")]
-            Mode => "mode",
+            Mode(_) => "mode",
             #[cfg(feature = "moment")]
             Skew(_) => "skew",
             #[cfg(feature = "moment")]
@@ -676,6 +823,12 @@ impl Display for FunctionExpr {
             Repeat => "repeat",
             #[cfg(feature = "rank")]
             Rank { .. } => "rank",
+            #[cfg(feature = "rank")]
+            PercentRank { .. } => "percent_rank",
+            #[cfg(feature = "rank")]
+            CumeDist { .. } => "cume_dist",
+            #[cfg(feature = "rank")]
+            Ntile { .. } => "ntile",
             #[cfg(feature = "round_series")]
             Clip { has_min, has_max } => match (has_min, has_max) {
                 (true, true) => "clip",
@@ -713,6 +866,10 @@ impl Display for FunctionExpr {
             Reverse => "reverse",
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => "approx_n_unique",
+            #[cfg(feature = "approx_unique")]
+            ApproxNUniqueSketch(func) => return write!(f, "{func}"),
+            #[cfg(feature = "approx_quantile")]
+            ApproxQuantile => "approx_quantile",
             Coalesce => "coalesce",
             ShrinkType => "shrink_dtype",
             #[cfg(feature = "diff")]
@@ -745,7 +902,11 @@ impl Display for FunctionExpr {
             #[cfg(feature = "round_series")]
             Floor => "floor",
             #[cfg(feature = "round_series")]
+            FloorDecimals { .. } => "floor_decimals",
+            #[cfg(feature = "round_series")]
             Ceil => "ceil",
+            #[cfg(feature = "round_series")]
+            CeilDecimals { .. } => "ceil_decimals",
             UpperBound => "upper_bound",
             LowerBound => "lower_bound",
             #[cfg(feature = "fused")]
@@ -753,6 +914,8 @@ impl Display for FunctionExpr {
             ConcatExpr(_) => "concat_expr",
             #[cfg(feature = "cov")]
             Correlation { method, .. } => return Display::fmt(method, f),
+            #[cfg(feature = "weighted_agg")]
+            WeightedAgg(method) => return Display::fmt(method, f),
             #[cfg(feature = "peaks")]
             PeakMin => "peak_min",
             #[cfg(feature = "peaks")]
@@ -785,10 +948,18 @@ impl Display for FunctionExpr {
             EwmMean { .. } => "ewm_mean",
             #[cfg(feature = "ewma_by")]
             EwmMeanBy { .. } => "ewm_mean_by",
+            #[cfg(feature = "ewma_by")]
+            EwmStdBy { .. } => "ewm_std_by",
+            #[cfg(feature = "ewma_by")]
+            EwmVarBy { .. } => "ewm_var_by",
             #[cfg(feature = "ewma")]
             EwmStd { .. } => "ewm_std",
             #[cfg(feature = "ewma")]
             EwmVar { .. } => "ewm_var",
+            #[cfg(feature = "ewma")]
+            EwmCov { .. } => "ewm_cov",
+            #[cfg(feature = "ewma")]
+            EwmCorr { .. } => "ewm_corr",
             #[cfg(feature = "hist")]
             Hist { .. } => "hist",
             #[cfg(feature = "replace")]
@@ -800,6 +971,10 @@ impl Display for FunctionExpr {
             #[cfg(feature = "reinterpret")]
             Reinterpret(_) => "reinterpret",
             ExtendConstant => "extend_constant",
+            #[cfg(feature = "scatter_gather")]
+            ScatterByMask => "scatter_by_mask",
+            #[cfg(feature = "scatter_gather")]
+            GatherByRanges => "gather_by_ranges",
         };
         write!(f, "{s}")
     }
@@ -906,6 +1081,12 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
             TemporalExpr(func) => func.into(),
             #[cfg(feature = "bitwise")]
             Bitwise(func) => func.into(),
+            #[cfg(feature = "ip")]
+            Ip(func) => func.into(),
+            #[cfg(feature = "geometry")]
+            Geometry(func) => func.into(),
+            #[cfg(feature = "url")]
+            Url(func) => func.into(),
 
             // Other expressions
             Boolean(func) => func.into(),
@@ -933,6 +1114,12 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Hash(k0, k1, k2, k3) => {
                 map!(row_hash::row_hash, k0, k1, k2, k3)
             },
+            #[cfg(feature = "row_hash")]
+            StableHash(k0, k1, algorithm) => {
+                map!(row_hash::stable_row_hash, k0, k1, algorithm)
+            },
+            ArgMinBy => map_as_slice!(dispatch::arg_min_by),
+            ArgMaxBy => map_as_slice!(dispatch::arg_max_by),
             #[cfg(feature = "arg_where")]
             ArgWhere => {
                 wrap!(arg_where::arg_where)
@@ -945,6 +1132,19 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
             SearchSorted(side) => {
                 map_as_slice!(search_sorted::search_sorted_impl, side)
             },
+            #[cfg(feature = "search_sorted")]
+            SearchSortedBy {
+                side,
+                descending,
+                nulls_last,
+            } => {
+                map_as_slice!(
+                    search_sorted::search_sorted_by_impl,
+                    side,
+                    &descending,
+                    &nulls_last
+                )
+            },
             #[cfg(feature = "range")]
             Range(func) => func.into(),
 
@@ -975,6 +1175,8 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
                     Quantile(options) => map!(rolling::rolling_quantile, options.clone()),
                     Var(options) => map!(rolling::rolling_var, options.clone()),
                     Std(options) => map!(rolling::rolling_std, options.clone()),
+                    Mode(options) => map!(rolling::rolling_mode, options.clone()),
+                    NUnique(options) => map!(rolling::rolling_n_unique, options.clone()),
                     #[cfg(feature = "moment")]
                     Skew(window_size, bias) => map!(rolling::rolling_skew, window_size, bias),
                     #[cfg(feature = "cov")]
@@ -1030,7 +1232,7 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
                 map_as_slice!(clip::clip, has_min, has_max)
             },
             #[cfg(feature = "mode")]
-            Mode => map!(dispatch::mode),
+            Mode(ties) => map!(dispatch::mode, ties),
             #[cfg(feature = "moment")]
             Skew(bias) => map!(dispatch::skew, bias),
             #[cfg(feature = "moment")]
@@ -1039,6 +1241,12 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Repeat => map_as_slice!(repeat::repeat),
             #[cfg(feature = "rank")]
             Rank { options, seed } => map!(dispatch::rank, options, seed),
+            #[cfg(feature = "rank")]
+            PercentRank { descending } => map!(dispatch::percent_rank, descending),
+            #[cfg(feature = "rank")]
+            CumeDist { descending } => map!(dispatch::cume_dist, descending),
+            #[cfg(feature = "rank")]
+            Ntile { n, descending } => map!(dispatch::ntile, n, descending),
             #[cfg(feature = "dtype-struct")]
             AsStruct => {
                 map_as_slice!(coerce::as_struct)
@@ -1078,6 +1286,10 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Reverse => map!(dispatch::reverse),
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => map!(dispatch::approx_n_unique),
+            #[cfg(feature = "approx_unique")]
+            ApproxNUniqueSketch(func) => func.into(),
+            #[cfg(feature = "approx_quantile")]
+            ApproxQuantile => map_as_slice!(approx_quantile::approx_quantile),
             Coalesce => map_as_slice!(fill_null::coalesce),
             ShrinkType => map_owned!(shrink_type::shrink),
             #[cfg(feature = "diff")]
@@ -1108,7 +1320,11 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
             #[cfg(feature = "round_series")]
             Floor => map!(round::floor),
             #[cfg(feature = "round_series")]
+            FloorDecimals { decimals } => map!(round::floor_decimals, decimals),
+            #[cfg(feature = "round_series")]
             Ceil => map!(round::ceil),
+            #[cfg(feature = "round_series")]
+            CeilDecimals { decimals } => map!(round::ceil_decimals, decimals),
             UpperBound => map!(bounds::upper_bound),
             LowerBound => map!(bounds::lower_bound),
             #[cfg(feature = "fused")]
@@ -1116,6 +1332,8 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
             ConcatExpr(rechunk) => map_as_slice!(concat::concat_expr, rechunk),
             #[cfg(feature = "cov")]
             Correlation { method } => map_as_slice!(correlation::corr, method),
+            #[cfg(feature = "weighted_agg")]
+            WeightedAgg(method) => map_as_slice!(weighted::weighted_agg, method),
             #[cfg(feature = "peaks")]
             PeakMin => map!(peaks::peak_min),
             #[cfg(feature = "peaks")]
@@ -1199,10 +1417,18 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
             EwmMean { options } => map!(ewm::ewm_mean, options),
             #[cfg(feature = "ewma_by")]
             EwmMeanBy { half_life } => map_as_slice!(ewm_by::ewm_mean_by, half_life),
+            #[cfg(feature = "ewma_by")]
+            EwmStdBy { half_life } => map_as_slice!(ewm_by::ewm_std_by, half_life),
+            #[cfg(feature = "ewma_by")]
+            EwmVarBy { half_life } => map_as_slice!(ewm_by::ewm_var_by, half_life),
             #[cfg(feature = "ewma")]
             EwmStd { options } => map!(ewm::ewm_std, options),
             #[cfg(feature = "ewma")]
             EwmVar { options } => map!(ewm::ewm_var, options),
+            #[cfg(feature = "ewma")]
+            EwmCov { options } => map_as_slice!(ewm::ewm_cov, options),
+            #[cfg(feature = "ewma")]
+            EwmCorr { options } => map_as_slice!(ewm::ewm_corr, options),
             #[cfg(feature = "replace")]
             Replace => {
                 map_as_slice!(dispatch::replace)
@@ -1217,6 +1443,10 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
             #[cfg(feature = "reinterpret")]
             Reinterpret(signed) => map!(dispatch::reinterpret, signed),
             ExtendConstant => map_as_slice!(dispatch::extend_constant),
+            #[cfg(feature = "scatter_gather")]
+            ScatterByMask => map_as_slice!(dispatch::scatter_by_mask),
+            #[cfg(feature = "scatter_gather")]
+            GatherByRanges => map_as_slice!(dispatch::gather_by_ranges),
         }
     }
 }
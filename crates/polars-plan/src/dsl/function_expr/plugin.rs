@@ -4,6 +4,7 @@ use std::sync::{LazyLock, RwLock};
 
 use arrow::ffi::{ArrowSchema, import_field_from_c};
 use libloading::Library;
+use polars_ffi::manifest::*;
 
 use super::*;
 
@@ -26,7 +27,9 @@ fn get_lib(lib: &str) -> PolarsResult<&'static PluginAndVersion> {
         let version_function: libloading::Symbol<unsafe extern "C" fn() -> u32> = unsafe {
             library
                 .get("_polars_plugin_get_version".as_bytes())
-                .unwrap()
+                .map_err(|_| {
+                    polars_err!(ComputeError: "the dynamic library '{}' isn't a Polars plugin", lib)
+                })?
         };
 
         let version = unsafe { version_function() };
@@ -203,3 +206,125 @@ fn check_panic(msg: &str) -> PolarsResult<()> {
     polars_ensure!(msg != "PANIC", ComputeError: "the plugin panicked\n\nThe message is suppressed. Set POLARS_VERBOSE=1 to send the panic message to stderr.");
     Ok(())
 }
+
+/// A plugin library that is loaded at runtime, and whose kernels are listed by the manifest that
+/// it exports (see [`polars_ffi::manifest`]).
+///
+/// This makes the expression plugins of Python available to Rust programs, which can call
+/// kernels that are compiled separately from Polars.
+#[derive(Clone, Debug)]
+pub struct PluginLibrary {
+    path: PlSmallStr,
+    kernels: Arc<PlIndexMap<PlSmallStr, u32>>,
+}
+
+impl PluginLibrary {
+    /// Load the plugin library at `path` and read its manifest. A library is only loaded once
+    /// per process.
+    ///
+    /// # Safety
+    /// Loading the library runs its initialization routines, and calling its kernels runs its
+    /// code; it must be a Polars plugin library, built with a compatible version of `polars-ffi`.
+    pub unsafe fn load(path: impl Into<PlSmallStr>) -> PolarsResult<Self> {
+        let path = path.into();
+        let (lib, major, _) = get_lib(&path)?;
+        polars_ensure!(
+            *major == 0,
+            ComputeError: "this Polars engine doesn't support plugin version: {}", major
+        );
+
+        // SAFETY: the caller guarantees that this is a plugin library, which exports the manifest
+        // with this signature.
+        let get_manifest: libloading::Symbol<unsafe extern "C" fn() -> *const PluginManifest> =
+            unsafe { lib.get(MANIFEST_SYMBOL) }.map_err(|_| {
+                polars_err!(ComputeError: "the plugin library '{}' doesn't export a manifest", path)
+            })?;
+        // SAFETY: the manifest is a static of the library, which is never unloaded.
+        let manifest = unsafe { &*get_manifest() };
+        polars_ensure!(
+            manifest.version() == MANIFEST_VERSION,
+            ComputeError: "this Polars engine doesn't support plugin manifest version: {}",
+            manifest.version()
+        );
+
+        // SAFETY: the manifest comes from the loaded library and has the supported version.
+        let kernels = unsafe { manifest.kernels() };
+        let kernels = kernels
+            .iter()
+            .map(|kernel| {
+                // SAFETY: the kernel comes from the manifest of the loaded library.
+                let name = unsafe { kernel.name() }.to_str().map_err(|_| {
+                    polars_err!(
+                        ComputeError: "the plugin library '{}' has a kernel name that isn't UTF-8",
+                        path
+                    )
+                })?;
+                Ok((PlSmallStr::from_str(name), kernel.flags()))
+            })
+            .collect::<PolarsResult<_>>()?;
+        Ok(Self {
+            path,
+            kernels: Arc::new(kernels),
+        })
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The names of the kernels of the library, in the order of its manifest.
+    pub fn kernels(&self) -> impl Iterator<Item = &str> {
+        self.kernels.keys().map(|name| name.as_str())
+    }
+
+    /// Call the kernel `name` with `inputs`.
+    ///
+    /// The `kwargs` are passed to the kernel as they are; kernels of `pyo3-polars` expect a
+    /// pickled dictionary, and kernels without keyword arguments ignore them.
+    pub fn call<E: AsRef<[Expr]>>(
+        &self,
+        name: &str,
+        inputs: E,
+        kwargs: &[u8],
+    ) -> PolarsResult<Expr> {
+        let Some(&kernel_flags) = self.kernels.get(name) else {
+            polars_bail!(
+                ComputeError: "the plugin library '{}' doesn't have a kernel named '{}'",
+                self.path, name
+            );
+        };
+        let is_set = |flag: u32| kernel_flags & flag != 0;
+
+        let mut flags = FunctionFlags::default();
+        flags.set(FunctionFlags::CHANGES_LENGTH, is_set(KERNEL_CHANGES_LENGTH));
+        flags.set(
+            FunctionFlags::PASS_NAME_TO_APPLY,
+            is_set(KERNEL_PASS_NAME_TO_APPLY),
+        );
+        flags.set(FunctionFlags::RETURNS_SCALAR, is_set(KERNEL_RETURNS_SCALAR));
+        flags.set(
+            FunctionFlags::INPUT_WILDCARD_EXPANSION,
+            is_set(KERNEL_INPUT_WILDCARD_EXPANSION),
+        );
+
+        Ok(Expr::Function {
+            input: inputs.as_ref().to_vec(),
+            function: FunctionExpr::FfiPlugin {
+                lib: self.path.clone(),
+                symbol: name.into(),
+                kwargs: kwargs.into(),
+            },
+            options: FunctionOptions {
+                collect_groups: if is_set(KERNEL_ELEMENTWISE) {
+                    ApplyOptions::ElementWise
+                } else {
+                    ApplyOptions::GroupWise
+                },
+                cast_options: is_set(KERNEL_CAST_TO_SUPERTYPE)
+                    .then(CastingRules::cast_to_supertypes),
+                flags,
+                ..Default::default()
+            },
+        })
+    }
+}
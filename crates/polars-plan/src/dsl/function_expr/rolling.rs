@@ -18,6 +18,8 @@ pub enum RollingFunction {
     Quantile(RollingOptionsFixedWindow),
     Var(RollingOptionsFixedWindow),
     Std(RollingOptionsFixedWindow),
+    Mode(RollingOptionsFixedWindow),
+    NUnique(RollingOptionsFixedWindow),
     #[cfg(feature = "moment")]
     Skew(usize, bool),
     #[cfg(feature = "cov")]
@@ -41,6 +43,8 @@ impl Display for RollingFunction {
             Quantile(_) => "rolling_quantile",
             Var(_) => "rolling_var",
             Std(_) => "rolling_std",
+            Mode(_) => "rolling_mode",
+            NUnique(_) => "rolling_n_unique",
             #[cfg(feature = "moment")]
             Skew(..) => "rolling_skew",
             #[cfg(feature = "cov")]
@@ -129,6 +133,21 @@ pub(super) fn rolling_std(s: &Column, options: RollingOptionsFixedWindow) -> Pol
         .map(Column::from)
 }
 
+pub(super) fn rolling_mode(s: &Column, options: RollingOptionsFixedWindow) -> PolarsResult<Column> {
+    s.as_materialized_series()
+        .rolling_mode(&options)
+        .map(Column::from)
+}
+
+pub(super) fn rolling_n_unique(
+    s: &Column,
+    options: RollingOptionsFixedWindow,
+) -> PolarsResult<Column> {
+    s.as_materialized_series()
+        .rolling_n_unique(&options)
+        .map(Column::from)
+}
+
 #[cfg(feature = "moment")]
 pub(super) fn rolling_skew(s: &Column, window_size: usize, bias: bool) -> PolarsResult<Column> {
     // @scalar-opt
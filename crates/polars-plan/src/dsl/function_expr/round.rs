@@ -12,6 +12,14 @@ pub(super) fn floor(c: &Column) -> PolarsResult<Column> {
     c.try_apply_unary_elementwise(Series::floor)
 }
 
+pub(super) fn floor_decimals(c: &Column, decimals: u32) -> PolarsResult<Column> {
+    c.try_apply_unary_elementwise(|s| s.floor_decimals(decimals))
+}
+
 pub(super) fn ceil(c: &Column) -> PolarsResult<Column> {
     c.try_apply_unary_elementwise(Series::ceil)
 }
+
+pub(super) fn ceil_decimals(c: &Column, decimals: u32) -> PolarsResult<Column> {
+    c.try_apply_unary_elementwise(|s| s.ceil_decimals(decimals))
+}
@@ -15,3 +15,12 @@ pub(super) fn row_hash(c: &Column, k0: u64, k1: u64, k2: u64, k3: u64) -> Polars
         .hash(PlSeedableRandomStateQuality::seed_from_u64(seed))
         .into_column())
 }
+
+pub(super) fn stable_row_hash(
+    c: &Column,
+    k0: u64,
+    k1: u64,
+    algorithm: HashAlgorithm,
+) -> PolarsResult<Column> {
+    stable_hash(c.as_materialized_series(), algorithm, k0, k1).map(Column::from)
+}
@@ -28,6 +28,12 @@ impl FunctionExpr {
             TemporalExpr(fun) => fun.get_field(mapper),
             #[cfg(feature = "bitwise")]
             Bitwise(fun) => fun.get_field(mapper),
+            #[cfg(feature = "ip")]
+            Ip(fun) => fun.get_field(mapper),
+            #[cfg(feature = "geometry")]
+            Geometry(fun) => fun.get_field(mapper),
+            #[cfg(feature = "url")]
+            Url(fun) => fun.get_field(mapper),
 
             // Other expressions
             Boolean(func) => func.get_field(mapper),
@@ -35,6 +41,7 @@ impl FunctionExpr {
             Business(func) => match func {
                 BusinessFunction::BusinessDayCount { .. } => mapper.with_dtype(DataType::Int32),
                 BusinessFunction::AddBusinessDay { .. } => mapper.with_same_dtype(),
+                BusinessFunction::IsBusinessDay { .. } => mapper.with_dtype(DataType::Boolean),
             },
             #[cfg(feature = "abs")]
             Abs => mapper.with_same_dtype(),
@@ -47,12 +54,17 @@ impl FunctionExpr {
             Coalesce => mapper.map_to_supertype(),
             #[cfg(feature = "row_hash")]
             Hash(..) => mapper.with_dtype(DataType::UInt64),
+            #[cfg(feature = "row_hash")]
+            StableHash(.., HashAlgorithm::Sha256) => mapper.with_dtype(DataType::Binary),
+            #[cfg(feature = "row_hash")]
+            StableHash(..) => mapper.with_dtype(DataType::UInt64),
+            ArgMinBy | ArgMaxBy => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "arg_where")]
             ArgWhere => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "index_of")]
             IndexOf => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "search_sorted")]
-            SearchSorted(_) => mapper.with_dtype(IDX_DTYPE),
+            SearchSorted(_) | SearchSortedBy { .. } => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "range")]
             Range(func) => func.get_field(mapper),
             #[cfg(feature = "trigonometry")]
@@ -66,9 +78,10 @@ impl FunctionExpr {
             RollingExpr(rolling_func, ..) => {
                 use RollingFunction::*;
                 match rolling_func {
-                    Min(_) | Max(_) => mapper.with_same_dtype(),
+                    Min(_) | Max(_) | Mode(_) => mapper.with_same_dtype(),
                     Mean(_) | Quantile(_) | Var(_) | Std(_) => mapper.map_to_float_dtype(),
                     Sum(_) => mapper.sum_dtype(),
+                    NUnique(_) => mapper.with_dtype(IDX_DTYPE),
                     #[cfg(feature = "cov")]
                     CorrCov {..} => mapper.map_to_float_dtype(),
                     #[cfg(feature = "moment")]
@@ -90,7 +103,7 @@ impl FunctionExpr {
             #[cfg(feature = "round_series")]
             Clip { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "mode")]
-            Mode => mapper.with_same_dtype(),
+            Mode(_) => mapper.with_same_dtype(),
             #[cfg(feature = "moment")]
             Skew(_) => mapper.with_dtype(DataType::Float64),
             #[cfg(feature = "moment")]
@@ -102,6 +115,10 @@ impl FunctionExpr {
                 RankMethod::Average => DataType::Float64,
                 _ => IDX_DTYPE,
             }),
+            #[cfg(feature = "rank")]
+            PercentRank { .. } | CumeDist { .. } => mapper.with_dtype(DataType::Float64),
+            #[cfg(feature = "rank")]
+            Ntile { .. } => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "dtype-struct")]
             AsStruct => Ok(Field::new(
                 fields[0].name().clone(),
@@ -143,6 +160,10 @@ impl FunctionExpr {
             CumMax { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => mapper.with_dtype(IDX_DTYPE),
+            #[cfg(feature = "approx_unique")]
+            ApproxNUniqueSketch(func) => func.get_field(mapper),
+            #[cfg(feature = "approx_quantile")]
+            ApproxQuantile => approx_quantile::approx_quantile_field(mapper),
             #[cfg(feature = "hist")]
             Hist {
                 include_category,
@@ -189,7 +210,9 @@ impl FunctionExpr {
             }),
             #[cfg(feature = "interpolate")]
             Interpolate(method) => match method {
-                InterpolationMethod::Linear => mapper.map_numeric_to_float_dtype(),
+                InterpolationMethod::Linear
+                | InterpolationMethod::Spline
+                | InterpolationMethod::Pchip => mapper.map_numeric_to_float_dtype(),
                 InterpolationMethod::Nearest => mapper.with_same_dtype(),
             },
             #[cfg(feature = "interpolate_by")]
@@ -221,13 +244,20 @@ impl FunctionExpr {
             Entropy { .. } | Log { .. } | Log1p | Exp => mapper.map_to_float_dtype(),
             Unique(_) => mapper.with_same_dtype(),
             #[cfg(feature = "round_series")]
-            Round { .. } | RoundSF { .. } | Floor | Ceil => mapper.with_same_dtype(),
+            Round { .. }
+            | RoundSF { .. }
+            | Floor
+            | FloorDecimals { .. }
+            | Ceil
+            | CeilDecimals { .. } => mapper.with_same_dtype(),
             UpperBound | LowerBound => mapper.with_same_dtype(),
             #[cfg(feature = "fused")]
             Fused(_) => mapper.map_to_supertype(),
             ConcatExpr(_) => mapper.map_to_supertype(),
             #[cfg(feature = "cov")]
             Correlation { .. } => mapper.map_to_float_dtype(),
+            #[cfg(feature = "weighted_agg")]
+            WeightedAgg(_) => mapper.with_dtype(DataType::Float64),
             #[cfg(feature = "peaks")]
             PeakMin => mapper.with_same_dtype(),
             #[cfg(feature = "peaks")]
@@ -353,11 +383,18 @@ impl FunctionExpr {
             #[cfg(feature = "ewma")]
             EwmMean { .. } => mapper.map_to_float_dtype(),
             #[cfg(feature = "ewma_by")]
-            EwmMeanBy { .. } => mapper.map_to_float_dtype(),
+            EwmMeanBy { .. } | EwmStdBy { .. } | EwmVarBy { .. } => mapper.map_to_float_dtype(),
             #[cfg(feature = "ewma")]
             EwmStd { .. } => mapper.map_to_float_dtype(),
             #[cfg(feature = "ewma")]
             EwmVar { .. } => mapper.map_to_float_dtype(),
+            #[cfg(feature = "ewma")]
+            EwmCov { .. } | EwmCorr { .. } => mapper.try_map_dtypes(|dtypes| {
+                Ok(match dtypes {
+                    [DataType::Float32, DataType::Float32] => DataType::Float32,
+                    _ => DataType::Float64,
+                })
+            }),
             #[cfg(feature = "replace")]
             Replace => mapper.with_same_dtype(),
             #[cfg(feature = "replace")]
@@ -374,6 +411,8 @@ impl FunctionExpr {
                 mapper.with_dtype(dt)
             },
             ExtendConstant => mapper.with_same_dtype(),
+            #[cfg(feature = "scatter_gather")]
+            ScatterByMask | GatherByRanges => mapper.with_same_dtype(),
         }
     }
 
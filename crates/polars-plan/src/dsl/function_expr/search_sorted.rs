@@ -12,3 +12,14 @@ pub(super) fn search_sorted_impl(s: &mut [Column], side: SearchSortedSide) -> Po
     )
     .map(|ca| ca.into_column())
 }
+
+pub(super) fn search_sorted_by_impl(
+    s: &mut [Column],
+    side: SearchSortedSide,
+    descending: &[bool],
+    nulls_last: &[bool],
+) -> PolarsResult<Column> {
+    let (by, search_values) = s.split_at(descending.len());
+    polars_ops::series::search_sorted_by(by, search_values, side, descending, nulls_last)
+        .map(|ca| ca.into_column())
+}
@@ -63,6 +63,8 @@ pub enum StringFunction {
     },
     #[cfg(feature = "extract_jsonpath")]
     JsonPathMatch,
+    #[cfg(feature = "extract_jsonpath")]
+    JsonExtractAll,
     #[cfg(feature = "regex")]
     Replace {
         // negative is replace all
@@ -76,6 +78,24 @@ pub enum StringFunction {
     },
     #[cfg(feature = "string_reverse")]
     Reverse,
+    #[cfg(feature = "string_phonetic")]
+    Soundex {
+        ascii_fold: bool,
+    },
+    #[cfg(feature = "string_phonetic")]
+    Metaphone {
+        ascii_fold: bool,
+    },
+    #[cfg(feature = "string_similarity")]
+    Levenshtein {
+        normalized: bool,
+    },
+    #[cfg(feature = "string_similarity")]
+    Hamming {
+        normalized: bool,
+    },
+    #[cfg(feature = "string_similarity")]
+    JaroWinkler,
     #[cfg(feature = "string_pad")]
     PadStart {
         length: usize,
@@ -115,6 +135,11 @@ pub enum StringFunction {
     Split(bool),
     #[cfg(feature = "dtype-decimal")]
     ToDecimal(usize),
+    #[cfg(feature = "dtype-duration")]
+    ToDuration {
+        time_unit: TimeUnit,
+        strict: bool,
+    },
     #[cfg(feature = "nightly")]
     Titlecase,
     Uppercase,
@@ -164,6 +189,8 @@ impl StringFunction {
             JsonDecode { dtype, .. } => mapper.with_opt_dtype(dtype.clone()),
             #[cfg(feature = "extract_jsonpath")]
             JsonPathMatch => mapper.with_dtype(DataType::String),
+            #[cfg(feature = "extract_jsonpath")]
+            JsonExtractAll => mapper.with_dtype(DataType::List(Box::new(DataType::String))),
             LenBytes => mapper.with_dtype(DataType::UInt32),
             LenChars => mapper.with_dtype(DataType::UInt32),
             #[cfg(feature = "regex")]
@@ -172,6 +199,18 @@ impl StringFunction {
             Normalize { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "string_reverse")]
             Reverse => mapper.with_same_dtype(),
+            #[cfg(feature = "string_phonetic")]
+            Soundex { .. } | Metaphone { .. } => mapper.with_same_dtype(),
+            #[cfg(feature = "string_similarity")]
+            Levenshtein { normalized } | Hamming { normalized } => {
+                if *normalized {
+                    mapper.with_dtype(DataType::Float64)
+                } else {
+                    mapper.with_dtype(DataType::UInt32)
+                }
+            },
+            #[cfg(feature = "string_similarity")]
+            JaroWinkler => mapper.with_dtype(DataType::Float64),
             #[cfg(feature = "temporal")]
             Strptime(dtype, _) => mapper.with_dtype(dtype.clone()),
             Split(_) => mapper.with_dtype(DataType::List(Box::new(DataType::String))),
@@ -179,6 +218,8 @@ impl StringFunction {
             Titlecase => mapper.with_same_dtype(),
             #[cfg(feature = "dtype-decimal")]
             ToDecimal(_) => mapper.with_dtype(DataType::Decimal(None, None)),
+            #[cfg(feature = "dtype-duration")]
+            ToDuration { time_unit, .. } => mapper.with_dtype(DataType::Duration(*time_unit)),
             #[cfg(feature = "string_encoding")]
             HexEncode => mapper.with_same_dtype(),
             #[cfg(feature = "binary_encoding")]
@@ -243,6 +284,8 @@ impl Display for StringFunction {
             JsonDecode { .. } => "json_decode",
             #[cfg(feature = "extract_jsonpath")]
             JsonPathMatch => "json_path_match",
+            #[cfg(feature = "extract_jsonpath")]
+            JsonExtractAll => "json_extract_all",
             LenBytes => "len_bytes",
             Lowercase => "lowercase",
             LenChars => "len_chars",
@@ -256,6 +299,20 @@ impl Display for StringFunction {
             Normalize { .. } => "normalize",
             #[cfg(feature = "string_reverse")]
             Reverse => "reverse",
+            #[cfg(feature = "string_phonetic")]
+            Soundex { .. } => "soundex",
+            #[cfg(feature = "string_phonetic")]
+            Metaphone { .. } => "metaphone",
+            #[cfg(feature = "string_similarity")]
+            Levenshtein { normalized: false } => "levenshtein",
+            #[cfg(feature = "string_similarity")]
+            Levenshtein { normalized: true } => "levenshtein_similarity",
+            #[cfg(feature = "string_similarity")]
+            Hamming { normalized: false } => "hamming",
+            #[cfg(feature = "string_similarity")]
+            Hamming { normalized: true } => "hamming_similarity",
+            #[cfg(feature = "string_similarity")]
+            JaroWinkler => "jaro_winkler",
             #[cfg(feature = "string_encoding")]
             HexEncode => "hex_encode",
             #[cfg(feature = "binary_encoding")]
@@ -294,6 +351,8 @@ impl Display for StringFunction {
             Titlecase => "titlecase",
             #[cfg(feature = "dtype-decimal")]
             ToDecimal(_) => "to_decimal",
+            #[cfg(feature = "dtype-duration")]
+            ToDuration { .. } => "to_duration",
             Uppercase => "uppercase",
             #[cfg(feature = "string_pad")]
             ZFill => "zfill",
@@ -374,6 +433,16 @@ impl From<StringFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Normalize { form } => map!(strings::normalize, form.clone()),
             #[cfg(feature = "string_reverse")]
             Reverse => map!(strings::reverse),
+            #[cfg(feature = "string_phonetic")]
+            Soundex { ascii_fold } => map!(strings::soundex, ascii_fold),
+            #[cfg(feature = "string_phonetic")]
+            Metaphone { ascii_fold } => map!(strings::metaphone, ascii_fold),
+            #[cfg(feature = "string_similarity")]
+            Levenshtein { normalized } => map_as_slice!(strings::levenshtein, normalized),
+            #[cfg(feature = "string_similarity")]
+            Hamming { normalized } => map_as_slice!(strings::hamming, normalized),
+            #[cfg(feature = "string_similarity")]
+            JaroWinkler => map_as_slice!(strings::jaro_winkler),
             Uppercase => map!(uppercase),
             Lowercase => map!(lowercase),
             #[cfg(feature = "nightly")]
@@ -398,6 +467,8 @@ impl From<StringFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Base64Decode(strict) => map!(strings::base64_decode, strict),
             #[cfg(feature = "dtype-decimal")]
             ToDecimal(infer_len) => map!(strings::to_decimal, infer_len),
+            #[cfg(feature = "dtype-duration")]
+            ToDuration { time_unit, strict } => map!(strings::to_duration, time_unit, strict),
             #[cfg(feature = "extract_jsonpath")]
             JsonDecode {
                 dtype,
@@ -405,6 +476,8 @@ impl From<StringFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             } => map!(strings::json_decode, dtype.clone(), infer_schema_len),
             #[cfg(feature = "extract_jsonpath")]
             JsonPathMatch => map_as_slice!(strings::json_path_match),
+            #[cfg(feature = "extract_jsonpath")]
+            JsonExtractAll => map_as_slice!(strings::json_extract_all),
             #[cfg(feature = "find_many")]
             ContainsAny {
                 ascii_case_insensitive,
@@ -784,6 +857,17 @@ fn to_datetime(
     Ok(out.into_column())
 }
 
+#[cfg(feature = "dtype-duration")]
+pub(super) fn to_duration(s: &Column, time_unit: TimeUnit, strict: bool) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    let out = ca.as_duration(time_unit).into_column();
+
+    if strict && ca.null_count() != out.null_count() {
+        handle_casting_failures(s.as_materialized_series(), out.as_materialized_series())?;
+    }
+    Ok(out)
+}
+
 #[cfg(feature = "dtype-time")]
 fn to_time(s: &Column, options: &StrptimeOptions) -> PolarsResult<Column> {
     polars_ensure!(
@@ -1006,6 +1090,50 @@ pub(super) fn normalize(s: &Column, form: UnicodeForm) -> PolarsResult<Column> {
     Ok(ca.str_normalize(form).into_column())
 }
 
+#[cfg(feature = "string_phonetic")]
+pub(super) fn soundex(s: &Column, ascii_fold: bool) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    Ok(ca.str_soundex(ascii_fold).into_column())
+}
+
+#[cfg(feature = "string_phonetic")]
+pub(super) fn metaphone(s: &Column, ascii_fold: bool) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    Ok(ca.str_metaphone(ascii_fold).into_column())
+}
+
+#[cfg(feature = "string_similarity")]
+pub(super) fn levenshtein(s: &[Column], normalized: bool) -> PolarsResult<Column> {
+    _check_same_length(s, "levenshtein")?;
+    let ca = s[0].str()?;
+    let other = s[1].str()?;
+    Ok(if normalized {
+        ca.str_levenshtein_similarity(other).into_column()
+    } else {
+        ca.str_levenshtein(other).into_column()
+    })
+}
+
+#[cfg(feature = "string_similarity")]
+pub(super) fn hamming(s: &[Column], normalized: bool) -> PolarsResult<Column> {
+    _check_same_length(s, "hamming")?;
+    let ca = s[0].str()?;
+    let other = s[1].str()?;
+    Ok(if normalized {
+        ca.str_hamming_similarity(other).into_column()
+    } else {
+        ca.str_hamming(other).into_column()
+    })
+}
+
+#[cfg(feature = "string_similarity")]
+pub(super) fn jaro_winkler(s: &[Column]) -> PolarsResult<Column> {
+    _check_same_length(s, "jaro_winkler")?;
+    let ca = s[0].str()?;
+    let other = s[1].str()?;
+    Ok(ca.str_jaro_winkler(other).into_column())
+}
+
 #[cfg(feature = "string_reverse")]
 pub(super) fn reverse(s: &Column) -> PolarsResult<Column> {
     let ca = s.str()?;
@@ -1107,6 +1235,14 @@ pub(super) fn json_path_match(s: &[Column]) -> PolarsResult<Column> {
     Ok(ca.json_path_match(pat)?.into_column())
 }
 
+#[cfg(feature = "extract_jsonpath")]
+pub(super) fn json_extract_all(s: &[Column]) -> PolarsResult<Column> {
+    _check_same_length(s, "json_extract_all")?;
+    let ca = s[0].str()?;
+    let pat = s[1].str()?;
+    Ok(ca.json_extract_all(pat)?.into_column())
+}
+
 #[cfg(feature = "regex")]
 pub(super) fn escape_regex(s: &Column) -> PolarsResult<Column> {
     let ca = s.str()?;
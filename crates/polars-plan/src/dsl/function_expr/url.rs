@@ -0,0 +1,63 @@
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::*;
+use crate::map;
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub enum UrlFunction {
+    Host,
+    Path,
+    QueryParam(PlSmallStr),
+    Decode,
+}
+
+impl UrlFunction {
+    pub(super) fn get_field(&self, mapper: FieldsMapper) -> PolarsResult<Field> {
+        mapper.with_dtype(DataType::String)
+    }
+}
+
+impl fmt::Display for UrlFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use UrlFunction::*;
+        let s = match self {
+            Host => "host",
+            Path => "path",
+            QueryParam(_) => "query_param",
+            Decode => "decode",
+        };
+        write!(f, "url.{s}")
+    }
+}
+
+impl From<UrlFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
+    fn from(func: UrlFunction) -> Self {
+        use UrlFunction::*;
+        match func {
+            Host => map!(host),
+            Path => map!(path),
+            QueryParam(name) => map!(query_param, &name),
+            Decode => map!(decode),
+        }
+    }
+}
+
+fn host(c: &Column) -> PolarsResult<Column> {
+    Ok(polars_ops::chunked_array::strings::url_host(c.str()?).into_column())
+}
+
+fn path(c: &Column) -> PolarsResult<Column> {
+    Ok(polars_ops::chunked_array::strings::url_path(c.str()?).into_column())
+}
+
+fn query_param(c: &Column, name: &str) -> PolarsResult<Column> {
+    Ok(polars_ops::chunked_array::strings::url_query_param(c.str()?, name).into_column())
+}
+
+fn decode(c: &Column) -> PolarsResult<Column> {
+    Ok(polars_ops::chunked_array::strings::url_decode(c.str()?).into_column())
+}
@@ -0,0 +1,47 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, PartialEq, Debug, Hash)]
+pub enum WeightedAggMethod {
+    Mean,
+    Std(u8),
+    Quantile,
+}
+
+impl Display for WeightedAggMethod {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use WeightedAggMethod::*;
+        let s = match self {
+            Mean => "weighted_mean",
+            Std(_) => "weighted_std",
+            Quantile => "weighted_quantile",
+        };
+        write!(f, "{s}")
+    }
+}
+
+fn get_quantile(quantile: &Column) -> PolarsResult<f64> {
+    polars_ensure!(
+        quantile.len() == 1,
+        ComputeError: "polars only supports computing a single quantile; \
+        make sure the 'quantile' expression input produces a single quantile"
+    );
+    let quantile: Option<f64> = quantile.get(0)?.extract();
+    quantile.ok_or_else(|| polars_err!(ComputeError: "quantile should not be null"))
+}
+
+pub(super) fn weighted_agg(s: &[Column], method: WeightedAggMethod) -> PolarsResult<Column> {
+    let values = s[0].as_materialized_series();
+    let weights = s[1].as_materialized_series();
+
+    use polars_ops::series::{weighted_mean, weighted_quantile, weighted_std};
+    let out = match method {
+        WeightedAggMethod::Mean => weighted_mean(values, weights)?,
+        WeightedAggMethod::Std(ddof) => weighted_std(values, weights, ddof)?,
+        WeightedAggMethod::Quantile => weighted_quantile(values, weights, get_quantile(&s[2])?)?,
+    };
+    Ok(Column::new(values.name().clone(), &[out]))
+}
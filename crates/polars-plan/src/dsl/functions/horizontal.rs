@@ -308,6 +308,41 @@ pub fn mean_horizontal<E: AsRef<[Expr]>>(exprs: E, ignore_nulls: bool) -> Polars
     })
 }
 
+#[cfg(feature = "bitwise")]
+fn bitwise_horizontal<E: AsRef<[Expr]>>(exprs: E, function: BitwiseFunction) -> PolarsResult<Expr> {
+    let exprs = exprs.as_ref().to_vec();
+    polars_ensure!(!exprs.is_empty(), ComputeError: "cannot return empty fold because the number of output rows is unknown");
+
+    Ok(Expr::Function {
+        input: exprs,
+        function: FunctionExpr::Bitwise(function),
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::ElementWise,
+            flags: FunctionFlags::default()
+                | FunctionFlags::INPUT_WILDCARD_EXPANSION & !FunctionFlags::RETURNS_SCALAR,
+            ..Default::default()
+        },
+    })
+}
+
+/// Compute the bitwise AND of all values horizontally across columns, skipping nulls.
+#[cfg(feature = "bitwise")]
+pub fn bitwise_and_horizontal<E: AsRef<[Expr]>>(exprs: E) -> PolarsResult<Expr> {
+    bitwise_horizontal(exprs, BitwiseFunction::AndHorizontal)
+}
+
+/// Compute the bitwise OR of all values horizontally across columns, skipping nulls.
+#[cfg(feature = "bitwise")]
+pub fn bitwise_or_horizontal<E: AsRef<[Expr]>>(exprs: E) -> PolarsResult<Expr> {
+    bitwise_horizontal(exprs, BitwiseFunction::OrHorizontal)
+}
+
+/// Compute the bitwise XOR of all values horizontally across columns, skipping nulls.
+#[cfg(feature = "bitwise")]
+pub fn bitwise_xor_horizontal<E: AsRef<[Expr]>>(exprs: E) -> PolarsResult<Expr> {
+    bitwise_horizontal(exprs, BitwiseFunction::XorHorizontal)
+}
+
 /// Folds the expressions from left to right keeping the first non-null values.
 ///
 /// It is an error to provide an empty `exprs`.
@@ -1,3 +1,6 @@
+#[cfg(feature = "search_sorted")]
+use polars_core::prelude::sort::_broadcast_bools;
+
 use super::*;
 
 /// Find the indexes that would sort these series in order of appearance.
@@ -14,6 +17,68 @@ pub fn arg_sort_by<E: AsRef<[Expr]>>(by: E, sort_options: SortMultipleOptions) -
         .alias(name)
 }
 
+/// Get the index of the row that comes first when ordering lexicographically by `by`.
+///
+/// Nulls are ordered last and rows in which every value is null are ignored.
+pub fn arg_min_by<E: AsRef<[Expr]>>(by: E) -> Expr {
+    arg_min_max_by(by, FunctionExpr::ArgMinBy)
+}
+
+/// Get the index of the row that comes last when ordering lexicographically by `by`.
+///
+/// Nulls are ordered last and rows in which every value is null are ignored.
+pub fn arg_max_by<E: AsRef<[Expr]>>(by: E) -> Expr {
+    arg_min_max_by(by, FunctionExpr::ArgMaxBy)
+}
+
+fn arg_min_max_by<E: AsRef<[Expr]>>(by: E, function: FunctionExpr) -> Expr {
+    Expr::Function {
+        input: by.as_ref().to_vec(),
+        function,
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::GroupWise,
+            flags: FunctionFlags::default()
+                | FunctionFlags::RETURNS_SCALAR
+                | FunctionFlags::INPUT_WILDCARD_EXPANSION,
+            ..Default::default()
+        },
+    }
+}
+
+/// Find the indices where the rows of `element` should be inserted into the rows of `by` to
+/// maintain order.
+///
+/// The rows of `by` must be sorted lexicographically, with every column ordered according to
+/// `descending` and `nulls_last`. A single value for either is used for all columns.
+#[cfg(feature = "search_sorted")]
+pub fn search_sorted_by<E: AsRef<[Expr]>, V: AsRef<[Expr]>>(
+    by: E,
+    element: V,
+    side: SearchSortedSide,
+    mut descending: Vec<bool>,
+    mut nulls_last: Vec<bool>,
+) -> Expr {
+    let by = by.as_ref();
+    _broadcast_bools(by.len(), &mut descending);
+    _broadcast_bools(by.len(), &mut nulls_last);
+
+    let mut input = by.to_vec();
+    input.extend_from_slice(element.as_ref());
+    Expr::Function {
+        input,
+        function: FunctionExpr::SearchSortedBy {
+            side,
+            descending,
+            nulls_last,
+        },
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::GroupWise,
+            flags: FunctionFlags::default() | FunctionFlags::RETURNS_SCALAR,
+            ..Default::default()
+        },
+    }
+}
+
 #[cfg(feature = "arg_where")]
 /// Get the indices where `condition` evaluates `true`.
 pub fn arg_where<E: Into<Expr>>(condition: E) -> Expr {
@@ -10,7 +10,6 @@ mod concat;
 #[cfg(feature = "cov")]
 mod correlation;
 pub(crate) mod horizontal;
-#[cfg(any(feature = "range", feature = "arg_where"))]
 mod index;
 #[cfg(feature = "range")]
 mod range;
@@ -29,8 +28,9 @@ pub use concat::*;
 #[cfg(feature = "cov")]
 pub use correlation::*;
 pub use horizontal::*;
-#[cfg(any(feature = "range", feature = "arg_where"))]
 pub use index::*;
+// These shadow the kernels of the same name, which are glob imported from `polars_ops`.
+pub use index::{arg_max_by, arg_min_by};
 #[cfg(feature = "dtype-struct")]
 use polars_core::utils::get_supertype;
 #[cfg(all(feature = "range", feature = "temporal"))]
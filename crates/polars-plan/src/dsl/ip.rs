@@ -0,0 +1,49 @@
+use super::*;
+
+/// Specialized expressions for IP addresses.
+///
+/// IP addresses are represented as 16 byte [`DataType::Binary`] values, where IPv4 addresses
+/// are mapped to IPv6 addresses. Besides such values, the expressions accept strings, which are
+/// parsed, and unsigned integers, which are read as IPv4 addresses.
+pub struct IpNameSpace(pub(crate) Expr);
+
+impl IpNameSpace {
+    /// Parse IPv4 and IPv6 addresses. If not `strict`, invalid addresses are set to null instead
+    /// of raising an error.
+    pub fn parse(self, strict: bool) -> Expr {
+        self.0
+            .map_private(FunctionExpr::Ip(IpFunction::Parse { strict }))
+    }
+
+    /// Format the IP addresses in their canonical text representation.
+    pub fn to_string(self) -> Expr {
+        self.0.map_private(FunctionExpr::Ip(IpFunction::ToString))
+    }
+
+    /// Get the version of the IP addresses, 4 or 6.
+    pub fn version(self) -> Expr {
+        self.0.map_private(FunctionExpr::Ip(IpFunction::Version))
+    }
+
+    /// Check whether the IP addresses are in a CIDR block such as `10.0.0.0/8`.
+    pub fn is_in_cidr(self, cidr: Expr) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::Ip(IpFunction::IsInCidr),
+            &[cidr],
+            false,
+            Some(Default::default()),
+        )
+    }
+
+    /// Get the network address of the subnet with a prefix of `prefix_len` bits. For IPv4
+    /// addresses, prefix lengths above 32 leave the address unchanged.
+    pub fn subnet(self, prefix_len: u8) -> Expr {
+        self.0
+            .map_private(FunctionExpr::Ip(IpFunction::Subnet { prefix_len }))
+    }
+
+    /// Convert IPv4 addresses to unsigned 32-bit integers. IPv6 addresses are set to null.
+    pub fn to_integer(self) -> Expr {
+        self.0.map_private(FunctionExpr::Ip(IpFunction::ToInteger))
+    }
+}
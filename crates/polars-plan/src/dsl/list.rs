@@ -342,11 +342,19 @@ impl ListNameSpace {
 
     /// Return the SET UNION between both list arrays.
     #[cfg(feature = "list_sets")]
-    pub fn union<E: Into<Expr>>(self, other: E) -> Expr {
+    pub fn set_union<E: Into<Expr>>(self, other: E) -> Expr {
         let other = other.into();
         self.set_operation(other, SetOperation::Union)
     }
 
+    /// Return the SET UNION between both list arrays.
+    ///
+    /// Alias for [`ListNameSpace::set_union`].
+    #[cfg(feature = "list_sets")]
+    pub fn union<E: Into<Expr>>(self, other: E) -> Expr {
+        self.set_union(other)
+    }
+
     /// Return the SET DIFFERENCE between both list arrays.
     #[cfg(feature = "list_sets")]
     pub fn set_difference<E: Into<Expr>>(self, other: E) -> Expr {
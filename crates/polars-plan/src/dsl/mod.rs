@@ -25,6 +25,8 @@ mod format;
 mod from;
 pub mod function_expr;
 pub mod functions;
+#[cfg(feature = "ip")]
+pub mod ip;
 mod list;
 #[cfg(feature = "meta")]
 mod meta;
@@ -34,14 +36,19 @@ mod options;
 pub mod python_dsl;
 #[cfg(feature = "random")]
 mod random;
+mod registered_udf;
 mod scan_sources;
 mod selector;
+#[cfg(feature = "geometry")]
+pub mod st;
 mod statistics;
 #[cfg(feature = "strings")]
 pub mod string;
 #[cfg(feature = "dtype-struct")]
 mod struct_;
 pub mod udf;
+#[cfg(feature = "url")]
+pub mod url;
 
 use std::fmt::Debug;
 use std::sync::Arc;
@@ -70,6 +77,13 @@ use polars_core::series::ops::NullBehavior;
 #[cfg(any(feature = "search_sorted", feature = "is_between"))]
 use polars_core::utils::SuperTypeFlags;
 use polars_core::utils::{SuperTypeOptions, try_get_supertype};
+#[cfg(feature = "rolling_window")]
+pub use registered_udf::rolling_registered_udf;
+pub use registered_udf::{
+    REGISTERED_UDF_MAGIC_BYTE_MARK, RustUdfFn, UdfKind, call_registered_udf, register_udf,
+    unregister_udf,
+};
+pub(crate) use registered_udf::{RegisteredUdfExpression, RegisteredUdfOutput};
 pub use selector::Selector;
 #[cfg(feature = "dtype-struct")]
 pub use struct_::*;
@@ -79,7 +93,7 @@ pub use file_scan::*;
 pub use scan_sources::{ScanSource, ScanSourceIter, ScanSourceRef, ScanSources};
 
 use crate::constants::MAP_LIST_NAME;
-pub use crate::plans::lit;
+pub use crate::plans::{lit, placeholder};
 use crate::prelude::*;
 
 impl Expr {
@@ -365,6 +379,18 @@ impl Expr {
         )
     }
 
+    /// Get the value of this expression at the row where `by` is the smallest, comparing the
+    /// columns of `by` lexicographically.
+    pub fn min_by<E: AsRef<[Expr]>>(self, by: E) -> Self {
+        self.get(functions::arg_min_by(by))
+    }
+
+    /// Get the value of this expression at the row where `by` is the largest, comparing the
+    /// columns of `by` lexicographically.
+    pub fn max_by<E: AsRef<[Expr]>>(self, by: E) -> Self {
+        self.get(functions::arg_max_by(by))
+    }
+
     /// Get the index values that would sort this expression.
     pub fn arg_sort(self, sort_options: SortOptions) -> Self {
         let options = FunctionOptions {
@@ -912,6 +938,13 @@ impl Expr {
         self.map_private(FunctionExpr::Floor)
     }
 
+    /// Floor underlying floating point array to the lowest value with `decimals` digits smaller or
+    /// equal to the float value.
+    #[cfg(feature = "round_series")]
+    pub fn floor_decimals(self, decimals: u32) -> Self {
+        self.map_private(FunctionExpr::FloorDecimals { decimals })
+    }
+
     /// Constant Pi
     #[cfg(feature = "round_series")]
     pub fn pi() -> Self {
@@ -924,6 +957,13 @@ impl Expr {
         self.map_private(FunctionExpr::Ceil)
     }
 
+    /// Ceil underlying floating point array to the highest value with `decimals` digits larger or
+    /// equal to the float value.
+    #[cfg(feature = "round_series")]
+    pub fn ceil_decimals(self, decimals: u32) -> Self {
+        self.map_private(FunctionExpr::CeilDecimals { decimals })
+    }
+
     /// Clip underlying values to a set boundary.
     #[cfg(feature = "round_series")]
     pub fn clip(self, min: Expr, max: Expr) -> Self {
@@ -1154,6 +1194,84 @@ impl Expr {
             })
     }
 
+    /// Get the HyperLogLog sketch [`Expr::approx_n_unique`] estimates the count of unique values
+    /// from, as a binary value. Sketches of different columns or datasets can be merged.
+    #[cfg(feature = "approx_unique")]
+    pub fn approx_n_unique_sketch(self) -> Self {
+        self.apply_sketch_function(ApproxNUniqueSketchFunction::Build)
+    }
+
+    /// Merge the sketches of [`Expr::approx_n_unique_sketch`] into a single sketch.
+    #[cfg(feature = "approx_unique")]
+    pub fn approx_n_unique_merge(self) -> Self {
+        self.apply_sketch_function(ApproxNUniqueSketchFunction::Merge)
+    }
+
+    /// Get the approximate count of unique values of the union of the sketches of
+    /// [`Expr::approx_n_unique_sketch`].
+    #[cfg(feature = "approx_unique")]
+    pub fn approx_n_unique_estimate(self) -> Self {
+        self.apply_sketch_function(ApproxNUniqueSketchFunction::Estimate)
+    }
+
+    /// Get the approximate quantile of the values, computed with a mergeable t-digest sketch. This
+    /// only needs a single pass with bounded memory per group.
+    #[cfg(feature = "approx_quantile")]
+    pub fn approx_quantile(self, quantile: Expr) -> Self {
+        self.apply_many_private(FunctionExpr::ApproxQuantile, &[quantile], true, false)
+    }
+
+    /// Get the mean of the values weighted by `weights`. Values or weights that are null are
+    /// ignored.
+    #[cfg(feature = "weighted_agg")]
+    pub fn weighted_mean(self, weights: Expr) -> Self {
+        self.apply_many_private(
+            FunctionExpr::WeightedAgg(WeightedAggMethod::Mean),
+            &[weights],
+            true,
+            false,
+        )
+    }
+
+    /// Get the standard deviation of the values weighted by `weights`, where the weights are
+    /// treated as frequencies and `ddof` is subtracted from their sum.
+    #[cfg(feature = "weighted_agg")]
+    pub fn weighted_std(self, weights: Expr, ddof: u8) -> Self {
+        self.apply_many_private(
+            FunctionExpr::WeightedAgg(WeightedAggMethod::Std(ddof)),
+            &[weights],
+            true,
+            false,
+        )
+    }
+
+    /// Get the quantile of the values weighted by `weights`: the smallest value whose
+    /// cumulative weight is at least `quantile` times the total weight.
+    #[cfg(feature = "weighted_agg")]
+    pub fn weighted_quantile(self, weights: Expr, quantile: Expr) -> Self {
+        self.apply_many_private(
+            FunctionExpr::WeightedAgg(WeightedAggMethod::Quantile),
+            &[weights, quantile],
+            true,
+            false,
+        )
+    }
+
+    /// Get the approximate median of the values, see [`Expr::approx_quantile`].
+    #[cfg(feature = "approx_quantile")]
+    pub fn approx_median(self) -> Self {
+        self.approx_quantile(lit(0.5))
+    }
+
+    #[cfg(feature = "approx_unique")]
+    fn apply_sketch_function(self, function: ApproxNUniqueSketchFunction) -> Self {
+        self.apply_private(FunctionExpr::ApproxNUniqueSketch(function))
+            .with_function_options(|mut options| {
+                options.flags |= FunctionFlags::RETURNS_SCALAR;
+                options
+            })
+    }
+
     /// Bitwise "and" operation.
     pub fn and<E: Into<Expr>>(self, expr: E) -> Self {
         binary_expr(self, Operator::And, expr.into())
@@ -1298,7 +1416,22 @@ impl Expr {
     #[cfg(feature = "mode")]
     /// Compute the mode(s) of this column. This is the most occurring value.
     pub fn mode(self) -> Expr {
-        self.apply_private(FunctionExpr::Mode)
+        self.mode_with_ties(ModeTies::All)
+    }
+
+    #[cfg(feature = "mode")]
+    /// Compute the mode of this column, where `ties` picks the values to return if several
+    /// values are the most occurring. Unless all of them are returned, this is an aggregation
+    /// to a single value.
+    pub fn mode_with_ties(self, ties: ModeTies) -> Expr {
+        let expr = self.apply_private(FunctionExpr::Mode(ties));
+        if ties == ModeTies::All {
+            return expr;
+        }
+        expr.with_function_options(|mut options| {
+            options.flags |= FunctionFlags::RETURNS_SCALAR;
+            options
+        })
     }
 
     /// Exclude a column from a wildcard/regex selection.
@@ -1488,6 +1621,22 @@ impl Expr {
         self.finish_rolling(options, RollingFunction::Std)
     }
 
+    /// Apply a rolling mode.
+    ///
+    /// See: [`RollingSeries::rolling_mode`]
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_mode(self, options: RollingOptionsFixedWindow) -> Expr {
+        self.finish_rolling(options, RollingFunction::Mode)
+    }
+
+    /// Apply a rolling count of the unique values.
+    ///
+    /// See: [`RollingSeries::rolling_n_unique`]
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_n_unique(self, options: RollingOptionsFixedWindow) -> Expr {
+        self.finish_rolling(options, RollingFunction::NUnique)
+    }
+
     /// Apply a rolling skew.
     #[cfg(feature = "rolling_window")]
     #[cfg(feature = "moment")]
@@ -1519,6 +1668,34 @@ impl Expr {
         .with_fmt("rolling_map")
     }
 
+    #[cfg(feature = "rolling_window")]
+    /// Apply a custom function over a rolling/ moving window of the values, which don't have to
+    /// be numeric. The function must return a single value of `output_dtype` per window.
+    ///
+    /// See [`rolling_registered_udf`] to call a registered UDF per window instead, which can be
+    /// serialized.
+    pub fn rolling_map_udf<F>(
+        self,
+        f: F,
+        output_dtype: DataType,
+        options: RollingOptionsFixedWindow,
+    ) -> Expr
+    where
+        F: Fn(&Series) -> PolarsResult<Series> + Send + Sync + 'static,
+    {
+        let output_type = GetOutput::from_type(output_dtype.clone());
+        self.apply(
+            move |c: Column| {
+                c.as_materialized_series()
+                    .rolling_map_udf(&f, &output_dtype, &options)
+                    .map(Column::from)
+                    .map(Some)
+            },
+            output_type,
+        )
+        .with_fmt("rolling_map_udf")
+    }
+
     #[cfg(feature = "rolling_window")]
     /// Apply a custom function over a rolling/ moving window of the array.
     /// Prefer this over rolling_apply in case of floating point numbers as this is faster.
@@ -1575,6 +1752,24 @@ impl Expr {
         self.apply_private(FunctionExpr::Rank { options, seed })
     }
 
+    #[cfg(feature = "rank")]
+    /// Compute the relative rank `(rank - 1) / (n - 1)` of each value, like SQL's `PERCENT_RANK`.
+    pub fn percent_rank(self, descending: bool) -> Expr {
+        self.apply_private(FunctionExpr::PercentRank { descending })
+    }
+
+    #[cfg(feature = "rank")]
+    /// Compute the cumulative distribution of each value, like SQL's `CUME_DIST`.
+    pub fn cume_dist(self, descending: bool) -> Expr {
+        self.apply_private(FunctionExpr::CumeDist { descending })
+    }
+
+    #[cfg(feature = "rank")]
+    /// Divide the sorted values into `n` buckets of near-equal size, like SQL's `NTILE`.
+    pub fn ntile(self, n: IdxSize, descending: bool) -> Expr {
+        self.apply_private(FunctionExpr::Ntile { n, descending })
+    }
+
     #[cfg(feature = "replace")]
     /// Replace the given values with other values.
     pub fn replace<E: Into<Expr>>(self, old: E, new: E) -> Expr {
@@ -1799,6 +1994,18 @@ impl Expr {
         )
     }
 
+    #[cfg(feature = "ewma_by")]
+    /// Calculate the exponentially-weighted moving standard deviation by a time column.
+    pub fn ewm_std_by(self, times: Expr, half_life: Duration) -> Self {
+        self.apply_many_private(FunctionExpr::EwmStdBy { half_life }, &[times], false, false)
+    }
+
+    #[cfg(feature = "ewma_by")]
+    /// Calculate the exponentially-weighted moving variance by a time column.
+    pub fn ewm_var_by(self, times: Expr, half_life: Duration) -> Self {
+        self.apply_many_private(FunctionExpr::EwmVarBy { half_life }, &[times], false, false)
+    }
+
     #[cfg(feature = "ewma")]
     /// Calculate the exponentially-weighted moving standard deviation.
     pub fn ewm_std(self, options: EWMOptions) -> Self {
@@ -1811,6 +2018,18 @@ impl Expr {
         self.apply_private(FunctionExpr::EwmVar { options })
     }
 
+    #[cfg(feature = "ewma")]
+    /// Calculate the exponentially-weighted moving covariance with `other`.
+    pub fn ewm_cov(self, other: Expr, options: EWMOptions) -> Self {
+        self.apply_many_private(FunctionExpr::EwmCov { options }, &[other], false, false)
+    }
+
+    #[cfg(feature = "ewma")]
+    /// Calculate the exponentially-weighted moving correlation with `other`.
+    pub fn ewm_corr(self, other: Expr, options: EWMOptions) -> Self {
+        self.apply_many_private(FunctionExpr::EwmCorr { options }, &[other], false, false)
+    }
+
     /// Returns whether any of the values in the column are `true`.
     ///
     /// If `ignore_nulls` is `False`, [Kleene logic] is used to deal with nulls:
@@ -1926,6 +2145,26 @@ impl Expr {
         self.map_private(FunctionExpr::Hash(k0, k1, k2, k3))
     }
 
+    #[cfg(feature = "row_hash")]
+    /// Compute the hash of every element with the given `algorithm`.
+    ///
+    /// The keys are used as seeds as described by the [`HashAlgorithm`]. Only the output of
+    /// [`HashAlgorithm::is_stable`] algorithms can be persisted across Polars versions.
+    pub fn hash_with_algorithm(
+        self,
+        k0: u64,
+        k1: u64,
+        k2: u64,
+        k3: u64,
+        algorithm: HashAlgorithm,
+    ) -> Expr {
+        if algorithm.is_stable() {
+            self.map_private(FunctionExpr::StableHash(k0, k1, algorithm))
+        } else {
+            self.hash(k0, k1, k2, k3)
+        }
+    }
+
     pub fn to_physical(self) -> Expr {
         self.map_private(FunctionExpr::ToPhysical)
     }
@@ -1943,6 +2182,30 @@ impl Expr {
         self.apply_many_private(FunctionExpr::ExtendConstant, &[value, n], false, false)
     }
 
+    #[cfg(feature = "scatter_gather")]
+    /// Replace the values where `mask` is `true` by the values of `values`, in order.
+    ///
+    /// `values` can also be a single value that is set at every masked position.
+    pub fn scatter_by_mask<E: Into<Expr>>(self, mask: E, values: E) -> Expr {
+        self.apply_many_private(
+            FunctionExpr::ScatterByMask,
+            &[mask.into(), values.into()],
+            false,
+            false,
+        )
+    }
+
+    #[cfg(feature = "scatter_gather")]
+    /// Take the rows `start..end` for every pair of `starts` and `ends`.
+    pub fn gather_by_ranges<E: Into<Expr>>(self, starts: E, ends: E) -> Expr {
+        self.apply_many_private(
+            FunctionExpr::GatherByRanges,
+            &[starts.into(), ends.into()],
+            false,
+            false,
+        )
+    }
+
     #[cfg(feature = "strings")]
     /// Get the [`string::StringNameSpace`]
     pub fn str(self) -> string::StringNameSpace {
@@ -1954,6 +2217,24 @@ impl Expr {
         binary::BinaryNameSpace(self)
     }
 
+    #[cfg(feature = "ip")]
+    /// Get the [`ip::IpNameSpace`]
+    pub fn ip(self) -> ip::IpNameSpace {
+        ip::IpNameSpace(self)
+    }
+
+    #[cfg(feature = "geometry")]
+    /// Get the [`st::GeometryNameSpace`]
+    pub fn st(self) -> st::GeometryNameSpace {
+        st::GeometryNameSpace(self)
+    }
+
+    #[cfg(feature = "url")]
+    /// Get the [`url::UrlNameSpace`]
+    pub fn url(self) -> url::UrlNameSpace {
+        url::UrlNameSpace(self)
+    }
+
     #[cfg(feature = "temporal")]
     /// Get the [`dt::DateLikeNameSpace`]
     pub fn dt(self) -> dt::DateLikeNameSpace {
@@ -2090,6 +2371,55 @@ where
     }
 }
 
+/// Apply a native function over multiple columns that produces a [`Series`] of a known dtype.
+///
+/// Unlike [`map_multiple`], the function receives the inputs as [`Series`] and the output dtype
+/// is declared up front, so the schema is known without running the function.
+///
+/// If `elementwise` is set, the caller promises that every output row only depends on the input
+/// values in the same row and that the output has the same length as the inputs. This allows the
+/// optimizer to push predicates and projections through the function and the streaming engine to
+/// run it per morsel. Otherwise the function is applied on the full columns (or per group in an
+/// aggregation).
+pub fn map_many<F, E>(exprs: E, function: F, output_dtype: DataType, elementwise: bool) -> Expr
+where
+    F: Fn(&[Series]) -> PolarsResult<Series> + 'static + Send + Sync,
+    E: AsRef<[Expr]>,
+{
+    let input = exprs.as_ref().to_vec();
+    let output_type = GetOutput::from_type(output_dtype.clone());
+    let function = move |c: &mut [Column]| {
+        let s = c
+            .iter()
+            .map(|c| c.as_materialized_series().clone())
+            .collect::<Vec<_>>();
+        let out = function(&s)?;
+        polars_ensure!(
+            out.dtype() == &output_dtype,
+            SchemaMismatch: "map_many function returned dtype {}, expected {}",
+            out.dtype(), output_dtype
+        );
+        Ok(Some(out.into_column()))
+    };
+
+    let collect_groups = if elementwise {
+        ApplyOptions::ElementWise
+    } else {
+        ApplyOptions::GroupWise
+    };
+
+    Expr::AnonymousFunction {
+        input,
+        function: new_column_udf(function),
+        output_type,
+        options: FunctionOptions {
+            collect_groups,
+            fmt_str: "map_many",
+            ..Default::default()
+        },
+    }
+}
+
 /// Return the number of rows in the context.
 pub fn len() -> Expr {
     Expr::Len
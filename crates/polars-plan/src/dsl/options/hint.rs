@@ -0,0 +1,53 @@
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// How a sub-plan is joined, see [`QueryHint::Join`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum JoinStrategy {
+    /// Build the hash table of the join on this side and probe it with the other side, e.g.
+    /// because this side is small.
+    Broadcast,
+    /// Stream this side through the hash table of the other side.
+    Stream,
+}
+
+/// Run the sub-plan on the in-memory engine, see [`QueryHint::NoStreaming`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NoStreaming;
+
+/// A hint applied to a sub-plan that overrides a decision of the optimizer or the engine for
+/// that sub-plan only. Hints don't change the result of a query.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum QueryHint {
+    /// How the sub-plan is joined when it is an input of an equi-join on the streaming engine.
+    /// Ignored if the join maintains the order of its inputs, as that decides the build side.
+    Join(JoinStrategy),
+    /// Run the sub-plan on the in-memory engine when the query runs on the streaming engine.
+    NoStreaming,
+}
+
+impl From<JoinStrategy> for QueryHint {
+    fn from(strategy: JoinStrategy) -> Self {
+        Self::Join(strategy)
+    }
+}
+
+impl From<NoStreaming> for QueryHint {
+    fn from(_: NoStreaming) -> Self {
+        Self::NoStreaming
+    }
+}
+
+impl fmt::Display for QueryHint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Join(JoinStrategy::Broadcast) => write!(f, "broadcast join"),
+            Self::Join(JoinStrategy::Stream) => write!(f, "stream join"),
+            Self::NoStreaming => write!(f, "no streaming"),
+        }
+    }
+}
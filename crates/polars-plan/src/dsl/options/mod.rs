@@ -4,8 +4,10 @@ use std::num::NonZeroUsize;
 use std::str::FromStr;
 use std::sync::Arc;
 
+mod hint;
 mod sink;
 
+pub use hint::*;
 use polars_core::error::PolarsResult;
 use polars_core::prelude::*;
 #[cfg(feature = "csv")]
@@ -19,6 +21,8 @@ use polars_io::parquet::write::ParquetWriteOptions;
 use polars_io::{HiveOptions, RowIndex};
 #[cfg(feature = "iejoin")]
 use polars_ops::frame::IEJoinOptions;
+#[cfg(feature = "cast_policy")]
+use polars_ops::frame::cast_policy::CastPolicy;
 use polars_ops::frame::{CrossJoinFilter, CrossJoinOptions, JoinTypeOptions};
 use polars_ops::prelude::{JoinArgs, JoinType};
 #[cfg(feature = "dynamic_group_by")]
@@ -33,7 +37,7 @@ pub use sink::*;
 use strum_macros::IntoStaticStr;
 
 use super::ExprIR;
-use crate::dsl::Selector;
+use crate::dsl::{Expr, Selector};
 
 #[derive(Copy, Clone, PartialEq, Debug, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -206,6 +210,15 @@ pub struct FileScanOptions {
     pub glob: bool,
     pub include_file_paths: Option<PlSmallStr>,
     pub allow_missing_columns: bool,
+    /// Expressions that parse columns as they are read, e.g.
+    /// `col("price").str.replace_all(",", "").cast(Decimal)`. Every expression replaces the column
+    /// with its output name and must be elementwise, so that predicates on the parsed columns can
+    /// still be pushed into the scan.
+    pub column_parsers: Option<Arc<[Expr]>>,
+    /// Conform every file to the schema of the scan with this policy, instead of requiring the
+    /// files to match it.
+    #[cfg(feature = "cast_policy")]
+    pub cast_policy: Option<CastPolicy>,
 }
 
 #[derive(Clone, Debug, Copy, Eq, PartialEq, Hash)]
@@ -357,6 +357,8 @@ impl SinkTypeIR {
             Self::Memory => {},
             Self::File(f) => f.hash(state),
             Self::Partition(f) => {
+                f.base_path.hash(state);
+                f.file_path_cb.is_some().hash(state);
                 f.file_type.hash(state);
                 f.sink_options.hash(state);
                 f.variant.traverse_and_hash(expr_arena, state);
@@ -0,0 +1,261 @@
+//! Rust UDFs registered by name.
+//!
+//! Unlike the closures of [`Expr::map`] and friends, a call to a registered UDF only refers to the
+//! UDF by its name, so plans calling it can be serialized. Deserializing such a plan looks the
+//! UDF up again by its name, which means the process deserializing the plan must have registered
+//! a UDF with the same name.
+use std::sync::{Arc, LazyLock, RwLock};
+
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use polars_utils::pl_serialize;
+
+use crate::prelude::*;
+
+/// Marks the serialized bytes of a call to a registered UDF.
+pub const REGISTERED_UDF_MAGIC_BYTE_MARK: &[u8] = b"PLREGUDF";
+
+/// The implementation of a registered UDF, called with the evaluated arguments.
+pub type RustUdfFn = Arc<dyn Fn(&[Series]) -> PolarsResult<Series> + Send + Sync>;
+
+/// How a registered UDF maps its arguments to its output.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum UdfKind {
+    /// Every row of the output only depends on the same row of the arguments.
+    Elementwise,
+    /// The output is a single value, in a group-by the UDF is called once per group.
+    Aggregation,
+    /// The output depends on all rows of the arguments, in a group-by the UDF is called once per
+    /// group.
+    GroupWise,
+}
+
+#[derive(Clone)]
+struct RegisteredUdf {
+    output_dtype: DataType,
+    kind: UdfKind,
+    function: RustUdfFn,
+}
+
+static UDF_REGISTRY: LazyLock<RwLock<PlHashMap<PlSmallStr, RegisteredUdf>>> =
+    LazyLock::new(Default::default);
+
+/// Registers `function` as a UDF of the process under `name`, replacing the UDF with the same
+/// name. The UDF must return a [`Series`] of `output_dtype`.
+///
+/// Calls to the UDF that were created before it was replaced keep calling the replaced UDF.
+pub fn register_udf(
+    name: impl Into<PlSmallStr>,
+    output_dtype: DataType,
+    kind: UdfKind,
+    function: RustUdfFn,
+) {
+    let udf = RegisteredUdf {
+        output_dtype,
+        kind,
+        function,
+    };
+    UDF_REGISTRY.write().unwrap().insert(name.into(), udf);
+}
+
+/// Removes the UDF with the given name, returning whether it was registered.
+pub fn unregister_udf(name: &str) -> bool {
+    UDF_REGISTRY.write().unwrap().remove(name).is_some()
+}
+
+fn get_registered_udf(name: &str) -> PolarsResult<RegisteredUdf> {
+    let registry = UDF_REGISTRY.read().unwrap();
+    let udf = registry.get(name);
+    polars_ensure!(udf.is_some(), ComputeError: "no UDF is registered with the name '{}'", name);
+    Ok(udf.unwrap().clone())
+}
+
+/// Calls the registered UDF `name` with `args`. The output is named after the first argument.
+pub fn call_registered_udf(name: &str, args: Vec<Expr>) -> PolarsResult<Expr> {
+    polars_ensure!(
+        !args.is_empty(),
+        InvalidOperation: "registered UDF '{}' must be called with at least one argument", name
+    );
+    let udf = get_registered_udf(name)?;
+
+    let (collect_groups, mut flags) = match udf.kind {
+        UdfKind::Elementwise => (ApplyOptions::ElementWise, FunctionFlags::default()),
+        UdfKind::Aggregation => (
+            ApplyOptions::GroupWise,
+            FunctionFlags::default() | FunctionFlags::RETURNS_SCALAR,
+        ),
+        UdfKind::GroupWise => (ApplyOptions::GroupWise, FunctionFlags::default()),
+    };
+    flags |= FunctionFlags::OPTIONAL_RE_ENTRANT;
+
+    let output_field = RegisteredUdfOutput {
+        output_dtype: udf.output_dtype.clone(),
+    };
+
+    Ok(Expr::AnonymousFunction {
+        input: args,
+        function: new_column_udf(RegisteredUdfExpression {
+            call: RegisteredUdfCall {
+                name: name.into(),
+                rolling: None,
+            },
+            udf,
+        }),
+        output_type: SpecialEq::new(Arc::new(output_field) as Arc<dyn FunctionOutputField>),
+        options: FunctionOptions {
+            collect_groups,
+            fmt_str: "registered_udf",
+            flags,
+            ..Default::default()
+        },
+    })
+}
+
+/// Calls the registered aggregation UDF `name` on every fixed-size window of `input`, like
+/// [`Expr::rolling_map_udf`].
+#[cfg(feature = "rolling_window")]
+pub fn rolling_registered_udf(
+    name: &str,
+    input: Expr,
+    options: RollingOptionsFixedWindow,
+) -> PolarsResult<Expr> {
+    let udf = get_registered_udf(name)?;
+    polars_ensure!(
+        udf.kind == UdfKind::Aggregation,
+        InvalidOperation: "registered UDF '{}' must be an aggregation to be called on windows", name
+    );
+
+    let output_field = RegisteredUdfOutput {
+        output_dtype: udf.output_dtype.clone(),
+    };
+
+    Ok(Expr::AnonymousFunction {
+        input: vec![input],
+        function: new_column_udf(RegisteredUdfExpression {
+            call: RegisteredUdfCall {
+                name: name.into(),
+                rolling: Some(options),
+            },
+            udf,
+        }),
+        output_type: SpecialEq::new(Arc::new(output_field) as Arc<dyn FunctionOutputField>),
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::GroupWise,
+            fmt_str: "rolling_registered_udf",
+            flags: FunctionFlags::default() | FunctionFlags::OPTIONAL_RE_ENTRANT,
+            ..Default::default()
+        },
+    })
+}
+
+/// What a call to a registered UDF refers to, this is what gets serialized.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct RegisteredUdfCall {
+    name: PlSmallStr,
+    /// Call the UDF on every window of the argument instead of on all of its values.
+    ///
+    /// Always serialized, so that the serialized calls don't depend on the enabled features.
+    rolling: Option<RollingOptionsFixedWindow>,
+}
+
+/// A call to a registered UDF.
+pub(crate) struct RegisteredUdfExpression {
+    call: RegisteredUdfCall,
+    udf: RegisteredUdf,
+}
+
+impl RegisteredUdfExpression {
+    #[cfg(feature = "serde")]
+    pub(crate) fn try_deserialize(buf: &[u8]) -> PolarsResult<Arc<dyn ColumnsUdf>> {
+        debug_assert!(buf.starts_with(REGISTERED_UDF_MAGIC_BYTE_MARK));
+        let buf = &buf[REGISTERED_UDF_MAGIC_BYTE_MARK.len()..];
+
+        let call: RegisteredUdfCall = pl_serialize::deserialize_from_reader::<_, _, true>(buf)?;
+        let udf = get_registered_udf(&call.name)?;
+        Ok(Arc::new(Self { call, udf }))
+    }
+
+    fn call_function(&self, args: &[Series]) -> PolarsResult<Series> {
+        let out = (self.udf.function)(args)?;
+        polars_ensure!(
+            out.dtype() == &self.udf.output_dtype,
+            SchemaMismatch: "registered UDF '{}' returned dtype '{}', expected '{}'",
+            self.call.name, out.dtype(), self.udf.output_dtype
+        );
+        Ok(out)
+    }
+}
+
+impl ColumnsUdf for RegisteredUdfExpression {
+    fn call_udf(&self, s: &mut [Column]) -> PolarsResult<Option<Column>> {
+        if let Some(options) = &self.call.rolling {
+            #[cfg(feature = "rolling_window")]
+            {
+                let f = |window: &Series| self.call_function(std::slice::from_ref(window));
+                let out = s[0].as_materialized_series().rolling_map_udf(
+                    &f,
+                    &self.udf.output_dtype,
+                    options,
+                )?;
+                return Ok(Some(out.into_column()));
+            }
+            #[cfg(not(feature = "rolling_window"))]
+            {
+                let _ = options;
+                polars_bail!(
+                    InvalidOperation: "calling registered UDF '{}' on windows requires the \
+                    'rolling_window' feature", self.call.name
+                );
+            }
+        }
+
+        let args = s
+            .iter()
+            .map(|c| c.as_materialized_series().clone())
+            .collect::<Vec<_>>();
+        self.call_function(&args).map(|out| Some(out.into_column()))
+    }
+
+    #[cfg(feature = "serde")]
+    fn try_serialize(&self, buf: &mut Vec<u8>) -> PolarsResult<()> {
+        buf.extend_from_slice(REGISTERED_UDF_MAGIC_BYTE_MARK);
+        pl_serialize::serialize_into_writer::<_, _, true>(&mut *buf, &self.call)
+    }
+}
+
+/// Serializable output field of a call to a registered UDF.
+pub(crate) struct RegisteredUdfOutput {
+    output_dtype: DataType,
+}
+
+impl RegisteredUdfOutput {
+    #[cfg(feature = "serde")]
+    pub(crate) fn try_deserialize(buf: &[u8]) -> PolarsResult<Arc<dyn FunctionOutputField>> {
+        debug_assert!(buf.starts_with(REGISTERED_UDF_MAGIC_BYTE_MARK));
+        let buf = &buf[REGISTERED_UDF_MAGIC_BYTE_MARK.len()..];
+
+        let output_dtype = pl_serialize::deserialize_from_reader::<_, _, true>(buf)?;
+        Ok(Arc::new(Self { output_dtype }))
+    }
+}
+
+impl FunctionOutputField for RegisteredUdfOutput {
+    fn get_field(
+        &self,
+        _input_schema: &Schema,
+        _cntxt: Context,
+        fields: &[Field],
+    ) -> PolarsResult<Field> {
+        Ok(Field::new(
+            fields[0].name().clone(),
+            self.output_dtype.clone(),
+        ))
+    }
+
+    #[cfg(feature = "serde")]
+    fn try_serialize(&self, buf: &mut Vec<u8>) -> PolarsResult<()> {
+        buf.extend_from_slice(REGISTERED_UDF_MAGIC_BYTE_MARK);
+        pl_serialize::serialize_into_writer::<_, _, true>(&mut *buf, &self.output_dtype)
+    }
+}
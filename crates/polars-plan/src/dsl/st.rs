@@ -0,0 +1,43 @@
+use super::*;
+
+/// Specialized expressions for planar geometries.
+///
+/// Geometries are represented as [`DataType::Geometry`] values holding Well-Known Binary (WKB).
+/// The expressions also accept [`DataType::Binary`] values holding WKB.
+pub struct GeometryNameSpace(pub(crate) Expr);
+
+impl GeometryNameSpace {
+    /// Compute the area of the geometries. Holes are subtracted, and points and lines have an
+    /// area of 0.
+    pub fn area(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::Geometry(GeometryFunction::Area))
+    }
+
+    /// Compute the centroid of the geometries as a point.
+    pub fn centroid(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::Geometry(GeometryFunction::Centroid))
+    }
+
+    /// Check whether the geometries contain the `other` geometries.
+    pub fn contains(self, other: Expr) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::Geometry(GeometryFunction::Contains),
+            &[other],
+            false,
+            Some(Default::default()),
+        )
+    }
+
+    /// Compute the minimum Euclidean distance between the geometries and the `other`
+    /// geometries.
+    pub fn distance(self, other: Expr) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::Geometry(GeometryFunction::Distance),
+            &[other],
+            false,
+            Some(Default::default()),
+        )
+    }
+}
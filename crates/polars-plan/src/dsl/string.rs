@@ -354,6 +354,14 @@ impl StringNameSpace {
         self.strptime(DataType::Time, options, lit("raise"))
     }
 
+    /// Convert a String column of ISO 8601 durations (or durations as displayed by Polars)
+    /// into a Duration column.
+    #[cfg(feature = "dtype-duration")]
+    pub fn to_duration(self, time_unit: TimeUnit, strict: bool) -> Expr {
+        self.0
+            .map_private(StringFunction::ToDuration { time_unit, strict }.into())
+    }
+
     /// Convert a String column into a Decimal column.
     #[cfg(feature = "dtype-decimal")]
     pub fn to_decimal(self, infer_length: usize) -> Expr {
@@ -484,6 +492,91 @@ impl StringNameSpace {
         )
     }
 
+    #[cfg(feature = "string_phonetic")]
+    /// Encode each string with American Soundex, a letter followed by three digits. Strings
+    /// that sound alike get the same code. If `ascii_fold`, non-ASCII letters are folded to
+    /// ASCII, otherwise they are ignored.
+    pub fn soundex(self, ascii_fold: bool) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::StringExpr(StringFunction::Soundex { ascii_fold }),
+            &[],
+            false,
+            None,
+        )
+    }
+
+    #[cfg(feature = "string_phonetic")]
+    /// Encode each string with Metaphone, a more accurate phonetic encoding of English
+    /// pronunciation than Soundex. If `ascii_fold`, non-ASCII letters are folded to ASCII,
+    /// otherwise they are ignored.
+    pub fn metaphone(self, ascii_fold: bool) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::StringExpr(StringFunction::Metaphone { ascii_fold }),
+            &[],
+            false,
+            None,
+        )
+    }
+
+    #[cfg(feature = "string_similarity")]
+    /// Compute the Levenshtein distance to the `other` string, the minimum number of single
+    /// character insertions, deletions and substitutions to change one into the other.
+    pub fn levenshtein(self, other: Expr) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::StringExpr(StringFunction::Levenshtein { normalized: false }),
+            &[other],
+            false,
+            Some(Default::default()),
+        )
+    }
+
+    #[cfg(feature = "string_similarity")]
+    /// Compute the Levenshtein similarity to the `other` string, the distance divided by the
+    /// length of the longest string subtracted from 1.
+    pub fn levenshtein_similarity(self, other: Expr) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::StringExpr(StringFunction::Levenshtein { normalized: true }),
+            &[other],
+            false,
+            Some(Default::default()),
+        )
+    }
+
+    #[cfg(feature = "string_similarity")]
+    /// Compute the Hamming distance to the `other` string, the number of positions at which
+    /// the characters differ. Null if the strings have a different length.
+    pub fn hamming(self, other: Expr) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::StringExpr(StringFunction::Hamming { normalized: false }),
+            &[other],
+            false,
+            Some(Default::default()),
+        )
+    }
+
+    #[cfg(feature = "string_similarity")]
+    /// Compute the Hamming similarity to the `other` string, the distance divided by the
+    /// length of the strings subtracted from 1. Null if the strings have a different length.
+    pub fn hamming_similarity(self, other: Expr) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::StringExpr(StringFunction::Hamming { normalized: true }),
+            &[other],
+            false,
+            Some(Default::default()),
+        )
+    }
+
+    #[cfg(feature = "string_similarity")]
+    /// Compute the Jaro-Winkler similarity to the `other` string, between 0 and 1.
+    pub fn jaro_winkler(self, other: Expr) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::StringExpr(StringFunction::JaroWinkler),
+            &[other],
+            false,
+            Some(Default::default()),
+        )
+    }
+
     /// Remove leading and trailing characters, or whitespace if matches is None.
     pub fn strip_chars(self, matches: Expr) -> Expr {
         self.0.map_many_private(
@@ -639,6 +732,17 @@ impl StringNameSpace {
         )
     }
 
+    /// Extract all matches of a JSON path as a list of strings.
+    #[cfg(feature = "extract_jsonpath")]
+    pub fn json_extract_all(self, pat: Expr) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::StringExpr(StringFunction::JsonExtractAll),
+            &[pat],
+            false,
+            None,
+        )
+    }
+
     #[cfg(feature = "regex")]
     pub fn escape_regex(self) -> Expr {
         self.0.map_many_private(
@@ -0,0 +1,30 @@
+use super::*;
+
+/// Specialized expressions for parsing URL strings.
+///
+/// Strings that are not absolute URLs, e.g. `/path` or `example.com`, are parsed as null.
+pub struct UrlNameSpace(pub(crate) Expr);
+
+impl UrlNameSpace {
+    /// Get the host of the URLs, e.g. `example.com`.
+    pub fn host(self) -> Expr {
+        self.0.map_private(FunctionExpr::Url(UrlFunction::Host))
+    }
+
+    /// Get the path of the URLs, e.g. `/search`. The path is not percent-decoded.
+    pub fn path(self) -> Expr {
+        self.0.map_private(FunctionExpr::Url(UrlFunction::Path))
+    }
+
+    /// Get the decoded value of the first query parameter called `name`.
+    pub fn query_param(self, name: PlSmallStr) -> Expr {
+        self.0
+            .map_private(FunctionExpr::Url(UrlFunction::QueryParam(name)))
+    }
+
+    /// Decode percent-encoded sequences such as `%20`. Strings that don't decode to valid UTF-8
+    /// are set to null.
+    pub fn decode(self) -> Expr {
+        self.0.map_private(FunctionExpr::Url(UrlFunction::Decode))
+    }
+}
@@ -38,6 +38,17 @@ bitflags! {
         /// Check if operations are order dependent and unset maintaining_order if
         /// the order would not be observed.
         const CHECK_ORDER_OBSERVE = 1 << 16;
+        /// Reorder chains of inner joins so that the joins with the smallest estimated tables run
+        /// first.
+        const JOIN_REORDER = 1 << 17;
+        /// Turn filters on the existence of rows of another table into semi and anti joins, and
+        /// filter the probe side of joins with the keys of small in-memory tables.
+        const SEMI_JOIN_REDUCTION = 1 << 18;
+        /// Keep low-cardinality string columns of Parquet scans dictionary-encoded as categoricals
+        /// until their values are needed.
+        const DICTIONARY_STRINGS = 1 << 19;
+        /// Take the sort order of columns from the metadata of scanned files.
+        const SORTED_METADATA = 1 << 20;
     }
 }
 
@@ -58,6 +69,22 @@ impl OptFlags {
         self.contains(OptFlags::COLLAPSE_JOINS)
     }
 
+    pub fn join_reorder(&self) -> bool {
+        self.contains(OptFlags::JOIN_REORDER)
+    }
+
+    pub fn semi_join_reduction(&self) -> bool {
+        self.contains(OptFlags::SEMI_JOIN_REDUCTION)
+    }
+
+    pub fn dictionary_strings(&self) -> bool {
+        self.contains(OptFlags::DICTIONARY_STRINGS)
+    }
+
+    pub fn sorted_metadata(&self) -> bool {
+        self.contains(OptFlags::SORTED_METADATA)
+    }
+
     pub fn predicate_pushdown(&self) -> bool {
         self.contains(OptFlags::PREDICATE_PUSHDOWN)
     }
@@ -84,7 +111,18 @@ impl OptFlags {
 
 impl Default for OptFlags {
     fn default() -> Self {
-        Self::from_bits_truncate(u32::MAX) & !Self::NEW_STREAMING & !Self::STREAMING & !Self::EAGER
+        Self::from_bits_truncate(u32::MAX)
+            & !Self::NEW_STREAMING
+            & !Self::STREAMING
+            & !Self::EAGER
+            // Changes the order of the rows of inner joins, so it must be enabled explicitly.
+            & !Self::JOIN_REORDER
+            // Pushing down the keys of in-memory tables can cost more than it saves.
+            & !Self::SEMI_JOIN_REDUCTION
+            // The casts can cost more than they save for columns with many distinct values.
+            & !Self::DICTIONARY_STRINGS
+            // Files may declare a sort order that their data doesn't have.
+            & !Self::SORTED_METADATA
     }
 }
 
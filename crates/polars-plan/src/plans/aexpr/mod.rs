@@ -57,6 +57,8 @@ pub enum IRAggExpr {
     Std(Node, u8),
     Var(Node, u8),
     AggGroups(Node),
+    #[cfg(feature = "bitwise")]
+    Bitwise(Node, GroupByBitwiseMethod),
 }
 
 impl Hash for IRAggExpr {
@@ -70,6 +72,8 @@ impl Hash for IRAggExpr {
                 method: interpol, ..
             } => interpol.hash(state),
             Self::Std(_, v) | Self::Var(_, v) => v.hash(state),
+            #[cfg(feature = "bitwise")]
+            Self::Bitwise(_, method) => method.hash(state),
             _ => {},
         }
     }
@@ -99,6 +103,8 @@ impl IRAggExpr {
             (Quantile { method: l, .. }, Quantile { method: r, .. }) => l == r,
             (Std(_, l), Std(_, r)) => l == r,
             (Var(_, l), Var(_, r)) => l == r,
+            #[cfg(feature = "bitwise")]
+            (Bitwise(_, l), Bitwise(_, r)) => l == r,
             _ => std::mem::discriminant(self) == std::mem::discriminant(other),
         }
     }
@@ -133,6 +139,8 @@ impl From<IRAggExpr> for GroupByMethod {
             Std(_, ddof) => GroupByMethod::Std(ddof),
             Var(_, ddof) => GroupByMethod::Var(ddof),
             AggGroups(_) => GroupByMethod::Groups,
+            #[cfg(feature = "bitwise")]
+            Bitwise(_, method) => GroupByMethod::Bitwise(method),
             Quantile { .. } => unreachable!(),
         }
     }
@@ -1,6 +1,7 @@
 #[cfg(feature = "dtype-decimal")]
 use polars_core::chunked_array::arithmetic::{
-    _get_decimal_scale_add_sub, _get_decimal_scale_div, _get_decimal_scale_mul,
+    _get_decimal_precision_add_sub, _get_decimal_precision_mul, _get_decimal_scale_add_sub,
+    _get_decimal_scale_div, _get_decimal_scale_mul,
 };
 use recursive::recursive;
 
@@ -9,6 +10,12 @@ use super::*;
 fn float_type(field: &mut Field) {
     let should_coerce = match &field.dtype {
         DataType::Float32 => false,
+        // Half-precision floats are aggregated in single precision.
+        #[cfg(feature = "dtype-f16")]
+        DataType::Float16 => {
+            field.coerce(DataType::Float32);
+            return;
+        },
         #[cfg(feature = "dtype-decimal")]
         DataType::Decimal(..) => true,
         DataType::Boolean => true,
@@ -236,13 +243,25 @@ impl AExpr {
                     Sum(expr) => {
                         *agg_list = false;
                         let mut field = ctx.arena.get(*expr).to_field_impl(ctx, &mut false)?;
-                        let dt = match field.dtype() {
+                        // Arrays are summed elementwise.
+                        let (inner, width): (_, Option<usize>) = match field.dtype() {
+                            #[cfg(feature = "dtype-array")]
+                            Array(inner, width) => (inner.as_ref(), Some(*width)),
+                            dt => (dt, None),
+                        };
+                        let dt = match inner {
                             Boolean => Some(IDX_DTYPE),
                             UInt8 | Int8 | Int16 | UInt16 => Some(Int64),
+                            #[cfg(feature = "dtype-f16")]
+                            Float16 => Some(Float32),
                             _ => None,
                         };
                         if let Some(dt) = dt {
-                            field.coerce(dt);
+                            match width {
+                                #[cfg(feature = "dtype-array")]
+                                Some(width) => field.coerce(Array(Box::new(dt), width)),
+                                _ => field.coerce(dt),
+                            }
                         }
                         Ok(field)
                     },
@@ -260,6 +279,17 @@ impl AExpr {
                         let mut field = ctx.arena.get(*expr).to_field_impl(ctx, &mut false)?;
                         match field.dtype {
                             Date => field.coerce(Datetime(TimeUnit::Milliseconds, None)),
+                            #[cfg(feature = "dtype-decimal")]
+                            Decimal(_, Some(scale)) => {
+                                field.coerce(Decimal(None, Some(_get_decimal_scale_div(scale))))
+                            },
+                            // Arrays are averaged elementwise.
+                            #[cfg(feature = "dtype-array")]
+                            Array(ref inner, width) => {
+                                let mut inner = Field::new(PlSmallStr::EMPTY, (**inner).clone());
+                                float_type(&mut inner);
+                                field.coerce(Array(Box::new(inner.dtype), width))
+                            },
                             _ => float_type(&mut field),
                         }
                         Ok(field)
@@ -305,6 +335,17 @@ impl AExpr {
                         float_type(&mut field);
                         Ok(field)
                     },
+                    #[cfg(feature = "bitwise")]
+                    Bitwise(expr, method) => {
+                        *agg_list = false;
+                        let field = ctx.arena.get(*expr).to_field_impl(ctx, &mut false)?;
+                        let dtype = field.dtype();
+                        polars_ensure!(
+                            dtype.is_bool() || dtype.is_integer() || dtype.is_float(),
+                            InvalidOperation: "dtype {} not supported in '{}' operation", dtype, method
+                        );
+                        Ok(field)
+                    },
                 }
             },
             Cast { expr, dtype, .. } => {
@@ -505,9 +546,18 @@ fn get_arithmetic_field(
                     )?)
                 },
                 #[cfg(feature = "dtype-decimal")]
-                (Decimal(_, Some(scale_left)), Decimal(_, Some(scale_right))) => {
+                (
+                    Decimal(precision_left, Some(scale_left)),
+                    Decimal(precision_right, Some(scale_right)),
+                ) => {
                     let scale = _get_decimal_scale_add_sub(*scale_left, *scale_right);
-                    Decimal(None, Some(scale))
+                    let precision = _get_decimal_precision_add_sub(
+                        *precision_left,
+                        *scale_left,
+                        *precision_right,
+                        *scale_right,
+                    );
+                    Decimal(precision, Some(scale))
                 },
                 (left, right) => try_get_supertype(left, right)?,
             }
@@ -559,9 +609,18 @@ fn get_arithmetic_field(
                     )?)
                 },
                 #[cfg(feature = "dtype-decimal")]
-                (Decimal(_, Some(scale_left)), Decimal(_, Some(scale_right))) => {
+                (
+                    Decimal(precision_left, Some(scale_left)),
+                    Decimal(precision_right, Some(scale_right)),
+                ) => {
                     let scale = _get_decimal_scale_add_sub(*scale_left, *scale_right);
-                    Decimal(None, Some(scale))
+                    let precision = _get_decimal_precision_add_sub(
+                        *precision_left,
+                        *scale_left,
+                        *precision_right,
+                        *scale_right,
+                    );
+                    Decimal(precision, Some(scale))
                 },
                 (left, right) => try_get_supertype(left, right)?,
             }
@@ -604,18 +663,24 @@ fn get_arithmetic_field(
                     },
                 },
                 #[cfg(feature = "dtype-decimal")]
-                (Decimal(_, Some(scale_left)), Decimal(_, Some(scale_right))) => {
-                    let scale = match op {
-                        Operator::Multiply => _get_decimal_scale_mul(*scale_left, *scale_right),
+                (
+                    Decimal(precision_left, Some(scale_left)),
+                    Decimal(precision_right, Some(scale_right)),
+                ) => {
+                    let (precision, scale) = match op {
+                        Operator::Multiply => (
+                            _get_decimal_precision_mul(*precision_left, *precision_right),
+                            _get_decimal_scale_mul(*scale_left, *scale_right),
+                        ),
                         Operator::Divide | Operator::TrueDivide => {
-                            _get_decimal_scale_div(*scale_left)
+                            (None, _get_decimal_scale_div(*scale_left))
                         },
                         _ => {
                             debug_assert!(false);
-                            *scale_left
+                            (None, *scale_left)
                         },
                     };
-                    let dtype = Decimal(None, Some(scale));
+                    let dtype = Decimal(precision, Some(scale));
                     left_field.coerce(dtype);
                     return Ok(left_field);
                 },
@@ -677,6 +742,13 @@ fn get_arithmetic_field(
         },
     };
 
+    // Half-precision arithmetic is computed in single precision.
+    let super_type = if super_type.is_float16() {
+        Float32
+    } else {
+        super_type
+    };
+
     left_field.coerce(super_type);
     Ok(left_field)
 }
@@ -721,6 +793,8 @@ fn get_truediv_dtype(left_dtype: &DataType, right_dtype: &DataType) -> PolarsRes
             list_dtype.cast_leaf(dtype)
         },
         (Float32, _) => Float32,
+        #[cfg(feature = "dtype-f16")]
+        (Float16, _) => Float32,
         #[cfg(feature = "dtype-decimal")]
         (Decimal(_, Some(scale_left)), Decimal(_, _)) => {
             let scale = _get_decimal_scale_div(*scale_left);
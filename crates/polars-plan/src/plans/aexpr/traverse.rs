@@ -173,6 +173,8 @@ impl IRAggExpr {
             Std(input, _) => Single(*input),
             Var(input, _) => Single(*input),
             AggGroups(input) => Single(*input),
+            #[cfg(feature = "bitwise")]
+            Bitwise(input, _) => Single(*input),
         }
     }
     pub fn set_input(&mut self, input: Node) {
@@ -192,6 +194,8 @@ impl IRAggExpr {
             Std(input, _) => input,
             Var(input, _) => input,
             AggGroups(input) => input,
+            #[cfg(feature = "bitwise")]
+            Bitwise(input, _) => input,
         };
         *node = input;
     }
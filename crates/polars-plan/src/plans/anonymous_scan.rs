@@ -7,6 +7,8 @@ use crate::dsl::Expr;
 
 pub struct AnonymousScanArgs {
     pub n_rows: Option<usize>,
+    /// The columns to read if projection pushdown is allowed, `None` meaning all columns. This
+    /// includes the columns that are only needed by the pushed down predicate.
     pub with_columns: Option<Arc<[PlSmallStr]>>,
     pub schema: SchemaRef,
     pub output_schema: Option<SchemaRef>,
@@ -24,6 +26,15 @@ pub trait AnonymousScan: Send + Sync {
         self.scan(scan_opts).map(Some)
     }
 
+    /// Specify if [`AnonymousScan::next_batch`] produces successive batches of the source and
+    /// returns `None` once it is exhausted, so that the streaming engine can consume the scan
+    /// batch by batch.
+    ///
+    /// Defaults to `false`, in which case the scan runs on the in-memory engine.
+    fn is_batched(&self) -> bool {
+        false
+    }
+
     /// function to supply the schema.
     /// Allows for an optional infer schema argument for data sources with dynamic schemas
     fn schema(&self, _infer_schema_length: Option<usize>) -> PolarsResult<SchemaRef> {
@@ -41,6 +52,14 @@ pub trait AnonymousScan: Send + Sync {
     fn allows_projection_pushdown(&self) -> bool {
         false
     }
+    /// Specify the columns the scan provider can skip cheaply if they are not projected. The other
+    /// columns are always passed in `with_columns`, and are dropped after the scan if they are not
+    /// needed.
+    ///
+    /// Defaults to `None`, meaning that all columns can be skipped.
+    fn skippable_columns(&self) -> Option<Arc<[PlSmallStr]>> {
+        None
+    }
     /// Specify if the scan provider should allow slice pushdowns.
     ///
     /// Defaults to `false`
@@ -4,6 +4,12 @@ use polars_core::prelude::*;
 
 pub trait DataFrameUdf: Send + Sync {
     fn call_udf(&self, df: DataFrame) -> PolarsResult<DataFrame>;
+
+    /// The name of the operator registered with the streaming engine that runs this function
+    /// natively, if any. Other engines call [`DataFrameUdf::call_udf`] instead.
+    fn streaming_operator(&self) -> Option<&str> {
+        None
+    }
 }
 
 impl<F> DataFrameUdf for F
@@ -138,6 +138,8 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
             scan_type,
             cached_ir,
         } => {
+            let column_parsers = file_options.column_parsers.clone();
+
             // Note that the first metadata can still end up being `None` later if the files were
             // filtered from predicate pushdown.
             let mut cached_ir = cached_ir.lock().unwrap();
@@ -360,7 +362,14 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                 cached_ir.replace(ir);
             }
 
-            cached_ir.clone().unwrap()
+            let ir = cached_ir.clone().unwrap();
+            if let Some(column_parsers) = column_parsers {
+                drop(cached_ir);
+                let input = ctxt.lp_arena.add(ir);
+                return resolve_column_parsers(column_parsers.to_vec(), input, ctxt)
+                    .map_err(|e| e.context(failed_here!(column parsers)));
+            }
+            ir
         },
         #[cfg(feature = "python")]
         DslPlan::PythonScan { mut options } => {
@@ -1108,6 +1117,49 @@ fn expand_filter(
     Ok(predicate)
 }
 
+/// Applies the column parsers of a scan in an [`IR::HStack`] directly on top of it.
+///
+/// Predicate pushdown recognizes this node and rewrites predicates on the parsed columns in terms of
+/// the parser expressions, so that they can still be pushed into the scan.
+fn resolve_column_parsers(
+    column_parsers: Vec<Expr>,
+    input: Node,
+    ctxt: &mut DslConversionContext,
+) -> PolarsResult<Node> {
+    let input_schema = ctxt.lp_arena.get(input).schema(ctxt.lp_arena).into_owned();
+    let (exprs, schema) = resolve_with_columns(
+        column_parsers,
+        input,
+        ctxt.lp_arena,
+        ctxt.expr_arena,
+        ctxt.opt_flags,
+    )?;
+
+    for e in exprs.iter() {
+        polars_ensure!(
+            input_schema.contains(e.output_name()),
+            ColumnNotFound: "column parser output '{}' is not a column of the scan", e.output_name()
+        );
+        polars_ensure!(
+            is_elementwise_rec(e.node(), ctxt.expr_arena),
+            InvalidOperation: "column parser for '{}' must be elementwise", e.output_name()
+        );
+    }
+
+    ctxt.conversion_optimizer
+        .fill_scratch(&exprs, ctxt.expr_arena);
+    let lp = IR::HStack {
+        input,
+        exprs,
+        schema,
+        options: ProjectionOptions {
+            should_broadcast: false,
+            ..Default::default()
+        },
+    };
+    run_conversion(lp, ctxt, "column parsers")
+}
+
 fn resolve_with_columns(
     exprs: Vec<Expr>,
     input: Node,
@@ -247,6 +247,11 @@ pub(super) fn to_aexpr_impl(
                 AggExpr::AggGroups(expr) => {
                     IRAggExpr::AggGroups(to_aexpr_impl_materialized_lit(owned(expr), arena, state)?)
                 },
+                #[cfg(feature = "bitwise")]
+                AggExpr::Bitwise(expr, method) => IRAggExpr::Bitwise(
+                    to_aexpr_impl_materialized_lit(owned(expr), arena, state)?,
+                    method,
+                ),
             };
             AExpr::Agg(a_agg)
         },
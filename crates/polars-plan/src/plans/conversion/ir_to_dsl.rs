@@ -156,6 +156,11 @@ pub fn node_to_expr(node: Node, expr_arena: &Arena<AExpr>) -> Expr {
                 let exp = node_to_expr(expr, expr_arena);
                 AggExpr::AggGroups(Arc::new(exp)).into()
             },
+            #[cfg(feature = "bitwise")]
+            IRAggExpr::Bitwise(expr, method) => {
+                let exp = node_to_expr(expr, expr_arena);
+                AggExpr::Bitwise(Arc::new(exp), method).into()
+            },
             IRAggExpr::Count(expr, include_nulls) => {
                 let expr = node_to_expr(expr, expr_arena);
                 AggExpr::Count(Arc::new(expr), include_nulls).into()
@@ -248,6 +248,11 @@ pub(super) fn process_binary(
         st = String
     }
 
+    // Half-precision arithmetic is computed in single precision.
+    if op.is_arithmetic() && st.is_float16() {
+        st = Float32
+    }
+
     // TODO! raise here?
     // We should at least never cast to Unknown.
     if matches!(st, DataType::Unknown(UnknownKind::Any)) {
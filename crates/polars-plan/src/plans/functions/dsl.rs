@@ -47,6 +47,7 @@ pub enum DslFunction {
     /// FillValue
     FillNan(Expr),
     Drop(DropFunction),
+    Hint(QueryHint),
     // Function that is already converted to IR.
     #[cfg_attr(feature = "serde", serde(skip))]
     FunctionIR(FunctionIR),
@@ -143,6 +144,7 @@ impl DslFunction {
                 validate_columns_in_input(columns.as_ref(), input_schema, "explode")?;
                 FunctionIR::Unnest { columns }
             },
+            DslFunction::Hint(hint) => FunctionIR::Hint(hint),
             #[cfg(feature = "python")]
             DslFunction::OpaquePython(inner) => FunctionIR::OpaquePython(inner),
             DslFunction::Stats(_)
@@ -78,6 +78,8 @@ pub enum FunctionIR {
         // used for formatting
         fmt_str: PlSmallStr,
     },
+    /// A hint for the sub-plan of its input, which doesn't change the data.
+    Hint(QueryHint),
     /// Streaming engine pipeline
     #[cfg_attr(feature = "ir_serde", serde(skip))]
     Pipeline {
@@ -118,6 +120,7 @@ impl PartialEq for FunctionIR {
             #[cfg(feature = "pivot")]
             (Unpivot { args: l, .. }, Unpivot { args: r, .. }) => l == r,
             (RowIndex { name: l, .. }, RowIndex { name: r, .. }) => l == r,
+            (Hint(l), Hint(r)) => l == r,
             _ => false,
         }
     }
@@ -142,6 +145,7 @@ impl Hash for FunctionIR {
             FunctionIR::Pipeline { .. } => {},
             FunctionIR::Unnest { columns } => columns.hash(state),
             FunctionIR::Rechunk => {},
+            FunctionIR::Hint(hint) => hint.hash(state),
             FunctionIR::Rename {
                 existing,
                 new,
@@ -179,6 +183,7 @@ impl FunctionIR {
             #[cfg(feature = "python")]
             OpaquePython(OpaquePythonUdf { streamable, .. }) => *streamable,
             RowIndex { .. } => false,
+            Hint(hint) => *hint != QueryHint::NoStreaming,
         }
     }
 
@@ -201,7 +206,7 @@ impl FunctionIR {
             OpaquePython(OpaquePythonUdf { predicate_pd, .. }) => *predicate_pd,
             #[cfg(feature = "pivot")]
             Unpivot { .. } => true,
-            Rechunk | Unnest { .. } | Rename { .. } | Explode { .. } => true,
+            Rechunk | Unnest { .. } | Rename { .. } | Explode { .. } | Hint(_) => true,
             RowIndex { .. } | FastCount { .. } => false,
             Pipeline { .. } => unimplemented!(),
         }
@@ -216,7 +221,7 @@ impl FunctionIR {
             Rechunk | FastCount { .. } | Unnest { .. } | Rename { .. } | Explode { .. } => true,
             #[cfg(feature = "pivot")]
             Unpivot { .. } => true,
-            RowIndex { .. } => true,
+            RowIndex { .. } | Hint(_) => true,
             Pipeline { .. } => unimplemented!(),
         }
     }
@@ -250,6 +255,7 @@ impl FunctionIR {
                 df.as_single_chunk_par();
                 Ok(df)
             },
+            Hint(_) => Ok(df),
             Unnest { columns: _columns } => {
                 feature_gated!("dtype-struct", df.unnest(_columns.iter().cloned()))
             },
@@ -292,6 +298,28 @@ impl FunctionIR {
     }
 }
 
+/// The hints of the sub-plan starting at `node`, looking through the projections and filters
+/// the optimizer may have put on top of the hints.
+pub fn sub_plan_hints(mut node: Node, lp_arena: &Arena<IR>) -> Vec<QueryHint> {
+    let mut hints = Vec::new();
+    loop {
+        node = match lp_arena.get(node) {
+            IR::MapFunction {
+                input,
+                function: FunctionIR::Hint(hint),
+            } => {
+                hints.push(*hint);
+                *input
+            },
+            IR::SimpleProjection { input, .. }
+            | IR::Select { input, .. }
+            | IR::HStack { input, .. }
+            | IR::Filter { input, .. } => *input,
+            _ => return hints,
+        };
+    }
+}
+
 impl Debug for FunctionIR {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{self}")
@@ -303,6 +331,7 @@ impl Display for FunctionIR {
         use FunctionIR::*;
         match self {
             Opaque { fmt_str, .. } => write!(f, "{fmt_str}"),
+            Hint(hint) => write!(f, "HINT: {hint}"),
             Unnest { columns } => {
                 write!(f, "UNNEST by:")?;
                 let columns = columns.as_ref();
@@ -638,6 +638,8 @@ impl Display for ExprIRDisplay<'_> {
                     Var(expr, _) => write!(f, "{}.var()", self.with_root(expr)),
                     Std(expr, _) => write!(f, "{}.std()", self.with_root(expr)),
                     Quantile { expr, .. } => write!(f, "{}.quantile()", self.with_root(expr)),
+                    #[cfg(feature = "bitwise")]
+                    Bitwise(expr, method) => write!(f, "{}.{method}()", self.with_root(expr)),
                 }
             },
             Cast {
@@ -775,6 +777,7 @@ impl fmt::Debug for LiteralValue {
             },
             Range(range) => fmt::Debug::fmt(range, f),
             Dyn(d) => fmt::Debug::fmt(d, f),
+            Placeholder(p) => write!(f, "placeholder({}: {})", p.name, p.dtype),
         }
     }
 }
@@ -2,6 +2,8 @@ mod dot;
 mod format;
 mod inputs;
 mod schema;
+#[cfg(feature = "substrait")]
+mod substrait;
 pub(crate) mod tree_format;
 
 use std::borrow::Cow;
@@ -0,0 +1,871 @@
+//! Conversion of an [`IR`] plan to a [Substrait](https://substrait.io) plan, in the JSON encoding
+//! of its protobuf messages.
+//!
+//! Scans of Parquet and IPC files and of in-memory frames, filters, projections, joins,
+//! aggregations, sorts, slices and unions are supported, with the expressions that have an
+//! equivalent in the standard Substrait extensions. Other nodes and expressions raise an error.
+//! Polars doesn't track nullability, so all types are nullable. Unsigned integers are widened to
+//! the next signed integer type, as Substrait has no unsigned types.
+
+use std::path::Path;
+
+use polars_core::prelude::*;
+use serde_json::{Value, json};
+
+use super::format::ExprIRDisplay;
+use crate::prelude::*;
+
+/// The Substrait version the plans are produced for.
+const SUBSTRAIT_MINOR_VERSION: u32 = 57;
+const EXTENSIONS_URI: &str = "https://github.com/substrait-io/substrait/blob/main/extensions/";
+
+const AGGREGATE_GENERIC: &str = "functions_aggregate_generic.yaml";
+const ARITHMETIC: &str = "functions_arithmetic.yaml";
+const BOOLEAN: &str = "functions_boolean.yaml";
+const COMPARISON: &str = "functions_comparison.yaml";
+
+const NULLABLE: &str = "NULLABILITY_NULLABLE";
+
+impl IRPlanRef<'_> {
+    /// Convert the plan to a Substrait `Plan` message in its JSON encoding.
+    pub fn to_substrait(self) -> PolarsResult<Value> {
+        let mut producer = SubstraitProducer {
+            lp_arena: self.lp_arena,
+            expr_arena: self.expr_arena,
+            functions: vec![],
+        };
+        let input = producer.rel(self.lp_top)?;
+        let schema = self.root().schema(self.lp_arena);
+        let root = json!({ "root": { "input": input, "names": field_names(&schema) } });
+        Ok(producer.finish(root))
+    }
+}
+
+impl IRPlan {
+    /// Convert the plan to a Substrait `Plan` message in its JSON encoding.
+    pub fn to_substrait(&self) -> PolarsResult<Value> {
+        self.as_ref().to_substrait()
+    }
+}
+
+struct SubstraitProducer<'a> {
+    lp_arena: &'a Arena<IR>,
+    expr_arena: &'a Arena<AExpr>,
+    /// The extension file and name of the functions used by the plan, the anchor of a function
+    /// is its index plus one.
+    functions: Vec<(&'static str, &'static str)>,
+}
+
+impl SubstraitProducer<'_> {
+    fn finish(self, root: Value) -> Value {
+        let mut uris: Vec<&str> = vec![];
+        let mut extensions = Vec::with_capacity(self.functions.len());
+        for (i, (file, name)) in self.functions.into_iter().enumerate() {
+            let uri = match uris.iter().position(|uri| *uri == file) {
+                Some(uri) => uri,
+                None => {
+                    uris.push(file);
+                    uris.len() - 1
+                },
+            };
+            extensions.push(json!({
+                "extensionFunction": {
+                    "extensionUriReference": uri + 1,
+                    "functionAnchor": i + 1,
+                    "name": name,
+                }
+            }));
+        }
+        let uris = uris
+            .iter()
+            .enumerate()
+            .map(|(i, file)| {
+                json!({ "extensionUriAnchor": i + 1, "uri": format!("{EXTENSIONS_URI}{file}") })
+            })
+            .collect::<Vec<_>>();
+
+        json!({
+            "version": { "minorNumber": SUBSTRAIT_MINOR_VERSION, "producer": "polars" },
+            "extensionUris": uris,
+            "extensions": extensions,
+            "relations": [root],
+        })
+    }
+
+    fn function_reference(&mut self, file: &'static str, name: &'static str) -> usize {
+        let position = self
+            .functions
+            .iter()
+            .position(|function| *function == (file, name));
+        let index = position.unwrap_or_else(|| {
+            self.functions.push((file, name));
+            self.functions.len() - 1
+        });
+        index + 1
+    }
+
+    fn input_schema(&self, input: Node) -> SchemaRef {
+        self.lp_arena.get(input).schema(self.lp_arena).into_owned()
+    }
+
+    fn rel(&mut self, node: Node) -> PolarsResult<Value> {
+        use IR::*;
+        let lp_arena = self.lp_arena;
+        let lp = lp_arena.get(node);
+        let rel = match lp {
+            Scan {
+                sources,
+                file_info,
+                hive_parts,
+                predicate,
+                output_schema,
+                scan_type,
+                file_options,
+            } => {
+                polars_ensure!(
+                    hive_parts.is_none()
+                        && file_options.row_index.is_none()
+                        && file_options.include_file_paths.is_none(),
+                    InvalidOperation: "converting a scan with hive partitions, a row index or a \
+                    file path column to Substrait is not supported"
+                );
+                polars_ensure!(
+                    predicate.is_none() || file_options.pre_slice.is_none(),
+                    InvalidOperation: "converting a scan with both a predicate and a slice to \
+                    Substrait is not supported"
+                );
+                let format = match &**scan_type {
+                    #[cfg(feature = "parquet")]
+                    FileScan::Parquet { .. } => "parquet",
+                    #[cfg(feature = "ipc")]
+                    FileScan::Ipc { .. } => "arrow",
+                    _ => polars_bail!(
+                        InvalidOperation: "converting a {} scan to Substrait is not supported",
+                        lp.name()
+                    ),
+                };
+                let Some(paths) = sources.as_paths() else {
+                    polars_bail!(
+                        InvalidOperation: "only scans of files can be converted to Substrait"
+                    );
+                };
+                let items = paths
+                    .iter()
+                    .map(|path| json!({ "uriFile": file_uri(path), format: {} }))
+                    .collect::<Vec<_>>();
+
+                let schema = &file_info.schema;
+                let mut read = json!({
+                    "baseSchema": named_struct(schema)?,
+                    "localFiles": { "items": items },
+                });
+                if let Some(predicate) = predicate {
+                    read["filter"] = self.expression(predicate.node(), schema, 0)?;
+                }
+                if let Some(output_schema) = output_schema {
+                    read["projection"] = mask_expression(schema, output_schema)?;
+                }
+                let rel = json!({ "read": read });
+                match file_options.pre_slice {
+                    Some((offset, len)) => fetch(rel, offset, len)?,
+                    None => rel,
+                }
+            },
+            DataFrameScan {
+                df,
+                schema,
+                output_schema,
+            } => {
+                let columns = df.get_columns();
+                let rows = (0..df.height())
+                    .map(|i| {
+                        let fields = columns
+                            .iter()
+                            .map(|c| Ok(json!({ "literal": literal(&c.get(i)?, c.dtype())? })))
+                            .collect::<PolarsResult<Vec<_>>>()?;
+                        Ok(json!({ "fields": fields }))
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+
+                let mut read = json!({
+                    "baseSchema": named_struct(schema)?,
+                    "virtualTable": { "expressions": rows },
+                });
+                if let Some(output_schema) = output_schema {
+                    read["projection"] = mask_expression(schema, output_schema)?;
+                }
+                json!({ "read": read })
+            },
+            Filter { input, predicate } => {
+                let schema = self.input_schema(*input);
+                json!({
+                    "filter": {
+                        "input": self.rel(*input)?,
+                        "condition": self.expression(predicate.node(), &schema, 0)?,
+                    }
+                })
+            },
+            Select { input, expr, .. } => {
+                let schema = self.input_schema(*input);
+                let expr_arena = self.expr_arena;
+                let is_agg =
+                    |e: &ExprIR| matches!(expr_arena.get(e.node()), AExpr::Agg(_) | AExpr::Len);
+                if expr.iter().any(is_agg) {
+                    polars_ensure!(
+                        expr.iter().all(is_agg),
+                        InvalidOperation: "converting a selection that mixes aggregations with \
+                        other expressions to Substrait is not supported"
+                    );
+                    self.aggregate(*input, &[], expr, &schema)?
+                } else {
+                    let expressions = expr
+                        .iter()
+                        .map(|e| self.expression(e.node(), &schema, 0))
+                        .collect::<PolarsResult<Vec<_>>>()?;
+                    let mapping = (schema.len()..schema.len() + expr.len()).collect::<Vec<_>>();
+                    project(self.rel(*input)?, expressions, mapping)
+                }
+            },
+            HStack {
+                input,
+                exprs,
+                schema: output_schema,
+                ..
+            } => {
+                let schema = self.input_schema(*input);
+                let expressions = exprs
+                    .iter()
+                    .map(|e| self.expression(e.node(), &schema, 0))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                let mapping = output_schema
+                    .iter_names()
+                    .map(
+                        |name| match exprs.iter().position(|e| e.output_name() == name) {
+                            Some(i) => Ok(schema.len() + i),
+                            None => schema.try_index_of(name),
+                        },
+                    )
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                project(self.rel(*input)?, expressions, mapping)
+            },
+            SimpleProjection { input, columns } => {
+                let schema = self.input_schema(*input);
+                let mapping = columns
+                    .iter_names()
+                    .map(|name| schema.try_index_of(name))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                project(self.rel(*input)?, vec![], mapping)
+            },
+            Sort {
+                input,
+                by_column,
+                slice,
+                sort_options,
+            } => {
+                let schema = self.input_schema(*input);
+                let option = |options: &[bool], i: usize| {
+                    if options.len() == 1 {
+                        options[0]
+                    } else {
+                        options[i]
+                    }
+                };
+                let sorts = by_column
+                    .iter()
+                    .enumerate()
+                    .map(|(i, e)| {
+                        let direction = match (
+                            option(&sort_options.descending, i),
+                            option(&sort_options.nulls_last, i),
+                        ) {
+                            (false, false) => "SORT_DIRECTION_ASC_NULLS_FIRST",
+                            (false, true) => "SORT_DIRECTION_ASC_NULLS_LAST",
+                            (true, false) => "SORT_DIRECTION_DESC_NULLS_FIRST",
+                            (true, true) => "SORT_DIRECTION_DESC_NULLS_LAST",
+                        };
+                        Ok(json!({
+                            "expr": self.expression(e.node(), &schema, 0)?,
+                            "direction": direction,
+                        }))
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                let rel = json!({ "sort": { "input": self.rel(*input)?, "sorts": sorts } });
+                match slice {
+                    Some((offset, len)) => fetch(rel, *offset, *len)?,
+                    None => rel,
+                }
+            },
+            Slice { input, offset, len } => fetch(self.rel(*input)?, *offset, *len as usize)?,
+            Cache { input, .. } => self.rel(*input)?,
+            GroupBy {
+                input,
+                keys,
+                aggs,
+                options,
+                apply,
+                ..
+            } => {
+                polars_ensure!(
+                    apply.is_none() && !options.is_dynamic() && !options.is_rolling(),
+                    InvalidOperation: "converting a dynamic, rolling or custom group-by to \
+                    Substrait is not supported"
+                );
+                let schema = self.input_schema(*input);
+                let rel = self.aggregate(*input, keys, aggs, &schema)?;
+                match options.slice {
+                    Some((offset, len)) => fetch(rel, offset, len)?,
+                    None => rel,
+                }
+            },
+            Join {
+                input_left,
+                input_right,
+                schema,
+                left_on,
+                right_on,
+                options,
+            } => self.join(
+                *input_left,
+                *input_right,
+                schema,
+                left_on,
+                right_on,
+                options,
+            )?,
+            Distinct { input, options } => {
+                let schema = self.input_schema(*input);
+                let all_columns = options
+                    .subset
+                    .as_ref()
+                    .is_none_or(|subset| subset.len() == schema.len());
+                polars_ensure!(
+                    all_columns && !matches!(options.keep_strategy, UniqueKeepStrategy::None),
+                    InvalidOperation: "converting a distinct over a subset of the columns or \
+                    keeping no duplicates to Substrait is not supported"
+                );
+                // Grouping by all columns without measures keeps one of each distinct row.
+                let keys = (0..schema.len()).map(field_reference).collect::<Vec<_>>();
+                let rel = json!({
+                    "aggregate": {
+                        "input": self.rel(*input)?,
+                        "groupings": [{ "groupingExpressions": keys }],
+                    }
+                });
+                match options.slice {
+                    Some((offset, len)) => fetch(rel, offset, len)?,
+                    None => rel,
+                }
+            },
+            Union { inputs, options } => {
+                let inputs = inputs
+                    .iter()
+                    .map(|input| self.rel(*input))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                let rel = json!({ "set": { "inputs": inputs, "op": "SET_OP_UNION_ALL" } });
+                match options.slice {
+                    Some((offset, len)) => fetch(rel, offset, len)?,
+                    None => rel,
+                }
+            },
+            _ => polars_bail!(
+                InvalidOperation: "converting a {} node to Substrait is not supported",
+                lp.name()
+            ),
+        };
+        Ok(rel)
+    }
+
+    fn join(
+        &mut self,
+        input_left: Node,
+        input_right: Node,
+        schema: &Schema,
+        left_on: &[ExprIR],
+        right_on: &[ExprIR],
+        options: &JoinOptions,
+    ) -> PolarsResult<Value> {
+        let args = &options.args;
+        let join_type = match args.how {
+            JoinType::Inner => "JOIN_TYPE_INNER",
+            JoinType::Left => "JOIN_TYPE_LEFT",
+            JoinType::Right if !args.should_coalesce() => "JOIN_TYPE_RIGHT",
+            JoinType::Full if !args.should_coalesce() => "JOIN_TYPE_OUTER",
+            #[cfg(feature = "semi_anti_join")]
+            JoinType::Semi => "JOIN_TYPE_LEFT_SEMI",
+            #[cfg(feature = "semi_anti_join")]
+            JoinType::Anti => "JOIN_TYPE_LEFT_ANTI",
+            JoinType::Cross => "",
+            _ => polars_bail!(
+                InvalidOperation: "converting a {} join to Substrait is not supported", args.how
+            ),
+        };
+        polars_ensure!(
+            options.options.is_none(),
+            InvalidOperation: "converting a join with join type options to Substrait is not \
+            supported"
+        );
+
+        let left_schema = self.input_schema(input_left);
+        let right_schema = self.input_schema(input_right);
+        let left = self.rel(input_left)?;
+        let right = self.rel(input_right)?;
+
+        let mut rel = if matches!(args.how, JoinType::Cross) {
+            json!({ "cross": { "left": left, "right": right } })
+        } else {
+            let equal = if args.nulls_equal {
+                "is_not_distinct_from"
+            } else {
+                "equal"
+            };
+            let mut condition = None;
+            for (l, r) in left_on.iter().zip(right_on) {
+                let arguments = vec![
+                    self.expression(l.node(), &left_schema, 0)?,
+                    self.expression(r.node(), &right_schema, left_schema.len())?,
+                ];
+                let key = self.scalar_function(COMPARISON, equal, arguments, &DataType::Boolean)?;
+                condition = Some(match condition {
+                    None => key,
+                    Some(condition) => {
+                        let arguments = vec![condition, key];
+                        self.scalar_function(BOOLEAN, "and", arguments, &DataType::Boolean)?
+                    },
+                });
+            }
+            let Some(condition) = condition else {
+                polars_bail!(InvalidOperation: "cannot convert a join without keys to Substrait");
+            };
+            json!({
+                "join": {
+                    "left": left,
+                    "right": right,
+                    "expression": condition,
+                    "type": join_type,
+                }
+            })
+        };
+
+        // The output of a join has all columns of both inputs, select and order them like Polars.
+        if !join_type.ends_with("SEMI") && !join_type.ends_with("ANTI") {
+            let mapping = schema
+                .iter_names()
+                .map(|name| {
+                    if let Some(i) = left_schema.index_of(name) {
+                        return Ok(i);
+                    }
+                    let right_name = match right_schema.contains(name) {
+                        true => name.as_str(),
+                        false => name.strip_suffix(args.suffix().as_str()).unwrap_or(name),
+                    };
+                    Ok(left_schema.len() + right_schema.try_index_of(right_name)?)
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+            let rel_type = if matches!(args.how, JoinType::Cross) {
+                "cross"
+            } else {
+                "join"
+            };
+            rel[rel_type]["common"] = emit(mapping);
+        }
+
+        match args.slice {
+            Some((offset, len)) => fetch(rel, offset, len),
+            None => Ok(rel),
+        }
+    }
+
+    fn aggregate(
+        &mut self,
+        input: Node,
+        keys: &[ExprIR],
+        aggs: &[ExprIR],
+        schema: &Schema,
+    ) -> PolarsResult<Value> {
+        let keys = keys
+            .iter()
+            .map(|e| self.expression(e.node(), schema, 0))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let measures = aggs
+            .iter()
+            .map(|e| self.measure(e, schema))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let groupings = match keys.is_empty() {
+            true => vec![],
+            false => vec![json!({ "groupingExpressions": keys })],
+        };
+        Ok(json!({
+            "aggregate": {
+                "input": self.rel(input)?,
+                "groupings": groupings,
+                "measures": measures,
+            }
+        }))
+    }
+
+    fn measure(&mut self, e: &ExprIR, schema: &Schema) -> PolarsResult<Value> {
+        use IRAggExpr::*;
+        let expr_arena = self.expr_arena;
+        let output_type =
+            expr_arena
+                .get(e.node())
+                .to_dtype(schema, Context::Default, expr_arena)?;
+
+        let (file, name, input, distribution) = match expr_arena.get(e.node()) {
+            AExpr::Len | AExpr::Agg(Count(_, true)) => (AGGREGATE_GENERIC, "count", None, None),
+            AExpr::Agg(agg) => match agg {
+                Count(input, false) => (AGGREGATE_GENERIC, "count", Some(*input), None),
+                Sum(input) => (ARITHMETIC, "sum", Some(*input), None),
+                Mean(input) => (ARITHMETIC, "avg", Some(*input), None),
+                Min { input, .. } => (ARITHMETIC, "min", Some(*input), None),
+                Max { input, .. } => (ARITHMETIC, "max", Some(*input), None),
+                Std(input, ddof @ (0 | 1)) => (ARITHMETIC, "std_dev", Some(*input), Some(*ddof)),
+                Var(input, ddof @ (0 | 1)) => (ARITHMETIC, "variance", Some(*input), Some(*ddof)),
+                _ => polars_bail!(
+                    InvalidOperation: "converting the aggregation {} to Substrait is not supported",
+                    ExprIRDisplay::display_node(e.node(), expr_arena)
+                ),
+            },
+            _ => polars_bail!(
+                InvalidOperation: "converting the aggregation {} to Substrait is not supported",
+                ExprIRDisplay::display_node(e.node(), expr_arena)
+            ),
+        };
+
+        let arguments = match input {
+            Some(input) => vec![json!({ "value": self.expression(input, schema, 0)? })],
+            None => vec![],
+        };
+        let mut measure = json!({
+            "functionReference": self.function_reference(file, name),
+            "arguments": arguments,
+            "outputType": data_type(&output_type)?,
+            "phase": "AGGREGATION_PHASE_INITIAL_TO_RESULT",
+            "invocation": "AGGREGATION_INVOCATION_ALL",
+        });
+        if let Some(ddof) = distribution {
+            let distribution = if ddof == 0 { "POPULATION" } else { "SAMPLE" };
+            measure["options"] = json!([{ "name": "distribution", "preference": [distribution] }]);
+        }
+        Ok(json!({ "measure": measure }))
+    }
+
+    fn scalar_function(
+        &mut self,
+        file: &'static str,
+        name: &'static str,
+        arguments: Vec<Value>,
+        output_type: &DataType,
+    ) -> PolarsResult<Value> {
+        let arguments = arguments
+            .into_iter()
+            .map(|value| json!({ "value": value }))
+            .collect::<Vec<_>>();
+        Ok(json!({
+            "scalarFunction": {
+                "functionReference": self.function_reference(file, name),
+                "arguments": arguments,
+                "outputType": data_type(output_type)?,
+            }
+        }))
+    }
+
+    /// Converts an expression over the columns of `schema`, which start at field `offset` of the
+    /// input of the relation.
+    fn expression(&mut self, node: Node, schema: &Schema, offset: usize) -> PolarsResult<Value> {
+        let expr_arena = self.expr_arena;
+        let not_supported = || {
+            polars_err!(
+                InvalidOperation: "converting the expression {} to Substrait is not supported",
+                ExprIRDisplay::display_node(node, expr_arena)
+            )
+        };
+        let ae = expr_arena.get(node);
+        let output_type = ae.to_dtype(schema, Context::Default, expr_arena)?;
+
+        let expression = match ae {
+            AExpr::Column(name) => field_reference(schema.try_index_of(name)? + offset),
+            AExpr::Literal(lv) => {
+                let lv = lv.clone().materialize();
+                let av = lv.to_any_value().ok_or_else(not_supported)?;
+                json!({ "literal": literal(&av, &lv.get_datatype())? })
+            },
+            AExpr::BinaryExpr { left, op, right } => {
+                use Operator::*;
+                let (file, name) = match op {
+                    Eq => (COMPARISON, "equal"),
+                    EqValidity => (COMPARISON, "is_not_distinct_from"),
+                    NotEq => (COMPARISON, "not_equal"),
+                    NotEqValidity => (COMPARISON, "is_distinct_from"),
+                    Lt => (COMPARISON, "lt"),
+                    LtEq => (COMPARISON, "lte"),
+                    Gt => (COMPARISON, "gt"),
+                    GtEq => (COMPARISON, "gte"),
+                    Plus => (ARITHMETIC, "add"),
+                    Minus => (ARITHMETIC, "subtract"),
+                    Multiply => (ARITHMETIC, "multiply"),
+                    TrueDivide => (ARITHMETIC, "divide"),
+                    And | LogicalAnd if output_type.is_bool() => (BOOLEAN, "and"),
+                    Or | LogicalOr if output_type.is_bool() => (BOOLEAN, "or"),
+                    Xor if output_type.is_bool() => (BOOLEAN, "xor"),
+                    _ => return Err(not_supported()),
+                };
+                let (left, right) = (*left, *right);
+                let mut arguments = vec![
+                    self.expression(left, schema, offset)?,
+                    self.expression(right, schema, offset)?,
+                ];
+                // Substrait divides integers with integer division.
+                if matches!(op, TrueDivide) {
+                    for (argument, node) in arguments.iter_mut().zip([left, right]) {
+                        let dtype =
+                            expr_arena
+                                .get(node)
+                                .to_dtype(schema, Context::Default, expr_arena)?;
+                        if !dtype.is_float() {
+                            *argument = cast(argument.take(), &output_type, true)?;
+                        }
+                    }
+                }
+                self.scalar_function(file, name, arguments, &output_type)?
+            },
+            AExpr::Cast {
+                expr,
+                dtype,
+                options,
+            } => {
+                let input = self.expression(*expr, schema, offset)?;
+                cast(input, dtype, options.strict())?
+            },
+            AExpr::Ternary {
+                predicate,
+                truthy,
+                falsy,
+            } => {
+                let (predicate, truthy, falsy) = (*predicate, *truthy, *falsy);
+                json!({
+                    "ifThen": {
+                        "ifs": [{
+                            "if": self.expression(predicate, schema, offset)?,
+                            "then": self.expression(truthy, schema, offset)?,
+                        }],
+                        "else": self.expression(falsy, schema, offset)?,
+                    }
+                })
+            },
+            AExpr::Function {
+                input,
+                function: FunctionExpr::Boolean(function),
+                ..
+            } => {
+                let (file, name) = match function {
+                    BooleanFunction::IsNull => (COMPARISON, "is_null"),
+                    BooleanFunction::IsNotNull => (COMPARISON, "is_not_null"),
+                    BooleanFunction::Not if output_type.is_bool() => (BOOLEAN, "not"),
+                    _ => return Err(not_supported()),
+                };
+                let arguments = input
+                    .iter()
+                    .map(|e| self.expression(e.node(), schema, offset))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                self.scalar_function(file, name, arguments, &output_type)?
+            },
+            _ => return Err(not_supported()),
+        };
+        Ok(expression)
+    }
+}
+
+fn file_uri(path: &Path) -> String {
+    let path = path.to_string_lossy();
+    if path.contains("://") {
+        return path.into_owned();
+    }
+    let path = std::path::absolute(&*path)
+        .map_or(path.to_string(), |path| path.to_string_lossy().into_owned());
+    format!("file://{path}")
+}
+
+fn field_reference(field: usize) -> Value {
+    json!({
+        "selection": {
+            "directReference": { "structField": { "field": field } },
+            "rootReference": {},
+        }
+    })
+}
+
+/// A `RelCommon` that outputs the given fields of the relation.
+fn emit(mapping: Vec<usize>) -> Value {
+    json!({ "emit": { "outputMapping": mapping } })
+}
+
+/// Appends `expressions` to the fields of `input` and outputs the fields in `mapping`.
+fn project(input: Value, expressions: Vec<Value>, mapping: Vec<usize>) -> Value {
+    json!({
+        "project": {
+            "common": emit(mapping),
+            "input": input,
+            "expressions": expressions,
+        }
+    })
+}
+
+fn fetch(input: Value, offset: i64, len: usize) -> PolarsResult<Value> {
+    polars_ensure!(
+        offset >= 0,
+        InvalidOperation: "converting a slice with a negative offset to Substrait is not supported"
+    );
+    // 64-bit integers are encoded as strings.
+    Ok(json!({
+        "fetch": {
+            "input": input,
+            "offset": offset.to_string(),
+            "count": len.to_string(),
+        }
+    }))
+}
+
+fn cast(input: Value, dtype: &DataType, strict: bool) -> PolarsResult<Value> {
+    let failure_behavior = if strict {
+        "FAILURE_BEHAVIOR_THROW_EXCEPTION"
+    } else {
+        "FAILURE_BEHAVIOR_RETURN_NULL"
+    };
+    Ok(json!({
+        "cast": {
+            "type": data_type(dtype)?,
+            "input": input,
+            "failureBehavior": failure_behavior,
+        }
+    }))
+}
+
+/// Selects the columns of `output_schema` from the columns of `schema` in a read.
+fn mask_expression(schema: &Schema, output_schema: &Schema) -> PolarsResult<Value> {
+    let items = output_schema
+        .iter_names()
+        .map(|name| Ok(json!({ "field": schema.try_index_of(name)? })))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    Ok(json!({ "select": { "structItems": items }, "maintainSingularStruct": true }))
+}
+
+/// The names of the fields of `schema`, including the names of nested struct fields in depth-first
+/// order, as Substrait expects them.
+fn field_names(schema: &Schema) -> Vec<PlSmallStr> {
+    fn push_names(name: &PlSmallStr, dtype: &DataType, names: &mut Vec<PlSmallStr>) {
+        names.push(name.clone());
+        #[cfg(feature = "dtype-struct")]
+        if let DataType::Struct(fields) = dtype {
+            for field in fields {
+                push_names(&field.name, &field.dtype, names);
+            }
+        }
+        #[cfg(not(feature = "dtype-struct"))]
+        let _ = dtype;
+    }
+
+    let mut names = Vec::with_capacity(schema.len());
+    for (name, dtype) in schema.iter() {
+        push_names(name, dtype, &mut names);
+    }
+    names
+}
+
+fn named_struct(schema: &Schema) -> PolarsResult<Value> {
+    let types = schema
+        .iter_values()
+        .map(data_type)
+        .collect::<PolarsResult<Vec<_>>>()?;
+    Ok(json!({
+        "names": field_names(schema),
+        "struct": { "types": types, "nullability": "NULLABILITY_REQUIRED" },
+    }))
+}
+
+fn time_unit_precision(tu: TimeUnit) -> u8 {
+    match tu {
+        TimeUnit::Milliseconds => 3,
+        TimeUnit::Microseconds => 6,
+        TimeUnit::Nanoseconds => 9,
+    }
+}
+
+fn data_type(dtype: &DataType) -> PolarsResult<Value> {
+    let simple = |kind: &str| json!({ kind: { "nullability": NULLABLE } });
+    let out = match dtype {
+        DataType::Boolean => simple("bool"),
+        DataType::Int8 => simple("i8"),
+        DataType::Int16 | DataType::UInt8 => simple("i16"),
+        DataType::Int32 | DataType::UInt16 => simple("i32"),
+        DataType::Int64 | DataType::UInt32 => simple("i64"),
+        DataType::Float32 => simple("fp32"),
+        DataType::Float64 => simple("fp64"),
+        DataType::String => simple("string"),
+        DataType::Binary => simple("binary"),
+        DataType::Date => simple("date"),
+        DataType::Datetime(tu, tz) => {
+            let kind = match tz {
+                None => "precisionTimestamp",
+                Some(_) => "precisionTimestampTz",
+            };
+            json!({ kind: { "precision": time_unit_precision(*tu), "nullability": NULLABLE } })
+        },
+        #[cfg(feature = "dtype-decimal")]
+        DataType::Decimal(precision, scale) => json!({
+            "decimal": {
+                "precision": precision.unwrap_or(38),
+                "scale": scale.unwrap_or(0),
+                "nullability": NULLABLE,
+            }
+        }),
+        DataType::List(inner) => {
+            json!({ "list": { "type": data_type(inner)?, "nullability": NULLABLE } })
+        },
+        #[cfg(feature = "dtype-struct")]
+        DataType::Struct(fields) => {
+            let types = fields
+                .iter()
+                .map(|field| data_type(&field.dtype))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            json!({ "struct": { "types": types, "nullability": NULLABLE } })
+        },
+        _ => polars_bail!(
+            InvalidOperation: "converting the data type {} to Substrait is not supported", dtype
+        ),
+    };
+    Ok(out)
+}
+
+fn literal(value: &AnyValue, dtype: &DataType) -> PolarsResult<Value> {
+    let out = match value {
+        AnyValue::Null => json!({ "null": data_type(dtype)? }),
+        AnyValue::Boolean(v) => json!({ "boolean": v }),
+        AnyValue::Int8(v) => json!({ "i8": v }),
+        AnyValue::Int16(v) => json!({ "i16": v }),
+        AnyValue::UInt8(v) => json!({ "i16": v }),
+        AnyValue::Int32(v) => json!({ "i32": v }),
+        AnyValue::UInt16(v) => json!({ "i32": v }),
+        // 64-bit integers are encoded as strings.
+        AnyValue::Int64(v) => json!({ "i64": v.to_string() }),
+        AnyValue::UInt32(v) => json!({ "i64": v.to_string() }),
+        AnyValue::Float32(v) => json!({ "fp32": v }),
+        AnyValue::Float64(v) => json!({ "fp64": v }),
+        AnyValue::String(v) => json!({ "string": v }),
+        AnyValue::StringOwned(v) => json!({ "string": v.as_str() }),
+        #[cfg(feature = "dtype-date")]
+        AnyValue::Date(v) => json!({ "date": v }),
+        #[cfg(feature = "dtype-datetime")]
+        AnyValue::Datetime(v, tu, tz) => {
+            let kind = match tz {
+                None => "precisionTimestamp",
+                Some(_) => "precisionTimestampTz",
+            };
+            json!({ kind: { "precision": time_unit_precision(*tu), "value": v.to_string() } })
+        },
+        _ => polars_bail!(
+            InvalidOperation: "converting the literal {} to Substrait is not supported", value
+        ),
+    };
+    Ok(out)
+}
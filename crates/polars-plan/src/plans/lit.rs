@@ -5,7 +5,6 @@ use chrono::{Duration as ChronoDuration, NaiveDate, NaiveDateTime};
 use polars_core::chunked_array::cast::CastOptions;
 use polars_core::prelude::*;
 use polars_core::utils::materialize_dyn_int;
-use polars_utils::hashing::hash_to_partition;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -62,6 +61,13 @@ pub struct RangeLiteralValue {
     pub high: i128,
     pub dtype: DataType,
 }
+/// A named value of a fixed type that is only known when the query runs, see [`placeholder`].
+#[derive(Clone, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PlaceholderLiteralValue {
+    pub name: PlSmallStr,
+    pub dtype: DataType,
+}
 #[derive(Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum LiteralValue {
@@ -70,6 +76,9 @@ pub enum LiteralValue {
     Scalar(Scalar),
     Series(SpecialEq<Series>),
     Range(RangeLiteralValue),
+    /// A value that is bound when the query runs. This needs to be replaced by a scalar with
+    /// [`bind_placeholders`] before it is evaluated.
+    Placeholder(PlaceholderLiteralValue),
 }
 
 pub enum MaterializedLiteralValue {
@@ -252,6 +261,9 @@ impl LiteralValue {
             L::Series(s) => Ok(MaterializedLiteralValue::Series(
                 s.cast_with_options(dtype, CastOptions::Strict)?,
             )),
+            L::Placeholder(p) => polars_bail!(
+                InvalidOperation: "placeholder `{}` is not bound to a value", p.name
+            ),
         }
     }
 
@@ -306,7 +318,7 @@ impl LiteralValue {
                 let s = range.clone().try_materialize_to_series(&range.dtype).ok()?;
                 AnyValue::List(s)
             },
-            Self::Series(_) => return None,
+            Self::Series(_) | Self::Placeholder(_) => return None,
             Self::Dyn(d) => match d {
                 DynLiteralValue::Int(v) => materialize_dyn_int(*v),
                 DynLiteralValue::Float(v) => AnyValue::Float64(*v),
@@ -329,6 +341,7 @@ impl LiteralValue {
             Self::Scalar(sc) => sc.dtype().clone(),
             Self::Series(s) => s.dtype().clone(),
             Self::Range(s) => s.dtype.clone(),
+            Self::Placeholder(p) => p.dtype.clone(),
         }
     }
 
@@ -607,27 +620,71 @@ pub fn typed_lit<L: TypedLiteral>(t: L) -> Expr {
     t.typed_lit()
 }
 
+/// Create a placeholder for a value of type `dtype` that is bound when the query runs, like a
+/// parameter of a prepared statement.
+///
+/// The values are given when collecting with the streaming engine, e.g.
+/// `lf.filter(col("a").gt(placeholder("min", DataType::Int64)))` can be collected many times with
+/// different values of `min` while reusing the optimized plan from the plan cache.
+pub fn placeholder(name: impl Into<PlSmallStr>, dtype: DataType) -> Expr {
+    Expr::Literal(LiteralValue::Placeholder(PlaceholderLiteralValue {
+        name: name.into(),
+        dtype,
+    }))
+}
+
+/// Replaces the placeholders in the expression arena with the values in `params`, cast to the
+/// types of the placeholders.
+pub fn bind_placeholders(
+    expr_arena: &mut Arena<AExpr>,
+    params: &PlHashMap<PlSmallStr, Scalar>,
+) -> PolarsResult<()> {
+    for i in 0..expr_arena.len() {
+        let node = Node(i);
+        let AExpr::Literal(LiteralValue::Placeholder(p)) = expr_arena.get(node) else {
+            continue;
+        };
+        let Some(value) = params.get(&p.name) else {
+            polars_bail!(InvalidOperation: "no value given for placeholder `{}`", p.name);
+        };
+        let value = value
+            .clone()
+            .cast_with_options(&p.dtype, CastOptions::Strict)?;
+        expr_arena.replace(node, AExpr::Literal(value.into()));
+    }
+    Ok(())
+}
+
+/// Returns whether the expression arena has placeholders that are not bound yet.
+pub fn has_placeholders(expr_arena: &Arena<AExpr>) -> bool {
+    (0..expr_arena.len()).any(|i| {
+        matches!(
+            expr_arena.get(Node(i)),
+            AExpr::Literal(LiteralValue::Placeholder(_))
+        )
+    })
+}
+
 impl Hash for LiteralValue {
     fn hash<H: Hasher>(&self, state: &mut H) {
         std::mem::discriminant(self).hash(state);
         match self {
             LiteralValue::Series(s) => {
-                // Free stats
                 s.dtype().hash(state);
-                let len = s.len();
-                len.hash(state);
+                s.len().hash(state);
+                // Only hash a cheap fingerprint of the values, equal hashes are compared in full
+                // with `PartialEq`.
                 s.null_count().hash(state);
-                const RANDOM: u64 = 0x2c194fa5df32a367;
-                let mut rng = (len as u64) ^ RANDOM;
-                for _ in 0..std::cmp::min(5, len) {
-                    let idx = hash_to_partition(rng, len);
+                const NUM_SAMPLES: usize = 16;
+                let step = s.len().div_ceil(NUM_SAMPLES).max(1);
+                for idx in (0..s.len()).step_by(step).chain(s.len().checked_sub(1)) {
                     s.get(idx).unwrap().hash(state);
-                    rng = rng.rotate_right(17).wrapping_add(RANDOM);
                 }
             },
             LiteralValue::Range(range) => range.hash(state),
             LiteralValue::Scalar(sc) => sc.hash(state),
             LiteralValue::Dyn(d) => d.hash(state),
+            LiteralValue::Placeholder(p) => p.hash(state),
         }
     }
 }
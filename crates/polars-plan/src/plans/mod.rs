@@ -25,6 +25,7 @@ pub mod python;
 #[cfg(feature = "python")]
 pub use python::*;
 mod schema;
+pub mod statistics;
 pub mod visitor;
 
 pub use aexpr::*;
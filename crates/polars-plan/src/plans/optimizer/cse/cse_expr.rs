@@ -251,6 +251,9 @@ struct ExprIdentifierVisitor<'a> {
     has_sub_expr: bool,
     // During aggregation we only identify element-wise operations
     is_group_by: bool,
+    // Only identify element-wise operations, so that the temporary columns have the length of the
+    // dataframe.
+    elementwise_only: bool,
 }
 
 impl ExprIdentifierVisitor<'_> {
@@ -259,6 +262,7 @@ impl ExprIdentifierVisitor<'_> {
         identifier_array: &'a mut IdentifierArray,
         visit_stack: &'a mut Vec<VisitRecord>,
         is_group_by: bool,
+        elementwise_only: bool,
         name_validation: &'a mut PlHashMap<u64, u32>,
     ) -> ExprIdentifierVisitor<'a> {
         let id_array_offset = identifier_array.len();
@@ -272,6 +276,7 @@ impl ExprIdentifierVisitor<'_> {
             id_array_offset,
             has_sub_expr: false,
             is_group_by,
+            elementwise_only,
         }
     }
 
@@ -327,7 +332,7 @@ impl ExprIdentifierVisitor<'_> {
             },
             AExpr::Column(_) | AExpr::Alias(_, _) => REFUSE_ALLOW_MEMBER,
             AExpr::Len => {
-                if self.is_group_by {
+                if self.is_group_by || self.elementwise_only {
                     REFUSE_NO_MEMBER
                 } else {
                     REFUSE_ALLOW_MEMBER
@@ -348,7 +353,7 @@ impl ExprIdentifierVisitor<'_> {
                 // During aggregation we only store elementwise operation in the state
                 // other operations we cannot add to the state as they have the output size of the
                 // groups, not the original dataframe
-                if self.is_group_by {
+                if self.is_group_by || self.elementwise_only {
                     if !ae.is_elementwise_top_level() {
                         return REFUSE_NO_MEMBER;
                     }
@@ -612,11 +617,16 @@ pub(crate) struct CommonSubExprOptimizer {
     // these are cleared per expr node
     visit_stack: Vec<VisitRecord>,
     name_validation: PlHashMap<u64, u32>,
+    /// Only eliminate element-wise sub-expressions in all contexts. The new streaming engine
+    /// broadcasts the temporary columns to the length of the dataframe, so temporaries of other
+    /// lengths, e.g. aggregations in a `select`, would change the result.
+    elementwise_only: bool,
 }
 
 impl CommonSubExprOptimizer {
-    pub(crate) fn new() -> Self {
+    pub(crate) fn new(elementwise_only: bool) -> Self {
         Self {
+            elementwise_only,
             se_count: Default::default(),
             id_array: Default::default(),
             visit_stack: Default::default(),
@@ -637,6 +647,7 @@ impl CommonSubExprOptimizer {
             &mut self.id_array,
             &mut self.visit_stack,
             is_group_by,
+            self.elementwise_only,
             &mut self.name_validation,
         );
         ae_node.visit(&mut visitor, expr_arena).map(|_| ())?;
@@ -0,0 +1,100 @@
+//! Rewrite rules registered from outside of polars, e.g. to replace an expensive UDF with a
+//! specialized scan in a domain-specific library.
+//!
+//! The rules are [`OptimizationRule`]s, so they see every node of the plan with the IR and
+//! expression arenas. The schema of a node is available with `lp_arena.get(node).schema(lp_arena)`.
+//! A rule must keep the schema of the nodes it rewrites.
+use std::sync::{Arc, LazyLock};
+
+use parking_lot::RwLock;
+use polars_core::prelude::*;
+
+use super::{OptimizationRule, StackOptimizer};
+use crate::prelude::*;
+
+/// The stage of the optimization a custom rule runs at.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum OptimizationStage {
+    /// Before the built-in optimizations, on the IR as converted from the DSL.
+    Start,
+    /// After predicate and projection pushdown, so the scans have their filters and projections.
+    AfterPushdown,
+    /// After all built-in optimizations, right before the plan is executed.
+    End,
+}
+
+/// Creates a new instance of a custom rule for every query that is optimized.
+pub type OptimizationRuleFactory = Arc<dyn Fn() -> Box<dyn OptimizationRule> + Send + Sync>;
+
+struct CustomRule {
+    name: PlSmallStr,
+    stage: OptimizationStage,
+    factory: OptimizationRuleFactory,
+}
+
+#[derive(Default)]
+struct CustomRules {
+    /// Changes whenever a rule is registered or removed.
+    version: u64,
+    rules: Vec<CustomRule>,
+}
+
+static CUSTOM_RULES: LazyLock<RwLock<CustomRules>> = LazyLock::new(Default::default);
+
+/// Registers a rewrite rule that runs at `stage` when optimizing any query of the process,
+/// replacing the rule with the same name. Rules of the same stage run in the order they were
+/// registered.
+pub fn register_optimization_rule(
+    name: impl Into<PlSmallStr>,
+    stage: OptimizationStage,
+    factory: OptimizationRuleFactory,
+) {
+    let name = name.into();
+    let mut custom_rules = CUSTOM_RULES.write();
+    let rule = CustomRule {
+        name,
+        stage,
+        factory,
+    };
+    match custom_rules.rules.iter_mut().find(|r| r.name == rule.name) {
+        Some(r) => *r = rule,
+        None => custom_rules.rules.push(rule),
+    }
+    custom_rules.version += 1;
+}
+
+/// Removes the rewrite rule with the given name, returning whether it was registered.
+pub fn unregister_optimization_rule(name: &str) -> bool {
+    let mut custom_rules = CUSTOM_RULES.write();
+    let len = custom_rules.rules.len();
+    custom_rules.rules.retain(|r| r.name != name);
+    let removed = custom_rules.rules.len() != len;
+    custom_rules.version += removed as u64;
+    removed
+}
+
+/// The version of the set of registered rules, which changes whenever a rule is registered or
+/// removed. Caches of optimized plans include it in their key.
+pub fn optimization_rules_version() -> u64 {
+    CUSTOM_RULES.read().version
+}
+
+/// Runs the custom rules of `stage` until they reach a fixed point.
+pub(super) fn run_custom_rules(
+    stage: OptimizationStage,
+    lp_top: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<Node> {
+    let mut rules = CUSTOM_RULES
+        .read()
+        .rules
+        .iter()
+        .filter(|r| r.stage == stage)
+        .map(|r| (r.factory)())
+        .collect::<Vec<_>>();
+    if rules.is_empty() {
+        return Ok(lp_top);
+    }
+    StackOptimizer {}.optimize_loop(&mut rules, expr_arena, lp_arena, lp_top)
+}
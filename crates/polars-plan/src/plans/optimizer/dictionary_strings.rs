@@ -0,0 +1,421 @@
+//! Optimization that keeps low-cardinality string columns dictionary-encoded while they flow
+//! through the plan.
+//!
+//! String columns of Parquet scans that have a dictionary in every row group of the file are read
+//! as `Categorical`, so the Parquet reader decodes their dictionaries instead of expanding the
+//! strings. Columns the filter of the scan refers to are left alone. They stay categorical through
+//! the operations that give the same result on the codes as on the strings:
+//!
+//! - filters that only compare them with string literals or check them for nulls,
+//! - selections and projections that pass them through unchanged,
+//! - the keys of group-bys,
+//! - the payload of joins. The keys of joins are decoded, as the codes of both sides differ.
+//!
+//! Before any other operation, and at the root of the plan, they are cast back to `String`, so
+//! the values are only materialized for the rows that are left at that point.
+
+use either::Either;
+use polars_core::chunked_array::cast::CastOptions;
+use polars_core::prelude::*;
+use polars_io::prelude::read_strings_as_categorical;
+use polars_ops::frame::JoinType;
+use polars_utils::arena::{Arena, Node};
+use polars_utils::format_pl_smallstr;
+
+use super::{AExpr, IR};
+use crate::dsl::{BooleanFunction, FunctionExpr, Operator};
+use crate::plans::{ExprIR, FileScan, IRBuilder, OutputName, ProjectionOptions};
+use crate::utils::aexpr_to_leaf_names_iter;
+
+type Encoded = PlHashSet<PlSmallStr>;
+
+pub fn optimize(
+    root: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<()> {
+    let mut opt = DictionaryStrings {
+        lp_arena,
+        expr_arena,
+    };
+    let encoded = opt.rewrite(root)?;
+    if !encoded.is_empty() {
+        // Keep the node of the root, the caller refers to it.
+        let ir = opt.lp_arena.take(root);
+        let input = opt.lp_arena.add(ir);
+        let input = opt.materialize(input, &encoded);
+        let ir = opt.lp_arena.take(input);
+        opt.lp_arena.replace(root, ir);
+    }
+    Ok(())
+}
+
+struct DictionaryStrings<'a> {
+    lp_arena: &'a mut Arena<IR>,
+    expr_arena: &'a mut Arena<AExpr>,
+}
+
+fn column_name(node: Node, expr_arena: &Arena<AExpr>) -> Option<&PlSmallStr> {
+    match expr_arena.get(node) {
+        AExpr::Column(name) => Some(name),
+        _ => None,
+    }
+}
+
+/// Drop the cached dtype of an expression, as its input may have changed.
+fn refresh(e: &ExprIR) -> ExprIR {
+    ExprIR::new(e.node(), e.output_name_inner().clone())
+}
+
+/// The encoded columns `node` uses other than by comparing them with string literals or checking
+/// them for nulls.
+fn decoded_uses(node: Node, encoded: &Encoded, expr_arena: &Arena<AExpr>, out: &mut Encoded) {
+    let is_encoded = |n: Node| column_name(n, expr_arena).is_some_and(|c| encoded.contains(c));
+    let is_str_lit =
+        |n: Node| matches!(expr_arena.get(n), AExpr::Literal(lv) if lv.extract_str().is_some());
+
+    match expr_arena.get(node) {
+        AExpr::BinaryExpr { left, op, right }
+            if matches!(
+                op,
+                Operator::Eq | Operator::NotEq | Operator::EqValidity | Operator::NotEqValidity
+            ) && ((is_encoded(*left) && is_str_lit(*right))
+                || (is_str_lit(*left) && is_encoded(*right))) => {},
+        AExpr::Function {
+            input,
+            function: FunctionExpr::Boolean(BooleanFunction::IsNull | BooleanFunction::IsNotNull),
+            ..
+        } if is_encoded(input[0].node()) => {},
+        AExpr::Column(name) => {
+            if encoded.contains(name) {
+                out.insert(name.clone());
+            }
+        },
+        ae => {
+            let mut inputs = Vec::new();
+            ae.inputs_rev(&mut inputs);
+            for input in inputs {
+                decoded_uses(input, encoded, expr_arena, out);
+            }
+        },
+    }
+}
+
+impl DictionaryStrings<'_> {
+    /// All encoded columns `e` refers to.
+    fn leaves(&self, e: &ExprIR, encoded: &Encoded, out: &mut Encoded) {
+        out.extend(
+            aexpr_to_leaf_names_iter(e.node(), self.expr_arena).filter(|c| encoded.contains(c)),
+        );
+    }
+
+    /// Whether `e` passes an encoded column through unchanged.
+    fn is_pass_through(&self, e: &ExprIR, encoded: &Encoded) -> bool {
+        column_name(e.node(), self.expr_arena).is_some_and(|c| encoded.contains(c))
+    }
+
+    /// Materialize the encoded columns that `exprs` use other than by passing them through,
+    /// returning the new input and the columns that are still encoded.
+    fn decode_for(
+        &mut self,
+        input: Node,
+        mut encoded: Encoded,
+        pass_through: &[ExprIR],
+        other: &[ExprIR],
+    ) -> (Node, Encoded) {
+        let mut decoded = Encoded::default();
+        for e in pass_through {
+            if !self.is_pass_through(e, &encoded) {
+                self.leaves(e, &encoded, &mut decoded);
+            }
+        }
+        for e in other {
+            self.leaves(e, &encoded, &mut decoded);
+        }
+        encoded.retain(|c| !decoded.contains(c));
+        (self.materialize(input, &decoded), encoded)
+    }
+
+    /// The output names of the expressions that pass an encoded column through.
+    fn pass_through_names(&self, exprs: &[ExprIR], encoded: &Encoded) -> Encoded {
+        exprs
+            .iter()
+            .filter(|e| self.is_pass_through(e, encoded))
+            .map(|e| e.output_name().clone())
+            .collect()
+    }
+
+    fn cast_columns(&mut self, input: Node, columns: &Encoded, dtype: DataType) -> Node {
+        if columns.is_empty() {
+            return input;
+        }
+        let mut columns = columns.iter().cloned().collect::<Vec<_>>();
+        columns.sort();
+        let exprs = columns
+            .into_iter()
+            .map(|name| {
+                let column = self.expr_arena.add(AExpr::Column(name.clone()));
+                let node = self.expr_arena.add(AExpr::Cast {
+                    expr: column,
+                    dtype: dtype.clone(),
+                    options: CastOptions::Strict,
+                });
+                ExprIR::new(node, OutputName::ColumnLhs(name))
+            })
+            .collect();
+        IRBuilder::new(input, self.expr_arena, self.lp_arena)
+            .with_columns(exprs, ProjectionOptions::default())
+            .node()
+    }
+
+    /// Cast the encoded `columns` of `input` back to strings.
+    fn materialize(&mut self, input: Node, columns: &Encoded) -> Node {
+        self.cast_columns(input, columns, DataType::String)
+    }
+
+    /// Rewrite the plan at `node`, returning the columns of its output that are encoded.
+    fn rewrite(&mut self, node: Node) -> PolarsResult<Encoded> {
+        let ir = self.lp_arena.get(node).clone();
+        let encoded = match ir {
+            IR::Scan {
+                sources,
+                mut file_info,
+                hive_parts,
+                predicate,
+                mut output_schema,
+                scan_type,
+                file_options,
+            } => {
+                let FileScan::Parquet {
+                    metadata: Some(metadata),
+                    ..
+                } = scan_type.as_ref()
+                else {
+                    return Ok(Encoded::default());
+                };
+                // Hive columns are not read from the file.
+                let Some(Either::Left(reader_schema)) = &file_info.reader_schema else {
+                    return Ok(Encoded::default());
+                };
+                let mut filtered = Encoded::default();
+                if let Some(predicate) = &predicate {
+                    filtered.extend(aexpr_to_leaf_names_iter(predicate.node(), self.expr_arena));
+                }
+                let schema = output_schema.as_ref().unwrap_or(&file_info.schema);
+                let encoded = schema
+                    .iter()
+                    .filter(|(name, dtype)| {
+                        dtype.is_string()
+                            && reader_schema.contains(name)
+                            && !filtered.contains(*name)
+                            && !metadata.row_groups.is_empty()
+                            && metadata.row_groups.iter().all(|row_group| {
+                                row_group
+                                    .columns_under_root_iter(name)
+                                    .is_some_and(|mut c| {
+                                        c.len() == 1 && c.next().unwrap().has_dictionary_page()
+                                    })
+                            })
+                    })
+                    .map(|(name, _)| name.clone())
+                    .collect::<Encoded>();
+                if encoded.is_empty() {
+                    return Ok(encoded);
+                }
+
+                if let Some(reader_schema) =
+                    read_strings_as_categorical(reader_schema, metadata, |c| encoded.contains(c))
+                {
+                    file_info.reader_schema = Some(Either::Left(Arc::new(reader_schema)));
+                }
+                let dtype = DataType::Categorical(None, Default::default());
+                let with_categoricals = |schema: &Schema| -> SchemaRef {
+                    let schema = schema.iter().map(|(name, d)| {
+                        let d = if encoded.contains(name) { &dtype } else { d };
+                        (name.clone(), d.clone())
+                    });
+                    Arc::new(schema.collect())
+                };
+                file_info.schema = with_categoricals(&file_info.schema);
+                output_schema = output_schema.map(|schema| with_categoricals(&schema));
+                let ir = IR::Scan {
+                    sources,
+                    file_info,
+                    hive_parts,
+                    predicate,
+                    output_schema,
+                    scan_type,
+                    file_options,
+                };
+                self.lp_arena.replace(node, ir);
+                encoded
+            },
+            IR::Filter { input, predicate } => {
+                let mut encoded = self.rewrite(input)?;
+                let mut decoded = Encoded::default();
+                decoded_uses(predicate.node(), &encoded, self.expr_arena, &mut decoded);
+                let input = self.materialize(input, &decoded);
+                encoded.retain(|c| !decoded.contains(c));
+                self.lp_arena.replace(node, IR::Filter { input, predicate });
+                encoded
+            },
+            IR::Slice { input, offset, len } => {
+                let encoded = self.rewrite(input)?;
+                self.lp_arena
+                    .replace(node, IR::Slice { input, offset, len });
+                encoded
+            },
+            IR::Distinct { input, options } => {
+                let encoded = self.rewrite(input)?;
+                self.lp_arena.replace(node, IR::Distinct { input, options });
+                encoded
+            },
+            IR::Sort {
+                input,
+                by_column,
+                slice,
+                sort_options,
+            } => {
+                // The codes don't sort like the strings.
+                let encoded = self.rewrite(input)?;
+                let (input, encoded) = self.decode_for(input, encoded, &[], &by_column);
+                let by_column = by_column.iter().map(refresh).collect();
+                let ir = IR::Sort {
+                    input,
+                    by_column,
+                    slice,
+                    sort_options,
+                };
+                self.lp_arena.replace(node, ir);
+                encoded
+            },
+            IR::SimpleProjection { input, columns } => {
+                let mut encoded = self.rewrite(input)?;
+                encoded.retain(|c| columns.contains(c));
+                let ir = IRBuilder::new(input, self.expr_arena, self.lp_arena)
+                    .project_simple(columns.iter_names().cloned())?
+                    .build();
+                self.lp_arena.replace(node, ir);
+                encoded
+            },
+            IR::Select {
+                input,
+                expr,
+                options,
+                ..
+            } => {
+                let encoded = self.rewrite(input)?;
+                let (input, encoded) = self.decode_for(input, encoded, &expr, &[]);
+                let out = self.pass_through_names(&expr, &encoded);
+                let expr = expr.iter().map(refresh).collect();
+                let ir = IRBuilder::new(input, self.expr_arena, self.lp_arena)
+                    .project(expr, options)
+                    .build();
+                self.lp_arena.replace(node, ir);
+                out
+            },
+            IR::HStack {
+                input,
+                exprs,
+                options,
+                ..
+            } => {
+                let encoded = self.rewrite(input)?;
+                let (input, encoded) = self.decode_for(input, encoded, &exprs, &[]);
+                let mut out = encoded.clone();
+                for e in &exprs {
+                    out.remove(e.output_name());
+                }
+                out.extend(self.pass_through_names(&exprs, &encoded));
+                let exprs = exprs.iter().map(refresh).collect();
+                let ir = IRBuilder::new(input, self.expr_arena, self.lp_arena)
+                    .with_columns(exprs, options)
+                    .build();
+                self.lp_arena.replace(node, ir);
+                out
+            },
+            IR::GroupBy {
+                input,
+                keys,
+                aggs,
+                apply: None,
+                maintain_order,
+                options,
+                ..
+            } if !options.is_rolling() && !options.is_dynamic() => {
+                let encoded = self.rewrite(input)?;
+                let (input, encoded) = self.decode_for(input, encoded, &keys, &aggs);
+                let out = self.pass_through_names(&keys, &encoded);
+                let keys = keys.iter().map(refresh).collect();
+                let aggs = aggs.iter().map(refresh).collect();
+                let ir = IRBuilder::new(input, self.expr_arena, self.lp_arena)
+                    .group_by(keys, aggs, None, maintain_order, options)
+                    .build();
+                self.lp_arena.replace(node, ir);
+                out
+            },
+            IR::Join {
+                input_left,
+                input_right,
+                left_on,
+                right_on,
+                options,
+                ..
+            } if options.options.is_none()
+                && matches!(
+                    options.args.how,
+                    JoinType::Inner | JoinType::Left | JoinType::Right | JoinType::Full
+                ) =>
+            {
+                // The codes of the two sides differ, so the keys are decoded.
+                let encoded_left = self.rewrite(input_left)?;
+                let encoded_right = self.rewrite(input_right)?;
+                let (input_left, encoded_left) =
+                    self.decode_for(input_left, encoded_left, &[], &left_on);
+                let (input_right, encoded_right) =
+                    self.decode_for(input_right, encoded_right, &[], &right_on);
+
+                let left_schema = self.lp_arena.get(input_left).schema(self.lp_arena);
+                let left_schema = left_schema.into_owned();
+                let left_on = left_on.iter().map(refresh).collect();
+                let right_on = right_on.iter().map(refresh).collect();
+                let suffix = options.args.suffix().clone();
+                let ir = IRBuilder::new(input_left, self.expr_arena, self.lp_arena)
+                    .join(input_right, left_on, right_on, options)
+                    .build();
+                let schema = ir.schema(self.lp_arena).into_owned();
+                self.lp_arena.replace(node, ir);
+
+                // The columns of the right side that are also on the left side get a suffix.
+                let out_left = encoded_left.into_iter();
+                let out_right = encoded_right.into_iter().map(|c| {
+                    if left_schema.contains(&c) {
+                        format_pl_smallstr!("{c}{suffix}")
+                    } else {
+                        c
+                    }
+                });
+                out_left
+                    .chain(out_right)
+                    .filter(|c| matches!(schema.get(c), Some(DataType::Categorical(_, _))))
+                    .collect()
+            },
+            // Cached plans are shared with other parts of the plan, so they are left alone.
+            IR::Cache { .. } => Encoded::default(),
+            ir => {
+                let mut inputs = ir.get_inputs_vec();
+                if inputs.is_empty() {
+                    return Ok(Encoded::default());
+                }
+                for input in inputs.iter_mut() {
+                    let encoded = self.rewrite(*input)?;
+                    *input = self.materialize(*input, &encoded);
+                }
+                let ir = ir.with_exprs_and_input(ir.get_exprs(), inputs);
+                self.lp_arena.replace(node, ir);
+                Encoded::default()
+            },
+        };
+        Ok(encoded)
+    }
+}
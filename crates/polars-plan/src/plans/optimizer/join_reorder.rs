@@ -0,0 +1,271 @@
+//! Optimization that reorders chains of inner joins, so that the most selective joins run first.
+//!
+//! For example, with a fact table joined with several dimension tables in a star schema,
+//! `fact.join(big_dim, ..).join(small_dim, ..)` is reordered to
+//! `fact.join(small_dim, ..).join(big_dim, ..)`, so that the rows that don't match the smaller
+//! dimension are dropped before joining with the bigger one.
+
+use std::sync::Arc;
+
+use polars_core::schema::*;
+use polars_ops::frame::{JoinType, JoinValidation, MaintainOrderJoin};
+use polars_utils::arena::{Arena, Node};
+
+use super::{AExpr, IR, JoinOptions};
+use crate::dsl::Operator;
+use crate::plans::statistics::{TableStatistics, get_table_statistics};
+use crate::plans::{ArenaExprIter, ExprIR};
+
+/// Every comparison in a filter is assumed to keep this fraction of the rows, like the row
+/// estimates of the streaming engine do.
+const FILTER_SELECTIVITY: f64 = 0.9;
+
+/// A join of a chain with a dimension table.
+struct JoinStep {
+    input_right: Node,
+    left_on: Vec<ExprIR>,
+    right_on: Vec<ExprIR>,
+    options: Arc<JoinOptions>,
+    /// The columns this join adds to its left input.
+    added: Schema,
+}
+
+pub fn optimize(root: Node, lp_arena: &mut Arena<IR>, expr_arena: &Arena<AExpr>) {
+    let mut ir_stack = Vec::with_capacity(16);
+    ir_stack.push(root);
+
+    while let Some(current) = ir_stack.pop() {
+        if reorder_chain(current, lp_arena, expr_arena, &mut ir_stack) {
+            continue;
+        }
+        lp_arena.get(current).copy_inputs(&mut ir_stack);
+    }
+}
+
+/// Whether the joins of a chain can be run in any order.
+fn is_reorderable(options: &JoinOptions, left_on: &[ExprIR], right_on: &[ExprIR]) -> bool {
+    let args = &options.args;
+    // The order of the rows and the uniqueness of the left keys depend on the order of the joins.
+    matches!(args.how, JoinType::Inner)
+        && args.slice.is_none()
+        && matches!(args.maintain_order, MaintainOrderJoin::None)
+        && matches!(
+            args.validation,
+            JoinValidation::ManyToMany | JoinValidation::ManyToOne
+        )
+        && options.options.is_none()
+        && !left_on.is_empty()
+        && left_on.len() == right_on.len()
+}
+
+/// Reorders the chain of joins starting at `top`, if it has at least two joins that can be
+/// reordered. On success the inputs of the chain are pushed to `ir_stack`.
+fn reorder_chain(
+    top: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+    ir_stack: &mut Vec<Node>,
+) -> bool {
+    let mut steps = Vec::new();
+    let mut current = top;
+    while let IR::Join {
+        input_left,
+        input_right,
+        schema,
+        left_on,
+        right_on,
+        options,
+    } = lp_arena.get(current)
+    {
+        if !is_reorderable(options, left_on, right_on) {
+            break;
+        }
+        let left_schema = lp_arena.get(*input_left).schema(lp_arena);
+        let right_schema = lp_arena.get(*input_right).schema(lp_arena);
+
+        // The join must keep all columns of its left input and add columns of the right input
+        // without renaming them, so that it adds the same columns in any order.
+        let added: Schema = schema
+            .iter()
+            .filter(|(name, _)| !left_schema.contains(name))
+            .map(|(name, dtype)| (name.clone(), dtype.clone()))
+            .collect();
+        if schema.len() != left_schema.len() + added.len()
+            || !added.iter_names().all(|name| right_schema.contains(name))
+            || !right_on.iter().all(|e| is_column(e, expr_arena))
+        {
+            break;
+        }
+
+        steps.push(JoinStep {
+            input_right: *input_right,
+            left_on: left_on.clone(),
+            right_on: right_on.clone(),
+            options: options.clone(),
+            added,
+        });
+        current = *input_left;
+    }
+    let base = current;
+
+    // All joins must join on columns of the base, otherwise they depend on the joins before
+    // them. A shorter chain below such a join is tried when visiting its input.
+    let base_schema = lp_arena.get(base).schema(lp_arena).into_owned();
+    let joins_on_base = |step: &JoinStep| {
+        step.left_on
+            .iter()
+            .all(|e| is_column(e, expr_arena) && base_schema.contains(e.output_name()))
+    };
+    if steps.len() < 2 || !steps.iter().all(joins_on_base) {
+        return false;
+    }
+    steps.reverse();
+
+    let estimates = steps
+        .iter()
+        .map(|step| estimate_rows(step.input_right, lp_arena, expr_arena).unwrap_or(usize::MAX))
+        .collect::<Vec<_>>();
+    let mut order = (0..steps.len()).collect::<Vec<_>>();
+    // Stable, so that joins with the same or unknown estimates keep their order.
+    order.sort_by_key(|i| estimates[*i]);
+
+    if order.iter().enumerate().all(|(i, j)| i == *j) {
+        ir_stack.push(base);
+        ir_stack.extend(steps.iter().map(|step| step.input_right));
+        return true;
+    }
+
+    let top_schema = lp_arena.get(top).schema(lp_arena).into_owned();
+    let mut input_left = base;
+    let mut schema = base_schema;
+    for i in order {
+        let step = &steps[i];
+        let mut out_schema = (*schema).clone();
+        out_schema.extend(
+            step.added
+                .iter()
+                .map(|(name, dtype)| (name.clone(), dtype.clone())),
+        );
+        schema = Arc::new(out_schema);
+
+        input_left = lp_arena.add(IR::Join {
+            input_left,
+            input_right: step.input_right,
+            schema: schema.clone(),
+            left_on: step.left_on.clone(),
+            right_on: step.right_on.clone(),
+            options: step.options.clone(),
+        });
+    }
+
+    // Restore the original order of the columns.
+    lp_arena.replace(
+        top,
+        IR::SimpleProjection {
+            input: input_left,
+            columns: top_schema,
+        },
+    );
+
+    ir_stack.push(base);
+    ir_stack.extend(steps.iter().map(|step| step.input_right));
+    true
+}
+
+fn is_column(e: &ExprIR, expr_arena: &Arena<AExpr>) -> bool {
+    matches!(expr_arena.get(e.node()), AExpr::Column(name) if name == e.output_name())
+}
+
+/// A rough estimate of the number of rows `node` produces, if it can be estimated cheaply. This
+/// uses the statistics collected by `LazyFrame::analyze`, if any.
+fn estimate_rows(node: Node, lp_arena: &Arena<IR>, expr_arena: &Arena<AExpr>) -> Option<usize> {
+    match lp_arena.get(node) {
+        IR::DataFrameScan { df, .. } => Some(df.height()),
+        IR::Scan {
+            sources,
+            file_info,
+            predicate,
+            scan_type,
+            file_options,
+            ..
+        } => {
+            let statistics = get_table_statistics(sources, scan_type, file_options);
+            let rows = statistics
+                .as_ref()
+                .map_or(file_info.row_estimation.1, |stats| stats.num_rows);
+            Some(match predicate {
+                Some(predicate) => apply_filter(rows, predicate, expr_arena, statistics.as_deref()),
+                None => rows,
+            })
+        },
+        IR::Filter { input, predicate } => {
+            let statistics = scan_statistics(*input, lp_arena);
+            estimate_rows(*input, lp_arena, expr_arena)
+                .map(|rows| apply_filter(rows, predicate, expr_arena, statistics.as_deref()))
+        },
+        IR::Slice { input, len, .. } => {
+            estimate_rows(*input, lp_arena, expr_arena).map(|rows| rows.min(*len as usize))
+        },
+        // Grouping and deduplicating keep at most the rows of their input.
+        IR::Select { input, .. }
+        | IR::HStack { input, .. }
+        | IR::SimpleProjection { input, .. }
+        | IR::Sort { input, .. }
+        | IR::Cache { input, .. }
+        | IR::GroupBy { input, .. }
+        | IR::Distinct { input, .. } => estimate_rows(*input, lp_arena, expr_arena),
+        _ => None,
+    }
+}
+
+/// The statistics of the scan `node` reads its rows from, without changing them.
+fn scan_statistics(node: Node, lp_arena: &Arena<IR>) -> Option<Arc<TableStatistics>> {
+    match lp_arena.get(node) {
+        IR::Scan {
+            sources,
+            scan_type,
+            file_options,
+            ..
+        } => get_table_statistics(sources, scan_type, file_options),
+        IR::Filter { input, .. }
+        | IR::SimpleProjection { input, .. }
+        | IR::Cache { input, .. }
+        | IR::HStack { input, .. } => scan_statistics(*input, lp_arena),
+        _ => None,
+    }
+}
+
+fn apply_filter(
+    rows: usize,
+    predicate: &ExprIR,
+    expr_arena: &Arena<AExpr>,
+    statistics: Option<&TableStatistics>,
+) -> usize {
+    if rows == usize::MAX {
+        return rows;
+    }
+
+    // Comparing a column with a value keeps one of its distinct values.
+    let equality_selectivity = |left: Node, right: Node| {
+        let ((AExpr::Column(name), AExpr::Literal(_)) | (AExpr::Literal(_), AExpr::Column(name))) =
+            (expr_arena.get(left), expr_arena.get(right))
+        else {
+            return None;
+        };
+        Some(statistics?.column(name)?.equality_selectivity())
+    };
+
+    let mut selectivity = FILTER_SELECTIVITY;
+    for (_, ae) in expr_arena.iter(predicate.node()) {
+        selectivity *= match ae {
+            AExpr::BinaryExpr {
+                left,
+                op: Operator::Eq,
+                right,
+            } => equality_selectivity(*left, *right).unwrap_or(FILTER_SELECTIVITY),
+            AExpr::BinaryExpr { .. } => FILTER_SELECTIVITY,
+            _ => 1.0,
+        };
+    }
+    (rows as f64 * selectivity) as usize
+}
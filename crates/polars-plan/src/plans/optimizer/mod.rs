@@ -4,6 +4,8 @@ use crate::prelude::*;
 
 mod cache_states;
 mod delay_rechunk;
+#[cfg(all(feature = "parquet", feature = "dtype-categorical"))]
+mod dictionary_strings;
 
 mod cluster_with_columns;
 mod collapse_and_project;
@@ -12,20 +14,30 @@ mod collect_members;
 mod count_star;
 #[cfg(feature = "cse")]
 mod cse;
+mod custom_rules;
 mod flatten_union;
 #[cfg(feature = "fused")]
 mod fused;
+mod join_reorder;
 mod join_utils;
 pub(crate) use join_utils::ExprOrigin;
 mod predicate_pushdown;
 mod projection_pushdown;
+#[cfg(all(feature = "semi_anti_join", feature = "is_in"))]
+mod semi_join_reduction;
 mod set_order;
 mod simplify_expr;
 mod slice_pushdown_expr;
 mod slice_pushdown_lp;
+mod sortedness;
 mod stack_opt;
 
 use collapse_and_project::SimpleProjectionAndCollapse;
+use custom_rules::run_custom_rules;
+pub use custom_rules::{
+    OptimizationRuleFactory, OptimizationStage, optimization_rules_version,
+    register_optimization_rule, unregister_optimization_rule,
+};
 use delay_rechunk::DelayRechunk;
 use polars_core::config::verbose;
 use polars_io::predicates::PhysicalIoExpr;
@@ -68,9 +80,18 @@ pub fn optimize(
     scratch: &mut Vec<Node>,
     expr_eval: ExprEval<'_>,
 ) -> PolarsResult<Node> {
-    #[allow(dead_code)]
-    let verbose = verbose();
+    let lp_top = to_alp_for_optimization(logical_plan, &mut opt_flags, lp_arena, expr_arena)?;
+    optimize_ir(lp_top, opt_flags, lp_arena, expr_arena, scratch, expr_eval)
+}
 
+/// Converts the DSL to IR before it is optimized with [`optimize_ir`], updating the flags for the
+/// conversion.
+pub fn to_alp_for_optimization(
+    logical_plan: DslPlan,
+    opt_flags: &mut OptFlags,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<Node> {
     #[cfg(feature = "python")]
     if opt_flags.streaming() {
         polars_warn!(
@@ -85,18 +106,31 @@ More information on the new streaming engine: https://github.com/pola-rs/polars/
         )
     }
 
-    // Gradually fill the rules passed to the optimizer
-    let opt = StackOptimizer {};
-    let mut rules: Vec<Box<dyn OptimizationRule>> = Vec::with_capacity(8);
-
     // Unset CSE
     // This can be turned on again during ir-conversion.
     #[allow(clippy::eq_op)]
     #[cfg(feature = "cse")]
     if opt_flags.contains(OptFlags::EAGER) {
-        opt_flags &= !(OptFlags::COMM_SUBEXPR_ELIM | OptFlags::COMM_SUBEXPR_ELIM);
+        *opt_flags &= !(OptFlags::COMM_SUBEXPR_ELIM | OptFlags::COMM_SUBEXPR_ELIM);
     }
-    let mut lp_top = to_alp(logical_plan, expr_arena, lp_arena, &mut opt_flags)?;
+    to_alp(logical_plan, expr_arena, lp_arena, opt_flags)
+}
+
+/// Optimizes an IR plan, with the flags as updated by [`to_alp_for_optimization`].
+pub fn optimize_ir(
+    mut lp_top: Node,
+    opt_flags: OptFlags,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+    scratch: &mut Vec<Node>,
+    expr_eval: ExprEval<'_>,
+) -> PolarsResult<Node> {
+    #[allow(dead_code)]
+    let verbose = verbose();
+
+    // Gradually fill the rules passed to the optimizer
+    let opt = StackOptimizer {};
+    let mut rules: Vec<Box<dyn OptimizationRule>> = Vec::with_capacity(8);
 
     // Don't run optimizations that don't make sense on a single node.
     // This keeps eager execution more snappy.
@@ -112,6 +146,8 @@ More information on the new streaming engine: https://github.com/pola-rs/polars/
     #[cfg(debug_assertions)]
     let prev_schema = lp_arena.get(lp_top).schema(lp_arena).into_owned();
 
+    lp_top = run_custom_rules(OptimizationStage::Start, lp_top, lp_arena, expr_arena)?;
+
     let mut _opt_members = &mut None;
 
     macro_rules! get_or_init_members {
@@ -166,6 +202,16 @@ More information on the new streaming engine: https://github.com/pola-rs/polars/
     #[cfg(not(feature = "cse"))]
     let _cse_plan_changed = false;
 
+    // Should be run before projection and predicate pushdown, so that the filters it adds are
+    // pushed down to the scans.
+    #[cfg(all(feature = "semi_anti_join", feature = "is_in"))]
+    if opt_flags.semi_join_reduction() && {
+        let members = get_or_init_members!();
+        members.has_joins_or_unions || members.has_ext_context
+    } {
+        semi_join_reduction::optimize(lp_top, lp_arena, expr_arena)?;
+    }
+
     // Should be run before predicate pushdown.
     if opt_flags.projection_pushdown() {
         let mut projection_pushdown_opt = ProjectionPushDown::new(opt_flags.new_streaming());
@@ -187,6 +233,13 @@ More information on the new streaming engine: https://github.com/pola-rs/polars/
         lp_arena.replace(lp_top, alp);
     }
 
+    lp_top = run_custom_rules(
+        OptimizationStage::AfterPushdown,
+        lp_top,
+        lp_arena,
+        expr_arena,
+    )?;
+
     if opt_flags.cluster_with_columns() {
         cluster_with_columns::optimize(lp_top, lp_arena, expr_arena)
     }
@@ -196,6 +249,27 @@ More information on the new streaming engine: https://github.com/pola-rs/polars/
         collapse_joins::optimize(lp_top, lp_arena, expr_arena);
     }
 
+    // Make sure it is after predicate and projection pushdown, so that the estimated sizes of the
+    // joined tables include their filters.
+    if opt_flags.join_reorder() && get_or_init_members!().has_joins_or_unions {
+        join_reorder::optimize(lp_top, lp_arena, expr_arena);
+    }
+
+    // Make sure it is before slice pushdown, which pushes down the slices of the removed sorts.
+    if {
+        let members = get_or_init_members!();
+        members.has_sort || members.has_joins_or_unions
+    } {
+        sortedness::optimize(lp_top, lp_arena, expr_arena, opt_flags.sorted_metadata())?;
+    }
+
+    // Make sure it is after predicate and projection pushdown, so that only the columns that are
+    // read are encoded and the filters of the scans run on the strings.
+    #[cfg(all(feature = "parquet", feature = "dtype-categorical"))]
+    if opt_flags.dictionary_strings() {
+        dictionary_strings::optimize(lp_top, lp_arena, expr_arena)?;
+    }
+
     // Make sure its before slice pushdown.
     if opt_flags.fast_projection() {
         rules.push(Box::new(SimpleProjectionAndCollapse::new(
@@ -249,7 +323,7 @@ More information on the new streaming engine: https://github.com/pola-rs/polars/
     // This one should run (nearly) last as this modifies the projections
     #[cfg(feature = "cse")]
     if comm_subexpr_elim && !get_or_init_members!().has_ext_context {
-        let mut optimizer = CommonSubExprOptimizer::new();
+        let mut optimizer = CommonSubExprOptimizer::new(opt_flags.new_streaming());
         let alp_node = IRNode::new_mutate(lp_top);
 
         lp_top = try_with_ir_arena(lp_arena, expr_arena, |arena| {
@@ -258,6 +332,8 @@ More information on the new streaming engine: https://github.com/pola-rs/polars/
         })?;
     }
 
+    lp_top = run_custom_rules(OptimizationStage::End, lp_top, lp_arena, expr_arena)?;
+
     // During debug we check if the optimizations have not modified the final schema.
     #[cfg(debug_assertions)]
     {
@@ -95,6 +95,55 @@ impl PredicatePushDown<'_> {
         Ok(())
     }
 
+    /// Pushes all predicates past the column parsers of a scan, see
+    /// [`FileScanOptions::column_parsers`].
+    ///
+    /// The parsers only replace columns of the scan, so predicates on the parsed columns can be
+    /// evaluated by the scan after substituting the parser expressions.
+    fn push_down_column_parsers(
+        &mut self,
+        lp: IR,
+        acc_predicates: PlHashMap<PlSmallStr, ExprIR>,
+        lp_arena: &mut Arena<IR>,
+        expr_arena: &mut Arena<AExpr>,
+    ) -> PolarsResult<IR> {
+        let IR::HStack {
+            input,
+            exprs,
+            schema,
+            options,
+        } = lp
+        else {
+            unreachable!()
+        };
+
+        let parsers: PlHashMap<PlSmallStr, Expr> = exprs
+            .iter()
+            .map(|e| (e.output_name().clone(), node_to_expr(e.node(), expr_arena)))
+            .collect();
+
+        let mut pushdown_predicates = optimizer::init_hashmap(Some(acc_predicates.len()));
+        for (_, mut predicate) in acc_predicates {
+            let new_expr = node_to_expr(predicate.node(), expr_arena).map_expr(|e| match e {
+                Expr::Column(name) => match parsers.get(&name) {
+                    Some(parser) => parser.clone(),
+                    None => Expr::Column(name),
+                },
+                e => e,
+            });
+            predicate.set_node(to_aexpr(new_expr, expr_arena)?);
+            insert_and_combine_predicate(&mut pushdown_predicates, &predicate, expr_arena);
+        }
+        self.pushdown_and_assign(input, pushdown_predicates, lp_arena, expr_arena)?;
+
+        Ok(IR::HStack {
+            input,
+            exprs,
+            schema,
+            options,
+        })
+    }
+
     /// Filter will be pushed down.
     fn pushdown_and_continue(
         &mut self,
@@ -721,6 +770,9 @@ impl PredicatePushDown<'_> {
             lp @ Sink { .. } | lp @ SinkMultiple { .. } => {
                 self.pushdown_and_continue(lp, acc_predicates, lp_arena, expr_arena, false)
             },
+            lp @ HStack { .. } if is_scan_column_parsers(&lp, lp_arena) => {
+                self.push_down_column_parsers(lp, acc_predicates, lp_arena, expr_arena)
+            },
             // Pushed down passed these nodes
             lp @ HStack { .. }
             | lp @ Select { .. }
@@ -332,12 +332,14 @@ pub fn pushdown_eligibility(
     }
 
     // Note: has_window is constant.
+    // Filtering on the partition keys of all windows drops whole partitions, so the windows of
+    // the remaining rows are unchanged. The keys must not be overwritten by the projection, as the
+    // predicate would then refer to a different column than the windows are partitioned by. E.g.:
+    // .with_columns(col(A) + 1, sum=sum().over(A))
+    // .filter(col(A) == ..)
     let can_use_column = |col: &str| {
-        if has_window {
-            common_window_inputs.contains(col)
-        } else {
-            !modified_projection_columns.contains(col)
-        }
+        !modified_projection_columns.contains(col)
+            && (!has_window || common_window_inputs.contains(col))
     };
 
     let to_local = acc_predicates
@@ -382,3 +384,23 @@ pub fn pushdown_eligibility(
         _ => Ok((PushdownEligibility::Partial { to_local }, alias_to_col_map)),
     }
 }
+
+/// Whether this is the [`IR::HStack`] that applies the column parsers of the scan below it.
+pub(super) fn is_scan_column_parsers(lp: &IR, lp_arena: &Arena<IR>) -> bool {
+    let IR::HStack { input, exprs, .. } = lp else {
+        return false;
+    };
+    match lp_arena.get(*input) {
+        IR::Scan {
+            file_info,
+            file_options,
+            ..
+        } => {
+            file_options.column_parsers.is_some()
+                && exprs
+                    .iter()
+                    .all(|e| file_info.schema.contains(e.output_name()))
+        },
+        _ => false,
+    }
+}
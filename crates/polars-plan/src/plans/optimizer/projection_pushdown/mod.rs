@@ -454,6 +454,21 @@ impl ProjectionPushDown {
                     FileScan::Parquet { .. } => true,
                 };
 
+                // An anonymous scan reads the columns it can't skip cheaply anyway, so they are
+                // projected as well and dropped again after the scan.
+                let num_projections = ctx.acc_projections.len();
+                if let FileScan::Anonymous { function, .. } = &*scan_type {
+                    if do_optimization && !self.is_count_star && ctx.has_pushed_down() {
+                        if let Some(skippable) = function.skippable_columns() {
+                            for name in file_info.schema.iter_names() {
+                                if !skippable.contains(name) {
+                                    add_str_to_accumulated(name.clone(), &mut ctx, expr_arena);
+                                }
+                            }
+                        }
+                    }
+                }
+
                 if do_optimization {
                     file_options.with_columns = get_scan_columns(
                         &ctx.acc_projections,
@@ -656,7 +671,9 @@ impl ProjectionPushDown {
                 // TODO: Our scans don't perfectly give the right projection order with combinations
                 // of hive columns that exist in the file, so we always add a `Select {}` node here.
 
-                if self.in_new_streaming_engine {
+                let has_unskippable_columns = ctx.acc_projections.len() > num_projections;
+                ctx.acc_projections.truncate(num_projections);
+                if self.in_new_streaming_engine && !has_unskippable_columns {
                     Ok(lp)
                 } else {
                     let builder = IRBuilder::from_lp(lp, expr_arena, lp_arena);
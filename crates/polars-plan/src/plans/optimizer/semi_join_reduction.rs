@@ -0,0 +1,431 @@
+//! Optimization that turns filters on the existence of rows of another table into semi and anti
+//! joins, and filters the probe side of joins with the keys of a small in-memory build side.
+//!
+//! - `lf.with_context(sub).filter(pl.col.a.is_in(pl.col.b))`, which is what `IN (SELECT ..)`
+//!   subqueries of SQL become, is turned into a semi join of `lf` with `sub`. A negated `is_in` is
+//!   turned into an anti join.
+//! - `left.join(right, how='left', coalesce=False).filter(pl.col.key_right.is_null())` is turned
+//!   into an anti join, with the columns of `right` added as nulls.
+//! - The probe side of inner and semi joins whose build side is an in-memory `DataFrame` is
+//!   filtered with `pl.col.key.is_in(keys)`. Predicate pushdown moves this filter into the scan
+//!   of the probe side, so that e.g. Parquet row groups without any of the keys are skipped.
+
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+use polars_ops::frame::{JoinArgs, JoinType, MaintainOrderJoin};
+use polars_utils::arena::{Arena, Node};
+
+use super::{AExpr, IR, JoinOptions};
+use crate::dsl::{BooleanFunction, FunctionExpr, Operator, col, lit};
+use crate::plans::{ArenaLpIter, ExprIR, MintermIter, OutputName, ProjectionOptions, to_expr_ir};
+use crate::utils::aexpr_to_leaf_names_iter;
+
+/// The maximum number of keys of a build side that are pushed down to the probe side.
+const MAX_PUSHED_KEYS: usize = 10_000;
+
+pub fn optimize(
+    root: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<()> {
+    let mut ir_stack = Vec::with_capacity(16);
+    ir_stack.push(root);
+
+    while let Some(current) = ir_stack.pop() {
+        if let Some(ir) = in_subquery_to_join(current, lp_arena, expr_arena)? {
+            lp_arena.replace(current, ir);
+        } else if let Some(ir) = left_join_to_anti_join(current, lp_arena, expr_arena) {
+            lp_arena.replace(current, ir);
+        }
+        push_down_join_keys(current, lp_arena, expr_arena)?;
+        lp_arena.get(current).copy_inputs(&mut ir_stack);
+    }
+    Ok(())
+}
+
+fn is_column(e: &ExprIR, expr_arena: &Arena<AExpr>) -> bool {
+    matches!(expr_arena.get(e.node()), AExpr::Column(name) if name == e.output_name())
+}
+
+fn column_expr_ir(name: &PlSmallStr, expr_arena: &mut Arena<AExpr>) -> ExprIR {
+    let node = expr_arena.add(AExpr::Column(name.clone()));
+    ExprIR::new(node, OutputName::ColumnLhs(name.clone()))
+}
+
+/// Matches `pl.col.left.is_in(pl.col.right)`, returning the names of the columns, whether it is
+/// negated and whether nulls are equal.
+fn match_is_in_column(
+    node: Node,
+    expr_arena: &Arena<AExpr>,
+) -> Option<(PlSmallStr, PlSmallStr, bool, bool)> {
+    let (node, negated) = match expr_arena.get(node) {
+        AExpr::Function {
+            input,
+            function: FunctionExpr::Boolean(BooleanFunction::Not),
+            ..
+        } => (input[0].node(), true),
+        _ => (node, false),
+    };
+    let AExpr::Function {
+        input,
+        function: FunctionExpr::Boolean(BooleanFunction::IsIn { nulls_equal }),
+        ..
+    } = expr_arena.get(node)
+    else {
+        return None;
+    };
+    let (AExpr::Column(left), AExpr::Column(right)) = (
+        expr_arena.get(input[0].node()),
+        expr_arena.get(input[1].node()),
+    ) else {
+        return None;
+    };
+    Some((left.clone(), right.clone(), negated, *nulls_equal))
+}
+
+/// Turns `is_in` filters on a column of a context of `with_context` into semi and anti joins with
+/// the context.
+fn in_subquery_to_join(
+    node: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<Option<IR>> {
+    let IR::Filter { input, predicate } = lp_arena.get(node) else {
+        return Ok(None);
+    };
+    let IR::ExtContext {
+        input: ext_input,
+        contexts,
+        schema: ext_schema,
+    } = lp_arena.get(*input)
+    else {
+        return Ok(None);
+    };
+    let mut left = *ext_input;
+    let mut contexts = contexts.clone();
+    let ext_schema = ext_schema.clone();
+    let input_schema = lp_arena.get(left).schema(lp_arena).into_owned();
+
+    let mut joins = Vec::new();
+    let mut remaining = Vec::new();
+    let mut joined_columns = Vec::new();
+    let minterms = MintermIter::new(predicate.node(), expr_arena).collect::<Vec<_>>();
+    for minterm in minterms {
+        let Some((left_name, right_name, negated, nulls_equal)) =
+            match_is_in_column(minterm, expr_arena)
+        else {
+            remaining.push(minterm);
+            continue;
+        };
+        // Columns of the input take precedence over the columns of the contexts.
+        let context = contexts.iter().position(|ctx| {
+            let ctx_schema = lp_arena.get(*ctx).schema(lp_arena);
+            !input_schema.contains(&right_name)
+                && ctx_schema.get(&right_name).is_some()
+                && ctx_schema.get(&right_name) == input_schema.get(&left_name)
+        });
+        let Some(context) = context else {
+            remaining.push(minterm);
+            continue;
+        };
+
+        let context = contexts.remove(context);
+        joined_columns.extend(
+            lp_arena
+                .get(context)
+                .schema(lp_arena)
+                .iter_names()
+                .filter(|name| !input_schema.contains(name))
+                .cloned(),
+        );
+        joins.push((context, left_name, right_name, negated, nulls_equal));
+    }
+    if joins.is_empty() {
+        return Ok(None);
+    }
+    // The remaining predicates can't refer to the contexts that are joined.
+    let uses_joined_column = remaining.iter().any(|node| {
+        aexpr_to_leaf_names_iter(*node, expr_arena).any(|name| joined_columns.contains(&name))
+    });
+    if uses_joined_column {
+        return Ok(None);
+    }
+
+    for (context, left_name, right_name, negated, nulls_equal) in joins {
+        let how = if negated {
+            // Anti joins keep the rows with null keys, but `null.is_in(..).not()` is null.
+            if !nulls_equal {
+                let predicate = to_expr_ir(col(left_name.clone()).is_not_null(), expr_arena)?;
+                left = lp_arena.add(IR::Filter {
+                    input: left,
+                    predicate,
+                });
+            }
+            JoinType::Anti
+        } else {
+            JoinType::Semi
+        };
+        let mut args = JoinArgs::new(how);
+        args.nulls_equal = nulls_equal;
+        // The filter kept the order of the rows.
+        args.maintain_order = MaintainOrderJoin::Left;
+        left = lp_arena.add(IR::Join {
+            input_left: left,
+            input_right: context,
+            schema: input_schema.clone(),
+            left_on: vec![column_expr_ir(&left_name, expr_arena)],
+            right_on: vec![column_expr_ir(&right_name, expr_arena)],
+            options: Arc::new(JoinOptions {
+                args,
+                ..Default::default()
+            }),
+        });
+    }
+
+    // Keep the schema of the filter, which includes the columns of the contexts.
+    let ext_context = IR::ExtContext {
+        input: left,
+        contexts,
+        schema: ext_schema,
+    };
+    if remaining.is_empty() {
+        return Ok(Some(ext_context));
+    }
+    let ext_context = lp_arena.add(ext_context);
+    let predicate = remaining
+        .into_iter()
+        .reduce(|left, right| {
+            expr_arena.add(AExpr::BinaryExpr {
+                left,
+                op: Operator::And,
+                right,
+            })
+        })
+        .unwrap();
+    Ok(Some(IR::Filter {
+        input: ext_context,
+        predicate: ExprIR::from_node(predicate, expr_arena),
+    }))
+}
+
+/// Turns a left join filtered on a right key being null into an anti join.
+fn left_join_to_anti_join(
+    node: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> Option<IR> {
+    let IR::Filter { input, predicate } = lp_arena.get(node) else {
+        return None;
+    };
+    let IR::Join {
+        input_left,
+        input_right,
+        schema,
+        left_on,
+        right_on,
+        options,
+    } = lp_arena.get(*input)
+    else {
+        return None;
+    };
+    let args = &options.args;
+    if !matches!(args.how, JoinType::Left)
+        || args.should_coalesce()
+        || args.nulls_equal
+        || args.slice.is_some()
+        || options.options.is_some()
+    {
+        return None;
+    }
+
+    let AExpr::Function {
+        input: function_input,
+        function: FunctionExpr::Boolean(BooleanFunction::IsNull),
+        ..
+    } = expr_arena.get(predicate.node())
+    else {
+        return None;
+    };
+    let AExpr::Column(null_column) = expr_arena.get(function_input[0].node()) else {
+        return None;
+    };
+
+    // The right keys are only null for the rows without a match, as null keys never match.
+    let left_schema = lp_arena.get(*input_left).schema(lp_arena).into_owned();
+    let is_right_key = right_on.iter().any(|e| {
+        let key = e.output_name();
+        let name = if left_schema.contains(key) {
+            format!("{key}{}", args.suffix())
+        } else {
+            key.to_string()
+        };
+        is_column(e, expr_arena) && name == null_column.as_str()
+    });
+    // The join must add the columns of the right side after the columns of the left side.
+    let keeps_left_columns = schema.len() >= left_schema.len()
+        && schema
+            .iter_names()
+            .zip(left_schema.iter_names())
+            .all(|(a, b)| a == b);
+    if !is_right_key || !keeps_left_columns {
+        return None;
+    }
+
+    let mut options = (**options).clone();
+    options.args.how = JoinType::Anti;
+    let join = IR::Join {
+        input_left: *input_left,
+        input_right: *input_right,
+        schema: left_schema.clone(),
+        left_on: left_on.clone(),
+        right_on: right_on.clone(),
+        options: Arc::new(options),
+    };
+    let schema = schema.clone();
+    let join = lp_arena.add(join);
+
+    let exprs = schema
+        .iter()
+        .skip(left_schema.len())
+        .map(|(name, dtype)| {
+            let node = expr_arena.add(AExpr::Literal(Scalar::null(dtype.clone()).into()));
+            ExprIR::new(node, OutputName::Alias(name.clone()))
+        })
+        .collect();
+    Some(IR::HStack {
+        input: join,
+        exprs,
+        schema,
+        options: ProjectionOptions::default(),
+    })
+}
+
+/// The values of the column `name` of `node`, if all its rows come from an in-memory `DataFrame`
+/// without changing the column. Filters are ignored, as filtering the probe side with more keys
+/// than needed is still correct. The values aren't deduplicated, as that would run a computation
+/// while optimizing.
+fn build_side_keys(mut node: Node, name: &str, lp_arena: &Arena<IR>) -> Option<Series> {
+    loop {
+        node = match lp_arena.get(node) {
+            IR::DataFrameScan { df, .. } => {
+                let column = df.column(name).ok()?;
+                return Some(column.as_materialized_series().clone());
+            },
+            IR::Filter { input, .. }
+            | IR::Slice { input, .. }
+            | IR::Sort { input, .. }
+            | IR::Cache { input, .. }
+            | IR::Distinct { input, .. } => *input,
+            IR::SimpleProjection { input, columns } if columns.contains(name) => *input,
+            IR::HStack { input, exprs, .. } if exprs.iter().all(|e| e.output_name() != name) => {
+                *input
+            },
+            _ => return None,
+        };
+    }
+}
+
+fn supports_key_pushdown(dtype: &DataType) -> bool {
+    dtype.is_primitive_numeric() || dtype.is_bool() || dtype.is_string() || dtype.is_temporal()
+}
+
+/// Filters the probe sides of an inner or semi join with the keys of an in-memory build side.
+fn push_down_join_keys(
+    node: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<()> {
+    let IR::Join {
+        input_left,
+        input_right,
+        left_on,
+        right_on,
+        options,
+        ..
+    } = lp_arena.get(node)
+    else {
+        return Ok(());
+    };
+    let (input_left, input_right) = (*input_left, *input_right);
+    if options.args.slice.is_some() || options.options.is_some() {
+        return Ok(());
+    }
+    let nulls_equal = options.args.nulls_equal;
+    let pairs = left_on
+        .iter()
+        .zip(right_on)
+        .filter(|(l, r)| is_column(l, expr_arena) && is_column(r, expr_arena))
+        .map(|(l, r)| (l.output_name().clone(), r.output_name().clone()))
+        .collect::<Vec<_>>();
+    // Only the left side of a semi join is reduced by the join.
+    let reduce_right = match options.args.how {
+        JoinType::Inner => true,
+        JoinType::Semi => false,
+        _ => return Ok(()),
+    };
+
+    let mut reduce = |probe: Node,
+                      build: Node,
+                      lp_arena: &mut Arena<IR>,
+                      left: bool|
+     -> PolarsResult<Option<Node>> {
+        if !(&*lp_arena)
+            .iter(probe)
+            .any(|(_, ir)| matches!(ir, IR::Scan { .. }))
+        {
+            return Ok(None);
+        }
+        let probe_schema = lp_arena.get(probe).schema(lp_arena).into_owned();
+        let build_schema = lp_arena.get(build).schema(lp_arena).into_owned();
+
+        let mut predicates = Vec::new();
+        for (l, r) in &pairs {
+            let (probe_key, build_key) = if left { (l, r) } else { (r, l) };
+            let Some(dtype) = probe_schema.get(probe_key) else {
+                continue;
+            };
+            if build_schema.get(build_key) != Some(dtype) || !supports_key_pushdown(dtype) {
+                continue;
+            }
+            let Some(keys) = build_side_keys(build, build_key, lp_arena) else {
+                continue;
+            };
+            if keys.len() > MAX_PUSHED_KEYS {
+                continue;
+            }
+            let predicate = col(probe_key.clone()).is_in(lit(keys), nulls_equal);
+            predicates.push(to_expr_ir(predicate, expr_arena)?.node());
+        }
+        let Some(predicate) = predicates.into_iter().reduce(|left, right| {
+            expr_arena.add(AExpr::BinaryExpr {
+                left,
+                op: Operator::And,
+                right,
+            })
+        }) else {
+            return Ok(None);
+        };
+        Ok(Some(lp_arena.add(IR::Filter {
+            input: probe,
+            predicate: ExprIR::from_node(predicate, expr_arena),
+        })))
+    };
+
+    let new_left = reduce(input_left, input_right, lp_arena, true)?;
+    let new_right = if reduce_right {
+        reduce(input_right, input_left, lp_arena, false)?
+    } else {
+        None
+    };
+    if let IR::Join {
+        input_left,
+        input_right,
+        ..
+    } = lp_arena.get_mut(node)
+    {
+        *input_left = new_left.unwrap_or(*input_left);
+        *input_right = new_right.unwrap_or(*input_right);
+    }
+    Ok(())
+}
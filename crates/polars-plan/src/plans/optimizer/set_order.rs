@@ -20,6 +20,8 @@ fn is_order_dependent_top_level(ae: &AExpr, ctx: Context) -> bool {
             IRAggExpr::Std(_, _) => false,
             IRAggExpr::Var(_, _) => false,
             IRAggExpr::AggGroups(_) => true,
+            #[cfg(feature = "bitwise")]
+            IRAggExpr::Bitwise(_, _) => false,
         },
         AExpr::Column(_) => matches!(ctx, Context::Aggregation),
         _ => true,
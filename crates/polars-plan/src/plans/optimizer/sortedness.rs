@@ -0,0 +1,393 @@
+//! Tracks the columns that are known to be sorted through the plan, and uses them to remove
+//! sorts of inputs that are already sorted.
+//!
+//! The sort order of columns is known from
+//! - the sorted flags of the columns of in-memory `DataFrame`s,
+//! - the sorting columns of the metadata of a Parquet file with a single row group, if
+//!   `OptFlags::SORTED_METADATA` is on,
+//! - the row index of scans,
+//! - `set_sorted` / `set_sorted_flag`, which the user asserts to be correct,
+//! - the first key of sorts.
+//!
+//! It is kept by operations that don't reorder rows, e.g. filters, slices and projections.
+//! Removing a sort before e.g. a `group_by_dynamic` also allows the streaming engine to run it
+//! without collecting its input in memory. When both keys of an inner or left join are known to
+//! be sorted, their sorted flags are set so that the in-memory engine uses a sorted merge join.
+
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+use polars_core::series::IsSorted;
+use polars_ops::frame::{JoinType, MaintainOrderJoin};
+use polars_utils::arena::{Arena, Node};
+
+use super::{AExpr, IR};
+use crate::dsl::{FunctionExpr, col};
+use crate::plans::{ExprIR, FunctionIR, ProjectionOptions, to_expr_ir};
+
+/// The sort order of a column.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Sortedness {
+    descending: bool,
+    /// Whether the nulls are at the end. `None` if the column has no nulls, or if its sort order
+    /// was asserted by the user, in which case the nulls are assumed to be where a sort puts
+    /// them.
+    nulls_last: Option<bool>,
+}
+
+impl Sortedness {
+    fn from_is_sorted(is_sorted: IsSorted) -> Option<Self> {
+        match is_sorted {
+            IsSorted::Ascending => Some(Self {
+                descending: false,
+                nulls_last: None,
+            }),
+            IsSorted::Descending => Some(Self {
+                descending: true,
+                nulls_last: None,
+            }),
+            IsSorted::Not => None,
+        }
+    }
+
+    /// Whether a sort with these options is a no-op on a column with this sort order.
+    fn satisfies(&self, descending: bool, nulls_last: bool) -> bool {
+        self.descending == descending && self.nulls_last.is_none_or(|n| n == nulls_last)
+    }
+}
+
+type SortedColumns = Arc<PlHashMap<PlSmallStr, Sortedness>>;
+
+/// The sort order of the outputs of the nodes that were visited.
+struct SortedCache {
+    /// Whether the sort order of scans is taken from the metadata of their files.
+    use_metadata: bool,
+    nodes: PlHashMap<Node, SortedColumns>,
+}
+
+pub(super) fn optimize(
+    root: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+    use_metadata: bool,
+) -> PolarsResult<()> {
+    let mut cache = SortedCache {
+        use_metadata,
+        nodes: PlHashMap::default(),
+    };
+    let mut ir_stack = Vec::with_capacity(16);
+    ir_stack.push(root);
+
+    while let Some(current) = ir_stack.pop() {
+        if let Some(ir) = elide_sort(current, lp_arena, expr_arena, &mut cache) {
+            lp_arena.replace(current, ir);
+            // The input of the sort is now the current node.
+            ir_stack.push(current);
+            continue;
+        }
+        set_sorted_join_keys(current, lp_arena, expr_arena, &mut cache)?;
+        lp_arena.get(current).copy_inputs(&mut ir_stack);
+    }
+    Ok(())
+}
+
+fn column_name<'a>(e: &'a ExprIR, expr_arena: &Arena<AExpr>) -> Option<&'a PlSmallStr> {
+    match expr_arena.get(e.node()) {
+        AExpr::Column(name) if name == e.output_name() => Some(e.output_name()),
+        _ => None,
+    }
+}
+
+/// Replaces a sort by a single column of an input that is already sorted by it with its input,
+/// or with a slice if the sort has one.
+fn elide_sort(
+    node: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+    cache: &mut SortedCache,
+) -> Option<IR> {
+    let IR::Sort {
+        input,
+        by_column,
+        slice,
+        sort_options,
+    } = lp_arena.get(node)
+    else {
+        return None;
+    };
+    let [by] = by_column.as_slice() else {
+        return None;
+    };
+    let name = column_name(by, expr_arena)?;
+    let sorted = sorted_columns(*input, lp_arena, expr_arena, cache);
+    if !sorted
+        .get(name)
+        .is_some_and(|s| s.satisfies(sort_options.descending[0], sort_options.nulls_last[0]))
+    {
+        return None;
+    }
+
+    let (input, slice) = (*input, *slice);
+    Some(match slice {
+        Some((offset, len)) => IR::Slice {
+            input,
+            offset,
+            len: len.min(IdxSize::MAX as usize) as IdxSize,
+        },
+        None => lp_arena.take(input),
+    })
+}
+
+/// Sets the sorted flags of the keys of an inner or left join if both are known to be sorted in
+/// ascending order, so that the join can merge them.
+fn set_sorted_join_keys(
+    node: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+    cache: &mut SortedCache,
+) -> PolarsResult<()> {
+    let IR::Join {
+        input_left,
+        input_right,
+        left_on,
+        right_on,
+        options,
+        ..
+    } = lp_arena.get(node)
+    else {
+        return Ok(());
+    };
+    if !matches!(options.args.how, JoinType::Inner | JoinType::Left) {
+        return Ok(());
+    }
+    let ([left_key], [right_key]) = (left_on.as_slice(), right_on.as_slice()) else {
+        return Ok(());
+    };
+    let (Some(left_name), Some(right_name)) = (
+        column_name(left_key, expr_arena).cloned(),
+        column_name(right_key, expr_arena).cloned(),
+    ) else {
+        return Ok(());
+    };
+    let (input_left, input_right) = (*input_left, *input_right);
+
+    let is_ascending =
+        |sorted: &SortedColumns, name: &PlSmallStr| sorted.get(name).is_some_and(|s| !s.descending);
+    let left_sorted = sorted_columns(input_left, lp_arena, expr_arena, cache);
+    let right_sorted = sorted_columns(input_right, lp_arena, expr_arena, cache);
+    if !is_ascending(&left_sorted, &left_name) || !is_ascending(&right_sorted, &right_name) {
+        return Ok(());
+    }
+
+    let input_left = set_sorted_flag(input_left, left_name, lp_arena, expr_arena)?;
+    let input_right = set_sorted_flag(input_right, right_name, lp_arena, expr_arena)?;
+    if let IR::Join {
+        input_left: l,
+        input_right: r,
+        ..
+    } = lp_arena.get_mut(node)
+    {
+        *l = input_left;
+        *r = input_right;
+    }
+    Ok(())
+}
+
+/// Adds `with_columns(pl.col(name).set_sorted())` on top of `input`.
+fn set_sorted_flag(
+    input: Node,
+    name: PlSmallStr,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<Node> {
+    let schema = lp_arena.get(input).schema(lp_arena).into_owned();
+    let expr = to_expr_ir(col(name).set_sorted_flag(IsSorted::Ascending), expr_arena)?;
+    Ok(lp_arena.add(IR::HStack {
+        input,
+        exprs: vec![expr],
+        schema,
+        options: ProjectionOptions::default(),
+    }))
+}
+
+/// The sort order of the columns of the output of `node` that are known to be sorted.
+fn sorted_columns(
+    node: Node,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+    cache: &mut SortedCache,
+) -> SortedColumns {
+    if let Some(sorted) = cache.nodes.get(&node) {
+        return sorted.clone();
+    }
+
+    let use_metadata = cache.use_metadata;
+    let mut rec = |input: Node| sorted_columns(input, lp_arena, expr_arena, cache);
+    let sorted = match lp_arena.get(node) {
+        IR::DataFrameScan { df, .. } => Arc::new(
+            df.get_columns()
+                .iter()
+                .filter_map(|c| {
+                    let mut sortedness = Sortedness::from_is_sorted(c.is_sorted_flag())?;
+                    if c.null_count() > 0 {
+                        // The nulls are either all at the start or all at the end.
+                        sortedness.nulls_last = Some(c.get(0).is_ok_and(|v| !v.is_null()));
+                    }
+                    Some((c.name().clone(), sortedness))
+                })
+                .collect(),
+        ),
+        IR::Scan {
+            sources,
+            scan_type,
+            file_options,
+            ..
+        } => {
+            let mut sorted = PlHashMap::default();
+            // The metadata is of the first file, which says nothing about the order of the others.
+            #[cfg(feature = "parquet")]
+            if let crate::dsl::FileScan::Parquet {
+                metadata: Some(metadata),
+                ..
+            } = scan_type.as_ref()
+            {
+                if use_metadata && sources.len() == 1 && file_options.column_parsers.is_none() {
+                    sorted.extend(parquet_sorting_column(metadata));
+                }
+            }
+            #[cfg(not(feature = "parquet"))]
+            let _ = (sources, scan_type, use_metadata);
+            if let Some(row_index) = &file_options.row_index {
+                let sortedness = Sortedness {
+                    descending: false,
+                    nulls_last: None,
+                };
+                sorted.insert(row_index.name.clone(), sortedness);
+            }
+            Arc::new(sorted)
+        },
+        IR::Filter { input, .. }
+        | IR::Slice { input, .. }
+        | IR::Cache { input, .. }
+        | IR::SimpleProjection { input, .. } => rec(*input),
+        IR::Distinct { input, options } if options.maintain_order => rec(*input),
+        IR::Select { input, expr, .. } => {
+            let input_sorted = rec(*input);
+            Arc::new(project_sorted(&input_sorted, expr, expr_arena))
+        },
+        IR::HStack { input, exprs, .. } => {
+            let input_sorted = rec(*input);
+            let mut sorted = (*input_sorted).clone();
+            for e in exprs {
+                sorted.remove(e.output_name());
+            }
+            sorted.extend(project_sorted(&input_sorted, exprs, expr_arena));
+            Arc::new(sorted)
+        },
+        IR::Sort {
+            by_column,
+            sort_options,
+            ..
+        } => {
+            let mut sorted = PlHashMap::default();
+            if let Some(name) = by_column.first().and_then(|e| column_name(e, expr_arena)) {
+                let sortedness = Sortedness {
+                    descending: sort_options.descending[0],
+                    nulls_last: Some(sort_options.nulls_last[0]),
+                };
+                sorted.insert(name.clone(), sortedness);
+            }
+            Arc::new(sorted)
+        },
+        IR::Join {
+            input_left,
+            schema,
+            options,
+            ..
+        } if matches!(options.args.how, JoinType::Inner | JoinType::Left)
+            && matches!(
+                options.args.maintain_order,
+                MaintainOrderJoin::Left | MaintainOrderJoin::LeftRight
+            ) =>
+        {
+            // The columns of the left side keep their names and their order.
+            let left_sorted = rec(*input_left);
+            let left_schema = lp_arena.get(*input_left).schema(lp_arena);
+            Arc::new(
+                left_sorted
+                    .iter()
+                    .filter(|(name, _)| {
+                        left_schema.contains(name.as_str()) && schema.contains(name.as_str())
+                    })
+                    .map(|(name, s)| (name.clone(), *s))
+                    .collect(),
+            )
+        },
+        IR::MapFunction {
+            input,
+            function: FunctionIR::RowIndex { name, .. },
+        } => {
+            let mut sorted = (*rec(*input)).clone();
+            let sortedness = Sortedness {
+                descending: false,
+                nulls_last: None,
+            };
+            sorted.insert(name.clone(), sortedness);
+            Arc::new(sorted)
+        },
+        _ => Default::default(),
+    };
+
+    cache.nodes.insert(node, sorted.clone());
+    sorted
+}
+
+/// The sort order of the outputs of `exprs` that are columns of the input or have their sorted
+/// flag set.
+fn project_sorted(
+    input_sorted: &PlHashMap<PlSmallStr, Sortedness>,
+    exprs: &[ExprIR],
+    expr_arena: &Arena<AExpr>,
+) -> PlHashMap<PlSmallStr, Sortedness> {
+    exprs
+        .iter()
+        .filter_map(|e| {
+            let sortedness = match expr_arena.get(e.node()) {
+                AExpr::Column(name) => *input_sorted.get(name)?,
+                AExpr::Function {
+                    function: FunctionExpr::SetSortedFlag(is_sorted),
+                    ..
+                } => Sortedness::from_is_sorted(*is_sorted)?,
+                _ => return None,
+            };
+            Some((e.output_name().clone(), sortedness))
+        })
+        .collect()
+}
+
+/// The column declared as sorted by the metadata of a Parquet file with a single row group.
+///
+/// The sorting columns of the metadata only apply to the rows of a row group, so they don't say
+/// anything about the order of the rows of different row groups. Only the first sorting column
+/// is sorted by itself, the others are only sorted within the ties of the columns before them.
+#[cfg(feature = "parquet")]
+fn parquet_sorting_column(
+    metadata: &polars_io::parquet::metadata::FileMetadataRef,
+) -> Option<(PlSmallStr, Sortedness)> {
+    let [row_group] = metadata.row_groups.as_slice() else {
+        return None;
+    };
+    let sorting = row_group.sorting_columns()?.first()?;
+    let column = metadata
+        .schema_descr
+        .columns()
+        .get(usize::try_from(sorting.column_idx).ok()?)?;
+    let [name] = column.path_in_schema.as_slice() else {
+        return None;
+    };
+    let sortedness = Sortedness {
+        descending: sorting.descending,
+        nulls_last: Some(!sorting.nulls_first),
+    };
+    Some((name.clone(), sortedness))
+}
@@ -16,8 +16,8 @@ fn accept_as_io_predicate(e: &Expr) -> bool {
             },
             LiteralValue::Series(s) => s.estimated_size() < LIMIT,
 
-            // Don't accept dynamic types
-            LiteralValue::Dyn(_) => false,
+            // Don't accept dynamic types or values that are bound later
+            LiteralValue::Dyn(_) | LiteralValue::Placeholder(_) => false,
             _ => true,
         },
         Expr::Wildcard | Expr::Column(_) => true,
@@ -0,0 +1,121 @@
+//! Statistics of scanned tables, collected by `LazyFrame::analyze` for cost-based optimizations.
+
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, LazyLock};
+
+use parking_lot::RwLock;
+use polars_core::prelude::*;
+use polars_core::scalar::Scalar;
+use polars_utils::pl_str::PlSmallStr;
+
+use crate::dsl::{FileScan, FileScanOptions, ScanSources};
+
+/// Statistics of a single column of a table.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColumnStatistics {
+    pub null_count: usize,
+    /// An estimate of the number of distinct values, including null.
+    pub n_unique: usize,
+    /// The smallest value, if the column can be ordered and has non-null values.
+    pub min: Option<Scalar>,
+    /// The largest value, if the column can be ordered and has non-null values.
+    pub max: Option<Scalar>,
+}
+
+impl ColumnStatistics {
+    /// The estimated fraction of rows an equality predicate on this column keeps.
+    pub fn equality_selectivity(&self) -> f64 {
+        1.0 / self.n_unique.max(1) as f64
+    }
+}
+
+/// Statistics of a table, see [`set_table_statistics`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct TableStatistics {
+    pub num_rows: usize,
+    pub columns: PlIndexMap<PlSmallStr, ColumnStatistics>,
+}
+
+impl TableStatistics {
+    pub fn column(&self, name: &str) -> Option<&ColumnStatistics> {
+        self.columns.get(name)
+    }
+}
+
+/// Identifies a table scanned from files: the same files read with the same options.
+#[derive(PartialEq, Eq, Hash)]
+struct CatalogKey {
+    /// The paths after globs and directories were expanded, as they are in the IR.
+    paths: Vec<PathBuf>,
+    scan_type: FileScan,
+    file_options: FileScanOptions,
+}
+
+static STATISTICS_CATALOG: LazyLock<RwLock<PlHashMap<CatalogKey, Arc<TableStatistics>>>> =
+    LazyLock::new(Default::default);
+
+static STATISTICS_VERSION: AtomicU64 = AtomicU64::new(0);
+
+/// The version of the statistics catalog, which changes whenever statistics are stored or
+/// removed. Caches of optimized plans include it in their key.
+pub fn table_statistics_version() -> u64 {
+    STATISTICS_VERSION.load(Ordering::Relaxed)
+}
+
+fn catalog_key(
+    sources: &ScanSources,
+    scan_type: &FileScan,
+    file_options: &FileScanOptions,
+) -> Option<CatalogKey> {
+    let paths = sources.as_paths()?.to_vec();
+    // The projected columns and how the output is chunked don't change the rows of the table.
+    let file_options = FileScanOptions {
+        with_columns: None,
+        cache: false,
+        rechunk: false,
+        ..file_options.clone()
+    };
+    Some(CatalogKey {
+        paths,
+        scan_type: scan_type.clone(),
+        file_options,
+    })
+}
+
+/// Returns the statistics of the table scanned from `sources` with `scan_type` and
+/// `file_options`, if they were collected.
+pub fn get_table_statistics(
+    sources: &ScanSources,
+    scan_type: &FileScan,
+    file_options: &FileScanOptions,
+) -> Option<Arc<TableStatistics>> {
+    let key = catalog_key(sources, scan_type, file_options)?;
+    STATISTICS_CATALOG.read().get(&key).cloned()
+}
+
+/// Stores the statistics of the table scanned from `sources` with `scan_type` and `file_options`,
+/// which must be expanded and resolved like those of an [`IR::Scan`](crate::plans::IR::Scan).
+/// Only tables scanned from paths are stored, as other sources can't be identified across
+/// queries. A scan only uses the statistics if it reads the files with the same options, e.g. the
+/// same `n_rows`. The statistics are not invalidated if the files change, call this again or
+/// [`clear_table_statistics`] instead.
+pub fn set_table_statistics(
+    sources: &ScanSources,
+    scan_type: &FileScan,
+    file_options: &FileScanOptions,
+    statistics: Arc<TableStatistics>,
+) -> bool {
+    let Some(key) = catalog_key(sources, scan_type, file_options) else {
+        return false;
+    };
+    STATISTICS_CATALOG.write().insert(key, statistics);
+    STATISTICS_VERSION.fetch_add(1, Ordering::Relaxed);
+    true
+}
+
+/// Removes the statistics of all tables.
+pub fn clear_table_statistics() {
+    STATISTICS_CATALOG.write().clear();
+    STATISTICS_VERSION.fetch_add(1, Ordering::Relaxed);
+}
@@ -1,10 +1,11 @@
 use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
-use polars_utils::arena::Arena;
+use polars_utils::arena::{Arena, Node};
 
 use super::*;
-use crate::plans::{AExpr, IR};
+use crate::dsl::{FileScan, PartitionVariantIR, SinkTypeIR};
+use crate::plans::{AExpr, ArenaExprIter, ArenaLpIter, FunctionIR, IR};
 use crate::prelude::ExprIR;
 use crate::prelude::aexpr::traverse_and_hash_aexpr;
 
@@ -39,6 +40,75 @@ impl HashableEqLP<'_> {
     }
 }
 
+/// Hashes the whole plan below `root`: every node with its expressions and the shape of the tree.
+///
+/// Returns `None` if the plan contains user-defined functions, as their hash doesn't identify what
+/// they compute.
+pub fn hash_ir_plan<H: Hasher>(
+    root: Node,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+    state: &mut H,
+) -> Option<()> {
+    let mut inputs: Vec<Node> = Vec::new();
+    let mut exprs = Vec::new();
+    for (node, ir) in lp_arena.iter(root) {
+        let has_udf = match ir {
+            #[cfg(feature = "python")]
+            IR::PythonScan { .. } => true,
+            IR::Scan { scan_type, .. } => matches!(&**scan_type, FileScan::Anonymous { .. }),
+            IR::MapFunction { function, .. } => !matches!(
+                function,
+                FunctionIR::RowIndex { .. }
+                    | FunctionIR::FastCount { .. }
+                    | FunctionIR::Unnest { .. }
+                    | FunctionIR::Rechunk
+                    | FunctionIR::Hint(_)
+                    | FunctionIR::Rename { .. }
+                    | FunctionIR::Explode { .. }
+            ),
+            IR::GroupBy { apply, .. } => apply.is_some(),
+            IR::Sink {
+                payload: SinkTypeIR::Partition(p),
+                ..
+            } => p.file_path_cb.is_some(),
+            _ => false,
+        };
+        exprs.clear();
+        ir.copy_exprs(&mut exprs);
+        let has_udf = has_udf
+            || exprs.iter().any(|e| {
+                expr_arena
+                    .iter(e.node())
+                    .any(|(_, ae)| matches!(ae, AExpr::AnonymousFunction { .. }))
+            });
+        if has_udf {
+            return None;
+        }
+
+        IRNode::new(node)
+            .hashable_and_cmp(lp_arena, expr_arena)
+            .hash(state);
+        inputs.clear();
+        ir.copy_inputs(&mut inputs);
+        inputs.len().hash(state);
+    }
+    Some(())
+}
+
+/// Whether the plans below `left` and `right` are equal, which may be in different arenas. Plans
+/// with an equal hash from [`hash_ir_plan`] are compared with this to rule out hash collisions.
+pub fn ir_plan_eq(
+    left: Node,
+    left_arenas: (&Arena<IR>, &Arena<AExpr>),
+    right: Node,
+    right_arenas: (&Arena<IR>, &Arena<AExpr>),
+) -> bool {
+    let (left, right) = (IRNode::new(left), IRNode::new(right));
+    left.hashable_and_cmp(left_arenas.0, left_arenas.1)
+        == right.hashable_and_cmp(right_arenas.0, right_arenas.1)
+}
+
 fn hash_option_expr<H: Hasher>(expr: &Option<ExprIR>, expr_arena: &Arena<AExpr>, state: &mut H) {
     if let Some(e) = expr {
         e.traverse_and_hash(expr_arena, state)
@@ -203,22 +273,67 @@ impl Hash for HashableEqLP<'_> {
     }
 }
 
-fn expr_irs_eq(l: &[ExprIR], r: &[ExprIR], expr_arena: &Arena<AExpr>) -> bool {
-    l.len() == r.len() && l.iter().zip(r).all(|(l, r)| expr_ir_eq(l, r, expr_arena))
+/// The expression arenas of the left and right side of a comparison.
+type ExprArenas<'a> = (&'a Arena<AExpr>, &'a Arena<AExpr>);
+
+fn expr_irs_eq(l: &[ExprIR], r: &[ExprIR], arenas: ExprArenas) -> bool {
+    l.len() == r.len() && l.iter().zip(r).all(|(l, r)| expr_ir_eq(l, r, arenas))
 }
 
-fn expr_ir_eq(l: &ExprIR, r: &ExprIR, expr_arena: &Arena<AExpr>) -> bool {
+fn expr_ir_eq(l: &ExprIR, r: &ExprIR, (l_arena, r_arena): ExprArenas) -> bool {
     l.get_alias() == r.get_alias() && {
         let l = AexprNode::new(l.node());
         let r = AexprNode::new(r.node());
-        l.hashable_and_cmp(expr_arena) == r.hashable_and_cmp(expr_arena)
+        l.hashable_and_cmp(l_arena) == r.hashable_and_cmp(r_arena)
     }
 }
 
-fn opt_expr_ir_eq(l: &Option<ExprIR>, r: &Option<ExprIR>, expr_arena: &Arena<AExpr>) -> bool {
+fn opt_expr_ir_eq(l: &Option<ExprIR>, r: &Option<ExprIR>, arenas: ExprArenas) -> bool {
     match (l, r) {
         (None, None) => true,
-        (Some(l), Some(r)) => expr_ir_eq(l, r, expr_arena),
+        (Some(l), Some(r)) => expr_ir_eq(l, r, arenas),
+        _ => false,
+    }
+}
+
+fn sink_type_eq(l: &SinkTypeIR, r: &SinkTypeIR, arenas: ExprArenas) -> bool {
+    match (l, r) {
+        (SinkTypeIR::Memory, SinkTypeIR::Memory) => true,
+        (SinkTypeIR::File(l), SinkTypeIR::File(r)) => l == r,
+        (SinkTypeIR::Partition(l), SinkTypeIR::Partition(r)) => {
+            let variant_eq = match (&l.variant, &r.variant) {
+                (PartitionVariantIR::MaxSize(l), PartitionVariantIR::MaxSize(r)) => l == r,
+                (
+                    PartitionVariantIR::Parted {
+                        key_exprs: l,
+                        include_key: il,
+                    },
+                    PartitionVariantIR::Parted {
+                        key_exprs: r,
+                        include_key: ir,
+                    },
+                )
+                | (
+                    PartitionVariantIR::ByKey {
+                        key_exprs: l,
+                        include_key: il,
+                    },
+                    PartitionVariantIR::ByKey {
+                        key_exprs: r,
+                        include_key: ir,
+                    },
+                ) => il == ir && expr_irs_eq(l, r, arenas),
+                _ => false,
+            };
+            // a callback is a user-defined function, which is never equal to another
+            l.file_path_cb.is_none()
+                && r.file_path_cb.is_none()
+                && l.base_path == r.base_path
+                && l.file_type == r.file_type
+                && l.sink_options == r.sink_options
+                && l.cloud_options == r.cloud_options
+                && variant_eq
+        },
         _ => false,
     }
 }
@@ -226,7 +341,8 @@ fn opt_expr_ir_eq(l: &Option<ExprIR>, r: &Option<ExprIR>, expr_arena: &Arena<AEx
 impl HashableEqLP<'_> {
     fn is_equal(&self, other: &Self) -> bool {
         let alp_l = self.node.to_alp(self.lp_arena);
-        let alp_r = other.node.to_alp(self.lp_arena);
+        let alp_r = other.node.to_alp(other.lp_arena);
+        let expr_arenas = (self.expr_arena, other.expr_arena);
         if std::mem::discriminant(alp_l) != std::mem::discriminant(alp_r) {
             return false;
         }
@@ -252,7 +368,7 @@ impl HashableEqLP<'_> {
                     input: _,
                     predicate: r,
                 },
-            ) => expr_ir_eq(l, r, self.expr_arena),
+            ) => expr_ir_eq(l, r, expr_arenas),
             (
                 IR::Scan {
                     sources: pl,
@@ -273,10 +389,10 @@ impl HashableEqLP<'_> {
                     file_options: or,
                 },
             ) => {
-                pl.as_paths() == pr.as_paths()
+                pl == pr
                     && stl == str
                     && ol == or
-                    && opt_expr_ir_eq(pred_l, pred_r, self.expr_arena)
+                    && opt_expr_ir_eq(pred_l, pred_r, expr_arenas)
             },
             (
                 IR::DataFrameScan {
@@ -313,7 +429,7 @@ impl HashableEqLP<'_> {
                     options: or,
                     schema: _,
                 },
-            ) => ol == or && expr_irs_eq(el, er, self.expr_arena),
+            ) => ol == or && expr_irs_eq(el, er, expr_arenas),
             (
                 IR::Sort {
                     input: _,
@@ -327,10 +443,7 @@ impl HashableEqLP<'_> {
                     slice: r_slice,
                     sort_options: r_options,
                 },
-            ) => {
-                (l_slice == r_slice && l_options == r_options)
-                    && expr_irs_eq(cl, cr, self.expr_arena)
-            },
+            ) => (l_slice == r_slice && l_options == r_options) && expr_irs_eq(cl, cr, expr_arenas),
             (
                 IR::GroupBy {
                     input: _,
@@ -355,8 +468,8 @@ impl HashableEqLP<'_> {
                     && apply_r.is_none()
                     && ol == or
                     && maintain_l == maintain_r
-                    && expr_irs_eq(keys_l, keys_r, self.expr_arena)
-                    && expr_irs_eq(aggs_l, aggs_r, self.expr_arena)
+                    && expr_irs_eq(keys_l, keys_r, expr_arenas)
+                    && expr_irs_eq(aggs_l, aggs_r, expr_arenas)
             },
             (
                 IR::Join {
@@ -375,11 +488,7 @@ impl HashableEqLP<'_> {
                     right_on: rr,
                     options: or,
                 },
-            ) => {
-                ol == or
-                    && expr_irs_eq(ll, lr, self.expr_arena)
-                    && expr_irs_eq(rl, rr, self.expr_arena)
-            },
+            ) => ol == or && expr_irs_eq(ll, lr, expr_arenas) && expr_irs_eq(rl, rr, expr_arenas),
             (
                 IR::HStack {
                     input: _,
@@ -393,7 +502,7 @@ impl HashableEqLP<'_> {
                     schema: _,
                     options: or,
                 },
-            ) => ol == or && expr_irs_eq(el, er, self.expr_arena),
+            ) => ol == or && expr_irs_eq(el, er, expr_arenas),
             (
                 IR::Distinct {
                     input: _,
@@ -451,10 +560,46 @@ impl HashableEqLP<'_> {
                 l.len() == r.len()
                     && l.iter().zip(r.iter()).all(|(l, r)| {
                         let l = AexprNode::new(*l).hashable_and_cmp(self.expr_arena);
-                        let r = AexprNode::new(*r).hashable_and_cmp(self.expr_arena);
+                        let r = AexprNode::new(*r).hashable_and_cmp(other.expr_arena);
                         l == r
                     })
             },
+            (
+                IR::Sink {
+                    input: _,
+                    payload: l,
+                },
+                IR::Sink {
+                    input: _,
+                    payload: r,
+                },
+            ) => sink_type_eq(l, r, expr_arenas),
+            (IR::SinkMultiple { .. }, IR::SinkMultiple { .. }) => true,
+            (
+                IR::Cache {
+                    input: _,
+                    id: id_l,
+                    cache_hits: hits_l,
+                },
+                IR::Cache {
+                    input: _,
+                    id: id_r,
+                    cache_hits: hits_r,
+                },
+            ) => id_l == id_r && hits_l == hits_r,
+            #[cfg(feature = "merge_sorted")]
+            (
+                IR::MergeSorted {
+                    input_left: _,
+                    input_right: _,
+                    key: l,
+                },
+                IR::MergeSorted {
+                    input_left: _,
+                    input_right: _,
+                    key: r,
+                },
+            ) => l == r,
             _ => false,
         }
     }
@@ -474,7 +619,7 @@ impl PartialEq for HashableEqLP<'_> {
                     let l = IRNode::new(l);
                     let r = IRNode::new(r);
                     let l_alp = l.to_alp(self.lp_arena);
-                    let r_alp = r.to_alp(self.lp_arena);
+                    let r_alp = r.to_alp(other.lp_arena);
 
                     if self.ignore_cache {
                         match (l_alp, r_alp) {
@@ -499,7 +644,7 @@ impl PartialEq for HashableEqLP<'_> {
 
                     if !l
                         .hashable_and_cmp(self.lp_arena, self.expr_arena)
-                        .is_equal(&r.hashable_and_cmp(self.lp_arena, self.expr_arena))
+                        .is_equal(&r.hashable_and_cmp(other.lp_arena, other.expr_arena))
                     {
                         return false;
                     }
@@ -8,6 +8,8 @@ mod lp;
 mod visitors;
 
 pub use expr::*;
+#[cfg(feature = "cse")]
+pub use hash::{hash_ir_plan, ir_plan_eq};
 pub use lp::*;
 pub use visitors::*;
 
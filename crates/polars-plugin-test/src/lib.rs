@@ -0,0 +1,115 @@
+//! A plugin library with a manifest, to test that Rust programs can load it and call its kernels
+//! with `PluginLibrary`. The kernels are exported by hand, like `pyo3-polars` does.
+#![allow(clippy::missing_safety_doc)]
+use std::cell::RefCell;
+use std::ffi::{CString, c_char};
+
+use arrow::ffi::{ArrowSchema, export_field_to_c, import_field_from_c};
+use polars_core::prelude::*;
+use polars_ffi::manifest::{KERNEL_ELEMENTWISE, KERNEL_RETURNS_SCALAR};
+use polars_ffi::version_0::{CallerContext, SeriesExport, export_series, import_series_buffer};
+
+polars_ffi::polars_plugin_manifest! {
+    c"add_one" => KERNEL_ELEMENTWISE,
+    c"total" => KERNEL_RETURNS_SCALAR,
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<CString> = RefCell::new(CString::default());
+}
+
+/// Version 0.1 of the plugin interface.
+#[unsafe(no_mangle)]
+pub extern "C" fn _polars_plugin_get_version() -> u32 {
+    1
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn _polars_plugin_get_last_error_message() -> *const c_char {
+    LAST_ERROR.with(|msg| msg.borrow().as_ptr())
+}
+
+unsafe fn call_kernel(
+    inputs: *const SeriesExport,
+    n_inputs: usize,
+    return_value: *mut SeriesExport,
+    kernel: impl FnOnce(&[Series]) -> PolarsResult<Series>,
+) {
+    let out = unsafe { import_series_buffer(inputs as *mut SeriesExport, n_inputs) }
+        .and_then(|inputs| kernel(&inputs));
+    match out {
+        Ok(out) => unsafe { std::ptr::write(return_value, export_series(&out)) },
+        Err(e) => LAST_ERROR.with(|msg| *msg.borrow_mut() = CString::new(e.to_string()).unwrap()),
+    }
+}
+
+unsafe fn kernel_field(
+    fields: *const ArrowSchema,
+    n_fields: usize,
+    return_value: *mut ArrowSchema,
+    output_field: impl FnOnce(&[Field]) -> Field,
+) {
+    let fields = (0..n_fields)
+        .map(|i| {
+            let field = unsafe { import_field_from_c(&*fields.add(i)) }.unwrap();
+            Field::from(&field)
+        })
+        .collect::<Vec<_>>();
+    let field = output_field(&fields).to_arrow(CompatLevel::newest());
+    unsafe { std::ptr::write(return_value, export_field_to_c(&field)) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn _polars_plugin_add_one(
+    inputs: *const SeriesExport,
+    n_inputs: usize,
+    _kwargs: *const u8,
+    _kwargs_len: usize,
+    return_value: *mut SeriesExport,
+    _context: *const CallerContext,
+) {
+    unsafe { call_kernel(inputs, n_inputs, return_value, |s| Ok(&s[0] + 1)) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn _polars_plugin_field_add_one(
+    fields: *const ArrowSchema,
+    n_fields: usize,
+    return_value: *mut ArrowSchema,
+    _kwargs: *const u8,
+    _kwargs_len: usize,
+) {
+    unsafe { kernel_field(fields, n_fields, return_value, |f| f[0].clone()) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn _polars_plugin_total(
+    inputs: *const SeriesExport,
+    n_inputs: usize,
+    _kwargs: *const u8,
+    _kwargs_len: usize,
+    return_value: *mut SeriesExport,
+    _context: *const CallerContext,
+) {
+    unsafe {
+        call_kernel(inputs, n_inputs, return_value, |s| {
+            let total = s[0].sum::<i64>()?;
+            Ok(Series::new(s[0].name().clone(), [total]))
+        })
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn _polars_plugin_field_total(
+    fields: *const ArrowSchema,
+    n_fields: usize,
+    return_value: *mut ArrowSchema,
+    _kwargs: *const u8,
+    _kwargs_len: usize,
+) {
+    unsafe {
+        kernel_field(fields, n_fields, return_value, |f| {
+            Field::new(f[0].name().clone(), DataType::Int64)
+        })
+    }
+}
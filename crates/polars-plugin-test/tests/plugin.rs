@@ -0,0 +1,47 @@
+use std::env::consts::{DLL_PREFIX, DLL_SUFFIX};
+
+use polars_core::df;
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+
+/// The `cdylib` of this crate, which is built next to the test binary.
+fn plugin_library() -> PluginLibrary {
+    let path = std::env::current_exe()
+        .unwrap()
+        .parent()
+        .unwrap()
+        .join(format!("{DLL_PREFIX}polars_plugin_test{DLL_SUFFIX}"));
+    unsafe { PluginLibrary::load(path.to_str().unwrap()) }.unwrap()
+}
+
+#[test]
+fn test_plugin_library_call() -> PolarsResult<()> {
+    let plugin = plugin_library();
+    assert_eq!(plugin.kernels().collect::<Vec<_>>(), ["add_one", "total"]);
+
+    let df = df![
+        "g" => ["a", "b", "a"],
+        "x" => [1i64, 2, 3],
+    ]?;
+    let out = df
+        .clone()
+        .lazy()
+        .select([plugin.call("add_one", [col("x")], &[])?])
+        .collect()?;
+    assert_eq!(out, df![ "x" => [2i64, 3, 4] ]?);
+
+    let out = df
+        .lazy()
+        .group_by([col("g")])
+        .agg([plugin.call("total", [col("x")], &[])?])
+        .sort(["g"], Default::default())
+        .collect()?;
+    assert_eq!(out, df![ "g" => ["a", "b"], "x" => [4i64, 2] ]?);
+
+    let err = plugin.call("missing", [col("x")], &[]).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("doesn't have a kernel named 'missing'")
+    );
+    Ok(())
+}
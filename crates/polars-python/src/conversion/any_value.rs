@@ -216,8 +216,9 @@ pub(crate) fn py_object_to_any_value<'py>(
             let f = ob.extract::<f64>()?;
             Ok(AnyValue::Float64(f))
         } else {
+            // There is no unsigned 128-bit integer type, so `Int128` is the widest one.
             Err(PyOverflowError::new_err(format!(
-                "int value too large for Polars integer types: {ob}"
+                "int value too large for Polars integer types: {ob} (Int128 is the largest integer type, UInt128 is not supported)"
             )))
         }
     }
@@ -224,6 +224,10 @@ impl<'py> IntoPyObject<'py> for &Wrap<DataType> {
                 let class = pl.getattr(intern!(py, "Int128"))?;
                 class.call0()
             },
+            DataType::Float16 => {
+                let class = pl.getattr(intern!(py, "Float16"))?;
+                class.call0()
+            },
             DataType::Float32 => {
                 let class = pl.getattr(intern!(py, "Float32"))?;
                 class.call0()
@@ -314,6 +318,14 @@ impl<'py> IntoPyObject<'py> for &Wrap<DataType> {
                 let class = pl.getattr(intern!(py, "Unknown"))?;
                 class.call0()
             },
+            #[cfg(feature = "geometry")]
+            DataType::Geometry => {
+                let class = pl.getattr(intern!(py, "Geometry"))?;
+                class.call0()
+            },
+            // Python has no extension types yet, these are shown as their storage type.
+            #[cfg(feature = "dtype-extension")]
+            DataType::Extension(ext) => Wrap(ext.storage().clone()).into_pyobject(py),
             DataType::BinaryOffset => {
                 unimplemented!()
             },
@@ -357,11 +369,14 @@ impl<'py> FromPyObject<'py> for Wrap<DataType> {
                     "UInt16" => DataType::UInt16,
                     "UInt32" => DataType::UInt32,
                     "UInt64" => DataType::UInt64,
+                    "Float16" => DataType::Float16,
                     "Float32" => DataType::Float32,
                     "Float64" => DataType::Float64,
                     "Boolean" => DataType::Boolean,
                     "String" => DataType::String,
                     "Binary" => DataType::Binary,
+                    #[cfg(feature = "geometry")]
+                    "Geometry" => DataType::Geometry,
                     "Categorical" => DataType::Categorical(None, Default::default()),
                     "Enum" => DataType::Enum(None, Default::default()),
                     "Date" => DataType::Date,
@@ -392,11 +407,14 @@ impl<'py> FromPyObject<'py> for Wrap<DataType> {
             "UInt16" => DataType::UInt16,
             "UInt32" => DataType::UInt32,
             "UInt64" => DataType::UInt64,
+            "Float16" => DataType::Float16,
             "Float32" => DataType::Float32,
             "Float64" => DataType::Float64,
             "Boolean" => DataType::Boolean,
             "String" => DataType::String,
             "Binary" => DataType::Binary,
+            #[cfg(feature = "geometry")]
+            "Geometry" => DataType::Geometry,
             "Categorical" => {
                 let ordering = ob.getattr(intern!(py, "ordering")).unwrap();
                 let ordering = ordering.extract::<Wrap<CategoricalOrdering>>()?.0;
@@ -749,14 +767,36 @@ impl<'py> FromPyObject<'py> for Wrap<AsofStrategy> {
     }
 }
 
+impl<'py> FromPyObject<'py> for Wrap<UpsampleFill> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if let Ok(s) = ob.extract::<PyBackedStr>() {
+            let parsed = match &*s {
+                "forward" => UpsampleFill::Forward,
+                "backward" => UpsampleFill::Backward,
+                "interpolate" => UpsampleFill::Interpolate,
+                v => {
+                    return Err(PyValueError::new_err(format!(
+                        "upsample fill strategy must be one of {{'forward', 'backward', 'interpolate'}}, got {v}",
+                    )));
+                },
+            };
+            return Ok(Wrap(parsed));
+        }
+        let av = ob.extract::<Wrap<AnyValue>>()?.0.into_static();
+        Ok(Wrap(UpsampleFill::Constant(Scalar::new(av.dtype(), av))))
+    }
+}
+
 impl<'py> FromPyObject<'py> for Wrap<InterpolationMethod> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = match &*(ob.extract::<PyBackedStr>()?) {
             "linear" => InterpolationMethod::Linear,
             "nearest" => InterpolationMethod::Nearest,
+            "spline" => InterpolationMethod::Spline,
+            "pchip" => InterpolationMethod::Pchip,
             v => {
                 return Err(PyValueError::new_err(format!(
-                    "interpolation `method` must be one of {{'linear', 'nearest'}}, got {v}",
+                    "interpolation `method` must be one of {{'linear', 'nearest', 'spline', 'pchip'}}, got {v}",
                 )));
             },
         };
@@ -1103,6 +1143,40 @@ impl<'py> FromPyObject<'py> for Wrap<SearchSortedSide> {
     }
 }
 
+#[cfg(feature = "binary_encryption")]
+impl<'py> FromPyObject<'py> for Wrap<EncryptionAlgorithm> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "aes-gcm" => EncryptionAlgorithm::AesGcm,
+            "aes-gcm-siv" => EncryptionAlgorithm::AesGcmSiv,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`algorithm` must be one of {{'aes-gcm', 'aes-gcm-siv'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+impl<'py> FromPyObject<'py> for Wrap<HashAlgorithm> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "default" => HashAlgorithm::Default,
+            "xxhash64" => HashAlgorithm::XxHash64,
+            "wyhash" => HashAlgorithm::WyHash,
+            "siphash" => HashAlgorithm::SipHash,
+            "sha256" => HashAlgorithm::Sha256,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`algorithm` must be one of {{'default', 'xxhash64', 'wyhash', 'siphash', 'sha256'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 impl<'py> FromPyObject<'py> for Wrap<ClosedInterval> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
@@ -1120,6 +1194,38 @@ impl<'py> FromPyObject<'py> for Wrap<ClosedInterval> {
     }
 }
 
+#[cfg(feature = "fuzzy_join")]
+impl<'py> FromPyObject<'py> for Wrap<FuzzyMetric> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "levenshtein" => FuzzyMetric::Levenshtein,
+            "jaro_winkler" => FuzzyMetric::JaroWinkler,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`metric` must be one of {{'levenshtein', 'jaro_winkler'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+impl<'py> FromPyObject<'py> for Wrap<ModeTies> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "all" => ModeTies::All,
+            "first" => ModeTies::First,
+            "smallest" => ModeTies::Smallest,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`ties` must be one of {{'all', 'first', 'smallest'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 impl<'py> FromPyObject<'py> for Wrap<WindowMapping> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
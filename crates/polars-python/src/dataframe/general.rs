@@ -445,6 +445,77 @@ impl PyDataFrame {
         Ok(unsafe { std::mem::transmute::<Vec<DataFrame>, Vec<PyDataFrame>>(out) })
     }
 
+    #[cfg(feature = "frame_compare")]
+    #[pyo3(signature = (other, keys, tolerance))]
+    #[allow(clippy::type_complexity)]
+    pub fn compare(
+        &self,
+        py: Python,
+        other: &PyDataFrame,
+        keys: Vec<PyBackedStr>,
+        tolerance: Option<f64>,
+    ) -> PyResult<(Self, Self, Vec<(String, Self)>, Vec<String>, Vec<String>)> {
+        let keys = strings_to_pl_smallstr(keys);
+        let report = py.enter_polars(|| self.df.compare(&other.df, &keys, tolerance))?;
+        let names = |names: Vec<PlSmallStr>| -> Vec<String> {
+            names.iter().map(|name| name.to_string()).collect()
+        };
+        Ok((
+            report.left_only.into(),
+            report.right_only.into(),
+            report
+                .mismatches
+                .into_iter()
+                .map(|(name, df)| (name.to_string(), df.into()))
+                .collect(),
+            names(report.left_only_columns),
+            names(report.right_only_columns),
+        ))
+    }
+
+    #[cfg(feature = "fuzzy_join")]
+    #[pyo3(signature = (
+        other, left_on, right_on, metric, threshold, ngram_size, similarity_column, suffix
+    ))]
+    pub fn join_fuzzy(
+        &self,
+        py: Python,
+        other: &PyDataFrame,
+        left_on: &str,
+        right_on: &str,
+        metric: Wrap<FuzzyMetric>,
+        threshold: f64,
+        ngram_size: usize,
+        similarity_column: Option<String>,
+        suffix: String,
+    ) -> PyResult<Self> {
+        let options = FuzzyJoinOptions {
+            metric: metric.0,
+            threshold,
+            ngram_size,
+            similarity_column: similarity_column.map(|s| s.into()),
+            suffix: Some(suffix.into()),
+        };
+        py.enter_polars_df(|| self.df.join_fuzzy(&other.df, left_on, right_on, options))
+    }
+
+    pub fn join_violations(
+        &self,
+        py: Python,
+        other: &PyDataFrame,
+        left_on: Vec<PyBackedStr>,
+        right_on: Vec<PyBackedStr>,
+        validate: Wrap<JoinValidation>,
+        nulls_equal: bool,
+    ) -> PyResult<Self> {
+        let left_on = strings_to_pl_smallstr(left_on);
+        let right_on = strings_to_pl_smallstr(right_on);
+        py.enter_polars_df(|| {
+            self.df
+                .join_violations(&other.df, left_on, right_on, validate.0, nulls_equal)
+        })
+    }
+
     pub fn lazy(&self) -> PyLazyFrame {
         self.df.clone().lazy().into()
     }
@@ -545,10 +616,18 @@ impl PyDataFrame {
         index_column: &str,
         every: &str,
         stable: bool,
+        fill: Vec<(String, Wrap<UpsampleFill>)>,
     ) -> PyResult<Self> {
         let every = Duration::try_parse(every).map_err(PyPolarsErr::from)?;
+        let fill = fill
+            .into_iter()
+            .map(|(name, strategy)| (name.into(), strategy.0))
+            .collect::<Vec<_>>();
         py.enter_polars_df(|| {
-            if stable {
+            if !fill.is_empty() {
+                self.df
+                    .upsample_with_fill(by, index_column, every, &fill, stable)
+            } else if stable {
                 self.df.upsample_stable(by, index_column, every)
             } else {
                 self.df.upsample(by, index_column, every)
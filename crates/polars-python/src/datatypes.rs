@@ -38,6 +38,9 @@ pub(crate) enum PyDataType {
     Array(usize),
     Enum(Utf8ViewArray),
     Int128,
+    Float16,
+    #[cfg(feature = "geometry")]
+    Geometry,
 }
 
 impl From<&DataType> for PyDataType {
@@ -53,6 +56,7 @@ impl From<&DataType> for PyDataType {
             DataType::UInt16 => UInt16,
             DataType::UInt32 => UInt32,
             DataType::UInt64 => UInt64,
+            DataType::Float16 => Float16,
             DataType::Float32 => Float32,
             DataType::Float64 => Float64,
             DataType::Decimal(p, s) => Decimal(*p, s.expect("unexpected null decimal scale")),
@@ -70,6 +74,10 @@ impl From<&DataType> for PyDataType {
             DataType::Categorical(_, _) => Categorical,
             DataType::Enum(rev_map, _) => Enum(rev_map.as_ref().unwrap().get_categories().clone()),
             DataType::Struct(_) => Struct,
+            #[cfg(feature = "geometry")]
+            DataType::Geometry => Geometry,
+            #[cfg(feature = "dtype-extension")]
+            DataType::Extension(ext) => ext.storage().into(),
             DataType::Null | DataType::Unknown(_) | DataType::BinaryOffset => {
                 panic!("null or unknown not expected here")
             },
@@ -113,6 +121,9 @@ impl From<PyDataType> for DataType {
             PyDataType::Decimal(p, s) => Decimal(p, Some(s)),
             PyDataType::Array(width) => Array(DataType::Null.into(), width),
             PyDataType::Int128 => Int128,
+            PyDataType::Float16 => Float16,
+            #[cfg(feature = "geometry")]
+            PyDataType::Geometry => Geometry,
         }
     }
 }
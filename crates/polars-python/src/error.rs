@@ -7,7 +7,7 @@ use polars_error::PolarsWarning;
 use pyo3::PyTypeInfo;
 use pyo3::exceptions::{
     PyDeprecationWarning, PyFileExistsError, PyFileNotFoundError, PyIOError, PyPermissionError,
-    PyRuntimeError, PyUserWarning,
+    PyRuntimeError, PyTimeoutError, PyUserWarning,
 };
 use pyo3::prelude::*;
 
@@ -105,6 +105,7 @@ impl From<PyPolarsErr> for PyErr {
                 PolarsError::StructFieldNotFound(name) => {
                     StructFieldNotFoundError::new_err(name.to_string())
                 },
+                PolarsError::Timeout(err) => PyTimeoutError::new_err(err.to_string()),
                 PolarsError::Context { .. } => {
                     let tmp = PyPolarsErr::Polars(err.context_trace());
                     PyErr::from(tmp)
@@ -137,6 +137,18 @@ impl PyExpr {
         self.inner.clone().arr().shift(n.inner).into()
     }
 
+    fn arr_dot(&self, other: PyExpr) -> Self {
+        self.inner.clone().arr().dot(other.inner).into()
+    }
+
+    fn arr_cosine_similarity(&self, other: PyExpr) -> Self {
+        self.inner
+            .clone()
+            .arr()
+            .cosine_similarity(other.inner)
+            .into()
+    }
+
     fn arr_explode(&self) -> Self {
         self.inner.clone().arr().explode().into()
     }
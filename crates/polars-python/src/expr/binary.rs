@@ -1,4 +1,6 @@
 use polars::prelude::DataType;
+#[cfg(feature = "binary_encryption")]
+use polars::prelude::EncryptionAlgorithm;
 use pyo3::prelude::*;
 
 use crate::PyExpr;
@@ -42,6 +44,24 @@ impl PyExpr {
         self.inner.clone().binary().base64_encode().into()
     }
 
+    #[cfg(feature = "binary_encryption")]
+    fn bin_encrypt(&self, key: &str, algorithm: Wrap<EncryptionAlgorithm>) -> Self {
+        self.inner
+            .clone()
+            .binary()
+            .encrypt(key.into(), algorithm.0)
+            .into()
+    }
+
+    #[cfg(feature = "binary_encryption")]
+    fn bin_decrypt(&self, key: &str, algorithm: Wrap<EncryptionAlgorithm>) -> Self {
+        self.inner
+            .clone()
+            .binary()
+            .decrypt(key.into(), algorithm.0)
+            .into()
+    }
+
     #[cfg(feature = "binary_encoding")]
     #[allow(clippy::wrong_self_convention)]
     fn from_buffer(&self, dtype: Wrap<DataType>, kind: &str) -> PyResult<Self> {
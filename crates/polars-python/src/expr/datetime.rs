@@ -20,6 +20,14 @@ impl PyExpr {
             .into()
     }
 
+    fn dt_is_business_day(&self, week_mask: [bool; 7], holidays: Vec<i32>) -> Self {
+        self.inner
+            .clone()
+            .dt()
+            .is_business_day(week_mask, holidays)
+            .into()
+    }
+
     fn dt_to_string(&self, format: &str) -> Self {
         self.inner.clone().dt().to_string(format).into()
     }
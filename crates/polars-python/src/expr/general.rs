@@ -161,6 +161,25 @@ impl PyExpr {
             .into()
     }
 
+    fn approx_quantile(&self, quantile: Self) -> Self {
+        self.inner.clone().approx_quantile(quantile.inner).into()
+    }
+
+    fn weighted_mean(&self, weights: Self) -> Self {
+        self.inner.clone().weighted_mean(weights.inner).into()
+    }
+
+    fn weighted_std(&self, weights: Self, ddof: u8) -> Self {
+        self.inner.clone().weighted_std(weights.inner, ddof).into()
+    }
+
+    fn weighted_quantile(&self, weights: Self, quantile: Self) -> Self {
+        self.inner
+            .clone()
+            .weighted_quantile(weights.inner, quantile.inner)
+            .into()
+    }
+
     #[pyo3(signature = (breaks, labels, left_closed, include_breaks))]
     #[cfg(feature = "cutqcut")]
     fn cut(
@@ -322,6 +341,16 @@ impl PyExpr {
         self.inner.clone().arg_min().into()
     }
 
+    fn min_by(&self, by: Vec<Self>) -> Self {
+        let by = by.into_iter().map(|e| e.inner).collect::<Vec<_>>();
+        self.inner.clone().min_by(by).into()
+    }
+
+    fn max_by(&self, by: Vec<Self>) -> Self {
+        let by = by.into_iter().map(|e| e.inner).collect::<Vec<_>>();
+        self.inner.clone().max_by(by).into()
+    }
+
     #[cfg(feature = "index_of")]
     fn index_of(&self, element: Self) -> Self {
         self.inner.clone().index_of(element.inner).into()
@@ -454,6 +483,22 @@ impl PyExpr {
         self.inner.clone().gather_every(n, offset).into()
     }
 
+    #[cfg(feature = "scatter_gather")]
+    fn gather_by_ranges(&self, starts: Self, ends: Self) -> Self {
+        self.inner
+            .clone()
+            .gather_by_ranges(starts.inner, ends.inner)
+            .into()
+    }
+
+    #[cfg(feature = "scatter_gather")]
+    fn scatter_by_mask(&self, mask: Self, values: Self) -> Self {
+        self.inner
+            .clone()
+            .scatter_by_mask(mask.inner, values.inner)
+            .into()
+    }
+
     fn slice(&self, offset: Self, length: Self) -> Self {
         self.inner.clone().slice(offset.inner, length.inner).into()
     }
@@ -481,10 +526,18 @@ impl PyExpr {
         self.inner.clone().floor().into()
     }
 
+    fn floor_decimals(&self, decimals: u32) -> Self {
+        self.inner.clone().floor_decimals(decimals).into()
+    }
+
     fn ceil(&self) -> Self {
         self.inner.clone().ceil().into()
     }
 
+    fn ceil_decimals(&self, decimals: u32) -> Self {
+        self.inner.clone().ceil_decimals(decimals).into()
+    }
+
     #[pyo3(signature = (min=None, max=None))]
     fn clip(&self, min: Option<Self>, max: Option<Self>) -> Self {
         let expr = self.inner.clone();
@@ -730,8 +783,8 @@ impl PyExpr {
     fn reinterpret(&self, signed: bool) -> Self {
         self.inner.clone().reinterpret(signed).into()
     }
-    fn mode(&self) -> Self {
-        self.inner.clone().mode().into()
+    fn mode(&self, ties: Wrap<ModeTies>) -> Self {
+        self.inner.clone().mode_with_ties(ties.0).into()
     }
     fn exclude(&self, columns: Vec<String>) -> Self {
         self.inner.clone().exclude(columns).into()
@@ -764,6 +817,18 @@ impl PyExpr {
         self.inner.clone().rank(options, seed).into()
     }
 
+    fn percent_rank(&self, descending: bool) -> Self {
+        self.inner.clone().percent_rank(descending).into()
+    }
+
+    fn cume_dist(&self, descending: bool) -> Self {
+        self.inner.clone().cume_dist(descending).into()
+    }
+
+    fn ntile(&self, n: IdxSize, descending: bool) -> Self {
+        self.inner.clone().ntile(n, descending).into()
+    }
+
     fn diff(&self, n: i64, null_behavior: Wrap<NullBehavior>) -> Self {
         self.inner.clone().diff(n, null_behavior.0).into()
     }
@@ -835,6 +900,16 @@ impl PyExpr {
             .into())
     }
 
+    fn ewm_std_by(&self, times: PyExpr, half_life: &str) -> PyResult<Self> {
+        let half_life = Duration::try_parse(half_life).map_err(PyPolarsErr::from)?;
+        Ok(self.inner.clone().ewm_std_by(times.inner, half_life).into())
+    }
+
+    fn ewm_var_by(&self, times: PyExpr, half_life: &str) -> PyResult<Self> {
+        let half_life = Duration::try_parse(half_life).map_err(PyPolarsErr::from)?;
+        Ok(self.inner.clone().ewm_var_by(times.inner, half_life).into())
+    }
+
     fn ewm_std(
         &self,
         alpha: f64,
@@ -869,6 +944,41 @@ impl PyExpr {
         };
         self.inner.clone().ewm_var(options).into()
     }
+    fn ewm_cov(
+        &self,
+        other: PyExpr,
+        alpha: f64,
+        adjust: bool,
+        bias: bool,
+        min_periods: usize,
+        ignore_nulls: bool,
+    ) -> Self {
+        let options = EWMOptions {
+            alpha,
+            adjust,
+            bias,
+            min_periods,
+            ignore_nulls,
+        };
+        self.inner.clone().ewm_cov(other.inner, options).into()
+    }
+    fn ewm_corr(
+        &self,
+        other: PyExpr,
+        alpha: f64,
+        adjust: bool,
+        min_periods: usize,
+        ignore_nulls: bool,
+    ) -> Self {
+        let options = EWMOptions {
+            alpha,
+            adjust,
+            bias: true,
+            min_periods,
+            ignore_nulls,
+        };
+        self.inner.clone().ewm_corr(other.inner, options).into()
+    }
     fn extend_constant(&self, value: PyExpr, n: PyExpr) -> Self {
         self.inner
             .clone()
@@ -901,6 +1011,19 @@ impl PyExpr {
     fn hash(&self, seed: u64, seed_1: u64, seed_2: u64, seed_3: u64) -> Self {
         self.inner.clone().hash(seed, seed_1, seed_2, seed_3).into()
     }
+    fn hash_with_algorithm(
+        &self,
+        seed: u64,
+        seed_1: u64,
+        seed_2: u64,
+        seed_3: u64,
+        algorithm: Wrap<HashAlgorithm>,
+    ) -> Self {
+        self.inner
+            .clone()
+            .hash_with_algorithm(seed, seed_1, seed_2, seed_3, algorithm.0)
+            .into()
+    }
     fn set_sorted_flag(&self, descending: bool) -> Self {
         let is_sorted = if descending {
             IsSorted::Descending
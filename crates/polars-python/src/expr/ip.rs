@@ -0,0 +1,30 @@
+use pyo3::prelude::*;
+
+use crate::PyExpr;
+
+#[pymethods]
+impl PyExpr {
+    fn ip_parse(&self, strict: bool) -> Self {
+        self.inner.clone().ip().parse(strict).into()
+    }
+
+    fn ip_to_string(&self) -> Self {
+        self.inner.clone().ip().to_string().into()
+    }
+
+    fn ip_version(&self) -> Self {
+        self.inner.clone().ip().version().into()
+    }
+
+    fn ip_is_in_cidr(&self, cidr: PyExpr) -> Self {
+        self.inner.clone().ip().is_in_cidr(cidr.inner).into()
+    }
+
+    fn ip_subnet(&self, prefix_len: u8) -> Self {
+        self.inner.clone().ip().subnet(prefix_len).into()
+    }
+
+    fn ip_to_integer(&self) -> Self {
+        self.inner.clone().ip().to_integer().into()
+    }
+}
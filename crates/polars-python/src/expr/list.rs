@@ -271,7 +271,7 @@ impl PyExpr {
         match operation.0 {
             SetOperation::Intersection => e.set_intersection(other.inner),
             SetOperation::Difference => e.set_difference(other.inner),
-            SetOperation::Union => e.union(other.inner),
+            SetOperation::Union => e.set_union(other.inner),
             SetOperation::SymmetricDifference => e.set_symmetric_difference(other.inner),
         }
         .into()
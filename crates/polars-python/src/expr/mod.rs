@@ -10,6 +10,8 @@ mod categorical;
 mod datetime;
 #[cfg(feature = "pymethods")]
 mod general;
+#[cfg(all(feature = "ip", feature = "pymethods"))]
+mod ip;
 #[cfg(feature = "pymethods")]
 mod list;
 #[cfg(all(feature = "meta", feature = "pymethods"))]
@@ -20,10 +22,14 @@ mod name;
 mod rolling;
 #[cfg(feature = "pymethods")]
 mod serde;
+#[cfg(all(feature = "geometry", feature = "pymethods"))]
+mod st;
 #[cfg(feature = "pymethods")]
 mod string;
 #[cfg(feature = "pymethods")]
 mod r#struct;
+#[cfg(all(feature = "url", feature = "pymethods"))]
+mod url;
 
 use std::mem::ManuallyDrop;
 
@@ -4,7 +4,8 @@ use pyo3::types::PyFloat;
 
 use crate::conversion::Wrap;
 use crate::error::PyPolarsErr;
-use crate::map::lazy::call_lambda_with_series;
+use crate::map::lazy::{ToSeries, call_lambda_with_series};
+use crate::py_modules::polars;
 use crate::{PyExpr, PySeries};
 
 #[pymethods]
@@ -323,6 +324,35 @@ impl PyExpr {
             .into())
     }
 
+    #[pyo3(signature = (window_size, min_periods, center))]
+    fn rolling_mode(&self, window_size: usize, min_periods: Option<usize>, center: bool) -> Self {
+        let min_periods = min_periods.unwrap_or(window_size);
+        let options = RollingOptionsFixedWindow {
+            window_size,
+            min_periods,
+            center,
+            ..Default::default()
+        };
+        self.inner.clone().rolling_mode(options).into()
+    }
+
+    #[pyo3(signature = (window_size, min_periods, center))]
+    fn rolling_n_unique(
+        &self,
+        window_size: usize,
+        min_periods: Option<usize>,
+        center: bool,
+    ) -> Self {
+        let min_periods = min_periods.unwrap_or(window_size);
+        let options = RollingOptionsFixedWindow {
+            window_size,
+            min_periods,
+            center,
+            ..Default::default()
+        };
+        self.inner.clone().rolling_n_unique(options).into()
+    }
+
     fn rolling_skew(&self, window_size: usize, bias: bool) -> Self {
         self.inner.clone().rolling_skew(window_size, bias).into()
     }
@@ -494,4 +524,36 @@ impl PyExpr {
             .with_fmt("rolling_map")
             .into()
     }
+
+    #[pyo3(signature = (lambda, window_size, weights, min_periods, center, return_dtype))]
+    fn rolling_map_udf(
+        &self,
+        lambda: PyObject,
+        window_size: usize,
+        weights: Option<Vec<f64>>,
+        min_periods: Option<usize>,
+        center: bool,
+        return_dtype: Wrap<DataType>,
+    ) -> Self {
+        let min_periods = min_periods.unwrap_or(window_size);
+        let options = RollingOptionsFixedWindow {
+            window_size,
+            weights,
+            min_periods,
+            center,
+            ..Default::default()
+        };
+        let function = move |s: &Series| {
+            Python::with_gil(|py| {
+                let out = call_lambda_with_series(py, s.clone(), &lambda).map_err(
+                    |e| polars_err!(ComputeError: "custom python function failed: {}", e.value(py)),
+                )?;
+                out.to_series(py, polars(py), s.name())
+            })
+        };
+        self.inner
+            .clone()
+            .rolling_map_udf(function, return_dtype.0, options)
+            .into()
+    }
 }
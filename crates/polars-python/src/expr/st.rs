@@ -0,0 +1,22 @@
+use pyo3::prelude::*;
+
+use crate::PyExpr;
+
+#[pymethods]
+impl PyExpr {
+    fn st_area(&self) -> Self {
+        self.inner.clone().st().area().into()
+    }
+
+    fn st_centroid(&self) -> Self {
+        self.inner.clone().st().centroid().into()
+    }
+
+    fn st_contains(&self, other: PyExpr) -> Self {
+        self.inner.clone().st().contains(other.inner).into()
+    }
+
+    fn st_distance(&self, other: PyExpr) -> Self {
+        self.inner.clone().st().distance(other.inner).into()
+    }
+}
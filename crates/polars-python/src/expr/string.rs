@@ -164,6 +164,42 @@ impl PyExpr {
         self.inner.clone().str().reverse().into()
     }
 
+    fn str_soundex(&self, ascii_fold: bool) -> Self {
+        self.inner.clone().str().soundex(ascii_fold).into()
+    }
+
+    fn str_metaphone(&self, ascii_fold: bool) -> Self {
+        self.inner.clone().str().metaphone(ascii_fold).into()
+    }
+
+    fn str_levenshtein(&self, other: Self) -> Self {
+        self.inner.clone().str().levenshtein(other.inner).into()
+    }
+
+    fn str_levenshtein_similarity(&self, other: Self) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .levenshtein_similarity(other.inner)
+            .into()
+    }
+
+    fn str_hamming(&self, other: Self) -> Self {
+        self.inner.clone().str().hamming(other.inner).into()
+    }
+
+    fn str_hamming_similarity(&self, other: Self) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .hamming_similarity(other.inner)
+            .into()
+    }
+
+    fn str_jaro_winkler(&self, other: Self) -> Self {
+        self.inner.clone().str().jaro_winkler(other.inner).into()
+    }
+
     fn str_pad_start(&self, length: usize, fill_char: char) -> Self {
         self.inner.clone().str().pad_start(length, fill_char).into()
     }
@@ -249,6 +285,11 @@ impl PyExpr {
         self.inner.clone().str().json_path_match(pat.inner).into()
     }
 
+    #[cfg(feature = "extract_jsonpath")]
+    fn str_json_extract_all(&self, pat: Self) -> Self {
+        self.inner.clone().str().json_extract_all(pat.inner).into()
+    }
+
     fn str_extract(&self, pat: Self, group_index: usize) -> Self {
         self.inner
             .clone()
@@ -308,6 +349,14 @@ impl PyExpr {
         self.inner.clone().str().to_decimal(infer_len).into()
     }
 
+    fn str_to_duration(&self, time_unit: Wrap<TimeUnit>, strict: bool) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .to_duration(time_unit.0, strict)
+            .into()
+    }
+
     #[cfg(feature = "find_many")]
     fn str_contains_any(&self, patterns: PyExpr, ascii_case_insensitive: bool) -> Self {
         self.inner
@@ -0,0 +1,22 @@
+use pyo3::prelude::*;
+
+use crate::PyExpr;
+
+#[pymethods]
+impl PyExpr {
+    fn url_host(&self) -> Self {
+        self.inner.clone().url().host().into()
+    }
+
+    fn url_path(&self) -> Self {
+        self.inner.clone().url().path().into()
+    }
+
+    fn url_query_param(&self, name: &str) -> Self {
+        self.inner.clone().url().query_param(name.into()).into()
+    }
+
+    fn url_decode(&self) -> Self {
+        self.inner.clone().url().decode().into()
+    }
+}
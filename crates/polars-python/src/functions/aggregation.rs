@@ -19,6 +19,27 @@ pub fn any_horizontal(exprs: Vec<PyExpr>) -> PyResult<PyExpr> {
     Ok(e.into())
 }
 
+#[pyfunction]
+pub fn bitwise_and_horizontal(exprs: Vec<PyExpr>) -> PyResult<PyExpr> {
+    let exprs = exprs.to_exprs();
+    let e = dsl::bitwise_and_horizontal(exprs).map_err(PyPolarsErr::from)?;
+    Ok(e.into())
+}
+
+#[pyfunction]
+pub fn bitwise_or_horizontal(exprs: Vec<PyExpr>) -> PyResult<PyExpr> {
+    let exprs = exprs.to_exprs();
+    let e = dsl::bitwise_or_horizontal(exprs).map_err(PyPolarsErr::from)?;
+    Ok(e.into())
+}
+
+#[pyfunction]
+pub fn bitwise_xor_horizontal(exprs: Vec<PyExpr>) -> PyResult<PyExpr> {
+    let exprs = exprs.to_exprs();
+    let e = dsl::bitwise_xor_horizontal(exprs).map_err(PyPolarsErr::from)?;
+    Ok(e.into())
+}
+
 #[pyfunction]
 pub fn max_horizontal(exprs: Vec<PyExpr>) -> PyResult<PyExpr> {
     let exprs = exprs.to_exprs();
@@ -1,7 +1,9 @@
 use polars::lazy::dsl;
+use polars::prelude::BusinessCalendar;
 use pyo3::prelude::*;
 
 use crate::PyExpr;
+use crate::error::PyPolarsErr;
 
 #[pyfunction]
 pub fn business_day_count(
@@ -14,3 +16,33 @@ pub fn business_day_count(
     let end = end.inner;
     dsl::business_day_count(start, end, week_mask, holidays).into()
 }
+
+#[pyfunction]
+pub fn register_business_calendar(
+    name: &str,
+    week_mask: [bool; 7],
+    holidays: Vec<i32>,
+) -> PyResult<()> {
+    let calendar = BusinessCalendar::new(week_mask, holidays).map_err(PyPolarsErr::from)?;
+    polars::prelude::register_business_calendar(name.into(), calendar);
+    Ok(())
+}
+
+#[pyfunction]
+pub fn unregister_business_calendar(name: &str) -> bool {
+    polars::prelude::unregister_business_calendar(name)
+}
+
+#[pyfunction]
+pub fn get_business_calendar(name: &str) -> PyResult<([bool; 7], Vec<i32>)> {
+    let calendar = polars::prelude::get_business_calendar(name).map_err(PyPolarsErr::from)?;
+    Ok((calendar.week_mask, calendar.holidays))
+}
+
+#[pyfunction]
+pub fn business_calendar_names() -> Vec<String> {
+    polars::prelude::business_calendar_names()
+        .into_iter()
+        .map(|name| name.to_string())
+        .collect()
+}
@@ -83,6 +83,36 @@ pub fn arg_sort_by(
     )
     .into()
 }
+
+#[pyfunction]
+pub fn arg_min_by(by: Vec<PyExpr>) -> PyExpr {
+    dsl::arg_min_by(by.to_exprs()).into()
+}
+
+#[pyfunction]
+pub fn arg_max_by(by: Vec<PyExpr>) -> PyExpr {
+    dsl::arg_max_by(by.to_exprs()).into()
+}
+
+#[cfg(feature = "search_sorted")]
+#[pyfunction]
+pub fn search_sorted_by(
+    by: Vec<PyExpr>,
+    element: Vec<PyExpr>,
+    side: Wrap<SearchSortedSide>,
+    descending: Vec<bool>,
+    nulls_last: Vec<bool>,
+) -> PyExpr {
+    dsl::search_sorted_by(
+        by.to_exprs(),
+        element.to_exprs(),
+        side.0,
+        descending,
+        nulls_last,
+    )
+    .into()
+}
+
 #[pyfunction]
 pub fn arg_where(condition: PyExpr) -> PyExpr {
     dsl::arg_where(condition.inner).into()
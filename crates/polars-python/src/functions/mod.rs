@@ -7,6 +7,8 @@ mod meta;
 mod misc;
 mod random;
 mod range;
+#[cfg(feature = "binary_encryption")]
+mod secrets;
 mod string_cache;
 mod strings;
 mod utils;
@@ -21,6 +23,8 @@ pub use meta::*;
 pub use misc::*;
 pub use random::*;
 pub use range::*;
+#[cfg(feature = "binary_encryption")]
+pub use secrets::*;
 pub use string_cache::*;
 pub use strings::*;
 pub use utils::*;
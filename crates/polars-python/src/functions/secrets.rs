@@ -0,0 +1,22 @@
+use std::sync::Arc;
+
+use polars::prelude::*;
+use polars_ops::chunked_array::{SecretProvider, set_secret_provider as set_provider};
+use pyo3::prelude::*;
+
+/// A [`SecretProvider`] backed by a Python callable that takes the name of a secret and returns
+/// its bytes.
+struct PySecretProvider(PyObject);
+
+impl SecretProvider for PySecretProvider {
+    fn get_secret(&self, name: &str) -> PolarsResult<Vec<u8>> {
+        Python::with_gil(|py| self.0.call1(py, (name,))?.extract::<Vec<u8>>(py)).map_err(
+            |e| polars_err!(ComputeError: "secret provider failed to get secret '{}': {}", name, e),
+        )
+    }
+}
+
+#[pyfunction]
+pub fn set_secret_provider(provider: Option<PyObject>) {
+    set_provider(provider.map(|p| Arc::new(PySecretProvider(p)) as Arc<dyn SecretProvider>));
+}
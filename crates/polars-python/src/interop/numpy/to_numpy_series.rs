@@ -197,6 +197,10 @@ fn series_to_numpy_with_copy(py: Python, s: &Series, writable: bool) -> PyObject
         UInt16 => numeric_series_to_numpy::<UInt16Type, f32>(py, s),
         UInt32 => numeric_series_to_numpy::<UInt32Type, f64>(py, s),
         UInt64 => numeric_series_to_numpy::<UInt64Type, f64>(py, s),
+        Float16 => {
+            let s = s.cast(&DataType::Float32).unwrap();
+            series_to_numpy(py, &s, writable, true).unwrap()
+        },
         Float32 => numeric_series_to_numpy::<Float32Type, f32>(py, s),
         Float64 => numeric_series_to_numpy::<Float64Type, f64>(py, s),
         Boolean => boolean_series_to_numpy(py, s),
@@ -239,6 +243,16 @@ fn series_to_numpy_with_copy(py: Python, s: &Series, writable: bool) -> PyObject
             let values = ca.iter().map(|s| s.into_py_any(py).unwrap());
             PyArray1::from_iter(py, values).into_py_any(py).unwrap()
         },
+        #[cfg(feature = "geometry")]
+        Geometry => {
+            let s = s.to_physical_repr();
+            series_to_numpy(py, &s, writable, true).unwrap()
+        },
+        #[cfg(feature = "dtype-extension")]
+        Extension(_) => {
+            let s = s.extension().unwrap().storage();
+            series_to_numpy(py, s, writable, true).unwrap()
+        },
         Binary => {
             let ca = s.binary().unwrap();
             let values = ca.iter().map(|s| s.into_py_any(py).unwrap());
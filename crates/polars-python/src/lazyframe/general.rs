@@ -21,6 +21,7 @@ use crate::expr::ToExprs;
 use crate::interop::arrow::to_rust::pyarrow_schema_to_rust;
 use crate::lazyframe::visit::NodeTraverser;
 use crate::prelude::*;
+use crate::series::import::{call_arrow_c_stream, validate_pycapsule_name};
 use crate::utils::EnterPolarsExt;
 use crate::{PyDataFrame, PyExpr, PyLazyGroupBy};
 
@@ -355,6 +356,7 @@ impl PyLazyFrame {
             glob,
             include_file_paths: include_file_paths.map(|x| x.into()),
             allow_missing_columns,
+            ..Default::default()
         };
 
         let sources = sources.0;
@@ -457,6 +459,17 @@ impl PyLazyFrame {
         Ok(lf.into())
     }
 
+    #[staticmethod]
+    fn scan_arrow_c_stream(source: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let capsule = call_arrow_c_stream(source)?;
+        validate_pycapsule_name(&capsule, "arrow_array_stream")?;
+        // SAFETY: the capsule holds a valid `ArrowArrayStream`, as defined by the Arrow PyCapsule
+        // Interface.
+        let lf = unsafe { LazyFrame::scan_arrow_c_stream(capsule.pointer() as _) }
+            .map_err(PyPolarsErr::from)?;
+        Ok(lf.into())
+    }
+
     #[staticmethod]
     fn scan_from_python_function_arrow_schema(
         schema: &Bound<'_, PyList>,
@@ -1213,6 +1226,32 @@ impl PyLazyFrame {
             .into())
     }
 
+    #[pyo3(signature = (other, left_on, right_lower, right_upper, closed, suffix))]
+    fn join_between(
+        &self,
+        other: Self,
+        left_on: PyExpr,
+        right_lower: PyExpr,
+        right_upper: PyExpr,
+        closed: Wrap<ClosedInterval>,
+        suffix: String,
+    ) -> PyResult<Self> {
+        let ldf = self.ldf.clone();
+        let other = other.ldf;
+
+        Ok(ldf
+            .join_builder()
+            .with(other)
+            .suffix(suffix)
+            .join_between(
+                left_on.inner,
+                right_lower.inner,
+                right_upper.inner,
+                closed.0,
+            )
+            .into())
+    }
+
     fn with_columns(&mut self, exprs: Vec<PyExpr>) -> Self {
         let ldf = self.ldf.clone();
         ldf.with_columns(exprs.to_exprs()).into()
@@ -1368,6 +1407,36 @@ impl PyLazyFrame {
         ldf.unpivot(args).into()
     }
 
+    #[cfg(feature = "pivot")]
+    #[pyo3(signature = (
+        on, on_columns, index, values, aggregate_expr, maintain_order, sort_columns, separator
+    ))]
+    fn pivot(
+        &self,
+        py: Python,
+        on: Vec<PyBackedStr>,
+        on_columns: Option<PyDataFrame>,
+        index: Option<Vec<PyBackedStr>>,
+        values: Option<Vec<PyBackedStr>>,
+        aggregate_expr: PyExpr,
+        maintain_order: bool,
+        sort_columns: bool,
+        separator: &str,
+    ) -> PyResult<Self> {
+        let args = polars_lazy::frame::pivot::PivotArgs {
+            on: strings_to_pl_smallstr(on),
+            on_columns: on_columns.map(|df| df.df),
+            index: index.map(strings_to_pl_smallstr),
+            values: values.map(strings_to_pl_smallstr),
+            agg: aggregate_expr.inner,
+            maintain_order,
+            sort_columns,
+            separator: separator.into(),
+        };
+        let ldf = self.ldf.clone();
+        py.enter_polars(|| ldf.pivot(args)).map(Into::into)
+    }
+
     #[pyo3(signature = (name, offset=None))]
     fn with_row_index(&self, name: &str, offset: Option<IdxSize>) -> Self {
         let ldf = self.ldf.clone();
@@ -22,6 +22,10 @@ macro_rules! flag_getter_setters {
                 self.inner.remove(OptFlags::CLUSTER_WITH_COLUMNS);
                 self.inner.remove(OptFlags::COLLAPSE_JOINS);
                 self.inner.remove(OptFlags::CHECK_ORDER_OBSERVE);
+                self.inner.remove(OptFlags::JOIN_REORDER);
+                self.inner.remove(OptFlags::SEMI_JOIN_REDUCTION);
+                self.inner.remove(OptFlags::DICTIONARY_STRINGS);
+                self.inner.remove(OptFlags::SORTED_METADATA);
                 self.inner.remove(OptFlags::SIMPLIFY_EXPR);
                 self.inner.remove(OptFlags::SLICE_PUSHDOWN);
             }
@@ -52,4 +56,8 @@ flag_getter_setters! {
     (COMM_SUBEXPR_ELIM, get_comm_subexpr_elim, set_comm_subexpr_elim)
     (COLLAPSE_JOINS, get_collapse_joins, set_collapse_joins)
     (CHECK_ORDER_OBSERVE, get_check_order_observe, set_check_order_observe)
+    (JOIN_REORDER, get_join_reorder, set_join_reorder)
+    (SEMI_JOIN_REDUCTION, get_semi_join_reduction, set_semi_join_reduction)
+    (DICTIONARY_STRINGS, get_dictionary_strings, set_dictionary_strings)
+    (SORTED_METADATA, get_sorted_metadata, set_sorted_metadata)
 }
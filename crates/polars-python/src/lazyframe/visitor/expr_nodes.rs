@@ -175,6 +175,13 @@ pub enum PyStringFunction {
     ReplaceMany,
     EscapeRegex,
     Normalize,
+    Soundex,
+    Metaphone,
+    Levenshtein,
+    Hamming,
+    JaroWinkler,
+    JsonExtractAll,
+    ToDuration,
 }
 
 #[pymethods]
@@ -557,6 +564,9 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     return Err(PyNotImplementedError::new_err("range literal"));
                 },
                 LiteralValue::Series(s) => PySeries::new((**s).clone()).into_py_any(py)?,
+                LiteralValue::Placeholder(_) => {
+                    return Err(PyNotImplementedError::new_err("placeholder literal"));
+                },
             };
 
             Literal {
@@ -700,6 +710,11 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 arguments: vec![n.0],
                 options: py.None(),
             },
+            IRAggExpr::Bitwise(n, method) => Agg {
+                name: method.to_string().into_py_any(py)?,
+                arguments: vec![n.0],
+                options: py.None(),
+            },
         }
         .into_py_any(py),
         AExpr::Ternary {
@@ -736,6 +751,18 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::Bitwise(_) => {
                     return Err(PyNotImplementedError::new_err("bitwise expr"));
                 },
+                #[cfg(feature = "ip")]
+                FunctionExpr::Ip(_) => {
+                    return Err(PyNotImplementedError::new_err("ip expr"));
+                },
+                #[cfg(feature = "geometry")]
+                FunctionExpr::Geometry(_) => {
+                    return Err(PyNotImplementedError::new_err("geometry expr"));
+                },
+                #[cfg(feature = "url")]
+                FunctionExpr::Url(_) => {
+                    return Err(PyNotImplementedError::new_err("url expr"));
+                },
                 FunctionExpr::StringExpr(strfun) => match strfun {
                     StringFunction::ConcatHorizontal {
                         delimiter,
@@ -793,6 +820,10 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     StringFunction::JsonPathMatch => {
                         (PyStringFunction::JsonPathMatch,).into_py_any(py)
                     },
+                    #[cfg(feature = "extract_jsonpath")]
+                    StringFunction::JsonExtractAll => {
+                        (PyStringFunction::JsonExtractAll,).into_py_any(py)
+                    },
                     #[cfg(feature = "regex")]
                     StringFunction::Replace { n, literal } => {
                         (PyStringFunction::Replace, n, literal).into_py_any(py)
@@ -808,6 +839,19 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     )
                         .into_py_any(py),
                     StringFunction::Reverse => (PyStringFunction::Reverse,).into_py_any(py),
+                    StringFunction::Soundex { ascii_fold } => {
+                        (PyStringFunction::Soundex, ascii_fold).into_py_any(py)
+                    },
+                    StringFunction::Metaphone { ascii_fold } => {
+                        (PyStringFunction::Metaphone, ascii_fold).into_py_any(py)
+                    },
+                    StringFunction::Levenshtein { normalized } => {
+                        (PyStringFunction::Levenshtein, normalized).into_py_any(py)
+                    },
+                    StringFunction::Hamming { normalized } => {
+                        (PyStringFunction::Hamming, normalized).into_py_any(py)
+                    },
+                    StringFunction::JaroWinkler => (PyStringFunction::JaroWinkler,).into_py_any(py),
                     StringFunction::PadStart { length, fill_char } => {
                         (PyStringFunction::PadStart, length, fill_char).into_py_any(py)
                     },
@@ -857,6 +901,9 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     StringFunction::ToDecimal(inference_length) => {
                         (PyStringFunction::ToDecimal, inference_length).into_py_any(py)
                     },
+                    StringFunction::ToDuration { time_unit, strict } => {
+                        (PyStringFunction::ToDuration, Wrap(*time_unit), strict).into_py_any(py)
+                    },
                     #[cfg(feature = "nightly")]
                     StringFunction::Titlecase => (PyStringFunction::Titlecase,).into_py_any(py),
                     StringFunction::Uppercase => (PyStringFunction::Uppercase,).into_py_any(py),
@@ -1042,6 +1089,11 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::Hash(seed, seed_1, seed_2, seed_3) => {
                     ("hash", seed, seed_1, seed_2, seed_3).into_py_any(py)
                 },
+                FunctionExpr::StableHash(seed, seed_1, algorithm) => {
+                    ("stable_hash", seed, seed_1, algorithm.to_string()).into_py_any(py)
+                },
+                FunctionExpr::ArgMinBy => ("arg_min_by",).into_py_any(py),
+                FunctionExpr::ArgMaxBy => ("arg_max_by",).into_py_any(py),
                 FunctionExpr::ArgWhere => ("argwhere",).into_py_any(py),
                 #[cfg(feature = "index_of")]
                 FunctionExpr::IndexOf => ("index_of",).into_py_any(py),
@@ -1055,6 +1107,22 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     },
                 )
                     .into_py_any(py),
+                #[cfg(feature = "search_sorted")]
+                FunctionExpr::SearchSortedBy {
+                    side,
+                    descending,
+                    nulls_last,
+                } => (
+                    "search_sorted_by",
+                    match side {
+                        SearchSortedSide::Any => "any",
+                        SearchSortedSide::Left => "left",
+                        SearchSortedSide::Right => "right",
+                    },
+                    descending.clone(),
+                    nulls_last.clone(),
+                )
+                    .into_py_any(py),
                 FunctionExpr::Range(_) => return Err(PyNotImplementedError::new_err("range")),
                 #[cfg(feature = "trigonometry")]
                 FunctionExpr::Trigonometry(trigfun) => {
@@ -1106,6 +1174,12 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     RollingFunction::Std(_) => {
                         return Err(PyNotImplementedError::new_err("rolling std"));
                     },
+                    RollingFunction::Mode(_) => {
+                        return Err(PyNotImplementedError::new_err("rolling mode"));
+                    },
+                    RollingFunction::NUnique(_) => {
+                        return Err(PyNotImplementedError::new_err("rolling n_unique"));
+                    },
                     RollingFunction::Skew(_, _) => {
                         return Err(PyNotImplementedError::new_err("rolling skew"));
                     },
@@ -1140,7 +1214,7 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::Shift => ("shift",).into_py_any(py),
                 FunctionExpr::DropNans => ("drop_nans",).into_py_any(py),
                 FunctionExpr::DropNulls => ("drop_nulls",).into_py_any(py),
-                FunctionExpr::Mode => ("mode",).into_py_any(py),
+                FunctionExpr::Mode(ties) => ("mode", Into::<&str>::into(ties)).into_py_any(py),
                 FunctionExpr::Skew(bias) => ("skew", bias).into_py_any(py),
                 FunctionExpr::Kurtosis(fisher, bias) => ("kurtosis", fisher, bias).into_py_any(py),
                 FunctionExpr::Reshape(_) => return Err(PyNotImplementedError::new_err("reshape")),
@@ -1152,6 +1226,11 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     options: _,
                     seed: _,
                 } => return Err(PyNotImplementedError::new_err("rank")),
+                FunctionExpr::PercentRank { descending } => {
+                    ("percent_rank", descending).into_py_any(py)
+                },
+                FunctionExpr::CumeDist { descending } => ("cume_dist", descending).into_py_any(py),
+                FunctionExpr::Ntile { n, descending } => ("ntile", n, descending).into_py_any(py),
                 FunctionExpr::Clip { has_min, has_max } => {
                     ("clip", has_min, has_max).into_py_any(py)
                 },
@@ -1172,6 +1251,10 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 } => ("value_counts", sort, parallel, name.as_str(), normalize).into_py_any(py),
                 FunctionExpr::UniqueCounts => ("unique_counts",).into_py_any(py),
                 FunctionExpr::ApproxNUnique => ("approx_n_unique",).into_py_any(py),
+                FunctionExpr::ApproxNUniqueSketch(_) => {
+                    return Err(PyNotImplementedError::new_err("approx_n_unique sketch"));
+                },
+                FunctionExpr::ApproxQuantile => ("approx_quantile",).into_py_any(py),
                 FunctionExpr::Coalesce => ("coalesce",).into_py_any(py),
                 FunctionExpr::ShrinkType => ("shrink_dtype",).into_py_any(py),
                 FunctionExpr::Diff(n, null_behaviour) => (
@@ -1190,6 +1273,8 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     match method {
                         InterpolationMethod::Linear => "linear",
                         InterpolationMethod::Nearest => "nearest",
+                        InterpolationMethod::Spline => "spline",
+                        InterpolationMethod::Pchip => "pchip",
                     },
                 )
                     .into_py_any(py),
@@ -1204,7 +1289,13 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::Round { decimals } => ("round", decimals).into_py_any(py),
                 FunctionExpr::RoundSF { digits } => ("round_sig_figs", digits).into_py_any(py),
                 FunctionExpr::Floor => ("floor",).into_py_any(py),
+                FunctionExpr::FloorDecimals { decimals } => {
+                    ("floor_decimals", decimals).into_py_any(py)
+                },
                 FunctionExpr::Ceil => ("ceil",).into_py_any(py),
+                FunctionExpr::CeilDecimals { decimals } => {
+                    ("ceil_decimals", decimals).into_py_any(py)
+                },
                 FunctionExpr::UpperBound => ("upper_bound",).into_py_any(py),
                 FunctionExpr::LowerBound => ("lower_bound",).into_py_any(py),
                 FunctionExpr::Fused(_) => return Err(PyNotImplementedError::new_err("fused")),
@@ -1214,6 +1305,9 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::Correlation { .. } => {
                     return Err(PyNotImplementedError::new_err("corr"));
                 },
+                FunctionExpr::WeightedAgg(_) => {
+                    return Err(PyNotImplementedError::new_err("weighted aggregation"));
+                },
                 #[cfg(feature = "peaks")]
                 FunctionExpr::PeakMin => ("peak_max",).into_py_any(py),
                 #[cfg(feature = "peaks")]
@@ -1262,6 +1356,12 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::EwmVar { options: _ } => {
                     return Err(PyNotImplementedError::new_err("ewm var"));
                 },
+                FunctionExpr::EwmCov { options: _ } => {
+                    return Err(PyNotImplementedError::new_err("ewm cov"));
+                },
+                FunctionExpr::EwmCorr { options: _ } => {
+                    return Err(PyNotImplementedError::new_err("ewm corr"));
+                },
                 FunctionExpr::Replace => ("replace",).into_py_any(py),
                 FunctionExpr::ReplaceStrict { return_dtype: _ } => {
                     // Can ignore the return dtype because it is encoded in the schema.
@@ -1276,6 +1376,10 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 },
                 FunctionExpr::Reinterpret(signed) => ("reinterpret", signed).into_py_any(py),
                 FunctionExpr::ExtendConstant => ("extend_constant",).into_py_any(py),
+                #[cfg(feature = "scatter_gather")]
+                FunctionExpr::ScatterByMask => ("scatter_by_mask",).into_py_any(py),
+                #[cfg(feature = "scatter_gather")]
+                FunctionExpr::GatherByRanges => ("gather_by_ranges",).into_py_any(py),
                 FunctionExpr::Business(_) => {
                     return Err(PyNotImplementedError::new_err("business"));
                 },
@@ -1284,6 +1388,12 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::EwmMeanBy { half_life: _ } => {
                     return Err(PyNotImplementedError::new_err("ewm_mean_by"));
                 },
+                FunctionExpr::EwmStdBy { half_life: _ } => {
+                    return Err(PyNotImplementedError::new_err("ewm_std_by"));
+                },
+                FunctionExpr::EwmVarBy { half_life: _ } => {
+                    return Err(PyNotImplementedError::new_err("ewm_var_by"));
+                },
             }?,
             options: py.None(),
         }
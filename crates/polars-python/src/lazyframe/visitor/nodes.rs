@@ -574,6 +574,7 @@ pub(crate) fn into_py(py: Python<'_>, plan: &IR) -> PyResult<PyObject> {
                 )
                     .into_py_any(py)?,
                 FunctionIR::Rechunk => ("rechunk",).into_py_any(py)?,
+                FunctionIR::Hint(hint) => ("hint", hint.to_string()).into_py_any(py)?,
                 FunctionIR::Rename {
                     existing,
                     new,
@@ -56,8 +56,8 @@ impl PySeries {
                 py.enter_polars_ok(|| self.series.cast(&DataType::UInt8).unwrap().mean_reduce()),
                 py,
             ),
-            // For non-numeric output types we require mean_reduce.
-            dt if dt.is_temporal() => {
+            // For non-float output types we require mean_reduce.
+            dt if dt.is_temporal() || dt.is_decimal() => {
                 scalar_to_py(py.enter_polars_ok(|| self.series.mean_reduce()), py)
             },
             _ => Ok(self.series.mean().into_pyobject(py)?),
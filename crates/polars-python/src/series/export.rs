@@ -28,6 +28,10 @@ impl PySeries {
                 DataType::Int32 => PyList::new(py, series.i32().map_err(PyPolarsErr::from)?)?,
                 DataType::Int64 => PyList::new(py, series.i64().map_err(PyPolarsErr::from)?)?,
                 DataType::Int128 => PyList::new(py, series.i128().map_err(PyPolarsErr::from)?)?,
+                DataType::Float16 => {
+                    let ca = series.float16().map_err(PyPolarsErr::from)?;
+                    PyList::new(py, ca.physical())?
+                },
                 DataType::Float32 => PyList::new(py, series.f32().map_err(PyPolarsErr::from)?)?,
                 DataType::Float64 => PyList::new(py, series.f64().map_err(PyPolarsErr::from)?)?,
                 DataType::Categorical(_, _) | DataType::Enum(_, _) => PyList::new(
@@ -107,6 +111,16 @@ impl PySeries {
                     let ca = series.binary().map_err(PyPolarsErr::from)?;
                     return Wrap(ca).into_bound_py_any(py);
                 },
+                #[cfg(feature = "geometry")]
+                DataType::Geometry => {
+                    let ca = series.geometry().map_err(PyPolarsErr::from)?;
+                    return Wrap(ca.physical()).into_bound_py_any(py);
+                },
+                #[cfg(feature = "dtype-extension")]
+                DataType::Extension(_) => {
+                    let ext = series.extension().map_err(PyPolarsErr::from)?;
+                    return to_list_recursive(py, ext.storage());
+                },
                 DataType::Null => {
                     let null: Option<u8> = None;
                     let n = series.len();
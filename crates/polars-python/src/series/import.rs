@@ -9,7 +9,10 @@ use pyo3::types::{PyCapsule, PyTuple, PyType};
 use super::PySeries;
 
 /// Validate PyCapsule has provided name
-fn validate_pycapsule_name(capsule: &Bound<PyCapsule>, expected_name: &str) -> PyResult<()> {
+pub(crate) fn validate_pycapsule_name(
+    capsule: &Bound<PyCapsule>,
+    expected_name: &str,
+) -> PyResult<()> {
     let capsule_name = capsule.name()?;
     if let Some(capsule_name) = capsule_name {
         let capsule_name = capsule_name.to_str()?;
@@ -75,7 +78,7 @@ pub(crate) fn import_array_pycapsules(
 }
 
 /// Import `__arrow_c_stream__` across Python boundary.
-fn call_arrow_c_stream<'py>(ob: &'py Bound<PyAny>) -> PyResult<Bound<'py, PyCapsule>> {
+pub(crate) fn call_arrow_c_stream<'py>(ob: &'py Bound<PyAny>) -> PyResult<Bound<'py, PyCapsule>> {
     if !ob.hasattr("__arrow_c_stream__")? {
         return Err(PyValueError::new_err(
             "Expected an object with dunder __arrow_c_stream__",
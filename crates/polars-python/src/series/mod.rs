@@ -15,7 +15,7 @@ mod export;
 #[cfg(feature = "pymethods")]
 mod general;
 #[cfg(feature = "pymethods")]
-mod import;
+pub(crate) mod import;
 #[cfg(feature = "pymethods")]
 mod map;
 #[cfg(feature = "pymethods")]
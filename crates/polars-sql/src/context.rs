@@ -1,5 +1,5 @@
 use std::cell::RefCell;
-use std::ops::Deref;
+use std::ops::{ControlFlow, Deref};
 
 use polars_core::frame::row::Row;
 use polars_core::prelude::*;
@@ -9,20 +9,24 @@ use polars_plan::dsl::function_expr::StructFunction;
 use polars_plan::prelude::*;
 use polars_utils::format_pl_smallstr;
 use sqlparser::ast::{
-    BinaryOperator, CreateTable, Delete, Distinct, ExcludeSelectItem, Expr as SQLExpr, FromTable,
-    FunctionArg, GroupByExpr, Ident, JoinConstraint, JoinOperator, ObjectName, ObjectType, Offset,
-    OrderBy, Query, RenameSelectItem, Select, SelectItem, SetExpr, SetOperator, SetQuantifier,
-    Statement, TableAlias, TableFactor, TableWithJoins, UnaryOperator, Value as SQLValue, Values,
-    WildcardAdditionalOptions,
+    BinaryOperator, CopyOption, CopySource, CopyTarget, CreateTable, Cte, Delete, Distinct,
+    ExcludeSelectItem, Expr as SQLExpr, FromTable, FunctionArg, FunctionArgExpr, GroupByExpr,
+    Ident, JoinConstraint, JoinOperator, ObjectName, ObjectType, Offset, OrderBy, Query,
+    RenameSelectItem, Select, SelectItem, SetExpr, SetOperator, SetQuantifier, Statement,
+    TableAlias, TableFactor, TableWithJoins, UnaryOperator, Value as SQLValue, Values,
+    WildcardAdditionalOptions, visit_relations,
 };
-use sqlparser::dialect::GenericDialect;
 use sqlparser::parser::{Parser, ParserOptions};
+use sqlparser::tokenizer::{Token, Tokenizer};
 
-use crate::function_registry::{DefaultFunctionRegistry, FunctionRegistry};
+use crate::dialect::SQLDialect;
+use crate::function_registry::{DefaultFunctionRegistry, FunctionRegistry, Volatility};
+use crate::functions::PolarsSQLFunctions;
 use crate::sql_expr::{
     parse_sql_array, parse_sql_expr, resolve_compound_identifier, to_sql_interface_err,
 };
 use crate::table_functions::PolarsTableFunctions;
+use crate::types::map_polars_dtype_to_sql;
 
 #[derive(Clone)]
 pub struct TableInfo {
@@ -59,8 +63,13 @@ pub struct SQLContext {
     pub(crate) function_registry: Arc<dyn FunctionRegistry>,
     pub(crate) lp_arena: Arena<IR>,
     pub(crate) expr_arena: Arena<AExpr>,
+    /// The values bound to the placeholders (`$1`, `$2`, ...) of the executing statement.
+    pub(crate) params: Vec<AnyValue<'static>>,
+    pub(crate) dialect: SQLDialect,
 
+    read_only: bool,
     cte_map: RefCell<PlHashMap<String, LazyFrame>>,
+    recursion_limit: usize,
     table_aliases: RefCell<PlHashMap<String, String>>,
     joined_aliases: RefCell<PlHashMap<String, PlHashMap<String, String>>>,
 }
@@ -68,13 +77,17 @@ pub struct SQLContext {
 impl Default for SQLContext {
     fn default() -> Self {
         Self {
-            function_registry: Arc::new(DefaultFunctionRegistry {}),
+            function_registry: Arc::new(DefaultFunctionRegistry::default()),
             table_map: Default::default(),
             cte_map: Default::default(),
+            recursion_limit: 1000,
             table_aliases: Default::default(),
             joined_aliases: Default::default(),
             lp_arena: Default::default(),
             expr_arena: Default::default(),
+            params: Default::default(),
+            dialect: Default::default(),
+            read_only: false,
         }
     }
 }
@@ -141,20 +154,62 @@ impl SQLContext {
     /// # }
     ///```
     pub fn execute(&mut self, query: &str) -> PolarsResult<LazyFrame> {
-        let mut parser = Parser::new(&GenericDialect);
+        self.execute_with_params(query, &[])
+    }
+
+    /// Execute a SQL query with values bound to its placeholders, returning a [`LazyFrame`].
+    ///
+    /// Placeholders are either numbered (`$1`, `$2`, ...), referring to the parameter at that
+    /// position, or anonymous (`?`), taking the parameters in order. The parameters are bound as
+    /// literals instead of being formatted into the query, so the same query can be executed
+    /// with different parameters and a parameter can never change the query itself.
+    /// ```rust
+    /// # use polars_sql::SQLContext;
+    /// # use polars_core::prelude::*;
+    /// # use polars_lazy::prelude::*;
+    /// # fn main() {
+    ///
+    /// let mut ctx = SQLContext::new();
+    /// let df = df! {
+    ///    "a" =>  [1, 2, 3],
+    ///    "b" =>  ["x", "y", "z"],
+    /// }
+    /// .unwrap();
+    ///
+    /// ctx.register("df", df.lazy());
+    /// let sql = "SELECT a FROM df WHERE a > $1 AND b <> $2";
+    /// let params = [AnyValue::Int32(1), AnyValue::String("z")];
+    /// let sql_df = ctx.execute_with_params(sql, &params).unwrap().collect().unwrap();
+    /// assert!(sql_df.equals(&df! { "a" => [2] }.unwrap()));
+    /// # }
+    ///```
+    pub fn execute_with_params(
+        &mut self,
+        query: &str,
+        params: &[AnyValue<'_>],
+    ) -> PolarsResult<LazyFrame> {
+        let dialect = self.dialect.parser_dialect();
+        let mut tokens = Tokenizer::new(dialect, query)
+            .tokenize_with_location()
+            .map_err(to_sql_interface_err)?;
+        number_placeholders(tokens.iter_mut().map(|t| &mut t.token), params.len())?;
+
+        let mut parser = Parser::new(dialect);
         parser = parser.with_options(ParserOptions {
             trailing_commas: true,
             ..Default::default()
         });
 
         let ast = parser
-            .try_with_sql(query)
-            .map_err(to_sql_interface_err)?
+            .with_tokens_with_locations(tokens)
             .parse_statements()
             .map_err(to_sql_interface_err)?;
 
         polars_ensure!(ast.len() == 1, SQLInterface: "one (and only one) statement can be parsed at a time");
-        let res = self.execute_statement(ast.first().unwrap())?;
+        self.params = params.iter().map(|v| v.clone().into_static()).collect();
+        let res = self.execute_statement(ast.first().unwrap());
+        self.params.clear();
+        let res = res?;
 
         // Ensure the result uses the proper arenas.
         // This will instantiate new arenas with a new version.
@@ -177,6 +232,43 @@ impl SQLContext {
         self
     }
 
+    /// Set the maximum number of iterations of a recursive CTE (default 1000); a recursive
+    /// CTE that doesn't finish within the limit raises an error.
+    pub fn with_recursion_limit(mut self, recursion_limit: usize) -> Self {
+        self.recursion_limit = recursion_limit;
+        self
+    }
+
+    /// Set the SQL dialect of the queries (default [`SQLDialect::Generic`]).
+    /// ```rust
+    /// # use polars_sql::{SQLContext, SQLDialect};
+    /// # use polars_core::prelude::*;
+    /// # use polars_lazy::prelude::*;
+    /// # fn main() {
+    /// let mut ctx = SQLContext::new().with_dialect(SQLDialect::MySql);
+    /// ctx.register("df", df! { "a" => [1, 2, 3] }.unwrap().lazy());
+    /// let sql = "SELECT `a` / 2 AS half, UCASE(\"x\") AS x FROM df";
+    /// let sql_df = ctx.execute(sql).unwrap().collect().unwrap();
+    /// assert!(sql_df.equals(&df! { "half" => [0.5, 1.0, 1.5], "x" => ["X"; 3] }.unwrap()));
+    /// # }
+    ///```
+    pub fn with_dialect(mut self, dialect: SQLDialect) -> Self {
+        self.dialect = dialect;
+        self
+    }
+
+    /// Only execute queries (`SELECT` and its set operations) on the registered tables; any other
+    /// statement, and the table functions that read files (such as `read_csv`), raise an error.
+    pub fn with_read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// Get the SQL dialect of the SQLContext
+    pub fn dialect(&self) -> SQLDialect {
+        self.dialect
+    }
+
     /// Get the function registry of the SQLContext
     pub fn registry(&self) -> &Arc<dyn FunctionRegistry> {
         &self.function_registry
@@ -186,20 +278,95 @@ impl SQLContext {
     pub fn registry_mut(&mut self) -> &mut dyn FunctionRegistry {
         Arc::get_mut(&mut self.function_registry).unwrap()
     }
+
+    /// Register a Rust function as a SQL scalar function.
+    ///
+    /// The arguments are cast to `arg_types`, and the function must return a column of
+    /// `return_type` with the same length as its arguments.
+    /// ```rust
+    /// # use polars_sql::SQLContext;
+    /// # use polars_sql::function_registry::Volatility;
+    /// # use polars_core::prelude::*;
+    /// # use polars_lazy::prelude::*;
+    /// # fn main() {
+    ///
+    /// let mut ctx = SQLContext::new();
+    /// ctx.register_scalar_function(
+    ///     "twice",
+    ///     vec![DataType::Int64],
+    ///     DataType::Int64,
+    ///     Volatility::Immutable,
+    ///     |args| Ok((args[0].as_materialized_series() * 2).into_column()),
+    /// )
+    /// .unwrap();
+    /// ctx.register("df", df! { "a" => [1i64, 2] }.unwrap().lazy());
+    /// let sql_df = ctx.execute("SELECT twice(a) AS b FROM df").unwrap().collect().unwrap();
+    /// assert!(sql_df.equals(&df! { "b" => [2i64, 4] }.unwrap()));
+    /// # }
+    ///```
+    pub fn register_scalar_function(
+        &mut self,
+        name: &str,
+        arg_types: Vec<DataType>,
+        return_type: DataType,
+        volatility: Volatility,
+        fun: impl Fn(&[Column]) -> PolarsResult<Column> + Send + Sync + 'static,
+    ) -> PolarsResult<()> {
+        let mut udf = new_sql_udf(name, arg_types, return_type, fun);
+        if volatility == Volatility::Immutable {
+            udf.options.set_elementwise();
+        }
+        self.register_function(name, udf)
+    }
+
+    /// Register a Rust function as a SQL aggregate function.
+    ///
+    /// The arguments are cast to `arg_types`, and the function is called on all rows, or on the
+    /// rows of every group of a `GROUP BY`, returning a single value of `return_type`.
+    pub fn register_aggregate_function(
+        &mut self,
+        name: &str,
+        arg_types: Vec<DataType>,
+        return_type: DataType,
+        fun: impl Fn(&[Column]) -> PolarsResult<Column> + Send + Sync + 'static,
+    ) -> PolarsResult<()> {
+        let mut udf = new_sql_udf(name, arg_types, return_type, fun);
+        udf.options.flags |= FunctionFlags::RETURNS_SCALAR;
+        self.register_function(name, udf)
+    }
+
+    fn register_function(&mut self, name: &str, udf: UserDefinedFunction) -> PolarsResult<()> {
+        let name = name.to_lowercase();
+        polars_ensure!(
+            !PolarsSQLFunctions::keywords().contains(&name.as_str()),
+            SQLInterface: "cannot register function '{}'; it is a built-in function", name
+        );
+        let Some(registry) = Arc::get_mut(&mut self.function_registry) else {
+            polars_bail!(
+                SQLInterface: "cannot register function '{}'; the function registry is shared", name
+            );
+        };
+        registry.register(&name, udf)
+    }
 }
 
 impl SQLContext {
     pub(crate) fn execute_statement(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
         let ast = stmt;
+        if self.read_only && !matches!(ast, Statement::Query(_)) {
+            polars_bail!(SQLInterface: "only queries can be executed by a read-only SQLContext");
+        }
         Ok(match ast {
             Statement::Query(query) => self.execute_query(query)?,
             stmt @ Statement::ShowTables { .. } => self.execute_show_tables(stmt)?,
             stmt @ Statement::CreateTable { .. } => self.execute_create_table(stmt)?,
+            stmt @ Statement::Copy { .. } => self.execute_copy(stmt)?,
             stmt @ Statement::Drop {
                 object_type: ObjectType::Table,
                 ..
             } => self.execute_drop_table(stmt)?,
             stmt @ Statement::Explain { .. } => self.execute_explain(stmt)?,
+            stmt @ Statement::ExplainTable { .. } => self.execute_describe_table(stmt)?,
             stmt @ Statement::Truncate { .. } => self.execute_truncate_table(stmt)?,
             stmt @ Statement::Delete { .. } => self.execute_delete_from_table(stmt)?,
             _ => polars_bail!(
@@ -483,6 +650,63 @@ impl SQLContext {
         Ok(df.lazy())
     }
 
+    // DESCRIBE <tbl>
+    fn execute_describe_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        let Statement::ExplainTable { table_name, .. } = stmt else {
+            unreachable!()
+        };
+        let tbl_name = table_name.0.first().unwrap().value.as_str();
+        let Some(mut lf) = self.get_table_from_current_scope(tbl_name) else {
+            polars_bail!(SQLInterface: "relation '{}' was not found", tbl_name);
+        };
+        let schema = self.get_frame_schema(&mut lf)?;
+        let df = df! {
+            "column_name" => schema.iter_names().map(|n| n.as_str()).collect::<Vec<_>>(),
+            "column_type" => schema.iter_values().map(map_polars_dtype_to_sql).collect::<Vec<_>>(),
+        }?;
+        Ok(df.lazy())
+    }
+
+    /// The `information_schema.tables` and `information_schema.columns` views, which describe
+    /// the registered tables and their columns.
+    fn execute_information_schema(&mut self, view: &str) -> PolarsResult<LazyFrame> {
+        let tables = self.get_tables();
+        let df = match view.to_lowercase().as_str() {
+            "tables" => df! {
+                "table_schema" => vec!["public"; tables.len()],
+                "table_name" => &tables,
+                "table_type" => vec!["BASE TABLE"; tables.len()],
+            }?,
+            "columns" => {
+                let mut table_name = vec![];
+                let mut column_name = vec![];
+                let mut ordinal_position = vec![];
+                let mut data_type = vec![];
+                for tbl in &tables {
+                    let mut lf = self.table_map[tbl].clone();
+                    let schema = self.get_frame_schema(&mut lf)?;
+                    for (idx, (name, dtype)) in schema.iter().enumerate() {
+                        table_name.push(tbl.as_str());
+                        column_name.push(name.to_string());
+                        ordinal_position.push(idx as u32 + 1);
+                        data_type.push(map_polars_dtype_to_sql(dtype));
+                    }
+                }
+                let n_columns = table_name.len();
+                df! {
+                    "table_schema" => vec!["public"; n_columns],
+                    "table_name" => table_name,
+                    "column_name" => column_name,
+                    "ordinal_position" => ordinal_position,
+                    "data_type" => data_type,
+                    "is_nullable" => vec!["YES"; n_columns],
+                }?
+            },
+            _ => polars_bail!(SQLInterface: "relation 'information_schema.{}' was not found", view),
+        };
+        Ok(df.lazy())
+    }
+
     // DROP TABLE <tbl>
     fn execute_drop_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
         match stmt {
@@ -594,24 +818,127 @@ impl SQLContext {
 
     fn register_ctes(&mut self, query: &Query) -> PolarsResult<()> {
         if let Some(with) = &query.with {
-            if with.recursive {
-                polars_bail!(SQLInterface: "recursive CTEs are not supported")
-            }
             for cte in &with.cte_tables {
                 let cte_name = cte.alias.name.value.clone();
-                let mut lf = self.execute_query(&cte.query)?;
-                lf = self.rename_columns_from_table_alias(lf, &cte.alias)?;
+                let lf = if with.recursive {
+                    self.execute_recursive_cte(cte)?
+                } else {
+                    let lf = self.execute_query(&cte.query)?;
+                    self.rename_columns_from_table_alias(lf, &cte.alias)?
+                };
                 self.register_cte(&cte_name, lf);
             }
         }
         Ok(())
     }
 
+    /// Execute a CTE of a 'WITH RECURSIVE' clause.
+    ///
+    /// A recursive CTE is the union of an anchor query and a recursive query that references
+    /// the CTE. The recursive query is executed on the rows of the previous iteration, starting
+    /// with the rows of the anchor, until it no longer returns any (new) rows.
+    fn execute_recursive_cte(&mut self, cte: &Cte) -> PolarsResult<LazyFrame> {
+        let cte_name = cte.alias.name.value.as_str();
+        let (left, right, distinct) = match &*cte.query.body {
+            SetExpr::SetOperation {
+                op: SetOperator::Union,
+                set_quantifier,
+                left,
+                right,
+            } if references_table(right, cte_name) => {
+                let distinct = match set_quantifier {
+                    SetQuantifier::All => false,
+                    SetQuantifier::Distinct | SetQuantifier::None => true,
+                    _ => polars_bail!(
+                        SQLInterface:
+                        "'UNION {}' is not supported in recursive CTEs", set_quantifier
+                    ),
+                };
+                (left, right, distinct)
+            },
+            // CTEs of a 'WITH RECURSIVE' clause don't have to be recursive
+            _ => {
+                let lf = self.execute_query(&cte.query)?;
+                return self.rename_columns_from_table_alias(lf, &cte.alias);
+            },
+        };
+        polars_ensure!(
+            !references_table(left, cte_name),
+            SQLInterface: "the first term of recursive CTE '{}' must not reference itself", cte_name
+        );
+        self.register_ctes(&cte.query)?;
+
+        let anchor = self.process_query(left, &cte.query)?;
+        let mut result = self
+            .rename_columns_from_table_alias(anchor, &cte.alias)?
+            .collect()?;
+        if distinct {
+            result = result.unique_stable(None, UniqueKeepStrategy::First, None)?;
+        }
+        let schema = result.schema().clone();
+        let mut working = result.clone();
+        let mut iterations = 0;
+        while working.height() > 0 {
+            polars_ensure!(
+                iterations < self.recursion_limit,
+                SQLInterface: "recursive CTE '{}' did not finish within {} iterations; \
+                consider increasing the recursion limit",
+                cte_name, self.recursion_limit
+            );
+            iterations += 1;
+
+            self.register_cte(cte_name, working.lazy());
+            let mut df = self.process_query(right, &cte.query)?.collect()?;
+            polars_ensure!(
+                df.width() == schema.len(),
+                SQLInterface: "the terms of recursive CTE '{}' must have the same number of \
+                columns (found {} and {})",
+                cte_name, schema.len(), df.width()
+            );
+            // the names and types of the columns are those of the anchor
+            let height = df.height();
+            let columns = df
+                .take_columns()
+                .into_iter()
+                .zip(schema.iter())
+                .map(|(c, (name, dtype))| Ok(c.strict_cast(dtype)?.with_name(name.clone())))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            let new = DataFrame::new_with_height(height, columns)?;
+
+            let n_prev = result.height();
+            result.vstack_mut(&new)?;
+            working = if distinct {
+                // only the rows that are new to the union are recursed on
+                result = result.unique_stable(None, UniqueKeepStrategy::First, None)?;
+                result.slice(n_prev as i64, usize::MAX)
+            } else {
+                new
+            };
+        }
+        result.rechunk_mut();
+        Ok(result.lazy())
+    }
+
     /// execute the 'FROM' part of the query
     fn execute_from_statement(&mut self, tbl_expr: &TableWithJoins) -> PolarsResult<LazyFrame> {
         let (l_name, mut lf) = self.get_table(&tbl_expr.relation)?;
         if !tbl_expr.joins.is_empty() {
             for join in &tbl_expr.joins {
+                if let Some((array_exprs, alias)) = lateral_unnest(&join.relation)? {
+                    let keep_empty = match &join.join_operator {
+                        JoinOperator::CrossJoin | JoinOperator::Inner(JoinConstraint::None) => {
+                            false
+                        },
+                        JoinOperator::Inner(JoinConstraint::On(on)) if is_true(on) => false,
+                        JoinOperator::LeftOuter(JoinConstraint::On(on)) if is_true(on) => true,
+                        _ => polars_bail!(
+                            SQLInterface:
+                            "UNNEST of a column can only be CROSS JOINed, or JOINed ON TRUE"
+                        ),
+                    };
+                    lf = self.execute_lateral_unnest(lf, &array_exprs, alias, keep_empty)?;
+                    continue;
+                }
                 let (r_name, mut rf) = self.get_table(&join.relation)?;
                 if r_name.is_empty() {
                     // Require non-empty to avoid duplicate column errors from nested self-joins.
@@ -712,10 +1039,21 @@ impl SQLContext {
             // Note: implicit joins need more work to support properly,
             // explicit joins are preferred for now (ref: #16662)
             let from = select_stmt.clone().from;
-            if from.len() > 1 {
-                polars_bail!(SQLInterface: "multiple tables in FROM clause are not currently supported (found {}); use explicit JOIN syntax instead", from.len())
+            let mut lateral = Vec::with_capacity(from.len() - 1);
+            for tbl_expr in &from[1..] {
+                match lateral_unnest(&tbl_expr.relation)? {
+                    Some(unnest) if tbl_expr.joins.is_empty() => lateral.push(unnest),
+                    _ => polars_bail!(
+                        SQLInterface:
+                        "multiple tables in FROM clause are not currently supported (found {}); use explicit JOIN syntax instead", from.len()
+                    ),
+                }
+            }
+            let mut lf = self.execute_from_statement(from.first().unwrap())?;
+            for (array_exprs, alias) in lateral {
+                lf = self.execute_lateral_unnest(lf, &array_exprs, alias, false)?;
             }
-            self.execute_from_statement(from.first().unwrap())?
+            lf
         };
 
         // Filter expression (WHERE clause)
@@ -773,11 +1111,7 @@ impl SQLContext {
                     },
                     _ => {
                         // If not quick-matched, add if no nested agg/window expressions
-                        if !has_expr(expr, |e| {
-                            matches!(e, Expr::Agg(_))
-                                || matches!(e, Expr::Len)
-                                || matches!(e, Expr::Window { .. })
-                        }) {
+                        if !has_expr(expr, is_agg_or_window_expr) {
                             group_by_keys.push(expr.clone())
                         }
                     },
@@ -1076,19 +1410,18 @@ impl SQLContext {
         }) = stmt
         {
             let tbl_name = name.0.first().unwrap().value.as_str();
-            // CREATE TABLE IF NOT EXISTS
+            let out = df! {
+                "Response" => ["CREATE TABLE"]
+            }
+            .unwrap()
+            .lazy();
+            // CREATE TABLE IF NOT EXISTS keeps an existing table, otherwise it's replaced
             if *if_not_exists && self.table_map.contains_key(tbl_name) {
-                polars_bail!(SQLInterface: "relation '{}' already exists", tbl_name);
-                // CREATE OR REPLACE TABLE
+                return Ok(out);
             }
             if let Some(query) = query {
                 let lf = self.execute_query(query)?;
                 self.register(tbl_name, lf);
-                let out = df! {
-                    "Response" => ["CREATE TABLE"]
-                }
-                .unwrap()
-                .lazy();
                 Ok(out)
             } else {
                 polars_bail!(SQLInterface: "only `CREATE TABLE AS SELECT ...` is currently supported");
@@ -1098,6 +1431,206 @@ impl SQLContext {
         }
     }
 
+    /// Write the result of a table or query to a file with `COPY ... TO 'path'`.
+    ///
+    /// The file format is given by the `FORMAT` option, or inferred from the file extension.
+    /// Returns the sink plan, so the file is written once the result is collected.
+    fn execute_copy(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        let Statement::Copy {
+            source,
+            to,
+            target,
+            options,
+            legacy_options,
+            ..
+        } = stmt
+        else {
+            unreachable!()
+        };
+        polars_ensure!(
+            *to,
+            SQLInterface: "COPY FROM is not supported; consider using a table function instead"
+        );
+        let CopyTarget::File { filename } = target else {
+            polars_bail!(SQLInterface: "COPY only supports writing to files (found {})", target);
+        };
+        polars_ensure!(
+            legacy_options.is_empty(),
+            SQLInterface: "COPY options must be given as a list, such as `(FORMAT csv)`"
+        );
+
+        #[cfg_attr(
+            not(any(
+                feature = "csv",
+                feature = "ipc",
+                feature = "json",
+                feature = "parquet"
+            )),
+            allow(unused_variables)
+        )]
+        let lf = match source {
+            CopySource::Table {
+                table_name,
+                columns,
+            } => {
+                let tbl_name = table_name.to_string();
+                let Some(lf) = self.get_table_from_current_scope(&tbl_name) else {
+                    polars_bail!(SQLInterface: "relation '{}' was not found", tbl_name);
+                };
+                if columns.is_empty() {
+                    lf
+                } else {
+                    lf.select(
+                        columns
+                            .iter()
+                            .map(|c| col(c.value.as_str()))
+                            .collect::<Vec<_>>(),
+                    )
+                }
+            },
+            CopySource::Query(query) => self.execute_query(query)?,
+        };
+
+        let mut format = None;
+        let (mut header, mut delimiter, mut null) = (None, None, None);
+        for option in options {
+            match option {
+                CopyOption::Format(ident) => format = Some(ident.value.to_lowercase()),
+                CopyOption::Header(h) => header = Some(*h),
+                CopyOption::Delimiter(d) => delimiter = Some(*d),
+                CopyOption::Null(n) => null = Some(n.clone()),
+                _ => polars_bail!(SQLInterface: "COPY option {} is not supported", option),
+            }
+        }
+        let format = match format {
+            Some(format) => format,
+            None => match std::path::Path::new(filename).extension() {
+                Some(ext) => ext.to_string_lossy().to_lowercase(),
+                None => polars_bail!(
+                    SQLInterface: "cannot infer the file format of '{}'; consider setting FORMAT",
+                    filename
+                ),
+            },
+        };
+        polars_ensure!(
+            format == "csv" || (header.is_none() && delimiter.is_none() && null.is_none()),
+            SQLInterface: "the HEADER, DELIMITER and NULL options of COPY are only supported by CSV"
+        );
+
+        #[cfg(any(
+            feature = "csv",
+            feature = "ipc",
+            feature = "json",
+            feature = "parquet"
+        ))]
+        let (target, sink_options) = (
+            SinkTarget::Path(Arc::new(filename.into())),
+            SinkOptions::default(),
+        );
+        match format.as_str() {
+            #[cfg(feature = "csv")]
+            "csv" => {
+                let mut options = CsvWriterOptions::default();
+                if let Some(header) = header {
+                    options.include_header = header;
+                }
+                if let Some(delimiter) = delimiter {
+                    polars_ensure!(
+                        delimiter.is_ascii(),
+                        SQLSyntax: "COPY DELIMITER must be a single-byte character (found '{}')",
+                        delimiter
+                    );
+                    options.serialize_options.separator = delimiter as u8;
+                }
+                if let Some(null) = null {
+                    options.serialize_options.null = null;
+                }
+                lf.sink_csv(target, options, None, sink_options)
+            },
+            #[cfg(feature = "ipc")]
+            "arrow" | "feather" | "ipc" => {
+                lf.sink_ipc(target, IpcWriterOptions::default(), None, sink_options)
+            },
+            #[cfg(feature = "json")]
+            "json" | "jsonl" | "ndjson" => {
+                lf.sink_json(target, JsonWriterOptions::default(), None, sink_options)
+            },
+            #[cfg(feature = "parquet")]
+            "parquet" => {
+                lf.sink_parquet(target, ParquetWriteOptions::default(), None, sink_options)
+            },
+            _ => polars_bail!(SQLInterface: "COPY does not support the '{}' format", format),
+        }
+    }
+
+    /// Pair every row of `lf` with each of the elements of the given lists of that row.
+    ///
+    /// The lists are exploded together, so they must have equal lengths. Rows without any
+    /// elements are dropped, unless `keep_empty` is set (as for a `LEFT JOIN ... ON TRUE`), in
+    /// which case they are kept with null elements. The elements are added as new columns, named
+    /// by the column aliases of the table, or `unnest`, `unnest_1`, ... otherwise.
+    fn execute_lateral_unnest(
+        &mut self,
+        mut lf: LazyFrame,
+        array_exprs: &[&SQLExpr],
+        alias: &Option<TableAlias>,
+        keep_empty: bool,
+    ) -> PolarsResult<LazyFrame> {
+        let schema = self.get_frame_schema(&mut lf)?;
+        let lists = array_exprs
+            .iter()
+            .map(|e| parse_sql_expr(e, self, Some(&schema)))
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let aliases: Vec<PlSmallStr> = match alias.as_ref().filter(|a| !a.columns.is_empty()) {
+            Some(alias) => {
+                if alias.columns.len() != lists.len() {
+                    let plural = if lists.len() > 1 { "s" } else { "" };
+                    polars_bail!(
+                        SQLSyntax:
+                        "UNNEST table alias requires {} column name{}, found {}", lists.len(), plural, alias.columns.len()
+                    );
+                }
+                alias
+                    .columns
+                    .iter()
+                    .map(|c| c.name.value.as_str().into())
+                    .collect()
+            },
+            // Don't overwrite the unnested columns.
+            None => (0..lists.len())
+                .map(|i| match i {
+                    0 => PlSmallStr::from_static("unnest"),
+                    i => format_pl_smallstr!("unnest_{i}"),
+                })
+                .collect(),
+        };
+        let lists = lists
+            .into_iter()
+            .zip(aliases)
+            .map(|(e, name)| e.alias(name))
+            .collect::<Vec<_>>();
+        let mut names = Vec::with_capacity(lists.len());
+        for e in &lists {
+            let field = e.to_field(&schema, Context::Default)?;
+            polars_ensure!(
+                matches!(field.dtype, DataType::List(_)),
+                SQLSyntax: "UNNEST expects a list, but '{}' is {}", field.name, field.dtype
+            );
+            names.push(field.name);
+        }
+
+        if !keep_empty {
+            let has_elements = lists
+                .iter()
+                .map(|e| e.clone().list().len().gt(lit(0)))
+                .reduce(|acc, e| acc.or(e))
+                .unwrap();
+            lf = lf.filter(has_elements);
+        }
+        Ok(lf.with_columns(lists).explode(names))
+    }
+
     fn get_table(&mut self, relation: &TableFactor) -> PolarsResult<(String, LazyFrame)> {
         match relation {
             TableFactor::Table {
@@ -1106,6 +1639,20 @@ impl SQLContext {
                 if let Some(args) = args {
                     return self.execute_table_function(name, alias, &args.args);
                 }
+                if let [schema, view] = name.0.as_slice() {
+                    if schema.value.eq_ignore_ascii_case("information_schema") {
+                        let lf = self.execute_information_schema(&view.value)?;
+                        let tbl_name = match alias {
+                            Some(alias) => alias.name.value.clone(),
+                            None => view.value.clone(),
+                        };
+                        // note: the view is only visible to the current statement
+                        self.cte_map
+                            .borrow_mut()
+                            .insert(tbl_name.clone(), lf.clone());
+                        return Ok((tbl_name, lf));
+                    }
+                }
                 let tbl_name = name.0.first().unwrap().value.as_str();
                 if let Some(lf) = self.get_table_from_current_scope(tbl_name) {
                     match alias {
@@ -1126,7 +1673,10 @@ impl SQLContext {
                 subquery,
                 alias,
             } => {
-                polars_ensure!(!(*lateral), SQLInterface: "LATERAL not supported");
+                polars_ensure!(
+                    !(*lateral),
+                    SQLInterface: "LATERAL subqueries are not supported; consider using UNNEST"
+                );
                 if let Some(alias) = alias {
                     let mut lf = self.execute_query_no_ctes(subquery)?;
                     lf = self.rename_columns_from_table_alias(lf, alias)?;
@@ -1220,6 +1770,10 @@ impl SQLContext {
         args: &[FunctionArg],
     ) -> PolarsResult<(String, LazyFrame)> {
         let tbl_fn = name.0.first().unwrap().value.as_str();
+        polars_ensure!(
+            !self.read_only,
+            SQLInterface: "table function '{}' can't be used by a read-only SQLContext", tbl_fn
+        );
         let read_fn = tbl_fn.parse::<PolarsTableFunctions>()?;
         let (tbl_name, lf) = read_fn.execute(args)?;
         #[allow(clippy::useless_asref)]
@@ -1306,9 +1860,7 @@ impl SQLContext {
 
         for mut e in projections {
             // `Len` represents COUNT(*) so we treat as an aggregation here.
-            let is_agg_or_window = has_expr(e, |e| {
-                matches!(e, Expr::Agg(_) | Expr::Len | Expr::Window { .. })
-            });
+            let is_agg_or_window = has_expr(e, is_agg_or_window_expr);
 
             // Note: if simple aliased expression we defer aliasing until after the group_by.
             if let Expr::Alias(expr, alias) = e {
@@ -1546,6 +2098,21 @@ impl SQLContext {
     }
 }
 
+/// Whether the query selects from the table `name`, including in its subqueries.
+fn references_table(expr: &SetExpr, name: &str) -> bool {
+    if let SetExpr::Table(tbl) = expr {
+        return tbl.table_name.as_deref() == Some(name);
+    }
+    visit_relations(expr, |relation| {
+        if relation.0.first().is_some_and(|ident| ident.value == name) {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_break()
+}
+
 fn collect_compound_identifiers(
     left: &[Ident],
     right: &[Ident],
@@ -1708,3 +2275,113 @@ impl ExprSqlProjectionHeightBehavior {
         }
     }
 }
+
+/// Replace the anonymous placeholders (`?`) of a query by numbered ones (`$1`, `$2`, ...), and
+/// check that every placeholder refers to one of the `n_params` parameters.
+fn number_placeholders<'a>(
+    tokens: impl Iterator<Item = &'a mut Token>,
+    n_params: usize,
+) -> PolarsResult<()> {
+    let (mut n_anonymous, mut n_numbered) = (0, 0);
+    for token in tokens {
+        let Token::Placeholder(placeholder) = token else {
+            continue;
+        };
+        let idx = if placeholder == "?" {
+            n_anonymous += 1;
+            *placeholder = format!("${n_anonymous}");
+            n_anonymous
+        } else {
+            n_numbered += 1;
+            match placeholder
+                .strip_prefix(['$', '?'])
+                .map(str::parse::<usize>)
+            {
+                Some(Ok(idx)) if idx > 0 => idx,
+                _ => polars_bail!(
+                    SQLSyntax: "invalid placeholder '{}'; use `?` or `$1`, `$2`, ...", placeholder
+                ),
+            }
+        };
+        polars_ensure!(
+            idx <= n_params,
+            SQLInterface: "no value was bound to placeholder ${} (number of parameters: {})",
+            idx, n_params
+        );
+    }
+    polars_ensure!(
+        n_anonymous == 0 || n_numbered == 0,
+        SQLSyntax: "cannot mix anonymous (`?`) and numbered (`$1`) placeholders"
+    );
+    Ok(())
+}
+
+fn is_true(expr: &SQLExpr) -> bool {
+    matches!(expr, SQLExpr::Value(SQLValue::Boolean(true)))
+}
+
+/// The array expressions and alias of an `UNNEST` of (the columns of) preceding tables in a
+/// FROM clause; `UNNEST` of only literal arrays is a regular table.
+fn lateral_unnest(
+    relation: &TableFactor,
+) -> PolarsResult<Option<(Vec<&SQLExpr>, &Option<TableAlias>)>> {
+    Ok(match relation {
+        TableFactor::UNNEST {
+            alias,
+            array_exprs,
+            with_offset,
+            ..
+        } if !array_exprs.iter().all(|e| matches!(e, SQLExpr::Array(_))) => {
+            polars_ensure!(
+                !with_offset,
+                SQLInterface: "UNNEST tables do not (yet) support WITH OFFSET/ORDINALITY"
+            );
+            Some((array_exprs.iter().collect(), alias))
+        },
+        TableFactor::Function {
+            lateral: true,
+            name,
+            args,
+            alias,
+        } if name.to_string().eq_ignore_ascii_case("unnest") => {
+            let array_exprs = args
+                .iter()
+                .map(|arg| match arg {
+                    FunctionArg::Unnamed(FunctionArgExpr::Expr(e)) => Ok(e),
+                    _ => polars_bail!(SQLSyntax: "invalid UNNEST argument: {}", arg),
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+            Some((array_exprs, alias))
+        },
+        _ => None,
+    })
+}
+
+/// Whether an expression is an aggregation, including aggregate UDFs, or a window expression.
+fn is_agg_or_window_expr(e: &Expr) -> bool {
+    match e {
+        Expr::Agg(_) | Expr::Len | Expr::Window { .. } => true,
+        Expr::AnonymousFunction { options, .. } => options.returns_scalar(),
+        _ => false,
+    }
+}
+
+/// A [`UserDefinedFunction`] of which the arguments are cast to `arg_types`.
+fn new_sql_udf(
+    name: &str,
+    arg_types: Vec<DataType>,
+    return_type: DataType,
+    fun: impl Fn(&[Column]) -> PolarsResult<Column> + Send + Sync + 'static,
+) -> UserDefinedFunction {
+    let input_fields = arg_types
+        .into_iter()
+        .enumerate()
+        .map(|(idx, dtype)| Field::new(format_pl_smallstr!("arg_{}", idx + 1), dtype))
+        .collect();
+    UserDefinedFunction::new(
+        name.into(),
+        input_fields,
+        GetOutput::from_type(return_type),
+        move |c: &mut [Column]| fun(c).map(Some),
+    )
+}
@@ -0,0 +1,68 @@
+use sqlparser::dialect::{Dialect, DuckDbDialect, GenericDialect, MySqlDialect, PostgreSqlDialect};
+
+/// The SQL dialect that the queries of a [`SQLContext`](crate::SQLContext) are written in.
+///
+/// The dialect controls how identifiers are quoted and strings are escaped, what `/` does with
+/// integers, and which function names of that system are accepted as aliases of the Polars
+/// SQL functions.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SQLDialect {
+    /// Accepts most of the syntax of the other dialects; identifiers are quoted with double
+    /// quotes or backticks, and `/` floors the division of integers.
+    #[default]
+    Generic,
+    /// Identifiers are quoted with double quotes, backslash escapes are only recognised in
+    /// `E'...'` strings, and `/` truncates the division of integers towards zero.
+    PostgreSql,
+    /// Identifiers are quoted with backticks, double quotes delimit strings, backslash escapes
+    /// are recognised in all strings, and `/` always returns a float (use `DIV` to truncate).
+    MySql,
+    /// Identifiers are quoted with double quotes, and `/` always returns a float (use `//`
+    /// for floor division).
+    DuckDb,
+}
+
+impl SQLDialect {
+    /// The dialect used to tokenize and parse the queries.
+    pub(crate) fn parser_dialect(self) -> &'static dyn Dialect {
+        match self {
+            Self::Generic => &GenericDialect {},
+            Self::PostgreSql => &PostgreSqlDialect {},
+            Self::MySql => &MySqlDialect {},
+            Self::DuckDb => &DuckDbDialect {},
+        }
+    }
+
+    /// Whether `/` returns a float for integer operands, instead of flooring.
+    pub(crate) fn true_division(self) -> bool {
+        matches!(self, Self::MySql | Self::DuckDb)
+    }
+
+    /// Whether `/` truncates the division of integers towards zero, instead of flooring.
+    pub(crate) fn truncating_division(self) -> bool {
+        matches!(self, Self::PostgreSql)
+    }
+
+    /// The name of the Polars SQL function that `name` (in lowercase) is an alias of, if any.
+    pub(crate) fn function_alias(self, name: &str) -> Option<&'static str> {
+        Some(match (self, name) {
+            (Self::MySql | Self::DuckDb, "ucase") => "upper",
+            (Self::MySql | Self::DuckDb, "lcase") => "lower",
+            (Self::MySql | Self::DuckDb, "instr") => "strpos",
+            (Self::MySql, "mid") => "substr",
+            (Self::DuckDb, "mean") => "avg",
+            (Self::DuckDb, "list") => "array_agg",
+            (Self::DuckDb, "prefix") => "starts_with",
+            (Self::DuckDb, "suffix") => "ends_with",
+            (Self::DuckDb, "datepart") => "date_part",
+            (Self::DuckDb, "list_contains" | "list_has") => "array_contains",
+            (Self::DuckDb, "list_distinct") => "array_unique",
+            (Self::DuckDb, "list_reverse") => "array_reverse",
+            (Self::DuckDb, "list_sum") => "array_sum",
+            (Self::DuckDb, "list_avg") => "array_mean",
+            (Self::DuckDb, "list_min") => "array_lower",
+            (Self::DuckDb, "list_max") => "array_upper",
+            _ => return None,
+        })
+    }
+}
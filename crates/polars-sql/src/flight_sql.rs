@@ -0,0 +1,793 @@
+//! An [Arrow Flight SQL](https://arrow.apache.org/docs/format/FlightSql.html) service that
+//! runs the queries of its clients on a [`SQLContext`].
+//!
+//! The service supports ad-hoc and prepared statements (without parameters) and the catalog,
+//! schema, table and table type metadata commands. Query results are streamed to the client by
+//! the streaming engine as they are produced, instead of being collected first.
+use std::collections::BTreeMap;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use arrow::io::ipc::IpcField;
+use arrow::io::ipc::write::{
+    DictionaryTracker, EncodedData, WriteOptions, default_ipc_fields, encode_new_dictionaries,
+    encode_record_batch, schema_to_bytes,
+};
+use arrow_format::flight::data::{
+    Action, ActionType, Criteria, Empty, FlightData, FlightDescriptor, FlightEndpoint, FlightInfo,
+    HandshakeRequest, HandshakeResponse, PutResult, Result as FlightResult, SchemaResult, Ticket,
+};
+use arrow_format::flight::service::flight_service_server::{FlightService, FlightServiceServer};
+use async_trait::async_trait;
+use futures::{Stream, StreamExt, stream};
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_plan::dsl::Engine;
+use prost::Message;
+use tokio::sync::mpsc;
+use tonic::metadata::MetadataMap;
+use tonic::{Request, Response, Status, Streaming};
+
+use crate::SQLContext;
+
+type FlightStream<T> = Pin<Box<dyn Stream<Item = Result<T, Status>> + Send + 'static>>;
+
+/// The morsels of a query result that may be buffered before the engine waits for the client.
+const MORSEL_BUFFER_SIZE: usize = 2;
+
+/// Gives every streamed query a unique streaming operator name.
+static NEXT_QUERY_ID: AtomicU64 = AtomicU64::new(0);
+
+/// The tickets, and the prepared statements, that a service keeps by default.
+const DEFAULT_MAX_OPEN_HANDLES: usize = 1024;
+
+/// How long a ticket or prepared statement is kept by default without being used.
+const DEFAULT_HANDLE_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// Authenticates the clients of a [`FlightSqlService`].
+pub trait FlightSqlAuthenticator: Send + Sync + 'static {
+    /// Exchange the credentials of a client, from the headers and payload of its `Handshake`
+    /// call, for the token that it sends in the `authorization` header of its other calls.
+    fn handshake(&self, metadata: &MetadataMap, payload: &[u8]) -> Result<Vec<u8>, Status>;
+
+    /// Check the credentials in the headers of a call, before it is served.
+    fn authorize(&self, metadata: &MetadataMap) -> Result<(), Status>;
+}
+
+/// Accepts the calls that send a fixed token in their `authorization` header, as
+/// `Bearer <token>`; the handshake returns the same token.
+pub struct BearerTokenAuthenticator {
+    token: String,
+}
+
+impl BearerTokenAuthenticator {
+    /// Accept the calls that send `token`.
+    pub fn new(token: impl Into<String>) -> Self {
+        Self {
+            token: token.into(),
+        }
+    }
+}
+
+impl FlightSqlAuthenticator for BearerTokenAuthenticator {
+    fn handshake(&self, metadata: &MetadataMap, _payload: &[u8]) -> Result<Vec<u8>, Status> {
+        self.authorize(metadata)?;
+        Ok(self.token.clone().into_bytes())
+    }
+
+    fn authorize(&self, metadata: &MetadataMap) -> Result<(), Status> {
+        let token = metadata
+            .get("authorization")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "));
+        // compare every byte, so the time taken doesn't reveal the length of a matching prefix
+        let matches = token.is_some_and(|token| {
+            token.len() == self.token.len()
+                && token
+                    .bytes()
+                    .zip(self.token.bytes())
+                    .fold(0, |acc, (a, b)| acc | (a ^ b))
+                    == 0
+        });
+        if matches {
+            Ok(())
+        } else {
+            Err(Status::unauthenticated("invalid or missing bearer token"))
+        }
+    }
+}
+
+/// An Arrow Flight SQL service over a [`SQLContext`].
+///
+/// Clients can only run queries on the tables registered on the context: other statements and
+/// the table functions that read files are rejected. A statement is planned once, when the
+/// client gets its flight info or prepares it, and the ticket of the flight info can be
+/// redeemed once. Serve it with a tonic server, e.g.
+/// `Server::builder().add_service(FlightSqlService::new(ctx).into_server())`.
+///
+/// Without an authenticator every client is served; see
+/// [`with_authenticator`](Self::with_authenticator). Tickets that aren't redeemed and prepared
+/// statements that aren't closed are dropped once they expire, or to make room for new ones; see
+/// [`with_handle_limits`](Self::with_handle_limits).
+#[derive(Clone)]
+pub struct FlightSqlService {
+    ctx: Arc<Mutex<SQLContext>>,
+    authenticator: Option<Arc<dyn FlightSqlAuthenticator>>,
+    next_handle: Arc<AtomicU64>,
+    max_open_handles: usize,
+    handle_ttl: Duration,
+    /// The plans of the tickets that haven't been redeemed yet.
+    tickets: Arc<Mutex<Handles>>,
+    /// The plans of the open prepared statements.
+    prepared_statements: Arc<Mutex<Handles>>,
+}
+
+impl FlightSqlService {
+    /// Create a service that runs the queries of its clients on the tables of `ctx`; use
+    /// [`SQLContext::new_from_table_map`] to serve a set of [`LazyFrame`]s.
+    pub fn new(ctx: SQLContext) -> Self {
+        Self {
+            ctx: Arc::new(Mutex::new(ctx.with_read_only(true))),
+            authenticator: None,
+            next_handle: Default::default(),
+            max_open_handles: DEFAULT_MAX_OPEN_HANDLES,
+            handle_ttl: DEFAULT_HANDLE_TTL,
+            tickets: Default::default(),
+            prepared_statements: Default::default(),
+        }
+    }
+
+    /// Authenticate every call of the clients with `authenticator`.
+    pub fn with_authenticator(mut self, authenticator: Arc<dyn FlightSqlAuthenticator>) -> Self {
+        self.authenticator = Some(authenticator);
+        self
+    }
+
+    /// Keep at most `max_open` tickets, and as many prepared statements, each for at most `ttl`
+    /// since it was last used. The oldest are dropped first; clients get a `NOT_FOUND` status
+    /// for the handles that were dropped. Defaults to 1024 handles that are kept for an hour.
+    pub fn with_handle_limits(mut self, max_open: usize, ttl: Duration) -> Self {
+        self.max_open_handles = max_open.max(1);
+        self.handle_ttl = ttl;
+        self
+    }
+
+    /// Wrap the service in the tonic server of the Flight protocol.
+    pub fn into_server(self) -> FlightServiceServer<Self> {
+        FlightServiceServer::new(self)
+    }
+
+    fn authorize<T>(&self, request: &Request<T>) -> Result<(), Status> {
+        match &self.authenticator {
+            Some(authenticator) => authenticator.authorize(request.metadata()),
+            None => Ok(()),
+        }
+    }
+
+    /// Run `f` on a thread that may block, as planning a query can take the lock of the context
+    /// and collect data.
+    async fn blocking<T, F>(&self, f: F) -> Result<T, Status>
+    where
+        T: Send + 'static,
+        F: FnOnce(&Self) -> Result<T, Status> + Send + 'static,
+    {
+        let service = self.clone();
+        tokio::task::spawn_blocking(move || f(&service))
+            .await
+            .map_err(|err| Status::internal(err.to_string()))?
+    }
+
+    fn execute(&self, query: &str, params: &[AnyValue<'_>]) -> PolarsResult<LazyFrame> {
+        // statements don't share the tables that they register, such as their derived tables
+        let mut ctx = self.ctx.lock().unwrap().clone();
+        ctx.execute_with_params(query, params)
+    }
+
+    fn insert_handle(&self, handles: &Mutex<Handles>, lf: LazyFrame) -> Vec<u8> {
+        let handle = self.next_handle.fetch_add(1, Ordering::Relaxed);
+        handles
+            .lock()
+            .unwrap()
+            .insert(handle, lf, self.max_open_handles, self.handle_ttl);
+        handle.to_le_bytes().to_vec()
+    }
+
+    /// The plan of the result of a Flight SQL command.
+    fn plan(&self, cmd: &Any) -> Result<LazyFrame, Status> {
+        let lf = match cmd.name() {
+            Some("CommandStatementQuery") => {
+                let cmd: CommandStatementQuery = cmd.unpack()?;
+                self.execute(&cmd.query, &[])
+            },
+            Some("CommandPreparedStatementQuery") => {
+                let cmd: CommandPreparedStatementQuery = cmd.unpack()?;
+                let handle = decode_handle(&cmd.prepared_statement_handle)?;
+                let mut prepared_statements = self.prepared_statements.lock().unwrap();
+                return prepared_statements
+                    .get(handle, self.handle_ttl)
+                    .ok_or_else(|| Status::not_found("unknown or expired prepared statement"));
+            },
+            Some("CommandGetCatalogs") => {
+                // tables are not grouped into catalogs
+                df! { "catalog_name" => Vec::<String>::new() }.map(|df| df.lazy())
+            },
+            Some("CommandGetDbSchemas") => {
+                let cmd: CommandGetDbSchemas = cmd.unpack()?;
+                let query = r#"
+                    SELECT DISTINCT
+                      CAST(NULL AS VARCHAR) AS catalog_name,
+                      table_schema AS db_schema_name
+                    FROM information_schema.tables
+                    WHERE table_schema LIKE $1
+                    ORDER BY db_schema_name
+                "#;
+                self.execute(query, &[pattern(cmd.db_schema_filter_pattern)])
+                    .map(|lf| in_catalog(lf, cmd.catalog))
+            },
+            Some("CommandGetTables") => {
+                let cmd: CommandGetTables = cmd.unpack()?;
+                self.get_tables(cmd)
+            },
+            Some("CommandGetTableTypes") => {
+                df! { "table_type" => ["BASE TABLE"] }.map(|df| df.lazy())
+            },
+            _ => {
+                return Err(Status::unimplemented(format!(
+                    "Flight SQL command '{}' is not supported",
+                    cmd.type_url
+                )));
+            },
+        };
+        lf.map_err(to_status)
+    }
+
+    fn get_tables(&self, cmd: CommandGetTables) -> PolarsResult<LazyFrame> {
+        let mut query = String::from(
+            r#"
+            SELECT
+              CAST(NULL AS VARCHAR) AS catalog_name,
+              table_schema AS db_schema_name,
+              table_name,
+              table_type
+            FROM information_schema.tables
+            WHERE table_schema LIKE $1 AND table_name LIKE $2
+            "#,
+        );
+        let mut params = vec![
+            pattern(cmd.db_schema_filter_pattern),
+            pattern(cmd.table_name_filter_pattern),
+        ];
+        if !cmd.table_types.is_empty() {
+            let placeholders = (0..cmd.table_types.len())
+                .map(|i| format!("${}", params.len() + i + 1))
+                .collect::<Vec<_>>();
+            query.push_str(&format!(" AND table_type IN ({})", placeholders.join(", ")));
+            params.extend(
+                cmd.table_types
+                    .into_iter()
+                    .map(|t| AnyValue::StringOwned(t.into())),
+            );
+        }
+        query.push_str(" ORDER BY table_name");
+        let lf = in_catalog(self.execute(&query, &params)?, cmd.catalog);
+        if !cmd.include_schema {
+            return Ok(lf);
+        }
+
+        let mut df = lf.collect()?;
+        let mut ctx = self.ctx.lock().unwrap();
+        let schemas = df
+            .column("table_name")?
+            .str()?
+            .into_no_null_iter()
+            .map(|name| {
+                let mut lf = ctx.table_map[name].clone();
+                let schema = ctx.get_frame_schema(&mut lf)?;
+                Ok(ipc_schema(&schema))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        df.with_column(BinaryChunked::from_slice("table_schema".into(), &schemas))?;
+        Ok(df.lazy())
+    }
+
+    /// Plan a command, and return the schema of its result with the ticket to fetch it.
+    fn flight_info(&self, descriptor: FlightDescriptor, cmd: &Any) -> Result<FlightInfo, Status> {
+        let mut lf = self.plan(cmd)?;
+        let schema = lf.collect_schema().map_err(to_status)?;
+        let ticket = TicketStatementQuery {
+            statement_handle: self.insert_handle(&self.tickets, lf),
+        };
+        Ok(FlightInfo {
+            schema: ipc_schema(&schema),
+            flight_descriptor: Some(descriptor),
+            endpoint: vec![FlightEndpoint {
+                ticket: Some(Ticket {
+                    ticket: Any::pack("TicketStatementQuery", &ticket).encode_to_vec(),
+                }),
+                location: vec![],
+            }],
+            total_records: -1,
+            total_bytes: -1,
+        })
+    }
+}
+
+#[async_trait]
+impl FlightService for FlightSqlService {
+    type HandshakeStream = FlightStream<HandshakeResponse>;
+    type ListFlightsStream = FlightStream<FlightInfo>;
+    type DoGetStream = FlightStream<FlightData>;
+    type DoPutStream = FlightStream<PutResult>;
+    type DoExchangeStream = FlightStream<FlightData>;
+    type DoActionStream = FlightStream<FlightResult>;
+    type ListActionsStream = FlightStream<ActionType>;
+
+    async fn handshake(
+        &self,
+        request: Request<Streaming<HandshakeRequest>>,
+    ) -> Result<Response<Self::HandshakeStream>, Status> {
+        let Some(authenticator) = &self.authenticator else {
+            return Err(Status::unimplemented(
+                "the service doesn't authenticate its clients",
+            ));
+        };
+        let metadata = request.metadata().clone();
+        let handshake = request
+            .into_inner()
+            .next()
+            .await
+            .ok_or_else(|| Status::invalid_argument("missing handshake request"))??;
+        let response = HandshakeResponse {
+            protocol_version: handshake.protocol_version,
+            payload: authenticator.handshake(&metadata, &handshake.payload)?,
+        };
+        let responses: Self::HandshakeStream = Box::pin(stream::iter([Ok(response)]));
+        Ok(Response::new(responses))
+    }
+
+    async fn list_flights(
+        &self,
+        request: Request<Criteria>,
+    ) -> Result<Response<Self::ListFlightsStream>, Status> {
+        self.authorize(&request)?;
+        Err(Status::unimplemented(
+            "use the Flight SQL metadata commands to list tables",
+        ))
+    }
+
+    async fn get_flight_info(
+        &self,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        self.authorize(&request)?;
+        let descriptor = request.into_inner();
+        let cmd = Any::decode(descriptor.cmd.as_slice()).map_err(invalid_message)?;
+        let info = self
+            .blocking(move |service| service.flight_info(descriptor, &cmd))
+            .await?;
+        Ok(Response::new(info))
+    }
+
+    async fn get_schema(
+        &self,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<SchemaResult>, Status> {
+        self.authorize(&request)?;
+        let cmd = Any::decode(request.into_inner().cmd.as_slice()).map_err(invalid_message)?;
+        let schema = self
+            .blocking(move |service| service.plan(&cmd)?.collect_schema().map_err(to_status))
+            .await?;
+        Ok(Response::new(SchemaResult {
+            schema: ipc_schema(&schema),
+        }))
+    }
+
+    async fn do_get(
+        &self,
+        request: Request<Ticket>,
+    ) -> Result<Response<Self::DoGetStream>, Status> {
+        self.authorize(&request)?;
+        let ticket =
+            Any::decode(request.into_inner().ticket.as_slice()).map_err(invalid_message)?;
+        if !ticket.is("TicketStatementQuery") {
+            return Err(Status::invalid_argument("invalid ticket"));
+        }
+        let ticket: TicketStatementQuery = ticket.unpack()?;
+        let handle = decode_handle(&ticket.statement_handle)?;
+        let lf = self
+            .tickets
+            .lock()
+            .unwrap()
+            .remove(handle, self.handle_ttl)
+            .ok_or_else(|| Status::not_found("unknown, expired or already redeemed ticket"))?;
+        let stream = self
+            .blocking(move |_| stream_query(lf).map_err(to_status))
+            .await?;
+        Ok(Response::new(stream))
+    }
+
+    async fn do_put(
+        &self,
+        request: Request<Streaming<FlightData>>,
+    ) -> Result<Response<Self::DoPutStream>, Status> {
+        self.authorize(&request)?;
+        Err(Status::unimplemented(
+            "updates and statement parameters are not supported",
+        ))
+    }
+
+    async fn do_exchange(
+        &self,
+        request: Request<Streaming<FlightData>>,
+    ) -> Result<Response<Self::DoExchangeStream>, Status> {
+        self.authorize(&request)?;
+        Err(Status::unimplemented("DoExchange is not supported"))
+    }
+
+    async fn do_action(
+        &self,
+        request: Request<Action>,
+    ) -> Result<Response<Self::DoActionStream>, Status> {
+        self.authorize(&request)?;
+        let action = request.into_inner();
+        let cmd = Any::decode(action.body.as_slice()).map_err(invalid_message)?;
+        let body = match action.r#type.as_str() {
+            CREATE_PREPARED_STATEMENT => {
+                let request: ActionCreatePreparedStatementRequest = cmd.unpack()?;
+                let (lf, schema) = self
+                    .blocking(move |service| {
+                        let mut lf = service.execute(&request.query, &[]).map_err(to_status)?;
+                        let schema = lf.collect_schema().map_err(to_status)?;
+                        Ok((lf, schema))
+                    })
+                    .await?;
+                let result = ActionCreatePreparedStatementResult {
+                    prepared_statement_handle: self.insert_handle(&self.prepared_statements, lf),
+                    dataset_schema: ipc_schema(&schema),
+                    parameter_schema: vec![],
+                };
+                Any::pack("ActionCreatePreparedStatementResult", &result).encode_to_vec()
+            },
+            CLOSE_PREPARED_STATEMENT => {
+                let request: ActionClosePreparedStatementRequest = cmd.unpack()?;
+                let handle = decode_handle(&request.prepared_statement_handle)?;
+                self.prepared_statements
+                    .lock()
+                    .unwrap()
+                    .remove(handle, self.handle_ttl);
+                vec![]
+            },
+            other => {
+                return Err(Status::invalid_argument(format!(
+                    "unknown action type '{other}'"
+                )));
+            },
+        };
+        let results: Self::DoActionStream = Box::pin(stream::iter([Ok(FlightResult { body })]));
+        Ok(Response::new(results))
+    }
+
+    async fn list_actions(
+        &self,
+        request: Request<Empty>,
+    ) -> Result<Response<Self::ListActionsStream>, Status> {
+        self.authorize(&request)?;
+        let actions = [
+            (
+                CREATE_PREPARED_STATEMENT,
+                "Create a prepared statement from a query",
+            ),
+            (CLOSE_PREPARED_STATEMENT, "Close a prepared statement"),
+        ]
+        .map(|(r#type, description)| {
+            Ok(ActionType {
+                r#type: r#type.into(),
+                description: description.into(),
+            })
+        });
+        let actions: Self::ListActionsStream = Box::pin(stream::iter(actions));
+        Ok(Response::new(actions))
+    }
+}
+
+/// Sends the morsels of a query result to the client, instead of passing them on.
+/// The plans stored under the handles given to the clients, with the time they were last used.
+///
+/// Handles are given out in increasing order, so the first plans are the oldest. These are
+/// dropped when they expire or to make room for a new plan, so clients that never redeem or
+/// close their handles can't grow the service without bound.
+#[derive(Default)]
+struct Handles {
+    plans: BTreeMap<u64, (Instant, LazyFrame)>,
+}
+
+impl Handles {
+    fn insert(&mut self, handle: u64, lf: LazyFrame, max_open: usize, ttl: Duration) {
+        let now = Instant::now();
+        loop {
+            let full = self.plans.len() >= max_open;
+            let Some(oldest) = self.plans.first_entry() else {
+                break;
+            };
+            if !full && now.duration_since(oldest.get().0) < ttl {
+                break;
+            }
+            oldest.remove();
+        }
+        self.plans.insert(handle, (now, lf));
+    }
+
+    fn get(&mut self, handle: u64, ttl: Duration) -> Option<LazyFrame> {
+        let (last_used, lf) = self.plans.get_mut(&handle)?;
+        if last_used.elapsed() >= ttl {
+            self.plans.remove(&handle);
+            return None;
+        }
+        *last_used = Instant::now();
+        Some(lf.clone())
+    }
+
+    fn remove(&mut self, handle: u64, ttl: Duration) -> Option<LazyFrame> {
+        let (last_used, lf) = self.plans.remove(&handle)?;
+        (last_used.elapsed() < ttl).then_some(lf)
+    }
+}
+
+struct SendMorsels {
+    tx: mpsc::Sender<PolarsResult<DataFrame>>,
+}
+
+#[async_trait]
+impl StreamingOperator for SendMorsels {
+    async fn process(&mut self, df: DataFrame) -> PolarsResult<DataFrame> {
+        let empty = df.clear();
+        // fails once the client has gone, which stops the query
+        self.tx
+            .send(Ok(df))
+            .await
+            .map_err(|_| polars_err!(ComputeError: "the Flight SQL client disconnected"))?;
+        Ok(empty)
+    }
+}
+
+/// Run the query on the streaming engine, and encode the morsels of its result as Flight data.
+fn stream_query(mut lf: LazyFrame) -> PolarsResult<FlightStream<FlightData>> {
+    // Flight SQL clients don't generally support the string view types.
+    let schema = lf.collect_schema()?.to_arrow(CompatLevel::oldest());
+    let fields = default_ipc_fields(schema.iter_values());
+    let schema_message = FlightData {
+        data_header: schema_to_bytes(&schema, &fields, None),
+        ..Default::default()
+    };
+
+    let (tx, rx) = mpsc::channel(MORSEL_BUFFER_SIZE);
+    let name = format!(
+        "flight_sql_query_{}",
+        NEXT_QUERY_ID.fetch_add(1, Ordering::Relaxed)
+    );
+    let operator_tx = tx.clone();
+    register_streaming_operator(
+        &name,
+        Arc::new(move || {
+            Box::new(SendMorsels {
+                tx: operator_tx.clone(),
+            }) as Box<dyn StreamingOperator>
+        }),
+    );
+    std::thread::spawn(move || {
+        let result = lf
+            .map_streaming_operator(&name, None)
+            .collect_with_engine(Engine::Streaming);
+        // drops the senders of the operator, which ends the stream
+        unregister_streaming_operator(&name);
+        if let Err(err) = result {
+            let _ = tx.blocking_send(Err(err));
+        }
+    });
+
+    let encoder = FlightEncoder {
+        fields,
+        dictionary_tracker: DictionaryTracker {
+            dictionaries: Default::default(),
+            cannot_replace: false,
+        },
+    };
+    let batches = stream::unfold((rx, encoder), |(mut rx, mut encoder)| async move {
+        let messages = match rx.recv().await?.and_then(|df| encoder.encode(df)) {
+            Ok(messages) => messages.into_iter().map(Ok).collect(),
+            Err(err) => vec![Err(to_status(err))],
+        };
+        Some((stream::iter(messages), (rx, encoder)))
+    })
+    .flatten();
+    Ok(Box::pin(
+        stream::once(async move { Ok(schema_message) }).chain(batches),
+    ))
+}
+
+/// Encodes data frames as Flight data, in the IPC stream format.
+struct FlightEncoder {
+    fields: Vec<IpcField>,
+    dictionary_tracker: DictionaryTracker,
+}
+
+impl FlightEncoder {
+    fn encode(&mut self, mut df: DataFrame) -> PolarsResult<Vec<FlightData>> {
+        let options = WriteOptions { compression: None };
+        let mut messages = vec![];
+        df.align_chunks_par();
+        for batch in df.iter_chunks(CompatLevel::oldest(), false) {
+            let mut encoded = vec![];
+            for (field, array) in self.fields.iter().zip(batch.arrays()) {
+                encode_new_dictionaries(
+                    field,
+                    array.as_ref(),
+                    &options,
+                    &mut self.dictionary_tracker,
+                    &mut encoded,
+                )?;
+            }
+            let mut record_batch = EncodedData::default();
+            encode_record_batch(&batch, &options, &mut record_batch);
+            encoded.push(record_batch);
+            messages.extend(encoded.into_iter().map(|data| FlightData {
+                data_header: data.ipc_message,
+                data_body: data.arrow_data,
+                ..Default::default()
+            }));
+        }
+        Ok(messages)
+    }
+}
+
+/// The schema as an encapsulated IPC message, as in a [`FlightInfo`].
+fn ipc_schema(schema: &Schema) -> Vec<u8> {
+    let schema = schema.to_arrow(CompatLevel::oldest());
+    let fields = default_ipc_fields(schema.iter_values());
+    let message = schema_to_bytes(&schema, &fields, None);
+    let padding = (8 - message.len() % 8) % 8;
+    let mut out = Vec::with_capacity(8 + message.len() + padding);
+    out.extend_from_slice(&[0xff; 4]);
+    out.extend_from_slice(&((message.len() + padding) as i32).to_le_bytes());
+    out.extend_from_slice(&message);
+    out.resize(out.len() + padding, 0);
+    out
+}
+
+/// Keep the rows of a metadata frame only if they are in the requested catalog; as tables are
+/// not grouped into catalogs that means none were requested.
+fn in_catalog(lf: LazyFrame, catalog: Option<String>) -> LazyFrame {
+    match catalog {
+        Some(catalog) if !catalog.is_empty() => lf.limit(0),
+        _ => lf,
+    }
+}
+
+fn pattern(pattern: Option<String>) -> AnyValue<'static> {
+    AnyValue::StringOwned(pattern.unwrap_or_else(|| "%".into()).into())
+}
+
+fn decode_handle(handle: &[u8]) -> Result<u64, Status> {
+    let handle = <[u8; 8]>::try_from(handle)
+        .map_err(|_| Status::invalid_argument("invalid statement handle"))?;
+    Ok(u64::from_le_bytes(handle))
+}
+
+fn to_status(err: PolarsError) -> Status {
+    match err {
+        PolarsError::SQLInterface(_)
+        | PolarsError::SQLSyntax(_)
+        | PolarsError::ColumnNotFound(_)
+        | PolarsError::SchemaMismatch(_) => Status::invalid_argument(err.to_string()),
+        _ => Status::internal(err.to_string()),
+    }
+}
+
+fn invalid_message(err: prost::DecodeError) -> Status {
+    Status::invalid_argument(format!("invalid Flight SQL message: {err}"))
+}
+
+const TYPE_URL_PREFIX: &str = "type.googleapis.com/arrow.flight.protocol.sql.";
+const CREATE_PREPARED_STATEMENT: &str = "CreatePreparedStatement";
+const CLOSE_PREPARED_STATEMENT: &str = "ClosePreparedStatement";
+
+// The messages of the Flight SQL protocol that the service uses, as defined in `FlightSql.proto`.
+
+/// A `google.protobuf.Any`, which wraps every Flight SQL command.
+#[derive(Clone, PartialEq, Message)]
+struct Any {
+    #[prost(string, tag = "1")]
+    type_url: String,
+    #[prost(bytes = "vec", tag = "2")]
+    value: Vec<u8>,
+}
+
+impl Any {
+    fn pack(name: &str, message: &impl Message) -> Self {
+        Self {
+            type_url: format!("{TYPE_URL_PREFIX}{name}"),
+            value: message.encode_to_vec(),
+        }
+    }
+
+    /// The name of the Flight SQL message, if it is one.
+    fn name(&self) -> Option<&str> {
+        self.type_url.strip_prefix(TYPE_URL_PREFIX)
+    }
+
+    fn is(&self, name: &str) -> bool {
+        self.name() == Some(name)
+    }
+
+    fn unpack<M: Message + Default>(&self) -> Result<M, Status> {
+        M::decode(self.value.as_slice()).map_err(invalid_message)
+    }
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct CommandStatementQuery {
+    #[prost(string, tag = "1")]
+    query: String,
+    #[prost(bytes = "vec", optional, tag = "2")]
+    transaction_id: Option<Vec<u8>>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct TicketStatementQuery {
+    #[prost(bytes = "vec", tag = "1")]
+    statement_handle: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct CommandPreparedStatementQuery {
+    #[prost(bytes = "vec", tag = "1")]
+    prepared_statement_handle: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct ActionCreatePreparedStatementRequest {
+    #[prost(string, tag = "1")]
+    query: String,
+    #[prost(bytes = "vec", optional, tag = "2")]
+    transaction_id: Option<Vec<u8>>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct ActionCreatePreparedStatementResult {
+    #[prost(bytes = "vec", tag = "1")]
+    prepared_statement_handle: Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    dataset_schema: Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    parameter_schema: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct ActionClosePreparedStatementRequest {
+    #[prost(bytes = "vec", tag = "1")]
+    prepared_statement_handle: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct CommandGetDbSchemas {
+    #[prost(string, optional, tag = "1")]
+    catalog: Option<String>,
+    #[prost(string, optional, tag = "2")]
+    db_schema_filter_pattern: Option<String>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct CommandGetTables {
+    #[prost(string, optional, tag = "1")]
+    catalog: Option<String>,
+    #[prost(string, optional, tag = "2")]
+    db_schema_filter_pattern: Option<String>,
+    #[prost(string, optional, tag = "3")]
+    table_name_filter_pattern: Option<String>,
+    #[prost(string, repeated, tag = "4")]
+    table_types: Vec<String>,
+    #[prost(bool, tag = "5")]
+    include_schema: bool,
+}
@@ -1,6 +1,7 @@
 //! This module defines a FunctionRegistry for supported SQL functions and UDFs.
 
-use polars_error::{PolarsResult, polars_bail};
+use polars_core::prelude::PlHashMap;
+use polars_error::PolarsResult;
 use polars_plan::prelude::udf::UserDefinedFunction;
 pub use polars_plan::prelude::{Context, FunctionOptions};
 /// A registry that holds user defined functions.
@@ -13,18 +14,35 @@ pub trait FunctionRegistry: Send + Sync {
     fn contains(&self, name: &str) -> bool;
 }
 
-/// A default registry that does not support registering or calling functions.
-pub struct DefaultFunctionRegistry {}
+/// A default registry that holds the functions registered on a
+/// [`SQLContext`](crate::SQLContext).
+#[derive(Default)]
+pub struct DefaultFunctionRegistry {
+    functions: PlHashMap<String, UserDefinedFunction>,
+}
 
 impl FunctionRegistry for DefaultFunctionRegistry {
-    fn register(&mut self, _name: &str, _fun: UserDefinedFunction) -> PolarsResult<()> {
-        polars_bail!(ComputeError: "'register' not implemented on DefaultFunctionRegistry'")
+    fn register(&mut self, name: &str, fun: UserDefinedFunction) -> PolarsResult<()> {
+        self.functions.insert(name.to_string(), fun);
+        Ok(())
     }
 
-    fn get_udf(&self, _name: &str) -> PolarsResult<Option<UserDefinedFunction>> {
-        polars_bail!(ComputeError: "'get_udf' not implemented on DefaultFunctionRegistry'")
+    fn get_udf(&self, name: &str) -> PolarsResult<Option<UserDefinedFunction>> {
+        Ok(self.functions.get(name).cloned())
     }
-    fn contains(&self, _name: &str) -> bool {
-        false
+    fn contains(&self, name: &str) -> bool {
+        self.functions.contains_key(name)
     }
 }
+
+/// Whether a scalar function always returns the same output for the same input.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Volatility {
+    /// The output only depends on the input, so the function may be called on any batch of
+    /// rows, such as the morsels of the streaming engine, and in parallel.
+    #[default]
+    Immutable,
+    /// The output may also depend on state, such as a random number generator, so the function
+    /// is always called once on the whole column.
+    Volatile,
+}
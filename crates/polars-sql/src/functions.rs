@@ -2,7 +2,8 @@ use std::ops::Sub;
 
 use polars_core::chunked_array::ops::{SortMultipleOptions, SortOptions};
 use polars_core::prelude::{
-    DataType, PolarsResult, QuantileMethod, Schema, TimeUnit, polars_bail, polars_err,
+    DataType, PolarsResult, QuantileMethod, Schema, TimeUnit, polars_bail, polars_ensure,
+    polars_err,
 };
 use polars_lazy::dsl::Expr;
 #[cfg(feature = "list_eval")]
@@ -22,6 +23,7 @@ use sqlparser::tokenizer::Span;
 
 use crate::SQLContext;
 use crate::sql_expr::{adjust_one_indexed_param, parse_extract_date_part, parse_sql_expr};
+use crate::window::{SQLWindow, WindowAggregate};
 
 pub(crate) struct SQLFunctionVisitor<'a> {
     pub(crate) func: &'a SQLFunction,
@@ -582,6 +584,42 @@ pub(crate) enum PolarsSQLFunctions {
     /// ```
     Variance,
 
+    // ----
+    // Window functions
+    // ----
+    /// SQL 'dense_rank' function
+    /// Returns the rank of the row in its partition, without gaps between peers.
+    /// ```sql
+    /// SELECT DENSE_RANK() OVER (PARTITION BY column_1 ORDER BY column_2) FROM df;
+    /// ```
+    DenseRank,
+    /// SQL 'lag' function
+    /// Returns the value of the row that is the given number of rows (default 1) before the
+    /// current row in its partition, or the given default if there is no such row.
+    /// ```sql
+    /// SELECT LAG(column_1, 1, 0) OVER (PARTITION BY column_2 ORDER BY column_3) FROM df;
+    /// ```
+    Lag,
+    /// SQL 'lead' function
+    /// Returns the value of the row that is the given number of rows (default 1) after the
+    /// current row in its partition, or the given default if there is no such row.
+    /// ```sql
+    /// SELECT LEAD(column_1, 1, 0) OVER (PARTITION BY column_2 ORDER BY column_3) FROM df;
+    /// ```
+    Lead,
+    /// SQL 'rank' function
+    /// Returns the rank of the row in its partition, with gaps after peers.
+    /// ```sql
+    /// SELECT RANK() OVER (PARTITION BY column_1 ORDER BY column_2) FROM df;
+    /// ```
+    Rank,
+    /// SQL 'row_number' function
+    /// Returns the number of the row in its partition, starting at 1.
+    /// ```sql
+    /// SELECT ROW_NUMBER() OVER (PARTITION BY column_1 ORDER BY column_2) FROM df;
+    /// ```
+    RowNumber,
+
     // ----
     // Array functions
     // ----
@@ -715,6 +753,7 @@ impl PolarsSQLFunctions {
             "date",
             "date_part",
             "degrees",
+            "dense_rank",
             "ends_with",
             "exp",
             "first",
@@ -723,7 +762,9 @@ impl PolarsSQLFunctions {
             "if",
             "ifnull",
             "initcap",
+            "lag",
             "last",
+            "lead",
             "least",
             "left",
             "length",
@@ -747,11 +788,13 @@ impl PolarsSQLFunctions {
             "quantile_cont",
             "quantile_disc",
             "radians",
+            "rank",
             "regexp_like",
             "replace",
             "reverse",
             "right",
             "round",
+            "row_number",
             "rtrim",
             "sign",
             "sin",
@@ -780,7 +823,12 @@ impl PolarsSQLFunctions {
 
 impl PolarsSQLFunctions {
     fn try_from_sql(function: &'_ SQLFunction, ctx: &'_ SQLContext) -> PolarsResult<Self> {
-        let function_name = function.name.0[0].value.to_lowercase();
+        let mut function_name = function.name.0[0].value.to_lowercase();
+        if !ctx.function_registry.contains(&function_name) {
+            if let Some(alias) = ctx.dialect.function_alias(&function_name) {
+                function_name = alias.to_string();
+            }
+        }
         Ok(match function_name.as_str() {
             // ----
             // Bitwise functions
@@ -895,6 +943,15 @@ impl PolarsSQLFunctions {
             "sum" => Self::Sum,
             "var" | "variance" | "var_samp" => Self::Variance,
 
+            // ----
+            // Window functions
+            // ----
+            "dense_rank" => Self::DenseRank,
+            "lag" => Self::Lag,
+            "lead" => Self::Lead,
+            "rank" => Self::Rank,
+            "row_number" => Self::RowNumber,
+
             // ----
             // Array functions
             // ----
@@ -1360,11 +1417,11 @@ impl SQLFunctionVisitor<'_> {
             // ----
             // Aggregate functions
             // ----
-            Avg => self.visit_unary(Expr::mean),
+            Avg => self.visit_unary_with_opt_frame(Expr::mean, WindowAggregate::Avg),
             Count => self.visit_count(),
             First => self.visit_unary(Expr::first),
             Last => self.visit_unary(Expr::last),
-            Max => self.visit_unary_with_opt_cumulative(Expr::max, Expr::cum_max),
+            Max => self.visit_unary_with_opt_frame(Expr::max, WindowAggregate::Max),
             Median => self.visit_unary(Expr::median),
             QuantileCont => {
                 let args = extract_args(function)?;
@@ -1418,11 +1475,20 @@ impl SQLFunctionVisitor<'_> {
                     _ => polars_bail!(SQLSyntax: "QUANTILE_DISC expects 2 arguments (found {})", args.len()),
                 }
             },
-            Min => self.visit_unary_with_opt_cumulative(Expr::min, Expr::cum_min),
+            Min => self.visit_unary_with_opt_frame(Expr::min, WindowAggregate::Min),
             StdDev => self.visit_unary(|e| e.std(1)),
-            Sum => self.visit_unary_with_opt_cumulative(Expr::sum, Expr::cum_sum),
+            Sum => self.visit_unary_with_opt_frame(Expr::sum, WindowAggregate::Sum),
             Variance => self.visit_unary(|e| e.var(1)),
 
+            // ----
+            // Window functions
+            // ----
+            DenseRank => self.visit_window_nullary(|w| w.rank(true)),
+            Lag => self.visit_lag_lead(false),
+            Lead => self.visit_lag_lead(true),
+            Rank => self.visit_window_nullary(|w| w.rank(false)),
+            RowNumber => self.visit_window_nullary(SQLWindow::row_number),
+
             // ----
             // Array functions
             // ----
@@ -1506,50 +1572,26 @@ impl SQLFunctionVisitor<'_> {
             })
             .collect::<PolarsResult<Vec<_>>>()?;
 
-        self.ctx
+        let udf = self
+            .ctx
             .function_registry
             .get_udf(func_name)?
-            .ok_or_else(|| polars_err!(SQLInterface: "UDF {} not found", func_name))?
-            .call(args)
-    }
-
-    /// Window specs without partition bys are essentially cumulative functions
-    /// e.g. SUM(a) OVER (ORDER BY b DESC) -> CUMSUM(a, false)
-    fn apply_cumulative_window(
-        &mut self,
-        f: impl Fn(Expr) -> Expr,
-        cumulative_f: impl Fn(Expr, bool) -> Expr,
-        WindowSpec {
-            partition_by,
-            order_by,
-            ..
-        }: &WindowSpec,
-    ) -> PolarsResult<Expr> {
-        if !order_by.is_empty() && partition_by.is_empty() {
-            let (order_by, desc): (Vec<Expr>, Vec<bool>) = order_by
-                .iter()
-                .map(|o| {
-                    let expr = parse_sql_expr(&o.expr, self.ctx, self.active_schema)?;
-                    Ok(match o.asc {
-                        Some(b) => (expr, !b),
-                        None => (expr, false),
-                    })
-                })
-                .collect::<PolarsResult<Vec<_>>>()?
-                .into_iter()
-                .unzip();
-            self.visit_unary_no_window(|e| {
-                cumulative_f(
-                    e.sort_by(
-                        &order_by,
-                        SortMultipleOptions::default().with_order_descending_multi(desc.clone()),
-                    ),
-                    false,
-                )
+            .ok_or_else(|| polars_err!(SQLInterface: "UDF {} not found", func_name))?;
+        polars_ensure!(
+            args.len() == udf.input_fields.len(),
+            SQLSyntax: "{} expects {} arguments (found {})",
+            func_name, udf.input_fields.len(), args.len()
+        );
+        // cast the arguments to the declared types of the function
+        let args = args
+            .into_iter()
+            .zip(&udf.input_fields)
+            .map(|(arg, field)| match field.dtype() {
+                DataType::Unknown(_) => arg,
+                dtype => arg.strict_cast(dtype.clone()),
             })
-        } else {
-            self.visit_unary(f)
-        }
+            .collect();
+        Ok(udf.call_unchecked(args))
     }
 
     fn visit_unary(&mut self, f: impl Fn(Expr) -> Expr) -> PolarsResult<Expr> {
@@ -1572,26 +1614,19 @@ impl SQLFunctionVisitor<'_> {
         .and_then(|e| self.apply_window_spec(e, &self.func.over))
     }
 
-    /// Some functions have cumulative equivalents that can be applied to window specs
-    /// e.g. SUM(a) OVER (ORDER BY b DESC) -> CUMSUM(a, false)
-    /// visit_unary_with_cumulative_window will take in a function & a cumulative function
-    /// if there is a cumulative window spec, it will apply the cumulative function,
-    /// otherwise it will apply the function
-    fn visit_unary_with_opt_cumulative(
+    /// Aggregate functions can be computed over the frame of a window spec, e.g.
+    /// SUM(a) OVER (PARTITION BY b ORDER BY c ROWS BETWEEN 2 PRECEDING AND CURRENT ROW)
+    fn visit_unary_with_opt_frame(
         &mut self,
         f: impl Fn(Expr) -> Expr,
-        cumulative_f: impl Fn(Expr, bool) -> Expr,
+        agg: WindowAggregate,
     ) -> PolarsResult<Expr> {
-        match self.func.over.as_ref() {
-            Some(WindowType::WindowSpec(spec)) => {
-                self.apply_cumulative_window(f, cumulative_f, spec)
-            },
-            Some(WindowType::NamedWindow(named_window)) => polars_bail!(
-                SQLInterface: "Named windows are not currently supported; found {:?}",
-                named_window
-            ),
-            _ => self.visit_unary(f),
-        }
+        let func = self.func;
+        let Some(spec) = window_spec(func)? else {
+            return self.visit_unary(f);
+        };
+        let expr = self.visit_unary_no_window(|e| e)?;
+        SQLWindow::try_new(spec, self.ctx, self.active_schema)?.aggregate(expr, agg)
     }
 
     fn visit_unary_no_window(&mut self, f: impl Fn(Expr) -> Expr) -> PolarsResult<Expr> {
@@ -1679,6 +1714,54 @@ impl SQLFunctionVisitor<'_> {
         Ok(f())
     }
 
+    /// Window functions without arguments, e.g. ROW_NUMBER() OVER (ORDER BY a)
+    fn visit_window_nullary(&mut self, f: impl Fn(&SQLWindow) -> Expr) -> PolarsResult<Expr> {
+        let func = self.func;
+        if !extract_args(func)?.is_empty() {
+            return self.not_supported_error();
+        }
+        let Some(spec) = window_spec(func)? else {
+            polars_bail!(SQLSyntax: "{}() requires an OVER clause", func.name)
+        };
+        Ok(f(&SQLWindow::try_new(spec, self.ctx, self.active_schema)?))
+    }
+
+    fn visit_lag_lead(&mut self, lead: bool) -> PolarsResult<Expr> {
+        let func = self.func;
+        let Some(spec) = window_spec(func)? else {
+            polars_bail!(SQLSyntax: "{}() requires an OVER clause", func.name)
+        };
+        let args = extract_args(func)?;
+        let (expr, offset, default) = match args.as_slice() {
+            [FunctionArgExpr::Expr(expr)] => (expr, None, None),
+            [FunctionArgExpr::Expr(expr), FunctionArgExpr::Expr(offset)] => {
+                (expr, Some(offset), None)
+            },
+            [
+                FunctionArgExpr::Expr(expr),
+                FunctionArgExpr::Expr(offset),
+                FunctionArgExpr::Expr(default),
+            ] => (expr, Some(offset), Some(default)),
+            _ => return self.not_supported_error(),
+        };
+        let expr = parse_sql_expr(expr, self.ctx, self.active_schema)?;
+        let n = match offset {
+            None => 1,
+            Some(offset) => match parse_sql_expr(offset, self.ctx, self.active_schema)? {
+                Expr::Literal(LiteralValue::Dyn(DynLiteralValue::Int(n))) if n >= 0 => n as i64,
+                _ => polars_bail!(
+                    SQLSyntax: "{} offset must be a non-negative integer (found {})",
+                    func.name, offset
+                ),
+            },
+        };
+        let default = default
+            .map(|d| parse_sql_expr(d, self.ctx, self.active_schema))
+            .transpose()?;
+        let window = SQLWindow::try_new(spec, self.ctx, self.active_schema)?;
+        Ok(window.shift(expr, if lead { -n } else { n }, default))
+    }
+
     fn visit_arr_agg(&mut self) -> PolarsResult<Expr> {
         let (args, is_distinct, clauses) = extract_args_and_clauses(self.func)?;
         match args.as_slice() {
@@ -1753,12 +1836,18 @@ impl SQLFunctionVisitor<'_> {
         let (args, is_distinct) = extract_args_distinct(self.func)?;
         match (is_distinct, args.as_slice()) {
             // count(*), count()
-            (false, [FunctionArgExpr::Wildcard] | []) => Ok(len()),
+            (false, [FunctionArgExpr::Wildcard] | []) => match window_spec(self.func)? {
+                Some(spec) => SQLWindow::try_new(spec, self.ctx, self.active_schema)?.count_rows(),
+                None => Ok(len()),
+            },
             // count(column_name)
             (false, [FunctionArgExpr::Expr(sql_expr)]) => {
                 let expr = parse_sql_expr(sql_expr, self.ctx, self.active_schema)?;
-                let expr = self.apply_window_spec(expr, &self.func.over)?;
-                Ok(expr.count())
+                match window_spec(self.func)? {
+                    Some(spec) => SQLWindow::try_new(spec, self.ctx, self.active_schema)?
+                        .aggregate(expr, WindowAggregate::Count),
+                    None => Ok(expr.count()),
+                }
             },
             // count(distinct column_name)
             (true, [FunctionArgExpr::Expr(sql_expr)]) => {
@@ -1799,6 +1888,13 @@ impl SQLFunctionVisitor<'_> {
     ) -> PolarsResult<Expr> {
         Ok(match &window_type {
             Some(WindowType::WindowSpec(window_spec)) => {
+                if window_spec.window_frame.is_some() {
+                    polars_bail!(
+                        SQLInterface:
+                        "window frames are only supported by AVG, COUNT, MAX, MIN and SUM ({})",
+                        self.func
+                    )
+                }
                 if window_spec.partition_by.is_empty() {
                     let exprs = window_spec
                         .order_by
@@ -1838,6 +1934,17 @@ impl SQLFunctionVisitor<'_> {
     }
 }
 
+fn window_spec(func: &SQLFunction) -> PolarsResult<Option<&WindowSpec>> {
+    match &func.over {
+        Some(WindowType::WindowSpec(spec)) => Ok(Some(spec)),
+        Some(WindowType::NamedWindow(named_window)) => polars_bail!(
+            SQLInterface: "Named windows are not currently supported; found {:?}",
+            named_window
+        ),
+        None => Ok(None),
+    }
+}
+
 fn extract_args(func: &SQLFunction) -> PolarsResult<Vec<&FunctionArgExpr>> {
     let (args, _, _) = _extract_func_args(func, false, false)?;
     Ok(args)
@@ -2,12 +2,17 @@
 //! This crate provides a SQL interface for Polars DataFrames
 #![deny(missing_docs)]
 mod context;
+mod dialect;
+#[cfg(feature = "flight_sql")]
+pub mod flight_sql;
 pub mod function_registry;
 mod functions;
 pub mod keywords;
 mod sql_expr;
 mod table_functions;
 mod types;
+mod window;
 
 pub use context::SQLContext;
+pub use dialect::SQLDialect;
 pub use sql_expr::sql_expr;
@@ -12,7 +12,7 @@ use std::ops::Div;
 use polars_core::prelude::*;
 use polars_lazy::prelude::*;
 use polars_plan::plans::DynLiteralValue;
-use polars_plan::prelude::typed_lit;
+use polars_plan::prelude::{Context, Operator, binary_expr, typed_lit};
 use polars_time::Duration;
 use rand::distributions::Alphanumeric;
 use rand::{Rng, thread_rng};
@@ -452,6 +452,21 @@ impl SQLExprVisitor<'_> {
         Ok(expr)
     }
 
+    /// Divide `lhs` by `rhs`, truncating the quotient of integers towards zero.
+    ///
+    /// Other operands are divided as usual, as are operands of which the type can't be resolved.
+    fn truncating_divide(&self, lhs: Expr, rhs: Expr) -> Expr {
+        let empty_schema = Schema::default();
+        let schema = self.active_schema.unwrap_or(&empty_schema);
+        let quotient = lhs.clone() / rhs.clone();
+        match quotient.to_field(schema, Context::Default) {
+            Ok(field) if field.dtype().is_integer() => {
+                binary_expr(lhs, Operator::TrueDivide, rhs).cast(field.dtype().clone())
+            },
+            _ => quotient,
+        }
+    }
+
     /// Visit a SQL binary operator.
     ///
     /// e.g. "column + 1", "column1 <= column2"
@@ -511,8 +526,18 @@ impl SQLExprVisitor<'_> {
             // General operators
             // ----
             SQLBinaryOperator::And => lhs.and(rhs),  // "x AND y"
+            SQLBinaryOperator::Divide if self.ctx.dialect.true_division() => {  // "x / y"
+                binary_expr(lhs, Operator::TrueDivide, rhs)
+            },
+            SQLBinaryOperator::Divide if self.ctx.dialect.truncating_division() => {  // "x / y"
+                self.truncating_divide(lhs, rhs)
+            },
             SQLBinaryOperator::Divide => lhs / rhs,  // "x / y"
             SQLBinaryOperator::DuckIntegerDivide => lhs.floor_div(rhs).cast(DataType::Int64),  // "x // y"
+            SQLBinaryOperator::MyIntegerDivide => {  // "x DIV y"
+                // casting the quotient truncates towards zero, where floor division rounds down
+                binary_expr(lhs, Operator::TrueDivide, rhs).cast(DataType::Int64)
+            },
             SQLBinaryOperator::Eq => lhs.eq(rhs),  // "x = y"
             SQLBinaryOperator::Gt => lhs.gt(rhs),  // "x > y"
             SQLBinaryOperator::GtEq => lhs.gt_eq(rhs),  // "x >= y"
@@ -798,6 +823,10 @@ impl SQLExprVisitor<'_> {
                 lit(hex::decode(x.clone()).unwrap())
             },
             SQLValue::Null => Expr::Literal(LiteralValue::untyped_null()),
+            SQLValue::Placeholder(p) => {
+                let value = self.visit_placeholder(p)?;
+                lit(Scalar::new(value.dtype(), value))
+            },
             SQLValue::Number(s, _) => {
                 // Check for existence of decimal separator dot
                 if s.contains('.') {
@@ -813,7 +842,9 @@ impl SQLExprVisitor<'_> {
                 // patterned the token name after BigQuery (where b'str' really IS a byte string)
                 bitstring_to_bytes_literal(b)?
             },
-            SQLValue::SingleQuotedString(s) => lit(s.clone()),
+            SQLValue::SingleQuotedString(s)
+            | SQLValue::DoubleQuotedString(s)
+            | SQLValue::EscapedStringLiteral(s) => lit(s.clone()),
             other => {
                 polars_bail!(SQLInterface: "value {:?} is not a supported literal type", other)
             },
@@ -837,6 +868,13 @@ impl SQLExprVisitor<'_> {
                 AnyValue::BinaryOwned(hex::decode(x.clone()).unwrap())
             },
             SQLValue::Null => AnyValue::Null,
+            SQLValue::Placeholder(p) => {
+                polars_ensure!(
+                    op.is_none(),
+                    SQLInterface: "unary op {:?} not supported for placeholder {}", op.unwrap(), p
+                );
+                self.visit_placeholder(p)?
+            },
             SQLValue::Number(s, _) => {
                 let negate = match op {
                     Some(UnaryOperator::Minus) => true,
@@ -870,11 +908,24 @@ impl SQLExprVisitor<'_> {
                     },
                 }
             },
-            SQLValue::SingleQuotedString(s) => AnyValue::StringOwned(s.as_str().into()),
+            SQLValue::SingleQuotedString(s)
+            | SQLValue::DoubleQuotedString(s)
+            | SQLValue::EscapedStringLiteral(s) => AnyValue::StringOwned(s.as_str().into()),
             other => polars_bail!(SQLInterface: "value {:?} is not currently supported", other),
         })
     }
 
+    /// The value bound to a numbered placeholder; see [`SQLContext::execute_with_params`].
+    fn visit_placeholder(&self, placeholder: &str) -> PolarsResult<AnyValue<'static>> {
+        let idx = placeholder
+            .strip_prefix('$')
+            .and_then(|n| n.parse::<usize>().ok());
+        match idx.and_then(|idx| self.ctx.params.get(idx.checked_sub(1)?)) {
+            Some(value) => Ok(value.clone()),
+            None => polars_bail!(SQLInterface: "no value was bound to placeholder {}", placeholder),
+        }
+    }
+
     /// Visit a SQL `BETWEEN` expression.
     /// See [sqlparser::ast::Expr::Between] for more details
     fn visit_between(
@@ -6,11 +6,18 @@ use std::str::FromStr;
     feature = "ipc",
     feature = "json"
 ))]
-use polars_core::prelude::polars_ensure;
+use polars_core::prelude::{PlSmallStr, polars_ensure};
 use polars_core::prelude::{PolarsError, PolarsResult, polars_bail};
 #[cfg(feature = "csv")]
 use polars_lazy::prelude::LazyCsvReader;
 use polars_lazy::prelude::LazyFrame;
+#[cfg(any(
+    feature = "csv",
+    feature = "parquet",
+    feature = "ipc",
+    feature = "json"
+))]
+use sqlparser::ast::{BinaryOperator, Expr as SQLExpr, Value as SQLValue};
 use sqlparser::ast::{FunctionArg, FunctionArgExpr};
 
 /// Table functions that are supported by Polars
@@ -19,12 +26,14 @@ pub(crate) enum PolarsTableFunctions {
     /// SQL 'read_csv' function
     /// ```sql
     /// SELECT * FROM read_csv('path/to/file.csv')
+    /// SELECT * FROM read_csv('path/to/*.csv', has_header => false, separator => ';')
     /// ```
     #[cfg(feature = "csv")]
     ReadCsv,
     /// SQL 'read_parquet' function
     /// ```sql
     /// SELECT * FROM read_parquet('path/to/file.parquet')
+    /// SELECT * FROM read_parquet('s3://bucket/*.parquet', hive_partitioning => true)
     /// ```
     #[cfg(feature = "parquet")]
     ReadParquet,
@@ -34,9 +43,10 @@ pub(crate) enum PolarsTableFunctions {
     /// ```
     #[cfg(feature = "ipc")]
     ReadIpc,
-    /// SQL 'read_json' function. *Only ndjson is currently supported.*
+    /// SQL 'read_json' and 'read_ndjson' functions. *Only ndjson is currently supported.*
     /// ```sql
     /// SELECT * FROM read_json('path/to/file.json')
+    /// SELECT * FROM read_ndjson('path/to/file.ndjson', n_rows => 100)
     /// ```
     #[cfg(feature = "json")]
     ReadJson,
@@ -55,7 +65,7 @@ impl FromStr for PolarsTableFunctions {
             #[cfg(feature = "ipc")]
             "read_ipc" => PolarsTableFunctions::ReadIpc,
             #[cfg(feature = "json")]
-            "read_json" => PolarsTableFunctions::ReadJson,
+            "read_json" | "read_ndjson" => PolarsTableFunctions::ReadJson,
             _ => polars_bail!(SQLInterface: "'{}' is not a supported table function", s),
         })
     }
@@ -79,59 +89,260 @@ impl PolarsTableFunctions {
 
     #[cfg(feature = "csv")]
     fn read_csv(&self, args: &[FunctionArg]) -> PolarsResult<(String, LazyFrame)> {
-        polars_ensure!(args.len() == 1, SQLSyntax: "`read_csv` expects a single file path; found {:?} arguments", args.len());
-
+        use polars_io::csv::read::NullValues;
         use polars_lazy::frame::LazyFileListReader;
-        let path = self.get_file_path_from_arg(&args[0])?;
-        let lf = LazyCsvReader::new(&path)
-            .with_try_parse_dates(true)
+
+        let mut args = ScanArgs::try_new("read_csv", args)?;
+        let mut reader = LazyCsvReader::new(&args.path)
+            .with_try_parse_dates(args.bool("try_parse_dates")?.unwrap_or(true))
             .with_missing_is_null(true)
-            .finish()?;
-        Ok((path, lf))
+            .with_n_rows(args.usize("n_rows")?)
+            .with_comment_prefix(args.string("comment_prefix")?)
+            .with_null_values(
+                args.string("null_values")?
+                    .map(NullValues::AllColumnsSingle),
+            )
+            .with_include_file_paths(args.string("include_file_paths")?);
+        if let Some(has_header) = args.bool("has_header")? {
+            reader = reader.with_has_header(has_header);
+        }
+        if let Some(separator) = args.byte("separator")? {
+            reader = reader.with_separator(separator);
+        }
+        if let Some(quote_char) = args.byte("quote_char")? {
+            reader = reader.with_quote_char(Some(quote_char));
+        }
+        if let Some(skip_rows) = args.usize("skip_rows")? {
+            reader = reader.with_skip_rows(skip_rows);
+        }
+        if let Some(n) = args.usize("infer_schema_length")? {
+            reader = reader.with_infer_schema_length(Some(n));
+        }
+        if let Some(ignore_errors) = args.bool("ignore_errors")? {
+            reader = reader.with_ignore_errors(ignore_errors);
+        }
+        if let Some(glob) = args.bool("glob")? {
+            reader = reader.with_glob(glob);
+        }
+        let path = args.finish()?;
+        Ok((path, reader.finish()?))
     }
 
     #[cfg(feature = "parquet")]
     fn read_parquet(&self, args: &[FunctionArg]) -> PolarsResult<(String, LazyFrame)> {
-        polars_ensure!(args.len() == 1, SQLSyntax: "`read_parquet` expects a single file path; found {:?} arguments", args.len());
+        use polars_lazy::prelude::ScanArgsParquet;
 
-        let path = self.get_file_path_from_arg(&args[0])?;
-        let lf = LazyFrame::scan_parquet(&path, Default::default())?;
+        let mut args = ScanArgs::try_new("read_parquet", args)?;
+        let mut scan_args = ScanArgsParquet {
+            n_rows: args.usize("n_rows")?,
+            include_file_paths: args.string("include_file_paths")?,
+            ..Default::default()
+        };
+        if let Some(hive_partitioning) = args.bool("hive_partitioning")? {
+            scan_args.hive_options.enabled = Some(hive_partitioning);
+        }
+        if let Some(glob) = args.bool("glob")? {
+            scan_args.glob = glob;
+        }
+        if let Some(allow_missing_columns) = args.bool("allow_missing_columns")? {
+            scan_args.allow_missing_columns = allow_missing_columns;
+        }
+        let path = args.finish()?;
+        let lf = LazyFrame::scan_parquet(&path, scan_args)?;
         Ok((path, lf))
     }
 
     #[cfg(feature = "ipc")]
     fn read_ipc(&self, args: &[FunctionArg]) -> PolarsResult<(String, LazyFrame)> {
-        polars_ensure!(args.len() == 1, SQLSyntax: "`read_ipc` expects a single file path; found {:?} arguments", args.len());
+        use polars_lazy::prelude::ScanArgsIpc;
 
-        let path = self.get_file_path_from_arg(&args[0])?;
-        let lf = LazyFrame::scan_ipc(&path, Default::default())?;
+        let mut args = ScanArgs::try_new("read_ipc", args)?;
+        let mut scan_args = ScanArgsIpc {
+            n_rows: args.usize("n_rows")?,
+            include_file_paths: args.string("include_file_paths")?,
+            ..Default::default()
+        };
+        if let Some(hive_partitioning) = args.bool("hive_partitioning")? {
+            scan_args.hive_options.enabled = Some(hive_partitioning);
+        }
+        let path = args.finish()?;
+        let lf = LazyFrame::scan_ipc(&path, scan_args)?;
         Ok((path, lf))
     }
+
     #[cfg(feature = "json")]
     fn read_ndjson(&self, args: &[FunctionArg]) -> PolarsResult<(String, LazyFrame)> {
-        polars_ensure!(args.len() == 1, SQLSyntax: "`read_ndjson` expects a single file path; found {:?} arguments", args.len());
+        use std::num::NonZeroUsize;
 
         use polars_lazy::frame::LazyFileListReader;
         use polars_lazy::prelude::LazyJsonLineReader;
 
-        let path = self.get_file_path_from_arg(&args[0])?;
-        let lf = LazyJsonLineReader::new(path.clone()).finish()?;
-        Ok((path, lf))
+        let mut args = ScanArgs::try_new("read_ndjson", args)?;
+        let mut reader = LazyJsonLineReader::new(args.path.clone())
+            .with_n_rows(args.usize("n_rows")?)
+            .with_include_file_paths(args.string("include_file_paths")?);
+        if let Some(n) = args.usize("infer_schema_length")? {
+            reader = reader.with_infer_schema_length(NonZeroUsize::new(n));
+        }
+        if let Some(ignore_errors) = args.bool("ignore_errors")? {
+            reader = reader.with_ignore_errors(ignore_errors);
+        }
+        let path = args.finish()?;
+        Ok((path, reader.finish()?))
     }
+}
 
-    #[allow(dead_code)]
-    fn get_file_path_from_arg(&self, arg: &FunctionArg) -> PolarsResult<String> {
-        use sqlparser::ast::{Expr as SQLExpr, Value as SQLValue};
-        match arg {
+/// The arguments of a table function that scans files; a single file path, followed by named
+/// options such as `read_csv('data.csv', has_header => false)`.
+#[cfg(any(
+    feature = "csv",
+    feature = "parquet",
+    feature = "ipc",
+    feature = "json"
+))]
+struct ScanArgs {
+    func: &'static str,
+    path: String,
+    options: Vec<(String, SQLValue)>,
+}
+
+#[cfg(any(
+    feature = "csv",
+    feature = "parquet",
+    feature = "ipc",
+    feature = "json"
+))]
+impl ScanArgs {
+    fn try_new(func: &'static str, args: &[FunctionArg]) -> PolarsResult<Self> {
+        let mut paths = vec![];
+        let mut options: Vec<(String, SQLValue)> = vec![];
+        for arg in args {
+            let Some((name, value)) = named_arg(arg) else {
+                paths.push(arg);
+                continue;
+            };
+            let name = name.to_lowercase();
+            let SQLExpr::Value(value) = value else {
+                polars_bail!(
+                    SQLSyntax: "option '{}' of `{}` must be a literal value; found {}",
+                    name, func, value
+                );
+            };
+            polars_ensure!(
+                options.iter().all(|(n, _)| *n != name),
+                SQLSyntax: "option '{}' of `{}` is given more than once", name, func
+            );
+            options.push((name, value.clone()));
+        }
+        polars_ensure!(
+            paths.len() == 1,
+            SQLSyntax: "`{}` expects a single file path; found {:?} arguments", func, paths.len()
+        );
+        let path = match paths[0] {
             FunctionArg::Unnamed(FunctionArgExpr::Expr(SQLExpr::Value(
                 SQLValue::SingleQuotedString(s),
-            ))) => Ok(s.to_string()),
-            _ => polars_bail!(
+            ))) => s.to_string(),
+            arg => polars_bail!(
                 SQLSyntax:
                 "expected a valid file path as a single-quoted string; found: {}", arg,
             ),
+        };
+        Ok(Self {
+            func,
+            path,
+            options,
+        })
+    }
+
+    /// Remove the option called `name`, if it was given.
+    fn take(&mut self, name: &str) -> Option<SQLValue> {
+        let idx = self.options.iter().position(|(n, _)| n == name)?;
+        Some(self.options.remove(idx).1)
+    }
+
+    fn bool(&mut self, name: &str) -> PolarsResult<Option<bool>> {
+        match self.take(name) {
+            None => Ok(None),
+            Some(SQLValue::Boolean(b)) => Ok(Some(b)),
+            Some(v) => self.invalid(name, "a boolean", &v),
+        }
+    }
+
+    fn usize(&mut self, name: &str) -> PolarsResult<Option<usize>> {
+        let Some(value) = self.take(name) else {
+            return Ok(None);
+        };
+        match &value {
+            SQLValue::Number(n, _) => n
+                .parse()
+                .map(Some)
+                .or_else(|_| self.invalid(name, "a non-negative integer", &value)),
+            _ => self.invalid(name, "a non-negative integer", &value),
+        }
+    }
+
+    fn string(&mut self, name: &str) -> PolarsResult<Option<PlSmallStr>> {
+        match self.take(name) {
+            None => Ok(None),
+            Some(SQLValue::SingleQuotedString(s)) => Ok(Some(s.into())),
+            Some(v) => self.invalid(name, "a string", &v),
+        }
+    }
+
+    /// An option that is a single-byte character, such as a separator.
+    fn byte(&mut self, name: &str) -> PolarsResult<Option<u8>> {
+        match self.take(name) {
+            None => Ok(None),
+            Some(SQLValue::SingleQuotedString(s)) if s.len() == 1 => Ok(Some(s.as_bytes()[0])),
+            Some(v) => self.invalid(name, "a single-byte character", &v),
         }
     }
+
+    fn invalid<T>(&self, name: &str, expected: &str, value: &SQLValue) -> PolarsResult<T> {
+        polars_bail!(
+            SQLSyntax: "option '{}' of `{}` expects {}; found {}", name, self.func, expected, value
+        )
+    }
+
+    /// Check that every option was used, and return the file path.
+    fn finish(self) -> PolarsResult<String> {
+        if let Some((name, _)) = self.options.first() {
+            polars_bail!(SQLSyntax: "`{}` does not support the option '{}'", self.func, name);
+        }
+        Ok(self.path)
+    }
+}
+
+/// The name and value of a named function argument.
+#[cfg(any(
+    feature = "csv",
+    feature = "parquet",
+    feature = "ipc",
+    feature = "json"
+))]
+fn named_arg(arg: &FunctionArg) -> Option<(&str, &SQLExpr)> {
+    match arg {
+        FunctionArg::Named {
+            name,
+            arg: FunctionArgExpr::Expr(value),
+            ..
+        } => Some((name.value.as_str(), value)),
+        FunctionArg::ExprNamed {
+            name: SQLExpr::Identifier(name),
+            arg: FunctionArgExpr::Expr(value),
+            ..
+        } => Some((name.value.as_str(), value)),
+        // dialects without `name = value` arguments parse them as a comparison
+        FunctionArg::Unnamed(FunctionArgExpr::Expr(SQLExpr::BinaryOp {
+            left,
+            op: BinaryOperator::Eq,
+            right,
+        })) => match &**left {
+            SQLExpr::Identifier(name) => Some((name.value.as_str(), &**right)),
+            _ => None,
+        },
+        _ => None,
+    }
 }
 
 impl PolarsTableFunctions {
@@ -146,6 +357,8 @@ impl PolarsTableFunctions {
             "read_ipc",
             #[cfg(feature = "json")]
             "read_json",
+            #[cfg(feature = "json")]
+            "read_ndjson",
         ]
     }
 }
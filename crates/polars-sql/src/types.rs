@@ -190,3 +190,40 @@ pub(crate) fn map_sql_dtype_to_polars(dtype: &SQLDataType) -> PolarsResult<DataT
         },
     })
 }
+
+/// The SQL name of a Polars datatype, as reported by `DESCRIBE` and `information_schema`.
+pub(crate) fn map_polars_dtype_to_sql(dtype: &DataType) -> String {
+    match dtype {
+        DataType::Boolean => "BOOLEAN".into(),
+        DataType::Int8 => "TINYINT".into(),
+        DataType::Int16 => "SMALLINT".into(),
+        DataType::Int32 => "INTEGER".into(),
+        DataType::Int64 => "BIGINT".into(),
+        DataType::UInt8 => "TINYINT UNSIGNED".into(),
+        DataType::UInt16 => "SMALLINT UNSIGNED".into(),
+        DataType::UInt32 => "INTEGER UNSIGNED".into(),
+        DataType::UInt64 => "BIGINT UNSIGNED".into(),
+        DataType::Float32 => "REAL".into(),
+        DataType::Float64 => "DOUBLE PRECISION".into(),
+        #[cfg(feature = "dtype-decimal")]
+        DataType::Decimal(Some(p), Some(s)) => format!("DECIMAL({p},{s})"),
+        DataType::String => "VARCHAR".into(),
+        DataType::Binary => "BYTEA".into(),
+        DataType::Date => "DATE".into(),
+        DataType::Time => "TIME".into(),
+        DataType::Datetime(tu, tz) => {
+            let precision = match tu {
+                TimeUnit::Milliseconds => 3,
+                TimeUnit::Microseconds => 6,
+                TimeUnit::Nanoseconds => 9,
+            };
+            match tz {
+                Some(_) => format!("TIMESTAMP({precision}) WITH TIME ZONE"),
+                None => format!("TIMESTAMP({precision})"),
+            }
+        },
+        DataType::Duration(_) => "INTERVAL".into(),
+        DataType::List(inner) => format!("{}[]", map_polars_dtype_to_sql(inner)),
+        dt => dt.to_string().to_uppercase(),
+    }
+}
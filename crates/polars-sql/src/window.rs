@@ -0,0 +1,438 @@
+//! Lowering of SQL window functions to Polars window expressions.
+//!
+//! An ordered window is evaluated on its rows in window order, and the results are mapped back
+//! to the original rows. Partitioned windows are ordered by `over`, unpartitioned windows gather
+//! their rows in window order and gather the result back.
+use polars_core::chunked_array::ops::{SortMultipleOptions, SortOptions};
+use polars_core::prelude::{
+    DataType, IDX_DTYPE, PolarsResult, RollingOptionsFixedWindow, Schema, polars_bail,
+};
+use polars_lazy::dsl::Expr;
+use polars_plan::dsl::{
+    WindowMapping, arg_sort_by, int_range, len, max_horizontal, min_horizontal, when,
+};
+use polars_plan::plans::{DynLiteralValue, LiteralValue};
+use polars_plan::prelude::lit;
+use sqlparser::ast::{WindowFrame, WindowFrameBound, WindowFrameUnits, WindowSpec};
+
+use crate::SQLContext;
+use crate::sql_expr::parse_sql_expr;
+
+/// Aggregations that can be computed over a window frame.
+#[derive(Copy, Clone)]
+pub(crate) enum WindowAggregate {
+    Avg,
+    Count,
+    Max,
+    Min,
+    Sum,
+}
+
+/// The rows of a window frame, as offsets relative to the current row.
+#[derive(Copy, Clone)]
+struct Frame {
+    /// The first row of the frame, `None` if unbounded.
+    start: Option<i64>,
+    /// The last row of the frame, `None` if unbounded.
+    end: Option<i64>,
+    /// Whether a bound at the current row extends to its peers, as in `RANGE` frames.
+    peers: bool,
+}
+
+impl Frame {
+    fn is_whole_partition(&self) -> bool {
+        self.start.is_none() && self.end.is_none()
+    }
+}
+
+pub(crate) struct SQLWindow {
+    partition_by: Vec<Expr>,
+    order_by: Vec<Expr>,
+    /// The permutation that sorts the rows in window order, if the window is ordered.
+    arg_sort: Option<Expr>,
+    frame: Frame,
+}
+
+impl SQLWindow {
+    pub(crate) fn try_new(
+        spec: &WindowSpec,
+        ctx: &mut SQLContext,
+        active_schema: Option<&Schema>,
+    ) -> PolarsResult<Self> {
+        let partition_by = spec
+            .partition_by
+            .iter()
+            .map(|p| parse_sql_expr(p, ctx, active_schema))
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let mut order_by = Vec::with_capacity(spec.order_by.len());
+        let mut descending = Vec::with_capacity(spec.order_by.len());
+        let mut nulls_last = Vec::with_capacity(spec.order_by.len());
+        for ob in &spec.order_by {
+            // note: if not specified 'NULLS FIRST' is default for DESC, 'NULLS LAST' otherwise
+            let desc_order = !ob.asc.unwrap_or(true);
+            order_by.push(parse_sql_expr(&ob.expr, ctx, active_schema)?);
+            nulls_last.push(!ob.nulls_first.unwrap_or(desc_order));
+            descending.push(desc_order);
+        }
+        let arg_sort = (!order_by.is_empty()).then(|| {
+            arg_sort_by(
+                &order_by,
+                SortMultipleOptions::default()
+                    .with_order_descending_multi(descending)
+                    .with_nulls_last_multi(nulls_last)
+                    .with_maintain_order(true),
+            )
+        });
+
+        let frame = match &spec.window_frame {
+            Some(frame) => parse_frame(frame, ctx, active_schema)?,
+            // the default frame of an ordered window ends at the last peer of the current row
+            None if arg_sort.is_some() => Frame {
+                start: None,
+                end: Some(0),
+                peers: true,
+            },
+            None => Frame {
+                start: None,
+                end: None,
+                peers: false,
+            },
+        };
+        Ok(Self {
+            partition_by,
+            order_by,
+            arg_sort,
+            frame,
+        })
+    }
+
+    /// SQL `ROW_NUMBER`; the 1-based position of the row in its partition.
+    pub(crate) fn row_number(&self) -> Expr {
+        self.finish(self.row() + lit(1))
+    }
+
+    /// SQL `RANK` and `DENSE_RANK`; the 1-based rank of the row in its partition, where peers
+    /// share a rank.
+    pub(crate) fn rank(&self, dense: bool) -> Expr {
+        let rank = if dense {
+            self.peer_starts().cum_sum(false)
+        } else {
+            self.first_peer() + lit(1)
+        };
+        self.finish(rank)
+    }
+
+    /// SQL `LAG` and `LEAD`; the value `n` rows before the row in its partition.
+    pub(crate) fn shift(&self, expr: Expr, n: i64, default: Option<Expr>) -> Expr {
+        let expr = self.sorted(expr);
+        self.finish(match default {
+            Some(default) => expr.shift_and_fill(lit(n), default),
+            None => expr.shift(lit(n)),
+        })
+    }
+
+    /// Aggregate `expr` over the frame of every row.
+    pub(crate) fn aggregate(&self, expr: Expr, agg: WindowAggregate) -> PolarsResult<Expr> {
+        if self.frame.is_whole_partition() {
+            let expr = match agg {
+                WindowAggregate::Avg => expr.mean(),
+                WindowAggregate::Count => expr.count(),
+                WindowAggregate::Max => expr.max(),
+                WindowAggregate::Min => expr.min(),
+                WindowAggregate::Sum => expr.sum(),
+            };
+            return Ok(self.over_partition(expr));
+        }
+        let out = self.framed(self.sorted(expr), agg)?;
+        Ok(self.finish(out))
+    }
+
+    /// SQL `COUNT(*)` over the frame of every row.
+    pub(crate) fn count_rows(&self) -> PolarsResult<Expr> {
+        if self.frame.is_whole_partition() {
+            return Ok(self.over_partition(len()));
+        }
+        let out = self.framed(self.row(), WindowAggregate::Count)?;
+        Ok(self.finish(out))
+    }
+
+    fn over_partition(&self, expr: Expr) -> Expr {
+        if self.partition_by.is_empty() {
+            expr
+        } else {
+            expr.over(&self.partition_by)
+        }
+    }
+
+    /// Put `expr` in window order. The rows of partitioned windows are already ordered by
+    /// [`SQLWindow::finish`].
+    fn sorted(&self, expr: Expr) -> Expr {
+        match &self.arg_sort {
+            Some(arg_sort) if self.partition_by.is_empty() => expr.gather(arg_sort.clone()),
+            _ => expr,
+        }
+    }
+
+    /// Map `expr`, evaluated in window order, back to the original rows.
+    fn finish(&self, expr: Expr) -> Expr {
+        let Some(arg_sort) = &self.arg_sort else {
+            return self.over_partition(expr);
+        };
+        let position = arg_sort.clone().arg_sort(SortOptions::default());
+        if self.partition_by.is_empty() {
+            expr.gather(position)
+        } else {
+            expr.over_with_options(
+                self.partition_by.clone(),
+                Some((vec![position], SortOptions::default())),
+                WindowMapping::GroupsToRows,
+            )
+        }
+    }
+
+    /// The 0-based position of every row in its partition.
+    fn row(&self) -> Expr {
+        int_range(lit(0), len(), 1, IDX_DTYPE)
+    }
+
+    /// Whether every row is the first of its peers.
+    fn peer_starts(&self) -> Expr {
+        self.order_by.iter().fold(self.row().eq(lit(0)), |acc, e| {
+            let e = self.sorted(e.clone());
+            acc.or(e.clone().neq_missing(e.shift(lit(1))))
+        })
+    }
+
+    /// Whether every row is the last of its peers.
+    fn peer_ends(&self) -> Expr {
+        let last = self.row().eq(len() - lit(1));
+        self.order_by.iter().fold(last, |acc, e| {
+            let e = self.sorted(e.clone());
+            acc.or(e.clone().neq_missing(e.shift(lit(-1))))
+        })
+    }
+
+    /// The position of the first peer of every row.
+    fn first_peer(&self) -> Expr {
+        when(self.peer_starts())
+            .then(self.row())
+            .otherwise(lit(LiteralValue::untyped_null()))
+            .forward_fill(None)
+    }
+
+    /// The position of the last peer of every row.
+    fn last_peer(&self) -> Expr {
+        when(self.peer_ends())
+            .then(self.row())
+            .otherwise(lit(LiteralValue::untyped_null()))
+            .backward_fill(None)
+    }
+
+    /// Aggregate `expr`, in window order, over the frame of every row.
+    fn framed(&self, expr: Expr, agg: WindowAggregate) -> PolarsResult<Expr> {
+        let null = || lit(LiteralValue::untyped_null());
+        // the number of non-null values in the frame
+        let count = || self.frame_sum(expr.clone().is_not_null().cast(IDX_DTYPE));
+        let out = match agg {
+            WindowAggregate::Avg => {
+                let (count, sum) = (count(), self.frame_sum(expr.fill_null(lit(0))));
+                when(count.clone().gt(lit(0)))
+                    .then(sum.cast(DataType::Float64) / count.cast(DataType::Float64))
+                    .otherwise(null())
+            },
+            WindowAggregate::Count => count(),
+            WindowAggregate::Max => self.frame_extremum(expr, true)?,
+            WindowAggregate::Min => self.frame_extremum(expr, false)?,
+            WindowAggregate::Sum => {
+                let (count, sum) = (count(), self.frame_sum(expr.fill_null(lit(0))));
+                when(count.gt(lit(0))).then(sum).otherwise(null())
+            },
+        };
+
+        // Extend a bound at the current row to its peers, by taking the value of the last (or
+        // first) peer, whose frame ends (or starts) at that peer.
+        let Frame { start, end, peers } = self.frame;
+        Ok(match (start, end) {
+            (None, Some(0)) if peers => out.gather(self.last_peer()),
+            (Some(0), None) if peers => out.gather(self.first_peer()),
+            _ => out,
+        })
+    }
+
+    /// The sum of `expr` over the frame of every row.
+    ///
+    /// Like [`SQLWindow::frame_extremum`], this sums the rows before and after the current row
+    /// with rolling sums of the frame width, so a non-finite value only affects the frames that
+    /// contain it.
+    fn frame_sum(&self, expr: Expr) -> Expr {
+        // The sum over the `n` rows before (or after) the current row, and the row itself.
+        let preceding = |n: Option<i64>| match n {
+            Some(0) => expr.clone(),
+            Some(n) => rolling_sum(expr.clone(), n as usize + 1),
+            None => expr.clone().cum_sum(false),
+        };
+        let following = |n: Option<i64>| match n {
+            Some(0) => expr.clone(),
+            Some(n) => rolling_sum(expr.clone().reverse(), n as usize + 1).reverse(),
+            None => expr.clone().cum_sum(true),
+        };
+
+        // Shifting leaves nulls for the rows of which the frame is empty.
+        let Frame { start, end, .. } = self.frame;
+        match (start, end) {
+            // the frame ends before the current row
+            (_, Some(end)) if end < 0 => preceding(start.map(|s| end - s))
+                .shift(lit(-end))
+                .fill_null(lit(0)),
+            // the frame starts after the current row
+            (Some(start), _) if start > 0 => following(end.map(|e| e - start))
+                .shift(lit(-start))
+                .fill_null(lit(0)),
+            (Some(0), _) => following(end),
+            (_, Some(0)) => preceding(start.map(|s| -s)),
+            // the current row is summed with the rows before it, not with the rows after it
+            _ => {
+                let after = following(end.map(|e| e - 1))
+                    .shift(lit(-1))
+                    .fill_null(lit(0));
+                preceding(start.map(|s| -s)) + after
+            },
+        }
+    }
+
+    /// The minimum or maximum of `expr` over the frame of every row.
+    fn frame_extremum(&self, expr: Expr, max: bool) -> PolarsResult<Expr> {
+        // The extremum over the `n` rows before (or after) the current row, and the row itself.
+        let preceding = |n: Option<i64>| match n {
+            Some(0) => expr.clone(),
+            Some(n) => rolling_extremum(expr.clone(), n as usize + 1, max),
+            None if max => expr.clone().cum_max(false),
+            None => expr.clone().cum_min(false),
+        };
+        let following = |n: Option<i64>| match n {
+            Some(0) => expr.clone(),
+            Some(n) => rolling_extremum(expr.clone().reverse(), n as usize + 1, max).reverse(),
+            None if max => expr.clone().cum_max(true),
+            None => expr.clone().cum_min(true),
+        };
+
+        let Frame { start, end, .. } = self.frame;
+        Ok(match (start, end) {
+            // the frame ends before the current row
+            (_, Some(end)) if end < 0 => preceding(start.map(|s| end - s)).shift(lit(-end)),
+            // the frame starts after the current row
+            (Some(start), _) if start > 0 => following(end.map(|e| e - start)).shift(lit(-start)),
+            (Some(0), _) => following(end),
+            (_, Some(0)) => preceding(start.map(|s| -s)),
+            _ => {
+                let parts = [preceding(start.map(|s| -s)), following(end)];
+                if max {
+                    max_horizontal(parts)?
+                } else {
+                    min_horizontal(parts)?
+                }
+            },
+        })
+    }
+}
+
+fn rolling_options(window_size: usize) -> RollingOptionsFixedWindow {
+    RollingOptionsFixedWindow {
+        window_size,
+        min_periods: 1,
+        ..Default::default()
+    }
+}
+
+fn rolling_sum(expr: Expr, window_size: usize) -> Expr {
+    expr.rolling_sum(rolling_options(window_size))
+}
+
+fn rolling_extremum(expr: Expr, window_size: usize, max: bool) -> Expr {
+    let options = rolling_options(window_size);
+    if max {
+        expr.rolling_max(options)
+    } else {
+        expr.rolling_min(options)
+    }
+}
+
+fn parse_frame(
+    frame: &WindowFrame,
+    ctx: &mut SQLContext,
+    active_schema: Option<&Schema>,
+) -> PolarsResult<Frame> {
+    let mut offset = |bound: &WindowFrameBound| -> PolarsResult<Option<i64>> {
+        let (n, sign) = match bound {
+            WindowFrameBound::CurrentRow => return Ok(Some(0)),
+            WindowFrameBound::Preceding(None) | WindowFrameBound::Following(None) => {
+                return Ok(None);
+            },
+            WindowFrameBound::Preceding(Some(n)) => (n, -1),
+            WindowFrameBound::Following(Some(n)) => (n, 1),
+        };
+        match parse_sql_expr(n, ctx, active_schema)? {
+            Expr::Literal(LiteralValue::Dyn(DynLiteralValue::Int(n))) if n >= 0 => {
+                Ok(Some(sign * n as i64))
+            },
+            _ => polars_bail!(
+                SQLSyntax: "window frame offsets must be non-negative integers (found {})", n
+            ),
+        }
+    };
+
+    let start = offset(&frame.start_bound)?;
+    // note: a frame without an end bound ends at the current row
+    let end = match &frame.end_bound {
+        Some(bound) => offset(bound)?,
+        None => Some(0),
+    };
+    // unbounded starts must precede, and unbounded ends must follow, the current row
+    if matches!(frame.start_bound, WindowFrameBound::Following(None))
+        || matches!(frame.end_bound, Some(WindowFrameBound::Preceding(None)))
+    {
+        polars_bail!(SQLSyntax: "invalid window frame ({})", frame_to_string(frame));
+    }
+    if let (Some(start), Some(end)) = (start, end) {
+        if start > end {
+            polars_bail!(
+                SQLSyntax: "window frame starts after it ends ({})", frame_to_string(frame)
+            );
+        }
+    }
+
+    match frame.units {
+        WindowFrameUnits::Rows => Ok(Frame {
+            start,
+            end,
+            peers: false,
+        }),
+        WindowFrameUnits::Range if start.is_none_or(|s| s == 0) && end.is_none_or(|e| e == 0) => {
+            if start == Some(0) && end == Some(0) {
+                polars_bail!(
+                    SQLInterface:
+                    "RANGE frames between CURRENT ROW and CURRENT ROW are not supported"
+                )
+            }
+            Ok(Frame {
+                start,
+                end,
+                peers: true,
+            })
+        },
+        WindowFrameUnits::Range => polars_bail!(
+            SQLInterface: "RANGE frames with offsets are not supported; consider using ROWS"
+        ),
+        WindowFrameUnits::Groups => {
+            polars_bail!(SQLInterface: "GROUPS frames are not supported; consider using ROWS")
+        },
+    }
+}
+
+fn frame_to_string(frame: &WindowFrame) -> String {
+    match &frame.end_bound {
+        Some(end) => format!("{} BETWEEN {} AND {}", frame.units, frame.start_bound, end),
+        None => format!("{} {}", frame.units, frame.start_bound),
+    }
+}
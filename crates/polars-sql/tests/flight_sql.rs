@@ -0,0 +1,259 @@
+#![cfg(feature = "flight_sql")]
+use std::sync::Arc;
+use std::time::Duration;
+
+use arrow::io::ipc::read::FlightConsumer;
+use arrow::io::ipc::write::EncodedData;
+use arrow_format::flight::data::{Action, FlightData, FlightDescriptor, Ticket};
+use arrow_format::flight::service::flight_service_server::FlightService;
+use futures::StreamExt;
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::SQLContext;
+use polars_sql::flight_sql::{BearerTokenAuthenticator, FlightSqlService};
+use prost::Message;
+use tonic::Request;
+
+#[derive(Clone, PartialEq, Message)]
+struct Any {
+    #[prost(string, tag = "1")]
+    type_url: String,
+    #[prost(bytes = "vec", tag = "2")]
+    value: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct CommandStatementQuery {
+    #[prost(string, tag = "1")]
+    query: String,
+}
+
+fn command(name: &str, value: Vec<u8>) -> FlightDescriptor {
+    FlightDescriptor {
+        // CMD
+        r#type: 2,
+        cmd: pack(name, value).encode_to_vec(),
+        path: vec![],
+    }
+}
+
+fn statement(query: &str) -> FlightDescriptor {
+    let query = CommandStatementQuery {
+        query: query.into(),
+    };
+    command("CommandStatementQuery", query.encode_to_vec())
+}
+
+fn service() -> FlightSqlService {
+    let mut ctx = SQLContext::new();
+    let df = df! { "a" => [1, 2, 3], "b" => ["x", "y", "z"] }.unwrap();
+    ctx.register("df", df.lazy());
+    FlightSqlService::new(ctx)
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct ActionCreatePreparedStatementRequest {
+    #[prost(string, tag = "1")]
+    query: String,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct ActionCreatePreparedStatementResult {
+    #[prost(bytes = "vec", tag = "1")]
+    prepared_statement_handle: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct CommandPreparedStatementQuery {
+    #[prost(bytes = "vec", tag = "1")]
+    prepared_statement_handle: Vec<u8>,
+}
+
+fn pack(name: &str, value: Vec<u8>) -> Any {
+    Any {
+        type_url: format!("type.googleapis.com/arrow.flight.protocol.sql.{name}"),
+        value,
+    }
+}
+
+async fn get_ticket(service: &FlightSqlService, descriptor: FlightDescriptor) -> Ticket {
+    let info = service
+        .get_flight_info(Request::new(descriptor))
+        .await
+        .unwrap();
+    info.into_inner().endpoint[0].ticket.clone().unwrap()
+}
+
+/// Get the ticket of `descriptor`, and return the columns of the batches streamed for it.
+async fn fetch(service: &FlightSqlService, descriptor: FlightDescriptor) -> Vec<Series> {
+    let ticket = get_ticket(service, descriptor).await;
+    let mut stream = service
+        .do_get(Request::new(ticket))
+        .await
+        .unwrap()
+        .into_inner();
+
+    let encoded = |data: FlightData| EncodedData {
+        ipc_message: data.data_header,
+        arrow_data: data.data_body,
+    };
+    let schema_message = stream.next().await.unwrap().unwrap();
+    let mut consumer = FlightConsumer::new(encoded(schema_message)).unwrap();
+    let names = consumer.schema().iter_names().cloned().collect::<Vec<_>>();
+    let mut columns: Vec<Series> = vec![];
+    while let Some(data) = stream.next().await {
+        let Some(batch) = consumer.consume(encoded(data.unwrap())).unwrap() else {
+            continue;
+        };
+        for (i, (name, array)) in names.iter().zip(batch.into_arrays()).enumerate() {
+            let s = Series::try_from((name.clone(), array)).unwrap();
+            match columns.get_mut(i) {
+                Some(column) => {
+                    column.append(&s).unwrap();
+                },
+                None => columns.push(s),
+            }
+        }
+    }
+    columns
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_flight_sql_statement() {
+    let service = service();
+    let columns = fetch(
+        &service,
+        statement("SELECT b, a * 2 AS c FROM df WHERE a > 1"),
+    )
+    .await;
+    assert_eq!(columns.len(), 2);
+    assert!(columns[0].equals(&Series::new("b".into(), ["y", "z"])));
+    assert!(columns[1].equals(&Series::new("c".into(), [4, 6])));
+
+    let columns = fetch(&service, command("CommandGetTables", vec![])).await;
+    assert!(columns[2].equals(&Series::new("table_name".into(), ["df"])));
+
+    let err = service
+        .get_flight_info(Request::new(command("CommandGetSqlInfo", vec![])))
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), tonic::Code::Unimplemented);
+
+    let descriptor = statement("SELECT * FROM missing");
+    let err = service
+        .get_flight_info(Request::new(descriptor))
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), tonic::Code::InvalidArgument);
+
+    // a ticket can be redeemed once
+    let ticket = get_ticket(&service, statement("SELECT a FROM df")).await;
+    service.do_get(Request::new(ticket.clone())).await.unwrap();
+    let err = service.do_get(Request::new(ticket)).await.err().unwrap();
+    assert_eq!(err.code(), tonic::Code::NotFound);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_flight_sql_read_only() {
+    let service = service();
+    for query in [
+        "CREATE TABLE t AS SELECT * FROM df",
+        "DROP TABLE df",
+        "COPY df TO 'out.csv'",
+        "SELECT * FROM read_csv('data.csv')",
+    ] {
+        let err = service
+            .get_flight_info(Request::new(statement(query)))
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), tonic::Code::InvalidArgument, "{query}");
+    }
+    let columns = fetch(&service, command("CommandGetTables", vec![])).await;
+    assert!(columns[2].equals(&Series::new("table_name".into(), ["df"])));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_flight_sql_prepared_statement() {
+    let service = service();
+    let request = ActionCreatePreparedStatementRequest {
+        query: "SELECT a FROM df ORDER BY a DESC".into(),
+    };
+    let action = Action {
+        r#type: "CreatePreparedStatement".into(),
+        body: pack(
+            "ActionCreatePreparedStatementRequest",
+            request.encode_to_vec(),
+        )
+        .encode_to_vec(),
+    };
+    let mut results = service
+        .do_action(Request::new(action))
+        .await
+        .unwrap()
+        .into_inner();
+    let body = results.next().await.unwrap().unwrap().body;
+    let result: ActionCreatePreparedStatementResult =
+        Message::decode(Any::decode(body.as_slice()).unwrap().value.as_slice()).unwrap();
+
+    let query = CommandPreparedStatementQuery {
+        prepared_statement_handle: result.prepared_statement_handle.clone(),
+    };
+    for _ in 0..2 {
+        let descriptor = command("CommandPreparedStatementQuery", query.encode_to_vec());
+        let columns = fetch(&service, descriptor).await;
+        assert!(columns[0].equals(&Series::new("a".into(), [3, 2, 1])));
+    }
+
+    let action = Action {
+        r#type: "ClosePreparedStatement".into(),
+        body: pack("ActionClosePreparedStatementRequest", query.encode_to_vec()).encode_to_vec(),
+    };
+    service.do_action(Request::new(action)).await.unwrap();
+    let descriptor = command("CommandPreparedStatementQuery", query.encode_to_vec());
+    let err = service
+        .get_flight_info(Request::new(descriptor))
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), tonic::Code::NotFound);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_flight_sql_handle_limits() {
+    // the oldest ticket is dropped to make room for a new one
+    let service = service().with_handle_limits(1, Duration::from_secs(3600));
+    let first = get_ticket(&service, statement("SELECT a FROM df")).await;
+    let second = get_ticket(&service, statement("SELECT b FROM df")).await;
+    let err = service.do_get(Request::new(first)).await.err().unwrap();
+    assert_eq!(err.code(), tonic::Code::NotFound);
+    service.do_get(Request::new(second)).await.unwrap();
+
+    // and tickets can't be redeemed once they expire
+    let expiring = self::service().with_handle_limits(16, Duration::ZERO);
+    let ticket = get_ticket(&expiring, statement("SELECT a FROM df")).await;
+    let err = expiring.do_get(Request::new(ticket)).await.err().unwrap();
+    assert_eq!(err.code(), tonic::Code::NotFound);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_flight_sql_authentication() {
+    let service = service().with_authenticator(Arc::new(BearerTokenAuthenticator::new("secret")));
+
+    let err = service
+        .get_flight_info(Request::new(statement("SELECT a FROM df")))
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), tonic::Code::Unauthenticated);
+
+    let mut request = Request::new(statement("SELECT a FROM df"));
+    request
+        .metadata_mut()
+        .insert("authorization", "Bearer wrong".parse().unwrap());
+    let err = service.get_flight_info(request).await.unwrap_err();
+    assert_eq!(err.code(), tonic::Code::Unauthenticated);
+
+    let mut request = Request::new(statement("SELECT a FROM df"));
+    request
+        .metadata_mut()
+        .insert("authorization", "Bearer secret".parse().unwrap());
+    service.get_flight_info(request).await.unwrap();
+}
@@ -1,8 +1,8 @@
-#[cfg(any(feature = "csv", feature = "ipc"))]
+#[cfg(any(feature = "csv", feature = "ipc", feature = "parquet"))]
 use polars_core::prelude::*;
-#[cfg(any(feature = "csv", feature = "ipc"))]
+#[cfg(any(feature = "csv", feature = "ipc", feature = "parquet"))]
 use polars_lazy::prelude::*;
-#[cfg(any(feature = "csv", feature = "ipc"))]
+#[cfg(any(feature = "csv", feature = "ipc", feature = "parquet"))]
 use polars_sql::*;
 
 #[test]
@@ -64,6 +64,39 @@ fn read_csv_tbl_func_inline_2() {
     assert!(df_sql.equals(&expected));
 }
 
+#[test]
+#[cfg(feature = "csv")]
+fn read_csv_tbl_func_options() {
+    let mut context = SQLContext::new();
+    let sql = r#"
+            SELECT *
+            FROM read_csv('../../examples/datasets/foods1.csv', n_rows => 3, has_header => false)"#;
+    let df_sql = context.execute(sql).unwrap().collect().unwrap();
+    assert_eq!(df_sql.height(), 3);
+    assert_eq!(
+        df_sql.get_column_names(),
+        &["column_1", "column_2", "column_3", "column_4"]
+    );
+
+    for (sql, msg) in [
+        (
+            "SELECT * FROM read_csv('foods.csv', headers => true)",
+            "`read_csv` does not support the option 'headers'",
+        ),
+        (
+            "SELECT * FROM read_csv('foods.csv', separator => ';;')",
+            "option 'separator' of `read_csv` expects a single-byte character",
+        ),
+        (
+            "SELECT * FROM read_csv(n_rows => 3)",
+            "`read_csv` expects a single file path; found 0 arguments",
+        ),
+    ] {
+        let err = context.execute(sql).unwrap_err();
+        assert!(err.to_string().contains(msg), "{sql}: {err}");
+    }
+}
+
 #[test]
 #[cfg(feature = "parquet")]
 fn read_parquet_tbl() {
@@ -109,3 +142,69 @@ fn read_ipc_tbl() {
     assert_eq!(df_2.height(), 27);
     assert_eq!(df_2.width(), 4);
 }
+
+#[test]
+#[cfg(feature = "csv")]
+fn copy_to_csv() {
+    let path = std::env::temp_dir().join("polars_sql_copy_to.csv");
+    let mut context = SQLContext::new();
+    context.register(
+        "df",
+        df! { "a" => [1, 2, 3], "b" => ["x", "y", "z"] }
+            .unwrap()
+            .lazy(),
+    );
+    let sql = format!(
+        "COPY (SELECT a, b FROM df WHERE a > 1) TO '{}' (FORMAT csv, DELIMITER ';')",
+        path.display()
+    );
+    context.execute(&sql).unwrap().collect().unwrap();
+
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "a;b\n2;y\n3;z\n");
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+fn copy_table_to_parquet() {
+    let path = std::env::temp_dir().join("polars_sql_copy_to.parquet");
+    let df = df! { "a" => [1, 2, 3], "b" => ["x", "y", "z"] }.unwrap();
+    let mut context = SQLContext::new();
+    context.register("df", df.clone().lazy());
+    // the format is inferred from the file extension
+    let sql = format!("COPY df (b) TO '{}'", path.display());
+    context.execute(&sql).unwrap().collect().unwrap();
+
+    let sql = format!("SELECT * FROM read_parquet('{}')", path.display());
+    let df_sql = context.execute(&sql).unwrap().collect().unwrap();
+    assert!(df_sql.equals(&df.select(["b"]).unwrap()));
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+fn read_parquet_hive_partitioning() {
+    let dir = std::env::temp_dir().join("polars_sql_read_parquet_hive");
+    std::fs::create_dir_all(dir.join("k=1")).unwrap();
+    let mut context = SQLContext::new();
+    context.register("df", df! { "a" => [1, 2] }.unwrap().lazy());
+    let sql = format!(
+        "COPY df TO '{}'",
+        dir.join("k=1").join("0.parquet").display()
+    );
+    context.execute(&sql).unwrap().collect().unwrap();
+
+    // hive partitioning is only disabled if asked for
+    for (option, columns) in [
+        ("", &["a", "k"][..]),
+        (", hive_partitioning => false", &["a"][..]),
+    ] {
+        let sql = format!(
+            "SELECT * FROM read_parquet('{}/**/*.parquet'{option})",
+            dir.display()
+        );
+        let df = context.execute(&sql).unwrap().collect().unwrap();
+        assert_eq!(df.get_column_names(), columns, "{sql}");
+    }
+    std::fs::remove_dir_all(&dir).unwrap();
+}
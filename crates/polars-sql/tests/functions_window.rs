@@ -0,0 +1,153 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn create_ctx() -> SQLContext {
+    let df = df! {
+      "grp" => ["a", "b", "a", "b", "a", "b", "a"],
+      "day" => [1, 1, 2, 3, 2, 2, 4],
+      "v" => [Some(10), Some(5), Some(20), None, Some(30), Some(15), Some(40)]
+    }
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("df", df.lazy());
+    ctx
+}
+
+#[test]
+fn test_ranking() {
+    let mut ctx = create_ctx();
+    let sql = r#"
+      SELECT
+        ROW_NUMBER() OVER (PARTITION BY grp ORDER BY day, v) AS rn,
+        RANK() OVER (PARTITION BY grp ORDER BY day) AS rnk,
+        DENSE_RANK() OVER (PARTITION BY grp ORDER BY day DESC) AS drnk,
+        ROW_NUMBER() OVER (ORDER BY v DESC) AS rn_all
+      FROM df
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+      "rn" => [1u32, 1, 2, 3, 3, 2, 4],
+      "rnk" => [1u32, 1, 2, 3, 2, 2, 4],
+      "drnk" => [3u32, 3, 2, 1, 2, 2, 1],
+      // nulls come first when descending
+      "rn_all" => [6u32, 7, 4, 1, 3, 5, 2]
+    }
+    .unwrap();
+    assert!(
+        expected.equals(&actual),
+        "expected {expected:?}, got {actual:?}"
+    );
+}
+
+#[test]
+fn test_lag_lead() {
+    let mut ctx = create_ctx();
+    let sql = r#"
+      SELECT
+        LAG(v) OVER (PARTITION BY grp ORDER BY day, v) AS prev,
+        LEAD(v, 1, 0) OVER (PARTITION BY grp ORDER BY day, v) AS next
+      FROM df
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+      "prev" => [None, None, Some(10), Some(15), Some(20), Some(5), Some(30)],
+      "next" => [Some(20), Some(15), Some(30), Some(0), Some(40), None, Some(0)]
+    }
+    .unwrap();
+    assert!(
+        expected.equals_missing(&actual),
+        "expected {expected:?}, got {actual:?}"
+    );
+}
+
+#[test]
+fn test_aggregate_frames() {
+    let mut ctx = create_ctx();
+    let sql = r#"
+      SELECT
+        SUM(v) OVER (
+          PARTITION BY grp ORDER BY day, v ROWS BETWEEN 1 PRECEDING AND CURRENT ROW
+        ) AS s,
+        SUM(v) OVER (PARTITION BY grp ORDER BY day) AS running,
+        MAX(v) OVER (
+          PARTITION BY grp ORDER BY day, v ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING
+        ) AS mx,
+        COUNT(v) OVER (
+          PARTITION BY grp ORDER BY day, v ROWS BETWEEN UNBOUNDED PRECEDING AND 1 PRECEDING
+        ) AS cnt,
+        AVG(v) OVER (
+          ORDER BY v ROWS BETWEEN CURRENT ROW AND UNBOUNDED FOLLOWING
+        ) AS avg_after
+      FROM df
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+      "s" => [10, 5, 30, 15, 50, 20, 70],
+      // the default frame includes the peers of the current row
+      "running" => [10, 5, 60, 20, 60, 20, 100],
+      "mx" => [20, 15, 30, 15, 40, 15, 40],
+      "cnt" => [0u32, 0, 1, 2, 2, 1, 3],
+      "avg_after" => [
+        Some(23.0), Some(20.0), Some(30.0), None, Some(35.0), Some(26.25), Some(40.0)
+      ]
+    }
+    .unwrap();
+    assert!(
+        expected.equals_missing(&actual),
+        "expected {expected:?}, got {actual:?}"
+    );
+}
+
+#[test]
+fn test_aggregate_frames_non_finite() {
+    let df = df! {
+      "i" => [1, 2, 3, 4],
+      "v" => [f64::INFINITY, 1.0, 2.0, 1e300]
+    }
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("df", df.lazy());
+    let sql = r#"
+      SELECT
+        SUM(v) OVER (ORDER BY i ROWS 1 PRECEDING) AS s,
+        SUM(v) OVER (ORDER BY i ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING) AS around,
+        AVG(v) OVER (ORDER BY i ROWS BETWEEN 2 PRECEDING AND 1 PRECEDING) AS avg_before
+      FROM df
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    // a value only affects the frames that contain it
+    let expected = df! {
+      "s" => [f64::INFINITY, f64::INFINITY, 3.0, 1e300],
+      "around" => [f64::INFINITY, f64::INFINITY, 1e300, 1e300],
+      "avg_before" => [None, Some(f64::INFINITY), Some(f64::INFINITY), Some(1.5)]
+    }
+    .unwrap();
+    assert!(
+        expected.equals_missing(&actual),
+        "expected {expected:?}, got {actual:?}"
+    );
+}
+
+#[test]
+fn test_window_errors() {
+    let mut ctx = create_ctx();
+    for (sql, msg) in [
+        ("SELECT LAG(v) FROM df", "requires an OVER clause"),
+        (
+            "SELECT SUM(v) OVER (ORDER BY day RANGE BETWEEN 1 PRECEDING AND CURRENT ROW) FROM df",
+            "RANGE frames with offsets are not supported",
+        ),
+        (
+            "SELECT SUM(v) OVER (ORDER BY day ROWS BETWEEN 1 FOLLOWING AND CURRENT ROW) FROM df",
+            "window frame starts after it ends",
+        ),
+        (
+            "SELECT MEDIAN(v) OVER (ORDER BY day ROWS BETWEEN 1 PRECEDING AND CURRENT ROW) FROM df",
+            "window frames are only supported by",
+        ),
+    ] {
+        let err = ctx.execute(sql).and_then(|lf| lf.collect()).unwrap_err();
+        assert!(err.to_string().contains(msg), "{sql}: {err}");
+    }
+}
@@ -161,6 +161,422 @@ fn test_union_all() {
     assert!(actual.equals(&expected));
 }
 
+#[test]
+fn test_recursive_cte() {
+    let mut ctx = SQLContext::new();
+    let sql = r#"
+    WITH RECURSIVE nums(n) AS (
+        SELECT 1
+        UNION ALL
+        SELECT n + 1 FROM nums WHERE n < 5
+    )
+    SELECT n FROM nums
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df!["n" => [1, 2, 3, 4, 5]].unwrap();
+    assert!(actual.equals(&expected));
+}
+
+#[test]
+fn test_recursive_cte_hierarchy() {
+    let employees = df![
+        "id" => [1, 2, 3, 4, 5, 6],
+        "manager_id" => [None, Some(1), Some(1), Some(2), Some(4), Some(3)],
+        "name" => ["ceo", "cto", "cfo", "dev", "intern", "accountant"]
+    ]
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("employees", employees.lazy());
+
+    let sql = r#"
+    WITH RECURSIVE reports AS (
+        SELECT id, name, 0 AS depth FROM employees WHERE id = 2
+        UNION ALL
+        SELECT e.id, e.name, r.depth + 1
+        FROM employees e JOIN reports r ON e.manager_id = r.id
+    )
+    SELECT * FROM reports ORDER BY depth, id
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df![
+        "id" => [2, 4, 5],
+        "name" => ["cto", "dev", "intern"],
+        "depth" => [0, 1, 2]
+    ]
+    .unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected {expected:?}, got {actual:?}"
+    );
+}
+
+#[test]
+fn test_recursive_cte_cycle() {
+    let edges = df![
+        "src" => [1, 2, 3],
+        "dst" => [2, 3, 1]
+    ]
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("edges", edges.lazy());
+
+    // UNION only recurses on new rows, so cycles terminate
+    let sql = r#"
+    WITH RECURSIVE reachable(node) AS (
+        SELECT 1
+        UNION
+        SELECT edges.dst FROM edges JOIN reachable ON edges.src = reachable.node
+    )
+    SELECT node FROM reachable ORDER BY node
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df!["node" => [1, 2, 3]].unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected {expected:?}, got {actual:?}"
+    );
+
+    // UNION ALL doesn't, so the recursion limit is hit
+    let mut ctx = ctx.with_recursion_limit(10);
+    let sql = sql.replace("UNION", "UNION ALL");
+    let err = ctx.execute(&sql).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("did not finish within 10 iterations"),
+        "{err}"
+    );
+}
+
+#[test]
+fn test_recursive_cte_subquery() {
+    let edges = df![
+        "src" => [1, 2, 3],
+        "dst" => [2, 3, 1]
+    ]
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("edges", edges.lazy());
+
+    // the recursive term only references the CTE in a subquery
+    let sql = r#"
+    WITH RECURSIVE reachable(node) AS (
+        SELECT 1
+        UNION
+        SELECT dst FROM edges WHERE src IN (SELECT node FROM reachable)
+    )
+    SELECT node FROM reachable ORDER BY node
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df!["node" => [1, 2, 3]].unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected {expected:?}, got {actual:?}"
+    );
+
+    let sql = r#"
+    WITH RECURSIVE reachable(node) AS (
+        SELECT src FROM edges WHERE dst IN (SELECT node FROM reachable)
+        UNION
+        SELECT edges.dst FROM edges JOIN reachable ON edges.src = reachable.node
+    )
+    SELECT node FROM reachable
+    "#;
+    let err = ctx.execute(sql).unwrap_err();
+    assert!(
+        err.to_string().contains("must not reference itself"),
+        "{err}"
+    );
+}
+
+#[test]
+fn test_create_table_exists() {
+    let mut ctx = create_ctx();
+    ctx.execute("CREATE TABLE t AS SELECT a FROM df").unwrap();
+
+    // IF NOT EXISTS keeps the existing table, otherwise it's replaced
+    ctx.execute("CREATE TABLE IF NOT EXISTS t AS SELECT b FROM df")
+        .unwrap();
+    let df = ctx.execute("SELECT * FROM t").unwrap().collect().unwrap();
+    assert_eq!(df.get_column_names(), &["a"]);
+
+    ctx.execute("CREATE TABLE t AS SELECT b FROM df").unwrap();
+    let df = ctx.execute("SELECT * FROM t").unwrap().collect().unwrap();
+    assert_eq!(df.get_column_names(), &["b"]);
+
+    ctx.execute("CREATE OR REPLACE TABLE t AS SELECT a FROM df")
+        .unwrap();
+    let df = ctx.execute("SELECT * FROM t").unwrap().collect().unwrap();
+    assert_eq!(df.get_column_names(), &["a"]);
+}
+
+#[test]
+fn test_execute_with_params() {
+    let mut ctx = create_ctx();
+    let sql = "SELECT b FROM df WHERE b > $1 AND b <= $2 ORDER BY b";
+    for (params, expected) in [([3, 5], vec![4i64, 5]), ([7, 9], vec![8, 9])] {
+        let params = params.map(AnyValue::Int32);
+        let actual = ctx
+            .execute_with_params(sql, &params)
+            .unwrap()
+            .collect()
+            .unwrap();
+        assert!(
+            actual.equals(&df! { "b" => expected }.unwrap()),
+            "{actual:?}"
+        );
+    }
+
+    let sql = "SELECT b, ? AS s FROM df WHERE b IN (?, ?)";
+    let params = [
+        AnyValue::String("x"),
+        AnyValue::Int64(2),
+        AnyValue::Int64(4),
+    ];
+    let actual = ctx
+        .execute_with_params(sql, &params)
+        .unwrap()
+        .collect()
+        .unwrap();
+    let expected = df! { "b" => [2i64, 4], "s" => ["x", "x"] }.unwrap();
+    assert!(actual.equals(&expected), "{actual:?}");
+
+    // parameters are values, not SQL
+    let sql = "SELECT b FROM df WHERE CAST(b AS VARCHAR) = $1";
+    let params = [AnyValue::String("1' OR '1' = '1")];
+    let actual = ctx
+        .execute_with_params(sql, &params)
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert_eq!(actual.height(), 0);
+}
+
+#[test]
+fn test_execute_with_params_errors() {
+    let mut ctx = create_ctx();
+    for (sql, n_params, msg) in [
+        (
+            "SELECT * FROM df WHERE b > $2",
+            1,
+            "no value was bound to placeholder $2",
+        ),
+        (
+            "SELECT * FROM df WHERE b > ?",
+            0,
+            "no value was bound to placeholder $1",
+        ),
+        (
+            "SELECT * FROM df WHERE b > ? AND a < $1",
+            2,
+            "cannot mix anonymous",
+        ),
+        (
+            "SELECT * FROM df WHERE b > $0",
+            1,
+            "invalid placeholder '$0'",
+        ),
+    ] {
+        let params = vec![AnyValue::Int64(1); n_params];
+        let err = ctx.execute_with_params(sql, &params).unwrap_err();
+        assert!(err.to_string().contains(msg), "{sql}: {err}");
+    }
+}
+
+#[test]
+fn test_describe_and_information_schema() {
+    let mut ctx = create_ctx();
+    let other = df! { "x" => ["a"], "y" => [1.5] }.unwrap();
+    ctx.register("other", other.lazy());
+
+    let actual = ctx.execute("DESCRIBE df").unwrap().collect().unwrap();
+    let expected = df! {
+        "column_name" => ["a", "b"],
+        "column_type" => ["BIGINT", "BIGINT"],
+    }
+    .unwrap();
+    assert!(actual.equals(&expected), "{actual:?}");
+
+    let sql = "SELECT table_name, table_type FROM information_schema.tables";
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "table_name" => ["df", "other"],
+        "table_type" => ["BASE TABLE", "BASE TABLE"],
+    }
+    .unwrap();
+    assert!(actual.equals(&expected), "{actual:?}");
+
+    let sql = r#"
+        SELECT c.column_name, c.ordinal_position, c.data_type
+        FROM information_schema.columns AS c
+        WHERE c.table_name = 'other'
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "column_name" => ["x", "y"],
+        "ordinal_position" => [1u32, 2],
+        "data_type" => ["VARCHAR", "DOUBLE PRECISION"],
+    }
+    .unwrap();
+    assert!(actual.equals(&expected), "{actual:?}");
+
+    let err = ctx.execute("DESCRIBE missing").unwrap_err();
+    assert!(
+        err.to_string().contains("relation 'missing' was not found"),
+        "{err}"
+    );
+}
+
+#[test]
+fn test_sql_dialects() {
+    let df = df! { "a" => [7, 9], "b" => [2, 2] }.unwrap();
+    let sql_ctx = |dialect| {
+        let mut ctx = SQLContext::new().with_dialect(dialect);
+        ctx.register("df", df.clone().lazy());
+        ctx
+    };
+
+    // division of integers floors (or truncates), unless the dialect divides into floats
+    for (dialect, expected) in [
+        (SQLDialect::Generic, df! { "q" => [3, 4] }),
+        (SQLDialect::PostgreSql, df! { "q" => [3, 4] }),
+        (SQLDialect::MySql, df! { "q" => [3.5, 4.5] }),
+        (SQLDialect::DuckDb, df! { "q" => [3.5, 4.5] }),
+    ] {
+        let mut ctx = sql_ctx(dialect);
+        let actual = ctx
+            .execute("SELECT a / b AS q FROM df")
+            .unwrap()
+            .collect()
+            .unwrap();
+        assert!(actual.equals(&expected.unwrap()), "{dialect:?}: {actual:?}");
+    }
+
+    // mysql quotes identifiers with backticks and strings with double quotes
+    let mut ctx = sql_ctx(SQLDialect::MySql);
+    let sql = r#"SELECT `a` DIV `b` AS q, UCASE("it\'s") AS s FROM df"#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! { "q" => [3i64, 4], "s" => ["IT'S", "IT'S"] }.unwrap();
+    assert!(actual.equals(&expected), "{actual:?}");
+
+    // DIV truncates towards zero
+    let div = df! {
+        "a" => [-7i64, 7, -7, 9, 7],
+        "b" => [2i64, -2, -2, 3, 0],
+        "f" => [-7.5f64, 7.5, -7.5, 7.5, 7.5],
+    }
+    .unwrap();
+    ctx.register("div", div.clone().lazy());
+    let sql = "SELECT a DIV b AS q, f DIV b AS g FROM div";
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "q" => [Some(-3i64), Some(-3), Some(3), Some(3), None],
+        "g" => [Some(-3i64), Some(-3), Some(3), Some(2), None],
+    }
+    .unwrap();
+    assert!(actual.equals_missing(&expected), "{actual:?}");
+
+    // as does postgres' division of integers, which keeps their type
+    let mut ctx = sql_ctx(SQLDialect::PostgreSql);
+    ctx.register("div", div.lazy());
+    let sql = "SELECT a / b AS q, f / b AS g FROM div";
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "q" => [Some(-3i64), Some(-3), Some(3), Some(3), None],
+        "g" => [Some(-3.75f64), Some(-3.75), Some(3.75), Some(2.5), Some(f64::INFINITY)],
+    }
+    .unwrap();
+    assert!(actual.equals_missing(&expected), "{actual:?}");
+
+    // postgres only recognises backslash escapes in escaped strings
+    let mut ctx = sql_ctx(SQLDialect::PostgreSql);
+    let sql = r#"SELECT 'a\b' AS x, E'a\tb' AS y FROM "df" LIMIT 1"#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! { "x" => ["a\\b"], "y" => ["a\tb"] }.unwrap();
+    assert!(actual.equals(&expected), "{actual:?}");
+
+    let mut ctx = sql_ctx(SQLDialect::DuckDb);
+    let sql = "SELECT MEAN(b) AS m, SUFFIX('polars', 'rs') AS s FROM df";
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! { "m" => [2.0], "s" => [true] }.unwrap();
+    assert!(actual.equals(&expected), "{actual:?}");
+
+    // dialect aliases are not recognised by the other dialects
+    let mut ctx = sql_ctx(SQLDialect::Generic);
+    let err = ctx.execute("SELECT UCASE('x') FROM df").unwrap_err();
+    assert!(
+        err.to_string().contains("unsupported function 'ucase'"),
+        "{err}"
+    );
+}
+
+#[test]
+fn test_unnest_lateral_join() {
+    let mut ctx = SQLContext::new();
+    let items = [vec![1i64, 2], vec![], vec![3]]
+        .into_iter()
+        .map(|v| Series::new(PlSmallStr::EMPTY, v))
+        .collect::<Vec<_>>();
+    let df = df! { "id" => [1, 2, 3], "items" => items }.unwrap();
+    ctx.register("t", df.lazy());
+
+    let expected = df! { "id" => [1, 1, 3], "item" => [1i64, 2, 3] }.unwrap();
+    for sql in [
+        "SELECT t.id, u.item FROM t CROSS JOIN UNNEST(t.items) AS u(item)",
+        "SELECT id, item FROM t CROSS JOIN LATERAL UNNEST(items) AS u(item)",
+        "SELECT id, item FROM t, UNNEST(t.items) AS u(item)",
+    ] {
+        let actual = ctx.execute(sql).unwrap().collect().unwrap();
+        assert!(actual.equals(&expected), "{sql}: {actual:?}");
+    }
+
+    // rows without elements are kept by a left join
+    let sql = "SELECT id, item FROM t LEFT JOIN UNNEST(t.items) AS u(item) ON TRUE";
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "id" => [1, 1, 2, 3],
+        "item" => [Some(1i64), Some(2), None, Some(3)],
+    }
+    .unwrap();
+    assert!(actual.equals_missing(&expected), "{actual:?}");
+
+    // without column aliases, the elements are a new column
+    let sql = "SELECT * FROM t, UNNEST(t.items) ORDER BY id, unnest";
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    assert_eq!(actual.get_column_names(), &["id", "items", "unnest"]);
+    assert_eq!(
+        actual.column("unnest").unwrap().i64().unwrap().to_vec(),
+        &[Some(1), Some(2), Some(3)]
+    );
+
+    let sql = r#"
+        SELECT id, SUM(item) AS total
+        FROM t, UNNEST(t.items) AS u(item)
+        GROUP BY id
+        ORDER BY id
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! { "id" => [1, 3], "total" => [3i64, 3] }.unwrap();
+    assert!(actual.equals(&expected), "{actual:?}");
+
+    for (sql, msg) in [
+        (
+            "SELECT * FROM t JOIN UNNEST(t.items) AS u(x) ON u.x = t.id",
+            "can only be CROSS JOINed",
+        ),
+        (
+            "SELECT * FROM t, UNNEST(t.id) AS u(x)",
+            "UNNEST expects a list",
+        ),
+        (
+            "SELECT * FROM t, UNNEST(t.items) AS u(x, y)",
+            "requires 1 column name, found 2",
+        ),
+    ] {
+        let err = ctx.execute(sql).and_then(|lf| lf.collect()).unwrap_err();
+        assert!(err.to_string().contains(msg), "{sql}: {err}");
+    }
+}
+
 #[test]
 fn test_drop_table() {
     let mut ctx = create_ctx();
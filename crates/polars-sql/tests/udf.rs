@@ -2,7 +2,7 @@ use polars_core::prelude::*;
 use polars_lazy::prelude::IntoLazy;
 use polars_plan::prelude::{GetOutput, UserDefinedFunction};
 use polars_sql::SQLContext;
-use polars_sql::function_registry::FunctionRegistry;
+use polars_sql::function_registry::{FunctionRegistry, Volatility};
 
 struct MyFunctionRegistry {
     functions: PlHashMap<String, UserDefinedFunction>,
@@ -98,3 +98,56 @@ fn test_udfs() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_register_functions() -> PolarsResult<()> {
+    let mut ctx = SQLContext::new();
+    ctx.register_scalar_function(
+        "halve",
+        vec![DataType::Float64],
+        DataType::Float64,
+        Volatility::Immutable,
+        |c| Ok((c[0].as_materialized_series() / 2.0).into_column()),
+    )?;
+    ctx.register_aggregate_function("span", vec![DataType::Int64], DataType::Int64, |c| {
+        let v = c[0].i64()?;
+        let span = v.max().zip(v.min()).map(|(max, min)| max - min);
+        Ok(Column::new(c[0].name().clone(), [span]))
+    })?;
+
+    let df = df! {
+        "grp" => ["a", "b", "a", "b", "a"],
+        "v" => [1, 8, 5, 2, 3],
+    }?;
+    ctx.register("df", df.lazy());
+
+    // the integer argument is cast to the declared type
+    let res = ctx.execute("SELECT HALVE(v) AS h FROM df")?.collect()?;
+    assert!(res.equals(&df! { "h" => [0.5, 4.0, 2.5, 1.0, 1.5] }?));
+
+    let res = ctx
+        .execute("SELECT grp, span(v) AS s FROM df GROUP BY grp ORDER BY grp")?
+        .collect()?;
+    assert!(res.equals(&df! { "grp" => ["a", "b"], "s" => [4i64, 6] }?));
+
+    let res = ctx.execute("SELECT span(v) AS s FROM df")?.collect()?;
+    assert!(res.equals(&df! { "s" => [7i64] }?));
+
+    let err = ctx.execute("SELECT span(v, v) FROM df").unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("span expects 1 arguments (found 2)"),
+        "{err}"
+    );
+
+    let err = ctx
+        .register_aggregate_function("sum", vec![DataType::Int64], DataType::Int64, |c| {
+            Ok(c[0].clone())
+        })
+        .unwrap_err();
+    assert!(
+        err.to_string().contains("it is a built-in function"),
+        "{err}"
+    );
+    Ok(())
+}
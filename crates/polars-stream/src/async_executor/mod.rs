@@ -15,7 +15,7 @@ use std::time::Duration;
 use crossbeam_deque::{Injector, Steal, Stealer, Worker as WorkQueue};
 use crossbeam_utils::CachePadded;
 use park_group::ParkGroup;
-use parking_lot::Mutex;
+use parking_lot::{Mutex, RwLock};
 use rand::rngs::SmallRng;
 use rand::{Rng, SeedableRng};
 use slotmap::SlotMap;
@@ -66,6 +66,126 @@ pub enum TaskPriority {
     High,
 }
 
+/// The share of the executor a query gets while other queries are running.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum QueryPriority {
+    Low,
+    #[default]
+    Normal,
+    High,
+}
+
+impl QueryPriority {
+    /// The relative amount of time the tasks of a query with this priority get.
+    fn weight(self) -> u64 {
+        match self {
+            Self::Low => 1,
+            Self::Normal => 4,
+            Self::High => 16,
+        }
+    }
+}
+
+/// The ready tasks of a single query while multiple queries are running.
+///
+/// The executor picks the query whose tasks were polled for the least time relative to its
+/// priority, so concurrent queries share the executor fairly. Tasks that are scheduled on the
+/// thread-local queues of the executor threads are not accounted for, so this is approximate.
+pub struct QueryTasks {
+    priority: QueryPriority,
+    /// The time spent polling the tasks of this query in nanoseconds, scaled by its weight.
+    vruntime: AtomicU64,
+    high_prio_tasks: Injector<ReadyTask>,
+    low_prio_tasks: Injector<ReadyTask>,
+}
+
+impl QueryTasks {
+    fn new(priority: QueryPriority, vruntime: u64) -> Self {
+        Self {
+            priority,
+            vruntime: AtomicU64::new(vruntime),
+            high_prio_tasks: Injector::new(),
+            low_prio_tasks: Injector::new(),
+        }
+    }
+
+    fn push(&self, task: ReadyTask) {
+        if task.metadata().priority == TaskPriority::High {
+            self.high_prio_tasks.push(task);
+        } else {
+            self.low_prio_tasks.push(task);
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.high_prio_tasks.is_empty() && self.low_prio_tasks.is_empty()
+    }
+
+    fn steal(&self) -> Option<ReadyTask> {
+        for queue in [&self.high_prio_tasks, &self.low_prio_tasks] {
+            loop {
+                match queue.steal() {
+                    Steal::Empty => break,
+                    Steal::Success(task) => return Some(task),
+                    Steal::Retry => std::hint::spin_loop(),
+                }
+            }
+        }
+        None
+    }
+
+    fn polled_for(&self, ns: u64) {
+        let scaled = ns * QueryPriority::High.weight() / self.priority.weight();
+        self.vruntime.fetch_add(scaled, Ordering::Relaxed);
+    }
+}
+
+/// Registers a query with the executor, so it is scheduled fairly with respect to the other
+/// queries. The query is deregistered when this is dropped ([RAII]).
+///
+/// [RAII]: https://en.wikipedia.org/wiki/Resource_acquisition_is_initialization
+pub struct QueryRegistration {
+    tasks: Arc<QueryTasks>,
+}
+
+impl QueryRegistration {
+    pub fn new(priority: QueryPriority) -> Self {
+        let executor = Executor::global();
+        let mut queries = executor.queries.write();
+        // Start at the least time spent of the running queries, so a new query neither starves
+        // the others nor gets starved itself.
+        let vruntime = queries
+            .iter()
+            .map(|q| q.vruntime.load(Ordering::Relaxed))
+            .min()
+            .unwrap_or(0);
+        let tasks = Arc::new(QueryTasks::new(priority, vruntime));
+        queries.push(tasks.clone());
+        executor.num_queries.store(queries.len(), Ordering::Relaxed);
+        Self { tasks }
+    }
+
+    pub fn tasks(&self) -> &Arc<QueryTasks> {
+        &self.tasks
+    }
+}
+
+impl Drop for QueryRegistration {
+    fn drop(&mut self) {
+        let executor = Executor::global();
+        let mut queries = executor.queries.write();
+        queries.retain(|q| !Arc::ptr_eq(q, &self.tasks));
+        executor.num_queries.store(queries.len(), Ordering::Relaxed);
+        drop(queries);
+
+        // Tasks which were woken after the query finished still have to run to clean up.
+        while let Some(task) = self.tasks.steal() {
+            executor.global_low_prio_task_queue.push(task);
+            executor.park_group.unpark_one();
+        }
+    }
+}
+
 /// Metadata associated with a task to help schedule it and clean it up.
 struct ScopedTaskMetadata {
     task_key: TaskKey,
@@ -78,6 +198,12 @@ struct TaskMetadata {
     priority: TaskPriority,
     freshly_spawned: AtomicBool,
     scoped: Option<ScopedTaskMetadata>,
+    /// If set, the time spent polling this task is added to this counter.
+    ns_spent_polling: Option<Arc<AtomicU64>>,
+    /// The tracing span entered while polling this task.
+    span: tracing::Span,
+    /// The query this task belongs to, if it is scheduled fairly with other queries.
+    query: Option<Arc<QueryTasks>>,
 }
 
 impl Drop for TaskMetadata {
@@ -114,12 +240,26 @@ struct Executor {
     thread_task_lists: Vec<CachePadded<ThreadLocalTaskList>>,
     global_high_prio_task_queue: Injector<ReadyTask>,
     global_low_prio_task_queue: Injector<ReadyTask>,
+    queries: RwLock<Vec<Arc<QueryTasks>>>,
+    num_queries: AtomicUsize,
 }
 
 impl Executor {
     fn schedule_task(&self, task: ReadyTask) {
         let thread = TLS_THREAD_ID.get();
         let meta = task.metadata();
+
+        // With concurrent queries the tasks go to the queue of their query, from which they are
+        // picked fairly.
+        if let Some(query) = &meta.query {
+            if self.num_queries.load(Ordering::Relaxed) > 1 {
+                meta.freshly_spawned.store(false, Ordering::Relaxed);
+                let query = query.clone();
+                query.push(task);
+                self.park_group.unpark_one();
+                return;
+            }
+        }
         let opt_ttl = self.thread_task_lists.get(thread);
 
         let mut use_global_queue = opt_ttl.is_none();
@@ -174,6 +314,10 @@ impl Executor {
             }
         }
 
+        if let Some(task) = self.try_steal_query_task() {
+            return Some(task);
+        }
+
         loop {
             match self.global_low_prio_task_queue.steal() {
                 Steal::Empty => break,
@@ -208,6 +352,21 @@ impl Executor {
         None
     }
 
+    /// Takes a task of the query which was polled for the least time relative to its priority.
+    fn try_steal_query_task(&self) -> Option<ReadyTask> {
+        if self.num_queries.load(Ordering::Relaxed) == 0 {
+            return None;
+        }
+
+        let queries = self.queries.read();
+        loop {
+            let query = least_served(queries.iter().filter(|q| !q.is_empty()))?;
+            if let Some(task) = query.steal() {
+                return Some(task);
+            }
+        }
+    }
+
     fn runner(&self, thread: usize) {
         TLS_THREAD_ID.set(thread);
 
@@ -256,7 +415,24 @@ impl Executor {
                     }
                 }
                 worker.recruit_next();
-                task.run();
+                let _span = (!task.metadata().span.is_disabled())
+                    .then(|| task.metadata().span.clone().entered());
+                let meta = task.metadata();
+                if meta.ns_spent_polling.is_some() || meta.query.is_some() {
+                    let ns_spent_polling = meta.ns_spent_polling.clone();
+                    let query = meta.query.clone();
+                    let start = std::time::Instant::now();
+                    task.run();
+                    let ns: u64 = start.elapsed().as_nanos().try_into().unwrap();
+                    if let Some(ns_spent_polling) = ns_spent_polling {
+                        ns_spent_polling.fetch_add(ns, Ordering::Relaxed);
+                    }
+                    if let Some(query) = query {
+                        query.polled_for(ns);
+                    }
+                } else {
+                    task.run();
+                }
             }
         }
     }
@@ -272,10 +448,9 @@ impl Executor {
 
             let thread_task_lists = (0..n_threads)
                 .map(|t| {
-                    std::thread::Builder::new()
-                        .name(format!("async-executor-{t}"))
-                        .spawn(move || Self::global().runner(t))
-                        .unwrap();
+                    crate::runtime::spawn_thread(format!("async-executor-{t}"), move || {
+                        Self::global().runner(t)
+                    });
 
                     let high_prio_tasks = WorkQueue::new_lifo();
                     CachePadded::new(ThreadLocalTaskList {
@@ -290,6 +465,8 @@ impl Executor {
                 thread_task_lists,
                 global_high_prio_task_queue: Injector::new(),
                 global_low_prio_task_queue: Injector::new(),
+                queries: RwLock::default(),
+                num_queries: AtomicUsize::new(0),
             }
         })
     }
@@ -303,6 +480,13 @@ pub struct TaskScope<'scope, 'env: 'scope> {
     cancel_handles: Mutex<SlotMap<TaskKey, CancelHandle>>,
     completed_tasks: Arc<Mutex<Vec<TaskKey>>>,
 
+    // The counter the poll time of newly spawned tasks is added to.
+    poll_time_counter: Mutex<Option<Arc<AtomicU64>>>,
+    // The tracing span of newly spawned tasks.
+    task_span: Mutex<tracing::Span>,
+    // The query the tasks spawned in this scope belong to.
+    query: Option<Arc<QueryTasks>>,
+
     // Copied from std::thread::scope. Necessary to prevent unsoundness.
     scope: PhantomData<&'scope mut &'scope ()>,
     env: PhantomData<&'env mut &'env ()>,
@@ -317,6 +501,18 @@ impl<'scope> TaskScope<'scope, '_> {
         }
     }
 
+    /// Adds the time spent polling the tasks spawned from now on to the given counter, in
+    /// nanoseconds.
+    pub fn set_poll_time_counter(&self, counter: Option<Arc<AtomicU64>>) {
+        *self.poll_time_counter.lock() = counter;
+    }
+
+    /// Enters the given tracing span while polling the tasks spawned from now on. If the span is
+    /// disabled the tasks use the span that is current when they are spawned.
+    pub fn set_task_span(&self, span: tracing::Span) {
+        *self.task_span.lock() = span;
+    }
+
     fn clear_completed_tasks(&self) {
         let mut cancel_handles = self.cancel_handles.lock();
         for t in self.completed_tasks.lock().drain(..) {
@@ -335,6 +531,11 @@ impl<'scope> TaskScope<'scope, '_> {
     {
         let spawn_location = Location::caller();
         self.clear_completed_tasks();
+        let ns_spent_polling = self.poll_time_counter.lock().clone();
+        let mut span = self.task_span.lock().clone();
+        if span.is_disabled() {
+            span = tracing::Span::current();
+        }
 
         let mut runnable = None;
         let mut join_handle = None;
@@ -355,6 +556,9 @@ impl<'scope> TaskScope<'scope, '_> {
                             task_key,
                             completed_tasks: Arc::downgrade(&self.completed_tasks),
                         }),
+                        ns_spent_polling,
+                        span,
+                        query: self.query.clone(),
                     },
                 )
             };
@@ -369,6 +573,14 @@ impl<'scope> TaskScope<'scope, '_> {
 }
 
 pub fn task_scope<'env, F, T>(f: F) -> T
+where
+    F: for<'scope> FnOnce(&'scope TaskScope<'scope, 'env>) -> T,
+{
+    query_task_scope(None, f)
+}
+
+/// Like [`task_scope`], with the tasks spawned in the scope belonging to the given query.
+pub fn query_task_scope<'env, F, T>(query: Option<Arc<QueryTasks>>, f: F) -> T
 where
     F: for<'scope> FnOnce(&'scope TaskScope<'scope, 'env>) -> T,
 {
@@ -378,6 +590,9 @@ where
     let scope = TaskScope {
         cancel_handles: Mutex::default(),
         completed_tasks: Arc::new(Mutex::default()),
+        poll_time_counter: Mutex::default(),
+        task_span: Mutex::new(tracing::Span::none()),
+        query,
         scope: PhantomData,
         env: PhantomData,
     };
@@ -410,12 +625,22 @@ where
             priority,
             freshly_spawned: AtomicBool::new(true),
             scoped: None,
+            ns_spent_polling: None,
+            span: tracing::Span::current(),
+            query: None,
         },
     );
     runnable.schedule();
     join_handle
 }
 
+/// The query whose tasks were polled for the least time relative to its priority.
+fn least_served<'a>(
+    queries: impl Iterator<Item = &'a Arc<QueryTasks>>,
+) -> Option<&'a Arc<QueryTasks>> {
+    queries.min_by_key(|q| q.vruntime.load(Ordering::Relaxed))
+}
+
 fn random_permutation<R: Rng>(len: u32, rng: &mut R) -> impl Iterator<Item = u32> {
     let modulus = len.next_power_of_two();
     let halfwidth = modulus.trailing_zeros() / 2;
@@ -443,3 +668,26 @@ fn random_permutation<R: Rng>(len: u32, rng: &mut R) -> impl Iterator<Item = u32
             i
         })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_least_served_query() {
+        let low = Arc::new(QueryTasks::new(QueryPriority::Low, 0));
+        let high = Arc::new(QueryTasks::new(QueryPriority::High, 0));
+        let queries = [low.clone(), high.clone()];
+
+        // After polling both for the same time the high priority query is served next.
+        low.polled_for(1_000);
+        high.polled_for(1_000);
+        assert!(Arc::ptr_eq(least_served(queries.iter()).unwrap(), &high));
+
+        // It keeps being picked until it used its larger share of the time.
+        high.polled_for(14_000);
+        assert!(Arc::ptr_eq(least_served(queries.iter()).unwrap(), &high));
+        high.polled_for(2_000);
+        assert!(Arc::ptr_eq(least_served(queries.iter()).unwrap(), &low));
+    }
+}
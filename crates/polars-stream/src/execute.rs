@@ -1,13 +1,27 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
 use polars_core::POOL;
 use polars_core::frame::DataFrame;
 use polars_error::PolarsResult;
-use polars_expr::state::ExecutionState;
+use polars_expr::state::{CancelToken, ExecutionState};
 use polars_utils::aliases::PlHashSet;
+use polars_utils::pl_str::PlSmallStr;
 use slotmap::{SecondaryMap, SparseSecondaryMap};
 
-use crate::async_executor;
+use crate::async_executor::{self, JoinHandle, QueryRegistration, TaskPriority, TaskScope};
+use crate::async_primitives::connector::{Receiver, Sender};
 use crate::graph::{Graph, GraphNode, GraphNodeKey, LogicalPipeKey, PortState};
+use crate::metrics::{PipeMetrics, QueryMetrics, QueryMetricsCollector};
+use crate::morsel::Morsel;
+use crate::pause::PauseToken;
 use crate::pipe::PhysicalPipe;
+use crate::progress::{NodeProgress, get_progress_observer};
+use crate::runtime;
+use crate::skeleton::{BufferSizes, QueryOptions};
+use crate::utils::flush_deadline::FlushDeadline;
+use crate::utils::memory_budget::{MemoryBudget, MemoryReservation, get_memory_limit};
+use crate::utils::spill::get_spill_threshold;
 
 #[derive(Clone)]
 pub struct StreamingExecutionState {
@@ -16,6 +30,34 @@ pub struct StreamingExecutionState {
 
     // The ExecutionState passed to any non-streaming operations.
     pub in_memory_exec_state: ExecutionState,
+
+    // The memory the operators of this query may buffer, if limited.
+    pub memory_budget: Option<Arc<MemoryBudget>>,
+
+    // The capacities of the channels between the nodes of this query.
+    pub buffer_sizes: BufferSizes,
+
+    // Nodes flush their buffered rows once the oldest has waited this long, if set.
+    pub flush_deadline: Option<Duration>,
+
+    // Whether the sinks must write the morsels in order. The order within the query is always
+    // maintained, as nodes such as zips and slices depend on it.
+    pub maintain_order: bool,
+
+    // The number of bytes an operator may buffer before it spills to disk.
+    pub spill_threshold: usize,
+}
+
+impl StreamingExecutionState {
+    /// Returns a new reservation of the memory budget for the node with the given name.
+    pub fn memory_reservation(&self, node: &str) -> MemoryReservation {
+        MemoryReservation::new(self.memory_budget.clone(), node)
+    }
+
+    /// Returns a new [`FlushDeadline`] for a node which buffers rows.
+    pub fn flush_deadline(&self) -> FlushDeadline {
+        FlushDeadline::new(self.flush_deadline)
+    }
 }
 
 /// Finds all runnable pipeline blockers in the graph, that is, nodes which:
@@ -108,19 +150,130 @@ fn reuse_vec<T, U>(v: Vec<T>) -> Vec<U> {
     v.into_iter().filter_map(|_| None).collect()
 }
 
+/// The optional instrumentation of the graph execution.
+struct Instrumentation {
+    progress: Option<SecondaryMap<GraphNodeKey, Arc<NodeProgress>>>,
+    metrics: Option<QueryMetricsCollector>,
+    /// Whether a tracing event is emitted for every morsel sent.
+    trace_morsels: bool,
+    /// Holds back the output of the sources while the query is paused.
+    pause: Option<PauseToken>,
+}
+
+impl Instrumentation {
+    /// Whether the morsels sent over the pipes are observed.
+    fn taps_pipes(&self) -> bool {
+        self.progress.is_some()
+            || self.metrics.is_some()
+            || self.trace_morsels
+            || self.pause.is_some()
+    }
+}
+
+/// What the relay of a single pipe reports to, or is controlled by.
+struct RelayTaps<'a> {
+    progress: Option<&'a Arc<NodeProgress>>,
+    metrics: Option<&'a Arc<PipeMetrics>>,
+    trace_name: Option<&'a str>,
+    pause: Option<&'a PauseToken>,
+}
+
+/// Forwards the morsels sent by a node, reporting the rows it produced.
+fn spawn_relay<'env, 's>(
+    scope: &'s TaskScope<'s, 'env>,
+    receivers: Vec<Receiver<Morsel>>,
+    senders: Vec<Sender<Morsel>>,
+    taps: RelayTaps<'_>,
+    join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+) {
+    for (mut recv, mut send) in receivers.into_iter().zip(senders) {
+        let progress = taps.progress.cloned();
+        let metrics = taps.metrics.cloned();
+        let trace_name = taps.trace_name.map(PlSmallStr::from);
+        let pause = taps.pause.cloned();
+        join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+            while let Ok(morsel) = recv.recv().await {
+                if let Some(pause) = &pause {
+                    pause.wait_while_paused().await;
+                }
+                let df = morsel.df();
+                if let Some(name) = &trace_name {
+                    tracing::trace!(
+                        target: "polars_stream::morsel",
+                        node = name.as_str(),
+                        seq = morsel.seq().to_u64(),
+                        rows = df.height(),
+                        bytes = df.estimated_size(),
+                        "morsel sent"
+                    );
+                }
+                if let Some(progress) = &progress {
+                    progress.rows_produced(df.height() as u64, df.estimated_size() as u64);
+                }
+                if let Some(metrics) = &metrics {
+                    metrics.morsel_sent(df.height() as u64);
+                }
+                if send.send(morsel).await.is_err() {
+                    break;
+                }
+            }
+            Ok(())
+        }));
+    }
+}
+
 /// Runs the given subgraph. Assumes the set of pipes is correct for the subgraph.
 fn run_subgraph(
     graph: &mut Graph,
     nodes: &PlHashSet<GraphNodeKey>,
     pipes: &[LogicalPipeKey],
     state: &StreamingExecutionState,
+    instrumentation: &Instrumentation,
+    query: &QueryRegistration,
 ) -> PolarsResult<()> {
+    let tap_pipes_enabled = instrumentation.taps_pipes();
+    let progress = instrumentation.progress.as_ref();
+    let metrics = instrumentation.metrics.as_ref();
+
+    let phase_span = tracing::info_span!("phase", num_nodes = nodes.len());
+    let _phase_span = phase_span.enter();
+
     // Construct physical pipes for the logical pipes we'll use.
     let mut physical_pipes = SecondaryMap::new();
     for pipe_key in pipes.iter().copied() {
         physical_pipes.insert(pipe_key, PhysicalPipe::new(state.num_pipelines));
     }
 
+    // If we observe the pipes every pipe is split in two with a relay in between, the senders
+    // use these pipes.
+    let mut tap_pipes = SecondaryMap::new();
+    if tap_pipes_enabled {
+        for pipe_key in pipes.iter().copied() {
+            tap_pipes.insert(pipe_key, PhysicalPipe::new(state.num_pipelines));
+        }
+    }
+    if let Some(progress) = progress {
+        for node_key in nodes {
+            progress[*node_key].phase_started();
+        }
+    }
+    let phase_start = Instant::now();
+
+    // The nodes are mutably borrowed while spawning, so the names for tracing are collected first.
+    let node_names: SecondaryMap<GraphNodeKey, PlSmallStr> = if instrumentation.trace_morsels {
+        nodes
+            .iter()
+            .map(|k| (*k, graph.nodes[*k].compute.name().into()))
+            .collect()
+    } else {
+        SecondaryMap::new()
+    };
+    let source_nodes: PlHashSet<GraphNodeKey> = nodes
+        .iter()
+        .copied()
+        .filter(|k| graph.nodes[*k].inputs.is_empty())
+        .collect();
+
     // We do a topological sort of the graph: we want to spawn each node,
     // starting with the sinks and moving backwards. This order is important
     // for the initialization of physical pipes - the receive port must be
@@ -140,7 +293,7 @@ fn run_subgraph(
         }
     }
 
-    async_executor::task_scope(|scope| {
+    async_executor::query_task_scope(Some(query.tasks().clone()), |scope| {
         // Using SlotMap::iter_mut we can get simultaneous mutable references. By storing them and
         // removing the references from the secondary map as we do our topological sort we ensure
         // they are unique.
@@ -162,7 +315,11 @@ fn run_subgraph(
                 input_pipes.push(physical_pipes.remove(*input));
             }
             for output in &node.outputs {
-                output_pipes.push(physical_pipes.remove(*output));
+                if tap_pipes_enabled {
+                    output_pipes.push(tap_pipes.remove(*output));
+                } else {
+                    output_pipes.push(physical_pipes.remove(*output));
+                }
             }
 
             // Construct the receive/send ports.
@@ -174,6 +331,15 @@ fn run_subgraph(
             }
 
             // Spawn a task per pipeline.
+            if let Some(metrics) = metrics {
+                scope.set_poll_time_counter(Some(metrics.poll_time_counter(node_key)));
+            }
+            scope.set_task_span(tracing::info_span!(
+                parent: &phase_span,
+                "node",
+                name = node.compute.name(),
+                key = ?node_key
+            ));
             node.compute.spawn(
                 scope,
                 &mut recv_ports[..],
@@ -181,6 +347,8 @@ fn run_subgraph(
                 state,
                 &mut join_handles,
             );
+            scope.set_poll_time_counter(None);
+            scope.set_task_span(tracing::Span::none());
 
             // Ensure the ports were consumed.
             assert!(recv_ports.iter().all(|p| p.is_none()));
@@ -195,11 +363,30 @@ fn run_subgraph(
                 if let Some(pipe) = input_pipe {
                     physical_pipes.insert(*input, pipe);
 
+                    // Connect the pipe of the sender through a relay which observes its morsels.
+                    let sender = graph.pipes[*input].sender;
+                    if tap_pipes_enabled {
+                        let receivers = tap_pipes[*input].recv_port().parallel();
+                        let senders = physical_pipes[*input].send_port().parallel();
+                        let taps = RelayTaps {
+                            progress: progress.map(|p| &p[sender]),
+                            metrics: metrics.map(|m| m.pipe(*input)),
+                            trace_name: instrumentation
+                                .trace_morsels
+                                .then(|| node_names[sender].as_str()),
+                            // Only the sources are paused, the rest of the query drains.
+                            pause: instrumentation
+                                .pause
+                                .as_ref()
+                                .filter(|_| source_nodes.contains(&sender)),
+                        };
+                        spawn_relay(scope, receivers, senders, taps, &mut join_handles);
+                    }
+
                     // For all the receive ports we just initialized inside spawn(), decrement
                     // the num_send_ports_not_yet_ready for the node it was connected to and mark
                     // the node as ready to spawn if all its send ports are connected to
                     // initialized recv ports.
-                    let sender = graph.pipes[*input].sender;
                     if let Some(count) = num_send_ports_not_yet_ready.get_mut(sender) {
                         if *count > 0 {
                             *count -= 1;
@@ -212,7 +399,11 @@ fn run_subgraph(
             }
             for (output, output_pipe) in node.outputs.iter().zip(output_pipes.drain(..)) {
                 if let Some(pipe) = output_pipe {
-                    physical_pipes.insert(*output, pipe);
+                    if tap_pipes_enabled {
+                        tap_pipes.insert(*output, pipe);
+                    } else {
+                        physical_pipes.insert(*output, pipe);
+                    }
                 }
             }
 
@@ -223,8 +414,8 @@ fn run_subgraph(
 
         // Spawn tasks for all the physical pipes (no-op on most, but needed for
         // those with distributors or linearizers).
-        for pipe in physical_pipes.values_mut() {
-            pipe.spawn(scope, &mut join_handles);
+        for pipe in physical_pipes.values_mut().chain(tap_pipes.values_mut()) {
+            pipe.spawn(scope, &state.buffer_sizes, &mut join_handles);
         }
 
         // Wait until all tasks are done.
@@ -233,11 +424,20 @@ fn run_subgraph(
         if std::env::var("POLARS_TRACK_WAIT_STATS").as_deref() == Ok("1") {
             async_executor::track_task_wait_statistics(true);
         }
-        let ret = polars_io::pl_async::get_runtime().block_on(async move {
-            for handle in join_handles {
-                handle.await?;
-            }
-            PolarsResult::Ok(())
+        let cancel_token = state.in_memory_exec_state.cancel_token();
+        let ret = runtime::block_on(async move {
+            let tasks = std::pin::pin!(async move {
+                for handle in join_handles {
+                    handle.await?;
+                }
+                PolarsResult::Ok(())
+            });
+            let cancelled = std::pin::pin!(wait_for_cancellation(&cancel_token));
+            // Returning early drops the task scope, which cancels all tasks still running.
+            futures::future::select(tasks, cancelled)
+                .await
+                .factor_first()
+                .0
         });
         if std::env::var("POLARS_TRACK_WAIT_STATS").as_deref() == Ok("1") {
             async_executor::track_task_wait_statistics(false);
@@ -245,19 +445,88 @@ fn run_subgraph(
         ret
     })?;
 
+    if let Some(metrics) = metrics {
+        let phase_duration = phase_start.elapsed();
+        for node_key in nodes {
+            metrics.node_ran(*node_key, phase_duration);
+        }
+        metrics.sample_memory(graph);
+    }
+    if let Some(progress) = progress {
+        for node_key in nodes {
+            progress[*node_key].phase_finished();
+        }
+    }
+
     Ok(())
 }
 
+/// Returns an error once the query is cancelled.
+async fn wait_for_cancellation(cancel_token: &CancelToken) -> PolarsResult<()> {
+    cancel_token.cancelled().await;
+    Err(CancelToken::error())
+}
+
+/// Reports the nodes whose ports are all done, once.
+fn report_finished_nodes(
+    graph: &Graph,
+    progress: &SecondaryMap<GraphNodeKey, Arc<NodeProgress>>,
+    finished: &mut SecondaryMap<GraphNodeKey, ()>,
+) {
+    for (node_key, node) in graph.nodes.iter() {
+        if finished.contains_key(node_key) {
+            continue;
+        }
+        let inputs_done = node
+            .inputs
+            .iter()
+            .all(|i| graph.pipes[*i].recv_state == PortState::Done);
+        let outputs_done = node
+            .outputs
+            .iter()
+            .all(|o| graph.pipes[*o].send_state == PortState::Done);
+        if inputs_done && outputs_done {
+            progress[node_key].node_finished();
+            finished.insert(node_key, ());
+        }
+    }
+}
+
+/// Executes the graph, returning the output of the in-memory nodes and, if `collect_metrics` is
+/// set, the [`QueryMetrics`].
+///
+/// The execution is controlled by the [`QueryOptions`].
 pub fn execute_graph(
     graph: &mut Graph,
-) -> PolarsResult<SparseSecondaryMap<GraphNodeKey, DataFrame>> {
+    collect_metrics: bool,
+    options: QueryOptions,
+) -> PolarsResult<(
+    SparseSecondaryMap<GraphNodeKey, DataFrame>,
+    Option<QueryMetrics>,
+)> {
+    options.buffer_sizes.validate()?;
+
     // Get the number of threads from the rayon thread-pool as that respects our config.
     let num_pipelines = POOL.current_num_threads();
     async_executor::set_num_threads(num_pipelines);
+    // All queries share the executor, scheduled fairly according to their priority.
+    let query = QueryRegistration::new(options.priority);
 
+    let mut in_memory_exec_state = ExecutionState::default();
+    if let Some(cancel_token) = options.cancel_token {
+        in_memory_exec_state.set_cancel_token(cancel_token);
+    }
     let state = StreamingExecutionState {
         num_pipelines,
-        in_memory_exec_state: ExecutionState::default(),
+        in_memory_exec_state,
+        memory_budget: get_memory_limit()?.map(|limit| Arc::new(MemoryBudget::new(limit))),
+        buffer_sizes: options.buffer_sizes,
+        flush_deadline: options.flush_deadline,
+        maintain_order: options.maintain_order,
+        spill_threshold: match options.spill_threshold {
+            Some(threshold) => threshold,
+            None => get_spill_threshold()?,
+        },
     };
 
     // Ensure everything is properly connected.
@@ -272,11 +541,45 @@ pub fn execute_graph(
         }
     }
 
+    let start = Instant::now();
+    let progress = get_progress_observer().map(|observer| {
+        graph
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(id, (node_key, node))| {
+                let progress = NodeProgress::new(
+                    observer.clone(),
+                    id,
+                    node.compute.name(),
+                    node.expected_rows,
+                );
+                (node_key, Arc::new(progress))
+            })
+            .collect::<SecondaryMap<_, _>>()
+    });
+    let instrumentation = Instrumentation {
+        progress,
+        metrics: collect_metrics.then(|| QueryMetricsCollector::new(graph)),
+        trace_morsels: tracing::enabled!(target: "polars_stream::morsel", tracing::Level::TRACE),
+        pause: options.pause_token,
+    };
+    if let Some(metrics) = &instrumentation.metrics {
+        metrics.sample_memory(graph);
+    }
+    let mut finished = SecondaryMap::new();
+
     loop {
+        if state.in_memory_exec_state.cancel_token().is_cancelled() {
+            return Err(CancelToken::error());
+        }
         if polars_core::config::verbose() {
             eprintln!("polars-stream: updating graph state");
         }
         graph.update_all_states(&state)?;
+        if let Some(progress) = &instrumentation.progress {
+            report_finished_nodes(graph, progress, &mut finished);
+        }
         let (nodes, pipes) = find_runnable_subgraph(graph);
         if polars_core::config::verbose() {
             for node in &nodes {
@@ -289,7 +592,7 @@ pub fn execute_graph(
         if nodes.is_empty() {
             break;
         }
-        run_subgraph(graph, &nodes, &pipes, &state)?;
+        run_subgraph(graph, &nodes, &pipes, &state, &instrumentation, &query)?;
         if polars_core::config::verbose() {
             eprintln!("polars-stream: done running graph phase");
         }
@@ -308,5 +611,8 @@ pub fn execute_graph(
         }
     }
 
-    Ok(out)
+    let metrics = instrumentation
+        .metrics
+        .map(|metrics| metrics.finish(graph, start.elapsed()));
+    Ok((out, metrics))
 }
@@ -5,6 +5,8 @@ use polars_core::prelude::Column;
 use polars_error::PolarsResult;
 use polars_expr::prelude::{ExecutionState, PhysicalExpr};
 
+use crate::runtime;
+
 #[derive(Clone)]
 pub struct StreamExpr {
     inner: Arc<dyn PhysicalExpr>,
@@ -26,8 +28,7 @@ impl StreamExpr {
             let state = state.clone();
             let phys_expr = self.inner.clone();
             let df = df.clone();
-            polars_io::pl_async::get_runtime()
-                .spawn_blocking(move || phys_expr.evaluate(&df, &state))
+            runtime::spawn_blocking(move || phys_expr.evaluate(&df, &state))
                 .await
                 .unwrap()
         } else {
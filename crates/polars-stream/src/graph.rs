@@ -40,6 +40,7 @@ impl Graph {
             compute: Box::new(node),
             inputs: Vec::new(),
             outputs: Vec::new(),
+            expected_rows: None,
         });
 
         // Create and add pipes that connect input to output.
@@ -145,6 +146,9 @@ pub struct GraphNode {
     pub compute: Box<dyn ComputeNode>,
     pub inputs: Vec<LogicalPipeKey>,
     pub outputs: Vec<LogicalPipeKey>,
+    /// The (estimated) number of rows this node will produce, if known up front. Used to
+    /// estimate the completion of the query.
+    pub expected_rows: Option<u64>,
 }
 
 /// A pipe sends data between nodes.
@@ -4,15 +4,33 @@ mod skeleton;
 
 use std::sync::LazyLock;
 
-pub use skeleton::run_query;
+pub use async_executor::QueryPriority;
+pub use metrics::{NodeMetrics, QueryMetrics};
+pub use operator::{
+    StreamingOperator, StreamingOperatorFactory, StreamingOperatorUdf, register_streaming_operator,
+    unregister_streaming_operator,
+};
+pub use pause::PauseToken;
+pub use physical_plan::PhysicalPlanFormat;
+pub use progress::{ProgressEvent, ProgressObserver, set_progress_observer};
+pub use runtime::{StreamingRuntime, set_streaming_runtime};
+pub use skeleton::{
+    BufferSizes, QueryOptions, StreamingPlan, explain_analyze, run_query, run_query_with_metrics,
+    run_query_with_options, visualize_physical_plan,
+};
 
 mod execute;
 pub(crate) mod expression;
 mod graph;
+mod metrics;
 mod morsel;
 mod nodes;
+mod operator;
+mod pause;
 mod physical_plan;
 mod pipe;
+mod progress;
+mod runtime;
 mod utils;
 
 // TODO: experiment with these.
@@ -0,0 +1,170 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::time::Duration;
+
+use polars_core::frame::DataFrame;
+use polars_core::prelude::Column;
+use polars_error::PolarsResult;
+use polars_utils::pl_str::PlSmallStr;
+use slotmap::SecondaryMap;
+
+use crate::graph::{Graph, GraphNodeKey, LogicalPipeKey};
+
+/// The runtime metrics of a single node of a streaming query.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NodeMetrics {
+    /// The index of the node, the same as in the [`ProgressEvent`](crate::ProgressEvent)s.
+    pub node: usize,
+    pub name: PlSmallStr,
+    /// The number of rows the node received and sent, over all its ports.
+    pub rows_in: u64,
+    pub rows_out: u64,
+    /// The number of morsels the node sent.
+    pub morsels_out: u64,
+    /// The total duration of the execution phases the node ran in.
+    pub wall_time: Duration,
+    /// The time spent polling the tasks of the node. Work the node offloads to the thread pool is
+    /// not included.
+    pub cpu_time: Duration,
+    /// The peak number of bytes the node held in memory in between execution phases, as estimated
+    /// by the node itself. This is zero for nodes that don't buffer data.
+    pub peak_memory: usize,
+    /// The number of bytes the node spilled to disk once it exceeded its memory budget.
+    pub spilled_bytes: usize,
+}
+
+/// The runtime metrics of a streaming query, see [`run_query_with_metrics`](crate::run_query_with_metrics).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct QueryMetrics {
+    pub nodes: Vec<NodeMetrics>,
+    /// The duration of the whole query execution.
+    pub wall_time: Duration,
+}
+
+impl QueryMetrics {
+    /// Returns the metrics with a row per node. Durations are given in nanoseconds.
+    pub fn to_df(&self) -> PolarsResult<DataFrame> {
+        let nodes = &self.nodes;
+        let column = |name: &'static str, f: fn(&NodeMetrics) -> u64| {
+            Column::new(
+                PlSmallStr::from_static(name),
+                nodes.iter().map(f).collect::<Vec<_>>(),
+            )
+        };
+        DataFrame::new(vec![
+            column("node", |m| m.node as u64),
+            Column::new(
+                PlSmallStr::from_static("name"),
+                nodes.iter().map(|m| m.name.as_str()).collect::<Vec<_>>(),
+            ),
+            column("rows_in", |m| m.rows_in),
+            column("rows_out", |m| m.rows_out),
+            column("morsels_out", |m| m.morsels_out),
+            column("wall_time_ns", |m| m.wall_time.as_nanos() as u64),
+            column("cpu_time_ns", |m| m.cpu_time.as_nanos() as u64),
+            column("peak_memory", |m| m.peak_memory as u64),
+            column("spilled_bytes", |m| m.spilled_bytes as u64),
+        ])
+    }
+}
+
+/// The number of rows and morsels sent over a pipe.
+#[derive(Default)]
+pub(crate) struct PipeMetrics {
+    rows: AtomicU64,
+    morsels: AtomicU64,
+}
+
+impl PipeMetrics {
+    pub fn morsel_sent(&self, rows: u64) {
+        self.rows.fetch_add(rows, Ordering::Relaxed);
+        self.morsels.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[derive(Default)]
+struct NodeMetricsCollector {
+    ns_spent_polling: Arc<AtomicU64>,
+    ns_spent_running: AtomicU64,
+    peak_memory: AtomicUsize,
+}
+
+/// Collects the [`QueryMetrics`] while executing a graph.
+pub(crate) struct QueryMetricsCollector {
+    nodes: SecondaryMap<GraphNodeKey, NodeMetricsCollector>,
+    pipes: SecondaryMap<LogicalPipeKey, Arc<PipeMetrics>>,
+}
+
+impl QueryMetricsCollector {
+    pub fn new(graph: &Graph) -> Self {
+        Self {
+            nodes: graph
+                .nodes
+                .keys()
+                .map(|k| (k, Default::default()))
+                .collect(),
+            pipes: graph
+                .pipes
+                .keys()
+                .map(|k| (k, Default::default()))
+                .collect(),
+        }
+    }
+
+    pub fn pipe(&self, pipe_key: LogicalPipeKey) -> &Arc<PipeMetrics> {
+        &self.pipes[pipe_key]
+    }
+
+    /// The counter the time spent polling the tasks of this node is added to.
+    pub fn poll_time_counter(&self, node_key: GraphNodeKey) -> Arc<AtomicU64> {
+        self.nodes[node_key].ns_spent_polling.clone()
+    }
+
+    pub fn node_ran(&self, node_key: GraphNodeKey, duration: Duration) {
+        let ns: u64 = duration.as_nanos().try_into().unwrap();
+        self.nodes[node_key]
+            .ns_spent_running
+            .fetch_add(ns, Ordering::Relaxed);
+    }
+
+    pub fn sample_memory(&self, graph: &Graph) {
+        for (node_key, node) in graph.nodes.iter() {
+            self.nodes[node_key]
+                .peak_memory
+                .fetch_max(node.compute.buffered_bytes(), Ordering::Relaxed);
+        }
+    }
+
+    pub fn finish(self, graph: &Graph, wall_time: Duration) -> QueryMetrics {
+        let pipe_sum = |pipes: &[LogicalPipeKey], f: fn(&PipeMetrics) -> &AtomicU64| -> u64 {
+            pipes
+                .iter()
+                .map(|p| f(&self.pipes[*p]).load(Ordering::Relaxed))
+                .sum()
+        };
+        let nodes = graph
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(id, (node_key, node))| {
+                let collector = &self.nodes[node_key];
+                NodeMetrics {
+                    node: id,
+                    name: node.compute.name().into(),
+                    rows_in: pipe_sum(&node.inputs, |p| &p.rows),
+                    rows_out: pipe_sum(&node.outputs, |p| &p.rows),
+                    morsels_out: pipe_sum(&node.outputs, |p| &p.morsels),
+                    wall_time: Duration::from_nanos(
+                        collector.ns_spent_running.load(Ordering::Relaxed),
+                    ),
+                    cpu_time: Duration::from_nanos(
+                        collector.ns_spent_polling.load(Ordering::Relaxed),
+                    ),
+                    peak_memory: collector.peak_memory.load(Ordering::Relaxed),
+                    spilled_bytes: node.compute.spilled_bytes(),
+                }
+            })
+            .collect();
+        QueryMetrics { nodes, wall_time }
+    }
+}
@@ -1,8 +1,10 @@
 use std::future::Future;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::{Arc, OnceLock};
+use std::sync::{Arc, LazyLock, OnceLock};
+use std::time::Duration;
 
 use polars_core::frame::DataFrame;
+use polars_error::{PolarsResult, polars_err};
 
 use crate::async_primitives::wait_group::WaitToken;
 
@@ -16,6 +18,77 @@ pub fn get_ideal_morsel_size() -> usize {
     })
 }
 
+static ADAPTIVE_MORSEL_SIZE: LazyLock<bool> =
+    LazyLock::new(|| std::env::var("POLARS_ADAPTIVE_MORSEL_SIZE").as_deref() != Ok("0"));
+
+static TARGET_MORSEL_DURATION: LazyLock<Result<Duration, String>> = LazyLock::new(|| {
+    let Ok(micros) = std::env::var("POLARS_TARGET_MORSEL_DURATION_US") else {
+        return Ok(Duration::from_micros(10_000));
+    };
+    let micros = micros.parse::<u64>().map_err(|_| micros)?;
+    Ok(Duration::from_micros(micros))
+});
+
+/// The time a pipeline should spend on a morsel, read from `POLARS_TARGET_MORSEL_DURATION_US`.
+fn target_morsel_duration() -> PolarsResult<Duration> {
+    TARGET_MORSEL_DURATION.clone().map_err(|value| {
+        polars_err!(
+            InvalidOperation: "POLARS_TARGET_MORSEL_DURATION_US must be a number of microseconds, got '{}'",
+            value
+        )
+    })
+}
+
+/// Adapts the number of rows per morsel of a single pipeline to how long the morsels take to be
+/// processed.
+///
+/// Expensive pipelines get smaller morsels so that results (and backpressure) propagate quickly,
+/// cheap pipelines get larger morsels to reduce the per-morsel overhead. The size never changes
+/// by more than a factor two per morsel, and stays within 1/64 and 4 times the ideal morsel size.
+/// Setting `POLARS_ADAPTIVE_MORSEL_SIZE=0` disables this.
+pub struct MorselSizer {
+    size: usize,
+    target: Duration,
+}
+
+impl MorselSizer {
+    pub fn new(initial_size: usize) -> PolarsResult<Self> {
+        Ok(Self::with_target(initial_size, target_morsel_duration()?))
+    }
+
+    fn with_target(initial_size: usize, target: Duration) -> Self {
+        Self {
+            size: initial_size.max(1),
+            target,
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Updates the morsel size after a morsel of `rows` rows was consumed `elapsed` after it was
+    /// sent, of which `blocked` was spent waiting for room in the output channel.
+    pub fn observe(&mut self, rows: usize, elapsed: Duration, blocked: Duration) {
+        if !*ADAPTIVE_MORSEL_SIZE || rows == 0 {
+            return;
+        }
+
+        let processing = elapsed.saturating_sub(blocked).as_secs_f64().max(1e-6);
+        let ideal = rows as f64 * self.target.as_secs_f64() / processing;
+        let mut new_size = (ideal as usize).clamp(self.size / 2, self.size * 2);
+
+        // If we mostly wait for the consumer the pipeline is saturated, larger morsels would only
+        // add latency.
+        if blocked > elapsed.saturating_sub(blocked) {
+            new_size = new_size.min(self.size);
+        }
+
+        let ideal_morsel_size = get_ideal_morsel_size();
+        self.size = new_size.clamp((ideal_morsel_size / 64).max(1), ideal_morsel_size * 4);
+    }
+}
+
 /// A token indicating the order of morsels in a stream.
 ///
 /// The sequence tokens going through a pipe are monotonely non-decreasing and are allowed to be
@@ -162,3 +235,41 @@ impl Morsel {
         core::mem::replace(&mut self.source_token, new_token)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_morsel_sizer() {
+        if !*ADAPTIVE_MORSEL_SIZE {
+            return;
+        }
+        let ideal = get_ideal_morsel_size();
+        let target = Duration::from_millis(10);
+        let ms = Duration::from_millis;
+
+        // Expensive morsels shrink, by at most a factor two at a time.
+        let mut sizer = MorselSizer::with_target(ideal, target);
+        sizer.observe(ideal, ms(100), Duration::ZERO);
+        assert_eq!(sizer.size(), ideal / 2);
+        for _ in 0..20 {
+            sizer.observe(sizer.size(), ms(100), Duration::ZERO);
+        }
+        assert_eq!(sizer.size(), (ideal / 64).max(1));
+
+        // Cheap morsels grow, up to 4 times the ideal size.
+        let mut sizer = MorselSizer::with_target(ideal, target);
+        sizer.observe(ideal, ms(1), Duration::ZERO);
+        assert_eq!(sizer.size(), ideal * 2);
+        for _ in 0..20 {
+            sizer.observe(sizer.size(), ms(1), Duration::ZERO);
+        }
+        assert_eq!(sizer.size(), ideal * 4);
+
+        // A pipeline that mostly waits for its consumer doesn't grow its morsels.
+        let mut sizer = MorselSizer::with_target(ideal, target);
+        sizer.observe(ideal, ms(10), ms(9));
+        assert_eq!(sizer.size(), ideal);
+    }
+}
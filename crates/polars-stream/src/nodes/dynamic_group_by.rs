@@ -0,0 +1,387 @@
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_error::{polars_bail, polars_ensure};
+use polars_plan::plans::DataFrameUdf;
+use polars_plan::prelude::GroupbyOptions;
+use polars_time::Duration;
+use polars_time::prelude::StartBy;
+use polars_utils::pl_str::PlSmallStr;
+
+use super::compute_node_prelude::*;
+use crate::morsel::{SourceToken, get_ideal_morsel_size};
+use crate::nodes::in_memory_source::InMemorySourceNode;
+use crate::utils::memory_budget::MemoryReservation;
+
+pub const LB_NAME: &str = "_lower_boundary";
+pub const UP_NAME: &str = "_upper_boundary";
+const NS_PER_DAY: i64 = 24 * 60 * 60 * 1_000_000_000;
+
+/// How the windows of a [`DynamicGroupByNode`] are determined.
+pub enum WindowKind {
+    /// `group_by_dynamic`. The group-by always includes the window boundary columns, so that we
+    /// can determine which windows are complete.
+    Dynamic { include_boundaries: bool },
+    /// `rolling`. Every row `t` has the window `(t + offset, t + offset + period]` (modulo
+    /// closedness). Both are given in the physical unit of the index column.
+    Rolling { offset: i64, period: i64 },
+}
+
+impl WindowKind {
+    /// Returns the index column and window kind, or `None` if the group-by can't be streamed.
+    ///
+    /// Windows that start at the first data point can't be streamed as they depend on where the
+    /// buffer starts, and rolling windows must have a constant duration so that the boundaries
+    /// can be computed on the physical values.
+    pub fn try_new(
+        options: &GroupbyOptions,
+        input_schema: &Schema,
+    ) -> Option<(PlSmallStr, WindowKind)> {
+        if let Some(options) = &options.dynamic {
+            if options.start_by == StartBy::DataPoint {
+                return None;
+            }
+            let windows = WindowKind::Dynamic {
+                include_boundaries: options.include_boundaries,
+            };
+            Some((options.index_column.clone(), windows))
+        } else if let Some(options) = &options.rolling {
+            let dtype = input_schema.get(&options.index_column)?;
+            let (tz, ns_per_unit) = match dtype {
+                DataType::Datetime(tu, tz) => {
+                    let ns_per_unit = match tu {
+                        TimeUnit::Nanoseconds => 1,
+                        TimeUnit::Microseconds => 1_000,
+                        TimeUnit::Milliseconds => 1_000_000,
+                    };
+                    (tz.as_deref(), ns_per_unit)
+                },
+                DataType::Date => (None, NS_PER_DAY),
+                // Integer durations are stored as nanoseconds.
+                DataType::Int32 | DataType::Int64 | DataType::UInt32 | DataType::UInt64 => {
+                    (None, 1)
+                },
+                _ => return None,
+            };
+            if !options.period.is_constant_duration(tz) || !options.offset.is_constant_duration(tz)
+            {
+                return None;
+            }
+            let to_physical = |d: &Duration| {
+                let v = d.duration_ns() / ns_per_unit;
+                if d.negative() { -v } else { v }
+            };
+            let windows = WindowKind::Rolling {
+                offset: to_physical(&options.offset),
+                period: to_physical(&options.period),
+            };
+            Some((options.index_column.clone(), windows))
+        } else {
+            None
+        }
+    }
+}
+
+enum DynamicGroupByState {
+    Running,
+    Flushing(InMemorySourceNode),
+    Done,
+}
+
+/// A `group_by_dynamic` / `rolling` on an input sorted by the index column.
+///
+/// The input is buffered until enough rows are available, after which the in-memory group-by is
+/// run on the buffer. All windows that can no longer receive rows (their upper boundary lies
+/// before the last seen index value) are emitted, and rows only belonging to those windows are
+/// dropped from the buffer. This keeps the state bounded by the rows of the open windows.
+pub struct DynamicGroupByNode {
+    state: DynamicGroupByState,
+    windows: WindowBuffer,
+}
+
+impl DynamicGroupByNode {
+    pub fn new(
+        group_by: Arc<dyn DataFrameUdf>,
+        index_column: PlSmallStr,
+        windows: WindowKind,
+    ) -> Self {
+        Self {
+            state: DynamicGroupByState::Running,
+            windows: WindowBuffer {
+                group_by,
+                index_column,
+                kind: windows,
+                buffer: Vec::new(),
+                buffered_rows: 0,
+                buffered_bytes: 0,
+                reservation: None,
+                flush_threshold: get_ideal_morsel_size(),
+                last_time: None,
+                emitted_lower: None,
+                num_emitted: 0,
+                seq: MorselSeq::default(),
+            },
+        }
+    }
+}
+
+/// The rows of the open windows of a [`DynamicGroupByNode`], kept apart from its state so that
+/// the running task can borrow it while the state is borrowed by the flushing source.
+struct WindowBuffer {
+    group_by: Arc<dyn DataFrameUdf>,
+    index_column: PlSmallStr,
+    kind: WindowKind,
+    buffer: Vec<DataFrame>,
+    buffered_rows: usize,
+    buffered_bytes: usize,
+    /// Accounts for the buffered rows, set when the node is first spawned.
+    reservation: Option<MemoryReservation>,
+    /// Flush once this many rows are buffered. Grows if the open windows hold many rows, so that
+    /// we don't recompute large windows for every incoming morsel.
+    flush_threshold: usize,
+    /// The last index value seen, used to check that the input is sorted across morsels.
+    last_time: Option<i64>,
+    /// Dynamic: windows with a lower boundary below this have already been emitted.
+    emitted_lower: Option<i64>,
+    /// Rolling: the number of leading rows in the buffer whose output was already emitted.
+    num_emitted: usize,
+    seq: MorselSeq,
+}
+
+impl WindowBuffer {
+    fn push(&mut self, df: DataFrame) -> PolarsResult<()> {
+        if df.height() == 0 {
+            return Ok(());
+        }
+        let time = physical_time(df.column(&self.index_column)?)?;
+        polars_ensure!(
+            time.null_count() == 0,
+            ComputeError: "null values in dynamic group_by not supported, fill nulls."
+        );
+        let first = time.get(0).unwrap();
+        if let Some(last) = self.last_time {
+            if first < last {
+                polars_bail!(
+                    InvalidOperation:
+                    "argument in operation 'group_by_dynamic' is not sorted, please sort the 'expr/series/column' first"
+                );
+            }
+        }
+        self.last_time = time.last();
+        self.buffer_df(df)
+    }
+
+    fn buffer_df(&mut self, df: DataFrame) -> PolarsResult<()> {
+        let bytes = df.estimated_size();
+        self.reservation.as_ref().unwrap().grow(bytes)?;
+        self.buffered_bytes += bytes;
+        self.buffered_rows += df.height();
+        self.buffer.push(df);
+        Ok(())
+    }
+
+    /// Computes the output of all windows that are complete. If `finished` all windows are
+    /// considered complete.
+    fn flush(&mut self, finished: bool) -> PolarsResult<DataFrame> {
+        if self.buffer.is_empty() {
+            return Ok(DataFrame::empty());
+        }
+        let df = accumulate_dataframes_vertical_unchecked(self.buffer.drain(..));
+        self.reservation
+            .as_ref()
+            .unwrap()
+            .shrink(self.buffered_bytes);
+        self.buffered_bytes = 0;
+        self.buffered_rows = 0;
+        if df.height() == 0 {
+            return Ok(df);
+        }
+        let out = self.group_by.call_udf(df.clone())?;
+        let time = physical_time(df.column(&self.index_column)?)?;
+        let time = time.cont_slice().unwrap();
+        let t_last = *time.last().unwrap();
+
+        // Returns the output and the number of leading rows of the buffer that are no longer needed.
+        let (out, num_dropped) = match self.kind {
+            WindowKind::Dynamic { include_boundaries } => {
+                let lower = physical_time(out.column(LB_NAME)?)?;
+                let upper = physical_time(out.column(UP_NAME)?)?;
+                let lower = lower.cont_slice().unwrap();
+                let upper = upper.cont_slice().unwrap();
+
+                // Windows that were completed and emitted before can show up again if they
+                // overlap with the rows we kept around.
+                let start = match self.emitted_lower {
+                    Some(emitted) => lower.partition_point(|l| *l < emitted),
+                    None => 0,
+                };
+                let stop = if finished {
+                    out.height()
+                } else {
+                    start + upper[start..].partition_point(|u| *u < t_last)
+                };
+
+                // All rows before the first incomplete window only belong to complete windows.
+                let keep_from = lower.get(stop).copied().unwrap_or(t_last);
+                self.emitted_lower = Some(keep_from);
+
+                let mut out = out.slice(start as i64, stop - start);
+                if !include_boundaries {
+                    out = out.drop_many([LB_NAME, UP_NAME]);
+                }
+                (out, time.partition_point(|t| *t < keep_from))
+            },
+            WindowKind::Rolling { offset, period } => {
+                let start = self.num_emitted;
+                let stop = if finished {
+                    out.height()
+                } else {
+                    start
+                        + time[start..]
+                            .partition_point(|t| t.saturating_add(offset + period) < t_last)
+                };
+
+                // Rows are still needed by the windows of the rows that are not yet emitted, and
+                // the rows that are not yet emitted themselves.
+                let keep_from = time.get(stop).copied().unwrap_or(t_last) + offset;
+                let num_dropped = time.partition_point(|t| *t < keep_from).min(stop);
+                self.num_emitted = stop - num_dropped;
+
+                (out.slice(start as i64, stop - start), num_dropped)
+            },
+        };
+
+        if !finished {
+            let rest = df.slice(num_dropped as i64, df.height() - num_dropped);
+            self.buffer_df(rest)?;
+            self.flush_threshold = get_ideal_morsel_size().max(2 * self.buffered_rows);
+        }
+
+        Ok(out)
+    }
+}
+
+/// Gets the index column as its physical `i64` representation.
+fn physical_time(c: &Column) -> PolarsResult<Int64Chunked> {
+    let phys = c.to_physical_repr().cast(&DataType::Int64)?;
+    Ok(phys.as_materialized_series().i64()?.rechunk().into_owned())
+}
+
+impl ComputeNode for DynamicGroupByNode {
+    fn name(&self) -> &str {
+        match self.windows.kind {
+            WindowKind::Dynamic { .. } => "group_by_dynamic",
+            WindowKind::Rolling { .. } => "rolling",
+        }
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        use DynamicGroupByState::*;
+
+        if send[0] == PortState::Done {
+            self.state = Done;
+        }
+
+        if recv[0] == PortState::Done {
+            if let Running = &self.state {
+                let df = self.windows.flush(true)?;
+                self.state = if df.height() == 0 {
+                    Done
+                } else {
+                    Flushing(InMemorySourceNode::new(Arc::new(df), self.windows.seq))
+                };
+            }
+        }
+
+        match &mut self.state {
+            Running => {
+                recv.swap_with_slice(send);
+            },
+            Flushing(node) => {
+                recv[0] = PortState::Done;
+                node.update_state(&mut [], send, state)?;
+                if send[0] == PortState::Done {
+                    self.state = Done;
+                }
+            },
+            Done => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Done;
+            },
+        }
+        Ok(())
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 1 && send_ports.len() == 1);
+        let windows = match &mut self.state {
+            DynamicGroupByState::Running => {
+                self.windows
+                    .reservation
+                    .get_or_insert_with(|| state.memory_reservation("dynamic_group_by"));
+                &mut self.windows
+            },
+            DynamicGroupByState::Flushing(node) => {
+                assert!(recv_ports[0].is_none());
+                node.spawn(scope, &mut [], send_ports, state, join_handles);
+                return;
+            },
+            DynamicGroupByState::Done => {
+                assert!(recv_ports[0].is_none() && send_ports[0].is_none());
+                return;
+            },
+        };
+
+        let mut recv = recv_ports[0].take().unwrap().serial();
+        let mut send = send_ports[0].take().unwrap().serial();
+        let mut flush_deadline = state.flush_deadline();
+        join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+            let source_token = SourceToken::new();
+
+            loop {
+                // In the low-latency mode we also flush once the buffered rows waited too long.
+                match flush_deadline.race(recv.recv()).await {
+                    Some(Ok(morsel)) => {
+                        let num_buffered = windows.buffered_rows;
+                        windows.push(morsel.into_df())?;
+                        if windows.buffered_rows > num_buffered {
+                            flush_deadline.buffered();
+                        }
+                        if windows.buffered_rows < windows.flush_threshold
+                            && !flush_deadline.is_expired()
+                        {
+                            continue;
+                        }
+                    },
+                    Some(Err(())) => break,
+                    None => {},
+                }
+
+                let df = windows.flush(false)?;
+                flush_deadline.flushed();
+                if df.height() > 0 {
+                    let morsel = Morsel::new(df, windows.seq, source_token.clone());
+                    windows.seq = windows.seq.successor();
+                    if send.send(morsel).await.is_err() {
+                        break;
+                    }
+                }
+            }
+
+            Ok(())
+        }));
+    }
+}
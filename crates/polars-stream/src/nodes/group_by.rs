@@ -16,6 +16,7 @@ use crate::GROUP_BY_MIN_ROWS_PER_PARTITION;
 use crate::async_primitives::connector::Receiver;
 use crate::expression::StreamExpr;
 use crate::nodes::in_memory_source::InMemorySourceNode;
+use crate::utils::memory_budget::MemoryReservation;
 
 struct LocalGroupBySinkState {
     grouper: Box<dyn Grouper>,
@@ -42,6 +43,8 @@ struct GroupBySinkState {
     grouped_reductions: Vec<Box<dyn GroupedReduction>>,
     local: Vec<LocalGroupBySinkState>,
     random_state: PlRandomState,
+    /// Accounts for the keys of the groups, the state of the reductions is not included.
+    reservation: Option<MemoryReservation>,
 }
 
 impl GroupBySinkState {
@@ -62,6 +65,9 @@ impl GroupBySinkState {
                     .map(|r| r.new_empty())
                     .collect(),
             });
+        let reservation = &*self
+            .reservation
+            .get_or_insert_with(|| state.memory_reservation("group_by"));
         for (mut recv, local) in receivers.into_iter().zip(&mut self.local) {
             let key_selectors = &self.key_selectors;
             let grouped_reduction_selectors = &self.grouped_reduction_selectors;
@@ -79,8 +85,12 @@ impl GroupBySinkState {
                     }
                     let keys = DataFrame::new_with_broadcast_len(key_columns, df.height())?;
                     let hash_keys = HashKeys::from_df(&keys, *random_state, true, true);
+                    let key_size = hash_keys.estimated_size() / hash_keys.len().max(1);
+                    let num_groups = local.grouper.num_groups() as usize;
                     group_idxs.clear();
                     local.grouper.insert_keys(hash_keys, &mut group_idxs);
+                    let new_groups = local.grouper.num_groups() as usize - num_groups;
+                    reservation.grow(new_groups * key_size)?;
 
                     // Update reductions.
                     for (selector, reduction) in grouped_reduction_selectors
@@ -261,6 +271,7 @@ impl GroupByNode {
                 grouper,
                 local: Vec::new(),
                 random_state,
+                reservation: None,
             }),
             output_schema,
         }
@@ -76,6 +76,14 @@ impl ComputeNode for InMemoryMapNode {
         matches!(self, Self::Sink { .. })
     }
 
+    fn buffered_bytes(&self) -> usize {
+        match self {
+            Self::Sink { sink_node, .. } => sink_node.buffered_bytes(),
+            Self::Source(source_node) => source_node.buffered_bytes(),
+            Self::Done => 0,
+        }
+    }
+
     fn spawn<'env, 's>(
         &'env mut self,
         scope: &'s TaskScope<'s, 'env>,
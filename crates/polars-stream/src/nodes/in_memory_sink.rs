@@ -6,10 +6,12 @@ use polars_core::utils::accumulate_dataframes_vertical_unchecked;
 
 use super::compute_node_prelude::*;
 use crate::utils::in_memory_linearize::linearize;
+use crate::utils::memory_budget::MemoryReservation;
 
 pub struct InMemorySinkNode {
     morsels_per_pipe: Mutex<Vec<Vec<Morsel>>>,
     schema: Arc<Schema>,
+    reservation: Option<MemoryReservation>,
 }
 
 impl InMemorySinkNode {
@@ -17,6 +19,7 @@ impl InMemorySinkNode {
         Self {
             morsels_per_pipe: Mutex::default(),
             schema,
+            reservation: None,
         }
     }
 }
@@ -47,22 +50,36 @@ impl ComputeNode for InMemorySinkNode {
         true
     }
 
+    fn buffered_bytes(&self) -> usize {
+        self.morsels_per_pipe
+            .lock()
+            .iter()
+            .flatten()
+            .map(|morsel| morsel.df().estimated_size())
+            .sum()
+    }
+
     fn spawn<'env, 's>(
         &'env mut self,
         scope: &'s TaskScope<'s, 'env>,
         recv_ports: &mut [Option<RecvPort<'_>>],
         send_ports: &mut [Option<SendPort<'_>>],
-        _state: &'s StreamingExecutionState,
+        state: &'s StreamingExecutionState,
         join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
     ) {
         assert!(recv_ports.len() == 1 && send_ports.is_empty());
         let receivers = recv_ports[0].take().unwrap().parallel();
+        if self.reservation.is_none() {
+            self.reservation = Some(state.memory_reservation(self.name()));
+        }
 
         for mut recv in receivers {
             let slf = &*self;
             join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                let reservation = slf.reservation.as_ref().unwrap();
                 let mut morsels = Vec::new();
                 while let Ok(mut morsel) = recv.recv().await {
+                    reservation.grow(morsel.df().estimated_size())?;
                     morsel.take_consume_token();
                     morsels.push(morsel);
                 }
@@ -75,6 +92,8 @@ impl ComputeNode for InMemorySinkNode {
 
     fn get_output(&mut self) -> PolarsResult<Option<DataFrame>> {
         let morsels_per_pipe = core::mem::take(&mut *self.morsels_per_pipe.get_mut());
+        // The output is handed over, so it no longer counts towards the memory budget.
+        self.reservation = None;
         let dataframes = linearize(morsels_per_pipe);
         if dataframes.is_empty() {
             Ok(Some(DataFrame::empty_with_schema(&self.schema)))
@@ -1,14 +1,18 @@
 use std::sync::Arc;
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Instant;
+
+use parking_lot::Mutex;
 
 use super::compute_node_prelude::*;
 use crate::async_primitives::wait_group::WaitGroup;
-use crate::morsel::{MorselSeq, SourceToken, get_ideal_morsel_size};
+use crate::morsel::{MorselSeq, MorselSizer, SourceToken, get_ideal_morsel_size};
 
 pub struct InMemorySourceNode {
     source: Option<Arc<DataFrame>>,
+    /// The initial morsel size of each pipeline, which adapts from there.
     morsel_size: usize,
-    seq: AtomicU64,
+    /// The sequence number and row offset of the next morsel.
+    next: Mutex<(u64, usize)>,
     seq_offset: MorselSeq,
 }
 
@@ -17,7 +21,7 @@ impl InMemorySourceNode {
         InMemorySourceNode {
             source: Some(source),
             morsel_size: 0,
-            seq: AtomicU64::new(0),
+            next: Mutex::new((0, 0)),
             seq_offset,
         }
     }
@@ -28,6 +32,10 @@ impl ComputeNode for InMemorySourceNode {
         "in_memory_source"
     }
 
+    fn buffered_bytes(&self) -> usize {
+        self.source.as_ref().map_or(0, |df| df.estimated_size())
+    }
+
     fn update_state(
         &mut self,
         recv: &mut [PortState],
@@ -42,7 +50,7 @@ impl ComputeNode for InMemorySourceNode {
             let ideal_morsel_count = (len / get_ideal_morsel_size()).max(1);
             let morsel_count = ideal_morsel_count.next_multiple_of(state.num_pipelines);
             self.morsel_size = len.div_ceil(morsel_count).max(1);
-            self.seq = AtomicU64::new(0);
+            *self.next.get_mut() = (0, 0);
         }
 
         // As a temporary hack for some nodes (like the FunctionIR::FastCount)
@@ -50,8 +58,8 @@ impl ComputeNode for InMemorySourceNode {
         // morsel.
         // TODO: remove this hack.
         let exhausted = if let Some(src) = &self.source {
-            let seq = self.seq.load(Ordering::Relaxed);
-            seq > 0 && seq * self.morsel_size as u64 >= src.height() as u64
+            let (seq, offset) = *self.next.get_mut();
+            seq > 0 && offset >= src.height()
         } else {
             true
         };
@@ -83,10 +91,16 @@ impl ComputeNode for InMemorySourceNode {
             let source_token = source_token.clone();
             join_handles.push(scope.spawn_task(TaskPriority::Low, async move {
                 let wait_group = WaitGroup::default();
+                let mut sizer = MorselSizer::new(slf.morsel_size)?;
                 loop {
-                    let seq = slf.seq.fetch_add(1, Ordering::Relaxed);
-                    let offset = (seq as usize * slf.morsel_size) as i64;
-                    let df = source.slice(offset, slf.morsel_size);
+                    let (seq, offset) = {
+                        let mut next = slf.next.lock();
+                        let (seq, offset) = *next;
+                        *next = (seq + 1, offset + sizer.size());
+                        (seq, offset)
+                    };
+                    let df = source.slice(offset as i64, sizer.size());
+                    let rows = df.height();
 
                     // TODO: remove this 'always sent at least one morsel'
                     // condition, see update_state.
@@ -97,11 +111,14 @@ impl ComputeNode for InMemorySourceNode {
                     let morsel_seq = MorselSeq::new(seq).offset_by(slf.seq_offset);
                     let mut morsel = Morsel::new(df, morsel_seq, source_token.clone());
                     morsel.set_consume_token(wait_group.token());
+                    let start = Instant::now();
                     if send.send(morsel).await.is_err() {
                         break;
                     }
+                    let blocked = start.elapsed();
 
                     wait_group.wait().await;
+                    sizer.observe(rows, start.elapsed(), blocked);
                     if source_token.stop_requested() {
                         break;
                     }
@@ -60,7 +60,7 @@ impl SinkNode for CsvSinkNode {
             join_handles,
             recv_port_rx,
             state.num_pipelines,
-            self.sink_options.maintain_order,
+            self.sink_options.maintain_order && state.maintain_order,
         );
 
         // 16MB
@@ -100,6 +100,7 @@ impl SinkNode for IpcSinkNode {
             dist_tx,
             chunk_size as usize,
             self.input_schema.clone(),
+            state.flush_deadline(),
         ));
 
         // Encoding tasks.
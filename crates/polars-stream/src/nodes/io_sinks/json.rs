@@ -54,7 +54,7 @@ impl SinkNode for NDJsonSinkNode {
             join_handles,
             recv_port_rx,
             state.num_pipelines,
-            self.sink_options.maintain_order,
+            self.sink_options.maintain_order && state.maintain_order,
         );
 
         // 16MB
@@ -18,6 +18,8 @@ use crate::async_primitives::linearizer::{Inserter, Linearizer};
 use crate::async_primitives::wait_group::WaitGroup;
 use crate::execute::StreamingExecutionState;
 use crate::nodes::TaskPriority;
+use crate::runtime;
+use crate::utils::flush_deadline::FlushDeadline;
 
 #[cfg(feature = "csv")]
 pub mod csv;
@@ -65,11 +67,14 @@ impl SinkInputPort {
 
 /// Spawn a task that linearizes and buffers morsels until a given a maximum chunk size is reached
 /// and then distributes the columns amongst worker tasks.
+///
+/// With a `flush_deadline` smaller chunks are distributed once the buffered rows waited too long.
 fn buffer_and_distribute_columns_task(
     mut recv_port_rx: Receiver<(PhaseOutcome, SinkInputPort)>,
     mut dist_tx: distributor_channel::Sender<(usize, usize, Column)>,
     chunk_size: usize,
     schema: SchemaRef,
+    mut flush_deadline: FlushDeadline,
 ) -> JoinHandle<PolarsResult<()>> {
     spawn(TaskPriority::High, async move {
         let mut seq = 0usize;
@@ -77,12 +82,24 @@ fn buffer_and_distribute_columns_task(
 
         while let Ok((outcome, rx)) = recv_port_rx.recv().await {
             let mut rx = rx.serial();
-            while let Ok(morsel) = rx.recv().await {
-                let (df, _, _, consume_token) = morsel.into_inner();
-                // @NOTE: This also performs schema validation.
-                buffer.vstack_mut(&df)?;
+            loop {
+                let mut consume_token = None;
+                match flush_deadline.race(rx.recv()).await {
+                    Some(Ok(morsel)) => {
+                        let df;
+                        (df, _, _, consume_token) = morsel.into_inner();
+                        // @NOTE: This also performs schema validation.
+                        buffer.vstack_mut(&df)?;
+                        if df.height() > 0 {
+                            flush_deadline.buffered();
+                        }
+                    },
+                    Some(Err(())) => break,
+                    None => {},
+                }
 
-                while buffer.height() >= chunk_size {
+                let flush_partial = buffer.height() > 0 && flush_deadline.is_expired();
+                while buffer.height() >= chunk_size || (flush_partial && buffer.height() > 0) {
                     let df;
                     (df, buffer) = buffer.split_at(buffer.height().min(chunk_size) as i64);
 
@@ -93,6 +110,9 @@ fn buffer_and_distribute_columns_task(
                     }
                     seq += 1;
                 }
+                if buffer.height() == 0 {
+                    flush_deadline.flushed();
+                }
                 drop(consume_token); // Increase the backpressure. Only free up a pipeline when the
                 // morsel has started encoding in its entirety. This still
                 // allows for parallelism of Morsels, but prevents large
@@ -219,7 +239,7 @@ impl ComputeNode for SinkComputeNode {
         if recv[0] == PortState::Done {
             if let Some(mut started) = self.started.take() {
                 drop(started.input_send);
-                polars_io::pl_async::get_runtime().block_on(async move {
+                runtime::block_on(async move {
                     // Either the task finished or some error occurred.
                     while let Some(ret) = started.join_handles.next().await {
                         ret?;
@@ -262,11 +282,14 @@ impl ComputeNode for SinkComputeNode {
 
         let wait_group = WaitGroup::default();
         let recv = recv_ports[0].take().unwrap();
-        let sink_input = if self.sink.is_sink_input_parallel() {
-            SinkInputPort::Parallel(recv.parallel())
-        } else {
-            SinkInputPort::Serial(recv.serial_with_maintain_order(self.sink.do_maintain_order()))
-        };
+        let sink_input =
+            if self.sink.is_sink_input_parallel() {
+                SinkInputPort::Parallel(recv.parallel())
+            } else {
+                SinkInputPort::Serial(recv.serial_with_maintain_order(
+                    self.sink.do_maintain_order() && state.maintain_order,
+                ))
+            };
         join_handles.push(scope.spawn_task(TaskPriority::High, async move {
             let (token, outcome) = PhaseOutcome::new_shared_wait(wait_group.token());
             if started.input_send.send((outcome, sink_input)).await.is_ok() {
@@ -116,6 +116,7 @@ impl SinkNode for ParquetSinkNode {
                 .row_group_size
                 .unwrap_or(DEFAULT_ROW_GROUP_SIZE),
             self.input_schema.clone(),
+            state.flush_deadline(),
         ));
 
         // Encode task.
@@ -114,7 +114,7 @@ impl SinkNode for PartitionByKeySinkNode {
             join_handles,
             recv_port_rx,
             state.num_pipelines,
-            self.sink_options.maintain_order,
+            self.sink_options.maintain_order && state.maintain_order,
         );
 
         join_handles.extend(pass_rxs.into_iter().map(|mut pass_rx| {
@@ -0,0 +1,179 @@
+use futures::{StreamExt, TryStreamExt};
+use polars_core::frame::DataFrame;
+use polars_core::prelude::Column;
+use polars_error::{PolarsResult, polars_err};
+use polars_plan::dsl::{FileScan, ScanSource, ScanSources};
+use polars_utils::pl_str::PlSmallStr;
+use polars_utils::{IdxSize, format_pl_smallstr};
+use tokio::sync::oneshot;
+
+use super::multi_scan::max_concurrent_scans;
+use super::{
+    JoinHandle, Morsel, MorselSeq, SourceNode, SourceOutput, StreamingExecutionState, TaskPriority,
+};
+use crate::async_executor::spawn;
+use crate::async_primitives::connector::Receiver;
+use crate::morsel::SourceToken;
+
+/// Counts the rows of a scan without decoding its values, e.g. for `scan(..).select(len())`.
+///
+/// The row counts come from the metadata of Parquet and IPC files and from counting the lines of
+/// CSV files.
+pub struct CountSourceNode {
+    name: PlSmallStr,
+    sources: ScanSources,
+    scan_type: Box<FileScan>,
+    alias: PlSmallStr,
+}
+
+impl CountSourceNode {
+    pub fn new(sources: ScanSources, scan_type: Box<FileScan>, alias: Option<PlSmallStr>) -> Self {
+        let scan_type_name: &str = (&*scan_type).into();
+        Self {
+            name: format_pl_smallstr!("count[{scan_type_name}]"),
+            sources,
+            scan_type,
+            alias: alias.unwrap_or(PlSmallStr::from_static(polars_plan::constants::LEN)),
+        }
+    }
+
+    /// Whether the rows of this type of scan can be counted without decoding them.
+    pub fn is_supported(scan_type: &FileScan) -> bool {
+        match scan_type {
+            #[cfg(feature = "csv")]
+            FileScan::Csv { .. } => true,
+            #[cfg(feature = "ipc")]
+            FileScan::Ipc { .. } => true,
+            #[cfg(feature = "parquet")]
+            FileScan::Parquet { .. } => true,
+            _ => false,
+        }
+    }
+}
+
+async fn count_rows(
+    source: ScanSource,
+    scan_type: &FileScan,
+    scan_source_idx: usize,
+) -> PolarsResult<IdxSize> {
+    // Only the readers of some formats need these.
+    #[cfg(not(any(feature = "csv", feature = "ipc", feature = "parquet")))]
+    let _ = source;
+    #[cfg(not(feature = "parquet"))]
+    let _ = scan_source_idx;
+
+    match scan_type {
+        #[cfg(feature = "csv")]
+        FileScan::Csv {
+            options,
+            cloud_options,
+        } => {
+            use super::csv::CsvSourceNode;
+            use super::multi_scan::MultiScanable;
+
+            let mut source = <CsvSourceNode as MultiScanable>::new(
+                source,
+                options,
+                cloud_options.as_ref(),
+                None,
+            )
+            .await?;
+            source.unrestricted_row_count().await
+        },
+        #[cfg(feature = "ipc")]
+        FileScan::Ipc {
+            options,
+            cloud_options,
+            ..
+        } => {
+            use super::ipc::IpcSourceNode;
+            use super::multi_scan::MultiScanable;
+
+            let mut source = <IpcSourceNode as MultiScanable>::new(
+                source,
+                options,
+                cloud_options.as_ref(),
+                None,
+            )
+            .await?;
+            source.unrestricted_row_count().await
+        },
+        #[cfg(feature = "parquet")]
+        FileScan::Parquet {
+            options,
+            cloud_options,
+            metadata,
+        } => {
+            use std::sync::Arc;
+
+            use super::multi_file_reader::reader_interface::builder::FileReaderBuilder;
+            use super::parquet::builder::ParquetReaderBuilder;
+
+            let builder = ParquetReaderBuilder {
+                first_metadata: metadata.clone(),
+                options: Arc::new(options.clone()),
+            };
+            let mut reader = builder.build_file_reader(
+                source,
+                cloud_options.clone().map(Arc::new),
+                scan_source_idx,
+            );
+            reader.initialize().await?;
+            reader.n_rows_in_file().await
+        },
+        _ => unreachable!(),
+    }
+}
+
+impl SourceNode for CountSourceNode {
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn is_source_output_parallel(&self, _is_receiver_serial: bool) -> bool {
+        false
+    }
+
+    /// Sends a single morsel with the row count. The count is also sent to
+    /// `unrestricted_row_count`, if given.
+    fn spawn_source(
+        &mut self,
+        mut output_recv: Receiver<SourceOutput>,
+        state: &StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+        unrestricted_row_count: Option<oneshot::Sender<IdxSize>>,
+    ) {
+        let sources = self.sources.clone();
+        let scan_type = self.scan_type.clone();
+        let alias = self.alias.clone();
+        let max_concurrent_scans = max_concurrent_scans(state.num_pipelines);
+        join_handles.push(spawn(TaskPriority::Low, async move {
+            let Ok(phase_output) = output_recv.recv().await else {
+                return Ok(());
+            };
+
+            let num_rows: usize = futures::stream::iter(0..sources.len())
+                .map(|i| {
+                    let scan_type = scan_type.as_ref();
+                    let source = sources.at(i).into_owned();
+                    async move { count_rows(source?, scan_type, i).await }
+                })
+                .buffered(max_concurrent_scans)
+                .try_fold(0, |acc, n| async move { Ok(acc + n as usize) })
+                .await?;
+            let num_rows = IdxSize::try_from(num_rows)
+                .map_err(|_| polars_err!(bigidx, ctx = "scan", size = num_rows))?;
+
+            if let Some(unrestricted_row_count) = unrestricted_row_count {
+                _ = unrestricted_row_count.send(num_rows);
+            }
+
+            let df = DataFrame::new(vec![Column::new(alias, [num_rows])])?;
+            let mut sender = phase_output.port.serial();
+            _ = sender
+                .send(Morsel::new(df, MorselSeq::default(), SourceToken::new()))
+                .await;
+            Ok(())
+        }));
+    }
+}
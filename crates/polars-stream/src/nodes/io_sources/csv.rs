@@ -26,7 +26,6 @@ use polars_utils::pl_str::PlSmallStr;
 
 use super::multi_scan::MultiScanable;
 use super::{RowRestriction, SourceNode, SourceOutput};
-use crate::DEFAULT_DISTRIBUTOR_BUFFER_SIZE;
 use crate::async_executor::{self, spawn};
 use crate::async_primitives::connector::{Receiver, connector};
 use crate::async_primitives::distributor_channel::distributor_channel;
@@ -102,7 +101,7 @@ impl SourceNode for CsvSourceNode {
         self.schema = Some(self.file_info.reader_schema.take().unwrap().unwrap_right());
 
         let (line_batch_receivers, chunk_reader, line_batch_source_task_handle) =
-            self.init_line_batch_source(state.num_pipelines, unrestricted_row_count);
+            self.init_line_batch_source(state, unrestricted_row_count);
 
         join_handles.extend(line_batch_receivers.into_iter().zip(recv_from).map(
             |(mut line_batch_rx, mut recv_from)| {
@@ -188,13 +187,14 @@ impl SourceNode for CsvSourceNode {
 impl CsvSourceNode {
     fn init_line_batch_source(
         &mut self,
-        num_pipelines: usize,
+        state: &StreamingExecutionState,
         unrestricted_row_count: Option<tokio::sync::oneshot::Sender<IdxSize>>,
     ) -> AsyncTaskData {
         let verbose = self.verbose;
+        let num_pipelines = state.num_pipelines;
 
         let (mut line_batch_sender, line_batch_receivers) =
-            distributor_channel(num_pipelines, *DEFAULT_DISTRIBUTOR_BUFFER_SIZE);
+            distributor_channel(num_pipelines, state.buffer_sizes.distributor);
 
         let scan_source = self.scan_source.clone();
         let run_async = matches!(&scan_source, ScanSource::Path(p) if polars_io::is_cloud_url(p) || config::force_async());
@@ -37,7 +37,6 @@ use crate::async_primitives::wait_group::WaitGroup;
 use crate::execute::StreamingExecutionState;
 use crate::morsel::{SourceToken, get_ideal_morsel_size};
 use crate::nodes::{JoinHandle, Morsel, MorselSeq, TaskPriority};
-use crate::{DEFAULT_DISTRIBUTOR_BUFFER_SIZE, DEFAULT_LINEARIZER_BUFFER_SIZE};
 
 const ROW_COUNT_OVERFLOW_ERR: PolarsError = PolarsError::ComputeError(ErrString::new_static(
     "\
@@ -85,6 +84,8 @@ impl IpcSourceNode {
             glob: _,
             include_file_paths: _,
             allow_missing_columns: _,
+            column_parsers: _, // Applied on top of the scan.
+            .. // The `cast_policy` is only set for Parquet scans.
         } = file_options;
 
         let memslice = {
@@ -221,12 +222,12 @@ impl SourceNode for IpcSourceNode {
 
         // Walker task -> Decoder tasks.
         let (mut batch_tx, batch_rxs) =
-            distributor_channel::<BatchMessage>(num_pipelines, *DEFAULT_DISTRIBUTOR_BUFFER_SIZE);
+            distributor_channel::<BatchMessage>(num_pipelines, state.buffer_sizes.distributor);
         // Decoder tasks -> Distributor task.
         let (mut decoded_rx, decoded_tx) =
             Linearizer::<Priority<Reverse<MorselSeq>, DataFrame>>::new(
                 num_pipelines,
-                *DEFAULT_LINEARIZER_BUFFER_SIZE,
+                state.buffer_sizes.linearizer,
             );
 
         // Distributor task.
@@ -18,6 +18,7 @@ use crate::nodes::compute_node_prelude::*;
 pub mod multi_file_reader;
 
 pub mod batch;
+pub mod count;
 #[cfg(feature = "csv")]
 pub mod csv;
 #[cfg(feature = "ipc")]
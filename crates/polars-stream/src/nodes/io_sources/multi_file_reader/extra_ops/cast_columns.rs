@@ -1,7 +1,15 @@
+#[cfg(feature = "cast_policy")]
+use std::sync::Arc;
+
 use polars_core::frame::DataFrame;
 use polars_core::prelude::DataType;
 use polars_core::schema::SchemaRef;
 use polars_error::{PolarsResult, polars_bail};
+use polars_io::predicates::ScanIOPredicate;
+#[cfg(feature = "cast_policy")]
+use polars_ops::frame::cast_policy::{LossyCasts, cast_column};
+#[cfg(feature = "cast_policy")]
+use polars_utils::pl_str::PlSmallStr;
 
 /// TODO: Eventually move this enum to polars-plan
 #[derive(Debug, Clone, Default)]
@@ -9,10 +17,19 @@ pub enum CastColumnsPolicy {
     /// Raise an error if the datatypes do not match
     #[default]
     ErrorOnMismatch,
+    /// Cast the columns to the target datatypes, dealing with lossy casts as given.
+    #[cfg(feature = "cast_policy")]
+    Cast(LossyCasts),
 }
 
 #[derive(Debug)]
-pub struct CastColumns {}
+pub struct CastColumns {
+    /// The columns to cast, with their target datatypes.
+    #[cfg(feature = "cast_policy")]
+    casts: Vec<(PlSmallStr, DataType)>,
+    #[cfg(feature = "cast_policy")]
+    lossy_casts: LossyCasts,
+}
 
 impl CastColumns {
     pub fn try_init_from_policy(
@@ -52,11 +69,51 @@ impl CastColumns {
 
                 Ok(None)
             },
+            #[cfg(feature = "cast_policy")]
+            CastColumnsPolicy::Cast(lossy_casts) => {
+                let casts = incoming_schema_iter
+                    .filter_map(|(name, dtype)| {
+                        let target_dtype = target_schema
+                            .get(name)
+                            .expect("impl error: column should exist in casting map");
+                        (dtype != target_dtype).then(|| (name.into(), target_dtype.clone()))
+                    })
+                    .collect::<Vec<_>>();
+
+                Ok((!casts.is_empty()).then_some(Self { casts, lossy_casts }))
+            },
         }
     }
 
+    #[cfg(feature = "cast_policy")]
+    pub fn apply_cast(&self, df: &mut DataFrame) -> PolarsResult<()> {
+        for (name, dtype) in &self.casts {
+            let idx = df.try_get_column_index(name)?;
+            let column = cast_column(&df.get_columns()[idx], dtype, self.lossy_casts)?;
+            unsafe { df.get_columns_mut()[idx] = column };
+        }
+        df.clear_schema();
+        Ok(())
+    }
+
+    /// Evaluate `predicate` on the columns after casting them.
+    #[cfg(feature = "cast_policy")]
+    pub fn cast_before_predicate(self, predicate: &mut ScanIOPredicate) {
+        let lossy_casts = self.lossy_casts;
+        predicate.set_column_casts(
+            self.casts,
+            Arc::new(move |column, dtype| cast_column(column, dtype, lossy_casts)),
+        );
+    }
+
+    /// Only the `Cast` policy creates [`CastColumns`], which needs the `cast_policy` feature.
+    #[cfg(not(feature = "cast_policy"))]
     pub fn apply_cast(&self, _df: &mut DataFrame) -> PolarsResult<()> {
-        unimplemented!()
-        // df.clear_schema(); // remember to do this if cast was applied
+        unreachable!()
+    }
+
+    #[cfg(not(feature = "cast_policy"))]
+    pub fn cast_before_predicate(self, _predicate: &mut ScanIOPredicate) {
+        unreachable!()
     }
 }
@@ -37,13 +37,16 @@ impl ExtraOperations {
 
 /// TODO: Eventually move this enum to polars-plan
 #[derive(Clone)]
-#[expect(unused)]
 pub enum SchemaNamesMatchPolicy {
     /// * If the schema lengths match, ensure that all columns match in the same order
     /// * Otherwise, ensure that there are no extra columns in the incoming schema that
     ///   cannot be found in the target schema.
     ///   * Ignores if the incoming schema is missing columns, this is handled by a separate module.
+    #[expect(unused)]
     OrderedExact,
+    /// Ensure that there are no extra columns in the incoming schema that cannot be found in the
+    /// target schema, in any order.
+    NoExtraColumns,
 }
 
 impl SchemaNamesMatchPolicy {
@@ -78,6 +81,20 @@ impl SchemaNamesMatchPolicy {
                     )
                 }
 
+                Ok(())
+            },
+            NoExtraColumns => {
+                if let Some(extra_col) = incoming_schema
+                    .iter_names()
+                    .find(|x| !target_schema.contains(x))
+                {
+                    polars_bail!(
+                        SchemaMismatch:
+                        "column {} is not in the target schema, consider dropping extra columns",
+                        extra_col,
+                    )
+                }
+
                 Ok(())
             },
         }
@@ -13,9 +13,11 @@ use initialization::MultiScanTaskInitializer;
 use polars_core::config;
 use polars_core::schema::SchemaRef;
 use polars_error::PolarsResult;
+use polars_io::RowIndex;
 use polars_io::cloud::CloudOptions;
 use polars_io::predicates::ScanIOPredicate;
-use polars_io::{RowIndex, pl_async};
+#[cfg(feature = "cast_policy")]
+use polars_ops::frame::cast_policy::CastPolicy;
 use polars_plan::dsl::ScanSources;
 use polars_plan::plans::hive::HivePartitionsDf;
 use polars_utils::format_pl_smallstr;
@@ -30,6 +32,7 @@ use crate::execute::StreamingExecutionState;
 use crate::graph::PortState;
 use crate::morsel::Morsel;
 use crate::nodes::ComputeNode;
+use crate::runtime;
 
 // Some parts are called MultiFileReader for now to avoid conflict with existing MultiScan.
 
@@ -52,8 +55,12 @@ pub struct MultiFileReaderConfig {
     hive_parts: Option<Arc<HivePartitionsDf>>,
     include_file_paths: Option<PlSmallStr>,
     allow_missing_columns: bool,
+    #[cfg(feature = "cast_policy")]
+    cast_policy: Option<CastPolicy>,
 
     num_pipelines: AtomicUsize,
+    /// Number of row groups a Parquet reader may prefetch.
+    row_group_prefetch_size: AtomicUsize,
     /// Number of readers to initialize concurrently. e.g. Parquet will want to fetch metadata in this
     /// step.
     n_readers_pre_init: usize,
@@ -67,6 +74,11 @@ impl MultiFileReaderConfig {
             .load(std::sync::atomic::Ordering::Relaxed)
     }
 
+    fn row_group_prefetch_size(&self) -> usize {
+        self.row_group_prefetch_size
+            .load(std::sync::atomic::Ordering::Relaxed)
+    }
+
     fn verbose(&self) -> bool {
         self.verbose.load(std::sync::atomic::Ordering::Relaxed)
     }
@@ -132,7 +144,10 @@ impl MultiFileReader {
                     hive_parts,
                     include_file_paths,
                     allow_missing_columns,
+                    #[cfg(feature = "cast_policy")]
+                    cast_policy: None,
                     num_pipelines: AtomicUsize::new(0),
+                    row_group_prefetch_size: AtomicUsize::new(0),
                     n_readers_pre_init: 3,
                     verbose: AtomicBool::new(false),
                 }),
@@ -140,6 +155,17 @@ impl MultiFileReader {
             verbose: config::verbose(),
         }
     }
+
+    /// Conform every file to the full file schema with this policy, instead of requiring the files
+    /// to match it.
+    #[cfg(feature = "cast_policy")]
+    pub fn with_cast_policy(mut self, cast_policy: Option<CastPolicy>) -> Self {
+        let MultiScanState::Uninitialized { config } = &mut self.state else {
+            unreachable!()
+        };
+        Arc::get_mut(config).unwrap().cast_policy = cast_policy;
+        self
+    }
 }
 
 impl ComputeNode for MultiFileReader {
@@ -165,8 +191,9 @@ impl ComputeNode for MultiFileReader {
             // Refresh first - in case there is an error we end here instead of ending when we go
             // into spawn.
             async_executor::task_scope(|s| {
-                pl_async::get_runtime()
-                    .block_on(s.spawn_task(TaskPriority::High, self.state.refresh(self.verbose)))
+                runtime::block_on(
+                    s.spawn_task(TaskPriority::High, self.state.refresh(self.verbose)),
+                )
             })?;
 
             match self.state {
@@ -190,12 +217,14 @@ impl ComputeNode for MultiFileReader {
 
         let phase_morsel_tx = send_ports[0].take().unwrap().serial();
         let num_pipelines = state.num_pipelines;
+        let row_group_prefetch_size = state.buffer_sizes.row_group_prefetch;
         let verbose = self.verbose;
 
         join_handles.push(scope.spawn_task(TaskPriority::Low, async move {
             use MultiScanState::*;
 
-            self.state.initialize(num_pipelines, verbose);
+            self.state
+                .initialize(num_pipelines, row_group_prefetch_size, verbose);
             self.state.refresh(verbose).await?;
 
             match &mut self.state {
@@ -291,7 +320,7 @@ impl MultiScanState {
     }
 
     /// Initialize state if not yet initialized.
-    fn initialize(&mut self, num_pipelines: usize, verbose: bool) {
+    fn initialize(&mut self, num_pipelines: usize, row_group_prefetch_size: usize, verbose: bool) {
         use MultiScanState::*;
 
         let slf = std::mem::replace(self, Finished);
@@ -304,6 +333,10 @@ impl MultiScanState {
         config
             .num_pipelines
             .store(num_pipelines, std::sync::atomic::Ordering::Relaxed);
+        config.row_group_prefetch_size.store(
+            row_group_prefetch_size,
+            std::sync::atomic::Ordering::Relaxed,
+        );
         config
             .verbose
             .store(verbose, std::sync::atomic::Ordering::Relaxed);
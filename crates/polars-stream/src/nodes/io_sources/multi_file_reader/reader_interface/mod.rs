@@ -59,6 +59,14 @@ pub trait FileReader: Send + Sync {
         }
     }
 
+    /// The number of rows in the file if it is known without reading the file, e.g. from the
+    /// metadata fetched by `initialize()`.
+    ///
+    /// This FileReader must be initialized before calling this.
+    fn fast_n_rows_in_file(&self) -> Option<IdxSize> {
+        None
+    }
+
     /// Returns the row position after applying a slice.
     ///
     /// This is essentially `n_rows_in_file`, but potentially with early stopping.
@@ -121,6 +129,8 @@ pub struct BeginReadArgs {
     pub missing_columns_policy: MissingColumnsPolicy,
 
     pub num_pipelines: usize,
+    /// Number of row groups a reader may fetch ahead of decoding.
+    pub row_group_prefetch_size: usize,
     pub callbacks: FileReaderCallbacks,
     // TODO
     // We could introduce dynamic `Option<Box<dyn Any>>` for the reader to use. That would help
@@ -139,6 +149,7 @@ impl Default for BeginReadArgs {
             cast_columns_policy: CastColumnsPolicy::ErrorOnMismatch,
             missing_columns_policy: MissingColumnsPolicy::Insert,
             num_pipelines: 1,
+            row_group_prefetch_size: polars_core::config::get_rg_prefetch_size(),
             callbacks: FileReaderCallbacks::default(),
         }
     }
@@ -1,5 +1,6 @@
 use std::collections::VecDeque;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 use arrow::bitmap::Bitmap;
 use futures::StreamExt;
@@ -9,6 +10,8 @@ use polars_core::scalar::Scalar;
 use polars_core::schema::SchemaRef;
 use polars_error::PolarsResult;
 use polars_io::predicates::ScanIOPredicate;
+#[cfg(feature = "cast_policy")]
+use polars_ops::frame::cast_policy::{ExtraColumns, MissingColumns};
 use polars_plan::dsl::ScanSource;
 use polars_plan::plans::hive::HivePartitionsDf;
 use polars_utils::IdxSize;
@@ -94,16 +97,30 @@ impl MultiScanTaskInitializer {
             },
         };
 
+        #[cfg(feature = "cast_policy")]
+        let (insert_missing_columns, cast_columns_policy, check_schema_names) =
+            match self.config.cast_policy {
+                Some(policy) => (
+                    policy.missing_columns == MissingColumns::Insert,
+                    CastColumnsPolicy::Cast(policy.lossy_casts),
+                    (policy.extra_columns == ExtraColumns::Raise)
+                        .then_some(SchemaNamesMatchPolicy::NoExtraColumns),
+                ),
+                None => (false, CastColumnsPolicy::ErrorOnMismatch, None),
+            };
+        #[cfg(not(feature = "cast_policy"))]
+        let (insert_missing_columns, cast_columns_policy, check_schema_names) =
+            (false, CastColumnsPolicy::ErrorOnMismatch, None);
+
         let extra_ops = ExtraOperations {
             row_index,
             pre_slice,
-            missing_columns_policy: if self.config.allow_missing_columns {
+            missing_columns_policy: if self.config.allow_missing_columns || insert_missing_columns {
                 MissingColumnsPolicy::Insert
             } else {
                 MissingColumnsPolicy::Raise
             },
-            // TODO: Expose config for this
-            cast_columns_policy: CastColumnsPolicy::ErrorOnMismatch,
+            cast_columns_policy,
             include_file_paths: self.config.include_file_paths.clone(),
             predicate,
         };
@@ -131,6 +148,15 @@ impl MultiScanTaskInitializer {
 
         let config = self.config.clone();
 
+        // With a positive slice, files past the end of the slice are never read. Readers that know
+        // their row count after initialization add it here, so that we stop initializing further
+        // files once the files initialized so far cover the slice.
+        let slice_end = match &extra_ops.pre_slice {
+            Some(slice @ Slice::Positive { .. }) => slice.end_position(),
+            _ => usize::MAX,
+        };
+        let n_rows_initialized = Arc::new(AtomicUsize::new(0));
+
         // Buffered initialization stream. This concurrently calls `FileReader::initialize()`,
         // allowing for e.g. concurrent Parquet metadata fetch.
         let readers_init_iter = {
@@ -156,12 +182,31 @@ impl MultiScanTaskInitializer {
                 )
             }
 
+            let n_rows_initialized_2 = n_rows_initialized.clone();
+
             futures::stream::iter(range)
+                .take_while(move |scan_source_idx| {
+                    let n_rows = n_rows_initialized_2.load(Ordering::Relaxed);
+                    // Only files before `scan_source_idx` have been initialized, so they contain
+                    // the end of the slice.
+                    let covered = n_rows >= slice_end;
+
+                    if covered && verbose {
+                        eprintln!(
+                            "[MultiScanTaskInitializer]: Stopping reader initialization at \
+                            source {} (pre_slice end: {}, initialized rows: {})",
+                            scan_source_idx, slice_end, n_rows
+                        )
+                    }
+
+                    std::future::ready(!covered)
+                })
                 .map(move |scan_source_idx| {
                     let cloud_options = config.cloud_options.clone();
                     let file_reader_builder = config.file_reader_builder.clone();
                     let sources = config.sources.clone();
                     let skip_files_mask = skip_files_mask.clone();
+                    let n_rows_initialized = n_rows_initialized.clone();
 
                     let maybe_initialized = initialized_readers.pop_front();
                     let scan_source = sources.get(scan_source_idx).unwrap().into_owned();
@@ -198,6 +243,14 @@ impl MultiScanTaskInitializer {
                             }
 
                             reader.initialize().await?;
+
+                            if slice_end != usize::MAX {
+                                if let Some(n_rows) = reader.fast_n_rows_in_file() {
+                                    n_rows_initialized
+                                        .fetch_add(n_rows as usize, Ordering::Relaxed);
+                                }
+                            }
+
                             PolarsResult::Ok((scan_source, reader, None))
                         }
                         .await?;
@@ -219,6 +272,7 @@ impl MultiScanTaskInitializer {
         let projected_file_schema = self.config.projected_file_schema.clone();
         let full_file_schema = self.config.full_file_schema.clone();
         let num_pipelines = self.config.num_pipelines();
+        let row_group_prefetch_size = self.config.row_group_prefetch_size();
         let max_concurrent_scans = max_concurrent_scans(num_pipelines).min(sources.len());
 
         let (started_reader_tx, started_reader_rx) =
@@ -240,9 +294,10 @@ impl MultiScanTaskInitializer {
                     final_output_schema,
                     projected_file_schema,
                     full_file_schema,
-                    check_schema_names: None,
+                    check_schema_names,
                 },
                 num_pipelines,
+                row_group_prefetch_size,
                 verbose,
             }
             .run(),
@@ -285,6 +340,7 @@ struct ReaderStarter {
     extra_ops: ExtraOperations,
     constant_args: StartReaderArgsConstant,
     num_pipelines: usize,
+    row_group_prefetch_size: usize,
     verbose: bool,
 }
 
@@ -300,6 +356,7 @@ impl ReaderStarter {
             extra_ops,
             constant_args,
             num_pipelines,
+            row_group_prefetch_size,
             verbose,
         } = self;
 
@@ -342,6 +399,8 @@ impl ReaderStarter {
                 if verbose {
                     eprintln!("[ReaderStarter]: Stopping (pre_slice)")
                 }
+                // Cancel the initialization of files that are still being prefetched.
+                drop(readers_init_iter);
                 break;
             }
 
@@ -468,6 +527,7 @@ impl ReaderStarter {
                 cast_columns_policy: extra_ops_post.cast_columns_policy.clone(),
                 missing_columns_policy: extra_ops_post.missing_columns_policy.clone(),
                 num_pipelines,
+                row_group_prefetch_size,
                 callbacks,
             };
 
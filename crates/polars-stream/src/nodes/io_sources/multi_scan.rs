@@ -25,7 +25,6 @@ use polars_utils::{IdxSize, format_pl_smallstr};
 use tokio::sync::oneshot;
 
 use super::{RowRestriction, SourceNode, SourceOutput};
-use crate::DEFAULT_LINEARIZER_BUFFER_SIZE;
 use crate::async_executor::{AbortOnDropHandle, spawn};
 use crate::async_primitives::connector::{Receiver, connector};
 use crate::async_primitives::linearizer::Linearizer;
@@ -1049,7 +1048,7 @@ impl<T: MultiScanable> SourceNode for MultiScanNode<T> {
                                     SourceInput::Serial(rx) => rx,
                                     SourceInput::Parallel(rxs) => {
                                         let (mut tx, rx) = connector();
-                                        let (mut lin_rx, lin_txs) = Linearizer::new(state.num_pipelines, *DEFAULT_LINEARIZER_BUFFER_SIZE);
+                                        let (mut lin_rx, lin_txs) = Linearizer::new(state.num_pipelines, state.buffer_sizes.linearizer);
 
                                         linearizer_tasks.extend(rxs.into_iter().zip(lin_txs).map(|(mut rx, mut lin_tx)|
                                             AbortOnDropHandle::new(spawn(TaskPriority::High, async move {
@@ -65,6 +65,7 @@ impl FileReader for NDJsonFileReader {
             pre_slice,
 
             num_pipelines,
+            row_group_prefetch_size: _,
             callbacks:
                 FileReaderCallbacks {
                     file_schema_tx,
@@ -0,0 +1,256 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::LazyLock;
+use std::time::UNIX_EPOCH;
+
+use parking_lot::Mutex;
+use polars_core::prelude::{ArrowField, Column, InitHashMaps, PlHashMap};
+use polars_error::{PolarsResult, polars_err};
+use polars_io::utils::byte_source::DynByteSource;
+use polars_utils::format_pl_smallstr;
+use polars_utils::pl_str::PlSmallStr;
+
+use crate::runtime;
+
+/// Decoded column chunks of Parquet files, shared by all queries of the process so that queries
+/// scanning the same files don't decode the same row groups again.
+///
+/// The cache is enabled by setting `POLARS_PARQUET_DECODE_CACHE_SIZE` to its size in bytes. Once
+/// it is full the least recently used column chunks are evicted. Only files scanned from a path
+/// are cached, and the row groups are identified by the path and version of their file and their
+/// byte offset, so a rewritten file is decoded again.
+static DECODED_COLUMN_CACHE: LazyLock<Result<Option<DecodedColumnCache>, String>> =
+    LazyLock::new(|| {
+        let Ok(limit) = std::env::var("POLARS_PARQUET_DECODE_CACHE_SIZE") else {
+            return Ok(None);
+        };
+        let limit = limit.parse::<usize>().map_err(|_| limit)?;
+        Ok((limit > 0).then(|| DecodedColumnCache::new(limit)))
+    });
+
+/// Returns the [`DECODED_COLUMN_CACHE`] if it is enabled.
+pub(super) fn decoded_column_cache() -> PolarsResult<Option<&'static DecodedColumnCache>> {
+    match &*DECODED_COLUMN_CACHE {
+        Ok(cache) => Ok(cache.as_ref()),
+        Err(value) => Err(polars_err!(
+            InvalidOperation: "POLARS_PARQUET_DECODE_CACHE_SIZE must be a number of bytes, got '{}'",
+            value
+        )),
+    }
+}
+
+/// A file whose column chunks can be cached, with the version it had when it was scanned.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub(super) struct CachedFile {
+    path: PlSmallStr,
+    /// The time of modification and size of a local file, or the version of a cloud object.
+    version: PlSmallStr,
+}
+
+impl CachedFile {
+    /// Returns `None` if the version of the file is unknown, its column chunks are then not
+    /// cached.
+    pub(super) async fn try_new(
+        path: &Path,
+        byte_source: &DynByteSource,
+    ) -> PolarsResult<Option<Self>> {
+        let version = if polars_io::is_cloud_url(path) {
+            byte_source.cloud_object_version().await?
+        } else {
+            let path = path.to_path_buf();
+            let metadata = runtime::spawn_blocking(move || std::fs::metadata(path))
+                .await
+                .unwrap()?;
+            metadata
+                .modified()
+                .ok()
+                .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+                .map(|time| format_pl_smallstr!("{}:{}", time.as_nanos(), metadata.len()))
+        };
+        Ok(version.map(|version| Self {
+            path: PlSmallStr::from_str(&path.to_string_lossy()),
+            version,
+        }))
+    }
+}
+
+/// (file, byte offset of the row group, column)
+type CacheKey = (CachedFile, u64, PlSmallStr);
+
+struct CacheEntry {
+    arrow_field: ArrowField,
+    column: Column,
+    size: usize,
+    last_used: u64,
+}
+
+pub(super) struct DecodedColumnCache {
+    limit: usize,
+    state: Mutex<CacheState>,
+}
+
+struct CacheState {
+    entries: PlHashMap<CacheKey, CacheEntry>,
+    /// The keys of the entries by the time they were last used, least recently used first.
+    lru: BTreeMap<u64, CacheKey>,
+    used: usize,
+    clock: u64,
+}
+
+impl CacheState {
+    fn tick(&mut self) -> u64 {
+        self.clock += 1;
+        self.clock
+    }
+
+    fn remove(&mut self, key: &CacheKey) -> Option<CacheEntry> {
+        let entry = self.entries.remove(key)?;
+        self.lru.remove(&entry.last_used);
+        self.used -= entry.size;
+        Some(entry)
+    }
+}
+
+impl DecodedColumnCache {
+    fn new(limit: usize) -> Self {
+        Self {
+            limit,
+            state: Mutex::new(CacheState {
+                entries: PlHashMap::new(),
+                lru: BTreeMap::new(),
+                used: 0,
+                clock: 0,
+            }),
+        }
+    }
+
+    /// Returns the decoded column chunk, if it is cached with the same field.
+    pub(super) fn get(
+        &self,
+        file: &CachedFile,
+        row_group_offset: u64,
+        arrow_field: &ArrowField,
+    ) -> Option<Column> {
+        let mut state = self.state.lock();
+        let clock = state.tick();
+        let key = (file.clone(), row_group_offset, arrow_field.name.clone());
+        let entry = state.entries.get_mut(&key)?;
+        if entry.arrow_field != *arrow_field {
+            return None;
+        }
+        let last_used = std::mem::replace(&mut entry.last_used, clock);
+        let column = entry.column.clone();
+        state.lru.remove(&last_used);
+        state.lru.insert(clock, key);
+        Some(column)
+    }
+
+    /// Caches a decoded column chunk, evicting the least recently used chunks if the cache is
+    /// full. Chunks larger than the whole cache are not cached.
+    pub(super) fn insert(
+        &self,
+        file: &CachedFile,
+        row_group_offset: u64,
+        arrow_field: &ArrowField,
+        column: &Column,
+    ) {
+        let size = column.as_materialized_series().estimated_size();
+        if size > self.limit {
+            return;
+        }
+
+        let mut state = self.state.lock();
+        let clock = state.tick();
+        let key = (file.clone(), row_group_offset, arrow_field.name.clone());
+        state.remove(&key);
+        while state.used + size > self.limit {
+            let (_, oldest) = state.lru.pop_first().unwrap();
+            let evicted = state.entries.remove(&oldest).unwrap();
+            state.used -= evicted.size;
+        }
+
+        let entry = CacheEntry {
+            arrow_field: arrow_field.clone(),
+            column: column.clone(),
+            size,
+            last_used: clock,
+        };
+        state.lru.insert(clock, key.clone());
+        state.entries.insert(key, entry);
+        state.used += size;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use polars_core::prelude::*;
+
+    use super::*;
+
+    fn file(version: &str) -> CachedFile {
+        CachedFile {
+            path: "data.parquet".into(),
+            version: version.into(),
+        }
+    }
+
+    fn field(name: &str) -> ArrowField {
+        ArrowField::new(name.into(), ArrowDataType::Int64, true)
+    }
+
+    fn column(name: &str) -> Column {
+        Column::new(name.into(), [1i64, 2, 3])
+    }
+
+    #[test]
+    fn test_decoded_column_cache_lru() {
+        let size = column("a").as_materialized_series().estimated_size();
+        let cache = DecodedColumnCache::new(2 * size);
+        let v1 = file("1");
+        cache.insert(&v1, 0, &field("a"), &column("a"));
+        cache.insert(&v1, 0, &field("b"), &column("b"));
+        // `a` is used more recently than `b`, so `b` is evicted for `c`.
+        assert!(cache.get(&v1, 0, &field("a")).is_some());
+        cache.insert(&v1, 0, &field("c"), &column("c"));
+        assert!(cache.get(&v1, 0, &field("b")).is_none());
+        assert!(cache.get(&v1, 0, &field("a")).is_some());
+        assert!(cache.get(&v1, 0, &field("c")).is_some());
+        assert_eq!(cache.state.lock().used, 2 * size);
+
+        // Other row groups, fields and versions of the file are not served from the cache.
+        assert!(cache.get(&v1, 4, &field("a")).is_none());
+        let other_dtype = ArrowField::new("a".into(), ArrowDataType::Int32, true);
+        assert!(cache.get(&v1, 0, &other_dtype).is_none());
+        assert!(cache.get(&file("2"), 0, &field("a")).is_none());
+
+        // Replacing an entry doesn't count it twice.
+        cache.insert(&v1, 0, &field("a"), &column("a"));
+        let state = cache.state.lock();
+        assert_eq!(
+            (state.entries.len(), state.lru.len(), state.used),
+            (2, 2, 2 * size)
+        );
+    }
+
+    #[test]
+    fn test_cached_file_version() -> PolarsResult<()> {
+        let dir = std::env::temp_dir().join(format!("polars-decode-cache-{}", std::process::id()));
+        std::fs::create_dir_all(&dir)?;
+        let path = dir.join("data.parquet");
+        std::fs::write(&path, b"a")?;
+
+        let version = |path: &Path| {
+            let path = path.to_path_buf();
+            polars_io::pl_async::get_runtime().block_on(async move {
+                CachedFile::try_new(&path, &DynByteSource::default()).await
+            })
+        };
+        let v1 = version(&path)?.unwrap();
+        assert_eq!(version(&path)?.unwrap(), v1);
+        std::fs::write(&path, b"ab")?;
+        assert_ne!(version(&path)?.unwrap(), v1);
+
+        std::fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+}
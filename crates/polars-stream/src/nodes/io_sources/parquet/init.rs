@@ -15,11 +15,11 @@ use polars_utils::{IdxSize, format_pl_smallstr};
 use super::row_group_data_fetch::RowGroupDataFetcher;
 use super::row_group_decode::RowGroupDecoder;
 use super::{AsyncTaskData, ParquetReadImpl};
-use crate::async_executor;
 use crate::morsel::{Morsel, SourceToken, get_ideal_morsel_size};
 use crate::nodes::io_sources::multi_file_reader::reader_interface::output::FileReaderOutputSend;
 use crate::nodes::{MorselSeq, TaskPriority};
 use crate::utils::task_handles_ext::{self, AbortOnDropHandle};
+use crate::{async_executor, runtime};
 
 async fn calculate_row_group_pred_pushdown_skip_mask(
     row_group_slice: Range<usize>,
@@ -128,8 +128,6 @@ impl ParquetReadImpl {
     #[allow(clippy::type_complexity)]
     pub(super) fn init_morsel_distributor(&mut self) -> AsyncTaskData {
         let verbose = self.verbose;
-        let io_runtime = polars_io::pl_async::get_runtime();
-
         let use_statistics = self.options.use_statistics;
 
         let (mut morsel_sender, morsel_rx) = FileReaderOutputSend::new_serial();
@@ -137,7 +135,7 @@ impl ParquetReadImpl {
         if let Some((_, 0)) = self.normalized_pre_slice {
             return (
                 morsel_rx,
-                task_handles_ext::AbortOnDropHandle(io_runtime.spawn(std::future::ready(Ok(())))),
+                task_handles_ext::AbortOnDropHandle(runtime::spawn(std::future::ready(Ok(())))),
             );
         }
 
@@ -167,10 +165,10 @@ impl ParquetReadImpl {
         let normalized_pre_slice = self.normalized_pre_slice;
         let byte_source = self.byte_source.clone();
 
-        // Prefetch loop (spawns prefetches on the tokio scheduler).
+        // Prefetch loop (spawns prefetches on the I/O runtime).
         let (prefetch_send, mut prefetch_recv) =
             tokio::sync::mpsc::channel(row_group_prefetch_size);
-        let prefetch_task = AbortOnDropHandle(io_runtime.spawn(async move {
+        let prefetch_task = AbortOnDropHandle(runtime::spawn(async move {
             polars_ensure!(
                 metadata.num_rows < IdxSize::MAX as usize,
                 bigidx,
@@ -257,7 +255,7 @@ impl ParquetReadImpl {
 
         // Decode loop (spawns decodes on the computational executor).
         let (decode_send, mut decode_recv) = tokio::sync::mpsc::channel(self.config.num_pipelines);
-        let decode_task = AbortOnDropHandle(io_runtime.spawn(async move {
+        let decode_task = AbortOnDropHandle(runtime::spawn(async move {
             while let Some(prefetch) = prefetch_recv.recv().await {
                 let row_group_data = prefetch.await.unwrap()?;
                 let row_group_decoder = row_group_decoder.clone();
@@ -327,7 +325,7 @@ impl ParquetReadImpl {
             PolarsResult::Ok(())
         });
 
-        let join_task = io_runtime.spawn(async move {
+        let join_task = runtime::spawn(async move {
             prefetch_task.await.unwrap()?;
             decode_task.await.unwrap()?;
             distribute_task.await?;
@@ -408,6 +406,7 @@ impl ParquetReadImpl {
             predicate_arrow_field_indices,
             non_predicate_arrow_field_indices,
             min_values_per_thread,
+            cache_file: self.cache_file.clone(),
         }
     }
 }
@@ -7,9 +7,8 @@ use polars_core::scalar::Scalar;
 use polars_core::schema::{Schema, SchemaExt};
 use polars_error::{PolarsResult, polars_err};
 use polars_io::cloud::CloudOptions;
-use polars_io::pl_async;
 use polars_io::predicates::ScanIOPredicate;
-use polars_io::prelude::{FileMetadata, ParquetOptions};
+use polars_io::prelude::{FileMetadata, ParquetOptions, read_strings_as_categorical};
 use polars_io::utils::byte_source::{DynByteSource, DynByteSourceBuilder, MemSliceByteSource};
 use polars_parquet::read::schema::infer_schema_with_options;
 use polars_plan::dsl::ScanSource;
@@ -19,7 +18,8 @@ use polars_utils::mem::prefetch::get_memory_prefetch_func;
 use polars_utils::pl_str::PlSmallStr;
 use polars_utils::slice_enum::Slice;
 
-use super::multi_file_reader::extra_ops::cast_columns::{CastColumns, CastColumnsPolicy};
+use self::decode_cache::CachedFile;
+use super::multi_file_reader::extra_ops::cast_columns::CastColumns;
 use super::multi_file_reader::extra_ops::missing_columns::MissingColumnsPolicy;
 use super::multi_file_reader::reader_interface::output::{
     FileReaderOutputRecv, FileReaderOutputSend,
@@ -30,9 +30,11 @@ use super::multi_file_reader::reader_interface::{
 use crate::async_executor::{self};
 use crate::nodes::compute_node_prelude::*;
 use crate::nodes::{TaskPriority, io_sources};
+use crate::runtime;
 use crate::utils::task_handles_ext;
 
 pub mod builder;
+mod decode_cache;
 mod init;
 mod metadata_utils;
 mod row_group_data_fetch;
@@ -55,6 +57,8 @@ struct InitializedState {
     file_metadata: Arc<FileMetadata>,
     file_schema: Arc<ArrowSchema>,
     byte_source: Arc<DynByteSource>,
+    /// Identifies the file in the decoded column cache, if its columns can be cached.
+    cache_file: Option<CachedFile>,
 }
 
 #[async_trait]
@@ -70,30 +74,36 @@ impl FileReader for ParquetFileReader {
         let byte_source_builder = self.byte_source_builder.clone();
         let cloud_options = self.cloud_options.clone();
 
-        let byte_source = pl_async::get_runtime()
-            .spawn(async move {
-                scan_source
-                    .as_scan_source_ref()
-                    .to_dyn_byte_source(&byte_source_builder, cloud_options.as_deref())
-                    .await
-            })
-            .await
-            .unwrap()?;
+        let byte_source = runtime::spawn(async move {
+            scan_source
+                .as_scan_source_ref()
+                .to_dyn_byte_source(&byte_source_builder, cloud_options.as_deref())
+                .await
+        })
+        .await
+        .unwrap()?;
 
         let mut byte_source = Arc::new(byte_source);
 
+        // Only files with a path can be identified across queries.
+        let cache_file = match &self.scan_source {
+            ScanSource::Path(path) if decode_cache::decoded_column_cache()?.is_some() => {
+                CachedFile::try_new(path, &byte_source).await?
+            },
+            _ => None,
+        };
+
         let file_metadata = if let Some(v) = self.metadata.clone() {
             v
         } else {
             let (metadata_bytes, opt_full_bytes) = {
                 let byte_source = byte_source.clone();
 
-                pl_async::get_runtime()
-                    .spawn(async move {
-                        metadata_utils::read_parquet_metadata_bytes(&byte_source, verbose).await
-                    })
-                    .await
-                    .unwrap()?
+                runtime::spawn(async move {
+                    metadata_utils::read_parquet_metadata_bytes(&byte_source, verbose).await
+                })
+                .await
+                .unwrap()?
             };
 
             if let Some(full_bytes) = opt_full_bytes {
@@ -112,6 +122,7 @@ impl FileReader for ParquetFileReader {
             file_metadata,
             file_schema,
             byte_source,
+            cache_file,
         });
 
         Ok(())
@@ -127,6 +138,7 @@ impl FileReader for ParquetFileReader {
             file_metadata,
             file_schema,
             byte_source,
+            cache_file,
         } = self.init_data.as_ref().unwrap();
 
         let BeginReadArgs {
@@ -137,6 +149,7 @@ impl FileReader for ParquetFileReader {
             cast_columns_policy,
             missing_columns_policy,
             num_pipelines,
+            row_group_prefetch_size,
             callbacks:
                 FileReaderCallbacks {
                     file_schema_tx,
@@ -151,6 +164,14 @@ impl FileReader for ParquetFileReader {
             .clone()
             .map(|x| x.restrict_to_bounds(usize::try_from(n_rows_in_file).unwrap()));
 
+        // Read the strings that the scan expects as categoricals as categoricals.
+        let file_schema = read_strings_as_categorical(file_schema, file_metadata, |name| {
+            projected_schema
+                .get(name)
+                .is_some_and(|dtype| dtype.is_categorical())
+        })
+        .map_or_else(|| file_schema.clone(), Arc::new);
+
         let file_schema_pl =
             std::cell::LazyCell::new(|| Arc::new(Schema::from_arrow_schema(file_schema.as_ref())));
 
@@ -195,7 +216,6 @@ impl FileReader for ParquetFileReader {
         // Prepare parameters for dispatch
 
         let memory_prefetch_func = get_memory_prefetch_func(verbose);
-        let row_group_prefetch_size = polars_core::config::get_rg_prefetch_size();
 
         // This can be set to 1 to force column-per-thread parallelism, e.g. for bug reproduction.
         let min_values_per_thread = std::env::var("POLARS_MIN_VALUES_PER_THREAD")
@@ -256,11 +276,7 @@ impl FileReader for ParquetFileReader {
                 },
             }
 
-            if !matches!(cast_columns_policy, CastColumnsPolicy::ErrorOnMismatch) {
-                unimplemented!("column casting w/ predicate in parquet")
-            }
-
-            CastColumns::try_init_from_policy_from_iter(
+            let cast_columns = CastColumns::try_init_from_policy_from_iter(
                 cast_columns_policy,
                 &projected_schema,
                 &mut file_schema_pl
@@ -268,6 +284,10 @@ impl FileReader for ParquetFileReader {
                     .filter(|(name, _)| predicate.live_columns.contains(*name))
                     .map(|(name, dtype)| (name.as_ref(), dtype)),
             )?;
+            // The predicate compares the values after they are cast.
+            if let Some(cast_columns) = cast_columns {
+                cast_columns.cast_before_predicate(predicate);
+            }
         }
 
         let (output_recv, handle) = ParquetReadImpl {
@@ -290,6 +310,7 @@ impl FileReader for ParquetFileReader {
             projected_arrow_schema,
             memory_prefetch_func,
             row_index: row_index.map(|ri| Arc::new((ri.name, AtomicIdxSize::new(ri.offset)))),
+            cache_file: cache_file.clone(),
         }
         .run();
 
@@ -303,6 +324,10 @@ impl FileReader for ParquetFileReader {
         self._n_rows_in_file()
     }
 
+    fn fast_n_rows_in_file(&self) -> Option<IdxSize> {
+        self._n_rows_in_file().ok()
+    }
+
     async fn row_position_after_slice(
         &mut self,
         pre_slice: Option<Slice>,
@@ -347,6 +372,8 @@ struct ParquetReadImpl {
     /// so the row index offset needs to be updated by the initializer to
     /// reflect this (https://github.com/pola-rs/polars/issues/19607).
     row_index: Option<Arc<(PlSmallStr, AtomicIdxSize)>>,
+    /// Identifies the file in the cache of decoded columns shared between queries, if enabled.
+    cache_file: Option<CachedFile>,
 }
 
 #[derive(Debug)]
@@ -13,6 +13,7 @@ use polars_parquet::read::RowGroupMetadata;
 use polars_utils::mmap::MemSlice;
 use polars_utils::pl_str::PlSmallStr;
 
+use crate::runtime;
 use crate::utils::task_handles_ext;
 
 /// Represents byte-data that can be transformed into a DataFrame after some computation.
@@ -80,9 +81,7 @@ impl RowGroupDataFetcher {
             let current_byte_source = self.byte_source.clone();
             let projection = self.projection.clone();
             let memory_prefetch_func = self.memory_prefetch_func;
-            let io_runtime = polars_io::pl_async::get_runtime();
-
-            let handle = io_runtime.spawn(async move {
+            let handle = runtime::spawn(async move {
                 let row_group_metadata = &metadata.row_groups[idx];
                 let fetched_bytes =
                     if let DynByteSource::MemSlice(mem_slice) = current_byte_source.as_ref() {
@@ -17,6 +17,7 @@ use polars_utils::IdxSize;
 use polars_utils::index::AtomicIdxSize;
 use polars_utils::pl_str::PlSmallStr;
 
+use super::decode_cache::{CachedFile, decoded_column_cache};
 use super::row_group_data_fetch::RowGroupData;
 use crate::async_executor;
 use crate::nodes::TaskPriority;
@@ -32,6 +33,8 @@ pub(super) struct RowGroupDecoder {
     /// Indices into `projected_arrow_schema. This must be sorted.
     pub(super) non_predicate_arrow_field_indices: Vec<usize>,
     pub(super) min_values_per_thread: usize,
+    /// Identifies the file in the decoded column cache, if its columns can be cached.
+    pub(super) cache_file: Option<CachedFile>,
 }
 
 impl RowGroupDecoder {
@@ -165,14 +168,15 @@ impl RowGroupDecoder {
         ) else {
             // Single-threaded
             for s in projected_arrow_schema.iter_values().map(|arrow_field| {
-                decode_column(
+                decode_column_cached(
                     arrow_field,
                     row_group_data,
                     filter.clone(),
                     expected_num_rows,
+                    self.cache_file.as_ref(),
                 )
             }) {
-                out_vec.push(s?.0)
+                out_vec.push(s?)
             }
 
             return Ok(());
@@ -184,6 +188,7 @@ impl RowGroupDecoder {
         let task_handles = {
             let projected_arrow_schema = projected_arrow_schema.clone();
             let filter = filter.clone();
+            let cache_file = self.cache_file.clone();
 
             (remainder..projected_arrow_schema.len())
                 .step_by(cols_per_thread)
@@ -191,6 +196,7 @@ impl RowGroupDecoder {
                     let row_group_data = row_group_data_2.clone();
                     let projected_arrow_schema = projected_arrow_schema.clone();
                     let filter = filter.clone();
+                    let cache_file = cache_file.clone();
 
                     async move {
                         // This is exact as we have already taken out the remainder.
@@ -199,11 +205,12 @@ impl RowGroupDecoder {
                                 let (_, arrow_field) =
                                     projected_arrow_schema.get_at_index(i).unwrap();
 
-                                decode_column(
+                                decode_column_cached(
                                     arrow_field,
                                     &row_group_data,
                                     filter.clone(),
                                     expected_num_rows,
+                                    cache_file.as_ref(),
                                 )
                             })
                             .collect::<PolarsResult<Vec<_>>>()
@@ -222,25 +229,64 @@ impl RowGroupDecoder {
             .iter_values()
             .take(remainder)
             .map(|arrow_field| {
-                decode_column(
+                decode_column_cached(
                     arrow_field,
                     row_group_data,
                     filter.clone(),
                     expected_num_rows,
+                    self.cache_file.as_ref(),
                 )
             })
         {
-            out_vec.push(out?.0);
+            out_vec.push(out?);
         }
 
         for handle in task_handles {
-            out_vec.extend(handle.await?.into_iter().map(|(c, _)| c));
+            out_vec.extend(handle.await?);
         }
 
         Ok(())
     }
 }
 
+/// Decodes a column like [`decode_column`], going through the decoded column cache if the
+/// file can be cached. Only whole column chunks are cached, slices of them are taken from the
+/// cache but not added to it.
+fn decode_column_cached(
+    arrow_field: &ArrowField,
+    row_group_data: &RowGroupData,
+    filter: Option<polars_parquet::read::Filter>,
+    expected_num_rows: usize,
+    cache_file: Option<&CachedFile>,
+) -> PolarsResult<Column> {
+    let (Some(cache), Some(file)) = (decoded_column_cache()?, cache_file) else {
+        return Ok(decode_column(arrow_field, row_group_data, filter, expected_num_rows)?.0);
+    };
+    let range = match &filter {
+        None => 0..row_group_data.row_group_metadata.num_rows(),
+        Some(Filter::Range(range)) => range.clone(),
+        Some(_) => {
+            return Ok(decode_column(arrow_field, row_group_data, filter, expected_num_rows)?.0);
+        },
+    };
+    let is_full = range.len() == row_group_data.row_group_metadata.num_rows();
+    let row_group_offset = row_group_data.row_group_metadata.full_byte_range().start;
+
+    if let Some(column) = cache.get(file, row_group_offset, arrow_field) {
+        return Ok(if is_full {
+            column
+        } else {
+            column.slice(range.start as i64, range.len())
+        });
+    }
+
+    let (column, _) = decode_column(arrow_field, row_group_data, filter, expected_num_rows)?;
+    if is_full {
+        cache.insert(file, row_group_offset, arrow_field, &column);
+    }
+    Ok(column)
+}
+
 fn decode_column(
     arrow_field: &ArrowField,
     row_group_data: &RowGroupData,
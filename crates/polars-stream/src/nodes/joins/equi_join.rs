@@ -2,6 +2,7 @@ use std::cmp::Reverse;
 use std::collections::BinaryHeap;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::time::Instant;
 
 use arrow::array::builder::ShareStrategy;
 use polars_core::frame::builder::DataFrameBuilder;
@@ -10,7 +11,6 @@ use polars_core::schema::{Schema, SchemaExt};
 use polars_core::{POOL, config};
 use polars_expr::hash_keys::HashKeys;
 use polars_expr::idx_table::{IdxTable, new_idx_table};
-use polars_io::pl_async::get_runtime;
 use polars_ops::frame::{JoinArgs, JoinType, MaintainOrderJoin};
 use polars_ops::series::coalesce_columns;
 use polars_utils::cardinality_sketch::CardinalitySketch;
@@ -26,9 +26,11 @@ use super::{BufferedStream, JOIN_SAMPLE_LIMIT, LOPSIDED_SAMPLE_FACTOR};
 use crate::async_primitives::connector::{Receiver, Sender};
 use crate::async_primitives::wait_group::WaitGroup;
 use crate::expression::StreamExpr;
-use crate::morsel::{SourceToken, get_ideal_morsel_size};
+use crate::morsel::{MorselSizer, SourceToken, get_ideal_morsel_size};
 use crate::nodes::compute_node_prelude::*;
 use crate::nodes::in_memory_source::InMemorySourceNode;
+use crate::runtime;
+use crate::utils::memory_budget::MemoryReservation;
 
 struct EquiJoinParams {
     left_is_build: Option<bool>,
@@ -198,8 +200,6 @@ fn estimate_cardinality(
     let last_morsel_idx = to_process_end - 1;
     let last_morsel_len = morsels[last_morsel_idx].df().height();
     let last_morsel_slice = last_morsel_len - total_height.saturating_sub(sample_limit);
-    let runtime = get_runtime();
-
     POOL.install(|| {
         let sample_cardinality = morsels[..to_process_end]
             .par_iter()
@@ -215,7 +215,7 @@ fn estimate_cardinality(
                         morsel.df()
                     };
                     let hash_keys =
-                        runtime.block_on(select_keys(df, key_selectors, params, state))?;
+                        runtime::block_on(select_keys(df, key_selectors, params, state))?;
                     hash_keys.sketch_cardinality(&mut sketch);
                     PolarsResult::Ok(sketch)
                 },
@@ -369,7 +369,7 @@ impl SampleState {
                     ));
                 }
 
-                polars_io::pl_async::get_runtime().block_on(async move {
+                runtime::block_on(async move {
                     for handle in join_handles {
                         handle.await?;
                     }
@@ -428,6 +428,7 @@ impl BuildState {
         local: &mut LocalBuilder,
         partitioner: HashPartitioner,
         params: &EquiJoinParams,
+        reservation: &MemoryReservation,
         state: &StreamingExecutionState,
     ) -> PolarsResult<()> {
         let track_unmatchable = params.emit_unmatched_build();
@@ -452,6 +453,7 @@ impl BuildState {
             .await?;
             let mut payload = select_payload(morsel.df().clone(), payload_selector);
             payload.rechunk_mut();
+            reservation.grow(payload.estimated_size() + hash_keys.estimated_size())?;
 
             hash_keys.gen_idxs_per_partition(
                 &partitioner,
@@ -1068,6 +1070,7 @@ impl EmitUnmatchedState {
         let morsel_count = ideal_morsel_count.next_multiple_of(num_pipelines);
         let morsel_size = total_len.div_ceil(morsel_count).max(1);
 
+        let mut sizer = MorselSizer::new(morsel_size)?;
         let wait_group = WaitGroup::default();
         let source_token = SourceToken::new();
         let mut unmarked_idxs = Vec::new();
@@ -1077,7 +1080,7 @@ impl EmitUnmatchedState {
                 self.offset_in_active_p += p.hash_table.unmarked_keys(
                     &mut unmarked_idxs,
                     self.offset_in_active_p as IdxSize,
-                    morsel_size as IdxSize,
+                    sizer.size() as IdxSize,
                 ) as usize;
                 if unmarked_idxs.is_empty() {
                     break;
@@ -1098,16 +1101,20 @@ impl EmitUnmatchedState {
                     }
                 };
                 let out_df = postprocess_join(out_df, params);
+                let rows = out_df.height();
 
                 // Send and wait until consume token is consumed.
                 let mut morsel = Morsel::new(out_df, self.morsel_seq, source_token.clone());
                 self.morsel_seq = self.morsel_seq.successor();
                 morsel.set_consume_token(wait_group.token());
+                let start = Instant::now();
                 if send.send(morsel).await.is_err() {
                     return Ok(());
                 }
+                let blocked = start.elapsed();
 
                 wait_group.wait().await;
+                sizer.observe(rows, start.elapsed(), blocked);
                 if source_token.stop_requested() {
                     return Ok(());
                 }
@@ -1134,6 +1141,8 @@ pub struct EquiJoinNode {
     state: EquiJoinState,
     params: EquiJoinParams,
     table: Box<dyn IdxTable>,
+    /// Accounts for the build side, which is held until the join is done.
+    reservation: Option<MemoryReservation>,
 }
 
 impl EquiJoinNode {
@@ -1147,11 +1156,14 @@ impl EquiJoinNode {
         left_key_selectors: Vec<StreamExpr>,
         right_key_selectors: Vec<StreamExpr>,
         args: JoinArgs,
+        left_is_build: Option<bool>,
         num_pipelines: usize,
     ) -> PolarsResult<Self> {
         let left_is_build = match args.maintain_order {
             MaintainOrderJoin::None => {
-                if *JOIN_SAMPLE_LIMIT == 0 {
+                if left_is_build.is_some() {
+                    left_is_build
+                } else if *JOIN_SAMPLE_LIMIT == 0 {
                     Some(true)
                 } else {
                     None
@@ -1213,6 +1225,7 @@ impl EquiJoinNode {
                 random_state: PlRandomState::default(),
             },
             table: new_idx_table(unique_key_schema),
+            reservation: None,
         })
     }
 }
@@ -1427,6 +1440,9 @@ impl ComputeNode for EquiJoinNode {
                 assert!(send_ports[0].is_none());
                 assert!(recv_ports[probe_idx].is_none());
                 let receivers = recv_ports[build_idx].take().unwrap().parallel();
+                let reservation = &*self
+                    .reservation
+                    .get_or_insert_with(|| state.memory_reservation("equi_join"));
 
                 let partitioner = HashPartitioner::new(state.num_pipelines, 0);
                 for (local_builder, recv) in build_state.local_builders.iter_mut().zip(receivers) {
@@ -1437,6 +1453,7 @@ impl ComputeNode for EquiJoinNode {
                             local_builder,
                             partitioner.clone(),
                             &self.params,
+                            reservation,
                             state,
                         ),
                     ));
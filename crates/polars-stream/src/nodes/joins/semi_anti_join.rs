@@ -17,6 +17,7 @@ use polars_utils::sparse_init_vec::SparseInitVec;
 use crate::async_primitives::connector::{Receiver, Sender};
 use crate::expression::StreamExpr;
 use crate::nodes::compute_node_prelude::*;
+use crate::utils::memory_budget::MemoryReservation;
 
 async fn select_keys(
     df: &DataFrame,
@@ -51,6 +52,8 @@ pub struct SemiAntiJoinNode {
     state: SemiAntiJoinState,
     params: SemiAntiJoinParams,
     grouper: Box<dyn Grouper>,
+    /// Accounts for the keys of the build side, which are held until the join is done.
+    reservation: Option<MemoryReservation>,
 }
 
 impl SemiAntiJoinNode {
@@ -79,6 +82,7 @@ impl SemiAntiJoinNode {
                 is_anti,
             },
             grouper: new_hash_grouper(unique_key_schema),
+            reservation: None,
         })
     }
 }
@@ -127,6 +131,7 @@ impl BuildState {
         local: &mut LocalBuilder,
         partitioner: HashPartitioner,
         params: &SemiAntiJoinParams,
+        reservation: &MemoryReservation,
         state: &StreamingExecutionState,
     ) -> PolarsResult<()> {
         let key_selectors = if params.left_is_build {
@@ -143,6 +148,7 @@ impl BuildState {
                 &state.in_memory_exec_state,
             )
             .await?;
+            reservation.grow(hash_keys.estimated_size())?;
 
             hash_keys.gen_idxs_per_partition(
                 &partitioner,
@@ -420,6 +426,9 @@ impl ComputeNode for SemiAntiJoinNode {
                 assert!(send_ports[0].is_none());
                 assert!(recv_ports[probe_idx].is_none());
                 let receivers = recv_ports[build_idx].take().unwrap().parallel();
+                let reservation = &*self
+                    .reservation
+                    .get_or_insert_with(|| state.memory_reservation("semi_anti_join"));
 
                 let partitioner = HashPartitioner::new(state.num_pipelines, 0);
                 for (local_builder, recv) in build_state.local_builders.iter_mut().zip(receivers) {
@@ -430,6 +439,7 @@ impl ComputeNode for SemiAntiJoinNode {
                             local_builder,
                             partitioner.clone(),
                             &self.params,
+                            reservation,
                             state,
                         ),
                     ));
@@ -0,0 +1,369 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use polars_core::prelude::{BooleanChunked, ChunkCompareIneq, Column};
+use polars_core::schema::Schema;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_ops::frame::_merge_sorted_dfs;
+use polars_utils::pl_str::PlSmallStr;
+
+use crate::async_primitives::connector::Receiver;
+use crate::async_primitives::distributor_channel::distributor_channel;
+use crate::morsel::{SourceToken, get_ideal_morsel_size};
+use crate::nodes::compute_node_prelude::*;
+
+#[derive(Default)]
+struct MergeInput {
+    /// Not yet merged rows, all with a non-null key.
+    unmerged: VecDeque<DataFrame>,
+    /// Whether a row with a null key was seen before the first non-null key.
+    has_leading_nulls: bool,
+    seen_non_null: bool,
+    done: bool,
+}
+
+/// Merges any number of inputs that are sorted on a key column into one sorted output.
+///
+/// Rows are buffered per input. All rows up to the smallest last buffered key of the inputs that
+/// are not done yet can be merged, as no input can produce a smaller key anymore.
+///
+/// Null keys are placed where the inputs place them: if the first non-null key of an input is
+/// preceded by nulls the output starts with all null keys, otherwise they are emitted at the end.
+pub struct KWayMergeSortedNode {
+    key_column_idx: usize,
+
+    seq: MorselSeq,
+
+    inputs: Vec<MergeInput>,
+
+    /// `None` until the first non-null key is seen.
+    nulls_first: Option<bool>,
+    /// Rows with a null key seen before we know where nulls are placed.
+    pending_nulls: Vec<DataFrame>,
+    /// Rows with a null key that can be sent right away.
+    ready_nulls: Vec<DataFrame>,
+    /// Rows with a null key that are sent once all inputs are done.
+    deferred_nulls: Vec<DataFrame>,
+}
+
+impl KWayMergeSortedNode {
+    pub fn new(schema: Arc<Schema>, key: PlSmallStr, num_inputs: usize) -> Self {
+        assert!(schema.contains(key.as_str()));
+        let key_column_idx = schema.index_of(key.as_str()).unwrap();
+
+        Self {
+            key_column_idx,
+
+            seq: MorselSeq::default(),
+
+            inputs: (0..num_inputs).map(|_| MergeInput::default()).collect(),
+
+            nulls_first: None,
+            pending_nulls: Vec::new(),
+            ready_nulls: Vec::new(),
+            deferred_nulls: Vec::new(),
+        }
+    }
+
+    fn has_buffered(&self) -> bool {
+        self.inputs.iter().any(|i| !i.unmerged.is_empty())
+            || !self.pending_nulls.is_empty()
+            || !self.ready_nulls.is_empty()
+            || !self.deferred_nulls.is_empty()
+    }
+
+    fn push_leading_nulls(&mut self, df: DataFrame) {
+        match self.nulls_first {
+            None => self.pending_nulls.push(df),
+            Some(true) => self.ready_nulls.push(df),
+            Some(false) => self.deferred_nulls.push(df),
+        }
+    }
+
+    /// Buffer a dataframe received from input `idx`, splitting off the rows with a null key.
+    fn push(&mut self, idx: usize, mut df: DataFrame) {
+        if df.height() == 0 {
+            return;
+        }
+
+        if !self.inputs[idx].seen_non_null {
+            let num_leading_nulls = leading_false(&df[self.key_column_idx].is_not_null());
+            if num_leading_nulls > 0 {
+                self.inputs[idx].has_leading_nulls = true;
+                let nulls;
+                (nulls, df) = df.split_at(num_leading_nulls as i64);
+                self.push_leading_nulls(nulls);
+            }
+            if df.height() == 0 {
+                return;
+            }
+
+            self.inputs[idx].seen_non_null = true;
+            if self.nulls_first.is_none() {
+                let nulls_first = self.inputs[idx].has_leading_nulls;
+                self.nulls_first = Some(nulls_first);
+                let pending = std::mem::take(&mut self.pending_nulls);
+                if nulls_first {
+                    self.ready_nulls.extend(pending);
+                } else {
+                    self.deferred_nulls.extend(pending);
+                }
+            }
+        }
+
+        let null_count = df[self.key_column_idx].null_count();
+        if null_count > 0 {
+            let trailing_nulls;
+            (df, trailing_nulls) = df.split_at((df.height() - null_count) as i64);
+            self.deferred_nulls.push(trailing_nulls);
+        }
+        if df.height() > 0 {
+            self.inputs[idx].unmerged.push_back(df);
+        }
+    }
+
+    /// Returns the first input that has to produce more rows before we can merge.
+    fn blocking_input(&self) -> Option<usize> {
+        self.inputs
+            .iter()
+            .position(|i| !i.done && i.unmerged.is_empty())
+    }
+
+    /// Takes the next batch of dataframes that can be merged.
+    ///
+    /// This returns `None` if there is nothing mergeable at this point.
+    fn next_batch(&mut self) -> PolarsResult<Option<Vec<DataFrame>>> {
+        if !self.ready_nulls.is_empty() {
+            let nulls = accumulate_dataframes_vertical_unchecked(self.ready_nulls.drain(..));
+            return Ok(Some(vec![nulls]));
+        }
+        if self.blocking_input().is_some() {
+            return Ok(None);
+        }
+
+        // We can merge up to the minimum of the last keys of the inputs that are not done. If all
+        // inputs are done everything can be merged.
+        let mut frontier: Option<Column> = None;
+        for input in self.inputs.iter().filter(|i| !i.done) {
+            let last = input.unmerged.back().unwrap()[self.key_column_idx].tail(Some(1));
+            frontier = match frontier {
+                Some(f) if !f.gt(&last)?.all() => Some(f),
+                _ => Some(last),
+            };
+        }
+
+        let mut batch = Vec::new();
+        for input in &mut self.inputs {
+            let mut parts = Vec::new();
+            while let Some(df) = input.unmerged.pop_front() {
+                let cutoff = match &frontier {
+                    // @TODO: This is essentially search sorted, but that does not support
+                    // categoricals at moment.
+                    Some(f) => leading_false(&df[self.key_column_idx].gt(f)?),
+                    None => df.height(),
+                };
+                if cutoff < df.height() {
+                    let (mergeable, rest) = df.split_at(cutoff as i64);
+                    input.unmerged.push_front(rest);
+                    if mergeable.height() > 0 {
+                        parts.push(mergeable);
+                    }
+                    break;
+                }
+                parts.push(df);
+            }
+            if !parts.is_empty() {
+                batch.push(accumulate_dataframes_vertical_unchecked(parts));
+            }
+        }
+
+        if batch.is_empty() && self.inputs.iter().all(|i| i.done) {
+            let mut nulls = std::mem::take(&mut self.pending_nulls);
+            nulls.append(&mut self.deferred_nulls);
+            if !nulls.is_empty() {
+                batch.push(accumulate_dataframes_vertical_unchecked(nulls));
+            }
+        }
+
+        Ok((!batch.is_empty()).then_some(batch))
+    }
+
+    /// Request the port to stop producing morsels and buffer the ones that were already produced.
+    async fn buffer_remaining(&mut self, idx: usize, port: &mut Receiver<Morsel>) {
+        let Ok(morsel) = port.recv().await else {
+            return;
+        };
+        morsel.source_token().stop();
+        self.push(idx, morsel.into_df());
+        while let Ok(morsel) = port.recv().await {
+            self.push(idx, morsel.into_df());
+        }
+    }
+}
+
+/// Returns the number of leading `false` values of a mask without nulls.
+fn leading_false(mask: &BooleanChunked) -> usize {
+    let mask = mask.rechunk();
+    mask.downcast_as_array().values().leading_zeros()
+}
+
+/// Merges the sorted dataframes in a balanced fashion.
+fn merge_all(mut dfs: Vec<DataFrame>, key_column_idx: usize) -> PolarsResult<DataFrame> {
+    while dfs.len() > 1 {
+        let mut merged = Vec::with_capacity(dfs.len().div_ceil(2));
+        let mut iter = dfs.into_iter();
+        while let Some(left) = iter.next() {
+            let Some(right) = iter.next() else {
+                merged.push(left);
+                break;
+            };
+            let left_s = left[key_column_idx].as_materialized_series();
+            let right_s = right[key_column_idx].as_materialized_series();
+            merged.push(_merge_sorted_dfs(&left, &right, left_s, right_s, false)?);
+        }
+        dfs = merged;
+    }
+    Ok(dfs.pop().unwrap_or_default())
+}
+
+impl ComputeNode for KWayMergeSortedNode {
+    fn name(&self) -> &str {
+        "kway_merge_sorted"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        _state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert_eq!(send.len(), 1);
+        assert_eq!(recv.len(), self.inputs.len());
+
+        for (input, r) in self.inputs.iter_mut().zip(recv.iter()) {
+            input.done = *r == PortState::Done;
+        }
+
+        let block_inputs = |recv: &mut [PortState]| {
+            for r in recv.iter_mut() {
+                if *r != PortState::Done {
+                    *r = PortState::Blocked;
+                }
+            }
+        };
+
+        if send[0] == PortState::Done {
+            recv.fill(PortState::Done);
+        } else if recv.iter().all(|r| *r == PortState::Done) && !self.has_buffered() {
+            send[0] = PortState::Done;
+        } else if send[0] == PortState::Blocked {
+            // If the output port is blocked, the input ports are blocked as well.
+            block_inputs(recv);
+        } else if self
+            .inputs
+            .iter()
+            .zip(recv.iter())
+            .any(|(i, r)| *r == PortState::Blocked && i.unmerged.is_empty())
+        {
+            // If an input that we need rows from is blocked we cannot produce data.
+            send[0] = PortState::Blocked;
+            block_inputs(recv);
+        } else {
+            send[0] = PortState::Ready;
+        }
+
+        Ok(())
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert_eq!(recv_ports.len(), self.inputs.len());
+        assert_eq!(send_ports.len(), 1);
+
+        let send = send_ports[0].take().unwrap().parallel();
+        let mut recvs = recv_ports
+            .iter_mut()
+            .map(|p| p.take().map(|p| p.serial()))
+            .collect::<Vec<_>>();
+
+        let key_column_idx = self.key_column_idx;
+        let source_token = SourceToken::new();
+        let (mut distributor, dist_recv) =
+            distributor_channel(send.len(), state.buffer_sizes.distributor);
+
+        let merge_source_token = source_token.clone();
+        join_handles.push(scope.spawn_task(TaskPriority::Low, async move {
+            let source_token = merge_source_token;
+            loop {
+                while let Some(batch) = self.next_batch()? {
+                    let seq = self.seq;
+                    self.seq = seq.successor();
+                    if distributor.send((batch, seq)).await.is_err() {
+                        return Ok(());
+                    }
+                }
+
+                if source_token.stop_requested() {
+                    break;
+                }
+
+                // Get more rows from the input that is holding us back. If that input is blocked
+                // or its port closes, this phase is over.
+                let Some(idx) = self.blocking_input() else {
+                    break;
+                };
+                let Some(port) = recvs[idx].as_mut() else {
+                    break;
+                };
+                let Ok(morsel) = port.recv().await else {
+                    break;
+                };
+                self.push(idx, morsel.into_df());
+            }
+
+            for (idx, port) in recvs.iter_mut().enumerate() {
+                if let Some(port) = port {
+                    self.buffer_remaining(idx, port).await;
+                }
+            }
+
+            Ok(())
+        }));
+
+        // Tasks that actually merge the dataframes. Since this merge might be very expensive, this
+        // is split over several tasks.
+        join_handles.extend(dist_recv.into_iter().zip(send).map(|(mut recv, mut send)| {
+            let source_token = source_token.clone();
+            let ideal_morsel_size = get_ideal_morsel_size().max(1);
+            scope.spawn_task(TaskPriority::High, async move {
+                while let Ok((batch, seq)) = recv.recv().await {
+                    let merged = merge_all(batch, key_column_idx)?;
+
+                    // MorselSeq have to be monotonely non-decreasing so we can pass the same
+                    // sequence token several times.
+                    let mut offset = 0;
+                    while offset < merged.height() {
+                        let len = ideal_morsel_size.min(merged.height() - offset);
+                        let df = merged.slice(offset as i64, len);
+                        offset += len;
+                        if send
+                            .send(Morsel::new(df, seq, source_token.clone()))
+                            .await
+                            .is_err()
+                        {
+                            return Ok(());
+                        }
+                    }
+                }
+
+                Ok(())
+            })
+        }));
+    }
+}
@@ -6,7 +6,6 @@ use polars_core::schema::Schema;
 use polars_ops::frame::_merge_sorted_dfs;
 use polars_utils::pl_str::PlSmallStr;
 
-use crate::DEFAULT_DISTRIBUTOR_BUFFER_SIZE;
 use crate::async_primitives::connector::Receiver;
 use crate::async_primitives::distributor_channel::distributor_channel;
 use crate::morsel::{SourceToken, get_ideal_morsel_size};
@@ -231,7 +230,7 @@ impl ComputeNode for MergeSortedNode {
         scope: &'s TaskScope<'s, 'env>,
         recv_ports: &mut [Option<RecvPort<'_>>],
         send_ports: &mut [Option<SendPort<'_>>],
-        _state: &'s StreamingExecutionState,
+        state: &'s StreamingExecutionState,
         join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
     ) {
         assert_eq!(recv_ports.len(), 2);
@@ -309,7 +308,7 @@ impl ComputeNode for MergeSortedNode {
                 }
 
                 let (mut distributor, dist_recv) =
-                    distributor_channel(send.len(), *DEFAULT_DISTRIBUTOR_BUFFER_SIZE);
+                    distributor_channel(send.len(), state.buffer_sizes.distributor);
 
                 let mut left = left.map(|p| p.serial());
                 let mut right = right.map(|p| p.serial());
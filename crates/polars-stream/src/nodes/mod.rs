@@ -1,3 +1,5 @@
+#[cfg(feature = "dynamic_group_by")]
+pub mod dynamic_group_by;
 pub mod filter;
 pub mod group_by;
 pub mod in_memory_map;
@@ -7,6 +9,8 @@ pub mod input_independent_select;
 pub mod io_sinks;
 pub mod io_sources;
 pub mod joins;
+#[cfg(feature = "merge_sorted")]
+pub mod kway_merge_sorted;
 pub mod map;
 #[cfg(feature = "merge_sorted")]
 pub mod merge_sorted;
@@ -16,7 +20,13 @@ pub mod ordered_union;
 pub mod reduce;
 pub mod select;
 pub mod simple_projection;
+pub mod sort;
+pub mod streaming_operator;
 pub mod streaming_slice;
+pub mod top_k;
+pub mod unique;
+#[cfg(feature = "pivot")]
+pub mod unpivot;
 pub mod with_row_index;
 pub mod zip;
 
@@ -68,6 +78,18 @@ pub trait ComputeNode: Send {
         false
     }
 
+    /// The estimated number of bytes this node currently holds in memory. This is sampled
+    /// in between execution phases for the runtime metrics.
+    fn buffered_bytes(&self) -> usize {
+        0
+    }
+
+    /// The total number of bytes this node has written to disk because it exceeded its memory
+    /// budget, see [`SpillDir`](crate::utils::spill::SpillDir).
+    fn spilled_bytes(&self) -> usize {
+        0
+    }
+
     /// Spawn the tasks that this compute node needs to receive input(s),
     /// process it and send to its output(s). Called once per execution phase.
     fn spawn<'env, 's>(
@@ -5,6 +5,7 @@ use tokio::sync::mpsc::{UnboundedSender, unbounded_channel};
 use super::compute_node_prelude::*;
 use crate::async_primitives::wait_group::WaitGroup;
 use crate::morsel::SourceToken;
+use crate::utils::memory_budget::MemoryReservation;
 
 // TODO: replace this with an out-of-core buffering solution.
 enum BufferedStream {
@@ -20,12 +21,15 @@ impl BufferedStream {
 
 pub struct MultiplexerNode {
     buffers: Vec<BufferedStream>,
+    /// Accounts for the morsels held for outputs which aren't reading.
+    reservation: Option<MemoryReservation>,
 }
 
 impl MultiplexerNode {
     pub fn new() -> Self {
         Self {
             buffers: Vec::default(),
+            reservation: None,
         }
     }
 }
@@ -100,12 +104,16 @@ impl ComputeNode for MultiplexerNode {
         scope: &'s TaskScope<'s, 'env>,
         recv_ports: &mut [Option<RecvPort<'_>>],
         send_ports: &mut [Option<SendPort<'_>>],
-        _state: &'s StreamingExecutionState,
+        state: &'s StreamingExecutionState,
         join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
     ) {
         assert!(recv_ports.len() == 1 && !send_ports.is_empty());
         assert!(self.buffers.len() == send_ports.len());
 
+        let reservation = &*self
+            .reservation
+            .get_or_insert_with(|| state.memory_reservation("multiplexer"));
+
         enum Listener<'a> {
             Active(UnboundedSender<Morsel>),
             Buffering(&'a mut VecDeque<Morsel>),
@@ -156,6 +164,7 @@ impl ComputeNode for MultiplexerNode {
                                 Err(_) => *buf_sender = Listener::Inactive,
                             },
                             Listener::Buffering(b) => {
+                                reservation.grow(morsel.df().estimated_size())?;
                                 b.push_front(morsel.clone());
                                 anyone_interested = true;
                             },
@@ -188,6 +197,7 @@ impl ComputeNode for MultiplexerNode {
                 join_handles.push(scope.spawn_task(TaskPriority::High, async move {
                     // First we try to flush all the old buffered data.
                     while let Some(mut morsel) = buf.pop_back() {
+                        reservation.shrink(morsel.df().estimated_size());
                         morsel.replace_source_token(buffered_source_token.clone());
                         morsel.set_consume_token(wait_group.token());
                         if sender.send(morsel).await.is_err()
@@ -0,0 +1,503 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use arrow::array::BinaryArray;
+use polars_core::POOL;
+use polars_core::prelude::row_encode::_get_rows_encoded;
+use polars_core::prelude::sort::_broadcast_bools;
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_utils::pl_str::PlSmallStr;
+use rayon::prelude::*;
+
+use super::compute_node_prelude::*;
+use crate::async_primitives::connector::Receiver;
+use crate::morsel::{SourceToken, get_ideal_morsel_size};
+use crate::nodes::in_memory_source::InMemorySourceNode;
+use crate::utils::memory_budget::MemoryReservation;
+use crate::utils::spill::{SpillDir, SpilledDataFrame};
+
+const SEQ_NAME: &str = "__POLARS_SORT_SEQ";
+const ROW_NAME: &str = "__POLARS_SORT_ROW";
+
+/// A chunk of a sorted run, either still in memory or spilled to disk.
+enum RunChunk {
+    InMemory(DataFrame),
+    Spilled(SpilledDataFrame),
+}
+
+impl RunChunk {
+    fn load(self) -> PolarsResult<DataFrame> {
+        match self {
+            Self::InMemory(df) => Ok(df),
+            Self::Spilled(spilled) => spilled.load(),
+        }
+    }
+}
+
+/// A sorted sequence of rows, split into chunks which are loaded one at a time while merging.
+type Run = VecDeque<RunChunk>;
+
+struct LocalSortSinkState {
+    frames: Vec<DataFrame>,
+    buffered_bytes: usize,
+    runs: Vec<Run>,
+}
+
+/// Settings shared by all sink tasks and the merge.
+struct SortParams {
+    /// The columns to sort by, followed by the sequence and row columns if the order is
+    /// maintained.
+    by: Vec<PlSmallStr>,
+    /// The sort options, with `descending` and `nulls_last` given for every column in `by`.
+    sort_options: SortMultipleOptions,
+    /// Whether we track the position of every row in the input, which makes the sort stable.
+    track_order: bool,
+    spill_dir: SpillDir,
+}
+
+impl SortParams {
+    fn sort(&self, df: DataFrame) -> PolarsResult<DataFrame> {
+        df.sort(self.by.clone(), self.sort_options.clone())
+    }
+
+    /// Encodes the sort keys of `df` such that the byte order of the keys is the sort order.
+    fn encode_keys(&self, df: &DataFrame) -> PolarsResult<BinaryArray<i64>> {
+        let by = df.select_columns(self.by.clone())?;
+        let rows = _get_rows_encoded(
+            &by,
+            &self.sort_options.descending,
+            &self.sort_options.nulls_last,
+        )?;
+        Ok(rows.into_array())
+    }
+
+    /// Sorts the buffered frames of a local state into a run and writes it to disk in chunks,
+    /// returning their memory to the reservation.
+    fn spill(
+        &self,
+        local: &mut LocalSortSinkState,
+        reservation: &MemoryReservation,
+    ) -> PolarsResult<()> {
+        if !local.frames.is_empty() {
+            let df = self.sort(accumulate_dataframes_vertical_unchecked(
+                local.frames.drain(..),
+            ))?;
+            let run = split_df(df)
+                .map(|mut chunk| Ok(RunChunk::Spilled(self.spill_dir.spill(&mut chunk)?)))
+                .collect::<PolarsResult<_>>()?;
+            local.runs.push(run);
+        }
+        reservation.shrink(local.buffered_bytes);
+        local.buffered_bytes = 0;
+        Ok(())
+    }
+
+    fn finish(&self, df: DataFrame) -> DataFrame {
+        if self.track_order {
+            df.drop_many([SEQ_NAME, ROW_NAME])
+        } else {
+            df
+        }
+    }
+}
+
+/// Splits `df` into slices of the ideal morsel size.
+fn split_df(df: DataFrame) -> impl Iterator<Item = DataFrame> {
+    let morsel_size = get_ideal_morsel_size();
+    (0..df.height())
+        .step_by(morsel_size)
+        .map(move |offset| df.slice(offset as i64, morsel_size))
+}
+
+struct SortSinkState {
+    local: Vec<LocalSortSinkState>,
+}
+
+impl SortSinkState {
+    fn spawn<'env, 's>(
+        &'env mut self,
+        params: &'env SortParams,
+        reservation: &'env MemoryReservation,
+        spill_threshold: usize,
+        scope: &'s TaskScope<'s, 'env>,
+        receivers: Vec<Receiver<Morsel>>,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        self.local
+            .resize_with(receivers.len(), || LocalSortSinkState {
+                frames: Vec::new(),
+                buffered_bytes: 0,
+                runs: Vec::new(),
+            });
+        // Every pipeline gets an equal share of the memory budget.
+        let spill_threshold = spill_threshold / self.local.len();
+        for (mut recv, local) in receivers.into_iter().zip(&mut self.local) {
+            join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                while let Ok(morsel) = recv.recv().await {
+                    let seq = morsel.seq().to_u64();
+                    let mut df = morsel.into_df();
+                    if df.height() == 0 {
+                        continue;
+                    }
+                    if params.track_order {
+                        let height = df.height();
+                        let seq_col = Column::new_scalar(
+                            PlSmallStr::from_static(SEQ_NAME),
+                            Scalar::from(seq),
+                            height,
+                        );
+                        let row_col = IdxCa::from_vec(
+                            PlSmallStr::from_static(ROW_NAME),
+                            (0..height as IdxSize).collect(),
+                        );
+                        unsafe {
+                            df.with_column_unchecked(seq_col);
+                            df.with_column_unchecked(row_col.into_column());
+                        }
+                    }
+
+                    let morsel_bytes = df.estimated_size();
+                    local.frames.push(df);
+                    if !reservation.try_grow(morsel_bytes) {
+                        // The memory budget of the query is exhausted, free up what we hold. This
+                        // also spills the unreserved morsel.
+                        params.spill(local, reservation)?;
+                    } else {
+                        local.buffered_bytes += morsel_bytes;
+                        if local.buffered_bytes > spill_threshold {
+                            params.spill(local, reservation)?;
+                        }
+                    }
+                }
+                Ok(())
+            }));
+        }
+    }
+}
+
+/// The position of the merge in a single run.
+struct RunCursor {
+    run: Run,
+    df: DataFrame,
+    keys: BinaryArray<i64>,
+    offset: usize,
+}
+
+impl RunCursor {
+    /// Loads the next chunk of the run once the current one is consumed. Returns false if the
+    /// run is exhausted.
+    fn fill(&mut self, params: &SortParams) -> PolarsResult<bool> {
+        while self.offset == self.df.height() {
+            let Some(chunk) = self.run.pop_front() else {
+                return Ok(false);
+            };
+            self.df = chunk.load()?;
+            self.keys = params.encode_keys(&self.df)?;
+            self.offset = 0;
+        }
+        Ok(true)
+    }
+
+    /// Takes the rows of the current chunk with a key which is not larger than `bound`.
+    fn take_until(&mut self, bound: &[u8]) -> DataFrame {
+        let (mut lo, mut hi) = (self.offset, self.keys.len());
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if self.keys.value(mid) <= bound {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        let out = self.df.slice(self.offset as i64, lo - self.offset);
+        self.offset = lo;
+        out
+    }
+}
+
+/// Merges the sorted runs, emitting the rows in sorted order.
+struct SortSourceState {
+    cursors: Vec<RunCursor>,
+    ready: VecDeque<DataFrame>,
+    seq: MorselSeq,
+}
+
+impl SortSourceState {
+    fn new(runs: Vec<Run>, params: &SortParams) -> PolarsResult<Self> {
+        let mut cursors = Vec::with_capacity(runs.len());
+        for run in runs {
+            let mut cursor = RunCursor {
+                run,
+                df: DataFrame::empty(),
+                keys: BinaryArray::new_empty(ArrowDataType::LargeBinary),
+                offset: 0,
+            };
+            if cursor.fill(params)? {
+                cursors.push(cursor);
+            }
+        }
+        Ok(Self {
+            cursors,
+            ready: VecDeque::new(),
+            seq: MorselSeq::default(),
+        })
+    }
+
+    /// Merges the next batch of rows, or returns `None` if all runs are exhausted.
+    ///
+    /// Every row with a key up to the smallest last key of the loaded chunks can be emitted, as
+    /// all rows which are not loaded yet sort after it. The run the bound came from is fully
+    /// consumed, so at most one chunk of every run is in memory at any time.
+    fn next_batch(&mut self, params: &SortParams) -> PolarsResult<Option<DataFrame>> {
+        if self.cursors.is_empty() {
+            return Ok(None);
+        }
+        let bound = self
+            .cursors
+            .iter()
+            .map(|c| c.keys.value(c.keys.len() - 1))
+            .min()
+            .unwrap()
+            .to_vec();
+
+        let mut parts = Vec::with_capacity(self.cursors.len());
+        for cursor in &mut self.cursors {
+            let part = cursor.take_until(&bound);
+            if part.height() > 0 {
+                parts.push(part);
+            }
+        }
+        let mut cursors = Vec::with_capacity(self.cursors.len());
+        for mut cursor in self.cursors.drain(..) {
+            if cursor.fill(params)? {
+                cursors.push(cursor);
+            }
+        }
+        self.cursors = cursors;
+
+        let df = if parts.len() == 1 {
+            parts.pop().unwrap()
+        } else {
+            params.sort(accumulate_dataframes_vertical_unchecked(parts))?
+        };
+        Ok(Some(params.finish(df)))
+    }
+}
+
+enum SortState {
+    Sink(SortSinkState),
+    Source(SortSourceState),
+    Flushing(InMemorySourceNode),
+    Done,
+}
+
+/// Sorts its input by a set of columns, spilling to disk if the input doesn't fit in memory.
+///
+/// Every pipeline buffers its morsels. Once more than the spill threshold of the query is
+/// buffered or the memory budget of the query is exhausted, the buffered rows are sorted into
+/// a run which is written to disk. After all input is received the runs are merged, loading a
+/// single chunk of every run at a time. If nothing was spilled the input is sorted in memory.
+///
+/// If the order is maintained every row is tagged with its position in the input, which is used
+/// as the final sort key.
+pub struct SortNode {
+    state: SortState,
+    params: SortParams,
+    reservation: Option<MemoryReservation>,
+    output_schema: SchemaRef,
+}
+
+impl SortNode {
+    pub fn new(
+        output_schema: SchemaRef,
+        mut by: Vec<PlSmallStr>,
+        mut sort_options: SortMultipleOptions,
+    ) -> Self {
+        _broadcast_bools(by.len(), &mut sort_options.descending);
+        _broadcast_bools(by.len(), &mut sort_options.nulls_last);
+        let track_order = sort_options.maintain_order;
+        if track_order {
+            by.extend([SEQ_NAME, ROW_NAME].map(PlSmallStr::from_static));
+            sort_options.descending.extend([false, false]);
+            sort_options.nulls_last.extend([false, false]);
+        }
+        Self {
+            state: SortState::Sink(SortSinkState { local: Vec::new() }),
+            params: SortParams {
+                by,
+                sort_options,
+                track_order,
+                spill_dir: SpillDir::new("sort"),
+            },
+            reservation: None,
+            output_schema,
+        }
+    }
+
+    fn finalize(&mut self, sink: SortSinkState) -> PolarsResult<SortState> {
+        let params = &self.params;
+        let spilled = sink.local.iter().any(|l| !l.runs.is_empty());
+        if !spilled {
+            let frames = sink
+                .local
+                .into_iter()
+                .flat_map(|l| l.frames)
+                .collect::<Vec<_>>();
+            let df = if frames.is_empty() {
+                DataFrame::empty_with_schema(&self.output_schema)
+            } else {
+                params.finish(params.sort(accumulate_dataframes_vertical_unchecked(frames))?)
+            };
+            return Ok(SortState::Flushing(InMemorySourceNode::new(
+                Arc::new(df),
+                MorselSeq::default(),
+            )));
+        }
+
+        // The rows that are still buffered become in-memory runs.
+        let runs = POOL.install(|| {
+            sink.local
+                .into_par_iter()
+                .with_max_len(1)
+                .flat_map_iter(|l| {
+                    let buffered = (!l.frames.is_empty()).then(|| {
+                        let df = accumulate_dataframes_vertical_unchecked(l.frames);
+                        Ok(Run::from([RunChunk::InMemory(params.sort(df)?)]))
+                    });
+                    l.runs.into_iter().map(Ok).chain(buffered)
+                })
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+        Ok(SortState::Source(SortSourceState::new(runs, params)?))
+    }
+}
+
+impl ComputeNode for SortNode {
+    fn name(&self) -> &str {
+        "sort"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 1 && send.len() == 1);
+
+        // State transitions.
+        match &mut self.state {
+            // If the output doesn't want any more data, transition to being done.
+            _ if send[0] == PortState::Done => {
+                self.state = SortState::Done;
+            },
+            // Input is done, transition to being a source.
+            SortState::Sink(_) if matches!(recv[0], PortState::Done) => {
+                let SortState::Sink(sink) = std::mem::replace(&mut self.state, SortState::Done)
+                else {
+                    unreachable!()
+                };
+                self.state = self.finalize(sink)?;
+            },
+            SortState::Source(src) if src.cursors.is_empty() && src.ready.is_empty() => {
+                self.state = SortState::Done;
+            },
+            // Defer to source node implementation.
+            SortState::Flushing(src) => {
+                src.update_state(&mut [], send, state)?;
+                if send[0] == PortState::Done {
+                    self.state = SortState::Done;
+                }
+            },
+            // Nothing to change.
+            SortState::Done | SortState::Sink(_) | SortState::Source(_) => {},
+        }
+
+        // Communicate our state.
+        match &self.state {
+            SortState::Sink { .. } => {
+                send[0] = PortState::Blocked;
+                recv[0] = PortState::Ready;
+            },
+            SortState::Source(_) => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Ready;
+            },
+            SortState::Flushing(_) => {
+                recv[0] = PortState::Done;
+            },
+            SortState::Done => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Done;
+            },
+        }
+        Ok(())
+    }
+
+    fn spilled_bytes(&self) -> usize {
+        self.params.spill_dir.spilled_bytes()
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(send_ports.len() == 1 && recv_ports.len() == 1);
+        let params = &self.params;
+        let reservation = &*self
+            .reservation
+            .get_or_insert_with(|| state.memory_reservation("sort"));
+        match &mut self.state {
+            SortState::Sink(sink) => {
+                assert!(send_ports[0].is_none());
+                let receivers = recv_ports[0].take().unwrap().parallel();
+                sink.spawn(
+                    params,
+                    reservation,
+                    state.spill_threshold,
+                    scope,
+                    receivers,
+                    join_handles,
+                );
+            },
+            SortState::Source(src) => {
+                assert!(recv_ports[0].is_none());
+                let mut send = send_ports[0].take().unwrap().serial();
+                join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                    let source_token = SourceToken::new();
+                    loop {
+                        if src.ready.is_empty() {
+                            let Some(df) = src.next_batch(params)? else {
+                                break;
+                            };
+                            src.ready.extend(split_df(df));
+                            continue;
+                        }
+
+                        let df = src.ready.pop_front().unwrap();
+                        let morsel = Morsel::new(df, src.seq, source_token.clone());
+                        src.seq = src.seq.successor();
+                        if let Err(morsel) = send.send(morsel).await {
+                            src.ready.push_front(morsel.into_df());
+                            break;
+                        }
+                        if source_token.stop_requested() {
+                            break;
+                        }
+                    }
+                    Ok(())
+                }));
+            },
+            SortState::Flushing(src) => {
+                assert!(recv_ports[0].is_none());
+                src.spawn(scope, &mut [], send_ports, state, join_handles);
+            },
+            SortState::Done => unreachable!(),
+        }
+    }
+}
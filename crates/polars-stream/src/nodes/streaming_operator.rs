@@ -0,0 +1,119 @@
+use polars_utils::pl_str::PlSmallStr;
+
+use super::compute_node_prelude::*;
+use crate::morsel::SourceToken;
+use crate::operator::StreamingOperator;
+
+enum OperatorState {
+    Running,
+    /// The input is done, the output of `finish` still needs to be sent.
+    Finishing {
+        finished: bool,
+    },
+    Done,
+}
+
+/// Runs a user-defined [`StreamingOperator`] over its input, serially and in order.
+pub struct StreamingOperatorNode {
+    name: PlSmallStr,
+    operator: Box<dyn StreamingOperator>,
+    state: OperatorState,
+    next_seq: MorselSeq,
+}
+
+impl StreamingOperatorNode {
+    pub fn new(name: &str, operator: Box<dyn StreamingOperator>) -> Self {
+        Self {
+            name: name.into(),
+            operator,
+            state: OperatorState::Running,
+            next_seq: MorselSeq::default(),
+        }
+    }
+}
+
+impl ComputeNode for StreamingOperatorNode {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        _state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 1 && send.len() == 1);
+
+        match self.state {
+            _ if send[0] == PortState::Done => self.state = OperatorState::Done,
+            OperatorState::Running if recv[0] == PortState::Done => {
+                self.state = OperatorState::Finishing { finished: false };
+            },
+            OperatorState::Finishing { finished: true } => self.state = OperatorState::Done,
+            OperatorState::Running | OperatorState::Finishing { .. } | OperatorState::Done => {},
+        }
+
+        match self.state {
+            OperatorState::Running => recv.swap_with_slice(send),
+            OperatorState::Finishing { .. } => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Ready;
+            },
+            OperatorState::Done => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Done;
+            },
+        }
+        Ok(())
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        _state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 1 && send_ports.len() == 1);
+        let mut send = send_ports[0].take().unwrap().serial();
+        let operator = &mut self.operator;
+        let next_seq = &mut self.next_seq;
+
+        match &mut self.state {
+            OperatorState::Running => {
+                let mut recv = recv_ports[0].take().unwrap().serial();
+                join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                    while let Ok(morsel) = recv.recv().await {
+                        let (df, seq, source_token, consume_token) = morsel.into_inner();
+                        let df = operator.process(df).await?;
+                        *next_seq = (*next_seq).max(seq.successor());
+
+                        let mut morsel = Morsel::new(df, seq, source_token);
+                        if let Some(consume_token) = consume_token {
+                            morsel.set_consume_token(consume_token);
+                        }
+                        if send.send(morsel).await.is_err() {
+                            break;
+                        }
+                    }
+
+                    Ok(())
+                }));
+            },
+            OperatorState::Finishing { finished } => {
+                assert!(recv_ports[0].is_none());
+                join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                    if let Some(df) = operator.finish().await? {
+                        let morsel = Morsel::new(df, *next_seq, SourceToken::new());
+                        let _ = send.send(morsel).await;
+                    }
+                    *finished = true;
+                    Ok(())
+                }));
+            },
+            OperatorState::Done => unreachable!(),
+        }
+    }
+}
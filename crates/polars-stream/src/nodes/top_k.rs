@@ -0,0 +1,188 @@
+use std::sync::Arc;
+
+use polars_core::prelude::SortMultipleOptions;
+use polars_core::schema::Schema;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_utils::IdxSize;
+use polars_utils::pl_str::PlSmallStr;
+
+use super::compute_node_prelude::*;
+use super::in_memory_source::InMemorySourceNode;
+
+/// The minimum number of rows a pipeline buffers before it drops the rows outside of the top k.
+const MIN_BUFFERED_ROWS: usize = 1 << 14;
+
+enum TopKState {
+    Sink { kept: Vec<DataFrame> },
+    Source(InMemorySourceNode),
+    Done,
+}
+
+/// Sorts its input and slices `offset..offset + len` from it, without sorting all of the input.
+///
+/// Every pipeline buffers its morsels, and once it has buffered enough rows it only keeps the
+/// first `offset + len` rows by the sort order. The rows kept by all pipelines are sorted once
+/// the input is done.
+pub struct TopKNode {
+    state: TopKState,
+    input_schema: Arc<Schema>,
+    by: Vec<PlSmallStr>,
+    sort_options: SortMultipleOptions,
+    offset: usize,
+    len: usize,
+}
+
+impl TopKNode {
+    pub fn new(
+        input_schema: Arc<Schema>,
+        by: Vec<PlSmallStr>,
+        sort_options: SortMultipleOptions,
+        offset: usize,
+        len: usize,
+    ) -> Self {
+        Self {
+            state: TopKState::Sink { kept: Vec::new() },
+            input_schema,
+            by,
+            sort_options,
+            offset,
+            len,
+        }
+    }
+}
+
+/// The first `k` rows of `df` by the sort order.
+fn top_k(
+    df: DataFrame,
+    by: &[PlSmallStr],
+    sort_options: &SortMultipleOptions,
+    k: usize,
+) -> PolarsResult<DataFrame> {
+    let sort_options = SortMultipleOptions {
+        limit: Some(k.min(IdxSize::MAX as usize) as IdxSize),
+        ..sort_options.clone()
+    };
+    let df = df.sort(by.to_vec(), sort_options)?;
+    Ok(df.head(Some(k)))
+}
+
+impl ComputeNode for TopKNode {
+    fn name(&self) -> &str {
+        "top_k"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 1 && send.len() == 1);
+
+        // If the output doesn't want any more data, transition to being done.
+        if send[0] == PortState::Done && !matches!(self.state, TopKState::Done) {
+            self.state = TopKState::Done;
+        }
+
+        // If the input is done, sort the kept rows and transition to being a source.
+        if let TopKState::Sink { kept } = &mut self.state {
+            if recv[0] == PortState::Done {
+                let df = if kept.is_empty() {
+                    DataFrame::empty_with_schema(&self.input_schema)
+                } else {
+                    accumulate_dataframes_vertical_unchecked(kept.drain(..))
+                };
+                let df = top_k(
+                    df,
+                    &self.by,
+                    &self.sort_options,
+                    self.offset.saturating_add(self.len),
+                )?;
+                let df = df.slice(self.offset as i64, self.len);
+                self.state =
+                    TopKState::Source(InMemorySourceNode::new(Arc::new(df), MorselSeq::default()));
+            }
+        }
+
+        match &mut self.state {
+            TopKState::Sink { .. } => {
+                recv[0] = PortState::Ready;
+                send[0] = PortState::Blocked;
+            },
+            TopKState::Source(source_node) => {
+                recv[0] = PortState::Done;
+                source_node.update_state(&mut [], send, state)?;
+            },
+            TopKState::Done => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Done;
+            },
+        }
+        Ok(())
+    }
+
+    fn buffered_bytes(&self) -> usize {
+        match &self.state {
+            TopKState::Sink { kept } => kept.iter().map(|df| df.estimated_size()).sum(),
+            TopKState::Source(source_node) => source_node.buffered_bytes(),
+            TopKState::Done => 0,
+        }
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 1 && send_ports.len() == 1);
+        match &mut self.state {
+            TopKState::Sink { kept } => {
+                assert!(send_ports[0].is_none());
+                let recv = recv_ports[0].take().unwrap();
+                let by = &self.by;
+                let sort_options = &self.sort_options;
+                let k = self.offset.saturating_add(self.len);
+                let max_buffered = k.saturating_mul(2).max(MIN_BUFFERED_ROWS);
+
+                let parallel_tasks: Vec<_> = recv
+                    .parallel()
+                    .into_iter()
+                    .map(|mut recv| {
+                        scope.spawn_task(TaskPriority::High, async move {
+                            let mut buffered = Vec::new();
+                            let mut buffered_rows = 0;
+                            while let Ok(morsel) = recv.recv().await {
+                                let df = morsel.into_df();
+                                buffered_rows += df.height();
+                                buffered.push(df);
+                                if buffered_rows >= max_buffered {
+                                    let df = accumulate_dataframes_vertical_unchecked(
+                                        buffered.drain(..),
+                                    );
+                                    let df = top_k(df, by, sort_options, k)?;
+                                    buffered_rows = df.height();
+                                    buffered.push(df);
+                                }
+                            }
+                            PolarsResult::Ok(buffered)
+                        })
+                    })
+                    .collect();
+
+                join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                    for task in parallel_tasks {
+                        kept.extend(task.await?);
+                    }
+                    Ok(())
+                }));
+            },
+            TopKState::Source(source) => {
+                source.spawn(scope, &mut [], send_ports, state, join_handles)
+            },
+            TopKState::Done => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,430 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use polars_core::POOL;
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_expr::hash_keys::HashKeys;
+use polars_utils::hashing::HashPartitioner;
+use polars_utils::pl_str::PlSmallStr;
+use rayon::prelude::*;
+
+use super::compute_node_prelude::*;
+use crate::async_primitives::connector::Receiver;
+use crate::morsel::{SourceToken, get_ideal_morsel_size};
+use crate::nodes::in_memory_source::InMemorySourceNode;
+use crate::utils::memory_budget::MemoryReservation;
+use crate::utils::spill::{SpillDir, SpilledDataFrame};
+
+const SEQ_NAME: &str = "__POLARS_UNIQUE_SEQ";
+const ROW_NAME: &str = "__POLARS_UNIQUE_ROW";
+
+/// The rows of a single hash partition, partially spilled to disk.
+#[derive(Default)]
+struct Partition {
+    frames: Vec<DataFrame>,
+    spilled: Vec<SpilledDataFrame>,
+}
+
+impl Partition {
+    fn load(self) -> PolarsResult<DataFrame> {
+        let mut frames = self.frames;
+        for spilled in self.spilled {
+            frames.push(spilled.load()?);
+        }
+        Ok(accumulate_dataframes_vertical_unchecked(frames))
+    }
+}
+
+struct LocalUniqueSinkState {
+    partitions: Vec<Partition>,
+    buffered_bytes: usize,
+}
+
+/// Settings shared by all sink tasks and the final per-partition unique.
+struct UniqueParams {
+    key: Vec<PlSmallStr>,
+    keep: UniqueKeepStrategy,
+    /// Whether we track the position of every row in the input, which is needed to determine
+    /// the first / last occurrence and to restore the input order.
+    track_order: bool,
+    /// The position of the next row received. Morsels can share a sequence id, so the rows are
+    /// numbered across all morsels to keep the tags unique.
+    next_row: AtomicU64,
+    partitioner: HashPartitioner,
+    random_state: PlRandomState,
+    spill_dir: SpillDir,
+}
+
+impl UniqueParams {
+    /// Computes the unique rows of a single partition, sorted by the input order if it is tracked.
+    fn unique_partition(&self, df: DataFrame) -> PolarsResult<DataFrame> {
+        if df.height() == 0 {
+            return Ok(df);
+        }
+        let df = if self.track_order {
+            df.sort([SEQ_NAME, ROW_NAME], SortMultipleOptions::default())?
+        } else {
+            df
+        };
+        df.unique_impl(self.track_order, Some(self.key.clone()), self.keep, None)
+    }
+
+    /// Writes all buffered frames of a local state to disk, returning their memory to the
+    /// reservation. Unless we only keep unique keys the duplicates are removed first, as they can
+    /// never be part of the output.
+    fn spill(
+        &self,
+        local: &mut LocalUniqueSinkState,
+        reservation: &MemoryReservation,
+    ) -> PolarsResult<()> {
+        for partition in &mut local.partitions {
+            if partition.frames.is_empty() {
+                continue;
+            }
+            let mut df = accumulate_dataframes_vertical_unchecked(partition.frames.drain(..));
+            if self.keep != UniqueKeepStrategy::None {
+                df = self.unique_partition(df)?;
+            }
+            partition.spilled.push(self.spill_dir.spill(&mut df)?);
+        }
+        reservation.shrink(local.buffered_bytes);
+        local.buffered_bytes = 0;
+        Ok(())
+    }
+
+    fn finalize_partition(&self, partition: Partition) -> PolarsResult<DataFrame> {
+        self.unique_partition(partition.load()?)
+    }
+}
+
+struct UniqueSinkState {
+    local: Vec<LocalUniqueSinkState>,
+}
+
+impl UniqueSinkState {
+    fn spawn<'env, 's>(
+        &'env mut self,
+        params: &'env UniqueParams,
+        reservation: &'env MemoryReservation,
+        spill_threshold: usize,
+        scope: &'s TaskScope<'s, 'env>,
+        receivers: Vec<Receiver<Morsel>>,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        let num_partitions = params.partitioner.num_partitions();
+        self.local
+            .resize_with(receivers.len(), || LocalUniqueSinkState {
+                partitions: (0..num_partitions).map(|_| Partition::default()).collect(),
+                buffered_bytes: 0,
+            });
+        // Every pipeline gets an equal share of the memory budget.
+        let spill_threshold = spill_threshold / self.local.len();
+        for (mut recv, local) in receivers.into_iter().zip(&mut self.local) {
+            join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                let mut partition_idxs = vec![Vec::new(); num_partitions];
+                while let Ok(morsel) = recv.recv().await {
+                    let seq = morsel.seq().to_u64();
+                    let mut df = morsel.into_df();
+                    if df.height() == 0 {
+                        continue;
+                    }
+                    if params.track_order {
+                        let height = df.height();
+                        let seq_col = Column::new_scalar(
+                            PlSmallStr::from_static(SEQ_NAME),
+                            Scalar::from(seq),
+                            height,
+                        );
+                        let first_row = params.next_row.fetch_add(height as u64, Ordering::Relaxed);
+                        let row_col = UInt64Chunked::from_vec(
+                            PlSmallStr::from_static(ROW_NAME),
+                            (first_row..first_row + height as u64).collect(),
+                        );
+                        unsafe {
+                            df.with_column_unchecked(seq_col);
+                            df.with_column_unchecked(row_col.into_column());
+                        }
+                    }
+
+                    let keys = df.select(params.key.iter().cloned())?;
+                    let hash_keys = HashKeys::from_df(&keys, params.random_state, true, false);
+                    for idxs in &mut partition_idxs {
+                        idxs.clear();
+                    }
+                    hash_keys.gen_idxs_per_partition(
+                        &params.partitioner,
+                        &mut partition_idxs,
+                        &mut [],
+                        true,
+                    );
+
+                    let mut morsel_bytes = 0;
+                    for (partition, idxs) in local.partitions.iter_mut().zip(&partition_idxs) {
+                        if idxs.is_empty() {
+                            continue;
+                        }
+                        let part = unsafe { df.take_slice_unchecked(idxs) };
+                        morsel_bytes += part.estimated_size();
+                        partition.frames.push(part);
+                    }
+
+                    if !reservation.try_grow(morsel_bytes) {
+                        // The memory budget of the query is exhausted, free up what we hold. This
+                        // also spills the unreserved morsel.
+                        params.spill(local, reservation)?;
+                    } else {
+                        local.buffered_bytes += morsel_bytes;
+                        if local.buffered_bytes > spill_threshold {
+                            params.spill(local, reservation)?;
+                        }
+                    }
+                }
+                Ok(())
+            }));
+        }
+    }
+
+    fn into_partitions(self, num_partitions: usize) -> Vec<Partition> {
+        let mut partitions: Vec<Partition> =
+            (0..num_partitions).map(|_| Partition::default()).collect();
+        for local in self.local {
+            for (partition, l) in partitions.iter_mut().zip(local.partitions) {
+                partition.frames.extend(l.frames);
+                partition.spilled.extend(l.spilled);
+            }
+        }
+        partitions
+    }
+}
+
+/// Emits the unique rows one partition at a time, so only a single partition has to be in memory.
+struct UniqueSourceState {
+    partitions: VecDeque<Partition>,
+    ready: VecDeque<DataFrame>,
+    seq: MorselSeq,
+}
+
+enum UniqueState {
+    Sink(UniqueSinkState),
+    Source(UniqueSourceState),
+    Flushing(InMemorySourceNode),
+    Done,
+}
+
+/// A `unique` on (a subset of) the columns.
+///
+/// Incoming rows are hash partitioned on the key columns, and the partitions are spilled to disk
+/// once more than the spill threshold of the query is buffered or the memory budget of the query
+/// is exhausted. After all input is received the unique rows are determined per partition. If the
+/// order is tracked every row is tagged with its morsel sequence id and a row number unique within
+/// the node, which is used to find the first / last occurrence of each key and to restore the
+/// input order if `maintain_order` is set.
+pub struct UniqueNode {
+    state: UniqueState,
+    params: UniqueParams,
+    reservation: Option<MemoryReservation>,
+    maintain_order: bool,
+    output_schema: SchemaRef,
+}
+
+impl UniqueNode {
+    pub fn new(
+        output_schema: SchemaRef,
+        key: Vec<PlSmallStr>,
+        keep: UniqueKeepStrategy,
+        maintain_order: bool,
+        num_pipelines: usize,
+    ) -> Self {
+        let track_order =
+            maintain_order || matches!(keep, UniqueKeepStrategy::First | UniqueKeepStrategy::Last);
+        // More partitions than pipelines keeps the memory needed to process a single partition
+        // low after spilling.
+        let num_partitions = num_pipelines * 4;
+        Self {
+            state: UniqueState::Sink(UniqueSinkState { local: Vec::new() }),
+            params: UniqueParams {
+                key,
+                keep,
+                track_order,
+                next_row: AtomicU64::new(0),
+                partitioner: HashPartitioner::new(num_partitions, 0),
+                random_state: PlRandomState::default(),
+                spill_dir: SpillDir::new("unique"),
+            },
+            reservation: None,
+            maintain_order,
+            output_schema,
+        }
+    }
+
+    fn finalize(&mut self, sink: UniqueSinkState) -> PolarsResult<UniqueState> {
+        let partitions = sink.into_partitions(self.params.partitioner.num_partitions());
+        if !self.maintain_order {
+            return Ok(UniqueState::Source(UniqueSourceState {
+                partitions: partitions.into(),
+                ready: VecDeque::new(),
+                seq: MorselSeq::default(),
+            }));
+        }
+
+        let params = &self.params;
+        let frames = POOL.install(|| {
+            partitions
+                .into_par_iter()
+                .with_max_len(1)
+                .map(|p| params.finalize_partition(p))
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+        let df = if frames.is_empty() {
+            DataFrame::empty_with_schema(&self.output_schema)
+        } else {
+            accumulate_dataframes_vertical_unchecked(frames)
+                .sort([SEQ_NAME, ROW_NAME], SortMultipleOptions::default())?
+                .drop_many([SEQ_NAME, ROW_NAME])
+        };
+        Ok(UniqueState::Flushing(InMemorySourceNode::new(
+            Arc::new(df),
+            MorselSeq::default(),
+        )))
+    }
+}
+
+impl ComputeNode for UniqueNode {
+    fn name(&self) -> &str {
+        "unique"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 1 && send.len() == 1);
+
+        // State transitions.
+        match &mut self.state {
+            // If the output doesn't want any more data, transition to being done.
+            _ if send[0] == PortState::Done => {
+                self.state = UniqueState::Done;
+            },
+            // Input is done, transition to being a source.
+            UniqueState::Sink(_) if matches!(recv[0], PortState::Done) => {
+                let UniqueState::Sink(sink) = std::mem::replace(&mut self.state, UniqueState::Done)
+                else {
+                    unreachable!()
+                };
+                self.state = self.finalize(sink)?;
+            },
+            UniqueState::Source(src) if src.partitions.is_empty() && src.ready.is_empty() => {
+                self.state = UniqueState::Done;
+            },
+            // Defer to source node implementation.
+            UniqueState::Flushing(src) => {
+                src.update_state(&mut [], send, state)?;
+                if send[0] == PortState::Done {
+                    self.state = UniqueState::Done;
+                }
+            },
+            // Nothing to change.
+            UniqueState::Done | UniqueState::Sink(_) | UniqueState::Source(_) => {},
+        }
+
+        // Communicate our state.
+        match &self.state {
+            UniqueState::Sink { .. } => {
+                send[0] = PortState::Blocked;
+                recv[0] = PortState::Ready;
+            },
+            UniqueState::Source(_) => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Ready;
+            },
+            UniqueState::Flushing(_) => {
+                recv[0] = PortState::Done;
+            },
+            UniqueState::Done => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Done;
+            },
+        }
+        Ok(())
+    }
+
+    fn spilled_bytes(&self) -> usize {
+        self.params.spill_dir.spilled_bytes()
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(send_ports.len() == 1 && recv_ports.len() == 1);
+        let params = &self.params;
+        let reservation = &*self
+            .reservation
+            .get_or_insert_with(|| state.memory_reservation("unique"));
+        match &mut self.state {
+            UniqueState::Sink(sink) => {
+                assert!(send_ports[0].is_none());
+                let receivers = recv_ports[0].take().unwrap().parallel();
+                sink.spawn(
+                    params,
+                    reservation,
+                    state.spill_threshold,
+                    scope,
+                    receivers,
+                    join_handles,
+                );
+            },
+            UniqueState::Source(src) => {
+                assert!(recv_ports[0].is_none());
+                let mut send = send_ports[0].take().unwrap().serial();
+                join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                    let source_token = SourceToken::new();
+                    loop {
+                        if src.ready.is_empty() {
+                            let Some(partition) = src.partitions.pop_front() else {
+                                break;
+                            };
+                            let mut df = params.finalize_partition(partition)?;
+                            if params.track_order {
+                                df = df.drop_many([SEQ_NAME, ROW_NAME]);
+                            }
+                            let morsel_size = get_ideal_morsel_size();
+                            let mut offset = 0;
+                            while offset < df.height() {
+                                src.ready.push_back(df.slice(offset as i64, morsel_size));
+                                offset += morsel_size;
+                            }
+                            continue;
+                        }
+
+                        let df = src.ready.pop_front().unwrap();
+                        let morsel = Morsel::new(df, src.seq, source_token.clone());
+                        src.seq = src.seq.successor();
+                        if let Err(morsel) = send.send(morsel).await {
+                            src.ready.push_front(morsel.into_df());
+                            break;
+                        }
+                        if source_token.stop_requested() {
+                            break;
+                        }
+                    }
+                    Ok(())
+                }));
+            },
+            UniqueState::Flushing(src) => {
+                assert!(recv_ports[0].is_none());
+                src.spawn(scope, &mut [], send_ports, state, join_handles);
+            },
+            UniqueState::Done => unreachable!(),
+        }
+    }
+}
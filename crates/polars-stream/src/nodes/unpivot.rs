@@ -0,0 +1,106 @@
+use polars_core::prelude::*;
+use polars_utils::pl_str::PlSmallStr;
+
+use super::compute_node_prelude::*;
+
+/// Unpivots every morsel on its own.
+///
+/// The rows of a morsel are unpivoted together, so the output is ordered by morsel first, and
+/// then by the `on` column. Unlike the in-memory engine this doesn't order all rows by the `on`
+/// column, which would require materializing the whole input.
+pub struct UnpivotNode {
+    index: Vec<PlSmallStr>,
+    on: Vec<PlSmallStr>,
+    output_schema: SchemaRef,
+}
+
+impl UnpivotNode {
+    /// The `output_schema` is that of the plan: the `index` columns, followed by the variable and
+    /// the value column.
+    pub fn new(index: Vec<PlSmallStr>, on: Vec<PlSmallStr>, output_schema: SchemaRef) -> Self {
+        Self {
+            index,
+            on,
+            output_schema,
+        }
+    }
+
+    fn unpivot(&self, df: DataFrame) -> PolarsResult<DataFrame> {
+        let Some((first, rest)) = self.on.split_first() else {
+            return Ok(DataFrame::empty_with_schema(&self.output_schema));
+        };
+        let height = df.height();
+        let (variable_name, _) = self.output_schema.get_at_index(self.index.len()).unwrap();
+        let (value_name, value_dtype) = self
+            .output_schema
+            .get_at_index(self.index.len() + 1)
+            .unwrap();
+
+        let mut columns = Vec::with_capacity(self.output_schema.len());
+        for name in &self.index {
+            let column = df.column(name)?;
+            let mut repeated = column.clone();
+            for _ in 0..rest.len() {
+                repeated.append(column)?;
+            }
+            columns.push(repeated);
+        }
+
+        // Cast to the type of the plan, which is the supertype of all `on` columns.
+        let mut variable = StringChunked::full(variable_name.clone(), first, height);
+        let mut value = df.column(first)?.cast(value_dtype)?;
+        value.rename(value_name.clone());
+        for name in rest {
+            variable.append(&StringChunked::full(PlSmallStr::EMPTY, name, height))?;
+            value.append(&df.column(name)?.cast(value_dtype)?)?;
+        }
+        columns.push(variable.into_column());
+        columns.push(value);
+
+        DataFrame::new_with_height(height * self.on.len(), columns)
+    }
+}
+
+impl ComputeNode for UnpivotNode {
+    fn name(&self) -> &str {
+        "unpivot"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        _state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 1 && send.len() == 1);
+        recv.swap_with_slice(send);
+        Ok(())
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        _state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 1 && send_ports.len() == 1);
+        let receivers = recv_ports[0].take().unwrap().parallel();
+        let senders = send_ports[0].take().unwrap().parallel();
+
+        for (mut recv, mut send) in receivers.into_iter().zip(senders) {
+            let slf = &*self;
+            join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                while let Ok(morsel) = recv.recv().await {
+                    let morsel = morsel.try_map(|df| slf.unpivot(df))?;
+                    if send.send(morsel).await.is_err() {
+                        break;
+                    }
+                }
+
+                Ok(())
+            }));
+        }
+    }
+}
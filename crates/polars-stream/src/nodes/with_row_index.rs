@@ -3,7 +3,6 @@ use polars_core::utils::Container;
 use polars_utils::pl_str::PlSmallStr;
 
 use super::compute_node_prelude::*;
-use crate::DEFAULT_DISTRIBUTOR_BUFFER_SIZE;
 use crate::async_primitives::distributor_channel::distributor_channel;
 use crate::async_primitives::wait_group::WaitGroup;
 
@@ -42,7 +41,7 @@ impl ComputeNode for WithRowIndexNode {
         scope: &'s TaskScope<'s, 'env>,
         recv_ports: &mut [Option<RecvPort<'_>>],
         send_ports: &mut [Option<SendPort<'_>>],
-        _state: &'s StreamingExecutionState,
+        state: &'s StreamingExecutionState,
         join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
     ) {
         assert!(recv_ports.len() == 1 && send_ports.len() == 1);
@@ -50,7 +49,7 @@ impl ComputeNode for WithRowIndexNode {
         let senders = send_ports[0].take().unwrap().parallel();
 
         let (mut distributor, distr_receivers) =
-            distributor_channel(senders.len(), *DEFAULT_DISTRIBUTOR_BUFFER_SIZE);
+            distributor_channel(senders.len(), state.buffer_sizes.distributor);
 
         let name = self.name.clone();
 
@@ -8,7 +8,6 @@ use polars_error::polars_ensure;
 use polars_utils::itertools::Itertools;
 
 use super::compute_node_prelude::*;
-use crate::DEFAULT_ZIP_HEAD_BUFFER_SIZE;
 use crate::morsel::SourceToken;
 
 /// The head of an input stream.
@@ -212,7 +211,7 @@ impl ComputeNode for ZipNode {
         scope: &'s TaskScope<'s, 'env>,
         recv_ports: &mut [Option<RecvPort<'_>>],
         send_ports: &mut [Option<SendPort<'_>>],
-        _state: &'s StreamingExecutionState,
+        state: &'s StreamingExecutionState,
         join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
     ) {
         assert!(send_ports.len() == 1);
@@ -224,8 +223,7 @@ impl ComputeNode for ZipNode {
             .map(|recv_port| {
                 // Add buffering to each receiver to reduce contention between input heads.
                 let mut serial_recv = recv_port.take()?.serial();
-                let (buf_send, buf_recv) =
-                    tokio::sync::mpsc::channel(*DEFAULT_ZIP_HEAD_BUFFER_SIZE);
+                let (buf_send, buf_recv) = tokio::sync::mpsc::channel(state.buffer_sizes.zip_head);
                 join_handles.push(scope.spawn_task(TaskPriority::High, async move {
                     while let Ok(morsel) = serial_recv.recv().await {
                         if buf_send.send(morsel).await.is_err() {
@@ -0,0 +1,83 @@
+use std::sync::{Arc, LazyLock};
+
+use async_trait::async_trait;
+use parking_lot::RwLock;
+use polars_core::frame::DataFrame;
+use polars_core::prelude::{InitHashMaps, PlHashMap};
+use polars_error::{PolarsResult, polars_err};
+use polars_plan::plans::DataFrameUdf;
+use polars_utils::pl_str::PlSmallStr;
+
+/// Creates a new instance of a [`StreamingOperator`] for every query it is used in.
+pub type StreamingOperatorFactory = Arc<dyn Fn() -> Box<dyn StreamingOperator> + Send + Sync>;
+
+static STREAMING_OPERATORS: LazyLock<RwLock<PlHashMap<PlSmallStr, StreamingOperatorFactory>>> =
+    LazyLock::new(|| RwLock::new(PlHashMap::new()));
+
+/// A custom operator of the streaming engine, registered with [`register_streaming_operator`].
+///
+/// The operator receives the morsels of its input one at a time in order, and its output keeps
+/// the order of the input. As the morsels are processed serially the operator may keep state,
+/// e.g. to remove rows it has seen before, and it may await external services.
+#[async_trait]
+pub trait StreamingOperator: Send + Sync {
+    /// Processes a morsel of the input, returning the rows to output for it.
+    async fn process(&mut self, df: DataFrame) -> PolarsResult<DataFrame>;
+
+    /// Called once after the whole input was processed, returning any rows still to output.
+    async fn finish(&mut self) -> PolarsResult<Option<DataFrame>> {
+        Ok(None)
+    }
+}
+
+/// Registers a [`StreamingOperator`] under `name`, replacing any operator registered under the
+/// same name.
+pub fn register_streaming_operator(name: &str, factory: StreamingOperatorFactory) {
+    STREAMING_OPERATORS.write().insert(name.into(), factory);
+}
+
+/// Removes the [`StreamingOperator`] registered under `name`, returning whether it existed.
+pub fn unregister_streaming_operator(name: &str) -> bool {
+    STREAMING_OPERATORS.write().remove(name).is_some()
+}
+
+pub(crate) fn new_streaming_operator(name: &str) -> PolarsResult<Box<dyn StreamingOperator>> {
+    let factory = STREAMING_OPERATORS.read().get(name).cloned().ok_or_else(
+        || polars_err!(ComputeError: "no streaming operator is registered under '{}'", name),
+    )?;
+    Ok(factory())
+}
+
+/// Runs a registered [`StreamingOperator`] as a function of a query.
+///
+/// The streaming engine runs the operator natively. Other engines run a new instance of it over
+/// the whole input at once.
+pub struct StreamingOperatorUdf {
+    name: PlSmallStr,
+}
+
+impl StreamingOperatorUdf {
+    pub fn new(name: &str) -> Self {
+        Self { name: name.into() }
+    }
+}
+
+impl DataFrameUdf for StreamingOperatorUdf {
+    fn call_udf(&self, df: DataFrame) -> PolarsResult<DataFrame> {
+        let mut operator = new_streaming_operator(&self.name)?;
+        // This may be called from a worker thread of the async runtime. Blocking in place hands
+        // the other tasks of the worker to another thread first, so they aren't starved while the
+        // operator runs.
+        polars_io::pl_async::get_runtime().block_in_place_on(async move {
+            let mut out = operator.process(df).await?;
+            if let Some(rest) = operator.finish().await? {
+                out.vstack_mut(&rest)?;
+            }
+            Ok(out)
+        })
+    }
+
+    fn streaming_operator(&self) -> Option<&str> {
+        Some(&self.name)
+    }
+}
@@ -0,0 +1,52 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use tokio::sync::Notify;
+
+/// Pauses and resumes a running streaming query.
+///
+/// While paused the morsels produced by the sources are held back, so the sources stop reading
+/// once their output is full, while the rest of the query finishes processing the morsels it
+/// already received. Pausing does not affect operations that are already running outside of the
+/// streaming engine.
+#[derive(Clone, Default)]
+pub struct PauseToken {
+    inner: Arc<PauseTokenInner>,
+}
+
+#[derive(Default)]
+struct PauseTokenInner {
+    paused: AtomicBool,
+    resumed: Notify,
+}
+
+impl PauseToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn pause(&self) {
+        self.inner.paused.store(true, Ordering::SeqCst);
+    }
+
+    pub fn resume(&self) {
+        self.inner.paused.store(false, Ordering::SeqCst);
+        self.inner.resumed.notify_waiters();
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.inner.paused.load(Ordering::SeqCst)
+    }
+
+    /// Waits until the query is no longer paused.
+    pub(crate) async fn wait_while_paused(&self) {
+        loop {
+            // Register for the notification before checking, so a resume in between isn't missed.
+            let resumed = self.inner.resumed.notified();
+            if !self.is_paused() {
+                return;
+            }
+            resumed.await;
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use std::fmt::Write;
+use std::time::Duration;
 
+use polars_core::prelude::{InitHashMaps, PlHashSet};
 use polars_ops::frame::JoinType;
 use polars_plan::dsl::FileScan;
 use polars_plan::plans::expr_ir::ExprIR;
@@ -7,9 +9,45 @@ use polars_plan::plans::{AExpr, EscapeLabel};
 use polars_plan::prelude::FileType;
 use polars_utils::arena::Arena;
 use polars_utils::itertools::Itertools;
+use polars_utils::slice_enum::Slice;
 use slotmap::{Key, SecondaryMap, SlotMap};
 
 use super::{PhysNode, PhysNodeKey, PhysNodeKind};
+use crate::metrics::NodeMetrics;
+
+/// The output format of [`visualize_physical_plan`](crate::visualize_physical_plan).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PhysicalPlanFormat {
+    /// A Graphviz DOT graph.
+    #[default]
+    Dot,
+    /// A Mermaid flowchart.
+    Mermaid,
+}
+
+/// A node of the visualized plan, with its label escaped for Graphviz.
+struct PlanNode {
+    key: PhysNodeKey,
+    id: u64,
+    label: String,
+    parallelism: &'static str,
+}
+
+/// A stream from an output port of a node to an input port of another node.
+struct PlanEdge {
+    from: u64,
+    send_port: usize,
+    to: u64,
+    recv_port: usize,
+    /// Whether the receiving node has more than one input.
+    multiple_inputs: bool,
+}
+
+#[derive(Default)]
+struct PlanGraph {
+    nodes: Vec<PlanNode>,
+    edges: Vec<PlanEdge>,
+}
 
 fn escape_graphviz(s: &str) -> String {
     s.replace('\\', "\\\\")
@@ -31,7 +69,7 @@ fn visualize_plan_rec(
     phys_sm: &SlotMap<PhysNodeKey, PhysNode>,
     expr_arena: &Arena<AExpr>,
     visited: &mut SecondaryMap<PhysNodeKey, ()>,
-    out: &mut Vec<String>,
+    graph: &mut PlanGraph,
 ) {
     if visited.contains_key(node_key) {
         return;
@@ -47,11 +85,17 @@ fn visualize_plan_rec(
             ),
             &[][..],
         ),
+        PhysNodeKind::InMemorySubplan { .. } => ("in-memory-subplan".to_string(), &[][..]),
+        PhysNodeKind::AnonymousScan { .. } => ("anonymous-scan".to_string(), &[][..]),
+        PhysNodeKind::FastCount { scan_type, .. } => {
+            let scan_type: &str = (&**scan_type).into();
+            (format!("fast-count\\n{scan_type}"), &[][..])
+        },
         #[cfg(feature = "python")]
         PhysNodeKind::PythonScan { .. } => ("python-scan".to_string(), &[][..]),
         PhysNodeKind::SinkMultiple { sinks } => {
             for sink in sinks {
-                visualize_plan_rec(*sink, phys_sm, expr_arena, visited, out);
+                visualize_plan_rec(*sink, phys_sm, expr_arena, visited, graph);
             }
             return;
         },
@@ -146,6 +190,19 @@ fn visualize_plan_rec(
             ("in-memory-map".to_string(), from_ref(input))
         },
         PhysNodeKind::Map { input, map: _ } => ("map".to_string(), from_ref(input)),
+        #[cfg(feature = "pivot")]
+        PhysNodeKind::Unpivot { input, index, on } => (
+            format!(
+                "unpivot\\nindex: {}\\non: {}",
+                index.join(", "),
+                on.join(", ")
+            ),
+            from_ref(input),
+        ),
+        PhysNodeKind::StreamingOperator { input, name } => (
+            format!("streaming-operator\\nname: {name}"),
+            from_ref(input),
+        ),
         PhysNodeKind::Sort {
             input,
             by_column,
@@ -155,6 +212,19 @@ fn visualize_plan_rec(
             format!("sort\\n{}", fmt_exprs(by_column, expr_arena)),
             from_ref(input),
         ),
+        PhysNodeKind::TopK {
+            input,
+            by,
+            sort_options: _,
+            offset,
+            len,
+        } => (
+            format!(
+                "top-k\\nby: {}\\noffset: {offset}, len: {len}",
+                by.join(", ")
+            ),
+            from_ref(input),
+        ),
         PhysNodeKind::OrderedUnion { inputs } => ("ordered-union".to_string(), inputs.as_slice()),
         PhysNodeKind::Zip {
             inputs,
@@ -168,7 +238,15 @@ fn visualize_plan_rec(
             (label.to_string(), inputs.as_slice())
         },
         PhysNodeKind::Multiplexer { input } => ("multiplexer".to_string(), from_ref(input)),
-        PhysNodeKind::MultiScan { hive_parts, .. } => {
+        PhysNodeKind::MultiScan {
+            hive_parts,
+            file_schema,
+            projection,
+            predicate,
+            row_index,
+            pre_slice,
+            ..
+        } => {
             let mut out = "multi-scan-source".to_string();
             let mut f = EscapeLabel(&mut out);
 
@@ -176,6 +254,34 @@ fn visualize_plan_rec(
                 write!(f, "\nhive: {} columns", v).unwrap();
             }
 
+            let total_columns = file_schema.len();
+            match projection {
+                Some(projection) => {
+                    write!(f, "\nprojection: {}/{total_columns}", projection.set_bits())
+                },
+                None => write!(f, "\nprojection: */{total_columns}"),
+            }
+            .unwrap();
+
+            if let Some(polars_io::RowIndex { name, offset }) = row_index {
+                write!(f, r#"\nrow index: name: "{}", offset: {}"#, name, offset).unwrap();
+            }
+
+            match pre_slice {
+                Some(Slice::Positive { offset, len }) => {
+                    write!(f, "\nslice: offset: {}, len: {}", offset, len).unwrap()
+                },
+                Some(Slice::Negative {
+                    offset_from_end,
+                    len,
+                }) => write!(f, "\nslice: offset: -{}, len: {}", offset_from_end, len).unwrap(),
+                None => {},
+            }
+
+            if let Some(predicate) = predicate.as_ref() {
+                write!(f, "\nfilter: {}", predicate.display(expr_arena)).unwrap();
+            }
+
             (out, &[][..])
         },
         PhysNodeKind::FileScan {
@@ -242,6 +348,35 @@ fn visualize_plan_rec(
             ),
             from_ref(input),
         ),
+        PhysNodeKind::Unique {
+            input,
+            key,
+            keep,
+            maintain_order,
+        } => (
+            format!(
+                "unique\\nkey: [{}]\\nkeep: {keep:?}\\nmaintain_order: {maintain_order}",
+                key.join(", ")
+            ),
+            from_ref(input),
+        ),
+        #[cfg(feature = "dynamic_group_by")]
+        PhysNodeKind::DynamicGroupBy {
+            input,
+            aggs,
+            options,
+        } => (
+            format!(
+                "{}\\naggs:\\n{}",
+                if options.rolling.is_some() {
+                    "rolling"
+                } else {
+                    "group-by-dynamic"
+                },
+                fmt_exprs(aggs, expr_arena)
+            ),
+            from_ref(input),
+        ),
         PhysNodeKind::InMemoryJoin {
             input_left,
             input_right,
@@ -256,6 +391,7 @@ fn visualize_plan_rec(
             left_on,
             right_on,
             args,
+            left_is_build: _,
         }
         | PhysNodeKind::SemiAntiJoin {
             input_left,
@@ -296,43 +432,254 @@ fn visualize_plan_rec(
             if args.nulls_equal {
                 write!(label, r"\njoin-nulls").unwrap();
             }
+            if let PhysNodeKind::EquiJoin {
+                left_is_build: Some(left_is_build),
+                ..
+            } = phys_sm[node_key].kind
+            {
+                let side = if left_is_build { "left" } else { "right" };
+                write!(label, r"\nbuild: {side}").unwrap();
+            }
             (label, &[*input_left, *input_right][..])
         },
         #[cfg(feature = "merge_sorted")]
-        PhysNodeKind::MergeSorted {
-            input_left,
-            input_right,
-            key,
-        } => (
-            format!("merge sorted on '{key}'"),
-            &[*input_left, *input_right][..],
-        ),
+        PhysNodeKind::MergeSorted { inputs, key } => {
+            (format!("merge sorted on '{key}'"), inputs.as_slice())
+        },
     };
 
-    out.push(format!(
-        "{} [label=\"{}\"];",
-        node_key.data().as_ffi(),
-        label
-    ));
-    for input in inputs {
-        visualize_plan_rec(input.node, phys_sm, expr_arena, visited, out);
+    let id = node_key.data().as_ffi();
+    graph.nodes.push(PlanNode {
+        key: node_key,
+        id,
+        label,
+        parallelism: parallelism(&phys_sm[node_key].kind),
+    });
+    for (recv_port, input) in inputs.iter().enumerate() {
+        visualize_plan_rec(input.node, phys_sm, expr_arena, visited, graph);
+        graph.edges.push(PlanEdge {
+            from: input.node.data().as_ffi(),
+            send_port: input.port,
+            to: id,
+            recv_port,
+            multiple_inputs: inputs.len() > 1,
+        });
+    }
+}
+
+/// How a node processes the morsels of its inputs.
+fn parallelism(kind: &PhysNodeKind) -> &'static str {
+    match kind {
+        PhysNodeKind::StreamingSlice { .. }
+        | PhysNodeKind::NegativeSlice { .. }
+        | PhysNodeKind::Zip { .. }
+        | PhysNodeKind::Multiplexer { .. }
+        | PhysNodeKind::StreamingOperator { .. } => "serial",
+        #[cfg(feature = "dynamic_group_by")]
+        PhysNodeKind::DynamicGroupBy { .. } => "serial",
+        #[cfg(feature = "merge_sorted")]
+        PhysNodeKind::MergeSorted { .. } => "serial",
+        // These nodes collect all of their input and run it on the in-memory engine.
+        PhysNodeKind::InMemoryMap { .. }
+        | PhysNodeKind::InMemorySubplan { .. }
+        | PhysNodeKind::Sort { .. }
+        | PhysNodeKind::InMemoryJoin { .. } => "in-memory",
+        _ => "parallel",
+    }
+}
+
+/// The label of an edge, naming the ports of nodes with more than one input or output.
+fn edge_label(edge: &PlanEdge) -> Option<String> {
+    match (edge.send_port, edge.multiple_inputs) {
+        (0, false) => None,
+        (0, true) => Some(format!("in {}", edge.recv_port)),
+        (send_port, false) => Some(format!("out {send_port}")),
+        (send_port, true) => Some(format!("out {send_port} -> in {}", edge.recv_port)),
+    }
+}
+
+fn render_dot(graph: &PlanGraph) -> String {
+    let mut out = Vec::with_capacity(graph.nodes.len() + graph.edges.len() + 2);
+    out.push("digraph polars {\nrankdir=\"BT\"".to_string());
+    for node in &graph.nodes {
+        let style = match node.parallelism {
+            "in-memory" => ", style=filled, fillcolor=\"lightgray\"",
+            "serial" => ", style=dashed",
+            _ => "",
+        };
+        out.push(format!(
+            "{} [label=\"{}\\nparallelism: {}\"{style}];",
+            node.id, node.label, node.parallelism
+        ));
+    }
+    for edge in &graph.edges {
+        match edge_label(edge) {
+            Some(label) => out.push(format!(
+                "{} -> {} [label=\"{}\"];",
+                edge.from,
+                edge.to,
+                escape_graphviz(&label)
+            )),
+            None => out.push(format!("{} -> {};", edge.from, edge.to)),
+        }
+    }
+    out.push("}".to_string());
+    out.join("\n")
+}
+
+/// Reverts [`escape_graphviz`].
+fn unescape_graphviz(label: &str) -> String {
+    let mut out = String::with_capacity(label.len());
+    let mut chars = label.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some('n') => out.push('\n'),
+                Some(c) => out.push(c),
+                None => {},
+            },
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Converts a label escaped for Graphviz to the text of a Mermaid node.
+fn mermaid_label(label: &str) -> String {
+    unescape_graphviz(label)
+        .replace('"', "#quot;")
+        .replace('<', "#lt;")
+        .replace('>', "#gt;")
+        .replace('\n', "<br/>")
+}
+
+fn render_mermaid(graph: &PlanGraph) -> String {
+    let mut out = Vec::with_capacity(graph.nodes.len() + graph.edges.len() + 3);
+    out.push("flowchart BT".to_string());
+    for node in &graph.nodes {
         out.push(format!(
-            "{} -> {};",
-            input.node.data().as_ffi(),
-            node_key.data().as_ffi()
+            "  n{}[\"{}<br/>parallelism: {}\"]",
+            node.id,
+            mermaid_label(&node.label),
+            node.parallelism
         ));
     }
+    for edge in &graph.edges {
+        match edge_label(edge) {
+            Some(label) => out.push(format!(
+                "  n{} -->|\"{}\"| n{}",
+                edge.from,
+                mermaid_label(&label),
+                edge.to
+            )),
+            None => out.push(format!("  n{} --> n{}", edge.from, edge.to)),
+        }
+    }
+    out.push("  classDef inMemory fill:#ddd".to_string());
+    for node in graph.nodes.iter().filter(|n| n.parallelism == "in-memory") {
+        out.push(format!("  class n{} inMemory", node.id));
+    }
+    out.join("\n")
 }
 
+/// Renders the physical plan as a graph of its nodes, with the ports of the streams between them
+/// and whether each node processes its input in parallel, serially or on the in-memory engine.
 pub fn visualize_plan(
     root: PhysNodeKey,
     phys_sm: &SlotMap<PhysNodeKey, PhysNode>,
     expr_arena: &Arena<AExpr>,
+    format: PhysicalPlanFormat,
 ) -> String {
     let mut visited: SecondaryMap<PhysNodeKey, ()> = SecondaryMap::new();
-    let mut out = Vec::with_capacity(phys_sm.len() + 2);
-    out.push("digraph polars {\nrankdir=\"BT\"".to_string());
-    visualize_plan_rec(root, phys_sm, expr_arena, &mut visited, &mut out);
-    out.push("}".to_string());
-    out.join("\n")
+    let mut graph = PlanGraph::default();
+    visualize_plan_rec(root, phys_sm, expr_arena, &mut visited, &mut graph);
+    match format {
+        PhysicalPlanFormat::Dot => render_dot(&graph),
+        PhysicalPlanFormat::Mermaid => render_mermaid(&graph),
+    }
+}
+
+/// Renders the physical plan as a tree, annotating every node with its runtime metrics.
+pub fn explain_analyzed_plan(
+    root: PhysNodeKey,
+    phys_sm: &SlotMap<PhysNodeKey, PhysNode>,
+    expr_arena: &Arena<AExpr>,
+    metrics: &SecondaryMap<PhysNodeKey, NodeMetrics>,
+    wall_time: Duration,
+) -> String {
+    let mut visited: SecondaryMap<PhysNodeKey, ()> = SecondaryMap::new();
+    let mut graph = PlanGraph::default();
+    visualize_plan_rec(root, phys_sm, expr_arena, &mut visited, &mut graph);
+
+    let mut out = format!("total wall time: {wall_time:?}\n");
+    let mut explain = ExplainAnalyze {
+        graph: &graph,
+        metrics,
+        printed: PlHashSet::new(),
+        out: &mut out,
+    };
+    // The roots are the nodes that aren't the input of another node, e.g. multiple sinks.
+    let inputs = graph.edges.iter().map(|e| e.from).collect::<PlHashSet<_>>();
+    for node in graph.nodes.iter().filter(|n| !inputs.contains(&n.id)) {
+        explain.node(node.id, "", "");
+    }
+    out
+}
+
+struct ExplainAnalyze<'a> {
+    graph: &'a PlanGraph,
+    metrics: &'a SecondaryMap<PhysNodeKey, NodeMetrics>,
+    printed: PlHashSet<u64>,
+    out: &'a mut String,
+}
+
+impl ExplainAnalyze<'_> {
+    fn node(&mut self, id: u64, first_prefix: &str, prefix: &str) {
+        let node = self.graph.nodes.iter().find(|n| n.id == id).unwrap();
+        let label = unescape_graphviz(&node.label);
+        let mut lines = label.lines();
+        let name = lines.next().unwrap_or_default();
+        if !self.printed.insert(id) {
+            writeln!(self.out, "{first_prefix}{name} (shown above)").unwrap();
+            return;
+        }
+        writeln!(self.out, "{first_prefix}{name}").unwrap();
+
+        let inputs = self
+            .graph
+            .edges
+            .iter()
+            .filter(|e| e.to == id)
+            .collect::<Vec<_>>();
+        let detail_prefix = if inputs.is_empty() {
+            format!("{prefix}  ")
+        } else {
+            format!("{prefix}│ ")
+        };
+        for line in lines {
+            writeln!(self.out, "{detail_prefix}{line}").unwrap();
+        }
+        if let Some(m) = self.metrics.get(node.key) {
+            writeln!(
+                self.out,
+                "{detail_prefix}rows in: {}, rows out: {}, wall time: {:?}, cpu time: {:?}, \
+                peak memory: {} bytes, spilled: {} bytes",
+                m.rows_in, m.rows_out, m.wall_time, m.cpu_time, m.peak_memory, m.spilled_bytes
+            )
+            .unwrap();
+        }
+
+        for (i, input) in inputs.iter().enumerate() {
+            let (first, rest) = if i + 1 == inputs.len() {
+                ("└─ ", "   ")
+            } else {
+                ("├─ ", "│  ")
+            };
+            self.node(
+                input.from,
+                &format!("{prefix}{first}"),
+                &format!("{prefix}{rest}"),
+            );
+        }
+    }
 }
@@ -346,6 +346,26 @@ fn is_length_preserving_ctx(expr_key: ExprNodeKey, ctx: &mut LowerExprContext) -
     )
 }
 
+/// Whether `function` reduces its first input to a single value with a mergeable state, so it
+/// can be computed by a reduce node.
+#[cfg_attr(not(feature = "approx_quantile"), allow(unused_variables))]
+pub(crate) fn is_function_reduction(
+    function: &FunctionExpr,
+    input: &[ExprIR],
+    arena: &Arena<AExpr>,
+) -> bool {
+    match function {
+        #[cfg(feature = "approx_unique")]
+        FunctionExpr::ApproxNUnique
+        | FunctionExpr::ApproxNUniqueSketch(ApproxNUniqueSketchFunction::Build) => true,
+        #[cfg(feature = "approx_quantile")]
+        FunctionExpr::ApproxQuantile => {
+            matches!(arena.get(input[1].node()), AExpr::Literal(lit) if lit.is_scalar())
+        },
+        _ => false,
+    }
+}
+
 fn build_fallback_node_with_ctx(
     input: PhysStream,
     exprs: &[ExprIR],
@@ -833,6 +853,12 @@ fn lower_exprs_with_ctx(
                     fallback_subset.push(ExprIR::new(expr, OutputName::Alias(out_name.clone())));
                     transformed_exprs.push(ctx.expr_arena.add(AExpr::Column(out_name)));
                 },
+                #[cfg(feature = "bitwise")]
+                IRAggExpr::Bitwise(..) => {
+                    let out_name = unique_column_name();
+                    fallback_subset.push(ExprIR::new(expr, OutputName::Alias(out_name.clone())));
+                    transformed_exprs.push(ctx.expr_arena.add(AExpr::Column(out_name)));
+                },
             },
             AExpr::Len => {
                 let out_name = unique_column_name();
@@ -846,6 +872,32 @@ fn lower_exprs_with_ctx(
                 input_streams.insert(PhysStream::first(reduce_node_key));
                 transformed_exprs.push(ctx.expr_arena.add(AExpr::Column(out_name)));
             },
+            AExpr::Function {
+                input: mut inner_exprs,
+                function,
+                options,
+            } if is_function_reduction(&function, &inner_exprs, ctx.expr_arena) => {
+                let (trans_input, trans_exprs) =
+                    lower_exprs_with_ctx(input, &[inner_exprs[0].node()], ctx)?;
+                inner_exprs[0] =
+                    ExprIR::new(trans_exprs[0], inner_exprs[0].output_name_inner().clone());
+
+                let out_name = unique_column_name();
+                let trans_expr = ctx.expr_arena.add(AExpr::Function {
+                    input: inner_exprs,
+                    function,
+                    options,
+                });
+                let expr_ir = ExprIR::new(trans_expr, OutputName::Alias(out_name.clone()));
+                let output_schema = schema_for_select(trans_input, &[expr_ir.clone()], ctx)?;
+                let kind = PhysNodeKind::Reduce {
+                    input: trans_input,
+                    exprs: vec![expr_ir],
+                };
+                let reduce_node_key = ctx.phys_sm.insert(PhysNode::new(output_schema, kind));
+                input_streams.insert(PhysStream::first(reduce_node_key));
+                transformed_exprs.push(ctx.expr_arena.add(AExpr::Column(out_name)));
+            },
             AExpr::AnonymousFunction { .. }
             | AExpr::Function { .. }
             | AExpr::Slice { .. }
@@ -8,6 +8,8 @@ use polars_expr::state::ExecutionState;
 use polars_mem_engine::create_physical_plan;
 use polars_plan::plans::expr_ir::{ExprIR, OutputName};
 use polars_plan::plans::{AExpr, ArenaExprIter, DataFrameUdf, IR, IRAggExpr};
+#[cfg(feature = "approx_quantile")]
+use polars_plan::prelude::FunctionExpr;
 use polars_plan::prelude::GroupbyOptions;
 use polars_utils::arena::{Arena, Node};
 use polars_utils::itertools::Itertools;
@@ -18,6 +20,8 @@ use slotmap::SlotMap;
 
 use super::lower_expr::lower_exprs;
 use super::{ExprCache, PhysNode, PhysNodeKey, PhysNodeKind, PhysStream};
+#[cfg(feature = "approx_quantile")]
+use crate::physical_plan::lower_expr::is_function_reduction;
 use crate::physical_plan::lower_expr::{
     build_select_stream, compute_output_schema, is_fake_elementwise_function, is_input_independent,
 };
@@ -180,6 +184,37 @@ fn try_lower_elementwise_scalar_agg_expr(
             Some(expr_arena.add(new_node))
         },
 
+        // Unlike the t-digests of approximate quantiles, the HyperLogLog sketches of approximate
+        // unique counts are too large to keep one per group.
+        #[cfg(feature = "approx_quantile")]
+        AExpr::Function {
+            input,
+            function: function @ FunctionExpr::ApproxQuantile,
+            options,
+        } if is_function_reduction(function, input, expr_arena) => {
+            // Nested aggregates not supported.
+            if inside_agg {
+                return None;
+            }
+            let (mut input, function, options) = (input.clone(), function.clone(), *options);
+            let trans_input = lower_rec!(input[0].node(), true)?;
+            input[0] = ExprIR::new(trans_input, input[0].output_name_inner().clone());
+            let trans_node = expr_arena.add(AExpr::Function {
+                input,
+                function,
+                options,
+            });
+
+            let agg_expr = if let Some(name) = outer_name {
+                ExprIR::new(trans_node, OutputName::Alias(name))
+            } else {
+                ExprIR::new(trans_node, OutputName::Alias(unique_column_name()))
+            };
+            let result_node = expr_arena.add(AExpr::Column(agg_expr.output_name().clone()));
+            agg_exprs.push(agg_expr);
+            Some(result_node)
+        },
+
         AExpr::Function { .. } | AExpr::AnonymousFunction { .. } => None,
 
         AExpr::Cast {
@@ -234,6 +269,8 @@ fn try_lower_elementwise_scalar_agg_expr(
                 | IRAggExpr::Implode(..)
                 | IRAggExpr::Quantile { .. }
                 | IRAggExpr::AggGroups(..) => None, // TODO: allow all aggregates,
+                #[cfg(feature = "bitwise")]
+                IRAggExpr::Bitwise(..) => None,
             }
         },
         AExpr::Len => {
@@ -365,6 +402,46 @@ fn try_build_streaming_group_by(
     Some(out)
 }
 
+/// Tries to build a streaming `group_by_dynamic` / `rolling`.
+///
+/// These are only supported without additional group keys, as the in-memory engine orders the
+/// output by those keys first.
+#[cfg(feature = "dynamic_group_by")]
+fn try_build_dynamic_group_by(
+    input: PhysStream,
+    keys: &[ExprIR],
+    aggs: &[ExprIR],
+    output_schema: Arc<Schema>,
+    options: &Arc<GroupbyOptions>,
+    apply: Option<&Arc<dyn DataFrameUdf>>,
+    phys_sm: &mut SlotMap<PhysNodeKey, PhysNode>,
+) -> Option<PhysStream> {
+    use crate::nodes::dynamic_group_by::WindowKind;
+
+    if apply.is_some() || !keys.is_empty() {
+        return None;
+    }
+    let input_schema = &phys_sm[input.node].output_schema;
+    WindowKind::try_new(options, input_schema)?;
+
+    // The slice is applied on the streamed output instead.
+    let mut group_by_options = options.as_ref().clone();
+    group_by_options.slice = None;
+    let node = phys_sm.insert(PhysNode::new(
+        output_schema,
+        PhysNodeKind::DynamicGroupBy {
+            input,
+            aggs: aggs.to_vec(),
+            options: Arc::new(group_by_options),
+        },
+    ));
+    let stream = PhysStream::first(node);
+    Some(match options.slice {
+        Some((offset, len)) => build_slice_stream(stream, offset, len, phys_sm),
+        None => stream,
+    })
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn build_group_by_stream(
     input: PhysStream,
@@ -378,6 +455,21 @@ pub fn build_group_by_stream(
     phys_sm: &mut SlotMap<PhysNodeKey, PhysNode>,
     expr_cache: &mut ExprCache,
 ) -> PolarsResult<PhysStream> {
+    #[cfg(feature = "dynamic_group_by")]
+    if options.dynamic.is_some() || options.rolling.is_some() {
+        if let Some(stream) = try_build_dynamic_group_by(
+            input,
+            keys,
+            aggs,
+            output_schema.clone(),
+            &options,
+            apply.as_ref(),
+            phys_sm,
+        ) {
+            return Ok(stream);
+        }
+    }
+
     let streaming = try_build_streaming_group_by(
         input,
         keys,
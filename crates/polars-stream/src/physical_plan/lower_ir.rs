@@ -1,28 +1,25 @@
 use std::sync::Arc;
 
-use parking_lot::Mutex;
-use polars_core::frame::{DataFrame, UniqueKeepStrategy};
-use polars_core::prelude::{DataType, InitHashMaps, PlHashMap, PlHashSet, PlIndexMap};
+use polars_core::frame::DataFrame;
+use polars_core::prelude::{InitHashMaps, PlHashMap, PlHashSet, PlIndexMap};
 use polars_core::schema::{Schema, SchemaExt};
 use polars_core::utils::arrow::bitmap::MutableBitmap;
 use polars_error::{PolarsResult, polars_bail};
-use polars_expr::state::ExecutionState;
 use polars_io::RowIndex;
-use polars_mem_engine::create_physical_plan;
 use polars_plan::dsl::{
-    FileScan, FileSinkType, PartitionSinkTypeIR, PartitionVariantIR, ScanFlags, ScanSource,
-    SinkTypeIR,
+    FileScan, FileSinkType, JoinStrategy, PartitionSinkTypeIR, PartitionVariantIR, QueryHint,
+    ScanFlags, ScanSource, SinkTypeIR,
 };
 use polars_plan::plans::expr_ir::{ExprIR, OutputName};
-use polars_plan::plans::{AExpr, Context, FunctionIR, IR, IRAggExpr, LiteralValue};
-use polars_plan::prelude::GroupbyOptions;
+use polars_plan::plans::{AExpr, Context, FunctionIR, IR, sub_plan_hints};
+use polars_utils::IdxSize;
 use polars_utils::arena::{Arena, Node};
 use polars_utils::itertools::Itertools;
 use polars_utils::slice_enum::Slice;
-use polars_utils::{IdxSize, unique_column_name};
 use slotmap::SlotMap;
 
 use super::{PhysNode, PhysNodeKey, PhysNodeKind, PhysStream};
+use crate::nodes::io_sources::count::CountSourceNode;
 use crate::nodes::io_sources::multi_file_reader::reader_interface::builder::FileReaderBuilder;
 use crate::nodes::io_sources::multi_scan::MultiscanRowRestriction;
 use crate::nodes::io_sources::{RowRestriction, multi_file_reader};
@@ -31,7 +28,6 @@ use crate::physical_plan::lower_expr::{
     is_elementwise_rec_cached, lower_exprs,
 };
 use crate::physical_plan::lower_group_by::build_group_by_stream;
-use crate::utils::late_materialized_df::LateMaterializedDataFrame;
 
 /// Creates a new PhysStream which outputs a slice of the input stream.
 pub fn build_slice_stream(
@@ -337,17 +333,96 @@ pub fn lower_ir(
             input_right,
             key,
         } => {
-            let input_left = *input_left;
-            let input_right = *input_right;
             let key = key.clone();
 
-            let phys_left = lower_ir!(input_left)?;
-            let phys_right = lower_ir!(input_right)?;
+            // Nested merges on the same key are flattened into a single k-way merge.
+            let mut leaves = Vec::new();
+            let mut stack = vec![*input_right, *input_left];
+            while let Some(input) = stack.pop() {
+                match ir_arena.get(input) {
+                    IR::MergeSorted {
+                        input_left,
+                        input_right,
+                        key: input_key,
+                    } if *input_key == key => {
+                        stack.push(*input_right);
+                        stack.push(*input_left);
+                    },
+                    _ => leaves.push(input),
+                }
+            }
 
-            PhysNodeKind::MergeSorted {
-                input_left: phys_left,
-                input_right: phys_right,
-                key,
+            let inputs = leaves
+                .into_iter()
+                .map(|input| lower_ir!(input))
+                .collect::<Result<_, _>>()?;
+            PhysNodeKind::MergeSorted { inputs, key }
+        },
+
+        // The input of a fast count is a placeholder, the rows are counted from the sources.
+        IR::MapFunction {
+            input: _,
+            function:
+                FunctionIR::FastCount {
+                    sources,
+                    scan_type,
+                    alias,
+                },
+        } if CountSourceNode::is_supported(scan_type) => PhysNodeKind::FastCount {
+            sources: sources.clone(),
+            scan_type: scan_type.clone(),
+            alias: alias.clone(),
+        },
+
+        IR::MapFunction {
+            input,
+            function: FunctionIR::Opaque { function, .. },
+        } if function.streaming_operator().is_some() => {
+            let name = function.streaming_operator().unwrap().into();
+            let phys_input = lower_ir!(*input)?;
+            PhysNodeKind::StreamingOperator {
+                input: phys_input,
+                name,
+            }
+        },
+
+        IR::MapFunction {
+            input,
+            function: FunctionIR::Hint(hint),
+        } => {
+            let input = *input;
+            if *hint != QueryHint::NoStreaming {
+                // The other hints are read by the nodes that consume the sub-plan.
+                return lower_ir!(input);
+            }
+            PhysNodeKind::InMemorySubplan {
+                ir_arena: Arc::new(ir_arena.clone()),
+                ir_node: input,
+            }
+        },
+
+        #[cfg(feature = "pivot")]
+        IR::MapFunction {
+            input,
+            function: FunctionIR::Unpivot { args, .. },
+        } => {
+            let args = args.clone();
+            let phys_input = lower_ir!(*input)?;
+            // An empty `on` means all columns that aren't in `index`.
+            let on = if args.on.is_empty() {
+                let input_schema = &phys_sm[phys_input.node].output_schema;
+                input_schema
+                    .iter_names()
+                    .filter(|name| !args.index.contains(name))
+                    .cloned()
+                    .collect()
+            } else {
+                args.on.clone()
+            };
+            PhysNodeKind::Unpivot {
+                input: phys_input,
+                index: args.index.clone(),
+                on,
             }
         },
 
@@ -389,11 +464,33 @@ pub fn lower_ir(
             by_column,
             slice,
             sort_options,
-        } => PhysNodeKind::Sort {
-            by_column: by_column.clone(),
-            slice: *slice,
-            sort_options: sort_options.clone(),
-            input: lower_ir!(*input)?,
+        } => {
+            // A sort by plain columns followed by a non-negative slice doesn't need to sort all
+            // of its input.
+            let by = by_column
+                .iter()
+                .map(|e| match expr_arena.get(e.node()) {
+                    AExpr::Column(name) if name == e.output_name() => Some(name.clone()),
+                    _ => None,
+                })
+                .collect::<Option<Vec<_>>>();
+            match (slice, by) {
+                (Some((offset, len)), Some(by)) if *offset >= 0 && !sort_options.maintain_order => {
+                    PhysNodeKind::TopK {
+                        by,
+                        sort_options: sort_options.clone(),
+                        offset: *offset as usize,
+                        len: *len,
+                        input: lower_ir!(*input)?,
+                    }
+                },
+                _ => PhysNodeKind::Sort {
+                    by_column: by_column.clone(),
+                    slice: *slice,
+                    sort_options: sort_options.clone(),
+                    input: lower_ir!(*input)?,
+                },
+            }
         },
 
         IR::Union { inputs, options } => {
@@ -431,6 +528,46 @@ pub fn lower_ir(
             }
         },
 
+        IR::Scan {
+            scan_type,
+            file_info,
+            output_schema: scan_output_schema,
+            predicate,
+            file_options,
+            ..
+        } if matches!(&**scan_type, FileScan::Anonymous { .. }) => {
+            let FileScan::Anonymous { function, .. } = &**scan_type else {
+                unreachable!()
+            };
+            if !function.is_batched() {
+                PhysNodeKind::InMemorySubplan {
+                    ir_arena: Arc::new(ir_arena.clone()),
+                    ir_node: node,
+                }
+            } else {
+                let predicate = predicate.clone();
+                let pre_slice = file_options.pre_slice;
+                let kind = PhysNodeKind::AnonymousScan {
+                    function: function.clone(),
+                    schema: file_info.schema.clone(),
+                    output_schema: scan_output_schema.clone(),
+                    with_columns: file_options.with_columns.clone(),
+                };
+                let node_key = phys_sm.insert(PhysNode::new(output_schema, kind));
+                let mut stream = PhysStream::first(node_key);
+
+                // The batches are produced without pushing down the predicate or slice.
+                if let Some(predicate) = predicate {
+                    stream =
+                        build_filter_stream(stream, predicate, expr_arena, phys_sm, expr_cache)?;
+                }
+                if let Some((offset, length)) = pre_slice {
+                    stream = build_slice_stream(stream, offset, length, phys_sm);
+                }
+                return Ok(stream);
+            }
+        },
+
         v @ IR::Scan { .. } => {
             let IR::Scan {
                 sources: scan_sources,
@@ -444,6 +581,8 @@ pub fn lower_ir(
             else {
                 unreachable!();
             };
+            let row_estimate = (predicate.is_none() && file_options.pre_slice.is_none())
+                .then_some(file_info.row_estimation.1);
 
             if scan_sources.is_empty() || file_options.pre_slice.is_some_and(|(_, len)| len == 0) {
                 // If there are no sources, just provide an empty in-memory source with the right
@@ -504,6 +643,8 @@ pub fn lower_ir(
                     output_schema: output_schema.clone(),
                     allow_missing_columns: file_options.allow_missing_columns,
                     include_file_paths: file_options.include_file_paths,
+                    #[cfg(feature = "cast_policy")]
+                    cast_policy: file_options.cast_policy,
                     // TODO: Remove
                     row_restriction: None,
                     predicate: None,
@@ -515,6 +656,7 @@ pub fn lower_ir(
                     projected_file_schema,
                     cloud_options,
                     pre_slice: None,
+                    row_estimate,
                 };
 
                 let PhysNodeKind::MultiScan {
@@ -877,6 +1019,8 @@ pub fn lower_ir(
                     output_schema: output_schema.clone(),
                     allow_missing_columns: file_options.allow_missing_columns,
                     include_file_paths: file_options.include_file_paths,
+                    #[cfg(feature = "cast_policy")]
+                    cast_policy: file_options.cast_policy,
                     row_restriction,
                     predicate: predicate.clone(),
                     projection,
@@ -886,6 +1030,7 @@ pub fn lower_ir(
                     cloud_options: None,
                     projected_file_schema: Default::default(),
                     pre_slice: None,
+                    row_estimate,
                 };
 
                 let proj_schema = Arc::new(schema.try_project(output_schema.iter_names_cloned())?);
@@ -982,6 +1127,19 @@ pub fn lower_ir(
             let right_on = right_on.clone();
             let args = options.args.clone();
             let options = options.options.clone();
+            let join_strategy = |node| {
+                sub_plan_hints(node, ir_arena)
+                    .into_iter()
+                    .find_map(|hint| match hint {
+                        QueryHint::Join(strategy) => Some(strategy),
+                        QueryHint::NoStreaming => None,
+                    })
+            };
+            let left_is_build = match (join_strategy(input_left), join_strategy(input_right)) {
+                (Some(JoinStrategy::Broadcast), _) | (_, Some(JoinStrategy::Stream)) => Some(true),
+                (_, Some(JoinStrategy::Broadcast)) | (Some(JoinStrategy::Stream), _) => Some(false),
+                (None, None) => None,
+            };
             let phys_left = lower_ir!(input_left)?;
             let phys_right = lower_ir!(input_right)?;
             let supported_join_type = args.how.is_equi() || args.how.is_semi_anti();
@@ -1016,6 +1174,7 @@ pub fn lower_ir(
                             left_on: trans_left_on,
                             right_on: trans_right_on,
                             args: args.clone(),
+                            left_is_build,
                         },
                     ))
                 } else {
@@ -1052,138 +1211,31 @@ pub fn lower_ir(
             let options = options.clone();
             let phys_input = lower_ir!(*input)?;
 
-            // We don't have a dedicated distinct operator (yet), lower to group
-            // by with an aggregate for each column.
             let input_schema = &phys_sm[phys_input.node].output_schema;
             if input_schema.is_empty() {
-                // Can't group (or have duplicates) if dataframe has zero-width.
+                // Can't have duplicates if dataframe has zero-width.
                 return Ok(phys_input);
             }
 
-            if options.maintain_order && options.keep_strategy == UniqueKeepStrategy::Last {
-                // Unfortunately the order-preserving groupby always orders by the first occurrence
-                // of the group so we can't lower this and have to fallback.
-                let input_schema = phys_sm[phys_input.node].output_schema.clone();
-                let lmdf = Arc::new(LateMaterializedDataFrame::default());
-                let mut lp_arena = Arena::default();
-                let input_lp_node = lp_arena.add(lmdf.clone().as_ir_node(input_schema.clone()));
-                let distinct_lp_node = lp_arena.add(IR::Distinct {
-                    input: input_lp_node,
-                    options,
-                });
-                let executor = Mutex::new(create_physical_plan(
-                    distinct_lp_node,
-                    &mut lp_arena,
-                    expr_arena,
-                )?);
-
-                let distinct_node = PhysNode {
-                    output_schema,
-                    kind: PhysNodeKind::InMemoryMap {
-                        input: phys_input,
-                        map: Arc::new(move |df| {
-                            lmdf.set_materialized_dataframe(df);
-                            let mut state = ExecutionState::new();
-                            executor.lock().execute(&mut state)
-                        }),
-                    },
-                };
-
-                return Ok(PhysStream::first(phys_sm.insert(distinct_node)));
-            }
-
-            // Create the key and aggregate expressions.
-            let all_col_names = input_schema.iter_names().cloned().collect_vec();
-            let key_names = if let Some(subset) = options.subset {
+            let key = if let Some(subset) = options.subset {
                 subset.to_vec()
             } else {
-                all_col_names.clone()
+                input_schema.iter_names().cloned().collect_vec()
             };
-            let key_name_set: PlHashSet<_> = key_names.iter().cloned().collect();
-
-            let mut group_by_output_schema = Schema::with_capacity(all_col_names.len() + 1);
-            let keys = key_names
-                .iter()
-                .map(|name| {
-                    group_by_output_schema
-                        .insert(name.clone(), input_schema.get(name).unwrap().clone());
-                    let col_expr = expr_arena.add(AExpr::Column(name.clone()));
-                    ExprIR::new(col_expr, OutputName::ColumnLhs(name.clone()))
-                })
-                .collect_vec();
-
-            let mut aggs = all_col_names
-                .iter()
-                .filter(|name| !key_name_set.contains(*name))
-                .map(|name| {
-                    group_by_output_schema
-                        .insert(name.clone(), input_schema.get(name).unwrap().clone());
-                    let col_expr = expr_arena.add(AExpr::Column(name.clone()));
-                    use UniqueKeepStrategy::*;
-                    let agg_expr = match options.keep_strategy {
-                        First | None | Any => {
-                            expr_arena.add(AExpr::Agg(IRAggExpr::First(col_expr)))
-                        },
-                        Last => expr_arena.add(AExpr::Agg(IRAggExpr::Last(col_expr))),
-                    };
-                    ExprIR::new(agg_expr, OutputName::ColumnLhs(name.clone()))
-                })
-                .collect_vec();
-
-            if options.keep_strategy == UniqueKeepStrategy::None {
-                // Track the length so we can filter out non-unique keys later.
-                let name = unique_column_name();
-                group_by_output_schema.insert(name.clone(), DataType::new_idxsize());
-                aggs.push(ExprIR::new(
-                    expr_arena.add(AExpr::Len),
-                    OutputName::Alias(name),
-                ));
-            }
-
-            let mut stream = build_group_by_stream(
-                phys_input,
-                &keys,
-                &aggs,
-                Arc::new(group_by_output_schema),
-                options.maintain_order,
-                Arc::new(GroupbyOptions::default()),
-                None,
-                expr_arena,
-                phys_sm,
-                expr_cache,
-            )?;
-
-            if options.keep_strategy == UniqueKeepStrategy::None {
-                // Filter to keep only those groups with length 1.
-                let unique_name = aggs.last().unwrap().output_name();
-                let left = expr_arena.add(AExpr::Column(unique_name.clone()));
-                let right = expr_arena.add(AExpr::Literal(LiteralValue::new_idxsize(1)));
-                let predicate_aexpr = expr_arena.add(AExpr::BinaryExpr {
-                    left,
-                    op: polars_plan::dsl::Operator::Eq,
-                    right,
-                });
-                let predicate =
-                    ExprIR::new(predicate_aexpr, OutputName::ColumnLhs(unique_name.clone()));
-                stream = build_filter_stream(stream, predicate, expr_arena, phys_sm, expr_cache)?;
-            }
-
-            // Restore column order and drop the temporary length column if any.
-            let exprs = all_col_names
-                .iter()
-                .map(|name| {
-                    let col_expr = expr_arena.add(AExpr::Column(name.clone()));
-                    ExprIR::new(col_expr, OutputName::ColumnLhs(name.clone()))
-                })
-                .collect_vec();
-            stream = build_select_stream(stream, &exprs, expr_arena, phys_sm, expr_cache)?;
+            let node = phys_sm.insert(PhysNode::new(
+                output_schema,
+                PhysNodeKind::Unique {
+                    input: phys_input,
+                    key,
+                    keep: options.keep_strategy,
+                    maintain_order: options.maintain_order,
+                },
+            ));
 
-            // We didn't pass the slice earlier to build_group_by_stream because
-            // we might have the intermediate keep = "none" filter.
+            let mut stream = PhysStream::first(node);
             if let Some((offset, length)) = options.slice {
                 stream = build_slice_stream(stream, offset, length, phys_sm);
             }
-
             return Ok(stream);
         },
         IR::ExtContext { .. } => todo!(),
@@ -2,19 +2,23 @@ use std::path::PathBuf;
 use std::sync::Arc;
 
 use polars_core::frame::DataFrame;
-use polars_core::prelude::{IdxSize, InitHashMaps, PlHashMap, SortMultipleOptions};
+use polars_core::prelude::{
+    IdxSize, InitHashMaps, PlHashMap, SortMultipleOptions, UniqueKeepStrategy,
+};
 use polars_core::schema::{Schema, SchemaRef};
 use polars_core::utils::arrow::bitmap::Bitmap;
 use polars_error::PolarsResult;
 use polars_io::RowIndex;
 use polars_io::cloud::CloudOptions;
 use polars_ops::frame::JoinArgs;
+#[cfg(feature = "cast_policy")]
+use polars_ops::frame::cast_policy::CastPolicy;
 use polars_plan::dsl::{
     FileScan, JoinTypeOptionsIR, PartitionTargetCallback, PartitionVariantIR, ScanSource,
     ScanSources, SinkOptions, SinkTarget,
 };
 use polars_plan::plans::hive::HivePartitionsDf;
-use polars_plan::plans::{AExpr, DataFrameUdf, FileInfo, IR};
+use polars_plan::plans::{AExpr, AnonymousScan, DataFrameUdf, FileInfo, IR};
 use polars_plan::prelude::expr_ir::ExprIR;
 
 mod fmt;
@@ -23,8 +27,8 @@ mod lower_group_by;
 mod lower_ir;
 mod to_graph;
 
-pub use fmt::visualize_plan;
-use polars_plan::prelude::{FileScanOptions, FileType};
+pub use fmt::{PhysicalPlanFormat, explain_analyzed_plan, visualize_plan};
+use polars_plan::prelude::{FileScanOptions, FileType, GroupbyOptions};
 use polars_utils::arena::{Arena, Node};
 use polars_utils::pl_str::PlSmallStr;
 use polars_utils::slice_enum::Slice;
@@ -90,6 +94,20 @@ pub enum PhysNodeKind {
         df: Arc<DataFrame>,
     },
 
+    /// Runs a sub-plan of the IR on the in-memory engine, e.g. because it has the `NoStreaming`
+    /// hint.
+    InMemorySubplan {
+        ir_arena: Arc<Arena<IR>>,
+        ir_node: Node,
+    },
+
+    /// Counts the rows of the sources of a scan without reading their values.
+    FastCount {
+        sources: ScanSources,
+        scan_type: Box<FileScan>,
+        alias: Option<PlSmallStr>,
+    },
+
     Select {
         input: PhysStream,
         selectors: Vec<ExprIR>,
@@ -172,6 +190,20 @@ pub enum PhysNodeKind {
         map: Arc<dyn DataFrameUdf>,
     },
 
+    /// Unpivots the `on` columns of every morsel, keeping the `index` columns.
+    #[cfg(feature = "pivot")]
+    Unpivot {
+        input: PhysStream,
+        index: Vec<PlSmallStr>,
+        on: Vec<PlSmallStr>,
+    },
+
+    /// Runs the user-defined streaming operator registered under `name`.
+    StreamingOperator {
+        input: PhysStream,
+        name: PlSmallStr,
+    },
+
     Sort {
         input: PhysStream,
         by_column: Vec<ExprIR>,
@@ -179,6 +211,16 @@ pub enum PhysNodeKind {
         sort_options: SortMultipleOptions,
     },
 
+    /// A sort of plain columns followed by a slice, which only keeps the rows that can end up
+    /// in the slice.
+    TopK {
+        input: PhysStream,
+        by: Vec<PlSmallStr>,
+        sort_options: SortMultipleOptions,
+        offset: usize,
+        len: usize,
+    },
+
     OrderedUnion {
         inputs: Vec<PhysStream>,
     },
@@ -202,6 +244,10 @@ pub enum PhysNodeKind {
         scan_type: Box<FileScan>,
         allow_missing_columns: bool,
         include_file_paths: Option<PlSmallStr>,
+        /// Conform every file to `file_schema` with this policy, instead of requiring the files to
+        /// match it.
+        #[cfg(feature = "cast_policy")]
+        cast_policy: Option<CastPolicy>,
 
         /// Schema that all files are coerced into.
         ///
@@ -232,6 +278,9 @@ pub enum PhysNodeKind {
         projected_file_schema: SchemaRef,
         cloud_options: Option<Arc<CloudOptions>>,
         pre_slice: Option<Slice>,
+
+        /// The estimated number of output rows, if the scan has no predicate or slice.
+        row_estimate: Option<usize>,
     },
     FileScan {
         scan_source: ScanSource,
@@ -247,18 +296,45 @@ pub enum PhysNodeKind {
         options: polars_plan::plans::python::PythonOptions,
     },
 
+    /// Reads the batches of an [`AnonymousScan`] that [`AnonymousScan::is_batched`].
+    AnonymousScan {
+        function: Arc<dyn AnonymousScan>,
+        schema: SchemaRef,
+        output_schema: Option<SchemaRef>,
+        with_columns: Option<Arc<[PlSmallStr]>>,
+    },
+
     GroupBy {
         input: PhysStream,
         key: Vec<ExprIR>,
         aggs: Vec<ExprIR>,
     },
 
+    /// Keeps the unique rows of the input with respect to the `key` columns.
+    Unique {
+        input: PhysStream,
+        key: Vec<PlSmallStr>,
+        keep: UniqueKeepStrategy,
+        maintain_order: bool,
+    },
+
+    /// A `group_by_dynamic` or `rolling` without additional keys on an input
+    /// that is sorted by the index column.
+    #[cfg(feature = "dynamic_group_by")]
+    DynamicGroupBy {
+        input: PhysStream,
+        aggs: Vec<ExprIR>,
+        options: Arc<GroupbyOptions>,
+    },
+
     EquiJoin {
         input_left: PhysStream,
         input_right: PhysStream,
         left_on: Vec<ExprIR>,
         right_on: Vec<ExprIR>,
         args: JoinArgs,
+        /// Overrides the build side chosen by the join, from the hints of the inputs.
+        left_is_build: Option<bool>,
     },
 
     SemiAntiJoin {
@@ -282,10 +358,10 @@ pub enum PhysNodeKind {
         options: Option<JoinTypeOptionsIR>,
     },
 
+    /// Merge of any number of inputs sorted on `key`.
     #[cfg(feature = "merge_sorted")]
     MergeSorted {
-        input_left: PhysStream,
-        input_right: PhysStream,
+        inputs: Vec<PhysStream>,
 
         key: PlSmallStr,
     },
@@ -310,8 +386,11 @@ fn visit_node_inputs_mut(
     while let Some(node) = to_visit.pop() {
         match &mut phys_sm[node].kind {
             PhysNodeKind::InMemorySource { .. }
+            | PhysNodeKind::InMemorySubplan { .. }
+            | PhysNodeKind::FastCount { .. }
             | PhysNodeKind::MultiScan { .. }
             | PhysNodeKind::FileScan { .. }
+            | PhysNodeKind::AnonymousScan { .. }
             | PhysNodeKind::InputIndependentSelect { .. } => {},
             #[cfg(feature = "python")]
             PhysNodeKind::PythonScan { .. } => {},
@@ -327,9 +406,24 @@ fn visit_node_inputs_mut(
             | PhysNodeKind::PartitionSink { input, .. }
             | PhysNodeKind::InMemoryMap { input, .. }
             | PhysNodeKind::Map { input, .. }
+            | PhysNodeKind::StreamingOperator { input, .. }
             | PhysNodeKind::Sort { input, .. }
+            | PhysNodeKind::TopK { input, .. }
             | PhysNodeKind::Multiplexer { input }
-            | PhysNodeKind::GroupBy { input, .. } => {
+            | PhysNodeKind::GroupBy { input, .. }
+            | PhysNodeKind::Unique { input, .. } => {
+                rec!(input.node);
+                visit(input);
+            },
+
+            #[cfg(feature = "dynamic_group_by")]
+            PhysNodeKind::DynamicGroupBy { input, .. } => {
+                rec!(input.node);
+                visit(input);
+            },
+
+            #[cfg(feature = "pivot")]
+            PhysNodeKind::Unpivot { input, .. } => {
                 rec!(input.node);
                 visit(input);
             },
@@ -356,15 +450,11 @@ fn visit_node_inputs_mut(
             },
 
             #[cfg(feature = "merge_sorted")]
-            PhysNodeKind::MergeSorted {
-                input_left,
-                input_right,
-                ..
-            } => {
-                rec!(input_left.node);
-                rec!(input_right.node);
-                visit(input_left);
-                visit(input_right);
+            PhysNodeKind::MergeSorted { inputs, .. } => {
+                for input in inputs {
+                    rec!(input.node);
+                    visit(input);
+                }
             },
 
             PhysNodeKind::OrderedUnion { inputs } | PhysNodeKind::Zip { inputs, .. } => {
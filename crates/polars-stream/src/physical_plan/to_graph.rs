@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use parking_lot::Mutex;
 use polars_core::POOL;
+use polars_core::frame::DataFrame;
 use polars_core::prelude::PlRandomState;
 use polars_core::schema::Schema;
 use polars_error::{PolarsResult, polars_bail, polars_ensure, polars_err};
@@ -13,7 +14,7 @@ use polars_mem_engine::{create_physical_plan, create_scan_predicate};
 use polars_plan::dsl::{JoinOptions, PartitionVariantIR};
 use polars_plan::global::_set_n_rows_for_scan;
 use polars_plan::plans::expr_ir::ExprIR;
-use polars_plan::plans::{AExpr, ArenaExprIter, Context, IR};
+use polars_plan::plans::{AExpr, AnonymousScanArgs, ArenaExprIter, Context, IR};
 use polars_plan::prelude::{FileType, FunctionFlags};
 use polars_utils::arena::{Arena, Node};
 use polars_utils::format_pl_smallstr;
@@ -22,7 +23,7 @@ use polars_utils::pl_str::PlSmallStr;
 use recursive::recursive;
 use slotmap::{SecondaryMap, SlotMap};
 
-use super::{PhysNode, PhysNodeKey, PhysNodeKind};
+use super::{PhysNode, PhysNodeKey, PhysNodeKind, PhysStream};
 use crate::execute::StreamingExecutionState;
 use crate::expression::StreamExpr;
 use crate::graph::{Graph, GraphNodeKey};
@@ -89,6 +90,72 @@ pub fn physical_plan_to_graph(
     Ok((ctx.graph, ctx.phys_to_graph))
 }
 
+/// The converted inputs and key selectors of an equi-join or a semi/anti-join.
+struct JoinInputs {
+    left_input_key: GraphNodeKey,
+    right_input_key: GraphNodeKey,
+    left_input_schema: Arc<Schema>,
+    right_input_schema: Arc<Schema>,
+    left_key_schema: Arc<Schema>,
+    right_key_schema: Arc<Schema>,
+    unique_key_schema: Arc<Schema>,
+    left_key_selectors: Vec<StreamExpr>,
+    right_key_selectors: Vec<StreamExpr>,
+}
+
+fn join_inputs(
+    input_left: &PhysStream,
+    input_right: &PhysStream,
+    left_on: &[ExprIR],
+    right_on: &[ExprIR],
+    ctx: &mut GraphConversionContext<'_>,
+) -> PolarsResult<JoinInputs> {
+    let left_input_key = to_graph_rec(input_left.node, ctx)?;
+    let right_input_key = to_graph_rec(input_right.node, ctx)?;
+    let left_input_schema = ctx.phys_sm[input_left.node].output_schema.clone();
+    let right_input_schema = ctx.phys_sm[input_right.node].output_schema.clone();
+
+    let left_key_schema = compute_output_schema(&left_input_schema, left_on, ctx.expr_arena)?;
+    let right_key_schema = compute_output_schema(&right_input_schema, right_on, ctx.expr_arena)?;
+
+    // We use key columns entirely by position, and allow duplicate names in key selectors,
+    // so just assign arbitrary unique names for the selectors.
+    let unique_left_on = left_on
+        .iter()
+        .enumerate()
+        .map(|(i, expr)| expr.with_alias(format_pl_smallstr!("__POLARS_KEYCOL_{i}")))
+        .collect_vec();
+    let unique_right_on = right_on
+        .iter()
+        .enumerate()
+        .map(|(i, expr)| expr.with_alias(format_pl_smallstr!("__POLARS_KEYCOL_{i}")))
+        .collect_vec();
+
+    let left_key_selectors = unique_left_on
+        .iter()
+        .map(|e| create_stream_expr(e, ctx, &left_input_schema))
+        .try_collect_vec()?;
+    let right_key_selectors = unique_right_on
+        .iter()
+        .map(|e| create_stream_expr(e, ctx, &right_input_schema))
+        .try_collect_vec()?;
+
+    let unique_key_schema =
+        compute_output_schema(&right_input_schema, &unique_left_on, ctx.expr_arena)?;
+
+    Ok(JoinInputs {
+        left_input_key,
+        right_input_key,
+        left_input_schema,
+        right_input_schema,
+        left_key_schema,
+        right_key_schema,
+        unique_key_schema,
+        left_key_selectors,
+        right_key_selectors,
+    })
+}
+
 #[recursive]
 fn to_graph_rec<'a>(
     phys_node_key: PhysNodeKey,
@@ -106,6 +173,75 @@ fn to_graph_rec<'a>(
             nodes::in_memory_source::InMemorySourceNode::new(df.clone(), MorselSeq::default()),
             [],
         ),
+        InMemorySubplan { ir_arena, ir_node } => {
+            // The executor can only run once, so it is created for every run of the plan.
+            let mut lp_arena = (**ir_arena).clone();
+            let executor = Mutex::new(create_physical_plan(
+                *ir_node,
+                &mut lp_arena,
+                ctx.expr_arena,
+            )?);
+            let empty_key = ctx.graph.add_node(
+                nodes::in_memory_source::InMemorySourceNode::new(
+                    Arc::new(DataFrame::empty()),
+                    MorselSeq::default(),
+                ),
+                [],
+            );
+            ctx.graph.add_node(
+                nodes::in_memory_map::InMemoryMapNode::new(
+                    Arc::new(Schema::default()),
+                    Arc::new(move |_| {
+                        let mut state = ExecutionState::new();
+                        executor.lock().execute(&mut state)
+                    }),
+                ),
+                [(empty_key, 0)],
+            )
+        },
+        AnonymousScan {
+            function,
+            schema,
+            output_schema,
+            with_columns,
+        } => {
+            let function = function.clone();
+            let schema = schema.clone();
+            let output_schema = output_schema.clone();
+            let with_columns = with_columns.clone();
+            let batch_schema = node.output_schema.clone();
+            let get_batch_fn = Box::new(move |_state: &StreamingExecutionState| {
+                function.next_batch(AnonymousScanArgs {
+                    n_rows: None,
+                    with_columns: with_columns.clone(),
+                    schema: schema.clone(),
+                    output_schema: output_schema.clone(),
+                    predicate: None,
+                })
+            }) as Box<_>;
+            ctx.graph.add_node(
+                SourceComputeNode::new(BatchSourceNode::new(
+                    "anonymous_scan",
+                    batch_schema,
+                    Some(get_batch_fn),
+                )),
+                [],
+            )
+        },
+        FastCount {
+            sources,
+            scan_type,
+            alias,
+        } => ctx.graph.add_node(
+            nodes::io_sources::SourceComputeNode::new(
+                nodes::io_sources::count::CountSourceNode::new(
+                    sources.clone(),
+                    scan_type.clone(),
+                    alias.clone(),
+                ),
+            ),
+            [],
+        ),
         SinkMultiple { sinks } => {
             // @NOTE: This is always the root node and gets ignored by the physical_plan anyway so
             // we give one of the inputs back.
@@ -388,6 +524,15 @@ fn to_graph_rec<'a>(
             )
         },
 
+        StreamingOperator { input, name } => {
+            let operator = crate::operator::new_streaming_operator(name)?;
+            let input_key = to_graph_rec(input.node, ctx)?;
+            ctx.graph.add_node(
+                nodes::streaming_operator::StreamingOperatorNode::new(name, operator),
+                [(input_key, input.port)],
+            )
+        },
+
         Map { input, map } => {
             let input_key = to_graph_rec(input.node, ctx)?;
             ctx.graph.add_node(
@@ -396,6 +541,16 @@ fn to_graph_rec<'a>(
             )
         },
 
+        #[cfg(feature = "pivot")]
+        Unpivot { input, index, on } => {
+            let output_schema = node.output_schema.clone();
+            let input_key = to_graph_rec(input.node, ctx)?;
+            ctx.graph.add_node(
+                nodes::unpivot::UnpivotNode::new(index.clone(), on.clone(), output_schema),
+                [(input_key, input.port)],
+            )
+        },
+
         Sort {
             input,
             by_column,
@@ -403,30 +558,69 @@ fn to_graph_rec<'a>(
             sort_options,
         } => {
             let input_schema = ctx.phys_sm[input.node].output_schema.clone();
-            let lmdf = Arc::new(LateMaterializedDataFrame::default());
-            let mut lp_arena = Arena::default();
-            let df_node = lp_arena.add(lmdf.clone().as_ir_node(input_schema.clone()));
-            let sort_node = lp_arena.add(IR::Sort {
-                input: df_node,
-                by_column: by_column.clone(),
-                slice: *slice,
-                sort_options: sort_options.clone(),
-            });
-            let executor = Mutex::new(create_physical_plan(
-                sort_node,
-                &mut lp_arena,
-                ctx.expr_arena,
-            )?);
 
+            // A full sort by plain columns can be done out-of-core, other sorts are done in
+            // memory.
+            let by = by_column
+                .iter()
+                .map(|e| match ctx.expr_arena.get(e.node()) {
+                    AExpr::Column(name) if name == e.output_name() => Some(name.clone()),
+                    _ => None,
+                })
+                .collect::<Option<Vec<_>>>();
+            if let (None, Some(by)) = (slice, by) {
+                let input_key = to_graph_rec(input.node, ctx)?;
+                ctx.graph.add_node(
+                    nodes::sort::SortNode::new(input_schema, by, sort_options.clone()),
+                    [(input_key, input.port)],
+                )
+            } else {
+                let lmdf = Arc::new(LateMaterializedDataFrame::default());
+                let mut lp_arena = Arena::default();
+                let df_node = lp_arena.add(lmdf.clone().as_ir_node(input_schema.clone()));
+                let sort_node = lp_arena.add(IR::Sort {
+                    input: df_node,
+                    by_column: by_column.clone(),
+                    slice: *slice,
+                    sort_options: sort_options.clone(),
+                });
+                let executor = Mutex::new(create_physical_plan(
+                    sort_node,
+                    &mut lp_arena,
+                    ctx.expr_arena,
+                )?);
+
+                let input_key = to_graph_rec(input.node, ctx)?;
+                ctx.graph.add_node(
+                    nodes::in_memory_map::InMemoryMapNode::new(
+                        input_schema,
+                        Arc::new(move |df| {
+                            lmdf.set_materialized_dataframe(df);
+                            let mut state = ExecutionState::new();
+                            executor.lock().execute(&mut state)
+                        }),
+                    ),
+                    [(input_key, input.port)],
+                )
+            }
+        },
+
+        TopK {
+            input,
+            by,
+            sort_options,
+            offset,
+            len,
+        } => {
+            let input_schema = ctx.phys_sm[input.node].output_schema.clone();
             let input_key = to_graph_rec(input.node, ctx)?;
             ctx.graph.add_node(
-                nodes::in_memory_map::InMemoryMapNode::new(
+                nodes::top_k::TopKNode::new(
                     input_schema,
-                    Arc::new(move |df| {
-                        lmdf.set_materialized_dataframe(df);
-                        let mut state = ExecutionState::new();
-                        executor.lock().execute(&mut state)
-                    }),
+                    by.clone(),
+                    sort_options.clone(),
+                    *offset,
+                    *len,
                 ),
                 [(input_key, input.port)],
             )
@@ -475,6 +669,8 @@ fn to_graph_rec<'a>(
             file_schema,
             allow_missing_columns,
             include_file_paths,
+            #[cfg(feature = "cast_policy")]
+            cast_policy,
             projection,
             row_restriction,
             predicate,
@@ -484,6 +680,7 @@ fn to_graph_rec<'a>(
             file_reader_builder,
             projected_file_schema,
             cloud_options,
+            row_estimate: _,
         } => {
             let predicate = predicate
                 .as_ref()
@@ -505,23 +702,24 @@ fn to_graph_rec<'a>(
             if let Some(file_reader_builder) = file_reader_builder {
                 let hive_parts = hive_parts.clone();
 
-                ctx.graph.add_node(
-                    nodes::io_sources::multi_file_reader::MultiFileReader::new(
-                        scan_sources.clone(),
-                        file_reader_builder.clone(),
-                        cloud_options.clone(),
-                        output_schema.clone(),
-                        projected_file_schema.clone(),
-                        file_schema.clone(),
-                        row_index.clone(),
-                        pre_slice.clone(),
-                        predicate,
-                        hive_parts.map(Arc::new),
-                        include_file_paths.clone(),
-                        *allow_missing_columns,
-                    ),
-                    [],
-                )
+                let multi_file_reader = nodes::io_sources::multi_file_reader::MultiFileReader::new(
+                    scan_sources.clone(),
+                    file_reader_builder.clone(),
+                    cloud_options.clone(),
+                    output_schema.clone(),
+                    projected_file_schema.clone(),
+                    file_schema.clone(),
+                    row_index.clone(),
+                    pre_slice.clone(),
+                    predicate,
+                    hive_parts.map(Arc::new),
+                    include_file_paths.clone(),
+                    *allow_missing_columns,
+                );
+                #[cfg(feature = "cast_policy")]
+                let multi_file_reader = multi_file_reader.with_cast_policy(*cast_policy);
+
+                ctx.graph.add_node(multi_file_reader, [])
             } else {
                 match &**scan_type {
                     #[cfg(feature = "parquet")]
@@ -730,6 +928,84 @@ fn to_graph_rec<'a>(
             )
         },
 
+        Unique {
+            input,
+            key,
+            keep,
+            maintain_order,
+        } => {
+            let input_key = to_graph_rec(input.node, ctx)?;
+            ctx.graph.add_node(
+                nodes::unique::UniqueNode::new(
+                    node.output_schema.clone(),
+                    key.clone(),
+                    *keep,
+                    *maintain_order,
+                    ctx.num_pipelines,
+                ),
+                [(input_key, input.port)],
+            )
+        },
+
+        #[cfg(feature = "dynamic_group_by")]
+        DynamicGroupBy {
+            input,
+            aggs,
+            options,
+        } => {
+            use nodes::dynamic_group_by::{DynamicGroupByNode, LB_NAME, UP_NAME, WindowKind};
+
+            let input_schema = ctx.phys_sm[input.node].output_schema.clone();
+            let (index_column, windows) = WindowKind::try_new(options, &input_schema).unwrap();
+
+            // The windows are determined from the boundaries, so we always need them.
+            let mut options = options.as_ref().clone();
+            let mut group_by_schema = node.output_schema.clone();
+            if let Some(dynamic) = &mut options.dynamic {
+                if !dynamic.include_boundaries {
+                    dynamic.include_boundaries = true;
+                    let dtype = input_schema.try_get(&dynamic.index_column)?.clone();
+                    let mut schema = Schema::with_capacity(group_by_schema.len() + 2);
+                    schema.insert(LB_NAME.into(), dtype.clone());
+                    schema.insert(UP_NAME.into(), dtype);
+                    schema.merge_from_ref(&group_by_schema);
+                    group_by_schema = Arc::new(schema);
+                }
+            }
+
+            let lmdf = Arc::new(LateMaterializedDataFrame::default());
+            let mut lp_arena = Arena::default();
+            let df_node = lp_arena.add(lmdf.clone().as_ir_node(input_schema));
+            let group_by_node = lp_arena.add(IR::GroupBy {
+                input: df_node,
+                keys: Vec::new(),
+                aggs: aggs.clone(),
+                schema: group_by_schema,
+                maintain_order: false,
+                options: Arc::new(options),
+                apply: None,
+            });
+            let executor = Mutex::new(create_physical_plan(
+                group_by_node,
+                &mut lp_arena,
+                ctx.expr_arena,
+            )?);
+
+            let input_key = to_graph_rec(input.node, ctx)?;
+            ctx.graph.add_node(
+                DynamicGroupByNode::new(
+                    Arc::new(move |df| {
+                        lmdf.set_materialized_dataframe(df);
+                        let mut state = ExecutionState::new();
+                        executor.lock().execute(&mut state)
+                    }),
+                    index_column,
+                    windows,
+                ),
+                [(input_key, input.port)],
+            )
+        },
+
         InMemoryJoin {
             input_left,
             input_right,
@@ -796,107 +1072,79 @@ fn to_graph_rec<'a>(
             left_on,
             right_on,
             args,
-        }
-        | SemiAntiJoin {
+            left_is_build,
+        } => {
+            let inputs = join_inputs(input_left, input_right, left_on, right_on, ctx)?;
+            ctx.graph.add_node(
+                nodes::joins::equi_join::EquiJoinNode::new(
+                    inputs.left_input_schema,
+                    inputs.right_input_schema,
+                    inputs.left_key_schema,
+                    inputs.right_key_schema,
+                    inputs.unique_key_schema,
+                    inputs.left_key_selectors,
+                    inputs.right_key_selectors,
+                    args.clone(),
+                    *left_is_build,
+                    ctx.num_pipelines,
+                )?,
+                [
+                    (inputs.left_input_key, input_left.port),
+                    (inputs.right_input_key, input_right.port),
+                ],
+            )
+        },
+
+        SemiAntiJoin {
             input_left,
             input_right,
             left_on,
             right_on,
             args,
-            output_bool: _,
+            output_bool,
         } => {
-            let args = args.clone();
-            let left_input_key = to_graph_rec(input_left.node, ctx)?;
-            let right_input_key = to_graph_rec(input_right.node, ctx)?;
-            let left_input_schema = ctx.phys_sm[input_left.node].output_schema.clone();
-            let right_input_schema = ctx.phys_sm[input_right.node].output_schema.clone();
-
-            let left_key_schema =
-                compute_output_schema(&left_input_schema, left_on, ctx.expr_arena)?;
-            let right_key_schema =
-                compute_output_schema(&right_input_schema, right_on, ctx.expr_arena)?;
-
-            // We use key columns entirely by position, and allow duplicate names in key selectors,
-            // so just assign arbitrary unique names for the selectors.
-            let unique_left_on = left_on
-                .iter()
-                .enumerate()
-                .map(|(i, expr)| expr.with_alias(format_pl_smallstr!("__POLARS_KEYCOL_{i}")))
-                .collect_vec();
-            let unique_right_on = right_on
-                .iter()
-                .enumerate()
-                .map(|(i, expr)| expr.with_alias(format_pl_smallstr!("__POLARS_KEYCOL_{i}")))
-                .collect_vec();
+            let inputs = join_inputs(input_left, input_right, left_on, right_on, ctx)?;
+            ctx.graph.add_node(
+                nodes::joins::semi_anti_join::SemiAntiJoinNode::new(
+                    inputs.unique_key_schema,
+                    inputs.left_key_selectors,
+                    inputs.right_key_selectors,
+                    args.clone(),
+                    *output_bool,
+                    ctx.num_pipelines,
+                )?,
+                [
+                    (inputs.left_input_key, input_left.port),
+                    (inputs.right_input_key, input_right.port),
+                ],
+            )
+        },
 
-            let left_key_selectors = unique_left_on
-                .iter()
-                .map(|e| create_stream_expr(e, ctx, &left_input_schema))
-                .try_collect_vec()?;
-            let right_key_selectors = unique_right_on
+        #[cfg(feature = "merge_sorted")]
+        MergeSorted { inputs, key } => {
+            let input_schema = ctx.phys_sm[inputs[0].node].output_schema.clone();
+            let input_keys = inputs
                 .iter()
-                .map(|e| create_stream_expr(e, ctx, &right_input_schema))
+                .map(|i| PolarsResult::Ok((to_graph_rec(i.node, ctx)?, i.port)))
                 .try_collect_vec()?;
 
-            let unique_key_schema =
-                compute_output_schema(&right_input_schema, &unique_left_on, ctx.expr_arena)?;
-
-            if let SemiAntiJoin { output_bool, .. } = node.kind {
+            if inputs.len() == 2 {
                 ctx.graph.add_node(
-                    nodes::joins::semi_anti_join::SemiAntiJoinNode::new(
-                        unique_key_schema,
-                        left_key_selectors,
-                        right_key_selectors,
-                        args,
-                        output_bool,
-                        ctx.num_pipelines,
-                    )?,
-                    [
-                        (left_input_key, input_left.port),
-                        (right_input_key, input_right.port),
-                    ],
+                    nodes::merge_sorted::MergeSortedNode::new(input_schema, key.clone()),
+                    input_keys,
                 )
             } else {
                 ctx.graph.add_node(
-                    nodes::joins::equi_join::EquiJoinNode::new(
-                        left_input_schema,
-                        right_input_schema,
-                        left_key_schema,
-                        right_key_schema,
-                        unique_key_schema,
-                        left_key_selectors,
-                        right_key_selectors,
-                        args,
-                        ctx.num_pipelines,
-                    )?,
-                    [
-                        (left_input_key, input_left.port),
-                        (right_input_key, input_right.port),
-                    ],
+                    nodes::kway_merge_sorted::KWayMergeSortedNode::new(
+                        input_schema,
+                        key.clone(),
+                        inputs.len(),
+                    ),
+                    input_keys,
                 )
             }
         },
 
-        #[cfg(feature = "merge_sorted")]
-        MergeSorted {
-            input_left,
-            input_right,
-            key,
-        } => {
-            let left_input_key = to_graph_rec(input_left.node, ctx)?;
-            let right_input_key = to_graph_rec(input_right.node, ctx)?;
-
-            let input_schema = ctx.phys_sm[input_left.node].output_schema.clone();
-
-            ctx.graph.add_node(
-                nodes::merge_sorted::MergeSortedNode::new(input_schema, key.clone()),
-                [
-                    (left_input_key, input_left.port),
-                    (right_input_key, input_right.port),
-                ],
-            )
-        },
-
         #[cfg(feature = "python")]
         PythonScan { options } => {
             use polars_plan::dsl::python_dsl::PythonScanSource as S;
@@ -1036,6 +1284,12 @@ fn to_graph_rec<'a>(
         },
     };
 
+    ctx.graph.nodes[graph_key].expected_rows = match &node.kind {
+        InMemorySource { df } => Some(df.height() as u64),
+        MultiScan { row_estimate, .. } => row_estimate.map(|n| n as u64),
+        _ => None,
+    };
+
     ctx.phys_to_graph.insert(phys_node_key, graph_key);
     Ok(graph_key)
 }
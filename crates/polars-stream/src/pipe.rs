@@ -9,7 +9,7 @@ use crate::async_primitives::distributor_channel::distributor_channel;
 use crate::async_primitives::linearizer::Linearizer;
 use crate::async_primitives::wait_group::WaitGroup;
 use crate::morsel::{Morsel, MorselSeq};
-use crate::{DEFAULT_DISTRIBUTOR_BUFFER_SIZE, DEFAULT_LINEARIZER_BUFFER_SIZE};
+use crate::skeleton::BufferSizes;
 
 pub enum PhysicalPipe {
     Uninit(usize),
@@ -112,6 +112,7 @@ impl PhysicalPipe {
     pub fn spawn<'env, 's>(
         &'env mut self,
         scope: &'s TaskScope<'s, 'env>,
+        buffer_sizes: &BufferSizes,
         handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
     ) {
         match core::mem::replace(self, Self::Initialized) {
@@ -126,7 +127,7 @@ impl PhysicalPipe {
                 let (mut linearizer, inserters) =
                     Linearizer::<Priority<Reverse<MorselSeq>, Morsel>>::new_with_maintain_order(
                         num_pipelines,
-                        *DEFAULT_LINEARIZER_BUFFER_SIZE,
+                        buffer_sizes.linearizer,
                         maintain_order,
                     );
 
@@ -164,7 +165,7 @@ impl PhysicalPipe {
             Self::NeedsDistributor(mut receiver, senders) => {
                 let num_pipelines = senders.len();
                 let (mut distributor, distr_receivers) =
-                    distributor_channel(num_pipelines, *DEFAULT_DISTRIBUTOR_BUFFER_SIZE);
+                    distributor_channel(num_pipelines, buffer_sizes.distributor);
 
                 handles.push(scope.spawn_task(TaskPriority::High, async move {
                     while let Ok(morsel) = receiver.recv().await {
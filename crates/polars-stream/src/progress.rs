@@ -0,0 +1,111 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use parking_lot::RwLock;
+use polars_utils::pl_str::PlSmallStr;
+
+static PROGRESS_OBSERVER: RwLock<Option<Arc<dyn ProgressObserver>>> = RwLock::new(None);
+
+/// An event emitted while a streaming query runs.
+///
+/// Nodes are identified by an index which is stable for the duration of a query, together with
+/// their name.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ProgressEvent<'a> {
+    /// The node starts running in a new execution phase.
+    PhaseStarted { node: usize, name: &'a str },
+    /// The node stopped running at the end of an execution phase.
+    PhaseFinished { node: usize, name: &'a str },
+    /// The node is done and will not produce any more rows.
+    NodeFinished { node: usize, name: &'a str },
+    /// The node produced a morsel.
+    RowsProduced {
+        node: usize,
+        name: &'a str,
+        /// The number of rows and estimated bytes in this morsel.
+        rows: u64,
+        bytes: u64,
+        /// The total number of rows produced by the node so far.
+        total_rows: u64,
+        /// The estimated fraction of the output that was produced, for bounded sources.
+        estimated_completion: Option<f64>,
+    },
+}
+
+/// Receives the [`ProgressEvent`]s of streaming queries, e.g. to render progress bars.
+///
+/// Events are emitted from the threads executing the query, so observers should return quickly.
+pub trait ProgressObserver: Send + Sync {
+    fn on_event(&self, event: &ProgressEvent<'_>);
+}
+
+/// Sets the observer that receives the progress of all streaming queries, or removes it.
+pub fn set_progress_observer(observer: Option<Arc<dyn ProgressObserver>>) {
+    *PROGRESS_OBSERVER.write() = observer;
+}
+
+pub(crate) fn get_progress_observer() -> Option<Arc<dyn ProgressObserver>> {
+    PROGRESS_OBSERVER.read().clone()
+}
+
+/// The progress of a single node in the graph.
+pub(crate) struct NodeProgress {
+    observer: Arc<dyn ProgressObserver>,
+    id: usize,
+    name: PlSmallStr,
+    expected_rows: Option<u64>,
+    total_rows: AtomicU64,
+}
+
+impl NodeProgress {
+    pub fn new(
+        observer: Arc<dyn ProgressObserver>,
+        id: usize,
+        name: &str,
+        expected_rows: Option<u64>,
+    ) -> Self {
+        Self {
+            observer,
+            id,
+            name: name.into(),
+            expected_rows,
+            total_rows: AtomicU64::new(0),
+        }
+    }
+
+    pub fn phase_started(&self) {
+        self.observer.on_event(&ProgressEvent::PhaseStarted {
+            node: self.id,
+            name: &self.name,
+        });
+    }
+
+    pub fn phase_finished(&self) {
+        self.observer.on_event(&ProgressEvent::PhaseFinished {
+            node: self.id,
+            name: &self.name,
+        });
+    }
+
+    pub fn node_finished(&self) {
+        self.observer.on_event(&ProgressEvent::NodeFinished {
+            node: self.id,
+            name: &self.name,
+        });
+    }
+
+    pub fn rows_produced(&self, rows: u64, bytes: u64) {
+        let total_rows = self.total_rows.fetch_add(rows, Ordering::Relaxed) + rows;
+        let estimated_completion = self
+            .expected_rows
+            .map(|expected| (total_rows as f64 / expected.max(1) as f64).min(1.0));
+        self.observer.on_event(&ProgressEvent::RowsProduced {
+            node: self.id,
+            name: &self.name,
+            rows,
+            bytes,
+            total_rows,
+            estimated_completion,
+        });
+    }
+}
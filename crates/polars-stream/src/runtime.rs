@@ -0,0 +1,193 @@
+//! The runtime that the streaming engine runs its I/O, blocking work and threads on.
+//!
+//! By default the I/O runs on the Tokio runtime of `polars-io` and the executor runs on OS
+//! threads. Targets without them, such as wasm32 in a browser, install their own runtime with
+//! [`set_streaming_runtime`] before running queries, e.g. one that polls futures with
+//! `wasm-bindgen-futures` and runs threads in web workers. Files are then read with a
+//! [`RangeReader`](polars_io::utils::byte_source::RangeReader), e.g. one that uses `fetch`. The
+//! sinks still write their files with the Tokio runtime, which only has a current-thread flavor
+//! there.
+use std::future::Future;
+use std::panic::AssertUnwindSafe;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures::channel::oneshot;
+use futures::future::{AbortHandle, Abortable, BoxFuture, LocalBoxFuture};
+use futures::{FutureExt, ready};
+use parking_lot::RwLock;
+
+static STREAMING_RUNTIME: RwLock<Option<Arc<dyn StreamingRuntime>>> = RwLock::new(None);
+
+/// Runs the futures, blocking functions and threads of the streaming engine.
+pub trait StreamingRuntime: Send + Sync {
+    /// Polls `future` to completion in the background.
+    fn spawn(&self, future: BoxFuture<'static, ()>);
+
+    /// Runs `f`, which may block, without blocking the futures of the runtime.
+    fn spawn_blocking(&self, f: Box<dyn FnOnce() + Send>);
+
+    /// Blocks the current thread until `future` completes.
+    ///
+    /// Streaming queries are collected with this, so they must run on a thread that may block,
+    /// e.g. a web worker rather than the main thread of a browser.
+    fn block_on(&self, future: LocalBoxFuture<'_, ()>) {
+        futures::executor::block_on(future)
+    }
+
+    /// Returns a future that completes after `duration`. Dropping the future cancels the timer.
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()>;
+
+    /// Runs `f` on a new thread named `name`, for the executor of the streaming engine.
+    fn spawn_thread(&self, name: String, f: Box<dyn FnOnce() + Send>) {
+        std::thread::Builder::new().name(name).spawn(f).unwrap();
+    }
+}
+
+/// Sets the runtime of all streaming queries, or restores the default one.
+///
+/// The executor threads are only started once per process, by the runtime that is set when the
+/// first streaming query runs.
+pub fn set_streaming_runtime(runtime: Option<Arc<dyn StreamingRuntime>>) {
+    *STREAMING_RUNTIME.write() = runtime;
+}
+
+#[cfg(not(target_family = "wasm"))]
+fn get_runtime() -> Arc<dyn StreamingRuntime> {
+    STREAMING_RUNTIME
+        .read()
+        .clone()
+        .unwrap_or_else(|| Arc::new(TokioRuntime))
+}
+
+#[cfg(target_family = "wasm")]
+fn get_runtime() -> Arc<dyn StreamingRuntime> {
+    STREAMING_RUNTIME
+        .read()
+        .clone()
+        .expect("the streaming engine has no default runtime on wasm, call `set_streaming_runtime`")
+}
+
+/// The default runtime, the Tokio runtime of `polars-io`.
+#[cfg(not(target_family = "wasm"))]
+struct TokioRuntime;
+
+#[cfg(not(target_family = "wasm"))]
+impl StreamingRuntime for TokioRuntime {
+    fn spawn(&self, future: BoxFuture<'static, ()>) {
+        polars_io::pl_async::get_runtime().spawn(future);
+    }
+
+    fn spawn_blocking(&self, f: Box<dyn FnOnce() + Send>) {
+        polars_io::pl_async::get_runtime().spawn_blocking(f);
+    }
+
+    fn block_on(&self, future: LocalBoxFuture<'_, ()>) {
+        polars_io::pl_async::get_runtime().block_on(future)
+    }
+
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+        // Timers only work within the tokio runtime, which the tasks of the nodes don't run on.
+        let timer = polars_io::pl_async::get_runtime().spawn(tokio::time::sleep(duration));
+        let abort_timer = AbortOnDrop(timer.abort_handle());
+        Box::pin(async move {
+            let _abort_timer = abort_timer;
+            let _ = timer.await;
+        })
+    }
+}
+
+/// Aborts a Tokio task when dropped, so that timers don't outlive the futures waiting on them.
+#[cfg(not(target_family = "wasm"))]
+struct AbortOnDrop(tokio::task::AbortHandle);
+
+#[cfg(not(target_family = "wasm"))]
+impl Drop for AbortOnDrop {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+/// The error of a task of the runtime that was cancelled before it completed.
+#[derive(Debug)]
+pub(crate) struct Cancelled;
+
+/// A handle to a task spawned on the runtime, which resolves to its output, and resumes its panic
+/// if it panicked.
+///
+/// Dropping the handle detaches the task, [`JoinHandle::abort`] cancels it.
+pub(crate) struct JoinHandle<T> {
+    output: oneshot::Receiver<std::thread::Result<T>>,
+    abort: AbortHandle,
+}
+
+impl<T> JoinHandle<T> {
+    pub(crate) fn abort(&self) {
+        self.abort.abort();
+    }
+}
+
+impl<T> Future for JoinHandle<T> {
+    type Output = Result<T, Cancelled>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Poll::Ready(match ready!(Pin::new(&mut self.output).poll(cx)) {
+            Ok(Ok(output)) => Ok(output),
+            Ok(Err(panic)) => std::panic::resume_unwind(panic),
+            Err(_) => Err(Cancelled),
+        })
+    }
+}
+
+/// Spawns `future` on the runtime.
+pub(crate) fn spawn<F>(future: F) -> JoinHandle<F::Output>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    let (send, output) = oneshot::channel();
+    let (abort, registration) = AbortHandle::new_pair();
+    let task = Abortable::new(AssertUnwindSafe(future).catch_unwind(), registration).map(|out| {
+        if let Ok(out) = out {
+            // The handle may have been dropped.
+            let _ = send.send(out);
+        }
+    });
+    get_runtime().spawn(Box::pin(task));
+    JoinHandle { output, abort }
+}
+
+/// Runs `f`, which may block, on the runtime.
+pub(crate) fn spawn_blocking<F, R>(f: F) -> JoinHandle<R>
+where
+    F: FnOnce() -> R + Send + 'static,
+    R: Send + 'static,
+{
+    let (send, output) = oneshot::channel();
+    let (abort, _) = AbortHandle::new_pair();
+    get_runtime().spawn_blocking(Box::new(move || {
+        let _ = send.send(std::panic::catch_unwind(AssertUnwindSafe(f)));
+    }));
+    JoinHandle { output, abort }
+}
+
+/// Blocks the current thread until `future` completes.
+pub(crate) fn block_on<F: Future>(future: F) -> F::Output {
+    let mut output = None;
+    get_runtime().block_on(Box::pin(async {
+        output = Some(future.await);
+    }));
+    output.unwrap()
+}
+
+/// Completes after `duration`.
+pub(crate) fn sleep(duration: Duration) -> BoxFuture<'static, ()> {
+    get_runtime().sleep(duration)
+}
+
+/// Runs `f` on a new thread named `name`.
+pub(crate) fn spawn_thread(name: String, f: impl FnOnce() + Send + 'static) {
+    get_runtime().spawn_thread(name, Box::new(f))
+}
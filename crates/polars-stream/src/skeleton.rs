@@ -1,16 +1,112 @@
 #![allow(unused)] // TODO: remove me
 use std::cmp::Reverse;
+use std::sync::Arc;
+use std::time::Duration;
 
 use polars_core::POOL;
 use polars_core::prelude::*;
 use polars_expr::planner::{ExpressionConversionState, create_physical_expr, get_expr_depth_limit};
+use polars_expr::state::CancelToken;
 use polars_plan::plans::{Context, IR, IRPlan};
 use polars_plan::prelude::AExpr;
 use polars_plan::prelude::expr_ir::ExprIR;
 use polars_utils::arena::{Arena, Node};
 use slotmap::{SecondaryMap, SlotMap};
 
-use crate::physical_plan::PhysNodeKind;
+use crate::async_executor::QueryPriority;
+use crate::metrics::QueryMetrics;
+use crate::pause::PauseToken;
+use crate::physical_plan::{PhysNode, PhysNodeKey, PhysNodeKind, PhysicalPlanFormat};
+
+/// Options to control a streaming query while it runs, see [`run_query_with_options`].
+#[derive(Clone)]
+pub struct QueryOptions {
+    /// Once cancelled, the query stops with an error as soon as possible.
+    pub cancel_token: Option<CancelToken>,
+    /// Holds back the output of the sources while it is paused.
+    pub pause_token: Option<PauseToken>,
+    /// The share of the executor the query gets while other queries are running.
+    pub priority: QueryPriority,
+    /// The capacities of the channels between the nodes of the query.
+    pub buffer_sizes: BufferSizes,
+    /// Enables the low-latency mode: nodes which buffer rows to fill their output, such as file
+    /// sinks and dynamic group-bys, flush whatever they have once the oldest buffered row has
+    /// waited this long. This reduces the end-to-end latency at the cost of smaller morsels and
+    /// row groups, and so of throughput.
+    pub flush_deadline: Option<Duration>,
+    /// Whether the sinks write the rows in the order of the query, which is the default. Without
+    /// it the file sinks write the morsels as they come in. The order within the query is always
+    /// maintained, as operations such as `with_row_index`, slices and horizontal concatenations
+    /// depend on it. Only use this if the order of the written rows doesn't matter.
+    pub maintain_order: bool,
+    /// The number of bytes an operator which can spill, such as a sort or unique, may buffer
+    /// before it spills to disk. Defaults to the `POLARS_STREAMING_SPILL_THRESHOLD` env var.
+    pub spill_threshold: Option<usize>,
+    /// The values bound to the placeholders of the query, by their names. The query fails if a
+    /// placeholder has no value or the value can't be cast to the type of its placeholder.
+    pub params: PlHashMap<PlSmallStr, Scalar>,
+}
+
+impl Default for QueryOptions {
+    fn default() -> Self {
+        Self {
+            cancel_token: None,
+            pause_token: None,
+            priority: QueryPriority::default(),
+            buffer_sizes: BufferSizes::default(),
+            flush_deadline: None,
+            maintain_order: true,
+            spill_threshold: None,
+            params: PlHashMap::default(),
+        }
+    }
+}
+
+/// The number of morsels or row groups the streaming engine buffers ahead, see [`QueryOptions`].
+///
+/// Larger buffers smooth out differences in throughput between nodes at the cost of memory. The
+/// defaults are read from the `POLARS_DEFAULT_*_BUFFER_SIZE` and `POLARS_ROW_GROUP_PREFETCH_SIZE`
+/// env vars.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BufferSizes {
+    /// The morsels buffered per pipeline when merging parallel pipelines into one.
+    pub linearizer: usize,
+    /// The morsels buffered per pipeline when distributing a serial stream over the pipelines.
+    pub distributor: usize,
+    /// The morsels buffered from the head of the inputs of horizontal concatenations.
+    pub zip_head: usize,
+    /// The row groups a Parquet source fetches ahead of the ones it is decoding.
+    pub row_group_prefetch: usize,
+}
+
+impl BufferSizes {
+    /// Checks that every buffer can hold at least one morsel or row group.
+    pub fn validate(&self) -> PolarsResult<()> {
+        for (name, size) in [
+            ("linearizer", self.linearizer),
+            ("distributor", self.distributor),
+            ("zip_head", self.zip_head),
+            ("row_group_prefetch", self.row_group_prefetch),
+        ] {
+            polars_ensure!(
+                size >= 1,
+                InvalidOperation: "buffer size '{}' must be at least 1, got {}", name, size
+            );
+        }
+        Ok(())
+    }
+}
+
+impl Default for BufferSizes {
+    fn default() -> Self {
+        Self {
+            linearizer: *crate::DEFAULT_LINEARIZER_BUFFER_SIZE,
+            distributor: *crate::DEFAULT_DISTRIBUTOR_BUFFER_SIZE,
+            zip_head: *crate::DEFAULT_ZIP_HEAD_BUFFER_SIZE,
+            row_group_prefetch: polars_core::config::get_rg_prefetch_size(),
+        }
+    }
+}
 
 /// Executes the IR with the streaming engine.
 ///
@@ -28,6 +124,170 @@ pub fn run_query(
     ir_arena: &mut Arena<IR>,
     expr_arena: &mut Arena<AExpr>,
 ) -> PolarsResult<Result<DataFrame, Vec<DataFrame>>> {
+    run_query_impl(
+        node,
+        ir_arena,
+        expr_arena,
+        false,
+        QueryOptions::default(),
+        None,
+    )
+    .map(|(out, _)| out)
+}
+
+/// Executes the IR with the streaming engine like [`run_query`], controlled by the given
+/// [`QueryOptions`].
+///
+/// All running tasks of the query are dropped on cancellation, which also aborts their in-flight
+/// cloud requests.
+pub fn run_query_with_options(
+    node: Node,
+    ir_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+    options: QueryOptions,
+) -> PolarsResult<Result<DataFrame, Vec<DataFrame>>> {
+    run_query_impl(node, ir_arena, expr_arena, false, options, None).map(|(out, _)| out)
+}
+
+/// Executes the IR with the streaming engine like [`run_query`], also returning the runtime
+/// metrics of every node in the streaming graph.
+pub fn run_query_with_metrics(
+    node: Node,
+    ir_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<(Result<DataFrame, Vec<DataFrame>>, QueryMetrics)> {
+    run_query_impl(
+        node,
+        ir_arena,
+        expr_arena,
+        true,
+        QueryOptions::default(),
+        None,
+    )
+    .map(|(out, metrics)| (out, metrics.unwrap()))
+}
+
+/// Executes the IR with the streaming engine, returning the physical plan annotated with the
+/// runtime metrics of every node: the rows it received and sent, the time it ran, its peak memory
+/// and the bytes it spilled to disk.
+pub fn explain_analyze(
+    node: Node,
+    ir_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<String> {
+    let mut explained = String::new();
+    run_query_impl(
+        node,
+        ir_arena,
+        expr_arena,
+        true,
+        QueryOptions::default(),
+        Some(&mut explained),
+    )?;
+    Ok(explained)
+}
+
+/// A physical plan of the streaming engine, lowered from the IR once so that it can be run many
+/// times.
+#[derive(Clone)]
+pub struct StreamingPlan(PlanState);
+
+#[derive(Clone)]
+enum PlanState {
+    Lowered {
+        root: PhysNodeKey,
+        phys_sm: SlotMap<PhysNodeKey, PhysNode>,
+        expr_arena: Arena<AExpr>,
+    },
+    /// The optimized IR of a plan with placeholders, which is lowered every time it runs. The
+    /// lowering builds the physical expressions of some nodes, e.g. the in-memory maps and
+    /// fallback group-bys, so the placeholders must be bound before it.
+    Unbound {
+        node: Node,
+        ir_arena: Arena<IR>,
+        expr_arena: Arena<AExpr>,
+    },
+}
+
+impl StreamingPlan {
+    /// Lowers the optimized IR to the physical plan of the streaming engine.
+    pub fn lower(
+        node: Node,
+        ir_arena: &mut Arena<IR>,
+        expr_arena: &mut Arena<AExpr>,
+    ) -> PolarsResult<Self> {
+        if polars_plan::plans::has_placeholders(expr_arena) {
+            return Ok(Self(PlanState::Unbound {
+                node,
+                ir_arena: ir_arena.clone(),
+                expr_arena: expr_arena.clone(),
+            }));
+        }
+
+        let mut phys_sm = SlotMap::with_capacity_and_key(ir_arena.len());
+        let root =
+            crate::physical_plan::build_physical_plan(node, ir_arena, expr_arena, &mut phys_sm)?;
+        Ok(Self(PlanState::Lowered {
+            root,
+            phys_sm,
+            expr_arena: expr_arena.clone(),
+        }))
+    }
+
+    /// Executes the plan, with the same output as [`run_query`].
+    pub fn run(&self, options: QueryOptions) -> PolarsResult<Result<DataFrame, Vec<DataFrame>>> {
+        match &self.0 {
+            PlanState::Lowered {
+                root,
+                phys_sm,
+                expr_arena,
+            } => {
+                // Converting the plan to a graph adds the expressions of the nodes to the arena.
+                let mut expr_arena = expr_arena.clone();
+                run_physical_plan(*root, phys_sm, &mut expr_arena, false, options, None)
+            },
+            PlanState::Unbound {
+                node,
+                ir_arena,
+                expr_arena,
+            } => run_query_impl(
+                *node,
+                &mut ir_arena.clone(),
+                &mut expr_arena.clone(),
+                false,
+                options,
+                None,
+            ),
+        }
+        .map(|(out, _)| out)
+    }
+}
+
+/// Renders the physical plan the streaming engine would run for the IR, without running it.
+///
+/// The graph shows the nodes with the pushdowns of the scans, the ports of the streams between
+/// them and whether each node runs in parallel, serially or on the in-memory engine.
+pub fn visualize_physical_plan(
+    node: Node,
+    ir_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+    format: PhysicalPlanFormat,
+) -> PolarsResult<String> {
+    let mut phys_sm = SlotMap::with_capacity_and_key(ir_arena.len());
+    let root = crate::physical_plan::build_physical_plan(node, ir_arena, expr_arena, &mut phys_sm)?;
+    Ok(crate::physical_plan::visualize_plan(
+        root, &phys_sm, expr_arena, format,
+    ))
+}
+
+fn run_query_impl(
+    node: Node,
+    ir_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+    collect_metrics: bool,
+    options: QueryOptions,
+    explain_analyzed: Option<&mut String>,
+) -> PolarsResult<(Result<DataFrame, Vec<DataFrame>>, Option<QueryMetrics>)> {
     if let Ok(visual_path) = std::env::var("POLARS_VISUALIZE_IR") {
         let plan = IRPlan {
             lp_top: node,
@@ -37,18 +297,59 @@ pub fn run_query(
         let visualization = plan.display_dot().to_string();
         std::fs::write(visual_path, visualization).unwrap();
     }
+    polars_plan::plans::bind_placeholders(expr_arena, &options.params)?;
     let mut phys_sm = SlotMap::with_capacity_and_key(ir_arena.len());
     let root = crate::physical_plan::build_physical_plan(node, ir_arena, expr_arena, &mut phys_sm)?;
+    run_physical_plan(
+        root,
+        &phys_sm,
+        expr_arena,
+        collect_metrics,
+        options,
+        explain_analyzed,
+    )
+}
+
+fn run_physical_plan(
+    root: PhysNodeKey,
+    phys_sm: &SlotMap<PhysNodeKey, PhysNode>,
+    expr_arena: &mut Arena<AExpr>,
+    collect_metrics: bool,
+    options: QueryOptions,
+    explain_analyzed: Option<&mut String>,
+) -> PolarsResult<(Result<DataFrame, Vec<DataFrame>>, Option<QueryMetrics>)> {
     if let Ok(visual_path) = std::env::var("POLARS_VISUALIZE_PHYSICAL_PLAN") {
-        let visualization = crate::physical_plan::visualize_plan(root, &phys_sm, expr_arena);
+        let visualization = crate::physical_plan::visualize_plan(
+            root,
+            phys_sm,
+            expr_arena,
+            PhysicalPlanFormat::Dot,
+        );
         std::fs::write(visual_path, visualization).unwrap();
     }
 
     let (mut graph, phys_to_graph) =
-        crate::physical_plan::physical_plan_to_graph(root, &phys_sm, expr_arena)?;
+        crate::physical_plan::physical_plan_to_graph(root, phys_sm, expr_arena)?;
 
     crate::async_executor::clear_task_wait_statistics();
-    let mut results = crate::execute::execute_graph(&mut graph)?;
+    let (mut results, metrics) =
+        crate::execute::execute_graph(&mut graph, collect_metrics, options)?;
+
+    if let (Some(explained), Some(metrics)) = (explain_analyzed, &metrics) {
+        // The metrics are in the order of the nodes of the graph.
+        let graph_idx: SecondaryMap<_, _> = graph.nodes.keys().zip(0..).collect();
+        let phys_metrics: SecondaryMap<_, _> = phys_to_graph
+            .iter()
+            .map(|(phys_key, graph_key)| (phys_key, metrics.nodes[graph_idx[*graph_key]].clone()))
+            .collect();
+        *explained = crate::physical_plan::explain_analyzed_plan(
+            root,
+            phys_sm,
+            expr_arena,
+            &phys_metrics,
+            metrics.wall_time,
+        );
+    }
 
     if std::env::var("POLARS_TRACK_WAIT_STATS").as_deref() == Ok("1") {
         let mut stats = crate::async_executor::get_task_wait_statistics();
@@ -59,24 +360,18 @@ pub fn run_query(
         }
     }
 
-    match ir_arena.get(node) {
-        IR::SinkMultiple { inputs } => {
-            let phys_node = &phys_sm[root];
-            let PhysNodeKind::SinkMultiple { sinks } = phys_node.kind() else {
-                unreachable!();
-            };
-
-            Ok(Err(sinks
-                .iter()
-                .map(|phys_node_key| {
-                    results
-                        .remove(phys_to_graph[*phys_node_key])
-                        .unwrap_or_else(DataFrame::empty)
-                })
-                .collect()))
-        },
-        _ => Ok(Ok(results
+    let out = match phys_sm[root].kind() {
+        PhysNodeKind::SinkMultiple { sinks } => Err(sinks
+            .iter()
+            .map(|phys_node_key| {
+                results
+                    .remove(phys_to_graph[*phys_node_key])
+                    .unwrap_or_else(DataFrame::empty)
+            })
+            .collect()),
+        _ => Ok(results
             .remove(phys_to_graph[root])
-            .unwrap_or_else(DataFrame::empty))),
-    }
+            .unwrap_or_else(DataFrame::empty)),
+    };
+    Ok((out, metrics))
 }
@@ -0,0 +1,57 @@
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+use crate::runtime;
+
+/// Tracks how long the oldest rows buffered by a node have been waiting, for the low-latency mode
+/// of [`QueryOptions::flush_deadline`](crate::QueryOptions::flush_deadline).
+///
+/// Without a budget the deadline never expires, so nodes only flush once their buffer is full.
+pub struct FlushDeadline {
+    budget: Option<Duration>,
+    deadline: Option<Instant>,
+}
+
+impl FlushDeadline {
+    pub fn new(budget: Option<Duration>) -> Self {
+        Self {
+            budget,
+            deadline: None,
+        }
+    }
+
+    /// Registers that rows were buffered, starting the clock if the buffer was empty.
+    pub fn buffered(&mut self) {
+        if self.deadline.is_none() {
+            self.deadline = self.budget.map(|budget| Instant::now() + budget);
+        }
+    }
+
+    /// Registers that the buffer was flushed.
+    pub fn flushed(&mut self) {
+        self.deadline = None;
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    /// Awaits `fut`, or returns `None` if the deadline expires first. `fut` must be cancel-safe,
+    /// e.g. receiving from a connector.
+    pub async fn race<T>(&self, fut: impl Future<Output = T>) -> Option<T> {
+        let Some(deadline) = self.deadline else {
+            return Some(fut.await);
+        };
+
+        let timer = runtime::sleep(deadline.saturating_duration_since(Instant::now()));
+        match futures::future::select(std::pin::pin!(fut), timer).await {
+            futures::future::Either::Left((out, timer)) => {
+                // Aborts the timer, instead of leaving it running until the deadline.
+                drop(timer);
+                Some(out)
+            },
+            futures::future::Either::Right(_) => None,
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use polars_error::{PolarsResult, polars_bail, polars_err};
+use polars_utils::pl_str::PlSmallStr;
+
+/// Reads the memory limit of a query in bytes from `POLARS_STREAMING_MEMORY_LIMIT`. This is read
+/// when a query starts, so every query can have a different limit.
+pub fn get_memory_limit() -> PolarsResult<Option<usize>> {
+    match std::env::var("POLARS_STREAMING_MEMORY_LIMIT") {
+        Ok(limit) => limit.parse().map(Some).map_err(|_| {
+            polars_err!(ComputeError: "could not parse 'POLARS_STREAMING_MEMORY_LIMIT' env var")
+        }),
+        Err(_) => Ok(None),
+    }
+}
+
+/// The memory the operators of a single query may buffer, shared by all of them.
+///
+/// This is accounted for with estimates of the size of the buffered data, not the actual
+/// allocations.
+pub struct MemoryBudget {
+    limit: usize,
+    used: AtomicUsize,
+}
+
+impl MemoryBudget {
+    pub fn new(limit: usize) -> Self {
+        Self {
+            limit,
+            used: AtomicUsize::new(0),
+        }
+    }
+
+    fn try_reserve(&self, bytes: usize) -> bool {
+        self.used
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |used| {
+                used.checked_add(bytes).filter(|used| *used <= self.limit)
+            })
+            .is_ok()
+    }
+
+    fn release(&self, bytes: usize) {
+        self.used.fetch_sub(bytes, Ordering::Relaxed);
+    }
+}
+
+/// The part of the [`MemoryBudget`] held by a single node, which is returned when dropped.
+///
+/// If the query has no budget every reservation succeeds.
+pub struct MemoryReservation {
+    budget: Option<Arc<MemoryBudget>>,
+    node: PlSmallStr,
+    bytes: AtomicUsize,
+}
+
+impl MemoryReservation {
+    pub fn new(budget: Option<Arc<MemoryBudget>>, node: &str) -> Self {
+        Self {
+            budget,
+            node: node.into(),
+            bytes: AtomicUsize::new(0),
+        }
+    }
+
+    /// Grows the reservation by `bytes`, returning false if that exceeds the budget.
+    pub fn try_grow(&self, bytes: usize) -> bool {
+        if let Some(budget) = &self.budget {
+            if !budget.try_reserve(bytes) {
+                return false;
+            }
+        }
+        self.bytes.fetch_add(bytes, Ordering::Relaxed);
+        true
+    }
+
+    /// Grows the reservation by `bytes`, failing the query if that exceeds the budget. Operators
+    /// which can spill should use [`MemoryReservation::try_grow`] instead.
+    pub fn grow(&self, bytes: usize) -> PolarsResult<()> {
+        if !self.try_grow(bytes) {
+            let limit = self.budget.as_ref().unwrap().limit;
+            polars_bail!(
+                ComputeError: "memory budget of {} bytes exceeded at node '{}'; \
+                raise POLARS_STREAMING_MEMORY_LIMIT or reduce the data held by this operation",
+                limit, self.node
+            );
+        }
+        Ok(())
+    }
+
+    pub fn shrink(&self, bytes: usize) {
+        self.bytes.fetch_sub(bytes, Ordering::Relaxed);
+        if let Some(budget) = &self.budget {
+            budget.release(bytes);
+        }
+    }
+}
+
+impl Drop for MemoryReservation {
+    fn drop(&mut self) {
+        if let Some(budget) = &self.budget {
+            budget.release(*self.bytes.get_mut());
+        }
+    }
+}
@@ -1,3 +1,6 @@
+pub mod flush_deadline;
 pub mod in_memory_linearize;
 pub mod late_materialized_df;
+pub mod memory_budget;
+pub mod spill;
 pub mod task_handles_ext;
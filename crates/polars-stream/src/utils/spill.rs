@@ -0,0 +1,125 @@
+use std::fs::File;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use parking_lot::Mutex;
+use polars_core::config::verbose;
+use polars_core::frame::DataFrame;
+use polars_core::prelude::CompatLevel;
+use polars_error::{PolarsResult, polars_err, to_compute_err};
+use polars_io::prelude::*;
+
+/// Reads the number of bytes an operator may buffer in memory before it spills to disk from
+/// `POLARS_STREAMING_SPILL_THRESHOLD`, defaulting to 1 GiB. This is read when a query starts,
+/// unless the query sets its own threshold in its options.
+pub fn get_spill_threshold() -> PolarsResult<usize> {
+    match std::env::var("POLARS_STREAMING_SPILL_THRESHOLD") {
+        Ok(threshold) => threshold.parse().map_err(|_| {
+            polars_err!(ComputeError: "could not parse 'POLARS_STREAMING_SPILL_THRESHOLD' env var")
+        }),
+        Err(_) => Ok(1 << 30),
+    }
+}
+
+static SPILL_DIR_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// A directory holding the spilled data of a single operator, removed when dropped.
+///
+/// The directory is only created once the first [`DataFrame`] is spilled. If the query has a
+/// temporary directory the directory is registered as one of its artifacts.
+pub struct SpillDir {
+    label: &'static str,
+    query_temp_dir: Option<Arc<QueryTempDir>>,
+    path: Mutex<Option<PathBuf>>,
+    num_files: AtomicUsize,
+    num_bytes: AtomicUsize,
+}
+
+impl SpillDir {
+    /// Must be called on the thread that runs the query, to pick up its temporary directory.
+    pub fn new(label: &'static str) -> Self {
+        Self {
+            label,
+            query_temp_dir: active_query_temp_dir(),
+            path: Mutex::new(None),
+            num_files: AtomicUsize::new(0),
+            num_bytes: AtomicUsize::new(0),
+        }
+    }
+
+    fn path(&self) -> PolarsResult<PathBuf> {
+        let mut path = self.path.lock();
+        if path.is_none() {
+            let dir = if let Some(query_dir) = &self.query_temp_dir {
+                query_dir.new_artifact(TempArtifactKind::Spill, self.label)?
+            } else {
+                let id = SPILL_DIR_COUNTER.fetch_add(1, Ordering::Relaxed);
+                POLARS_TEMP_DIR_BASE_PATH.join(format!(
+                    "stream-spill/{}-{id}-{}",
+                    std::process::id(),
+                    self.label
+                ))
+            };
+            std::fs::create_dir_all(&dir).map_err(to_compute_err)?;
+            *path = Some(dir);
+        }
+        Ok(path.clone().unwrap())
+    }
+
+    /// Writes the [`DataFrame`] to a new IPC file in this directory.
+    pub fn spill(&self, df: &mut DataFrame) -> PolarsResult<SpilledDataFrame> {
+        let file_idx = self.num_files.fetch_add(1, Ordering::Relaxed);
+        let file_path = self.path()?.join(format!("{file_idx}.ipc"));
+
+        let file = File::create(&file_path).map_err(to_compute_err)?;
+        IpcWriter::new(file)
+            .with_compat_level(CompatLevel::newest())
+            .finish(df)?;
+        let file_size = std::fs::metadata(&file_path).map_err(to_compute_err)?.len();
+        self.num_bytes
+            .fetch_add(file_size as usize, Ordering::Relaxed);
+        if verbose() {
+            eprintln!(
+                "[{}]: spilled {} rows ({file_size} bytes) to {}",
+                self.label,
+                df.height(),
+                file_path.display()
+            );
+        }
+        Ok(SpilledDataFrame { path: file_path })
+    }
+
+    /// The total size of the files spilled to this directory, including the ones that were
+    /// loaded again.
+    pub fn spilled_bytes(&self) -> usize {
+        self.num_bytes.load(Ordering::Relaxed)
+    }
+}
+
+impl Drop for SpillDir {
+    fn drop(&mut self) {
+        if let Some(path) = self.path.get_mut() {
+            let _ = std::fs::remove_dir_all(path);
+        }
+    }
+}
+
+/// A [`DataFrame`] that was written to disk by a [`SpillDir`].
+pub struct SpilledDataFrame {
+    path: PathBuf,
+}
+
+impl SpilledDataFrame {
+    /// Reads the [`DataFrame`] back into memory, removing the file.
+    pub fn load(self) -> PolarsResult<DataFrame> {
+        let file = File::open(&self.path).map_err(to_compute_err)?;
+        IpcReader::new(file).set_rechunk(false).finish()
+    }
+}
+
+impl Drop for SpilledDataFrame {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
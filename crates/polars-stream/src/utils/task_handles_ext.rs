@@ -2,11 +2,13 @@ use std::future::Future;
 use std::pin::Pin;
 use std::task::{Context, Poll};
 
-/// Calls [`tokio::task::JoinHandle::abort`] on the join handle when dropped.
-pub struct AbortOnDropHandle<T>(pub tokio::task::JoinHandle<T>);
+use crate::runtime::{Cancelled, JoinHandle};
+
+/// Calls [`JoinHandle::abort`] on the join handle when dropped.
+pub(crate) struct AbortOnDropHandle<T>(pub(crate) JoinHandle<T>);
 
 impl<T> Future for AbortOnDropHandle<T> {
-    type Output = Result<T, tokio::task::JoinError>;
+    type Output = Result<T, Cancelled>;
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
         Pin::new(&mut self.0).poll(cx)
@@ -0,0 +1,81 @@
+use std::sync::{LazyLock, RwLock};
+
+use polars_core::prelude::*;
+
+/// A named business day calendar.
+///
+/// Used to resolve the `week_mask` and `holidays` arguments of the business day functions,
+/// so that a calendar only has to be constructed once per process.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BusinessCalendar {
+    /// A boolean array of length 7, where `true` indicates that the day is a business day.
+    pub week_mask: [bool; 7],
+    /// Holidays as the number of days since the UNIX epoch, sorted and deduplicated.
+    pub holidays: Vec<i32>,
+}
+
+impl BusinessCalendar {
+    pub fn new(week_mask: [bool; 7], mut holidays: Vec<i32>) -> PolarsResult<Self> {
+        polars_ensure!(
+            week_mask.iter().any(|&x| x),
+            ComputeError: "`week_mask` must have at least one business day"
+        );
+        holidays.sort_unstable();
+        holidays.dedup();
+        Ok(Self {
+            week_mask,
+            holidays,
+        })
+    }
+}
+
+static BUSINESS_CALENDARS: LazyLock<RwLock<PlHashMap<PlSmallStr, BusinessCalendar>>> =
+    LazyLock::new(Default::default);
+
+/// Register a calendar under `name`, replacing any calendar previously registered under it.
+pub fn register_business_calendar(name: PlSmallStr, calendar: BusinessCalendar) {
+    BUSINESS_CALENDARS.write().unwrap().insert(name, calendar);
+}
+
+/// Remove the calendar registered under `name`. Returns whether it existed.
+pub fn unregister_business_calendar(name: &str) -> bool {
+    BUSINESS_CALENDARS.write().unwrap().remove(name).is_some()
+}
+
+/// Get the calendar registered under `name`.
+pub fn get_business_calendar(name: &str) -> PolarsResult<BusinessCalendar> {
+    let calendars = BUSINESS_CALENDARS.read().unwrap();
+    match calendars.get(name) {
+        Some(calendar) => Ok(calendar.clone()),
+        None => polars_bail!(ComputeError: "business calendar '{}' is not registered", name),
+    }
+}
+
+/// The names of all registered calendars, sorted.
+pub fn business_calendar_names() -> Vec<PlSmallStr> {
+    let mut names: Vec<_> = BUSINESS_CALENDARS.read().unwrap().keys().cloned().collect();
+    names.sort_unstable();
+    names
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_business_calendar_registry() {
+        let week_mask = [true, true, true, true, true, false, false];
+        let calendar = BusinessCalendar::new(week_mask, vec![19725, 19358, 19725]).unwrap();
+        assert_eq!(calendar.holidays, &[19358, 19725]);
+
+        register_business_calendar("test-registry".into(), calendar.clone());
+        assert_eq!(get_business_calendar("test-registry").unwrap(), calendar);
+        assert!(business_calendar_names().contains(&"test-registry".into()));
+
+        assert!(unregister_business_calendar("test-registry"));
+        assert!(!unregister_business_calendar("test-registry"));
+        assert!(get_business_calendar("test-registry").is_err());
+
+        assert!(BusinessCalendar::new([false; 7], vec![]).is_err());
+    }
+}
@@ -0,0 +1,211 @@
+//! Parsing of duration strings.
+//!
+//! Both ISO 8601 durations (`P1DT2H3M4.5S`) and the format Polars uses to display
+//! durations (`1d 2h 3m 4s 500ms`) are accepted, so that `to_string("iso")` and
+//! `to_string("polars")` round-trip.
+use polars_core::prelude::TimeUnit;
+
+const NS_PER_SEC: i128 = 1_000_000_000;
+const NS_PER_MIN: i128 = 60 * NS_PER_SEC;
+const NS_PER_HOUR: i128 = 60 * NS_PER_MIN;
+const NS_PER_DAY: i128 = 24 * NS_PER_HOUR;
+const NS_PER_WEEK: i128 = 7 * NS_PER_DAY;
+
+/// Parse `[0-9]+([.,][0-9]+)?` and scale it by `size`, truncating sub-nanosecond precision.
+fn parse_scaled_number(s: &[u8], i: &mut usize, size: impl Fn(u8) -> Option<i128>) -> Option<i128> {
+    let start = *i;
+    let mut int: i128 = 0;
+    while let Some(d) = s.get(*i).filter(|c| c.is_ascii_digit()) {
+        int = int.checked_mul(10)?.checked_add((d - b'0') as i128)?;
+        *i += 1;
+    }
+    if *i == start {
+        return None;
+    }
+
+    let mut frac: i128 = 0;
+    let mut frac_scale: i128 = 1;
+    if matches!(s.get(*i), Some(b'.' | b',')) {
+        *i += 1;
+        let frac_start = *i;
+        while let Some(d) = s.get(*i).filter(|c| c.is_ascii_digit()) {
+            // Anything beyond nanosecond precision of a week is dropped.
+            if frac_scale < 10i128.pow(15) {
+                frac = frac * 10 + (d - b'0') as i128;
+                frac_scale *= 10;
+            }
+            *i += 1;
+        }
+        if *i == frac_start {
+            return None;
+        }
+    }
+
+    let size = size(*s.get(*i)?)?;
+    *i += 1;
+    int.checked_mul(size)?.checked_add(frac * size / frac_scale)
+}
+
+/// Parse the part of an ISO 8601 duration after the `P`, in nanoseconds.
+fn parse_iso_duration(s: &[u8]) -> Option<i128> {
+    let mut total: i128 = 0;
+    let mut i = 0;
+    let mut in_time = false;
+    let mut n_parts = 0;
+    while i < s.len() {
+        if s[i] == b'T' {
+            if in_time {
+                return None;
+            }
+            in_time = true;
+            n_parts = 0;
+            i += 1;
+            continue;
+        }
+        // Years and months are not supported as they don't have a fixed length.
+        let part = parse_scaled_number(s, &mut i, |unit| match (in_time, unit) {
+            (false, b'W') => Some(NS_PER_WEEK),
+            (false, b'D') => Some(NS_PER_DAY),
+            (true, b'H') => Some(NS_PER_HOUR),
+            (true, b'M') => Some(NS_PER_MIN),
+            (true, b'S') => Some(NS_PER_SEC),
+            _ => None,
+        })?;
+        total = total.checked_add(part)?;
+        n_parts += 1;
+    }
+    // `P` and `T` must both be followed by at least one part.
+    (n_parts > 0).then_some(total)
+}
+
+/// Parse a duration as displayed by Polars, e.g. `1d 2h 3m 4s 500ms`, in nanoseconds.
+fn parse_polars_duration(s: &str) -> Option<i128> {
+    let b = s.as_bytes();
+    let mut total: i128 = 0;
+    let mut i = 0;
+    let mut n_parts = 0;
+    loop {
+        while b.get(i).is_some_and(|c| c.is_ascii_whitespace()) {
+            i += 1;
+        }
+        if i == b.len() {
+            break;
+        }
+        let negative = b[i] == b'-';
+        if negative {
+            i += 1;
+        }
+        let mut value: i128 = 0;
+        let digits_start = i;
+        while let Some(d) = b.get(i).filter(|c| c.is_ascii_digit()) {
+            value = value.checked_mul(10)?.checked_add((d - b'0') as i128)?;
+            i += 1;
+        }
+        let unit_start = i;
+        while b
+            .get(i)
+            .is_some_and(|c| !(c.is_ascii_digit() || c.is_ascii_whitespace() || *c == b'-'))
+        {
+            i += 1;
+        }
+        if digits_start == unit_start || unit_start == i {
+            return None;
+        }
+        let size = match &s[unit_start..i] {
+            "w" => NS_PER_WEEK,
+            "d" => NS_PER_DAY,
+            "h" => NS_PER_HOUR,
+            "m" => NS_PER_MIN,
+            "s" => NS_PER_SEC,
+            "ms" => 1_000_000,
+            "us" | "µs" => 1_000,
+            "ns" => 1,
+            _ => return None,
+        };
+        let part = value.checked_mul(size)?;
+        total = if negative {
+            total.checked_sub(part)?
+        } else {
+            total.checked_add(part)?
+        };
+        n_parts += 1;
+    }
+    (n_parts > 0).then_some(total)
+}
+
+/// Parse an ISO 8601 duration or a duration as displayed by Polars into a number of
+/// `time_unit`s. Returns `None` if the string is not a valid duration or out of range.
+pub fn parse_duration_string(s: &str, time_unit: TimeUnit) -> Option<i64> {
+    let s = s.trim();
+    let (negative, unsigned) = match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+    let ns = match unsigned.strip_prefix('P') {
+        Some(iso) => {
+            let ns = parse_iso_duration(iso.as_bytes())?;
+            if negative { -ns } else { ns }
+        },
+        None => parse_polars_duration(s)?,
+    };
+    let value = match time_unit {
+        TimeUnit::Nanoseconds => ns,
+        TimeUnit::Microseconds => ns / 1_000,
+        TimeUnit::Milliseconds => ns / 1_000_000,
+    };
+    i64::try_from(value).ok()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_iso_duration() {
+        let parse = |s| parse_duration_string(s, TimeUnit::Milliseconds);
+        assert_eq!(parse("PT0S"), Some(0));
+        assert_eq!(parse("P1D"), Some(86_400_000));
+        assert_eq!(parse("P1W"), Some(7 * 86_400_000));
+        assert_eq!(parse("P1DT2H3M4.5S"), Some(93_784_500));
+        assert_eq!(parse("PT1,5M"), Some(90_000));
+        assert_eq!(parse("PT0.25H"), Some(900_000));
+        assert_eq!(parse("-PT1M"), Some(-60_000));
+        assert_eq!(
+            parse_duration_string("PT0.000000001S", TimeUnit::Nanoseconds),
+            Some(1)
+        );
+
+        for invalid in [
+            "P", "PT", "P1DT", "P1Y", "P1M", "PT1D", "P1.D", "1D", "PT1H1",
+        ] {
+            assert_eq!(parse(invalid), None, "{invalid}");
+        }
+    }
+
+    #[test]
+    fn test_parse_polars_duration() {
+        let parse = |s| parse_duration_string(s, TimeUnit::Microseconds);
+        assert_eq!(parse("0µs"), Some(0));
+        assert_eq!(parse("1d 2h 3m 4s 5ms 6µs"), Some(93_784_005_006));
+        assert_eq!(parse("1h30m"), Some(5_400_000_000));
+        assert_eq!(
+            parse("-3d -22m"),
+            Some(-(3 * 86_400_000_000 + 22 * 60_000_000))
+        );
+        assert_eq!(parse("1500ns"), Some(1));
+
+        for invalid in ["", "1", "d", "1x", "1 d", "1.5d"] {
+            assert_eq!(parse(invalid), None, "{invalid}");
+        }
+    }
+
+    #[test]
+    fn test_parse_duration_out_of_range() {
+        assert_eq!(
+            parse_duration_string("P1000000D", TimeUnit::Nanoseconds),
+            None
+        );
+        assert!(parse_duration_string("P1000000D", TimeUnit::Microseconds).is_some());
+    }
+}
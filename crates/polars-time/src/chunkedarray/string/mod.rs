@@ -1,5 +1,9 @@
+#[cfg(feature = "dtype-duration")]
+mod duration;
 pub mod infer;
 use chrono::DateTime;
+#[cfg(feature = "dtype-duration")]
+pub use duration::parse_duration_string;
 mod patterns;
 mod strptime;
 use chrono::ParseError;
@@ -90,6 +94,17 @@ fn sniff_fmt_time(ca_string: &StringChunked) -> PolarsResult<&'static str> {
 }
 
 pub trait StringMethods: AsString {
+    #[cfg(feature = "dtype-duration")]
+    /// Parse ISO 8601 durations (e.g. `P1DT2H`) or durations as displayed by Polars
+    /// (e.g. `1d 2h`) and return a [`DurationChunked`]. Invalid values become null.
+    fn as_duration(&self, time_unit: TimeUnit) -> DurationChunked {
+        let string_ca = self.as_string();
+        let ca: Int64Chunked =
+            unary_elementwise(string_ca, |opt_s| parse_duration_string(opt_s?, time_unit));
+        ca.with_name(string_ca.name().clone())
+            .into_duration(time_unit)
+    }
+
     #[cfg(feature = "dtype-time")]
     /// Parsing string values and return a [`TimeChunked`]
     fn as_time(&self, fmt: Option<&str>, use_cache: bool) -> PolarsResult<TimeChunked> {
@@ -1,6 +1,8 @@
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 #[cfg(feature = "timezones")]
 mod base_utc_offset;
+#[cfg(feature = "business")]
+mod calendar;
 pub mod chunkedarray;
 mod date_range;
 #[cfg(feature = "timezones")]
@@ -24,6 +26,8 @@ mod windows;
 
 #[cfg(feature = "timezones")]
 pub use base_utc_offset::*;
+#[cfg(feature = "business")]
+pub use calendar::*;
 pub use date_range::*;
 #[cfg(feature = "timezones")]
 pub use dst_offset::*;
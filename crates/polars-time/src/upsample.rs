@@ -6,6 +6,20 @@ use polars_ops::series::SeriesMethods;
 
 use crate::prelude::*;
 
+/// How to fill the nulls that upsampling introduces in a column.
+#[derive(Clone, Debug, PartialEq)]
+pub enum UpsampleFill {
+    /// Carry the last observed value forward.
+    Forward,
+    /// Carry the next observed value backward.
+    Backward,
+    /// Linearly interpolate between the surrounding observed values.
+    #[cfg(feature = "interpolate")]
+    Interpolate,
+    /// Fill with a constant value.
+    Constant(Scalar),
+}
+
 pub trait PolarsUpsample {
     /// Upsample a [`DataFrame`] at a regular frequency.
     ///
@@ -85,6 +99,23 @@ pub trait PolarsUpsample {
         time_column: &str,
         every: Duration,
     ) -> PolarsResult<DataFrame>;
+
+    /// Upsample a [`DataFrame`] at a regular frequency and fill the introduced nulls.
+    ///
+    /// Every `(column, strategy)` pair in `fill` is applied to the upsampled rows of each
+    /// group, so the values never leak across groups. Columns that are not listed keep
+    /// their nulls.
+    ///
+    /// See [`upsample`][PolarsUpsample::upsample] for the other arguments. If `stable`
+    /// is set, the order of the groups is maintained.
+    fn upsample_with_fill<I: IntoVec<PlSmallStr>>(
+        &self,
+        by: I,
+        time_column: &str,
+        every: Duration,
+        fill: &[(PlSmallStr, UpsampleFill)],
+        stable: bool,
+    ) -> PolarsResult<DataFrame>;
 }
 
 impl PolarsUpsample for DataFrame {
@@ -97,7 +128,7 @@ impl PolarsUpsample for DataFrame {
         let by = by.into_vec();
         let time_type = self.column(time_column)?.dtype();
         ensure_duration_matches_dtype(every, time_type, "every")?;
-        upsample_impl(self, by, time_column, every, false)
+        upsample_impl(self, by, time_column, every, &[], false)
     }
 
     fn upsample_stable<I: IntoVec<PlSmallStr>>(
@@ -109,7 +140,28 @@ impl PolarsUpsample for DataFrame {
         let by = by.into_vec();
         let time_type = self.column(time_column)?.dtype();
         ensure_duration_matches_dtype(every, time_type, "every")?;
-        upsample_impl(self, by, time_column, every, true)
+        upsample_impl(self, by, time_column, every, &[], true)
+    }
+
+    fn upsample_with_fill<I: IntoVec<PlSmallStr>>(
+        &self,
+        by: I,
+        time_column: &str,
+        every: Duration,
+        fill: &[(PlSmallStr, UpsampleFill)],
+        stable: bool,
+    ) -> PolarsResult<DataFrame> {
+        let by = by.into_vec();
+        let time_type = self.column(time_column)?.dtype();
+        ensure_duration_matches_dtype(every, time_type, "every")?;
+        for (name, _) in fill {
+            polars_ensure!(
+                name != time_column,
+                InvalidOperation: "cannot fill the upsample time column '{}'", name
+            );
+            self.column(name)?;
+        }
+        upsample_impl(self, by, time_column, every, fill, stable)
     }
 }
 
@@ -118,6 +170,7 @@ fn upsample_impl(
     by: Vec<PlSmallStr>,
     index_column: &str,
     every: Duration,
+    fill: &[(PlSmallStr, UpsampleFill)],
     stable: bool,
 ) -> PolarsResult<DataFrame> {
     let s = source.column(index_column)?;
@@ -129,7 +182,7 @@ fn upsample_impl(
                 .unwrap()
         })
         .unwrap();
-        let mut out = upsample_impl(&df, by, index_column, every, stable)?;
+        let mut out = upsample_impl(&df, by, index_column, every, fill, stable)?;
         out.apply(index_column, |s| s.cast(time_type).unwrap())
             .unwrap();
         Ok(out)
@@ -146,7 +199,7 @@ fn upsample_impl(
                 .unwrap()
         })
         .unwrap();
-        let mut out = upsample_impl(&df, by, index_column, every, stable)?;
+        let mut out = upsample_impl(&df, by, index_column, every, fill, stable)?;
         out.apply(index_column, |s| s.cast(time_type).unwrap())
             .unwrap();
         Ok(out)
@@ -157,13 +210,13 @@ fn upsample_impl(
                 .unwrap()
         })
         .unwrap();
-        let mut out = upsample_impl(&df, by, index_column, every, stable)?;
+        let mut out = upsample_impl(&df, by, index_column, every, fill, stable)?;
         out.apply(index_column, |s| s.cast(time_type).unwrap())
             .unwrap();
         Ok(out)
     } else if by.is_empty() {
         let index_column = source.column(index_column)?;
-        upsample_single_impl(source, index_column.as_materialized_series(), every)
+        upsample_single_impl(source, index_column.as_materialized_series(), every, fill)
     } else {
         let gb = if stable {
             source.group_by_stable(by)
@@ -173,7 +226,7 @@ fn upsample_impl(
         // don't parallelize this, this may SO on large data.
         gb?.apply(|df| {
             let index_column = df.column(index_column)?;
-            upsample_single_impl(&df, index_column.as_materialized_series(), every)
+            upsample_single_impl(&df, index_column.as_materialized_series(), every, fill)
         })
     }
 }
@@ -182,6 +235,7 @@ fn upsample_single_impl(
     source: &DataFrame,
     index_column: &Series,
     every: Duration,
+    fill: &[(PlSmallStr, UpsampleFill)],
 ) -> PolarsResult<DataFrame> {
     index_column.ensure_sorted_arg("upsample")?;
     let index_col_name = index_column.name();
@@ -211,13 +265,19 @@ fn upsample_single_impl(
                     )?
                     .into_series()
                     .into_frame();
-                    range.join(
+                    let mut out = range.join(
                         source,
                         [index_col_name.clone()],
                         [index_col_name.clone()],
                         JoinArgs::new(JoinType::Left),
                         None,
-                    )
+                    )?;
+                    for (name, strategy) in fill {
+                        let s = out.column(name)?.as_materialized_series();
+                        let filled = fill_upsampled(s, strategy)?;
+                        out.with_column(filled)?;
+                    }
+                    Ok(out)
                 },
                 _ => polars_bail!(
                     ComputeError: "cannot determine upsample boundaries: all elements are null"
@@ -229,3 +289,20 @@ fn upsample_single_impl(
         ),
     }
 }
+
+fn fill_upsampled(s: &Series, strategy: &UpsampleFill) -> PolarsResult<Series> {
+    match strategy {
+        UpsampleFill::Forward => s.fill_null(FillNullStrategy::Forward(None)),
+        UpsampleFill::Backward => s.fill_null(FillNullStrategy::Backward(None)),
+        #[cfg(feature = "interpolate")]
+        UpsampleFill::Interpolate => Ok(interpolate(s, InterpolationMethod::Linear)),
+        UpsampleFill::Constant(value) => {
+            let value = value
+                .clone()
+                .into_series(s.name().clone())
+                .strict_cast(s.dtype())?
+                .new_from_index(0, s.len());
+            s.zip_with(&s.is_not_null(), &value)
+        },
+    }
+}
@@ -0,0 +1,123 @@
+use polars::prelude::*;
+
+fn target_schema() -> Schema {
+    Schema::from_iter([
+        Field::new("a".into(), DataType::Int8),
+        Field::new("b".into(), DataType::String),
+    ])
+}
+
+#[test]
+fn test_cast_with_policy_strict() -> PolarsResult<()> {
+    let df = df!["b" => ["x", "y"], "a" => [1i64, 2]]?;
+    let out = df.cast_with_policy(&target_schema(), CastPolicy::default())?;
+    assert!(out.equals(&df!["a" => [1i8, 2], "b" => ["x", "y"]]?));
+
+    let policy = CastPolicy::default();
+    let extra = df!["a" => [1i64], "b" => ["x"], "c" => [1]]?;
+    assert!(extra.cast_with_policy(&target_schema(), policy).is_err());
+    let missing = df!["a" => [1i64]]?;
+    assert!(missing.cast_with_policy(&target_schema(), policy).is_err());
+    let lossy = df!["a" => [1i64, 300], "b" => ["x", "y"]]?;
+    assert!(lossy.cast_with_policy(&target_schema(), policy).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_cast_with_policy_lenient() -> PolarsResult<()> {
+    let df = df!["a" => [-300i64, 1, 300], "c" => [1, 2, 3]]?;
+    let policy = CastPolicy::default()
+        .with_missing_columns(MissingColumns::Insert)
+        .with_extra_columns(ExtraColumns::Drop);
+
+    let out = df.cast_with_policy(&target_schema(), policy.with_lossy_casts(LossyCasts::Null))?;
+    let expected = df![
+        "a" => [None, Some(1i8), None],
+        "b" => [None::<&str>, None, None],
+    ]?;
+    assert!(out.equals_missing(&expected));
+
+    let out = df.cast_with_policy(
+        &target_schema(),
+        policy.with_lossy_casts(LossyCasts::Saturate),
+    )?;
+    assert_eq!(
+        out.column("a")?.i8()?.to_vec(),
+        &[Some(-128), Some(1), Some(127)]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_cast_with_policy_saturate_float() -> PolarsResult<()> {
+    let df = df!["a" => [-1.5f64, 0.5, 1e10], "b" => ["x", "y", "z"]]?;
+    let schema = Schema::from_iter([
+        Field::new("a".into(), DataType::UInt16),
+        Field::new("b".into(), DataType::String),
+    ]);
+    let policy = CastPolicy::default().with_lossy_casts(LossyCasts::Saturate);
+    let out = df.cast_with_policy(&schema, policy)?;
+    assert_eq!(
+        out.column("a")?.u16()?.to_vec(),
+        &[Some(0), Some(0), Some(u16::MAX)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "lazy", feature = "parquet"))]
+fn test_scan_parquet_cast_policy() -> PolarsResult<()> {
+    let dir = std::env::temp_dir().join("polars-test-scan-parquet-cast-policy");
+    std::fs::create_dir_all(&dir)?;
+    // The files disagree with each other and the target schema on the data types and columns.
+    let mut first = df!["a" => [1i64, 300], "b" => ["x", "y"], "c" => [1, 2]]?;
+    let mut second = df!["a" => [3i16]]?;
+    ParquetWriter::new(std::fs::File::create(dir.join("0.parquet"))?).finish(&mut first)?;
+    ParquetWriter::new(std::fs::File::create(dir.join("1.parquet"))?).finish(&mut second)?;
+
+    let scan = |policy: CastPolicy| {
+        let args = ScanArgsParquet {
+            schema: Some(Arc::new(target_schema())),
+            cast_policy: Some(policy),
+            ..Default::default()
+        };
+        LazyFrame::scan_parquet(dir.join("*.parquet").to_str().unwrap(), args)
+    };
+
+    let policy = CastPolicy::default()
+        .with_missing_columns(MissingColumns::Insert)
+        .with_extra_columns(ExtraColumns::Drop)
+        .with_lossy_casts(LossyCasts::Null);
+    let expected = df![
+        "a" => [Some(1i8), None, Some(3)],
+        "b" => [Some("x"), Some("y"), None],
+    ]?;
+    let out = scan(policy)?.collect()?;
+    assert!(out.equals_missing(&expected));
+    #[cfg(feature = "new_streaming")]
+    {
+        let out = scan(policy)?.collect_with_engine(Engine::Streaming)?;
+        assert!(out.equals_missing(&expected));
+    }
+
+    // Predicates compare the values after they are cast.
+    let expected = df!["a" => [None::<i8>], "b" => ["y"]]?;
+    let out = scan(policy)?.filter(col("a").is_null()).collect()?;
+    assert!(out.equals_missing(&expected));
+    #[cfg(feature = "new_streaming")]
+    {
+        let out = scan(policy)?
+            .filter(col("a").is_null())
+            .collect_with_engine(Engine::Streaming)?;
+        assert!(out.equals_missing(&expected));
+    }
+
+    assert!(scan(CastPolicy::default())?.collect().is_err());
+    #[cfg(feature = "new_streaming")]
+    assert!(
+        scan(CastPolicy::default())?
+            .collect_with_engine(Engine::Streaming)
+            .is_err()
+    );
+    Ok(())
+}
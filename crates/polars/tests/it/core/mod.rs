@@ -1,3 +1,5 @@
+#[cfg(feature = "cast_policy")]
+mod cast_policy;
 mod date_like;
 mod group_by;
 mod joins;
@@ -8,6 +10,8 @@ mod pivot;
 #[cfg(feature = "rolling_window")]
 mod rolling_window;
 mod series;
+#[cfg(feature = "derive")]
+mod typed_rows;
 mod utils;
 
 use polars::prelude::*;
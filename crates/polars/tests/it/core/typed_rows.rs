@@ -0,0 +1,138 @@
+use chrono::NaiveDate;
+
+use super::*;
+
+#[derive(Debug, PartialEq, IntoDataFrame, FromDataFrame)]
+struct Trade {
+    #[polars(rename = "sym")]
+    symbol: String,
+    price: f64,
+    size: Option<i64>,
+    fills: Vec<u32>,
+    day: NaiveDate,
+}
+
+fn trades() -> Vec<Trade> {
+    vec![
+        Trade {
+            symbol: "AAPL".into(),
+            price: 1.5,
+            size: Some(10),
+            fills: vec![4, 6],
+            day: NaiveDate::from_ymd_opt(2024, 1, 2).unwrap(),
+        },
+        Trade {
+            symbol: "MSFT".into(),
+            price: 2.5,
+            size: None,
+            fills: vec![],
+            day: NaiveDate::from_ymd_opt(1969, 12, 31).unwrap(),
+        },
+    ]
+}
+
+#[test]
+fn test_derive_rows_round_trip() -> PolarsResult<()> {
+    let df = Trade::into_df(trades())?;
+    assert_eq!(**df.schema(), Trade::schema());
+    assert_eq!(
+        df.get_column_names_str(),
+        &["sym", "price", "size", "fills", "day"]
+    );
+    assert_eq!(df.column("day")?.dtype(), &DataType::Date);
+    assert_eq!(df.column("size")?.null_count(), 1);
+    assert_eq!(Trade::from_df(&df)?, trades());
+
+    let mut builder = TradeRowsBuilder::default();
+    for trade in trades() {
+        builder.push(trade);
+    }
+    assert_eq!(builder.len(), 2);
+    assert!(builder.finish()?.equals_missing(&df));
+    Ok(())
+}
+
+#[test]
+fn test_derive_rows_from_df() -> PolarsResult<()> {
+    // Columns are looked up by name and cast to the field types.
+    let df = df![
+        "day" => [NaiveDate::from_ymd_opt(2024, 1, 2).unwrap()],
+        "fills" => [Series::new("".into(), [1i64, 2])],
+        "size" => [Some(3i32)],
+        "price" => [2i32],
+        "sym" => ["A"],
+        "extra" => [true],
+    ]?;
+    let trades = Trade::from_df(&df)?;
+    assert_eq!(trades[0].fills, vec![1, 2]);
+    assert_eq!(trades[0].price, 2.0);
+    assert_eq!(trades[0].size, Some(3));
+
+    // Nulls can only be extracted into `Option` fields.
+    let mut df = df;
+    df.with_column(Series::new_null("price".into(), 1))?;
+    assert!(Trade::from_df(&df).is_err());
+    assert!(Trade::from_df(&df.drop("sym")?).is_err());
+    Ok(())
+}
+
+#[cfg(all(feature = "time", feature = "dtype-datetime", feature = "dtype-time"))]
+#[derive(Clone, Debug, PartialEq, IntoDataFrame, FromDataFrame)]
+struct Event {
+    day: time_crate::Date,
+    at: time_crate::PrimitiveDateTime,
+    clock: time_crate::Time,
+}
+
+#[test]
+#[cfg(all(feature = "time", feature = "dtype-datetime", feature = "dtype-time"))]
+fn test_derive_rows_time_round_trip() -> PolarsResult<()> {
+    use time_crate::{Date, Month, PrimitiveDateTime, Time};
+
+    let new_year = Date::from_calendar_date(2024, Month::January, 2).unwrap();
+    let new_years_eve = Date::from_calendar_date(1969, Month::December, 31).unwrap();
+    let events = vec![
+        Event {
+            day: new_year,
+            at: PrimitiveDateTime::new(new_year, Time::from_hms_micro(3, 4, 5, 6).unwrap()),
+            clock: Time::from_hms_nano(23, 59, 59, 999_999_999).unwrap(),
+        },
+        Event {
+            day: new_years_eve,
+            at: PrimitiveDateTime::new(
+                new_years_eve,
+                Time::from_hms_micro(23, 59, 59, 999_999).unwrap(),
+            ),
+            clock: Time::MIDNIGHT,
+        },
+    ];
+    let df = Event::into_df(events.clone())?;
+    assert_eq!(df.column("day")?.dtype(), &DataType::Date);
+    assert_eq!(
+        df.column("at")?.dtype(),
+        &DataType::Datetime(TimeUnit::Microseconds, None)
+    );
+    assert_eq!(df.column("clock")?.dtype(), &DataType::Time);
+
+    // The physical values match those of the `chrono` types.
+    let day = df.column("day")?.to_physical_repr();
+    assert_eq!(day.i32()?.get(1), Some(-1));
+    let at = df.column("at")?.to_physical_repr();
+    assert_eq!(at.i64()?.get(1), Some(-1));
+    let clock = df.column("clock")?.to_physical_repr();
+    assert_eq!(clock.i64()?.get(0), Some(86_399_999_999_999));
+    assert_eq!(Event::from_df(&df)?, events);
+
+    // Sub-microsecond values are floored, also before the epoch.
+    let at = PrimitiveDateTime::new(
+        new_years_eve,
+        Time::from_hms_nano(23, 59, 59, 999_999_500).unwrap(),
+    );
+    let df = Event::into_df(vec![Event {
+        at,
+        ..events[1].clone()
+    }])?;
+    assert_eq!(df.column("at")?.to_physical_repr().i64()?.get(0), Some(-1));
+    assert_eq!(Event::from_df(&df)?, events[1..]);
+    Ok(())
+}
@@ -206,3 +206,65 @@ fn test_vstack_empty_3220() -> PolarsResult<()> {
     assert!(stacked.equals(&read_df));
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "dtype-extension")]
+fn test_extension_type_round_trip() -> PolarsResult<()> {
+    #[derive(Debug)]
+    struct Tagged {
+        storage: DataType,
+        tag: String,
+    }
+
+    impl PolarsExtensionType for Tagged {
+        fn name(&self) -> &str {
+            "test.parquet_tagged"
+        }
+
+        fn storage(&self) -> &DataType {
+            &self.storage
+        }
+
+        fn metadata(&self) -> Option<String> {
+            Some(self.tag.clone())
+        }
+    }
+
+    // The tag is `<storage>:<tag>`.
+    register_extension_type(
+        "test.parquet_tagged",
+        Arc::new(|metadata| {
+            let (storage, tag) = metadata.unwrap_or_default().split_once(':').unwrap();
+            let storage = match storage {
+                "i64" => DataType::Int64,
+                _ => DataType::String,
+            };
+            let tag = tag.to_string();
+            Ok(Arc::new(Tagged { storage, tag }) as ExtensionTypeRef)
+        }),
+    );
+    let tagged = |storage: Series, metadata: &str| -> PolarsResult<Column> {
+        let ext = get_extension_type("test.parquet_tagged", Some(metadata)).unwrap()?;
+        Ok(ExtensionSeries::new(ext, storage)?.into_series().into())
+    };
+
+    let mut df = DataFrame::new(vec![
+        tagged(
+            Series::new("a".into(), [Some(1i64), None, Some(3)]),
+            "i64:cents",
+        )?,
+        tagged(Series::new("b".into(), ["x", "y", "z"]), "str:code")?,
+    ])?;
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf).finish(&mut df)?;
+    let read_df = ParquetReader::new(buf).finish()?;
+
+    // Extension types are equal if their names and metadata are.
+    assert_eq!(read_df.schema(), df.schema());
+    for (read, written) in read_df.get_columns().iter().zip(df.get_columns()) {
+        let read = read.as_materialized_series().to_physical_repr();
+        let written = written.as_materialized_series().to_physical_repr();
+        assert!(read.equals_missing(&written));
+    }
+    Ok(())
+}
@@ -58,8 +58,10 @@ from polars.datatypes import (
     Duration,
     Enum,
     Field,
+    Float16,
     Float32,
     Float64,
+    Geometry,
     Int8,
     Int16,
     Int32,
@@ -89,8 +91,14 @@ from polars.functions import (
     arange,
     arctan2,
     arctan2d,
+    arg_max_by,
+    arg_min_by,
     arg_sort_by,
     arg_where,
+    bitwise_and_horizontal,
+    bitwise_or_horizontal,
+    bitwise_xor_horizontal,
+    business_calendars,
     business_day_count,
     coalesce,
     col,
@@ -148,11 +156,14 @@ from polars.functions import (
     ones,
     quantile,
     reduce,
+    register_business_calendar,
     repeat,
     rolling_corr,
     rolling_cov,
+    search_sorted_by,
     select,
     set_random_seed,
+    set_secret_provider,
     sql_expr,
     std,
     struct,
@@ -162,6 +173,7 @@ from polars.functions import (
     time,
     time_range,
     time_ranges,
+    unregister_business_calendar,
     var,
     when,
     zeros,
@@ -191,6 +203,7 @@ from polars.io import (
     read_ods,
     read_parquet,
     read_parquet_schema,
+    scan_arrow_c_stream,
     scan_csv,
     scan_delta,
     scan_iceberg,
@@ -255,8 +268,10 @@ __all__ = [
     "Duration",
     "Enum",
     "Field",
+    "Float16",
     "Float32",
     "Float64",
+    "Geometry",
     "Int8",
     "Int16",
     "Int32",
@@ -298,6 +313,7 @@ __all__ = [
     "read_ods",
     "read_parquet",
     "read_parquet_schema",
+    "scan_arrow_c_stream",
     "scan_csv",
     "scan_delta",
     "scan_iceberg",
@@ -325,6 +341,7 @@ __all__ = [
     # polars.functions
     "align_frames",
     "arg_where",
+    "business_calendars",
     "business_day_count",
     "concat",
     "date_range",
@@ -333,9 +350,11 @@ __all__ = [
     "datetime_ranges",
     "element",
     "ones",
+    "register_business_calendar",
     "repeat",
     "time_range",
     "time_ranges",
+    "unregister_business_calendar",
     "zeros",
     "escape_regex",
     # polars.functions.aggregation
@@ -343,6 +362,9 @@ __all__ = [
     "all_horizontal",
     "any",
     "any_horizontal",
+    "bitwise_and_horizontal",
+    "bitwise_or_horizontal",
+    "bitwise_xor_horizontal",
     "cum_sum",
     "cum_sum_horizontal",
     "max",
@@ -357,6 +379,8 @@ __all__ = [
     "arange",
     "arctan2",
     "arctan2d",
+    "arg_max_by",
+    "arg_min_by",
     "arg_sort_by",
     "coalesce",
     "col",
@@ -400,6 +424,7 @@ __all__ = [
     "reduce",
     "rolling_corr",
     "rolling_cov",
+    "search_sorted_by",
     "select",
     "std",
     "struct",
@@ -410,6 +435,8 @@ __all__ = [
     "len",
     # polars.functions.random
     "set_random_seed",
+    # polars.functions.secrets
+    "set_secret_provider",
     # polars.convert
     "from_arrow",
     "from_dataframe",
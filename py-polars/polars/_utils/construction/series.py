@@ -33,6 +33,8 @@ from polars.datatypes import (
     Decimal,
     Duration,
     Enum,
+    Float16,
+    Geometry,
     List,
     Null,
     Object,
@@ -153,6 +155,8 @@ def sequence_to_pyseries(
             Boolean,
             Enum,
             Decimal,
+            Float16,
+            Geometry,
         ):
             if pyseries.dtype() != dtype:
                 pyseries = pyseries.cast(dtype, strict=strict, wrap_numerical=False)
@@ -70,6 +70,8 @@ _POLARS_CFG_ENV_VARS = {
     "POLARS_FMT_TABLE_INLINE_COLUMN_DATA_TYPE",
     "POLARS_FMT_TABLE_ROUNDED_CORNERS",
     "POLARS_STREAMING_CHUNK_SIZE",
+    "POLARS_STREAMING_MEMORY_LIMIT",
+    "POLARS_STREAMING_SPILL_THRESHOLD",
     "POLARS_TABLE_WIDTH",
     "POLARS_VERBOSE",
     "POLARS_MAX_EXPR_DEPTH",
@@ -103,6 +105,8 @@ class ConfigParameters(TypedDict, total=False):
     fmt_str_lengths: int | None
     fmt_table_cell_list_len: int | None
     streaming_chunk_size: int | None
+    streaming_memory_limit: int | None
+    streaming_spill_threshold: int | None
     tbl_cell_alignment: Literal["LEFT", "CENTER", "RIGHT"] | None
     tbl_cell_numeric_alignment: Literal["LEFT", "CENTER", "RIGHT"] | None
     tbl_cols: int | None
@@ -128,6 +132,8 @@ class ConfigParameters(TypedDict, total=False):
     set_fmt_str_lengths: int | None
     set_fmt_table_cell_list_len: int | None
     set_streaming_chunk_size: int | None
+    set_streaming_memory_limit: int | None
+    set_streaming_spill_threshold: int | None
     set_tbl_cell_alignment: Literal["LEFT", "CENTER", "RIGHT"] | None
     set_tbl_cell_numeric_alignment: Literal["LEFT", "CENTER", "RIGHT"] | None
     set_tbl_cols: int | None
@@ -890,6 +896,59 @@ class Config(contextlib.ContextDecorator):
             os.environ["POLARS_STREAMING_CHUNK_SIZE"] = str(size)
         return cls
 
+    @classmethod
+    def set_streaming_memory_limit(cls, limit: int | None) -> type[Config]:
+        """
+        Limit the memory a query may buffer in the `streaming` engine.
+
+        Operations that can spill to disk do so once the limit is reached, the
+        others make the query fail with an error naming the operation, instead
+        of the process running out of memory. The memory is estimated from the
+        size of the buffered data: the rows held by sorts, joins, uniques,
+        rolling and dynamic group-bys and collected results, and the keys of
+        group-bys. The aggregation states of group-bys are not accounted for.
+
+        Parameters
+        ----------
+        limit
+            Number of bytes every query may buffer. Set to `None` to remove the
+            limit.
+        """
+        if limit is None:
+            os.environ.pop("POLARS_STREAMING_MEMORY_LIMIT", None)
+        else:
+            if limit < 0:
+                msg = "memory limit must be >= 0"
+                raise ValueError(msg)
+
+            os.environ["POLARS_STREAMING_MEMORY_LIMIT"] = str(limit)
+        return cls
+
+    @classmethod
+    def set_streaming_spill_threshold(cls, threshold: int | None) -> type[Config]:
+        """
+        Set the memory an operation may buffer before it spills to disk.
+
+        Applies to the operations of the `streaming` engine that can spill, such as
+        sorts and `unique`. The threshold is shared by the threads running the
+        operation.
+
+        Parameters
+        ----------
+        threshold
+            Number of bytes an operation may buffer. Set to `None` to use the
+            default of 1 GiB.
+        """
+        if threshold is None:
+            os.environ.pop("POLARS_STREAMING_SPILL_THRESHOLD", None)
+        else:
+            if threshold < 0:
+                msg = "spill threshold must be >= 0"
+                raise ValueError(msg)
+
+            os.environ["POLARS_STREAMING_SPILL_THRESHOLD"] = str(threshold)
+        return cls
+
     @classmethod
     def set_tbl_cell_alignment(
         cls, format: Literal["LEFT", "CENTER", "RIGHT"] | None
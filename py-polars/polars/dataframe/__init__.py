@@ -1,5 +1,7 @@
+from polars.dataframe.compare import DataFrameComparison
 from polars.dataframe.frame import DataFrame
 
 __all__ = [
     "DataFrame",
+    "DataFrameComparison",
 ]
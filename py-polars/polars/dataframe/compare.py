@@ -0,0 +1,45 @@
+from __future__ import annotations
+
+from dataclasses import dataclass
+from typing import TYPE_CHECKING
+
+if TYPE_CHECKING:
+    from polars import DataFrame
+
+
+@dataclass(frozen=True)
+class DataFrameComparison:
+    """
+    The differences between two DataFrames, as returned by :meth:`DataFrame.compare`.
+
+    Attributes
+    ----------
+    left_only
+        The rows of the left frame whose keys don't occur in the right frame.
+    right_only
+        The rows of the right frame whose keys don't occur in the left frame.
+    mismatches
+        For every compared column with differing values, a frame with the keys of
+        the differing rows, followed by a `left` and a `right` column holding the
+        values of both frames.
+    left_only_columns
+        The non-key columns that only exist in the left frame.
+    right_only_columns
+        The non-key columns that only exist in the right frame.
+    """
+
+    left_only: DataFrame
+    right_only: DataFrame
+    mismatches: dict[str, DataFrame]
+    left_only_columns: list[str]
+    right_only_columns: list[str]
+
+    def is_equal(self) -> bool:
+        """Whether the frames hold the same rows and columns."""
+        return (
+            self.left_only.is_empty()
+            and self.right_only.is_empty()
+            and not self.mismatches
+            and not self.left_only_columns
+            and not self.right_only_columns
+        )
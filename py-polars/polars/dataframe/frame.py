@@ -55,6 +55,7 @@ from polars._utils.various import (
 )
 from polars._utils.wrap import wrap_expr, wrap_ldf, wrap_s
 from polars.dataframe._html import NotebookFormatter
+from polars.dataframe.compare import DataFrameComparison
 from polars.dataframe.group_by import DynamicGroupBy, GroupBy, RollingGroupBy
 from polars.dataframe.plotting import DataFramePlot
 from polars.datatypes import (
@@ -165,6 +166,7 @@ if TYPE_CHECKING:
         StartBy,
         UniqueKeepStrategy,
         UnstackDirection,
+        UpsampleFillStrategy,
     )
     from polars._utils.various import NoDefault
     from polars.interchange.dataframe import PolarsDataFrame
@@ -5918,6 +5920,92 @@ class DataFrame:
         """
         return self._df.equals(other._df, null_equal=null_equal)
 
+    @unstable()
+    def compare(
+        self,
+        other: DataFrame,
+        on: str | Sequence[str],
+        *,
+        abs_tol: float | None = None,
+    ) -> DataFrameComparison:
+        """
+        Compare the DataFrame with another DataFrame and report all differences.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Rows are matched on the `on` columns, regardless of their order. Unlike
+        :func:`polars.testing.assert_frame_equal`, which only tells whether two frames
+        are equal, this reports the rows that only occur in one of the frames and the
+        differing values of every column, which helps to track down regressions in
+        the output of a pipeline.
+
+        Parameters
+        ----------
+        other
+            DataFrame to compare with.
+        on
+            Name(s) of the key columns that identify a row. The keys must be unique in
+            both frames; null keys match each other.
+        abs_tol
+            Absolute tolerance for float columns. Values of all other columns must be
+            equal. Nulls are equal to nulls, and NaNs to NaNs.
+
+        Returns
+        -------
+        DataFrameComparison
+            The rows and columns that only exist in one of the frames, and per column
+            the keys and values of the rows where the frames differ.
+
+        See Also
+        --------
+        equals
+        polars.testing.assert_frame_equal
+
+        Examples
+        --------
+        >>> df1 = pl.DataFrame({"id": [1, 2, 3], "value": [1.0, 2.0, 3.0]})
+        >>> df2 = pl.DataFrame({"id": [1, 2, 4], "value": [1.0, 2.5, 4.0]})
+        >>> comparison = df1.compare(df2, on="id")
+        >>> comparison.is_equal()
+        False
+        >>> comparison.left_only
+        shape: (1, 2)
+        ┌─────┬───────┐
+        │ id  ┆ value │
+        │ --- ┆ ---   │
+        │ i64 ┆ f64   │
+        ╞═════╪═══════╡
+        │ 3   ┆ 3.0   │
+        └─────┴───────┘
+        >>> comparison.mismatches["value"]
+        shape: (1, 3)
+        ┌─────┬──────┬───────┐
+        │ id  ┆ left ┆ right │
+        │ --- ┆ ---  ┆ ---   │
+        │ i64 ┆ f64  ┆ f64   │
+        ╞═════╪══════╪═══════╡
+        │ 2   ┆ 2.0  ┆ 2.5   │
+        └─────┴──────┴───────┘
+
+        Allow small differences in float columns.
+
+        >>> df1.compare(df2, on="id", abs_tol=0.5).mismatches
+        {}
+        """
+        keys = [on] if isinstance(on, str) else list(on)
+        left_only, right_only, mismatches, left_only_columns, right_only_columns = (
+            self._df.compare(other._df, keys, abs_tol)
+        )
+        return DataFrameComparison(
+            left_only=self._from_pydf(left_only),
+            right_only=self._from_pydf(right_only),
+            mismatches={name: self._from_pydf(df) for name, df in mismatches},
+            left_only_columns=left_only_columns,
+            right_only_columns=right_only_columns,
+        )
+
     def slice(self, offset: int, length: int | None = None) -> DataFrame:
         """
         Get a slice of this DataFrame.
@@ -7093,6 +7181,7 @@ class DataFrame:
         every: str | timedelta,
         group_by: str | Sequence[str] | None = None,
         maintain_order: bool = False,
+        fill: Mapping[str, UpsampleFillStrategy | Any] | None = None,
     ) -> DataFrame:
         """
         Upsample a DataFrame at a regular frequency.
@@ -7132,6 +7221,17 @@ class DataFrame:
             First group by these columns and then upsample for every group.
         maintain_order
             Keep the ordering predictable. This is slower.
+        fill
+            Mapping of column names to the way the nulls introduced by upsampling
+            are filled. This is applied within each group as part of the upsample.
+
+            - 'forward': carry the last observed value forward.
+            - 'backward': carry the next observed value backward.
+            - 'interpolate': linearly interpolate between the observed values; the
+              column is converted to a float type.
+            - any other (non-string) value is used as a constant fill value.
+
+            Columns that are not listed keep their nulls.
 
         Returns
         -------
@@ -7173,6 +7273,26 @@ class DataFrame:
         │ 2021-05-01 00:00:00 ┆ B      ┆ 1      │
         │ 2021-06-01 00:00:00 ┆ B      ┆ 3      │
         └─────────────────────┴────────┴────────┘
+
+        Fill the upsampled rows with a different strategy per column.
+
+        >>> df.upsample(
+        ...     time_column="time",
+        ...     every="1mo",
+        ...     fill={"groups": "forward", "values": "interpolate"},
+        ... )
+        shape: (5, 3)
+        ┌─────────────────────┬────────┬────────┐
+        │ time                ┆ groups ┆ values │
+        │ ---                 ┆ ---    ┆ ---    │
+        │ datetime[μs]        ┆ str    ┆ f64    │
+        ╞═════════════════════╪════════╪════════╡
+        │ 2021-02-01 00:00:00 ┆ A      ┆ 0.0    │
+        │ 2021-03-01 00:00:00 ┆ A      ┆ 0.5    │
+        │ 2021-04-01 00:00:00 ┆ B      ┆ 1.0    │
+        │ 2021-05-01 00:00:00 ┆ A      ┆ 2.0    │
+        │ 2021-06-01 00:00:00 ┆ B      ┆ 3.0    │
+        └─────────────────────┴────────┴────────┘
         """
         if group_by is None:
             group_by = []
@@ -7180,9 +7300,10 @@ class DataFrame:
             group_by = [group_by]
 
         every = parse_as_duration_string(every)
+        fill_spec = list(fill.items()) if fill is not None else []
 
         return self._from_pydf(
-            self._df.upsample(group_by, time_column, every, maintain_order)
+            self._df.upsample(group_by, time_column, every, maintain_order, fill_spec)
         )
 
     def join_asof(
@@ -7602,6 +7723,9 @@ class DataFrame:
             .. note::
                 This is currently not supported by the streaming engine.
 
+            If the validation fails, the error lists some of the duplicate keys;
+            use :meth:`join_violations` to get all of them.
+
         nulls_equal
             Join on null values. By default null values will never produce matches.
         coalesce
@@ -7847,6 +7971,286 @@ class DataFrame:
             .collect(_eager=True)
         )
 
+    @unstable()
+    def join_between(
+        self,
+        other: DataFrame,
+        left_on: str | Expr,
+        right_lower: str | Expr,
+        right_upper: str | Expr,
+        *,
+        closed: ClosedInterval = "both",
+        suffix: str = "_right",
+    ) -> DataFrame:
+        """
+        Perform a range join: match rows where a value lies within a window.
+
+        This matches every row of this frame with the rows of `other` for which
+        `left_on` lies between `right_lower` and `right_upper`, e.g. to find the
+        validity window of an event timestamp. The join runs as a sort-based
+        inequality join, which is much faster than a cross join followed by a filter.
+
+        This performs an inner join, so a row from either DataFrame may be included
+        multiple times in the result.
+
+        .. note::
+            The row order of the input DataFrames is not preserved.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        other
+            DataFrame to join with.
+        left_on
+            Value of this DataFrame to look up. Accepts expression input. Strings are
+            parsed as column names.
+        right_lower
+            Lower bound of the window in `other`. Accepts expression input. Strings
+            are parsed as column names.
+        right_upper
+            Upper bound of the window in `other`. Accepts expression input. Strings
+            are parsed as column names.
+        closed : {'both', 'left', 'right', 'none'}
+            Define which sides of the window are closed (inclusive).
+        suffix
+            Suffix to append to columns with a duplicate name.
+
+        See Also
+        --------
+        join_where
+
+        Examples
+        --------
+        >>> events = pl.DataFrame({"event": ["a", "b", "c"], "ts": [1, 5, 9]})
+        >>> windows = pl.DataFrame(
+        ...     {"window": ["w1", "w2"], "start": [0, 4], "end": [5, 8]}
+        ... )
+        >>> events.join_between(windows, "ts", "start", "end").sort(
+        ...     "event", "window"
+        ... )
+        shape: (3, 5)
+        ┌───────┬─────┬────────┬───────┬─────┐
+        │ event ┆ ts  ┆ window ┆ start ┆ end │
+        │ ---   ┆ --- ┆ ---    ┆ ---   ┆ --- │
+        │ str   ┆ i64 ┆ str    ┆ i64   ┆ i64 │
+        ╞═══════╪═════╪════════╪═══════╪═════╡
+        │ a     ┆ 1   ┆ w1     ┆ 0     ┆ 5   │
+        │ b     ┆ 5   ┆ w1     ┆ 0     ┆ 5   │
+        │ b     ┆ 5   ┆ w2     ┆ 4     ┆ 8   │
+        └───────┴─────┴────────┴───────┴─────┘
+        """
+        if not isinstance(other, DataFrame):
+            msg = f"expected `other` join table to be a DataFrame, got {type(other).__name__!r}"
+            raise TypeError(msg)
+
+        return (
+            self.lazy()
+            .join_between(
+                other.lazy(),
+                left_on,
+                right_lower,
+                right_upper,
+                closed=closed,
+                suffix=suffix,
+            )
+            .collect(_eager=True)
+        )
+
+    @unstable()
+    def join_fuzzy(
+        self,
+        other: DataFrame,
+        *,
+        on: str | None = None,
+        left_on: str | None = None,
+        right_on: str | None = None,
+        threshold: float = 0.8,
+        metric: Literal["levenshtein", "jaro_winkler"] = "jaro_winkler",
+        ngram_size: int = 3,
+        similarity_column: str | None = None,
+        suffix: str = "_right",
+    ) -> DataFrame:
+        """
+        Join on the similarity of string keys.
+
+        Rows are matched if the similarity of their keys is at least `threshold`,
+        which resolves the same entities in tables where they are spelled slightly
+        differently, e.g. customer names typed in by hand. A row from either DataFrame
+        may be included multiple times in the result. Null keys never match.
+
+        To avoid comparing all pairs of keys, only keys that share at least one
+        n-gram (substring of `ngram_size` characters, where the start and end of a key
+        count as characters) are compared.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        other
+            DataFrame to join with.
+        on
+            Name of the string key column in both DataFrames.
+        left_on
+            Name of the string key column of this DataFrame.
+        right_on
+            Name of the string key column of `other`.
+        threshold
+            Minimum similarity of the keys, between 0 and 1.
+        metric : {'jaro_winkler', 'levenshtein'}
+            Similarity metric.
+
+            - *jaro_winkler*: the Jaro-Winkler similarity, which favors keys with a
+              common prefix.
+            - *levenshtein*: one minus the Levenshtein distance divided by the length
+              of the longest key.
+        ngram_size
+            Length of the n-grams used to find candidate pairs of keys. Smaller values
+            find more matches between short keys, but compare more pairs.
+        similarity_column
+            Name of a column to add with the similarity of each match.
+        suffix
+            Suffix to append to columns with a duplicate name.
+
+        See Also
+        --------
+        Expr.str.levenshtein_similarity
+        Expr.str.jaro_winkler
+
+        Examples
+        --------
+        >>> customers = pl.DataFrame(
+        ...     {"name": ["Jon Smith", "Alice Jones", "Bob Brown"]}
+        ... )
+        >>> crm = pl.DataFrame(
+        ...     {
+        ...         "customer": ["John Smith", "Alice Jnoes", "Robert Brown"],
+        ...         "id": [1, 2, 3],
+        ...     }
+        ... )
+        >>> customers.join_fuzzy(
+        ...     crm,
+        ...     left_on="name",
+        ...     right_on="customer",
+        ...     metric="levenshtein",
+        ...     similarity_column="similarity",
+        ... )
+        shape: (2, 4)
+        ┌─────────────┬─────────────┬─────┬────────────┐
+        │ name        ┆ customer    ┆ id  ┆ similarity │
+        │ ---         ┆ ---         ┆ --- ┆ ---        │
+        │ str         ┆ str         ┆ i64 ┆ f64        │
+        ╞═════════════╪═════════════╪═════╪════════════╡
+        │ Jon Smith   ┆ John Smith  ┆ 1   ┆ 0.9        │
+        │ Alice Jones ┆ Alice Jnoes ┆ 2   ┆ 0.818182   │
+        └─────────────┴─────────────┴─────┴────────────┘
+        """
+        if not isinstance(other, DataFrame):
+            msg = f"expected `other` join table to be a DataFrame, got {type(other).__name__!r}"
+            raise TypeError(msg)
+        if on is not None:
+            if left_on is not None or right_on is not None:
+                msg = "cannot use `on` together with `left_on` or `right_on`"
+                raise ValueError(msg)
+            left_on = right_on = on
+        if left_on is None or right_on is None:
+            msg = "either `on` or both `left_on` and `right_on` should be passed"
+            raise ValueError(msg)
+
+        return self._from_pydf(
+            self._df.join_fuzzy(
+                other._df,
+                left_on,
+                right_on,
+                metric,
+                threshold,
+                ngram_size,
+                similarity_column,
+                suffix,
+            )
+        )
+
+    def join_violations(
+        self,
+        other: DataFrame,
+        on: str | Sequence[str] | None = None,
+        *,
+        left_on: str | Sequence[str] | None = None,
+        right_on: str | Sequence[str] | None = None,
+        validate: JoinValidation = "1:1",
+        nulls_equal: bool = False,
+    ) -> DataFrame:
+        """
+        Get the join keys that violate a join validation.
+
+        A :meth:`join` with `validate` raises an error that only lists some of the
+        duplicate keys; this method returns all of them.
+
+        Parameters
+        ----------
+        other
+            DataFrame to join with.
+        on
+            Name(s) of the join columns in both DataFrames.
+        left_on
+            Name(s) of the left join column(s).
+        right_on
+            Name(s) of the right join column(s).
+        validate: {'1:1', '1:m', 'm:1', 'm:m'}
+            The validation to check, see :meth:`join`.
+        nulls_equal
+            Join on null values. By default null values will never produce matches,
+            so duplicate null keys don't violate the validation.
+
+        Returns
+        -------
+        DataFrame
+            A DataFrame with a `side` column holding `"left"` or `"right"`, the key
+            columns named after the left keys, and a `count` column with the number
+            of rows of that side with the key. It is empty if the join keys fulfill
+            the validation.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"id": [1, 2, 2, 3], "x": ["a", "b", "c", "d"]})
+        >>> other = pl.DataFrame({"id": [1, 1, 2], "y": [1.0, 2.0, 3.0]})
+        >>> df.join_violations(other, on="id", validate="1:1")
+        shape: (2, 3)
+        ┌───────┬─────┬───────┐
+        │ side  ┆ id  ┆ count │
+        │ ---   ┆ --- ┆ ---   │
+        │ str   ┆ i64 ┆ u32   │
+        ╞═══════╪═════╪═══════╡
+        │ left  ┆ 2   ┆ 2     │
+        │ right ┆ 1   ┆ 2     │
+        └───────┴─────┴───────┘
+        """
+        if not isinstance(other, DataFrame):
+            msg = f"expected `other` join table to be a DataFrame, got {type(other).__name__!r}"
+            raise TypeError(msg)
+        if on is not None:
+            if left_on is not None or right_on is not None:
+                msg = "cannot use `on` together with `left_on` or `right_on`"
+                raise ValueError(msg)
+            left_on = right_on = on
+        if left_on is None or right_on is None:
+            msg = "either `on` or both `left_on` and `right_on` should be passed"
+            raise ValueError(msg)
+        if isinstance(left_on, str):
+            left_on = [left_on]
+        if isinstance(right_on, str):
+            right_on = [right_on]
+
+        return self._from_pydf(
+            self._df.join_violations(
+                other._df, list(left_on), list(right_on), validate, nulls_equal
+            )
+        )
+
     def map_rows(
         self,
         function: Callable[[tuple[Any, ...]], Any],
@@ -12,8 +12,10 @@ from polars.datatypes.classes import (
     Duration,
     Enum,
     Field,
+    Float16,
     Float32,
     Float64,
+    Geometry,
     Int8,
     Int16,
     Int32,
@@ -69,8 +71,10 @@ __all__ = [
     "Duration",
     "Enum",
     "Field",
+    "Float16",
     "Float32",
     "Float64",
+    "Geometry",
     "Int16",
     "Int128",
     "Int32",
@@ -336,6 +336,9 @@ class Int128(SignedIntegerType):
     """
     128-bit signed integer type.
 
+    There is no unsigned 128-bit integer type; values that don't fit in an `Int128`
+    are rejected.
+
     .. warning::
         This functionality is considered **unstable**.
         It is a work-in-progress feature and may not always work as expected.
@@ -359,6 +362,24 @@ class UInt64(UnsignedIntegerType):
     """64-bit unsigned integer type."""
 
 
+class Float16(FloatType):
+    """
+    16-bit (half-precision) floating point type.
+
+    Values are stored as 32-bit floats and rounded to the nearest representable
+    half-precision value. Arithmetic is performed in 32-bit precision, so the result
+    of an arithmetic operation on a `Float16` column is of type `Float32`.
+    Aggregations also accumulate in 32-bit precision: `sum`, `mean` and other
+    aggregations that compute new values return `Float32`, while `min` and `max`
+    return `Float16`.
+
+    .. warning::
+        This functionality is considered **unstable**.
+        It is a work-in-progress feature and may not always work as expected.
+        It may be changed at any point without it being considered a breaking change.
+    """
+
+
 class Float32(FloatType):
     """32-bit floating point type."""
 
@@ -439,6 +460,22 @@ class Binary(DataType):
     """Binary type."""
 
 
+class Geometry(DataType):
+    """
+    Geometry type, holding planar geometries encoded as Well-Known Binary (WKB).
+
+    Geometries are stored as :class:`Binary` values and can be created by casting
+    binary values holding WKB. The geometry functions are available under the
+    `st` namespace. Geometry columns are written to Parquet with GeoParquet
+    metadata.
+
+    .. warning::
+        This functionality is considered **unstable**.
+        It is a work-in-progress feature and may not always work as expected.
+        It may be changed at any point without it being considered a breaking change.
+    """
+
+
 class Date(TemporalType):
     """
     Data type representing a calendar date.
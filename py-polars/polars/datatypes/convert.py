@@ -22,8 +22,10 @@ from polars.datatypes.classes import (
     Duration,
     Enum,
     Field,
+    Float16,
     Float32,
     Float64,
+    Geometry,
     Int8,
     Int16,
     Int32,
@@ -148,6 +150,7 @@ class _DataTypeMappings:
             Datetime: "datetime",
             Decimal: "decimal",
             Duration: "duration",
+            Float16: "f16",
             Float32: "f32",
             Float64: "f64",
             Int128: "i128",
@@ -177,8 +180,10 @@ class _DataTypeMappings:
             Datetime: datetime,
             Decimal: PyDecimal,
             Duration: timedelta,
+            Float16: float,
             Float32: float,
             Float64: float,
+            Geometry: bytes,
             Int128: int,
             Int16: int,
             Int32: int,
@@ -794,3 +794,66 @@ class ExprArrayNameSpace:
         """
         n = parse_into_expression(n)
         return wrap_expr(self._pyexpr.arr_shift(n))
+
+    def dot(self, other: IntoExpr) -> Expr:
+        """
+        Compute the dot product of every sub-array with the sub-array of `other`.
+
+        The result is null if either sub-array is null or contains a null.
+
+        Parameters
+        ----------
+        other
+            Array expression of the same width. A single array, e.g. a list literal,
+            is used for every row.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[1, 2], [3, 4]], "b": [[1, 0], [0, 1]]},
+        ...     schema={"a": pl.Array(pl.Int64, 2), "b": pl.Array(pl.Int64, 2)},
+        ... )
+        >>> df.select(dot=pl.col("a").arr.dot("b"))
+        shape: (2, 1)
+        ┌─────┐
+        │ dot │
+        │ --- │
+        │ f64 │
+        ╞═════╡
+        │ 1.0 │
+        │ 4.0 │
+        └─────┘
+        """
+        other = parse_into_expression(other)
+        return wrap_expr(self._pyexpr.arr_dot(other))
+
+    def cosine_similarity(self, other: IntoExpr) -> Expr:
+        """
+        Compute the cosine similarity of every sub-array with the sub-array of `other`.
+
+        The result is null if either sub-array is null or contains a null.
+
+        Parameters
+        ----------
+        other
+            Array expression of the same width. A single array, e.g. a list literal,
+            is used for every row.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[1, 2], [3, 4]]}, schema={"a": pl.Array(pl.Float64, 2)}
+        ... )
+        >>> df.select(sim=pl.col("a").arr.cosine_similarity([0.0, 1.0]))
+        shape: (2, 1)
+        ┌──────────┐
+        │ sim      │
+        │ ---      │
+        │ f64      │
+        ╞══════════╡
+        │ 0.894427 │
+        │ 0.8      │
+        └──────────┘
+        """
+        other = parse_into_expression(other)
+        return wrap_expr(self._pyexpr.arr_cosine_similarity(other))
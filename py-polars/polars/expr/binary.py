@@ -10,6 +10,7 @@ from polars.datatypes import parse_into_dtype
 if TYPE_CHECKING:
     from polars import Expr
     from polars._typing import (
+        EncryptionAlgorithm,
         Endianness,
         IntoExpr,
         PolarsDataType,
@@ -338,3 +339,86 @@ class ExprBinaryNameSpace:
         dtype = parse_into_dtype(dtype)
 
         return wrap_expr(self._pyexpr.from_buffer(dtype, endianness))
+
+    def encrypt(self, key: str, algorithm: EncryptionAlgorithm = "aes-gcm") -> Expr:
+        r"""
+        Encrypt binary values with authenticated encryption.
+
+        Every value is encrypted with a fresh random nonce, so equal values encrypt
+        to different outputs. Each output value holds the 12 byte nonce, followed by
+        the ciphertext and a 16 byte authentication tag.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        key
+            Name of the key to encrypt with. The key itself is never part of the
+            query; it is fetched from the secret provider when the expression is
+            evaluated. By default, this reads a hex-encoded key from the environment
+            variable with this name. Use :func:`set_secret_provider` to change this.
+            Keys must be 16 bytes (AES-128) or 32 bytes (AES-256) long.
+        algorithm : {'aes-gcm', 'aes-gcm-siv'}
+            The encryption algorithm to use. AES-GCM-SIV stays secure if a nonce is
+            ever repeated, at the cost of some speed.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Binary`.
+
+        See Also
+        --------
+        decrypt
+
+        Examples
+        --------
+        >>> pl.set_secret_provider(lambda name: b"0123456789abcdef")
+        >>> df = pl.DataFrame({"data": [b"secret", None]})
+        >>> df.select(
+        ...     pl.col("data").bin.encrypt("my_key").bin.decrypt("my_key")
+        ... )
+        shape: (2, 1)
+        ┌───────────┐
+        │ data      │
+        │ ---       │
+        │ binary    │
+        ╞═══════════╡
+        │ b"secret" │
+        │ null      │
+        └───────────┘
+        >>> pl.set_secret_provider(None)
+        """
+        return wrap_expr(self._pyexpr.bin_encrypt(key, algorithm))
+
+    def decrypt(self, key: str, algorithm: EncryptionAlgorithm = "aes-gcm") -> Expr:
+        r"""
+        Decrypt binary values encrypted with :meth:`encrypt`.
+
+        Values are authenticated as they are decrypted; an error is raised if a value
+        was encrypted with a different key or has been modified.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        key
+            Name of the key to decrypt with, which is fetched from the secret
+            provider when the expression is evaluated.
+        algorithm : {'aes-gcm', 'aes-gcm-siv'}
+            The encryption algorithm the values were encrypted with.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Binary`.
+
+        See Also
+        --------
+        encrypt
+        """
+        return wrap_expr(self._pyexpr.bin_decrypt(key, algorithm))
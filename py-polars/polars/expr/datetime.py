@@ -10,6 +10,7 @@ from polars._utils.deprecation import deprecate_function, deprecate_nonkeyword_a
 from polars._utils.parse import parse_into_expression, parse_into_list_of_expressions
 from polars._utils.wrap import wrap_expr
 from polars.datatypes import DTYPE_TEMPORAL_UNITS, Date, Int32
+from polars.functions.business import _resolve_business_calendar
 
 if TYPE_CHECKING:
     from collections.abc import Iterable
@@ -41,6 +42,8 @@ class ExprDateTimeNameSpace:
         week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
         holidays: Iterable[dt.date] = (),
         roll: Roll = "raise",
+        *,
+        calendar: str | None = None,
     ) -> Expr:
         """
         Offset by `n` business days.
@@ -73,6 +76,11 @@ class ExprDateTimeNameSpace:
             - `'raise'`: raise an error
             - `'forward'`: move to the next business day
             - `'backward'`: move to the previous business day
+        calendar
+            Name of a calendar registered with
+            :func:`polars.register_business_calendar`. Its week mask is used and its
+            holidays are combined with `holidays`. The calendar is resolved when the
+            expression is created.
 
         Returns
         -------
@@ -144,15 +152,93 @@ class ExprDateTimeNameSpace:
         └────────────┴─────────────────┘
         """
         n_pyexpr = parse_into_expression(n)
-        unix_epoch = dt.date(1970, 1, 1)
+        week_mask, holiday_days = _resolve_business_calendar(
+            week_mask, holidays, calendar
+        )
         return wrap_expr(
-            self._pyexpr.dt_add_business_days(
-                n_pyexpr,
-                week_mask,
-                [(holiday - unix_epoch).days for holiday in holidays],
-                roll,
-            )
+            self._pyexpr.dt_add_business_days(n_pyexpr, week_mask, holiday_days, roll)
+        )
+
+    def is_business_day(
+        self,
+        week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
+        holidays: Iterable[dt.date] = (),
+        *,
+        calendar: str | None = None,
+    ) -> Expr:
+        """
+        Determine whether each day lands on a business day.
+
+        Parameters
+        ----------
+        week_mask
+            Which days of the week to count. The default is Monday to Friday.
+            If you wanted to count only Monday to Thursday, you would pass
+            `(True, True, True, True, False, False, False)`.
+        holidays
+            Holidays to exclude from the count.
+        calendar
+            Name of a calendar registered with
+            :func:`polars.register_business_calendar`. Its week mask is used and its
+            holidays are combined with `holidays`. The calendar is resolved when the
+            expression is created.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame({"start": [date(2020, 1, 3), date(2020, 1, 5)]})
+        >>> df.with_columns(is_business_day=pl.col("start").dt.is_business_day())
+        shape: (2, 2)
+        ┌────────────┬─────────────────┐
+        │ start      ┆ is_business_day │
+        │ ---        ┆ ---             │
+        │ date       ┆ bool            │
+        ╞════════════╪═════════════════╡
+        │ 2020-01-03 ┆ true            │
+        │ 2020-01-05 ┆ false           │
+        └────────────┴─────────────────┘
+
+        You can pass a custom weekend - for example, if you only take Sunday off:
+
+        >>> week_mask = (True, True, True, True, True, True, False)
+        >>> df.with_columns(
+        ...     is_business_day=pl.col("start").dt.is_business_day(week_mask=week_mask)
+        ... )
+        shape: (2, 2)
+        ┌────────────┬─────────────────┐
+        │ start      ┆ is_business_day │
+        │ ---        ┆ ---             │
+        │ date       ┆ bool            │
+        ╞════════════╪═════════════════╡
+        │ 2020-01-03 ┆ true            │
+        │ 2020-01-05 ┆ false           │
+        └────────────┴─────────────────┘
+
+        You can also pass a list of holidays:
+
+        >>> holidays = [date(2020, 1, 3), date(2020, 1, 6)]
+        >>> df.with_columns(
+        ...     is_business_day=pl.col("start").dt.is_business_day(holidays=holidays)
+        ... )
+        shape: (2, 2)
+        ┌────────────┬─────────────────┐
+        │ start      ┆ is_business_day │
+        │ ---        ┆ ---             │
+        │ date       ┆ bool            │
+        ╞════════════╪═════════════════╡
+        │ 2020-01-03 ┆ false           │
+        │ 2020-01-05 ┆ false           │
+        └────────────┴─────────────────┘
+        """
+        week_mask, holiday_days = _resolve_business_calendar(
+            week_mask, holidays, calendar
         )
+        return wrap_expr(self._pyexpr.dt_is_business_day(week_mask, holiday_days))
 
     def truncate(self, every: str | dt.timedelta | Expr) -> Expr:
         """
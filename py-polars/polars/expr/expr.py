@@ -49,11 +49,14 @@ from polars.expr.array import ExprArrayNameSpace
 from polars.expr.binary import ExprBinaryNameSpace
 from polars.expr.categorical import ExprCatNameSpace
 from polars.expr.datetime import ExprDateTimeNameSpace
+from polars.expr.ip import ExprIpNameSpace
 from polars.expr.list import ExprListNameSpace
 from polars.expr.meta import ExprMetaNameSpace
 from polars.expr.name import ExprNameNameSpace
+from polars.expr.st import ExprGeometryNameSpace
 from polars.expr.string import ExprStringNameSpace
 from polars.expr.struct import ExprStructNameSpace
+from polars.expr.url import ExprUrlNameSpace
 from polars.meta import thread_pool_size
 
 with contextlib.suppress(ImportError):  # Module not available when building docs
@@ -71,10 +74,12 @@ if TYPE_CHECKING:
     from polars._typing import (
         ClosedInterval,
         FillNullStrategy,
+        HashAlgorithm,
         InterpolationMethod,
         IntoExpr,
         IntoExprColumn,
         MapElementsStrategy,
+        ModeTies,
         NullBehavior,
         NumericLiteral,
         PolarsDataType,
@@ -110,12 +115,15 @@ class Expr:
         "arr",
         "cat",
         "dt",
+        "ip",
         "list",
         "meta",
         "name",
         "str",
         "bin",
+        "st",
         "struct",
+        "url",
     }
 
     @classmethod
@@ -1581,11 +1589,17 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.cum_count(reverse))
 
-    def floor(self) -> Expr:
+    def floor(self, decimals: int = 0) -> Expr:
         """
-        Rounds down to the nearest integer value.
+        Rounds down to the nearest integer value, or to `decimals` digits.
 
-        Only works on floating point Series.
+        Only works on floating point and Decimal Series.
+
+        Parameters
+        ----------
+        decimals
+            Number of decimals to round down to. For Decimal columns the scale of the
+            result is unchanged.
 
         Examples
         --------
@@ -1603,13 +1617,21 @@ class Expr:
         │ 1.0 │
         └─────┘
         """
-        return self._from_pyexpr(self._pyexpr.floor())
+        if decimals == 0:
+            return self._from_pyexpr(self._pyexpr.floor())
+        return self._from_pyexpr(self._pyexpr.floor_decimals(decimals))
 
-    def ceil(self) -> Expr:
+    def ceil(self, decimals: int = 0) -> Expr:
         """
-        Rounds up to the nearest integer value.
+        Rounds up to the nearest integer value, or to `decimals` digits.
 
-        Only works on floating point Series.
+        Only works on floating point and Decimal Series.
+
+        Parameters
+        ----------
+        decimals
+            Number of decimals to round up to. For Decimal columns the scale of the
+            result is unchanged.
 
         Examples
         --------
@@ -1627,7 +1649,9 @@ class Expr:
         │ 2.0 │
         └─────┘
         """
-        return self._from_pyexpr(self._pyexpr.ceil())
+        if decimals == 0:
+            return self._from_pyexpr(self._pyexpr.ceil())
+        return self._from_pyexpr(self._pyexpr.ceil_decimals(decimals))
 
     def round(self, decimals: int = 0) -> Expr:
         """
@@ -1712,31 +1736,58 @@ class Expr:
         other = parse_into_expression(other)
         return self._from_pyexpr(self._pyexpr.dot(other))
 
-    def mode(self) -> Expr:
+    def mode(self, *, ties: ModeTies = "all") -> Expr:
         """
         Compute the most occurring value(s).
 
-        Can return multiple Values.
+        Parameters
+        ----------
+        ties : {'all', 'first', 'smallest'}
+            Which values to return if several values occur most often.
+
+            - 'all': Return all of them, in the order of their first occurrence.
+            - 'first': Return the one that occurs first.
+            - 'smallest': Return the smallest one.
+
+            Unless `ties='all'`, this is an aggregation that returns a single value,
+            which is null if there are no values.
 
         Examples
         --------
         >>> df = pl.DataFrame(
         ...     {
-        ...         "a": [1, 1, 2, 3],
-        ...         "b": [1, 1, 2, 2],
+        ...         "g": ["x", "x", "x", "x", "y", "y"],
+        ...         "v": [3, 1, 1, 3, 5, 4],
         ...     }
         ... )
-        >>> df.select(pl.all().mode().first())  # doctest: +IGNORE_RESULT
-        shape: (2, 2)
-        ┌─────┬─────┐
-        │ a   ┆ b   │
-        │ --- ┆ --- │
-        │ i64 ┆ i64 │
-        ╞═════╪═════╡
-        │ 1   ┆ 1   │
-        └─────┴─────┘
+        >>> df.select(pl.col("v").mode())
+        shape: (2, 1)
+        ┌─────┐
+        │ v   │
+        │ --- │
+        │ i64 │
+        ╞═════╡
+        │ 3   │
+        │ 1   │
+        └─────┘
+
+        Return a single value per group:
+
+        >>> df.group_by("g", maintain_order=True).agg(
+        ...     first=pl.col("v").mode(ties="first"),
+        ...     smallest=pl.col("v").mode(ties="smallest"),
+        ... )
+        shape: (2, 3)
+        ┌─────┬───────┬──────────┐
+        │ g   ┆ first ┆ smallest │
+        │ --- ┆ ---   ┆ ---      │
+        │ str ┆ i64   ┆ i64      │
+        ╞═════╪═══════╪══════════╡
+        │ x   ┆ 3     ┆ 1        │
+        │ y   ┆ 5     ┆ 4        │
+        └─────┴───────┴──────────┘
         """
-        return self._from_pyexpr(self._pyexpr.mode())
+        return self._from_pyexpr(self._pyexpr.mode(ties))
 
     def cast(
         self,
@@ -2311,6 +2362,106 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.arg_min())
 
+    def min_by(self, by: IntoExpr | Iterable[IntoExpr], *more_by: IntoExpr) -> Expr:
+        """
+        Get the value at the row with the smallest values of the `by` column(s).
+
+        Rows are compared lexicographically: the first `by` column determines the
+        ordering, the next column is only used to break ties, and so on. Null values
+        are ordered after all other values and rows in which every `by` value is null
+        are ignored. If multiple rows share the smallest values, the first one is used.
+
+        This gives the same result as sorting by the `by` columns and taking the first
+        value, but without the cost of a sort.
+
+        Parameters
+        ----------
+        by
+            Column(s) to order by. Accepts expression input. Strings are parsed as
+            column names.
+        *more_by
+            Additional columns to order by, specified as positional arguments.
+
+        See Also
+        --------
+        polars.arg_min_by
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "group": ["a", "a", "b", "b", "b"],
+        ...         "item": ["x", "y", "z", "u", "v"],
+        ...         "price": [3, 1, 2, 2, 5],
+        ...         "qty": [1, 4, 7, 3, 1],
+        ...     }
+        ... )
+        >>> df.group_by("group", maintain_order=True).agg(
+        ...     cheapest=pl.col("item").min_by("price", "qty")
+        ... )
+        shape: (2, 2)
+        ┌───────┬──────────┐
+        │ group ┆ cheapest │
+        │ ---   ┆ ---      │
+        │ str   ┆ str      │
+        ╞═══════╪══════════╡
+        │ a     ┆ y        │
+        │ b     ┆ u        │
+        └───────┴──────────┘
+        """
+        by = parse_into_list_of_expressions(by, *more_by)
+        return self._from_pyexpr(self._pyexpr.min_by(by))
+
+    def max_by(self, by: IntoExpr | Iterable[IntoExpr], *more_by: IntoExpr) -> Expr:
+        """
+        Get the value at the row with the largest values of the `by` column(s).
+
+        Rows are compared lexicographically: the first `by` column determines the
+        ordering, the next column is only used to break ties, and so on. Null values
+        are ordered after all other values and rows in which every `by` value is null
+        are ignored. If multiple rows share the largest values, the first one is used.
+
+        This gives the same result as sorting by the `by` columns in descending order
+        and taking the first value, but without the cost of a sort.
+
+        Parameters
+        ----------
+        by
+            Column(s) to order by. Accepts expression input. Strings are parsed as
+            column names.
+        *more_by
+            Additional columns to order by, specified as positional arguments.
+
+        See Also
+        --------
+        polars.arg_max_by
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "group": ["a", "a", "b", "b", "b"],
+        ...         "item": ["x", "y", "z", "u", "v"],
+        ...         "price": [3, 1, 2, 2, 5],
+        ...         "qty": [1, 4, 7, 3, 1],
+        ...     }
+        ... )
+        >>> df.group_by("group", maintain_order=True).agg(
+        ...     priciest=pl.col("item").max_by("price", "qty")
+        ... )
+        shape: (2, 2)
+        ┌───────┬──────────┐
+        │ group ┆ priciest │
+        │ ---   ┆ ---      │
+        │ str   ┆ str      │
+        ╞═══════╪══════════╡
+        │ a     ┆ x        │
+        │ b     ┆ v        │
+        └───────┴──────────┘
+        """
+        by = parse_into_list_of_expressions(by, *more_by)
+        return self._from_pyexpr(self._pyexpr.max_by(by))
+
     def index_of(self, element: IntoExpr) -> Expr:
         """
         Get the index of the first occurrence of a value, or ``None`` if it's not found.
@@ -3908,6 +4059,209 @@ class Expr:
         quantile = parse_into_expression(quantile)
         return self._from_pyexpr(self._pyexpr.quantile(quantile, interpolation))
 
+    @unstable()
+    def approx_quantile(self, quantile: float) -> Expr:
+        """
+        Get an approximate quantile value.
+
+        The quantile is estimated with a t-digest sketch, which only needs a single
+        pass over the data and a bounded amount of memory per group. Sketches of
+        different batches of the data are merged, so the streaming engine can compute
+        it for groups that don't fit in memory.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        quantile
+            Quantile between 0.0 and 1.0.
+
+        See Also
+        --------
+        quantile : Get the exact quantile value.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [0, 1, 2, 3, 4, 5]})
+        >>> df.select(pl.col("a").approx_quantile(0.5))
+        shape: (1, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ f64 │
+        ╞═════╡
+        │ 2.5 │
+        └─────┘
+        """
+        quantile_pyexpr = parse_into_expression(quantile)
+        return self._from_pyexpr(self._pyexpr.approx_quantile(quantile_pyexpr))
+
+    @unstable()
+    def approx_median(self) -> Expr:
+        """
+        Get an approximate median value.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        See Also
+        --------
+        approx_quantile
+        median : Get the exact median value.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [0, 1, 2, 3, 4, 5]})
+        >>> df.select(pl.col("a").approx_median())
+        shape: (1, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ f64 │
+        ╞═════╡
+        │ 2.5 │
+        └─────┘
+        """
+        return self.approx_quantile(0.5)
+
+    def weighted_mean(self, weights: IntoExpr) -> Expr:
+        """
+        Get the mean value weighted by `weights`.
+
+        Values whose weight is null, and weights whose value is null, are ignored.
+        The result is null if the remaining weights sum to zero.
+
+        Parameters
+        ----------
+        weights
+            Non-negative weights, one for each value. Accepts expression input;
+            strings are parsed as column names.
+
+        See Also
+        --------
+        weighted_std
+        weighted_quantile
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "g": ["a", "a", "b", "b", "b"],
+        ...         "x": [1.0, 3.0, 2.0, 4.0, 10.0],
+        ...         "w": [3, 1, 1, 1, 2],
+        ...     }
+        ... )
+        >>> df.group_by("g", maintain_order=True).agg(pl.col("x").weighted_mean("w"))
+        shape: (2, 2)
+        ┌─────┬─────┐
+        │ g   ┆ x   │
+        │ --- ┆ --- │
+        │ str ┆ f64 │
+        ╞═════╪═════╡
+        │ a   ┆ 1.5 │
+        │ b   ┆ 6.5 │
+        └─────┴─────┘
+        """
+        weights_pyexpr = parse_into_expression(weights)
+        return self._from_pyexpr(self._pyexpr.weighted_mean(weights_pyexpr))
+
+    def weighted_std(self, weights: IntoExpr, ddof: int = 1) -> Expr:
+        """
+        Get the standard deviation weighted by `weights`.
+
+        The weights are treated as frequencies: the weighted sum of squared
+        deviations from the weighted mean is divided by `sum(weights) - ddof`.
+        Values whose weight is null, and weights whose value is null, are ignored.
+
+        Parameters
+        ----------
+        weights
+            Non-negative weights, one for each value. Accepts expression input;
+            strings are parsed as column names.
+        ddof
+            “Delta Degrees of Freedom”: the divisor used in the calculation is
+            `sum(weights) - ddof`. By default ddof is 1.
+
+        See Also
+        --------
+        weighted_mean
+        std
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "g": ["a", "a", "b", "b", "b"],
+        ...         "x": [1.0, 3.0, 2.0, 4.0, 10.0],
+        ...         "w": [3, 1, 1, 1, 2],
+        ...     }
+        ... )
+        >>> df.group_by("g", maintain_order=True).agg(pl.col("x").weighted_std("w"))
+        shape: (2, 2)
+        ┌─────┬──────────┐
+        │ g   ┆ x        │
+        │ --- ┆ ---      │
+        │ str ┆ f64      │
+        ╞═════╪══════════╡
+        │ a   ┆ 1.0      │
+        │ b   ┆ 4.123106 │
+        └─────┴──────────┘
+        """
+        weights_pyexpr = parse_into_expression(weights)
+        return self._from_pyexpr(self._pyexpr.weighted_std(weights_pyexpr, ddof))
+
+    def weighted_quantile(self, weights: IntoExpr, quantile: float | Expr) -> Expr:
+        """
+        Get the quantile value weighted by `weights`.
+
+        This is the smallest value whose cumulative weight, in sorted order, is at
+        least `quantile` times the total weight. Values whose weight is null, and
+        weights whose value is null, are ignored.
+
+        Parameters
+        ----------
+        weights
+            Non-negative weights, one for each value. Accepts expression input;
+            strings are parsed as column names.
+        quantile
+            Quantile between 0.0 and 1.0.
+
+        See Also
+        --------
+        weighted_mean
+        quantile
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "g": ["a", "a", "b", "b", "b"],
+        ...         "x": [1.0, 3.0, 2.0, 4.0, 10.0],
+        ...         "w": [3, 1, 1, 1, 2],
+        ...     }
+        ... )
+        >>> df.group_by("g", maintain_order=True).agg(
+        ...     pl.col("x").weighted_quantile("w", 0.5)
+        ... )
+        shape: (2, 2)
+        ┌─────┬─────┐
+        │ g   ┆ x   │
+        │ --- ┆ --- │
+        │ str ┆ f64 │
+        ╞═════╪═════╡
+        │ a   ┆ 1.0 │
+        │ b   ┆ 4.0 │
+        └─────┴─────┘
+        """
+        weights_pyexpr = parse_into_expression(weights)
+        quantile_pyexpr = parse_into_expression(quantile)
+        return self._from_pyexpr(
+            self._pyexpr.weighted_quantile(weights_pyexpr, quantile_pyexpr)
+        )
+
     @unstable()
     def cut(
         self,
@@ -4927,39 +5281,161 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.gather_every(n, offset))
 
-    def head(self, n: int | Expr = 10) -> Expr:
+    @unstable()
+    def gather_by_ranges(
+        self,
+        starts: int | Sequence[int] | IntoExprColumn,
+        ends: int | Sequence[int] | IntoExprColumn,
+    ) -> Expr:
         """
-        Get the first `n` rows.
+        Take the rows `start` up to (but excluding) `end` for every pair of bounds.
+
+        The ranges are concatenated in order. This is much faster than gathering the
+        same rows by index, as every range is a zero-copy slice.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
 
         Parameters
         ----------
-        n
-            Number of rows to return.
+        starts
+            The (inclusive) starts of the ranges.
+        ends
+            The (exclusive) ends of the ranges. Either bound can be a single value
+            that is used for all ranges.
+
+        See Also
+        --------
+        gather : Take values by index.
+        slice : Take a single range of values.
 
         Examples
         --------
-        >>> df = pl.DataFrame({"foo": [1, 2, 3, 4, 5, 6, 7]})
-        >>> df.select(pl.col("foo").head(3))
-        shape: (3, 1)
+        >>> df = pl.DataFrame({"a": [0, 1, 2, 3, 4, 5, 6]})
+        >>> df.select(pl.col("a").gather_by_ranges([0, 4], [2, 6]))
+        shape: (4, 1)
         ┌─────┐
-        │ foo │
+        │ a   │
         │ --- │
         │ i64 │
         ╞═════╡
+        │ 0   │
         │ 1   │
-        │ 2   │
-        │ 3   │
+        │ 4   │
+        │ 5   │
         └─────┘
         """
-        return self.slice(0, n)
+        starts_pyexpr, ends_pyexpr = (
+            F.lit(pl.Series("", bound, dtype=Int64))._pyexpr
+            if isinstance(bound, Sequence) and not isinstance(bound, str)
+            else parse_into_expression(bound)
+            for bound in (starts, ends)
+        )
+        return self._from_pyexpr(
+            self._pyexpr.gather_by_ranges(starts_pyexpr, ends_pyexpr)
+        )
 
-    def tail(self, n: int | Expr = 10) -> Expr:
+    @unstable()
+    def scatter_by_mask(
+        self, mask: IntoExprColumn, values: IntoExpr | Sequence[Any]
+    ) -> Expr:
         """
-        Get the last `n` rows.
+        Replace the values where `mask` is true.
+
+        Unlike `when/then/otherwise`, `values` only holds the new values, one for
+        every true value of `mask`, which are set in order. Numeric columns are
+        updated in place where possible.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
 
         Parameters
         ----------
-        n
+        mask
+            A boolean expression that is true where values should be replaced.
+            Null values are treated as false.
+        values
+            The new values. Either one value for every true value of `mask`, or a
+            single value that is set at all of them.
+
+        See Also
+        --------
+        when : Conditionally choose between values.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 2, 3, 4]})
+        >>> df.with_columns(pl.col("a").scatter_by_mask(pl.col("a") % 2 == 0, 0))
+        shape: (4, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ i64 │
+        ╞═════╡
+        │ 1   │
+        │ 0   │
+        │ 3   │
+        │ 0   │
+        └─────┘
+        >>> df.with_columns(
+        ...     pl.col("a").scatter_by_mask(pl.col("a") > 2, pl.Series([30, 40]))
+        ... )
+        shape: (4, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ i64 │
+        ╞═════╡
+        │ 1   │
+        │ 2   │
+        │ 30  │
+        │ 40  │
+        └─────┘
+        """
+        mask_pyexpr = parse_into_expression(mask)
+        if isinstance(values, Sequence) and not isinstance(values, str):
+            values_pyexpr = F.lit(pl.Series("", values))._pyexpr
+        else:
+            values_pyexpr = parse_into_expression(values, str_as_lit=True)
+        return self._from_pyexpr(
+            self._pyexpr.scatter_by_mask(mask_pyexpr, values_pyexpr)
+        )
+
+    def head(self, n: int | Expr = 10) -> Expr:
+        """
+        Get the first `n` rows.
+
+        Parameters
+        ----------
+        n
+            Number of rows to return.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"foo": [1, 2, 3, 4, 5, 6, 7]})
+        >>> df.select(pl.col("foo").head(3))
+        shape: (3, 1)
+        ┌─────┐
+        │ foo │
+        │ --- │
+        │ i64 │
+        ╞═════╡
+        │ 1   │
+        │ 2   │
+        │ 3   │
+        └─────┘
+        """
+        return self.slice(0, n)
+
+    def tail(self, n: int | Expr = 10) -> Expr:
+        """
+        Get the last `n` rows.
+
+        Parameters
+        ----------
+        n
             Number of rows to return.
 
         Examples
@@ -5990,11 +6466,13 @@ class Expr:
         seed_1: int | None = None,
         seed_2: int | None = None,
         seed_3: int | None = None,
+        *,
+        algorithm: HashAlgorithm = "default",
     ) -> Expr:
         """
         Hash the elements in the selection.
 
-        The hash value is of type `UInt64`.
+        The hash value is of type `UInt64`, or `Binary` for the `'sha256'` algorithm.
 
         Parameters
         ----------
@@ -6006,12 +6484,29 @@ class Expr:
             Random seed parameter. Defaults to `seed` if not set.
         seed_3
             Random seed parameter. Defaults to `seed` if not set.
+        algorithm : {'default', 'xxhash64', 'wyhash', 'siphash', 'sha256'}
+            The hash algorithm to use.
+
+            - 'default': the fast hasher Polars uses internally, seeded with all four
+              seeds. Supports all data types.
+            - 'xxhash64': XXH64, seeded with `seed`.
+            - 'wyhash': wyhash as implemented by version 0.5 of the `wyhash` crate,
+              seeded with `seed`.
+            - 'siphash': SipHash-2-4, keyed with `seed` and `seed_1`.
+            - 'sha256': SHA-256, returning the 32 byte digest. Seeds are ignored.
 
         Notes
         -----
-        This implementation of `hash` does not guarantee stable results
-        across different Polars versions. Its stability is only guaranteed within a
-        single version.
+        The `'default'` algorithm does not guarantee stable results across different
+        Polars versions. Its stability is only guaranteed within a single version.
+
+        The other algorithms are stable across versions and platforms, so their output
+        can be persisted, e.g. as keys, and compared with other systems. They hash a
+        canonical byte representation of every value: booleans are a single byte,
+        integers, temporal and decimal values are the little-endian bytes of their
+        physical representation, floats are their little-endian bytes with `-0.0`
+        mapped to `0.0` and a single NaN value, and strings and categoricals are their
+        UTF-8 bytes. Null values stay null and nested data types are not supported.
 
         Examples
         --------
@@ -6032,12 +6527,32 @@ class Expr:
         │ 1101441246220388612  ┆ 11638928888656214026 │
         │ 11638928888656214026 ┆ 13382926553367784577 │
         └──────────────────────┴──────────────────────┘
+
+        Use a stable algorithm to get hashes that can be compared with other systems.
+
+        >>> df.select(
+        ...     pl.col("b").hash(algorithm="sha256").bin.encode("hex").str.head(16)
+        ... )
+        shape: (3, 1)
+        ┌──────────────────┐
+        │ b                │
+        │ ---              │
+        │ str              │
+        ╞══════════════════╡
+        │ 2d711642b726b044 │
+        │ null             │
+        │ 594e519ae499312b │
+        └──────────────────┘
         """
         k0 = seed
         k1 = seed_1 if seed_1 is not None else seed
         k2 = seed_2 if seed_2 is not None else seed
         k3 = seed_3 if seed_3 is not None else seed
-        return self._from_pyexpr(self._pyexpr.hash(k0, k1, k2, k3))
+        if algorithm == "default":
+            return self._from_pyexpr(self._pyexpr.hash(k0, k1, k2, k3))
+        return self._from_pyexpr(
+            self._pyexpr.hash_with_algorithm(k0, k1, k2, k3, algorithm)
+        )
 
     def reinterpret(self, *, signed: bool = True) -> Expr:
         """
@@ -6107,15 +6622,35 @@ class Expr:
 
         return self.map_batches(inspect, return_dtype=None, agg_list=True)
 
-    def interpolate(self, method: InterpolationMethod = "linear") -> Expr:
+    def interpolate(
+        self, method: InterpolationMethod = "linear", *, by: IntoExpr | None = None
+    ) -> Expr:
         """
         Fill null values using interpolation.
 
         Parameters
         ----------
-        method : {'linear', 'nearest'}
+        method : {'linear', 'nearest', 'spline', 'pchip', 'time'}
             Interpolation method.
 
+            - 'linear': linear interpolation between the surrounding values.
+            - 'nearest': use the nearest non-null value.
+            - 'spline': natural cubic spline through the non-null values.
+            - 'pchip': monotonic piecewise cubic Hermite interpolation; does not
+              overshoot the surrounding values.
+            - 'time': linear interpolation weighted by the distance between the
+              values of `by`, typically a datetime column. Equivalent to
+              :meth:`interpolate_by`.
+        by
+            Column to weight the interpolation by. Required for `method='time'`
+            and not allowed for other methods.
+
+        Notes
+        -----
+        The 'linear', 'nearest', 'spline' and 'pchip' methods treat the values as
+        equally spaced. Nulls before the first and after the last non-null value
+        are not filled.
+
         Examples
         --------
         Fill null values using linear interpolation.
@@ -6181,7 +6716,55 @@ class Expr:
         │ 9           ┆ 18.0   │
         │ 10          ┆ 20.0   │
         └─────────────┴────────┘
-        """
+
+        Fill null values using a monotonic cubic interpolation.
+
+        >>> df = pl.DataFrame({"a": [0.0, None, 1.0, None, 1.0, None, 5.0]})
+        >>> df.select(pl.col("a").interpolate("pchip"))
+        shape: (7, 1)
+        ┌────────┐
+        │ a      │
+        │ ---    │
+        │ f64    │
+        ╞════════╡
+        │ 0.0    │
+        │ 0.6875 │
+        │ 1.0    │
+        │ 1.0    │
+        │ 1.0    │
+        │ 2.25   │
+        │ 5.0    │
+        └────────┘
+
+        Fill null values weighted by the distance in time.
+
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "time": [date(2020, 1, 1), date(2020, 1, 2), date(2020, 1, 5)],
+        ...         "value": [1.0, None, 5.0],
+        ...     }
+        ... )
+        >>> df.with_columns(pl.col("value").interpolate("time", by="time"))
+        shape: (3, 2)
+        ┌────────────┬───────┐
+        │ time       ┆ value │
+        │ ---        ┆ ---   │
+        │ date       ┆ f64   │
+        ╞════════════╪═══════╡
+        │ 2020-01-01 ┆ 1.0   │
+        │ 2020-01-02 ┆ 2.0   │
+        │ 2020-01-05 ┆ 5.0   │
+        └────────────┴───────┘
+        """
+        if method == "time":
+            if by is None:
+                msg = "`by` must be given when interpolating with `method='time'`"
+                raise ValueError(msg)
+            return self.interpolate_by(by)
+        if by is not None:
+            msg = f"`by` is only supported for `method='time'`, got method={method!r}"
+            raise ValueError(msg)
         return self._from_pyexpr(self._pyexpr.interpolate(method))
 
     def interpolate_by(self, by: IntoExpr) -> Expr:
@@ -8333,6 +8916,117 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.rolling_skew(window_size, bias))
 
+    @unstable()
+    def rolling_mode(
+        self,
+        window_size: int,
+        *,
+        min_samples: int | None = None,
+        center: bool = False,
+    ) -> Expr:
+        """
+        Compute the most frequent value in a rolling window.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The window at a given row will include the row itself, and the `window_size - 1`
+        elements before it. Null values are ignored, and ties are broken in favor of the
+        smallest value. The values can be of any data type that can be sorted, such as
+        strings or categoricals.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window in number of elements.
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        Notes
+        -----
+        The number of occurrences of every value is updated as the window slides, so
+        the cost doesn't grow with the window size.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"signal": ["a", "b", "b", "c", "a", "c", "c"]})
+        >>> df.with_columns(mode=pl.col("signal").rolling_mode(window_size=3))
+        shape: (7, 2)
+        ┌────────┬──────┐
+        │ signal ┆ mode │
+        │ ---    ┆ ---  │
+        │ str    ┆ str  │
+        ╞════════╪══════╡
+        │ a      ┆ null │
+        │ b      ┆ null │
+        │ b      ┆ b    │
+        │ c      ┆ b    │
+        │ a      ┆ a    │
+        │ c      ┆ c    │
+        │ c      ┆ c    │
+        └────────┴──────┘
+        """
+        return self._from_pyexpr(
+            self._pyexpr.rolling_mode(window_size, min_samples, center)
+        )
+
+    @unstable()
+    def rolling_n_unique(
+        self,
+        window_size: int,
+        *,
+        min_samples: int | None = None,
+        center: bool = False,
+    ) -> Expr:
+        """
+        Compute the number of unique values in a rolling window.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The window at a given row will include the row itself, and the `window_size - 1`
+        elements before it. Null values are ignored.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window in number of elements.
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"signal": ["a", "b", "b", "c", "a", "c", "c"]})
+        >>> df.with_columns(
+        ...     n_unique=pl.col("signal").rolling_n_unique(window_size=3, min_samples=1)
+        ... )
+        shape: (7, 2)
+        ┌────────┬──────────┐
+        │ signal ┆ n_unique │
+        │ ---    ┆ ---      │
+        │ str    ┆ u32      │
+        ╞════════╪══════════╡
+        │ a      ┆ 1        │
+        │ b      ┆ 2        │
+        │ b      ┆ 2        │
+        │ c      ┆ 2        │
+        │ a      ┆ 3        │
+        │ c      ┆ 2        │
+        │ c      ┆ 2        │
+        └────────┴──────────┘
+        """
+        return self._from_pyexpr(
+            self._pyexpr.rolling_n_unique(window_size, min_samples, center)
+        )
+
     @unstable()
     @deprecate_renamed_parameter("min_periods", "min_samples", version="1.21.0")
     def rolling_map(
@@ -8343,6 +9037,7 @@ class Expr:
         *,
         min_samples: int | None = None,
         center: bool = False,
+        return_dtype: PolarsDataType | None = None,
     ) -> Expr:
         """
         Compute a custom rolling window function.
@@ -8365,6 +9060,11 @@ class Expr:
             a result. If set to `None` (default), it will be set equal to `window_size`.
         center
             Set the labels at the center of the window.
+        return_dtype
+            Dtype of the value the function returns per window. If set, the function
+            may return a value of a different dtype than the values, and the values
+            don't have to be numeric. If not set, the result is converted to the dtype
+            of the values.
 
         Warnings
         --------
@@ -8388,9 +9088,40 @@ class Expr:
         │ 11.0 │
         │ 17.0 │
         └──────┘
+
+        Compute a statistic of a different dtype than the values.
+
+        >>> df = pl.DataFrame({"a": ["x", "y", "x", "x"]})
+        >>> df.select(
+        ...     pl.col("a").rolling_map(
+        ...         lambda s: s.n_unique(), window_size=2, return_dtype=pl.UInt32
+        ...     )
+        ... )
+        shape: (4, 1)
+        ┌──────┐
+        │ a    │
+        │ ---  │
+        │ u32  │
+        ╞══════╡
+        │ null │
+        │ 2    │
+        │ 2    │
+        │ 1    │
+        └──────┘
         """
         if min_samples is None:
             min_samples = window_size
+        if return_dtype is not None:
+            return self._from_pyexpr(
+                self._pyexpr.rolling_map_udf(
+                    function,
+                    window_size,
+                    weights,
+                    min_samples,
+                    center,
+                    parse_into_dtype(return_dtype),
+                )
+            )
         return self._from_pyexpr(
             self._pyexpr.rolling_map(
                 function, window_size, weights, min_samples, center
@@ -8537,6 +9268,118 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.rank(method, descending, seed))
 
+    def percent_rank(self, *, descending: bool = False) -> Expr:
+        """
+        Compute the relative rank of each value, like SQL's `PERCENT_RANK`.
+
+        The relative rank is `(rank - 1) / (n - 1)`, where `rank` is the 'min' rank
+        and `n` is the number of non-null values, so it ranges from 0 to 1. Null
+        values stay null.
+
+        Parameters
+        ----------
+        descending
+            Rank in descending order.
+
+        See Also
+        --------
+        cume_dist
+        rank
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 1, 2, 2, 2], "b": [6, 7, 5, 14, 11]})
+        >>> df.with_columns(pl.col("b").percent_rank().over("a").alias("percent_rank"))
+        shape: (5, 3)
+        ┌─────┬─────┬──────────────┐
+        │ a   ┆ b   ┆ percent_rank │
+        │ --- ┆ --- ┆ ---          │
+        │ i64 ┆ i64 ┆ f64          │
+        ╞═════╪═════╪══════════════╡
+        │ 1   ┆ 6   ┆ 0.0          │
+        │ 1   ┆ 7   ┆ 1.0          │
+        │ 2   ┆ 5   ┆ 0.0          │
+        │ 2   ┆ 14  ┆ 1.0          │
+        │ 2   ┆ 11  ┆ 0.5          │
+        └─────┴─────┴──────────────┘
+        """
+        return self._from_pyexpr(self._pyexpr.percent_rank(descending))
+
+    def cume_dist(self, *, descending: bool = False) -> Expr:
+        """
+        Compute the cumulative distribution of each value, like SQL's `CUME_DIST`.
+
+        This is the fraction of non-null values that are ordered before or tied with
+        the value, so it ranges from `1 / n` to 1. Null values stay null.
+
+        Parameters
+        ----------
+        descending
+            Order the values in descending order.
+
+        See Also
+        --------
+        percent_rank
+        rank
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 1, 2, 2, 2], "b": [6, 7, 5, 14, 11]})
+        >>> df.with_columns(pl.col("b").cume_dist().over("a").alias("cume_dist"))
+        shape: (5, 3)
+        ┌─────┬─────┬───────────┐
+        │ a   ┆ b   ┆ cume_dist │
+        │ --- ┆ --- ┆ ---       │
+        │ i64 ┆ i64 ┆ f64       │
+        ╞═════╪═════╪═══════════╡
+        │ 1   ┆ 6   ┆ 0.5       │
+        │ 1   ┆ 7   ┆ 1.0       │
+        │ 2   ┆ 5   ┆ 0.333333  │
+        │ 2   ┆ 14  ┆ 1.0       │
+        │ 2   ┆ 11  ┆ 0.666667  │
+        └─────┴─────┴───────────┘
+        """
+        return self._from_pyexpr(self._pyexpr.cume_dist(descending))
+
+    def ntile(self, n: int, *, descending: bool = False) -> Expr:
+        """
+        Divide the sorted values into `n` buckets, like SQL's `NTILE`.
+
+        Buckets are numbered from 1 and differ in size by at most one, with the
+        larger buckets first. Tied values are assigned in order of occurrence, so
+        they may end up in different buckets. Null values stay null.
+
+        Parameters
+        ----------
+        n
+            Number of buckets; must be positive.
+        descending
+            Order the values in descending order.
+
+        See Also
+        --------
+        rank
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [3, 6, 1, 1, 6, 2]})
+        >>> df.with_columns(pl.col("a").ntile(3).alias("ntile"))
+        shape: (6, 2)
+        ┌─────┬───────┐
+        │ a   ┆ ntile │
+        │ --- ┆ ---   │
+        │ i64 ┆ u32   │
+        ╞═════╪═══════╡
+        │ 3   ┆ 2     │
+        │ 6   ┆ 3     │
+        │ 1   ┆ 1     │
+        │ 1   ┆ 1     │
+        │ 6   ┆ 3     │
+        │ 2   ┆ 2     │
+        └─────┴───────┘
+        """
+        return self._from_pyexpr(self._pyexpr.ntile(n, descending))
+
     def diff(self, n: int = 1, null_behavior: NullBehavior = "ignore") -> Expr:
         """
         Calculate the first discrete difference between shifted items.
@@ -9354,20 +10197,29 @@ class Expr:
         """
         Sample from this expression.
 
+        In a group context, such as :meth:`GroupBy.agg` or :meth:`over`, every group
+        is sampled separately. Combined with `over(..., mapping_strategy="explode")`,
+        this gives a stratified sample of the rows of a frame.
+
         Parameters
         ----------
         n
             Number of items to return. Cannot be used with `fraction`. Defaults to 1 if
-            `fraction` is None.
+            `fraction` is None. In a group context, this is the number of items per
+            group and may be an expression that gives a single value per group.
         fraction
-            Fraction of items to return. Cannot be used with `n`.
+            Fraction of items to return. Cannot be used with `n`. In a group context,
+            this is the fraction of every group and may be an expression that gives a
+            single value per group.
         with_replacement
             Allow values to be sampled more than once.
         shuffle
             Shuffle the order of sampled data points.
         seed
             Seed for the random number generator. If set to None (default), a
-            random seed is generated for each sample operation.
+            random seed is generated for each sample operation. In a group context,
+            every group is sampled with this seed, so sampling multiple columns with the
+            same seed selects the same rows.
 
         Examples
         --------
@@ -9383,6 +10235,22 @@ class Expr:
         │ 1   │
         │ 1   │
         └─────┘
+
+        Sample half of every group.
+
+        >>> df = pl.DataFrame({"g": ["x", "x", "x", "x", "y", "y"], "a": range(6)})
+        >>> df.group_by("g", maintain_order=True).agg(
+        ...     pl.col("a").sample(fraction=0.5, seed=0).len()
+        ... )
+        shape: (2, 2)
+        ┌─────┬─────┐
+        │ g   ┆ a   │
+        │ --- ┆ --- │
+        │ str ┆ u32 │
+        ╞═════╪═════╡
+        │ x   ┆ 2   │
+        │ y   ┆ 1   │
+        └─────┴─────┘
         """
         if n is not None and fraction is not None:
             msg = "cannot specify both `n` and `fraction`"
@@ -9581,6 +10449,162 @@ class Expr:
         half_life = parse_as_duration_string(half_life)
         return self._from_pyexpr(self._pyexpr.ewm_mean_by(by, half_life))
 
+    def ewm_std_by(
+        self,
+        by: str | IntoExpr,
+        *,
+        half_life: str | timedelta,
+    ) -> Expr:
+        r"""
+        Compute time-based exponentially weighted moving standard deviation.
+
+        Given observations :math:`x_0, x_1, \ldots, x_{n-1}` at times
+        :math:`t_0, t_1, \ldots, t_{n-1}`, the mean :math:`y_i` is updated as in
+        :meth:`ewm_mean_by` and the variance as
+
+            .. math::
+
+                v_0 &= 0
+
+                v_i &= (1 - \alpha_i) \left( v_{i-1} + \alpha_i (x_i - y_{i-1})^2
+                    \right); \quad i > 0
+
+        where :math:`\alpha_i` depends on the time since the previous observation
+        and the `half_life`, so irregularly sampled data is weighted correctly. The
+        result is :math:`\sqrt{v_i}`.
+
+        Parameters
+        ----------
+        by
+            Times to calculate the standard deviation by. Should be ``DateTime``,
+            ``Date``, ``UInt64``, ``UInt32``, ``Int64``, or ``Int32`` data type.
+        half_life
+            Unit over which observation decays to half its value. Accepts the same
+            values as :meth:`ewm_mean_by`, and is likewise treated as a constant
+            duration.
+
+        Returns
+        -------
+        Expr
+            Float32 if input is Float32, otherwise Float64.
+
+        See Also
+        --------
+        ewm_mean_by
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "values": [0, 1, 2, None, 4],
+        ...         "times": [
+        ...             date(2020, 1, 1),
+        ...             date(2020, 1, 3),
+        ...             date(2020, 1, 10),
+        ...             date(2020, 1, 15),
+        ...             date(2020, 1, 17),
+        ...         ],
+        ...     }
+        ... ).sort("times")
+        >>> df.with_columns(
+        ...     result=pl.col("values").ewm_std_by("times", half_life="4d"),
+        ... )
+        shape: (5, 3)
+        ┌────────┬────────────┬──────────┐
+        │ values ┆ times      ┆ result   │
+        │ ---    ┆ ---        ┆ ---      │
+        │ i64    ┆ date       ┆ f64      │
+        ╞════════╪════════════╪══════════╡
+        │ 0      ┆ 2020-01-01 ┆ 0.0      │
+        │ 1      ┆ 2020-01-03 ┆ 0.45509  │
+        │ 2      ┆ 2020-01-10 ┆ 0.818774 │
+        │ null   ┆ 2020-01-15 ┆ null     │
+        │ 4      ┆ 2020-01-17 ┆ 1.229996 │
+        └────────┴────────────┴──────────┘
+        """
+        by = parse_into_expression(by)
+        half_life = parse_as_duration_string(half_life)
+        return self._from_pyexpr(self._pyexpr.ewm_std_by(by, half_life))
+
+    def ewm_var_by(
+        self,
+        by: str | IntoExpr,
+        *,
+        half_life: str | timedelta,
+    ) -> Expr:
+        r"""
+        Compute time-based exponentially weighted moving variance.
+
+        Given observations :math:`x_0, x_1, \ldots, x_{n-1}` at times
+        :math:`t_0, t_1, \ldots, t_{n-1}`, the mean :math:`y_i` is updated as in
+        :meth:`ewm_mean_by` and the variance as
+
+            .. math::
+
+                v_0 &= 0
+
+                v_i &= (1 - \alpha_i) \left( v_{i-1} + \alpha_i (x_i - y_{i-1})^2
+                    \right); \quad i > 0
+
+        where :math:`\alpha_i` depends on the time since the previous observation
+        and the `half_life`, so irregularly sampled data is weighted correctly. The
+        result is :math:`v_i`.
+
+        Parameters
+        ----------
+        by
+            Times to calculate the variance by. Should be ``DateTime``, ``Date``,
+            ``UInt64``, ``UInt32``, ``Int64``, or ``Int32`` data type.
+        half_life
+            Unit over which observation decays to half its value. Accepts the same
+            values as :meth:`ewm_mean_by`, and is likewise treated as a constant
+            duration.
+
+        Returns
+        -------
+        Expr
+            Float32 if input is Float32, otherwise Float64.
+
+        See Also
+        --------
+        ewm_mean_by
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "values": [0, 1, 2, None, 4],
+        ...         "times": [
+        ...             date(2020, 1, 1),
+        ...             date(2020, 1, 3),
+        ...             date(2020, 1, 10),
+        ...             date(2020, 1, 15),
+        ...             date(2020, 1, 17),
+        ...         ],
+        ...     }
+        ... ).sort("times")
+        >>> df.with_columns(
+        ...     result=pl.col("values").ewm_var_by("times", half_life="4d"),
+        ... )
+        shape: (5, 3)
+        ┌────────┬────────────┬──────────┐
+        │ values ┆ times      ┆ result   │
+        │ ---    ┆ ---        ┆ ---      │
+        │ i64    ┆ date       ┆ f64      │
+        ╞════════╪════════════╪══════════╡
+        │ 0      ┆ 2020-01-01 ┆ 0.0      │
+        │ 1      ┆ 2020-01-03 ┆ 0.207107 │
+        │ 2      ┆ 2020-01-10 ┆ 0.670392 │
+        │ null   ┆ 2020-01-15 ┆ null     │
+        │ 4      ┆ 2020-01-17 ┆ 1.512891 │
+        └────────┴────────────┴──────────┘
+        """
+        by = parse_into_expression(by)
+        half_life = parse_as_duration_string(half_life)
+        return self._from_pyexpr(self._pyexpr.ewm_var_by(by, half_life))
+
     @deprecate_renamed_parameter("min_periods", "min_samples", version="1.21.0")
     def ewm_std(
         self,
@@ -9765,6 +10789,208 @@ class Expr:
             self._pyexpr.ewm_var(alpha, adjust, bias, min_samples, ignore_nulls)
         )
 
+    @unstable()
+    def ewm_cov(
+        self,
+        other: IntoExpr,
+        *,
+        com: float | None = None,
+        span: float | None = None,
+        half_life: float | None = None,
+        alpha: float | None = None,
+        adjust: bool = True,
+        bias: bool = False,
+        min_samples: int = 1,
+        ignore_nulls: bool = False,
+    ) -> Expr:
+        r"""
+        Compute exponentially-weighted moving covariance with another expression.
+
+        Only the rows in which both values are not null are observations; the result
+        is null in the other rows.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        other
+            Expression of the same length to compute the covariance with. Accepts
+            expression input; strings are parsed as column names.
+        com
+            Specify decay in terms of center of mass, :math:`\gamma`, with
+
+                .. math::
+                    \alpha = \frac{1}{1 + \gamma} \; \forall \; \gamma \geq 0
+        span
+            Specify decay in terms of span, :math:`\theta`, with
+
+                .. math::
+                    \alpha = \frac{2}{\theta + 1} \; \forall \; \theta \geq 1
+        half_life
+            Specify decay in terms of half-life, :math:`\lambda`, with
+
+                .. math::
+                    \alpha = 1 - \exp \left\{ \frac{ -\ln(2) }{ \lambda } \right\} \;
+                    \forall \; \lambda > 0
+        alpha
+            Specify smoothing factor alpha directly, :math:`0 < \alpha \leq 1`.
+        adjust
+            Divide by decaying adjustment factor in beginning periods to account for
+            imbalance in relative weightings
+
+                - When `adjust=True` (the default) the EW function is calculated
+                  using weights :math:`w_i = (1 - \alpha)^i`
+                - When `adjust=False` the EW function is calculated
+                  recursively by
+
+                  .. math::
+                    y_0 &= x_0 \\
+                    y_t &= (1 - \alpha)y_{t - 1} + \alpha x_t
+        bias
+            When `bias=False`, apply a correction to make the estimate statistically
+            unbiased.
+        min_samples
+            Minimum number of observations in window required to have a value
+            (otherwise result is null).
+        ignore_nulls
+            Ignore missing values when calculating weights.
+
+                - When `ignore_nulls=False` (default), weights are based on absolute
+                  positions.
+                - When `ignore_nulls=True`, weights are based on relative positions.
+
+            See :meth:`ewm_var` for the weights in either case.
+
+        See Also
+        --------
+        ewm_corr
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 2, 3, 4], "b": [2, 4, 5, 9]})
+        >>> df.select(pl.col("a").ewm_cov("b", com=1))
+        shape: (4, 1)
+        ┌──────────┐
+        │ a        │
+        │ ---      │
+        │ f64      │
+        ╞══════════╡
+        │ 0.0      │
+        │ 1.0      │
+        │ 1.285714 │
+        │ 3.514286 │
+        └──────────┘
+        """
+        alpha = _prepare_alpha(com, span, half_life, alpha)
+        other_pyexpr = parse_into_expression(other)
+        return self._from_pyexpr(
+            self._pyexpr.ewm_cov(
+                other_pyexpr, alpha, adjust, bias, min_samples, ignore_nulls
+            )
+        )
+
+    @unstable()
+    def ewm_corr(
+        self,
+        other: IntoExpr,
+        *,
+        com: float | None = None,
+        span: float | None = None,
+        half_life: float | None = None,
+        alpha: float | None = None,
+        adjust: bool = True,
+        min_samples: int = 1,
+        ignore_nulls: bool = False,
+    ) -> Expr:
+        r"""
+        Compute exponentially-weighted moving correlation with another expression.
+
+        The covariance and the standard deviations are estimated on the same
+        observations: the rows in which both values are not null. The result is null
+        in the other rows, and NaN while either standard deviation is zero, such as
+        at the first observation.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        other
+            Expression of the same length to compute the correlation with. Accepts
+            expression input; strings are parsed as column names.
+        com
+            Specify decay in terms of center of mass, :math:`\gamma`, with
+
+                .. math::
+                    \alpha = \frac{1}{1 + \gamma} \; \forall \; \gamma \geq 0
+        span
+            Specify decay in terms of span, :math:`\theta`, with
+
+                .. math::
+                    \alpha = \frac{2}{\theta + 1} \; \forall \; \theta \geq 1
+        half_life
+            Specify decay in terms of half-life, :math:`\lambda`, with
+
+                .. math::
+                    \alpha = 1 - \exp \left\{ \frac{ -\ln(2) }{ \lambda } \right\} \;
+                    \forall \; \lambda > 0
+        alpha
+            Specify smoothing factor alpha directly, :math:`0 < \alpha \leq 1`.
+        adjust
+            Divide by decaying adjustment factor in beginning periods to account for
+            imbalance in relative weightings
+
+                - When `adjust=True` (the default) the EW function is calculated
+                  using weights :math:`w_i = (1 - \alpha)^i`
+                - When `adjust=False` the EW function is calculated
+                  recursively by
+
+                  .. math::
+                    y_0 &= x_0 \\
+                    y_t &= (1 - \alpha)y_{t - 1} + \alpha x_t
+        min_samples
+            Minimum number of observations in window required to have a value
+            (otherwise result is null).
+        ignore_nulls
+            Ignore missing values when calculating weights.
+
+                - When `ignore_nulls=False` (default), weights are based on absolute
+                  positions.
+                - When `ignore_nulls=True`, weights are based on relative positions.
+
+            See :meth:`ewm_var` for the weights in either case.
+
+        See Also
+        --------
+        ewm_cov
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 2, 3, 4], "b": [2, 4, 5, 9]})
+        >>> df.select(pl.col("a").ewm_corr("b", com=1))
+        shape: (4, 1)
+        ┌──────────┐
+        │ a        │
+        │ ---      │
+        │ f64      │
+        ╞══════════╡
+        │ NaN      │
+        │ 1.0      │
+        │ 0.979071 │
+        │ 0.959256 │
+        └──────────┘
+        """
+        alpha = _prepare_alpha(com, span, half_life, alpha)
+        other_pyexpr = parse_into_expression(other)
+        return self._from_pyexpr(
+            self._pyexpr.ewm_corr(
+                other_pyexpr, alpha, adjust, min_samples, ignore_nulls
+            )
+        )
+
     def extend_constant(self, value: IntoExpr, n: int | IntoExprColumn) -> Expr:
         """
         Extremely fast method for extending the Series with 'n' copies of a value.
@@ -10830,6 +12056,21 @@ class Expr:
         """Create an object namespace of all datetime related methods."""
         return ExprDateTimeNameSpace(self)
 
+    @property
+    def ip(self) -> ExprIpNameSpace:
+        """Create an object namespace of all IP address related methods."""
+        return ExprIpNameSpace(self)
+
+    @property
+    def st(self) -> ExprGeometryNameSpace:
+        """Create an object namespace of all spatial related methods."""
+        return ExprGeometryNameSpace(self)
+
+    @property
+    def url(self) -> ExprUrlNameSpace:
+        """Create an object namespace of all URL related methods."""
+        return ExprUrlNameSpace(self)
+
     # Keep the `list` and `str` properties below at the end of the definition of Expr,
     # as to not confuse mypy with the type annotation `str` and `list`
 
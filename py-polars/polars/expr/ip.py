@@ -0,0 +1,250 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars._utils.parse import parse_into_expression
+from polars._utils.unstable import unstable
+from polars._utils.wrap import wrap_expr
+
+if TYPE_CHECKING:
+    from polars import Expr
+    from polars._typing import IntoExprColumn
+
+
+class ExprIpNameSpace:
+    """
+    Namespace for IP address related expressions.
+
+    IP addresses are represented as 16 byte :class:`Binary` values, where IPv4
+    addresses are stored as IPv4-mapped IPv6 addresses. Besides such values, the
+    methods accept strings, which are parsed, and unsigned integers, which are
+    read as IPv4 addresses.
+    """
+
+    _accessor = "ip"
+
+    def __init__(self, expr: Expr) -> None:
+        self._pyexpr = expr._pyexpr
+
+    @unstable()
+    def parse(self, *, strict: bool = True) -> Expr:
+        """
+        Parse IPv4 and IPv6 addresses.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        strict
+            Raise an error if a string is not a valid IP address. If set to `False`,
+            invalid addresses are set to null instead.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Binary`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"addr": ["192.168.1.10", "2001:DB8::1", "not an ip", None]}
+        ... )
+        >>> df.with_columns(ip=pl.col("addr").ip.parse(strict=False).ip.to_string())
+        shape: (4, 2)
+        ┌──────────────┬──────────────┐
+        │ addr         ┆ ip           │
+        │ ---          ┆ ---          │
+        │ str          ┆ str          │
+        ╞══════════════╪══════════════╡
+        │ 192.168.1.10 ┆ 192.168.1.10 │
+        │ 2001:DB8::1  ┆ 2001:db8::1  │
+        │ not an ip    ┆ null         │
+        │ null         ┆ null         │
+        └──────────────┴──────────────┘
+        """
+        return wrap_expr(self._pyexpr.ip_parse(strict))
+
+    @unstable()
+    def to_string(self) -> Expr:
+        """
+        Format IP addresses in their canonical text representation.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        IPv4 addresses are formatted in dotted decimal notation, and IPv6 addresses
+        in their shortest form, e.g. `2001:db8::1`.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`String`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"addr": ["192.168.1.10", "2001:DB8::1", "not an ip", None]}
+        ... )
+        >>> df.with_columns(ip=pl.col("addr").ip.parse(strict=False).ip.to_string())
+        shape: (4, 2)
+        ┌──────────────┬──────────────┐
+        │ addr         ┆ ip           │
+        │ ---          ┆ ---          │
+        │ str          ┆ str          │
+        ╞══════════════╪══════════════╡
+        │ 192.168.1.10 ┆ 192.168.1.10 │
+        │ 2001:DB8::1  ┆ 2001:db8::1  │
+        │ not an ip    ┆ null         │
+        │ null         ┆ null         │
+        └──────────────┴──────────────┘
+        """
+        return wrap_expr(self._pyexpr.ip_to_string())
+
+    @unstable()
+    def version(self) -> Expr:
+        """
+        Get the version of IP addresses, 4 or 6.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`UInt8`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"addr": ["192.168.1.10", "10.0.0.1", "2001:db8::1"]})
+        >>> df.with_columns(version=pl.col("addr").ip.version())
+        shape: (3, 2)
+        ┌──────────────┬─────────┐
+        │ addr         ┆ version │
+        │ ---          ┆ ---     │
+        │ str          ┆ u8      │
+        ╞══════════════╪═════════╡
+        │ 192.168.1.10 ┆ 4       │
+        │ 10.0.0.1     ┆ 4       │
+        │ 2001:db8::1  ┆ 6       │
+        └──────────────┴─────────┘
+        """
+        return wrap_expr(self._pyexpr.ip_version())
+
+    @unstable()
+    def is_in_cidr(self, cidr: IntoExprColumn) -> Expr:
+        """
+        Check if IP addresses are in a CIDR block.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        cidr
+            A CIDR block such as `"10.0.0.0/8"` or `"2001:db8::/32"`, or an
+            expression of such blocks. An address without a prefix length is a
+            block of a single address. IPv4 blocks also contain the equivalent
+            IPv4-mapped IPv6 addresses.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"addr": ["192.168.1.10", "10.0.0.1", "2001:db8::1"]})
+        >>> df.with_columns(
+        ...     private=pl.col("addr").ip.is_in_cidr("10.0.0.0/8")
+        ...     | pl.col("addr").ip.is_in_cidr("192.168.0.0/16")
+        ... )
+        shape: (3, 2)
+        ┌──────────────┬─────────┐
+        │ addr         ┆ private │
+        │ ---          ┆ ---     │
+        │ str          ┆ bool    │
+        ╞══════════════╪═════════╡
+        │ 192.168.1.10 ┆ true    │
+        │ 10.0.0.1     ┆ true    │
+        │ 2001:db8::1  ┆ false   │
+        └──────────────┴─────────┘
+        """
+        cidr_pyexpr = parse_into_expression(cidr, str_as_lit=True)
+        return wrap_expr(self._pyexpr.ip_is_in_cidr(cidr_pyexpr))
+
+    @unstable()
+    def subnet(self, prefix_len: int) -> Expr:
+        """
+        Get the network address of the subnet that contains each IP address.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        prefix_len
+            The number of leading bits of the address that identify the subnet.
+            The prefix length counts in the address space of each address, so
+            prefix lengths above 32 leave IPv4 addresses unchanged.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Binary`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"addr": ["192.168.1.10", "10.0.0.1", "2001:db8::1"]})
+        >>> df.with_columns(subnet=pl.col("addr").ip.subnet(16).ip.to_string())
+        shape: (3, 2)
+        ┌──────────────┬─────────────┐
+        │ addr         ┆ subnet      │
+        │ ---          ┆ ---         │
+        │ str          ┆ str         │
+        ╞══════════════╪═════════════╡
+        │ 192.168.1.10 ┆ 192.168.0.0 │
+        │ 10.0.0.1     ┆ 10.0.0.0    │
+        │ 2001:db8::1  ┆ 2001::      │
+        └──────────────┴─────────────┘
+        """
+        return wrap_expr(self._pyexpr.ip_subnet(prefix_len))
+
+    @unstable()
+    def to_integer(self) -> Expr:
+        """
+        Convert IPv4 addresses to integers.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        IPv6 addresses don't fit in a 32-bit integer and are set to null. Integers
+        can be converted back to IP addresses with :meth:`parse`.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`UInt32`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"addr": ["192.168.1.10", "10.0.0.1", "2001:db8::1"]})
+        >>> df.with_columns(int=pl.col("addr").ip.to_integer())
+        shape: (3, 2)
+        ┌──────────────┬────────────┐
+        │ addr         ┆ int        │
+        │ ---          ┆ ---        │
+        │ str          ┆ u32        │
+        ╞══════════════╪════════════╡
+        │ 192.168.1.10 ┆ 3232235786 │
+        │ 10.0.0.1     ┆ 167772161  │
+        │ 2001:db8::1  ┆ null       │
+        └──────────────┴────────────┘
+        """
+        return wrap_expr(self._pyexpr.ip_to_integer())
@@ -0,0 +1,178 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars._utils.parse import parse_into_expression
+from polars._utils.unstable import unstable
+from polars._utils.wrap import wrap_expr
+
+if TYPE_CHECKING:
+    from polars import Expr
+    from polars._typing import IntoExpr
+
+
+class ExprGeometryNameSpace:
+    """
+    Namespace for spatial expressions.
+
+    Geometries are represented as :class:`Geometry` values holding planar geometries
+    encoded as Well-Known Binary (WKB). The methods also accept :class:`Binary`
+    values holding WKB. Only the `x` and `y` coordinates are taken into account.
+    """
+
+    _accessor = "st"
+
+    def __init__(self, expr: Expr) -> None:
+        self._pyexpr = expr._pyexpr
+
+    @unstable()
+    def area(self) -> Expr:
+        """
+        Compute the area of geometries.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The area of holes is subtracted from the area of polygons. Points and lines
+        have an area of 0.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Float64`.
+
+        Examples
+        --------
+        >>> import struct
+        >>> square = struct.pack("<BIII10d", 1, 3, 1, 5, 0, 0, 2, 0, 2, 2, 0, 2, 0, 0)
+        >>> point = struct.pack("<BI2d", 1, 1, 3, 4)
+        >>> df = pl.DataFrame({"geom": [square, point]}, schema={"geom": pl.Geometry})
+        >>> df.select(pl.col("geom").st.area())
+        shape: (2, 1)
+        ┌──────┐
+        │ geom │
+        │ ---  │
+        │ f64  │
+        ╞══════╡
+        │ 4.0  │
+        │ 0.0  │
+        └──────┘
+        """
+        return wrap_expr(self._pyexpr.st_area())
+
+    @unstable()
+    def centroid(self) -> Expr:
+        """
+        Compute the centroid of geometries.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The centroid of polygons is weighted by area and the centroid of lines by
+        length. Only the components of the highest dimension of a geometry are taken
+        into account. The centroid of an empty geometry is null.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Geometry`, holding points.
+
+        Examples
+        --------
+        >>> import struct
+        >>> square = struct.pack("<BIII10d", 1, 3, 1, 5, 0, 0, 2, 0, 2, 2, 0, 2, 0, 0)
+        >>> point = struct.pack("<BI2d", 1, 1, 3, 4)
+        >>> df = pl.DataFrame({"geom": [square, point]}, schema={"geom": pl.Geometry})
+        >>> centroids = df.select(pl.col("geom").st.centroid()).to_series()
+        >>> [struct.unpack("<2d", wkb[5:]) for wkb in centroids]
+        [(1.0, 1.0), (3.0, 4.0)]
+        """
+        return wrap_expr(self._pyexpr.st_centroid())
+
+    @unstable()
+    def contains(self, other: IntoExpr) -> Expr:
+        """
+        Check if geometries contain other geometries.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        A geometry contains another geometry if no point of the other geometry
+        lies outside of it, and at least one point lies in its interior. Hence a
+        polygon does not contain the points on its boundary.
+
+        Parameters
+        ----------
+        other
+            The geometries to check for, as WKB `bytes` or an expression.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> import struct
+        >>> square = struct.pack("<BIII10d", 1, 3, 1, 5, 0, 0, 2, 0, 2, 2, 0, 2, 0, 0)
+        >>> point = struct.pack("<BI2d", 1, 1, 3, 4)
+        >>> df = pl.DataFrame({"geom": [square, point]}, schema={"geom": pl.Geometry})
+        >>> df.select(pl.col("geom").st.contains(struct.pack("<BI2d", 1, 1, 1, 1)))
+        shape: (2, 1)
+        ┌───────┐
+        │ geom  │
+        │ ---   │
+        │ bool  │
+        ╞═══════╡
+        │ true  │
+        │ false │
+        └───────┘
+        """
+        other_pyexpr = parse_into_expression(other)
+        return wrap_expr(self._pyexpr.st_contains(other_pyexpr))
+
+    @unstable()
+    def distance(self, other: IntoExpr) -> Expr:
+        """
+        Compute the minimum Euclidean distance between geometries.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The distance is 0 if the geometries intersect, and null if either of the
+        geometries is empty.
+
+        Parameters
+        ----------
+        other
+            The geometries to compute the distance to, as WKB `bytes` or an
+            expression.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Float64`.
+
+        Examples
+        --------
+        >>> import struct
+        >>> square = struct.pack("<BIII10d", 1, 3, 1, 5, 0, 0, 2, 0, 2, 2, 0, 2, 0, 0)
+        >>> point = struct.pack("<BI2d", 1, 1, 3, 4)
+        >>> df = pl.DataFrame({"geom": [square, point]}, schema={"geom": pl.Geometry})
+        >>> df.select(pl.col("geom").st.distance(struct.pack("<BI2d", 1, 1, 0, 0)))
+        shape: (2, 1)
+        ┌──────┐
+        │ geom │
+        │ ---  │
+        │ f64  │
+        ╞══════╡
+        │ 0.0  │
+        │ 5.0  │
+        └──────┘
+        """
+        other_pyexpr = parse_into_expression(other)
+        return wrap_expr(self._pyexpr.st_distance(other_pyexpr))
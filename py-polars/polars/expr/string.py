@@ -206,6 +206,42 @@ class ExprStringNameSpace:
         _validate_format_argument(format)
         return wrap_expr(self._pyexpr.str_to_time(format, strict, cache))
 
+    def to_duration(self, time_unit: TimeUnit = "us", *, strict: bool = True) -> Expr:
+        """
+        Convert a String column into a Duration column.
+
+        Accepts ISO 8601 durations, such as `"P1DT2H"` or `"-PT0.5S"`, and durations
+        as displayed by Polars, such as `"1d 2h 30m"`. Year and month designators
+        are not supported as they do not have a fixed length.
+
+        Parameters
+        ----------
+        time_unit : {'us', 'ns', 'ms'}
+            Time unit of the resulting Duration column.
+        strict
+            Raise an error if any conversion fails. If set to `False`, values that
+            cannot be parsed are set to null.
+
+        See Also
+        --------
+        polars.Expr.dt.to_string : Format durations as ISO 8601 (`"iso"`) or as
+            displayed by Polars (`"polars"`).
+
+        Examples
+        --------
+        >>> s = pl.Series(["P1DT2H", "PT1M30.5S", "-PT15M", "1d 2h"])
+        >>> s.str.to_duration()
+        shape: (4,)
+        Series: '' [duration[μs]]
+        [
+                1d 2h
+                1m 30s 500ms
+                -15m
+                1d 2h
+        ]
+        """
+        return wrap_expr(self._pyexpr.str_to_duration(time_unit, strict))
+
     def strptime(
         self,
         dtype: PolarsTemporalType,
@@ -1273,7 +1309,9 @@ class ExprStringNameSpace:
         are cast to :class:`String`, regardless of the original value.
 
         Documentation on the JSONPath standard can be found
-        `here <https://goessner.net/articles/JsonPath/>`_.
+        `here <https://goessner.net/articles/JsonPath/>`_. The full syntax is
+        supported, including recursive descent (`$..a`), filters
+        (`$.a[?(@.b > 1)]`) and array slices (`$.a[1:3]`).
 
         Parameters
         ----------
@@ -1286,6 +1324,10 @@ class ExprStringNameSpace:
             Expression of data type :class:`String`. Contains null values if original
             value is null or the json_path returns nothing.
 
+        See Also
+        --------
+        json_extract_all : Extract all matches from a JSON string.
+
         Examples
         --------
         >>> df = pl.DataFrame(
@@ -1308,6 +1350,66 @@ class ExprStringNameSpace:
         json_path = parse_into_expression(json_path, str_as_lit=True)
         return wrap_expr(self._pyexpr.str_json_path_match(json_path))
 
+    @unstable()
+    def json_extract_all(self, json_path: IntoExprColumn) -> Expr:
+        """
+        Extract all matches from a JSON string using the provided JSONPath.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Unlike :meth:`json_path_match`, which only returns the first match, this
+        returns every value the query matches, in document order. This makes
+        queries with recursive descent, filters or slices useful without first
+        decoding the JSON into structs. All values are cast to :class:`String`,
+        and JSON nulls become null elements.
+
+        Parameters
+        ----------
+        json_path
+            A valid JSONPath query string.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`List` with inner data type
+            :class:`String`. Null if the original value is null or not valid JSON;
+            an empty list if the json_path matches nothing.
+
+        See Also
+        --------
+        json_path_match : Extract the first match from a JSON string.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "json_val": [
+        ...             '{"items":[{"id":1,"tag":"a"},{"id":2,"tag":"b"}]}',
+        ...             '{"items":[{"id":3,"tag":"c"}]}',
+        ...             None,
+        ...         ]
+        ...     }
+        ... )
+        >>> df.select(
+        ...     ids=pl.col("json_val").str.json_extract_all("$..id"),
+        ...     tags=pl.col("json_val").str.json_extract_all("$.items[?(@.id>1)].tag"),
+        ... )
+        shape: (3, 2)
+        ┌────────────┬───────────┐
+        │ ids        ┆ tags      │
+        │ ---        ┆ ---       │
+        │ list[str]  ┆ list[str] │
+        ╞════════════╪═══════════╡
+        │ ["1", "2"] ┆ ["b"]     │
+        │ ["3"]      ┆ ["c"]     │
+        │ null       ┆ null      │
+        └────────────┴───────────┘
+        """
+        json_path = parse_into_expression(json_path, str_as_lit=True)
+        return wrap_expr(self._pyexpr.str_json_extract_all(json_path))
+
     def decode(self, encoding: TransferEncoding, *, strict: bool = True) -> Expr:
         r"""
         Decode values using the provided encoding.
@@ -2980,6 +3082,371 @@ class ExprStringNameSpace:
         """  # noqa: RUF002
         return wrap_expr(self._pyexpr.str_normalize(form))
 
+    @unstable()
+    def soundex(self, *, ascii_fold: bool = True) -> Expr:
+        """
+        Encode string values with American Soundex.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Soundex encodes a string as its first letter followed by three digits, so
+        that names that sound alike in English get the same code. This makes the
+        codes useful as join keys for approximate name matching.
+
+        Parameters
+        ----------
+        ascii_fold
+            If True, fold non-ASCII letters to their closest ASCII equivalents
+            before encoding, e.g. `é` to `e` and `ß` to `ss`. Otherwise, non-ASCII
+            letters are ignored.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`String`. Strings without any letters
+            are encoded as null.
+
+        See Also
+        --------
+        metaphone : Encode string values with Metaphone.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"name": ["Robert", "Rupert", "Müller", "Mueller", None]})
+        >>> df.with_columns(pl.col("name").str.soundex().alias("soundex"))
+        shape: (5, 2)
+        ┌─────────┬─────────┐
+        │ name    ┆ soundex │
+        │ ---     ┆ ---     │
+        │ str     ┆ str     │
+        ╞═════════╪═════════╡
+        │ Robert  ┆ R163    │
+        │ Rupert  ┆ R163    │
+        │ Müller  ┆ M460    │
+        │ Mueller ┆ M460    │
+        │ null    ┆ null    │
+        └─────────┴─────────┘
+        """
+        return wrap_expr(self._pyexpr.str_soundex(ascii_fold))
+
+    @unstable()
+    def metaphone(self, *, ascii_fold: bool = True) -> Expr:
+        """
+        Encode string values with Metaphone.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Metaphone encodes a string by the way it is pronounced in English, so that
+        words that sound alike get the same code. It is more accurate than Soundex
+        and its codes don't have a fixed length. This makes the codes useful as join
+        keys for approximate name matching.
+
+        Parameters
+        ----------
+        ascii_fold
+            If True, fold non-ASCII letters to their closest ASCII equivalents
+            before encoding, e.g. `é` to `e` and `ß` to `ss`. Otherwise, non-ASCII
+            letters are ignored.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`String`. Strings without any letters
+            are encoded as null.
+
+        See Also
+        --------
+        soundex : Encode string values with American Soundex.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"name": ["Robert", "Rupert", "Müller", "Mueller", None]})
+        >>> df.with_columns(pl.col("name").str.metaphone().alias("metaphone"))
+        shape: (5, 2)
+        ┌─────────┬───────────┐
+        │ name    ┆ metaphone │
+        │ ---     ┆ ---       │
+        │ str     ┆ str       │
+        ╞═════════╪═══════════╡
+        │ Robert  ┆ RBRT      │
+        │ Rupert  ┆ RPRT      │
+        │ Müller  ┆ MLR       │
+        │ Mueller ┆ MLR       │
+        │ null    ┆ null      │
+        └─────────┴───────────┘
+        """
+        return wrap_expr(self._pyexpr.str_metaphone(ascii_fold))
+
+    @unstable()
+    def levenshtein(self, other: IntoExprColumn) -> Expr:
+        """
+        Compute the Levenshtein distance to another string.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The Levenshtein distance is the minimum number of single character
+        insertions, deletions and substitutions needed to change one string into the
+        other. Characters are compared as Unicode code points.
+
+        Parameters
+        ----------
+        other
+            The string to compare to. Accepts expression input; strings are parsed
+            as string literals.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`UInt32`.
+
+        See Also
+        --------
+        levenshtein_similarity : Compute the normalized Levenshtein similarity.
+        jaro_winkler : Compute the Jaro-Winkler similarity to another string.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": ["kitten", "flaw", "abc", None],
+        ...         "b": ["sitting", "lawn", "abd", "x"],
+        ...     }
+        ... )
+        >>> df.with_columns(dist=pl.col("a").str.levenshtein(pl.col("b")))
+        shape: (4, 3)
+        ┌────────┬─────────┬──────┐
+        │ a      ┆ b       ┆ dist │
+        │ ---    ┆ ---     ┆ ---  │
+        │ str    ┆ str     ┆ u32  │
+        ╞════════╪═════════╪══════╡
+        │ kitten ┆ sitting ┆ 3    │
+        │ flaw   ┆ lawn    ┆ 2    │
+        │ abc    ┆ abd     ┆ 1    │
+        │ null   ┆ x       ┆ null │
+        └────────┴─────────┴──────┘
+        """
+        other = parse_into_expression(other, str_as_lit=True)
+        return wrap_expr(self._pyexpr.str_levenshtein(other))
+
+    @unstable()
+    def levenshtein_similarity(self, other: IntoExprColumn) -> Expr:
+        """
+        Compute the normalized Levenshtein similarity to another string.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The similarity is `1 - distance / max(len(a), len(b))`, where the lengths
+        are counted in Unicode code points. Two empty strings have a similarity of 1.
+
+        Parameters
+        ----------
+        other
+            The string to compare to. Accepts expression input; strings are parsed
+            as string literals.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Float64`.
+
+        See Also
+        --------
+        levenshtein : Compute the Levenshtein distance to another string.
+        jaro_winkler : Compute the Jaro-Winkler similarity to another string.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": ["kitten", "flaw", "abc", None],
+        ...         "b": ["sitting", "lawn", "abd", "x"],
+        ...     }
+        ... )
+        >>> df.with_columns(sim=pl.col("a").str.levenshtein_similarity(pl.col("b")))
+        shape: (4, 3)
+        ┌────────┬─────────┬──────────┐
+        │ a      ┆ b       ┆ sim      │
+        │ ---    ┆ ---     ┆ ---      │
+        │ str    ┆ str     ┆ f64      │
+        ╞════════╪═════════╪══════════╡
+        │ kitten ┆ sitting ┆ 0.571429 │
+        │ flaw   ┆ lawn    ┆ 0.5      │
+        │ abc    ┆ abd     ┆ 0.666667 │
+        │ null   ┆ x       ┆ null     │
+        └────────┴─────────┴──────────┘
+        """
+        other = parse_into_expression(other, str_as_lit=True)
+        return wrap_expr(self._pyexpr.str_levenshtein_similarity(other))
+
+    @unstable()
+    def hamming(self, other: IntoExprColumn) -> Expr:
+        """
+        Compute the Hamming distance to another string.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The Hamming distance is the number of positions at which the characters of
+        two strings of equal length differ. The result is null for strings that have
+        a different length.
+
+        Parameters
+        ----------
+        other
+            The string to compare to. Accepts expression input; strings are parsed
+            as string literals.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`UInt32`.
+
+        See Also
+        --------
+        hamming_similarity : Compute the normalized Hamming similarity.
+        jaro_winkler : Compute the Jaro-Winkler similarity to another string.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": ["kitten", "flaw", "abc", None],
+        ...         "b": ["sitting", "lawn", "abd", "x"],
+        ...     }
+        ... )
+        >>> df.with_columns(dist=pl.col("a").str.hamming(pl.col("b")))
+        shape: (4, 3)
+        ┌────────┬─────────┬──────┐
+        │ a      ┆ b       ┆ dist │
+        │ ---    ┆ ---     ┆ ---  │
+        │ str    ┆ str     ┆ u32  │
+        ╞════════╪═════════╪══════╡
+        │ kitten ┆ sitting ┆ null │
+        │ flaw   ┆ lawn    ┆ 4    │
+        │ abc    ┆ abd     ┆ 1    │
+        │ null   ┆ x       ┆ null │
+        └────────┴─────────┴──────┘
+        """
+        other = parse_into_expression(other, str_as_lit=True)
+        return wrap_expr(self._pyexpr.str_hamming(other))
+
+    @unstable()
+    def hamming_similarity(self, other: IntoExprColumn) -> Expr:
+        """
+        Compute the normalized Hamming similarity to another string.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The similarity is `1 - distance / len(a)`. The result is null for strings that
+        have a different length, and two empty strings have a similarity of 1.
+
+        Parameters
+        ----------
+        other
+            The string to compare to. Accepts expression input; strings are parsed
+            as string literals.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Float64`.
+
+        See Also
+        --------
+        hamming : Compute the Hamming distance to another string.
+        jaro_winkler : Compute the Jaro-Winkler similarity to another string.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": ["kitten", "flaw", "abc", None],
+        ...         "b": ["sitting", "lawn", "abd", "x"],
+        ...     }
+        ... )
+        >>> df.with_columns(sim=pl.col("a").str.hamming_similarity(pl.col("b")))
+        shape: (4, 3)
+        ┌────────┬─────────┬──────────┐
+        │ a      ┆ b       ┆ sim      │
+        │ ---    ┆ ---     ┆ ---      │
+        │ str    ┆ str     ┆ f64      │
+        ╞════════╪═════════╪══════════╡
+        │ kitten ┆ sitting ┆ null     │
+        │ flaw   ┆ lawn    ┆ 0.0      │
+        │ abc    ┆ abd     ┆ 0.666667 │
+        │ null   ┆ x       ┆ null     │
+        └────────┴─────────┴──────────┘
+        """
+        other = parse_into_expression(other, str_as_lit=True)
+        return wrap_expr(self._pyexpr.str_hamming_similarity(other))
+
+    @unstable()
+    def jaro_winkler(self, other: IntoExprColumn) -> Expr:
+        """
+        Compute the Jaro-Winkler similarity to another string.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The Jaro similarity counts the matching characters that are close to each
+        other and the transpositions among them. The Jaro-Winkler similarity gives a
+        higher score to strings that share a common prefix of up to 4 characters.
+        The similarity is between 0 (no similarity) and 1 (equal strings).
+
+        Parameters
+        ----------
+        other
+            The string to compare to. Accepts expression input; strings are parsed
+            as string literals.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Float64`.
+
+        See Also
+        --------
+        levenshtein : Compute the Levenshtein distance to another string.
+        levenshtein_similarity : Compute the normalized Levenshtein similarity.
+        hamming : Compute the Hamming distance to another string.
+        hamming_similarity : Compute the normalized Hamming similarity.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": ["kitten", "flaw", "abc", None],
+        ...         "b": ["sitting", "lawn", "abd", "x"],
+        ...     }
+        ... )
+        >>> df.with_columns(sim=pl.col("a").str.jaro_winkler(pl.col("b")))
+        shape: (4, 3)
+        ┌────────┬─────────┬──────────┐
+        │ a      ┆ b       ┆ sim      │
+        │ ---    ┆ ---     ┆ ---      │
+        │ str    ┆ str     ┆ f64      │
+        ╞════════╪═════════╪══════════╡
+        │ kitten ┆ sitting ┆ 0.746032 │
+        │ flaw   ┆ lawn    ┆ 0.833333 │
+        │ abc    ┆ abd     ┆ 0.822222 │
+        │ null   ┆ x       ┆ null     │
+        └────────┴─────────┴──────────┘
+        """
+        other = parse_into_expression(other, str_as_lit=True)
+        return wrap_expr(self._pyexpr.str_jaro_winkler(other))
+
 
 def _validate_format_argument(format: str | None) -> None:
     if format is not None and ".%f" in format:
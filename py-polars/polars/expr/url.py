@@ -0,0 +1,200 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars._utils.unstable import unstable
+from polars._utils.wrap import wrap_expr
+
+if TYPE_CHECKING:
+    from polars import Expr
+
+
+class ExprUrlNameSpace:
+    """
+    Namespace for URL related expressions.
+
+    URLs are parsed following the WHATWG URL standard. Strings that are not absolute
+    URLs, e.g. `"/path"` or `"example.com"`, are parsed as null.
+    """
+
+    _accessor = "url"
+
+    def __init__(self, expr: Expr) -> None:
+        self._pyexpr = expr._pyexpr
+
+    @unstable()
+    def host(self) -> Expr:
+        """
+        Get the host of URLs.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The host is normalized to lowercase. URLs without a host, such as
+        `"mailto:someone@example.com"`, are set to null.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`String`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "url": [
+        ...             "https://pola.rs/posts?ref=hn",
+        ...             "http://Example.com/s?q=a+b",
+        ...             "not a url",
+        ...             None,
+        ...         ]
+        ...     }
+        ... )
+        >>> df.with_columns(host=pl.col("url").url.host())
+        shape: (4, 2)
+        ┌──────────────────────────────┬─────────────┐
+        │ url                          ┆ host        │
+        │ ---                          ┆ ---         │
+        │ str                          ┆ str         │
+        ╞══════════════════════════════╪═════════════╡
+        │ https://pola.rs/posts?ref=hn ┆ pola.rs     │
+        │ http://Example.com/s?q=a+b   ┆ example.com │
+        │ not a url                    ┆ null        │
+        │ null                         ┆ null        │
+        └──────────────────────────────┴─────────────┘
+        """
+        return wrap_expr(self._pyexpr.url_host())
+
+    @unstable()
+    def path(self) -> Expr:
+        """
+        Get the path of URLs.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The path is returned as it appears in the URL, without decoding
+        percent-encoded sequences. Use :meth:`decode` to decode them.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`String`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "url": [
+        ...             "https://pola.rs/posts?ref=hn",
+        ...             "http://Example.com/s?q=a+b",
+        ...             "not a url",
+        ...             None,
+        ...         ]
+        ...     }
+        ... )
+        >>> df.with_columns(path=pl.col("url").url.path())
+        shape: (4, 2)
+        ┌──────────────────────────────┬────────┐
+        │ url                          ┆ path   │
+        │ ---                          ┆ ---    │
+        │ str                          ┆ str    │
+        ╞══════════════════════════════╪════════╡
+        │ https://pola.rs/posts?ref=hn ┆ /posts │
+        │ http://Example.com/s?q=a+b   ┆ /s     │
+        │ not a url                    ┆ null   │
+        │ null                         ┆ null   │
+        └──────────────────────────────┴────────┘
+        """
+        return wrap_expr(self._pyexpr.url_path())
+
+    @unstable()
+    def query_param(self, name: str) -> Expr:
+        """
+        Get the value of a query parameter of URLs.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        name
+            The name of the query parameter. If a URL has multiple parameters with
+            this name, the first one is used; if it has none, the value is null.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`String`.
+
+        Notes
+        -----
+        The value is decoded, including a `+` to a space.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "url": [
+        ...             "https://pola.rs/posts?ref=hn",
+        ...             "http://Example.com/s?q=a+b",
+        ...             "not a url",
+        ...             None,
+        ...         ]
+        ...     }
+        ... )
+        >>> df.with_columns(q=pl.col("url").url.query_param("q"))
+        shape: (4, 2)
+        ┌──────────────────────────────┬──────┐
+        │ url                          ┆ q    │
+        │ ---                          ┆ ---  │
+        │ str                          ┆ str  │
+        ╞══════════════════════════════╪══════╡
+        │ https://pola.rs/posts?ref=hn ┆ null │
+        │ http://Example.com/s?q=a+b   ┆ a b  │
+        │ not a url                    ┆ null │
+        │ null                         ┆ null │
+        └──────────────────────────────┴──────┘
+        """
+        return wrap_expr(self._pyexpr.url_query_param(name))
+
+    @unstable()
+    def decode(self) -> Expr:
+        """
+        Decode percent-encoded sequences, e.g. `%20` to a space.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Strings that don't decode to valid UTF-8 are set to null. A `+` is kept as
+        is; :meth:`query_param` decodes it to a space.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`String`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"encoded": ["a%20b", "caf%C3%A9", "100%25", "%FF", None]}
+        ... )
+        >>> df.with_columns(decoded=pl.col("encoded").url.decode())
+        shape: (5, 2)
+        ┌───────────┬─────────┐
+        │ encoded   ┆ decoded │
+        │ ---       ┆ ---     │
+        │ str       ┆ str     │
+        ╞═══════════╪═════════╡
+        │ a%20b     ┆ a b     │
+        │ caf%C3%A9 ┆ café    │
+        │ 100%25    ┆ 100%    │
+        │ %FF       ┆ null    │
+        │ null      ┆ null    │
+        └───────────┴─────────┘
+        """
+        return wrap_expr(self._pyexpr.url_decode())
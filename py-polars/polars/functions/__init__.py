@@ -3,6 +3,9 @@ from polars.functions.aggregation import (
     all_horizontal,
     any,
     any_horizontal,
+    bitwise_and_horizontal,
+    bitwise_or_horizontal,
+    bitwise_xor_horizontal,
     cum_sum,
     cum_sum_horizontal,
     max,
@@ -24,7 +27,12 @@ from polars.functions.as_datatype import (
 from polars.functions.as_datatype import date_ as date
 from polars.functions.as_datatype import datetime_ as datetime
 from polars.functions.as_datatype import time_ as time
-from polars.functions.business import business_day_count
+from polars.functions.business import (
+    business_calendars,
+    business_day_count,
+    register_business_calendar,
+    unregister_business_calendar,
+)
 from polars.functions.col import col
 from polars.functions.eager import align_frames, concat
 from polars.functions.escape_regex import escape_regex
@@ -32,6 +40,8 @@ from polars.functions.lazy import (
     approx_n_unique,
     arctan2,
     arctan2d,
+    arg_max_by,
+    arg_min_by,
     arg_sort_by,
     arg_where,
     coalesce,
@@ -64,6 +74,7 @@ from polars.functions.lazy import (
     reduce,
     rolling_corr,
     rolling_cov,
+    search_sorted_by,
     select,
     sql_expr,
     std,
@@ -87,6 +98,7 @@ from polars.functions.range import (
     time_ranges,
 )
 from polars.functions.repeat import ones, repeat, zeros
+from polars.functions.secrets import set_secret_provider
 from polars.functions.whenthen import when
 
 __all__ = [
@@ -99,10 +111,17 @@ __all__ = [
     "sum",
     "all_horizontal",
     "any_horizontal",
+    "bitwise_and_horizontal",
+    "bitwise_or_horizontal",
+    "bitwise_xor_horizontal",
     "cum_sum_horizontal",
     "max_horizontal",
     "min_horizontal",
     "sum_horizontal",
+    # polars.functions.business
+    "business_calendars",
+    "register_business_calendar",
+    "unregister_business_calendar",
     # polars.functions.eager
     "align_frames",
     "approx_n_unique",
@@ -122,6 +141,8 @@ __all__ = [
     "arange",
     "arctan2",
     "arctan2d",
+    "arg_max_by",
+    "arg_min_by",
     "arg_sort_by",
     "business_day_count",
     "coalesce",
@@ -167,8 +188,10 @@ __all__ = [
     "reduce",
     "rolling_corr",
     "rolling_cov",
+    "search_sorted_by",
     "select",
     "set_random_seed",
+    "set_secret_provider",
     "std",
     "struct",
     "tail",
@@ -1,6 +1,9 @@
 from polars.functions.aggregation.horizontal import (
     all_horizontal,
     any_horizontal,
+    bitwise_and_horizontal,
+    bitwise_or_horizontal,
+    bitwise_xor_horizontal,
     cum_sum_horizontal,
     max_horizontal,
     mean_horizontal,
@@ -21,6 +24,9 @@ __all__ = [
     "all_horizontal",
     "any",
     "any_horizontal",
+    "bitwise_and_horizontal",
+    "bitwise_or_horizontal",
+    "bitwise_xor_horizontal",
     "cum_sum",
     "cum_sum_horizontal",
     "max",
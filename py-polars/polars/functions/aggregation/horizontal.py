@@ -296,3 +296,120 @@ def cum_sum_horizontal(*exprs: IntoExpr | Iterable[IntoExpr]) -> Expr:
     return F.cum_fold(F.lit(0).cast(UInt32), lambda a, b: a + b, exprs_wrapped).alias(
         "cum_sum"
     )
+
+
+def bitwise_and_horizontal(*exprs: IntoExpr | Iterable[IntoExpr]) -> Expr:
+    """
+    Compute the bitwise AND of integer values horizontally across columns.
+
+    Parameters
+    ----------
+    *exprs
+        Column(s) to use in the aggregation. Accepts expression input. Strings are
+        parsed as column names, other non-expression inputs are parsed as literals.
+
+    Notes
+    -----
+    Null values are skipped; a row is only null if all of its values are null.
+
+    Examples
+    --------
+    >>> df = pl.DataFrame(
+    ...     {
+    ...         "a": [12, 10, None],
+    ...         "b": [10, None, None],
+    ...         "c": [6, 3, 1],
+    ...     }
+    ... )
+    >>> df.with_columns(mask=pl.bitwise_and_horizontal("a", "b", "c"))
+    shape: (3, 4)
+    ┌──────┬──────┬─────┬──────┐
+    │ a    ┆ b    ┆ c   ┆ mask │
+    │ ---  ┆ ---  ┆ --- ┆ ---  │
+    │ i64  ┆ i64  ┆ i64 ┆ i64  │
+    ╞══════╪══════╪═════╪══════╡
+    │ 12   ┆ 10   ┆ 6   ┆ 0    │
+    │ 10   ┆ null ┆ 3   ┆ 2    │
+    │ null ┆ null ┆ 1   ┆ 1    │
+    └──────┴──────┴─────┴──────┘
+    """
+    pyexprs = parse_into_list_of_expressions(*exprs)
+    return wrap_expr(plr.bitwise_and_horizontal(pyexprs))
+
+
+def bitwise_or_horizontal(*exprs: IntoExpr | Iterable[IntoExpr]) -> Expr:
+    """
+    Compute the bitwise OR of integer values horizontally across columns.
+
+    Parameters
+    ----------
+    *exprs
+        Column(s) to use in the aggregation. Accepts expression input. Strings are
+        parsed as column names, other non-expression inputs are parsed as literals.
+
+    Notes
+    -----
+    Null values are skipped; a row is only null if all of its values are null.
+
+    Examples
+    --------
+    >>> df = pl.DataFrame(
+    ...     {
+    ...         "a": [12, 10, None],
+    ...         "b": [10, None, None],
+    ...         "c": [6, 3, 1],
+    ...     }
+    ... )
+    >>> df.with_columns(mask=pl.bitwise_or_horizontal("a", "b", "c"))
+    shape: (3, 4)
+    ┌──────┬──────┬─────┬──────┐
+    │ a    ┆ b    ┆ c   ┆ mask │
+    │ ---  ┆ ---  ┆ --- ┆ ---  │
+    │ i64  ┆ i64  ┆ i64 ┆ i64  │
+    ╞══════╪══════╪═════╪══════╡
+    │ 12   ┆ 10   ┆ 6   ┆ 14   │
+    │ 10   ┆ null ┆ 3   ┆ 11   │
+    │ null ┆ null ┆ 1   ┆ 1    │
+    └──────┴──────┴─────┴──────┘
+    """
+    pyexprs = parse_into_list_of_expressions(*exprs)
+    return wrap_expr(plr.bitwise_or_horizontal(pyexprs))
+
+
+def bitwise_xor_horizontal(*exprs: IntoExpr | Iterable[IntoExpr]) -> Expr:
+    """
+    Compute the bitwise XOR of integer values horizontally across columns.
+
+    Parameters
+    ----------
+    *exprs
+        Column(s) to use in the aggregation. Accepts expression input. Strings are
+        parsed as column names, other non-expression inputs are parsed as literals.
+
+    Notes
+    -----
+    Null values are skipped; a row is only null if all of its values are null.
+
+    Examples
+    --------
+    >>> df = pl.DataFrame(
+    ...     {
+    ...         "a": [12, 10, None],
+    ...         "b": [10, None, None],
+    ...         "c": [6, 3, 1],
+    ...     }
+    ... )
+    >>> df.with_columns(mask=pl.bitwise_xor_horizontal("a", "b", "c"))
+    shape: (3, 4)
+    ┌──────┬──────┬─────┬──────┐
+    │ a    ┆ b    ┆ c   ┆ mask │
+    │ ---  ┆ ---  ┆ --- ┆ ---  │
+    │ i64  ┆ i64  ┆ i64 ┆ i64  │
+    ╞══════╪══════╪═════╪══════╡
+    │ 12   ┆ 10   ┆ 6   ┆ 0    │
+    │ 10   ┆ null ┆ 3   ┆ 9    │
+    │ null ┆ null ┆ 1   ┆ 1    │
+    └──────┴──────┴─────┴──────┘
+    """
+    pyexprs = parse_into_list_of_expressions(*exprs)
+    return wrap_expr(plr.bitwise_xor_horizontal(pyexprs))
@@ -17,11 +17,124 @@ if TYPE_CHECKING:
     from polars._typing import IntoExprColumn
 
 
+_DEFAULT_WEEK_MASK = (True, True, True, True, True, False, False)
+
+
+def register_business_calendar(
+    name: str,
+    *,
+    week_mask: Iterable[bool] = _DEFAULT_WEEK_MASK,
+    holidays: Iterable[date] = (),
+) -> None:
+    """
+    Register a named business day calendar.
+
+    The calendar can then be passed by name to the `calendar` parameter of
+    :func:`business_day_count`, :meth:`Expr.dt.add_business_days` and
+    :meth:`Expr.dt.is_business_day`. Registering a calendar under an existing name
+    replaces it.
+
+    Parameters
+    ----------
+    name
+        Name of the calendar.
+    week_mask
+        Which days of the week are business days. The default is Monday to Friday.
+    holidays
+        Holidays of the calendar.
+
+    Examples
+    --------
+    >>> from datetime import date
+    >>> pl.register_business_calendar(
+    ...     "example",
+    ...     holidays=[date(2020, 1, 1), date(2020, 12, 25)],
+    ... )
+    >>> df = pl.DataFrame({"date": [date(2020, 1, 1), date(2020, 1, 2)]})
+    >>> df.with_columns(
+    ...     is_business_day=pl.col("date").dt.is_business_day(calendar="example")
+    ... )
+    shape: (2, 2)
+    ┌────────────┬─────────────────┐
+    │ date       ┆ is_business_day │
+    │ ---        ┆ ---             │
+    │ date       ┆ bool            │
+    ╞════════════╪═════════════════╡
+    │ 2020-01-01 ┆ false           │
+    │ 2020-01-02 ┆ true            │
+    └────────────┴─────────────────┘
+    >>> pl.unregister_business_calendar("example")
+    True
+    """
+    unix_epoch = date(1970, 1, 1)
+    plr.register_business_calendar(
+        name,
+        tuple(week_mask),
+        [(holiday - unix_epoch).days for holiday in holidays],
+    )
+
+
+def unregister_business_calendar(name: str) -> bool:
+    """
+    Remove a business day calendar registered with :func:`register_business_calendar`.
+
+    Expressions that were created with the calendar keep working, as its week mask
+    and holidays are resolved when the expression is created.
+
+    Parameters
+    ----------
+    name
+        Name of the calendar.
+
+    Returns
+    -------
+    bool
+        Whether a calendar with this name was registered.
+    """
+    return plr.unregister_business_calendar(name)
+
+
+def business_calendars() -> list[str]:
+    """
+    Return the names of all registered business day calendars.
+
+    Examples
+    --------
+    >>> week_mask = [True, True, True, True, False, False, False]
+    >>> pl.register_business_calendar("four-day-week", week_mask=week_mask)
+    >>> "four-day-week" in pl.business_calendars()
+    True
+    >>> pl.unregister_business_calendar("four-day-week")
+    True
+    """
+    return plr.business_calendar_names()
+
+
+def _resolve_business_calendar(
+    week_mask: Iterable[bool],
+    holidays: Iterable[date],
+    calendar: str | None,
+) -> tuple[tuple[bool, ...], list[int]]:
+    """Combine the `week_mask`, `holidays` and `calendar` arguments."""
+    week_mask = tuple(week_mask)
+    unix_epoch = date(1970, 1, 1)
+    holiday_days = [(holiday - unix_epoch).days for holiday in holidays]
+    if calendar is None:
+        return week_mask, holiday_days
+    if week_mask != _DEFAULT_WEEK_MASK:
+        msg = "`week_mask` cannot be combined with `calendar`"
+        raise ValueError(msg)
+    calendar_week_mask, calendar_holidays = plr.get_business_calendar(calendar)
+    return tuple(calendar_week_mask), calendar_holidays + holiday_days
+
+
 def business_day_count(
     start: date | IntoExprColumn,
     end: date | IntoExprColumn,
-    week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
+    week_mask: Iterable[bool] = _DEFAULT_WEEK_MASK,
     holidays: Iterable[date] = (),
+    *,
+    calendar: str | None = None,
 ) -> Expr:
     """
     Count the number of business days between `start` and `end` (not including `end`).
@@ -49,6 +162,10 @@ def business_day_count(
             my_holidays = holidays.country_holidays("NL", years=range(2020, 2025))
 
         and pass `holidays=my_holidays` when you call `business_day_count`.
+    calendar
+        Name of a calendar registered with :func:`register_business_calendar`. Its
+        week mask is used and its holidays are combined with `holidays`. The
+        calendar is resolved when the expression is created.
 
     Returns
     -------
@@ -114,12 +231,7 @@ def business_day_count(
     """
     start_pyexpr = parse_into_expression(start)
     end_pyexpr = parse_into_expression(end)
-    unix_epoch = date(1970, 1, 1)
+    week_mask, holiday_days = _resolve_business_calendar(week_mask, holidays, calendar)
     return wrap_expr(
-        plr.business_day_count(
-            start_pyexpr,
-            end_pyexpr,
-            week_mask,
-            [(holiday - unix_epoch).days for holiday in holidays],
-        )
+        plr.business_day_count(start_pyexpr, end_pyexpr, week_mask, holiday_days)
     )
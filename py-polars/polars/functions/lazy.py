@@ -38,6 +38,7 @@ if TYPE_CHECKING:
         IntoExpr,
         PolarsDataType,
         RollingInterpolationMethod,
+        SearchSortedSide,
     )
 
 
@@ -1678,6 +1679,177 @@ def arg_sort_by(
     )
 
 
+def arg_min_by(exprs: IntoExpr | Iterable[IntoExpr], *more_exprs: IntoExpr) -> Expr:
+    """
+    Return the index of the row with the smallest values of the column(s).
+
+    Rows are compared lexicographically: the first column determines the ordering,
+    the next column is only used to break ties, and so on. Null values are ordered
+    after all other values and rows in which every value is null are ignored. If
+    multiple rows share the smallest values, the index of the first one is returned.
+
+    Parameters
+    ----------
+    exprs
+        Column(s) to order by. Accepts expression input. Strings are parsed as column
+        names.
+    *more_exprs
+        Additional columns to order by, specified as positional arguments.
+
+    See Also
+    --------
+    arg_max_by
+    Expr.min_by : Get the value at the row with the smallest values of the column(s).
+
+    Examples
+    --------
+    >>> df = pl.DataFrame(
+    ...     {
+    ...         "a": [0, 1, 1, 0],
+    ...         "b": [3, 2, 3, 2],
+    ...     }
+    ... )
+    >>> df.select(pl.arg_min_by("a", "b"))
+    shape: (1, 1)
+    ┌─────┐
+    │ a   │
+    │ --- │
+    │ u32 │
+    ╞═════╡
+    │ 3   │
+    └─────┘
+    """
+    exprs = parse_into_list_of_expressions(exprs, *more_exprs)
+    return wrap_expr(plr.arg_min_by(exprs))
+
+
+def arg_max_by(exprs: IntoExpr | Iterable[IntoExpr], *more_exprs: IntoExpr) -> Expr:
+    """
+    Return the index of the row with the largest values of the column(s).
+
+    Rows are compared lexicographically: the first column determines the ordering,
+    the next column is only used to break ties, and so on. Null values are ordered
+    after all other values and rows in which every value is null are ignored. If
+    multiple rows share the largest values, the index of the first one is returned.
+
+    Parameters
+    ----------
+    exprs
+        Column(s) to order by. Accepts expression input. Strings are parsed as column
+        names.
+    *more_exprs
+        Additional columns to order by, specified as positional arguments.
+
+    See Also
+    --------
+    arg_min_by
+    Expr.max_by : Get the value at the row with the largest values of the column(s).
+
+    Examples
+    --------
+    >>> df = pl.DataFrame(
+    ...     {
+    ...         "a": [0, 1, 1, 0],
+    ...         "b": [3, 2, 3, 2],
+    ...     }
+    ... )
+    >>> df.select(pl.arg_max_by("a", "b"))
+    shape: (1, 1)
+    ┌─────┐
+    │ a   │
+    │ --- │
+    │ u32 │
+    ╞═════╡
+    │ 2   │
+    └─────┘
+    """
+    exprs = parse_into_list_of_expressions(exprs, *more_exprs)
+    return wrap_expr(plr.arg_max_by(exprs))
+
+
+def search_sorted_by(
+    by: IntoExpr | Iterable[IntoExpr],
+    element: IntoExpr | Sequence[IntoExpr],
+    side: SearchSortedSide = "any",
+    *,
+    descending: bool | Sequence[bool] = False,
+    nulls_last: bool | Sequence[bool] = False,
+) -> Expr:
+    """
+    Find indices where rows should be inserted to maintain a multi-column order.
+
+    This performs a binary search over the rows of `by`, which must be sorted
+    lexicographically, e.g. with :meth:`DataFrame.sort` using the same `descending`
+    and `nulls_last` arguments.
+
+    Parameters
+    ----------
+    by
+        Column(s) the frame is sorted by. Accepts expression input. Strings are parsed
+        as column names.
+    element
+        The values to search for, one expression or scalar for every column in `by`.
+        Strings are parsed as literals. Scalars are broadcast to the length of the
+        other values.
+    side : {'any', 'left', 'right'}
+        If 'any', the index of the first suitable location found is given.
+        If 'left', the index of the leftmost suitable location found is given.
+        If 'right', return the rightmost suitable location found is given.
+    descending
+        Whether the columns are sorted in descending order. Can be specified per
+        column by passing a sequence of booleans.
+    nulls_last
+        Whether nulls are placed last in the sorted columns. Can be specified per
+        column by passing a sequence of booleans.
+
+    See Also
+    --------
+    Expr.search_sorted
+
+    Examples
+    --------
+    >>> df = pl.DataFrame({"a": [1, 1, 2, 2, 3], "b": ["x", "z", "x", "y", "x"]})
+    >>> df.select(pl.search_sorted_by(["a", "b"], [2, "y"], side="left"))
+    shape: (1, 1)
+    ┌─────┐
+    │ a   │
+    │ --- │
+    │ u32 │
+    ╞═════╡
+    │ 3   │
+    └─────┘
+
+    Search for multiple rows at once.
+
+    >>> df.select(
+    ...     pl.search_sorted_by(
+    ...         ["a", "b"],
+    ...         [pl.Series([1, 2, 4]), pl.Series(["y", "y", "a"])],
+    ...         side="left",
+    ...     )
+    ... )
+    shape: (3, 1)
+    ┌─────┐
+    │ a   │
+    │ --- │
+    │ u32 │
+    ╞═════╡
+    │ 1   │
+    │ 3   │
+    │ 5   │
+    └─────┘
+    """
+    by = parse_into_list_of_expressions(by)
+    if not isinstance(element, (list, tuple)):
+        element = [element]
+    values = [
+        parse_into_expression(e, str_as_lit=True, list_as_series=True) for e in element
+    ]
+    descending = extend_bool(descending, len(by), "descending", "by")
+    nulls_last = extend_bool(nulls_last, len(by), "nulls_last", "by")
+    return wrap_expr(plr.search_sorted_by(by, values, side, descending, nulls_last))
+
+
 @deprecate_streaming_parameter()
 def collect_all(
     lazy_frames: Iterable[LazyFrame],
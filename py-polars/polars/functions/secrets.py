@@ -0,0 +1,39 @@
+from __future__ import annotations
+
+import contextlib
+from typing import TYPE_CHECKING
+
+with contextlib.suppress(ImportError):  # Module not available when building docs
+    import polars.polars as plr
+
+if TYPE_CHECKING:
+    from collections.abc import Callable
+
+
+def set_secret_provider(provider: Callable[[str], bytes] | None) -> None:
+    """
+    Set the global secret provider for Polars.
+
+    The secret provider supplies the keys used by :meth:`Expr.bin.encrypt` and
+    :meth:`Expr.bin.decrypt`. Queries only refer to keys by name; the provider is
+    called with that name when the query is executed, so key material never ends up
+    in a (serialized) query plan.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    provider
+        A function that takes the name of a secret and returns its bytes. It may be
+        called from multiple threads. Pass `None` to restore the default provider,
+        which reads hex-encoded secrets from environment variables.
+
+    Examples
+    --------
+    >>> keys = {"customer_key": bytes(range(32))}
+    >>> pl.set_secret_provider(keys.__getitem__)
+    >>> pl.set_secret_provider(None)
+    """
+    plr.set_secret_provider(provider)
@@ -1,5 +1,6 @@
 """Functions for reading data."""
 
+from polars.io.arrow_c_stream import scan_arrow_c_stream
 from polars.io.avro import read_avro
 from polars.io.clipboard import read_clipboard
 from polars.io.csv import read_csv, read_csv_batched, scan_csv
@@ -46,6 +47,7 @@ __all__ = [
     "read_ods",
     "read_parquet",
     "read_parquet_schema",
+    "scan_arrow_c_stream",
     "scan_csv",
     "scan_delta",
     "scan_iceberg",
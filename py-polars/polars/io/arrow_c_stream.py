@@ -0,0 +1,60 @@
+from __future__ import annotations
+
+import contextlib
+from typing import TYPE_CHECKING, Any
+
+from polars._utils.unstable import unstable
+from polars._utils.wrap import wrap_ldf
+
+with contextlib.suppress(ImportError):  # Module not available when building docs
+    from polars.polars import PyLazyFrame
+
+if TYPE_CHECKING:
+    from polars import LazyFrame
+
+
+@unstable()
+def scan_arrow_c_stream(source: Any) -> LazyFrame:
+    """
+    Lazily read from an object that exports an Arrow C stream.
+
+    The source can be any object that implements the `__arrow_c_stream__` method
+    of the `Arrow PyCapsule Interface
+    <https://arrow.apache.org/docs/dev/format/CDataInterface/PyCapsuleInterface.html>`_,
+    such as a DuckDB relation, a DataFusion DataFrame or a PyArrow
+    `RecordBatchReader`. The record batches are imported without copying as the
+    query consumes them, so with the streaming engine the source is never fully
+    materialized.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    source
+        An object with an `__arrow_c_stream__` method that produces a stream of
+        record batches.
+
+    Notes
+    -----
+    A stream can only be read once, so collecting the resulting LazyFrame again
+    after the stream was exhausted raises an error.
+
+    Examples
+    --------
+    >>> import pyarrow as pa
+    >>> table = pa.table({"a": [1, 2, 3], "b": ["x", "y", "z"]})
+    >>> reader = pa.RecordBatchReader.from_batches(table.schema, table.to_batches())
+    >>> pl.scan_arrow_c_stream(reader).filter(pl.col("a") > 1).collect()
+    shape: (2, 2)
+    ┌─────┬─────┐
+    │ a   ┆ b   │
+    │ --- ┆ --- │
+    │ i64 ┆ str │
+    ╞═════╪═════╡
+    │ 2   ┆ y   │
+    │ 3   ┆ z   │
+    └─────┴─────┘
+    """
+    return wrap_ldf(PyLazyFrame.scan_arrow_c_stream(source))
@@ -94,7 +94,7 @@ from polars.lazyframe.engine_config import GPUEngine
 from polars.lazyframe.group_by import LazyGroupBy
 from polars.lazyframe.in_process import InProcessQuery
 from polars.schema import Schema
-from polars.selectors import by_dtype, expand_selector
+from polars.selectors import _expand_selectors, by_dtype, expand_selector
 
 with contextlib.suppress(ImportError):  # Module not available when building docs
     from polars.polars import PyLazyFrame, get_engine_affinity
@@ -109,7 +109,7 @@ if TYPE_CHECKING:
     with contextlib.suppress(ImportError):  # Module not available when building docs
         from polars.polars import PyPartitioning
 
-    from polars import DataFrame, DataType, Expr
+    from polars import DataFrame, DataType, Expr, Series
     from polars._typing import (
         AsofJoinStrategy,
         ClosedInterval,
@@ -127,6 +127,7 @@ if TYPE_CHECKING:
         Label,
         MaintainOrderJoin,
         Orientation,
+        PivotAgg,
         PolarsDataType,
         PythonDataType,
         RollingInterpolationMethod,
@@ -5735,6 +5736,92 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             )
         )
 
+    @unstable()
+    def join_between(
+        self,
+        other: LazyFrame,
+        left_on: str | Expr,
+        right_lower: str | Expr,
+        right_upper: str | Expr,
+        *,
+        closed: ClosedInterval = "both",
+        suffix: str = "_right",
+    ) -> LazyFrame:
+        """
+        Perform a range join: match rows where a value lies within a window.
+
+        This matches every row of this frame with the rows of `other` for which
+        `left_on` lies between `right_lower` and `right_upper`, e.g. to find the
+        validity window of an event timestamp. The join runs as a sort-based
+        inequality join, which is much faster than a cross join followed by a filter.
+
+        This performs an inner join, so a row from either LazyFrame may be included
+        multiple times in the result.
+
+        .. note::
+            The row order of the input LazyFrames is not preserved.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        other
+            LazyFrame to join with.
+        left_on
+            Value of this LazyFrame to look up. Accepts expression input. Strings are
+            parsed as column names.
+        right_lower
+            Lower bound of the window in `other`. Accepts expression input. Strings
+            are parsed as column names.
+        right_upper
+            Upper bound of the window in `other`. Accepts expression input. Strings
+            are parsed as column names.
+        closed : {'both', 'left', 'right', 'none'}
+            Define which sides of the window are closed (inclusive).
+        suffix
+            Suffix to append to columns with a duplicate name.
+
+        See Also
+        --------
+        join_where
+
+        Examples
+        --------
+        >>> events = pl.LazyFrame({"event": ["a", "b", "c"], "ts": [1, 5, 9]})
+        >>> windows = pl.LazyFrame(
+        ...     {"window": ["w1", "w2"], "start": [0, 4], "end": [5, 8]}
+        ... )
+        >>> events.join_between(windows, "ts", "start", "end").sort(
+        ...     "event", "window"
+        ... ).collect()
+        shape: (3, 5)
+        ┌───────┬─────┬────────┬───────┬─────┐
+        │ event ┆ ts  ┆ window ┆ start ┆ end │
+        │ ---   ┆ --- ┆ ---    ┆ ---   ┆ --- │
+        │ str   ┆ i64 ┆ str    ┆ i64   ┆ i64 │
+        ╞═══════╪═════╪════════╪═══════╪═════╡
+        │ a     ┆ 1   ┆ w1     ┆ 0     ┆ 5   │
+        │ b     ┆ 5   ┆ w1     ┆ 0     ┆ 5   │
+        │ b     ┆ 5   ┆ w2     ┆ 4     ┆ 8   │
+        └───────┴─────┴────────┴───────┴─────┘
+        """
+        if not isinstance(other, LazyFrame):
+            msg = f"expected `other` join table to be a LazyFrame, not a {type(other).__name__!r}"
+            raise TypeError(msg)
+
+        return self._from_pyldf(
+            self._ldf.join_between(
+                other._ldf,
+                parse_into_expression(left_on),
+                parse_into_expression(right_lower),
+                parse_into_expression(right_upper),
+                closed,
+                suffix,
+            )
+        )
+
     def with_columns(
         self,
         *exprs: IntoExpr | Iterable[IntoExpr],
@@ -7372,6 +7459,138 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             subset = parse_into_list_of_expressions(subset)
         return self._from_pyldf(self._ldf.drop_nulls(subset))
 
+    @unstable()
+    def pivot(
+        self,
+        on: ColumnNameOrSelector | Sequence[ColumnNameOrSelector],
+        on_columns: Sequence[Any] | Series | DataFrame | None = None,
+        *,
+        index: ColumnNameOrSelector | Sequence[ColumnNameOrSelector] | None = None,
+        values: ColumnNameOrSelector | Sequence[ColumnNameOrSelector] | None = None,
+        aggregate_function: PivotAgg | Expr | None = None,
+        maintain_order: bool = True,
+        sort_columns: bool = False,
+        separator: str = "_",
+    ) -> LazyFrame:
+        """
+        Create a spreadsheet-style pivot table as a LazyFrame.
+
+        The output columns depend on the values of the `on` columns. If these values
+        are given as `on_columns`, the pivot is planned as a group-by aggregation,
+        which can run on the streaming engine. Otherwise, the unique values of `on`
+        are collected first, which requires an extra pass over the data.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        on
+            The column(s) whose values will be used as the new columns of the output
+            LazyFrame.
+        on_columns
+            The values of `on` to create columns for, in order. If `on` is a single
+            column, a sequence or Series of values; otherwise a DataFrame with a column
+            for each column of `on`. If None, the unique values of `on` in order of
+            appearance are used. Rows with other values are ignored.
+        index
+            The column(s) that remain from the input to the output. The output will
+            have one row for each unique combination of the `index`'s values.
+            If None, all remaining columns not specified on `on` and `values` will be
+            used. At least one of `index` and `values` must be specified.
+        values
+            The existing column(s) of values which will be moved under the new columns
+            from index. If None, all remaining columns not specified on `on` and
+            `index` will be used. At least one of `index` and `values` must be
+            specified.
+        aggregate_function
+            Choose from:
+
+            - None: take the first value. Unlike :meth:`DataFrame.pivot`, this does
+              not raise an error if there are multiple values.
+            - A predefined aggregate function string, one of
+              {'min', 'max', 'first', 'last', 'sum', 'mean', 'median', 'len'}
+            - An expression to do the aggregation.
+        maintain_order
+            Ensure the values of `index` are sorted by discovery order.
+        sort_columns
+            Sort the transposed columns by name. Default is by the order of
+            `on_columns`.
+        separator
+            Used as separator/delimiter in generated column names in case of multiple
+            `values` columns.
+
+        See Also
+        --------
+        DataFrame.pivot
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame(
+        ...     {
+        ...         "name": ["Cady", "Cady", "Karen", "Karen"],
+        ...         "subject": ["maths", "physics", "maths", "physics"],
+        ...         "test_1": [98, 99, 61, 58],
+        ...     }
+        ... )
+        >>> lf.pivot(
+        ...     "subject", ["maths", "physics"], index="name", values="test_1"
+        ... ).collect()
+        shape: (2, 3)
+        ┌───────┬───────┬─────────┐
+        │ name  ┆ maths ┆ physics │
+        │ ---   ┆ ---   ┆ ---     │
+        │ str   ┆ i64   ┆ i64     │
+        ╞═══════╪═══════╪═════════╡
+        │ Cady  ┆ 98    ┆ 99      │
+        │ Karen ┆ 61    ┆ 58      │
+        └───────┴───────┴─────────┘
+        """
+        on = _expand_selectors(self, on)
+        if values is not None:
+            values = _expand_selectors(self, values)
+        if index is not None:
+            index = _expand_selectors(self, index)
+
+        if on_columns is not None and not isinstance(on_columns, pl.DataFrame):
+            if len(on) != 1:
+                msg = "`on_columns` must be a DataFrame if `on` has multiple columns"
+                raise TypeError(msg)
+            on_columns = pl.DataFrame([pl.Series(on[0], on_columns)])
+
+        if aggregate_function is None:
+            aggregate_function = "first"
+        if isinstance(aggregate_function, str):
+            element = F.element()
+            aggregations = {
+                "min": element.min(),
+                "max": element.max(),
+                "first": element.first(),
+                "last": element.last(),
+                "sum": element.sum(),
+                "mean": element.mean(),
+                "median": element.median(),
+                "len": element.len(),
+            }
+            if aggregate_function not in aggregations:
+                msg = f"invalid input for `aggregate_function` argument: {aggregate_function!r}"
+                raise ValueError(msg)
+            aggregate_function = aggregations[aggregate_function]
+
+        return self._from_pyldf(
+            self._ldf.pivot(
+                on,
+                None if on_columns is None else on_columns._df,
+                index,
+                values,
+                aggregate_function._pyexpr,
+                maintain_order,
+                sort_columns,
+                separator,
+            )
+        )
+
     def unpivot(
         self,
         on: ColumnNameOrSelector | Sequence[ColumnNameOrSelector] | None = None,
@@ -7411,6 +7630,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         but with `index` replacing `id_vars` and `on` replacing `value_vars`.
         In other frameworks, you might know this operation as `pivot_longer`.
 
+        The streaming engine unpivots the data batch by batch without materializing
+        it, so its output is ordered by batch first, and then by the `on` column.
+
         Examples
         --------
         >>> lf = pl.LazyFrame(
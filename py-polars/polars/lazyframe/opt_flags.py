@@ -21,6 +21,10 @@ class OptFlags:
         cluster_with_columns: bool = True,
         collapse_joins: bool = True,
         check_order_observe: bool = True,
+        join_reorder: bool = False,
+        semi_join_reduction: bool = False,
+        dictionary_strings: bool = False,
+        sorted_metadata: bool = False,
     ) -> None:
         self._pyoptflags = PyOptFlags.empty()
 
@@ -34,6 +38,10 @@ class OptFlags:
         self._pyoptflags.comm_subexpr_elim = comm_subexpr_elim
         self._pyoptflags.collapse_joins = collapse_joins
         self._pyoptflags.check_order_observe = check_order_observe
+        self._pyoptflags.join_reorder = join_reorder
+        self._pyoptflags.semi_join_reduction = semi_join_reduction
+        self._pyoptflags.dictionary_strings = dictionary_strings
+        self._pyoptflags.sorted_metadata = sorted_metadata
 
     def no_optimizations(self) -> None:
         """Remove selected optimizations."""
@@ -119,3 +127,39 @@ class OptFlags:
     @check_order_observe.setter
     def check_order_observe(self, value: bool) -> None:
         self._pyoptflags.check_order_observe = value
+
+    @property
+    def join_reorder(self) -> bool:
+        """Reorder chains of inner joins so that the smallest tables are joined first."""
+        return self._pyoptflags.join_reorder
+
+    @join_reorder.setter
+    def join_reorder(self, value: bool) -> None:
+        self._pyoptflags.join_reorder = value
+
+    @property
+    def semi_join_reduction(self) -> bool:
+        """Turn filters on the existence of rows in other tables into semi and anti joins."""
+        return self._pyoptflags.semi_join_reduction
+
+    @semi_join_reduction.setter
+    def semi_join_reduction(self, value: bool) -> None:
+        self._pyoptflags.semi_join_reduction = value
+
+    @property
+    def dictionary_strings(self) -> bool:
+        """Keep dictionary-encoded string columns of Parquet scans as categoricals."""
+        return self._pyoptflags.dictionary_strings
+
+    @dictionary_strings.setter
+    def dictionary_strings(self, value: bool) -> None:
+        self._pyoptflags.dictionary_strings = value
+
+    @property
+    def sorted_metadata(self) -> bool:
+        """Take the sort order of columns from the metadata of scanned files."""
+        return self._pyoptflags.sorted_metadata
+
+    @sorted_metadata.setter
+    def sorted_metadata(self, value: bool) -> None:
+        self._pyoptflags.sorted_metadata = value
@@ -643,3 +643,51 @@ class ArrayNameSpace:
             [6, null, null]
         ]
         """
+
+    def dot(self, other: IntoExpr) -> Series:
+        """
+        Compute the dot product of every sub-array with the sub-array of `other`.
+
+        The result is null if either sub-array is null or contains a null.
+
+        Parameters
+        ----------
+        other
+            Array Series of the same width. A single array, e.g. a list, is used for
+            every row.
+
+        Examples
+        --------
+        >>> s = pl.Series([[1, 2], [3, 4]], dtype=pl.Array(pl.Int64, 2))
+        >>> s.arr.dot([1, 1])
+        shape: (2,)
+        Series: '' [f64]
+        [
+            3.0
+            7.0
+        ]
+        """
+
+    def cosine_similarity(self, other: IntoExpr) -> Series:
+        """
+        Compute the cosine similarity of every sub-array with the sub-array of `other`.
+
+        The result is null if either sub-array is null or contains a null.
+
+        Parameters
+        ----------
+        other
+            Array Series of the same width. A single array, e.g. a list, is used for
+            every row.
+
+        Examples
+        --------
+        >>> s = pl.Series([[1.0, 2.0], [3.0, 4.0]], dtype=pl.Array(pl.Float64, 2))
+        >>> s.arr.cosine_similarity([0.0, 1.0])
+        shape: (2,)
+        Series: '' [f64]
+        [
+            0.894427
+            0.8
+        ]
+        """
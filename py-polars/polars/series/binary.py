@@ -7,6 +7,7 @@ from polars.series.utils import expr_dispatch
 if TYPE_CHECKING:
     from polars import Series
     from polars._typing import (
+        EncryptionAlgorithm,
         Endianness,
         IntoExpr,
         PolarsDataType,
@@ -248,3 +249,65 @@ class BinaryNameSpace:
         ]
 
         """
+
+    def encrypt(self, key: str, algorithm: EncryptionAlgorithm = "aes-gcm") -> Series:
+        r"""
+        Encrypt binary values with authenticated encryption.
+
+        Every value is encrypted with a fresh random nonce, so equal values encrypt
+        to different outputs.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        key
+            Name of the key to encrypt with, which is fetched from the secret
+            provider. By default, this reads a hex-encoded key from the environment
+            variable with this name. Use :func:`set_secret_provider` to change this.
+            Keys must be 16 bytes (AES-128) or 32 bytes (AES-256) long.
+        algorithm : {'aes-gcm', 'aes-gcm-siv'}
+            The encryption algorithm to use.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Binary`.
+
+        Examples
+        --------
+        >>> pl.set_secret_provider(lambda name: b"0123456789abcdef")
+        >>> s = pl.Series("data", [b"secret", None])
+        >>> s.bin.encrypt("my_key").bin.decrypt("my_key")
+        shape: (2,)
+        Series: 'data' [binary]
+        [
+            b"secret"
+            null
+        ]
+        >>> pl.set_secret_provider(None)
+        """
+
+    def decrypt(self, key: str, algorithm: EncryptionAlgorithm = "aes-gcm") -> Series:
+        r"""
+        Decrypt binary values encrypted with :meth:`encrypt`.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        key
+            Name of the key to decrypt with, which is fetched from the secret
+            provider.
+        algorithm : {'aes-gcm', 'aes-gcm-siv'}
+            The encryption algorithm the values were encrypted with.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Binary`.
+        """
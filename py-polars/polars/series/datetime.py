@@ -43,6 +43,8 @@ class DateTimeNameSpace:
         week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
         holidays: Iterable[dt.date] = (),
         roll: Roll = "raise",
+        *,
+        calendar: str | None = None,
     ) -> Series:
         """
         Offset by `n` business days.
@@ -75,6 +77,11 @@ class DateTimeNameSpace:
             - `'raise'`: raise an error
             - `'forward'`: move to the next business day
             - `'backward'`: move to the previous business day
+        calendar
+            Name of a calendar registered with
+            :func:`polars.register_business_calendar`. Its week mask is used and its
+            holidays are combined with `holidays`. The calendar is resolved when the
+            expression is created.
 
         Returns
         -------
@@ -128,6 +135,48 @@ class DateTimeNameSpace:
         ]
         """
 
+    def is_business_day(
+        self,
+        week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
+        holidays: Iterable[dt.date] = (),
+        *,
+        calendar: str | None = None,
+    ) -> Series:
+        """
+        Determine whether each day lands on a business day.
+
+        Parameters
+        ----------
+        week_mask
+            Which days of the week to count. The default is Monday to Friday.
+            If you wanted to count only Monday to Thursday, you would pass
+            `(True, True, True, True, False, False, False)`.
+        holidays
+            Holidays to exclude from the count.
+        calendar
+            Name of a calendar registered with
+            :func:`polars.register_business_calendar`. Its week mask is used and its
+            holidays are combined with `holidays`. The calendar is resolved when the
+            expression is created.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> s = pl.Series([date(2020, 1, 3), date(2020, 1, 5)])
+        >>> s.dt.is_business_day()
+        shape: (2,)
+        Series: '' [bool]
+        [
+                true
+                false
+        ]
+        """
+
     def min(self) -> dt.date | dt.datetime | dt.timedelta | None:
         """
         Return minimum as Python datetime.
@@ -0,0 +1,217 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars._utils.unstable import unstable
+from polars.series.utils import expr_dispatch
+
+if TYPE_CHECKING:
+    from polars import Series
+    from polars._typing import IntoExprColumn
+    from polars.polars import PySeries
+
+
+@expr_dispatch
+class IpNameSpace:
+    """
+    Series.ip namespace.
+
+    IP addresses are represented as 16 byte :class:`Binary` values, where IPv4
+    addresses are stored as IPv4-mapped IPv6 addresses. Besides such values, the
+    methods accept strings, which are parsed, and unsigned integers, which are
+    read as IPv4 addresses.
+    """
+
+    _accessor = "ip"
+
+    def __init__(self, series: Series) -> None:
+        self._s: PySeries = series._s
+
+    @unstable()
+    def parse(self, *, strict: bool = True) -> Series:
+        """
+        Parse IPv4 and IPv6 addresses.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        strict
+            Raise an error if a string is not a valid IP address. If set to `False`,
+            invalid addresses are set to null instead.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Binary`.
+
+        Examples
+        --------
+        >>> s = pl.Series("addr", ["192.168.1.10", "2001:DB8::1", "not an ip"])
+        >>> s.ip.parse(strict=False).ip.to_string()
+        shape: (3,)
+        Series: 'addr' [str]
+        [
+            "192.168.1.10"
+            "2001:db8::1"
+            null
+        ]
+        """
+
+    @unstable()
+    def to_string(self) -> Series:
+        """
+        Format IP addresses in their canonical text representation.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        IPv4 addresses are formatted in dotted decimal notation, and IPv6 addresses
+        in their shortest form, e.g. `2001:db8::1`.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`String`.
+
+        Examples
+        --------
+        >>> s = pl.Series("addr", ["192.168.1.10", "2001:DB8::1", "not an ip"])
+        >>> s.ip.parse(strict=False).ip.to_string()
+        shape: (3,)
+        Series: 'addr' [str]
+        [
+            "192.168.1.10"
+            "2001:db8::1"
+            null
+        ]
+        """
+
+    @unstable()
+    def version(self) -> Series:
+        """
+        Get the version of IP addresses, 4 or 6.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`UInt8`.
+
+        Examples
+        --------
+        >>> s = pl.Series("addr", ["192.168.1.10", "10.0.0.1", "2001:db8::1"])
+        >>> s.ip.version()
+        shape: (3,)
+        Series: 'addr' [u8]
+        [
+            4
+            4
+            6
+        ]
+        """
+
+    @unstable()
+    def is_in_cidr(self, cidr: IntoExprColumn) -> Series:
+        """
+        Check if IP addresses are in a CIDR block.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        cidr
+            A CIDR block such as `"10.0.0.0/8"` or `"2001:db8::/32"`, or a Series
+            of such blocks. An address without a prefix length is a block of a
+            single address. IPv4 blocks also contain the equivalent IPv4-mapped
+            IPv6 addresses.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> s = pl.Series("addr", ["192.168.1.10", "10.0.0.1", "2001:db8::1"])
+        >>> s.ip.is_in_cidr("10.0.0.0/8")
+        shape: (3,)
+        Series: 'addr' [bool]
+        [
+            false
+            true
+            false
+        ]
+        """
+
+    @unstable()
+    def subnet(self, prefix_len: int) -> Series:
+        """
+        Get the network address of the subnet that contains each IP address.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        prefix_len
+            The number of leading bits of the address that identify the subnet.
+            The prefix length counts in the address space of each address, so
+            prefix lengths above 32 leave IPv4 addresses unchanged.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Binary`.
+
+        Examples
+        --------
+        >>> s = pl.Series("addr", ["192.168.1.10", "10.0.0.1", "2001:db8::1"])
+        >>> s.ip.subnet(16).ip.to_string()
+        shape: (3,)
+        Series: 'addr' [str]
+        [
+            "192.168.0.0"
+            "10.0.0.0"
+            "2001::"
+        ]
+        """
+
+    @unstable()
+    def to_integer(self) -> Series:
+        """
+        Convert IPv4 addresses to integers.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        IPv6 addresses don't fit in a 32-bit integer and are set to null. Integers
+        can be converted back to IP addresses with :meth:`parse`.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`UInt32`.
+
+        Examples
+        --------
+        >>> s = pl.Series("addr", ["192.168.1.10", "10.0.0.1", "2001:db8::1"])
+        >>> s.ip.to_integer()
+        shape: (3,)
+        Series: 'addr' [u32]
+        [
+            3232235786
+            167772161
+            null
+        ]
+        """
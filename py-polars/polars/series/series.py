@@ -99,10 +99,13 @@ from polars.series.array import ArrayNameSpace
 from polars.series.binary import BinaryNameSpace
 from polars.series.categorical import CatNameSpace
 from polars.series.datetime import DateTimeNameSpace
+from polars.series.ip import IpNameSpace
 from polars.series.list import ListNameSpace
 from polars.series.plotting import SeriesPlot
+from polars.series.st import GeometryNameSpace
 from polars.series.string import StringNameSpace
 from polars.series.struct import StructNameSpace
+from polars.series.url import UrlNameSpace
 from polars.series.utils import expr_dispatch, get_ffi_func
 
 with contextlib.suppress(ImportError):  # Module not available when building docs
@@ -124,9 +127,11 @@ if TYPE_CHECKING:
         ClosedInterval,
         ComparisonOperator,
         FillNullStrategy,
+        HashAlgorithm,
         InterpolationMethod,
         IntoExpr,
         IntoExprColumn,
+        ModeTies,
         MultiIndexSelector,
         NonNestedLiteral,
         NullBehavior,
@@ -249,11 +254,14 @@ class Series:
         "arr",
         "cat",
         "dt",
+        "ip",
         "list",
         "str",
         "bin",
+        "st",
         "struct",
         "plot",
+        "url",
     }
 
     def __init__(
@@ -3226,6 +3234,91 @@ class Series:
         ]
         """
 
+    @unstable()
+    def gather_by_ranges(
+        self,
+        starts: int | Sequence[int] | IntoExprColumn,
+        ends: int | Sequence[int] | IntoExprColumn,
+    ) -> Series:
+        """
+        Take the values `start` up to (but excluding) `end` for every pair of bounds.
+
+        The ranges are concatenated in order. This is much faster than gathering the
+        same values by index, as every range is a zero-copy slice.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        starts
+            The (inclusive) starts of the ranges.
+        ends
+            The (exclusive) ends of the ranges. Either bound can be a single value
+            that is used for all ranges.
+
+        See Also
+        --------
+        gather : Take values by index.
+        slice : Take a single range of values.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [0, 1, 2, 3, 4, 5, 6])
+        >>> s.gather_by_ranges([0, 4], [2, 6])
+        shape: (4,)
+        Series: 'a' [i64]
+        [
+            0
+            1
+            4
+            5
+        ]
+        """
+
+    @unstable()
+    def scatter_by_mask(
+        self, mask: Series | IntoExprColumn, values: IntoExpr | Sequence[Any]
+    ) -> Series:
+        """
+        Replace the values where `mask` is true.
+
+        Unlike :meth:`zip_with`, `values` only holds the new values, one for every
+        true value of `mask`, which are set in order. Numeric data is updated in
+        place where possible.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        mask
+            A boolean Series that is true where values should be replaced.
+            Null values are treated as false.
+        values
+            The new values. Either one value for every true value of `mask`, or a
+            single value that is set at all of them.
+
+        See Also
+        --------
+        scatter : Replace values by index.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [1, 2, 3, 4])
+        >>> s.scatter_by_mask(pl.Series([False, True, None, True]), [20, 40])
+        shape: (4,)
+        Series: 'a' [i64]
+        [
+            1
+            20
+            3
+            40
+        ]
+        """
+
     def sort(
         self,
         *,
@@ -4980,11 +5073,17 @@ class Series:
         ]
         """
 
-    def floor(self) -> Series:
+    def floor(self, decimals: int = 0) -> Series:
         """
-        Rounds down to the nearest integer value.
+        Rounds down to the nearest integer value, or to `decimals` digits.
+
+        Only works on floating point and Decimal Series.
 
-        Only works on floating point Series.
+        Parameters
+        ----------
+        decimals
+            Number of decimals to round down to. For Decimal Series the scale of the
+            result is unchanged.
 
         Examples
         --------
@@ -4997,13 +5096,27 @@ class Series:
                 2.0
                 3.0
         ]
+        >>> s.floor(2)
+        shape: (3,)
+        Series: 'a' [f64]
+        [
+                1.12
+                2.56
+                3.9
+        ]
         """
 
-    def ceil(self) -> Series:
+    def ceil(self, decimals: int = 0) -> Series:
         """
-        Rounds up to the nearest integer value.
+        Rounds up to the nearest integer value, or to `decimals` digits.
 
-        Only works on floating point Series.
+        Only works on floating point and Decimal Series.
+
+        Parameters
+        ----------
+        decimals
+            Number of decimals to round up to. For Decimal Series the scale of the
+            result is unchanged.
 
         Examples
         --------
@@ -5086,11 +5199,18 @@ class Series:
             raise ShapeError(msg)
         return self._s.dot(other._s)
 
-    def mode(self) -> Series:
+    def mode(self, *, ties: ModeTies = "all") -> Series:
         """
         Compute the most occurring value(s).
 
-        Can return multiple Values.
+        Parameters
+        ----------
+        ties : {'all', 'first', 'smallest'}
+            Which values to return if several values occur most often.
+
+            - 'all': Return all of them, in the order of their first occurrence.
+            - 'first': Return the one that occurs first.
+            - 'smallest': Return the smallest one.
 
         Examples
         --------
@@ -5856,6 +5976,7 @@ class Series:
         *,
         min_samples: int | None = None,
         center: bool = False,
+        return_dtype: PolarsDataType | None = None,
     ) -> Series:
         """
         Compute a custom rolling window function.
@@ -5878,6 +5999,11 @@ class Series:
             a result. If set to `None` (default), it will be set equal to `window_size`.
         center
             Set the labels at the center of the window.
+        return_dtype
+            Dtype of the value the function returns per window. If set, the function
+            may return a value of a different dtype than the values, and the values
+            don't have to be numeric. If not set, the result is converted to the dtype
+            of the values.
 
         Warnings
         --------
@@ -6052,6 +6178,95 @@ class Series:
         (0.38180177416060584, 0.47033046033698594)
         """
 
+    @unstable()
+    def rolling_mode(
+        self,
+        window_size: int,
+        *,
+        min_samples: int | None = None,
+        center: bool = False,
+    ) -> Series:
+        """
+        Compute the most frequent value in a rolling window.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The window at a given row will include the row itself, and the `window_size - 1`
+        elements before it. Null values are ignored, and ties are broken in favor of the
+        smallest value.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window in number of elements.
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [3, 1, 1, 2, 3, 3])
+        >>> s.rolling_mode(window_size=3)
+        shape: (6,)
+        Series: 'a' [i64]
+        [
+            null
+            null
+            1
+            1
+            1
+            3
+        ]
+        """
+
+    @unstable()
+    def rolling_n_unique(
+        self,
+        window_size: int,
+        *,
+        min_samples: int | None = None,
+        center: bool = False,
+    ) -> Series:
+        """
+        Compute the number of unique values in a rolling window.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The window at a given row will include the row itself, and the `window_size - 1`
+        elements before it. Null values are ignored.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window in number of elements.
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [3, 1, 1, 2, 3, 3])
+        >>> s.rolling_n_unique(window_size=3)
+        shape: (6,)
+        Series: 'a' [u32]
+        [
+            null
+            null
+            2
+            2
+            3
+            2
+        ]
+        """
+
     def sample(
         self,
         n: int | None = None,
@@ -6162,11 +6377,13 @@ class Series:
         seed_1: int | None = None,
         seed_2: int | None = None,
         seed_3: int | None = None,
+        *,
+        algorithm: HashAlgorithm = "default",
     ) -> Series:
         """
         Hash the Series.
 
-        The hash value is of type `UInt64`.
+        The hash value is of type `UInt64`, or `Binary` for the `'sha256'` algorithm.
 
         Parameters
         ----------
@@ -6178,12 +6395,15 @@ class Series:
             Random seed parameter. Defaults to `seed` if not set.
         seed_3
             Random seed parameter. Defaults to `seed` if not set.
+        algorithm : {'default', 'xxhash64', 'wyhash', 'siphash', 'sha256'}
+            The hash algorithm to use. See :meth:`Expr.hash` for how the seeds are used.
 
         Notes
         -----
-        This implementation of `hash` does not guarantee stable results
-        across different Polars versions. Its stability is only guaranteed within a
-        single version.
+        The `'default'` algorithm does not guarantee stable results across different
+        Polars versions. Its stability is only guaranteed within a single version.
+        The other algorithms are stable across versions and platforms, see
+        :meth:`Expr.hash` for details.
 
         Examples
         --------
@@ -6231,15 +6451,29 @@ class Series:
         ]
         """
 
-    def interpolate(self, method: InterpolationMethod = "linear") -> Series:
+    def interpolate(
+        self, method: InterpolationMethod = "linear", *, by: IntoExpr | None = None
+    ) -> Series:
         """
         Fill null values using interpolation.
 
         Parameters
         ----------
-        method : {'linear', 'nearest'}
+        method : {'linear', 'nearest', 'spline', 'pchip', 'time'}
             Interpolation method.
 
+            - 'linear': linear interpolation between the surrounding values.
+            - 'nearest': use the nearest non-null value.
+            - 'spline': natural cubic spline through the non-null values.
+            - 'pchip': monotonic piecewise cubic Hermite interpolation; does not
+              overshoot the surrounding values.
+            - 'time': linear interpolation weighted by the distance between the
+              values of `by`, typically a datetime Series. Equivalent to
+              :meth:`interpolate_by`.
+        by
+            Series to weight the interpolation by. Required for `method='time'`
+            and not allowed for other methods.
+
         Examples
         --------
         >>> s = pl.Series("a", [1, 2, None, None, 5])
@@ -6253,6 +6487,17 @@ class Series:
             4.0
             5.0
         ]
+        >>> s = pl.Series("a", [0.0, None, 4.0, None, 0.0])
+        >>> s.interpolate("spline")
+        shape: (5,)
+        Series: 'a' [f64]
+        [
+            0.0
+            2.75
+            4.0
+            2.75
+            0.0
+        ]
         """
 
     def interpolate_by(self, by: IntoExpr) -> Series:
@@ -6366,6 +6611,105 @@ class Series:
         ]
         """
 
+    def percent_rank(self, *, descending: bool = False) -> Series:
+        """
+        Compute the relative rank of each value, like SQL's `PERCENT_RANK`.
+
+        The relative rank is `(rank - 1) / (n - 1)`, where `rank` is the 'min' rank
+        and `n` is the number of non-null values, so it ranges from 0 to 1. Null
+        values stay null.
+
+        Parameters
+        ----------
+        descending
+            Rank in descending order.
+
+        See Also
+        --------
+        cume_dist
+        rank
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [3, 6, 1, 1, 6])
+        >>> s.percent_rank()
+        shape: (5,)
+        Series: 'a' [f64]
+        [
+            0.5
+            0.75
+            0.0
+            0.0
+            0.75
+        ]
+        """
+
+    def cume_dist(self, *, descending: bool = False) -> Series:
+        """
+        Compute the cumulative distribution of each value, like SQL's `CUME_DIST`.
+
+        This is the fraction of non-null values that are ordered before or tied with
+        the value, so it ranges from `1 / n` to 1. Null values stay null.
+
+        Parameters
+        ----------
+        descending
+            Order the values in descending order.
+
+        See Also
+        --------
+        percent_rank
+        rank
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [3, 6, 1, 1, 6])
+        >>> s.cume_dist()
+        shape: (5,)
+        Series: 'a' [f64]
+        [
+            0.6
+            1.0
+            0.4
+            0.4
+            1.0
+        ]
+        """
+
+    def ntile(self, n: int, *, descending: bool = False) -> Series:
+        """
+        Divide the sorted values into `n` buckets, like SQL's `NTILE`.
+
+        Buckets are numbered from 1 and differ in size by at most one, with the
+        larger buckets first. Tied values are assigned in order of occurrence, so
+        they may end up in different buckets. Null values stay null.
+
+        Parameters
+        ----------
+        n
+            Number of buckets; must be positive.
+        descending
+            Order the values in descending order.
+
+        See Also
+        --------
+        rank
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [3, 6, 1, 1, 6])
+        >>> s.ntile(2)
+        shape: (5,)
+        Series: 'a' [u32]
+        [
+            1
+            2
+            1
+            1
+            2
+        ]
+        """
+
     def diff(self, n: int = 1, null_behavior: NullBehavior = "ignore") -> Series:
         """
         Calculate the first discrete difference between shifted items.
@@ -7128,6 +7472,146 @@ class Series:
         ]
         """
 
+    def ewm_std_by(
+        self,
+        by: IntoExpr,
+        *,
+        half_life: str | timedelta,
+    ) -> Series:
+        r"""
+        Compute time-based exponentially weighted moving standard deviation.
+
+        Given observations :math:`x_0, x_1, \ldots, x_{n-1}` at times
+        :math:`t_0, t_1, \ldots, t_{n-1}`, the mean :math:`y_i` is updated as in
+        :meth:`ewm_mean_by` and the variance as
+
+            .. math::
+
+                v_0 &= 0
+
+                v_i &= (1 - \alpha_i) \left( v_{i-1} + \alpha_i (x_i - y_{i-1})^2
+                    \right); \quad i > 0
+
+        where :math:`\alpha_i` depends on the time since the previous observation
+        and the `half_life`, so irregularly sampled data is weighted correctly. The
+        result is :math:`\sqrt{v_i}`.
+
+        Parameters
+        ----------
+        by
+            Times to calculate the standard deviation by. Should be ``DateTime``,
+            ``Date``, ``UInt64``, ``UInt32``, ``Int64``, or ``Int32`` data type.
+        half_life
+            Unit over which observation decays to half its value. Accepts the same
+            values as :meth:`ewm_mean_by`, and is likewise treated as a constant
+            duration.
+
+        Returns
+        -------
+        Series
+            Float32 if input is Float32, otherwise Float64.
+
+        See Also
+        --------
+        ewm_mean_by
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "values": [0, 1, 2, None, 4],
+        ...         "times": [
+        ...             date(2020, 1, 1),
+        ...             date(2020, 1, 3),
+        ...             date(2020, 1, 10),
+        ...             date(2020, 1, 15),
+        ...             date(2020, 1, 17),
+        ...         ],
+        ...     }
+        ... ).sort("times")
+        >>> df["values"].ewm_std_by(df["times"], half_life="4d")
+        shape: (5,)
+        Series: 'values' [f64]
+        [
+                0.0
+                0.45509
+                0.818774
+                null
+                1.229996
+        ]
+        """
+
+    def ewm_var_by(
+        self,
+        by: IntoExpr,
+        *,
+        half_life: str | timedelta,
+    ) -> Series:
+        r"""
+        Compute time-based exponentially weighted moving variance.
+
+        Given observations :math:`x_0, x_1, \ldots, x_{n-1}` at times
+        :math:`t_0, t_1, \ldots, t_{n-1}`, the mean :math:`y_i` is updated as in
+        :meth:`ewm_mean_by` and the variance as
+
+            .. math::
+
+                v_0 &= 0
+
+                v_i &= (1 - \alpha_i) \left( v_{i-1} + \alpha_i (x_i - y_{i-1})^2
+                    \right); \quad i > 0
+
+        where :math:`\alpha_i` depends on the time since the previous observation
+        and the `half_life`, so irregularly sampled data is weighted correctly. The
+        result is :math:`v_i`.
+
+        Parameters
+        ----------
+        by
+            Times to calculate the variance by. Should be ``DateTime``, ``Date``,
+            ``UInt64``, ``UInt32``, ``Int64``, or ``Int32`` data type.
+        half_life
+            Unit over which observation decays to half its value. Accepts the same
+            values as :meth:`ewm_mean_by`, and is likewise treated as a constant
+            duration.
+
+        Returns
+        -------
+        Series
+            Float32 if input is Float32, otherwise Float64.
+
+        See Also
+        --------
+        ewm_mean_by
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "values": [0, 1, 2, None, 4],
+        ...         "times": [
+        ...             date(2020, 1, 1),
+        ...             date(2020, 1, 3),
+        ...             date(2020, 1, 10),
+        ...             date(2020, 1, 15),
+        ...             date(2020, 1, 17),
+        ...         ],
+        ...     }
+        ... ).sort("times")
+        >>> df["values"].ewm_var_by(df["times"], half_life="4d")
+        shape: (5,)
+        Series: 'values' [f64]
+        [
+                0.0
+                0.207107
+                0.670392
+                null
+                1.512891
+        ]
+        """
+
     @deprecate_renamed_parameter("min_periods", "min_samples", version="1.21.0")
     def ewm_std(
         self,
@@ -7514,6 +7998,21 @@ class Series:
         """Create an object namespace of all datetime related methods."""
         return DateTimeNameSpace(self)
 
+    @property
+    def ip(self) -> IpNameSpace:
+        """Create an object namespace of all IP address related methods."""
+        return IpNameSpace(self)
+
+    @property
+    def st(self) -> GeometryNameSpace:
+        """Create an object namespace of all spatial related methods."""
+        return GeometryNameSpace(self)
+
+    @property
+    def url(self) -> UrlNameSpace:
+        """Create an object namespace of all URL related methods."""
+        return UrlNameSpace(self)
+
     @property
     def list(self) -> ListNameSpace:
         """Create an object namespace of all list related methods."""
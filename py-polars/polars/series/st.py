@@ -0,0 +1,162 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars._utils.unstable import unstable
+from polars.series.utils import expr_dispatch
+
+if TYPE_CHECKING:
+    from polars import Series
+    from polars._typing import IntoExpr
+    from polars.polars import PySeries
+
+
+@expr_dispatch
+class GeometryNameSpace:
+    """
+    Series.st namespace.
+
+    Geometries are represented as :class:`Geometry` values holding planar geometries
+    encoded as Well-Known Binary (WKB). The methods also accept :class:`Binary`
+    values holding WKB. Only the `x` and `y` coordinates are taken into account.
+    """
+
+    _accessor = "st"
+
+    def __init__(self, series: Series) -> None:
+        self._s: PySeries = series._s
+
+    @unstable()
+    def area(self) -> Series:
+        """
+        Compute the area of geometries.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The area of holes is subtracted from the area of polygons. Points and lines
+        have an area of 0.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Float64`.
+
+        Examples
+        --------
+        >>> import struct
+        >>> square = struct.pack("<BIII10d", 1, 3, 1, 5, 0, 0, 2, 0, 2, 2, 0, 2, 0, 0)
+        >>> point = struct.pack("<BI2d", 1, 1, 3, 4)
+        >>> s = pl.Series("geom", [square, point], dtype=pl.Geometry)
+        >>> s.st.area()
+        shape: (2,)
+        Series: 'geom' [f64]
+        [
+            4.0
+            0.0
+        ]
+        """
+
+    @unstable()
+    def centroid(self) -> Series:
+        """
+        Compute the centroid of geometries.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The centroid of polygons is weighted by area and the centroid of lines by
+        length. Only the components of the highest dimension of a geometry are taken
+        into account. The centroid of an empty geometry is null.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Geometry`, holding points.
+
+        Examples
+        --------
+        >>> import struct
+        >>> square = struct.pack("<BIII10d", 1, 3, 1, 5, 0, 0, 2, 0, 2, 2, 0, 2, 0, 0)
+        >>> point = struct.pack("<BI2d", 1, 1, 3, 4)
+        >>> s = pl.Series("geom", [square, point], dtype=pl.Geometry)
+        >>> [struct.unpack("<2d", wkb[5:]) for wkb in s.st.centroid()]
+        [(1.0, 1.0), (3.0, 4.0)]
+        """
+
+    @unstable()
+    def contains(self, other: IntoExpr) -> Series:
+        """
+        Check if geometries contain other geometries.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        A geometry contains another geometry if no point of the other geometry
+        lies outside of it, and at least one point lies in its interior. Hence a
+        polygon does not contain the points on its boundary.
+
+        Parameters
+        ----------
+        other
+            The geometries to check for, as WKB `bytes` or a Series.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> import struct
+        >>> square = struct.pack("<BIII10d", 1, 3, 1, 5, 0, 0, 2, 0, 2, 2, 0, 2, 0, 0)
+        >>> point = struct.pack("<BI2d", 1, 1, 3, 4)
+        >>> s = pl.Series("geom", [square, point], dtype=pl.Geometry)
+        >>> s.st.contains(struct.pack("<BI2d", 1, 1, 1, 1))
+        shape: (2,)
+        Series: 'geom' [bool]
+        [
+            true
+            false
+        ]
+        """
+
+    @unstable()
+    def distance(self, other: IntoExpr) -> Series:
+        """
+        Compute the minimum Euclidean distance between geometries.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The distance is 0 if the geometries intersect, and null if either of the
+        geometries is empty.
+
+        Parameters
+        ----------
+        other
+            The geometries to compute the distance to, as WKB `bytes` or a Series.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Float64`.
+
+        Examples
+        --------
+        >>> import struct
+        >>> square = struct.pack("<BIII10d", 1, 3, 1, 5, 0, 0, 2, 0, 2, 2, 0, 2, 0, 0)
+        >>> point = struct.pack("<BI2d", 1, 1, 3, 4)
+        >>> s = pl.Series("geom", [square, point], dtype=pl.Geometry)
+        >>> s.st.distance(struct.pack("<BI2d", 1, 1, 0, 0))
+        shape: (2,)
+        Series: 'geom' [f64]
+        [
+            0.0
+            5.0
+        ]
+        """
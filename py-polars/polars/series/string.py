@@ -182,6 +182,41 @@ class StringNameSpace:
         ]
         """
 
+    def to_duration(self, time_unit: TimeUnit = "us", *, strict: bool = True) -> Series:
+        """
+        Convert a String column into a Duration column.
+
+        Accepts ISO 8601 durations, such as `"P1DT2H"` or `"-PT0.5S"`, and durations
+        as displayed by Polars, such as `"1d 2h 30m"`. Year and month designators
+        are not supported as they do not have a fixed length.
+
+        Parameters
+        ----------
+        time_unit : {'us', 'ns', 'ms'}
+            Time unit of the resulting Duration column.
+        strict
+            Raise an error if any conversion fails. If set to `False`, values that
+            cannot be parsed are set to null.
+
+        See Also
+        --------
+        polars.Series.dt.to_string : Format durations as ISO 8601 (`"iso"`) or as
+            displayed by Polars (`"polars"`).
+
+        Examples
+        --------
+        >>> s = pl.Series(["P1DT2H", "PT1M30.5S", "-PT15M", "1d 2h"])
+        >>> s.str.to_duration()
+        shape: (4,)
+        Series: '' [duration[μs]]
+        [
+                1d 2h
+                1m 30s 500ms
+                -15m
+                1d 2h
+        ]
+        """
+
     def strptime(
         self,
         dtype: PolarsTemporalType,
@@ -687,7 +722,9 @@ class StringNameSpace:
         All return values will be cast to String regardless of the original value.
 
         Documentation on JSONPath standard can be found
-        `here <https://goessner.net/articles/JsonPath/>`_.
+        `here <https://goessner.net/articles/JsonPath/>`_. The full syntax is
+        supported, including recursive descent (`$..a`), filters
+        (`$.a[?(@.b > 1)]`) and array slices (`$.a[1:3]`).
 
         Parameters
         ----------
@@ -700,6 +737,10 @@ class StringNameSpace:
             Series of data type :class:`String`. Contains null values if the original
             value is null or the json_path returns nothing.
 
+        See Also
+        --------
+        json_extract_all : Extract all matches from a JSON string.
+
         Examples
         --------
         >>> df = pl.DataFrame(
@@ -717,6 +758,51 @@ class StringNameSpace:
         ]
         """
 
+    @unstable()
+    def json_extract_all(self, json_path: IntoExprColumn) -> Series:
+        """
+        Extract all matches from a JSON string using the provided JSONPath.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Unlike :meth:`json_path_match`, which only returns the first match, this
+        returns every value the query matches, in document order. All values are
+        cast to :class:`String`, and JSON nulls become null elements.
+
+        Parameters
+        ----------
+        json_path
+            A valid JSONPath query string.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`List` with inner data type :class:`String`.
+            Null if the original value is null or not valid JSON; an empty list if
+            the json_path matches nothing.
+
+        See Also
+        --------
+        json_path_match : Extract the first match from a JSON string.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "json_val",
+        ...     ['{"a":[1,2,3]}', '{"a":[]}', None],
+        ... )
+        >>> s.str.json_extract_all("$.a[1:]")
+        shape: (3,)
+        Series: 'json_val' [list[str]]
+        [
+            ["2", "3"]
+            []
+            null
+        ]
+        """
+
     def extract(self, pattern: IntoExprColumn, group_index: int = 1) -> Series:
         r"""
         Extract the target capture group from provided patterns.
@@ -2254,3 +2340,283 @@ class StringNameSpace:
                 "KADOKAWA"
         ]
         """  # noqa: RUF002
+
+    @unstable()
+    def soundex(self, *, ascii_fold: bool = True) -> Series:
+        """
+        Encode string values with American Soundex.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Soundex encodes a string as its first letter followed by three digits, so
+        that names that sound alike in English get the same code. This makes the
+        codes useful as join keys for approximate name matching.
+
+        Parameters
+        ----------
+        ascii_fold
+            If True, fold non-ASCII letters to their closest ASCII equivalents
+            before encoding, e.g. `é` to `e` and `ß` to `ss`. Otherwise, non-ASCII
+            letters are ignored.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`String`. Strings without any letters are
+            encoded as null.
+
+        See Also
+        --------
+        metaphone : Encode string values with Metaphone.
+
+        Examples
+        --------
+        >>> s = pl.Series("name", ["Robert", "Rupert", "Müller", "Mueller", None])
+        >>> s.str.soundex()
+        shape: (5,)
+        Series: 'name' [str]
+        [
+            "R163"
+            "R163"
+            "M460"
+            "M460"
+            null
+        ]
+        """
+
+    @unstable()
+    def metaphone(self, *, ascii_fold: bool = True) -> Series:
+        """
+        Encode string values with Metaphone.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Metaphone encodes a string by the way it is pronounced in English, so that
+        words that sound alike get the same code. It is more accurate than Soundex
+        and its codes don't have a fixed length. This makes the codes useful as join
+        keys for approximate name matching.
+
+        Parameters
+        ----------
+        ascii_fold
+            If True, fold non-ASCII letters to their closest ASCII equivalents
+            before encoding, e.g. `é` to `e` and `ß` to `ss`. Otherwise, non-ASCII
+            letters are ignored.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`String`. Strings without any letters are
+            encoded as null.
+
+        See Also
+        --------
+        soundex : Encode string values with American Soundex.
+
+        Examples
+        --------
+        >>> s = pl.Series("name", ["Robert", "Rupert", "Müller", "Mueller", None])
+        >>> s.str.metaphone()
+        shape: (5,)
+        Series: 'name' [str]
+        [
+            "RBRT"
+            "RPRT"
+            "MLR"
+            "MLR"
+            null
+        ]
+        """
+
+    @unstable()
+    def levenshtein(self, other: IntoExprColumn) -> Series:
+        """
+        Compute the Levenshtein distance to another string.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The Levenshtein distance is the minimum number of single character
+        insertions, deletions and substitutions needed to change one string into the
+        other. Characters are compared as Unicode code points.
+
+        Parameters
+        ----------
+        other
+            The string to compare to. Accepts expression input; strings are parsed
+            as string literals.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`UInt32`.
+
+        Examples
+        --------
+        >>> s = pl.Series(["kitten", "sitting", "mitten", None])
+        >>> s.str.levenshtein("kitten")
+        shape: (4,)
+        Series: '' [u32]
+        [
+            0
+            3
+            1
+            null
+        ]
+        """
+
+    @unstable()
+    def levenshtein_similarity(self, other: IntoExprColumn) -> Series:
+        """
+        Compute the normalized Levenshtein similarity to another string.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The similarity is `1 - distance / max(len(a), len(b))`, where the lengths
+        are counted in Unicode code points. Two empty strings have a similarity of 1.
+
+        Parameters
+        ----------
+        other
+            The string to compare to. Accepts expression input; strings are parsed
+            as string literals.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Float64`.
+
+        Examples
+        --------
+        >>> s = pl.Series(["kitten", "sitting", "mitten", None])
+        >>> s.str.levenshtein_similarity("kitten")
+        shape: (4,)
+        Series: '' [f64]
+        [
+            1.0
+            0.571429
+            0.833333
+            null
+        ]
+        """
+
+    @unstable()
+    def hamming(self, other: IntoExprColumn) -> Series:
+        """
+        Compute the Hamming distance to another string.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The Hamming distance is the number of positions at which the characters of
+        two strings of equal length differ. The result is null for strings that have
+        a different length.
+
+        Parameters
+        ----------
+        other
+            The string to compare to. Accepts expression input; strings are parsed
+            as string literals.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`UInt32`.
+
+        Examples
+        --------
+        >>> s = pl.Series(["kitten", "sitting", "mitten", None])
+        >>> s.str.hamming("kitten")
+        shape: (4,)
+        Series: '' [u32]
+        [
+            0
+            null
+            1
+            null
+        ]
+        """
+
+    @unstable()
+    def hamming_similarity(self, other: IntoExprColumn) -> Series:
+        """
+        Compute the normalized Hamming similarity to another string.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The similarity is `1 - distance / len(a)`. The result is null for strings that
+        have a different length, and two empty strings have a similarity of 1.
+
+        Parameters
+        ----------
+        other
+            The string to compare to. Accepts expression input; strings are parsed
+            as string literals.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Float64`.
+
+        Examples
+        --------
+        >>> s = pl.Series(["kitten", "sitting", "mitten", None])
+        >>> s.str.hamming_similarity("kitten")
+        shape: (4,)
+        Series: '' [f64]
+        [
+            1.0
+            null
+            0.833333
+            null
+        ]
+        """
+
+    @unstable()
+    def jaro_winkler(self, other: IntoExprColumn) -> Series:
+        """
+        Compute the Jaro-Winkler similarity to another string.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The Jaro similarity counts the matching characters that are close to each
+        other and the transpositions among them. The Jaro-Winkler similarity gives a
+        higher score to strings that share a common prefix of up to 4 characters.
+        The similarity is between 0 (no similarity) and 1 (equal strings).
+
+        Parameters
+        ----------
+        other
+            The string to compare to. Accepts expression input; strings are parsed
+            as string literals.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Float64`.
+
+        Examples
+        --------
+        >>> s = pl.Series(["kitten", "sitting", "mitten", None])
+        >>> s.str.jaro_winkler("kitten")
+        shape: (4,)
+        Series: '' [f64]
+        [
+            1.0
+            0.746032
+            0.888889
+            null
+        ]
+        """
@@ -0,0 +1,163 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars._utils.unstable import unstable
+from polars.series.utils import expr_dispatch
+
+if TYPE_CHECKING:
+    from polars import Series
+    from polars.polars import PySeries
+
+
+@expr_dispatch
+class UrlNameSpace:
+    """
+    Series.url namespace.
+
+    URLs are parsed following the WHATWG URL standard. Strings that are not absolute
+    URLs, e.g. `"/path"` or `"example.com"`, are parsed as null.
+    """
+
+    _accessor = "url"
+
+    def __init__(self, series: Series) -> None:
+        self._s: PySeries = series._s
+
+    @unstable()
+    def host(self) -> Series:
+        """
+        Get the host of URLs.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The host is normalized to lowercase. URLs without a host, such as
+        `"mailto:someone@example.com"`, are set to null.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`String`.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "url",
+        ...     ["https://pola.rs/posts?ref=hn", "http://Example.com/s?q=a+b", None],
+        ... )
+        >>> s.url.host()
+        shape: (3,)
+        Series: 'url' [str]
+        [
+            "pola.rs"
+            "example.com"
+            null
+        ]
+        """
+
+    @unstable()
+    def path(self) -> Series:
+        """
+        Get the path of URLs.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The path is returned as it appears in the URL, without decoding
+        percent-encoded sequences. Use :meth:`decode` to decode them.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`String`.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "url",
+        ...     ["https://pola.rs/posts?ref=hn", "http://Example.com/s?q=a+b", None],
+        ... )
+        >>> s.url.path()
+        shape: (3,)
+        Series: 'url' [str]
+        [
+            "/posts"
+            "/s"
+            null
+        ]
+        """
+
+    @unstable()
+    def query_param(self, name: str) -> Series:
+        """
+        Get the value of a query parameter of URLs.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        name
+            The name of the query parameter. If a URL has multiple parameters with
+            this name, the first one is used; if it has none, the value is null.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`String`.
+
+        Notes
+        -----
+        The value is decoded, including a `+` to a space.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "url",
+        ...     ["https://pola.rs/posts?ref=hn", "http://Example.com/s?q=a+b", None],
+        ... )
+        >>> s.url.query_param("ref")
+        shape: (3,)
+        Series: 'url' [str]
+        [
+            "hn"
+            null
+            null
+        ]
+        """
+
+    @unstable()
+    def decode(self) -> Series:
+        """
+        Decode percent-encoded sequences, e.g. `%20` to a space.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Strings that don't decode to valid UTF-8 are set to null. A `+` is kept as
+        is; :meth:`query_param` decodes it to a space.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`String`.
+
+        Examples
+        --------
+        >>> s = pl.Series("encoded", ["a%20b", "caf%C3%A9", "100%25", "%FF", None])
+        >>> s.url.decode()
+        shape: (5,)
+        Series: 'encoded' [str]
+        [
+            "a b"
+            "café"
+            "100%"
+            null
+            null
+        ]
+        """
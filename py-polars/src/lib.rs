@@ -145,12 +145,26 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     // Functions - business
     m.add_wrapped(wrap_pyfunction!(functions::business_day_count))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::register_business_calendar))
+        .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::unregister_business_calendar))
+        .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::get_business_calendar))
+        .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::business_calendar_names))
+        .unwrap();
 
     // Functions - aggregation
     m.add_wrapped(wrap_pyfunction!(functions::all_horizontal))
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::any_horizontal))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::bitwise_and_horizontal))
+        .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::bitwise_or_horizontal))
+        .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::bitwise_xor_horizontal))
+        .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::max_horizontal))
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::min_horizontal))
@@ -165,6 +179,13 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::arg_where))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::arg_min_by))
+        .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::arg_max_by))
+        .unwrap();
+    #[cfg(feature = "search_sorted")]
+    m.add_wrapped(wrap_pyfunction!(functions::search_sorted_by))
+        .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::as_struct))
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::coalesce))
@@ -297,6 +318,11 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(functions::set_random_seed))
         .unwrap();
 
+    // Functions - secrets
+    #[cfg(feature = "binary_encryption")]
+    m.add_wrapped(wrap_pyfunction!(functions::set_secret_provider))
+        .unwrap();
+
     // Functions - escape_regex
     m.add_wrapped(wrap_pyfunction!(functions::escape_regex))
         .unwrap();
@@ -364,6 +364,12 @@ def test_fallback_without_dtype_large_int() -> None:
     assert result.to_list() == [1.0, 340282366920938500000000000000000000000.0, None]
 
 
+def test_fallback_without_dtype_unsigned_128_bit_int() -> None:
+    values = [1, 2**127, None]
+    with pytest.raises(OverflowError, match="UInt128 is not supported"):
+        PySeries.new_from_any_values("", values, strict=True)
+
+
 def test_fallback_with_dtype_large_int() -> None:
     values = [1, 2**128, None]
     with pytest.raises(OverflowError):
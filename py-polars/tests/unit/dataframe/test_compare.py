@@ -0,0 +1,97 @@
+import math
+
+import pytest
+
+import polars as pl
+from polars.exceptions import InvalidOperationError
+from polars.testing import assert_frame_equal
+
+
+def test_compare_equal() -> None:
+    df1 = pl.DataFrame(
+        {"id": [1, 2, 3], "a": ["x", "y", None], "b": [1.0, math.nan, 3.0]}
+    )
+    df2 = df1.reverse()
+
+    comparison = df1.compare(df2, on="id")
+    assert comparison.is_equal()
+    assert comparison.left_only.schema == df1.schema
+    assert comparison.mismatches == {}
+
+
+def test_compare_differences() -> None:
+    df1 = pl.DataFrame(
+        {
+            "id": [1, 2, 3, 4],
+            "a": ["x", "y", "z", "w"],
+            "b": [1, 2, 3, 4],
+            "c": [True, False, True, False],
+        }
+    )
+    df2 = pl.DataFrame(
+        {
+            "id": [5, 4, 2, 1],
+            "a": ["v", "w", None, "x"],
+            "b": [5, 40, 2, 10],
+            "d": [1, 2, 3, 4],
+        }
+    )
+
+    comparison = df1.compare(df2, on="id")
+    assert not comparison.is_equal()
+    assert_frame_equal(comparison.left_only, df1.slice(2, 1))
+    assert_frame_equal(comparison.right_only, df2.slice(0, 1))
+    assert comparison.left_only_columns == ["c"]
+    assert comparison.right_only_columns == ["d"]
+
+    assert list(comparison.mismatches) == ["a", "b"]
+    assert_frame_equal(
+        comparison.mismatches["a"],
+        pl.DataFrame(
+            {"id": [2], "left": ["y"], "right": [None]},
+            schema_overrides={"right": pl.String},
+        ),
+    )
+    assert_frame_equal(
+        comparison.mismatches["b"],
+        pl.DataFrame({"id": [1, 4], "left": [1, 4], "right": [10, 40]}),
+    )
+
+
+def test_compare_multiple_keys_with_nulls() -> None:
+    df1 = pl.DataFrame({"k1": [1, 1, None], "k2": ["a", "b", "c"], "v": [1, 2, 3]})
+    df2 = pl.DataFrame({"k1": [None, 1, 1], "k2": ["c", "b", "a"], "v": [3, 2, 0]})
+
+    comparison = df1.compare(df2, on=["k1", "k2"])
+    assert comparison.left_only.is_empty()
+    assert comparison.right_only.is_empty()
+    assert_frame_equal(
+        comparison.mismatches["v"],
+        pl.DataFrame({"k1": [1], "k2": ["a"], "left": [1], "right": [0]}),
+    )
+
+
+def test_compare_float_tolerance() -> None:
+    df1 = pl.DataFrame({"id": [1, 2, 3], "x": [1.0, 2.0, None]})
+    df2 = pl.DataFrame({"id": [1, 2, 3], "x": [1.05, 2.5, None]})
+
+    assert list(df1.compare(df2, on="id").mismatches) == ["x"]
+    assert_frame_equal(
+        df1.compare(df2, on="id", abs_tol=0.1).mismatches["x"],
+        pl.DataFrame({"id": [2], "left": [2.0], "right": [2.5]}),
+    )
+    assert df1.compare(df2, on="id", abs_tol=0.5).is_equal()
+
+
+def test_compare_errors() -> None:
+    df = pl.DataFrame({"id": [1, 1], "x": [1, 2]})
+    other = pl.DataFrame({"id": [1], "x": [1]})
+
+    with pytest.raises(InvalidOperationError, match="not unique in the left frame"):
+        df.compare(other, on="id")
+    with pytest.raises(InvalidOperationError, match="not unique in the right frame"):
+        other.compare(df, on="id")
+    with pytest.raises(InvalidOperationError, match="at least one key"):
+        other.compare(other, on=[])
+    with pytest.raises(InvalidOperationError, match="non-negative"):
+        other.compare(other, on="id", abs_tol=-1.0)
@@ -281,3 +281,63 @@ def test_upsample_sorted_only_within_group_but_no_group_by_provided() -> None:
         match=r"argument in operation 'upsample' is not sorted, please sort the 'expr/series/column' first",
     ):
         df.upsample(time_column="time", every="1mo")
+
+
+def test_upsample_fill_per_column() -> None:
+    df = pl.DataFrame(
+        {
+            "time": [
+                datetime(2021, 2, 1),
+                datetime(2021, 4, 1),
+                datetime(2021, 5, 1),
+                datetime(2021, 6, 1),
+            ],
+            "groups": ["A", "B", "A", "B"],
+            "values": [0, 1, 2, 3],
+            "other": [1.5, 2.5, 3.5, 4.5],
+        }
+    ).set_sorted("time")
+
+    result = df.upsample(
+        time_column="time",
+        every="1mo",
+        group_by="groups",
+        maintain_order=True,
+        fill={"groups": "forward", "values": "backward", "other": 0.0},
+    )
+    expected = pl.DataFrame(
+        {
+            "time": [
+                datetime(2021, 2, 1),
+                datetime(2021, 3, 1),
+                datetime(2021, 4, 1),
+                datetime(2021, 5, 1),
+                datetime(2021, 4, 1),
+                datetime(2021, 5, 1),
+                datetime(2021, 6, 1),
+            ],
+            "groups": ["A", "A", "A", "A", "B", "B", "B"],
+            "values": [0, 2, 2, 2, 1, 3, 3],
+            "other": [1.5, 0.0, 0.0, 3.5, 2.5, 0.0, 4.5],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+    result = df.upsample(
+        time_column="time", every="1mo", fill={"values": "interpolate"}
+    )
+    assert result["values"].to_list() == [0.0, 0.5, 1.0, 2.0, 3.0]
+    assert result["groups"].to_list() == ["A", None, "B", "A", "B"]
+
+
+def test_upsample_fill_invalid() -> None:
+    df = pl.DataFrame(
+        {"time": [datetime(2021, 2, 1), datetime(2021, 4, 1)], "values": [0, 1]}
+    ).set_sorted("time")
+
+    with pytest.raises(ValueError, match="upsample fill strategy must be one of"):
+        df.upsample(time_column="time", every="1mo", fill={"values": "mean"})
+    with pytest.raises(InvalidOperationError, match="cannot fill the upsample time"):
+        df.upsample(time_column="time", every="1mo", fill={"time": "forward"})
+    with pytest.raises(pl.exceptions.ColumnNotFoundError):
+        df.upsample(time_column="time", every="1mo", fill={"missing": "forward"})
@@ -326,9 +326,10 @@ def test_decimal_aggregations() -> None:
             "sum": [D("9110.33")],
             "min": [D("0.10")],
             "max": [D("9000.12")],
-            "mean": [2277.5825],
+            "mean": [D("2277.5825")],
             "median": [55.055],
-        }
+        },
+        schema_overrides={"mean": pl.Decimal(None, 6)},
     )
     assert_frame_equal(res, expected)
 
@@ -718,3 +719,92 @@ def test_decimal_min_over_21096() -> None:
     df = pl.Series("x", [1, 2], pl.Decimal(scale=2)).to_frame()
     result = df.select(pl.col("x").min().over("x"))
     assert result["x"].to_list() == [D("1.00"), D("2.00")]
+
+
+def test_decimal_arithmetic_precision() -> None:
+    df = pl.DataFrame(
+        {"a": [D("12.34"), D("-0.50")], "b": [D("1.50"), D("99.90")]},
+        schema={"a": pl.Decimal(4, 2), "b": pl.Decimal(4, 2)},
+    )
+    q = df.lazy().select(
+        add=pl.col("a") + pl.col("b"),
+        sub=pl.col("a") - pl.col("b"),
+        mul=pl.col("a") * pl.col("b"),
+        div=pl.col("a") / pl.col("b"),
+    )
+    expected_schema = pl.Schema(
+        {
+            "add": pl.Decimal(5, 2),
+            "sub": pl.Decimal(5, 2),
+            "mul": pl.Decimal(8, 4),
+            "div": pl.Decimal(None, 6),
+        }
+    )
+    assert q.collect_schema() == expected_schema
+
+    out = q.collect()
+    assert out.schema == expected_schema
+    assert out.to_dict(as_series=False) == {
+        "add": [D("13.84"), D("99.40")],
+        "sub": [D("10.84"), D("-100.40")],
+        "mul": [D("18.5100"), D("-49.9500")],
+        "div": [D("8.226666"), D("-0.005005")],
+    }
+
+    # precision is dropped once it no longer fits in 38 digits
+    s = pl.Series([D("1.5")], dtype=pl.Decimal(30, 1))
+    assert (s * s).dtype == pl.Decimal(None, 2)
+
+
+def test_decimal_supertype_precision() -> None:
+    a = pl.DataFrame({"x": [D("123.4")]}, schema={"x": pl.Decimal(4, 1)})
+    b = pl.DataFrame({"x": [D("1.23")]}, schema={"x": pl.Decimal(3, 2)})
+    c = pl.DataFrame({"x": [100]}, schema={"x": pl.Int16})
+
+    out = pl.concat([a, b], how="vertical_relaxed")
+    assert out.schema == {"x": pl.Decimal(5, 2)}
+    assert out["x"].to_list() == [D("123.40"), D("1.23")]
+
+    out = pl.concat([a, c], how="vertical_relaxed")
+    assert out.schema == {"x": pl.Decimal(6, 1)}
+    assert out["x"].to_list() == [D("123.4"), D("100.0")]
+
+
+def test_decimal_mean() -> None:
+    df = pl.DataFrame(
+        {
+            "g": [1, 1, 2, 2, 3],
+            "a": [D("0.10"), D("0.20"), D("10.01"), None, None],
+        },
+        schema={"g": pl.Int64, "a": pl.Decimal(10, 2)},
+    )
+    expected_dtype = pl.Decimal(None, 6)
+
+    assert df["a"].mean() == D("3.436666")
+
+    out = df.select(pl.col("a").mean())
+    assert out.schema == {"a": expected_dtype}
+    assert out.item() == D("3.436666")
+
+    out = df.group_by("g", maintain_order=True).agg(pl.col("a").mean())
+    assert out.schema == {"g": pl.Int64, "a": expected_dtype}
+    assert out["a"].to_list() == [D("0.15"), D("10.01"), None]
+
+    lf = df.lazy().group_by("g").agg(pl.col("a").mean())
+    out = lf.collect(engine="streaming").sort("g")
+    assert out["a"].to_list() == [D("0.15"), D("10.01"), None]
+
+
+def test_decimal_floor_ceil_decimals() -> None:
+    s = pl.Series("a", [D("1.237"), D("-1.231"), D("2.000"), None], pl.Decimal(6, 3))
+
+    assert_series_equal(
+        s.floor(2),
+        pl.Series("a", [D("1.230"), D("-1.240"), D("2.000"), None], pl.Decimal(6, 3)),
+    )
+    assert_series_equal(
+        s.ceil(2),
+        pl.Series("a", [D("1.240"), D("-1.230"), D("2.000"), None], pl.Decimal(6, 3)),
+    )
+    assert_series_equal(s.floor(3), s)
+    assert_series_equal(s.ceil(5), s)
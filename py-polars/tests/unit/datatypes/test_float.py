@@ -1,9 +1,16 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
 import pyarrow as pa
 import pytest
 
 import polars as pl
 from polars.testing import assert_series_equal
 
+if TYPE_CHECKING:
+    from polars._typing import EngineType
+
 
 def test_nan_in_group_by_agg() -> None:
     df = pl.DataFrame(
@@ -305,4 +312,77 @@ def test_arrow_float16_read_empty_20946() -> None:
 
     df = pl.from_arrow(table)
     assert df.shape == (0, 1)
-    assert df.schema == pl.Schema([("float_column", pl.Float32)])  # type: ignore[union-attr]
+    assert df.schema == pl.Schema([("float_column", pl.Float16)])  # type: ignore[union-attr]
+
+
+def test_float16_cast_rounds() -> None:
+    s = pl.Series("a", [0.1, 1.0, 65504.0, None], dtype=pl.Float64).cast(pl.Float16)
+    assert s.dtype == pl.Float16
+    assert s.to_list() == [0.0999755859375, 1.0, 65504.0, None]
+
+    s = pl.Series("a", [0.1, 70000.0], dtype=pl.Float16)
+    assert s.to_list() == [0.0999755859375, float("inf")]
+
+
+def test_float16_arithmetic_returns_float32() -> None:
+    lf = pl.LazyFrame({"a": [1.5, 2.0]}, schema={"a": pl.Float16}).select(
+        add=pl.col("a") + pl.col("a"),
+        mul=pl.col("a") * 2,
+        div=pl.col("a") / 2,
+    )
+    expected_schema = pl.Schema(
+        {"add": pl.Float32, "mul": pl.Float32, "div": pl.Float32}
+    )
+    assert lf.collect_schema() == expected_schema
+
+    out = lf.collect()
+    assert out.schema == expected_schema
+    assert out.row(0) == (3.0, 3.0, 0.75)
+
+
+def test_float16_supertype() -> None:
+    a = pl.Series("a", [1.0], dtype=pl.Float16)
+    for other, expected in [
+        (pl.Series("a", [2], dtype=pl.Int8), pl.Float16),
+        (pl.Series("a", [2.0], dtype=pl.Float32), pl.Float32),
+        (pl.Series("a", [2], dtype=pl.Int32), pl.Float64),
+        (pl.Series("a", [2.0], dtype=pl.Float64), pl.Float64),
+    ]:
+        assert pl.concat([a, other], how="vertical_relaxed").dtype == expected
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_float16_aggregations(engine: EngineType) -> None:
+    lf = pl.LazyFrame(
+        {"g": [1, 1, 2, 2, 2], "a": [0.5, 1.5, -2.0, None, 4.0]},
+        schema={"g": pl.Int64, "a": pl.Float16},
+    )
+    aggs = [
+        pl.col("a").sum().alias("sum"),
+        pl.col("a").min().alias("min"),
+        pl.col("a").max().alias("max"),
+        pl.col("a").mean().alias("mean"),
+    ]
+    expected_schema = {
+        "sum": pl.Float32,
+        "min": pl.Float16,
+        "max": pl.Float16,
+        "mean": pl.Float32,
+    }
+
+    out = lf.select(aggs).collect(engine=engine)
+    assert out.schema == pl.Schema(expected_schema)
+    assert out.row(0) == (4.0, -2.0, 4.0, 1.0)
+
+    out = lf.group_by("g").agg(aggs).sort("g").collect(engine=engine)
+    assert out.schema == pl.Schema({"g": pl.Int64, **expected_schema})
+    assert out.rows() == [(1, 2.0, 0.5, 1.5, 1.0), (2, 2.0, -2.0, 4.0, 1.0)]
+
+
+def test_float16_arrow_roundtrip() -> None:
+    s = pl.Series("a", [0.5, None, 1.5], dtype=pl.Float16)
+    arr = s.to_arrow()
+    assert arr.type == pa.float16()
+
+    out = pl.from_arrow(arr)
+    assert_series_equal(out, s)  # type: ignore[arg-type]
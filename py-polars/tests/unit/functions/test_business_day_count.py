@@ -130,6 +130,38 @@ def test_business_day_count_w_holidays() -> None:
     assert_series_equal(result, expected)
 
 
+def test_business_day_count_w_calendar() -> None:
+    pl.register_business_calendar(
+        "test-business-day-count", holidays=[date(2020, 1, 1), date(2020, 1, 9)]
+    )
+    try:
+        assert "test-business-day-count" in pl.business_calendars()
+        df = pl.DataFrame(
+            {
+                "start": [date(2020, 1, 1), date(2020, 1, 2), date(2020, 1, 2)],
+                "end": [date(2020, 1, 2), date(2020, 1, 10), date(2020, 1, 9)],
+            }
+        )
+        result = df.select(
+            business_day_count=pl.business_day_count(
+                "start", "end", calendar="test-business-day-count"
+            ),
+        )["business_day_count"]
+        expected = pl.Series("business_day_count", [0, 5, 5], pl.Int32)
+        assert_series_equal(result, expected)
+
+        result = df.select(
+            pl.col("start").dt.add_business_days(
+                5, calendar="test-business-day-count", roll="forward"
+            )
+        )["start"]
+        expected = pl.Series("start", [date(2020, 1, 10)] * 3)
+        assert_series_equal(result, expected)
+    finally:
+        pl.unregister_business_calendar("test-business-day-count")
+    assert "test-business-day-count" not in pl.business_calendars()
+
+
 @given(
     start=st.dates(min_value=dt.date(1969, 1, 1), max_value=dt.date(1970, 12, 31)),
     end=st.dates(min_value=dt.date(1969, 1, 1), max_value=dt.date(1970, 12, 31)),
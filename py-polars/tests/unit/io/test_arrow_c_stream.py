@@ -0,0 +1,63 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+import pyarrow as pa
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal
+from tests.unit.utils.pycapsule_utils import PyCapsuleStreamHolder
+
+if TYPE_CHECKING:
+    from polars._typing import EngineType
+
+
+def record_batch_reader(df: pl.DataFrame, batch_size: int) -> pa.RecordBatchReader:
+    table = df.to_arrow()
+    return pa.RecordBatchReader.from_batches(
+        table.schema, table.to_batches(max_chunksize=batch_size)
+    )
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_scan_arrow_c_stream(engine: EngineType) -> None:
+    df = pl.DataFrame(
+        {
+            "a": range(10),
+            "b": [str(i) for i in range(10)],
+            "c": [None, 1.5] * 5,
+        }
+    )
+    q = (
+        pl.scan_arrow_c_stream(record_batch_reader(df, 3))
+        .filter(pl.col("a") % 2 == 1)
+        .select("b", "c")
+    )
+    expected = df.filter(pl.col("a") % 2 == 1).select("b", "c")
+    assert_frame_equal(q.collect(engine=engine), expected)
+
+
+def test_scan_arrow_c_stream_polars_source() -> None:
+    df = pl.DataFrame({"x": [1, 2, 3], "y": ["a", None, "c"]})
+    out = pl.scan_arrow_c_stream(PyCapsuleStreamHolder(df)).collect()
+    assert_frame_equal(out, df)
+
+
+def test_scan_arrow_c_stream_empty() -> None:
+    df = pl.DataFrame(schema={"x": pl.Int64, "y": pl.String})
+    out = pl.scan_arrow_c_stream(record_batch_reader(df, 3)).collect()
+    assert_frame_equal(out, df)
+
+
+def test_scan_arrow_c_stream_invalid_source() -> None:
+    with pytest.raises(ValueError, match="__arrow_c_stream__"):
+        pl.scan_arrow_c_stream([1, 2, 3])
+
+
+def test_scan_arrow_c_stream_rescan() -> None:
+    df = pl.DataFrame({"x": [1, 2, 3]})
+    lf = pl.scan_arrow_c_stream(record_batch_reader(df, 2))
+    assert_frame_equal(lf.collect(), df)
+    with pytest.raises(pl.exceptions.ComputeError, match="already consumed"):
+        lf.collect()
@@ -248,7 +248,15 @@ def test_from_float16() -> None:
     f = io.BytesIO()
     pandas_df.to_feather(f)
     f.seek(0)
-    assert pl.read_ipc(f, use_pyarrow=False).dtypes == [pl.Float32]
+    assert pl.read_ipc(f, use_pyarrow=False).dtypes == [pl.Float16]
+
+
+def test_float16_roundtrip() -> None:
+    df = pl.DataFrame({"a": [0.5, None, 1.5]}, schema={"a": pl.Float16})
+    f = io.BytesIO()
+    df.write_ipc(f)
+    f.seek(0)
+    assert_frame_equal(pl.read_ipc(f), df)
 
 
 @pytest.mark.write_disk
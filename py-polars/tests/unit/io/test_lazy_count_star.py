@@ -3,6 +3,7 @@ from __future__ import annotations
 from typing import TYPE_CHECKING
 
 if TYPE_CHECKING:
+    from collections.abc import Callable
     from pathlib import Path
 
 import gzip
@@ -97,6 +98,34 @@ def test_count_ndjson(io_files_path: Path, path: str, n_rows: int) -> None:
     assert_frame_equal(lf.collect(), expected)
 
 
+@pytest.mark.parametrize(
+    ("scan", "pattern", "n_rows"),
+    [
+        (pl.scan_csv, "foods*.csv", 27 * 5),
+        (pl.scan_ipc, "foods*.ipc", 27 * 2),
+        (pl.scan_parquet, "foods*.parquet", 54),
+    ],
+)
+def test_count_streaming(
+    io_files_path: Path,
+    scan: Callable[..., pl.LazyFrame],
+    pattern: str,
+    n_rows: int,
+    capfd: pytest.CaptureFixture[str],
+) -> None:
+    lf = scan(io_files_path / pattern).select(pl.len().alias("n"))
+
+    expected = pl.DataFrame(pl.Series("n", [n_rows], dtype=pl.UInt32))
+    capfd.readouterr()
+    with pl.Config(verbose=True):
+        out = lf.collect(engine="streaming")
+    assert_frame_equal(out, expected)
+
+    # The rows are counted without decoding the files.
+    err = capfd.readouterr().err
+    assert "running count[" in err
+
+
 def test_count_compressed_csv_18057(io_files_path: Path) -> None:
     csv_file = io_files_path / "gzipped.csv.gz"
 
@@ -413,6 +413,25 @@ def test_multiscan_head(
     )
 
 
+def test_multiscan_head_stops_opening_files(
+    capfd: Any, monkeypatch: pytest.MonkeyPatch
+) -> None:
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+
+    f = io.BytesIO()
+    pl.Series("c1", range(10)).to_frame().write_parquet(f)
+    bs = f.getvalue()
+
+    assert_frame_equal(
+        pl.scan_parquet([bs] * 100).head(25).collect(engine="streaming"),
+        pl.Series("c1", [*range(10), *range(10), *range(5)]).to_frame(),
+    )
+
+    # Files past the end of the limit are not opened.
+    captured = capfd.readouterr().err
+    assert 3 <= captured.count("[MultiScan]: Initialize source") < 10
+
+
 @pytest.mark.parametrize(
     ("scan", "write"),
     [
@@ -2221,7 +2221,7 @@ def test_decode_f16() -> None:
         }
     )
 
-    df = pl.Series("x", values, pl.Float32).to_frame()
+    df = pl.Series("x", values, pl.Float16).to_frame()
 
     f = io.BytesIO()
     pq.write_table(table, f)
@@ -3119,3 +3119,37 @@ def test_unspecialized_decoding_prefiltering() -> None:
         .collect(engine="streaming")
     )
     assert_frame_equal(result, df.filter(expr))
+
+
+def test_f16_roundtrip() -> None:
+    df = pl.DataFrame(
+        {"x": [0.1, None, -2.5, float("inf")]}, schema={"x": pl.Float16}
+    )
+
+    f = io.BytesIO()
+    df.write_parquet(f)
+    f.seek(0)
+    assert_frame_equal(pl.read_parquet(f), df)
+
+    f.seek(0)
+    assert pq.read_table(f).schema.field("x").type == pa.float16()
+
+
+def test_int128_roundtrip() -> None:
+    values = [2**127 - 1, None, -(2**127), 0, 10**20]
+    df = pl.DataFrame(
+        {"x": values, "l": [[v] for v in values]},
+        schema={"x": pl.Int128, "l": pl.List(pl.Int128)},
+    )
+
+    f = io.BytesIO()
+    df.write_parquet(f, statistics=True)
+    f.seek(0)
+    assert_frame_equal(pl.read_parquet(f), df)
+
+    f.seek(0)
+    result = pl.scan_parquet(f).filter(pl.col("x") > 10**19).collect()
+    assert result["x"].to_list() == [2**127 - 1, 10**20]
+
+    f.seek(0)
+    assert pq.read_table(f).schema.field("x").type == pa.binary(16)
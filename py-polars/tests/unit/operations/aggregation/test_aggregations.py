@@ -13,7 +13,7 @@ from polars.testing import assert_frame_equal
 if TYPE_CHECKING:
     import numpy.typing as npt
 
-    from polars._typing import PolarsDataType
+    from polars._typing import EngineType, PolarsDataType
 
 
 def test_quantile_expr_input() -> None:
@@ -778,3 +778,127 @@ def test_empty_agg_22005() -> None:
         .select(pl.col("a").sum())
     )
     assert_frame_equal(out.collect(), pl.DataFrame({"a": 0}))
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_approx_quantile(engine: EngineType) -> None:
+    n = 100_000
+    lf = pl.LazyFrame(
+        {
+            "g": [i % 2 for i in range(n)],
+            "v": [float((i * 7919) % n) for i in range(n)],
+        }
+    )
+
+    out = (
+        lf.group_by("g")
+        .agg(
+            exact=pl.col("v").quantile(0.9, interpolation="linear"),
+            approx=pl.col("v").approx_quantile(0.9),
+            approx_median=pl.col("v").approx_median(),
+            median=pl.col("v").median(),
+        )
+        .collect(engine=engine)
+    )
+    assert ((out["approx"] - out["exact"]).abs() < 0.005 * n).all()
+    assert ((out["approx_median"] - out["median"]).abs() < 0.005 * n).all()
+
+    out = lf.select(
+        pl.col("v").approx_quantile(0.0),
+        q1=pl.col("v").approx_quantile(1.0),
+    )
+    assert out.collect(engine=engine).row(0) == (0.0, n - 1)
+
+    out = pl.LazyFrame({"v": [None, 1.0]}, schema={"v": pl.Float32}).select(
+        pl.col("v").approx_median()
+    )
+    expected = pl.DataFrame({"v": [1.0]}, schema={"v": pl.Float32})
+    assert_frame_equal(out.collect(engine=engine), expected)
+
+
+def test_weighted_aggregations() -> None:
+    df = pl.DataFrame(
+        {
+            "g": ["a", "a", "a", "b", "b", "b"],
+            "x": [1.0, 3.0, None, 2.0, 4.0, 10.0],
+            "w": [3, 1, 5, 1, None, 2],
+        }
+    )
+    out = df.group_by("g", maintain_order=True).agg(
+        mean=pl.col("x").weighted_mean("w"),
+        std=pl.col("x").weighted_std("w", ddof=0),
+        median=pl.col("x").weighted_quantile("w", 0.5),
+    )
+    expected = pl.DataFrame(
+        {
+            "g": ["a", "b"],
+            "mean": [1.5, 22 / 3],
+            "std": [0.75**0.5, (128 / 9) ** 0.5],
+            "median": [1.0, 10.0],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+    out = df.select(pl.col("x").weighted_mean("w").over("g"))
+    assert_frame_equal(out, pl.DataFrame({"x": [1.5] * 3 + [22 / 3] * 3}))
+
+    assert df.select(pl.col("x").weighted_mean(pl.lit(0)))["x"].to_list() == [None]
+    with pytest.raises(pl.exceptions.ComputeError, match="non-negative"):
+        df.select(pl.col("x").weighted_mean(-pl.col("w")))
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_array_sum_mean_elementwise(engine: EngineType) -> None:
+    lf = pl.LazyFrame(
+        {
+            "g": [1, 1, 2, 2, 3],
+            "a": [[1, 2], [3, None], [5, 6], None, None],
+        },
+        schema={"g": pl.Int64, "a": pl.Array(pl.Int8, 2)},
+    )
+    aggs = {"sum": pl.col("a").sum(), "mean": pl.col("a").mean()}
+    out = lf.group_by("g").agg(**aggs).sort("g").collect(engine=engine)
+    expected = pl.DataFrame(
+        {
+            "g": [1, 2, 3],
+            "sum": [[4, 2], [5, 6], [0, 0]],
+            "mean": [[2.0, 2.0], [5.0, 6.0], [None, None]],
+        },
+        schema={
+            "g": pl.Int64,
+            "sum": pl.Array(pl.Int64, 2),
+            "mean": pl.Array(pl.Float64, 2),
+        },
+    )
+    assert_frame_equal(out, expected)
+    assert lf.group_by("g").agg(**aggs).collect_schema() == out.schema
+
+    out = lf.select(**aggs).collect(engine=engine)
+    assert out.schema == expected.select("sum", "mean").schema
+    assert out.rows() == [([9, 8], [3.0, 4.0])]
+
+
+def test_mode_ties_group_by() -> None:
+    df = pl.DataFrame(
+        {
+            "g": ["x", "x", "x", "x", "y", "y", "z"],
+            "v": [3, 1, 1, 3, 5, 4, None],
+        }
+    )
+    out = df.group_by("g", maintain_order=True).agg(
+        all=pl.col("v").mode(),
+        first=pl.col("v").mode(ties="first"),
+        smallest=pl.col("v").mode(ties="smallest"),
+    )
+    expected = pl.DataFrame(
+        {
+            "g": ["x", "y", "z"],
+            "all": [[3, 1], [5, 4], [None]],
+            "first": [3, 5, None],
+            "smallest": [1, 4, None],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+    out = df.lazy().select(pl.col("v").mode(ties="smallest")).collect()
+    assert out.to_dict(as_series=False) == {"v": [1]}
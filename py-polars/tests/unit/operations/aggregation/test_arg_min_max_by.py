@@ -0,0 +1,90 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.exceptions import ShapeError
+from polars.testing import assert_frame_equal
+
+
+@pytest.fixture
+def df() -> pl.DataFrame:
+    return pl.DataFrame(
+        {
+            "g": [1, 1, 1, 2, 2, 3],
+            "a": [2, 1, 1, 5, None, None],
+            "b": ["x", "z", "y", None, "q", None],
+            "v": [10, 20, 30, 40, 50, 60],
+        }
+    )
+
+
+def test_arg_min_max_by(df: pl.DataFrame) -> None:
+    out = df.select(
+        min_a=pl.arg_min_by("a"),
+        min_ab=pl.arg_min_by("a", "b"),
+        max_ab=pl.arg_max_by(["a", "b"]),
+        max_ba=pl.arg_max_by(pl.col("b"), pl.col("a")),
+    )
+    expected = pl.DataFrame(
+        {"min_a": [1], "min_ab": [2], "max_ab": [3], "max_ba": [1]},
+        schema={
+            "min_a": pl.UInt32,
+            "min_ab": pl.UInt32,
+            "max_ab": pl.UInt32,
+            "max_ba": pl.UInt32,
+        },
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_min_max_by_group_by(df: pl.DataFrame) -> None:
+    out = df.group_by("g", maintain_order=True).agg(
+        min=pl.col("v").min_by("a", "b"),
+        max=pl.col("v").max_by("a", "b"),
+        arg_min=pl.arg_min_by("a", "b"),
+    )
+    expected = pl.DataFrame(
+        {
+            "g": [1, 2, 3],
+            "min": [30, 40, None],
+            "max": [10, 40, None],
+            "arg_min": [2, 0, None],
+        },
+        schema_overrides={"arg_min": pl.UInt32},
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_min_max_by_matches_sort(df: pl.DataFrame) -> None:
+    df = df.drop_nulls()
+    out = df.group_by("g", maintain_order=True).agg(
+        pl.col("v").min_by("a", "b").alias("min"),
+        pl.col("v").max_by("a", "b").alias("max"),
+    )
+    expected = df.group_by("g", maintain_order=True).agg(
+        pl.col("v").sort_by("a", "b").first().alias("min"),
+        pl.col("v").sort_by("a", "b", descending=True).first().alias("max"),
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_min_max_by_over_and_struct() -> None:
+    df = pl.DataFrame(
+        {
+            "g": ["a", "a", "b"],
+            "s": [{"x": 1, "y": 2}, {"x": 1, "y": 1}, {"x": 0, "y": 0}],
+            "v": [1, 2, 3],
+        }
+    )
+    out = df.select(pl.col("v").min_by("s").over("g"))
+    assert out["v"].to_list() == [2, 2, 3]
+
+
+def test_arg_min_max_by_empty_and_invalid() -> None:
+    df = pl.DataFrame({"a": [], "b": []}, schema={"a": pl.Int64, "b": pl.String})
+    assert df.select(pl.arg_min_by("a", "b")).item() is None
+    assert df.select(pl.col("a").max_by("b")).item() is None
+
+    with pytest.raises(ShapeError):
+        pl.DataFrame({"a": [1, 2]}).select(pl.arg_min_by("a", pl.Series([1, 2, 3])))
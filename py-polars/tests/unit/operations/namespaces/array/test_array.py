@@ -498,3 +498,30 @@ def test_explode_19049() -> None:
     df = pl.DataFrame({"a": [1, 2, 3]}, schema={"a": pl.Int64})
     with pytest.raises(InvalidOperationError, match="expected Array type, got: i64"):
         df.select(pl.col.a.arr.explode())
+
+
+def test_array_dot_cosine_similarity() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [[1, 2], [3, 4], None, [None, 1]],
+            "b": [[1, 0], [0, 1], [1, 1], [1, 1]],
+        },
+        schema={"a": pl.Array(pl.Int64, 2), "b": pl.Array(pl.Float32, 2)},
+    )
+    out = df.select(
+        dot=pl.col("a").arr.dot("b"),
+        sim=pl.col("a").arr.cosine_similarity("b"),
+        query=pl.col("a").arr.cosine_similarity([3.0, 4.0]),
+    )
+    expected = pl.DataFrame(
+        {
+            "dot": [1.0, 4.0, None, None],
+            "sim": [1 / 5**0.5, 0.8, None, None],
+            "query": [11 / (5 * 5**0.5), 1.0, None, None],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+    with pytest.raises(pl.exceptions.ShapeError, match="same width"):
+        query = pl.Series([[1, 2, 3]], dtype=pl.Array(pl.Int64, 3))
+        df.select(pl.col("a").arr.dot(pl.lit(query)))
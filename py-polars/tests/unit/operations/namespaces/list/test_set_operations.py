@@ -231,3 +231,45 @@ def test_broadcast_sliced() -> None:
     expected = pl.DataFrame({"a": [[2], [3, 4]]})
 
     assert_frame_equal(out, expected)
+
+
+def test_list_set_operations_boolean() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [[True, False], [True, None], None, [False]],
+            "b": [[True], [None, False], [True], []],
+        }
+    )
+    out = df.select(
+        union=pl.col("a").list.set_union("b"),
+        intersection=pl.col("a").list.set_intersection("b"),
+        difference=pl.col("a").list.set_difference("b"),
+        symmetric_difference=pl.col("a").list.set_symmetric_difference("b"),
+    )
+    expected = pl.DataFrame(
+        {
+            "union": [[True, False], [True, None, False], None, [False]],
+            "intersection": [[True], [None], None, []],
+            "difference": [[False], [True], None, [False]],
+            "symmetric_difference": [[False], [True, False], None, [False]],
+        },
+        schema={
+            "union": pl.List(pl.Boolean),
+            "intersection": pl.List(pl.Boolean),
+            "difference": pl.List(pl.Boolean),
+            "symmetric_difference": pl.List(pl.Boolean),
+        },
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_list_set_operations_supertype() -> None:
+    df = pl.DataFrame(
+        {"a": [[1, 2], [3]], "b": [[2.5, 1.0], [3.0]]},
+        schema={"a": pl.List(pl.Int32), "b": pl.List(pl.Float64)},
+    )
+    out = df.select(pl.col("a").list.set_union("b"))
+    expected = pl.DataFrame(
+        {"a": [[1.0, 2.0, 2.5], [3.0]]}, schema={"a": pl.List(pl.Float64)}
+    )
+    assert_frame_equal(out, expected)
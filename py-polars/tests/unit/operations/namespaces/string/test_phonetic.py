@@ -0,0 +1,61 @@
+from __future__ import annotations
+
+import polars as pl
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+def test_str_soundex() -> None:
+    s = pl.Series(
+        "a", ["Robert", "Rupert", "Ashcraft", "Tymczak", "Pfister", "", "1-2", None]
+    )
+    expected = pl.Series(
+        "a", ["R163", "R163", "A261", "T522", "P236", None, None, None]
+    )
+    assert_series_equal(s.str.soundex(), expected)
+
+
+def test_str_metaphone() -> None:
+    s = pl.Series(
+        "a", ["Knight", "Wright", "Philip", "Fillip", "Catherine", "Kathryn", None]
+    )
+    expected = pl.Series("a", ["NT", "RT", "FLP", "FLP", "K0RN", "K0RN", None])
+    assert_series_equal(s.str.metaphone(), expected)
+
+
+def test_str_phonetic_ascii_fold() -> None:
+    df = pl.DataFrame({"a": ["Straße", "José", "Ørsted"]})
+    out = df.select(
+        pl.col("a").str.soundex().alias("soundex"),
+        pl.col("a").str.soundex(ascii_fold=False).alias("soundex_raw"),
+        pl.col("a").str.metaphone().alias("metaphone"),
+        pl.col("a").str.metaphone(ascii_fold=False).alias("metaphone_raw"),
+    )
+    expected = pl.DataFrame(
+        {
+            "soundex": ["S362", "J200", "O623"],
+            "soundex_raw": ["S360", "J200", "R233"],
+            "metaphone": ["STRS", "JS", "ORSTT"],
+            "metaphone_raw": ["STR", "JS", "RSTT"],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_str_phonetic_join_key() -> None:
+    left = pl.DataFrame({"a": ["Smith", "Müller", "Jones"]})
+    right = pl.DataFrame({"b": ["Mueller", "Smyth"]})
+    out = left.with_columns(key=pl.col("a").str.metaphone()).join(
+        right.with_columns(key=pl.col("b").str.metaphone()), on="key"
+    )
+    expected = pl.DataFrame(
+        {"a": ["Smith", "Müller"], "key": ["SM0", "MLR"], "b": ["Smyth", "Mueller"]}
+    )
+    assert_frame_equal(out, expected, check_row_order=False)
+
+
+def test_str_phonetic_schema() -> None:
+    lf = pl.LazyFrame({"a": ["Robert"]}).select(
+        pl.col("a").str.soundex().alias("soundex"),
+        pl.col("a").str.metaphone().alias("metaphone"),
+    )
+    assert lf.collect_schema() == {"soundex": pl.String, "metaphone": pl.String}
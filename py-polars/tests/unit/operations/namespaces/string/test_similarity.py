@@ -0,0 +1,87 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.exceptions import ShapeError
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+@pytest.fixture
+def df() -> pl.DataFrame:
+    return pl.DataFrame(
+        {
+            "a": ["kitten", "flaw", "héllo", "", None, "abc"],
+            "b": ["sitting", "lawn", "hello", "", "x", None],
+        }
+    )
+
+
+def test_str_levenshtein(df: pl.DataFrame) -> None:
+    out = df.select(
+        dist=pl.col("a").str.levenshtein(pl.col("b")),
+        sim=pl.col("a").str.levenshtein_similarity(pl.col("b")),
+    )
+    expected = pl.DataFrame(
+        {
+            "dist": pl.Series([3, 2, 1, 0, None, None], dtype=pl.UInt32),
+            "sim": [1 - 3 / 7, 0.5, 0.8, 1.0, None, None],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_str_hamming(df: pl.DataFrame) -> None:
+    out = df.select(
+        dist=pl.col("a").str.hamming(pl.col("b")),
+        sim=pl.col("a").str.hamming_similarity(pl.col("b")),
+    )
+    expected = pl.DataFrame(
+        {
+            "dist": pl.Series([None, 4, 1, 0, None, None], dtype=pl.UInt32),
+            "sim": [None, 0.0, 0.8, 1.0, None, None],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_str_jaro_winkler() -> None:
+    s = pl.Series("a", ["martha", "dixon", "abc", "", None])
+    other = pl.Series(["marhta", "dicksonx", "xyz", "", "x"])
+    out = s.str.jaro_winkler(other)
+    expected = pl.Series("a", [0.961111, 0.813333, 0.0, 1.0, None])
+    assert_series_equal(out, expected, abs_tol=1e-6)
+
+
+def test_str_similarity_broadcast() -> None:
+    s = pl.Series("a", ["kitten", "mitten", None])
+    assert s.str.levenshtein("kitten").to_list() == [0, 1, None]
+    assert s.str.hamming("kitten").to_list() == [0, 1, None]
+
+    out = pl.select(pl.lit("kitten").str.levenshtein(s))
+    assert out.to_series().to_list() == [0, 1, None]
+
+
+def test_str_similarity_schema(df: pl.DataFrame) -> None:
+    lf = df.lazy().select(
+        pl.col("a").str.levenshtein("x").alias("levenshtein"),
+        pl.col("a").str.levenshtein_similarity("x").alias("levenshtein_similarity"),
+        pl.col("a").str.hamming("x").alias("hamming"),
+        pl.col("a").str.hamming_similarity("x").alias("hamming_similarity"),
+        pl.col("a").str.jaro_winkler("x").alias("jaro_winkler"),
+    )
+    expected = {
+        "levenshtein": pl.UInt32,
+        "levenshtein_similarity": pl.Float64,
+        "hamming": pl.UInt32,
+        "hamming_similarity": pl.Float64,
+        "jaro_winkler": pl.Float64,
+    }
+    assert lf.collect_schema() == expected
+    assert lf.collect().schema == expected
+
+
+def test_str_similarity_length_mismatch() -> None:
+    s = pl.Series(["a", "b", "c"])
+    with pytest.raises(ShapeError):
+        s.str.levenshtein(pl.Series(["a", "b"]))
@@ -803,6 +803,44 @@ def test_json_path_match() -> None:
     assert_frame_equal(out, expected)
 
 
+def test_json_path_match_full_syntax() -> None:
+    s = pl.Series(
+        [
+            '{"a":{"b":1},"c":[{"b":2,"d":"x"},{"b":3,"d":"y"},{"b":4,"d":"z"}]}',
+            None,
+        ]
+    )
+    assert s.str.json_path_match("$..b").to_list() == ["1", None]
+    assert s.str.json_path_match("$.c[?(@.b > 2)].d").to_list() == ["y", None]
+    assert s.str.json_path_match("$.c[2:].d").to_list() == ["z", None]
+
+
+def test_json_extract_all() -> None:
+    df = pl.DataFrame(
+        {
+            "str": [
+                '{"a":{"b":1},"c":[{"b":2,"d":"x"},{"b":null,"d":"y"}]}',
+                "not json",
+                None,
+                '{"a":2}',
+            ],
+            "pat": ["$.c[:].d", "$.a", None, "$.a"],
+        }
+    )
+    out = df.select(
+        lit_pat=pl.col("str").str.json_extract_all("$..b"),
+        col_pat=pl.col("str").str.json_extract_all(pl.col("pat")),
+    )
+    expected = pl.DataFrame(
+        {
+            "lit_pat": [["1", "2", None], None, None, []],
+            "col_pat": [["x", "y"], None, None, ["2"]],
+        },
+        schema={"lit_pat": pl.List(pl.String), "col_pat": pl.List(pl.String)},
+    )
+    assert_frame_equal(out, expected)
+
+
 def test_str_json_path_match_wrong_length() -> None:
     df = pl.DataFrame({"num": ["-10", "-1", "0"]})
     with pytest.raises((ShapeError, ComputeError)):
@@ -0,0 +1,80 @@
+from __future__ import annotations
+
+from datetime import timedelta
+from typing import TYPE_CHECKING
+
+import pytest
+
+import polars as pl
+from polars.exceptions import InvalidOperationError
+from polars.testing import assert_series_equal
+
+if TYPE_CHECKING:
+    from polars._typing import TimeUnit
+
+
+def test_str_to_duration_iso() -> None:
+    s = pl.Series(
+        "a",
+        ["P1DT2H3M4.5S", "P2W", "PT0S", "-PT1M", "PT1,5H", "P1D", None],
+    )
+    expected = pl.Series(
+        "a",
+        [
+            timedelta(days=1, hours=2, minutes=3, seconds=4.5),
+            timedelta(weeks=2),
+            timedelta(0),
+            timedelta(minutes=-1),
+            timedelta(hours=1, minutes=30),
+            timedelta(days=1),
+            None,
+        ],
+        dtype=pl.Duration("us"),
+    )
+    assert_series_equal(s.str.to_duration(), expected)
+
+
+def test_str_to_duration_polars_format() -> None:
+    s = pl.Series(["1d 2h 3m 4s 5ms 6µs", "1h30m", "-3d -22m", "7ns"])
+    result = s.str.to_duration("ns")
+    assert result.dtype == pl.Duration("ns")
+    assert result.cast(pl.Int64).to_list() == [
+        93_784_005_006_000,
+        5_400_000_000_000,
+        -(3 * 86_400 + 22 * 60) * 1_000_000_000,
+        7,
+    ]
+
+
+@pytest.mark.parametrize("time_unit", ["ms", "us", "ns"])
+@pytest.mark.parametrize("format", ["iso", "polars"])
+def test_str_to_duration_roundtrip(time_unit: TimeUnit, format: str) -> None:
+    s = pl.Series(
+        [
+            timedelta(days=3, hours=1, microseconds=5000),
+            timedelta(seconds=-90),
+            timedelta(0),
+            timedelta(weeks=10, milliseconds=1),
+            None,
+        ],
+        dtype=pl.Duration(time_unit),
+    )
+    result = s.dt.to_string(format).str.to_duration(time_unit)
+    assert_series_equal(result, s)
+
+
+def test_str_to_duration_strict() -> None:
+    s = pl.Series(["P1D", "P1Y", "1 day", "PT"])
+    with pytest.raises(InvalidOperationError, match="conversion from `str` to `duration"):
+        s.str.to_duration()
+
+    result = s.str.to_duration("ms", strict=False)
+    expected = pl.Series([timedelta(days=1), None, None, None], dtype=pl.Duration("ms"))
+    assert_series_equal(result, expected)
+
+
+def test_str_to_duration_lazy_schema() -> None:
+    lf = pl.LazyFrame({"a": ["PT1S"]})
+    result = lf.select(pl.col("a").str.to_duration("ms"))
+    assert result.collect_schema() == {"a": pl.Duration("ms")}
+    assert result.collect()["a"].item() == timedelta(seconds=1)
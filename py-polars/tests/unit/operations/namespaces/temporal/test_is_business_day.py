@@ -0,0 +1,92 @@
+from __future__ import annotations
+
+from datetime import date, datetime
+
+import hypothesis.strategies as st
+import numpy as np
+import pytest
+from hypothesis import given
+
+import polars as pl
+from polars.exceptions import ComputeError, InvalidOperationError
+from polars.testing import assert_series_equal
+
+
+def test_is_business_day() -> None:
+    s = pl.Series("date", [date(2020, 1, 3), date(2020, 1, 4), None, date(2020, 1, 6)])
+
+    result = s.dt.is_business_day()
+    expected = pl.Series("date", [True, False, None, True])
+    assert_series_equal(result, expected)
+
+    result = s.dt.is_business_day(holidays=[date(2020, 1, 6)])
+    expected = pl.Series("date", [True, False, None, False])
+    assert_series_equal(result, expected)
+
+    week_mask = (True, True, True, True, False, True, False)
+    result = s.dt.is_business_day(week_mask=week_mask)
+    expected = pl.Series("date", [False, True, None, True])
+    assert_series_equal(result, expected)
+
+
+def test_is_business_day_datetime() -> None:
+    s = pl.Series([datetime(2020, 1, 3, 23), datetime(2020, 1, 4, 1)])
+    assert s.dt.is_business_day().to_list() == [True, False]
+
+    s = s.dt.replace_time_zone("Asia/Kathmandu")
+    assert s.dt.is_business_day().to_list() == [True, False]
+
+
+def test_is_business_day_invalid() -> None:
+    s = pl.Series([date(2020, 1, 3)])
+    with pytest.raises(ComputeError, match="at least one business day"):
+        s.dt.is_business_day(week_mask=[False] * 7)
+    with pytest.raises(InvalidOperationError, match="expected date or datetime"):
+        pl.Series([1]).dt.is_business_day()
+
+
+def test_is_business_day_calendar() -> None:
+    pl.register_business_calendar(
+        "test-is-business-day",
+        week_mask=(True, True, True, True, False, False, False),
+        holidays=[date(2020, 1, 1)],
+    )
+    try:
+        s = pl.Series([date(2020, 1, 1), date(2020, 1, 2), date(2020, 1, 3)])
+        result = s.dt.is_business_day(calendar="test-is-business-day")
+        assert result.to_list() == [False, True, False]
+
+        # Extra holidays are added to the ones of the calendar.
+        result = s.dt.is_business_day(
+            holidays=[date(2020, 1, 2)], calendar="test-is-business-day"
+        )
+        assert result.to_list() == [False, False, False]
+
+        with pytest.raises(ValueError, match="cannot be combined with `calendar`"):
+            s.dt.is_business_day(week_mask=[True] * 7, calendar="test-is-business-day")
+    finally:
+        assert pl.unregister_business_calendar("test-is-business-day")
+
+    with pytest.raises(ComputeError, match="is not registered"):
+        s.dt.is_business_day(calendar="test-is-business-day")
+
+
+@given(
+    dates=st.lists(st.dates(min_value=date(1970, 1, 1), max_value=date(2100, 1, 1))),
+    week_mask=st.lists(st.sampled_from([True, False]), min_size=7, max_size=7),
+    holidays=st.lists(
+        st.dates(min_value=date(1970, 1, 1), max_value=date(2100, 1, 1)),
+        min_size=0,
+        max_size=100,
+    ),
+)
+def test_against_np_is_busday(
+    dates: list[date], week_mask: list[bool], holidays: list[date]
+) -> None:
+    if not any(week_mask):
+        return
+    result = pl.Series(dates, dtype=pl.Date).dt.is_business_day(
+        week_mask=week_mask, holidays=holidays
+    )
+    expected = np.is_busday(dates, weekmask=week_mask, holidays=holidays)
+    assert result.to_list() == expected.tolist()
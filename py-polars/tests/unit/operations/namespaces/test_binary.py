@@ -2,15 +2,18 @@ from __future__ import annotations
 
 import random
 import struct
-from typing import TYPE_CHECKING
+from typing import TYPE_CHECKING, Any
 
 import pytest
 
 import polars as pl
+from polars.exceptions import ComputeError
 from polars.testing import assert_frame_equal
 
 if TYPE_CHECKING:
-    from polars._typing import SizeUnit, TransferEncoding
+    from collections.abc import Iterator
+
+    from polars._typing import EncryptionAlgorithm, SizeUnit, TransferEncoding
 
 
 def test_binary_conversions() -> None:
@@ -272,3 +275,65 @@ def test_reinterpret_invalid() -> None:
     # Fails because dtype is invalid
     with pytest.raises(pl.exceptions.InvalidOperationError):
         df.select(pl.col("x").bin.reinterpret(dtype=pl.String))
+
+
+@pytest.fixture
+def secrets() -> Iterator[dict[str, bytes]]:
+    secrets: dict[str, bytes] = {}
+    pl.set_secret_provider(secrets.__getitem__)
+    yield secrets
+    pl.set_secret_provider(None)
+
+
+@pytest.mark.parametrize("algorithm", ["aes-gcm", "aes-gcm-siv"])
+@pytest.mark.parametrize("key_len", [16, 32])
+def test_encrypt_roundtrip(
+    secrets: dict[str, bytes], algorithm: EncryptionAlgorithm, key_len: int
+) -> None:
+    secrets["k"] = random.randbytes(key_len)
+    df = pl.DataFrame({"x": [b"secret", b"", None, b"secret"]})
+
+    encrypted = df.select(pl.col("x").bin.encrypt("k", algorithm))
+    assert encrypted.schema == {"x": pl.Binary}
+    values = encrypted["x"].to_list()
+    assert [None if v is None else len(v) for v in values] == [34, 28, None, 34]
+    # Every value gets its own nonce.
+    assert values[0] != values[3]
+
+    decrypted = encrypted.lazy().select(pl.col("x").bin.decrypt("k", algorithm))
+    assert_frame_equal(decrypted.collect(), df)
+
+
+def test_encrypt_wrong_key(secrets: dict[str, bytes]) -> None:
+    secrets["a"] = b"a" * 32
+    secrets["b"] = b"b" * 32
+    secrets["short"] = b"c" * 8
+
+    s = pl.Series([b"secret"]).bin.encrypt("a")
+    with pytest.raises(ComputeError, match="wrong key or modified data"):
+        s.bin.decrypt("b")
+    with pytest.raises(ComputeError, match="wrong key or modified data"):
+        s.bin.decrypt("a", "aes-gcm-siv")
+    with pytest.raises(ComputeError, match="must be 16 or 32 bytes, got 8"):
+        s.bin.decrypt("short")
+    with pytest.raises(ComputeError, match="failed to get secret 'missing'"):
+        s.bin.decrypt("missing")
+
+
+def test_encrypt_key_not_in_plan(secrets: dict[str, bytes]) -> None:
+    secrets["k"] = b"\xab" * 16
+    lf = pl.LazyFrame({"x": [b"secret"]}).select(pl.col("x").bin.encrypt("k"))
+    assert "encrypt" in lf.explain()
+    assert "ab" * 16 not in lf.explain()
+
+
+def test_encrypt_env_provider(monkeypatch: Any) -> None:
+    key = bytes(range(16))
+    monkeypatch.setenv("POLARS_TEST_ENCRYPTION_KEY", key.hex())
+    s = pl.Series([b"secret"])
+    out = s.bin.encrypt("POLARS_TEST_ENCRYPTION_KEY")
+    assert out.bin.decrypt("POLARS_TEST_ENCRYPTION_KEY").to_list() == [b"secret"]
+
+    monkeypatch.delenv("POLARS_TEST_ENCRYPTION_KEY")
+    with pytest.raises(ComputeError, match="not set in the environment"):
+        s.bin.encrypt("POLARS_TEST_ENCRYPTION_KEY")
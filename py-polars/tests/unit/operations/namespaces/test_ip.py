@@ -0,0 +1,124 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.exceptions import ComputeError
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+@pytest.fixture
+def df() -> pl.DataFrame:
+    return pl.DataFrame(
+        {"addr": ["192.168.1.10", "10.0.0.1", "2001:DB8::1", "::ffff:1.2.3.4", None]}
+    )
+
+
+def test_ip_parse_roundtrip(df: pl.DataFrame) -> None:
+    out = df.select(pl.col("addr").ip.parse())
+    assert out.schema == {"addr": pl.Binary}
+    assert out["addr"].to_list()[1] == bytes(10) + b"\xff\xff" + bytes([10, 0, 0, 1])
+
+    out = out.select(pl.col("addr").ip.to_string())
+    expected = pl.DataFrame(
+        {"addr": ["192.168.1.10", "10.0.0.1", "2001:db8::1", "1.2.3.4", None]}
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_ip_parse_strict() -> None:
+    s = pl.Series("addr", ["1.2.3.4", "1.2.3.256", " ::1 "])
+    with pytest.raises(ComputeError, match="invalid IP address: '1.2.3.256'"):
+        s.ip.parse()
+    out = s.ip.parse(strict=False).ip.to_string()
+    assert_series_equal(out, pl.Series("addr", ["1.2.3.4", None, "::1"]))
+
+
+def test_ip_version(df: pl.DataFrame) -> None:
+    out = df["addr"].ip.version()
+    expected = pl.Series("addr", [4, 4, 6, 4, None], dtype=pl.UInt8)
+    assert_series_equal(out, expected)
+
+
+def test_ip_is_in_cidr(df: pl.DataFrame) -> None:
+    out = df.select(
+        private=pl.col("addr").ip.is_in_cidr("192.168.0.0/16"),
+        doc=pl.col("addr").ip.is_in_cidr("2001:db8::/32"),
+    )
+    expected = pl.DataFrame(
+        {
+            "private": [True, False, False, False, None],
+            "doc": [False, False, True, False, None],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_ip_is_in_cidr_column() -> None:
+    df = pl.DataFrame(
+        {
+            "addr": ["10.1.2.3", "10.1.2.3", "2001:db8::1", "1.1.1.1"],
+            "cidr": ["10.1.0.0/16", "10.2.0.0/16", "::/0", None],
+        }
+    )
+    out = df.select(pl.col("addr").ip.is_in_cidr(pl.col("cidr")))
+    assert out["addr"].to_list() == [True, False, True, None]
+
+    with pytest.raises(ComputeError, match="invalid CIDR block"):
+        df.select(pl.col("addr").ip.is_in_cidr("10.0.0.0/33"))
+
+
+def test_ip_subnet(df: pl.DataFrame) -> None:
+    out = df["addr"].ip.subnet(24).ip.to_string()
+    expected = pl.Series(
+        "addr", ["192.168.1.0", "10.0.0.0", "2001:d00::", "1.2.3.0", None]
+    )
+    assert_series_equal(out, expected)
+
+    with pytest.raises(ComputeError, match="at most 128"):
+        df["addr"].ip.subnet(129)
+
+
+def test_ip_to_integer(df: pl.DataFrame) -> None:
+    out = df["addr"].ip.to_integer()
+    expected = pl.Series(
+        "addr", [3232235786, 167772161, None, 16909060, None], dtype=pl.UInt32
+    )
+    assert_series_equal(out, expected)
+
+    # Unsigned integers are read as IPv4 addresses.
+    assert out.ip.to_string().to_list() == [
+        "192.168.1.10",
+        "10.0.0.1",
+        None,
+        "1.2.3.4",
+        None,
+    ]
+
+
+def test_ip_invalid_dtype() -> None:
+    with pytest.raises(pl.exceptions.InvalidOperationError):
+        pl.Series([1.5]).ip.version()
+    with pytest.raises(ComputeError, match="expected 16 bytes"):
+        pl.Series([b"\x01\x02"]).ip.to_string()
+
+
+def test_ip_schema() -> None:
+    lf = pl.LazyFrame({"addr": ["10.0.0.1"]}).select(
+        pl.col("addr").ip.parse().alias("parse"),
+        pl.col("addr").ip.to_string().alias("to_string"),
+        pl.col("addr").ip.version().alias("version"),
+        pl.col("addr").ip.is_in_cidr("10.0.0.0/8").alias("is_in_cidr"),
+        pl.col("addr").ip.subnet(8).alias("subnet"),
+        pl.col("addr").ip.to_integer().alias("to_integer"),
+    )
+    expected = {
+        "parse": pl.Binary,
+        "to_string": pl.String,
+        "version": pl.UInt8,
+        "is_in_cidr": pl.Boolean,
+        "subnet": pl.Binary,
+        "to_integer": pl.UInt32,
+    }
+    assert lf.collect_schema() == expected
+    assert lf.collect().schema == expected
@@ -0,0 +1,142 @@
+from __future__ import annotations
+
+import io
+import json
+import struct
+
+import pyarrow as pa
+import pyarrow.parquet as pq
+import pytest
+
+import polars as pl
+from polars.exceptions import ComputeError, InvalidOperationError
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+def point(x: float, y: float) -> bytes:
+    return struct.pack("<BI2d", 1, 1, x, y)
+
+
+def polygon(*rings: list[tuple[float, float]]) -> bytes:
+    out = struct.pack("<BII", 1, 3, len(rings))
+    for ring in rings:
+        out += struct.pack("<I", len(ring))
+        out += b"".join(struct.pack("<2d", x, y) for x, y in ring)
+    return out
+
+
+def square(x: float, y: float, size: float) -> list[tuple[float, float]]:
+    return [(x, y), (x + size, y), (x + size, y + size), (x, y + size), (x, y)]
+
+
+def unpack_point(wkb: bytes) -> tuple[float, float]:
+    return struct.unpack("<2d", wkb[5:])
+
+
+@pytest.fixture
+def df() -> pl.DataFrame:
+    return pl.DataFrame(
+        {
+            "geom": [
+                polygon(square(0, 0, 4), square(1, 1, 2)),
+                polygon(square(2, 2, 2)),
+                point(1, 2),
+                None,
+            ]
+        },
+        schema={"geom": pl.Geometry},
+    )
+
+
+def test_geometry_dtype(df: pl.DataFrame) -> None:
+    assert df.schema == {"geom": pl.Geometry}
+    assert df["geom"][2] == point(1, 2)
+    assert df["geom"].cast(pl.Binary).dtype == pl.Binary
+
+    with pytest.raises(InvalidOperationError, match="binary values holding WKB"):
+        pl.Series([1, 2]).cast(pl.Geometry)
+
+
+def test_st_area(df: pl.DataFrame) -> None:
+    out = df.select(pl.col("geom").st.area())
+    expected = pl.DataFrame({"geom": [12.0, 4.0, 0.0, None]})
+    assert_frame_equal(out, expected)
+
+
+def test_st_centroid(df: pl.DataFrame) -> None:
+    out = df["geom"].st.centroid()
+    assert out.dtype == pl.Geometry
+    assert [unpack_point(v) if v else None for v in out] == [
+        (2.0, 2.0),
+        (3.0, 3.0),
+        (1.0, 2.0),
+        None,
+    ]
+
+
+def test_st_centroid_line() -> None:
+    # LINESTRING (0 0, 2 0, 2 6), weighted by the length of the segments.
+    line = struct.pack("<BII6d", 1, 2, 3, 0, 0, 2, 0, 2, 6)
+    out = pl.Series([line], dtype=pl.Binary).st.centroid()
+    assert unpack_point(out[0]) == (1.75, 2.25)
+
+
+def test_st_contains(df: pl.DataFrame) -> None:
+    out = df.select(pl.col("geom").st.contains(point(2.5, 3.5)))
+    expected = pl.DataFrame({"geom": [True, True, False, None]})
+    assert_frame_equal(out, expected)
+
+    other = pl.Series(
+        [point(0.5, 0.5), point(2, 2), point(1, 2), point(1, 2)], dtype=pl.Geometry
+    )
+    out = df["geom"].st.contains(other)
+    assert_series_equal(out, pl.Series("geom", [True, False, True, None]))
+
+
+def test_st_distance(df: pl.DataFrame) -> None:
+    out = df.select(pl.col("geom").st.distance(point(7, 8)))
+    expected = pl.DataFrame({"geom": [5.0, 5.0, 8.48528137423857, None]})
+    assert_frame_equal(out, expected)
+
+    out = df.select(pl.col("geom").st.distance(point(2, 2)))
+    expected = pl.DataFrame({"geom": [1.0, 0.0, 1.0, None]})
+    assert_frame_equal(out, expected)
+
+
+def test_st_invalid() -> None:
+    s = pl.Series([b"\x01\x01\x00"])
+    with pytest.raises(ComputeError, match="invalid WKB"):
+        s.st.area()
+    with pytest.raises(InvalidOperationError, match="expected geometries"):
+        pl.Series([1.0]).st.area()
+
+
+def test_geometry_parquet_roundtrip(df: pl.DataFrame) -> None:
+    f = io.BytesIO()
+    df.write_parquet(f)
+
+    f.seek(0)
+    assert_frame_equal(pl.read_parquet(f), df)
+
+    f.seek(0)
+    metadata = pq.read_metadata(f).metadata
+    geo = json.loads(metadata[b"geo"])
+    assert geo["primary_column"] == "geom"
+    assert geo["columns"]["geom"]["encoding"] == "WKB"
+
+
+def test_geoparquet_read() -> None:
+    table = pa.table({"id": [1, 2], "geometry": [point(1, 2), point(3, 4)]})
+    geo = {
+        "version": "1.1.0",
+        "primary_column": "geometry",
+        "columns": {"geometry": {"encoding": "WKB", "geometry_types": ["Point"]}},
+    }
+    table = table.replace_schema_metadata({"geo": json.dumps(geo)})
+
+    f = io.BytesIO()
+    pq.write_table(table, f, store_schema=False)
+    f.seek(0)
+    out = pl.read_parquet(f)
+    assert out.schema == {"id": pl.Int64, "geometry": pl.Geometry}
+    assert out["geometry"].to_list() == [point(1, 2), point(3, 4)]
@@ -0,0 +1,80 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.exceptions import SchemaError
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+@pytest.fixture
+def df() -> pl.DataFrame:
+    return pl.DataFrame(
+        {
+            "url": [
+                "https://Example.com/a/b%20c?q=polars&page=2&q=other",
+                "http://user@[::1]:8080/?q=a+b%26c",
+                "mailto:someone@example.com",
+                "/relative/path?q=1",
+                None,
+            ]
+        }
+    )
+
+
+def test_url_host_and_path(df: pl.DataFrame) -> None:
+    out = df.select(
+        host=pl.col("url").url.host(),
+        path=pl.col("url").url.path(),
+    )
+    expected = pl.DataFrame(
+        {
+            "host": ["example.com", "[::1]", None, None, None],
+            "path": ["/a/b%20c", "/", "someone@example.com", None, None],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_url_query_param(df: pl.DataFrame) -> None:
+    out = df.select(
+        q=pl.col("url").url.query_param("q"),
+        page=pl.col("url").url.query_param("page"),
+        missing=pl.col("url").url.query_param("missing"),
+    )
+    expected = pl.DataFrame(
+        {
+            "q": ["polars", "a b&c", None, None, None],
+            "page": ["2", None, None, None, None],
+            "missing": pl.Series([None] * 5, dtype=pl.String),
+        }
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_url_decode() -> None:
+    s = pl.Series("a", ["a%20b%2Fc", "caf%C3%A9+", "%FF", "100%", None])
+    expected = pl.Series("a", ["a b/c", "café+", None, "100%", None])
+    assert_series_equal(s.url.decode(), expected)
+
+
+def test_url_path_decode() -> None:
+    s = pl.Series(["https://pola.rs/caf%C3%A9/menu"])
+    assert s.url.path().url.decode().to_list() == ["/café/menu"]
+
+
+def test_url_invalid_dtype() -> None:
+    with pytest.raises(SchemaError):
+        pl.Series([1, 2]).url.host()
+
+
+def test_url_schema(df: pl.DataFrame) -> None:
+    lf = df.lazy().select(
+        pl.col("url").url.host().alias("host"),
+        pl.col("url").url.path().alias("path"),
+        pl.col("url").url.query_param("q").alias("query_param"),
+        pl.col("url").url.decode().alias("decode"),
+    )
+    expected = dict.fromkeys(["host", "path", "query_param", "decode"], pl.String)
+    assert lf.collect_schema() == expected
+    assert lf.collect().schema == expected
@@ -134,3 +134,28 @@ def test_rolling_map_rolling_std() -> None:
 
     expected = s.rolling_std(window_size=4, min_samples=3, center=False)
     assert_series_equal(result, expected)
+
+
+def test_rolling_map_return_dtype() -> None:
+    s = pl.Series("a", ["x", "y", "x", "x", None])
+
+    result = s.rolling_map(
+        lambda s: s.n_unique(), window_size=2, min_samples=1, return_dtype=pl.UInt32
+    )
+    expected = pl.Series("a", [1, 2, 2, 1, 2], dtype=pl.UInt32)
+    assert_series_equal(result, expected)
+
+    # Numeric values may produce a statistic of another dtype.
+    s = pl.Series("a", [1, 5, 2, 8])
+    result = s.rolling_map(
+        lambda s: s.max() > 4, window_size=2, return_dtype=pl.Boolean
+    )
+    expected = pl.Series("a", [None, True, True, True], dtype=pl.Boolean)
+    assert_series_equal(result, expected)
+
+    lf = pl.LazyFrame({"a": [1.0, 2.0, 3.0]})
+    result_lf = lf.select(
+        pl.col("a").rolling_map(lambda s: s.sum(), window_size=2, return_dtype=pl.Int64)
+    )
+    assert result_lf.collect_schema() == {"a": pl.Int64}
+    assert result_lf.collect()["a"].to_list() == [None, 3, 5]
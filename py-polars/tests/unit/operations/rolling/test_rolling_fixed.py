@@ -5,3 +5,50 @@ def test_rolling_var_stability_12905() -> None:
     s1 = pl.Series("a", [36743.6 for _ in range(10)])
     assert s1.rolling_var(window_size=12, min_samples=2).sum() == 0.0
     assert s1.rolling_std(window_size=12, min_samples=2).sum() == 0.0
+
+
+def test_rolling_mode_n_unique() -> None:
+    s = pl.Series("a", [3, 1, 1, 2, 3, 3])
+
+    out = s.rolling_mode(window_size=3)
+    assert out.to_list() == [None, None, 1, 1, 1, 3]
+    out = s.rolling_n_unique(window_size=3)
+    assert out.dtype == pl.UInt32
+    assert out.to_list() == [None, None, 2, 2, 3, 2]
+
+    out = s.rolling_mode(window_size=3, min_samples=1, center=True)
+    assert out.to_list() == [1, 1, 1, 1, 3, 3]
+    out = s.rolling_n_unique(window_size=3, min_samples=1, center=True)
+    assert out.to_list() == [2, 2, 2, 3, 2, 1]
+
+
+def test_rolling_mode_n_unique_nulls() -> None:
+    df = pl.DataFrame({"a": ["x", None, "y", "y", None, None, "x"]})
+    for dtype in (pl.String, pl.Categorical):
+        out = df.select(
+            pl.col("a").cast(dtype).rolling_mode(window_size=3, min_samples=2),
+            n_unique=pl.col("a").rolling_n_unique(window_size=3, min_samples=2),
+        )
+        assert out.schema == {"a": dtype, "n_unique": pl.UInt32}
+        assert out.to_dict(as_series=False) == {
+            "a": [None, None, "x", "y", "y", None, None],
+            "n_unique": [None, None, 2, 1, 1, None, None],
+        }
+
+
+def test_rolling_mode_n_unique_matches_windows() -> None:
+    s = pl.Series("a", [4, 1, 2, 2, 4, 1, 3, 3, 3, 0, 2, 4, 1, 1, 0, 2, 3, 4])
+    values = s.to_list()
+    window_size = 5
+
+    expected_mode = []
+    expected_n_unique = []
+    for i in range(len(values)):
+        window = values[max(0, i - window_size + 1) : i + 1]
+        counts = {v: window.count(v) for v in window}
+        max_count = max(counts.values())
+        expected_mode.append(min(v for v, c in counts.items() if c == max_count))
+        expected_n_unique.append(len(counts))
+
+    assert s.rolling_mode(window_size, min_samples=1).to_list() == expected_mode
+    assert s.rolling_n_unique(window_size, min_samples=1).to_list() == expected_n_unique
@@ -202,3 +202,72 @@ def test_scalar_bitwise_xor() -> None:
         pl.Series("x", [None, 0x80, 0x00, 0x80, 0x00], pl.UInt8),
         check_names=False,
     )
+
+
+@pytest.mark.parametrize("dtype", INTEGER_DTYPES)
+def test_bit_group_by_over(dtype: pl.DataType) -> None:
+    df = pl.DataFrame(
+        [
+            pl.Series("g", [1, 1, 2, 2], pl.Int8),
+            pl.Series("a", [0x74, 0x1C, 0x05, None], dtype),
+        ]
+    )
+
+    out = df.select(pl.col.a.bitwise_or().over("g"))
+    expected = pl.Series("a", [0x7C, 0x7C, 0x05, 0x05], dtype)
+    assert_series_equal(out.to_series(), expected)
+
+
+def test_bit_aggregations_invalid_dtype() -> None:
+    lf = pl.LazyFrame({"a": ["x", "y"]})
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="not supported"):
+        lf.select(pl.col.a.bitwise_and()).collect()
+
+
+@pytest.mark.parametrize("dtype", INTEGER_DTYPES)
+def test_bitwise_horizontal(dtype: pl.DataType) -> None:
+    df = pl.DataFrame(
+        [
+            pl.Series("a", [0x0C, 0x0A, None, None], dtype),
+            pl.Series("b", [0x0A, None, None, 0x03], dtype),
+            pl.Series("c", [0x06, 0x03, 0x01, None], dtype),
+        ]
+    )
+
+    out = df.select(
+        AND=pl.bitwise_and_horizontal(pl.all()),
+        OR=pl.bitwise_or_horizontal(pl.all()),
+        XOR=pl.bitwise_xor_horizontal("a", "b", "c"),
+    )
+
+    assert_frame_equal(
+        out,
+        pl.DataFrame(
+            [
+                pl.Series("AND", [0x00, 0x02, 0x01, 0x03], dtype),
+                pl.Series("OR", [0x0E, 0x0B, 0x01, 0x03], dtype),
+                pl.Series("XOR", [0x00, 0x09, 0x01, 0x03], dtype),
+            ]
+        ),
+    )
+
+
+def test_bitwise_horizontal_broadcast_and_nulls() -> None:
+    df = pl.DataFrame({"a": [0b0110, 0b1111, None], "b": [None, None, None]})
+
+    out = df.select(pl.bitwise_and_horizontal("a", "b", pl.lit(0b0011)))
+    assert_series_equal(out.to_series(), pl.Series("a", [0b0010, 0b0011, 0b0011]))
+
+    out = df.select(pl.bitwise_or_horizontal("b", pl.col("b").alias("c")))
+    assert out.to_series().to_list() == [None, None, None]
+
+    out = pl.DataFrame({"a": [True, False, None], "b": [True, True, None]}).select(
+        pl.bitwise_xor_horizontal("a", "b")
+    )
+    assert_series_equal(out.to_series(), pl.Series("a", [False, True, None]))
+
+
+def test_bitwise_horizontal_invalid_dtype() -> None:
+    lf = pl.LazyFrame({"a": [1.0], "b": [2.0]})
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="not supported"):
+        lf.select(pl.bitwise_and_horizontal("a", "b")).collect()
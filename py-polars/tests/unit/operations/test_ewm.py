@@ -9,7 +9,7 @@ from hypothesis import given
 
 import polars as pl
 from polars.expr.expr import _prepare_alpha
-from polars.testing import assert_series_equal
+from polars.testing import assert_frame_equal, assert_series_equal
 from polars.testing.parametric import series
 
 
@@ -174,6 +174,79 @@ def test_ewm_std_var_with_nulls() -> None:
     assert_series_equal(std**2, expected)
 
 
+def test_ewm_cov_corr() -> None:
+    df = pl.DataFrame(
+        {
+            "x": [1.0, 3.0, None, 2.0, 5.0, 4.0],
+            "y": [2.0, 1.0, 4.0, None, 6.0, 3.0],
+        }
+    )
+    out = df.select(
+        cov=pl.col("x").ewm_cov("y", alpha=0.5),
+        cov_biased=pl.col("x").ewm_cov("y", alpha=0.5, bias=True),
+        cov_ignore_nulls=pl.col("x").ewm_cov("y", alpha=0.5, ignore_nulls=True),
+        corr=pl.col("x").ewm_corr("y", alpha=0.5),
+        corr_ignore_nulls=pl.col("x").ewm_corr("y", alpha=0.5, ignore_nulls=True),
+    )
+    expected = pl.DataFrame(
+        {
+            "cov": [0.0, -1.0, None, None, 5.72, 1.7720364741641332],
+            "cov_biased": [
+                0.0,
+                -0.4444444444444444,
+                None,
+                None,
+                1.5844875346260388,
+                0.8965782391387925,
+            ],
+            "cov_ignore_nulls": [0.0, -1.0, None, None, 5.0, 2.085714285714286],
+            "corr": [
+                float("nan"),
+                -1.0,
+                None,
+                None,
+                0.8881987577639753,
+                0.8527648898713943,
+            ],
+            "corr_ignore_nulls": [
+                float("nan"),
+                -1.0,
+                None,
+                None,
+                0.8417256229267932,
+                0.7797792523123661,
+            ],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_ewm_cov_consistent_with_var() -> None:
+    s = pl.Series("a", [2, 5, None, 3, 8, 1])
+    df = s.to_frame()
+
+    for ignore_nulls in (False, True):
+        var = s.ewm_var(alpha=0.3, ignore_nulls=ignore_nulls)
+        cov = df.select(pl.col("a").ewm_cov("a", alpha=0.3, ignore_nulls=ignore_nulls))
+        assert_series_equal(cov.to_series(), var)
+
+    corr = df.select(pl.col("a").ewm_corr(pl.col("a") * 2 + 1, alpha=0.3)).to_series()
+    assert_series_equal(corr, pl.Series("a", [float("nan"), 1.0, None, 1.0, 1.0, 1.0]))
+
+
+def test_ewm_cov_float32() -> None:
+    df = pl.DataFrame(
+        {"a": [1.0, 2.0, 3.0], "b": [2.0, 1.0, 4.0], "c": [1, 2, 3]},
+        schema_overrides={"a": pl.Float32, "b": pl.Float32},
+    )
+    lf = df.lazy().select(
+        ab=pl.col("a").ewm_cov("b", alpha=0.5),
+        ac=pl.col("a").ewm_corr("c", alpha=0.5),
+    )
+    assert lf.collect_schema() == {"ab": pl.Float32, "ac": pl.Float64}
+    assert lf.collect().schema == lf.collect_schema()
+
+
 def test_ewm_param_validation() -> None:
     s = pl.Series("values", range(10))
 
@@ -239,3 +239,40 @@ def test_ewma_by_multiple_chunks() -> None:
     values = pl.Series([1, 2]).append(pl.Series([None], dtype=pl.Int64))
     result = values.ewm_mean_by(times, half_life="2i")
     assert_series_equal(result, expected)
+
+
+@pytest.mark.parametrize("sort", [True, False])
+def test_ewm_std_var_by(sort: bool) -> None:
+    df = pl.DataFrame(
+        {
+            "values": [0.0, 1.0, 2.0, None, 4.0],
+            "times": [
+                date(2020, 1, 1),
+                date(2020, 1, 3),
+                date(2020, 1, 10),
+                date(2020, 1, 15),
+                date(2020, 1, 17),
+            ],
+        }
+    )
+    if not sort:
+        df = df.reverse()
+    result = df.select(
+        var=pl.col("values").ewm_var_by("times", half_life="4d"),
+        std=pl.col("values").ewm_std_by("times", half_life="4d"),
+    )
+    var = [0.0, 0.20710678, 0.67039157, None, 1.51289067]
+    expected = pl.DataFrame(
+        {"var": var, "std": [None if v is None else v**0.5 for v in var]}
+    )
+    if not sort:
+        expected = expected.reverse()
+    assert_frame_equal(result, expected)
+
+
+def test_ewm_std_by_int_times_f32() -> None:
+    s = pl.Series("values", [1.0, 3.0, 3.0], dtype=pl.Float32)
+    result = s.ewm_std_by(pl.Series([0, 1, 1]), half_life="1i")
+    # The third observation has no elapsed time, so it carries no weight.
+    expected = pl.Series("values", [0.0, 1.0, 1.0], dtype=pl.Float32)
+    assert_series_equal(result, expected)
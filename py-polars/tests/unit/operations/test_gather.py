@@ -2,7 +2,7 @@ import numpy as np
 import pytest
 
 import polars as pl
-from polars.exceptions import ComputeError
+from polars.exceptions import ComputeError, OutOfBoundsError, ShapeError
 from polars.testing import assert_frame_equal, assert_series_equal
 
 
@@ -282,3 +282,30 @@ def test_gather_agg_group_update_multiple() -> None:
     )
     expected = pl.DataFrame({"gid": [0, 1], "x_at_0": ["0:0", "1:0"]})
     assert_frame_equal(df, expected)
+
+
+def test_gather_by_ranges() -> None:
+    s = pl.Series("a", ["a", "b", None, "d", "e", "f"])
+    assert_series_equal(
+        s.gather_by_ranges([0, 3, 5], [2, 3, 6]),
+        pl.Series("a", ["a", "b", "f"]),
+    )
+    assert_series_equal(
+        s.gather_by_ranges([1, 2], 4),
+        pl.Series("a", ["b", None, "d", None, "d"]),
+    )
+    assert s.gather_by_ranges([0, 1, 2], [2, 3, 4]).n_chunks() == 1
+
+    df = pl.DataFrame({"x": [0, 1, 2, 3, 4], "start": [3, 0, 0, 0, 0]})
+    out = df.select(pl.col("x").gather_by_ranges(pl.col("start").first(), 5))
+    assert_frame_equal(out, pl.DataFrame({"x": [3, 4]}))
+
+
+def test_gather_by_ranges_errors() -> None:
+    s = pl.Series([1, 2, 3])
+    with pytest.raises(OutOfBoundsError):
+        s.gather_by_ranges([0], [4])
+    with pytest.raises(OutOfBoundsError):
+        s.gather_by_ranges([2], [1])
+    with pytest.raises(ShapeError):
+        s.gather_by_ranges([0, 1], [1, 2, 3])
@@ -1,4 +1,17 @@
+from __future__ import annotations
+
+import hashlib
+import struct
+from typing import TYPE_CHECKING
+
+import pytest
+
 import polars as pl
+from polars.exceptions import InvalidOperationError
+from polars.testing import assert_series_equal
+
+if TYPE_CHECKING:
+    from polars._typing import HashAlgorithm
 
 
 def test_hash_struct() -> None:
@@ -9,3 +22,67 @@ def test_hash_struct() -> None:
         6116564025432436932,
         49592145888590321,
     ]
+
+
+def test_hash_sha256() -> None:
+    df = pl.DataFrame(
+        {
+            "str": ["a", "", None],
+            "bin": [b"a", b"", None],
+            "int": [1, -1, None],
+            "bool": [True, False, None],
+        },
+        schema_overrides={"int": pl.Int32},
+    )
+    out = df.select(pl.all().hash(algorithm="sha256"))
+    assert out.schema == dict.fromkeys(df.columns, pl.Binary)
+
+    def sha256(v: bytes | None) -> bytes | None:
+        return None if v is None else hashlib.sha256(v).digest()
+
+    assert out["str"].to_list() == [sha256(b"a"), sha256(b""), None]
+    assert out["bin"].to_list() == out["str"].to_list()
+    assert out["int"].to_list() == [
+        sha256(struct.pack("<i", 1)),
+        sha256(struct.pack("<i", -1)),
+        None,
+    ]
+    assert out["bool"].to_list() == [sha256(b"\x01"), sha256(b"\x00"), None]
+
+
+def test_hash_known_values() -> None:
+    s = pl.Series(["", "a"])
+    assert s.hash(algorithm="xxhash64").to_list() == [
+        0xEF46DB3751D8E999,
+        0xD24EC4F1A98C6E5B,
+    ]
+    out = s.hash(0x0706050403020100, 0x0F0E0D0C0B0A0908, algorithm="siphash")
+    assert out[0] == 0x726FDB47DD0E0E31
+
+
+@pytest.mark.parametrize("algorithm", ["xxhash64", "wyhash", "siphash"])
+def test_hash_stable_canonical(algorithm: HashAlgorithm) -> None:
+    s = pl.Series([0.0, -0.0, float("nan"), -float("nan"), None])
+    out = s.hash(7, algorithm=algorithm)
+    assert out.dtype == pl.UInt64
+    assert out[0] == out[1]
+    assert out[2] == out[3]
+    assert out[4] is None
+
+    strings = pl.Series(["x", "y", "x"])
+    expected = strings.hash(7, algorithm=algorithm)
+    for s in [strings.cast(pl.Categorical), strings.cast(pl.Binary)]:
+        out = s.hash(7, algorithm=algorithm)
+        assert_series_equal(out, expected)
+
+    assert strings.hash(8, algorithm=algorithm)[0] != expected[0]
+
+
+def test_hash_stable_invalid() -> None:
+    s = pl.Series([[1, 2], [3]])
+    assert s.hash().dtype == pl.UInt64
+
+    with pytest.raises(InvalidOperationError, match="not supported for dtype"):
+        s.hash(algorithm="xxhash64")
+    with pytest.raises(ValueError, match="`algorithm` must be one of"):
+        s.hash(algorithm="md5")  # type: ignore[arg-type]
@@ -15,6 +15,8 @@ from polars.testing.parametric.strategies import series
 if TYPE_CHECKING:
     from hypothesis.strategies import DrawFn, SearchStrategy
 
+    from polars._typing import ClosedInterval
+
 
 @pytest.mark.parametrize(
     ("pred_1", "pred_2"),
@@ -682,3 +684,56 @@ def test_boolean_predicate_join_where() -> None:
             categories, pl.col("url").str.starts_with(pl.col("base_url"))
         ).explain()
     )
+
+
+@pytest.mark.parametrize("closed", ["both", "left", "right", "none"])
+def test_join_between(closed: ClosedInterval) -> None:
+    events = pl.DataFrame({"event": [1, 2, 3, 4, 5], "ts": [0, 4, 5, 8, None]})
+    windows = pl.DataFrame(
+        {"window": ["a", "b", "c"], "lo": [0, 4, 6], "hi": [5, 8, None]}
+    )
+
+    actual = events.join_between(windows, "ts", "lo", "hi", closed=closed)
+    expected = events.join(windows, how="cross").filter(
+        pl.col("ts").is_between(pl.col("lo"), pl.col("hi"), closed=closed)
+    )
+    assert_frame_equal(actual, expected, check_row_order=False)
+
+
+def test_join_between_duplicate_names() -> None:
+    left = pl.LazyFrame({"start": [1, 10], "end": [2, 20], "x": [3, 15]})
+    right = pl.LazyFrame({"start": [0, 12], "end": [5, 18]})
+
+    q = left.join_between(right, pl.col("x"), "start", "end")
+    assert "IEJOIN" in q.explain()
+    assert q.collect_schema().names() == [
+        "start",
+        "end",
+        "x",
+        "start_right",
+        "end_right",
+    ]
+    assert q.collect().sort("x").to_dict(as_series=False) == {
+        "start": [1, 10],
+        "end": [2, 20],
+        "x": [3, 15],
+        "start_right": [0, 12],
+        "end_right": [5, 18],
+    }
+
+
+def test_join_between_expressions() -> None:
+    left = pl.DataFrame({"x": [1.5, 2.5, 3.5]})
+    right = pl.DataFrame({"mid": [2.0], "width": [0.5]})
+
+    out = left.join_between(
+        right,
+        pl.col("x"),
+        pl.col("mid") - pl.col("width"),
+        pl.col("mid") + pl.col("width"),
+    )
+    assert out.sort("x").to_dict(as_series=False) == {
+        "x": [1.5, 2.5],
+        "mid": [2.0, 2.0],
+        "width": [0.5, 0.5],
+    }
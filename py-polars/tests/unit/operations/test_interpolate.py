@@ -6,11 +6,15 @@ from typing import TYPE_CHECKING, Any
 import pytest
 
 import polars as pl
-from polars.testing import assert_frame_equal
+from polars.testing import assert_frame_equal, assert_series_equal
 from tests.unit.conftest import NUMERIC_DTYPES
 
 if TYPE_CHECKING:
-    from polars._typing import PolarsDataType, PolarsTemporalType
+    from polars._typing import (
+        InterpolationMethod,
+        PolarsDataType,
+        PolarsTemporalType,
+    )
 
 from zoneinfo import ZoneInfo
 
@@ -138,3 +142,71 @@ def test_interpolate_temporal_nearest(
     assert result.collect_schema()["a"] == input_dtype
     expected = pl.DataFrame({"a": output}, schema={"a": input_dtype})
     assert_frame_equal(result.collect(), expected)
+
+
+@pytest.mark.parametrize(
+    ("input_dtype", "output_dtype"),
+    [
+        (pl.Int32, pl.Float64),
+        (pl.UInt8, pl.Float64),
+        (pl.Float32, pl.Float32),
+        (pl.Float64, pl.Float64),
+    ],
+)
+@pytest.mark.parametrize("method", ["spline", "pchip"])
+def test_interpolate_cubic_dtypes(
+    method: InterpolationMethod,
+    input_dtype: PolarsDataType,
+    output_dtype: PolarsDataType,
+) -> None:
+    df = pl.LazyFrame({"a": [None, 1, None, 3, None]}, schema={"a": input_dtype})
+    result = df.with_columns(pl.all().interpolate(method=method))
+    assert result.collect_schema()["a"] == output_dtype
+    # Two knots reduce to linear interpolation; boundary nulls are kept.
+    expected = pl.DataFrame(
+        {"a": [None, 1.0, 2.0, 3.0, None]}, schema={"a": output_dtype}
+    )
+    assert_frame_equal(result.collect(), expected)
+
+
+def test_interpolate_spline() -> None:
+    s = pl.Series("a", [0.0, None, 4.0, None, 0.0])
+    expected = pl.Series("a", [0.0, 2.75, 4.0, 2.75, 0.0])
+    assert_series_equal(s.interpolate("spline"), expected)
+
+
+def test_interpolate_pchip_monotonic() -> None:
+    s = pl.Series("a", [0.0, None, 1.0, None, 1.0, None, 5.0])
+    expected = pl.Series("a", [0.0, 0.6875, 1.0, 1.0, 1.0, 2.25, 5.0])
+    assert_series_equal(s.interpolate("pchip"), expected)
+
+    # The spline overshoots on the flat section, pchip does not.
+    assert s.interpolate("spline")[3] < 1.0
+
+
+def test_interpolate_cubic_temporal() -> None:
+    s = pl.Series([date(2020, 1, 1), None, date(2020, 1, 5)])
+    assert s.interpolate("pchip").to_list() == [
+        date(2020, 1, 1),
+        date(2020, 1, 3),
+        date(2020, 1, 5),
+    ]
+
+
+def test_interpolate_time() -> None:
+    df = pl.DataFrame(
+        {
+            "ts": [datetime(2020, 1, 1), datetime(2020, 1, 2), datetime(2020, 1, 5)],
+            "value": [1.0, None, 5.0],
+        }
+    )
+    result = df.select(pl.col("value").interpolate("time", by="ts"))
+    assert result["value"].to_list() == [1.0, 2.0, 5.0]
+
+    s = pl.Series("value", [1.0, None, 5.0])
+    assert s.interpolate("time", by=df["ts"]).to_list() == [1.0, 2.0, 5.0]
+
+    with pytest.raises(ValueError, match="`by` must be given"):
+        pl.col("value").interpolate("time")
+    with pytest.raises(ValueError, match="only supported for `method='time'`"):
+        pl.col("value").interpolate("linear", by="ts")
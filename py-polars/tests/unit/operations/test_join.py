@@ -831,6 +831,85 @@ def test_join_validation_many_keys() -> None:
                 df1.join(df2, on=["val1", "val2"], how=join_type, validate=val)
 
 
+def test_join_validation_error_lists_duplicate_keys() -> None:
+    df1 = pl.DataFrame({"id": [1, 1, 2, 3, 3, 3], "x": range(6)})
+    df2 = pl.DataFrame({"id": [1, 2, 3], "y": range(3)})
+
+    for how in ["inner", "left", "full"]:
+        with pytest.raises(ComputeError) as exc:
+            df1.join(df2, on="id", how=how, validate="1:m")  # type: ignore[arg-type]
+        msg = str(exc.value)
+        assert "join keys did not fulfill 1:m validation" in msg
+        assert "2 keys occur more than once in the left frame" in msg
+        assert "1 (2 rows)" in msg
+        assert "3 (3 rows)" in msg
+        assert "right frame" not in msg
+
+    df1 = pl.DataFrame({"a": [1, 1, 2], "b": ["x", "x", "y"]})
+    with pytest.raises(ComputeError, match=r'\(1, "x"\) \(2 rows\)'):
+        df1.join(df1.unique(), on=["a", "b"], validate="1:1")
+
+
+def test_join_validation_error_samples_keys() -> None:
+    df1 = pl.DataFrame({"id": list(range(10)) * 2})
+    df2 = pl.DataFrame({"id": list(range(10))})
+
+    with pytest.raises(ComputeError) as exc:
+        df2.join(df1, on="id", validate="m:1")
+    msg = str(exc.value)
+    assert "10 keys occur more than once in the right frame" in msg
+    assert "4 (2 rows)" in msg
+    assert "5 (2 rows)" not in msg
+    assert "..." in msg
+
+
+def test_join_violations() -> None:
+    df1 = pl.DataFrame({"id": [1, 2, 2, 3, None, None]})
+    df2 = pl.DataFrame({"key": [3, 1, 3, 3, 2]})
+
+    expected = pl.DataFrame(
+        {"side": ["left", "right"], "id": [2, 3], "count": [2, 3]},
+        schema_overrides={"count": pl.get_index_type()},
+    )
+    out = df1.join_violations(df2, left_on="id", right_on="key")
+    assert_frame_equal(out, expected)
+
+    assert_frame_equal(
+        df1.join_violations(df2, left_on="id", right_on="key", validate="m:1"),
+        expected.slice(1),
+    )
+    assert_frame_equal(
+        df1.join_violations(df2, left_on="id", right_on="key", validate="m:m"),
+        expected.clear(),
+    )
+    assert_frame_equal(
+        df1.join_violations(
+            df2, left_on="id", right_on="key", validate="1:m", nulls_equal=True
+        ),
+        pl.DataFrame(
+            {"side": ["left", "left"], "id": [2, None], "count": [2, 2]},
+            schema_overrides={"count": pl.get_index_type()},
+        ),
+    )
+
+
+def test_join_violations_many_keys() -> None:
+    df1 = pl.DataFrame({"a": [1, 1, 1], "b": ["x", "y", "x"], "c": [0, 0, 0]})
+    df2 = pl.DataFrame({"a": [1, 1], "b": ["x", "y"]})
+
+    assert_frame_equal(
+        df1.join_violations(df2, on=["a", "b"]),
+        pl.DataFrame(
+            {"side": ["left"], "a": [1], "b": ["x"], "count": [2]},
+            schema_overrides={"count": pl.get_index_type()},
+        ),
+    )
+    assert df2.join_violations(df1, on=["a", "b"], validate="1:m").is_empty()
+
+    with pytest.raises(ValueError, match="`on`"):
+        df1.join_violations(df2, left_on="a")
+
+
 def test_full_outer_join_bool() -> None:
     df1 = pl.DataFrame({"id": [True, False], "val": [1, 2]})
     df2 = pl.DataFrame({"id": [True, False], "val": [0, -1]})
@@ -1990,3 +2069,77 @@ def test_join_categorical_21815() -> None:
         assert_frame_equal(
             cat_payload, expected, check_row_order=False, check_column_order=False
         )
+
+
+@pytest.mark.parametrize("how", ["inner", "left", "full", "semi", "anti"])
+def test_join_int128_keys_beyond_64_bits(how: JoinStrategy) -> None:
+    # Keys that are equal in their lower bits must not match.
+    left = pl.DataFrame(
+        {"a": [1, 2**64 + 1, 2**100], "x": [1, 2, 3]},
+        schema_overrides={"a": pl.Int128},
+    )
+    right = pl.DataFrame(
+        {"a": [2**64 + 1, 2**100, 2**32], "y": [4, 5, 6]},
+        schema_overrides={"a": pl.Int128},
+    )
+    result = left.join(right, on="a", how=how, coalesce=True).sort("a")
+    if how == "semi":
+        assert result["a"].to_list() == [2**64 + 1, 2**100]
+    elif how == "anti":
+        assert result["a"].to_list() == [1]
+    elif how == "inner":
+        assert result.rows() == [(2**64 + 1, 2, 4), (2**100, 3, 5)]
+    elif how == "left":
+        assert result.rows() == [(1, 1, None), (2**64 + 1, 2, 4), (2**100, 3, 5)]
+    else:
+        assert result.rows() == [
+            (1, 1, None),
+            (2**32, None, 6),
+            (2**64 + 1, 2, 4),
+            (2**100, 3, 5),
+        ]
+
+
+def test_join_asof_by_int128() -> None:
+    left = pl.DataFrame(
+        {"by": [1, 2**64 + 1, 2**100], "t": [1, 2, 3]},
+        schema_overrides={"by": pl.Int128},
+    )
+    right = pl.DataFrame(
+        {"by": [2**64 + 1, 2**100, 1], "t": [0, 0, 0], "y": [4, 5, 6]},
+        schema_overrides={"by": pl.Int128},
+    )
+    result = left.join_asof(right, on="t", by="by")
+    assert result.rows() == [(1, 1, 6), (2**64 + 1, 2, 4), (2**100, 3, 5)]
+
+
+def test_list_hash_int128() -> None:
+    s = pl.Series(
+        [[1, 2**64 + 1], [1, 2**64 + 1], [1, 1], [2**100]], dtype=pl.List(pl.Int128)
+    )
+    h = s.hash()
+    assert h[0] == h[1]
+    assert h[0] != h[2]
+    assert s.unique().len() == 3
+
+
+def test_join_keys_known_sorted_use_merge_join(
+    monkeypatch: pytest.MonkeyPatch, capfd: pytest.CaptureFixture[str]
+) -> None:
+    left = pl.LazyFrame({"k": [1, 2, 3, 4], "x": [1, 2, 3, 4]}).set_sorted("k")
+    right = pl.LazyFrame({"k": [1, 2, 3, 4], "y": [5, 6, 7, 8]}).set_sorted("k")
+    other = pl.LazyFrame({"k": [2, 3, 5], "z": [1, 2, 3]}).set_sorted("k")
+
+    # The key of the output of the left join is only known to be sorted by the plan.
+    q = left.join(right, on="k", how="left", maintain_order="left").join(other, on="k")
+
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+    out = q.collect()
+    captured = capfd.readouterr().err
+    assert "inner join: keys are sorted: use sorted merge join" in captured
+    assert out.to_dict(as_series=False) == {
+        "k": [2, 3],
+        "x": [2, 3],
+        "y": [6, 7],
+        "z": [1, 2],
+    }
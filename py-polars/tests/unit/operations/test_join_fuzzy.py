@@ -0,0 +1,104 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.exceptions import DuplicateError, InvalidOperationError
+from polars.testing import assert_frame_equal
+
+
+def test_join_fuzzy_levenshtein() -> None:
+    left = pl.DataFrame(
+        {"name": ["kitten", "apple", None, "banana"], "x": [1, 2, 3, 4]}
+    )
+    right = pl.DataFrame(
+        {"name": ["sitting", "kitten", "appel", None, "bananas"], "y": [1, 2, 3, 4, 5]}
+    )
+
+    out = left.join_fuzzy(
+        right,
+        on="name",
+        threshold=0.6,
+        metric="levenshtein",
+        similarity_column="similarity",
+    )
+    expected = pl.DataFrame(
+        {
+            "name": ["kitten", "apple", "banana"],
+            "x": [1, 2, 4],
+            "name_right": ["kitten", "appel", "bananas"],
+            "y": [2, 3, 5],
+            "similarity": [1.0, 0.6, 6 / 7],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_join_fuzzy_jaro_winkler_multiple_matches() -> None:
+    left = pl.DataFrame({"company": ["Acme Corp", "Globex"]})
+    right = pl.DataFrame({"name": ["ACME Corp", "Acme Corp.", "Acme Co", "Initech"]})
+
+    # The similarity is case-sensitive, so "ACME Corp" scores only 0.8.
+    out = left.join_fuzzy(right, left_on="company", right_on="name", threshold=0.9)
+    assert out.to_dict(as_series=False) == {
+        "company": ["Acme Corp", "Acme Corp"],
+        "name": ["Acme Corp.", "Acme Co"],
+    }
+
+
+def test_join_fuzzy_matches_cross_join() -> None:
+    left = pl.DataFrame({"a": ["polars", "pandas", "arrow", "duckdb", "spark", ""]})
+    right = pl.DataFrame({"b": ["polar", "panda", "arrows", "duck", "sparks", "x", ""]})
+
+    expected = (
+        left.join(right, how="cross")
+        .with_columns(s=pl.col("a").str.levenshtein_similarity(pl.col("b")))
+        .filter(pl.col("s") >= 0.7)
+    )
+    out = left.join_fuzzy(
+        right,
+        left_on="a",
+        right_on="b",
+        threshold=0.7,
+        metric="levenshtein",
+        ngram_size=2,
+        similarity_column="s",
+    )
+    assert_frame_equal(out, expected)
+
+
+@pytest.mark.parametrize("threshold", [0.0, 0.3])
+def test_join_fuzzy_low_threshold_compares_every_pair(threshold: float) -> None:
+    # "ab" and "ba" share no n-gram, but are similar enough at these thresholds.
+    left = pl.DataFrame({"a": ["ab", "kitten", None]})
+    right = pl.DataFrame({"b": ["ba", "sitting", "", None]})
+
+    expected = (
+        left.join(right, how="cross")
+        .with_columns(s=pl.col("a").str.levenshtein_similarity(pl.col("b")))
+        .filter(pl.col("s") >= threshold)
+    )
+    out = left.join_fuzzy(
+        right,
+        left_on="a",
+        right_on="b",
+        threshold=threshold,
+        metric="levenshtein",
+        similarity_column="s",
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_join_fuzzy_errors() -> None:
+    df = pl.DataFrame({"a": ["x"], "s": [1]})
+
+    with pytest.raises(InvalidOperationError, match="threshold"):
+        df.join_fuzzy(df, on="a", threshold=1.5)
+    with pytest.raises(InvalidOperationError, match="n-gram size"):
+        df.join_fuzzy(df, on="a", ngram_size=0)
+    with pytest.raises(DuplicateError, match="similarity column"):
+        df.join_fuzzy(df, on="a", similarity_column="s")
+    with pytest.raises(ValueError, match="`on`"):
+        df.join_fuzzy(df, left_on="a")
+    with pytest.raises(ValueError, match="metric"):
+        df.join_fuzzy(df, on="a", metric="cosine")  # type: ignore[arg-type]
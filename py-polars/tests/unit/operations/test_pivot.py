@@ -11,7 +11,7 @@ from polars.exceptions import ComputeError, DuplicateError
 from polars.testing import assert_frame_equal
 
 if TYPE_CHECKING:
-    from polars._typing import PivotAgg, PolarsIntegerType
+    from polars._typing import EngineType, PivotAgg, PolarsIntegerType
 
 
 def test_pivot() -> None:
@@ -573,3 +573,103 @@ def test_pivot_empty_index_dtypes(dtype: PolarsIntegerType) -> None:
     result = df.pivot(index="index", on="on", values="values")
     expected = pl.DataFrame({"index": index})
     assert_frame_equal(result, expected)
+
+
+@pytest.mark.parametrize(
+    "agg_fn", ["first", "last", "sum", "min", "max", "mean", "median", "len"]
+)
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_lazy_pivot_matches_eager(agg_fn: PivotAgg, engine: EngineType) -> None:
+    df = pl.DataFrame(
+        {
+            "a": ["x", "y", "x", "z", "y", "x", "z"],
+            "b": ["k", "l", "l", "k", "m", "k", "m"],
+            "c": [1, 2, 3, 4, 5, 6, None],
+            "d": [1.5, 2.5, None, 4.5, 5.5, 6.5, 7.5],
+        }
+    )
+    for values in ["c", ["c", "d"]]:
+        for sort_columns in [False, True]:
+            expected = df.pivot(
+                "b",
+                index="a",
+                values=values,
+                aggregate_function=agg_fn,
+                sort_columns=sort_columns,
+            )
+            for on_columns in [None, df["b"].unique(maintain_order=True)]:
+                result = df.lazy().pivot(
+                    "b",
+                    on_columns,
+                    index="a",
+                    values=values,
+                    aggregate_function=agg_fn,
+                    sort_columns=sort_columns,
+                )
+                assert_frame_equal(result.collect(engine=engine), expected)
+
+
+def test_lazy_pivot_on_columns() -> None:
+    lf = pl.LazyFrame(
+        {
+            "a": [1, 1, 2, 2, 2],
+            "b": [10, 20, 10, 30, 30],
+            "c": [1.0, 2.0, 3.0, 4.0, 5.0],
+        }
+    )
+
+    result = lf.pivot("b", [30, 10, 40], index="a", aggregate_function="sum")
+    expected = pl.DataFrame(
+        {
+            "a": [1, 2],
+            "30": [None, 9.0],
+            "10": [1.0, 3.0],
+            "40": pl.Series([None, None], dtype=pl.Float64),
+        }
+    )
+    assert_frame_equal(result.collect(), expected)
+
+    # The values are cast to the type of the `on` column.
+    result = lf.pivot(
+        "b", pl.Series([10.0]), index="a", aggregate_function=pl.element().max()
+    )
+    assert result.collect_schema().names() == ["a", "10"]
+
+
+def test_lazy_pivot_multiple_on_columns() -> None:
+    lf = pl.LazyFrame(
+        {
+            "a": ["beep", "bop"],
+            "b": ["a", "b"],
+            "c": ["s", "f"],
+            "d": [7, 8],
+            "e": ["x", "y"],
+        }
+    )
+    expected = {"b": ["a", "b"], '{"s","x"}': [1, None], '{"f","y"}': [None, 1]}
+
+    result = lf.pivot(
+        index="b", on=["c", "e"], values=["a"], aggregate_function="len"
+    ).collect()
+    assert result.to_dict(as_series=False) == expected
+
+    on_columns = pl.DataFrame({"c": ["s", "f"], "e": ["x", "y"]})
+    result = lf.pivot(
+        ["c", "e"], on_columns, index="b", values="a", aggregate_function="len"
+    ).collect()
+    assert result.to_dict(as_series=False) == expected
+
+    with pytest.raises(TypeError, match="must be a DataFrame"):
+        lf.pivot(["c", "e"], ["s", "f"], index="b")
+
+
+def test_lazy_pivot_invalid() -> None:
+    lf = pl.LazyFrame({"a": [1, 2], "b": [2, 3], "c": [3, 4]})
+
+    with pytest.raises(
+        pl.exceptions.InvalidOperationError,
+        match="`index` and `values` cannot both be None in `pivot` operation",
+    ):
+        lf.pivot("a")
+    with pytest.raises(ValueError, match="aggregate_function"):
+        lf.pivot("a", index="b", aggregate_function="mode")  # type: ignore[arg-type]
@@ -146,3 +146,80 @@ def test_sample_16232() -> None:
     assert df.select(pl.col("b").list.sample(n=pl.col("a"), seed=0)).to_dict(
         as_series=False
     ) == {"b": [[], [], [1]]}
+
+
+@pytest.fixture
+def strata() -> pl.DataFrame:
+    return pl.DataFrame(
+        {
+            "group": ["a"] * 10 + ["b"] * 4 + ["c"] * 2,
+            "x": range(16),
+            "y": [str(i) for i in range(16)],
+        }
+    )
+
+
+def test_sample_group_by(strata: pl.DataFrame) -> None:
+    out = strata.group_by("group", maintain_order=True).agg(
+        n=pl.col("x").sample(2, seed=0),
+        frac=pl.col("x").sample(fraction=0.5, seed=0),
+        per_group=pl.col("x").sample(pl.len() // 2, seed=0),
+        replace=pl.col("x").sample(5, with_replacement=True, seed=0),
+        y=pl.col("y").sample(2, seed=0),
+    )
+    assert out["n"].list.len().to_list() == [2, 2, 2]
+    assert out["frac"].list.len().to_list() == [5, 2, 1]
+    assert out["per_group"].list.len().to_list() == [5, 2, 1]
+    assert out["replace"].list.len().to_list() == [5, 5, 5]
+
+    # Every group is sampled from its own rows.
+    groups = strata.group_by("group", maintain_order=True).agg("x")["x"].to_list()
+    for col in ["n", "frac", "per_group", "replace"]:
+        for sample, group in zip(out[col].to_list(), groups):
+            assert set(sample) <= set(group)
+        for sample in out.filter(pl.col(col).list.len() > 1)[col].to_list():
+            if col != "replace":
+                assert len(set(sample)) == len(sample)
+
+    # The same seed selects the same rows in every column.
+    assert out["y"].to_list() == [[str(v) for v in s] for s in out["n"].to_list()]
+
+    with pytest.raises(ShapeError):
+        strata.group_by("group").agg(pl.col("x").sample(3))
+
+
+def test_sample_group_by_seed_per_group() -> None:
+    df = pl.DataFrame({"group": [i // 10 for i in range(200)], "x": range(200)})
+    q = (
+        df.lazy()
+        .group_by("group", maintain_order=True)
+        .agg(pl.col("x").sample(3, seed=0))
+    )
+    out = q.collect()
+    assert_frame_equal(out, q.collect())
+
+    # Groups of the same length don't all sample the same positions.
+    positions = {tuple(v % 10 for v in s) for s in out["x"].to_list()}
+    assert len(positions) > 1
+
+    # The result doesn't depend on the order in which the groups are found.
+    unordered = df.group_by("group").agg(pl.col("x").sample(3, seed=0))
+    assert_frame_equal(unordered.sort("group"), out)
+
+
+def test_sample_over(strata: pl.DataFrame) -> None:
+    out = strata.select(
+        pl.all().sample(fraction=0.5, seed=1).over("group", mapping_strategy="explode")
+    )
+    assert out["group"].to_list() == ["a"] * 5 + ["b"] * 2 + ["c"]
+    assert out.join(strata, on=["group", "x", "y"], how="anti").is_empty()
+    assert out["x"].is_unique().all()
+
+    # Sampling all rows keeps the length of every group.
+    out = strata.with_columns(
+        pl.col("x").sample(fraction=1.0, shuffle=True, seed=1).over("group")
+    )
+    assert_frame_equal(
+        out.group_by("group", maintain_order=True).agg(pl.col("x").sort()),
+        strata.group_by("group", maintain_order=True).agg("x"),
+    )
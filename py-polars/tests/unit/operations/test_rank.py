@@ -1,3 +1,5 @@
+import pytest
+
 import polars as pl
 from polars.testing import assert_frame_equal, assert_series_equal
 
@@ -95,3 +97,36 @@ def test_rank_series() -> None:
 
     assert s.rank(method="average").dtype == pl.Float64
     assert s.rank(method="max").dtype == pl.get_index_type()
+
+
+def test_percent_rank_cume_dist_over() -> None:
+    df = pl.DataFrame(
+        {"g": ["a", "a", "a", "b", "b", "b"], "x": [3, None, 1, 2, 2, 5]}
+    )
+    out = df.select(
+        pl.col("x").percent_rank().over("g").alias("percent_rank"),
+        pl.col("x").cume_dist().over("g").alias("cume_dist"),
+        pl.col("x").cume_dist(descending=True).over("g").alias("cume_dist_desc"),
+    )
+    expected = pl.DataFrame(
+        {
+            "percent_rank": [1.0, None, 0.0, 0.0, 0.0, 1.0],
+            "cume_dist": [1.0, None, 0.5, 2 / 3, 2 / 3, 1.0],
+            "cume_dist_desc": [0.5, None, 1.0, 1.0, 1.0, 1 / 3],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_ntile() -> None:
+    s = pl.Series("a", [5, 1, None, 4, 2, 3, 6])
+    assert s.ntile(3).to_list() == [3, 1, None, 2, 1, 2, 3]
+    assert s.ntile(3, descending=True).to_list() == [1, 3, None, 2, 3, 2, 1]
+    assert s.ntile(10).to_list() == [5, 1, None, 4, 2, 3, 6]
+
+    df = pl.DataFrame({"g": [1, 1, 1, 2, 2], "x": [1, 2, 3, 4, 5]})
+    out = df.select(pl.col("x").ntile(2).over("g"))
+    assert out["x"].to_list() == [1, 1, 2, 1, 2]
+
+    with pytest.raises(pl.exceptions.InvalidOperationError):
+        s.ntile(0)
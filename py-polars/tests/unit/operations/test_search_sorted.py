@@ -76,3 +76,57 @@ def test_raise_literal_numeric_search_sorted_18096() -> None:
 
     with pytest.raises(pl.exceptions.InvalidOperationError):
         df.with_columns(idx=pl.col("foo").search_sorted("bar"))
+
+
+def test_search_sorted_by() -> None:
+    df = pl.DataFrame({"a": [1, 1, 2, 2, 3], "b": ["x", "z", "x", "y", "x"]})
+    values = [pl.Series([0, 1, 2, 3]), pl.Series(["a", "y", "x", "z"])]
+
+    out = df.select(
+        left=pl.search_sorted_by(["a", "b"], values, side="left"),
+        right=pl.search_sorted_by(["a", "b"], values, side="right"),
+    )
+    assert out["left"].to_list() == [0, 1, 2, 5]
+    assert out["right"].to_list() == [0, 1, 3, 5]
+
+    # Scalars are broadcast against the other search values.
+    out = df.select(pl.search_sorted_by(["a", "b"], [2, pl.Series(["a", "x", "yy"])]))
+    assert out["a"].to_list() == [2, 2, 4]
+
+    # Searching in a struct column gives the same result.
+    s = df.select(pl.struct("a", "b")).to_series()
+    elements = pl.DataFrame({"a": values[0], "b": values[1]}).to_struct()
+    assert s.search_sorted(elements, side="left").to_list() == [0, 1, 2, 5]
+
+
+def test_search_sorted_by_descending_nulls_last() -> None:
+    df = pl.DataFrame({"a": [1, 1, 2, 2], "b": ["z", "x", "y", "x"]})
+    out = df.select(
+        pl.search_sorted_by(
+            [pl.col("a"), pl.col("b")],
+            [pl.Series([1, 2, 2]), pl.Series(["y", "z", "a"])],
+            side="left",
+            descending=[False, True],
+        )
+    )
+    assert out["a"].to_list() == [1, 2, 4]
+
+    df = pl.DataFrame({"a": [1, 1, None], "b": [2, None, 1]})
+    out = df.select(
+        pl.search_sorted_by(
+            ["a", "b"],
+            [pl.Series([1, None, 5]), pl.Series([None, 0, 0])],
+            side="left",
+            nulls_last=True,
+        )
+    )
+    assert out["a"].to_list() == [1, 2, 2]
+
+
+def test_search_sorted_by_invalid() -> None:
+    df = pl.DataFrame({"a": [1, 2], "b": [3, 4]})
+
+    with pytest.raises(pl.exceptions.ComputeError, match="expected 2 search values"):
+        df.select(pl.search_sorted_by(["a", "b"], [1]))
+    with pytest.raises(pl.exceptions.InvalidOperationError):
+        df.select(pl.search_sorted_by(["a", "b"], [1, "x"]))
@@ -1,3 +1,5 @@
+from pathlib import Path
+
 import pytest
 
 import polars as pl
@@ -119,3 +121,58 @@ def test_unpivot_categorical_global() -> None:
 def test_unpivot_categorical_raise_19770() -> None:
     with pytest.raises(pl.exceptions.ComputeError):
         (pl.DataFrame({"x": ["foo"]}).cast(pl.Categorical).unpivot())
+
+
+@pytest.mark.write_disk
+def test_unpivot_streaming_scan_sink(tmp_path: Path) -> None:
+    n = 100_000
+    df = pl.DataFrame(
+        {
+            "id": range(n),
+            "a": pl.int_range(n, eager=True, dtype=pl.Int32),
+            "b": pl.int_range(n, eager=True).cast(pl.Float64) / 2,
+        }
+    )
+    df.write_parquet(tmp_path / "in.parquet")
+
+    (
+        pl.scan_parquet(tmp_path / "in.parquet")
+        .with_row_index("row")
+        .unpivot(index=["row", "id"])
+        .with_row_index("unpivoted_row")
+        .sink_parquet(tmp_path / "out.parquet")
+    )
+    out = pl.read_parquet(tmp_path / "out.parquet")
+
+    assert out.schema == pl.Schema(
+        {
+            "unpivoted_row": pl.get_index_type(),
+            "row": pl.get_index_type(),
+            "id": pl.Int64,
+            "variable": pl.String,
+            "value": pl.Float64,
+        }
+    )
+    assert out["unpivoted_row"].to_list() == list(range(2 * n))
+    # The streaming engine unpivots batch by batch, so only the set of rows matches.
+    expected = df.with_row_index("row").unpivot(index=["row", "id"])
+    assert_frame_equal(
+        out.drop("unpivoted_row").sort("row", "variable"),
+        expected.sort("row", "variable"),
+    )
+
+
+@pytest.mark.parametrize("on", [["a"], ["a", "b"], None])
+def test_unpivot_engine_parity(on: list[str] | None) -> None:
+    lf = pl.LazyFrame(
+        {
+            "id": [1, 2, 3],
+            "a": [1, None, 3],
+            "b": [1.5, 2.5, None],
+        }
+    ).unpivot(on=on, index="id")
+    assert_frame_equal(
+        lf.collect(engine="streaming"),
+        lf.collect(engine="in-memory"),
+        check_row_order=False,
+    )
@@ -5,8 +5,13 @@ import numpy as np
 import pytest
 
 import polars as pl
-from polars.exceptions import ComputeError, InvalidOperationError, OutOfBoundsError
-from polars.testing import assert_series_equal
+from polars.exceptions import (
+    ComputeError,
+    InvalidOperationError,
+    OutOfBoundsError,
+    ShapeError,
+)
+from polars.testing import assert_frame_equal, assert_series_equal
 
 
 @pytest.mark.parametrize(
@@ -94,3 +99,46 @@ def test_scatter_logical_all_null() -> None:
     result = s.scatter(0, date(2022, 2, 2))
     expected = pl.Series("dt", [date(2022, 2, 2), None])
     assert_series_equal(result, expected)
+
+
+@pytest.mark.parametrize(
+    ("s", "values", "expected"),
+    [
+        (pl.Series([1, 2, 3, 4]), [20, 40], pl.Series([1, 20, 3, 40])),
+        (
+            pl.Series([1.0, None, 3.0, 4.0]),
+            [None, 0.5],
+            pl.Series([1.0, None, 3.0, 0.5]),
+        ),
+        (pl.Series(["a", "b", "c", "d"]), ["x", "y"], pl.Series(["a", "x", "c", "y"])),
+        (
+            pl.Series([date(2020, 1, 1)] * 4),
+            [date(2021, 1, 1), None],
+            pl.Series([date(2020, 1, 1), date(2021, 1, 1), date(2020, 1, 1), None]),
+        ),
+        (pl.Series([True, False, True, False]), True, pl.Series([True] * 4)),
+    ],
+)
+def test_scatter_by_mask(s: pl.Series, values: Any, expected: pl.Series) -> None:
+    mask = pl.Series([False, True, None, True])
+    assert_series_equal(s.scatter_by_mask(mask, values), expected)
+
+
+def test_scatter_by_mask_expr() -> None:
+    df = pl.DataFrame({"a": [1, 2, 3, 4], "b": [10, 20, 30, 40]})
+    mask = pl.col("b") > 15
+    out = df.select(pl.col("a").scatter_by_mask(mask, pl.col("b").filter(mask)))
+    assert_frame_equal(out, pl.DataFrame({"a": [1, 20, 30, 40]}))
+
+    out = df.select(pl.col("a").scatter_by_mask(pl.lit(False), 0))
+    assert_frame_equal(out, df.select("a"))
+
+
+def test_scatter_by_mask_errors() -> None:
+    s = pl.Series([1, 2, 3])
+    with pytest.raises(ShapeError):
+        s.scatter_by_mask(pl.Series([True, False]), 0)
+    with pytest.raises(ShapeError):
+        s.scatter_by_mask(pl.Series([True, True, False]), [1, 2, 3])
+    with pytest.raises(InvalidOperationError):
+        s.scatter_by_mask(pl.Series([True, False, False]), ["x"])
@@ -1008,6 +1008,24 @@ def test_mode() -> None:
     assert set(pl.int_range(0, 3, eager=True).mode().to_list()) == {0, 1, 2}
 
 
+def test_mode_ties() -> None:
+    s = pl.Series("a", [3, None, 1, 2, 1, 3, None])
+    assert s.mode().to_list() == [3, None, 1]
+    assert s.mode(ties="all").to_list() == [3, None, 1]
+    assert s.mode(ties="first").to_list() == [3]
+    assert s.mode(ties="smallest").to_list() == [1]
+
+    s = pl.Series("a", ["b", "c", "a", "c", "a"])
+    assert s.mode(ties="smallest").to_list() == ["a"]
+    assert s.mode(ties="first").to_list() == ["c"]
+
+    assert pl.Series("a", [], dtype=pl.Int64).mode(ties="first").to_list() == [None]
+    assert pl.Series("a", [None, None]).mode(ties="smallest").to_list() == [None]
+
+    with pytest.raises(ValueError, match="`ties` must be one of"):
+        s.mode(ties="last")  # type: ignore[arg-type]
+
+
 def test_diff() -> None:
     s = pl.Series("a", [1, 2, 3, 2, 2, 3, 0])
     expected = pl.Series("a", [1, 1, -1, 0, 1, -3])
@@ -1112,6 +1130,12 @@ def test_floor_divide() -> None:
     )
 
 
+def test_floor_ceil_decimals() -> None:
+    s = pl.Series("a", [0.29, 0.57, -0.29, 1.237, None])
+    assert_series_equal(s.floor(2), pl.Series("a", [0.29, 0.57, -0.29, 1.23, None]))
+    assert_series_equal(s.ceil(2), pl.Series("a", [0.29, 0.57, -0.29, 1.24, None]))
+
+
 def test_true_divide() -> None:
     s = pl.Series("a", [1, 2])
     assert_series_equal(s / 2, pl.Series("a", [0.5, 1.0]))
@@ -351,6 +351,30 @@ def test_read_csv(tmp_path: Path) -> None:
         pl.sql("SELECT * FROM read_csv('a','b','c')")
 
 
+def test_read_csv_options(tmp_path: Path) -> None:
+    csv_target = tmp_path / "test_sql_read_options.csv"
+    csv_target.write_text("# comment\n1;a\n2;b\n3;c\n")
+
+    res = pl.sql(
+        f"""
+        SELECT * FROM read_csv(
+          '{csv_target}',
+          has_header => false,
+          separator => ';',
+          comment_prefix => '#',
+          n_rows => 2
+        )
+        """
+    ).collect()
+    assert res.to_dict(as_series=False) == {"column_1": [1, 2], "column_2": ["a", "b"]}
+
+    with pytest.raises(
+        SQLSyntaxError,
+        match="`read_csv` does not support the option 'headers'",
+    ):
+        pl.sql(f"SELECT * FROM read_csv('{csv_target}', headers => false)")
+
+
 def test_global_variable_inference_17398() -> None:
     users = pl.DataFrame({"id": "1"})
 
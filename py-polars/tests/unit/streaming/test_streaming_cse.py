@@ -84,3 +84,28 @@ def test_cse_expr_group_by() -> None:
             comm_subexpr_elim=True, engine="streaming" if streaming else "in-memory"
         )
         assert_frame_equal(out, expected)
+
+
+def test_cse_expr_aggregations_streaming() -> None:
+    q = pl.LazyFrame({"a": [1, 2, 3, 4], "b": [4, 3, 2, 1]})
+
+    derived = pl.col("a") * pl.col("b")
+    total = pl.col("a").sum()
+
+    # Only the element-wise `derived` can be shared, the aggregation `total` can't.
+    q = q.select(
+        derived.sum().alias("sum"),
+        derived.max().alias("max"),
+        (total * 2).alias("total2"),
+        (total * 3).alias("total3"),
+    )
+
+    expected = pl.DataFrame({"sum": [20], "max": [6], "total2": [20], "total3": [30]})
+    assert_frame_equal(
+        q.collect(comm_subexpr_elim=True, engine="streaming"),
+        expected,
+    )
+    assert_frame_equal(
+        q.collect(comm_subexpr_elim=True, engine="in-memory"),
+        expected,
+    )
@@ -296,3 +296,47 @@ def test_sort_descending_nulls_last(descending: bool, nulls_last: bool) -> None:
         .collect(engine="streaming"),
         pl.DataFrame({"x": ref_x, "y": ref_y}),
     )
+
+
+@pytest.mark.write_disk
+@pytest.mark.parametrize("descending", [False, True])
+@pytest.mark.parametrize("nulls_last", [False, True])
+@pytest.mark.parametrize("maintain_order", [False, True])
+def test_streaming_sort_spill(
+    tmp_path: Path,
+    monkeypatch: Any,
+    capfd: Any,
+    descending: bool,
+    nulls_last: bool,
+    maintain_order: bool,
+) -> None:
+    monkeypatch.setenv("POLARS_TEMP_DIR", str(tmp_path))
+    monkeypatch.setenv("POLARS_IDEAL_MORSEL_SIZE", "100")
+    n = 5_000
+    df = pl.DataFrame(
+        {
+            "a": [None if i % 11 == 0 else (i * 7) % 13 for i in range(n)],
+            "b": [str((i * 31) % 17) for i in range(n)],
+            "c": range(n),
+        }
+    )
+    q = df.lazy().sort(
+        "a",
+        "b",
+        descending=descending,
+        nulls_last=nulls_last,
+        maintain_order=maintain_order,
+    )
+    with pl.Config(streaming_spill_threshold=1024, verbose=True):
+        out = q.collect(engine="streaming")
+    assert "[sort]: spilled" in capfd.readouterr().err
+
+    if maintain_order:
+        assert_frame_equal(out, q.collect(engine="in-memory"))
+    else:
+        expected = q.collect(engine="in-memory")
+        assert_frame_equal(out.drop("c"), expected.drop("c"))
+        assert Counter(out.rows()) == Counter(df.rows())
+
+    # Spilled files are removed after the query.
+    assert not list(tmp_path.rglob("*.ipc"))
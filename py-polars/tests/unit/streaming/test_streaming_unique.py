@@ -53,3 +53,34 @@ def test_streaming_unique(monkeypatch: Any, capfd: Any) -> None:
     assert_frame_equal(q.collect(engine="old-streaming"), q.collect(engine="in-memory"))  # type: ignore[call-overload]
     (_, err) = capfd.readouterr()
     assert "df -> re-project-sink -> sort_multiple" in err
+
+
+@pytest.mark.write_disk
+@pytest.mark.parametrize("keep", ["first", "last", "any", "none"])
+@pytest.mark.parametrize("maintain_order", [False, True])
+def test_streaming_unique_spill(
+    tmp_path: Path, monkeypatch: Any, capfd: Any, keep: Any, maintain_order: bool
+) -> None:
+    monkeypatch.setenv("POLARS_TEMP_DIR", str(tmp_path))
+    df = pl.DataFrame(
+        {
+            "a": [i % 7 for i in range(1_000)],
+            "b": [i % 3 for i in range(1_000)],
+            "c": list(range(1_000)),
+        }
+    )
+    q = df.lazy().unique(subset=["a", "b"], keep=keep, maintain_order=maintain_order)
+    capfd.readouterr()
+    with pl.Config(streaming_spill_threshold=1024, verbose=True):
+        out = q.collect(engine="streaming")
+    (_, err) = capfd.readouterr()
+    assert "[unique]: spilled" in err
+
+    expected = q.collect(engine="in-memory")
+    if not maintain_order:
+        out = out.sort("a", "b", "c")
+        expected = expected.sort("a", "b", "c")
+    if keep == "any":
+        assert out.select("a", "b").equals(expected.select("a", "b"))
+    else:
+        assert_frame_equal(out, expected)
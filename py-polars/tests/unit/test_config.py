@@ -838,6 +838,25 @@ def test_set_streaming_chunk_size() -> None:
         cfg.set_streaming_chunk_size(0)
 
 
+def test_set_streaming_memory_limit() -> None:
+    lf = pl.LazyFrame({"a": range(10_000)})
+    with pl.Config(streaming_memory_limit=1000):
+        with pytest.raises(pl.exceptions.ComputeError, match="memory budget"):
+            lf.collect(engine="streaming")
+
+    with pytest.raises(ValueError), pl.Config() as cfg:
+        cfg.set_streaming_memory_limit(-1)
+
+
+def test_set_streaming_spill_threshold() -> None:
+    with pl.Config(streaming_spill_threshold=1000):
+        assert os.environ["POLARS_STREAMING_SPILL_THRESHOLD"] == "1000"
+    assert "POLARS_STREAMING_SPILL_THRESHOLD" not in os.environ
+
+    with pytest.raises(ValueError), pl.Config() as cfg:
+        cfg.set_streaming_spill_threshold(-1)
+
+
 def test_set_fmt_str_lengths_invalid_length() -> None:
     with pl.Config() as cfg:
         with pytest.raises(ValueError):
@@ -993,6 +1012,13 @@ def test_warn_unstable(recwarn: pytest.WarningsRecorder) -> None:
             "1",
         ),
         ("POLARS_STREAMING_CHUNK_SIZE", "set_streaming_chunk_size", 100, "100"),
+        ("POLARS_STREAMING_MEMORY_LIMIT", "set_streaming_memory_limit", 1024, "1024"),
+        (
+            "POLARS_STREAMING_SPILL_THRESHOLD",
+            "set_streaming_spill_threshold",
+            1024,
+            "1024",
+        ),
         ("POLARS_TABLE_WIDTH", "set_tbl_width_chars", 80, "80"),
         ("POLARS_VERBOSE", "set_verbose", True, "1"),
         ("POLARS_WARN_UNSTABLE", "warn_unstable", True, "1"),
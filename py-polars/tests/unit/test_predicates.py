@@ -416,6 +416,35 @@ def test_predicate_pushdown_with_window_projections_12637() -> None:
     )
 
 
+def test_predicate_pushdown_window_overwritten_partition_key() -> None:
+    lf = pl.LazyFrame({"key": [1, 1, 2], "value": [1, 2, 3]})
+
+    # The filter refers to the overwritten key, not the one the window is partitioned by.
+    q = lf.with_columns(
+        pl.col("key") + 1,
+        pl.col("value").sum().over("key").alias("sum"),
+    ).filter(pl.col("key") == 2)
+
+    plan = q.explain()
+    assert re.search(r"FILTER.*FROM\n\s*DF", plan, re.DOTALL) is None
+    assert_frame_equal(
+        q.collect(),
+        pl.DataFrame({"key": [2, 2], "value": [1, 2], "sum": [3, 3]}),
+    )
+
+    # The window partitions are unchanged by filtering on the key.
+    q = lf.with_columns(
+        pl.col("value").sum().over("key").alias("sum"),
+    ).filter(pl.col("key") == 1)
+
+    plan = q.explain()
+    assert re.search(r"FILTER.*FROM\n\s*DF", plan, re.DOTALL) is not None
+    assert_frame_equal(
+        q.collect(),
+        pl.DataFrame({"key": [1, 1], "value": [1, 2], "sum": [3, 3]}),
+    )
+
+
 def test_predicate_reduction() -> None:
     # ensure we get clean reduction without casts
     lf = pl.LazyFrame({"a": [1], "b": [2]})